@@ -0,0 +1,47 @@
+use mc_server_wrapper_core::automation::{self, AutomationConfig, AutomationFormat, AutomationImportSummary};
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::scheduler::SchedulerManager;
+use anyhow::Context;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::State;
+use super::{CommandResult, AppError, resolve_instance_id};
+
+/// Writes schedules, webhooks and notification rules of `instance_ids` (all instances when
+/// empty) to `dest_path`, as TOML for `.toml` files and JSON otherwise.
+#[tauri::command]
+pub async fn export_automation_config(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_ids: Vec<String>,
+    dest_path: String,
+) -> CommandResult<String> {
+    let mut ids = Vec::new();
+    for instance_id in &instance_ids {
+        ids.push(resolve_instance_id(&instance_manager, instance_id).await?);
+    }
+    let config = automation::export_automation(&instance_manager, &ids).await.map_err(AppError::from)?;
+    let dest = PathBuf::from(dest_path);
+    let text = config.to_string(AutomationFormat::from_path(&dest)).map_err(AppError::from)?;
+    tokio::fs::write(&dest, text)
+        .await
+        .with_context(|| format!("Failed to write {}", dest.display()))
+        .map_err(AppError::from)?;
+    Ok(dest.to_string_lossy().to_string())
+}
+
+#[tauri::command]
+pub async fn import_automation_config(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    scheduler: State<'_, Arc<SchedulerManager>>,
+    source_path: String,
+) -> CommandResult<AutomationImportSummary> {
+    let source = PathBuf::from(source_path);
+    let text = tokio::fs::read_to_string(&source)
+        .await
+        .with_context(|| format!("Failed to read {}", source.display()))
+        .map_err(AppError::from)?;
+    let config = AutomationConfig::parse(&text, AutomationFormat::from_path(&source)).map_err(AppError::from)?;
+    automation::import_automation(&instance_manager, Some(&scheduler), &config)
+        .await
+        .map_err(AppError::from)
+}
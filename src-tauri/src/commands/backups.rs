@@ -1,61 +1,58 @@
 use mc_server_wrapper_core::backup::{BackupManager, BackupInfo};
-use mc_server_wrapper_core::instance::InstanceManager;
-use tauri::{State, Window, Emitter};
+use mc_server_wrapper_core::instance::{InstanceManager, InstanceMetadata};
+use mc_server_wrapper_core::manager::ServerManager;
+use mc_server_wrapper_core::notifications::{self, NotificationEvent};
+use mc_server_wrapper_core::tasks::{TaskKind, TaskManager};
+use mc_server_wrapper_core::timeline::TimelineKind;
+use tauri::State;
 use std::sync::Arc;
-use uuid::Uuid;
-use serde::Serialize;
-use super::{CommandResult, AppError};
-
-#[derive(Clone, Serialize)]
-struct BackupProgress {
-    instance_id: String,
-    current: u64,
-    total: u64,
-    message: String,
-}
+use super::{CommandResult, AppError, resolve_instance_id};
 
 #[tauri::command]
 pub async fn list_backups(
+    instance_manager: State<'_, Arc<InstanceManager>>,
     backup_manager: State<'_, Arc<BackupManager>>,
     instance_id: String,
 ) -> CommandResult<Vec<BackupInfo>> {
-    let id = Uuid::parse_str(&instance_id).map_err(AppError::from)?;
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
     backup_manager.list_backups(id).await.map_err(AppError::from)
 }
 
 #[tauri::command]
 pub async fn create_backup(
-    window: Window,
     backup_manager: State<'_, Arc<BackupManager>>,
     instance_manager: State<'_, Arc<InstanceManager>>,
+    server_manager: State<'_, Arc<ServerManager>>,
+    task_manager: State<'_, Arc<TaskManager>>,
     instance_id: String,
     name: String,
 ) -> CommandResult<BackupInfo> {
-    let id = Uuid::parse_str(&instance_id).map_err(AppError::from)?;
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
     let instance = instance_manager.get_instance(id).await
         .map_err(AppError::from)?
         .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
 
-    let instance_id_clone = instance_id.clone();
-    let window_clone = window.clone();
+    let task = task_manager.start(TaskKind::Backup, format!("Backing up {}", instance.name), Some(id));
+    let report = task.reporter();
+    let result = backup_manager.create_backup(id, &instance.path, &name, move |current, total| {
+        report(current, total, format!("Backing up files ({}/{})", current, total));
+    }).await;
+    let info = task.finish(result).map_err(AppError::from)?;
 
-    backup_manager.create_backup(id, &instance.path, &name, move |current, total| {
-        let _ = window_clone.emit("backup-progress", BackupProgress {
-            instance_id: instance_id_clone.clone(),
-            current,
-            total,
-            message: format!("Backing up files ({}/{})", current, total),
-        });
-    }).await.map_err(AppError::from)
+    instance_manager.note_timeline_event(id, TimelineKind::Backup, format!("Backup {}", info.name), None).await;
+    let cache = server_manager.get_cache();
+    notifications::notify(cache.get_client(), &instance, NotificationEvent::BackupCompleted { backup: info.name.clone() }).await;
+    Ok(info)
 }
 
 #[tauri::command]
 pub async fn delete_backup(
+    instance_manager: State<'_, Arc<InstanceManager>>,
     backup_manager: State<'_, Arc<BackupManager>>,
     instance_id: String,
     backup_name: String,
 ) -> CommandResult<()> {
-    let id = Uuid::parse_str(&instance_id).map_err(AppError::from)?;
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
     backup_manager.delete_backup(id, &backup_name).await.map_err(AppError::from)
 }
 
@@ -66,7 +63,7 @@ pub async fn restore_backup(
     instance_id: String,
     backup_name: String,
 ) -> CommandResult<()> {
-    let id = Uuid::parse_str(&instance_id).map_err(AppError::from)?;
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
     let instance = instance_manager.get_instance(id).await
         .map_err(AppError::from)?
         .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
@@ -76,11 +73,12 @@ pub async fn restore_backup(
 
 #[tauri::command]
 pub async fn open_backup(
+    instance_manager: State<'_, Arc<InstanceManager>>,
     backup_manager: State<'_, Arc<BackupManager>>,
     instance_id: String,
     backup_name: String,
 ) -> CommandResult<()> {
-    let id = Uuid::parse_str(&instance_id).map_err(AppError::from)?;
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
     let backups = backup_manager.list_backups(id).await.map_err(AppError::from)?;
     
     let backup = backups.into_iter()
@@ -117,3 +115,27 @@ pub async fn open_backup(
 
     Ok(())
 }
+
+/// Moves archives from an imported server's `backups/` folder into its backup list.
+/// Failures are logged rather than failing the import.
+pub(crate) async fn adopt_imported_backups(
+    backup_manager: &BackupManager,
+    instance_manager: &InstanceManager,
+    instance: &InstanceMetadata,
+) {
+    match backup_manager.adopt_backups(instance.id, &instance.path).await {
+        Ok(report) if !report.adopted.is_empty() || !report.skipped.is_empty() => {
+            let details = (!report.skipped.is_empty()).then(|| {
+                format!("Left in backups/ because they don't contain the whole server: {}", report.skipped.join(", "))
+            });
+            instance_manager.note_timeline_event(
+                instance.id,
+                TimelineKind::Backup,
+                format!("Adopted {} backups from the imported server", report.adopted.len()),
+                details,
+            ).await;
+        }
+        Ok(_) => {}
+        Err(e) => log::error!("Failed to adopt the backups of {}: {}", instance.name, e),
+    }
+}
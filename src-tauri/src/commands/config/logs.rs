@@ -0,0 +1,60 @@
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::log_files::{self, LogFileInfo, LogPage};
+use mc_server_wrapper_core::diagnostics::{self, DiagnosticsBundle, DiagnosticsOptions, DiagnosticsSources};
+use mc_server_wrapper_core::java::JavaManager;
+use tauri::State;
+use std::sync::Arc;
+use super::super::{CommandResult, AppError};
+use crate::setup::AppStorage;
+
+/// The folder holding the wrapper's `logs/`, laid out like an instance's so the log file
+/// helpers apply to it.
+fn app_log_root(storage: &AppStorage) -> std::path::PathBuf {
+    let log_dir = crate::setup::app_log_dir(storage.data_dir());
+    log_dir.parent().map(|p| p.to_path_buf()).unwrap_or(log_dir)
+}
+
+/// The wrapper's own log files, current and rotated.
+#[tauri::command]
+pub async fn list_app_logs(storage: State<'_, AppStorage>) -> CommandResult<Vec<LogFileInfo>> {
+    log_files::list_log_files(&app_log_root(&storage)).await.map_err(AppError::from)
+}
+
+/// A page of a wrapper log file, `app.log` unless named. Without an offset the end of
+/// the file is returned, which is what a bug report needs.
+#[tauri::command]
+pub async fn get_app_logs(
+    storage: State<'_, AppStorage>,
+    name: Option<String>,
+    offset: Option<u64>,
+    limit: Option<u64>,
+) -> CommandResult<LogPage> {
+    let root = app_log_root(&storage);
+    let name = name.unwrap_or_else(|| "app.log".to_string());
+    let limit = limit.unwrap_or(log_files::MAX_PAGE_SIZE);
+    match offset {
+        Some(offset) => log_files::read_log_page(&root, &name, offset, limit).await,
+        None => log_files::read_log_tail(&root, &name, limit).await,
+    }
+    .map_err(AppError::from)
+}
+
+/// Writes a zip of wrapper logs, sanitized instance metadata, latest.log tails, crash
+/// reports, Java runtimes and system info to `dest_path` for a bug report.
+#[tauri::command]
+pub async fn export_diagnostics(
+    storage: State<'_, AppStorage>,
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    java_manager: State<'_, Arc<JavaManager>>,
+    dest_path: String,
+    redact: bool,
+) -> CommandResult<DiagnosticsBundle> {
+    let sources = DiagnosticsSources {
+        app_log_root: app_log_root(&storage),
+        instances: instance_manager.list_instances().await.map_err(AppError::from)?,
+        java_versions: java_manager.discover_installed_versions().await.unwrap_or_default(),
+    };
+    diagnostics::export_diagnostics(sources, std::path::Path::new(&dest_path), DiagnosticsOptions { redact })
+        .await
+        .map_err(AppError::from)
+}
@@ -0,0 +1,304 @@
+pub mod logs;
+
+pub use logs::*;
+
+use mc_server_wrapper_core::instance::{InstanceManager, InstanceMetadata};
+use mc_server_wrapper_core::config_files;
+use mc_server_wrapper_core::app_config::{AppSettings, GlobalConfigManager};
+use mc_server_wrapper_core::credentials::{self, CredentialKind, CredentialStatus};
+use mc_server_wrapper_core::manager::ServerManager;
+use mc_server_wrapper_core::timeline::TimelineKind;
+use mc_server_wrapper_core::utils::{ApiMirror, UpstreamCheck, bmclapi_mirrors, check_upstreams};
+use tauri::State;
+use std::sync::Arc;
+use super::{CommandResult, AppError, resolve_instance_id};
+
+/// Whether config saves should keep the previous file as `<name>.bak`.
+pub(crate) async fn backup_on_save(config_manager: &GlobalConfigManager) -> bool {
+    config_manager.load().await.map(|s| s.backup_configs_on_save).unwrap_or(true)
+}
+
+#[tauri::command]
+pub async fn get_app_settings(
+    config_manager: State<'_, Arc<GlobalConfigManager>>,
+) -> CommandResult<AppSettings> {
+    config_manager.load().await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn update_app_settings(
+    config_manager: State<'_, Arc<GlobalConfigManager>>,
+    server_manager: State<'_, Arc<ServerManager>>,
+    settings: AppSettings,
+) -> CommandResult<()> {
+    // Checked first so a storage root that still holds instances isn't dropped
+    server_manager
+        .get_instance_manager()
+        .set_storage_roots(&settings.storage_roots)
+        .await
+        .map_err(AppError::from)?;
+    config_manager.save(&settings).await.map_err(AppError::from)?;
+    crate::setup::apply_log_level(settings.logging.level);
+    server_manager.get_cache().set_max_disk_bytes(settings.cache_limits.max_disk_bytes()).await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_config_value(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    rel_path: String,
+    format: Option<config_files::ConfigFormat>,
+) -> CommandResult<serde_json::Value> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+    let format = config_files::detect_config_format(&instance.path, &rel_path, format).await.map_err(AppError::from)?;
+    
+    config_files::read_config_value(&instance.path, &rel_path, format).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn save_config_value(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    config_manager: State<'_, Arc<GlobalConfigManager>>,
+    instance_id: String,
+    rel_path: String,
+    format: Option<config_files::ConfigFormat>,
+    value: serde_json::Value,
+) -> CommandResult<()> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+    let format = config_files::detect_config_format(&instance.path, &rel_path, format).await.map_err(AppError::from)?;
+    
+    let backup = backup_on_save(&config_manager).await;
+    config_files::save_config_value(&instance.path, &rel_path, format, value, backup).await.map_err(AppError::from)?;
+    instance_manager.note_timeline_event(id, TimelineKind::ConfigChange, format!("Edited {}", rel_path), None).await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_available_configs(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+) -> CommandResult<Vec<config_files::ConfigFile>> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+    
+    Ok(config_files::list_available_configs(&instance.path, instance.mod_loader.as_deref()).await)
+}
+
+#[tauri::command]
+pub async fn get_config_tree(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+) -> CommandResult<Vec<config_files::ConfigTreeNode>> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+
+    Ok(config_files::list_config_tree(&instance.path, instance.mod_loader.as_deref()).await)
+}
+
+/// Groups the instance's configs by owning mod or plugin without walking their folders;
+/// a group's files are listed by [`get_group_configs`] when it is opened.
+#[tauri::command]
+pub async fn get_config_groups(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+) -> CommandResult<Vec<config_files::ConfigGroup>> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+
+    Ok(config_files::list_config_groups(&instance.path).await)
+}
+
+#[tauri::command]
+pub async fn get_group_configs(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    kind: config_files::ConfigOwnerKind,
+    owner: String,
+) -> CommandResult<Vec<config_files::ConfigFile>> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+
+    Ok(config_files::list_group_configs(&instance.path, kind, &owner).await)
+}
+
+#[tauri::command]
+pub async fn get_config_file(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    rel_path: String,
+    format: Option<config_files::ConfigFormat>,
+) -> CommandResult<std::collections::HashMap<String, String>> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+    let format = config_files::detect_config_format(&instance.path, &rel_path, format).await.map_err(AppError::from)?;
+    
+    config_files::read_config_file(&instance.path, &rel_path, format).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn save_config_file(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    config_manager: State<'_, Arc<GlobalConfigManager>>,
+    instance_id: String,
+    rel_path: String,
+    format: Option<config_files::ConfigFormat>,
+    properties: std::collections::HashMap<String, String>,
+) -> CommandResult<()> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+    let format = config_files::detect_config_format(&instance.path, &rel_path, format).await.map_err(AppError::from)?;
+    
+    let backup = backup_on_save(&config_manager).await;
+    config_files::save_config_file(&instance.path, &rel_path, format, properties, backup).await.map_err(AppError::from)?;
+    instance_manager.note_timeline_event(id, TimelineKind::ConfigChange, format!("Edited {}", rel_path), None).await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_server_properties(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+) -> CommandResult<std::collections::HashMap<String, String>> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+    
+    config_files::read_config_file(&instance.path, "server.properties", config_files::ConfigFormat::Properties).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn save_server_properties(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    config_manager: State<'_, Arc<GlobalConfigManager>>,
+    instance_id: String,
+    properties: std::collections::HashMap<String, String>,
+) -> CommandResult<()> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+    
+    // Save the properties file
+    let backup = backup_on_save(&config_manager).await;
+    config_files::save_config_file(&instance.path, "server.properties", config_files::ConfigFormat::Properties, properties.clone(), backup).await.map_err(AppError::from)?;
+    instance_manager.note_timeline_event(id, TimelineKind::ConfigChange, "Edited server.properties", None).await;
+
+    // If the port changed, update the instance settings in the DB
+    if let Some(port_str) = properties.get("server-port") {
+        if let Ok(port) = port_str.parse::<u16>() {
+            if port != instance.settings.port {
+                let mut new_settings = instance.settings.clone();
+                new_settings.port = port;
+                instance_manager.update_settings(id, None, new_settings).await.map_err(AppError::from)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_related_instances(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+) -> CommandResult<Vec<InstanceMetadata>> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    instance_manager.get_related_instances(id).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn compare_instance_configs(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_ids: Vec<String>,
+) -> CommandResult<Vec<config_files::ConfigDrift>> {
+    let mut ids = Vec::with_capacity(instance_ids.len());
+    for instance_id in &instance_ids {
+        ids.push(resolve_instance_id(&instance_manager, instance_id).await?);
+    }
+    instance_manager.compare_instance_configs(&ids).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn sync_config_keys(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    source_id: String,
+    target_ids: Vec<String>,
+    rel_path: String,
+    format: config_files::ConfigFormat,
+    paths: Vec<Vec<String>>,
+) -> CommandResult<()> {
+    let source = resolve_instance_id(&instance_manager, &source_id).await?;
+    let mut targets = Vec::with_capacity(target_ids.len());
+    for target_id in &target_ids {
+        targets.push(resolve_instance_id(&instance_manager, target_id).await?);
+    }
+    instance_manager
+        .sync_config_keys(source, &targets, &rel_path, format, &paths)
+        .await
+        .map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn get_credential_status(
+    config_manager: State<'_, Arc<GlobalConfigManager>>,
+) -> CommandResult<CredentialStatus> {
+    let credentials = config_manager.load_credentials().await.map_err(AppError::from)?;
+    Ok(credentials.status())
+}
+
+#[tauri::command]
+pub async fn set_credential(
+    config_manager: State<'_, Arc<GlobalConfigManager>>,
+    kind: CredentialKind,
+    value: Option<String>,
+) -> CommandResult<()> {
+    config_manager.set_credential(kind, value).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn test_credential(
+    config_manager: State<'_, Arc<GlobalConfigManager>>,
+    server_manager: State<'_, Arc<ServerManager>>,
+    kind: CredentialKind,
+    value: Option<String>,
+) -> CommandResult<()> {
+    // Without a value, the stored credential is tested
+    let value = match value {
+        Some(value) => value,
+        None => config_manager
+            .load_credentials()
+            .await
+            .map_err(AppError::from)?
+            .get(kind)
+            .cloned()
+            .ok_or_else(|| AppError::NotFound("Credential is not set".to_string()))?,
+    };
+    let cache = server_manager.get_cache();
+    credentials::test_credential(cache.get_client(), kind, &value)
+        .await
+        .map_err(AppError::from)
+}
+
+/// Checks which version and loader APIs, and which of their mirrors, can be reached.
+#[tauri::command]
+pub async fn test_connectivity(
+    server_manager: State<'_, Arc<ServerManager>>,
+) -> CommandResult<Vec<UpstreamCheck>> {
+    let cache = server_manager.get_cache();
+    Ok(check_upstreams(cache.get_client(), cache.download_limits()).await)
+}
+
+#[tauri::command]
+pub async fn get_bmclapi_mirrors() -> CommandResult<Vec<ApiMirror>> {
+    Ok(bmclapi_mirrors())
+}
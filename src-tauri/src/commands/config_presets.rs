@@ -0,0 +1,53 @@
+use mc_server_wrapper_core::instance::{InstanceManager, InstanceMetadata};
+use mc_server_wrapper_core::config_files::{self, ConfigPreset, PresetPreview};
+use mc_server_wrapper_core::app_config::GlobalConfigManager;
+use mc_server_wrapper_core::timeline::TimelineKind;
+use tauri::State;
+use std::sync::Arc;
+use super::config::backup_on_save;
+use super::{CommandResult, AppError, resolve_instance_id};
+
+async fn paper_instance(instance_manager: &InstanceManager, instance_id: &str) -> CommandResult<InstanceMetadata> {
+    let id = resolve_instance_id(instance_manager, instance_id).await?;
+    let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+    if !config_files::presets_supported(instance.mod_loader.as_deref()) {
+        return Err(AppError::Validation("Config presets need a Paper, Purpur or Folia server".to_string()));
+    }
+    Ok(instance)
+}
+
+#[tauri::command]
+pub async fn list_config_presets() -> CommandResult<Vec<ConfigPreset>> {
+    Ok(config_files::builtin_presets())
+}
+
+#[tauri::command]
+pub async fn preview_config_preset(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    preset_id: String,
+) -> CommandResult<PresetPreview> {
+    let instance = paper_instance(&instance_manager, &instance_id).await?;
+    let preset = config_files::find_preset(&preset_id).map_err(AppError::from)?;
+    config_files::preview_preset(&instance.path, &preset).await.map_err(AppError::from)
+}
+
+/// Applies every key of the preset, or none of them. Returns the files that changed.
+#[tauri::command]
+pub async fn apply_config_preset(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    config_manager: State<'_, Arc<GlobalConfigManager>>,
+    instance_id: String,
+    preset_id: String,
+) -> CommandResult<Vec<String>> {
+    let instance = paper_instance(&instance_manager, &instance_id).await?;
+    let preset = config_files::find_preset(&preset_id).map_err(AppError::from)?;
+
+    let backup = backup_on_save(&config_manager).await;
+    let changed = config_files::apply_preset(&instance.path, &preset, backup).await.map_err(AppError::from)?;
+    if !changed.is_empty() {
+        instance_manager.note_timeline_event(instance.id, TimelineKind::ConfigChange, format!("Applied the {} preset", preset.name), None).await;
+    }
+    Ok(changed)
+}
@@ -0,0 +1,41 @@
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::config_files::{self, ConfigMatch, ConfigSearch, ReplaceReport};
+use mc_server_wrapper_core::app_config::GlobalConfigManager;
+use tauri::State;
+use std::sync::Arc;
+use super::config::backup_on_save;
+use super::{CommandResult, AppError, resolve_instance_id};
+
+#[tauri::command]
+pub async fn search_instance_configs(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    search: ConfigSearch,
+) -> CommandResult<Vec<ConfigMatch>> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+
+    config_files::search_configs(&instance.path, &search).await.map_err(AppError::from)
+}
+
+/// Replaces the matches the user reviewed; fails without writing if the files changed since.
+#[tauri::command]
+pub async fn replace_in_instance_configs(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    config_manager: State<'_, Arc<GlobalConfigManager>>,
+    instance_id: String,
+    search: ConfigSearch,
+    replacement: String,
+    files: Vec<String>,
+    expected_matches: usize,
+) -> CommandResult<ReplaceReport> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+
+    let backup = backup_on_save(&config_manager).await;
+    config_files::replace_in_configs(&instance.path, &search, &replacement, &files, expected_matches, backup)
+        .await
+        .map_err(AppError::from)
+}
@@ -0,0 +1,89 @@
+use super::server::ensure_server_logs_forwarded;
+use super::{AppError, AppState, CommandResult, resolve_instance_id};
+use mc_server_wrapper_core::console::{
+    CommandSuggestion, DiscoveredCommand, load_command_cache, suggest_commands,
+};
+use mc_server_wrapper_core::instance::{CommandHistoryEntry, InstanceManager};
+use mc_server_wrapper_core::manager::ServerManager;
+use std::sync::Arc;
+use tauri::State;
+use uuid::Uuid;
+
+/// Number of history entries consulted when building suggestions.
+const SUGGESTION_HISTORY_DEPTH: u32 = 100;
+
+#[tauri::command]
+pub async fn get_command_history(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    limit: Option<u32>,
+) -> CommandResult<Vec<CommandHistoryEntry>> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    instance_manager
+        .get_command_history(id, limit.unwrap_or(50))
+        .await
+        .map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn get_command_suggestions(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    prefix: String,
+) -> CommandResult<Vec<CommandSuggestion>> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager
+        .get_instance(id)
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+
+    let history: Vec<String> = instance_manager
+        .get_command_history(id, SUGGESTION_HISTORY_DEPTH)
+        .await
+        .map_err(AppError::from)?
+        .into_iter()
+        .map(|entry| entry.command)
+        .collect();
+
+    let discovered = load_command_cache(&instance.path).await.unwrap_or_default();
+
+    Ok(suggest_commands(
+        &prefix,
+        instance.mod_loader.as_deref(),
+        &history,
+        &discovered,
+    ))
+}
+
+#[tauri::command]
+pub async fn get_server_commands(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+    refresh: Option<bool>,
+) -> CommandResult<Vec<DiscoveredCommand>> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    server_manager
+        .get_server_commands(id, refresh.unwrap_or(false))
+        .await
+        .map_err(AppError::from)
+}
+
+/// Runs a shell command in the instance folder. Output shows up in the console like
+/// server output; the returned task id can be used to cancel it.
+#[tauri::command]
+pub async fn run_shell_command(
+    server_manager: State<'_, Arc<ServerManager>>,
+    app_state: State<'_, AppState>,
+    app_handle: tauri::AppHandle,
+    instance_id: String,
+    command: String,
+) -> CommandResult<Uuid> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    let server = server_manager.get_or_create_server(id).await.map_err(AppError::from)?;
+    ensure_server_logs_forwarded(&app_state, server, app_handle, id.to_string()).await?;
+    server_manager
+        .run_shell_command(id, &command)
+        .await
+        .map_err(AppError::from)
+}
@@ -0,0 +1,28 @@
+use mc_server_wrapper_core::crash::{CrashReport, CrashReportSummary};
+use mc_server_wrapper_core::instance::InstanceManager;
+use tauri::State;
+use std::sync::Arc;
+use super::{CommandResult, AppError, resolve_instance_id};
+
+#[tauri::command]
+pub async fn list_crash_reports(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+) -> CommandResult<Vec<CrashReportSummary>> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    instance_manager.list_crash_reports(id).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn get_crash_report(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    report_id: i64,
+) -> CommandResult<CrashReport> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    instance_manager
+        .get_crash_report(id, report_id)
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Crash report not found".to_string()))
+}
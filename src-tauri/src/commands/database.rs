@@ -1,12 +1,19 @@
-use crate::commands::CommandResult;
+use crate::commands::{CommandResult, resolve_instance_id};
 use log::{error, info};
-use mc_server_wrapper_core::database::explorer::{self, ColumnInfo, DatabaseGroup, TableData};
+use mc_server_wrapper_core::database::editor::{DatabaseEditor, RowUpdate, WritableDatabase};
+use mc_server_wrapper_core::database::explorer::{self, ColumnInfo, DatabaseGroup, QueryResult, TableData};
 use mc_server_wrapper_core::errors::AppError;
-use mc_server_wrapper_core::instance::InstanceManager;
-use std::path::PathBuf;
+use mc_server_wrapper_core::instance::{InstanceManager, QueryHistoryEntry};
+use mc_server_wrapper_core::java::JavaManager;
+use mc_server_wrapper_core::manager::ServerManager;
+use serde::Serialize;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tauri::State;
-use uuid::Uuid;
+use tauri::{AppHandle, Emitter, State};
+
+const QUERY_ROWS_EVENT: &str = "query-rows";
+const STREAM_BATCH_SIZE: usize = 500;
 
 #[tauri::command]
 pub async fn explore_find_databases(
@@ -14,9 +21,8 @@ pub async fn explore_find_databases(
     instance_id: String,
 ) -> CommandResult<Vec<DatabaseGroup>> {
     info!("Exploring databases for instance: {}", instance_id);
-    let id = Uuid::parse_str(&instance_id).map_err(|e| {
-        error!("Failed to parse instance UUID: {}", e);
-        AppError::from(e)
+    let id = resolve_instance_id(&instance_manager, &instance_id).await.inspect_err(|e| {
+        error!("Failed to resolve instance {}: {}", instance_id, e);
     })?;
 
     let instance = instance_manager
@@ -38,17 +44,23 @@ pub async fn explore_find_databases(
 }
 
 #[tauri::command]
-pub async fn explore_list_tables(path: PathBuf) -> CommandResult<Vec<String>> {
+pub async fn explore_list_tables(
+    java_manager: State<'_, Arc<JavaManager>>,
+    path: PathBuf,
+) -> CommandResult<Vec<String>> {
+    let path = explorer::readable_path(&path, &java_manager).await?;
     explorer::list_tables(&path).await.map_err(|e| e.into())
 }
 
 #[tauri::command]
 pub async fn explore_get_data(
+    java_manager: State<'_, Arc<JavaManager>>,
     path: PathBuf,
     table: String,
     limit: u32,
     offset: u32,
 ) -> CommandResult<TableData> {
+    let path = explorer::readable_path(&path, &java_manager).await?;
     explorer::get_table_data(&path, &table, limit, offset)
         .await
         .map_err(|e| e.into())
@@ -65,3 +77,155 @@ pub async fn explore_get_schema(path: PathBuf, table: String) -> CommandResult<V
         .await
         .map_err(|e| e.into())
 }
+
+async fn open_for_write(
+    instance_manager: &InstanceManager,
+    server_manager: &ServerManager,
+    editor: &DatabaseEditor,
+    instance_id: &str,
+    path: &Path,
+) -> CommandResult<WritableDatabase> {
+    let id = resolve_instance_id(instance_manager, instance_id).await?;
+    let instance = instance_manager
+        .get_instance(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
+    let status = server_manager.get_server_status(id).await;
+    Ok(editor.open_for_write(&instance.path, status, path).await?)
+}
+
+#[tauri::command]
+pub async fn explore_update_row(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    server_manager: State<'_, Arc<ServerManager>>,
+    editor: State<'_, Arc<DatabaseEditor>>,
+    instance_id: String,
+    path: PathBuf,
+    update: RowUpdate,
+) -> CommandResult<u64> {
+    let db = open_for_write(&instance_manager, &server_manager, &editor, &instance_id, &path).await?;
+    Ok(db.update_row(&update).await?)
+}
+
+#[tauri::command]
+pub async fn explore_delete_row(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    server_manager: State<'_, Arc<ServerManager>>,
+    editor: State<'_, Arc<DatabaseEditor>>,
+    instance_id: String,
+    path: PathBuf,
+    table: String,
+    rowid: i64,
+) -> CommandResult<u64> {
+    let db = open_for_write(&instance_manager, &server_manager, &editor, &instance_id, &path).await?;
+    Ok(db.delete_row(&table, rowid).await?)
+}
+
+#[tauri::command]
+pub async fn explore_execute(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    server_manager: State<'_, Arc<ServerManager>>,
+    editor: State<'_, Arc<DatabaseEditor>>,
+    instance_id: String,
+    path: PathBuf,
+    sql: String,
+    params: Vec<Value>,
+) -> CommandResult<u64> {
+    let db = open_for_write(&instance_manager, &server_manager, &editor, &instance_id, &path).await?;
+    Ok(db.execute_statement(&sql, &params).await?)
+}
+
+/// Resolves the instance owning a database and the key its query history is stored under.
+async fn history_target(
+    instance_manager: &InstanceManager,
+    instance_id: &str,
+    path: &Path,
+) -> CommandResult<(uuid::Uuid, String)> {
+    let id = resolve_instance_id(instance_manager, instance_id).await?;
+    let instance = instance_manager
+        .get_instance(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
+    Ok((id, explorer::history_key(&instance.path, path)))
+}
+
+#[tauri::command]
+pub async fn explore_execute_query(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    path: PathBuf,
+    sql: String,
+    limit: u32,
+    offset: u32,
+) -> CommandResult<QueryResult> {
+    let result = explorer::execute_query(&path, &sql, limit, offset).await?;
+    // Paging through the same query isn't a new entry
+    if offset == 0 {
+        let (id, key) = history_target(&instance_manager, &instance_id, &path).await?;
+        if let Err(e) = instance_manager.record_query(id, &key, &sql).await {
+            error!("Failed to record query history: {}", e);
+        }
+    }
+    Ok(result)
+}
+
+#[tauri::command]
+pub async fn explore_explain_query(path: PathBuf, sql: String) -> CommandResult<QueryResult> {
+    Ok(explorer::explain_query(&path, &sql).await?)
+}
+
+#[derive(Serialize, Clone)]
+struct QueryRowsPayload<'a> {
+    query_id: &'a str,
+    columns: &'a [String],
+    rows: Vec<Vec<Value>>,
+}
+
+/// Streams every row of a query to the UI as `query-rows` events, up to `max_rows`.
+/// Returns the number of rows sent.
+#[tauri::command]
+pub async fn explore_stream_query(
+    app_handle: AppHandle,
+    path: PathBuf,
+    sql: String,
+    query_id: String,
+    max_rows: u64,
+) -> CommandResult<u64> {
+    let mut sent = 0u64;
+    let delivered = explorer::stream_query(&path, &sql, STREAM_BATCH_SIZE, |columns, mut rows| {
+        rows.truncate(max_rows.saturating_sub(sent) as usize);
+        sent += rows.len() as u64;
+        let _ = app_handle.emit(
+            QUERY_ROWS_EVENT,
+            QueryRowsPayload {
+                query_id: &query_id,
+                columns,
+                rows,
+            },
+        );
+        sent < max_rows
+    })
+    .await?;
+    Ok(delivered.min(max_rows))
+}
+
+#[tauri::command]
+pub async fn explore_get_query_history(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    path: PathBuf,
+    limit: u32,
+) -> CommandResult<Vec<QueryHistoryEntry>> {
+    let (id, key) = history_target(&instance_manager, &instance_id, &path).await?;
+    Ok(instance_manager.get_query_history(id, &key, limit).await?)
+}
+
+#[tauri::command]
+pub async fn explore_clear_query_history(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    path: PathBuf,
+) -> CommandResult<()> {
+    let (id, key) = history_target(&instance_manager, &instance_id, &path).await?;
+    Ok(instance_manager.clear_query_history(id, &key).await?)
+}
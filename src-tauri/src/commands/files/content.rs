@@ -0,0 +1,117 @@
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::app_config::GlobalConfigManager;
+use mc_server_wrapper_core::file_manager::{self, FileChunk, TextChunk};
+use mc_server_wrapper_core::utils::{safe_join, write_atomic};
+use tauri::State;
+use std::sync::Arc;
+use super::instance_root;
+use super::super::{CommandResult, AppError};
+
+#[tauri::command]
+pub async fn read_text_file(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    rel_path: String,
+) -> CommandResult<String> {
+    let root = instance_root(&instance_manager, &instance_id).await?;
+    file_manager::read_text(&root, &rel_path).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn save_text_file(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    config_manager: State<'_, Arc<GlobalConfigManager>>,
+    instance_id: String,
+    rel_path: String,
+    content: String,
+) -> CommandResult<()> {
+    let root = instance_root(&instance_manager, &instance_id).await?;
+    let file_path = file_manager::resolve_for_write(&root, &rel_path).map_err(AppError::from)?;
+    
+    // Ensure parent directory exists
+    if let Some(parent) = file_path.parent() {
+        tokio::fs::create_dir_all(parent).await.map_err(AppError::from)?;
+    }
+
+    let backup = super::super::config::backup_on_save(&config_manager).await;
+    write_atomic(&file_path, content, backup).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn open_file_in_editor(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    rel_path: String,
+) -> CommandResult<()> {
+    let root = instance_root(&instance_manager, &instance_id).await?;
+    let file_path = safe_join(&root, &rel_path).map_err(AppError::from)?;
+    if !file_path.exists() {
+        return Err(AppError::NotFound("File does not exist".to_string()));
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        // On Windows, 'explorer' opens the parent folder if the path is a file.
+        // We use 'powershell' with 'Start-Process' to correctly open the file with its default application.
+        std::process::Command::new("powershell")
+            .arg("-Command")
+            .arg(format!("Start-Process '{}'", file_path.display()))
+            .spawn()
+            .map_err(AppError::from)?;
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        std::process::Command::new("open")
+            .arg(file_path)
+            .spawn()
+            .map_err(AppError::from)?;
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        std::process::Command::new("xdg-open")
+            .arg(file_path)
+            .spawn()
+            .map_err(AppError::from)?;
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn read_file_chunk(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    rel_path: String,
+    offset: u64,
+    length: u64,
+) -> CommandResult<FileChunk> {
+    let root = instance_root(&instance_manager, &instance_id).await?;
+    file_manager::read_chunk(&root, &rel_path, offset, length).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn read_text_chunk(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    rel_path: String,
+    offset: u64,
+    length: u64,
+) -> CommandResult<TextChunk> {
+    let root = instance_root(&instance_manager, &instance_id).await?;
+    file_manager::read_text_chunk(&root, &rel_path, offset, length).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn write_binary_file(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    config_manager: State<'_, Arc<GlobalConfigManager>>,
+    instance_id: String,
+    rel_path: String,
+    data: String,
+) -> CommandResult<()> {
+    let root = instance_root(&instance_manager, &instance_id).await?;
+    let backup = super::super::config::backup_on_save(&config_manager).await;
+    file_manager::write_base64(&root, &rel_path, &data, backup).await.map_err(AppError::from)
+}
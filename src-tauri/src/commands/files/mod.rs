@@ -0,0 +1,114 @@
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::file_manager::{self, FileEntry};
+use mc_server_wrapper_core::watcher::FileWatcher;
+use tauri::State;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use super::{CommandResult, AppError, resolve_instance_id};
+
+pub mod content;
+pub use content::*;
+
+async fn instance_root(instance_manager: &InstanceManager, instance_id: &str) -> CommandResult<PathBuf> {
+    let id = resolve_instance_id(instance_manager, instance_id).await?;
+    let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+    Ok(instance.path)
+}
+
+#[tauri::command]
+pub async fn list_instance_dir(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    rel_path: String,
+) -> CommandResult<Vec<FileEntry>> {
+    let root = instance_root(&instance_manager, &instance_id).await?;
+    file_manager::list_dir(&root, &rel_path).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn create_instance_dir(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    rel_path: String,
+) -> CommandResult<FileEntry> {
+    let root = instance_root(&instance_manager, &instance_id).await?;
+    file_manager::create_dir(&root, &rel_path).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn rename_instance_file(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    from: String,
+    to: String,
+) -> CommandResult<FileEntry> {
+    let root = instance_root(&instance_manager, &instance_id).await?;
+    file_manager::rename(&root, &from, &to).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn copy_instance_file(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    from: String,
+    to: String,
+) -> CommandResult<FileEntry> {
+    let root = instance_root(&instance_manager, &instance_id).await?;
+    file_manager::copy(&root, &from, &to).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn delete_instance_file(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    rel_path: String,
+) -> CommandResult<()> {
+    let root = instance_root(&instance_manager, &instance_id).await?;
+    file_manager::delete(&root, &rel_path).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn download_instance_file(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    rel_path: String,
+) -> CommandResult<String> {
+    let root = instance_root(&instance_manager, &instance_id).await?;
+    file_manager::read_base64(&root, &rel_path).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn export_instance_file(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    rel_path: String,
+    destination: String,
+) -> CommandResult<u64> {
+    let root = instance_root(&instance_manager, &instance_id).await?;
+    file_manager::export_file(&root, &rel_path, Path::new(&destination)).await.map_err(AppError::from)
+}
+
+/// Watches the instance open in the UI for external changes, replacing the previous one.
+#[tauri::command]
+pub async fn watch_instance_files(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    watcher: State<'_, Arc<FileWatcher>>,
+    instance_id: String,
+) -> CommandResult<()> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let root = instance_root(&instance_manager, &instance_id).await?;
+    watcher.retain(&[id]);
+    watcher.watch(id, &root).map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn unwatch_instance_files(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    watcher: State<'_, Arc<FileWatcher>>,
+    instance_id: String,
+) -> CommandResult<()> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    watcher.unwatch(id);
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::protocol::{self, ProxyCompatibilityReport, VersionCompatibility};
+use tauri::State;
+use std::sync::Arc;
+use super::super::{CommandResult, AppError, resolve_instance_id};
+
+#[tauri::command]
+pub async fn get_version_compatibility(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+) -> CommandResult<VersionCompatibility> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+    Ok(protocol::compatibility(&instance.version))
+}
+
+#[tauri::command]
+pub async fn get_proxy_compatibility(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+) -> CommandResult<ProxyCompatibilityReport> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    instance_manager.proxy_compatibility(id).await.map_err(AppError::from)
+}
@@ -0,0 +1,37 @@
+use mc_server_wrapper_core::connection::{self, ConnectionInfo};
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::protocol::{self, ServerProbe};
+use tauri::State;
+use std::sync::Arc;
+use super::super::{CommandResult, AppError, resolve_instance_id};
+
+/// Addresses players can join with, plus an invite message ready to copy.
+#[tauri::command]
+pub async fn get_connection_info(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+) -> CommandResult<ConnectionInfo> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+    Ok(connection::get_connection_info(&instance).await)
+}
+
+/// Pings the instance over the network like a client would, adding the Query player
+/// list when it's enabled. Fails when nothing answers on the instance's port.
+#[tauri::command]
+pub async fn ping_instance(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+) -> CommandResult<ServerProbe> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+    protocol::probe_instance(&instance).await.map_err(AppError::from)
+}
+
+/// Pings any Java server by `host[:port]`, such as the external servers in the app settings.
+#[tauri::command]
+pub async fn ping_address(address: String) -> CommandResult<ServerProbe> {
+    protocol::probe_address(&address).await.map_err(AppError::from)
+}
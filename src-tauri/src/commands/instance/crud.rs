@@ -1,11 +1,14 @@
 use super::super::{
-    AppError, AppState, CommandResult,
-    server::{LogPayload, ensure_server_logs_forwarded},
+    AppError, AppState, CommandResult, resolve_instance_id,
+    server::{emit_log_line, ensure_server_logs_forwarded},
 };
 use mc_server_wrapper_core::instance::{InstanceManager, InstanceMetadata};
-use mc_server_wrapper_core::manager::ServerManager;
+use mc_server_wrapper_core::backup::BackupManager;
+use mc_server_wrapper_core::manager::{DeletionSummary, ServerManager};
+use mc_server_wrapper_core::scheduler::SchedulerManager;
+use mc_server_wrapper_core::tasks::TaskKind;
 use std::sync::Arc;
-use tauri::{Emitter, State};
+use tauri::State;
 use uuid::Uuid;
 
 #[tauri::command]
@@ -30,46 +33,90 @@ pub async fn create_instance(
         .map_err(AppError::from)
 }
 
+/// True when `name` can't be used, either because its slug clashes with another instance
+/// or because it looks like a UUID.
 #[tauri::command]
 pub async fn check_instance_name_exists(
     instance_manager: State<'_, Arc<InstanceManager>>,
     name: String,
 ) -> CommandResult<bool> {
-    let instance = instance_manager
-        .get_instance_by_name(&name)
-        .await
-        .map_err(AppError::from)?;
-    Ok(instance.is_some())
+    Ok(instance_manager.ensure_name_available(&name, None).await.is_err())
 }
 
+/// Deletes an instance with its backups, schedules and database rows.
 #[tauri::command]
 pub async fn delete_instance(
-    instance_manager: State<'_, Arc<InstanceManager>>,
+    server_manager: State<'_, Arc<ServerManager>>,
+    backup_manager: State<'_, Arc<BackupManager>>,
+    scheduler_manager: State<'_, Arc<SchedulerManager>>,
     app_state: State<'_, AppState>,
     instance_id: String,
-) -> CommandResult<()> {
-    let id = Uuid::parse_str(&instance_id).map_err(AppError::from)?;
-
-    // Remove from subscribed servers so if a new instance is created with same ID (unlikely) it can be re-subscribed
-    let mut subscribed = app_state.subscribed_servers.lock().await;
-    subscribed.remove(&id);
-    drop(subscribed);
-
-    instance_manager
-        .delete_instance(id)
-        .await
-        .map_err(AppError::from)
+) -> CommandResult<DeletionSummary> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    let summary = delete_with_cleanup(&server_manager, &backup_manager, &scheduler_manager, &app_state, vec![id]).await;
+    match summary.failed.first() {
+        Some(failure) => Err(AppError::Instance(failure.error.clone())),
+        None => Ok(summary),
+    }
 }
 
 #[tauri::command]
 pub async fn delete_instance_by_name(
-    instance_manager: State<'_, Arc<InstanceManager>>,
+    server_manager: State<'_, Arc<ServerManager>>,
+    backup_manager: State<'_, Arc<BackupManager>>,
+    scheduler_manager: State<'_, Arc<SchedulerManager>>,
+    app_state: State<'_, AppState>,
     name: String,
 ) -> CommandResult<()> {
-    instance_manager
-        .delete_instance_by_name(&name)
+    let Some(instance) = server_manager
+        .get_instance_manager()
+        .get_instance_by_name(&name)
         .await
-        .map_err(AppError::from)
+        .map_err(AppError::from)?
+    else {
+        return Ok(());
+    };
+    let summary = delete_with_cleanup(&server_manager, &backup_manager, &scheduler_manager, &app_state, vec![instance.id]).await;
+    match summary.failed.first() {
+        Some(failure) => Err(AppError::Instance(failure.error.clone())),
+        None => Ok(()),
+    }
+}
+
+/// Deletes several instances at once; per-instance failures are listed in the summary.
+#[tauri::command]
+pub async fn delete_instances(
+    server_manager: State<'_, Arc<ServerManager>>,
+    backup_manager: State<'_, Arc<BackupManager>>,
+    scheduler_manager: State<'_, Arc<SchedulerManager>>,
+    app_state: State<'_, AppState>,
+    instance_ids: Vec<String>,
+) -> CommandResult<DeletionSummary> {
+    let instance_manager = server_manager.get_instance_manager();
+    let mut ids = Vec::with_capacity(instance_ids.len());
+    for instance_id in &instance_ids {
+        ids.push(resolve_instance_id(&instance_manager, instance_id).await?);
+    }
+    Ok(delete_with_cleanup(&server_manager, &backup_manager, &scheduler_manager, &app_state, ids).await)
+}
+
+async fn delete_with_cleanup(
+    server_manager: &ServerManager,
+    backup_manager: &BackupManager,
+    scheduler_manager: &SchedulerManager,
+    app_state: &AppState,
+    ids: Vec<Uuid>,
+) -> DeletionSummary {
+    let summary = server_manager
+        .delete_instances(&ids, backup_manager, Some(scheduler_manager))
+        .await;
+
+    // Forget log subscriptions so a new instance reusing an ID (unlikely) can be re-subscribed
+    let mut subscribed = app_state.subscribed_servers.lock().await;
+    for deleted in &summary.deleted {
+        subscribed.remove(&deleted.id);
+    }
+    summary
 }
 
 #[tauri::command]
@@ -78,7 +125,7 @@ pub async fn clone_instance(
     instance_id: String,
     new_name: String,
 ) -> CommandResult<mc_server_wrapper_core::instance::InstanceMetadata> {
-    let id = Uuid::parse_str(&instance_id).map_err(AppError::from)?;
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
     instance_manager
         .clone_instance(id, &new_name)
         .await
@@ -90,7 +137,7 @@ pub async fn open_instance_folder(
     instance_manager: State<'_, Arc<InstanceManager>>,
     instance_id: String,
 ) -> CommandResult<()> {
-    let id = Uuid::parse_str(&instance_id).map_err(AppError::from)?;
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
     if let Some(instance) = instance_manager
         .get_instance(id)
         .await
@@ -133,13 +180,20 @@ pub async fn create_instance_from_modpack(
     version: mc_server_wrapper_core::mods::types::ProjectVersion,
     startAfterCreation: bool,
 ) -> CommandResult<mc_server_wrapper_core::instance::InstanceMetadata> {
-    let app_handle_for_progress = app_handle.clone();
-    let mut instance = server_manager
+    let task = server_manager
+        .get_task_manager()
+        .start(TaskKind::Modpack, format!("Installing modpack {}", name), None);
+    let report = task.reporter();
+    let result = server_manager
         .create_instance_from_modpack(&name, &version, move |progress| {
-            let _ = app_handle_for_progress.emit("modpack-installation-progress", progress);
+            let message = match (progress.files_completed, progress.total_files) {
+                (Some(done), Some(total)) => format!("{} ({}/{})", progress.current_step, done, total),
+                _ => progress.current_step,
+            };
+            report((progress.progress * 100.0) as u64, 100, message);
         })
-        .await
-        .map_err(AppError::from)?;
+        .await;
+    let mut instance = task.finish(result).map_err(AppError::from)?;
 
     // Set status to Installing immediately so the UI reflects it right away
     instance.status = mc_server_wrapper_core::server::ServerStatus::Installing;
@@ -174,34 +228,16 @@ pub async fn create_instance_from_modpack(
         )
         .await
         {
-            let _ = app_handle_clone.emit(
-                "server-log",
-                LogPayload {
-                    instance_id: instance_id_clone.clone(),
-                    line: format!("Error setting up log forwarding: {}", e),
-                },
-            );
+            emit_log_line(&app_handle_clone, id, format!("Error setting up log forwarding: {}", e)).await;
         }
 
         if startAfterCreation {
             if let Err(e) = server_manager_clone.start_server(id).await {
-                let _ = app_handle_clone.emit(
-                    "server-log",
-                    LogPayload {
-                        instance_id: instance_id_clone,
-                        line: format!("Error starting server: {}", e),
-                    },
-                );
+                emit_log_line(&app_handle_clone, id, format!("Error starting server: {}", e)).await;
             }
         } else {
             if let Err(e) = server_manager_clone.prepare_server(id).await {
-                let _ = app_handle_clone.emit(
-                    "server-log",
-                    LogPayload {
-                        instance_id: instance_id_clone,
-                        line: format!("Error preparing server: {}", e),
-                    },
-                );
+                emit_log_line(&app_handle_clone, id, format!("Error preparing server: {}", e)).await;
             }
         }
     });
@@ -259,34 +295,16 @@ pub async fn create_instance_full(
         )
         .await
         {
-            let _ = app_handle_clone.emit(
-                "server-log",
-                LogPayload {
-                    instance_id: instance_id_clone.clone(),
-                    line: format!("Error setting up log forwarding: {}", e),
-                },
-            );
+            emit_log_line(&app_handle_clone, id, format!("Error setting up log forwarding: {}", e)).await;
         }
 
         if startAfterCreation {
             if let Err(e) = server_manager_clone.start_server(id).await {
-                let _ = app_handle_clone.emit(
-                    "server-log",
-                    LogPayload {
-                        instance_id: instance_id_clone,
-                        line: format!("Error starting server: {}", e),
-                    },
-                );
+                emit_log_line(&app_handle_clone, id, format!("Error starting server: {}", e)).await;
             }
         } else {
             if let Err(e) = server_manager_clone.prepare_server(id).await {
-                let _ = app_handle_clone.emit(
-                    "server-log",
-                    LogPayload {
-                        instance_id: instance_id_clone,
-                        line: format!("Error preparing server: {}", e),
-                    },
-                );
+                emit_log_line(&app_handle_clone, id, format!("Error preparing server: {}", e)).await;
             }
         }
     });
@@ -0,0 +1,38 @@
+use super::super::{AppError, CommandResult, resolve_instance_id};
+use mc_server_wrapper_core::instance::{ExportFormat, InstanceManager, InstanceMetadata};
+use mc_server_wrapper_core::manager::ServerManager;
+use mc_server_wrapper_core::tasks::{TaskKind, TaskManager};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::State;
+
+#[tauri::command]
+pub async fn export_instance(
+    server_manager: State<'_, Arc<ServerManager>>,
+    task_manager: State<'_, Arc<TaskManager>>,
+    instance_id: String,
+    dest_path: String,
+    format: ExportFormat,
+) -> CommandResult<String> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    let task = task_manager.start(TaskKind::Export, "Exporting instance", Some(id));
+    let result = server_manager
+        .export_instance(id, PathBuf::from(dest_path), format, task.reporter())
+        .await;
+    let path = task.finish(result).map_err(AppError::from)?;
+    Ok(path.to_string_lossy().to_string())
+}
+
+#[tauri::command]
+pub async fn import_exported_instance(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    task_manager: State<'_, Arc<TaskManager>>,
+    archive_path: String,
+    name: Option<String>,
+) -> CommandResult<InstanceMetadata> {
+    let task = task_manager.start(TaskKind::Import, "Importing exported instance", None);
+    let result = instance_manager
+        .import_exported_instance(PathBuf::from(archive_path), name, task.reporter())
+        .await;
+    task.finish(result).map_err(AppError::from)
+}
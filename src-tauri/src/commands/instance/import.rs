@@ -1,10 +1,14 @@
+use mc_server_wrapper_core::backup::BackupManager;
 use mc_server_wrapper_core::instance::manager::detection::ParsedScriptInfo;
+use mc_server_wrapper_core::instance::manager::import::ImportPreflight;
 use mc_server_wrapper_core::instance::{InstanceManager, InstanceMetadata};
+use mc_server_wrapper_core::tasks::{TaskKind, TaskManager};
 use serde::Serialize;
 use std::collections::HashSet;
 use std::path::PathBuf;
 use std::sync::Arc;
-use tauri::{Emitter, State};
+use tauri::State;
+use uuid::Uuid;
 
 use super::super::{AppError, CommandResult};
 
@@ -15,13 +19,6 @@ pub struct ZipEntry {
     pub is_dir: bool,
 }
 
-#[derive(Debug, Serialize, Clone)]
-pub struct ImportProgressPayload {
-    pub current: u64,
-    pub total: u64,
-    pub message: String,
-}
-
 #[tauri::command]
 #[allow(non_snake_case)]
 pub async fn preview_script_import(
@@ -98,17 +95,39 @@ pub async fn preview_script_import(
     Ok(mc_server_wrapper_core::instance::InstanceManager::parse_script_content(&content))
 }
 
+#[tauri::command]
+#[allow(non_snake_case)]
+pub async fn preflight_import(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    name: String,
+    sourcePath: String,
+    rootWithinZip: Option<String>,
+    link: Option<bool>,
+) -> CommandResult<ImportPreflight> {
+    let path = PathBuf::from(sourcePath);
+    if link.unwrap_or(false) {
+        return instance_manager.preflight_link(&name, &path).await.map_err(AppError::from);
+    }
+    instance_manager
+        .preflight_import(&name, &path, rootWithinZip)
+        .await
+        .map_err(AppError::from)
+}
+
 #[tauri::command]
 #[allow(non_snake_case)]
 pub async fn import_instance(
-    app_handle: tauri::AppHandle,
     instance_manager: State<'_, Arc<InstanceManager>>,
+    backup_manager: State<'_, Arc<BackupManager>>,
+    task_manager: State<'_, Arc<TaskManager>>,
     name: String,
     sourcePath: String,
     jarName: String,
     serverType: String,
     rootWithinZip: Option<String>,
     scriptPath: Option<String>,
+    taskId: Option<String>,
+    link: Option<bool>,
 ) -> CommandResult<InstanceMetadata> {
     let path = PathBuf::from(sourcePath);
     let mod_loader = if serverType == "vanilla" || serverType == "custom" {
@@ -116,9 +135,23 @@ pub async fn import_instance(
     } else {
         Some(serverType)
     };
+    // Linking copies nothing, so it needs no task or progress
+    if link.unwrap_or(false) {
+        let instance = instance_manager
+            .link_instance(&name, path, jarName, mod_loader, scriptPath)
+            .await
+            .map_err(AppError::from)?;
+        super::super::backups::adopt_imported_backups(&backup_manager, &instance_manager, &instance).await;
+        return Ok(instance);
+    }
+    // The UI may pick the task id so it can cancel before this command returns
+    let task_id = match taskId {
+        Some(id) => Uuid::parse_str(&id).map_err(AppError::from)?,
+        None => Uuid::new_v4(),
+    };
 
-    let app_handle_clone = app_handle.clone();
-    instance_manager
+    let task = task_manager.start_with_id(task_id, TaskKind::Import, format!("Importing {}", name), None);
+    let result = instance_manager
         .import_instance(
             &name,
             path,
@@ -126,19 +159,13 @@ pub async fn import_instance(
             mod_loader,
             rootWithinZip,
             scriptPath,
-            move |current, total, message| {
-                let _ = app_handle_clone.emit(
-                    "import-progress",
-                    ImportProgressPayload {
-                        current,
-                        total,
-                        message,
-                    },
-                );
-            },
+            task.cancel_flag(),
+            task.reporter(),
         )
-        .await
-        .map_err(AppError::from)
+        .await;
+    let instance = task.finish(result).map_err(AppError::from)?;
+    super::super::backups::adopt_imported_backups(&backup_manager, &instance_manager, &instance).await;
+    Ok(instance)
 }
 
 #[tauri::command]
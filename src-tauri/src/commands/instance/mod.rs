@@ -1,9 +1,23 @@
 pub mod crud;
 pub mod import;
+pub mod export;
 pub mod versions;
 pub mod settings;
+pub mod updates;
+pub mod worlds;
+pub mod compat;
+pub mod connection;
+pub mod server_list;
+pub mod remote;
 
 pub use crud::*;
 pub use import::*;
+pub use export::*;
 pub use versions::*;
 pub use settings::*;
+pub use updates::*;
+pub use worlds::*;
+pub use compat::*;
+pub use connection::*;
+pub use server_list::*;
+pub use remote::*;
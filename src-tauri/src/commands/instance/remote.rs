@@ -0,0 +1,45 @@
+use super::super::{AppError, CommandResult};
+use mc_server_wrapper_core::backup::BackupManager;
+use mc_server_wrapper_core::instance::{InstanceManager, InstanceMetadata};
+use mc_server_wrapper_core::remote::{self, RemoteListing, RemoteSource};
+use mc_server_wrapper_core::tasks::{TaskKind, TaskManager};
+use std::sync::Arc;
+use tauri::State;
+
+#[tauri::command]
+pub async fn fetch_remote_host_key(source: RemoteSource) -> CommandResult<String> {
+    remote::fetch_host_key(&source).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn list_remote_directory(source: RemoteSource, path: String) -> CommandResult<RemoteListing> {
+    remote::list_remote_dir(&source, &path).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub async fn import_remote_instance(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    backup_manager: State<'_, Arc<BackupManager>>,
+    task_manager: State<'_, Arc<TaskManager>>,
+    name: String,
+    source: RemoteSource,
+    remoteRoot: String,
+    jarName: String,
+    serverType: String,
+    scriptPath: Option<String>,
+) -> CommandResult<InstanceMetadata> {
+    let mod_loader = if serverType == "vanilla" || serverType == "custom" {
+        None
+    } else {
+        Some(serverType)
+    };
+
+    let task = task_manager.start(TaskKind::Import, format!("Importing {} from {}", name, source.host), None);
+    let result = instance_manager
+        .import_remote_instance(&name, &source, &remoteRoot, jarName, mod_loader, scriptPath, task.reporter())
+        .await;
+    let instance = task.finish(result).map_err(AppError::from)?;
+    super::super::backups::adopt_imported_backups(&backup_manager, &instance_manager, &instance).await;
+    Ok(instance)
+}
@@ -0,0 +1,82 @@
+use mc_server_wrapper_core::instance::{InstanceManager, InstanceMetadata};
+use mc_server_wrapper_core::server_list::{self, Motd, MotdFormat, MotdPreview};
+use tauri::State;
+use std::path::PathBuf;
+use std::sync::Arc;
+use super::super::{CommandResult, AppError, resolve_instance_id};
+
+async fn find_instance(instance_manager: &InstanceManager, instance_id: &str) -> CommandResult<InstanceMetadata> {
+    let id = resolve_instance_id(instance_manager, instance_id).await?;
+    instance_manager.get_instance(id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))
+}
+
+#[tauri::command]
+pub async fn get_motd(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+) -> CommandResult<Motd> {
+    let instance = find_instance(&instance_manager, &instance_id).await?;
+    server_list::read_motd(&instance.path).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn set_motd(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    text: String,
+    format: MotdFormat,
+) -> CommandResult<Motd> {
+    let instance = find_instance(&instance_manager, &instance_id).await?;
+    server_list::write_motd(&instance.path, &text, format).await
+        .map_err(|e| AppError::Validation(e.to_string()))
+}
+
+/// Converts MOTD text between formats for live previews, without saving it.
+#[tauri::command]
+pub async fn convert_motd(text: String, from: MotdFormat, to: MotdFormat) -> CommandResult<String> {
+    server_list::convert_motd(&text, from, to).map_err(|e| AppError::Validation(e.to_string()))
+}
+
+/// Renders MOTD text as the server list will show it and lists anything clients would
+/// cut off or ignore, without saving it.
+#[tauri::command]
+pub async fn preview_motd(text: String, format: MotdFormat) -> CommandResult<MotdPreview> {
+    Ok(server_list::preview_motd(&text, format))
+}
+
+/// Converts `source_path` to a 64x64 `server-icon.png` and shows it as the instance icon.
+#[tauri::command]
+pub async fn set_server_icon(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    source_path: String,
+) -> CommandResult<String> {
+    let instance = find_instance(&instance_manager, &instance_id).await?;
+    let icon = server_list::set_server_icon(&instance.path, &PathBuf::from(source_path)).await
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let icon = icon.to_string_lossy().to_string();
+    let mut settings = instance.settings.clone();
+    settings.icon_path = Some(icon.clone());
+    instance_manager.update_settings(instance.id, None, settings).await.map_err(AppError::from)?;
+    Ok(icon)
+}
+
+#[tauri::command]
+pub async fn remove_server_icon(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+) -> CommandResult<()> {
+    let instance = find_instance(&instance_manager, &instance_id).await?;
+    server_list::remove_server_icon(&instance.path).await.map_err(AppError::from)?;
+
+    // Keep a custom icon that was picked from elsewhere on disk
+    let server_icon = instance.path.join("server-icon.png").to_string_lossy().to_string();
+    if instance.settings.icon_path.as_deref() != Some(server_icon.as_str()) {
+        return Ok(());
+    }
+    let mut settings = instance.settings.clone();
+    settings.icon_path = None;
+    instance_manager.update_settings(instance.id, None, settings).await.map_err(AppError::from)
+}
@@ -1,10 +1,10 @@
-use mc_server_wrapper_core::instance::{InstanceManager, InstanceSettings};
+use mc_server_wrapper_core::instance::{InstanceManager, InstanceSettings, JvmOptions, LaunchMethod};
+use mc_server_wrapper_core::mod_loaders::find_server_argfile;
 use mc_server_wrapper_core::manager::ServerManager;
 use tauri::State;
 use std::sync::Arc;
-use uuid::Uuid;
 
-use super::super::CommandResult;
+use super::super::{CommandResult, resolve_instance_id};
 use mc_server_wrapper_core::errors::AppError;
 
 #[tauri::command]
@@ -15,7 +15,7 @@ pub async fn update_instance_settings(
     name: Option<String>,
     settings: InstanceSettings,
 ) -> CommandResult<()> {
-    let id = Uuid::parse_str(&instance_id).map_err(AppError::from)?;
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
     instance_manager.update_settings(id, name, settings).await.map_err(AppError::from)?;
     
     // If the server is already loaded in memory, update its config
@@ -24,12 +24,27 @@ pub async fn update_instance_settings(
     Ok(())
 }
 
+/// Validates JVM options before they are saved and returns warnings about them,
+/// such as missing agent jars or a publicly reachable debug port.
+#[tauri::command]
+pub async fn check_jvm_options(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    jvm: JvmOptions,
+) -> CommandResult<Vec<String>> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+    jvm.validate().map_err(AppError::from)?;
+    Ok(jvm.warnings(&instance.path))
+}
+
 #[tauri::command]
 pub async fn list_bat_files(
     instance_manager: State<'_, Arc<InstanceManager>>,
     instance_id: String,
 ) -> CommandResult<Vec<String>> {
-    let id = Uuid::parse_str(&instance_id).map_err(AppError::from)?;
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
     let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
         .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
     
@@ -59,7 +74,7 @@ pub async fn update_instance_jar(
     instance_id: String,
     source_path: String,
 ) -> CommandResult<()> {
-    let id = Uuid::parse_str(&instance_id).map_err(AppError::from)?;
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
     let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
         .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
     
@@ -71,14 +86,35 @@ pub async fn update_instance_jar(
 
 #[tauri::command]
 pub async fn get_startup_preview(
-    _instance_id: String,
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
     settings: InstanceSettings,
 ) -> CommandResult<String> {
-    let mut line = settings.startup_line.clone();
-    line = line.replace("{min_ram}", &settings.min_ram.to_string());
-    line = line.replace("{min_unit}", &settings.min_ram_unit);
-    line = line.replace("{max_ram}", &settings.max_ram.to_string());
-    line = line.replace("{max_unit}", &settings.max_ram_unit);
-    
+    let mut line = settings.render_startup_line();
+
+    // Modern Forge/NeoForge launch through their argfile instead of a jar
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?;
+    if let (Some(instance), LaunchMethod::StartupLine) = (instance, &settings.launch_method) {
+        let argfile = find_server_argfile(
+            &instance.path,
+            instance.mod_loader.as_deref(),
+            &instance.version,
+            instance.loader_version.as_deref(),
+        );
+        if let (Some(argfile), Some(idx)) = (argfile, line.find("-jar ")) {
+            let jar_end = line[idx + 5..].find(' ').map(|i| idx + 5 + i).unwrap_or(line.len());
+            line.replace_range(idx..jar_end, &format!("@{}", argfile.to_string_lossy()));
+        }
+    }
+
+    // JVM options go right before -jar (or the argfile), as they do at launch
+    let jvm_args = settings.jvm.jvm_args(std::path::Path::new(""));
+    if !jvm_args.is_empty() {
+        if let Some(idx) = line.find("-jar ").or_else(|| line.find(" @").map(|i| i + 1)) {
+            line.insert_str(idx, &format!("{} ", jvm_args.join(" ")));
+        }
+    }
+
     Ok(line)
 }
@@ -0,0 +1,26 @@
+use mc_server_wrapper_core::manager::{ServerBuildUpdate, ServerManager};
+use tauri::State;
+use std::sync::Arc;
+use super::super::{CommandResult, AppError, resolve_instance_id};
+
+#[tauri::command]
+pub async fn check_server_updates(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+) -> CommandResult<ServerBuildUpdate> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    server_manager.check_server_update(id).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn apply_server_update(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+    allow_experimental: Option<bool>,
+) -> CommandResult<ServerBuildUpdate> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    server_manager
+        .apply_server_update(id, allow_experimental.unwrap_or(false))
+        .await
+        .map_err(AppError::from)
+}
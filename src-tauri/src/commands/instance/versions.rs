@@ -32,3 +32,8 @@ pub async fn get_minecraft_versions(server_manager: State<'_, Arc<ServerManager>
 pub async fn get_mod_loaders(server_manager: State<'_, Arc<ServerManager>>, mc_version: String, server_type: Option<String>) -> CommandResult<Vec<mc_server_wrapper_core::mod_loaders::ModLoader>> {
     server_manager.get_mod_loader_client().get_available_loaders(&mc_version, server_type.as_deref()).await.map_err(AppError::from)
 }
+
+#[tauri::command]
+pub async fn get_paper_builds(server_manager: State<'_, Arc<ServerManager>>, mc_version: String) -> CommandResult<Vec<mc_server_wrapper_core::mod_loaders::PaperBuild>> {
+    server_manager.get_mod_loader_client().get_paper_builds(&mc_version).await.map_err(AppError::from)
+}
@@ -0,0 +1,106 @@
+use mc_server_wrapper_core::instance::{InstanceManager, WorldInfo};
+use mc_server_wrapper_core::manager::ServerManager;
+use mc_server_wrapper_core::pregen::PregenRequest;
+use mc_server_wrapper_core::world_info::{
+    CoordinateInfo, LevelSummary, SlimeChunkCheck, check_slime_chunks, describe_coordinates,
+};
+use mc_server_wrapper_core::world_upgrade::{WorldUpgradeRequest, WorldUpgradeStatus};
+use tauri::State;
+use std::sync::Arc;
+use super::super::{CommandResult, AppError, resolve_instance_id};
+
+#[tauri::command]
+pub async fn list_worlds(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+) -> CommandResult<Vec<WorldInfo>> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    instance_manager.list_worlds(id).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn switch_world(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+    name: String,
+) -> CommandResult<()> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    server_manager.switch_world(id, &name).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn duplicate_world(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+    source: String,
+    new_name: String,
+) -> CommandResult<WorldInfo> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    server_manager.duplicate_world(id, &source, &new_name).await.map_err(AppError::from)
+}
+
+/// Starts pregenerating the area around a point and returns the id of the task tracking
+/// it; progress, pausing and cancelling go through the task commands.
+#[tauri::command]
+pub async fn start_pregeneration(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+    request: PregenRequest,
+) -> CommandResult<String> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    let task_id = server_manager.inner().start_pregeneration(id, request).await.map_err(AppError::from)?;
+    Ok(task_id.to_string())
+}
+
+/// Runs the stopped server once with `--forceUpgrade` and returns the id of the task
+/// tracking the conversion.
+#[tauri::command]
+pub async fn start_world_upgrade(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+    request: WorldUpgradeRequest,
+) -> CommandResult<String> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    let task_id = server_manager.inner().start_world_upgrade(id, request).await.map_err(AppError::from)?;
+    Ok(task_id.to_string())
+}
+
+#[tauri::command]
+pub async fn get_world_upgrade_status(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+) -> CommandResult<WorldUpgradeStatus> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    instance_manager.world_upgrade_status(id).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn get_world_summary(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    world: Option<String>,
+) -> CommandResult<LevelSummary> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    instance_manager.world_summary(id, world.as_deref()).await.map_err(AppError::from)
+}
+
+/// Checks whether the chunk at block `x`/`z` is a slime chunk, using the world's seed,
+/// and lists slime chunks up to `radius` chunks around it.
+#[tauri::command]
+pub async fn find_slime_chunks(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    world: Option<String>,
+    x: i32,
+    z: i32,
+    radius: u32,
+) -> CommandResult<SlimeChunkCheck> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let summary = instance_manager.world_summary(id, world.as_deref()).await.map_err(AppError::from)?;
+    check_slime_chunks(summary.seed, x, z, radius).map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn convert_coordinates(x: i32, z: i32) -> CommandResult<CoordinateInfo> {
+    Ok(describe_coordinates(x, z))
+}
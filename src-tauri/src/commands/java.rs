@@ -1,6 +1,7 @@
 use mc_server_wrapper_core::java::JavaManager;
 use mc_server_wrapper_core::app_config::{ManagedJavaVersion, GlobalConfigManager};
-use tauri::{State, Window, Emitter};
+use mc_server_wrapper_core::tasks::{TaskKind, TaskManager};
+use tauri::State;
 use std::sync::Arc;
 use std::path::Path;
 use super::{CommandResult, AppError};
@@ -16,21 +17,20 @@ pub async fn get_managed_java_versions(
 pub async fn download_java_version(
     java_manager: State<'_, Arc<JavaManager>>,
     config_manager: State<'_, Arc<GlobalConfigManager>>,
-    window: Window,
+    task_manager: State<'_, Arc<TaskManager>>,
     major_version: u32,
 ) -> CommandResult<ManagedJavaVersion> {
     let release = java_manager.get_latest_release(major_version).await
         .map_err(AppError::from)?;
     
+    let task = task_manager.start(TaskKind::Java, format!("Downloading Java {}", release.release_name), None);
+    let report = task.reporter();
     let release_name = release.release_name.clone();
     
-    let version_info = java_manager.download_and_install(release, move |downloaded, total| {
-        let _ = window.emit("java_download_progress", serde_json::json!({
-            "release": release_name,
-            "downloaded": downloaded,
-            "total": total,
-        }));
-    }).await.map_err(AppError::from)?;
+    let result = java_manager.download_and_install(release, move |downloaded, total| {
+        report(downloaded, total, release_name.clone());
+    }).await;
+    let version_info = task.finish(result).map_err(AppError::from)?;
 
     // Update app settings with the new version
     let mut settings = config_manager.load().await.map_err(AppError::from)?;
@@ -0,0 +1,66 @@
+use mc_server_wrapper_core::instance::{InstanceManager, InstanceMetadata};
+use mc_server_wrapper_core::manager::ServerManager;
+use mc_server_wrapper_core::loadouts::{self, Loadout, LoadoutResult, LoadoutStore};
+use tauri::State;
+use std::sync::Arc;
+use super::{CommandResult, AppError, resolve_instance_id};
+
+async fn find_instance(instance_manager: &InstanceManager, instance_id: &str) -> CommandResult<InstanceMetadata> {
+    let id = resolve_instance_id(instance_manager, instance_id).await?;
+    instance_manager.get_instance(id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))
+}
+
+#[tauri::command]
+pub async fn list_loadouts(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+) -> CommandResult<LoadoutStore> {
+    let instance = find_instance(&instance_manager, &instance_id).await?;
+    Ok(loadouts::load_loadouts(&instance.path).await)
+}
+
+/// Saves `loadout`, or a snapshot of the current enabled/disabled state when `loadout` has no entries.
+#[tauri::command]
+pub async fn save_loadout(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    loadout: Loadout,
+) -> CommandResult<LoadoutStore> {
+    let instance = find_instance(&instance_manager, &instance_id).await?;
+    let loadout = if loadout.entries.is_empty() {
+        loadouts::capture_loadout(&instance.path, &loadout.name).await.map_err(AppError::from)?
+    } else {
+        loadout
+    };
+    loadouts::save_loadout(&instance.path, loadout).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn delete_loadout(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    name: String,
+) -> CommandResult<LoadoutStore> {
+    let instance = find_instance(&instance_manager, &instance_id).await?;
+    loadouts::delete_loadout(&instance.path, &name).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn apply_loadout(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+    name: String,
+) -> CommandResult<LoadoutResult> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    server_manager.apply_loadout(id, &name).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn revert_loadout(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+) -> CommandResult<LoadoutResult> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    server_manager.revert_loadout(id).await.map_err(AppError::from)
+}
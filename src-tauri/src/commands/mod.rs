@@ -1,19 +1,36 @@
 pub mod assets;
+pub mod automation;
 pub mod backups;
 pub mod config;
+pub mod config_presets;
+pub mod config_search;
+pub mod console;
+pub mod crash;
 pub mod database;
 pub mod files;
 pub mod instance;
 pub mod java;
+pub mod loadouts;
 pub mod mods;
+pub mod notifications;
 pub mod players;
 pub mod plugins;
+pub mod profiler;
 pub mod scheduler;
 pub mod server;
+pub mod storage;
+pub mod tasks;
+pub mod templates;
+pub mod timeline;
 
 use mc_server_wrapper_core::errors::AppError;
+use mc_server_wrapper_core::events::EventBus;
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::utils::BulkProgress;
+use serde::Serialize;
 use std::collections::HashSet;
 use std::sync::Arc;
+use tauri::Emitter;
 use tokio::sync::Mutex as TokioMutex;
 use uuid::Uuid;
 
@@ -22,4 +39,28 @@ pub type CommandResult<T> = Result<T, AppError>;
 #[derive(Clone)]
 pub struct AppState {
     pub subscribed_servers: Arc<TokioMutex<HashSet<Uuid>>>,
+    /// Recent console lines and progress, replayed to the UI after it reloads.
+    pub events: Arc<EventBus>,
+}
+
+/// Accepts an instance's UUID, exact name or slug, so callers don't need to look the UUID up first.
+pub async fn resolve_instance_id(instance_manager: &InstanceManager, instance_id: &str) -> CommandResult<Uuid> {
+    instance_manager
+        .resolve_instance_id(instance_id)
+        .await
+        .map_err(|e| AppError::NotFound(e.to_string()))
+}
+
+#[derive(Clone, Serialize)]
+pub struct BulkProgressPayload {
+    pub instance_id: Uuid,
+    #[serde(flatten)]
+    pub progress: BulkProgress,
+}
+
+/// Forwards the progress of a bulk mod/plugin operation to the UI.
+pub fn emit_bulk_progress(app_handle: tauri::AppHandle, instance_id: Uuid) -> impl Fn(BulkProgress) {
+    move |progress| {
+        let _ = app_handle.emit("bulk-operation-progress", BulkProgressPayload { instance_id, progress });
+    }
 }
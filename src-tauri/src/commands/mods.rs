@@ -1,19 +1,24 @@
-use super::{AppError, CommandResult};
+use super::{AppError, CommandResult, emit_bulk_progress, resolve_instance_id};
+use mc_server_wrapper_core::conflicts::{InstallResult, check_install_conflicts};
+use mc_server_wrapper_core::content_report::{self, ContentKind, ContentReport};
 use mc_server_wrapper_core::instance::InstanceManager;
 use mc_server_wrapper_core::manager::ServerManager;
 use mc_server_wrapper_core::mods::{
     self, InstalledMod, ModConfig, ModProvider, ModUpdate, Project, ResolvedDependency,
     SearchOptions,
 };
+use mc_server_wrapper_core::timeline::TimelineKind;
+use mc_server_wrapper_core::utils::BulkReport;
+use mc_server_wrapper_core::version_history::{self, InstalledVersion};
 use std::sync::Arc;
 use tauri::State;
-use uuid::Uuid;
 
 #[tauri::command]
 pub async fn list_installed_mods(
     instance_manager: State<'_, Arc<InstanceManager>>,
-    instance_id: Uuid,
+    instance_id: String,
 ) -> CommandResult<Vec<InstalledMod>> {
+    let instance_id = resolve_instance_id(&instance_manager, &instance_id).await?;
     let instances = instance_manager
         .list_instances()
         .await
@@ -31,10 +36,11 @@ pub async fn list_installed_mods(
 #[tauri::command]
 pub async fn toggle_mod(
     instance_manager: State<'_, Arc<InstanceManager>>,
-    instance_id: Uuid,
+    instance_id: String,
     filename: String,
     enable: bool,
 ) -> CommandResult<()> {
+    let instance_id = resolve_instance_id(&instance_manager, &instance_id).await?;
     let instances = instance_manager
         .list_instances()
         .await
@@ -51,11 +57,13 @@ pub async fn toggle_mod(
 
 #[tauri::command]
 pub async fn bulk_toggle_mods(
+    app_handle: tauri::AppHandle,
     instance_manager: State<'_, Arc<InstanceManager>>,
-    instance_id: Uuid,
+    instance_id: String,
     filenames: Vec<String>,
     enable: bool,
-) -> CommandResult<()> {
+) -> CommandResult<BulkReport> {
+    let instance_id = resolve_instance_id(&instance_manager, &instance_id).await?;
     let instances = instance_manager
         .list_instances()
         .await
@@ -65,18 +73,18 @@ pub async fn bulk_toggle_mods(
         .find(|i| i.id == instance_id)
         .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
 
-    mods::bulk_toggle_mods(&instance.path, filenames, enable)
-        .await
-        .map_err(AppError::from)
+    let progress = emit_bulk_progress(app_handle, instance_id);
+    Ok(mods::bulk_toggle_mods(&instance.path, filenames, enable, progress).await)
 }
 
 #[tauri::command]
 pub async fn uninstall_mod(
     instance_manager: State<'_, Arc<InstanceManager>>,
-    instance_id: Uuid,
+    instance_id: String,
     filename: String,
     delete_config: bool,
 ) -> CommandResult<()> {
+    let instance_id = resolve_instance_id(&instance_manager, &instance_id).await?;
     let instances = instance_manager
         .list_instances()
         .await
@@ -93,11 +101,13 @@ pub async fn uninstall_mod(
 
 #[tauri::command]
 pub async fn bulk_uninstall_mods(
+    app_handle: tauri::AppHandle,
     instance_manager: State<'_, Arc<InstanceManager>>,
-    instance_id: Uuid,
+    instance_id: String,
     filenames: Vec<String>,
     delete_config: bool,
-) -> CommandResult<()> {
+) -> CommandResult<BulkReport> {
+    let instance_id = resolve_instance_id(&instance_manager, &instance_id).await?;
     let instances = instance_manager
         .list_instances()
         .await
@@ -107,9 +117,8 @@ pub async fn bulk_uninstall_mods(
         .find(|i| i.id == instance_id)
         .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
 
-    mods::bulk_uninstall_mods(&instance.path, filenames, delete_config)
-        .await
-        .map_err(AppError::from)
+    let progress = emit_bulk_progress(app_handle, instance_id);
+    Ok(mods::bulk_uninstall_mods(&instance.path, filenames, delete_config, progress).await)
 }
 
 #[tauri::command]
@@ -118,8 +127,7 @@ pub async fn search_mods(
     options: SearchOptions,
     provider: Option<ModProvider>,
 ) -> CommandResult<Vec<Project>> {
-    // Get CurseForge API key from environment if available
-    let cf_api_key = std::env::var("CURSEFORGE_API_KEY").ok();
+    let cf_api_key = server_manager.get_cache().credentials().curseforge_api_key();
     mods::search_mods(&options, provider, cf_api_key, server_manager.get_cache())
         .await
         .map_err(AppError::from)
@@ -128,10 +136,11 @@ pub async fn search_mods(
 #[tauri::command]
 pub async fn get_mod_dependencies(
     server_manager: State<'_, Arc<ServerManager>>,
-    instance_id: Uuid,
+    instance_id: String,
     project_id: String,
     provider: ModProvider,
 ) -> CommandResult<Vec<ResolvedDependency>> {
+    let instance_id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
     let instances = server_manager
         .get_instance_manager()
         .list_instances()
@@ -142,7 +151,7 @@ pub async fn get_mod_dependencies(
         .find(|i| i.id == instance_id)
         .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
 
-    let cf_api_key = std::env::var("CURSEFORGE_API_KEY").ok();
+    let cf_api_key = server_manager.get_cache().credentials().curseforge_api_key();
     mods::get_mod_dependencies(
         &project_id,
         provider,
@@ -163,7 +172,7 @@ pub async fn get_mod_versions(
     game_version: Option<String>,
     loader: Option<String>,
 ) -> CommandResult<Vec<mc_server_wrapper_core::mods::ProjectVersion>> {
-    let cf_api_key = std::env::var("CURSEFORGE_API_KEY").ok();
+    let cf_api_key = server_manager.get_cache().credentials().curseforge_api_key();
 
     match provider {
         ModProvider::Modrinth => {
@@ -190,9 +199,10 @@ pub async fn get_mod_versions(
 #[tauri::command]
 pub async fn get_mod_configs(
     instance_manager: State<'_, Arc<InstanceManager>>,
-    instance_id: Uuid,
-    mod_name: String,
+    instance_id: String,
+    filename: String,
 ) -> CommandResult<Vec<ModConfig>> {
+    let instance_id = resolve_instance_id(&instance_manager, &instance_id).await?;
     let instances = instance_manager
         .list_instances()
         .await
@@ -202,7 +212,7 @@ pub async fn get_mod_configs(
         .find(|i| i.id == instance_id)
         .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
 
-    mods::get_mod_configs(&instance.path, &mod_name)
+    mods::get_mod_configs(&instance.path, &filename)
         .await
         .map_err(AppError::from)
 }
@@ -210,9 +220,10 @@ pub async fn get_mod_configs(
 #[tauri::command]
 pub async fn list_mod_config_files(
     instance_manager: State<'_, Arc<InstanceManager>>,
-    instance_id: Uuid,
+    instance_id: String,
     rel_path: String,
 ) -> CommandResult<Vec<String>> {
+    let instance_id = resolve_instance_id(&instance_manager, &instance_id).await?;
     let instances = instance_manager
         .list_instances()
         .await
@@ -230,11 +241,12 @@ pub async fn list_mod_config_files(
 #[tauri::command]
 pub async fn install_mod(
     server_manager: State<'_, Arc<ServerManager>>,
-    instance_id: Uuid,
+    instance_id: String,
     project_id: String,
     provider: ModProvider,
     version_id: Option<String>,
-) -> CommandResult<()> {
+) -> CommandResult<InstallResult> {
+    let instance_id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
     let instances = server_manager
         .get_instance_manager()
         .list_instances()
@@ -245,8 +257,8 @@ pub async fn install_mod(
         .find(|i| i.id == instance_id)
         .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
 
-    let cf_api_key = std::env::var("CURSEFORGE_API_KEY").ok();
-    mods::install_mod(
+    let cf_api_key = server_manager.get_cache().credentials().curseforge_api_key();
+    let filename = mods::install_mod(
         &instance.path,
         &project_id,
         provider,
@@ -257,15 +269,19 @@ pub async fn install_mod(
         server_manager.get_cache(),
     )
     .await
-    .map(|_| ())
-    .map_err(AppError::from)
+    .map_err(AppError::from)?;
+    let warnings = check_install_conflicts(&instance.path, ContentKind::Mod, &filename)
+        .await
+        .map_err(AppError::from)?;
+    Ok(InstallResult { filename, warnings })
 }
 
 #[tauri::command]
 pub async fn check_for_mod_updates(
     server_manager: State<'_, Arc<ServerManager>>,
-    instance_id: Uuid,
+    instance_id: String,
 ) -> CommandResult<Vec<ModUpdate>> {
+    let instance_id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
     let instances = server_manager
         .get_instance_manager()
         .list_instances()
@@ -276,7 +292,7 @@ pub async fn check_for_mod_updates(
         .find(|i| i.id == instance_id)
         .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
 
-    let cf_api_key = std::env::var("CURSEFORGE_API_KEY").ok();
+    let cf_api_key = server_manager.get_cache().credentials().curseforge_api_key();
     mods::check_for_updates(
         &instance.path,
         Some(instance.version.as_str()),
@@ -288,12 +304,32 @@ pub async fn check_for_mod_updates(
     .map_err(AppError::from)
 }
 
+/// Lists installed mods and plugins with their licenses and last update-check results.
+#[tauri::command]
+pub async fn get_content_report(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+) -> CommandResult<ContentReport> {
+    let instance_id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    let instance = server_manager
+        .get_instance_manager()
+        .get_instance(instance_id)
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
+
+    content_report::generate_content_report(&instance.path, server_manager.get_cache())
+        .await
+        .map_err(AppError::from)
+}
+
 #[tauri::command]
 pub async fn update_mod(
     server_manager: State<'_, Arc<ServerManager>>,
-    instance_id: Uuid,
+    instance_id: String,
     updates: Vec<ModUpdate>,
 ) -> CommandResult<()> {
+    let instance_id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
     let instances = server_manager
         .get_instance_manager()
         .list_instances()
@@ -304,9 +340,10 @@ pub async fn update_mod(
         .find(|i| i.id == instance_id)
         .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
 
-    let cf_api_key = std::env::var("CURSEFORGE_API_KEY").ok();
+    let cf_api_key = server_manager.get_cache().credentials().curseforge_api_key();
 
     for update in updates {
+        let summary = format!("Updated mod {}", update.filename);
         mods::update_mod(
             &instance.path,
             update.filename,
@@ -317,10 +354,63 @@ pub async fn update_mod(
             instance.mod_loader.as_deref(),
             cf_api_key.clone(),
             server_manager.get_cache(),
+            &server_manager.get_artifact_store(),
         )
         .await
         .map_err(AppError::from)?;
+        server_manager
+            .get_instance_manager()
+            .note_timeline_event(instance_id, TimelineKind::UpdateApplied, summary, None)
+            .await;
     }
 
     Ok(())
 }
+
+/// Previous versions kept from earlier updates, newest first.
+#[tauri::command]
+pub async fn list_mod_versions_installed(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    project_id: Option<String>,
+) -> CommandResult<Vec<InstalledVersion>> {
+    let instance_id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager
+        .get_instance(instance_id)
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
+
+    Ok(version_history::list_installed_versions(&instance.path, ContentKind::Mod, project_id.as_deref()).await)
+}
+
+#[tauri::command]
+pub async fn rollback_mod(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+    project_id: String,
+    sha1: String,
+) -> CommandResult<String> {
+    let instance_id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    let instance = server_manager
+        .get_instance_manager()
+        .get_instance(instance_id)
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
+
+    let filename = version_history::rollback_version(
+        &server_manager.get_artifact_store(),
+        &instance.path,
+        ContentKind::Mod,
+        &project_id,
+        &sha1,
+    )
+    .await
+    .map_err(AppError::from)?;
+    server_manager
+        .get_instance_manager()
+        .note_timeline_event(instance_id, TimelineKind::UpdateApplied, format!("Rolled back mod to {}", filename), None)
+        .await;
+    Ok(filename)
+}
@@ -0,0 +1,66 @@
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::manager::ServerManager;
+use mc_server_wrapper_core::notifications::{self, HeartbeatSettings, NotificationRule, WebhookSettings};
+use tauri::State;
+use std::sync::Arc;
+use super::{CommandResult, AppError, resolve_instance_id};
+
+/// Sends a test message using `webhooks`, or the instance's saved settings when omitted,
+/// so the form can be checked before it is saved.
+#[tauri::command]
+pub async fn test_webhook(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+    webhooks: Option<WebhookSettings>,
+) -> CommandResult<()> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+    let settings = webhooks.unwrap_or(instance.settings.webhooks);
+    let cache = server_manager.get_cache();
+    notifications::send_test_webhook(cache.get_client(), &settings, &instance.name).await.map_err(AppError::from)
+}
+
+/// Pings the heartbeat URL from `heartbeat`, or the instance's saved settings when omitted.
+#[tauri::command]
+pub async fn test_heartbeat(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    heartbeat: Option<HeartbeatSettings>,
+) -> CommandResult<()> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+    let settings = heartbeat.unwrap_or(instance.settings.heartbeat);
+    notifications::send_test_heartbeat(&settings).await.map_err(AppError::from)
+}
+
+/// Global rules, plus the instance's own rules when `instance_id` is given.
+#[tauri::command]
+pub async fn list_notification_rules(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: Option<String>,
+) -> CommandResult<Vec<NotificationRule>> {
+    let id = match instance_id {
+        Some(instance_id) => Some(resolve_instance_id(&instance_manager, &instance_id).await?),
+        None => None,
+    };
+    instance_manager.list_notification_rules(id).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn save_notification_rule(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    rule: NotificationRule,
+) -> CommandResult<i64> {
+    instance_manager.save_notification_rule(&rule).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn delete_notification_rule(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    rule_id: i64,
+) -> CommandResult<()> {
+    instance_manager.delete_notification_rule(rule_id).await.map_err(AppError::from)
+}
@@ -4,9 +4,8 @@ use mc_server_wrapper_core::players;
 use mc_server_wrapper_core::server::ServerStatus;
 use tauri::State;
 use std::sync::Arc;
-use uuid::Uuid;
 use chrono;
-use super::{CommandResult, AppError};
+use super::{CommandResult, AppError, resolve_instance_id};
 
 #[tauri::command]
 pub async fn open_player_list_file(
@@ -14,7 +13,7 @@ pub async fn open_player_list_file(
     instance_id: String,
     list_type: String,
 ) -> CommandResult<()> {
-    let id = Uuid::parse_str(&instance_id).map_err(AppError::from)?;
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
     if let Some(instance) = instance_manager.get_instance(id).await.map_err(AppError::from)? {
         let file_name = match list_type.as_str() {
             "whitelist" => "whitelist.json",
@@ -63,7 +62,7 @@ pub async fn get_online_players(
     server_manager: State<'_, Arc<ServerManager>>,
     instance_id: String,
 ) -> CommandResult<Vec<String>> {
-    let id = Uuid::parse_str(&instance_id).map_err(AppError::from)?;
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
     if let Some(server) = server_manager.get_server(id).await {
         Ok(server.get_online_players().await)
     } else {
@@ -76,7 +75,7 @@ pub async fn get_players(
     instance_manager: State<'_, Arc<InstanceManager>>,
     instance_id: String,
 ) -> CommandResult<players::AllPlayerLists> {
-    let id = Uuid::parse_str(&instance_id).map_err(AppError::from)?;
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
     let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
         .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
     
@@ -103,7 +102,7 @@ pub async fn add_player(
     list_type: String,
     username: String,
 ) -> CommandResult<()> {
-    let id = Uuid::parse_str(&instance_id).map_err(AppError::from)?;
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
 
     // Check if server is running and use console commands if possible
     if let Some(server) = server_manager.get_server(id).await {
@@ -125,32 +124,38 @@ pub async fn add_player(
 
     match list_type.as_str() {
         "whitelist" => {
-            let mut list = players::read_whitelist(&instance.path).await.map_err(AppError::from)?;
-            if !list.iter().any(|p| p.uuid == uuid) {
-                list.push(players::PlayerEntry { uuid, name });
-                players::write_whitelist(&instance.path, &list).await.map_err(AppError::from)?;
-            }
+            players::update_whitelist(instance_manager.player_list_locks(), &instance.path, |list| {
+                let missing = !list.iter().any(|p| p.uuid == uuid);
+                if missing {
+                    list.push(players::PlayerEntry { uuid, name });
+                }
+                missing
+            }).await.map_err(AppError::from)?;
         },
         "ops" => {
-            let mut list = players::read_ops(&instance.path).await.map_err(AppError::from)?;
-            if !list.iter().any(|p| p.uuid == uuid) {
-                list.push(players::OpEntry { uuid, name, level: 4, bypasses_player_limit: false });
-                players::write_ops(&instance.path, &list).await.map_err(AppError::from)?;
-            }
+            players::update_ops(instance_manager.player_list_locks(), &instance.path, |list| {
+                let missing = !list.iter().any(|p| p.uuid == uuid);
+                if missing {
+                    list.push(players::OpEntry { uuid, name, level: 4, bypasses_player_limit: false });
+                }
+                missing
+            }).await.map_err(AppError::from)?;
         },
         "banned-players" => {
-            let mut list = players::read_banned_players(&instance.path).await.map_err(AppError::from)?;
-            if !list.iter().any(|p| p.uuid == uuid) {
-                list.push(players::BannedPlayerEntry {
-                    uuid,
-                    name,
-                    created: chrono::Utc::now().to_rfc3339(),
-                    source: "Server Wrapper".to_string(),
-                    expires: "forever".to_string(),
-                    reason: "Banned by admin".to_string(),
-                });
-                players::write_banned_players(&instance.path, &list).await.map_err(AppError::from)?;
-            }
+            players::update_banned_players(instance_manager.player_list_locks(), &instance.path, |list| {
+                let missing = !list.iter().any(|p| p.uuid == uuid);
+                if missing {
+                    list.push(players::BannedPlayerEntry {
+                        uuid,
+                        name,
+                        created: chrono::Utc::now().to_rfc3339(),
+                        source: "Server Wrapper".to_string(),
+                        expires: "forever".to_string(),
+                        reason: "Banned by admin".to_string(),
+                    });
+                }
+                missing
+            }).await.map_err(AppError::from)?;
         },
         _ => return Err(AppError::Validation("Invalid list type".to_string())),
     }
@@ -164,7 +169,7 @@ pub async fn add_banned_ip(
     instance_id: String,
     ip: String,
 ) -> CommandResult<()> {
-    let id = Uuid::parse_str(&instance_id).map_err(AppError::from)?;
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
 
     // Check if server is running and use console commands if possible
     if let Some(server) = server_manager.get_server(id).await {
@@ -177,17 +182,19 @@ pub async fn add_banned_ip(
     let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
         .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
 
-    let mut list = players::read_banned_ips(&instance.path).await.map_err(AppError::from)?;
-    if !list.iter().any(|p| p.ip == ip) {
-        list.push(players::BannedIpEntry {
-            ip,
-            created: chrono::Utc::now().to_rfc3339(),
-            source: "Server Wrapper".to_string(),
-            expires: "forever".to_string(),
-            reason: "Banned by admin".to_string(),
-        });
-        players::write_banned_ips(&instance.path, &list).await.map_err(AppError::from)?;
-    }
+    players::update_banned_ips(instance_manager.player_list_locks(), &instance.path, |list| {
+        let missing = !list.iter().any(|p| p.ip == ip);
+        if missing {
+            list.push(players::BannedIpEntry {
+                ip,
+                created: chrono::Utc::now().to_rfc3339(),
+                source: "Server Wrapper".to_string(),
+                expires: "forever".to_string(),
+                reason: "Banned by admin".to_string(),
+            });
+        }
+        missing
+    }).await.map_err(AppError::from)?;
     Ok(())
 }
 
@@ -199,7 +206,7 @@ pub async fn remove_player(
     list_type: String,
     identifier: String,
 ) -> CommandResult<()> {
-    let id = Uuid::parse_str(&instance_id).map_err(AppError::from)?;
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
 
     // Check if server is running and use console commands if possible
     if let Some(server) = server_manager.get_server(id).await {
@@ -220,26 +227,91 @@ pub async fn remove_player(
 
     match list_type.as_str() {
         "whitelist" => {
-            let mut list = players::read_whitelist(&instance.path).await.map_err(AppError::from)?;
-            list.retain(|p| p.uuid != identifier && p.name != identifier);
-            players::write_whitelist(&instance.path, &list).await.map_err(AppError::from)?;
+            players::update_whitelist(instance_manager.player_list_locks(), &instance.path, |list| {
+                let before = list.len();
+                list.retain(|p| p.uuid != identifier && p.name != identifier);
+                list.len() != before
+            }).await.map_err(AppError::from)?;
         },
         "ops" => {
-            let mut list = players::read_ops(&instance.path).await.map_err(AppError::from)?;
-            list.retain(|p| p.uuid != identifier && p.name != identifier);
-            players::write_ops(&instance.path, &list).await.map_err(AppError::from)?;
+            players::update_ops(instance_manager.player_list_locks(), &instance.path, |list| {
+                let before = list.len();
+                list.retain(|p| p.uuid != identifier && p.name != identifier);
+                list.len() != before
+            }).await.map_err(AppError::from)?;
         },
         "banned-players" => {
-            let mut list = players::read_banned_players(&instance.path).await.map_err(AppError::from)?;
-            list.retain(|p| p.uuid != identifier && p.name != identifier);
-            players::write_banned_players(&instance.path, &list).await.map_err(AppError::from)?;
+            players::update_banned_players(instance_manager.player_list_locks(), &instance.path, |list| {
+                let before = list.len();
+                list.retain(|p| p.uuid != identifier && p.name != identifier);
+                list.len() != before
+            }).await.map_err(AppError::from)?;
         },
         "banned-ips" => {
-            let mut list = players::read_banned_ips(&instance.path).await.map_err(AppError::from)?;
-            list.retain(|p| p.ip != identifier);
-            players::write_banned_ips(&instance.path, &list).await.map_err(AppError::from)?;
+            players::update_banned_ips(instance_manager.player_list_locks(), &instance.path, |list| {
+                let before = list.len();
+                list.retain(|p| p.ip != identifier);
+                list.len() != before
+            }).await.map_err(AppError::from)?;
         },
         _ => return Err(AppError::Validation("Invalid list type".to_string())),
     }
     Ok(())
 }
+
+/// Lock-out mistakes in the player lists, e.g. an empty whitelist that's switched on.
+#[tauri::command]
+pub async fn get_player_access_warnings(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+) -> CommandResult<Vec<players::AccessWarning>> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+    players::check_player_access(&instance.path, instance.mod_loader.as_deref())
+        .await
+        .map_err(AppError::from)
+}
+
+/// Applies one of the fixes offered with an access warning. `username` is needed to
+/// add someone to the whitelist or make them an operator.
+#[tauri::command]
+pub async fn fix_player_access(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+    fix: players::AccessFix,
+    username: Option<String>,
+) -> CommandResult<()> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager.get_instance(id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+    let username = username.map(|u| u.trim().to_string()).filter(|u| !u.is_empty());
+
+    let running = match server_manager.get_server(id).await {
+        Some(server) if server.get_status().await == ServerStatus::Running => Some(server),
+        _ => None,
+    };
+
+    match fix {
+        players::AccessFix::DisableWhitelist => match running {
+            Some(server) => server.send_command("whitelist off").await.map_err(AppError::from),
+            None => players::disable_whitelist(&instance.path).await.map_err(AppError::from),
+        },
+        players::AccessFix::WhitelistKnownPlayers => match running {
+            Some(server) => {
+                let known = players::read_usercache(&instance.path).await.map_err(AppError::from)?;
+                for player in known {
+                    server.send_command(&format!("whitelist add {}", player.name)).await.map_err(AppError::from)?;
+                }
+                Ok(())
+            }
+            None => players::whitelist_known_players(instance_manager.player_list_locks(), &instance.path).await.map(|_| ()).map_err(AppError::from),
+        },
+        players::AccessFix::AddToWhitelist | players::AccessFix::AddOperator => {
+            let username = username.ok_or_else(|| AppError::Validation("Enter a username".to_string()))?;
+            let list_type = if fix == players::AccessFix::AddOperator { "ops" } else { "whitelist" };
+            add_player(instance_manager, server_manager, instance_id, list_type.to_string(), username).await
+        }
+    }
+}
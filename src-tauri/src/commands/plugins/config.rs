@@ -1,8 +1,7 @@
 use mc_server_wrapper_core::instance::InstanceManager;
 use tauri::State;
 use std::sync::Arc;
-use uuid::Uuid;
-use super::super::{CommandResult, AppError};
+use super::super::{CommandResult, AppError, resolve_instance_id};
 
 #[derive(serde::Serialize)]
 pub struct PluginConfigs {
@@ -13,10 +12,11 @@ pub struct PluginConfigs {
 #[tauri::command]
 pub async fn list_plugin_configs(
     instance_manager: State<'_, Arc<InstanceManager>>,
-    instance_id: Uuid,
+    instance_id: String,
     plugin_name: String,
     plugin_filename: String,
 ) -> CommandResult<PluginConfigs> {
+    let instance_id = resolve_instance_id(&instance_manager, &instance_id).await?;
     let instances = instance_manager.list_instances().await.map_err(AppError::from)?;
     let instance = instances.iter().find(|i| i.id == instance_id)
         .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
@@ -0,0 +1,25 @@
+use mc_server_wrapper_core::manager::{CrossplaySetupReport, ServerManager};
+use mc_server_wrapper_core::plugins::CrossplayPlan;
+use tauri::State;
+use std::sync::Arc;
+use super::super::{CommandResult, AppError, resolve_instance_id};
+
+#[tauri::command]
+pub async fn get_crossplay_plan(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+    bedrock_port: Option<u16>,
+) -> CommandResult<CrossplayPlan> {
+    let instance_id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    server_manager.plan_crossplay(instance_id, bedrock_port).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn setup_crossplay(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+    bedrock_port: Option<u16>,
+) -> CommandResult<CrossplaySetupReport> {
+    let instance_id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    server_manager.setup_crossplay(instance_id, bedrock_port).await.map_err(AppError::from)
+}
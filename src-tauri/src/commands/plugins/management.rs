@@ -1,15 +1,16 @@
 use mc_server_wrapper_core::instance::InstanceManager;
 use mc_server_wrapper_core::plugins::{self, InstalledPlugin};
+use mc_server_wrapper_core::utils::BulkReport;
 use tauri::State;
 use std::sync::Arc;
-use uuid::Uuid;
-use super::super::{CommandResult, AppError};
+use super::super::{CommandResult, AppError, emit_bulk_progress, resolve_instance_id};
 
 #[tauri::command]
 pub async fn list_installed_plugins(
     instance_manager: State<'_, Arc<InstanceManager>>,
-    instance_id: Uuid,
+    instance_id: String,
 ) -> CommandResult<Vec<InstalledPlugin>> {
+    let instance_id = resolve_instance_id(&instance_manager, &instance_id).await?;
     let instances = instance_manager.list_instances().await.map_err(AppError::from)?;
     let instance = instances.iter().find(|i| i.id == instance_id)
         .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
@@ -20,10 +21,11 @@ pub async fn list_installed_plugins(
 #[tauri::command]
 pub async fn toggle_plugin(
     instance_manager: State<'_, Arc<InstanceManager>>,
-    instance_id: Uuid,
+    instance_id: String,
     filename: String,
     enable: bool,
 ) -> CommandResult<()> {
+    let instance_id = resolve_instance_id(&instance_manager, &instance_id).await?;
     let instances = instance_manager.list_instances().await.map_err(AppError::from)?;
     let instance = instances.iter().find(|i| i.id == instance_id)
         .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
@@ -33,25 +35,29 @@ pub async fn toggle_plugin(
 
 #[tauri::command]
 pub async fn bulk_toggle_plugins(
+    app_handle: tauri::AppHandle,
     instance_manager: State<'_, Arc<InstanceManager>>,
-    instance_id: Uuid,
+    instance_id: String,
     filenames: Vec<String>,
     enable: bool,
-) -> CommandResult<()> {
+) -> CommandResult<BulkReport> {
+    let instance_id = resolve_instance_id(&instance_manager, &instance_id).await?;
     let instances = instance_manager.list_instances().await.map_err(AppError::from)?;
     let instance = instances.iter().find(|i| i.id == instance_id)
         .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
 
-    plugins::bulk_toggle_plugins(&instance.path, filenames, enable).await.map_err(AppError::from)
+    let progress = emit_bulk_progress(app_handle, instance_id);
+    Ok(plugins::bulk_toggle_plugins(&instance.path, filenames, enable, progress).await)
 }
 
 #[tauri::command]
 pub async fn uninstall_plugin(
     instance_manager: State<'_, Arc<InstanceManager>>,
-    instance_id: Uuid,
+    instance_id: String,
     filename: String,
     delete_config: bool,
 ) -> CommandResult<()> {
+    let instance_id = resolve_instance_id(&instance_manager, &instance_id).await?;
     let instances = instance_manager.list_instances().await.map_err(AppError::from)?;
     let instance = instances.iter().find(|i| i.id == instance_id)
         .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
@@ -61,14 +67,17 @@ pub async fn uninstall_plugin(
 
 #[tauri::command]
 pub async fn bulk_uninstall_plugins(
+    app_handle: tauri::AppHandle,
     instance_manager: State<'_, Arc<InstanceManager>>,
-    instance_id: Uuid,
+    instance_id: String,
     filenames: Vec<String>,
     delete_config: bool,
-) -> CommandResult<()> {
+) -> CommandResult<BulkReport> {
+    let instance_id = resolve_instance_id(&instance_manager, &instance_id).await?;
     let instances = instance_manager.list_instances().await.map_err(AppError::from)?;
     let instance = instances.iter().find(|i| i.id == instance_id)
         .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
 
-    plugins::bulk_uninstall_plugins(&instance.path, filenames, delete_config).await.map_err(AppError::from)
+    let progress = emit_bulk_progress(app_handle, instance_id);
+    Ok(plugins::bulk_uninstall_plugins(&instance.path, filenames, delete_config, progress).await)
 }
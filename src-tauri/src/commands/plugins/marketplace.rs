@@ -1,9 +1,10 @@
 use mc_server_wrapper_core::plugins::{self, Project, PluginProvider, SearchOptions, PluginDependencies};
 use mc_server_wrapper_core::manager::ServerManager;
+use mc_server_wrapper_core::conflicts::{InstallResult, check_install_conflicts};
+use mc_server_wrapper_core::content_report::ContentKind;
 use tauri::State;
 use std::sync::Arc;
-use uuid::Uuid;
-use super::super::{CommandResult, AppError};
+use super::super::{CommandResult, AppError, resolve_instance_id};
 
 #[tauri::command]
 pub async fn search_plugins(
@@ -17,10 +18,11 @@ pub async fn search_plugins(
 #[tauri::command]
 pub async fn get_plugin_dependencies(
     server_manager: State<'_, Arc<ServerManager>>,
-    instance_id: Uuid,
+    instance_id: String,
     project_id: String,
     provider: PluginProvider,
 ) -> CommandResult<PluginDependencies> {
+    let instance_id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
     let instances = server_manager.get_instance_manager().list_instances().await.map_err(AppError::from)?;
     let instance = instances.iter().find(|i| i.id == instance_id)
         .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
@@ -37,16 +39,17 @@ pub async fn get_plugin_dependencies(
 #[tauri::command]
 pub async fn install_plugin(
     server_manager: State<'_, Arc<ServerManager>>,
-    instance_id: Uuid,
+    instance_id: String,
     project_id: String,
     provider: PluginProvider,
     version_id: Option<String>,
-) -> CommandResult<String> {
+) -> CommandResult<InstallResult> {
+    let instance_id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
     let instances = server_manager.get_instance_manager().list_instances().await.map_err(AppError::from)?;
     let instance = instances.iter().find(|i| i.id == instance_id)
         .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
 
-    plugins::install_plugin(
+    let filename = plugins::install_plugin(
         &instance.path, 
         &project_id, 
         provider, 
@@ -56,5 +59,9 @@ pub async fn install_plugin(
         server_manager.get_cache()
     )
         .await
-        .map_err(AppError::from)
+        .map_err(AppError::from)?;
+    let warnings = check_install_conflicts(&instance.path, ContentKind::Plugin, &filename)
+        .await
+        .map_err(AppError::from)?;
+    Ok(InstallResult { filename, warnings })
 }
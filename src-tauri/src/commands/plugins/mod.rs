@@ -2,8 +2,12 @@ pub mod management;
 pub mod marketplace;
 pub mod updates;
 pub mod config;
+pub mod via;
+pub mod geyser;
 
 pub use management::*;
 pub use marketplace::*;
 pub use updates::*;
 pub use config::*;
+pub use via::*;
+pub use geyser::*;
@@ -1,15 +1,19 @@
+use mc_server_wrapper_core::content_report::ContentKind;
+use mc_server_wrapper_core::instance::InstanceManager;
 use mc_server_wrapper_core::manager::ServerManager;
 use mc_server_wrapper_core::plugins::{self, PluginProvider, PluginUpdate};
+use mc_server_wrapper_core::timeline::TimelineKind;
+use mc_server_wrapper_core::version_history::{self, InstalledVersion};
 use tauri::State;
 use std::sync::Arc;
-use uuid::Uuid;
-use super::super::{CommandResult, AppError};
+use super::super::{CommandResult, AppError, resolve_instance_id};
 
 #[tauri::command]
 pub async fn check_for_plugin_updates(
     server_manager: State<'_, Arc<ServerManager>>,
-    instance_id: Uuid,
+    instance_id: String,
 ) -> CommandResult<Vec<PluginUpdate>> {
+    let instance_id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
     let instances = server_manager.get_instance_manager().list_instances().await.map_err(AppError::from)?;
     let instance = instances.iter().find(|i| i.id == instance_id)
         .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
@@ -25,22 +29,65 @@ pub async fn check_for_plugin_updates(
 #[tauri::command]
 pub async fn update_plugin(
     server_manager: State<'_, Arc<ServerManager>>,
-    instance_id: Uuid,
+    instance_id: String,
     filename: String,
     project_id: String,
     provider: PluginProvider,
     latest_version_id: String,
 ) -> CommandResult<()> {
+    let instance_id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
     let instances = server_manager.get_instance_manager().list_instances().await.map_err(AppError::from)?;
     let instance = instances.iter().find(|i| i.id == instance_id)
         .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
 
+    let summary = format!("Updated plugin {}", filename);
     plugins::update_plugin(
         &instance.path, 
         filename, 
         project_id, 
         provider, 
         latest_version_id,
-        server_manager.get_cache()
-    ).await.map_err(AppError::from)
+        server_manager.get_cache(),
+        &server_manager.get_artifact_store()
+    ).await.map_err(AppError::from)?;
+    server_manager.get_instance_manager()
+        .note_timeline_event(instance_id, TimelineKind::UpdateApplied, summary, None).await;
+    Ok(())
+}
+
+/// Previous versions kept from earlier updates, newest first.
+#[tauri::command]
+pub async fn list_plugin_versions_installed(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    project_id: Option<String>,
+) -> CommandResult<Vec<InstalledVersion>> {
+    let instance_id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager.get_instance(instance_id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
+
+    Ok(version_history::list_installed_versions(&instance.path, ContentKind::Plugin, project_id.as_deref()).await)
+}
+
+#[tauri::command]
+pub async fn rollback_plugin(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+    project_id: String,
+    sha1: String,
+) -> CommandResult<String> {
+    let instance_id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    let instance = server_manager.get_instance_manager().get_instance(instance_id).await.map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
+
+    let filename = version_history::rollback_version(
+        &server_manager.get_artifact_store(),
+        &instance.path,
+        ContentKind::Plugin,
+        &project_id,
+        &sha1
+    ).await.map_err(AppError::from)?;
+    server_manager.get_instance_manager()
+        .note_timeline_event(instance_id, TimelineKind::UpdateApplied, format!("Rolled back plugin to {}", filename), None).await;
+    Ok(filename)
 }
@@ -0,0 +1,23 @@
+use mc_server_wrapper_core::manager::{ServerManager, ViaSetupReport};
+use mc_server_wrapper_core::plugins::ViaPlan;
+use tauri::State;
+use std::sync::Arc;
+use super::super::{CommandResult, AppError, resolve_instance_id};
+
+#[tauri::command]
+pub async fn get_via_plan(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+) -> CommandResult<ViaPlan> {
+    let instance_id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    server_manager.plan_via(instance_id).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn setup_via(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+) -> CommandResult<ViaSetupReport> {
+    let instance_id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    server_manager.setup_via(instance_id).await.map_err(AppError::from)
+}
@@ -0,0 +1,46 @@
+use super::{CommandResult, resolve_instance_id};
+use mc_server_wrapper_core::errors::AppError;
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::manager::ServerManager;
+use mc_server_wrapper_core::profiler::{ProfilerRequest, ProfilerSession};
+use std::sync::Arc;
+use tauri::State;
+
+/// Starts a spark profiler run and returns the id of the task tracking it; cancelling
+/// the task discards the run.
+#[tauri::command]
+pub async fn start_profiling(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+    request: ProfilerRequest,
+) -> CommandResult<String> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    let task_id = server_manager.inner().start_profiling(id, request).await.map_err(AppError::from)?;
+    Ok(task_id.to_string())
+}
+
+/// Stops the running profiler so spark uploads the result.
+#[tauri::command]
+pub async fn stop_profiling(server_manager: State<'_, Arc<ServerManager>>, instance_id: String) -> CommandResult<()> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    server_manager.stop_profiling(id).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn list_profiler_sessions(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+) -> CommandResult<Vec<ProfilerSession>> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    instance_manager.list_profiler_sessions(id).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn delete_profiler_session(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    session_id: i64,
+) -> CommandResult<bool> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    instance_manager.delete_profiler_session(id, session_id).await.map_err(AppError::from)
+}
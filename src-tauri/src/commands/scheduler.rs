@@ -1,19 +1,22 @@
 use tauri::State;
 use uuid::Uuid;
 use std::sync::Arc;
-use mc_server_wrapper_core::scheduler::{SchedulerManager, ScheduledTask, ScheduleType};
+use mc_server_wrapper_core::scheduler::{RestartWarning, SchedulerManager, ScheduledTask, ScheduleType};
 use mc_server_wrapper_core::instance::InstanceManager;
-use super::{CommandResult, AppError};
+use super::{CommandResult, AppError, resolve_instance_id};
 
 #[tauri::command]
 pub async fn add_scheduled_task(
-    instance_id: Uuid,
+    instance_id: String,
     task_type: ScheduleType,
     cron: String,
+    warning: Option<RestartWarning>,
     scheduler: State<'_, Arc<SchedulerManager>>,
     instance_manager: State<'_, Arc<InstanceManager>>,
 ) -> CommandResult<ScheduledTask> {
-    let task = ScheduledTask::new(instance_id, task_type, cron);
+    let instance_id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let mut task = ScheduledTask::new(instance_id, task_type, cron);
+    task.warning = warning;
     
     // Save to instance metadata
     instance_manager.add_schedule(instance_id, task.clone()).await
@@ -28,11 +31,12 @@ pub async fn add_scheduled_task(
 
 #[tauri::command]
 pub async fn remove_scheduled_task(
-    instance_id: Uuid,
+    instance_id: String,
     task_id: Uuid,
     scheduler: State<'_, Arc<SchedulerManager>>,
     instance_manager: State<'_, Arc<InstanceManager>>,
 ) -> CommandResult<()> {
+    let instance_id = resolve_instance_id(&instance_manager, &instance_id).await?;
     // Remove from running scheduler
     scheduler.remove_task(task_id).await
         .map_err(AppError::from)?;
@@ -46,8 +50,10 @@ pub async fn remove_scheduled_task(
 
 #[tauri::command]
 pub async fn list_scheduled_tasks(
-    instance_id: Uuid,
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
     scheduler: State<'_, Arc<SchedulerManager>>,
 ) -> CommandResult<Vec<ScheduledTask>> {
+    let instance_id = resolve_instance_id(&instance_manager, &instance_id).await?;
     Ok(scheduler.list_tasks(instance_id).await)
 }
@@ -0,0 +1,183 @@
+use mc_server_wrapper_core::manager::ServerManager;
+use mc_server_wrapper_core::server::{ChatEvent, ServerEvent, ServerStatus, ServerHandle, StartupProgress};
+use mc_server_wrapper_core::instance::dependencies::startup_waves;
+use mc_server_wrapper_core::events::{EventChannel, RecordedEvent};
+use tokio::sync::broadcast::error::RecvError;
+use tauri::{State, Emitter, Manager};
+use std::sync::Arc;
+use uuid::Uuid;
+use super::super::{AppState, CommandResult, AppError, resolve_instance_id};
+
+#[derive(Clone, serde::Serialize)]
+pub struct LogPayload {
+    pub instance_id: String,
+    pub line: String,
+    /// Number of the line in the server's console buffer, so the UI can skip lines a replay
+    /// already gave it. `None` for messages about a server that has no handle yet.
+    pub number: Option<u64>,
+}
+
+#[derive(Clone, serde::Serialize)]
+pub struct StartupProgressPayload {
+    pub instance_id: String,
+    pub progress: StartupProgress,
+    pub seq: u64,
+}
+
+#[derive(Clone, serde::Serialize)]
+pub struct ServerStatusPayload {
+    pub instance_id: String,
+    pub status: ServerStatus,
+}
+
+#[derive(Clone, serde::Serialize)]
+pub struct ChatPayload {
+    pub instance_id: String,
+    pub event: ChatEvent,
+}
+
+#[derive(Clone, serde::Serialize)]
+pub struct AutoStoppedPayload {
+    pub instance_id: String,
+    pub idle_secs: u64,
+}
+
+/// Shows a message from the wrapper in a server's console. Once the server's output is
+/// forwarded, the message goes through its console buffer like any other line, so a
+/// reloaded UI gets it back too; before that it is only sent live.
+pub async fn emit_log_line(app_handle: &tauri::AppHandle, instance_id: Uuid, line: String) {
+    let forwarded = app_handle.state::<AppState>().subscribed_servers.lock().await.contains(&instance_id);
+    let server = app_handle.state::<Arc<ServerManager>>().get_server(instance_id).await;
+    match server {
+        Some(server) if forwarded => server.emit_log(line),
+        _ => {
+            let _ = app_handle.emit("server-log", LogPayload {
+                instance_id: instance_id.to_string(),
+                line,
+                number: None,
+            });
+        }
+    }
+}
+
+/// Startup phases and task changes buffered after `since_seq`, so the UI can rebuild what it
+/// missed while reloading. Without an instance, returns the app-wide events. Console lines
+/// are replayed with `get_console_buffer`.
+#[tauri::command]
+pub async fn fetch_recent_events(
+    server_manager: State<'_, Arc<ServerManager>>,
+    app_state: State<'_, AppState>,
+    instance_id: Option<String>,
+    since_seq: u64,
+) -> CommandResult<Vec<RecordedEvent>> {
+    let id = match instance_id {
+        Some(instance_id) => Some(resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?),
+        None => None,
+    };
+    Ok(app_state.events.recent(id, since_seq))
+}
+
+pub async fn ensure_server_logs_forwarded(
+    app_state: &AppState,
+    server: Arc<ServerHandle>,
+    app_handle: tauri::AppHandle,
+    instance_id: String,
+) -> CommandResult<()> {
+    let id = Uuid::parse_str(&instance_id).map_err(AppError::from)?;
+    let mut subscribed = app_state.subscribed_servers.lock().await;
+    
+    if !subscribed.contains(&id) {
+        subscribed.insert(id);
+        
+        let mut rx = server.subscribe_console();
+        let mut rx_startup = server.subscribe_startup();
+        let mut rx_events = server.subscribe_events();
+        let mut rx_chat = server.subscribe_chat();
+        let app_handle_clone = app_handle.clone();
+        
+        tauri::async_runtime::spawn(async move {
+            loop {
+                match rx.recv().await {
+                    Ok(line) => {
+                        let _ = app_handle_clone.emit("server-log", LogPayload {
+                            instance_id: id.to_string(),
+                            line: line.raw,
+                            number: Some(line.number),
+                        });
+                    }
+                    // Missed lines are still in the buffer for the next replay
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+
+        let instance_id_clone3 = instance_id.clone();
+        let app_handle_clone3 = app_handle.clone();
+        let events = Arc::clone(&app_state.events);
+        tauri::async_runtime::spawn(async move {
+            while let Ok(progress) = rx_startup.recv().await {
+                let seq = events.publish(Some(id), EventChannel::Startup, &progress);
+                let _ = app_handle_clone3.emit("server-startup-progress", StartupProgressPayload {
+                    instance_id: instance_id_clone3.clone(),
+                    progress,
+                    seq,
+                });
+            }
+        });
+
+        let instance_id_clone5 = instance_id.clone();
+        let app_handle_clone5 = app_handle.clone();
+        tauri::async_runtime::spawn(async move {
+            while let Ok(event) = rx_chat.recv().await {
+                let _ = app_handle_clone5.emit("server-chat", ChatPayload {
+                    instance_id: instance_id_clone5.clone(),
+                    event,
+                });
+            }
+        });
+
+        let instance_id_clone4 = instance_id.clone();
+        let app_handle_clone4 = app_handle.clone();
+        tauri::async_runtime::spawn(async move {
+            loop {
+                match rx_events.recv().await {
+                    Ok(ServerEvent::AutoStopped { idle_secs }) => {
+                        let _ = app_handle_clone4.emit("auto-stopped", AutoStoppedPayload {
+                            instance_id: instance_id_clone4.clone(),
+                            idle_secs,
+                        });
+                    }
+                    // Status changes reach the UI as they happen instead of on its next poll
+                    Ok(ServerEvent::StatusChanged { status }) => {
+                        let _ = app_handle_clone4.emit("server-status", ServerStatusPayload {
+                            instance_id: instance_id_clone4.clone(),
+                            status,
+                        });
+                    }
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+    Ok(())
+}
+
+/// Forwards logs for the given servers and every dependency that starts with them,
+/// returning all of their IDs.
+pub async fn forward_logs_for_start(
+    server_manager: &ServerManager,
+    app_state: &AppState,
+    app_handle: &tauri::AppHandle,
+    ids: &[Uuid],
+) -> CommandResult<Vec<Uuid>> {
+    let instances = server_manager.get_instance_manager().list_instances().await.map_err(AppError::from)?;
+    let waves = startup_waves(&instances, ids).map_err(AppError::from)?;
+    let all: Vec<Uuid> = waves.into_iter().flatten().collect();
+    for id in &all {
+        let server = server_manager.get_or_create_server(*id).await.map_err(AppError::from)?;
+        ensure_server_logs_forwarded(app_state, server, app_handle.clone(), id.to_string()).await?;
+    }
+    Ok(all)
+}
@@ -0,0 +1,149 @@
+use mc_server_wrapper_core::manager::ServerManager;
+use tauri::State;
+use std::sync::Arc;
+use super::super::{AppState, CommandResult, AppError, resolve_instance_id};
+use super::events::{emit_log_line, ensure_server_logs_forwarded, forward_logs_for_start};
+
+#[tauri::command]
+pub async fn start_server(
+    server_manager: State<'_, Arc<ServerManager>>,
+    app_state: State<'_, AppState>,
+    app_handle: tauri::AppHandle,
+    instance_id: String,
+) -> CommandResult<()> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    let instance_id = id.to_string();
+    
+    // Get or create handle early so we can subscribe to logs during installation
+    let server = server_manager.get_or_create_server(id).await.map_err(AppError::from)?;
+    
+    ensure_server_logs_forwarded(&app_state, server, app_handle.clone(), instance_id.clone()).await?;
+
+    // Start the server in a separate task so the UI can receive logs immediately
+    // especially during the installation phase which might take time.
+    let server_manager_inner = server_manager.inner().clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = server_manager_inner.start_server(id).await {
+            emit_log_line(&app_handle, id, format!("Error starting server: {}", e)).await;
+        }
+    });
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn stop_server(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+) -> CommandResult<()> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    server_manager.stop_server(id).await.map_err(AppError::from)
+}
+
+/// Warns players with a countdown, kicks them, saves and then stops the server. Runs in
+/// the background since the countdown can take minutes.
+#[tauri::command]
+pub async fn stop_server_graceful(
+    server_manager: State<'_, Arc<ServerManager>>,
+    app_handle: tauri::AppHandle,
+    instance_id: String,
+    delay_secs: u64,
+    message: Option<String>,
+) -> CommandResult<()> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    let server_manager_inner = server_manager.inner().clone();
+    tauri::async_runtime::spawn(async move {
+        let delay = std::time::Duration::from_secs(delay_secs);
+        if let Err(e) = server_manager_inner.stop_server_graceful(id, delay, message.as_deref()).await {
+            emit_log_line(&app_handle, id, format!("Error stopping server: {}", e)).await;
+        }
+    });
+    Ok(())
+}
+
+/// Force-kills the server process without waiting for a clean shutdown.
+#[tauri::command]
+pub async fn kill_server(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+) -> CommandResult<()> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    server_manager.kill_server(id).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn restart_server(
+    server_manager: State<'_, Arc<ServerManager>>,
+    app_state: State<'_, AppState>,
+    app_handle: tauri::AppHandle,
+    instance_id: String,
+) -> CommandResult<()> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    let instance_id = id.to_string();
+    let server = server_manager.get_or_create_server(id).await.map_err(AppError::from)?;
+    ensure_server_logs_forwarded(&app_state, server, app_handle.clone(), instance_id.clone()).await?;
+
+    // Stopping can take a while, so the restart runs in the background like start_server
+    let server_manager_inner = server_manager.inner().clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = server_manager_inner.restart_server(id).await {
+            emit_log_line(&app_handle, id, format!("Error restarting server: {}", e)).await;
+        }
+    });
+
+    Ok(())
+}
+
+/// Downloads and installs the server files without starting it.
+#[tauri::command]
+pub async fn prepare_server(
+    server_manager: State<'_, Arc<ServerManager>>,
+    app_state: State<'_, AppState>,
+    app_handle: tauri::AppHandle,
+    instance_id: String,
+) -> CommandResult<()> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    let instance_id = id.to_string();
+    let server = server_manager.get_or_create_server(id).await.map_err(AppError::from)?;
+    ensure_server_logs_forwarded(&app_state, server, app_handle.clone(), instance_id.clone()).await?;
+
+    let server_manager_inner = server_manager.inner().clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = server_manager_inner.prepare_server(id).await {
+            emit_log_line(&app_handle, id, format!("Error preparing server: {}", e)).await;
+        }
+    });
+
+    Ok(())
+}
+
+/// Starts several servers in dependency order, starting any dependencies they declare first.
+#[tauri::command]
+pub async fn start_servers(
+    server_manager: State<'_, Arc<ServerManager>>,
+    app_state: State<'_, AppState>,
+    app_handle: tauri::AppHandle,
+    instance_ids: Vec<String>,
+) -> CommandResult<Vec<String>> {
+    let instance_manager = server_manager.get_instance_manager();
+    let mut ids = Vec::new();
+    for instance_id in &instance_ids {
+        ids.push(resolve_instance_id(&instance_manager, instance_id).await?);
+    }
+
+    forward_logs_for_start(&server_manager, &app_state, &app_handle, &ids).await?;
+    let started = server_manager.start_servers(&ids).await.map_err(AppError::from)?;
+    Ok(started.into_iter().map(|id| id.to_string()).collect())
+}
+
+#[tauri::command]
+pub async fn stop_all_servers(
+    server_manager: State<'_, Arc<ServerManager>>,
+    force: Option<bool>,
+) -> CommandResult<Vec<String>> {
+    let stopped = server_manager
+        .stop_all_servers(force.unwrap_or(false))
+        .await
+        .map_err(AppError::from)?;
+    Ok(stopped.into_iter().map(|id| id.to_string()).collect())
+}
@@ -0,0 +1,73 @@
+use mc_server_wrapper_core::manager::ServerManager;
+use mc_server_wrapper_core::server::ConsolePage;
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::log_files::{self, LogFileInfo, LogPage};
+use tauri::State;
+use std::sync::Arc;
+use super::super::{CommandResult, AppError, resolve_instance_id};
+
+#[tauri::command]
+pub async fn read_latest_log(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+) -> CommandResult<String> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    if let Some(instance) = instance_manager.get_instance(id).await.map_err(AppError::from)? {
+        let log_path = instance.path.join("logs").join("latest.log");
+        if log_path.exists() {
+            tokio::fs::read_to_string(log_path).await.map_err(AppError::from)
+        } else {
+            Ok("".to_string())
+        }
+    } else {
+        Err(AppError::NotFound("Instance not found".to_string()))
+    }
+}
+
+/// Console lines the server still has in memory, numbered `from_line` and up. Empty when the
+/// server hasn't been started since the app opened.
+#[tauri::command]
+pub async fn get_console_buffer(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+    from_line: u64,
+) -> CommandResult<ConsolePage> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    Ok(match server_manager.get_server(id).await {
+        Some(server) => server.get_console_buffer(from_line),
+        None => ConsolePage { lines: Vec::new(), next_line: 0, dropped: 0 },
+    })
+}
+
+#[tauri::command]
+pub async fn list_log_files(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+) -> CommandResult<Vec<LogFileInfo>> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager
+        .get_instance(id)
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+    log_files::list_log_files(&instance.path).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn read_log_file(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    name: String,
+    offset: u64,
+    limit: u64,
+) -> CommandResult<LogPage> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let instance = instance_manager
+        .get_instance(id)
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+    log_files::read_log_page(&instance.path, &name, offset, limit)
+        .await
+        .map_err(AppError::from)
+}
@@ -0,0 +1,139 @@
+pub mod events;
+pub mod lifecycle;
+pub mod logs;
+
+pub use events::*;
+pub use lifecycle::*;
+pub use logs::*;
+
+use mc_server_wrapper_core::doctor::DiagnosisReport;
+use mc_server_wrapper_core::manager::{InstallerRepair, ProvisionState, ServerManager};
+use mc_server_wrapper_core::server::{ChatEvent, ServerStatus, ResourceUsage};
+use mc_server_wrapper_core::instance::{InstanceManager, MetricSample};
+use tauri::State;
+use std::sync::Arc;
+use super::{CommandResult, AppError, resolve_instance_id};
+
+#[tauri::command]
+pub async fn send_command(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+    command: String,
+) -> CommandResult<()> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    server_manager.send_command(id, &command).await.map_err(AppError::from)
+}
+
+/// Broadcasts `message` to the server's players as the server.
+#[tauri::command]
+pub async fn send_chat(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+    message: String,
+) -> CommandResult<()> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    server_manager.send_chat(id, &message).await.map_err(AppError::from)
+}
+
+/// Chat, joins, deaths and advancements among the console lines the server kept in memory.
+#[tauri::command]
+pub async fn get_recent_chat(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+) -> CommandResult<Vec<ChatEvent>> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    Ok(match server_manager.get_server(id).await {
+        Some(server) => server.get_recent_chat(),
+        None => Vec::new(),
+    })
+}
+
+#[tauri::command]
+pub async fn get_server_status(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+) -> CommandResult<ServerStatus> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    Ok(server_manager.get_server_status(id).await)
+}
+
+#[tauri::command]
+pub async fn get_server_usage(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+) -> CommandResult<Option<ResourceUsage>> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    Ok(server_manager.get_server_usage(id).await)
+}
+
+/// Resource samples from the metrics store for the last `minutes` (default one hour).
+#[tauri::command]
+pub async fn get_metrics_history(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    minutes: Option<i64>,
+) -> CommandResult<Vec<MetricSample>> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let since = chrono::Utc::now() - chrono::Duration::minutes(minutes.unwrap_or(60));
+    instance_manager
+        .get_metric_samples(id, since)
+        .await
+        .map_err(AppError::from)
+}
+
+/// Writes stored samples between `from` and `until` (RFC 3339, both optional) to a CSV
+/// file at `dest_path`. Returns the number of rows.
+#[tauri::command]
+pub async fn export_metrics_csv(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    dest_path: String,
+    from: Option<String>,
+    until: Option<String>,
+) -> CommandResult<usize> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    let parse = |value: Option<String>| {
+        value
+            .map(|v| {
+                chrono::DateTime::parse_from_rfc3339(&v)
+                    .map(|d| d.with_timezone(&chrono::Utc))
+                    .map_err(|_| AppError::Validation(format!("Invalid date: {}", v)))
+            })
+            .transpose()
+    };
+    let from = parse(from)?.unwrap_or(chrono::DateTime::UNIX_EPOCH);
+    let until = parse(until)?;
+    instance_manager
+        .export_metrics_csv(id, from, until, std::path::Path::new(&dest_path))
+        .await
+        .map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn get_provisioning_state(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+) -> CommandResult<Option<ProvisionState>> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    server_manager.get_provisioning_state(id).await.map_err(AppError::from)
+}
+
+/// Re-runs the failed phase of a Forge/NeoForge install after removing broken libraries.
+#[tauri::command]
+pub async fn repair_installation(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+) -> CommandResult<InstallerRepair> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    server_manager.repair_installation(id).await.map_err(AppError::from)
+}
+
+/// Runs the instance health checks (jar, Java, EULA, port, mods, leftovers).
+#[tauri::command]
+pub async fn diagnose_instance(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+) -> CommandResult<DiagnosisReport> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    server_manager.diagnose_instance(id).await.map_err(AppError::from)
+}
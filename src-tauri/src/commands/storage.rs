@@ -0,0 +1,131 @@
+use super::{CommandResult, resolve_instance_id};
+use crate::setup::AppStorage;
+use mc_server_wrapper_core::artifacts::CachedServer;
+use mc_server_wrapper_core::assets::AssetManager;
+use mc_server_wrapper_core::cache::{CacheNamespace, CacheStats};
+use mc_server_wrapper_core::errors::AppError;
+use mc_server_wrapper_core::instance::{InstanceManager, InstanceMetadata, StorageRoot};
+use mc_server_wrapper_core::manager::{DeduplicationReport, ServerManager};
+use mc_server_wrapper_core::storage_mode::StorageMode;
+use serde::Serialize;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::State;
+
+/// Links the library jars of all stopped instances into the shared artifact store.
+#[tauri::command]
+pub async fn deduplicate_libraries(
+    server_manager: State<'_, Arc<ServerManager>>,
+) -> CommandResult<DeduplicationReport> {
+    server_manager.deduplicate_libraries().await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn get_cache_stats(server_manager: State<'_, Arc<ServerManager>>) -> CommandResult<CacheStats> {
+    server_manager.get_cache().stats().await.map_err(AppError::from)
+}
+
+/// Clears one namespace of cached data, or all of them. Returns the number of entries removed.
+#[tauri::command]
+pub async fn clear_cache(
+    server_manager: State<'_, Arc<ServerManager>>,
+    asset_manager: State<'_, Arc<AssetManager>>,
+    namespace: Option<CacheNamespace>,
+) -> CommandResult<u64> {
+    let namespaces = match namespace {
+        Some(namespace) => vec![namespace],
+        None => vec![
+            CacheNamespace::Manifests,
+            CacheNamespace::Search,
+            CacheNamespace::Projects,
+            CacheNamespace::Avatars,
+        ],
+    };
+    let cache = server_manager.get_cache();
+    let mut removed = 0;
+    for namespace in namespaces {
+        removed += match namespace {
+            CacheNamespace::Avatars => asset_manager.cleanup_assets(Duration::ZERO).await,
+            namespace => cache.clear_namespace(namespace).await,
+        }
+        .map_err(AppError::from)?;
+    }
+    Ok(removed)
+}
+
+/// Server versions that can be installed from the artifact store without a network.
+#[tauri::command]
+pub async fn get_offline_servers(server_manager: State<'_, Arc<ServerManager>>) -> CommandResult<Vec<CachedServer>> {
+    Ok(server_manager.get_offline_servers().await)
+}
+
+/// The default instances directory and the configured storage roots, with usage.
+#[tauri::command]
+pub async fn get_storage_roots(instance_manager: State<'_, Arc<InstanceManager>>) -> CommandResult<Vec<StorageRoot>> {
+    instance_manager.list_storage_roots().await.map_err(AppError::from)
+}
+
+/// Checks and applies new storage roots before they are saved with the settings, so a
+/// refused change can be reported. Returns the roots with their usage.
+#[tauri::command]
+pub async fn set_storage_roots(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    roots: Vec<PathBuf>,
+) -> CommandResult<Vec<StorageRoot>> {
+    instance_manager.set_storage_roots(&roots).await.map_err(AppError::from)?;
+    instance_manager.list_storage_roots().await.map_err(AppError::from)
+}
+
+/// Moves a stopped instance into another storage root, tracked as a task.
+#[tauri::command]
+pub async fn move_instance(
+    server_manager: State<'_, Arc<ServerManager>>,
+    instance_id: String,
+    target_root: String,
+) -> CommandResult<InstanceMetadata> {
+    let id = resolve_instance_id(&server_manager.get_instance_manager(), &instance_id).await?;
+    server_manager
+        .move_instance(id, &PathBuf::from(target_root))
+        .await
+        .map_err(AppError::from)
+}
+
+/// Where the app keeps its data, and whether a switch is waiting for the next start.
+#[derive(Debug, Serialize)]
+pub struct StorageModeInfo {
+    pub mode: StorageMode,
+    pub data_dir: PathBuf,
+    pub portable_dir: PathBuf,
+    pub installed_dir: PathBuf,
+    pub portable_available: bool,
+    pub pending: Option<StorageMode>,
+}
+
+async fn storage_mode_info(storage: &AppStorage) -> StorageModeInfo {
+    StorageModeInfo {
+        mode: storage.mode,
+        data_dir: storage.data_dir().to_path_buf(),
+        portable_dir: storage.locations.portable.clone(),
+        installed_dir: storage.locations.installed.clone(),
+        portable_available: storage.locations.portable_available(),
+        pending: storage.locations.pending_migration(storage.mode).await,
+    }
+}
+
+#[tauri::command]
+pub async fn get_storage_mode(storage: State<'_, AppStorage>) -> CommandResult<StorageModeInfo> {
+    Ok(storage_mode_info(&storage).await)
+}
+
+/// Schedules moving the app data to the other mode's folder on the next start, when
+/// nothing in it is open. Choosing the current mode withdraws a scheduled move.
+#[tauri::command]
+pub async fn set_storage_mode(storage: State<'_, AppStorage>, mode: StorageMode) -> CommandResult<StorageModeInfo> {
+    storage
+        .locations
+        .request_migration(storage.mode, mode)
+        .await
+        .map_err(AppError::from)?;
+    Ok(storage_mode_info(&storage).await)
+}
@@ -0,0 +1,30 @@
+use mc_server_wrapper_core::tasks::{TaskInfo, TaskManager};
+use tauri::State;
+use std::sync::Arc;
+use uuid::Uuid;
+use super::{CommandResult, AppError};
+
+#[tauri::command]
+pub async fn list_tasks(task_manager: State<'_, Arc<TaskManager>>) -> CommandResult<Vec<TaskInfo>> {
+    Ok(task_manager.list())
+}
+
+/// Returns false when the task has already finished or can't be cancelled.
+#[tauri::command]
+pub async fn cancel_task(task_manager: State<'_, Arc<TaskManager>>, task_id: String) -> CommandResult<bool> {
+    let id = Uuid::parse_str(&task_id).map_err(AppError::from)?;
+    Ok(task_manager.cancel(id))
+}
+
+/// Returns false when the task has already finished or can't be paused.
+#[tauri::command]
+pub async fn pause_task(task_manager: State<'_, Arc<TaskManager>>, task_id: String) -> CommandResult<bool> {
+    let id = Uuid::parse_str(&task_id).map_err(AppError::from)?;
+    Ok(task_manager.set_paused(id, true))
+}
+
+#[tauri::command]
+pub async fn resume_task(task_manager: State<'_, Arc<TaskManager>>, task_id: String) -> CommandResult<bool> {
+    let id = Uuid::parse_str(&task_id).map_err(AppError::from)?;
+    Ok(task_manager.set_paused(id, false))
+}
@@ -0,0 +1,49 @@
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::manager::ServerManager;
+use mc_server_wrapper_core::templates::{InstanceTemplate, TemplateReport};
+use tauri::State;
+use std::sync::Arc;
+use super::{CommandResult, AppError, resolve_instance_id};
+
+#[tauri::command]
+pub async fn list_templates(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+) -> CommandResult<Vec<InstanceTemplate>> {
+    instance_manager.list_templates().await.map_err(AppError::from)
+}
+
+/// Saves an instance as a template. `config_files` are paths relative to the instance root.
+#[tauri::command]
+pub async fn save_instance_as_template(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    instance_id: String,
+    name: String,
+    description: Option<String>,
+    config_files: Vec<String>,
+) -> CommandResult<InstanceTemplate> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    instance_manager
+        .save_instance_as_template(id, &name, description, &config_files)
+        .await
+        .map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn delete_template(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    template_id: i64,
+) -> CommandResult<()> {
+    instance_manager.delete_template(template_id).await.map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn create_instance_from_template(
+    server_manager: State<'_, Arc<ServerManager>>,
+    template_id: i64,
+    name: String,
+) -> CommandResult<TemplateReport> {
+    server_manager
+        .create_instance_from_template(template_id, &name)
+        .await
+        .map_err(AppError::from)
+}
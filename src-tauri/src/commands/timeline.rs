@@ -0,0 +1,19 @@
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::timeline::{DEFAULT_TIMELINE_LIMIT, TimelineEvent};
+use tauri::State;
+use std::sync::Arc;
+use super::{AppState, CommandResult, AppError, resolve_instance_id};
+
+#[tauri::command]
+pub async fn get_instance_timeline(
+    instance_manager: State<'_, Arc<InstanceManager>>,
+    app_state: State<'_, AppState>,
+    instance_id: String,
+    limit: Option<usize>,
+) -> CommandResult<Vec<TimelineEvent>> {
+    let id = resolve_instance_id(&instance_manager, &instance_id).await?;
+    instance_manager
+        .get_instance_timeline(id, limit.unwrap_or(DEFAULT_TIMELINE_LIMIT), &app_state.events)
+        .await
+        .map_err(AppError::from)
+}
@@ -1,14 +1,18 @@
 mod commands;
 mod setup;
+mod shutdown;
 
 use anyhow::Context;
 use commands::AppState;
 use mc_server_wrapper_core::app_config::{CloseBehavior, GlobalConfigManager};
 use mc_server_wrapper_core::backup::BackupManager;
 use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::database::editor::DatabaseEditor;
 use mc_server_wrapper_core::java::JavaManager;
 use mc_server_wrapper_core::manager::ServerManager;
 use mc_server_wrapper_core::scheduler::SchedulerManager;
+use mc_server_wrapper_core::storage_mode::{SETTINGS_FILE, StorageMode};
+use mc_server_wrapper_core::watcher::FileWatcher;
 use std::collections::HashSet;
 use std::sync::Arc;
 use tauri::Manager;
@@ -27,35 +31,58 @@ pub fn run() -> anyhow::Result<()> {
       setup::setup_window(app);
             setup::setup_tray(app).context("failed to setup tray")?;
 
-            // Initialize Directories next to the executable
             let exe_path = std::env::current_exe()
                 .context("failed to get exe path")?
                 .parent()
                 .context("failed to get exe directory")?
                 .to_path_buf();
 
-            setup::setup_logging(app, &exe_path).context("failed to setup logging")?;
-
             app.handle().plugin(tauri_plugin_dialog::init())?;
             app.handle().plugin(tauri_plugin_opener::init())?;
             // Removed: app.handle().plugin(tauri_plugin_notification::init())?;
 
-            setup::check_clutter(app, &exe_path);
+            // Data lives next to the executable in portable mode, in the user's app data folder otherwise
+            let storage = setup::resolve_storage(app, &exe_path).context("failed to resolve data folder")?;
+            let data_dir = storage.data_dir().to_path_buf();
+
+            if storage.mode == StorageMode::Portable {
+                setup::check_clutter(app, &exe_path);
+            }
+
+            // Initialize GlobalConfigManager
+            let config_manager = Arc::new(GlobalConfigManager::new(data_dir.join(SETTINGS_FILE)));
+            let log_settings = tauri::async_runtime::block_on(config_manager.load())
+                .map(|settings| settings.logging)
+                .unwrap_or_default();
+            setup::setup_logging(app, &data_dir, &log_settings).context("failed to setup logging")?;
 
             let app_dirs = tauri::async_runtime::block_on(async {
-                mc_server_wrapper_core::init::init_directories(&exe_path).await
+                mc_server_wrapper_core::init::init_directories(&data_dir).await
             })
             .context("failed to initialize directories")?;
 
-            // Initialize GlobalConfigManager
-            let config_manager =
-                Arc::new(GlobalConfigManager::new(exe_path.join("app_settings.json")));
+            if let Some(old_root) = &storage.migrated_from {
+                let rebased = tauri::async_runtime::block_on(config_manager.rebase_paths(old_root, &data_dir));
+                if let Err(e) = rebased {
+                    log::error!("Failed to update settings paths after moving the data folder: {}", e);
+                }
+            }
+            if let Err(e) = tauri::async_runtime::block_on(config_manager.load_credentials()) {
+                log::error!("Failed to load stored credentials: {}", e);
+            }
+            let settings = match tauri::async_runtime::block_on(config_manager.load()) {
+                Ok(settings) => Some(settings),
+                Err(e) => {
+                    log::error!("Failed to load download settings: {}", e);
+                    None
+                }
+            };
 
             // Initialize Database
             let db = Arc::new(
                 tauri::async_runtime::block_on(async {
                     mc_server_wrapper_core::database::Database::new(
-                        exe_path.join("resources").join("app.db"),
+                        data_dir.join("resources").join("app.db"),
                     )
                     .await
                 })
@@ -63,8 +90,11 @@ pub fn run() -> anyhow::Result<()> {
             );
 
             // Initialize JavaManager
-            let java_manager =
-                Arc::new(JavaManager::new().context("failed to initialize java manager")?);
+            let java_manager = Arc::new(
+                JavaManager::with_base_dir(data_dir.join("java"))
+                    .context("failed to initialize java manager")?
+                    .with_download_limits(config_manager.download_limits()),
+            );
 
             // Initialize InstanceManager using the 'server' directory
             let instance_manager = Arc::new(
@@ -73,13 +103,26 @@ pub fn run() -> anyhow::Result<()> {
                 })
                 .context("failed to initialize instance manager")?,
             );
+            if let Some(old_root) = &storage.migrated_from {
+                let rebased = tauri::async_runtime::block_on(instance_manager.rebase_paths(old_root, &data_dir));
+                if let Err(e) = rebased {
+                    log::error!("Failed to update instance paths after moving the data folder: {}", e);
+                }
+            }
+            if let Some(settings) = &settings {
+                if let Err(e) = instance_manager.load_storage_roots(&settings.storage_roots) {
+                    log::error!("Failed to load storage roots: {}", e);
+                }
+            }
 
             // Initialize CacheManager
             let cache_manager = Arc::new(mc_server_wrapper_core::cache::CacheManager::new(
                 1024,
                 std::time::Duration::from_secs(86400),
                 Some(app_dirs.cache.clone()),
-            ));
+            )
+            .with_credentials(config_manager.credentials())
+            .with_download_limits(config_manager.download_limits()));
 
             // Initialize AssetManager
             let asset_manager = Arc::new(mc_server_wrapper_core::assets::AssetManager::new(
@@ -95,7 +138,15 @@ pub fn run() -> anyhow::Result<()> {
             // Run maintenance tasks (migration and pruning) in the background
             let sm_clone = Arc::clone(&server_manager);
             let am_clone = Arc::clone(&asset_manager);
+            let cache_limits = settings.map(|s| s.cache_limits).unwrap_or_default();
             tauri::async_runtime::spawn(async move {
+                let cache = sm_clone.get_cache();
+                // The age check spares downloads that started since launch
+                if let Err(e) = cache.cleanup_temp_files(std::time::Duration::from_secs(3600)).await {
+                    log::error!("Failed to clean up temporary cache files: {}", e);
+                }
+                cache.set_max_disk_bytes(cache_limits.max_disk_bytes()).await;
+
                 if let Err(e) = sm_clone.perform_maintenance().await {
                     log::error!("Failed to perform server manager maintenance: {}", e);
                 }
@@ -131,15 +182,73 @@ pub fn run() -> anyhow::Result<()> {
             })?);
 
             app.manage(instance_manager);
+            app.manage(server_manager.get_task_manager());
+            let events = server_manager.get_event_bus();
             app.manage(server_manager);
             app.manage(backup_manager);
             app.manage(scheduler_manager);
             app.manage(config_manager);
+            app.manage(storage);
             app.manage(java_manager);
             app.manage(cache_manager);
             app.manage(asset_manager);
-            app.manage(AppState {
+            app.manage(FileWatcher::new());
+            app.manage(Arc::new(DatabaseEditor::new()));
+            let app_state = AppState {
                 subscribed_servers: Arc::new(TokioMutex::new(HashSet::new())),
+                events,
+            };
+            app.manage(app_state.clone());
+            setup::spawn_tray_refresh(app.handle().clone());
+            setup::spawn_notification_forwarder(app.handle().clone());
+            setup::spawn_wake_forwarder(app.handle().clone());
+            setup::spawn_task_forwarder(app.handle().clone());
+            setup::spawn_file_change_forwarder(app.handle().clone());
+
+            // Reattach to servers that kept running while the app was closed
+            let sm_adopt = Arc::clone(app.state::<Arc<ServerManager>>().inner());
+            let app_handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                let adopted = match sm_adopt.adopt_running_servers().await {
+                    Ok(ids) => ids,
+                    Err(e) => {
+                        log::error!("Failed to adopt running servers: {}", e);
+                        return;
+                    }
+                };
+                for id in adopted {
+                    if let Some(server) = sm_adopt.get_server(id).await {
+                        let _ = commands::server::ensure_server_logs_forwarded(
+                            &app_state,
+                            server,
+                            app_handle.clone(),
+                            id.to_string(),
+                        )
+                        .await;
+                    }
+                }
+
+                // Autostart runs after adoption so reattached servers aren't started twice
+                let autostart = match sm_adopt.autostart_instance_ids().await {
+                    Ok(ids) => ids,
+                    Err(e) => {
+                        log::error!("Failed to list autostart instances: {}", e);
+                        return;
+                    }
+                };
+                if autostart.is_empty() {
+                    return;
+                }
+                let _ = commands::server::forward_logs_for_start(
+                    &sm_adopt,
+                    &app_state,
+                    &app_handle,
+                    &autostart,
+                )
+                .await;
+                if let Err(e) = sm_adopt.autostart_servers().await {
+                    log::error!("Autostart failed: {}", e);
+                }
             });
 
             Ok(())
@@ -177,7 +286,9 @@ pub fn run() -> anyhow::Result<()> {
                         let _ = window.minimize();
                     }
                     CloseBehavior::Exit => {
-                        // Let the window close
+                        // Running servers are handled before the app actually exits
+                        api.prevent_close();
+                        shutdown::request_quit(app_handle);
                     }
                 }
             }
@@ -185,27 +296,62 @@ pub fn run() -> anyhow::Result<()> {
         .invoke_handler(tauri::generate_handler![
             commands::config::get_app_settings,
             commands::config::update_app_settings,
+            commands::config::get_credential_status,
+            commands::config::set_credential,
+            commands::config::test_credential,
+            commands::config::test_connectivity,
+            commands::config::get_bmclapi_mirrors,
+            commands::config::list_app_logs,
+            commands::config::get_app_logs,
+            commands::config::export_diagnostics,
             commands::files::read_text_file,
             commands::files::save_text_file,
             commands::files::open_file_in_editor,
+            commands::files::list_instance_dir,
+            commands::files::create_instance_dir,
+            commands::files::rename_instance_file,
+            commands::files::copy_instance_file,
+            commands::files::delete_instance_file,
+            commands::files::download_instance_file,
+            commands::files::export_instance_file,
+            commands::files::read_file_chunk,
+            commands::files::read_text_chunk,
+            commands::files::write_binary_file,
+            commands::files::watch_instance_files,
+            commands::files::unwatch_instance_files,
             commands::instance::list_instances,
             commands::instance::create_instance,
             commands::instance::check_instance_name_exists,
             commands::instance::preview_script_import,
+            commands::instance::preflight_import,
             commands::instance::import_instance,
             commands::instance::list_archive_contents,
             commands::instance::detect_server_type,
             commands::instance::list_jars_in_source,
             commands::instance::list_scripts_in_source,
             commands::instance::check_server_properties_exists,
+            commands::instance::fetch_remote_host_key,
+            commands::instance::list_remote_directory,
+            commands::instance::import_remote_instance,
             commands::instance::delete_instance,
             commands::instance::delete_instance_by_name,
+            commands::instance::delete_instances,
             commands::instance::clone_instance,
+            commands::instance::export_instance,
+            commands::instance::import_exported_instance,
             commands::database::explore_find_databases,
             commands::database::explore_list_tables,
             commands::database::explore_get_data,
             commands::database::explore_read_sql_file,
             commands::database::explore_get_schema,
+            commands::database::explore_update_row,
+            commands::database::explore_delete_row,
+            commands::database::explore_execute,
+            commands::database::explore_execute_query,
+            commands::database::explore_explain_query,
+            commands::database::explore_stream_query,
+            commands::database::explore_get_query_history,
+            commands::database::explore_clear_query_history,
             commands::instance::open_instance_folder,
             commands::instance::get_minecraft_versions,
             commands::instance::get_bedrock_versions,
@@ -213,32 +359,105 @@ pub fn run() -> anyhow::Result<()> {
             commands::instance::get_velocity_builds,
             commands::instance::get_bungeecord_versions,
             commands::instance::get_mod_loaders,
+            commands::instance::get_paper_builds,
             commands::instance::create_instance_full,
             commands::instance::create_instance_from_modpack,
             commands::instance::update_instance_settings,
             commands::instance::update_instance_jar,
             commands::instance::get_startup_preview,
             commands::instance::list_bat_files,
+            commands::instance::check_jvm_options,
+            commands::instance::check_server_updates,
+            commands::instance::apply_server_update,
+            commands::instance::list_worlds,
+            commands::instance::get_version_compatibility,
+            commands::instance::get_connection_info,
+            commands::instance::ping_instance,
+            commands::instance::ping_address,
+            commands::instance::get_proxy_compatibility,
+            commands::instance::get_motd,
+            commands::instance::set_motd,
+            commands::instance::convert_motd,
+            commands::instance::preview_motd,
+            commands::instance::set_server_icon,
+            commands::instance::remove_server_icon,
+            commands::instance::switch_world,
+            commands::instance::duplicate_world,
+            commands::instance::start_pregeneration,
+            commands::profiler::start_profiling,
+            commands::profiler::stop_profiling,
+            commands::profiler::list_profiler_sessions,
+            commands::profiler::delete_profiler_session,
+            commands::instance::start_world_upgrade,
+            commands::instance::get_world_upgrade_status,
+            commands::instance::get_world_summary,
+            commands::instance::find_slime_chunks,
+            commands::instance::convert_coordinates,
             commands::server::start_server,
             commands::server::stop_server,
+            commands::server::stop_server_graceful,
             commands::server::kill_server,
+            commands::server::restart_server,
+            commands::server::prepare_server,
+            commands::server::start_servers,
+            commands::server::stop_all_servers,
             commands::server::get_server_status,
             commands::server::get_server_usage,
+            commands::server::get_metrics_history,
+            commands::server::export_metrics_csv,
+            commands::server::get_provisioning_state,
+            commands::server::repair_installation,
+            commands::server::diagnose_instance,
             commands::server::send_command,
+            commands::server::send_chat,
+            commands::server::get_recent_chat,
             commands::server::read_latest_log,
+            commands::server::get_console_buffer,
+            commands::server::list_log_files,
+            commands::server::read_log_file,
+            commands::server::fetch_recent_events,
+            commands::tasks::list_tasks,
+            commands::tasks::cancel_task,
+            commands::tasks::pause_task,
+            commands::tasks::resume_task,
+            commands::crash::list_crash_reports,
+            commands::crash::get_crash_report,
+            commands::timeline::get_instance_timeline,
+            commands::notifications::test_webhook,
+            commands::notifications::test_heartbeat,
+            commands::notifications::list_notification_rules,
+            commands::notifications::save_notification_rule,
+            commands::notifications::delete_notification_rule,
+            commands::console::get_command_history,
+            commands::console::get_command_suggestions,
+            commands::console::get_server_commands,
+            commands::console::run_shell_command,
             commands::players::open_player_list_file,
             commands::players::get_players,
             commands::players::get_online_players,
             commands::players::add_player,
+            commands::players::get_player_access_warnings,
+            commands::players::fix_player_access,
             commands::players::add_banned_ip,
             commands::players::remove_player,
             commands::config::get_server_properties,
             commands::config::save_server_properties,
             commands::config::get_available_configs,
+            commands::config::get_config_groups,
+            commands::config::get_group_configs,
+            commands::config::get_config_tree,
             commands::config::get_config_file,
             commands::config::save_config_file,
+            commands::config::get_related_instances,
+            commands::config::compare_instance_configs,
+            commands::config::sync_config_keys,
             commands::config::get_config_value,
             commands::config::save_config_value,
+            commands::config_search::search_instance_configs,
+            commands::config_search::replace_in_instance_configs,
+            commands::config_presets::list_config_presets,
+            commands::config_presets::preview_config_preset,
+            commands::config_presets::apply_config_preset,
             commands::backups::list_backups,
             commands::backups::create_backup,
             commands::backups::delete_backup,
@@ -247,6 +466,8 @@ pub fn run() -> anyhow::Result<()> {
             commands::scheduler::add_scheduled_task,
             commands::scheduler::remove_scheduled_task,
             commands::scheduler::list_scheduled_tasks,
+            commands::automation::export_automation_config,
+            commands::automation::import_automation_config,
             commands::java::get_managed_java_versions,
             commands::java::download_java_version,
             commands::java::delete_java_version,
@@ -259,9 +480,15 @@ pub fn run() -> anyhow::Result<()> {
             commands::plugins::search_plugins,
             commands::plugins::install_plugin,
             commands::plugins::update_plugin,
+            commands::plugins::list_plugin_versions_installed,
+            commands::plugins::rollback_plugin,
             commands::plugins::check_for_plugin_updates,
             commands::plugins::list_plugin_configs,
             commands::plugins::get_plugin_dependencies,
+            commands::plugins::get_via_plan,
+            commands::plugins::setup_via,
+            commands::plugins::get_crossplay_plan,
+            commands::plugins::setup_crossplay,
             commands::mods::list_installed_mods,
             commands::mods::toggle_mod,
             commands::mods::bulk_toggle_mods,
@@ -274,11 +501,32 @@ pub fn run() -> anyhow::Result<()> {
             commands::mods::get_mod_configs,
             commands::mods::list_mod_config_files,
             commands::mods::check_for_mod_updates,
+            commands::mods::get_content_report,
             commands::mods::update_mod,
+            commands::mods::list_mod_versions_installed,
+            commands::mods::rollback_mod,
+            commands::loadouts::list_loadouts,
+            commands::loadouts::save_loadout,
+            commands::loadouts::delete_loadout,
+            commands::loadouts::apply_loadout,
+            commands::loadouts::revert_loadout,
+            commands::templates::list_templates,
+            commands::templates::save_instance_as_template,
+            commands::templates::delete_template,
+            commands::templates::create_instance_from_template,
             commands::assets::cache_asset,
             commands::assets::get_player_head_path,
             commands::assets::get_asset_cache_stats,
             commands::assets::cleanup_assets,
+            commands::storage::deduplicate_libraries,
+            commands::storage::get_cache_stats,
+            commands::storage::clear_cache,
+            commands::storage::get_offline_servers,
+            commands::storage::get_storage_roots,
+            commands::storage::set_storage_roots,
+            commands::storage::move_instance,
+            commands::storage::get_storage_mode,
+            commands::storage::set_storage_mode,
         ])
         .run(tauri::generate_context!())
         .context("error while running tauri application")?;
@@ -0,0 +1,25 @@
+use mc_server_wrapper_core::watcher::{FileChangeKind, FileWatcher};
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::sync::broadcast::error::RecvError;
+
+/// Tells the UI about files changed outside the app so it can reload what it shows.
+pub fn spawn_file_change_forwarder(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let watcher = Arc::clone(app.state::<Arc<FileWatcher>>().inner());
+        let mut rx = watcher.subscribe();
+        loop {
+            let change = match rx.recv().await {
+                Ok(change) => change,
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => break,
+            };
+            let event = match change.kind {
+                FileChangeKind::Config => "config-changed",
+                FileChangeKind::Mods => "mods-changed",
+                FileChangeKind::Plugins => "plugins-changed",
+            };
+            let _ = app.emit(event, &change);
+        }
+    });
+}
@@ -1,30 +1,58 @@
 use tauri::App;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use anyhow::Result;
+use mc_server_wrapper_core::app_config::{LogLevel, LogSettings};
 
-pub fn setup_logging(app: &mut App, exe_path: &Path) -> Result<()> {
-    let log_level = if cfg!(debug_assertions) {
-        log::LevelFilter::Debug
+/// Libraries that flood the log at debug and trace.
+const NOISY_TARGETS: [&str; 8] = ["hyper", "hyper_util", "reqwest", "h2", "rustls", "sqlx", "tao", "wry"];
+
+/// Folder holding the wrapper's own log files.
+pub fn app_log_dir(data_dir: &Path) -> PathBuf {
+    data_dir.join("resources").join("logs")
+}
+
+fn level_filter(level: LogLevel) -> log::LevelFilter {
+    match level {
+        LogLevel::Error => log::LevelFilter::Error,
+        LogLevel::Warn => log::LevelFilter::Warn,
+        LogLevel::Info => log::LevelFilter::Info,
+        LogLevel::Debug => log::LevelFilter::Debug,
+        LogLevel::Trace => log::LevelFilter::Trace,
+    }
+}
+
+/// Changes the log verbosity without a restart.
+pub fn apply_log_level(level: LogLevel) {
+    log::set_max_level(level_filter(level));
+}
+
+pub fn setup_logging(app: &mut App, data_dir: &Path, settings: &LogSettings) -> Result<()> {
+    let level = if cfg!(debug_assertions) {
+        settings.level.max(LogLevel::Debug)
     } else {
-        log::LevelFilter::Info
+        settings.level
     };
 
+    // Everything passes the plugin, so the global max level alone decides what is
+    // written and can be changed while running
+    let builder = NOISY_TARGETS.into_iter().fold(
+        tauri_plugin_log::Builder::default().level(log::LevelFilter::Trace),
+        |builder, target| builder.level_for(target, log::LevelFilter::Info),
+    );
     app.handle().plugin(
-        tauri_plugin_log::Builder::default()
-            .level(log_level)
-            .rotation_strategy(tauri_plugin_log::RotationStrategy::KeepAll)
+        builder
+            .max_file_size(settings.max_file_size_bytes() as u128)
+            .rotation_strategy(tauri_plugin_log::RotationStrategy::KeepSome(settings.max_files.max(1)))
             .targets([
                 tauri_plugin_log::Target::new(tauri_plugin_log::TargetKind::Stdout),
-                tauri_plugin_log::Target::new(tauri_plugin_log::TargetKind::LogDir {
-                    file_name: Some("app".to_string()),
-                }),
                 tauri_plugin_log::Target::new(tauri_plugin_log::TargetKind::Folder {
-                    path: exe_path.join("logs"),
+                    path: app_log_dir(data_dir),
                     file_name: Some("app".to_string()),
                 }),
             ])
             .build(),
     )?;
+    apply_log_level(level);
 
     Ok(())
 }
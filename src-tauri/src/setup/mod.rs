@@ -2,8 +2,18 @@ pub mod window;
 pub mod tray;
 pub mod logging;
 pub mod clutter;
+pub mod storage;
+pub mod notifications;
+pub mod wake;
+pub mod tasks;
+pub mod file_changes;
 
 pub use window::setup_window;
-pub use tray::setup_tray;
-pub use logging::setup_logging;
+pub use tray::{setup_tray, spawn_tray_refresh};
+pub use logging::{app_log_dir, apply_log_level, setup_logging};
 pub use clutter::check_clutter;
+pub use storage::{AppStorage, resolve_storage};
+pub use notifications::spawn_notification_forwarder;
+pub use wake::spawn_wake_forwarder;
+pub use tasks::spawn_task_forwarder;
+pub use file_changes::spawn_file_change_forwarder;
@@ -0,0 +1,36 @@
+use mc_server_wrapper_core::manager::ServerManager;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_notification::NotificationExt;
+use tokio::sync::broadcast::error::RecvError;
+
+/// Frontend event carrying every fired notification rule, for in-app history.
+pub const NOTIFICATION_EVENT: &str = "desktop-notification";
+
+/// Shows a native notification for each rule fired by the core, and forwards it to the UI.
+pub fn spawn_notification_forwarder(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let server_manager = Arc::clone(app.state::<Arc<ServerManager>>().inner());
+        let mut rx = server_manager.subscribe_notifications();
+        loop {
+            let notification = match rx.recv().await {
+                Ok(notification) => notification,
+                Err(RecvError::Lagged(skipped)) => {
+                    log::warn!("Dropped {} desktop notifications", skipped);
+                    continue;
+                }
+                Err(RecvError::Closed) => break,
+            };
+            if let Err(e) = app
+                .notification()
+                .builder()
+                .title(&notification.title)
+                .body(&notification.body)
+                .show()
+            {
+                log::error!("Failed to show notification: {}", e);
+            }
+            let _ = app.emit(NOTIFICATION_EVENT, &notification);
+        }
+    });
+}
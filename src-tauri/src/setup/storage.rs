@@ -0,0 +1,73 @@
+use anyhow::{Context, Result};
+use mc_server_wrapper_core::storage_mode::{DataLocations, StorageMode};
+use std::path::{Path, PathBuf};
+use tauri::{App, Manager};
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
+
+/// Where this session keeps its data.
+#[derive(Debug, Clone)]
+pub struct AppStorage {
+    pub locations: DataLocations,
+    pub mode: StorageMode,
+    /// The previous data folder, when this start moved the data out of it.
+    pub migrated_from: Option<PathBuf>,
+}
+
+impl AppStorage {
+    pub fn data_dir(&self) -> &Path {
+        self.locations.root(self.mode)
+    }
+}
+
+/// Picks the data folder: the mode that already holds data, or on the first run the
+/// user's choice. A mode switch requested in the settings is carried out here, before
+/// any file in the data folder is opened.
+pub fn resolve_storage(app: &mut App, exe_dir: &Path) -> Result<AppStorage> {
+    let app_data_dir = app.path().app_data_dir().context("failed to get app data directory")?;
+    let locations = DataLocations::new(exe_dir, app_data_dir);
+
+    let mode = match locations.detect() {
+        Some(mode) => mode,
+        // Installed somewhere read-only, e.g. Program Files
+        None if !locations.portable_available() => StorageMode::Installed,
+        None => {
+            let portable = app
+                .dialog()
+                .message(format!(
+                    "Where should servers, backups and settings be kept?\n\nPortable: next to the app, in {}\nUser folder: in {}\n\nYou can change this later in Settings > Storage.",
+                    locations.portable.display(),
+                    locations.installed.display()
+                ))
+                .kind(MessageDialogKind::Info)
+                .title("Choose a Data Folder")
+                .buttons(MessageDialogButtons::OkCancelCustom(
+                    "Portable".to_string(),
+                    "User folder".to_string(),
+                ))
+                .blocking_show();
+            if portable {
+                StorageMode::Portable
+            } else {
+                StorageMode::Installed
+            }
+        }
+    };
+
+    let migration = tauri::async_runtime::block_on(locations.run_pending_migration(mode));
+    match migration {
+        Ok(Some(target)) => Ok(AppStorage {
+            migrated_from: Some(locations.root(mode).to_path_buf()),
+            locations,
+            mode: target,
+        }),
+        Ok(None) => Ok(AppStorage { locations, mode, migrated_from: None }),
+        Err(e) => {
+            app.dialog()
+                .message(format!("The data folder couldn't be moved and stays where it was.\n\n{:#}", e))
+                .kind(MessageDialogKind::Error)
+                .title("Storage Mode Not Changed")
+                .blocking_show();
+            Ok(AppStorage { locations, mode, migrated_from: None })
+        }
+    }
+}
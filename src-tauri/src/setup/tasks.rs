@@ -0,0 +1,28 @@
+use crate::commands::AppState;
+use mc_server_wrapper_core::events::EventChannel;
+use mc_server_wrapper_core::tasks::TaskManager;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::sync::broadcast::error::RecvError;
+
+/// Frontend event carrying every change to a long-running task.
+pub const TASK_PROGRESS_EVENT: &str = "task-progress";
+
+/// Forwards task registrations, progress and outcomes from the core to the UI, recording
+/// them for replay after a reload.
+pub fn spawn_task_forwarder(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let task_manager = Arc::clone(app.state::<Arc<TaskManager>>().inner());
+        let events = Arc::clone(&app.state::<AppState>().events);
+        let mut rx = task_manager.subscribe();
+        loop {
+            let task = match rx.recv().await {
+                Ok(task) => task,
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => break,
+            };
+            events.publish(task.instance_id, EventChannel::Task, &task);
+            let _ = app.emit(TASK_PROGRESS_EVENT, &task);
+        }
+    });
+}
@@ -1,34 +1,41 @@
-use tauri::{App, Manager, menu::{Menu, MenuItem}, tray::{TrayIconBuilder, TrayIconEvent, MouseButton}};
+use tauri::{App, AppHandle, Manager, Wry, menu::{Menu, MenuItem, PredefinedMenuItem, Submenu}, tray::{TrayIconBuilder, TrayIconEvent, MouseButton}};
 use anyhow::Result;
+use mc_server_wrapper_core::manager::{InstanceSummary, ServerManager};
+use mc_server_wrapper_core::server::ServerStatus;
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+use crate::commands::AppState;
+
+const TRAY_ID: &str = "main";
+const REFRESH_INTERVAL: Duration = Duration::from_secs(3);
 
 pub fn setup_tray(app: &mut App) -> Result<()> {
-    let quit_i = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
-    let show_i = MenuItem::with_id(app, "show", "Show", true, None::<&str>)?;
-    let menu = Menu::with_items(app, &[&show_i, &quit_i])?;
+    let menu = build_menu(app.handle(), &[])?;
 
-    let mut tray_builder = TrayIconBuilder::new()
+    let mut tray_builder = TrayIconBuilder::with_id(TRAY_ID)
         .menu(&menu)
+        .tooltip("MC Server Wrapper")
         .on_menu_event(|app, event| {
             match event.id.as_ref() {
                 "quit" => {
-                    app.exit(0);
+                    crate::shutdown::request_quit(app);
                 }
-                "show" => {
-                    if let Some(window) = app.get_webview_window("main") {
-                        let _ = window.show();
-                        let _ = window.set_focus();
+                "show" => show_main_window(app),
+                id => {
+                    // Instance items are identified as "instance:<action>:<uuid>"
+                    let mut parts = id.splitn(3, ':');
+                    if let (Some("instance"), Some(action), Some(Ok(instance_id))) =
+                        (parts.next(), parts.next(), parts.next().map(Uuid::parse_str))
+                    {
+                        run_instance_action(app, action.to_string(), instance_id);
                     }
                 }
-                _ => {}
             }
         })
         .on_tray_icon_event(|tray, event| {
             if let TrayIconEvent::Click { button: MouseButton::Left, .. } = event {
-                let app = tray.app_handle();
-                if let Some(window) = app.get_webview_window("main") {
-                    let _ = window.show();
-                    let _ = window.set_focus();
-                }
+                show_main_window(tray.app_handle());
             }
         });
 
@@ -39,3 +46,141 @@ pub fn setup_tray(app: &mut App) -> Result<()> {
     let _tray = tray_builder.build(app)?;
     Ok(())
 }
+
+/// Keeps the tray menu in sync with instance status and player counts.
+///
+/// Must be called once the `ServerManager` is managed by the app.
+pub fn spawn_tray_refresh(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let server_manager = Arc::clone(app.state::<Arc<ServerManager>>().inner());
+        let mut last: Option<Vec<InstanceSummary>> = None;
+        loop {
+            match server_manager.get_instance_summaries().await {
+                Ok(summaries) if last.as_ref() != Some(&summaries) => {
+                    if let Err(e) = update_tray(&app, &summaries) {
+                        log::error!("Failed to update tray menu: {}", e);
+                    }
+                    last = Some(summaries);
+                }
+                Ok(_) => {}
+                Err(e) => log::error!("Failed to summarize instances for tray: {}", e),
+            }
+            tokio::time::sleep(REFRESH_INTERVAL).await;
+        }
+    });
+}
+
+fn update_tray(app: &AppHandle, summaries: &[InstanceSummary]) -> Result<()> {
+    let Some(tray) = app.tray_by_id(TRAY_ID) else {
+        return Ok(());
+    };
+    tray.set_menu(Some(build_menu(app, summaries)?))?;
+
+    let running = summaries.iter().filter(|s| s.status == ServerStatus::Running).count();
+    let players: usize = summaries.iter().map(|s| s.online_players).sum();
+    let tooltip = if running == 0 {
+        "MC Server Wrapper".to_string()
+    } else {
+        format!("MC Server Wrapper - {} running, {} online", running, players)
+    };
+    tray.set_tooltip(Some(tooltip))?;
+    Ok(())
+}
+
+fn build_menu(app: &AppHandle, summaries: &[InstanceSummary]) -> tauri::Result<Menu<Wry>> {
+    let menu = Menu::new(app)?;
+    menu.append(&MenuItem::with_id(app, "show", "Show", true, None::<&str>)?)?;
+
+    if !summaries.is_empty() {
+        menu.append(&PredefinedMenuItem::separator(app)?)?;
+        for summary in summaries {
+            menu.append(&instance_submenu(app, summary)?)?;
+        }
+    }
+
+    menu.append(&PredefinedMenuItem::separator(app)?)?;
+    menu.append(&MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?)?;
+    Ok(menu)
+}
+
+fn instance_submenu(app: &AppHandle, summary: &InstanceSummary) -> tauri::Result<Submenu<Wry>> {
+    let icon = match summary.status {
+        ServerStatus::Running => "🟢",
+        ServerStatus::Starting | ServerStatus::Installing | ServerStatus::Stopping => "🟡",
+        ServerStatus::Crashed => "🔴",
+        ServerStatus::Stopped => "⚪",
+    };
+    let players = match (summary.status, summary.max_players) {
+        (ServerStatus::Running, Some(max)) => format!(" ({}/{})", summary.online_players, max),
+        (ServerStatus::Running, None) => format!(" ({} online)", summary.online_players),
+        _ => String::new(),
+    };
+    let title = format!("{} {} - {}{}", icon, summary.name, summary.status, players);
+
+    let stopped = matches!(summary.status, ServerStatus::Stopped | ServerStatus::Crashed);
+    let running = summary.status == ServerStatus::Running;
+    let item = |action: &str, label: &str, enabled: bool| {
+        MenuItem::with_id(
+            app,
+            format!("instance:{}:{}", action, summary.id),
+            label,
+            enabled,
+            None::<&str>,
+        )
+    };
+
+    Submenu::with_items(
+        app,
+        title,
+        true,
+        &[
+            &item("start", "Start", stopped)?,
+            &item("stop", "Stop", running || summary.status == ServerStatus::Starting)?,
+            &item("restart", "Restart", running)?,
+        ],
+    )
+}
+
+fn run_instance_action(app: &AppHandle, action: String, instance_id: Uuid) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let server_manager = Arc::clone(app.state::<Arc<ServerManager>>().inner());
+
+        if action == "start" || action == "restart" {
+            // Forward logs so the console shows output if the window is opened later
+            match server_manager.get_or_create_server(instance_id).await {
+                Ok(server) => {
+                    let app_state = app.state::<AppState>();
+                    let _ = crate::commands::server::ensure_server_logs_forwarded(
+                        &app_state,
+                        server,
+                        app.clone(),
+                        instance_id.to_string(),
+                    )
+                    .await;
+                }
+                Err(e) => {
+                    log::error!("Tray {} failed for {}: {}", action, instance_id, e);
+                    return;
+                }
+            }
+        }
+
+        let result = match action.as_str() {
+            "start" => server_manager.start_server(instance_id).await,
+            "stop" => server_manager.stop_server(instance_id).await,
+            "restart" => server_manager.restart_server(instance_id).await,
+            _ => Ok(()),
+        };
+        if let Err(e) = result {
+            log::error!("Tray {} failed for {}: {}", action, instance_id, e);
+        }
+    });
+}
+
+fn show_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
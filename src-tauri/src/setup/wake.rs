@@ -0,0 +1,33 @@
+use crate::commands::AppState;
+use mc_server_wrapper_core::manager::ServerManager;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::sync::broadcast::error::RecvError;
+
+/// Starts the wake-on-connect listeners and hooks servers they wake up to the console,
+/// the same way a start from the UI would.
+pub fn spawn_wake_forwarder(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let server_manager = Arc::clone(app.state::<Arc<ServerManager>>().inner());
+        let mut rx = server_manager.subscribe_wakes();
+        server_manager.spawn_wake_supervisor();
+        loop {
+            let instance_id = match rx.recv().await {
+                Ok(id) => id,
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => break,
+            };
+            if let Some(server) = server_manager.get_server(instance_id).await {
+                let app_state = app.state::<AppState>();
+                let _ = crate::commands::server::ensure_server_logs_forwarded(
+                    &app_state,
+                    server,
+                    app.clone(),
+                    instance_id.to_string(),
+                )
+                .await;
+            }
+            let _ = app.emit("server-woken", instance_id.to_string());
+        }
+    });
+}
@@ -0,0 +1,76 @@
+use mc_server_wrapper_core::app_config::{ExitServerPolicy, GlobalConfigManager};
+use mc_server_wrapper_core::manager::ServerManager;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
+use uuid::Uuid;
+
+/// Quits the app, first handling running servers according to the exit policy
+/// so closing the wrapper never leaves JVMs running without the user knowing.
+pub fn request_quit(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let server_manager = Arc::clone(app.state::<Arc<ServerManager>>().inner());
+        // Instances marked to keep running are left alone and reattached on next launch
+        let running = server_manager
+            .servers_to_stop_on_exit()
+            .await
+            .unwrap_or_else(|e| {
+                log::error!("Failed to determine servers to stop on exit: {}", e);
+                Vec::new()
+            });
+        if running.is_empty() {
+            app.exit(0);
+            return;
+        }
+
+        let config_manager = app.state::<Arc<GlobalConfigManager>>();
+        let policy = match config_manager.load().await {
+            Ok(settings) => settings.exit_server_policy,
+            Err(e) => {
+                log::error!("Failed to load app settings on quit: {}", e);
+                ExitServerPolicy::default()
+            }
+        };
+
+        match policy {
+            ExitServerPolicy::StopAll => stop_and_exit(app, server_manager, running).await,
+            ExitServerPolicy::KeepRunning => {
+                log::info!("Exiting while {} server(s) keep running", running.len());
+                app.exit(0);
+            }
+            ExitServerPolicy::Ask => {
+                let app_clone = app.clone();
+                app.dialog()
+                    .message(format!(
+                        "{} server(s) are still running. Stop them before quitting?",
+                        running.len()
+                    ))
+                    .kind(MessageDialogKind::Warning)
+                    .title("Servers Still Running")
+                    .buttons(MessageDialogButtons::OkCancelCustom(
+                        "Stop servers".to_string(),
+                        "Keep running".to_string(),
+                    ))
+                    .show(move |stop| {
+                        tauri::async_runtime::spawn(async move {
+                            if stop {
+                                stop_and_exit(app_clone, server_manager, running).await;
+                            } else {
+                                app_clone.exit(0);
+                            }
+                        });
+                    });
+            }
+        }
+    });
+}
+
+async fn stop_and_exit(app: AppHandle, server_manager: Arc<ServerManager>, ids: Vec<Uuid>) {
+    log::info!("Stopping {} running server(s) before exit...", ids.len());
+    if let Err(e) = server_manager.stop_servers(&ids, false).await {
+        log::error!("Graceful shutdown failed, killing remaining servers: {}", e);
+        let _ = server_manager.stop_servers(&ids, true).await;
+    }
+    app.exit(0);
+}
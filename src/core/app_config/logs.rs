@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+/// Verbosity of the wrapper's own log.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Error,
+    Warn,
+    #[default]
+    Info,
+    Debug,
+    Trace,
+}
+
+/// The wrapper's own log files in `resources/logs`. The level applies right away; file
+/// size and count on the next start.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct LogSettings {
+    pub level: LogLevel,
+    /// The current file is rotated once it reaches this size.
+    pub max_file_size_mb: u64,
+    /// Rotated files kept before the oldest is deleted.
+    pub max_files: usize,
+}
+
+impl LogSettings {
+    pub fn max_file_size_bytes(&self) -> u64 {
+        self.max_file_size_mb.max(1) * 1024 * 1024
+    }
+}
+
+impl Default for LogSettings {
+    fn default() -> Self {
+        Self {
+            level: LogLevel::Info,
+            max_file_size_mb: 10,
+            max_files: 5,
+        }
+    }
+}
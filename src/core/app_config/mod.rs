@@ -0,0 +1,302 @@
+mod logs;
+
+pub use logs::{LogLevel, LogSettings};
+
+use crate::artifacts::ProvisionMode;
+use crate::credentials::{CredentialStore, KeyStore};
+use crate::server_properties::ServerPropertiesDefaults;
+use crate::storage_mode::rebase_path;
+use crate::utils::{DownloadLimits, DownloadSettings, write_atomic};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ManagedJavaVersion {
+    pub id: String,
+    pub name: String,
+    pub path: PathBuf,
+    pub version: String,
+    pub major_version: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AppSettings {
+    // Interface
+    pub display_ipv6: bool,
+    pub hide_ip_address: bool,
+    pub use_white_console_text: bool,
+
+    // Navigation
+    pub start_page: String, // "Dashboard", "Global Dashboard", etc.
+
+    // Player List
+    pub download_player_heads: bool,
+    pub use_helm_heads: bool,
+    pub query_heads_by_uuid: bool,
+
+    // Server Tabs
+    pub display_server_icon: bool,
+    pub display_online_player_count: bool,
+    pub display_server_version: bool,
+    pub display_server_status: bool,
+    pub display_navigational_buttons: bool,
+
+    // Close Preference
+    pub close_behavior: CloseBehavior,
+    pub show_tray_notification: bool,
+    #[serde(default)]
+    pub exit_server_policy: ExitServerPolicy,
+
+    // Appearance (Existing)
+    pub accent_color: String,
+    pub theme: String,
+    pub scaling: f32,
+
+    // Java Management
+    #[serde(default)]
+    pub managed_java_versions: Vec<ManagedJavaVersion>,
+
+    // New Instances
+    #[serde(default)]
+    pub server_properties_defaults: ServerPropertiesDefaults,
+
+    // Config Editing
+    /// Keep the previous version of a config file as `<name>.bak` when saving it.
+    #[serde(default = "default_backup_configs_on_save")]
+    pub backup_configs_on_save: bool,
+
+    // Downloads
+    #[serde(default)]
+    pub downloads: DownloadSettings,
+
+    // Resource Limits
+    #[serde(default)]
+    pub start_limits: StartLimits,
+
+    // Servers not managed by the wrapper, pinged for the overview
+    #[serde(default)]
+    pub external_servers: Vec<ExternalServer>,
+
+    // Instance Terminal
+    #[serde(default)]
+    pub shell: ShellSettings,
+
+    // Cache
+    #[serde(default)]
+    pub cache_limits: CacheLimits,
+
+    // Shared Storage
+    /// How library jars are shared between instances through the artifact store.
+    #[serde(default)]
+    pub library_sharing: ProvisionMode,
+
+    // Offline Mode
+    /// Install servers only from the artifact store, without asking any remote API.
+    #[serde(default)]
+    pub offline_mode: bool,
+
+    // Storage Locations
+    /// Extra folders, e.g. on another disk, that instances can be moved to.
+    #[serde(default)]
+    pub storage_roots: Vec<PathBuf>,
+
+    // Logging
+    #[serde(default)]
+    pub logging: LogSettings,
+}
+
+fn default_backup_configs_on_save() -> bool {
+    true
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum CloseBehavior {
+    HideToSystemTray,
+    HideToTaskbar,
+    Exit,
+}
+
+/// Caps on what may run at once, so starting one more server can't push the host
+/// into swapping or the OOM killer. 0 disables a limit.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(default)]
+pub struct StartLimits {
+    pub max_running_instances: u32,
+    /// Sum of the running servers' max heap (-Xmx), in MB.
+    pub ram_budget_mb: u64,
+}
+
+/// Size cap for cached API responses (manifests, search results, project details) on
+/// disk. Least recently used entries go first once it's reached.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct CacheLimits {
+    /// 0 for no cap.
+    pub max_disk_mb: u64,
+}
+
+impl CacheLimits {
+    pub fn max_disk_bytes(&self) -> u64 {
+        self.max_disk_mb * 1024 * 1024
+    }
+}
+
+impl Default for CacheLimits {
+    fn default() -> Self {
+        Self { max_disk_mb: 256 }
+    }
+}
+
+/// The per-instance terminal. Off by default since it runs anything the user types
+/// with the wrapper's permissions.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct ShellSettings {
+    pub enabled: bool,
+    /// Commands still running after this long are killed.
+    pub timeout_secs: u64,
+}
+
+impl Default for ShellSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            timeout_secs: 600,
+        }
+    }
+}
+
+/// A server elsewhere (a friend's, a network's lobby) shown next to the instances.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ExternalServer {
+    pub id: Uuid,
+    pub name: String,
+    /// `host` or `host:port`.
+    pub address: String,
+}
+
+/// What happens to running servers when the wrapper quits.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+pub enum ExitServerPolicy {
+    #[default]
+    StopAll,
+    KeepRunning,
+    Ask,
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            display_ipv6: false,
+            hide_ip_address: false,
+            use_white_console_text: false,
+            start_page: "Dashboard".to_string(),
+            download_player_heads: true,
+            use_helm_heads: true,
+            query_heads_by_uuid: false,
+            display_server_icon: true,
+            display_online_player_count: true,
+            display_server_version: true,
+            display_server_status: true,
+            display_navigational_buttons: true,
+            close_behavior: CloseBehavior::HideToSystemTray,
+            show_tray_notification: true,
+            exit_server_policy: ExitServerPolicy::StopAll,
+            accent_color: "Blue".to_string(),
+            theme: "dark".to_string(),
+            scaling: 1.0,
+            managed_java_versions: vec![],
+            server_properties_defaults: ServerPropertiesDefaults::default(),
+            backup_configs_on_save: true,
+            downloads: DownloadSettings::default(),
+            start_limits: StartLimits::default(),
+            external_servers: vec![],
+            shell: ShellSettings::default(),
+            cache_limits: CacheLimits::default(),
+            library_sharing: ProvisionMode::Copy,
+            offline_mode: false,
+            storage_roots: vec![],
+            logging: LogSettings::default(),
+        }
+    }
+}
+
+pub struct GlobalConfigManager {
+    pub(crate) config_path: PathBuf,
+    pub(crate) credentials: CredentialStore,
+    pub(crate) key_store: KeyStore,
+    download_limits: DownloadLimits,
+}
+
+impl GlobalConfigManager {
+    pub fn new(config_path: PathBuf) -> Self {
+        Self {
+            config_path,
+            credentials: CredentialStore::default(),
+            key_store: KeyStore::default(),
+            download_limits: DownloadLimits::default(),
+        }
+    }
+
+    /// Keeps the credentials key in `key_store` instead of the OS keychain.
+    pub fn with_key_store(mut self, key_store: KeyStore) -> Self {
+        self.key_store = key_store;
+        self
+    }
+
+    pub async fn load(&self) -> Result<AppSettings> {
+        if !self.config_path.exists() {
+            let default_config = AppSettings::default();
+            self.save(&default_config).await?;
+            return Ok(default_config);
+        }
+
+        let content = fs::read_to_string(&self.config_path)
+            .await
+            .context("Failed to read app settings file")?;
+        let config: AppSettings =
+            serde_json::from_str(&content).context("Failed to parse app settings JSON")?;
+        self.download_limits.configure(&config.downloads);
+        Ok(config)
+    }
+
+    /// The download limits from the current settings, for handing to anything that downloads.
+    pub fn download_limits(&self) -> DownloadLimits {
+        self.download_limits.clone()
+    }
+
+    pub async fn save(&self, config: &AppSettings) -> Result<()> {
+        let content =
+            serde_json::to_string_pretty(config).context("Failed to serialize app settings")?;
+        if let Some(parent) = self.config_path.parent() {
+            fs::create_dir_all(parent)
+                .await
+                .context("Failed to create config directory")?;
+        }
+        write_atomic(&self.config_path, content, false)
+            .await
+            .context("Failed to write app settings file")?;
+        self.download_limits.configure(&config.downloads);
+        Ok(())
+    }
+
+    /// Points managed Java versions and storage roots that were under `old_root` at the
+    /// same place under `new_root`, after the app data folder moved.
+    pub async fn rebase_paths(&self, old_root: &Path, new_root: &Path) -> Result<()> {
+        let mut config = self.load().await?;
+        for java in &mut config.managed_java_versions {
+            if let Some(path) = rebase_path(&java.path, old_root, new_root) {
+                java.path = path;
+            }
+        }
+        for root in &mut config.storage_roots {
+            if let Some(path) = rebase_path(root, old_root, new_root) {
+                *root = path;
+            }
+        }
+        self.save(&config).await
+    }
+}
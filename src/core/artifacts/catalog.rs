@@ -0,0 +1,110 @@
+use super::{ArtifactStore, HashAlgorithm};
+use crate::utils::write_atomic;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tokio::fs;
+
+/// File in the store root naming the server jars it holds, so servers can be installed
+/// without asking any API which file a version is.
+const SERVER_CATALOG_FILE: &str = "servers.json";
+
+/// A server jar held by the store, by what it installs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CachedServer {
+    /// Lowercase loader name; `None` for vanilla.
+    pub server_type: Option<String>,
+    pub mc_version: String,
+    /// Loader build or version; `None` when it was installed as the latest build.
+    pub loader_version: Option<String>,
+    pub sha1: String,
+    pub size: u64,
+    pub added_at: DateTime<Utc>,
+}
+
+impl CachedServer {
+    fn same_target(&self, other: &CachedServer) -> bool {
+        self.server_type == other.server_type
+            && self.mc_version == other.mc_version
+            && self.loader_version == other.loader_version
+    }
+}
+
+impl ArtifactStore {
+    async fn read_catalog(&self) -> Vec<CachedServer> {
+        match fs::read_to_string(self.base_dir.join(SERVER_CATALOG_FILE)).await {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// Adds a server jar to the store and records what it installs, replacing an older
+    /// jar recorded for the same server type, version and build.
+    pub async fn add_server_jar(
+        &self,
+        jar_path: impl AsRef<Path>,
+        server_type: Option<&str>,
+        mc_version: &str,
+        loader_version: Option<&str>,
+    ) -> Result<CachedServer> {
+        let jar_path = jar_path.as_ref();
+        let sha1 = self.calculate_hash(jar_path, HashAlgorithm::Sha1).await?;
+        self.add_artifact(jar_path, &sha1, HashAlgorithm::Sha1).await?;
+        let entry = CachedServer {
+            server_type: server_type.map(|t| t.to_lowercase()),
+            mc_version: mc_version.to_string(),
+            loader_version: loader_version.map(str::to_string),
+            size: fs::metadata(jar_path).await?.len(),
+            sha1,
+            added_at: Utc::now(),
+        };
+
+        let _guard = self.catalog_lock.lock().await;
+        let mut catalog = self.read_catalog().await;
+        catalog.retain(|e| !e.same_target(&entry));
+        catalog.push(entry.clone());
+        fs::create_dir_all(&self.base_dir).await?;
+        write_atomic(
+            &self.base_dir.join(SERVER_CATALOG_FILE),
+            serde_json::to_string_pretty(&catalog)?,
+            false,
+        )
+        .await?;
+        Ok(entry)
+    }
+
+    /// Server jars that are recorded in the catalog and still present in the store.
+    pub async fn cached_servers(&self) -> Vec<CachedServer> {
+        let _guard = self.catalog_lock.lock().await;
+        let mut servers = Vec::new();
+        for entry in self.read_catalog().await {
+            if self.exists(&entry.sha1, HashAlgorithm::Sha1).await {
+                servers.push(entry);
+            }
+        }
+        servers
+    }
+
+    /// The stored jar for a server. Without a loader version the newest jar of that
+    /// server type and Minecraft version is picked only when `any_build` is set, since
+    /// "latest" otherwise means whatever the API currently offers.
+    pub async fn find_server_jar(
+        &self,
+        server_type: Option<&str>,
+        mc_version: &str,
+        loader_version: Option<&str>,
+        any_build: bool,
+    ) -> Option<CachedServer> {
+        let server_type = server_type.map(|t| t.to_lowercase());
+        self.cached_servers()
+            .await
+            .into_iter()
+            .filter(|e| e.server_type == server_type && e.mc_version == mc_version)
+            .filter(|e| match loader_version {
+                Some(version) => e.loader_version.as_deref() == Some(version),
+                None => server_type.is_none() || any_build,
+            })
+            .max_by_key(|e| e.added_at)
+    }
+}
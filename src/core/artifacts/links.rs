@@ -0,0 +1,105 @@
+//! Filesystem helpers for placing store files into instances.
+
+use super::ProvisionMode;
+use std::path::Path;
+use tokio::fs;
+
+/// How `path` is linked to `artifact_path`, if it is.
+pub(super) async fn linked_mode(path: &Path, artifact_path: &Path) -> Option<ProvisionMode> {
+    let metadata = fs::symlink_metadata(path).await.ok()?;
+    if metadata.file_type().is_symlink() {
+        let target = fs::read_link(path).await.ok()?;
+        return (target == artifact_path).then_some(ProvisionMode::Symlink);
+    }
+    let (a, b) = (path.to_path_buf(), artifact_path.to_path_buf());
+    let same = tokio::task::spawn_blocking(move || {
+        file_id(&a).is_some_and(|id| Some(id) == file_id(&b))
+    })
+    .await
+    .ok()?;
+    same.then_some(ProvisionMode::Hardlink)
+}
+
+/// Volume and file index, equal for two hard links to the same file.
+#[cfg(unix)]
+fn file_id(path: &Path) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    let metadata = std::fs::metadata(path).ok()?;
+    Some((metadata.dev(), metadata.ino()))
+}
+
+#[cfg(windows)]
+fn file_id(path: &Path) -> Option<(u64, u64)> {
+    use std::os::windows::io::AsRawHandle;
+    use windows_sys::Win32::Storage::FileSystem::{BY_HANDLE_FILE_INFORMATION, GetFileInformationByHandle};
+
+    let file = std::fs::File::open(path).ok()?;
+    let mut info: BY_HANDLE_FILE_INFORMATION = unsafe { std::mem::zeroed() };
+    if unsafe { GetFileInformationByHandle(file.as_raw_handle() as _, &mut info) } == 0 {
+        return None;
+    }
+    let index = ((info.nFileIndexHigh as u64) << 32) | info.nFileIndexLow as u64;
+    Some((info.dwVolumeSerialNumber as u64, index))
+}
+
+#[cfg(unix)]
+pub(super) async fn symlink_file(original: &Path, link: &Path) -> std::io::Result<()> {
+    fs::symlink(original, link).await
+}
+
+#[cfg(windows)]
+pub(super) async fn symlink_file(original: &Path, link: &Path) -> std::io::Result<()> {
+    fs::symlink_file(original, link).await
+}
+
+/// Clones `from` to `to` sharing its blocks, on filesystems that support it (Btrfs, XFS,
+/// APFS, ReFS). Writing to either file afterwards leaves the other alone.
+pub(super) async fn reflink(from: &Path, to: &Path) -> std::io::Result<()> {
+    let (from, to) = (from.to_path_buf(), to.to_path_buf());
+    tokio::task::spawn_blocking(move || reflink_copy::reflink(from, to))
+        .await
+        .map_err(std::io::Error::other)?
+}
+
+/// Hard links share their content with the store and every other instance, so they are
+/// made read-only: tools then have to replace the file instead of writing into it.
+pub(super) async fn make_read_only(path: &Path) -> std::io::Result<()> {
+    let mut permissions = fs::metadata(path).await?.permissions();
+    permissions.set_readonly(true);
+    fs::set_permissions(path, permissions).await
+}
+
+/// Gives the owner write access back, for files copied or cloned from a read-only artifact.
+pub(super) async fn make_writable(path: &Path) -> std::io::Result<()> {
+    let mut permissions = fs::metadata(path).await?.permissions();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        permissions.set_mode(permissions.mode() | 0o200);
+    }
+    // Windows has no world-writable bit that clearing the flag could turn on
+    #[cfg(windows)]
+    #[allow(clippy::permissions_set_readonly_false)]
+    permissions.set_readonly(false);
+    fs::set_permissions(path, permissions).await
+}
+
+/// Lets a file be deleted or replaced; Windows refuses both for read-only files.
+#[cfg(windows)]
+pub(super) async fn prepare_removal(path: &Path) -> std::io::Result<()> {
+    if fs::try_exists(path).await? {
+        make_writable(path).await?;
+    }
+    Ok(())
+}
+
+#[cfg(not(windows))]
+pub(super) async fn prepare_removal(_path: &Path) -> std::io::Result<()> {
+    Ok(())
+}
+
+/// Deletes a stored file, read-only or not.
+pub(super) async fn remove_artifact(path: &Path) -> std::io::Result<()> {
+    prepare_removal(path).await?;
+    fs::remove_file(path).await
+}
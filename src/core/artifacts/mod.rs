@@ -0,0 +1,188 @@
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use sha2::Sha256;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tokio::io::AsyncReadExt;
+use tracing::{debug, info};
+use uuid::Uuid;
+
+mod catalog;
+mod links;
+mod provision;
+mod prune;
+#[cfg(test)]
+mod tests;
+
+pub use catalog::CachedServer;
+
+/// Supported hash algorithms for artifact verification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    Sha1,
+    Sha256,
+}
+
+/// How a file from the store is placed into an instance folder.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ProvisionMode {
+    /// Each instance gets its own copy.
+    #[default]
+    Copy,
+    /// The instance file and the stored artifact are the same file on disk, made read-only
+    /// so it can only be replaced, not changed in place. Needs both to be on the same
+    /// volume. A reflink is made instead where the filesystem supports one.
+    Hardlink,
+    /// The instance file is a copy-on-write clone of the stored artifact, sharing its
+    /// blocks until either is changed. Needs Btrfs, XFS, APFS or ReFS.
+    Reflink,
+    /// The instance file points at the stored artifact. Needs developer mode or admin
+    /// rights on Windows.
+    Symlink,
+}
+
+/// What [`ArtifactStore::share_file`] did with one file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SharedFile {
+    pub hash: String,
+    /// How the file is provisioned now; `Copy` when linking wasn't possible.
+    pub mode: ProvisionMode,
+    /// Disk space freed by replacing the file with a link.
+    pub bytes_saved: u64,
+}
+
+/// A centralized store for large binary artifacts (JARs, mods, etc.).
+/// Files are stored in a content-addressable structure based on their hashes.
+pub struct ArtifactStore {
+    base_dir: PathBuf,
+    catalog_lock: tokio::sync::Mutex<()>,
+}
+
+impl ArtifactStore {
+    /// Creates a new ArtifactStore at the specified base directory.
+    pub fn new(base_dir: PathBuf) -> Self {
+        Self {
+            base_dir,
+            catalog_lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Returns the path where an artifact with the given hash would be stored.
+    /// Structure: base_dir/sha256/aa/bb/aabbcc...
+    pub fn get_artifact_path(&self, hash: &str, algorithm: HashAlgorithm) -> PathBuf {
+        let algo_dir = match algorithm {
+            HashAlgorithm::Sha1 => "sha1",
+            HashAlgorithm::Sha256 => "sha256",
+        };
+
+        // Use a 2-level nested directory structure to avoid too many files in one directory
+        let prefix1 = &hash[0..2];
+        let prefix2 = &hash[2..4];
+
+        self.base_dir
+            .join(algo_dir)
+            .join(prefix1)
+            .join(prefix2)
+            .join(hash)
+    }
+
+    /// Checks if an artifact with the given hash exists in the store.
+    pub async fn exists(&self, hash: &str, algorithm: HashAlgorithm) -> bool {
+        let path = self.get_artifact_path(hash, algorithm);
+        path.exists()
+    }
+
+    /// Adds an artifact to the store from an existing file.
+    /// This will verify the hash of the source file before moving it to the store.
+    /// If the artifact already exists, it will not be overwritten.
+    pub async fn add_artifact(
+        &self,
+        source_path: impl AsRef<Path>,
+        expected_hash: &str,
+        algorithm: HashAlgorithm,
+    ) -> Result<PathBuf> {
+        let source_path = source_path.as_ref();
+        let target_path = self.get_artifact_path(expected_hash, algorithm);
+
+        if target_path.exists() {
+            debug!("Artifact {} already exists in store", expected_hash);
+            return Ok(target_path);
+        }
+
+        // Verify hash before adding
+        let actual_hash = self.calculate_hash(source_path, algorithm).await?;
+        if actual_hash != expected_hash {
+            return Err(anyhow!(
+                "Hash mismatch for artifact! Expected: {}, Got: {}",
+                expected_hash,
+                actual_hash
+            ));
+        }
+
+        // Ensure parent directory exists
+        if let Some(parent) = target_path.parent() {
+            fs::create_dir_all(parent)
+                .await
+                .with_context(|| format!("Failed to create directory {:?}", parent))?;
+        }
+
+        // Safe concurrent write: write to a unique temporary file and then rename
+        let temp_path = target_path.with_extension(format!("{}.tmp", Uuid::new_v4()));
+        fs::copy(source_path, &temp_path)
+            .await
+            .with_context(|| format!("Failed to copy {:?} to {:?}", source_path, temp_path))?;
+
+        // Rename is atomic on most filesystems. If the target already exists, 
+        // it might have been added by another process while we were copying.
+        if let Err(e) = fs::rename(&temp_path, &target_path).await {
+            if target_path.exists() {
+                debug!("Artifact {} was already added by another process", expected_hash);
+                let _ = fs::remove_file(&temp_path).await;
+            } else {
+                return Err(e).with_context(|| {
+                    format!("Failed to rename {:?} to {:?}", temp_path, target_path)
+                });
+            }
+        }
+
+        info!("Added artifact {} to store", expected_hash);
+        Ok(target_path)
+    }
+
+    /// Calculates the hash of a file.
+    pub async fn calculate_hash(&self, path: &Path, algorithm: HashAlgorithm) -> Result<String> {
+        let mut file = fs::File::open(path)
+            .await
+            .with_context(|| format!("Failed to open file for hashing: {:?}", path))?;
+
+        let mut buffer = vec![0u8; 8192];
+
+        match algorithm {
+            HashAlgorithm::Sha1 => {
+                let mut hasher = Sha1::new();
+                loop {
+                    let n = file.read(&mut buffer).await?;
+                    if n == 0 {
+                        break;
+                    }
+                    hasher.update(&buffer[..n]);
+                }
+                Ok(format!("{:x}", hasher.finalize()))
+            }
+            HashAlgorithm::Sha256 => {
+                let mut hasher = Sha256::new();
+                loop {
+                    let n = file.read(&mut buffer).await?;
+                    if n == 0 {
+                        break;
+                    }
+                    hasher.update(&buffer[..n]);
+                }
+                Ok(format!("{:x}", hasher.finalize()))
+            }
+        }
+    }
+}
+
@@ -0,0 +1,166 @@
+use super::links::{linked_mode, make_read_only, make_writable, prepare_removal, reflink, remove_artifact, symlink_file};
+use super::{ArtifactStore, HashAlgorithm, ProvisionMode, SharedFile};
+use anyhow::{Context, Result, anyhow};
+use std::path::Path;
+use tokio::fs;
+use tracing::debug;
+use uuid::Uuid;
+
+/// Places `artifact_path` at `temp_path` the way `mode` asks for and returns the mode used.
+async fn link(artifact_path: &Path, temp_path: &Path, mode: ProvisionMode) -> std::io::Result<ProvisionMode> {
+    match mode {
+        ProvisionMode::Copy => Err(std::io::Error::other("copy requested")),
+        ProvisionMode::Reflink => reflink(artifact_path, temp_path).await.map(|()| ProvisionMode::Reflink),
+        ProvisionMode::Hardlink => {
+            // A clone saves the same space without tying the files together
+            if reflink(artifact_path, temp_path).await.is_ok() {
+                return Ok(ProvisionMode::Reflink);
+            }
+            let _ = fs::remove_file(temp_path).await;
+            fs::hard_link(artifact_path, temp_path).await?;
+            if let Err(e) = make_read_only(temp_path).await {
+                let _ = fs::remove_file(temp_path).await;
+                return Err(e);
+            }
+            Ok(ProvisionMode::Hardlink)
+        }
+        ProvisionMode::Symlink => symlink_file(artifact_path, temp_path).await.map(|()| ProvisionMode::Symlink),
+    }
+}
+
+impl ArtifactStore {
+    /// Provisions an artifact from the store to a target path (e.g., an instance folder)
+    /// as a plain copy.
+    pub async fn provision(
+        &self,
+        hash: &str,
+        algorithm: HashAlgorithm,
+        target_path: impl AsRef<Path>,
+    ) -> Result<()> {
+        self.provision_as(hash, algorithm, target_path, ProvisionMode::Copy)
+            .await
+            .map(|_| ())
+    }
+
+    /// Provisions an artifact by linking it when `mode` asks for it, falling back to a
+    /// copy when the link can't be made (another volume, no symlink privilege...).
+    /// `Hardlink` prefers a reflink where the filesystem supports one, and makes the
+    /// hard link read-only otherwise. Returns the mode that was actually used.
+    pub async fn provision_as(
+        &self,
+        hash: &str,
+        algorithm: HashAlgorithm,
+        target_path: impl AsRef<Path>,
+        mode: ProvisionMode,
+    ) -> Result<ProvisionMode> {
+        let artifact_path = self.get_artifact_path(hash, algorithm);
+        if !artifact_path.exists() {
+            return Err(anyhow!("Artifact {} not found in store", hash));
+        }
+
+        let target_path = target_path.as_ref();
+        if let Some(parent) = target_path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+
+        // Use temp-and-rename for safe concurrent provisioning, which also replaces an
+        // existing target without ever leaving it half written
+        let temp_path = target_path.with_extension(format!("{}.tmp", Uuid::new_v4()));
+        let used = match link(&artifact_path, &temp_path, mode).await {
+            Ok(used) => used,
+            Err(e) => {
+                if mode != ProvisionMode::Copy {
+                    debug!("Couldn't {:?} artifact {}, copying instead: {}", mode, hash, e);
+                }
+                let _ = fs::remove_file(&temp_path).await;
+                fs::copy(&artifact_path, &temp_path)
+                    .await
+                    .with_context(|| format!("Failed to copy artifact to temporary path {:?}", temp_path))?;
+                ProvisionMode::Copy
+            }
+        };
+        // Copies and clones of a hard-linked, read-only artifact are the instance's own
+        if matches!(used, ProvisionMode::Copy | ProvisionMode::Reflink) {
+            make_writable(&temp_path).await?;
+        }
+
+        prepare_removal(target_path).await?;
+        if let Err(e) = fs::rename(&temp_path, target_path).await {
+            if target_path.exists() {
+                // Someone else already provisioned it
+                let _ = remove_artifact(&temp_path).await;
+            } else {
+                return Err(e).with_context(|| {
+                    format!("Failed to rename {:?} to {:?}", temp_path, target_path)
+                });
+            }
+        }
+
+        Ok(used)
+    }
+
+    /// Moves an instance file into the store and puts a link in its place, so every
+    /// instance with the same file shares one copy on disk. Files already linked to the
+    /// store are left alone.
+    pub async fn share_file(&self, path: impl AsRef<Path>, mode: ProvisionMode) -> Result<SharedFile> {
+        let path = path.as_ref();
+        let hash = self.calculate_hash(path, HashAlgorithm::Sha1).await?;
+        let artifact_path = self.get_artifact_path(&hash, HashAlgorithm::Sha1);
+        let unchanged = |mode| SharedFile { hash: hash.clone(), mode, bytes_saved: 0 };
+
+        if let Some(current) = linked_mode(path, &artifact_path).await {
+            if current == ProvisionMode::Hardlink {
+                make_read_only(path).await?;
+            }
+            return Ok(unchanged(current));
+        }
+        if mode == ProvisionMode::Copy {
+            return Ok(unchanged(ProvisionMode::Copy));
+        }
+
+        let size = fs::metadata(path).await?.len();
+        let existed = artifact_path.exists();
+        let mut copied_into_store = false;
+        if !existed {
+            // Adopting the file by reflink or hard link costs no space; copying it is the fallback
+            match self.adopt(path, &artifact_path, mode).await {
+                Ok(adopted) if mode != ProvisionMode::Symlink => return Ok(unchanged(adopted)),
+                Ok(_) => {}
+                Err(_) => {
+                    self.add_artifact(path, &hash, HashAlgorithm::Sha1).await?;
+                    copied_into_store = true;
+                }
+            }
+        }
+
+        let used = self.provision_as(&hash, HashAlgorithm::Sha1, path, mode).await?;
+        if used == ProvisionMode::Copy {
+            if copied_into_store {
+                let _ = remove_artifact(&artifact_path).await;
+            }
+            return Ok(unchanged(ProvisionMode::Copy));
+        }
+
+        Ok(SharedFile {
+            hash,
+            mode: used,
+            bytes_saved: if existed { size } else { 0 },
+        })
+    }
+
+    /// Puts `source_path` into the store at `target_path` without taking any space, by
+    /// reflink or, unless `mode` asks for reflinks only, by a read-only hard link.
+    async fn adopt(&self, source_path: &Path, target_path: &Path, mode: ProvisionMode) -> Result<ProvisionMode> {
+        if let Some(parent) = target_path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        let temp_path = target_path.with_extension(format!("{}.tmp", Uuid::new_v4()));
+        let link_mode = if mode == ProvisionMode::Reflink { mode } else { ProvisionMode::Hardlink };
+        let adopted = link(source_path, &temp_path, link_mode).await?;
+        if let Err(e) = fs::rename(&temp_path, target_path).await {
+            let _ = remove_artifact(&temp_path).await;
+            return Err(e.into());
+        }
+        Ok(adopted)
+    }
+}
@@ -0,0 +1,88 @@
+use super::links::remove_artifact;
+use super::{ArtifactStore, HashAlgorithm};
+use anyhow::{Context, Result};
+use std::collections::HashSet;
+use tokio::fs;
+use tracing::{debug, info};
+
+impl ArtifactStore {
+    /// Prunes artifacts that are not in the provided set of active hashes.
+    /// Returns the number of files deleted.
+    pub async fn prune(
+        &self,
+        active_hashes: &HashSet<String>,
+        algorithm: HashAlgorithm,
+    ) -> Result<u64> {
+        let algo_dir = match algorithm {
+            HashAlgorithm::Sha1 => "sha1",
+            HashAlgorithm::Sha256 => "sha256",
+        };
+        let base = self.base_dir.join(algo_dir);
+        if !base.exists() {
+            return Ok(0);
+        }
+
+        let mut deleted_count = 0;
+        let mut entries = fs::read_dir(&base).await?;
+
+        // Walk level 1: prefix1
+        while let Some(entry1) = entries.next_entry().await? {
+            let path1 = entry1.path();
+            if !path1.is_dir() {
+                continue;
+            }
+
+            let mut entries2 = fs::read_dir(&path1).await?;
+            // Walk level 2: prefix2
+            while let Some(entry2) = entries2.next_entry().await? {
+                let path2 = entry2.path();
+                if !path2.is_dir() {
+                    continue;
+                }
+
+                let mut entries3 = fs::read_dir(&path2).await?;
+                // Walk level 3: the actual hash files
+                while let Some(entry3) = entries3.next_entry().await? {
+                    let path3 = entry3.path();
+                    if !path3.is_file() {
+                        continue;
+                    }
+
+                    if let Some(hash) = path3.file_name().and_then(|n| n.to_str()) {
+                        // Temporary files might have .tmp extension, ignore them or clean them up
+                        if hash.ends_with(".tmp") {
+                            let _ = fs::remove_file(&path3).await;
+                            continue;
+                        }
+
+                        if !active_hashes.contains(hash) {
+                            debug!("Pruning unlinked artifact: {}", hash);
+                            remove_artifact(&path3).await.with_context(|| {
+                                format!("Failed to delete unlinked artifact: {:?}", path3)
+                            })?;
+                            deleted_count += 1;
+                        }
+                    }
+                }
+
+                // Try to remove empty directory
+                if fs::read_dir(&path2).await?.next_entry().await?.is_none() {
+                    let _ = fs::remove_dir(&path2).await;
+                }
+            }
+
+            // Try to remove empty directory
+            if fs::read_dir(&path1).await?.next_entry().await?.is_none() {
+                let _ = fs::remove_dir(&path1).await;
+            }
+        }
+
+        if deleted_count > 0 {
+            info!(
+                "Pruned {} artifacts from the store ({:?})",
+                deleted_count, algorithm
+            );
+        }
+        Ok(deleted_count)
+    }
+}
@@ -0,0 +1,113 @@
+use super::*;
+use std::collections::HashSet;
+use tempfile::tempdir;
+use tokio::fs;
+
+#[tokio::test]
+async fn test_artifact_store_add_and_exists() {
+    let dir = tempdir().unwrap();
+    let store = ArtifactStore::new(dir.path().to_path_buf());
+
+    let test_file = dir.path().join("test.txt");
+    let content = b"hello world";
+    fs::write(&test_file, content).await.unwrap();
+
+    // sha1 of "hello world"
+    let expected_sha1 = "2aae6c35c94fcfb415dbe95f408b9ce91ee846ed";
+
+    let path = store
+        .add_artifact(&test_file, expected_sha1, HashAlgorithm::Sha1)
+        .await
+        .unwrap();
+    assert!(path.exists());
+    assert!(store.exists(expected_sha1, HashAlgorithm::Sha1).await);
+
+    // Verify nested structure
+    assert!(path.to_string_lossy().contains("sha1"));
+    assert!(path.to_string_lossy().contains("2a"));
+    assert!(path.to_string_lossy().contains("ae"));
+}
+
+#[tokio::test]
+async fn test_artifact_store_mismatch() {
+    let dir = tempdir().unwrap();
+    let store = ArtifactStore::new(dir.path().to_path_buf());
+
+    let test_file = dir.path().join("test.txt");
+    fs::write(&test_file, b"wrong content").await.unwrap();
+
+    let result = store
+        .add_artifact(&test_file, "somehash", HashAlgorithm::Sha1)
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_artifact_store_prune() {
+    let dir = tempdir().unwrap();
+    let store = ArtifactStore::new(dir.path().to_path_buf());
+
+    let test_file = dir.path().join("test.txt");
+    let content = b"hello world";
+    fs::write(&test_file, content).await.unwrap();
+    let hash = "2aae6c35c94fcfb415dbe95f408b9ce91ee846ed";
+
+    store
+        .add_artifact(&test_file, hash, HashAlgorithm::Sha1)
+        .await
+        .unwrap();
+    assert!(store.exists(hash, HashAlgorithm::Sha1).await);
+
+    // Prune with empty set - should delete the artifact
+    let active = HashSet::new();
+    let deleted = store.prune(&active, HashAlgorithm::Sha1).await.unwrap();
+    assert_eq!(deleted, 1);
+    assert!(!store.exists(hash, HashAlgorithm::Sha1).await);
+
+    // Add it back
+    store
+        .add_artifact(&test_file, hash, HashAlgorithm::Sha1)
+        .await
+        .unwrap();
+
+    // Prune with hash in set - should keep it
+    let mut active = HashSet::new();
+    active.insert(hash.to_string());
+    let deleted = store.prune(&active, HashAlgorithm::Sha1).await.unwrap();
+    assert_eq!(deleted, 0);
+    assert!(store.exists(hash, HashAlgorithm::Sha1).await);
+}
+
+#[tokio::test]
+async fn test_server_catalog() {
+    let dir = tempdir().unwrap();
+    let store = ArtifactStore::new(dir.path().join("store"));
+
+    let vanilla = dir.path().join("vanilla.jar");
+    let paper_old = dir.path().join("paper-100.jar");
+    let paper_new = dir.path().join("paper-120.jar");
+    fs::write(&vanilla, b"vanilla").await.unwrap();
+    fs::write(&paper_old, b"paper 100").await.unwrap();
+    fs::write(&paper_new, b"paper 120").await.unwrap();
+
+    store.add_server_jar(&vanilla, None, "1.21.1", None).await.unwrap();
+    store.add_server_jar(&paper_old, Some("Paper"), "1.21.1", Some("100")).await.unwrap();
+    let newest = store.add_server_jar(&paper_new, Some("paper"), "1.21.1", Some("120")).await.unwrap();
+    assert_eq!(store.cached_servers().await.len(), 3);
+
+    assert!(store.find_server_jar(None, "1.21.1", None, false).await.is_some());
+    assert!(store.find_server_jar(None, "1.20.4", None, true).await.is_none());
+    let pinned = store.find_server_jar(Some("paper"), "1.21.1", Some("100"), false).await.unwrap();
+    assert_eq!(pinned.sha1, store.calculate_hash(&paper_old, HashAlgorithm::Sha1).await.unwrap());
+    // "Latest" only resolves to a stored build when offline
+    assert!(store.find_server_jar(Some("paper"), "1.21.1", None, false).await.is_none());
+    assert_eq!(store.find_server_jar(Some("paper"), "1.21.1", None, true).await.unwrap(), newest);
+
+    // Re-adding a target replaces its entry, and pruned jars drop out of the catalog
+    fs::write(&vanilla, b"vanilla rebuilt").await.unwrap();
+    let rebuilt = store.add_server_jar(&vanilla, None, "1.21.1", None).await.unwrap();
+    assert_eq!(store.cached_servers().await.len(), 3);
+    let keep: HashSet<String> = [rebuilt.sha1.clone()].into();
+    store.prune(&keep, HashAlgorithm::Sha1).await.unwrap();
+    assert_eq!(store.cached_servers().await, vec![rebuilt]);
+}
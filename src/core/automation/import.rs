@@ -0,0 +1,157 @@
+use super::{AutomationConfig, PortableRule};
+use crate::errors::AppError;
+use crate::instance::InstanceManager;
+use crate::notifications::{NotificationRule, NotificationTrigger};
+use crate::scheduler::{ScheduledTask, SchedulerManager};
+use anyhow::{Result, anyhow};
+use chrono::Utc;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use tokio_cron_scheduler::Job;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AutomationImportSummary {
+    pub schedules_added: usize,
+    /// Schedules with the same type and cron expression as an existing one.
+    pub schedules_skipped: usize,
+    pub webhooks_updated: usize,
+    pub rules_added: usize,
+    /// Existing rules with the same name (and instance) that were overwritten.
+    pub rules_updated: usize,
+}
+
+fn validate_rules(rules: &[PortableRule], scope: &str, errors: &mut Vec<String>) {
+    for rule in rules {
+        if rule.name.trim().is_empty() {
+            errors.push(format!("{}: a notification rule has no name", scope));
+        }
+        if let NotificationTrigger::LogMatch { pattern } = &rule.trigger
+            && let Err(e) = Regex::new(pattern)
+        {
+            errors.push(format!("{}: rule \"{}\" has an invalid pattern: {}", scope, rule.name, e));
+        }
+    }
+}
+
+/// Checks the whole document up front and resolves its instance names, so a bad entry
+/// leaves everything unchanged instead of applying half of it.
+async fn validate(instances: &InstanceManager, config: &AutomationConfig) -> Result<Vec<Uuid>> {
+    let mut errors = Vec::new();
+    let mut ids = Vec::new();
+    validate_rules(&config.global_rules, "Global rules", &mut errors);
+
+    for entry in &config.instances {
+        match instances.resolve_instance_id(&entry.instance).await {
+            Ok(id) if ids.contains(&id) => errors.push(format!("{}: listed more than once", entry.instance)),
+            Ok(id) => ids.push(id),
+            Err(e) => errors.push(format!("{}: {}", entry.instance, e)),
+        }
+        for schedule in &entry.schedules {
+            if let Err(e) = Job::new_async(schedule.cron.as_str(), |_, _| Box::pin(async {})) {
+                errors.push(format!("{}: invalid cron \"{}\": {}", entry.instance, schedule.cron, e));
+            }
+            if let Some(Err(e)) = schedule.warning.as_ref().map(|w| w.validate()) {
+                errors.push(format!("{}: {}", entry.instance, e));
+            }
+        }
+        validate_rules(&entry.rules, &entry.instance, &mut errors);
+    }
+
+    if !errors.is_empty() {
+        return Err(anyhow!("Automation config is invalid:\n{}", errors.join("\n")));
+    }
+    Ok(ids)
+}
+
+/// Adds rules that don't exist yet and overwrites the ones with the same name in the same scope.
+async fn merge_rules(
+    instances: &InstanceManager,
+    instance_id: Option<Uuid>,
+    rules: &[PortableRule],
+    summary: &mut AutomationImportSummary,
+) -> Result<()> {
+    let existing = instances.list_notification_rules(instance_id).await?;
+    for rule in rules {
+        let current = existing.iter().find(|r| r.instance_id == instance_id && r.name == rule.name);
+        let id = current.map(|r| r.id).unwrap_or(0);
+        if current.is_some_and(|r| PortableRule::from(r) == *rule) {
+            continue;
+        }
+        instances
+            .save_notification_rule(&NotificationRule {
+                id,
+                instance_id,
+                name: rule.name.clone(),
+                trigger: rule.trigger.clone(),
+                title: rule.title.clone(),
+                body: rule.body.clone(),
+                enabled: rule.enabled,
+                created_at: Utc::now(),
+            })
+            .await?;
+        if id == 0 {
+            summary.rules_added += 1;
+        } else {
+            summary.rules_updated += 1;
+        }
+    }
+    Ok(())
+}
+
+/// Validates `config` and merges it into the existing automation. New enabled schedules
+/// are also registered with `scheduler` so they run without a restart.
+pub async fn import_automation(
+    instances: &InstanceManager,
+    scheduler: Option<&SchedulerManager>,
+    config: &AutomationConfig,
+) -> Result<AutomationImportSummary> {
+    let ids = validate(instances, config).await?;
+    let mut summary = AutomationImportSummary::default();
+    merge_rules(instances, None, &config.global_rules, &mut summary).await?;
+
+    for (entry, id) in config.instances.iter().zip(ids) {
+        let mut instance = instances
+            .get_instance(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", entry.instance)))?;
+
+        let mut added = Vec::new();
+        for schedule in &entry.schedules {
+            let duplicate = instance
+                .schedules
+                .iter()
+                .any(|t| t.task_type == schedule.task_type && t.cron == schedule.cron);
+            if duplicate {
+                summary.schedules_skipped += 1;
+                continue;
+            }
+            let mut task = ScheduledTask::new(id, schedule.task_type.clone(), schedule.cron.clone());
+            task.enabled = schedule.enabled;
+            task.warning = schedule.warning.clone();
+            instance.schedules.push(task.clone());
+            added.push(task);
+        }
+        if let Some(webhooks) = &entry.webhooks
+            && *webhooks != instance.settings.webhooks
+        {
+            instance.settings.webhooks = webhooks.clone();
+            summary.webhooks_updated += 1;
+        }
+        instances.save_instance_to_db(&instance).await?;
+        summary.schedules_added += added.len();
+
+        if let Some(scheduler) = scheduler {
+            for task in added.into_iter().filter(|t| t.enabled) {
+                if let Err(e) = scheduler.add_task(task).await {
+                    warn!("Imported schedule for {} could not be started: {}", instance.name, e);
+                }
+            }
+        }
+        merge_rules(instances, Some(id), &entry.rules, &mut summary).await?;
+    }
+
+    info!("Imported automation config: {:?}", summary);
+    Ok(summary)
+}
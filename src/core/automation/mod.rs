@@ -0,0 +1,160 @@
+//! Portable copy of an installation's automation: schedules, webhook routes and desktop
+//! notification rules. Instances are referenced by name rather than UUID so a document
+//! exported on one machine can be applied to the matching instances on another.
+
+use crate::instance::InstanceManager;
+use crate::notifications::{NotificationRule, NotificationTrigger, WebhookSettings};
+use crate::scheduler::{RestartWarning, ScheduleType, ScheduledTask};
+use anyhow::{Context, Result, anyhow};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use uuid::Uuid;
+
+mod import;
+
+pub use import::{AutomationImportSummary, import_automation};
+
+pub const AUTOMATION_FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AutomationFormat {
+    Json,
+    Toml,
+}
+
+impl AutomationFormat {
+    /// Picks the format from a file extension; anything but `.toml` is read as JSON.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("toml") => Self::Toml,
+            _ => Self::Json,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PortableSchedule {
+    pub task_type: ScheduleType,
+    pub cron: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub warning: Option<RestartWarning>,
+}
+
+/// A notification rule without its database id or owning instance.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PortableRule {
+    pub name: String,
+    pub trigger: NotificationTrigger,
+    pub title: String,
+    pub body: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstanceAutomation {
+    /// Instance name or slug, resolved on import.
+    pub instance: String,
+    #[serde(default)]
+    pub schedules: Vec<PortableSchedule>,
+    /// Left untouched on import when missing.
+    #[serde(default)]
+    pub webhooks: Option<WebhookSettings>,
+    #[serde(default)]
+    pub rules: Vec<PortableRule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutomationConfig {
+    pub format_version: u32,
+    pub exported_at: DateTime<Utc>,
+    /// Rules that apply to every instance.
+    #[serde(default)]
+    pub global_rules: Vec<PortableRule>,
+    #[serde(default)]
+    pub instances: Vec<InstanceAutomation>,
+}
+
+impl AutomationConfig {
+    pub fn to_string(&self, format: AutomationFormat) -> Result<String> {
+        match format {
+            AutomationFormat::Json => Ok(serde_json::to_string_pretty(self)?),
+            AutomationFormat::Toml => toml::to_string_pretty(self).context("Failed to write TOML"),
+        }
+    }
+
+    pub fn parse(text: &str, format: AutomationFormat) -> Result<Self> {
+        let config: Self = match format {
+            AutomationFormat::Json => serde_json::from_str(text).context("Invalid automation JSON")?,
+            AutomationFormat::Toml => toml::from_str(text).context("Invalid automation TOML")?,
+        };
+        if config.format_version > AUTOMATION_FORMAT_VERSION {
+            return Err(anyhow!(
+                "Automation format {} is newer than this version supports ({})",
+                config.format_version,
+                AUTOMATION_FORMAT_VERSION
+            ));
+        }
+        Ok(config)
+    }
+}
+
+impl From<&ScheduledTask> for PortableSchedule {
+    fn from(task: &ScheduledTask) -> Self {
+        Self {
+            task_type: task.task_type.clone(),
+            cron: task.cron.clone(),
+            enabled: task.enabled,
+            warning: task.warning.clone(),
+        }
+    }
+}
+
+impl From<&NotificationRule> for PortableRule {
+    fn from(rule: &NotificationRule) -> Self {
+        Self {
+            name: rule.name.clone(),
+            trigger: rule.trigger.clone(),
+            title: rule.title.clone(),
+            body: rule.body.clone(),
+            enabled: rule.enabled,
+        }
+    }
+}
+
+/// Collects the automation of `ids`, or of every instance when `ids` is empty, plus all global rules.
+pub async fn export_automation(instances: &InstanceManager, ids: &[Uuid]) -> Result<AutomationConfig> {
+    let global_rules = instances.list_notification_rules(None).await?;
+    let mut config = AutomationConfig {
+        format_version: AUTOMATION_FORMAT_VERSION,
+        exported_at: Utc::now(),
+        global_rules: global_rules.iter().map(PortableRule::from).collect(),
+        instances: Vec::new(),
+    };
+
+    for instance in instances.list_instances().await? {
+        if !ids.is_empty() && !ids.contains(&instance.id) {
+            continue;
+        }
+        let rules = instances.list_notification_rules(Some(instance.id)).await?;
+        config.instances.push(InstanceAutomation {
+            instance: instance.name.clone(),
+            schedules: instance.schedules.iter().map(PortableSchedule::from).collect(),
+            webhooks: Some(instance.settings.webhooks.clone()),
+            rules: rules
+                .iter()
+                .filter(|rule| rule.instance_id == Some(instance.id))
+                .map(PortableRule::from)
+                .collect(),
+        });
+    }
+    Ok(config)
+}
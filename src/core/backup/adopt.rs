@@ -0,0 +1,119 @@
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use anyhow::{Context, Result};
+use serde::Serialize;
+use tracing::{info, warn};
+use uuid::Uuid;
+use super::types::BackupInfo;
+use super::BackupManager;
+
+/// Folder inside an imported server where other panels and backup plugins keep archives.
+pub const IMPORTED_BACKUPS_DIR: &str = "backups";
+
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct AdoptionReport {
+    pub adopted: Vec<BackupInfo>,
+    /// Archives left in place because restoring them would not recreate the whole server,
+    /// e.g. world-only backups.
+    pub skipped: Vec<String>,
+}
+
+/// Whether `path` is a zip of a whole server, i.e. has `server.properties` at its root.
+/// Restoring replaces the entire instance folder, so partial archives can't be adopted.
+pub fn is_full_server_backup(path: &Path) -> bool {
+    let Ok(file) = File::open(path) else {
+        return false;
+    };
+    let Ok(archive) = zip::ZipArchive::new(file) else {
+        return false;
+    };
+    archive.file_names().any(|name| name.trim_start_matches("./") == "server.properties")
+}
+
+/// Archives in the `backups/` folder of a server about to be imported.
+pub fn find_importable_backups(instance_dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(instance_dir.join(IMPORTED_BACKUPS_DIR)) else {
+        return vec![];
+    };
+    let mut archives: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_file() && p.extension().is_some_and(|e| e.eq_ignore_ascii_case("zip")))
+        .collect();
+    archives.sort();
+    archives
+}
+
+impl BackupManager {
+    /// Moves full-server archives from an imported instance's `backups/` folder into its
+    /// backup list, so they can be restored and aren't copied into every new backup.
+    pub async fn adopt_backups(&self, instance_id: Uuid, instance_dir: &Path) -> Result<AdoptionReport> {
+        let archives = {
+            let instance_dir = instance_dir.to_path_buf();
+            tokio::task::spawn_blocking(move || {
+                find_importable_backups(&instance_dir)
+                    .into_iter()
+                    .map(|p| (is_full_server_backup(&p), p))
+                    .collect::<Vec<_>>()
+            })
+            .await?
+        };
+        let mut report = AdoptionReport::default();
+        if archives.is_empty() {
+            return Ok(report);
+        }
+
+        let backup_dir = self.get_instance_backup_dir(instance_id);
+        tokio::fs::create_dir_all(&backup_dir).await?;
+        for (full, source) in archives {
+            let name = source.file_name().unwrap_or_default().to_string_lossy().into_owned();
+            if !full {
+                report.skipped.push(name);
+                continue;
+            }
+            let dest = unique_name(&backup_dir, &name);
+            // Keep the archive's date; it's when the backup was actually taken
+            let modified = std::fs::metadata(&source).and_then(|m| m.modified()).ok();
+            if tokio::fs::rename(&source, &dest).await.is_err() {
+                tokio::fs::copy(&source, &dest)
+                    .await
+                    .with_context(|| format!("Failed to adopt backup {}", name))?;
+                tokio::fs::remove_file(&source).await?;
+            }
+            if let Some(modified) = modified
+                && let Err(e) = File::options().write(true).open(&dest).and_then(|f| f.set_modified(modified))
+            {
+                warn!("Could not keep the date of backup {}: {}", name, e);
+            }
+            let metadata = tokio::fs::metadata(&dest).await?;
+            report.adopted.push(BackupInfo {
+                name: dest.file_name().unwrap_or_default().to_string_lossy().into_owned(),
+                path: dest,
+                size: metadata.len(),
+                created_at: metadata.modified()?.into(),
+            });
+        }
+
+        // Drop the folder if nothing else was in it
+        let _ = tokio::fs::remove_dir(instance_dir.join(IMPORTED_BACKUPS_DIR)).await;
+        info!(
+            "Adopted {} backups of instance {}, skipped {}",
+            report.adopted.len(),
+            instance_id,
+            report.skipped.len()
+        );
+        Ok(report)
+    }
+}
+
+/// A free `<name>.zip` in `dir`; the extension is lowercased so the backup list picks it up.
+fn unique_name(dir: &Path, name: &str) -> PathBuf {
+    let stem = name.rsplit_once('.').map_or(name, |(stem, _)| stem);
+    let mut path = dir.join(format!("{}.zip", stem));
+    let mut n = 2;
+    while path.exists() {
+        path = dir.join(format!("{} ({}).zip", stem, n));
+        n += 1;
+    }
+    path
+}
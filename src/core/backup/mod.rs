@@ -3,8 +3,10 @@ use uuid::Uuid;
 
 pub mod types;
 pub mod operations;
+pub mod adopt;
 
 pub use types::BackupInfo;
+pub use adopt::AdoptionReport;
 
 pub struct BackupManager {
     pub(crate) base_dir: PathBuf,
@@ -9,6 +9,7 @@ use walkdir::WalkDir;
 use uuid::Uuid;
 use super::types::BackupInfo;
 use super::BackupManager;
+use crate::utils::{dir_size, ensure_free_space};
 
 impl BackupManager {
     pub async fn create_backup<F>(&self, instance_id: Uuid, source_dir: impl AsRef<Path>, name: &str, on_progress: F) -> Result<BackupInfo> 
@@ -22,6 +23,14 @@ impl BackupManager {
             tokio::fs::create_dir_all(&backup_dir).await?;
         }
 
+        // Worlds are mostly already-compressed region files, so the archive ends up
+        // close to the folder's size.
+        let source_size = {
+            let source_dir = source_dir.clone();
+            tokio::task::spawn_blocking(move || dir_size(&source_dir)).await?
+        };
+        ensure_free_space(&backup_dir, source_size)?;
+
         let timestamp = Utc::now().format("%Y-%m-%d_%H-%M-%S");
         let backup_filename = if name.is_empty() {
             format!("Backup_{}.zip", timestamp)
@@ -103,7 +112,8 @@ impl BackupManager {
             let path = entry.path();
             if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some("zip") {
                 let metadata = entry.metadata().await?;
-                let created_at: DateTime<Utc> = metadata.created()?.into();
+                // Modification time, since adopted archives keep theirs but not their creation time
+                let created_at: DateTime<Utc> = metadata.modified()?.into();
                 
                 backups.push(BackupInfo {
                     name: path.file_name().unwrap().to_string_lossy().into_owned(),
@@ -130,6 +140,18 @@ impl BackupManager {
         Ok(())
     }
 
+    /// Removes every backup of an instance, returning how many there were and their total size.
+    pub async fn delete_all_backups(&self, instance_id: Uuid) -> Result<(usize, u64)> {
+        let backups = self.list_backups(instance_id).await?;
+        let bytes = backups.iter().map(|b| b.size).sum();
+        let backup_dir = self.get_instance_backup_dir(instance_id);
+        if backup_dir.exists() {
+            tokio::fs::remove_dir_all(&backup_dir).await?;
+            info!("Deleted {} backups of instance {}", backups.len(), instance_id);
+        }
+        Ok((backups.len(), bytes))
+    }
+
     pub async fn restore_backup(&self, instance_id: Uuid, backup_name: &str, target_dir: impl AsRef<Path>) -> Result<()> {
         let backup_dir = self.get_instance_backup_dir(instance_id);
         let backup_path = backup_dir.join(backup_name);
@@ -0,0 +1,180 @@
+use super::{CacheManager, PersistentCacheEntry};
+use anyhow::Result;
+use moka::future::Cache;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, PoisonError};
+use std::time::{Duration, SystemTime};
+use tokio::fs;
+use tokio::sync::Mutex;
+
+/// A file of the on-disk cache.
+pub(super) struct DiskEntry {
+    pub key: String,
+    pub path: PathBuf,
+    pub size: u64,
+    pub last_used: SystemTime,
+}
+
+impl CacheManager {
+    /// Ensures the background flush task is running if a cache directory is present.
+    pub(super) fn ensure_background_tasks(&self) {
+        let Some(cache_dir) = self.cache_dir.clone() else {
+            return;
+        };
+
+        if self.background_task_started.load(std::sync::atomic::Ordering::Relaxed) {
+            return;
+        }
+
+        let handle = match tokio::runtime::Handle::try_current() {
+            Ok(h) => h,
+            Err(_) => return, // No runtime available yet
+        };
+
+        if self.background_task_started.compare_exchange(
+            false,
+            true,
+            std::sync::atomic::Ordering::SeqCst,
+            std::sync::atomic::Ordering::SeqCst,
+        ).is_ok() {
+            let cache_clone = self.cache.clone();
+            let dirty_keys_clone = Arc::clone(&self.dirty_keys);
+            let max_disk_bytes = Arc::clone(&self.max_disk_bytes);
+            let last_used = Arc::clone(&self.last_used);
+
+            handle.spawn(async move {
+                let mut interval = tokio::time::interval(Duration::from_secs(60)); // Flush every minute
+                loop {
+                    interval.tick().await;
+                    if let Err(e) = Self::flush_to_disk(&cache_clone, &cache_dir, &dirty_keys_clone).await {
+                        tracing::error!("Failed to flush cache to disk: {}", e);
+                    }
+                    let limit = max_disk_bytes.load(Ordering::Relaxed);
+                    if let Err(e) = Self::evict_to_limit(&cache_dir, limit, &last_used).await {
+                        tracing::error!("Failed to evict cache entries: {}", e);
+                    }
+                }
+            });
+        }
+    }
+
+    /// Flushes dirty entries to disk.
+    pub(super) async fn flush_to_disk(
+        cache: &Cache<String, PersistentCacheEntry>,
+        cache_dir: &Path,
+        dirty_keys: &Arc<Mutex<HashSet<String>>>,
+    ) -> Result<()> {
+        let keys_to_flush = {
+            let mut dirty = dirty_keys.lock().await;
+            if dirty.is_empty() {
+                return Ok(());
+            }
+            std::mem::take(&mut *dirty)
+        };
+
+        let metadata_dir = cache_dir.join("metadata");
+        if !metadata_dir.exists() {
+            fs::create_dir_all(&metadata_dir).await?;
+        }
+
+        for key in keys_to_flush {
+            if let Some(entry) = cache.get(&key).await {
+                // Skip if very old (more than 2x TTL)
+                // In disk we can keep it even longer if we want, but let's be consistent
+                let file_path = metadata_dir.join(format!("{}.json", urlencoding::encode(&key)));
+                let content = serde_json::to_string(&entry)?;
+                fs::write(file_path, content).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Deletes the least recently used files until the disk cache fits in `limit` bytes.
+    /// Returns the number of files deleted.
+    async fn evict_to_limit(
+        cache_dir: &Path,
+        limit: u64,
+        last_used: &std::sync::Mutex<HashMap<String, SystemTime>>,
+    ) -> Result<u64> {
+        if limit == 0 {
+            return Ok(0);
+        }
+        let mut files = Self::disk_entries(cache_dir).await?;
+        let mut total: u64 = files.iter().map(|f| f.size).sum();
+        if total <= limit {
+            return Ok(0);
+        }
+
+        {
+            let last_used = last_used.lock().unwrap_or_else(PoisonError::into_inner);
+            for file in &mut files {
+                if let Some(used) = last_used.get(&file.key) {
+                    file.last_used = file.last_used.max(*used);
+                }
+            }
+        }
+        files.sort_by_key(|f| f.last_used);
+
+        let mut evicted = 0;
+        for file in files {
+            if total <= limit {
+                break;
+            }
+            match fs::remove_file(&file.path).await {
+                Ok(()) => evicted += 1,
+                // Already evicted by a concurrent pass
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(_) => continue,
+            }
+            total = total.saturating_sub(file.size);
+        }
+        tracing::debug!("Evicted {} cache files to stay under {} bytes", evicted, limit);
+        Ok(evicted)
+    }
+
+    pub(super) async fn disk_entries(cache_dir: &Path) -> Result<Vec<DiskEntry>> {
+        let metadata_dir = cache_dir.join("metadata");
+        let mut files = Vec::new();
+        let Ok(mut entries) = fs::read_dir(&metadata_dir).await else {
+            return Ok(files);
+        };
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            let Some(key) = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .and_then(|n| n.strip_suffix(".json"))
+                .and_then(|n| urlencoding::decode(n).ok())
+                .map(|k| k.into_owned())
+            else {
+                continue;
+            };
+            let metadata = entry.metadata().await?;
+            if !metadata.is_file() {
+                continue;
+            }
+            files.push(DiskEntry {
+                key,
+                path,
+                size: metadata.len(),
+                last_used: metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+            });
+        }
+        Ok(files)
+    }
+
+    /// Caps the on-disk cache, evicting least recently used entries once it's exceeded.
+    /// 0 removes the cap.
+    pub async fn set_max_disk_bytes(&self, bytes: u64) {
+        self.max_disk_bytes.store(bytes, Ordering::Relaxed);
+        let Some(ref cache_dir) = self.cache_dir else {
+            return;
+        };
+        if let Err(e) = Self::evict_to_limit(cache_dir, bytes, &self.last_used).await {
+            tracing::error!("Failed to evict cache entries: {}", e);
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use super::{CacheManager, CacheStatus, PersistentCacheEntry};
+use anyhow::{Context, Result};
+use chrono::Utc;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use tokio::fs;
+
+impl CacheManager {
+    /// Retrieves a value from the cache and deserializes it.
+    /// 
+    /// # Errors
+    /// Returns an error if the value exists but fails to deserialize into type `T`.
+    pub async fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>> {
+        match self.get_with_status::<T>(key).await? {
+            CacheStatus::Hit(data) => Ok(Some(data)),
+            CacheStatus::Stale(data) => Ok(Some(data)),
+            CacheStatus::Miss => Ok(None),
+        }
+    }
+
+    /// Retrieves a value from the cache with its status (Hit, Stale, or Miss).
+    pub async fn get_with_status<T: DeserializeOwned>(&self, key: &str) -> Result<CacheStatus<T>> {
+        self.ensure_background_tasks();
+        // 1. Try memory cache
+        if let Some(entry) = self.cache.get(key).await {
+            let data: T = serde_json::from_str(&entry.data)
+                .with_context(|| format!("Failed to deserialize cached value for key: {}", key))?;
+            
+            return Ok(self.record_hit(key, &entry, data));
+        }
+
+        // 2. Try disk cache if memory missed
+        if let Some(ref cache_dir) = self.cache_dir {
+            let file_path = cache_dir.join("metadata").join(format!("{}.json", urlencoding::encode(key)));
+            if file_path.exists() {
+                let content = fs::read_to_string(&file_path).await?;
+                let entry: PersistentCacheEntry = serde_json::from_str(&content)?;
+                
+                // Populate memory cache
+                self.cache.insert(key.to_string(), entry.clone()).await;
+                
+                let data: T = serde_json::from_str(&entry.data)?;
+                return Ok(self.record_hit(key, &entry, data));
+            }
+        }
+
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        Ok(CacheStatus::Miss)
+    }
+
+    fn record_hit<T>(&self, key: &str, entry: &PersistentCacheEntry, data: T) -> CacheStatus<T> {
+        self.touch(key);
+        if entry.expiry > Utc::now() {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            CacheStatus::Hit(data)
+        } else {
+            self.stale_hits.fetch_add(1, Ordering::Relaxed);
+            CacheStatus::Stale(data)
+        }
+    }
+
+    /// Retrieves a value from the cache or fetches it using the provided function (SWR).
+    pub async fn fetch_with_cache<T, F, Fut>(
+        self: &Arc<Self>,
+        key: String,
+        ttl: Duration,
+        fetch_fn: F,
+    ) -> Result<T>
+    where
+        T: Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = Result<T>> + Send,
+    {
+        self.fetch_with_options(key, ttl, true, fetch_fn).await
+    }
+
+    /// Retrieves a value from the cache or fetches it using the provided function (SWR) with options.
+    pub async fn fetch_with_options<T, F, Fut>(
+        self: &Arc<Self>,
+        key: String,
+        ttl: Duration,
+        persistent: bool,
+        fetch_fn: F,
+    ) -> Result<T>
+    where
+        T: Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = Result<T>> + Send,
+    {
+        match self.get_with_status::<T>(&key).await? {
+            CacheStatus::Hit(data) => Ok(data),
+            CacheStatus::Stale(data) => {
+                // Return stale data and refresh in background
+                let self_clone = Arc::clone(self);
+                let key_clone = key.clone();
+                tokio::spawn(async move {
+                    if let Ok(fresh_data) = fetch_fn().await {
+                        let _ = self_clone.set_with_ttl(key_clone, fresh_data, ttl, persistent, None).await;
+                    }
+                });
+                Ok(data)
+            }
+            CacheStatus::Miss => {
+                // Fetch fresh and wait
+                let data = fetch_fn().await?;
+                self.set_with_ttl(key, data.clone(), ttl, persistent, None).await?;
+                Ok(data)
+            }
+        }
+    }
+
+    /// Stores a value in the cache with the default TTL.
+    pub async fn set<T: Serialize>(&self, key: String, value: T) -> Result<()> {
+        self.set_with_ttl(key, value, self.default_ttl, true, None).await
+    }
+
+    /// Stores a value in the cache with a specific TTL, persistence option and optional ETag.
+    pub async fn set_with_ttl<T: Serialize>(
+        &self,
+        key: String,
+        value: T,
+        ttl: Duration,
+        persistent: bool,
+        etag: Option<String>,
+    ) -> Result<()> {
+        self.ensure_background_tasks();
+        let data = serde_json::to_string(&value)
+            .with_context(|| format!("Failed to serialize value for caching key: {}", key))?;
+        
+        let entry = PersistentCacheEntry {
+            data,
+            expiry: Utc::now() + chrono::Duration::from_std(ttl)?,
+            etag,
+        };
+
+        self.cache.insert(key.clone(), entry).await;
+
+        if persistent && self.cache_dir.is_some() {
+            self.dirty_keys.lock().await.insert(key);
+        }
+
+        Ok(())
+    }
+
+    /// Removes a value from the cache.
+    pub async fn invalidate(&self, key: &str) {
+        self.cache.invalidate(key).await;
+        if let Some(ref cache_dir) = self.cache_dir {
+            let file_path = cache_dir.join("metadata").join(format!("{}.json", urlencoding::encode(key)));
+            let _ = fs::remove_file(file_path).await;
+        }
+    }
+
+    /// Clears the entire cache.
+    pub async fn clear(&self) {
+        self.cache.invalidate_all();
+        if let Some(ref cache_dir) = self.cache_dir {
+            let metadata_dir = cache_dir.join("metadata");
+            let _ = fs::remove_dir_all(metadata_dir).await;
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use super::CacheManager;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use tokio::fs;
+
+/// Mod and plugin platforms whose responses are cached; everything else is a manifest.
+const PROVIDER_PREFIXES: [&str; 4] = ["modrinth_", "curseforge_", "hangar_", "spiget_"];
+
+/// Groups of cached data that can be cleared separately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CacheNamespace {
+    /// Version manifests and loader/build lists.
+    Manifests,
+    /// Mod and plugin search results.
+    Search,
+    /// Mod and plugin project, version and dependency details.
+    Projects,
+    /// Player heads and marketplace images, kept by the asset manager rather than here.
+    Avatars,
+}
+
+impl CacheNamespace {
+    pub fn of_key(key: &str) -> Self {
+        if !PROVIDER_PREFIXES.iter().any(|p| key.starts_with(p)) {
+            CacheNamespace::Manifests
+        } else if key.contains("_search_") {
+            CacheNamespace::Search
+        } else {
+            CacheNamespace::Projects
+        }
+    }
+}
+
+/// Counters since startup, plus what is on disk right now.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CacheStats {
+    pub hits: u64,
+    /// Expired entries served while a fresh copy is fetched in the background.
+    pub stale_hits: u64,
+    pub misses: u64,
+    pub memory_entries: u64,
+    pub disk_entries: u64,
+    pub disk_bytes: u64,
+    /// 0 when the disk cache isn't capped.
+    pub max_disk_bytes: u64,
+}
+
+impl CacheManager {
+    pub async fn stats(&self) -> Result<CacheStats> {
+        self.cache.run_pending_tasks().await;
+        let disk = match self.cache_dir {
+            Some(ref cache_dir) => Self::disk_entries(cache_dir).await?,
+            None => Vec::new(),
+        };
+        Ok(CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            stale_hits: self.stale_hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            memory_entries: self.cache.entry_count(),
+            disk_entries: disk.len() as u64,
+            disk_bytes: disk.iter().map(|f| f.size).sum(),
+            max_disk_bytes: self.max_disk_bytes.load(Ordering::Relaxed),
+        })
+    }
+
+    /// Drops every entry of a namespace from memory and disk. Returns how many keys were
+    /// removed. [`CacheNamespace::Avatars`] isn't stored here, so nothing is removed for it.
+    pub async fn clear_namespace(&self, namespace: CacheNamespace) -> Result<u64> {
+        let mut keys: HashSet<String> = self
+            .cache
+            .iter()
+            .map(|(key, _)| key.as_ref().clone())
+            .filter(|key| CacheNamespace::of_key(key) == namespace)
+            .collect();
+        if let Some(ref cache_dir) = self.cache_dir {
+            for file in Self::disk_entries(cache_dir).await? {
+                if CacheNamespace::of_key(&file.key) == namespace {
+                    fs::remove_file(&file.path).await?;
+                    keys.insert(file.key);
+                }
+            }
+        }
+
+        let mut dirty = self.dirty_keys.lock().await;
+        for key in &keys {
+            self.cache.invalidate(key).await;
+            dirty.remove(key);
+        }
+        Ok(keys.len() as u64)
+    }
+
+    /// Removes downloads and cache writes left behind by a crash or a killed app: files
+    /// in `temp/` and `.tmp` files older than `max_age`. Returns the number removed.
+    pub async fn cleanup_temp_files(&self, max_age: Duration) -> Result<u64> {
+        let Some(ref cache_dir) = self.cache_dir else {
+            return Ok(0);
+        };
+        let mut removed = 0;
+        for (dir, in_temp_dir) in [
+            (cache_dir.join("temp"), true),
+            (cache_dir.join("metadata"), false),
+            (cache_dir.clone(), false),
+        ] {
+            let Ok(mut entries) = fs::read_dir(&dir).await else {
+                continue;
+            };
+            while let Some(entry) = entries.next_entry().await? {
+                let path = entry.path();
+                let is_tmp = path.extension().is_some_and(|ext| ext == "tmp");
+                if !in_temp_dir && !is_tmp {
+                    continue;
+                }
+                let Ok(metadata) = entry.metadata().await else { continue };
+                let old_enough = metadata
+                    .modified()
+                    .ok()
+                    .and_then(|m| m.elapsed().ok())
+                    .is_some_and(|age| age >= max_age);
+                if metadata.is_file() && old_enough && fs::remove_file(&path).await.is_ok() {
+                    removed += 1;
+                }
+            }
+        }
+        if removed > 0 {
+            tracing::info!("Removed {} orphaned temporary files from the cache", removed);
+        }
+        Ok(removed)
+    }
+}
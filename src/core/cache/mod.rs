@@ -0,0 +1,151 @@
+use std::time::Duration;
+use moka::future::Cache;
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, PoisonError};
+use crate::credentials::CredentialStore;
+use crate::utils::DownloadLimits;
+use std::sync::atomic::AtomicU64;
+use std::time::SystemTime;
+use tokio::sync::Mutex;
+
+mod disk;
+mod entries;
+mod maintenance;
+#[cfg(test)]
+mod tests;
+
+pub use maintenance::{CacheNamespace, CacheStats};
+
+/// A wrapper for cached data that includes a timestamp.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheEntry<T> {
+    pub data: T,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl<T> CacheEntry<T> {
+    pub fn new(data: T) -> Self {
+        Self {
+            data,
+            timestamp: Utc::now(),
+        }
+    }
+}
+
+/// A persistent cache entry that can be saved to disk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersistentCacheEntry {
+    pub data: String, // JSON serialized data
+    pub expiry: DateTime<Utc>,
+    pub etag: Option<String>,
+}
+
+pub enum CacheStatus<T> {
+    Hit(T),
+    Stale(T),
+    Miss,
+}
+
+/// Centralized CacheManager providing a unified interface for get/set operations with TTL and disk persistence.
+/// 
+/// This manager uses an async-friendly in-memory cache (moka) to store
+/// serialized JSON values, and can optionally back them up to disk.
+pub struct CacheManager {
+    cache: Cache<String, PersistentCacheEntry>,
+    cache_dir: Option<PathBuf>,
+    dirty_keys: Arc<Mutex<HashSet<String>>>,
+    default_ttl: Duration,
+    background_task_started: Arc<std::sync::atomic::AtomicBool>,
+    client: reqwest::Client,
+    /// Provider credentials picked up by the clients built from this cache.
+    credentials: CredentialStore,
+    /// Download limits shared by the clients built from this cache.
+    download_limits: DownloadLimits,
+    max_disk_bytes: Arc<AtomicU64>,
+    /// When each key was last read, so eviction drops the least recently used files
+    /// first. Keys not read since startup fall back to the file's modified time.
+    last_used: Arc<std::sync::Mutex<HashMap<String, SystemTime>>>,
+    hits: AtomicU64,
+    stale_hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl CacheManager {
+    /// Creates a new CacheManager with the specified capacity and default TTL.
+    pub fn new(max_capacity: u64, default_ttl: Duration, cache_dir: Option<PathBuf>) -> Self {
+        let cache = Cache::builder()
+            .max_capacity(max_capacity)
+            // Keep entries in memory for twice the default TTL to support SWR
+            .time_to_live(default_ttl * 2)
+            .build();
+        
+        let client = reqwest::Client::builder()
+            .user_agent(concat!("mc-server-wrapper/", env!("CARGO_PKG_VERSION")))
+            .timeout(Duration::from_secs(30))
+            .connect_timeout(Duration::from_secs(10))
+            .build()
+            .unwrap_or_else(|_| reqwest::Client::new());
+        
+        let manager = Self {
+            cache,
+            cache_dir,
+            dirty_keys: Arc::new(Mutex::new(HashSet::new())),
+            default_ttl,
+            background_task_started: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            client,
+            credentials: CredentialStore::default(),
+            download_limits: DownloadLimits::default(),
+            max_disk_bytes: Arc::new(AtomicU64::new(0)),
+            last_used: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            hits: AtomicU64::new(0),
+            stale_hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        };
+
+        // Try to start background flush task if cache_dir is provided
+        manager.ensure_background_tasks();
+
+        manager
+    }
+
+    /// Gets the shared reqwest client.
+    pub fn get_client(&self) -> &reqwest::Client {
+        &self.client
+    }
+
+    /// Shares `credentials` with the provider clients built from this cache.
+    pub fn with_credentials(mut self, credentials: CredentialStore) -> Self {
+        self.credentials = credentials;
+        self
+    }
+
+    /// Credentials for the provider clients.
+    pub fn credentials(&self) -> &CredentialStore {
+        &self.credentials
+    }
+
+    /// Shares `download_limits` with the clients built from this cache.
+    pub fn with_download_limits(mut self, download_limits: DownloadLimits) -> Self {
+        self.download_limits = download_limits;
+        self
+    }
+
+    /// Download limits for the clients.
+    pub fn download_limits(&self) -> &DownloadLimits {
+        &self.download_limits
+    }
+
+    fn touch(&self, key: &str) {
+        self.last_used.lock().unwrap_or_else(PoisonError::into_inner).insert(key.to_string(), SystemTime::now());
+    }
+}
+
+impl Default for CacheManager {
+    fn default() -> Self {
+        // Default: 1000 entries, 1 hour TTL, no disk persistence
+        Self::new(1000, Duration::from_secs(3600), None)
+    }
+}
@@ -0,0 +1,167 @@
+use super::*;
+use serde::{Deserialize, Serialize};
+use tempfile::tempdir;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct TestValue {
+    name: String,
+    count: i32,
+}
+
+#[tokio::test]
+async fn test_cache_set_get() {
+    let manager = CacheManager::new(10, Duration::from_secs(60), None);
+    let key = "test_key".to_string();
+    let value = TestValue {
+        name: "test".to_string(),
+        count: 42,
+    };
+
+    manager.set(key.clone(), value.name.clone()).await.unwrap();
+    let retrieved: Option<String> = manager.get(&key).await.unwrap();
+
+    assert_eq!(retrieved, Some(value.name));
+}
+
+#[tokio::test]
+async fn test_cache_expiration() {
+    // Short TTL for testing
+    let manager = CacheManager::new(10, Duration::from_millis(10), None);
+    let key = "expire_key".to_string();
+    let value = "some_value".to_string();
+
+    manager.set(key.clone(), value.clone()).await.unwrap();
+    
+    // Wait for expiration
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    
+    // Moka might need a bit of time or a get to process expiration
+    let retrieved: Option<String> = manager.get(&key).await.unwrap();
+    assert_eq!(retrieved, None);
+}
+
+#[tokio::test]
+async fn test_cache_invalidate() {
+    let manager = CacheManager::default();
+    let key = "invalidate_key".to_string();
+    let value = "some_value".to_string();
+
+    manager.set(key.clone(), value.clone()).await.unwrap();
+    manager.invalidate(&key).await;
+    
+    let retrieved: Option<String> = manager.get(&key).await.unwrap();
+    assert_eq!(retrieved, None);
+}
+
+#[tokio::test]
+async fn test_disk_persistence() {
+    let dir = tempdir().unwrap();
+    let cache_dir = dir.path().to_path_buf();
+    let manager = CacheManager::new(10, Duration::from_secs(60), Some(cache_dir.clone()));
+    
+    let key = "disk_key".to_string();
+    let value = "disk_value".to_string();
+
+    manager.set(key.clone(), value.clone()).await.unwrap();
+    
+    // Manually trigger flush for testing
+    CacheManager::flush_to_disk(&manager.cache, &cache_dir, &manager.dirty_keys).await.unwrap();
+
+    // Create a new manager with the same disk path
+    let manager2 = CacheManager::new(10, Duration::from_secs(60), Some(cache_dir));
+    let retrieved: Option<String> = manager2.get(&key).await.unwrap();
+    
+    assert_eq!(retrieved, Some(value));
+}
+
+#[tokio::test]
+async fn test_swr_logic() {
+    let manager = Arc::new(CacheManager::new(10, Duration::from_millis(50), None));
+    let key = "swr_key".to_string();
+    
+    // Initial fetch
+    let val = manager.fetch_with_cache(key.clone(), Duration::from_millis(50), || async {
+        Ok("fresh".to_string())
+    }).await.unwrap();
+    assert_eq!(val, "fresh");
+
+    // Wait for it to become stale
+    tokio::time::sleep(Duration::from_millis(60)).await;
+
+    // Fetch again - should get stale "fresh" and trigger background update
+    let val2 = manager.fetch_with_cache(key.clone(), Duration::from_millis(50), || async {
+        Ok("updated".to_string())
+    }).await.unwrap();
+    assert_eq!(val2, "fresh");
+
+    // Wait a bit for background update
+    tokio::time::sleep(Duration::from_millis(20)).await;
+
+    // Fetch again - should get "updated"
+    let val3 = manager.fetch_with_cache(key.clone(), Duration::from_millis(50), || async {
+        Ok("never_called".to_string())
+    }).await.unwrap();
+    assert_eq!(val3, "updated");
+}
+
+#[tokio::test]
+async fn test_disk_limit_evicts_least_recently_used() {
+    let dir = tempdir().unwrap();
+    let cache_dir = dir.path().to_path_buf();
+    let manager = CacheManager::new(10, Duration::from_secs(60), Some(cache_dir.clone()));
+
+    for key in ["fabric_versions_1", "fabric_versions_2", "fabric_versions_3"] {
+        manager.set(key.to_string(), "x".repeat(1000)).await.unwrap();
+    }
+    CacheManager::flush_to_disk(&manager.cache, &cache_dir, &manager.dirty_keys).await.unwrap();
+    let size = manager.stats().await.unwrap().disk_bytes / 3;
+
+    // Files are flushed in no particular order, so reads decide what was used last
+    let _: Option<String> = manager.get("fabric_versions_1").await.unwrap();
+    let _: Option<String> = manager.get("fabric_versions_3").await.unwrap();
+    manager.set_max_disk_bytes(size * 2).await;
+
+    let stats = manager.stats().await.unwrap();
+    assert_eq!(stats.disk_entries, 2);
+    assert_eq!(stats.max_disk_bytes, size * 2);
+    let metadata = cache_dir.join("metadata");
+    assert!(metadata.join("fabric_versions_1.json").exists());
+    assert!(!metadata.join("fabric_versions_2.json").exists());
+}
+
+#[tokio::test]
+async fn test_clear_namespace_and_stats() {
+    let dir = tempdir().unwrap();
+    let cache_dir = dir.path().to_path_buf();
+    let manager = CacheManager::new(10, Duration::from_secs(60), Some(cache_dir.clone()));
+
+    manager.set("modrinth_search_abc".to_string(), 1).await.unwrap();
+    manager.set("modrinth_project_abc".to_string(), 2).await.unwrap();
+    manager.set("mojang_version_manifest".to_string(), 3).await.unwrap();
+    CacheManager::flush_to_disk(&manager.cache, &cache_dir, &manager.dirty_keys).await.unwrap();
+
+    assert_eq!(manager.clear_namespace(CacheNamespace::Search).await.unwrap(), 1);
+    assert_eq!(manager.get::<i32>("modrinth_search_abc").await.unwrap(), None);
+    assert_eq!(manager.get::<i32>("modrinth_project_abc").await.unwrap(), Some(2));
+    assert_eq!(manager.get::<i32>("mojang_version_manifest").await.unwrap(), Some(3));
+
+    let stats = manager.stats().await.unwrap();
+    assert_eq!((stats.hits, stats.misses), (2, 1));
+    assert_eq!(stats.disk_entries, 2);
+    assert_eq!(CacheNamespace::of_key("hangar_versions_x"), CacheNamespace::Projects);
+    assert_eq!(CacheNamespace::of_key("paper_builds_1.21"), CacheNamespace::Manifests);
+}
+
+#[tokio::test]
+async fn test_cleanup_temp_files() {
+    let dir = tempdir().unwrap();
+    let cache_dir = dir.path().to_path_buf();
+    std::fs::create_dir_all(cache_dir.join("temp")).unwrap();
+    std::fs::write(cache_dir.join("temp").join("mc_server_1.21.jar.tmp"), b"partial").unwrap();
+    std::fs::write(cache_dir.join("version_manifest.json"), b"{}").unwrap();
+    let manager = CacheManager::new(10, Duration::from_secs(60), Some(cache_dir.clone()));
+
+    assert_eq!(manager.cleanup_temp_files(Duration::from_secs(3600)).await.unwrap(), 0);
+    assert_eq!(manager.cleanup_temp_files(Duration::ZERO).await.unwrap(), 1);
+    assert!(cache_dir.join("version_manifest.json").exists());
+}
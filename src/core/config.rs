@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use anyhow::Result;
 use tokio::fs;
-use super::instance::CrashHandlingMode;
+use super::instance::{CrashHandlingMode, ResourceLimits};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ServerConfig {
@@ -16,7 +16,49 @@ pub struct ServerConfig {
     pub crash_handling: CrashHandlingMode,
     pub working_dir: PathBuf,
     pub stop_timeout: u64,
+    /// Seconds a server may stay in `Starting` before it is considered hung (0 disables).
+    #[serde(default)]
+    pub startup_timeout: u64,
     pub server_type: Option<String>,
+    /// Spawn the server in its own process group so it can keep running after the app exits.
+    #[serde(default)]
+    pub detached: bool,
+    /// Seconds between `tps`/`mspt` queries on Paper-family servers (0 disables).
+    #[serde(default)]
+    pub tick_sample_interval: u64,
+    /// Seconds without players before the server is stopped (0 disables).
+    #[serde(default)]
+    pub idle_timeout: u64,
+    /// Players whose presence doesn't keep the server running, e.g. AFK bots.
+    #[serde(default)]
+    pub idle_exempt_players: Vec<String>,
+    /// JVM flags placed before `-jar`, e.g. agents and remote debugging.
+    #[serde(default)]
+    pub jvm_args: Vec<String>,
+    /// Full command line run instead of the jar or script, for servers the other
+    /// launch strategies don't cover.
+    #[serde(default)]
+    pub custom_command: Option<String>,
+    /// Modern Forge/NeoForge `@argfile`, relative to the working directory. Replaces
+    /// `-jar` for installs that ship libraries instead of a runnable server jar.
+    #[serde(default)]
+    pub argfile: Option<PathBuf>,
+    /// Extra environment variables for the server process and its hooks.
+    #[serde(default)]
+    pub env: Vec<(String, String)>,
+    /// Shell command run before each launch; the launch is abandoned if it fails.
+    #[serde(default)]
+    pub pre_start_hook: Option<String>,
+    /// Shell command run once the server process has exited.
+    #[serde(default)]
+    pub post_stop_hook: Option<String>,
+    /// Hooks only run when the terminal is turned on in the app settings, since they
+    /// run with the wrapper's permissions just like it.
+    #[serde(default)]
+    pub hooks_enabled: bool,
+    /// Priority, CPU affinity and memory cap applied when the process is launched.
+    #[serde(default)]
+    pub resource_limits: ResourceLimits,
 }
 
 impl Default for ServerConfig {
@@ -32,7 +74,20 @@ impl Default for ServerConfig {
             crash_handling: CrashHandlingMode::Nothing,
             working_dir: PathBuf::from("."),
             stop_timeout: 30,
+            startup_timeout: 0,
             server_type: None,
+            detached: false,
+            tick_sample_interval: 0,
+            idle_timeout: 0,
+            idle_exempt_players: Vec::new(),
+            jvm_args: Vec::new(),
+            custom_command: None,
+            argfile: None,
+            env: Vec::new(),
+            pre_start_hook: None,
+            post_stop_hook: None,
+            hooks_enabled: false,
+            resource_limits: ResourceLimits::default(),
         }
     }
 }
@@ -0,0 +1,147 @@
+//! Works out a config file's format from its extension and content, so callers don't
+//! have to know it and a wrongly reported format can't rewrite a file in another syntax.
+
+use super::hocon::parse_hocon;
+use super::types::ConfigFormat;
+use crate::nbt::read_nbt;
+use anyhow::{Result, anyhow};
+use serde_yaml::Value as YamlValue;
+use std::path::Path;
+use tokio::fs;
+
+pub fn format_for_path(path: &str) -> Option<ConfigFormat> {
+    let ext = path.rsplit_once('.')?.1.to_lowercase();
+    match ext.as_str() {
+        "properties" => Some(ConfigFormat::Properties),
+        "yml" | "yaml" => Some(ConfigFormat::Yaml),
+        "toml" => Some(ConfigFormat::Toml),
+        "json" => Some(ConfigFormat::Json),
+        "conf" | "hocon" => Some(ConfigFormat::Hocon),
+        "dat" | "nbt" => Some(ConfigFormat::Nbt),
+        _ => None,
+    }
+}
+
+fn content_lines(content: &str) -> impl Iterator<Item = &str> {
+    content
+        .lines()
+        .filter(|l| !l.trim().is_empty() && !l.trim_start().starts_with(['#', '!']) && !l.trim_start().starts_with("//"))
+}
+
+/// A line opening a HOCON object, `key {` or `key = {`; YAML and TOML never end a line like that.
+fn opens_hocon_object(line: &str) -> bool {
+    let line = line.trim_end();
+    let Some(key) = line.strip_suffix('{') else {
+        return false;
+    };
+    let key = key.trim_end().trim_end_matches(['=', ':']).trim();
+    !key.is_empty() && !key.contains(['"', '{', '[']) && !key.contains(' ')
+}
+
+fn looks_like_properties(content: &str) -> bool {
+    let mut lines = content_lines(content).peekable();
+    lines.peek().is_some()
+        && lines.all(|l| {
+            !l.starts_with([' ', '\t'])
+                && l.find('=').is_some_and(|eq| l.find(':').is_none_or(|colon| eq < colon) && !l[..eq].contains(' '))
+        })
+}
+
+/// TOML parses most `.properties` files too; real TOML has sections or spaced `key = value` pairs.
+fn looks_like_toml(content: &str) -> bool {
+    let shaped = content_lines(content).any(|l| {
+        let l = l.trim();
+        (l.starts_with('[') && l.ends_with(']')) || l.contains(" = ")
+    });
+    shaped && ::toml::from_str::<::toml::Value>(content).is_ok()
+}
+
+/// What the content of a file looks like, independent of its name.
+fn sniff(content: &str) -> Option<ConfigFormat> {
+    let trimmed = content.trim_start();
+    if trimmed.is_empty() {
+        return None;
+    }
+    if trimmed.starts_with(['{', '[']) && serde_json::from_str::<serde_json::Value>(content).is_ok() {
+        return Some(ConfigFormat::Json);
+    }
+    // Sponge and some proxies use HOCON (`key { ... }`, `key = value`)
+    if content_lines(content).any(opens_hocon_object) {
+        return Some(ConfigFormat::Hocon);
+    }
+    if looks_like_toml(content) {
+        return Some(ConfigFormat::Toml);
+    }
+    if looks_like_properties(content) {
+        return Some(ConfigFormat::Properties);
+    }
+    // Bukkit's files (bukkit.yml, plugin configs) and Paper's (paper-global.yml) are both
+    // plain YAML documents; a bare scalar means this wasn't YAML at all.
+    match serde_yaml::from_str::<YamlValue>(content) {
+        Ok(YamlValue::Mapping(_) | YamlValue::Sequence(_)) => Some(ConfigFormat::Yaml),
+        _ => None,
+    }
+}
+
+/// Whether `content` can be read and written back as `format` without losing its syntax.
+fn fits(content: &str, format: &ConfigFormat, sniffed: Option<&ConfigFormat>) -> bool {
+    match format {
+        ConfigFormat::Json => serde_json::from_str::<serde_json::Value>(content).is_ok(),
+        ConfigFormat::Toml => ::toml::from_str::<::toml::Value>(content).is_ok(),
+        // JSON is valid YAML too, but saving it as YAML would change its syntax
+        ConfigFormat::Yaml => match sniffed {
+            Some(ConfigFormat::Yaml) => true,
+            None => matches!(serde_yaml::from_str::<YamlValue>(content), Ok(YamlValue::Null)),
+            _ => false,
+        },
+        ConfigFormat::Properties => matches!(sniffed, None | Some(ConfigFormat::Properties)),
+        // Plain `key = value` lines and JSON are HOCON too
+        ConfigFormat::Hocon => parse_hocon(content).is_ok(),
+        ConfigFormat::Nbt => false,
+    }
+}
+
+/// The format of a file that isn't text: only NBT is accepted, and only when no other
+/// format was expected.
+fn nbt_format(bytes: &[u8], expected: Option<ConfigFormat>, rel_path: &str) -> Result<ConfigFormat> {
+    let is_nbt = read_nbt(bytes).is_ok();
+    match expected {
+        None | Some(ConfigFormat::Nbt) if is_nbt => Ok(ConfigFormat::Nbt),
+        Some(ConfigFormat::Nbt) | None => Err(anyhow!("{} is not valid NBT", rel_path)),
+        Some(format) if is_nbt => {
+            Err(anyhow!("{} looks like Nbt, not {:?}; refusing to rewrite it", rel_path, format))
+        }
+        Some(format) => Err(anyhow!("{} is not valid {:?}", rel_path, format)),
+    }
+}
+
+/// Detects the format of `rel_path` from its content, falling back to its extension for new
+/// files. When `claimed` is given it is only accepted if the existing content fits it.
+pub async fn detect_config_format(instance_path: &Path, rel_path: &str, claimed: Option<ConfigFormat>) -> Result<ConfigFormat> {
+    let expected = claimed.clone().or_else(|| format_for_path(rel_path));
+
+    let bytes = match fs::read(instance_path.join(rel_path)).await {
+        Ok(bytes) if !bytes.is_empty() => bytes,
+        _ => return expected.ok_or_else(|| anyhow!("Cannot tell the format of {}", rel_path)),
+    };
+    // NBT is binary, and usually compressed, so it never reads as text
+    let content = match String::from_utf8(bytes) {
+        Ok(content) if expected != Some(ConfigFormat::Nbt) => content,
+        Ok(content) => return nbt_format(content.as_bytes(), expected, rel_path),
+        Err(e) => return nbt_format(e.as_bytes(), expected, rel_path),
+    };
+    if content.trim().is_empty() {
+        return expected.ok_or_else(|| anyhow!("Cannot tell the format of {}", rel_path));
+    }
+    let sniffed = sniff(&content);
+
+    match (expected, sniffed) {
+        (Some(format), sniffed) if fits(&content, &format, sniffed.as_ref()) => Ok(format),
+        (_, Some(found)) if claimed.is_none() => Ok(found),
+        (Some(format), Some(found)) => {
+            Err(anyhow!("{} looks like {:?}, not {:?}; refusing to rewrite it", rel_path, found, format))
+        }
+        (Some(format), None) => Err(anyhow!("{} is not valid {:?}", rel_path, format)),
+        (None, _) => Err(anyhow!("Cannot tell the format of {}", rel_path)),
+    }
+}
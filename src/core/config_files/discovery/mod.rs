@@ -0,0 +1,177 @@
+mod rules;
+
+pub use rules::config_owner;
+
+use rules::{ROOT_FILES, config_format, owner_dirs, server_configs};
+use std::collections::BTreeMap;
+use std::path::Path;
+use tokio::fs;
+use super::types::{ConfigFile, ConfigFormat, ConfigGroup, ConfigOwnerKind, ConfigTreeNode};
+
+/// Forge and NeoForge mods nest their configs (`config/<modid>/common.toml`), but never deeply.
+const MAX_CONFIG_DEPTH: usize = 4;
+/// Stops the walk on instances whose config folder is full of generated data.
+const MAX_CONFIG_FILES: usize = 2000;
+/// Larger files are data, not something to edit in a form.
+const MAX_CONFIG_FILE_SIZE: u64 = 2 * 1024 * 1024;
+
+/// The server's own configs, then one group per mod and plugin. Only reads the top level
+/// of each folder; [`list_group_configs`] walks a group's folders when it is opened.
+pub async fn list_config_groups(instance_path: &Path) -> Vec<ConfigGroup> {
+    let mut server_roots = vec!["server.properties".to_string()];
+    for (file, _) in &ROOT_FILES {
+        if instance_path.join(file).exists() {
+            server_roots.push(file.to_string());
+        }
+    }
+    let mut groups = vec![ConfigGroup { kind: ConfigOwnerKind::Server, owner: "server".to_string(), roots: server_roots }];
+
+    let mut owned: BTreeMap<(ConfigOwnerKind, String), Vec<String>> = BTreeMap::new();
+    for (dir, kind) in owner_dirs(instance_path).await {
+        let Ok(mut entries) = fs::read_dir(instance_path.join(&dir)).await else {
+            continue;
+        };
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.starts_with('.') {
+                continue;
+            }
+            let is_dir = entry.file_type().await.is_ok_and(|t| t.is_dir());
+            let owner = match kind {
+                ConfigOwnerKind::Plugin if is_dir => name.clone(),
+                // Loose files in plugins/ are the plugin jars themselves
+                ConfigOwnerKind::Plugin => continue,
+                _ if is_dir => name.to_lowercase(),
+                _ if config_format(&name, kind).is_some() => config_owner(&name),
+                _ => continue,
+            };
+            owned.entry((kind, owner)).or_default().push(format!("{}/{}", dir, name));
+        }
+    }
+
+    groups.extend(owned.into_iter().map(|((kind, owner), mut roots)| {
+        roots.sort();
+        ConfigGroup { kind, owner, roots }
+    }));
+    groups
+}
+
+/// Every config of one group from [`list_config_groups`], nested folders included.
+pub async fn list_group_configs(instance_path: &Path, kind: ConfigOwnerKind, owner: &str) -> Vec<ConfigFile> {
+    if kind == ConfigOwnerKind::Server {
+        return server_configs(instance_path);
+    }
+    let Some(group) = list_config_groups(instance_path).await.into_iter().find(|g| g.kind == kind && g.owner == owner) else {
+        return Vec::new();
+    };
+    let mut configs = walk_roots(instance_path, &group.roots, kind, MAX_CONFIG_FILES).await;
+    configs.sort_by(|a, b| a.path.cmp(&b.path));
+    configs
+}
+
+pub async fn list_available_configs(instance_path: &Path, _mod_loader: Option<&str>) -> Vec<ConfigFile> {
+    let mut configs = server_configs(instance_path);
+
+    let mut owned = Vec::new();
+    for group in list_config_groups(instance_path).await {
+        if group.kind == ConfigOwnerKind::Server {
+            continue;
+        }
+        let limit = MAX_CONFIG_FILES.saturating_sub(owned.len());
+        if limit == 0 {
+            break;
+        }
+        owned.extend(walk_roots(instance_path, &group.roots, group.kind, limit).await);
+    }
+    owned.sort_by(|a, b| a.path.cmp(&b.path));
+    configs.extend(owned);
+
+    configs
+}
+
+/// Editable files at or below `roots`, at most `limit` of them. Names drop the folder the
+/// roots sit in but keep subfolders, since several mods ship a `common.toml`.
+async fn walk_roots(instance_path: &Path, roots: &[String], kind: ConfigOwnerKind, limit: usize) -> Vec<ConfigFile> {
+    let mut configs = Vec::new();
+    let display_name = |rel_path: &str| rel_path.split_once('/').map_or(rel_path, |(_, rest)| rest).to_string();
+    let mut pending: Vec<(String, usize)> = Vec::new();
+
+    for root in roots {
+        let Ok(metadata) = fs::metadata(instance_path.join(root)).await else {
+            continue;
+        };
+        if metadata.is_dir() {
+            pending.push((root.clone(), 1));
+        } else if metadata.len() <= MAX_CONFIG_FILE_SIZE
+            && let Some(format) = config_format(root, kind)
+        {
+            configs.push(ConfigFile { name: display_name(root), path: root.clone(), format });
+        }
+    }
+
+    while let Some((rel_dir, depth)) = pending.pop() {
+        if configs.len() >= limit {
+            break;
+        }
+        let Ok(mut entries) = fs::read_dir(instance_path.join(&rel_dir)).await else {
+            continue;
+        };
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let Ok(metadata) = entry.metadata().await else {
+                continue;
+            };
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            let rel_path = format!("{}/{}", rel_dir, file_name);
+
+            if metadata.is_dir() {
+                if depth + 1 < MAX_CONFIG_DEPTH {
+                    pending.push((rel_path, depth + 1));
+                }
+            } else if metadata.len() <= MAX_CONFIG_FILE_SIZE
+                && let Some(format) = config_format(&file_name, kind)
+            {
+                configs.push(ConfigFile { name: display_name(&rel_path), path: rel_path, format });
+                if configs.len() >= limit {
+                    break;
+                }
+            }
+        }
+    }
+
+    configs
+}
+
+/// [`list_available_configs`] grouped into folders, folders first.
+pub async fn list_config_tree(instance_path: &Path, mod_loader: Option<&str>) -> Vec<ConfigTreeNode> {
+    let mut root = Vec::new();
+    for config in list_available_configs(instance_path, mod_loader).await {
+        insert_node(&mut root, "", &config.path, config.format);
+    }
+    sort_nodes(&mut root);
+    root
+}
+
+fn insert_node(nodes: &mut Vec<ConfigTreeNode>, parent: &str, rest: &str, format: ConfigFormat) {
+    let path = |name: &str| if parent.is_empty() { name.to_string() } else { format!("{}/{}", parent, name) };
+    match rest.split_once('/') {
+        None => nodes.push(ConfigTreeNode { name: rest.to_string(), path: path(rest), format: Some(format), children: Vec::new() }),
+        Some((dir, rest)) => {
+            let index = match nodes.iter().position(|n| n.format.is_none() && n.name == dir) {
+                Some(index) => index,
+                None => {
+                    nodes.push(ConfigTreeNode { name: dir.to_string(), path: path(dir), format: None, children: Vec::new() });
+                    nodes.len() - 1
+                }
+            };
+            let dir_path = nodes[index].path.clone();
+            insert_node(&mut nodes[index].children, &dir_path, rest, format);
+        }
+    }
+}
+
+fn sort_nodes(nodes: &mut [ConfigTreeNode]) {
+    nodes.sort_by(|a, b| a.format.is_some().cmp(&b.format.is_some()).then_with(|| a.name.cmp(&b.name)));
+    for node in nodes {
+        sort_nodes(&mut node.children);
+    }
+}
@@ -0,0 +1,78 @@
+use std::path::Path;
+use super::super::detect::format_for_path;
+use super::super::types::{ConfigFile, ConfigFormat, ConfigOwnerKind};
+use crate::server_properties::read_server_properties;
+
+/// Common files in the instance root.
+pub(super) const ROOT_FILES: [(&str, ConfigFormat); 10] = [
+    ("bukkit.yml", ConfigFormat::Yaml),
+    ("spigot.yml", ConfigFormat::Yaml),
+    ("paper.yml", ConfigFormat::Yaml),
+    ("purpur.yml", ConfigFormat::Yaml),
+    ("pufferfish.yml", ConfigFormat::Yaml),
+    ("commands.yml", ConfigFormat::Yaml),
+    ("help.yml", ConfigFormat::Yaml),
+    ("permissions.yml", ConfigFormat::Yaml),
+    ("fabric-loader.json", ConfigFormat::Json),
+    ("velocity.toml", ConfigFormat::Toml),
+];
+
+/// What mods append to their config names, e.g. `create-common.toml` or `jei_client.json`.
+const ROLE_SUFFIXES: [&str; 7] = ["common", "client", "server", "startup", "config", "settings", "options"];
+
+/// The mod a config file in `config/` belongs to, judged by its name: `create-common.toml`
+/// and `create/` both belong to `create`.
+pub fn config_owner(file_name: &str) -> String {
+    let stem = file_name.rsplit_once('.').map_or(file_name, |(stem, _)| stem).to_lowercase();
+    ROLE_SUFFIXES
+        .iter()
+        .find_map(|role| {
+            let owner = stem.strip_suffix(role)?.strip_suffix(['-', '_'])?;
+            (!owner.is_empty()).then(|| owner.to_string())
+        })
+        .unwrap_or(stem)
+}
+
+/// Folders whose entries belong to mods or plugins: `config/`, Forge's per-world
+/// `<world>/serverconfig/` and the plugins' data folders.
+pub(super) async fn owner_dirs(instance_path: &Path) -> Vec<(String, ConfigOwnerKind)> {
+    let world = read_server_properties(instance_path)
+        .await
+        .ok()
+        .and_then(|props| props.get("level-name").cloned())
+        .filter(|name| !name.is_empty() && !name.contains(['/', '\\']) && name != "..")
+        .unwrap_or_else(|| "world".to_string());
+    vec![
+        ("config".to_string(), ConfigOwnerKind::Mod),
+        (format!("{}/serverconfig", world), ConfigOwnerKind::Mod),
+        ("plugins".to_string(), ConfigOwnerKind::Plugin),
+    ]
+}
+
+/// Whether a file is listed as a config. Plugin folders hold binary data files next to
+/// their configs, so only text formats count there.
+pub(super) fn config_format(file_name: &str, kind: ConfigOwnerKind) -> Option<ConfigFormat> {
+    format_for_path(file_name)
+        .filter(|f| *f != ConfigFormat::Properties)
+        .filter(|f| kind != ConfigOwnerKind::Plugin || f.is_text())
+}
+
+pub(super) fn server_configs(instance_path: &Path) -> Vec<ConfigFile> {
+    let mut configs = vec![
+        ConfigFile {
+            name: "server.properties".to_string(),
+            path: "server.properties".to_string(),
+            format: ConfigFormat::Properties,
+        },
+    ];
+    for (file, format) in ROOT_FILES {
+        if instance_path.join(file).exists() {
+            configs.push(ConfigFile {
+                name: file.to_string(),
+                path: file.to_string(),
+                format,
+            });
+        }
+    }
+    configs
+}
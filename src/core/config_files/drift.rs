@@ -0,0 +1,138 @@
+use std::collections::{BTreeMap, HashMap};
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use uuid::Uuid;
+use super::types::ConfigFormat;
+
+/// Keys that are expected to differ between servers running side by side.
+const INSTANCE_SPECIFIC_KEYS: &[&str] = &[
+    "server-port",
+    "server-ip",
+    "query.port",
+    "rcon.port",
+    "rcon.password",
+    "motd",
+    "level-seed",
+    "bind",
+];
+
+/// A key whose value differs between the compared instances.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DriftEntry {
+    /// The path joined with dots, for display.
+    pub key: String,
+    /// The keys leading to the value from the root of the file. Keys may contain dots
+    /// themselves, so syncing goes by the path rather than `key`.
+    pub path: Vec<String>,
+    /// The value in each instance, `None` where the key is missing. Lists and other
+    /// non-text values are shown as JSON.
+    pub values: HashMap<Uuid, Option<String>>,
+    /// True for ports, addresses and similar keys that usually differ on purpose.
+    pub instance_specific: bool,
+}
+
+/// The differing keys of one config file across a group of instances.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigDrift {
+    pub path: String,
+    pub format: ConfigFormat,
+    pub entries: Vec<DriftEntry>,
+}
+
+/// Collects the values of `value` below tables, keyed by their path. Lists are kept
+/// whole, since their items have no stable identity to compare by.
+fn collect_leaves<'a>(path: &mut Vec<String>, value: &'a JsonValue, leaves: &mut BTreeMap<Vec<String>, &'a JsonValue>) {
+    match value {
+        JsonValue::Object(map) => {
+            for (key, child) in map {
+                path.push(key.clone());
+                collect_leaves(path, child, leaves);
+                path.pop();
+            }
+        }
+        _ if !path.is_empty() => {
+            leaves.insert(path.clone(), value);
+        }
+        _ => {}
+    }
+}
+
+fn display_value(value: &JsonValue) -> String {
+    match value {
+        JsonValue::String(s) => s.clone(),
+        JsonValue::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+/// Returns the values that are not identical across all given configs, sorted by path.
+pub fn diff_configs(configs: &[(Uuid, JsonValue)]) -> Vec<DriftEntry> {
+    let leaves: Vec<(Uuid, BTreeMap<Vec<String>, &JsonValue>)> = configs
+        .iter()
+        .map(|(id, config)| {
+            let mut leaves = BTreeMap::new();
+            collect_leaves(&mut Vec::new(), config, &mut leaves);
+            (*id, leaves)
+        })
+        .collect();
+    let mut paths: Vec<&Vec<String>> = leaves.iter().flat_map(|(_, l)| l.keys()).collect();
+    paths.sort();
+    paths.dedup();
+
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let found: Vec<Option<&JsonValue>> = leaves.iter().map(|(_, l)| l.get(path).copied()).collect();
+            if found.iter().all(|v| *v == found[0]) {
+                return None;
+            }
+            let key = path.join(".");
+            Some(DriftEntry {
+                instance_specific: INSTANCE_SPECIFIC_KEYS.contains(&key.as_str()),
+                key,
+                path: path.clone(),
+                values: leaves
+                    .iter()
+                    .zip(found)
+                    .map(|((id, _), value)| (*id, value.map(display_value)))
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+/// The value at `path` in `root`, if every key along it exists.
+pub fn value_at<'a>(root: &'a JsonValue, path: &[String]) -> Option<&'a JsonValue> {
+    path.iter().try_fold(root, |value, key| value.as_object()?.get(key))
+}
+
+/// Sets the value at `path` in `root`, creating tables along the way, or removes it with
+/// `None`. Values of other keys, and their types, are left as they are.
+pub fn set_value_at(root: &mut JsonValue, path: &[String], value: Option<JsonValue>) {
+    let Some((last, parents)) = path.split_last() else {
+        return;
+    };
+    let Some(value) = value else {
+        let parent = parents.iter().try_fold(root, |value, key| value.as_object_mut()?.get_mut(key));
+        if let Some(map) = parent.and_then(JsonValue::as_object_mut) {
+            map.remove(last);
+        }
+        return;
+    };
+
+    let mut table = ensure_table(root);
+    for key in parents {
+        table = ensure_table(table.entry(key.clone()).or_insert(JsonValue::Null));
+    }
+    table.insert(last.clone(), value);
+}
+
+fn ensure_table(value: &mut JsonValue) -> &mut serde_json::Map<String, JsonValue> {
+    if !value.is_object() {
+        *value = JsonValue::Object(serde_json::Map::new());
+    }
+    match value {
+        JsonValue::Object(map) => map,
+        _ => unreachable!("replaced by a table above"),
+    }
+}
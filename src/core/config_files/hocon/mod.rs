@@ -0,0 +1,80 @@
+//! Logic for HOCON (.conf) files, used by Sponge and some plugins and proxies.
+//!
+//! Parses into JSON: objects, arrays, quoted, triple-quoted and unquoted strings, dotted
+//! keys, `+=` and duplicate objects merging. Substitutions (`${a.b}`) are kept as text
+//! and written back unquoted. Saving an existing file patches the changed values into its
+//! text, so comments and layout are kept.
+
+use anyhow::Result;
+use parser::Parser;
+use serde_json::{Map, Value as JsonValue};
+
+mod parser;
+mod patch;
+mod tokens;
+mod write;
+
+pub use patch::patch_hocon;
+pub use write::serialize_hocon;
+
+/// Nesting deeper than this is treated as a broken file.
+const MAX_DEPTH: usize = 256;
+
+pub fn parse_hocon(content: &str) -> Result<JsonValue> {
+    parse_with_layout(content).map(|(value, _)| value)
+}
+
+/// Parses `content`, keeping the parser for where each field and object was written.
+fn parse_with_layout(content: &str) -> Result<(JsonValue, Parser)> {
+    let mut parser = Parser::new(content);
+    parser.skip_space(true);
+    let root = if parser.peek() == Some('{') {
+        parser.pos += 1;
+        let object = parser.fields(Some('}'), 0, Some(&[]))?;
+        parser.objects.push((Vec::new(), parser.pos - 1));
+        parser.skip_space(true);
+        object
+    } else {
+        let object = parser.fields(None, 0, Some(&[]))?;
+        parser.objects.push((Vec::new(), parser.chars.len()));
+        object
+    };
+    if parser.peek().is_some() {
+        return Err(parser.error("unexpected content after the root object"));
+    }
+    Ok((JsonValue::Object(root), parser))
+}
+
+/// Sets `path` in `object`, merging into an existing object, or appending for `+=`.
+fn insert_path(object: &mut Map<String, JsonValue>, path: &[String], value: JsonValue, append: bool) {
+    let Some((key, rest)) = path.split_first() else {
+        return;
+    };
+    if !rest.is_empty() {
+        let entry = object
+            .entry(key.clone())
+            .or_insert_with(|| JsonValue::Object(Map::new()));
+        if !entry.is_object() {
+            *entry = JsonValue::Object(Map::new());
+        }
+        if let JsonValue::Object(inner) = entry {
+            insert_path(inner, rest, value, append);
+        }
+        return;
+    }
+    match (object.get_mut(key), value) {
+        (Some(JsonValue::Array(items)), value) if append => items.push(value),
+        (Some(JsonValue::Object(existing)), JsonValue::Object(new)) => {
+            for (k, v) in new {
+                insert_path(existing, &[k], v, false);
+            }
+        }
+        (_, value) if append => {
+            object.insert(key.clone(), JsonValue::Array(vec![value]));
+        }
+        (_, value) => {
+            object.insert(key.clone(), value);
+        }
+    }
+}
+
@@ -0,0 +1,191 @@
+use super::{MAX_DEPTH, insert_path};
+use anyhow::{Result, anyhow};
+use serde_json::{Map, Value as JsonValue};
+
+/// Where a field was written, in character positions of the parsed text.
+#[derive(Debug, Clone)]
+pub(super) struct FieldSpan {
+    pub path: Vec<String>,
+    pub key_start: usize,
+    pub value_start: usize,
+    pub value_end: usize,
+}
+
+pub(super) struct Parser {
+    pub chars: Vec<char>,
+    pub pos: usize,
+    pub line: usize,
+    /// Every field outside arrays, in the order written.
+    pub fields: Vec<FieldSpan>,
+    /// Objects outside arrays and the position of their closing brace.
+    pub objects: Vec<(Vec<String>, usize)>,
+}
+
+impl Parser {
+    pub fn new(content: &str) -> Self {
+        Parser {
+            chars: content.chars().collect(),
+            pos: 0,
+            line: 1,
+            fields: Vec::new(),
+            objects: Vec::new(),
+        }
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    pub fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    pub fn starts_with(&self, s: &str) -> bool {
+        s.chars().enumerate().all(|(i, c)| self.peek_at(i) == Some(c))
+    }
+
+    pub fn error(&self, message: &str) -> anyhow::Error {
+        anyhow!("HOCON error on line {}: {}", self.line, message)
+    }
+
+    pub fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    pub fn at_comment(&self) -> bool {
+        self.peek() == Some('#') || self.starts_with("//")
+    }
+
+    /// Skips whitespace and comments; newlines too when `newlines` is set.
+    pub fn skip_space(&mut self, newlines: bool) {
+        while let Some(c) = self.peek() {
+            if self.at_comment() {
+                while self.peek().is_some_and(|c| c != '\n') {
+                    self.pos += 1;
+                }
+            } else if c == '\n' && !newlines {
+                break;
+            } else if c.is_whitespace() || c == '\u{feff}' {
+                self.bump();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Fields up to `close` (or the end of the input for the root), separated by commas or newlines.
+    /// `prefix` is the object's path, or `None` inside arrays, where fields aren't recorded.
+    pub fn fields(
+        &mut self,
+        close: Option<char>,
+        depth: usize,
+        prefix: Option<&[String]>,
+    ) -> Result<Map<String, JsonValue>> {
+        if depth > MAX_DEPTH {
+            return Err(self.error("nested too deeply"));
+        }
+        let mut object = Map::new();
+        loop {
+            self.skip_space(true);
+            while self.peek() == Some(',') {
+                self.bump();
+                self.skip_space(true);
+            }
+            match (self.peek(), close) {
+                (None, None) => return Ok(object),
+                (None, Some(close)) => return Err(self.error(&format!("missing '{}'", close))),
+                (Some(c), Some(close)) if c == close => {
+                    self.bump();
+                    return Ok(object);
+                }
+                _ => {}
+            }
+            if self.starts_with("include") && self.peek_at(7).is_some_and(|c| c == ' ' || c == '"') {
+                return Err(self.error("includes are not supported"));
+            }
+
+            let key_start = self.pos;
+            let path = self.key()?;
+            let full_path = prefix.map(|prefix| [prefix, path.as_slice()].concat());
+            self.skip_space(false);
+            let append = self.starts_with("+=");
+            match self.peek() {
+                Some('{') => {}
+                Some('=' | ':') => {
+                    self.bump();
+                    self.skip_space(false);
+                }
+                Some('+') if append => {
+                    self.pos += 2;
+                    self.skip_space(false);
+                }
+                _ => return Err(self.error(&format!("expected '=', ':' or '{{' after \"{}\"", path.join(".")))),
+            }
+            let value_start = self.pos;
+            let value = self.value(depth + 1, full_path.as_deref())?;
+            if let Some(path) = full_path {
+                // Values end before any whitespace a trailing comment is set off by
+                let value_end = (value_start..self.pos)
+                    .rev()
+                    .find(|&i| !self.chars[i].is_whitespace())
+                    .map_or(value_start, |i| i + 1);
+                self.fields.push(FieldSpan { path, key_start, value_start, value_end });
+            }
+            insert_path(&mut object, &path, value, append);
+
+            self.skip_space(false);
+            match self.peek() {
+                Some(',' | '\n') | None => {}
+                Some(c) if Some(c) == close => {}
+                Some(_) if self.at_comment() => {}
+                Some(c) => return Err(self.error(&format!("unexpected '{}'", c))),
+            }
+        }
+    }
+
+    fn value(&mut self, depth: usize, path: Option<&[String]>) -> Result<JsonValue> {
+        if depth > MAX_DEPTH {
+            return Err(self.error("nested too deeply"));
+        }
+        match self.peek() {
+            Some('{') => {
+                self.bump();
+                let object = self.fields(Some('}'), depth, path)?;
+                if let Some(path) = path {
+                    self.objects.push((path.to_vec(), self.pos - 1));
+                }
+                Ok(JsonValue::Object(object))
+            }
+            Some('[') => {
+                self.bump();
+                self.array(depth)
+            }
+            Some(_) => self.scalar(),
+            None => Err(self.error("expected a value")),
+        }
+    }
+
+    fn array(&mut self, depth: usize) -> Result<JsonValue> {
+        let mut items = Vec::new();
+        loop {
+            self.skip_space(true);
+            while self.peek() == Some(',') {
+                self.bump();
+                self.skip_space(true);
+            }
+            match self.peek() {
+                Some(']') => {
+                    self.bump();
+                    return Ok(JsonValue::Array(items));
+                }
+                None => return Err(self.error("missing ']'")),
+                _ => items.push(self.value(depth + 1, None)?),
+            }
+        }
+    }
+}
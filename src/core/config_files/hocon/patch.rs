@@ -0,0 +1,168 @@
+use super::parser::{FieldSpan, Parser};
+use super::write::{serialize_hocon, write_key, write_value};
+use super::{parse_hocon, parse_with_layout};
+use serde_json::Value as JsonValue;
+use std::cmp::Reverse;
+use tracing::debug;
+
+/// Replaces the characters `start..end` of the original text with `text`.
+struct Edit {
+    start: usize,
+    end: usize,
+    text: String,
+}
+
+/// Writes `value` over `content`, touching only the fields that changed so comments and
+/// layout stay. Falls back to writing the file out in full when `content` doesn't parse
+/// or a change can't be placed, such as a key defined in several places.
+pub fn patch_hocon(content: &str, value: &JsonValue) -> String {
+    try_patch(content, value).unwrap_or_else(|| {
+        debug!("HOCON change couldn't be patched in place; rewriting the file");
+        serialize_hocon(value)
+    })
+}
+
+fn try_patch(content: &str, value: &JsonValue) -> Option<String> {
+    let (old, layout) = parse_with_layout(content).ok()?;
+    let mut edits = Vec::new();
+    diff(&layout, &[], &old, value, &mut edits)?;
+
+    // Applied back to front so earlier positions stay valid; inserts at the same spot keep their order
+    let mut edits: Vec<(usize, Edit)> = edits.into_iter().enumerate().collect();
+    edits.sort_by_key(|(i, e)| Reverse((e.start, *i)));
+    if edits.windows(2).any(|pair| pair[1].1.end > pair[0].1.start) {
+        return None;
+    }
+    let mut chars = layout.chars;
+    for (_, edit) in edits {
+        chars.splice(edit.start..edit.end, edit.text.chars());
+    }
+    let patched: String = chars.into_iter().collect();
+    (parse_hocon(&patched).ok()? == *value).then_some(patched)
+}
+
+fn diff(layout: &Parser, path: &[String], old: &JsonValue, new: &JsonValue, edits: &mut Vec<Edit>) -> Option<()> {
+    if old == new {
+        return Some(());
+    }
+    let (JsonValue::Object(old), JsonValue::Object(new)) = (old, new) else {
+        edits.push(replace(layout, path, new)?);
+        return Some(());
+    };
+    let child = |key: &str| [path, &[key.to_string()]].concat();
+    for key in old.keys().filter(|k| !new.contains_key(*k)) {
+        for span in definitions(layout, &child(key)) {
+            edits.push(remove(&layout.chars, span));
+        }
+    }
+    for (key, value) in new {
+        match old.get(key) {
+            Some(old_value) => diff(layout, &child(key), old_value, value, edits)?,
+            None => edits.push(insert(layout, path, key, value)?),
+        }
+    }
+    Some(())
+}
+
+/// The fields that define `path` or something under it, leaving out those nested in another.
+fn definitions<'a>(layout: &'a Parser, path: &[String]) -> Vec<&'a FieldSpan> {
+    let under: Vec<&FieldSpan> = layout.fields.iter().filter(|f| f.path.starts_with(path)).collect();
+    under
+        .iter()
+        .filter(|f| {
+            !under.iter().any(|outer| {
+                !std::ptr::eq(*outer, **f) && outer.key_start <= f.key_start && f.value_end <= outer.value_end
+            })
+        })
+        .copied()
+        .collect()
+}
+
+fn line_start(chars: &[char], pos: usize) -> usize {
+    chars[..pos].iter().rposition(|&c| c == '\n').map_or(0, |i| i + 1)
+}
+
+fn indentation(chars: &[char], pos: usize) -> String {
+    chars[line_start(chars, pos)..].iter().take_while(|c| matches!(c, ' ' | '\t')).collect()
+}
+
+fn render(value: &JsonValue, indent: &str) -> String {
+    let mut out = String::new();
+    write_value(&mut out, value, indent.len() / 4);
+    out
+}
+
+fn replace(layout: &Parser, path: &[String], value: &JsonValue) -> Option<Edit> {
+    let [span] = definitions(layout, path)[..] else {
+        return None;
+    };
+    if span.path != path {
+        return None;
+    }
+    let mut text = render(value, &indentation(&layout.chars, span.key_start));
+    // `key { ... }` has no separator, which any other value needs
+    let separated = layout.chars[span.key_start..span.value_start].iter().any(|c| matches!(c, '=' | ':'));
+    if !separated && !value.is_object() {
+        text.insert_str(0, "= ");
+    }
+    Some(Edit { start: span.value_start, end: span.value_end, text })
+}
+
+/// Removes a field along with its line when nothing else is written on it.
+fn remove(chars: &[char], span: &FieldSpan) -> Edit {
+    let start = line_start(chars, span.key_start);
+    let line_end = chars[span.value_end..].iter().position(|&c| c == '\n').map(|i| span.value_end + i);
+    let rest: String = chars[span.value_end..line_end.unwrap_or(chars.len())].iter().collect();
+    let rest = rest.trim_start_matches([' ', '\t', ',']);
+    let alone = chars[start..span.key_start].iter().all(|c| c.is_whitespace())
+        && (rest.is_empty() || rest.starts_with('#') || rest.starts_with("//"));
+    if alone {
+        let end = line_end.map_or(chars.len(), |i| i + 1);
+        return Edit { start, end, text: String::new() };
+    }
+    let commas = chars[span.value_end..].iter().take_while(|c| matches!(c, ' ' | '\t' | ',')).count();
+    Edit { start: span.key_start, end: span.value_end + commas, text: String::new() }
+}
+
+/// Adds `key` to the object at `path`, or with a dotted key to the nearest enclosing
+/// object written out in the file.
+fn insert(layout: &Parser, path: &[String], key: &str, value: &JsonValue) -> Option<Edit> {
+    let chars = &layout.chars;
+    let (depth, close) = (0..=path.len()).rev().find_map(|n| {
+        let object = layout.objects.iter().rev().find(|(p, _)| p.as_slice() == &path[..n])?;
+        Some((n, object.1))
+    })?;
+    let sibling = layout
+        .fields
+        .iter()
+        .filter(|f| f.path.len() > depth && f.path.starts_with(&path[..depth]) && f.key_start < close)
+        .max_by_key(|f| f.key_start);
+    let indent = match sibling {
+        Some(sibling) => indentation(chars, sibling.key_start),
+        None if close == chars.len() => String::new(),
+        None => format!("{}    ", indentation(chars, close)),
+    };
+
+    let mut field = String::new();
+    for (i, segment) in path[depth..].iter().map(String::as_str).chain([key]).enumerate() {
+        if i > 0 {
+            field.push('.');
+        }
+        write_key(&mut field, segment);
+    }
+    field.push_str(if value.is_object() { " " } else { " = " });
+    field.push_str(&render(value, &indent));
+
+    let line = line_start(chars, close);
+    if close == chars.len() {
+        let newline = if chars.last().is_some_and(|&c| c != '\n') { "\n" } else { "" };
+        Some(Edit { start: close, end: close, text: format!("{}{}{}\n", newline, indent, field) })
+    } else if chars[line..close].iter().all(|c| c.is_whitespace()) {
+        Some(Edit { start: line, end: line, text: format!("{}{}\n", indent, field) })
+    } else {
+        // Right after the last field, or the opening brace of an empty object
+        let at = chars[..close].iter().rposition(|c| !c.is_whitespace()).map_or(close, |i| i + 1);
+        let text = if chars[at - 1] == '{' { format!(" {}", field) } else { format!(", {}", field) };
+        Some(Edit { start: at, end: at, text })
+    }
+}
@@ -0,0 +1,180 @@
+use super::parser::Parser;
+use anyhow::Result;
+use serde_json::Value as JsonValue;
+
+impl Parser {
+    /// A possibly dotted key, e.g. `a.b."c.d"`.
+    pub(super) fn key(&mut self) -> Result<Vec<String>> {
+        let mut segments = Vec::new();
+        let mut current = String::new();
+        let mut quoted = false;
+        loop {
+            match self.peek() {
+                Some('"') => {
+                    current.push_str(&self.quoted()?);
+                    quoted = true;
+                }
+                Some('.') => {
+                    self.bump();
+                    segments.push(std::mem::take(&mut current));
+                    quoted = false;
+                }
+                Some(c) if is_unquoted_char(c) && !self.at_comment() => {
+                    current.push(c);
+                    self.bump();
+                }
+                // `+` only ends a key as part of `+=`
+                Some('+') if !self.starts_with("+=") => {
+                    current.push('+');
+                    self.bump();
+                }
+                _ => break,
+            }
+        }
+        if current.is_empty() && !quoted {
+            return Err(self.error("expected a key"));
+        }
+        segments.push(current);
+        Ok(segments)
+    }
+
+    /// A string, number, boolean or null, including unquoted text up to the end of the value.
+    /// Adjacent parts concatenate, e.g. `"a" b ${c}`.
+    pub(super) fn scalar(&mut self) -> Result<JsonValue> {
+        let mut text = String::new();
+        // Whitespace between parts is kept, but not after the last one
+        let mut pending = String::new();
+        let mut any_quoted = false;
+        loop {
+            let part = if self.starts_with("\"\"\"") {
+                any_quoted = true;
+                self.triple_quoted()?
+            } else if self.peek() == Some('"') {
+                any_quoted = true;
+                self.quoted()?
+            } else if self.starts_with("${") {
+                let mut substitution = String::new();
+                while let Some(c) = self.bump() {
+                    substitution.push(c);
+                    if c == '}' {
+                        break;
+                    }
+                }
+                substitution
+            } else {
+                match self.peek() {
+                    Some(c) if c == '\n' || self.at_comment() => break,
+                    Some(c) if c.is_whitespace() => {
+                        pending.push(c);
+                        self.bump();
+                        continue;
+                    }
+                    Some(c) if is_unquoted_char(c) => {
+                        self.bump();
+                        c.to_string()
+                    }
+                    _ => break,
+                }
+            };
+            text.push_str(&std::mem::take(&mut pending));
+            text.push_str(&part);
+        }
+        if any_quoted {
+            return Ok(JsonValue::String(text));
+        }
+        if text.is_empty() {
+            return Err(self.error("expected a value"));
+        }
+        Ok(match text.as_str() {
+            "true" => JsonValue::Bool(true),
+            "false" => JsonValue::Bool(false),
+            "null" => JsonValue::Null,
+            _ => number(&text).unwrap_or(JsonValue::String(text)),
+        })
+    }
+
+    pub(super) fn quoted(&mut self) -> Result<String> {
+        self.bump();
+        let mut out = String::new();
+        loop {
+            match self.bump() {
+                Some('"') => return Ok(out),
+                Some('\\') => match self.bump() {
+                    Some('n') => out.push('\n'),
+                    Some('t') => out.push('\t'),
+                    Some('r') => out.push('\r'),
+                    Some('b') => out.push('\u{8}'),
+                    Some('f') => out.push('\u{c}'),
+                    Some('u') => {
+                        let hex: String = (0..4).filter_map(|_| self.bump()).collect();
+                        let c = u32::from_str_radix(&hex, 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                            .ok_or_else(|| self.error("invalid \\u escape"))?;
+                        out.push(c);
+                    }
+                    Some(c) => out.push(c),
+                    None => return Err(self.error("unterminated string")),
+                },
+                Some('\n') | None => return Err(self.error("unterminated string")),
+                Some(c) => out.push(c),
+            }
+        }
+    }
+
+    fn triple_quoted(&mut self) -> Result<String> {
+        self.pos += 3;
+        let mut out = String::new();
+        loop {
+            // Extra quotes before the closing three belong to the string
+            if self.starts_with("\"\"\"") && self.peek_at(3) != Some('"') {
+                self.pos += 3;
+                return Ok(out);
+            }
+            match self.bump() {
+                Some(c) => out.push(c),
+                None => return Err(self.error("unterminated \"\"\" string")),
+            }
+        }
+    }
+}
+
+/// Characters HOCON allows in unquoted keys and values, besides whitespace.
+pub(super) fn is_unquoted_char(c: char) -> bool {
+    !c.is_whitespace()
+        && !matches!(
+            c,
+            '$' | '"'
+                | '{'
+                | '}'
+                | '['
+                | ']'
+                | ':'
+                | '='
+                | ','
+                | '+'
+                | '#'
+                | '`'
+                | '^'
+                | '?'
+                | '!'
+                | '@'
+                | '*'
+                | '&'
+                | '\\'
+        )
+}
+
+fn number(text: &str) -> Option<JsonValue> {
+    if let Ok(n) = text.parse::<i64>() {
+        return Some(JsonValue::Number(n.into()));
+    }
+    // Rust also accepts "inf" and "NaN", which HOCON would read as strings
+    if !text.starts_with(|c: char| c.is_ascii_digit() || c == '-' || c == '.') {
+        return None;
+    }
+    text.parse::<f64>()
+        .ok()
+        .and_then(serde_json::Number::from_f64)
+        .map(JsonValue::Number)
+}
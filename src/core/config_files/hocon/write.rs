@@ -0,0 +1,79 @@
+use serde_json::{Map, Value as JsonValue};
+
+pub fn serialize_hocon(value: &JsonValue) -> String {
+    let mut out = String::new();
+    match value {
+        JsonValue::Object(object) => write_fields(&mut out, object, 0),
+        other => {
+            out.push_str("value = ");
+            write_value(&mut out, other, 0);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+pub(super) fn indent(out: &mut String, level: usize) {
+    out.push_str(&"    ".repeat(level));
+}
+
+pub(super) fn write_key(out: &mut String, key: &str) {
+    let plain = !key.is_empty() && key.chars().all(|c| c.is_alphanumeric() || matches!(c, '-' | '_'));
+    if plain {
+        out.push_str(key);
+    } else {
+        out.push_str(&JsonValue::from(key).to_string());
+    }
+}
+
+fn write_fields(out: &mut String, object: &Map<String, JsonValue>, level: usize) {
+    for (key, value) in object {
+        indent(out, level);
+        write_key(out, key);
+        // Objects read best as `key { ... }`, like Sponge writes them
+        out.push_str(if value.is_object() { " " } else { " = " });
+        write_value(out, value, level);
+        out.push('\n');
+    }
+}
+
+pub(super) fn write_value(out: &mut String, value: &JsonValue, level: usize) {
+    match value {
+        JsonValue::Object(object) if object.is_empty() => out.push_str("{}"),
+        JsonValue::Object(object) => {
+            out.push_str("{\n");
+            write_fields(out, object, level + 1);
+            indent(out, level);
+            out.push('}');
+        }
+        JsonValue::Array(items) if items.iter().all(|v| !v.is_object() && !v.is_array()) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write_value(out, item, level);
+            }
+            out.push(']');
+        }
+        JsonValue::Array(items) => {
+            out.push_str("[\n");
+            for item in items {
+                indent(out, level + 1);
+                write_value(out, item, level + 1);
+                out.push('\n');
+            }
+            indent(out, level);
+            out.push(']');
+        }
+        JsonValue::String(s) if is_substitution(s) => out.push_str(s),
+        JsonValue::String(s) => out.push_str(&JsonValue::from(s.as_str()).to_string()),
+        JsonValue::Number(n) => out.push_str(&n.to_string()),
+        JsonValue::Bool(b) => out.push_str(&b.to_string()),
+        JsonValue::Null => out.push_str("null"),
+    }
+}
+
+fn is_substitution(s: &str) -> bool {
+    s.starts_with("${") && s.ends_with('}') && !s[2..s.len() - 1].contains(['{', '}', '"', '\n'])
+}
@@ -5,8 +5,11 @@ use tokio::fs;
 use serde_json::Value as JsonValue;
 use serde_yaml::Value as YamlValue;
 use ::toml::Value as TomlValue;
+use super::detect::detect_config_format;
 use super::types::ConfigFormat;
-use super::{properties, yaml, toml, json};
+use super::{properties, yaml, toml, json, hocon, nbt};
+use crate::nbt::write_nbt;
+use crate::utils::write_atomic;
 
 pub async fn read_config_file(instance_path: &Path, rel_path: &str, format: ConfigFormat) -> Result<HashMap<String, String>> {
     let full_path = instance_path.join(rel_path);
@@ -14,6 +17,12 @@ pub async fn read_config_file(instance_path: &Path, rel_path: &str, format: Conf
         return Ok(HashMap::new());
     }
 
+    if format == ConfigFormat::Nbt {
+        let mut props = HashMap::new();
+        json::flatten_json("", &nbt::read_nbt_value(&full_path, rel_path).await?, &mut props);
+        return Ok(props);
+    }
+
     let content = fs::read_to_string(&full_path).await
         .context(format!("Failed to read config file: {}", rel_path))?;
 
@@ -39,6 +48,21 @@ pub async fn read_config_file(instance_path: &Path, rel_path: &str, format: Conf
             json::flatten_json("", &json, &mut props);
             Ok(props)
         }
+        ConfigFormat::Hocon => {
+            let json = hocon::parse_hocon(&content)?;
+            let mut props = HashMap::new();
+            json::flatten_json("", &json, &mut props);
+            Ok(props)
+        }
+        ConfigFormat::Nbt => unreachable!("NBT files are read as bytes above"),
+    }
+}
+
+/// An existing HOCON file gets the changes patched in, so its comments are kept.
+async fn hocon_text(full_path: &Path, value: &JsonValue) -> String {
+    match fs::read_to_string(full_path).await {
+        Ok(existing) => hocon::patch_hocon(&existing, value),
+        Err(_) => hocon::serialize_hocon(value),
     }
 }
 
@@ -48,6 +72,10 @@ pub async fn read_config_value(instance_path: &Path, rel_path: &str, format: Con
         return Ok(JsonValue::Null);
     }
 
+    if format == ConfigFormat::Nbt {
+        return nbt::read_nbt_value(&full_path, rel_path).await;
+    }
+
     let content = fs::read_to_string(&full_path).await
         .context(format!("Failed to read config file: {}", rel_path))?;
 
@@ -71,66 +99,87 @@ pub async fn read_config_value(instance_path: &Path, rel_path: &str, format: Con
             let json: JsonValue = serde_json::from_str(&content)?;
             Ok(json)
         }
+        ConfigFormat::Hocon => hocon::parse_hocon(&content),
+        ConfigFormat::Nbt => unreachable!("NBT files are read as bytes above"),
     }
 }
 
-pub async fn save_config_value(instance_path: &Path, rel_path: &str, format: ConfigFormat, value: JsonValue) -> Result<()> {
+/// Writes atomically; with `backup`, the previous file is kept as `<name>.bak`.
+pub async fn save_config_value(instance_path: &Path, rel_path: &str, format: ConfigFormat, value: JsonValue, backup: bool) -> Result<()> {
+    // Never rewrite an existing file in a different syntax than it is written in
+    let format = detect_config_format(instance_path, rel_path, Some(format)).await?;
     let full_path = instance_path.join(rel_path);
     
     let content = match format {
-        ConfigFormat::Properties => {
-            properties::serialize_json_as_properties(&value)
-        }
+        ConfigFormat::Properties => properties::serialize_json_as_properties(&value).into_bytes(),
         ConfigFormat::Yaml => {
             let yaml: YamlValue = serde_json::from_value(value)?;
-            serde_yaml::to_string(&yaml)?
+            serde_yaml::to_string(&yaml)?.into_bytes()
         }
         ConfigFormat::Toml => {
             let toml: TomlValue = serde_json::from_value(value)?;
-            ::toml::to_string_pretty(&toml)?
+            ::toml::to_string_pretty(&toml)?.into_bytes()
         }
-        ConfigFormat::Json => {
-            serde_json::to_string_pretty(&value)?
+        ConfigFormat::Json => serde_json::to_string_pretty(&value)?.into_bytes(),
+        ConfigFormat::Hocon => hocon_text(&full_path, &value).await.into_bytes(),
+        ConfigFormat::Nbt => {
+            // Applied onto the existing file so every tag keeps its type
+            let mut file = nbt::read_nbt_or_empty(&full_path, rel_path).await?;
+            file.root = nbt::apply_json(&file.root, &value, "")?;
+            write_nbt(&file)?
         }
     };
 
-    fs::write(&full_path, content).await
+    write_atomic(&full_path, content, backup).await
         .context(format!("Failed to write config file: {}", rel_path))?;
     
     Ok(())
 }
 
-pub async fn save_config_file(instance_path: &Path, rel_path: &str, format: ConfigFormat, properties: HashMap<String, String>) -> Result<()> {
+/// Writes atomically; with `backup`, the previous file is kept as `<name>.bak`.
+pub async fn save_config_file(instance_path: &Path, rel_path: &str, format: ConfigFormat, properties: HashMap<String, String>, backup: bool) -> Result<()> {
+    // Never rewrite an existing file in a different syntax than it is written in
+    let format = detect_config_format(instance_path, rel_path, Some(format)).await?;
     let full_path = instance_path.join(rel_path);
     
     let content = match format {
-        ConfigFormat::Properties => {
-            properties::serialize_properties(&properties)
-        }
+        ConfigFormat::Properties => properties::serialize_properties(&properties).into_bytes(),
         ConfigFormat::Yaml => {
             let mut yaml_map = serde_yaml::Mapping::new();
             for (key, value) in properties {
                 yaml::unflatten_yaml(&mut yaml_map, &key, value);
             }
-            serde_yaml::to_string(&YamlValue::Mapping(yaml_map))?
+            serde_yaml::to_string(&YamlValue::Mapping(yaml_map))?.into_bytes()
         }
         ConfigFormat::Toml => {
             let mut toml_map = ::toml::map::Map::new();
             for (key, value) in properties {
                 toml::unflatten_toml(&mut toml_map, &key, value);
             }
-            ::toml::to_string_pretty(&TomlValue::Table(toml_map))?
+            ::toml::to_string_pretty(&TomlValue::Table(toml_map))?.into_bytes()
         }
         ConfigFormat::Json => {
             let mut json_obj = serde_json::Map::new();
             for (key, value) in properties {
                 json::unflatten_json(&mut json_obj, &key, value);
             }
-            serde_json::to_string_pretty(&JsonValue::Object(json_obj))?
+            serde_json::to_string_pretty(&JsonValue::Object(json_obj))?.into_bytes()
+        }
+        ConfigFormat::Hocon => {
+            let mut json_obj = serde_json::Map::new();
+            for (key, value) in properties {
+                json::unflatten_json(&mut json_obj, &key, value);
+            }
+            hocon_text(&full_path, &JsonValue::Object(json_obj)).await.into_bytes()
+        }
+        ConfigFormat::Nbt => {
+            let mut file = nbt::read_nbt_or_empty(&full_path, rel_path).await?;
+            nbt::apply_flat(&mut file, &properties)?;
+            write_nbt(&file)?
         }
     };
 
-    fs::write(&full_path, content).await
+    write_atomic(&full_path, content, backup).await
         .context(format!("Failed to write config file: {}", rel_path))?;
     
     Ok(())
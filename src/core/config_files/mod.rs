@@ -1,11 +1,27 @@
 pub mod properties;
 pub mod yaml;
+pub mod yaml_patch;
 pub mod toml;
 pub mod json;
+pub mod hocon;
+pub mod nbt;
 pub mod types;
+pub mod detect;
 pub mod discovery;
 pub mod io;
+pub mod drift;
+pub mod search;
+pub mod replace;
+pub mod presets;
 
 pub use types::*;
+pub use detect::{detect_config_format, format_for_path};
 pub use discovery::*;
 pub use io::*;
+pub use drift::*;
+pub use search::{ConfigMatch, ConfigSearch, SearchTarget, list_searchable_configs, search_configs};
+pub use replace::{ReplaceReport, replace_in_configs};
+pub use presets::{
+    ConfigPreset, PresetChange, PresetDiff, PresetPreview, apply_preset, builtin_presets, find_preset, preview_preset,
+    presets_supported,
+};
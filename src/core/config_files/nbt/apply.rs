@@ -0,0 +1,126 @@
+use super::{child_path, mismatch};
+use crate::nbt::NbtTag;
+use anyhow::{Result, anyhow};
+use serde_json::Value as JsonValue;
+
+fn as_integer<T: TryFrom<i64>>(value: &JsonValue, path: &str, expected: &str) -> Result<T> {
+    let n = match value {
+        JsonValue::Bool(b) => Some(i64::from(*b)),
+        JsonValue::Number(n) => n.as_i64(),
+        JsonValue::String(s) => match s.trim() {
+            "true" => Some(1),
+            "false" => Some(0),
+            s => s.parse().ok(),
+        },
+        _ => None,
+    };
+    n.and_then(|n| T::try_from(n).ok())
+        .ok_or_else(|| mismatch(path, expected, value))
+}
+
+fn as_float(value: &JsonValue, path: &str) -> Result<f64> {
+    match value {
+        JsonValue::Number(n) => n.as_f64(),
+        JsonValue::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| mismatch(path, "a number", value))
+}
+
+fn integers<T: TryFrom<i64>>(value: &JsonValue, path: &str, expected: &str) -> Result<Vec<T>> {
+    match value {
+        JsonValue::Array(items) => items.iter().map(|item| as_integer(item, path, expected)).collect(),
+        _ => Err(mismatch(path, "an array", value)),
+    }
+}
+
+/// `value` as a tag of the same type as `original`. Compound entries missing from
+/// `value` are removed and new ones get a type from [`infer_tag`].
+pub fn apply_json(original: &NbtTag, value: &JsonValue, path: &str) -> Result<NbtTag> {
+    Ok(match original {
+        NbtTag::Byte(_) => NbtTag::Byte(as_integer(value, path, "a byte")?),
+        NbtTag::Short(_) => NbtTag::Short(as_integer(value, path, "a short")?),
+        NbtTag::Int(_) => NbtTag::Int(as_integer(value, path, "an int")?),
+        NbtTag::Long(_) => NbtTag::Long(as_integer(value, path, "a long")?),
+        NbtTag::Float(_) => NbtTag::Float(as_float(value, path)? as f32),
+        NbtTag::Double(_) => NbtTag::Double(as_float(value, path)?),
+        NbtTag::ByteArray(_) => NbtTag::ByteArray(integers(value, path, "a byte")?),
+        NbtTag::IntArray(_) => NbtTag::IntArray(integers(value, path, "an int")?),
+        NbtTag::LongArray(_) => NbtTag::LongArray(integers(value, path, "a long")?),
+        NbtTag::String(_) => NbtTag::String(match value {
+            JsonValue::String(s) => s.clone(),
+            JsonValue::Array(_) | JsonValue::Object(_) | JsonValue::Null => {
+                return Err(mismatch(path, "a string", value));
+            }
+            other => other.to_string(),
+        }),
+        NbtTag::List(items) => {
+            let JsonValue::Array(values) = value else {
+                return Err(mismatch(path, "a list", value));
+            };
+            let list = values
+                .iter()
+                .enumerate()
+                .map(|(i, item)| {
+                    let item_path = format!("{}[{}]", path, i);
+                    // New items take the type of the list's existing ones
+                    match items.get(i).or(items.first()) {
+                        Some(template) => apply_json(template, item, &item_path),
+                        None => infer_tag(item, &item_path),
+                    }
+                })
+                .collect::<Result<Vec<_>>>()?;
+            if list.windows(2).any(|pair| pair[0].id() != pair[1].id()) {
+                return Err(anyhow!("{} mixes value types, which NBT lists can't hold", path));
+            }
+            NbtTag::List(list)
+        }
+        NbtTag::Compound(entries) => {
+            let JsonValue::Object(object) = value else {
+                return Err(mismatch(path, "an object", value));
+            };
+            let mut result = Vec::with_capacity(object.len());
+            for (key, tag) in entries {
+                if let Some(value) = object.get(key) {
+                    result.push((key.clone(), apply_json(tag, value, &child_path(path, key))?));
+                }
+            }
+            for (key, value) in object {
+                if !entries.iter().any(|(k, _)| k == key) {
+                    result.push((key.clone(), infer_tag(value, &child_path(path, key))?));
+                }
+            }
+            NbtTag::Compound(result)
+        }
+    })
+}
+
+/// A tag for a value that has no existing tag to take its type from.
+pub fn infer_tag(value: &JsonValue, path: &str) -> Result<NbtTag> {
+    Ok(match value {
+        JsonValue::Bool(b) => NbtTag::Byte(i8::from(*b)),
+        JsonValue::Number(n) => match n.as_i64() {
+            Some(i) => i32::try_from(i).map_or(NbtTag::Long(i), NbtTag::Int),
+            None => NbtTag::Double(n.as_f64().unwrap_or_default()),
+        },
+        JsonValue::String(s) => NbtTag::String(s.clone()),
+        JsonValue::Array(items) => {
+            let items = items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| infer_tag(item, &format!("{}[{}]", path, i)))
+                .collect::<Result<Vec<_>>>()?;
+            if items.windows(2).any(|pair| pair[0].id() != pair[1].id()) {
+                return Err(anyhow!("{} mixes value types, which NBT lists can't hold", path));
+            }
+            NbtTag::List(items)
+        }
+        JsonValue::Object(object) => NbtTag::Compound(
+            object
+                .iter()
+                .map(|(k, v)| Ok((k.clone(), infer_tag(v, &child_path(path, k))?)))
+                .collect::<Result<_>>()?,
+        ),
+        JsonValue::Null => return Err(anyhow!("{} has no value", path)),
+    })
+}
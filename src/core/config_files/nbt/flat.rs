@@ -0,0 +1,97 @@
+use super::{apply_json, infer_tag, nbt_to_json};
+use crate::config_files::json::flatten_json;
+use crate::nbt::{NbtFile, NbtTag};
+use anyhow::{Result, anyhow};
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+
+enum Segment<'a> {
+    Key(&'a str),
+    Index(usize),
+}
+
+/// Splits a flattened key like `a.list[2].b` into its parts.
+fn flat_segments(key: &str) -> Vec<Segment<'_>> {
+    let mut segments = Vec::new();
+    for part in key.split('.') {
+        let (name, mut rest) = match part.find('[') {
+            Some(i) => (&part[..i], &part[i..]),
+            None => (part, ""),
+        };
+        segments.push(Segment::Key(name));
+        while let Some((index, after)) = rest.strip_prefix('[').and_then(|r| r.split_once(']')) {
+            let Ok(index) = index.parse() else { break };
+            segments.push(Segment::Index(index));
+            rest = after;
+        }
+    }
+    segments
+}
+
+/// Sets one flattened `key` (as produced by flattening [`nbt_to_json`]) to `value`.
+fn set_flat(root: &mut NbtTag, key: &str, value: &str) -> Result<()> {
+    let segments = flat_segments(key);
+    let mut tag = root;
+    for (i, segment) in segments.iter().enumerate() {
+        let last = i + 1 == segments.len();
+        tag = match (tag, segment) {
+            (NbtTag::Compound(entries), Segment::Key(name)) => {
+                let index = match entries.iter().position(|(k, _)| k == name) {
+                    Some(index) => index,
+                    None => {
+                        let new = if last {
+                            infer_tag(&guess_scalar(value), key)?
+                        } else {
+                            NbtTag::Compound(Vec::new())
+                        };
+                        entries.push((name.to_string(), new));
+                        entries.len() - 1
+                    }
+                };
+                &mut entries[index].1
+            }
+            (NbtTag::List(items), Segment::Index(index)) if *index < items.len() => &mut items[*index],
+            _ => return Err(anyhow!("{} does not match the structure of the file", key)),
+        };
+        if last {
+            let updated = apply_json(tag, &JsonValue::String(value.to_string()), key)?;
+            *tag = updated;
+        }
+    }
+    Ok(())
+}
+
+fn guess_scalar(value: &str) -> JsonValue {
+    match value {
+        "true" => JsonValue::Bool(true),
+        "false" => JsonValue::Bool(false),
+        _ => value
+            .parse::<i64>()
+            .map(JsonValue::from)
+            .ok()
+            .or_else(|| {
+                value
+                    .parse::<f64>()
+                    .ok()
+                    .and_then(serde_json::Number::from_f64)
+                    .map(JsonValue::Number)
+            })
+            .unwrap_or_else(|| JsonValue::String(value.to_string())),
+    }
+}
+
+/// Applies the flattened properties that differ from `file`. Keys the editor didn't send
+/// are left alone, since it has no way to delete them.
+pub fn apply_flat(file: &mut NbtFile, properties: &HashMap<String, String>) -> Result<()> {
+    let mut current = HashMap::new();
+    flatten_json("", &nbt_to_json(&file.root), &mut current);
+    let mut keys: Vec<&String> = properties
+        .keys()
+        .filter(|k| current.get(*k) != properties.get(*k))
+        .collect();
+    keys.sort();
+    for key in keys {
+        set_flat(&mut file.root, key, &properties[key])?;
+    }
+    Ok(())
+}
@@ -0,0 +1,85 @@
+//! Logic for NBT (.dat) configs, which some mods store instead of text.
+//!
+//! Values are edited as JSON. Saving applies the edits onto the file as it is on disk, so
+//! every tag keeps its NBT type (a byte stays a byte) and the file keeps its compression.
+
+use crate::nbt::{NbtCompression, NbtFile, NbtTag, read_nbt};
+use anyhow::{Context, Result, anyhow};
+use serde_json::{Map, Value as JsonValue};
+use std::path::Path;
+use tokio::fs;
+
+mod apply;
+mod flat;
+
+pub use apply::{apply_json, infer_tag};
+pub use flat::apply_flat;
+
+pub fn nbt_to_json(tag: &NbtTag) -> JsonValue {
+    match tag {
+        NbtTag::Byte(v) => (*v).into(),
+        NbtTag::Short(v) => (*v).into(),
+        NbtTag::Int(v) => (*v).into(),
+        NbtTag::Long(v) => (*v).into(),
+        // Through its shortest text so 0.1f shows as 0.1, not 0.10000000149011612
+        NbtTag::Float(v) => v
+            .to_string()
+            .parse()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map_or(JsonValue::Null, JsonValue::Number),
+        NbtTag::Double(v) => serde_json::Number::from_f64(*v).map_or(JsonValue::Null, JsonValue::Number),
+        NbtTag::ByteArray(values) => values.iter().map(|v| JsonValue::from(*v)).collect(),
+        NbtTag::String(s) => JsonValue::String(s.clone()),
+        NbtTag::List(items) => items.iter().map(nbt_to_json).collect(),
+        NbtTag::Compound(entries) => JsonValue::Object(
+            entries
+                .iter()
+                .map(|(k, v)| (k.clone(), nbt_to_json(v)))
+                .collect::<Map<_, _>>(),
+        ),
+        NbtTag::IntArray(values) => values.iter().map(|v| JsonValue::from(*v)).collect(),
+        NbtTag::LongArray(values) => values.iter().map(|v| JsonValue::from(*v)).collect(),
+    }
+}
+
+/// An empty gzip-compressed file, the way Minecraft writes new ones.
+pub fn empty_nbt() -> NbtFile {
+    NbtFile {
+        name: String::new(),
+        root: NbtTag::Compound(Vec::new()),
+        compression: NbtCompression::Gzip,
+    }
+}
+
+fn mismatch(path: &str, expected: &str, value: &JsonValue) -> anyhow::Error {
+    anyhow!(
+        "{} must be {}, got {}",
+        if path.is_empty() { "The root" } else { path },
+        expected,
+        value
+    )
+}
+
+fn child_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+pub(crate) async fn read_nbt_value(full_path: &Path, rel_path: &str) -> Result<JsonValue> {
+    let bytes = fs::read(full_path).await
+        .context(format!("Failed to read config file: {}", rel_path))?;
+    let file = read_nbt(&bytes).context(format!("{} is not a valid NBT file", rel_path))?;
+    Ok(nbt_to_json(&file.root))
+}
+
+/// The NBT file at `full_path`, or a new empty one when there isn't one yet.
+pub(crate) async fn read_nbt_or_empty(full_path: &Path, rel_path: &str) -> Result<NbtFile> {
+    match fs::read(full_path).await {
+        Ok(bytes) if !bytes.is_empty() => read_nbt(&bytes).context(format!("{} is not a valid NBT file", rel_path)),
+        _ => Ok(empty_nbt()),
+    }
+}
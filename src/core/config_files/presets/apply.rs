@@ -0,0 +1,180 @@
+use super::{ConfigPreset, PresetChange, PresetDiff, PresetPreview};
+use crate::config_files::detect::format_for_path;
+use crate::config_files::types::ConfigFormat;
+use crate::config_files::yaml::get_yaml_path;
+use crate::config_files::yaml_patch::patch_yaml_value;
+use crate::utils::{safe_join, write_atomic};
+use anyhow::{Context, Result, anyhow};
+use serde_json::Value as JsonValue;
+use serde_yaml::Value as YamlValue;
+use std::path::Path;
+
+fn display_json(value: &JsonValue) -> String {
+    match value {
+        JsonValue::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn display_yaml(value: &YamlValue) -> String {
+    match value {
+        YamlValue::String(s) => s.clone(),
+        other => serde_json::to_value(other)
+            .map(|v| display_json(&v))
+            .unwrap_or_default(),
+    }
+}
+
+fn property_value(content: &str, key: &str) -> Option<String> {
+    content.lines().find_map(|line| {
+        let line = line.trim();
+        if line.starts_with('#') {
+            return None;
+        }
+        let (k, v) = line.split_once('=')?;
+        (k.trim() == key).then(|| v.trim().to_string())
+    })
+}
+
+/// Replaces the line for `key` in place, or appends it, leaving comments and order alone.
+fn set_property(content: &str, key: &str, value: &str) -> String {
+    let mut found = false;
+    let mut out = String::with_capacity(content.len());
+    for line in content.lines() {
+        let trimmed = line.trim();
+        let matches = !trimmed.starts_with('#') && trimmed.split_once('=').is_some_and(|(k, _)| k.trim() == key);
+        if matches && !found {
+            out.push_str(&format!("{}={}", key, value));
+            found = true;
+        } else {
+            out.push_str(line);
+        }
+        out.push('\n');
+    }
+    if !found {
+        out.push_str(&format!("{}={}\n", key, value));
+    }
+    out
+}
+
+fn file_format(file: &str) -> Result<ConfigFormat> {
+    match format_for_path(file) {
+        Some(format @ (ConfigFormat::Properties | ConfigFormat::Yaml)) => Ok(format),
+        _ => Err(anyhow!("Presets can't edit {}", file)),
+    }
+}
+
+fn parse_yaml(content: &str, file: &str) -> Result<YamlValue> {
+    if content.trim().is_empty() {
+        return Ok(YamlValue::Null);
+    }
+    serde_yaml::from_str(content).with_context(|| format!("Could not parse {}", file))
+}
+
+/// The preset's files in the order they first appear, with the changes for each.
+fn changes_by_file(preset: &ConfigPreset) -> Vec<(&str, Vec<&PresetChange>)> {
+    let mut files: Vec<(&str, Vec<&PresetChange>)> = Vec::new();
+    for change in &preset.changes {
+        match files.iter_mut().find(|(file, _)| *file == change.file) {
+            Some((_, changes)) => changes.push(change),
+            None => files.push((&change.file, vec![change])),
+        }
+    }
+    files
+}
+
+/// Compares each of the preset's keys against what the instance has now.
+pub async fn preview_preset(instance_path: &Path, preset: &ConfigPreset) -> Result<PresetPreview> {
+    let mut diffs = Vec::new();
+    let mut missing_files = Vec::new();
+    for (file, changes) in changes_by_file(preset) {
+        let format = file_format(file)?;
+        let path = safe_join(instance_path, file)?;
+        let content = match tokio::fs::read_to_string(&path).await {
+            Ok(content) => Some(content),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                missing_files.push(file.to_string());
+                None
+            }
+            Err(e) => return Err(e).with_context(|| format!("Could not read {}", file)),
+        };
+        let yaml = match (&format, &content) {
+            (ConfigFormat::Yaml, Some(content)) => Some(parse_yaml(content, file)?),
+            _ => None,
+        };
+
+        for change in changes {
+            let current = match (&format, &content, &yaml) {
+                (ConfigFormat::Properties, Some(content), _) => property_value(content, &change.key),
+                (ConfigFormat::Yaml, _, Some(yaml)) => get_yaml_path(yaml, &change.key).map(display_yaml),
+                _ => None,
+            };
+            let proposed = display_json(&change.value);
+            diffs.push(PresetDiff {
+                file: change.file.clone(),
+                key: change.key.clone(),
+                changed: current.as_deref() != Some(proposed.as_str()),
+                current,
+                proposed,
+            });
+        }
+    }
+    Ok(PresetPreview {
+        preset_id: preset.id.clone(),
+        diffs,
+        missing_files,
+    })
+}
+
+/// Applies every change in `preset`. All files are edited in memory and checked first;
+/// if writing one fails, the files already written are put back, so the instance never
+/// ends up with half a preset. Returns the files that changed.
+///
+/// Only the lines holding the preset's keys are rewritten, so comments and layout stay.
+pub async fn apply_preset(instance_path: &Path, preset: &ConfigPreset, backup: bool) -> Result<Vec<String>> {
+    let mut pending = Vec::new();
+    for (file, changes) in changes_by_file(preset) {
+        let format = file_format(file)?;
+        let path = safe_join(instance_path, file)?;
+        let original = match tokio::fs::read_to_string(&path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(anyhow!(
+                    "{} doesn't exist yet; start the server once so it generates its config files",
+                    file
+                ));
+            }
+            Err(e) => return Err(e).with_context(|| format!("Could not read {}", file)),
+        };
+
+        let edited = match format {
+            ConfigFormat::Properties => changes.iter().fold(original.clone(), |content, change| {
+                set_property(&content, &change.key, &display_json(&change.value))
+            }),
+            _ => {
+                parse_yaml(&original, file)?;
+                let mut content = original.clone();
+                for change in changes {
+                    content = patch_yaml_value(&content, &change.key, &serde_yaml::to_value(&change.value)?)
+                        .with_context(|| format!("Could not apply the preset to {}", file))?;
+                }
+                content
+            }
+        };
+        if edited != original {
+            pending.push((file.to_string(), path, original, edited));
+        }
+    }
+
+    let mut written: Vec<(&Path, &str)> = Vec::new();
+    for (file, path, original, edited) in &pending {
+        if let Err(e) = write_atomic(path, edited, backup).await {
+            for (path, original) in written {
+                let _ = write_atomic(path, original, false).await;
+            }
+            return Err(e).with_context(|| format!("Failed to write {}; no files were changed", file));
+        }
+        written.push((path, original));
+    }
+    Ok(pending.into_iter().map(|(file, ..)| file).collect())
+}
@@ -0,0 +1,89 @@
+use super::{ConfigPreset, PAPER_WORLD_DEFAULTS, PresetChange};
+use serde_json::{Value as JsonValue, json};
+
+fn change(file: &str, key: &str, value: JsonValue) -> PresetChange {
+    PresetChange {
+        file: file.to_string(),
+        key: key.to_string(),
+        value,
+    }
+}
+
+fn preset(id: &str, name: &str, description: &str, changes: Vec<PresetChange>) -> ConfigPreset {
+    ConfigPreset {
+        id: id.to_string(),
+        name: name.to_string(),
+        description: description.to_string(),
+        changes,
+    }
+}
+
+/// The presets shipped with the wrapper.
+pub fn builtin_presets() -> Vec<ConfigPreset> {
+    let world = PAPER_WORLD_DEFAULTS;
+    let spigot = "world-settings.default";
+    vec![
+        preset(
+            "anti-xray",
+            "Anti-Xray",
+            "Hides ores from X-ray clients using Paper's engine mode 1, which has the lowest overhead.",
+            vec![
+                change(world, "anticheat.anti-xray.enabled", json!(true)),
+                change(world, "anticheat.anti-xray.engine-mode", json!(1)),
+                change(world, "anticheat.anti-xray.max-block-height", json!(64)),
+                change(world, "anticheat.anti-xray.update-radius", json!(2)),
+                change(world, "anticheat.anti-xray.lava-obscures", json!(false)),
+                change(world, "anticheat.anti-xray.use-permission", json!(false)),
+            ],
+        ),
+        preset(
+            "performance",
+            "Performance baseline",
+            "Shorter view and simulation distances, tighter entity activation ranges and lower spawn limits.",
+            vec![
+                change("server.properties", "view-distance", json!(8)),
+                change("server.properties", "simulation-distance", json!(6)),
+                change("server.properties", "sync-chunk-writes", json!(false)),
+                change("server.properties", "network-compression-threshold", json!(256)),
+                change("bukkit.yml", "spawn-limits.monsters", json!(50)),
+                change("bukkit.yml", "spawn-limits.ambient", json!(1)),
+                change("bukkit.yml", "ticks-per.monster-spawns", json!(2)),
+                change("bukkit.yml", "chunk-gc.period-in-ticks", json!(400)),
+                change(
+                    "spigot.yml",
+                    &format!("{}.entity-activation-range.animals", spigot),
+                    json!(16),
+                ),
+                change(
+                    "spigot.yml",
+                    &format!("{}.entity-activation-range.monsters", spigot),
+                    json!(24),
+                ),
+                change(
+                    "spigot.yml",
+                    &format!("{}.entity-activation-range.misc", spigot),
+                    json!(8),
+                ),
+                change("spigot.yml", &format!("{}.merge-radius.item", spigot), json!(3.5)),
+                change("spigot.yml", &format!("{}.merge-radius.exp", spigot), json!(4.0)),
+                change("spigot.yml", &format!("{}.mob-spawn-range", spigot), json!(6)),
+                change(world, "environment.optimize-explosions", json!(true)),
+                change(world, "chunks.max-auto-save-chunks-per-tick", json!(8)),
+                change(world, "collisions.max-entity-collisions", json!(2)),
+                change(world, "misc.redstone-implementation", json!("ALTERNATE_CURRENT")),
+            ],
+        ),
+        preset(
+            "hardcore",
+            "Hardcore survival",
+            "Hardcore mode on hard difficulty with PvP and no spawn protection. Mob caps scale per player.",
+            vec![
+                change("server.properties", "hardcore", json!(true)),
+                change("server.properties", "difficulty", json!("hard")),
+                change("server.properties", "pvp", json!(true)),
+                change("server.properties", "spawn-protection", json!(0)),
+                change(world, "entities.spawning.per-player-mob-spawns", json!(true)),
+            ],
+        ),
+    ]
+}
@@ -0,0 +1,66 @@
+//! Curated bundles of Paper/Spigot/Bukkit settings, previewed as a diff and applied
+//! all-or-nothing across the files they touch.
+
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+mod apply;
+mod catalog;
+
+pub use apply::{apply_preset, preview_preset};
+pub use catalog::builtin_presets;
+
+/// Per-world defaults since Paper 1.19; older Paper keeps these in `paper.yml`.
+pub const PAPER_WORLD_DEFAULTS: &str = "config/paper-world-defaults.yml";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PresetChange {
+    /// Relative to the instance root.
+    pub file: String,
+    /// Dotted path, e.g. `anticheat.anti-xray.enabled`. `server.properties` keys are used as-is.
+    pub key: String,
+    pub value: JsonValue,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ConfigPreset {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub changes: Vec<PresetChange>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PresetDiff {
+    pub file: String,
+    pub key: String,
+    /// `None` when the key isn't set yet.
+    pub current: Option<String>,
+    pub proposed: String,
+    pub changed: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PresetPreview {
+    pub preset_id: String,
+    pub diffs: Vec<PresetDiff>,
+    /// Files the preset edits that the server hasn't generated yet. Applying fails
+    /// until they exist.
+    pub missing_files: Vec<String>,
+}
+
+pub fn find_preset(id: &str) -> Result<ConfigPreset> {
+    builtin_presets()
+        .into_iter()
+        .find(|p| p.id == id)
+        .ok_or_else(|| anyhow!("Unknown config preset '{}'", id))
+}
+
+/// Presets edit Paper's own config files, so only Paper and its forks can use them.
+pub fn presets_supported(loader: Option<&str>) -> bool {
+    matches!(
+        loader.map(|l| l.to_lowercase()).as_deref(),
+        Some("paper" | "purpur" | "folia")
+    )
+}
@@ -0,0 +1,90 @@
+//! Guarded bulk replacement on top of [`search_configs`](super::search::search_configs).
+
+use super::detect::format_for_path;
+use super::search::{ConfigSearch, matches_in, read_searchable, target_range};
+use super::types::ConfigFormat;
+use crate::utils::{safe_join, write_atomic};
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReplaceReport {
+    pub files_changed: Vec<String>,
+    pub replacements: usize,
+}
+
+/// Checks that edited content still parses, so a replacement cannot break a file.
+fn validate(content: &str, format: &ConfigFormat) -> Result<()> {
+    match format {
+        ConfigFormat::Properties => Ok(()),
+        ConfigFormat::Yaml => serde_yaml::from_str::<serde_yaml::Value>(content).map(|_| ()).map_err(Into::into),
+        ConfigFormat::Toml => ::toml::from_str::<::toml::Value>(content).map(|_| ()).map_err(Into::into),
+        ConfigFormat::Json => serde_json::from_str::<serde_json::Value>(content).map(|_| ()).map_err(Into::into),
+        ConfigFormat::Hocon => super::hocon::parse_hocon(content).map(|_| ()),
+        ConfigFormat::Nbt => Err(anyhow!("NBT files are binary and can't be edited as text")),
+    }
+}
+
+/// Replaces every match of `search` in `files` with `replacement` (`$1` refers to
+/// regex groups).
+///
+/// `expected_matches` is the number of matches the user reviewed for these files. If
+/// the files changed since, or any edited file would no longer parse, nothing is written.
+pub async fn replace_in_configs(
+    instance_path: &Path,
+    search: &ConfigSearch,
+    replacement: &str,
+    files: &[String],
+    expected_matches: usize,
+    backup: bool,
+) -> Result<ReplaceReport> {
+    let re = search.compile()?;
+    let replacement = if search.regex { replacement.to_string() } else { replacement.replace('$', "$$") };
+
+    let mut pending = Vec::new();
+    let mut found = 0;
+    for rel_path in files {
+        let format = format_for_path(rel_path).ok_or_else(|| anyhow!("Not a config file: {}", rel_path))?;
+        let full_path = safe_join(instance_path, rel_path)?;
+        let content = read_searchable(instance_path, rel_path)
+            .await
+            .with_context(|| format!("Could not read {}", rel_path))?;
+        let count = matches_in(&content, rel_path, &format, search, &re).len();
+        if count == 0 {
+            continue;
+        }
+        found += count;
+
+        let mut edited = String::with_capacity(content.len());
+        for line in content.split_inclusive('\n') {
+            let body = line.trim_end_matches(['\n', '\r']);
+            match target_range(body, &format, search.target) {
+                Some(range) if re.is_match(&body[range.clone()]) => {
+                    edited.push_str(&body[..range.start]);
+                    edited.push_str(&re.replace_all(&body[range.clone()], replacement.as_str()));
+                    edited.push_str(&line[range.end..]);
+                }
+                _ => edited.push_str(line),
+            }
+        }
+        validate(&edited, &format).with_context(|| format!("Replacement would break {}", rel_path))?;
+        pending.push((rel_path.clone(), full_path, edited, count));
+    }
+
+    if found != expected_matches {
+        return Err(anyhow!(
+            "Expected {} matches but found {}; the files changed since the search, so nothing was replaced",
+            expected_matches,
+            found
+        ));
+    }
+
+    let mut report = ReplaceReport::default();
+    for (rel_path, full_path, edited, count) in pending {
+        write_atomic(&full_path, edited, backup).await?;
+        report.files_changed.push(rel_path);
+        report.replacements += count;
+    }
+    Ok(report)
+}
@@ -0,0 +1,167 @@
+//! Find a key or value pattern across every config file of an instance.
+
+use super::detect::format_for_path;
+use super::discovery::list_available_configs;
+use super::types::ConfigFormat;
+use crate::mods::config::list_mod_config_files;
+use anyhow::{Context, Result, anyhow};
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tokio::fs;
+
+/// Files larger than this are data, not configuration.
+const MAX_SEARCH_FILE_SIZE: u64 = 2 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchTarget {
+    Key,
+    Value,
+    #[default]
+    Any,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigSearch {
+    pub pattern: String,
+    /// Treat `pattern` as a regular expression instead of literal text.
+    #[serde(default)]
+    pub regex: bool,
+    #[serde(default)]
+    pub case_sensitive: bool,
+    #[serde(default)]
+    pub target: SearchTarget,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ConfigMatch {
+    /// Relative to the instance root.
+    pub path: String,
+    /// 1-based line number.
+    pub line: usize,
+    pub key: Option<String>,
+    pub text: String,
+}
+
+impl ConfigSearch {
+    pub(crate) fn compile(&self) -> Result<Regex> {
+        if self.pattern.is_empty() {
+            return Err(anyhow!("Search pattern cannot be empty"));
+        }
+        let pattern = if self.regex { self.pattern.clone() } else { regex::escape(&self.pattern) };
+        RegexBuilder::new(&pattern)
+            .case_insensitive(!self.case_sensitive)
+            .build()
+            .context("Invalid search pattern")
+    }
+}
+
+/// Splits a line into the byte ranges of its key and value, if it has the shape of one.
+pub(crate) fn split_line(line: &str, format: &ConfigFormat) -> Option<(std::ops::Range<usize>, std::ops::Range<usize>)> {
+    let trimmed = line.trim_start();
+    if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with("//") {
+        return None;
+    }
+    let sep = match format {
+        ConfigFormat::Properties => line.find(['=', ':'])?,
+        ConfigFormat::Yaml => line.find(": ").or_else(|| line.trim_end().ends_with(':').then(|| line.trim_end().len() - 1))?,
+        ConfigFormat::Toml => line.find('=')?,
+        ConfigFormat::Json => line.find("\":").map(|i| i + 1)?,
+        // `key { ...` opens an object rather than holding a value
+        ConfigFormat::Hocon => line.find(['=', ':'])?,
+        ConfigFormat::Nbt => return None,
+    };
+    let start = line.len() - trimmed.len();
+    let key = start..line[..sep].trim_end().trim_end_matches('+').trim_end().len();
+    let value_start = sep + 1 + (line[sep + 1..].len() - line[sep + 1..].trim_start().len());
+    Some((key, value_start..line.trim_end().len()))
+}
+
+/// Byte range of `line` that `target` applies to, or `None` when it has no such part.
+pub(crate) fn target_range(line: &str, format: &ConfigFormat, target: SearchTarget) -> Option<std::ops::Range<usize>> {
+    match target {
+        SearchTarget::Any => Some(0..line.len()),
+        SearchTarget::Key => split_line(line, format).map(|(key, _)| key),
+        SearchTarget::Value => split_line(line, format).map(|(_, value)| value),
+    }
+}
+
+fn clean_key(raw: &str) -> String {
+    raw.trim().trim_start_matches("- ").trim_matches('"').to_string()
+}
+
+/// Every text config the editor knows about: root and `config/` files, mod configs
+/// in nested folders, and plugin data folders.
+pub async fn list_searchable_configs(instance_path: &Path) -> Vec<(String, ConfigFormat)> {
+    let mut files: Vec<(String, ConfigFormat)> = list_available_configs(instance_path, None)
+        .await
+        .into_iter()
+        .filter(|c| c.format.is_text() && instance_path.join(&c.path).is_file())
+        .map(|c| (c.path, c.format))
+        .collect();
+
+    let mut extra = list_mod_config_files(instance_path, "config")
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .map(|rel| format!("config/{}", rel))
+        .collect::<Vec<_>>();
+
+    let mut stack = vec![instance_path.join("plugins")];
+    while let Some(dir) = stack.pop() {
+        let Ok(mut entries) = fs::read_dir(&dir).await else { continue };
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+            if path.is_dir() {
+                stack.push(path);
+            } else if let Ok(rel) = path.strip_prefix(instance_path) {
+                extra.push(rel.to_string_lossy().replace('\\', "/"));
+            }
+        }
+    }
+
+    extra.sort();
+    for rel in extra {
+        if let Some(format) = format_for_path(&rel).filter(ConfigFormat::is_text)
+            && !files.iter().any(|(p, _)| *p == rel)
+        {
+            files.push((rel, format));
+        }
+    }
+    files
+}
+
+pub(crate) async fn read_searchable(instance_path: &Path, rel_path: &str) -> Option<String> {
+    let path = instance_path.join(rel_path);
+    let size = fs::metadata(&path).await.ok()?.len();
+    if size > MAX_SEARCH_FILE_SIZE {
+        return None;
+    }
+    fs::read_to_string(&path).await.ok()
+}
+
+pub(crate) fn matches_in(content: &str, rel_path: &str, format: &ConfigFormat, search: &ConfigSearch, re: &Regex) -> Vec<ConfigMatch> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| target_range(line, format, search.target).is_some_and(|r| re.is_match(&line[r])))
+        .map(|(i, line)| ConfigMatch {
+            path: rel_path.to_string(),
+            line: i + 1,
+            key: split_line(line, format).map(|(key, _)| clean_key(&line[key])),
+            text: line.trim().to_string(),
+        })
+        .collect()
+}
+
+pub async fn search_configs(instance_path: &Path, search: &ConfigSearch) -> Result<Vec<ConfigMatch>> {
+    let re = search.compile()?;
+    let mut results = Vec::new();
+    for (rel_path, format) in list_searchable_configs(instance_path).await {
+        if let Some(content) = read_searchable(instance_path, &rel_path).await {
+            results.extend(matches_in(&content, &rel_path, &format, search, &re));
+        }
+    }
+    Ok(results)
+}
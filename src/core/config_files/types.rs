@@ -11,4 +11,49 @@ pub enum ConfigFormat {
     Yaml,
     Toml,
     Json,
+    /// Sponge and some plugins' `.conf` files.
+    Hocon,
+    /// Binary `.dat` configs some mods write.
+    Nbt,
+}
+
+impl ConfigFormat {
+    /// Whether files of this format are text, which the raw editor and search can work on.
+    pub fn is_text(&self) -> bool {
+        !matches!(self, ConfigFormat::Nbt)
+    }
+}
+
+/// What a group of configs belongs to.
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfigOwnerKind {
+    /// `server.properties` and the server's own files in the instance root.
+    Server,
+    Mod,
+    Plugin,
+}
+
+/// The configs of one mod or plugin, listed without walking their folders so the
+/// config browser opens quickly on large packs.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+pub struct ConfigGroup {
+    pub kind: ConfigOwnerKind,
+    /// Mod id as used in its config names, the plugin's data folder, or `server`.
+    pub owner: String,
+    /// Files and folders holding the group's configs, relative to the instance root.
+    /// Folders are only walked by [`list_group_configs`](super::list_group_configs).
+    pub roots: Vec<String>,
+}
+
+/// A config file, or a directory of them, as shown in the config browser.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+pub struct ConfigTreeNode {
+    pub name: String,
+    /// Relative to the instance root.
+    pub path: String,
+    /// `None` for directories.
+    pub format: Option<ConfigFormat>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<ConfigTreeNode>,
 }
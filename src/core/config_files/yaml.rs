@@ -1,3 +1,4 @@
+use anyhow::{Result, anyhow};
 use std::collections::HashMap;
 use serde_yaml::Value as YamlValue;
 
@@ -56,3 +57,30 @@ pub fn unflatten_yaml(map: &mut serde_yaml::Mapping, key: &str, value: String) {
         map.insert(YamlValue::String(key.to_string()), yaml_val);
     }
 }
+
+/// Value at a dotted path like `bedrock.port`.
+pub fn get_yaml_path<'a>(root: &'a YamlValue, key: &str) -> Option<&'a YamlValue> {
+    key.split('.').try_fold(root, |node, part| node.get(part))
+}
+
+/// Sets a dotted path, creating missing sections on the way. Fails if a section
+/// along the path holds a plain value.
+pub fn set_yaml_path(root: &mut YamlValue, key: &str, value: YamlValue) -> Result<()> {
+    let mut node = root;
+    let mut parts = key.split('.').peekable();
+    while let Some(part) = parts.next() {
+        if node.is_null() {
+            *node = YamlValue::Mapping(Default::default());
+        }
+        let map = node
+            .as_mapping_mut()
+            .ok_or_else(|| anyhow!("Can't set '{}': '{}' isn't a section", key, part))?;
+        let part = YamlValue::String(part.to_string());
+        if parts.peek().is_none() {
+            map.insert(part, value);
+            return Ok(());
+        }
+        node = map.entry(part).or_insert(YamlValue::Null);
+    }
+    Ok(())
+}
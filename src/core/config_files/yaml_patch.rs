@@ -0,0 +1,180 @@
+//! Edits single values in YAML text without re-serialising the document, so comments,
+//! key order and formatting survive.
+
+use super::yaml::get_yaml_path;
+use anyhow::{Context, Result, anyhow};
+use serde_yaml::Value as YamlValue;
+use std::ops::Range;
+
+/// A `key: value` line of a block mapping.
+struct KeyLine<'a> {
+    index: usize,
+    indent: usize,
+    key: &'a str,
+    /// The line up to and including the colon.
+    head: &'a str,
+    /// Everything after the colon.
+    rest: &'a str,
+}
+
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+/// Lines that carry YAML content, as opposed to blanks, comments and document markers.
+fn is_content(line: &str) -> bool {
+    let trimmed = line.trim();
+    !trimmed.is_empty() && !trimmed.starts_with('#') && trimmed != "---" && trimmed != "..."
+}
+
+fn key_line(index: usize, line: &str) -> Option<KeyLine<'_>> {
+    if !is_content(line) || line.trim_start().starts_with('-') {
+        return None;
+    }
+    let indent = indent_of(line);
+    let trimmed = &line[indent..];
+    let (key, colon) = match trimmed.chars().next() {
+        Some(quote @ ('"' | '\'')) => {
+            let close = trimmed[1..].find(quote)? + 1;
+            trimmed[close + 1..].starts_with(':').then_some((&trimmed[1..close], close + 1))?
+        }
+        _ => {
+            let colon = trimmed
+                .match_indices(':')
+                .map(|(i, _)| i)
+                .find(|&i| trimmed[i + 1..].is_empty() || trimmed[i + 1..].starts_with([' ', '\t']))?;
+            (trimmed[..colon].trim_end(), colon)
+        }
+    };
+    Some(KeyLine {
+        index,
+        indent,
+        key,
+        head: &line[..indent + colon + 1],
+        rest: &trimmed[colon + 1..],
+    })
+}
+
+/// Splits the text after a colon into its value and a trailing comment, keeping the
+/// whitespace in front of the comment with it.
+fn split_comment(rest: &str) -> (&str, &str) {
+    // Only a quoted value can hide a `#`; apostrophes inside plain values don't count
+    let start = rest.len() - rest.trim_start().len();
+    let mut quote = rest[start..].chars().next().filter(|c| matches!(c, '"' | '\''));
+    let opening = quote.map_or(0, |_| start + 1);
+    let mut prev_blank = true;
+    for (i, c) in rest.char_indices().skip_while(|(i, _)| *i < opening) {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '#' && prev_blank => {
+                let value = rest[..i].trim_end();
+                return (value, &rest[value.len()..]);
+            }
+            None => {}
+        }
+        prev_blank = c.is_whitespace();
+    }
+    (rest.trim_end(), "")
+}
+
+/// Where a key's nested lines end: the next content line indented no deeper than the key.
+/// A sequence may sit at the key's own indentation.
+fn block_end(lines: &[String], index: usize, indent: usize) -> usize {
+    (index + 1..lines.len())
+        .find(|&i| {
+            let line = &lines[i];
+            is_content(line)
+                && (indent_of(line) < indent
+                    || (indent_of(line) == indent && !line.trim_start().starts_with('-')))
+        })
+        .unwrap_or(lines.len())
+}
+
+fn render_scalar(value: &YamlValue) -> Result<String> {
+    match value {
+        YamlValue::Mapping(_) | YamlValue::Sequence(_) | YamlValue::Tagged(_) => {
+            Err(anyhow!("Only plain values can be patched into YAML"))
+        }
+        _ => Ok(serde_yaml::to_string(value)?.trim_end().to_string()),
+    }
+}
+
+/// Sets the value at a dotted path like `anticheat.anti-xray.enabled` by rewriting only
+/// the line that holds it, or by adding the missing keys at the end of their section.
+/// Fails if a section along the path holds a plain value or a list.
+pub fn patch_yaml_value(content: &str, path: &str, value: &YamlValue) -> Result<String> {
+    let rendered = render_scalar(value)?;
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    let step = lines
+        .iter()
+        .filter(|l| is_content(l))
+        .map(|l| indent_of(l))
+        .filter(|&i| i > 0)
+        .min()
+        .unwrap_or(2);
+
+    let parts: Vec<&str> = path.split('.').collect();
+    let mut range: Range<usize> = 0..lines.len();
+    let mut parent_indent = None;
+    for (depth, part) in parts.iter().enumerate() {
+        let first = range.clone().find(|&i| is_content(&lines[i]));
+        if let Some(first) = first
+            && lines[first].trim_start().starts_with('-')
+        {
+            return Err(anyhow!("Can't set '{}': '{}' holds a list", path, part));
+        }
+        let child_indent = first
+            .map(|i| indent_of(&lines[i]))
+            .unwrap_or_else(|| parent_indent.map_or(0, |p| p + step));
+        let found = range
+            .clone()
+            .filter_map(|i| key_line(i, &lines[i]))
+            .find(|k| k.indent == child_indent && k.key == *part)
+            .map(|k| {
+                let (inline, comment) = split_comment(k.rest);
+                (k.index, k.indent, k.head.to_string(), inline.is_empty(), comment.to_string())
+            });
+
+        let Some((index, indent, head, is_section, comment)) = found else {
+            // Add the rest of the path after the section's last line
+            let at = range.clone().rev().find(|&i| is_content(&lines[i])).map_or(range.start, |i| i + 1);
+            let added = parts[depth..].iter().enumerate().map(|(n, key)| {
+                let pad = " ".repeat(child_indent + n * step);
+                if depth + n + 1 == parts.len() {
+                    format!("{}{}: {}", pad, key, rendered)
+                } else {
+                    format!("{}{}:", pad, key)
+                }
+            });
+            lines.splice(at..at, added.collect::<Vec<_>>());
+            break;
+        };
+        let end = block_end(&lines, index, indent);
+
+        if depth + 1 == parts.len() {
+            if is_section && (index + 1..end).any(|i| is_content(&lines[i])) {
+                return Err(anyhow!("Can't set '{}': '{}' is a section", path, part));
+            }
+            lines[index] = format!("{} {}{}", head, rendered, comment);
+        } else if !is_section {
+            return Err(anyhow!("Can't set '{}': '{}' isn't a section", path, part));
+        } else {
+            range = index + 1..end;
+            parent_indent = Some(indent);
+        }
+    }
+
+    let newline = if content.contains("\r\n") { "\r\n" } else { "\n" };
+    let mut patched = lines.join(newline);
+    if content.is_empty() || content.ends_with('\n') {
+        patched.push_str(newline);
+    }
+
+    // Re-read the result so a layout this line editing misjudged never gets written
+    let parsed: YamlValue = serde_yaml::from_str(&patched).context("The patched YAML doesn't parse")?;
+    if get_yaml_path(&parsed, path) != Some(value) {
+        return Err(anyhow!("Could not patch '{}' in place", path));
+    }
+    Ok(patched)
+}
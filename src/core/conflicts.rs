@@ -0,0 +1,184 @@
+//! Checks a freshly installed mod or plugin against the jars already in the instance:
+//! the same project installed twice, two jars declaring the same mod ID or plugin name,
+//! and pairs known not to work together. Findings are returned as warnings alongside
+//! the install; nothing is removed.
+
+use crate::content_report::ContentKind;
+use crate::mods::metadata::parsers::ids::read_mod_ids_sync;
+use crate::mods::types::ModCache;
+use crate::plugins::metadata::{PluginCache, extract_metadata_sync};
+use anyhow::Result;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// Mod IDs that patch the same parts of the game and crash or misbehave together.
+const INCOMPATIBLE_MODS: &[(&str, &str)] = &[
+    ("phosphor", "starlight"),
+    ("starlight", "moonrise"),
+    ("lithium", "canary"),
+    ("lithium", "radium"),
+    ("sodium", "embeddium"),
+    ("sodium", "rubidium"),
+    ("optifine", "sodium"),
+];
+
+/// Plugin names (lowercased) that replace each other.
+const INCOMPATIBLE_PLUGINS: &[(&str, &str)] = &[
+    ("worldedit", "fastasyncworldedit"),
+    ("essentials", "cmi"),
+    ("luckperms", "permissionsex"),
+    ("luckperms", "groupmanager"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictKind {
+    /// Another jar was installed from the same provider project.
+    DuplicateProject,
+    /// Another jar declares the same mod ID or plugin name.
+    DuplicateId,
+    /// Another jar is on the list of known incompatibilities.
+    Incompatible,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InstallWarning {
+    pub kind: ConflictKind,
+    pub message: String,
+    /// The already installed jar the new one clashes with.
+    pub other_file: String,
+}
+
+/// What installing a mod or plugin returns to the UI.
+#[derive(Debug, Clone, Serialize)]
+pub struct InstallResult {
+    pub filename: String,
+    pub warnings: Vec<InstallWarning>,
+}
+
+struct Jar {
+    filename: String,
+    /// `provider/project id` of jars installed through the app.
+    project: Option<String>,
+    ids: Vec<String>,
+}
+
+/// Enabled jars of a folder; disabled ones aren't loaded and can't clash.
+fn enabled_jars(dir: &Path) -> Vec<(String, PathBuf)> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut jars: Vec<(String, PathBuf)> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| (e.file_name().to_string_lossy().to_string(), e.path()))
+        .filter(|(name, path)| path.is_file() && name.to_lowercase().ends_with(".jar"))
+        .collect();
+    jars.sort();
+    jars
+}
+
+fn read_cache<T: serde::de::DeserializeOwned + Default>(path: &Path) -> T {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn mod_jars(instance_path: &Path) -> Vec<Jar> {
+    let mods_dir = instance_path.join("mods");
+    let cache: ModCache = read_cache(&mods_dir.join(".mod_metadata_cache.json"));
+    enabled_jars(&mods_dir)
+        .into_iter()
+        .map(|(filename, path)| Jar {
+            project: cache
+                .sources
+                .get(&filename)
+                .map(|s| format!("{:?}/{}", s.provider, s.project_id)),
+            ids: read_mod_ids_sync(&path).unwrap_or_default(),
+            filename,
+        })
+        .collect()
+}
+
+fn plugin_jars(instance_path: &Path) -> Vec<Jar> {
+    let plugins_dir = instance_path.join("plugins");
+    let cache: PluginCache = read_cache(&plugins_dir.join(".plugin_metadata_cache.json"));
+    enabled_jars(&plugins_dir)
+        .into_iter()
+        .map(|(filename, path)| Jar {
+            project: cache
+                .sources
+                .get(&filename)
+                .map(|s| format!("{:?}/{}", s.provider, s.project_id)),
+            ids: extract_metadata_sync(&path)
+                .map(|p| vec![p.name.to_lowercase()])
+                .unwrap_or_default(),
+            filename,
+        })
+        .collect()
+}
+
+fn find_conflicts(new: &Jar, others: &[Jar], incompatible: &[(&str, &str)]) -> Vec<InstallWarning> {
+    let mut warnings = Vec::new();
+    for other in others.iter().filter(|o| o.filename != new.filename) {
+        if new.project.is_some() && new.project == other.project {
+            warnings.push(InstallWarning {
+                kind: ConflictKind::DuplicateProject,
+                message: format!("{} is another version of the same project", other.filename),
+                other_file: other.filename.clone(),
+            });
+            continue;
+        }
+
+        let shared: Vec<&str> = new
+            .ids
+            .iter()
+            .filter(|id| other.ids.contains(id))
+            .map(String::as_str)
+            .collect();
+        if !shared.is_empty() {
+            warnings.push(InstallWarning {
+                kind: ConflictKind::DuplicateId,
+                message: format!("{} also provides {}", other.filename, shared.join(", ")),
+                other_file: other.filename.clone(),
+            });
+            continue;
+        }
+
+        let clash = incompatible.iter().find(|(a, b)| {
+            let has = |jar: &Jar, id: &str| jar.ids.iter().any(|i| i == id);
+            (has(new, a) && has(other, b)) || (has(new, b) && has(other, a))
+        });
+        if let Some((a, b)) = clash {
+            warnings.push(InstallWarning {
+                kind: ConflictKind::Incompatible,
+                message: format!("{} and {} are known not to work together ({})", a, b, other.filename),
+                other_file: other.filename.clone(),
+            });
+        }
+    }
+    warnings
+}
+
+/// Checks a jar just installed into an instance's mods or plugins folder against the
+/// other enabled jars there.
+pub fn check_install_conflicts_sync(instance_path: &Path, kind: ContentKind, filename: &str) -> Vec<InstallWarning> {
+    let (jars, incompatible) = match kind {
+        ContentKind::Mod => (mod_jars(instance_path), INCOMPATIBLE_MODS),
+        ContentKind::Plugin => (plugin_jars(instance_path), INCOMPATIBLE_PLUGINS),
+    };
+    match jars.iter().find(|j| j.filename == filename) {
+        Some(new) => find_conflicts(new, &jars, incompatible),
+        None => Vec::new(),
+    }
+}
+
+pub async fn check_install_conflicts(
+    instance_path: impl AsRef<Path>,
+    kind: ContentKind,
+    filename: &str,
+) -> Result<Vec<InstallWarning>> {
+    let instance_path = instance_path.as_ref().to_path_buf();
+    let filename = filename.to_string();
+    Ok(tokio::task::spawn_blocking(move || check_install_conflicts_sync(&instance_path, kind, &filename)).await?)
+}
@@ -0,0 +1,175 @@
+use crate::instance::InstanceMetadata;
+use serde::Serialize;
+use std::net::{IpAddr, UdpSocket};
+
+pub mod stun;
+
+pub use stun::{DEFAULT_STUN_SERVERS, public_ip};
+
+pub const DEFAULT_JAVA_PORT: u16 = 25565;
+pub const DEFAULT_BEDROCK_PORT: u16 = 19132;
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AddressKind {
+    /// Same network as the host.
+    Lan,
+    /// The host's public IP; needs the port forwarded on the router.
+    Public,
+    /// An external tunnel such as playit.gg, reachable without port forwarding.
+    Tunnel,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ConnectionAddress {
+    pub kind: AddressKind,
+    /// What players type into the client, e.g. `203.0.113.7` or `192.168.1.20:25566`.
+    pub address: String,
+}
+
+/// Everything needed to tell players how to join, as shown on the dashboard.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ConnectionInfo {
+    pub port: u16,
+    pub bedrock: bool,
+    /// Best first: tunnel, then public, then LAN.
+    pub addresses: Vec<ConnectionAddress>,
+    /// Caveats such as port forwarding, shown next to the addresses.
+    pub notes: Vec<String>,
+    /// Ready-to-paste message for friends.
+    pub invite: String,
+}
+
+/// The IP other machines on the LAN reach us at. No packet is sent; connecting a UDP
+/// socket just makes the OS pick the outgoing interface.
+pub fn lan_ip() -> Option<IpAddr> {
+    let socket = UdpSocket::bind("0.0.0.0:0").ok()?;
+    socket.connect("8.8.8.8:80").ok()?;
+    let ip = socket.local_addr().ok()?.ip();
+    (!ip.is_unspecified()).then_some(ip)
+}
+
+/// `host`, or `host:port` when the port isn't the client's default.
+pub fn format_address(host: &str, port: u16, bedrock: bool) -> String {
+    let default_port = if bedrock {
+        DEFAULT_BEDROCK_PORT
+    } else {
+        DEFAULT_JAVA_PORT
+    };
+    let host = if host.contains(':') && !host.starts_with('[') {
+        format!("[{}]", host)
+    } else {
+        host.to_string()
+    };
+    if port == default_port {
+        host
+    } else {
+        format!("{}:{}", host, port)
+    }
+}
+
+/// 100.64.0.0/10, used by ISPs that share one public IP between customers. Forwarding
+/// a port on the home router doesn't help there.
+fn is_carrier_grade_nat(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => v4.octets()[0] == 100 && (v4.octets()[1] & 0xC0) == 64,
+        IpAddr::V6(_) => false,
+    }
+}
+
+pub fn build_connection_info(
+    name: &str,
+    version: &str,
+    port: u16,
+    bedrock: bool,
+    lan: Option<IpAddr>,
+    public: Option<IpAddr>,
+    tunnel: Option<&str>,
+) -> ConnectionInfo {
+    let mut addresses = Vec::new();
+    let mut notes = Vec::new();
+    let protocol = if bedrock { "UDP" } else { "TCP" };
+
+    if let Some(tunnel) = tunnel.map(str::trim).filter(|t| !t.is_empty()) {
+        addresses.push(ConnectionAddress {
+            kind: AddressKind::Tunnel,
+            address: tunnel.to_string(),
+        });
+    }
+    if let Some(public) = public {
+        addresses.push(ConnectionAddress {
+            kind: AddressKind::Public,
+            address: format_address(&public.to_string(), port, bedrock),
+        });
+        if is_carrier_grade_nat(&public) {
+            notes.push(
+                "Your ISP shares its public IP between customers (CGNAT), so port forwarding won't work. Use a tunnel instead."
+                    .to_string(),
+            );
+        } else {
+            let target = lan.map(|ip| format!(" to {}", ip)).unwrap_or_default();
+            notes.push(format!(
+                "Players outside your network need port {} ({}) forwarded{} on your router.",
+                port, protocol, target
+            ));
+        }
+    }
+    if let Some(lan) = lan {
+        addresses.push(ConnectionAddress {
+            kind: AddressKind::Lan,
+            address: format_address(&lan.to_string(), port, bedrock),
+        });
+    }
+
+    let mut invite = format!("Join {} (Minecraft {})!", name, version);
+    match addresses.first() {
+        Some(best) => invite.push_str(&format!("\nAddress: {}", best.address)),
+        None => notes.push("No network address could be found for this machine.".to_string()),
+    }
+    if bedrock {
+        invite.push_str(&format!("\nPort: {}", port));
+    }
+    if let Some(lan) = addresses.iter().skip(1).find(|a| a.kind == AddressKind::Lan) {
+        invite.push_str(&format!("\nOn the same Wi-Fi: {}", lan.address));
+    }
+
+    ConnectionInfo {
+        port,
+        bedrock,
+        addresses,
+        notes,
+        invite,
+    }
+}
+
+/// Connection info for an instance. The public IP comes from a STUN lookup, which is
+/// skipped silently when offline.
+pub async fn get_connection_info(instance: &InstanceMetadata) -> ConnectionInfo {
+    let bedrock = instance
+        .mod_loader
+        .as_deref()
+        .is_some_and(|l| l.eq_ignore_ascii_case("bedrock"));
+    let port = instance.port.unwrap_or(if bedrock {
+        DEFAULT_BEDROCK_PORT
+    } else {
+        DEFAULT_JAVA_PORT
+    });
+    // A server bound to one address is only reachable there
+    let bound = instance
+        .ip
+        .as_deref()
+        .and_then(|ip| ip.trim().parse::<IpAddr>().ok())
+        .filter(|ip| !ip.is_unspecified() && !ip.is_loopback());
+    let lan = bound.or_else(lan_ip);
+    let public = public_ip(&DEFAULT_STUN_SERVERS).await.ok();
+
+    build_connection_info(
+        &instance.name,
+        &instance.version,
+        port,
+        bedrock,
+        lan,
+        public,
+        Some(instance.settings.tunnel_address.as_str()),
+    )
+}
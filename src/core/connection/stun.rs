@@ -0,0 +1,130 @@
+use anyhow::{Context, Result, anyhow};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::time::Duration;
+use tokio::net::UdpSocket;
+
+/// Public STUN servers asked for the host's public address, in order.
+pub const DEFAULT_STUN_SERVERS: [&str; 2] = ["stun.cloudflare.com:3478", "stun.l.google.com:19302"];
+
+const STUN_TIMEOUT: Duration = Duration::from_secs(3);
+const MAGIC_COOKIE: u32 = 0x2112_A442;
+const BINDING_REQUEST: u16 = 0x0001;
+const BINDING_SUCCESS: u16 = 0x0101;
+const ATTR_MAPPED_ADDRESS: u16 = 0x0001;
+const ATTR_XOR_MAPPED_ADDRESS: u16 = 0x0020;
+
+/// An RFC 5389 Binding request with no attributes.
+pub fn binding_request(transaction_id: [u8; 12]) -> Vec<u8> {
+    let mut packet = Vec::with_capacity(20);
+    packet.extend_from_slice(&BINDING_REQUEST.to_be_bytes());
+    packet.extend_from_slice(&0u16.to_be_bytes());
+    packet.extend_from_slice(&MAGIC_COOKIE.to_be_bytes());
+    packet.extend_from_slice(&transaction_id);
+    packet
+}
+
+/// The address the STUN server saw us at, from a Binding success response.
+/// XOR-MAPPED-ADDRESS is preferred over the legacy MAPPED-ADDRESS.
+pub fn parse_binding_response(packet: &[u8], transaction_id: [u8; 12]) -> Result<SocketAddr> {
+    if packet.len() < 20 {
+        return Err(anyhow!("STUN response is too short"));
+    }
+    let message_type = u16::from_be_bytes([packet[0], packet[1]]);
+    let length = u16::from_be_bytes([packet[2], packet[3]]) as usize;
+    if message_type != BINDING_SUCCESS {
+        return Err(anyhow!("Unexpected STUN message type {:#06x}", message_type));
+    }
+    if packet[4..8] != MAGIC_COOKIE.to_be_bytes() || packet[8..20] != transaction_id {
+        return Err(anyhow!("STUN response doesn't match the request"));
+    }
+    let body = packet
+        .get(20..20 + length)
+        .ok_or_else(|| anyhow!("STUN response is truncated"))?;
+
+    let mut mapped = None;
+    let mut offset = 0;
+    while offset + 4 <= body.len() {
+        let attr_type = u16::from_be_bytes([body[offset], body[offset + 1]]);
+        let attr_len = u16::from_be_bytes([body[offset + 2], body[offset + 3]]) as usize;
+        let value = body
+            .get(offset + 4..offset + 4 + attr_len)
+            .ok_or_else(|| anyhow!("STUN attribute is truncated"))?;
+        match attr_type {
+            ATTR_XOR_MAPPED_ADDRESS => return decode_address(value, Some(transaction_id)),
+            ATTR_MAPPED_ADDRESS => mapped = Some(decode_address(value, None)?),
+            _ => {}
+        }
+        // Attributes are padded to 4 bytes
+        offset += 4 + attr_len.div_ceil(4) * 4;
+    }
+    mapped.ok_or_else(|| anyhow!("STUN response has no mapped address"))
+}
+
+fn decode_address(value: &[u8], xor_with: Option<[u8; 12]>) -> Result<SocketAddr> {
+    if value.len() < 8 {
+        return Err(anyhow!("STUN address attribute is too short"));
+    }
+    let cookie = MAGIC_COOKIE.to_be_bytes();
+    let mut port = u16::from_be_bytes([value[2], value[3]]);
+    if xor_with.is_some() {
+        port ^= (MAGIC_COOKIE >> 16) as u16;
+    }
+    let ip = match value[1] {
+        0x01 => {
+            let mut octets = [value[4], value[5], value[6], value[7]];
+            if xor_with.is_some() {
+                for (o, c) in octets.iter_mut().zip(cookie) {
+                    *o ^= c;
+                }
+            }
+            IpAddr::V4(Ipv4Addr::from(octets))
+        }
+        0x02 => {
+            let mut octets: [u8; 16] = value
+                .get(4..20)
+                .ok_or_else(|| anyhow!("STUN IPv6 address is too short"))?
+                .try_into()?;
+            if let Some(transaction_id) = xor_with {
+                let key = cookie.iter().chain(transaction_id.iter());
+                for (o, k) in octets.iter_mut().zip(key) {
+                    *o ^= k;
+                }
+            }
+            IpAddr::V6(Ipv6Addr::from(octets))
+        }
+        family => return Err(anyhow!("Unknown STUN address family {}", family)),
+    };
+    Ok(SocketAddr::new(ip, port))
+}
+
+/// Asks one STUN server for our public address.
+pub async fn stun_public_address(server: &str) -> Result<SocketAddr> {
+    let socket = UdpSocket::bind("0.0.0.0:0")
+        .await
+        .context("Failed to open a UDP socket")?;
+    socket
+        .connect(server)
+        .await
+        .with_context(|| format!("Failed to resolve STUN server {}", server))?;
+
+    let transaction_id: [u8; 12] = uuid::Uuid::new_v4().as_bytes()[..12].try_into()?;
+    socket.send(&binding_request(transaction_id)).await?;
+
+    let mut buf = [0u8; 512];
+    let len = tokio::time::timeout(STUN_TIMEOUT, socket.recv(&mut buf))
+        .await
+        .map_err(|_| anyhow!("STUN server {} didn't answer", server))??;
+    parse_binding_response(&buf[..len], transaction_id)
+}
+
+/// Our public IP from the first STUN server that answers.
+pub async fn public_ip(servers: &[&str]) -> Result<IpAddr> {
+    let mut last_error = anyhow!("No STUN servers configured");
+    for server in servers {
+        match stun_public_address(server).await {
+            Ok(address) => return Ok(address.ip()),
+            Err(e) => last_error = e,
+        }
+    }
+    Err(last_error)
+}
@@ -0,0 +1,201 @@
+use super::discovery::DiscoveredCommand;
+use serde::Serialize;
+
+/// Maximum number of suggestions returned for a single prefix.
+const MAX_SUGGESTIONS: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum CommandPlatform {
+    /// Built into every Java Edition server.
+    Vanilla,
+    /// Added by Bukkit, Spigot, Paper and their forks.
+    Bukkit,
+    /// Proxy commands (Velocity, BungeeCord).
+    Proxy,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CommandInfo {
+    pub name: &'static str,
+    pub usage: &'static str,
+    pub description: &'static str,
+    pub platform: CommandPlatform,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CommandSuggestion {
+    pub command: String,
+    pub usage: Option<String>,
+    pub description: Option<String>,
+    pub from_history: bool,
+}
+
+macro_rules! cmd {
+    ($platform:ident, $name:literal, $usage:literal, $desc:literal) => {
+        CommandInfo {
+            name: $name,
+            usage: $usage,
+            description: $desc,
+            platform: CommandPlatform::$platform,
+        }
+    };
+}
+
+const CATALOG: &[CommandInfo] = &[
+    cmd!(Vanilla, "advancement", "advancement (grant|revoke) <targets> <mode> [advancement]", "Grants or revokes advancements"),
+    cmd!(Vanilla, "ban", "ban <targets> [reason]", "Bans a player from the server"),
+    cmd!(Vanilla, "ban-ip", "ban-ip <target> [reason]", "Bans an IP address"),
+    cmd!(Vanilla, "banlist", "banlist [ips|players]", "Lists banned players or IPs"),
+    cmd!(Vanilla, "clear", "clear [targets] [item] [maxCount]", "Clears items from inventories"),
+    cmd!(Vanilla, "deop", "deop <targets>", "Revokes operator status"),
+    cmd!(Vanilla, "difficulty", "difficulty [peaceful|easy|normal|hard]", "Sets the difficulty"),
+    cmd!(Vanilla, "effect", "effect (give|clear) <targets> [effect] [seconds] [amplifier]", "Adds or removes status effects"),
+    cmd!(Vanilla, "enchant", "enchant <targets> <enchantment> [level]", "Enchants the held item"),
+    cmd!(Vanilla, "execute", "execute <subcommand> ... run <command>", "Runs a command with a modified context"),
+    cmd!(Vanilla, "experience", "experience (add|set|query) <targets> [amount] [levels|points]", "Manages player experience"),
+    cmd!(Vanilla, "fill", "fill <from> <to> <block> [destroy|hollow|keep|outline|replace]", "Fills a region with a block"),
+    cmd!(Vanilla, "gamemode", "gamemode <survival|creative|adventure|spectator> [target]", "Sets a player's game mode"),
+    cmd!(Vanilla, "gamerule", "gamerule <rule> [value]", "Sets or queries a game rule"),
+    cmd!(Vanilla, "give", "give <target> <item> [count]", "Gives an item to a player"),
+    cmd!(Vanilla, "help", "help [command]", "Shows command help"),
+    cmd!(Vanilla, "kick", "kick <targets> [reason]", "Kicks a player from the server"),
+    cmd!(Vanilla, "kill", "kill [targets]", "Kills entities"),
+    cmd!(Vanilla, "list", "list [uuids]", "Lists online players"),
+    cmd!(Vanilla, "locate", "locate (structure|biome|poi) <target>", "Locates the nearest structure or biome"),
+    cmd!(Vanilla, "msg", "msg <targets> <message>", "Sends a private message"),
+    cmd!(Vanilla, "op", "op <targets>", "Grants operator status"),
+    cmd!(Vanilla, "pardon", "pardon <targets>", "Removes a player ban"),
+    cmd!(Vanilla, "pardon-ip", "pardon-ip <target>", "Removes an IP ban"),
+    cmd!(Vanilla, "particle", "particle <name> [pos] [delta] [speed] [count]", "Creates particles"),
+    cmd!(Vanilla, "playsound", "playsound <sound> <source> <targets> [pos] [volume] [pitch]", "Plays a sound"),
+    cmd!(Vanilla, "reload", "reload", "Reloads data packs"),
+    cmd!(Vanilla, "save-all", "save-all [flush]", "Saves the world to disk"),
+    cmd!(Vanilla, "save-off", "save-off", "Disables automatic world saving"),
+    cmd!(Vanilla, "save-on", "save-on", "Enables automatic world saving"),
+    cmd!(Vanilla, "say", "say <message>", "Broadcasts a message to all players"),
+    cmd!(Vanilla, "scoreboard", "scoreboard (objectives|players) ...", "Manages scoreboard objectives and players"),
+    cmd!(Vanilla, "seed", "seed", "Shows the world seed"),
+    cmd!(Vanilla, "setblock", "setblock <pos> <block> [destroy|keep|replace]", "Places a block"),
+    cmd!(Vanilla, "setworldspawn", "setworldspawn [pos] [angle]", "Sets the world spawn"),
+    cmd!(Vanilla, "spawnpoint", "spawnpoint [targets] [pos] [angle]", "Sets a player's spawn point"),
+    cmd!(Vanilla, "stop", "stop", "Stops the server"),
+    cmd!(Vanilla, "summon", "summon <entity> [pos] [nbt]", "Summons an entity"),
+    cmd!(Vanilla, "tag", "tag <targets> (add|remove|list) [name]", "Manages entity tags"),
+    cmd!(Vanilla, "team", "team (add|remove|join|leave|list|modify) ...", "Manages teams"),
+    cmd!(Vanilla, "teleport", "teleport <targets> <destination|location>", "Teleports entities"),
+    cmd!(Vanilla, "tell", "tell <targets> <message>", "Sends a private message"),
+    cmd!(Vanilla, "time", "time (set|add|query) <value>", "Changes or queries the world time"),
+    cmd!(Vanilla, "title", "title <targets> (title|subtitle|actionbar|clear|reset|times) ...", "Shows a title to players"),
+    cmd!(Vanilla, "tp", "tp <targets> <destination|location>", "Teleports entities"),
+    cmd!(Vanilla, "weather", "weather (clear|rain|thunder) [duration]", "Sets the weather"),
+    cmd!(Vanilla, "whitelist", "whitelist (on|off|list|add|remove|reload) [targets]", "Manages the whitelist"),
+    cmd!(Vanilla, "worldborder", "worldborder (add|set|center|damage|get|warning) ...", "Manages the world border"),
+    cmd!(Vanilla, "xp", "xp (add|set|query) <targets> [amount] [levels|points]", "Manages player experience"),
+    cmd!(Bukkit, "mspt", "mspt", "Shows milliseconds per tick"),
+    cmd!(Bukkit, "paper", "paper (reload|version|dumpplugins|heap|mobcaps) ...", "Paper administration commands"),
+    cmd!(Bukkit, "plugins", "plugins", "Lists installed plugins"),
+    cmd!(Bukkit, "restart", "restart", "Restarts the server using the configured script"),
+    cmd!(Bukkit, "timings", "timings (on|off|paste|reset)", "Controls the timings profiler"),
+    cmd!(Bukkit, "tps", "tps", "Shows ticks per second"),
+    cmd!(Bukkit, "version", "version [plugin]", "Shows the server or plugin version"),
+    cmd!(Proxy, "alert", "alert <message>", "Broadcasts a message to every server"),
+    cmd!(Proxy, "end", "end", "Stops the BungeeCord proxy"),
+    cmd!(Proxy, "glist", "glist [all|server]", "Lists players on all servers"),
+    cmd!(Proxy, "send", "send <player|all|current> <server>", "Moves players to another server"),
+    cmd!(Proxy, "server", "server [name]", "Shows or switches the current server"),
+    cmd!(Proxy, "shutdown", "shutdown [reason]", "Stops the Velocity proxy"),
+    cmd!(Proxy, "velocity", "velocity (version|plugins|reload|dump|heap)", "Velocity administration commands"),
+];
+
+/// Returns the command platforms available on a server type.
+pub fn platforms_for(server_type: Option<&str>) -> Vec<CommandPlatform> {
+    match server_type.map(|t| t.to_lowercase()).as_deref() {
+        Some("velocity") | Some("bungeecord") => vec![CommandPlatform::Proxy],
+        Some("paper") | Some("purpur") | Some("pufferfish") | Some("spigot") | Some("bukkit") | Some("folia") => {
+            vec![CommandPlatform::Vanilla, CommandPlatform::Bukkit]
+        }
+        _ => vec![CommandPlatform::Vanilla],
+    }
+}
+
+/// Lists the catalog entries available on a server type.
+pub fn catalog_for(server_type: Option<&str>) -> Vec<&'static CommandInfo> {
+    let platforms = platforms_for(server_type);
+    CATALOG
+        .iter()
+        .filter(|c| platforms.contains(&c.platform))
+        .collect()
+}
+
+/// Suggests commands for a partially typed console line.
+///
+/// Previously sent commands that start with the input come first (most recent first),
+/// followed by command names. When the server's own command list has been discovered it
+/// replaces the static catalog, which then only fills in missing usage and descriptions.
+/// Once a command name is complete, its entry is returned so the usage can be shown as
+/// an argument hint.
+pub fn suggest_commands(
+    prefix: &str,
+    server_type: Option<&str>,
+    history: &[String],
+    discovered: &[DiscoveredCommand],
+) -> Vec<CommandSuggestion> {
+    let input = prefix.trim_start().trim_start_matches('/');
+    let input_lower = input.to_lowercase();
+    let (name, has_args) = match input_lower.split_once(' ') {
+        Some((name, _)) => (name, true),
+        None => (input_lower.as_str(), false),
+    };
+    let name_matches = |candidate: &str| {
+        if has_args {
+            candidate == name
+        } else {
+            candidate.starts_with(name)
+        }
+    };
+
+    let mut suggestions: Vec<CommandSuggestion> = Vec::new();
+    for command in history {
+        let command = command.trim_start_matches('/');
+        let seen = suggestions.iter().any(|s| s.command == command);
+        if !seen && command.to_lowercase().starts_with(&input_lower) && command != input {
+            suggestions.push(CommandSuggestion {
+                command: command.to_string(),
+                usage: None,
+                description: None,
+                from_history: true,
+            });
+        }
+    }
+
+    let catalog = catalog_for(server_type);
+    if discovered.is_empty() {
+        suggestions.extend(catalog.iter().filter(|c| name_matches(c.name)).map(|info| {
+            CommandSuggestion {
+                command: info.name.to_string(),
+                usage: Some(info.usage.to_string()),
+                description: Some(info.description.to_string()),
+                from_history: false,
+            }
+        }));
+    } else {
+        for command in discovered.iter().filter(|c| name_matches(&c.name)) {
+            let known = catalog.iter().find(|c| c.name == command.name);
+            suggestions.push(CommandSuggestion {
+                command: command.name.clone(),
+                usage: command
+                    .usage
+                    .clone()
+                    .or_else(|| known.map(|k| k.usage.to_string())),
+                description: command
+                    .description
+                    .clone()
+                    .or_else(|| known.map(|k| k.description.to_string())),
+                from_history: false,
+            });
+        }
+    }
+
+    suggestions.truncate(MAX_SUGGESTIONS);
+    suggestions
+}
@@ -0,0 +1,124 @@
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// File (relative to the instance root) caching the last discovered command list.
+pub const COMMAND_CACHE_FILE: &str = ".commands_cache.json";
+
+/// A command reported by the server itself, including plugin and mod commands.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DiscoveredCommand {
+    pub name: String,
+    pub usage: Option<String>,
+    pub description: Option<String>,
+}
+
+fn log_prefix_regex() -> &'static Regex {
+    static LOG_PREFIX: OnceLock<Regex> = OnceLock::new();
+    LOG_PREFIX.get_or_init(|| {
+        Regex::new(r"^(?:\[[^\]]*\]\s*)*:?\s*").unwrap()
+    })
+}
+
+/// Parses one line of `/help` output.
+///
+/// Handles the vanilla format (`/gamemode <gamemode> [<target>]`) and the Bukkit
+/// index format (`/plugins: Gets a list of plugins running on the server`).
+pub fn parse_help_line(line: &str) -> Option<DiscoveredCommand> {
+    static ANSI: OnceLock<Regex> = OnceLock::new();
+    let ansi = ANSI.get_or_init(|| Regex::new(r"\x1B\[[0-9;]*m|§.").unwrap());
+    let stripped = ansi.replace_all(line, "");
+    let content = log_prefix_regex().replace(stripped.trim(), "");
+    let content = content.trim().strip_prefix('/')?;
+
+    let (name, rest) = content.split_once(' ').unwrap_or((content, ""));
+    if let Some(name) = name.strip_suffix(':') {
+        return Some(DiscoveredCommand {
+            name: name.to_lowercase(),
+            usage: None,
+            description: Some(rest.trim().to_string()).filter(|d| !d.is_empty()),
+        });
+    }
+
+    if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || "-_:.".contains(c)) {
+        return None;
+    }
+    Some(DiscoveredCommand {
+        name: name.to_lowercase(),
+        usage: Some(content.to_string()).filter(|_| !rest.trim().is_empty()),
+        description: None,
+    })
+}
+
+/// Returns the total page count from a Bukkit help header such as
+/// `--------- Help: Index (1/4) ---------`.
+pub fn parse_help_page_count(line: &str) -> Option<u32> {
+    static PAGES: OnceLock<Regex> = OnceLock::new();
+    let re = PAGES.get_or_init(|| Regex::new(r"Help: Index \((\d+)/(\d+)\)").unwrap());
+    re.captures(line)?.get(2)?.as_str().parse().ok()
+}
+
+/// Parses the Brigadier tree written by the server's `--reports` data generator
+/// (`generated/reports/commands.json`).
+pub fn parse_commands_report(json: &str) -> Result<Vec<DiscoveredCommand>> {
+    let root: Value = serde_json::from_str(json).context("Invalid commands report")?;
+    let children = root
+        .get("children")
+        .and_then(Value::as_object)
+        .context("Commands report has no root children")?;
+
+    let mut commands: Vec<DiscoveredCommand> = children
+        .iter()
+        .map(|(name, node)| DiscoveredCommand {
+            name: name.clone(),
+            usage: summarize_arguments(node).map(|args| format!("{} {}", name, args)),
+            description: None,
+        })
+        .collect();
+    commands.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(commands)
+}
+
+/// Builds a one-level usage hint from a Brigadier node's children.
+fn summarize_arguments(node: &Value) -> Option<String> {
+    if let Some(redirect) = node.get("redirect").and_then(Value::as_array) {
+        let target = redirect.first()?.as_str()?;
+        return Some(format!("-> {}", target));
+    }
+    let children = node.get("children")?.as_object()?;
+    let alternatives: Vec<String> = children
+        .iter()
+        .filter_map(|(name, child)| match child.get("type").and_then(Value::as_str) {
+            Some("literal") => Some(name.clone()),
+            Some("argument") => Some(format!("<{}>", name)),
+            _ => None,
+        })
+        .collect();
+
+    match alternatives.len() {
+        0 => None,
+        1 => alternatives.into_iter().next(),
+        _ => Some(format!("({})", alternatives.join("|"))),
+    }
+}
+
+pub fn command_cache_path(instance_path: &Path) -> PathBuf {
+    instance_path.join(COMMAND_CACHE_FILE)
+}
+
+pub async fn load_command_cache(instance_path: &Path) -> Option<Vec<DiscoveredCommand>> {
+    let content = tokio::fs::read_to_string(command_cache_path(instance_path))
+        .await
+        .ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+pub async fn save_command_cache(instance_path: &Path, commands: &[DiscoveredCommand]) -> Result<()> {
+    let content = serde_json::to_string_pretty(commands)?;
+    tokio::fs::write(command_cache_path(instance_path), content)
+        .await
+        .context("Failed to write command cache")
+}
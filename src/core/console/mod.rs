@@ -0,0 +1,5 @@
+pub mod catalog;
+pub mod discovery;
+
+pub use catalog::*;
+pub use discovery::*;
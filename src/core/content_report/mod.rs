@@ -0,0 +1,135 @@
+use crate::cache::CacheManager;
+use crate::modrinth::ModrinthClient;
+use crate::mods::metadata::parsers::license::read_license_sync;
+use crate::mods::types::ModProvider;
+use crate::plugins::types::PluginProvider;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::Arc;
+
+pub mod status;
+
+pub use status::{UpdateCheckRecord, load_update_check, record_update_check};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ContentKind {
+    Mod,
+    Plugin,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateStatus {
+    /// When the last update check covering this file ran; `None` if it never has.
+    pub checked_at: Option<DateTime<Utc>>,
+    pub update_available: Option<bool>,
+    pub latest_version: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ContentReportEntry {
+    pub kind: ContentKind,
+    pub name: String,
+    pub filename: String,
+    pub enabled: bool,
+    pub version: Option<String>,
+    pub author: Option<String>,
+    /// `Modrinth`, `CurseForge`, `Spiget` or `Hangar`; `None` for manually added jars.
+    pub provider: Option<String>,
+    pub project_id: Option<String>,
+    pub license: Option<String>,
+    pub update: UpdateStatus,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ContentReport {
+    pub generated_at: DateTime<Utc>,
+    pub entries: Vec<ContentReportEntry>,
+}
+
+fn update_status(record: &UpdateCheckRecord, filename: &str) -> UpdateStatus {
+    match record.files.get(filename) {
+        Some(latest) => UpdateStatus {
+            checked_at: record.checked_at,
+            update_available: Some(latest.is_some()),
+            latest_version: latest.clone(),
+        },
+        None => UpdateStatus {
+            checked_at: None,
+            update_available: None,
+            latest_version: None,
+        },
+    }
+}
+
+/// Looks up a project's license on Modrinth. CurseForge, Spiget and Hangar do not
+/// expose licenses through the APIs used here.
+async fn provider_license(modrinth: &ModrinthClient, project_id: &str) -> Option<String> {
+    modrinth.get_project(project_id).await.ok().and_then(|p| p.license)
+}
+
+/// Lists every installed mod and plugin with its version, source, license and the
+/// outcome of the last update check.
+///
+/// Licenses declared in a mod's own metadata take precedence over the provider's.
+pub async fn generate_content_report(
+    instance_path: impl AsRef<Path>,
+    cache: Arc<CacheManager>,
+) -> Result<ContentReport> {
+    let instance_path = instance_path.as_ref();
+    let modrinth = ModrinthClient::new(cache);
+    let mut entries = Vec::new();
+
+    let mods_dir = instance_path.join("mods");
+    let mod_checks = load_update_check(&mods_dir).await;
+    for item in crate::mods::list_installed_mods(instance_path).await? {
+        let jar = mods_dir.join(&item.filename);
+        let mut license = tokio::task::spawn_blocking(move || read_license_sync(&jar))
+            .await?
+            .unwrap_or(None);
+        let modrinth_source = item.source.as_ref().filter(|s| s.provider == ModProvider::Modrinth);
+        if let (None, Some(source)) = (&license, modrinth_source) {
+            license = provider_license(&modrinth, &source.project_id).await;
+        }
+        entries.push(ContentReportEntry {
+            kind: ContentKind::Mod,
+            update: update_status(&mod_checks, &item.filename),
+            provider: item.source.as_ref().map(|s| format!("{:?}", s.provider)),
+            project_id: item.source.as_ref().map(|s| s.project_id.clone()),
+            name: item.name,
+            filename: item.filename,
+            enabled: item.enabled,
+            version: item.version,
+            author: item.author,
+            license,
+        });
+    }
+
+    let plugin_checks = load_update_check(&instance_path.join("plugins")).await;
+    for item in crate::plugins::list_installed_plugins(instance_path).await? {
+        let license = match item.source.as_ref() {
+            Some(source) if source.provider == PluginProvider::Modrinth => {
+                provider_license(&modrinth, &source.project_id).await
+            }
+            _ => None,
+        };
+        entries.push(ContentReportEntry {
+            kind: ContentKind::Plugin,
+            update: update_status(&plugin_checks, &item.filename),
+            provider: item.source.as_ref().map(|s| format!("{:?}", s.provider)),
+            project_id: item.source.as_ref().map(|s| s.project_id.clone()),
+            name: item.name,
+            filename: item.filename,
+            enabled: item.enabled,
+            version: item.version,
+            author: item.author,
+            license,
+        });
+    }
+
+    Ok(ContentReport {
+        generated_at: Utc::now(),
+        entries,
+    })
+}
@@ -0,0 +1,45 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use tokio::fs;
+
+/// Written next to the jars in `mods/` or `plugins/` after each update check.
+const STATUS_FILE: &str = ".update_check.json";
+
+/// Result of the most recent update check for a content folder.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateCheckRecord {
+    pub checked_at: Option<DateTime<Utc>>,
+    /// Latest version per checked filename; `None` when the file was already up to date.
+    #[serde(default)]
+    pub files: HashMap<String, Option<String>>,
+}
+
+/// Records which files an update check covered and the newer versions it found.
+pub async fn record_update_check(
+    content_dir: &Path,
+    checked: Vec<String>,
+    updates: impl IntoIterator<Item = (String, String)>,
+) -> Result<()> {
+    let mut files: HashMap<String, Option<String>> =
+        checked.into_iter().map(|f| (f, None)).collect();
+    for (filename, latest) in updates {
+        files.insert(filename, Some(latest));
+    }
+    let record = UpdateCheckRecord {
+        checked_at: Some(Utc::now()),
+        files,
+    };
+    fs::create_dir_all(content_dir).await?;
+    fs::write(content_dir.join(STATUS_FILE), serde_json::to_string(&record)?).await?;
+    Ok(())
+}
+
+pub async fn load_update_check(content_dir: &Path) -> UpdateCheckRecord {
+    match fs::read_to_string(content_dir.join(STATUS_FILE)).await {
+        Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+        Err(_) => UpdateCheckRecord::default(),
+    }
+}
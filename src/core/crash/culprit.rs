@@ -0,0 +1,56 @@
+use super::parser::ParsedCrashReport;
+
+/// Package prefixes of the game, loaders and common libraries; frames in these are
+/// never blamed on a mod or plugin.
+const PLATFORM_PACKAGES: &[&str] = &[
+    "java.", "javax.", "jdk.", "sun.", "com.sun.", "net.minecraft.", "com.mojang.",
+    "net.fabricmc.", "org.quiltmc.", "net.minecraftforge.", "net.neoforged.", "cpw.mods.",
+    "org.spongepowered.", "org.bukkit.", "org.spigotmc.", "io.papermc.", "com.destroystokyo.",
+    "io.netty.", "com.google.", "org.apache.", "org.slf4j.", "it.unimi.", "org.objectweb.",
+];
+
+/// Loader-owned module names seen in `TRANSFORMER/<id>@<version>/` frames.
+const PLATFORM_MODULES: &[&str] = &["minecraft", "forge", "neoforge", "java.base", "mixin"];
+
+fn normalize(s: &str) -> String {
+    s.chars().filter(|c| c.is_ascii_alphanumeric()).collect::<String>().to_lowercase()
+}
+
+/// Extracts the class name (or Forge module id) from a stack frame line.
+fn frame_target(line: &str) -> Option<&str> {
+    let frame = line.trim().strip_prefix("at ")?;
+    if let Some(rest) = frame.strip_prefix("TRANSFORMER/") {
+        let module = rest.split('@').next()?;
+        return (!PLATFORM_MODULES.contains(&module)).then_some(module);
+    }
+    // Drop a `module//` or `module@version/` prefix and the `(File.java:1)` suffix
+    let class = frame.split('(').next()?.rsplit('/').next()?;
+    (!PLATFORM_PACKAGES.iter().any(|p| class.starts_with(p))).then_some(class)
+}
+
+/// Guesses which installed mod or plugin caused a crash.
+///
+/// The loader's own "suspected mod" verdict wins; otherwise the first stack frame
+/// outside the game and loader is matched against installed content names. When no
+/// installed name matches, the frame's package is returned so there is still a lead.
+pub fn likely_culprit(report: &ParsedCrashReport, installed: &[String]) -> Option<String> {
+    if let Some(suspect) = report.suspected_mods.first() {
+        // `Name (id), Version: x` -> `Name`
+        let name = suspect.split([',', '(']).next().unwrap_or(suspect).trim();
+        return Some(name.to_string());
+    }
+
+    let target = report.stack_trace.iter().filter_map(|l| frame_target(l)).next()?;
+    let segments: Vec<String> = target.split(['.', '$', '-', '_']).map(normalize).collect();
+    let matched = installed.iter().find(|name| {
+        let name = normalize(name);
+        name.len() >= 3 && segments.contains(&name)
+    });
+    if let Some(name) = matched {
+        return Some(name.clone());
+    }
+
+    // Package without the class name, e.g. `com.example.coolmod`
+    let package = target.rsplit_once('.').map(|(p, _)| p).unwrap_or(target);
+    Some(package.to_string())
+}
@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+pub mod culprit;
+pub mod parser;
+mod store;
+
+pub use culprit::likely_culprit;
+pub use parser::{ParsedCrashReport, parse_crash_report};
+
+/// Folder (relative to the instance root) the server writes crash reports to.
+pub const CRASH_REPORTS_DIR: &str = "crash-reports";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrashReportSummary {
+    pub id: i64,
+    pub instance_id: Uuid,
+    pub filename: String,
+    pub detected_at: DateTime<Utc>,
+    /// `Time:` line of the report, as written by the server.
+    pub occurred_at: Option<String>,
+    pub description: Option<String>,
+    pub exception: Option<String>,
+    pub likely_culprit: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrashReport {
+    #[serde(flatten)]
+    pub summary: CrashReportSummary,
+    pub details: ParsedCrashReport,
+}
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Sections pulled out of a Minecraft `crash-reports/*.txt` file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ParsedCrashReport {
+    pub time: Option<String>,
+    pub description: Option<String>,
+    /// First line of the top-level stack trace, e.g. `java.lang.NullPointerException: ...`.
+    pub exception: Option<String>,
+    pub stack_trace: Vec<String>,
+    /// Entries from `Suspected Mod(s):` lines, as written by the loader.
+    pub suspected_mods: Vec<String>,
+    pub system_details: BTreeMap<String, String>,
+}
+
+fn is_no_suspect(value: &str) -> bool {
+    let value = value.trim().to_lowercase();
+    value.is_empty() || value == "none" || value.starts_with("no clue") || value == "unknown"
+}
+
+pub fn parse_crash_report(content: &str) -> ParsedCrashReport {
+    let mut report = ParsedCrashReport::default();
+    let lines: Vec<&str> = content.lines().collect();
+
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        let trimmed = line.trim();
+
+        if let Some(time) = trimmed.strip_prefix("Time: ").filter(|_| report.time.is_none()) {
+            report.time = Some(time.to_string());
+        } else if let Some(desc) = trimmed.strip_prefix("Description: ").filter(|_| report.description.is_none()) {
+            report.description = Some(desc.to_string());
+            // The top-level trace follows the description after one blank line
+            let mut j = i + 1;
+            while j < lines.len() && lines[j].trim().is_empty() {
+                j += 1;
+            }
+            while j < lines.len() && !lines[j].trim().is_empty() {
+                report.stack_trace.push(lines[j].trim_end().to_string());
+                j += 1;
+            }
+            report.exception = report.stack_trace.first().cloned();
+            i = j;
+            continue;
+        } else if let Some((_, value)) = trimmed
+            .split_once(':')
+            .filter(|(key, _)| *key == "Suspected Mod" || *key == "Suspected Mods")
+        {
+            if !is_no_suspect(value) {
+                report.suspected_mods.push(value.trim().to_string());
+            }
+            // NeoForge lists each suspect on its own indented line
+            let mut j = i + 1;
+            while j < lines.len() && lines[j].starts_with('\t') && !lines[j].starts_with("\t\t") {
+                let entry = lines[j].trim();
+                if entry.starts_with("at ") {
+                    break;
+                }
+                // Skip detail lines such as `Issue tracker URL: ...`
+                let is_detail = entry.contains(": ") && !entry.contains(", Version");
+                if !is_detail && !is_no_suspect(entry) {
+                    report.suspected_mods.push(entry.to_string());
+                }
+                j += 1;
+            }
+        } else if trimmed == "-- System Details --" {
+            for detail in lines[i + 1..].iter().filter(|l| l.starts_with('\t') && !l.starts_with("\t\t")) {
+                if let Some((key, value)) = detail.trim().split_once(": ") {
+                    report.system_details.insert(key.to_string(), value.to_string());
+                }
+            }
+            break;
+        }
+        i += 1;
+    }
+
+    report.suspected_mods.dedup();
+    report
+}
@@ -0,0 +1,150 @@
+use super::{
+    CRASH_REPORTS_DIR, CrashReport, CrashReportSummary, ParsedCrashReport, likely_culprit,
+    parse_crash_report,
+};
+use crate::instance::InstanceManager;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use sqlx::sqlite::SqliteRow;
+use std::collections::HashSet;
+use tokio::fs;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+const SUMMARY_COLUMNS: &str =
+    "id, instance_id, filename, detected_at, occurred_at, description, exception, likely_culprit";
+
+fn summary_from_row(row: &SqliteRow) -> CrashReportSummary {
+    let instance_id: String = row.get("instance_id");
+    let detected_at: String = row.get("detected_at");
+    CrashReportSummary {
+        id: row.get("id"),
+        instance_id: Uuid::parse_str(&instance_id).unwrap_or_default(),
+        filename: row.get("filename"),
+        detected_at: DateTime::parse_from_rfc3339(&detected_at)
+            .map(|d| d.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now()),
+        occurred_at: row.get("occurred_at"),
+        description: row.get("description"),
+        exception: row.get("exception"),
+        likely_culprit: row.get("likely_culprit"),
+    }
+}
+
+impl InstanceManager {
+    /// Parses crash reports in `crash-reports/` that have not been stored yet.
+    /// Returns how many new reports were found.
+    pub async fn scan_crash_reports(&self, instance_id: Uuid) -> Result<usize> {
+        let instance = self.get_instance(instance_id).await?.context("Instance not found")?;
+        let dir = instance.path.join(CRASH_REPORTS_DIR);
+        if !dir.exists() {
+            return Ok(0);
+        }
+
+        let known: HashSet<String> = sqlx::query("SELECT filename FROM crash_reports WHERE instance_id = ?")
+            .bind(instance_id.to_string())
+            .fetch_all(self.db.pool())
+            .await?
+            .iter()
+            .map(|row| row.get("filename"))
+            .collect();
+
+        let mut new_files = Vec::new();
+        let mut entries = fs::read_dir(&dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let filename = entry.file_name().to_string_lossy().to_string();
+            if filename.ends_with(".txt") && !known.contains(&filename) {
+                new_files.push((filename, entry.path()));
+            }
+        }
+        if new_files.is_empty() {
+            return Ok(0);
+        }
+        // Report file names start with a timestamp, so this stores them oldest first
+        new_files.sort();
+
+        let mut installed: Vec<String> = crate::mods::list_installed_mods(&instance.path)
+            .await
+            .map(|mods| mods.into_iter().map(|m| m.name).collect())
+            .unwrap_or_default();
+        if let Ok(plugins) = crate::plugins::list_installed_plugins(&instance.path).await {
+            installed.extend(plugins.into_iter().map(|p| p.name));
+        }
+
+        let mut stored = 0;
+        for (filename, path) in new_files {
+            let content = match fs::read(&path).await {
+                Ok(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+                Err(e) => {
+                    warn!("Failed to read crash report {:?}: {}", path, e);
+                    continue;
+                }
+            };
+            let parsed = parse_crash_report(&content);
+            let culprit = likely_culprit(&parsed, &installed);
+            sqlx::query(
+                "INSERT OR IGNORE INTO crash_reports
+                    (instance_id, filename, detected_at, occurred_at, description, exception, likely_culprit, details)
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(instance_id.to_string())
+            .bind(&filename)
+            .bind(Utc::now().to_rfc3339())
+            .bind(&parsed.time)
+            .bind(&parsed.description)
+            .bind(&parsed.exception)
+            .bind(&culprit)
+            .bind(serde_json::to_string(&parsed)?)
+            .execute(self.db.pool())
+            .await?;
+            stored += 1;
+        }
+
+        info!("Stored {} new crash report(s) for instance {}", stored, instance_id);
+        Ok(stored)
+    }
+
+    /// Lists an instance's crash reports, newest first, picking up new files on the way.
+    pub async fn list_crash_reports(&self, instance_id: Uuid) -> Result<Vec<CrashReportSummary>> {
+        if let Err(e) = self.scan_crash_reports(instance_id).await {
+            warn!("Failed to scan crash reports for {}: {}", instance_id, e);
+        }
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM crash_reports WHERE instance_id = ? ORDER BY filename DESC",
+            SUMMARY_COLUMNS
+        ))
+        .bind(instance_id.to_string())
+        .fetch_all(self.db.pool())
+        .await?;
+        Ok(rows.iter().map(summary_from_row).collect())
+    }
+
+    pub async fn get_crash_report(&self, instance_id: Uuid, report_id: i64) -> Result<Option<CrashReport>> {
+        let row = sqlx::query(&format!(
+            "SELECT {}, details FROM crash_reports WHERE instance_id = ? AND id = ?",
+            SUMMARY_COLUMNS
+        ))
+        .bind(instance_id.to_string())
+        .bind(report_id)
+        .fetch_optional(self.db.pool())
+        .await?;
+
+        Ok(row.map(|row| {
+            let details: String = row.get("details");
+            CrashReport {
+                summary: summary_from_row(&row),
+                details: serde_json::from_str::<ParsedCrashReport>(&details).unwrap_or_default(),
+            }
+        }))
+    }
+
+    pub async fn clear_crash_reports(&self, instance_id: Uuid) -> Result<()> {
+        sqlx::query("DELETE FROM crash_reports WHERE instance_id = ?")
+            .bind(instance_id.to_string())
+            .execute(self.db.pool())
+            .await?;
+        Ok(())
+    }
+}
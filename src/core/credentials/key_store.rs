@@ -0,0 +1,106 @@
+//! Where the key encrypting `credentials.enc` is kept. It lives in the OS keychain
+//! (Keychain, Credential Manager or Secret Service) so that copying the data folder
+//! doesn't hand out the credentials along with it.
+
+use aes_gcm::Aes256Gcm;
+use aes_gcm::aead::{KeyInit, OsRng};
+use anyhow::{Context, Result, anyhow};
+use base64::Engine;
+use std::path::Path;
+use std::sync::{Arc, Mutex, PoisonError};
+use tokio::fs;
+use tracing::info;
+
+/// Key file older versions kept next to `credentials.enc`; moved into the store on load.
+pub(crate) const LEGACY_KEY_FILE: &str = "credentials.key";
+const KEY_LEN: usize = 32;
+const KEYCHAIN_SERVICE: &str = "mc-server-wrapper";
+const KEYCHAIN_ACCOUNT: &str = "credentials-key";
+
+#[derive(Debug, Clone, Default)]
+pub enum KeyStore {
+    /// The OS keychain.
+    #[default]
+    Keychain,
+    /// Held in memory only, for tests.
+    Memory(Arc<Mutex<Option<Vec<u8>>>>),
+}
+
+impl KeyStore {
+    pub fn in_memory() -> Self {
+        Self::Memory(Arc::default())
+    }
+
+    /// Returns the credentials key, moving a key file left in `dir` by older versions
+    /// into the store first and creating a new key when there is none.
+    pub(crate) async fn load_or_create(&self, dir: &Path) -> Result<Vec<u8>> {
+        let legacy = dir.join(LEGACY_KEY_FILE);
+        if let Ok(key) = fs::read(&legacy).await {
+            check_len(&key)?;
+            self.store(&key).await?;
+            fs::remove_file(&legacy)
+                .await
+                .context("Failed to delete the old credentials key file")?;
+            info!("Moved the credentials key into the OS keychain");
+            return Ok(key);
+        }
+
+        if let Some(key) = self.get().await? {
+            return Ok(key);
+        }
+        let key = Aes256Gcm::generate_key(OsRng).to_vec();
+        self.store(&key).await?;
+        Ok(key)
+    }
+
+    async fn get(&self) -> Result<Option<Vec<u8>>> {
+        let key = match self {
+            Self::Keychain => tokio::task::spawn_blocking(read_keychain).await??,
+            Self::Memory(slot) => slot.lock().unwrap_or_else(PoisonError::into_inner).clone(),
+        };
+        if let Some(key) = &key {
+            check_len(key)?;
+        }
+        Ok(key)
+    }
+
+    async fn store(&self, key: &[u8]) -> Result<()> {
+        match self {
+            Self::Keychain => {
+                let encoded = base64::engine::general_purpose::STANDARD.encode(key);
+                tokio::task::spawn_blocking(move || {
+                    keychain_entry()?
+                        .set_password(&encoded)
+                        .context("Failed to save the credentials key to the OS keychain")
+                })
+                .await?
+            }
+            Self::Memory(slot) => {
+                *slot.lock().unwrap_or_else(PoisonError::into_inner) = Some(key.to_vec());
+                Ok(())
+            }
+        }
+    }
+}
+
+fn keychain_entry() -> Result<keyring::Entry> {
+    keyring::Entry::new(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT).context("Failed to open the OS keychain")
+}
+
+fn read_keychain() -> Result<Option<Vec<u8>>> {
+    match keychain_entry()?.get_password() {
+        Ok(encoded) => base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .map(Some)
+            .context("Credentials key in the OS keychain is corrupt"),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(anyhow!(e).context("Failed to read the credentials key from the OS keychain")),
+    }
+}
+
+fn check_len(key: &[u8]) -> Result<()> {
+    if key.len() != KEY_LEN {
+        return Err(anyhow!("Credentials key is corrupt"));
+    }
+    Ok(())
+}
@@ -0,0 +1,175 @@
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, PoisonError, RwLock};
+use tokio::fs;
+use tracing::warn;
+
+use crate::app_config::GlobalConfigManager;
+
+mod cipher;
+mod key_store;
+
+pub use key_store::KeyStore;
+pub(crate) use key_store::LEGACY_KEY_FILE;
+
+pub(crate) const CREDENTIALS_FILE: &str = "credentials.enc";
+
+/// API keys and tokens for third-party providers.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Credentials {
+    #[serde(default)]
+    pub curseforge_api_key: Option<String>,
+    /// Used for GitHub release downloads (e.g. Spiget resources hosted on GitHub).
+    #[serde(default)]
+    pub github_token: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum CredentialKind {
+    CurseForge,
+    GitHub,
+}
+
+/// Which credentials are configured, without exposing the secrets themselves.
+#[derive(Debug, Clone, Serialize)]
+pub struct CredentialStatus {
+    pub curseforge_api_key: bool,
+    pub github_token: bool,
+}
+
+impl Credentials {
+    pub fn get(&self, kind: CredentialKind) -> Option<&String> {
+        match kind {
+            CredentialKind::CurseForge => self.curseforge_api_key.as_ref(),
+            CredentialKind::GitHub => self.github_token.as_ref(),
+        }
+    }
+
+    pub fn set(&mut self, kind: CredentialKind, value: Option<String>) {
+        let value = value.map(|v| v.trim().to_string()).filter(|v| !v.is_empty());
+        match kind {
+            CredentialKind::CurseForge => self.curseforge_api_key = value,
+            CredentialKind::GitHub => self.github_token = value,
+        }
+    }
+
+    pub fn status(&self) -> CredentialStatus {
+        CredentialStatus {
+            curseforge_api_key: self.curseforge_api_key.is_some(),
+            github_token: self.github_token.is_some(),
+        }
+    }
+}
+
+/// Credentials currently in effect, owned by the [`GlobalConfigManager`] and shared with
+/// the [`CacheManager`](crate::cache::CacheManager) the provider clients are built from.
+#[derive(Debug, Clone, Default)]
+pub struct CredentialStore(Arc<RwLock<Credentials>>);
+
+impl CredentialStore {
+    /// Returns the stored CurseForge API key, falling back to the `CURSEFORGE_API_KEY` variable.
+    pub fn curseforge_api_key(&self) -> Option<String> {
+        let stored = self.0.read().unwrap_or_else(PoisonError::into_inner).curseforge_api_key.clone();
+        stored.or_else(|| std::env::var("CURSEFORGE_API_KEY").ok())
+    }
+
+    /// Returns the stored GitHub token, falling back to the `GITHUB_TOKEN` variable.
+    pub fn github_token(&self) -> Option<String> {
+        let stored = self.0.read().unwrap_or_else(PoisonError::into_inner).github_token.clone();
+        stored.or_else(|| std::env::var("GITHUB_TOKEN").ok())
+    }
+
+    fn replace(&self, credentials: &Credentials) {
+        *self.0.write().unwrap_or_else(PoisonError::into_inner) = credentials.clone();
+    }
+}
+
+impl GlobalConfigManager {
+    /// The credentials in effect, for handing to provider clients.
+    pub fn credentials(&self) -> CredentialStore {
+        self.credentials.clone()
+    }
+
+    fn credentials_dir(&self) -> PathBuf {
+        self.config_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default()
+    }
+
+    /// Loads and decrypts the stored credentials, making them the active ones. A file the
+    /// key can't decrypt, because the key was lost or the data folder came from another
+    /// machine, is moved aside to a `.bak` file and the credentials start out empty.
+    pub async fn load_credentials(&self) -> Result<Credentials> {
+        let path = self.credentials_dir().join(CREDENTIALS_FILE);
+        let credentials = if path.exists() {
+            let key = self.key_store.load_or_create(&self.credentials_dir()).await?;
+            let data = fs::read(&path).await.context("Failed to read credentials")?;
+            let parsed = cipher::decrypt(&key, &data).and_then(|plaintext| {
+                serde_json::from_slice(&plaintext).context("Failed to parse credentials")
+            });
+            match parsed {
+                Ok(credentials) => credentials,
+                Err(e) => {
+                    let stamp = chrono::Utc::now().format("%Y%m%d%H%M%S");
+                    let backup = path.with_extension(format!("enc.{}.bak", stamp));
+                    warn!("Stored credentials can't be read ({:#}); moving them to {:?}", e, backup);
+                    fs::rename(&path, &backup)
+                        .await
+                        .context("Failed to move unreadable credentials aside")?;
+                    Credentials::default()
+                }
+            }
+        } else {
+            Credentials::default()
+        };
+        self.credentials.replace(&credentials);
+        Ok(credentials)
+    }
+
+    /// Encrypts and stores the credentials, making them the active ones.
+    pub async fn save_credentials(&self, credentials: &Credentials) -> Result<()> {
+        let dir = self.credentials_dir();
+        fs::create_dir_all(&dir).await.context("Failed to create config directory")?;
+        let key = self.key_store.load_or_create(&dir).await?;
+        let data = cipher::encrypt(&key, &serde_json::to_vec(credentials)?)?;
+        fs::write(dir.join(CREDENTIALS_FILE), data)
+            .await
+            .context("Failed to write credentials")?;
+        self.credentials.replace(credentials);
+        Ok(())
+    }
+
+    /// Sets (or clears, with `None`) a single credential.
+    pub async fn set_credential(&self, kind: CredentialKind, value: Option<String>) -> Result<()> {
+        let mut credentials = self.load_credentials().await?;
+        credentials.set(kind, value);
+        self.save_credentials(&credentials).await
+    }
+}
+
+/// Checks a credential against its provider's API.
+pub async fn test_credential(client: &reqwest::Client, kind: CredentialKind, value: &str) -> Result<()> {
+    let request = match kind {
+        CredentialKind::CurseForge => client
+            .get("https://api.curseforge.com/v1/games/432")
+            .header("x-api-key", value),
+        CredentialKind::GitHub => client
+            .get("https://api.github.com/user")
+            .bearer_auth(value)
+            .header(
+                reqwest::header::USER_AGENT,
+                concat!("mc-server-wrapper/", env!("CARGO_PKG_VERSION")),
+            ),
+    };
+
+    let response = request.send().await.context("Failed to reach provider")?;
+    match response.status() {
+        s if s.is_success() => Ok(()),
+        reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN => {
+            Err(anyhow!("The provider rejected the credential"))
+        }
+        s => Err(anyhow!("Unexpected response from provider: {}", s)),
+    }
+}
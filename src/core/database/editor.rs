@@ -0,0 +1,190 @@
+use super::explorer::{DatabaseType, get_db_type};
+use crate::errors::AppError;
+use crate::server::ServerStatus;
+use anyhow::{Context, Result, anyhow};
+use serde::Deserialize;
+use serde_json::Value;
+use sqlx::sqlite::{SqliteArguments, SqliteConnectOptions};
+use sqlx::{Sqlite, SqlitePool};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use tokio::sync::Mutex;
+use tracing::info;
+
+type SqliteQuery<'q> = sqlx::query::Query<'q, Sqlite, SqliteArguments<'q>>;
+
+/// Guards writes to instance databases: the owning server has to be stopped, the file has to
+/// be a SQLite database inside the instance, and the first write to each file in a session
+/// is preceded by a `.bak` copy of it.
+#[derive(Default)]
+pub struct DatabaseEditor {
+    backed_up: Mutex<HashSet<PathBuf>>,
+}
+
+/// A database that passed the editor's checks. Only [`DatabaseEditor::open_for_write`]
+/// creates one, so every write goes through them.
+#[derive(Debug)]
+pub struct WritableDatabase {
+    path: PathBuf,
+    /// Set when this write made the session's backup.
+    pub backup: Option<PathBuf>,
+}
+
+/// New values for some columns of one row, addressed by its rowid.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RowUpdate {
+    pub table: String,
+    pub rowid: i64,
+    pub changes: serde_json::Map<String, Value>,
+}
+
+/// Where the session backup of `path` goes, e.g. `coreprotect/database.db.bak`.
+pub fn backup_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".bak");
+    path.with_file_name(name)
+}
+
+impl DatabaseEditor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Checks that `path` may be written while the server of `instance_path` is in `status`,
+    /// backing it up if this is the first write to it since the wrapper started.
+    pub async fn open_for_write(
+        &self,
+        instance_path: &Path,
+        status: ServerStatus,
+        path: &Path,
+    ) -> Result<WritableDatabase> {
+        if !matches!(status, ServerStatus::Stopped | ServerStatus::Crashed) {
+            return Err(AppError::Conflict("Stop the server before editing its databases".to_string()).into());
+        }
+        if get_db_type(path) != Some(DatabaseType::SQLite) {
+            return Err(anyhow!("Only SQLite databases can be edited"));
+        }
+        let canonical_base = instance_path
+            .canonicalize()
+            .with_context(|| format!("Failed to resolve {:?}", instance_path))?;
+        let path = path
+            .canonicalize()
+            .with_context(|| format!("Failed to resolve {:?}", path))?;
+        if !path.starts_with(&canonical_base) {
+            return Err(anyhow!("{:?} is not part of this instance", path));
+        }
+
+        let first_write = self.backed_up.lock().await.insert(path.clone());
+        let backup = if first_write {
+            match make_backup(&path).await {
+                Ok(backup) => Some(backup),
+                Err(e) => {
+                    // Let the next attempt try again rather than write without a backup
+                    self.backed_up.lock().await.remove(&path);
+                    return Err(e);
+                }
+            }
+        } else {
+            None
+        };
+        Ok(WritableDatabase { path, backup })
+    }
+}
+
+async fn make_backup(path: &Path) -> Result<PathBuf> {
+    let backup = backup_path(path);
+    tokio::fs::copy(path, &backup)
+        .await
+        .with_context(|| format!("Failed to back up {:?}", path))?;
+    // Pages not yet checkpointed live in the WAL; copy it along so the backup opens complete
+    let mut wal = path.as_os_str().to_os_string();
+    wal.push("-wal");
+    let wal = PathBuf::from(wal);
+    if wal.exists() {
+        let mut backup_wal = backup.as_os_str().to_os_string();
+        backup_wal.push("-wal");
+        tokio::fs::copy(&wal, PathBuf::from(backup_wal))
+            .await
+            .with_context(|| format!("Failed to back up {:?}", wal))?;
+    }
+    info!("Backed up {:?} before editing it", path);
+    Ok(backup)
+}
+
+impl WritableDatabase {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Applies `update` to its row. Returns the number of rows changed.
+    pub async fn update_row(&self, update: &RowUpdate) -> Result<u64> {
+        validate_identifier(&update.table)?;
+        if update.changes.is_empty() {
+            return Ok(0);
+        }
+        let assignments = update
+            .changes
+            .keys()
+            .map(|column| validate_identifier(column).map(|_| format!("\"{}\" = ?", column)))
+            .collect::<Result<Vec<_>>>()?;
+        let sql = format!(
+            "UPDATE \"{}\" SET {} WHERE rowid = ?",
+            update.table,
+            assignments.join(", ")
+        );
+        let query = update
+            .changes
+            .values()
+            .fold(sqlx::query(&sql), bind_value)
+            .bind(update.rowid);
+        self.execute(query).await
+    }
+
+    /// Deletes the row with `rowid`. Returns the number of rows deleted.
+    pub async fn delete_row(&self, table: &str, rowid: i64) -> Result<u64> {
+        validate_identifier(table)?;
+        let sql = format!("DELETE FROM \"{}\" WHERE rowid = ?", table);
+        self.execute(sqlx::query(&sql).bind(rowid)).await
+    }
+
+    /// Runs a single statement with `?` placeholders bound to `params`, in order. Returns the
+    /// number of rows it changed.
+    pub async fn execute_statement(&self, sql: &str, params: &[Value]) -> Result<u64> {
+        if sql.trim().trim_end_matches(';').contains(';') {
+            return Err(anyhow!("Run one statement at a time"));
+        }
+        let query = params.iter().fold(sqlx::query(sql), bind_value);
+        self.execute(query).await
+    }
+
+    async fn execute(&self, query: SqliteQuery<'_>) -> Result<u64> {
+        let options = SqliteConnectOptions::new().filename(&self.path);
+        let pool = SqlitePool::connect_with(options)
+            .await
+            .context(format!("Failed to open database at {:?}", self.path))?;
+        let result = query.execute(&pool).await;
+        pool.close().await;
+        Ok(result.context("Failed to write to the database")?.rows_affected())
+    }
+}
+
+fn bind_value<'q>(query: SqliteQuery<'q>, value: &'q Value) -> SqliteQuery<'q> {
+    match value {
+        Value::Null => query.bind(None::<String>),
+        Value::Bool(b) => query.bind(*b),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => query.bind(i),
+            None => query.bind(n.as_f64()),
+        },
+        Value::String(s) => query.bind(s.as_str()),
+        // Stored as their JSON text, the way plugins keep structured values in TEXT columns
+        other => query.bind(other.to_string()),
+    }
+}
+
+fn validate_identifier(name: &str) -> Result<()> {
+    if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return Err(anyhow!("Invalid table or column name: {}", name));
+    }
+    Ok(())
+}
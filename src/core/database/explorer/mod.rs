@@ -0,0 +1,142 @@
+use super::h2::H2Exporter;
+use crate::java::JavaManager;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::{SqliteConnectOptions, SqliteRow};
+use sqlx::{Column as _, Row as _, SqlitePool, TypeInfo as _, ValueRef as _};
+use std::path::{Path, PathBuf};
+
+mod query;
+mod scan;
+mod script;
+mod tables;
+
+pub use query::{check_read_only_query, execute_query, explain_query, history_key, stream_query};
+pub use scan::find_database_files;
+pub(crate) use scan::get_db_type;
+pub use tables::{get_table_columns, get_table_data, list_tables};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TableData {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<serde_json::Value>>,
+    /// SQLite rowid of each row, used to address rows when editing. Empty for sources that
+    /// can't be edited, like SQL scripts and tables declared `WITHOUT ROWID`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub row_ids: Vec<i64>,
+}
+
+/// A page of results from a custom query.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct QueryResult {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<serde_json::Value>>,
+    /// Whether more rows follow this page.
+    pub has_more: bool,
+    pub elapsed_ms: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ColumnInfo {
+    pub name: String,
+    pub data_type: String,
+    pub not_null: bool,
+    pub primary_key: bool,
+    pub default_value: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum DatabaseType {
+    SQLite,
+    H2,
+    SQL,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DatabaseFile {
+    pub name: String,
+    pub path: PathBuf,
+    pub db_type: DatabaseType,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DatabaseGroup {
+    pub name: String,
+    pub files: Vec<DatabaseFile>,
+}
+
+/// The file the explorer reads for `path`: H2 databases are exported to a SQL script with
+/// H2's own tooling, everything else is read as is.
+pub async fn readable_path(path: &Path, java_manager: &JavaManager) -> Result<PathBuf> {
+    match get_db_type(path) {
+        Some(DatabaseType::H2) => H2Exporter::new(java_manager).export(path).await,
+        _ => Ok(path.to_path_buf()),
+    }
+}
+
+/// Reads the content of a SQL script file.
+pub async fn read_sql_file(path: &Path) -> Result<String> {
+    let content = std::fs::read_to_string(path)?;
+    Ok(content)
+}
+
+/// Converts the columns of `row` from `first` on to JSON values.
+fn row_values(row: &SqliteRow, first: usize) -> Result<Vec<serde_json::Value>> {
+    let mut data_row = Vec::new();
+    for i in first..row.columns().len() {
+        let col = &row.columns()[i];
+
+        // Handle null values
+        let raw_value = row.try_get_raw(i)?;
+        if raw_value.is_null() {
+            data_row.push(serde_json::Value::Null);
+            continue;
+        }
+        // Expressions in custom queries have no declared type; go by the value's own
+        let type_name = if col.type_info().is_null() {
+            raw_value.type_info().name().to_uppercase()
+        } else {
+            col.type_info().name().to_uppercase()
+        };
+
+        let value = match type_name.as_str() {
+            "INTEGER" | "INT" | "TINYINT" | "SMALLINT" | "MEDIUMINT" | "BIGINT"
+            | "UNSIGNED BIG INT" | "INT2" | "INT8" => row
+                .try_get::<i64, _>(i)
+                .map(|v| serde_json::Value::Number(v.into()))
+                .unwrap_or(serde_json::Value::Null),
+            "REAL" | "DOUBLE" | "DOUBLE PRECISION" | "FLOAT" | "NUMERIC" | "DECIMAL" => row
+                .try_get::<f64, _>(i)
+                .map(|v| {
+                    serde_json::Number::from_f64(v)
+                        .map(serde_json::Value::Number)
+                        .unwrap_or_else(|| serde_json::Value::String(v.to_string()))
+                })
+                .unwrap_or(serde_json::Value::Null),
+            "BOOLEAN" | "BOOL" => row
+                .try_get::<bool, _>(i)
+                .map(serde_json::Value::Bool)
+                .unwrap_or(serde_json::Value::Null),
+            "BLOB" => row
+                .try_get::<Vec<u8>, _>(i)
+                .map(|v| serde_json::Value::String(format!("0x{}", hex::encode(v))))
+                .unwrap_or(serde_json::Value::Null),
+            _ => {
+                // Fallback to string for everything else (TEXT, VARCHAR, etc.)
+                row.try_get::<String, _>(i)
+                    .map(serde_json::Value::String)
+                    .unwrap_or(serde_json::Value::Null)
+            }
+        };
+        data_row.push(value);
+    }
+    Ok(data_row)
+}
+
+async fn get_connection(path: &Path) -> Result<SqlitePool> {
+    let options = SqliteConnectOptions::new().filename(path).read_only(true);
+
+    SqlitePool::connect_with(options)
+        .await
+        .context(format!("Failed to connect to database at {:?}", path))
+}
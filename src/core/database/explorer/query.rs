@@ -0,0 +1,193 @@
+use super::{DatabaseType, QueryResult, get_connection, get_db_type, row_values};
+use anyhow::{Context, Result};
+use futures_util::StreamExt;
+use sqlx::{Column as _, Executor as _, Row as _, SqlitePool};
+use std::path::Path;
+
+/// Checks that `sql` is a single statement that only reads: a SELECT, WITH, VALUES or
+/// EXPLAIN. The connection is opened read-only as well, so this is about clear errors, not
+/// the only guard. Returns the statement without its trailing semicolon.
+pub fn check_read_only_query(sql: &str) -> Result<&str> {
+    let sql = sql.trim().trim_end_matches(';').trim_end();
+    if sql.is_empty() {
+        return Err(anyhow::anyhow!("Enter a query to run"));
+    }
+    if has_second_statement(sql) {
+        return Err(anyhow::anyhow!("Run one statement at a time"));
+    }
+    let keyword = sql
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .next()
+        .unwrap_or_default()
+        .to_uppercase();
+    if !matches!(keyword.as_str(), "SELECT" | "WITH" | "VALUES" | "EXPLAIN") {
+        return Err(anyhow::anyhow!(
+            "Only SELECT, WITH, VALUES and EXPLAIN queries can be run here"
+        ));
+    }
+    Ok(sql)
+}
+
+/// Whether a `;` outside string literals, quoted names and comments splits `sql` in two,
+/// so `WHERE name = 'a;b'` still counts as one statement.
+fn has_second_statement(sql: &str) -> bool {
+    let mut chars = sql.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            ';' => return true,
+            // A doubled quote inside a literal closes and reopens it, which comes out the same
+            '\'' | '"' | '`' => {
+                chars.by_ref().find(|&n| n == c);
+            }
+            '[' => {
+                chars.by_ref().find(|&n| n == ']');
+            }
+            '-' if chars.peek() == Some(&'-') => {
+                chars.by_ref().find(|&n| n == '\n');
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = ' ';
+                chars.by_ref().find(|&n| std::mem::replace(&mut prev, n) == '*' && n == '/');
+            }
+            _ => {}
+        }
+    }
+    false
+}
+
+/// How a database is named in its query history: relative to the instance with forward
+/// slashes, so the history survives the instance moving and is the same on every OS.
+pub fn history_key(instance_path: &Path, path: &Path) -> String {
+    path.strip_prefix(instance_path)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+fn require_sqlite(path: &Path) -> Result<()> {
+    if get_db_type(path) != Some(DatabaseType::SQLite) {
+        return Err(anyhow::anyhow!(
+            "Queries can only be run against SQLite databases"
+        ));
+    }
+    Ok(())
+}
+
+/// Runs a read-only query and returns `limit` rows starting at `offset`. Rows are pulled one
+/// at a time and the query is dropped once the page is full, so large tables are never
+/// loaded whole.
+pub async fn execute_query(path: &Path, sql: &str, limit: u32, offset: u32) -> Result<QueryResult> {
+    require_sqlite(path)?;
+    let sql = check_read_only_query(sql)?;
+    let started = std::time::Instant::now();
+    let pool = get_connection(path).await?;
+
+    // EXPLAIN can't be nested in a subquery; its output is small enough to skip through
+    let is_explain = sql.to_uppercase().starts_with("EXPLAIN");
+    let (paged, skip) = if is_explain {
+        (sql.to_string(), offset as usize)
+    } else {
+        (
+            format!(
+                "SELECT * FROM ({}) LIMIT {} OFFSET {}",
+                sql,
+                limit as u64 + 1,
+                offset
+            ),
+            0,
+        )
+    };
+
+    let mut columns = None;
+    let mut rows = Vec::new();
+    let mut has_more = false;
+    let mut stream = sqlx::query(&paged).fetch(&pool).skip(skip);
+    while let Some(row) = stream.next().await {
+        let row = row.context("Query failed")?;
+        if rows.len() == limit as usize {
+            has_more = true;
+            break;
+        }
+        columns.get_or_insert_with(|| row.columns().iter().map(|c| c.name().to_string()).collect());
+        rows.push(row_values(&row, 0)?);
+    }
+    drop(stream);
+
+    let columns = match columns {
+        Some(columns) => columns,
+        None => query_columns(&pool, sql).await?,
+    };
+    Ok(QueryResult {
+        columns,
+        rows,
+        has_more,
+        elapsed_ms: started.elapsed().as_millis() as u64,
+    })
+}
+
+/// Shows how SQLite would run `sql`, using `EXPLAIN QUERY PLAN`.
+pub async fn explain_query(path: &Path, sql: &str) -> Result<QueryResult> {
+    let sql = check_read_only_query(sql)?;
+    if sql.to_uppercase().starts_with("EXPLAIN") {
+        return execute_query(path, sql, u32::MAX, 0).await;
+    }
+    execute_query(path, &format!("EXPLAIN QUERY PLAN {}", sql), u32::MAX, 0).await
+}
+
+/// Runs a read-only query and hands its rows to `on_batch` in batches of `batch_size`, for
+/// results too large to page through. `on_batch` gets the column names with every batch and
+/// returns false to stop early. Returns the number of rows delivered.
+pub async fn stream_query<F>(
+    path: &Path,
+    sql: &str,
+    batch_size: usize,
+    mut on_batch: F,
+) -> Result<u64>
+where
+    F: FnMut(&[String], Vec<Vec<serde_json::Value>>) -> bool,
+{
+    require_sqlite(path)?;
+    let sql = check_read_only_query(sql)?;
+    let batch_size = batch_size.max(1);
+    let pool = get_connection(path).await?;
+
+    let mut columns: Option<Vec<String>> = None;
+    let mut batch = Vec::with_capacity(batch_size);
+    let mut delivered = 0u64;
+    let mut stream = sqlx::query(sql).fetch(&pool);
+    while let Some(row) = stream.next().await {
+        let row = row.context("Query failed")?;
+        let columns = columns
+            .get_or_insert_with(|| row.columns().iter().map(|c| c.name().to_string()).collect());
+        batch.push(row_values(&row, 0)?);
+        if batch.len() == batch_size {
+            delivered += batch.len() as u64;
+            if !on_batch(
+                columns,
+                std::mem::replace(&mut batch, Vec::with_capacity(batch_size)),
+            ) {
+                return Ok(delivered);
+            }
+        }
+    }
+    drop(stream);
+
+    let columns = match columns {
+        Some(columns) => columns,
+        None => query_columns(&pool, sql).await?,
+    };
+    delivered += batch.len() as u64;
+    on_batch(&columns, batch);
+    Ok(delivered)
+}
+
+/// Column names of a query that returned no rows to read them from.
+async fn query_columns(pool: &SqlitePool, sql: &str) -> Result<Vec<String>> {
+    let described = pool.describe(sql).await.context("Query failed")?;
+    Ok(described
+        .columns()
+        .iter()
+        .map(|c| c.name().to_string())
+        .collect())
+}
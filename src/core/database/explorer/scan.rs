@@ -0,0 +1,130 @@
+use super::{DatabaseFile, DatabaseGroup, DatabaseType};
+use std::path::Path;
+use tracing::{info, warn};
+use walkdir::WalkDir;
+
+/// Scans for database files in the given root directory and groups them by their parent folder.
+pub fn find_database_files(root: &Path) -> Vec<DatabaseGroup> {
+    use std::collections::HashMap;
+    let mut groups: HashMap<String, Vec<DatabaseFile>> = HashMap::new();
+
+    info!("Scanning for databases in: {:?}", root);
+
+    // 1. Scan root level for databases (e.g. world-specific DBs if any, or general server DBs)
+    for entry in std::fs::read_dir(root)
+        .into_iter()
+        .flatten()
+        .filter_map(|e| e.ok())
+    {
+        if entry.file_type().map(|t| t.is_file()).unwrap_or(false) {
+            let path = entry.path();
+            if let Some(db_type) = get_db_type(&path) {
+                let db_file = DatabaseFile {
+                    name: path
+                        .file_name()
+                        .and_then(|n| n.to_str())
+                        .unwrap_or("Unknown")
+                        .to_string(),
+                    path: path.to_path_buf(),
+                    db_type,
+                };
+                groups
+                    .entry("Server Root".to_string())
+                    .or_default()
+                    .push(db_file);
+            }
+        }
+    }
+
+    // 2. Scan plugins directory
+    let plugins_root = root.join("plugins");
+    if plugins_root.exists() {
+        info!("Scanning plugins directory: {:?}", plugins_root);
+        for entry in WalkDir::new(&plugins_root)
+            .into_iter()
+            .filter_map(|e| e.ok())
+        {
+            if entry.file_type().is_file() {
+                let path = entry.path();
+                if let Some(db_type) = get_db_type(&path) {
+                    let relative_to_plugins = path.strip_prefix(&plugins_root).unwrap_or(path);
+                    let components: Vec<_> = relative_to_plugins.components().collect();
+
+                    let group_name = if components.len() > 1 {
+                        // The first component of the path relative to 'plugins/' is the plugin folder name
+                        components[0].as_os_str().to_string_lossy().to_string()
+                    } else {
+                        // File is directly in the 'plugins/' folder
+                        "General Plugins".to_string()
+                    };
+
+                    let db_file = DatabaseFile {
+                        name: path
+                            .file_name()
+                            .and_then(|n| n.to_str())
+                            .unwrap_or("Unknown")
+                            .to_string(),
+                        path: path.to_path_buf(),
+                        db_type,
+                    };
+
+                    groups.entry(group_name).or_default().push(db_file);
+                }
+            }
+        }
+    } else {
+        warn!("Plugins directory not found at {:?}", plugins_root);
+    }
+
+    let mut result: Vec<DatabaseGroup> = groups
+        .into_iter()
+        .map(|(name, files)| DatabaseGroup { name, files })
+        .collect();
+
+    // Sort groups: "Server Root" first, then others alphabetically
+    result.sort_by(|a, b| {
+        if a.name == "Server Root" {
+            std::cmp::Ordering::Less
+        } else if b.name == "Server Root" {
+            std::cmp::Ordering::Greater
+        } else {
+            a.name.cmp(&b.name)
+        }
+    });
+
+    info!("Found {} database groups", result.len());
+    result
+}
+
+pub(crate) fn get_db_type(path: &Path) -> Option<DatabaseType> {
+    let path_str = path.to_string_lossy().to_lowercase();
+
+    // Exclude trace files - these are logs, not databases
+    if path_str.ends_with(".trace.db") {
+        return None;
+    }
+
+    // H2 databases
+    if path_str.ends_with(".mv.db") || path_str.ends_with(".h2.db") {
+        return Some(DatabaseType::H2);
+    }
+
+    // SQL Scripts (H2 exports or general SQL)
+    if path_str.ends_with(".h2.sql") || path_str.ends_with(".sql") {
+        return Some(DatabaseType::SQL);
+    }
+
+    // SQLite or generic DB files
+    if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+        match ext.to_lowercase().as_str() {
+            "sqlite" | "sqlite3" => return Some(DatabaseType::SQLite),
+            "db" => {
+                // If it's just .db, it's usually SQLite in the Minecraft world (e.g., CoreProtect, LuckPerms)
+                return Some(DatabaseType::SQLite);
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
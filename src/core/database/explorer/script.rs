@@ -0,0 +1,134 @@
+use super::TableData;
+use regex::Regex;
+use std::collections::HashMap;
+
+pub(super) fn parse_h2_sql(content: &str) -> HashMap<String, TableData> {
+    let mut tables = HashMap::new();
+    let mut temp_data: HashMap<String, Vec<Vec<serde_json::Value>>> = HashMap::new();
+    let mut table_columns: HashMap<String, Vec<String>> = HashMap::new();
+
+    // 1. Parse temporary data: INSERT INTO O_... VALUES(...);
+    let insert_values_re = Regex::new(r"(?i)INSERT INTO (O_\d+) VALUES\((.*?)\);").unwrap();
+    for cap in insert_values_re.captures_iter(content) {
+        let temp_name = cap[1].to_string();
+        let values_str = &cap[2];
+
+        // Simple CSV-ish parser for values (handles strings in single quotes)
+        let mut row = Vec::new();
+        let mut current_val = String::new();
+        let mut in_quotes = false;
+        let mut chars = values_str.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '\'' => in_quotes = !in_quotes,
+                ',' if !in_quotes => {
+                    let val = current_val.trim();
+                    row.push(parse_sql_value(val));
+                    current_val.clear();
+                }
+                _ => current_val.push(c),
+            }
+        }
+        if !current_val.is_empty() {
+            row.push(parse_sql_value(current_val.trim()));
+        }
+
+        temp_data.entry(temp_name).or_default().push(row);
+    }
+
+    // 2. Parse table definitions: CREATE CACHED TABLE "PUBLIC"."TABLE_NAME"(...)
+    let create_table_re =
+        Regex::new(r#"(?is)CREATE CACHED TABLE "PUBLIC"\."(.*?)"\s*\((.*?)\)\;"#).unwrap();
+    for cap in create_table_re.captures_iter(content) {
+        let table_name = cap[1].to_string();
+        let columns_block = &cap[2];
+
+        let mut columns = Vec::new();
+        // Extract column names: "COL_NAME" TYPE ...
+        let col_re = Regex::new(r#""(.*?)"\s+[A-Z]+"#).unwrap();
+        for col_cap in col_re.captures_iter(columns_block) {
+            columns.push(col_cap[1].to_string());
+        }
+        table_columns.insert(table_name, columns);
+    }
+
+    // 3. Map temp data to real tables: INSERT INTO "PUBLIC"."TABLE_NAME" SELECT * FROM O_...;
+    let map_data_re =
+        Regex::new(r#"(?i)INSERT INTO "PUBLIC"\."(.*?)" SELECT \* FROM (O_\d+);"#).unwrap();
+    for cap in map_data_re.captures_iter(content) {
+        let table_name = cap[1].to_string();
+        let temp_name = cap[2].to_string();
+
+        if let (Some(columns), Some(rows)) =
+            (table_columns.get(&table_name), temp_data.get(&temp_name))
+        {
+            tables.insert(
+                table_name,
+                TableData {
+                    columns: columns.clone(),
+                    rows: rows.clone(),
+                    row_ids: Vec::new(),
+                },
+            );
+        }
+    }
+
+    // 4. Handle direct inserts: INSERT INTO "PUBLIC"."TABLE_NAME" VALUES(...);
+    let direct_insert_re =
+        Regex::new(r#"(?i)INSERT INTO "PUBLIC"\."(.*?)" VALUES\((.*?)\);"#).unwrap();
+    for cap in direct_insert_re.captures_iter(content) {
+        let table_name = cap[1].to_string();
+        let values_str = &cap[2];
+
+        // (Similar value parsing as above...)
+        let mut row = Vec::new();
+        let mut current_val = String::new();
+        let mut in_quotes = false;
+        let mut chars = values_str.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '\'' => in_quotes = !in_quotes,
+                ',' if !in_quotes => {
+                    row.push(parse_sql_value(current_val.trim()));
+                    current_val.clear();
+                }
+                _ => current_val.push(c),
+            }
+        }
+        if !current_val.is_empty() {
+            row.push(parse_sql_value(current_val.trim()));
+        }
+
+        if let Some(data) = tables.get_mut(&table_name) {
+            data.rows.push(row);
+        } else if let Some(columns) = table_columns.get(&table_name) {
+            tables.insert(
+                table_name.clone(),
+                TableData {
+                    columns: columns.clone(),
+                    rows: vec![row],
+                    row_ids: Vec::new(),
+                },
+            );
+        }
+    }
+
+    tables
+}
+
+fn parse_sql_value(val: &str) -> serde_json::Value {
+    if val.to_uppercase() == "NULL" {
+        serde_json::Value::Null
+    } else if val.starts_with('\'') && val.ends_with('\'') {
+        serde_json::Value::String(val[1..val.len() - 1].to_string())
+    } else if let Ok(n) = val.parse::<i64>() {
+        serde_json::Value::Number(n.into())
+    } else if let Ok(f) = val.parse::<f64>() {
+        serde_json::Number::from_f64(f)
+            .map(serde_json::Value::Number)
+            .unwrap_or(serde_json::Value::String(val.to_string()))
+    } else {
+        serde_json::Value::String(val.to_string())
+    }
+}
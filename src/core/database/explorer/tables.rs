@@ -0,0 +1,156 @@
+use super::script::parse_h2_sql;
+use super::{ColumnInfo, DatabaseType, TableData, get_connection, get_db_type, row_values};
+use anyhow::Result;
+use sqlx::{Column as _, Row as _};
+use std::path::Path;
+
+const ROWID_COLUMN: &str = "__rowid";
+
+/// Lists all tables in the database at the given path.
+pub async fn list_tables(path: &Path) -> Result<Vec<String>> {
+    let db_type = get_db_type(path).ok_or_else(|| anyhow::anyhow!("Unsupported database file"))?;
+
+    match db_type {
+        DatabaseType::SQLite => {
+            let pool = get_connection(path).await?;
+            let rows = sqlx::query(
+                "SELECT name FROM sqlite_master WHERE type='table' AND name NOT LIKE 'sqlite_%'",
+            )
+            .fetch_all(&pool)
+            .await?;
+
+            let tables = rows.iter().map(|row| row.get::<String, _>(0)).collect();
+            Ok(tables)
+        }
+        DatabaseType::H2 => Err(anyhow::anyhow!(
+            "H2 databases are read through their SQL export; resolve the path with readable_path first"
+        )),
+        DatabaseType::SQL => {
+            let content = std::fs::read_to_string(path)?;
+            let tables = parse_h2_sql(&content);
+            let mut table_names: Vec<String> = tables.keys().cloned().collect();
+            table_names.sort();
+            Ok(table_names)
+        }
+    }
+}
+
+/// Gets a page of data from a table.
+pub async fn get_table_data(
+    path: &Path,
+    table: &str,
+    limit: u32,
+    offset: u32,
+) -> Result<TableData> {
+    let db_type = get_db_type(path).ok_or_else(|| anyhow::anyhow!("Unsupported database file"))?;
+
+    if db_type == DatabaseType::SQL {
+        let content = std::fs::read_to_string(path)?;
+        let tables = parse_h2_sql(&content);
+        let table_data = tables
+            .get(table)
+            .ok_or_else(|| anyhow::anyhow!("Table not found in SQL script"))?;
+
+        let start = offset as usize;
+        let end = (offset + limit) as usize;
+        let rows_len = table_data.rows.len();
+        let paginated_rows = if start >= rows_len {
+            Vec::new()
+        } else {
+            table_data.rows[start..std::cmp::min(end, rows_len)].to_vec()
+        };
+
+        return Ok(TableData {
+            columns: table_data.columns.clone(),
+            rows: paginated_rows,
+            row_ids: Vec::new(),
+        });
+    }
+
+    if db_type != DatabaseType::SQLite {
+        return Err(anyhow::anyhow!(
+            "Data inspection is only supported for SQLite databases and SQL scripts."
+        ));
+    }
+
+    // Basic validation to prevent SQL injection for table names
+    if !table.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return Err(anyhow::anyhow!("Invalid table name: {}", table));
+    }
+
+    let pool = get_connection(path).await?;
+
+    // The rowid comes first so rows can be edited; tables declared WITHOUT ROWID have none
+    let with_rowid = format!(
+        "SELECT rowid AS \"{}\", * FROM {} LIMIT {} OFFSET {}",
+        ROWID_COLUMN, table, limit, offset
+    );
+    let (rows, first) = match sqlx::query(&with_rowid).fetch_all(&pool).await {
+        Ok(rows) => (rows, 1),
+        Err(_) => {
+            let query_str = format!("SELECT * FROM {} LIMIT {} OFFSET {}", table, limit, offset);
+            (sqlx::query(&query_str).fetch_all(&pool).await?, 0)
+        }
+    };
+
+    if rows.is_empty() {
+        let columns = get_table_columns(path, table).await?;
+        return Ok(TableData {
+            columns: columns.into_iter().map(|c| c.name).collect(),
+            rows: Vec::new(),
+            row_ids: Vec::new(),
+        });
+    }
+
+    let columns: Vec<String> = rows[0].columns()[first..]
+        .iter()
+        .map(|c| c.name().to_string())
+        .collect();
+    let mut data_rows = Vec::new();
+    let mut row_ids = Vec::new();
+
+    for row in rows {
+        if first == 1 {
+            row_ids.push(row.try_get::<i64, _>(0)?);
+        }
+        data_rows.push(row_values(&row, first)?);
+    }
+
+    Ok(TableData {
+        columns,
+        rows: data_rows,
+        row_ids,
+    })
+}
+
+/// Gets schema information for a table.
+pub async fn get_table_columns(path: &Path, table: &str) -> Result<Vec<ColumnInfo>> {
+    let db_type = get_db_type(path).ok_or_else(|| anyhow::anyhow!("Unsupported database file"))?;
+
+    if db_type != DatabaseType::SQLite {
+        return Err(anyhow::anyhow!(
+            "Schema inspection is only supported for SQLite databases."
+        ));
+    }
+
+    if !table.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return Err(anyhow::anyhow!("Invalid table name: {}", table));
+    }
+
+    let pool = get_connection(path).await?;
+    let query_str = format!("PRAGMA table_info({})", table);
+    let rows = sqlx::query(&query_str).fetch_all(&pool).await?;
+
+    let columns = rows
+        .iter()
+        .map(|row| ColumnInfo {
+            name: row.get(1),
+            data_type: row.get(2),
+            not_null: row.get::<i32, _>(3) != 0,
+            default_value: row.get(4),
+            primary_key: row.get::<i32, _>(5) != 0,
+        })
+        .collect();
+
+    Ok(columns)
+}
@@ -0,0 +1,153 @@
+use super::{H2_LEGACY_VERSION, H2_VERSION, export_dir, find_bundled_h2_jar, h2_database_name, jar, required_h2_major};
+use crate::java::JavaManager;
+use anyhow::{Context, Result, anyhow};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::fs;
+use tokio::process::Command;
+use tracing::{info, warn};
+
+const RECOVER_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Exports H2 databases to SQL scripts with H2's own `Recover` tool, run on a managed JRE.
+pub struct H2Exporter<'a> {
+    java_manager: &'a JavaManager,
+    tools_dir: PathBuf,
+    cache_root: PathBuf,
+}
+
+impl<'a> H2Exporter<'a> {
+    pub fn new(java_manager: &'a JavaManager) -> Self {
+        let data_dir = java_manager
+            .get_base_dir()
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+        Self::with_dirs(java_manager, data_dir.join("tools"), data_dir.join("cache").join("h2"))
+    }
+
+    pub fn with_dirs(java_manager: &'a JavaManager, tools_dir: PathBuf, cache_root: PathBuf) -> Self {
+        Self {
+            java_manager,
+            tools_dir,
+            cache_root,
+        }
+    }
+
+    /// Returns a `.h2.sql` script with the contents of the database at `db_path`, exporting it
+    /// first unless an export newer than the database is already cached.
+    ///
+    /// The database is copied before exporting, so a running server holding its lock is fine,
+    /// and `Recover` needs no credentials.
+    pub async fn export(&self, db_path: &Path) -> Result<PathBuf> {
+        let name = h2_database_name(db_path).ok_or_else(|| anyhow!("Not an H2 database: {:?}", db_path))?;
+        let dir = export_dir(&self.cache_root, db_path);
+        let script = dir.join(format!("{}.h2.sql", name));
+
+        let db_modified = fs::metadata(db_path)
+            .await
+            .with_context(|| format!("Failed to read {:?}", db_path))?
+            .modified()?;
+        if let Ok(meta) = fs::metadata(&script).await
+            && meta.modified()? >= db_modified
+        {
+            return Ok(script);
+        }
+
+        let header = read_header(db_path).await?;
+        let jar = self.h2_jar(db_path, required_h2_major(db_path, &header)).await?;
+        let java = self.java().await;
+
+        // Recover runs on a copy in a scratch folder that is deleted when it's dropped, so
+        // neither the copy nor partial output outlives the export
+        fs::create_dir_all(&self.cache_root)
+            .await
+            .context("Failed to create the H2 export directory")?;
+        let work = tempfile::Builder::new()
+            .prefix("export-")
+            .tempdir_in(&self.cache_root)
+            .context("Failed to create a folder for the H2 export")?;
+        let copy = work.path().join(db_path.file_name().unwrap_or_default());
+        fs::copy(db_path, &copy)
+            .await
+            .with_context(|| format!("Failed to copy {:?} for export", db_path))?;
+
+        info!("Exporting H2 database {:?} with {:?}", db_path, jar);
+        let mut command = Command::new(&java);
+        command
+            .arg("-cp")
+            .arg(&jar)
+            .arg("org.h2.tools.Recover")
+            .arg("-dir")
+            .arg(work.path())
+            .arg("-db")
+            .arg(&name)
+            .kill_on_drop(true);
+        let output = tokio::time::timeout(RECOVER_TIMEOUT, command.output())
+            .await
+            .map_err(|_| anyhow!("Exporting the H2 database timed out"))?
+            .with_context(|| format!("Failed to run Java at {:?}", java))?;
+
+        let exported = work.path().join(format!("{}.h2.sql", name));
+        if !output.status.success() || !exported.exists() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let reason = stderr.lines().find(|l| !l.trim().is_empty()).unwrap_or("no output");
+            return Err(anyhow!("H2 export failed: {}", reason));
+        }
+
+        if dir.exists() {
+            fs::remove_dir_all(&dir)
+                .await
+                .context("Failed to clear the previous H2 export")?;
+        }
+        fs::create_dir_all(&dir)
+            .await
+            .context("Failed to create the H2 export directory")?;
+        fs::rename(&exported, &script)
+            .await
+            .context("Failed to store the H2 export")?;
+        Ok(script)
+    }
+
+    /// Newest managed JRE, or `java` from PATH when none is installed.
+    async fn java(&self) -> PathBuf {
+        match self.java_manager.discover_installed_versions().await {
+            Ok(versions) => versions
+                .into_iter()
+                .max_by_key(|v| v.major_version)
+                .map(|v| v.path)
+                .unwrap_or_else(|| PathBuf::from("java")),
+            Err(e) => {
+                warn!("Failed to list managed Java versions: {}", e);
+                PathBuf::from("java")
+            }
+        }
+    }
+
+    async fn h2_jar(&self, db_path: &Path, major: u32) -> Result<PathBuf> {
+        if let Some(jar) = find_bundled_h2_jar(db_path, major) {
+            return Ok(jar);
+        }
+        let version = if major == 1 { H2_LEGACY_VERSION } else { H2_VERSION };
+        info!("Using the managed H2 {} to read {:?}", version, db_path);
+        jar::managed_jar(&self.java_manager.client, &self.java_manager.download_limits, &self.tools_dir, version).await
+    }
+}
+
+async fn read_header(path: &Path) -> Result<Vec<u8>> {
+    use tokio::io::AsyncReadExt;
+    let mut file = fs::File::open(path)
+        .await
+        .with_context(|| format!("Failed to open {:?}", path))?;
+    let mut header = vec![0u8; 4096];
+    let mut read = 0;
+    while read < header.len() {
+        let n = file.read(&mut header[read..]).await?;
+        if n == 0 {
+            break;
+        }
+        read += n;
+    }
+    header.truncate(read);
+    Ok(header)
+}
@@ -0,0 +1,90 @@
+//! The H2 jar `Recover` runs from. Jars downloaded from Maven Central are checked against
+//! the SHA-1 published with them, and their SHA-256 is recorded next to them and checked
+//! again before every run, so a jar replaced or damaged on disk is never executed.
+
+use crate::artifacts::HashAlgorithm;
+use crate::utils::{DownloadLimits, DownloadOptions, calculate_hash, download_with_resumption};
+use anyhow::{Context, Result, anyhow};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tracing::{info, warn};
+
+fn maven_url(version: &str) -> String {
+    format!("https://repo1.maven.org/maven2/com/h2database/h2/{0}/h2-{0}.jar", version)
+}
+
+/// File holding the SHA-256 the jar had when it was downloaded.
+fn checksum_path(jar: &Path) -> PathBuf {
+    jar.with_extension("jar.sha256")
+}
+
+/// Checks `jar` against the SHA-256 recorded when it was downloaded.
+pub(super) async fn verify_jar(jar: &Path) -> Result<()> {
+    let expected = fs::read_to_string(checksum_path(jar))
+        .await
+        .with_context(|| format!("No checksum recorded for {:?}", jar))?;
+    let actual = calculate_hash(jar, HashAlgorithm::Sha256).await?;
+    if !actual.eq_ignore_ascii_case(expected.trim()) {
+        return Err(anyhow!("{:?} doesn't match the checksum recorded when it was downloaded", jar));
+    }
+    Ok(())
+}
+
+/// Returns the verified H2 `version` jar in `tools_dir`, downloading it when it is missing
+/// or fails its checksum.
+pub(super) async fn managed_jar(
+    client: &reqwest::Client,
+    limits: &DownloadLimits,
+    tools_dir: &Path,
+    version: &str,
+) -> Result<PathBuf> {
+    let jar = tools_dir.join(format!("h2-{}.jar", version));
+    if jar.exists() {
+        match verify_jar(&jar).await {
+            Ok(()) => return Ok(jar),
+            Err(e) => {
+                warn!("Downloading H2 {} again: {}", version, e);
+                fs::remove_file(&jar).await.context("Failed to remove the H2 jar")?;
+            }
+        }
+    }
+
+    fs::create_dir_all(tools_dir)
+        .await
+        .context("Failed to create the tools directory")?;
+    let url = maven_url(version);
+    let published = client
+        .get(format!("{}.sha1", url))
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .context("Failed to fetch the H2 checksum")?
+        .text()
+        .await?;
+    let sha1 = published
+        .split_whitespace()
+        .next()
+        .filter(|h| h.len() == 40 && h.chars().all(|c| c.is_ascii_hexdigit()))
+        .ok_or_else(|| anyhow!("Maven Central returned an invalid checksum for H2 {}", version))?
+        .to_lowercase();
+
+    info!("Downloading H2 {}", version);
+    // Downloaded next to the jar and renamed, so an interrupted download isn't taken for the jar later
+    let part = tools_dir.join(format!("h2-{}.download.jar", version));
+    let options = DownloadOptions {
+        url: &url,
+        target_path: &part,
+        expected_hash: Some((&sha1, HashAlgorithm::Sha1)),
+        total_size: None,
+    };
+    download_with_resumption(client, limits, options, |_, _| {})
+        .await
+        .with_context(|| format!("Failed to download H2 {}", version))?;
+
+    let sha256 = calculate_hash(&part, HashAlgorithm::Sha256).await?;
+    fs::write(checksum_path(&jar), &sha256)
+        .await
+        .context("Failed to record the H2 checksum")?;
+    fs::rename(&part, &jar).await.context("Failed to store the H2 jar")?;
+    Ok(jar)
+}
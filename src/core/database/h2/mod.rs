@@ -0,0 +1,88 @@
+use regex::Regex;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+mod export;
+mod jar;
+
+pub use export::H2Exporter;
+
+/// Last release of the 1.4 line; reads page-store (`.h2.db`) files and MVStore format 1.
+pub const H2_LEGACY_VERSION: &str = "1.4.200";
+/// Reads MVStore formats 2 and 3, written by H2 2.x (LuckPerms 5.4+).
+pub const H2_VERSION: &str = "2.2.224";
+
+const MAX_LIB_DEPTH: usize = 3;
+
+/// Database file name without its H2 suffix, e.g. `luckperms-h2-v2` for `luckperms-h2-v2.mv.db`.
+pub fn h2_database_name(path: &Path) -> Option<String> {
+    let name = path.file_name()?.to_string_lossy();
+    let lower = name.to_lowercase();
+    [".mv.db", ".h2.db"]
+        .iter()
+        .find(|suffix| lower.ends_with(*suffix))
+        .map(|suffix| name[..name.len() - suffix.len()].to_string())
+}
+
+/// Reads the `format:N` field of an MVStore file header. H2 1.4 writes format 1, 2.x writes 2 or later.
+pub fn store_format(header: &[u8]) -> Option<u32> {
+    static FORMAT_REGEX: OnceLock<Regex> = OnceLock::new();
+    let format = FORMAT_REGEX.get_or_init(|| Regex::new(r"(?:^|,)format:([0-9a-fA-F]+)").unwrap());
+    let header = String::from_utf8_lossy(&header[..header.len().min(4096)]);
+    let header = header.split('\n').next()?;
+    u32::from_str_radix(&format.captures(header)?[1], 16).ok()
+}
+
+/// H2 major version able to read the file: page stores and MVStore format 1 need 1.x, anything newer 2.x.
+pub fn required_h2_major(path: &Path, header: &[u8]) -> u32 {
+    if path.to_string_lossy().to_lowercase().ends_with(".h2.db") {
+        return 1;
+    }
+    match store_format(header) {
+        Some(format) if format >= 2 => 2,
+        Some(_) => 1,
+        None => 2,
+    }
+}
+
+/// Version of an H2 jar from its file name, e.g. `h2-driver-2.1.214.jar` or `h2-1.4.199.jar`.
+pub fn h2_jar_version(file_name: &str) -> Option<(u32, u32, u32)> {
+    static JAR_REGEX: OnceLock<Regex> = OnceLock::new();
+    let jar = JAR_REGEX.get_or_init(|| Regex::new(r"(?i)^h2(?:-driver)?-(\d+)\.(\d+)\.(\d+)\.jar$").unwrap());
+    let caps = jar.captures(file_name)?;
+    Some((caps[1].parse().ok()?, caps[2].parse().ok()?, caps[3].parse().ok()?))
+}
+
+/// Looks for an H2 jar the owning plugin already ships (LuckPerms keeps the one it writes its
+/// database with in `libs/`), walking up from the database until the `plugins` folder.
+/// Using that exact version avoids format mismatches between H2 releases.
+pub fn find_bundled_h2_jar(db_path: &Path, major: u32) -> Option<PathBuf> {
+    let mut best: Option<((u32, u32, u32), PathBuf)> = None;
+    for dir in db_path.ancestors().skip(1).take(MAX_LIB_DEPTH) {
+        if dir.file_name().is_some_and(|n| n.eq_ignore_ascii_case("plugins")) {
+            break;
+        }
+        for candidate in [dir.to_path_buf(), dir.join("libs"), dir.join("lib")] {
+            for entry in std::fs::read_dir(&candidate).into_iter().flatten().flatten() {
+                let name = entry.file_name().to_string_lossy().to_string();
+                if let Some(version) = h2_jar_version(&name)
+                    && version.0 == major
+                    && best.as_ref().is_none_or(|(v, _)| version > *v)
+                {
+                    best = Some((version, entry.path()));
+                }
+            }
+        }
+    }
+    best.map(|(_, path)| path)
+}
+
+/// Where the export of `db_path` is kept. Named after the full path so two plugins' databases
+/// with the same file name don't share an export.
+pub fn export_dir(cache_root: &Path, db_path: &Path) -> PathBuf {
+    let mut hasher = DefaultHasher::new();
+    db_path.hash(&mut hasher);
+    cache_root.join(format!("{:016x}", hasher.finish()))
+}
@@ -3,7 +3,9 @@ use anyhow::{Result, Context};
 use sqlx::sqlite::{SqlitePool, SqliteConnectOptions};
 use std::str::FromStr;
 
+pub mod editor;
 pub mod explorer;
+pub mod h2;
 
 pub struct Database {
     pool: SqlitePool,
@@ -49,6 +51,155 @@ impl Database {
         .await
         .context("Failed to create instances table")?;
 
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS command_history (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                instance_id TEXT NOT NULL,
+                command TEXT NOT NULL,
+                sent_at TEXT NOT NULL
+            )"
+        )
+        .execute(&self.pool)
+        .await
+        .context("Failed to create command_history table")?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_command_history_instance
+                ON command_history (instance_id, id)"
+        )
+        .execute(&self.pool)
+        .await
+        .context("Failed to create command_history index")?;
+
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS query_history (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                instance_id TEXT NOT NULL,
+                db_path TEXT NOT NULL,
+                query TEXT NOT NULL,
+                ran_at TEXT NOT NULL
+            )"
+        )
+        .execute(&self.pool)
+        .await
+        .context("Failed to create query_history table")?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_query_history_database
+                ON query_history (instance_id, db_path, id)"
+        )
+        .execute(&self.pool)
+        .await
+        .context("Failed to create query_history index")?;
+
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS crash_reports (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                instance_id TEXT NOT NULL,
+                filename TEXT NOT NULL,
+                detected_at TEXT NOT NULL,
+                occurred_at TEXT,
+                description TEXT,
+                exception TEXT,
+                likely_culprit TEXT,
+                details TEXT NOT NULL,
+                UNIQUE (instance_id, filename)
+            )"
+        )
+        .execute(&self.pool)
+        .await
+        .context("Failed to create crash_reports table")?;
+
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS metrics_samples (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                instance_id TEXT NOT NULL,
+                sampled_at TEXT NOT NULL,
+                cpu_usage REAL NOT NULL,
+                memory_usage INTEGER NOT NULL,
+                player_count INTEGER NOT NULL,
+                tps REAL,
+                mspt REAL
+            )"
+        )
+        .execute(&self.pool)
+        .await
+        .context("Failed to create metrics_samples table")?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_metrics_samples_instance
+                ON metrics_samples (instance_id, sampled_at)"
+        )
+        .execute(&self.pool)
+        .await
+        .context("Failed to create metrics_samples index")?;
+
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS notification_rules (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                instance_id TEXT,
+                name TEXT NOT NULL,
+                trigger TEXT NOT NULL,
+                title TEXT NOT NULL,
+                body TEXT NOT NULL,
+                enabled INTEGER NOT NULL DEFAULT 1,
+                created_at TEXT NOT NULL
+            )"
+        )
+        .execute(&self.pool)
+        .await
+        .context("Failed to create notification_rules table")?;
+
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS instance_templates (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                name TEXT NOT NULL UNIQUE,
+                data TEXT NOT NULL,
+                created_at TEXT NOT NULL
+            )"
+        )
+        .execute(&self.pool)
+        .await
+        .context("Failed to create instance_templates table")?;
+
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS timeline_events (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                instance_id TEXT NOT NULL,
+                kind TEXT NOT NULL,
+                occurred_at TEXT NOT NULL,
+                summary TEXT NOT NULL,
+                details TEXT
+            )"
+        )
+        .execute(&self.pool)
+        .await
+        .context("Failed to create timeline_events table")?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_timeline_events_instance
+                ON timeline_events (instance_id, occurred_at)"
+        )
+        .execute(&self.pool)
+        .await
+        .context("Failed to create timeline_events index")?;
+
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS profiler_sessions (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                instance_id TEXT NOT NULL,
+                started_at TEXT NOT NULL,
+                finished_at TEXT,
+                duration_secs INTEGER,
+                status TEXT NOT NULL,
+                url TEXT,
+                error TEXT
+            )"
+        )
+        .execute(&self.pool)
+        .await
+        .context("Failed to create profiler_sessions table")?;
+
         Ok(())
     }
 
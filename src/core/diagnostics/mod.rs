@@ -0,0 +1,157 @@
+use crate::app_config::ManagedJavaVersion;
+use crate::crash::CRASH_REPORTS_DIR;
+use crate::doctor::java_major_version;
+use crate::instance::InstanceMetadata;
+use crate::log_files::{list_log_files, read_log_tail};
+use anyhow::{Context, Result};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tracing::{info, warn};
+use zip::write::SimpleFileOptions;
+
+mod redact;
+mod system;
+
+pub use redact::redact;
+use redact::sanitize_instance;
+use system::{JavaInfo, Summary, system_info};
+
+/// Bytes kept from the end of each log, enough to see what led up to a problem.
+pub const LOG_TAIL_BYTES: u64 = 512 * 1024;
+
+/// Newest crash reports included per instance.
+const MAX_CRASH_REPORTS: usize = 5;
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct DiagnosticsOptions {
+    /// Replace IP addresses, player names and JVM property values with placeholders.
+    pub redact: bool,
+}
+
+/// What goes into a diagnostics bundle, gathered by the caller.
+#[derive(Debug, Clone)]
+pub struct DiagnosticsSources {
+    /// Folder holding the wrapper's own `logs/`.
+    pub app_log_root: PathBuf,
+    pub instances: Vec<InstanceMetadata>,
+    /// Java runtimes found in the wrapper's Java folder.
+    pub java_versions: Vec<ManagedJavaVersion>,
+}
+
+/// A written diagnostics bundle.
+#[derive(Debug, Clone, Serialize)]
+pub struct DiagnosticsBundle {
+    pub path: PathBuf,
+    pub files: Vec<String>,
+    pub size: u64,
+    pub redacted: bool,
+}
+
+/// The newest crash reports of an instance, newest first.
+async fn latest_crash_reports(instance_path: &Path) -> Vec<(String, String)> {
+    let Ok(mut entries) = fs::read_dir(instance_path.join(CRASH_REPORTS_DIR)).await else {
+        return vec![];
+    };
+    let mut files = Vec::new();
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.ends_with(".txt") {
+            files.push((name, entry.path()));
+        }
+    }
+    // Report file names start with a timestamp
+    files.sort_by(|a, b| b.0.cmp(&a.0));
+
+    let mut reports = Vec::new();
+    for (name, path) in files.into_iter().take(MAX_CRASH_REPORTS) {
+        match fs::read(&path).await {
+            Ok(bytes) => reports.push((name, String::from_utf8_lossy(&bytes).into_owned())),
+            Err(e) => warn!("Failed to read crash report {:?}: {}", path, e),
+        }
+    }
+    reports
+}
+
+/// Packs the wrapper's logs, each instance's sanitized metadata, latest.log tail and
+/// newest crash reports, the Java runtimes found and system information into one zip
+/// at `dest`, for attaching to a bug report.
+pub async fn export_diagnostics(
+    sources: DiagnosticsSources,
+    dest: &Path,
+    options: DiagnosticsOptions,
+) -> Result<DiagnosticsBundle> {
+    let mut entries: Vec<(String, String)> = Vec::new();
+
+    for log in list_log_files(&sources.app_log_root).await.unwrap_or_default() {
+        if log.compressed {
+            continue;
+        }
+        match read_log_tail(&sources.app_log_root, &log.name, LOG_TAIL_BYTES).await {
+            Ok(page) => entries.push((format!("wrapper-logs/{}", log.name), page.content)),
+            Err(e) => warn!("Failed to read wrapper log {}: {}", log.name, e),
+        }
+    }
+
+    for instance in &sources.instances {
+        let dir = format!("instances/{}", instance.id);
+        let latest = read_log_tail(&instance.path, "latest.log", LOG_TAIL_BYTES).await.ok();
+        let metadata = sanitize_instance(instance, options.redact);
+        entries.push((format!("{}/instance.json", dir), serde_json::to_string_pretty(&metadata)?));
+        if let Some(latest) = latest {
+            entries.push((format!("{}/latest.log", dir), latest.content));
+        }
+        for (name, report) in latest_crash_reports(&instance.path).await {
+            entries.push((format!("{}/{}/{}", dir, CRASH_REPORTS_DIR, name), report));
+        }
+    }
+
+    let java = JavaInfo {
+        managed: sources.java_versions,
+        path_java: java_major_version(Path::new("java")).await,
+    };
+    entries.push(("java.json".to_string(), serde_json::to_string_pretty(&java)?));
+
+    let summary = Summary {
+        generated_at: Utc::now(),
+        wrapper_version: env!("CARGO_PKG_VERSION").to_string(),
+        redacted: options.redact,
+        instance_count: sources.instances.len(),
+        system: tokio::task::spawn_blocking(system_info).await?,
+    };
+    entries.insert(0, ("summary.json".to_string(), serde_json::to_string_pretty(&summary)?));
+
+    if options.redact {
+        for (_, content) in &mut entries {
+            *content = redact(content);
+        }
+    }
+
+    let files: Vec<String> = entries.iter().map(|(name, _)| name.clone()).collect();
+    let dest = dest.to_path_buf();
+    let zip_path = dest.clone();
+    tokio::task::spawn_blocking(move || {
+        let file = File::create(&zip_path).with_context(|| format!("Failed to create {}", zip_path.display()))?;
+        let mut zip = zip::ZipWriter::new(file);
+        let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+        for (name, content) in entries {
+            zip.start_file(name, options)?;
+            zip.write_all(content.as_bytes())?;
+        }
+        zip.finish().context("Failed to finish diagnostics archive")?;
+        Ok::<(), anyhow::Error>(())
+    })
+    .await??;
+
+    let size = fs::metadata(&dest).await.map(|m| m.len()).unwrap_or(0);
+    info!("Wrote diagnostics bundle with {} files to {:?}", files.len(), dest);
+    Ok(DiagnosticsBundle {
+        path: dest,
+        files,
+        size,
+        redacted: options.redact,
+    })
+}
@@ -0,0 +1,96 @@
+use crate::instance::InstanceMetadata;
+use regex::{Captures, Regex};
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::sync::LazyLock;
+
+/// Stands in for a redacted address.
+const REDACTED_IP: &str = "<ip>";
+/// Stands in for a redacted player name.
+const REDACTED_PLAYER: &str = "<player>";
+/// Stands in for a redacted JVM property or agent option.
+const REDACTED_VALUE: &str = "<redacted>";
+
+static IPV4_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b(?:\d{1,3}\.){3}\d{1,3}\b").unwrap());
+static IPV6_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b[0-9A-Fa-f]{1,4}(?::[0-9A-Fa-f]{0,4}){2,7}\b").unwrap());
+/// Lines that name a player: logins, joins, leaves, disconnects, commands, advancements,
+/// chat and crash report details. The one group that takes part holds the name.
+static PLAYER_LINE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(concat!(
+        r"UUID of player (\w{3,16}) is",
+        r"|\b(\w{3,16})\[/[^\]]*\] logged in",
+        r"|\b(\w{3,16}) (?:joined the game|left the game|lost connection:|issued server command:)",
+        r"|\b(\w{3,16}) has (?:made the advancement|completed the challenge|reached the goal)",
+        r"|Disconnecting (\w{3,16}) \(/",
+        r"|GameProfile\{id=[^,]*, name=(\w{3,16})",
+        r"|Player name: (\w{3,16})",
+        r"|<(\w{3,16})> ",
+    ))
+    .unwrap()
+});
+/// The value of a `-Dkey=value` property or of a `-javaagent:jar=options` agent.
+static JVM_VALUE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(-D[^\s=]+=|-javaagent:[^\s=]+=)[^\s"']+"#).unwrap());
+
+/// Replaces IP addresses, player names and JVM property values in text. Loopback and
+/// unspecified addresses are kept since they say nothing about the user and help with bind
+/// problems. Names are only replaced where a known log line puts them, so words that
+/// happen to match a name stay as they are.
+pub fn redact(text: &str) -> String {
+    let text = IPV4_RE.replace_all(text, |caps: &Captures| {
+        match caps[0].parse::<Ipv4Addr>() {
+            Ok(ip) if !ip.is_loopback() && !ip.is_unspecified() => REDACTED_IP.to_string(),
+            _ => caps[0].to_string(),
+        }
+    });
+    let text = IPV6_RE.replace_all(&text, |caps: &Captures| {
+        match caps[0].parse::<Ipv6Addr>() {
+            Ok(ip) if !ip.is_loopback() && !ip.is_unspecified() => REDACTED_IP.to_string(),
+            _ => caps[0].to_string(),
+        }
+    });
+    let text = PLAYER_LINE_RE.replace_all(&text, |caps: &Captures| {
+        let line = &caps[0];
+        let start = caps.get(0).map_or(0, |m| m.start());
+        match caps.iter().skip(1).flatten().next() {
+            Some(name) => format!(
+                "{}{}{}",
+                &line[..name.start() - start],
+                REDACTED_PLAYER,
+                &line[name.end() - start..]
+            ),
+            None => line.to_string(),
+        }
+    });
+    redact_jvm_values(&text)
+}
+
+fn redact_jvm_values(text: &str) -> String {
+    JVM_VALUE_RE.replace_all(text, format!("${{1}}{}", REDACTED_VALUE)).into_owned()
+}
+
+/// An instance's metadata with webhook and heartbeat URLs, environment variable values,
+/// hook commands, agent options and JVM property values blanked, since those often hold
+/// tokens. With `redact`, the tunnel address and idle-exempt players go too.
+pub(super) fn sanitize_instance(instance: &InstanceMetadata, redact: bool) -> InstanceMetadata {
+    let mut instance = instance.clone();
+    let settings = &mut instance.settings;
+    settings.webhooks.url.clear();
+    settings.heartbeat.url.clear();
+    for variable in &mut settings.environment.variables {
+        variable.value.clear();
+    }
+    settings.environment.pre_start_hook.clear();
+    settings.environment.post_stop_hook.clear();
+    for agent in &mut settings.jvm.agents {
+        agent.options.clear();
+    }
+    for flag in &mut settings.jvm.extra_flags {
+        *flag = redact_jvm_values(flag);
+    }
+    settings.startup_line = redact_jvm_values(&settings.startup_line);
+    if redact {
+        settings.tunnel_address.clear();
+        settings.idle_exempt_players.clear();
+    }
+    instance
+}
@@ -0,0 +1,48 @@
+use crate::app_config::ManagedJavaVersion;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sysinfo::{CpuRefreshKind, MemoryRefreshKind, RefreshKind, System};
+
+#[derive(Debug, Serialize)]
+pub(super) struct SystemInfo {
+    pub os: Option<String>,
+    pub kernel: Option<String>,
+    pub arch: String,
+    pub cpu: Option<String>,
+    pub cpu_cores: usize,
+    pub total_memory: u64,
+    pub available_memory: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct Summary {
+    pub generated_at: DateTime<Utc>,
+    pub wrapper_version: String,
+    pub redacted: bool,
+    pub instance_count: usize,
+    pub system: SystemInfo,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct JavaInfo {
+    pub managed: Vec<ManagedJavaVersion>,
+    /// Major version of the `java` on the PATH, None when there is none.
+    pub path_java: Option<u32>,
+}
+
+pub(super) fn system_info() -> SystemInfo {
+    let sys = System::new_with_specifics(
+        RefreshKind::nothing()
+            .with_memory(MemoryRefreshKind::nothing().with_ram())
+            .with_cpu(CpuRefreshKind::nothing()),
+    );
+    SystemInfo {
+        os: System::long_os_version(),
+        kernel: System::kernel_version(),
+        arch: System::cpu_arch(),
+        cpu: sys.cpus().first().map(|cpu| cpu.brand().trim().to_string()),
+        cpu_cores: sys.cpus().len(),
+        total_memory: sys.total_memory(),
+        available_memory: sys.available_memory(),
+    }
+}
@@ -0,0 +1,175 @@
+//! Checks that the server can launch at all: its jar, the EULA, its port and Java.
+//! Like the rest of the checks they only look at files and take everything else as
+//! arguments, so they can run off the async runtime and be tested on plain folders.
+
+use super::{Finding, Severity, SuggestedFix};
+use crate::config::ServerConfig;
+use crate::utils::fs::is_jar_valid;
+use std::net::TcpListener;
+use std::path::Path;
+
+fn is_installer_loader(loader: Option<&str>) -> bool {
+    matches!(loader, Some("forge") | Some("neoforge"))
+}
+
+/// The server jar (or run script, or Bedrock binary) exists and can be opened.
+pub fn check_launch_target(instance_path: &Path, config: &ServerConfig, loader: Option<&str>) -> Vec<Finding> {
+    let reinstall = if is_installer_loader(loader) {
+        SuggestedFix::RepairInstallation
+    } else {
+        SuggestedFix::Reinstall
+    };
+    if let Some(script) = &config.run_script {
+        if instance_path.join(script).exists() {
+            return Vec::new();
+        }
+        return vec![
+            Finding::new(
+                "launch_target",
+                Severity::Error,
+                format!("Run script {} is missing", script),
+                "Repair or reinstall the server so the installer recreates it",
+            )
+            .with_fix(reinstall),
+        ];
+    }
+    let Some(jar) = &config.jar_path else {
+        return vec![
+            Finding::new(
+                "launch_target",
+                Severity::Error,
+                "No server jar or run script is configured",
+                "Reinstall the server or pick a jar in the instance settings",
+            )
+            .with_fix(reinstall),
+        ];
+    };
+    let name = jar.file_name().unwrap_or_default().to_string_lossy();
+    if !jar.exists() {
+        return vec![
+            Finding::new(
+                "launch_target",
+                Severity::Error,
+                format!("{} is missing", name),
+                "Reinstall the server to download it again",
+            )
+            .with_fix(reinstall),
+        ];
+    }
+    let usable = match loader {
+        Some("bedrock") => std::fs::metadata(jar).is_ok_and(|m| m.len() > 0),
+        _ => is_jar_valid(jar),
+    };
+    if !usable {
+        return vec![
+            Finding::new(
+                "launch_target",
+                Severity::Error,
+                format!("{} is corrupt", name),
+                "Reinstall the server to download it again",
+            )
+            .with_fix(reinstall),
+        ];
+    }
+    Vec::new()
+}
+
+/// Servers refuse to start until `eula=true` is set. Bedrock and proxies have no EULA file.
+pub fn check_eula(instance_path: &Path, loader: Option<&str>) -> Vec<Finding> {
+    if matches!(loader, Some("bedrock") | Some("velocity") | Some("bungeecord")) {
+        return Vec::new();
+    }
+    let accepted = std::fs::read_to_string(instance_path.join("eula.txt"))
+        .is_ok_and(|text| text.lines().any(|l| l.trim().eq_ignore_ascii_case("eula=true")));
+    if accepted {
+        return Vec::new();
+    }
+    vec![
+        Finding::new(
+            "eula",
+            Severity::Error,
+            "The Minecraft EULA has not been accepted",
+            "Accept the EULA (https://aka.ms/MinecraftEULA) to let the server start",
+        )
+        .with_fix(SuggestedFix::AcceptEula),
+    ]
+}
+
+fn port_free(port: u16) -> bool {
+    TcpListener::bind(("0.0.0.0", port)).is_ok()
+}
+
+/// First port after `port` that no instance uses and nothing is listening on.
+pub fn next_free_port(port: u16, taken: &[u16]) -> Option<u16> {
+    (port.saturating_add(1)..=port.saturating_add(100)).find(|p| !taken.contains(p) && port_free(*p))
+}
+
+/// `others` lists the names of other instances configured with the same port.
+/// The bind test is skipped while the wrapper itself holds the port, with the server
+/// running or a wake listener waiting for players on it.
+pub fn check_port(port: u16, held_by_wrapper: bool, others: &[String], taken: &[u16]) -> Vec<Finding> {
+    let fix = next_free_port(port, taken).map(|port| SuggestedFix::ChangePort { port });
+    let mut findings = Vec::new();
+    if !others.is_empty() {
+        let mut finding = Finding::new(
+            "port",
+            Severity::Warning,
+            format!("Port {} is also used by {}", port, others.join(", ")),
+            "Only one of these servers can run at a time; give this one its own port",
+        );
+        finding.fix = fix.clone();
+        findings.push(finding);
+    }
+    if !held_by_wrapper && !port_free(port) {
+        let mut finding = Finding::new(
+            "port",
+            Severity::Error,
+            format!("Port {} is already in use by another program", port),
+            "Stop the program using it or change the server port",
+        );
+        finding.fix = fix;
+        findings.push(finding);
+    }
+    findings
+}
+
+/// `found` is the major version of the Java the server would launch with, `None` if it couldn't be run.
+pub fn check_java(required: u32, found: Option<u32>, java: &Path, loader: Option<&str>) -> Vec<Finding> {
+    let install = SuggestedFix::InstallJava {
+        major_version: required,
+    };
+    match found {
+        None => vec![
+            Finding::new(
+                "java",
+                Severity::Error,
+                format!("Java could not be run ({})", java.display()),
+                format!(
+                    "Install Java {} or choose a working Java in the instance settings",
+                    required
+                ),
+            )
+            .with_fix(install),
+        ],
+        Some(found) if found < required => vec![
+            Finding::new(
+                "java",
+                Severity::Error,
+                format!("This version needs Java {} but Java {} is selected", required, found),
+                format!("Install Java {} and select it in the instance settings", required),
+            )
+            .with_fix(install),
+        ],
+        // Legacy Forge relies on Java 8 internals and crashes on anything newer
+        Some(found) if required == 8 && found > 8 && loader == Some("forge") => vec![
+            Finding::new(
+                "java",
+                Severity::Warning,
+                format!("Forge for this version only runs on Java 8, Java {} is selected", found),
+                "Install Java 8 and select it in the instance settings",
+            )
+            .with_fix(install),
+        ],
+        Some(_) => Vec::new(),
+    }
+}
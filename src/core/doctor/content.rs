@@ -0,0 +1,120 @@
+//! Checks of what was added to the server over time: mods, loader versions and leftovers.
+
+use super::{Finding, Severity, SuggestedFix};
+use crate::mods::metadata::parsers::extract_metadata_sync;
+use crate::utils::fs::verify_jar_contents;
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// Folders skipped when looking for leftovers; they are large and never hold config files.
+const LEFTOVER_SKIP_DIRS: &[&str] = &["libraries", "logs", "crash-reports", "cache"];
+
+fn loads_mods_for(instance_loader: &str, mod_loader: &str) -> bool {
+    instance_loader == mod_loader
+        || (instance_loader == "quilt" && mod_loader == "fabric")
+        || (instance_loader == "neoforge" && mod_loader == "forge")
+}
+
+/// Reads every enabled mod jar in full (so CRC errors surface) and flags mods built for another loader.
+pub fn check_mod_jars(instance_path: &Path, loader: Option<&str>) -> Vec<Finding> {
+    let Ok(entries) = std::fs::read_dir(instance_path.join("mods")) else {
+        return Vec::new();
+    };
+    let modded = matches!(
+        loader,
+        Some("fabric") | Some("quilt") | Some("forge") | Some("neoforge")
+    );
+    let mut findings = Vec::new();
+    for entry in entries.filter_map(|e| e.ok()) {
+        let path = entry.path();
+        let filename = entry.file_name().to_string_lossy().to_string();
+        if !path.is_file() || !filename.to_lowercase().ends_with(".jar") {
+            continue;
+        }
+        if let Err(e) = verify_jar_contents(&path) {
+            findings.push(
+                Finding::new(
+                    "mod_jars",
+                    Severity::Error,
+                    format!("{} is corrupt ({})", filename, e),
+                    "Delete it and download the mod again",
+                )
+                .with_fix(SuggestedFix::DeleteFile {
+                    path: format!("mods/{}", filename),
+                }),
+            );
+            continue;
+        }
+        let (Some(instance_loader), true) = (loader, modded) else {
+            continue;
+        };
+        let mod_loader = extract_metadata_sync(&path)
+            .ok()
+            .and_then(|m| m.loader)
+            .map(|l| l.to_lowercase());
+        if let Some(mod_loader) = mod_loader.filter(|m| !loads_mods_for(instance_loader, m)) {
+            findings.push(
+                Finding::new(
+                    "mod_loader",
+                    Severity::Warning,
+                    format!(
+                        "{} is a {} mod but this server runs {}",
+                        filename, mod_loader, instance_loader
+                    ),
+                    "Disable it or replace it with the version made for this loader",
+                )
+                .with_fix(SuggestedFix::DisableMod { filename }),
+            );
+        }
+    }
+    findings
+}
+
+/// The loader version that was installed differs from the one the instance is set to.
+pub fn check_loader_version(configured: Option<&str>, installed: Option<&str>) -> Vec<Finding> {
+    match (configured, installed) {
+        (Some(configured), Some(installed)) if configured != installed => vec![
+            Finding::new(
+                "loader_version",
+                Severity::Warning,
+                format!(
+                    "Loader {} is installed but the instance is set to {}",
+                    installed, configured
+                ),
+                "Reinstall the server so the configured loader version is used",
+            )
+            .with_fix(SuggestedFix::Reinstall),
+        ],
+        _ => Vec::new(),
+    }
+}
+
+/// `.bak` copies kept by config edits and temp files left by interrupted writes.
+pub fn check_leftover_files(instance_path: &Path) -> Vec<Finding> {
+    WalkDir::new(instance_path)
+        .max_depth(4)
+        .into_iter()
+        .filter_entry(|e| e.depth() != 1 || !LEFTOVER_SKIP_DIRS.iter().any(|d| e.file_name() == *d))
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| {
+            let name = e.file_name().to_string_lossy();
+            let leftover = name.ends_with(".bak") || (name.starts_with('.') && name.ends_with(".tmp"));
+            let rel = e
+                .path()
+                .strip_prefix(instance_path)
+                .ok()?
+                .to_string_lossy()
+                .replace('\\', "/");
+            leftover.then(|| {
+                Finding::new(
+                    "leftover_files",
+                    Severity::Info,
+                    format!("Leftover file {}", rel),
+                    "Safe to delete once you no longer need the old copy",
+                )
+                .with_fix(SuggestedFix::DeleteFile { path: rel })
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,31 @@
+use regex::Regex;
+use std::path::Path;
+use std::sync::LazyLock;
+
+static VERSION_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"version "(\d+)(?:\.(\d+))?"#).unwrap());
+
+/// Runs `<java> -version` and returns its major version ("1.8.0_392" is 8, "17.0.7" is 17),
+/// or `None` when the executable can't be run.
+pub async fn java_major_version(java: &Path) -> Option<u32> {
+    let java = java.to_path_buf();
+    let output = tokio::task::spawn_blocking(move || std::process::Command::new(java).arg("-version").output())
+        .await
+        .ok()?
+        .ok()?;
+    // Java prints its version to stderr, some wrappers use stdout
+    let text = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stderr),
+        String::from_utf8_lossy(&output.stdout)
+    );
+    parse_java_major(&text)
+}
+
+fn parse_java_major(text: &str) -> Option<u32> {
+    let captures = VERSION_RE.captures(text)?;
+    let first: u32 = captures.get(1)?.as_str().parse().ok()?;
+    if first == 1 {
+        return captures.get(2)?.as_str().parse().ok();
+    }
+    Some(first)
+}
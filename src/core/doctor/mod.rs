@@ -0,0 +1,81 @@
+//! Health checks for an instance: each check looks at one thing that commonly stops a
+//! server from starting and reports what it found together with a suggested fix.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+pub mod checks;
+pub mod content;
+mod java;
+
+pub use java::java_major_version;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    Warning,
+    /// The server won't start (or won't work) until this is fixed.
+    Error,
+}
+
+/// Something the app can do about a finding, so the UI can offer it as a button.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum SuggestedFix {
+    /// Download and install the server again.
+    Reinstall,
+    /// Re-run the failed phase of a Forge/NeoForge install.
+    RepairInstallation,
+    AcceptEula,
+    ChangePort { port: u16 },
+    InstallJava { major_version: u32 },
+    DeleteFile { path: String },
+    DisableMod { filename: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Finding {
+    /// Which check produced this, e.g. `eula` or `mod_jars`.
+    pub check: String,
+    pub severity: Severity,
+    pub message: String,
+    /// What to do about it, in words.
+    pub suggestion: String,
+    pub fix: Option<SuggestedFix>,
+}
+
+impl Finding {
+    pub fn new(check: &str, severity: Severity, message: impl Into<String>, suggestion: impl Into<String>) -> Self {
+        Self {
+            check: check.to_string(),
+            severity,
+            message: message.into(),
+            suggestion: suggestion.into(),
+            fix: None,
+        }
+    }
+
+    pub fn with_fix(mut self, fix: SuggestedFix) -> Self {
+        self.fix = Some(fix);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiagnosisReport {
+    pub instance_id: Uuid,
+    pub checked_at: DateTime<Utc>,
+    /// Every check that ran, including the ones without findings.
+    pub checks: Vec<String>,
+    /// Most severe first.
+    pub findings: Vec<Finding>,
+}
+
+impl DiagnosisReport {
+    /// True when nothing worse than informational findings turned up.
+    pub fn is_healthy(&self) -> bool {
+        self.findings.iter().all(|f| f.severity == Severity::Info)
+    }
+}
@@ -4,7 +4,7 @@ pub use types::*;
 
 use crate::artifacts::{ArtifactStore, HashAlgorithm};
 use crate::cache::CacheManager;
-use crate::utils::{DownloadOptions, download_with_resumption, retry_async, SingleFlight};
+use crate::utils::{DownloadLimits, DownloadOptions, download_with_resumption, get_with_failover, retry_async, with_failover, SingleFlight};
 use anyhow::{Result, anyhow};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -17,6 +17,7 @@ const VERSION_MANIFEST_URL: &str = "https://launchermeta.mojang.com/mc/game/vers
 
 pub struct VersionDownloader {
     client: reqwest::Client,
+    download_limits: DownloadLimits,
     cache_dir: Option<PathBuf>,
     cache: Option<Arc<CacheManager>>,
     artifact_store: Option<Arc<ArtifactStore>>,
@@ -35,6 +36,7 @@ impl VersionDownloader {
                 .connect_timeout(Duration::from_secs(10))
                 .build()
                 .unwrap_or_else(|_| reqwest::Client::new()),
+            download_limits: cache.as_ref().map(|c| c.download_limits().clone()).unwrap_or_default(),
             cache_dir,
             cache,
             artifact_store,
@@ -45,19 +47,18 @@ impl VersionDownloader {
     pub async fn fetch_manifest(&self) -> Result<VersionManifest> {
         if let Some(ref cache) = self.cache {
             let client = self.client.clone();
+            let limits = self.download_limits.clone();
             return cache
                 .fetch_with_cache(
                     "mojang_version_manifest".to_string(),
                     Duration::from_secs(3600),
                     move || {
-                        let client = client.clone();
+                        let (client, limits) = (client.clone(), limits.clone());
                         async move {
                             info!("Fetching version manifest from {}", VERSION_MANIFEST_URL);
                             retry_async(
                                 || async {
-                                    client
-                                        .get(VERSION_MANIFEST_URL)
-                                        .send()
+                                    get_with_failover(&client, &limits, VERSION_MANIFEST_URL)
                                         .await?
                                         .json::<VersionManifest>()
                                         .await
@@ -96,9 +97,7 @@ impl VersionDownloader {
         info!("Fetching version manifest from {}", VERSION_MANIFEST_URL);
         let manifest = retry_async(
             || async {
-                self.client
-                    .get(VERSION_MANIFEST_URL)
-                    .send()
+                get_with_failover(&self.client, &self.download_limits, VERSION_MANIFEST_URL)
                     .await?
                     .json::<VersionManifest>()
                     .await
@@ -141,9 +140,7 @@ impl VersionDownloader {
         info!("Fetching details for version {}", version_id);
         let detail = retry_async(
             || async {
-                self.client
-                    .get(&version_info.url)
-                    .send()
+                get_with_failover(&self.client, &self.download_limits, &version_info.url)
                     .await?
                     .json::<VersionDetail>()
                     .await
@@ -198,23 +195,28 @@ impl VersionDownloader {
                 version_id, expected_sha1, Uuid::new_v4()
             ));
 
-            download_with_resumption(
-                &self.client,
-                DownloadOptions {
-                    url: &server_download.url,
-                    target_path: &temp_file_path,
-                    expected_hash: Some((&expected_sha1, HashAlgorithm::Sha1)),
-                    total_size: Some(total_size),
-                },
-                |_curr, _tot| {
-                    // We don't report progress from the actual download task to the UI here
-                    // because multiple UI callers might be waiting.
-                    // Instead, each caller will report its own progress (100% if they waited).
-                    // Actually, it would be better if progress was shared, but for now
-                    // let's just focus on deduplication.
-                    // on_progress(curr, tot); // This is from the closure, but we can't easily use it here because of lifetimes
-                },
-            )
+            with_failover(&self.download_limits, &server_download.url, |url| {
+                let temp_file_path = &temp_file_path;
+                let expected_sha1 = &expected_sha1;
+                async move {
+                    download_with_resumption(
+                        &self.client,
+                        &self.download_limits,
+                        DownloadOptions {
+                            url: &url,
+                            target_path: temp_file_path,
+                            expected_hash: Some((expected_sha1, HashAlgorithm::Sha1)),
+                            total_size: Some(total_size),
+                        },
+                        |_curr, _tot| {
+                            // We don't report progress from the actual download task to the UI here
+                            // because multiple UI callers might be waiting.
+                            // Instead, each caller will report its own progress (100% if they waited).
+                        },
+                    )
+                    .await
+                }
+            })
             .await?;
 
             // 3. Add to ArtifactStore
@@ -0,0 +1,65 @@
+use super::AppError;
+use crate::utils::{InsufficientSpace, RejectedDownload};
+
+impl From<std::io::Error> for AppError {
+    fn from(err: std::io::Error) -> Self {
+        match err.kind() {
+            std::io::ErrorKind::NotFound => AppError::NotFound(err.to_string()),
+            _ => AppError::Io(err.to_string()),
+        }
+    }
+}
+
+impl From<anyhow::Error> for AppError {
+    fn from(err: anyhow::Error) -> Self {
+        if let Some(app) = err.downcast_ref::<AppError>() {
+            return app.clone();
+        }
+        if let Some(space) = err.downcast_ref::<InsufficientSpace>() {
+            return AppError::InsufficientSpace(space.clone());
+        }
+        if let Some(rejected) = err.downcast_ref::<RejectedDownload>() {
+            return AppError::RejectedDownload(rejected.clone());
+        }
+        // Keep the kind of well-known causes; the message keeps the context added on the way
+        // up along with the cause itself
+        let message = format!("{err:#}");
+        if let Some(io) = err.downcast_ref::<std::io::Error>() {
+            return match io.kind() {
+                std::io::ErrorKind::NotFound => AppError::NotFound(message),
+                _ => AppError::Io(message),
+            };
+        }
+        if err.downcast_ref::<reqwest::Error>().is_some() {
+            return AppError::Network(message);
+        }
+        if err.downcast_ref::<sqlx::Error>().is_some() {
+            return AppError::Database(message);
+        }
+        AppError::Internal(message)
+    }
+}
+
+impl From<sqlx::Error> for AppError {
+    fn from(err: sqlx::Error) -> Self {
+        AppError::Database(err.to_string())
+    }
+}
+
+impl From<reqwest::Error> for AppError {
+    fn from(err: reqwest::Error) -> Self {
+        AppError::Network(err.to_string())
+    }
+}
+
+impl From<serde_json::Error> for AppError {
+    fn from(err: serde_json::Error) -> Self {
+        AppError::Config(err.to_string())
+    }
+}
+
+impl From<uuid::Error> for AppError {
+    fn from(err: uuid::Error) -> Self {
+        AppError::Validation(format!("Invalid UUID: {}", err))
+    }
+}
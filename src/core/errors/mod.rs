@@ -0,0 +1,154 @@
+mod convert;
+
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use thiserror::Error;
+use crate::utils::{InsufficientSpace, RejectedDownload};
+
+/// Error returned by Tauri commands. Serialized as `{ type, code, message }`, where `code`
+/// is a stable snake_case identifier the frontend can branch on.
+///
+/// Core code can return these through `anyhow` as well: converting an `anyhow::Error` back
+/// finds them, so e.g. a `NotFound` raised deep in a manager reaches the UI as `not_found`.
+#[derive(Debug, Clone, Error)]
+pub enum AppError {
+    #[error("IO error: {0}")]
+    Io(String),
+
+    #[error("Database error: {0}")]
+    Database(String),
+
+    #[error("Network error: {0}")]
+    Network(String),
+
+    #[error("Instance error: {0}")]
+    Instance(String),
+
+    #[error("Server error: {0}")]
+    Server(String),
+
+    #[error("Config error: {0}")]
+    Config(String),
+
+    #[error("Validation error: {0}")]
+    Validation(String),
+
+    #[error("Internal error: {0}")]
+    Internal(String),
+
+    #[error("Not found: {0}")]
+    NotFound(String),
+
+    /// The request clashes with the current state, e.g. the server is running.
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    #[error("Plugin error: {0}")]
+    Plugin(String),
+
+    #[error("Mod error: {0}")]
+    Mod(String),
+
+    #[error("Backup error: {0}")]
+    Backup(String),
+
+    #[error("Scheduler error: {0}")]
+    Scheduler(String),
+
+    #[error("Java error: {0}")]
+    Java(String),
+
+    #[error("{0}")]
+    InsufficientSpace(InsufficientSpace),
+
+    #[error("{0}")]
+    RejectedDownload(RejectedDownload),
+}
+
+impl AppError {
+    /// Machine-readable code sent to the frontend.
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::Io(_) => "io",
+            AppError::Database(_) => "database",
+            AppError::Network(_) => "network",
+            AppError::Instance(_) => "instance",
+            AppError::Server(_) => "server",
+            AppError::Config(_) => "config",
+            AppError::Validation(_) => "validation",
+            AppError::Internal(_) => "internal",
+            AppError::NotFound(_) => "not_found",
+            AppError::Conflict(_) => "conflict",
+            AppError::Plugin(_) => "plugin",
+            AppError::Mod(_) => "mod",
+            AppError::Backup(_) => "backup",
+            AppError::Scheduler(_) => "scheduler",
+            AppError::Java(_) => "java",
+            AppError::InsufficientSpace(_) => "insufficient_space",
+            AppError::RejectedDownload(_) => "rejected_download",
+        }
+    }
+
+    fn type_name(&self) -> &'static str {
+        match self {
+            AppError::Io(_) => "Io",
+            AppError::Database(_) => "Database",
+            AppError::Network(_) => "Network",
+            AppError::Instance(_) => "Instance",
+            AppError::Server(_) => "Server",
+            AppError::Config(_) => "Config",
+            AppError::Validation(_) => "Validation",
+            AppError::Internal(_) => "Internal",
+            AppError::NotFound(_) => "NotFound",
+            AppError::Conflict(_) => "Conflict",
+            AppError::Plugin(_) => "Plugin",
+            AppError::Mod(_) => "Mod",
+            AppError::Backup(_) => "Backup",
+            AppError::Scheduler(_) => "Scheduler",
+            AppError::Java(_) => "Java",
+            AppError::InsufficientSpace(_) => "InsufficientSpace",
+            AppError::RejectedDownload(_) => "RejectedDownload",
+        }
+    }
+}
+
+impl Serialize for AppError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("AppError", 3)?;
+        state.serialize_field("type", self.type_name())?;
+        state.serialize_field("code", self.code())?;
+        match self {
+            AppError::InsufficientSpace(space) => state.serialize_field("message", space)?,
+            AppError::RejectedDownload(rejected) => state.serialize_field("message", rejected)?,
+            AppError::Io(message)
+            | AppError::Database(message)
+            | AppError::Network(message)
+            | AppError::Instance(message)
+            | AppError::Server(message)
+            | AppError::Config(message)
+            | AppError::Validation(message)
+            | AppError::Internal(message)
+            | AppError::NotFound(message)
+            | AppError::Conflict(message)
+            | AppError::Plugin(message)
+            | AppError::Mod(message)
+            | AppError::Backup(message)
+            | AppError::Scheduler(message)
+            | AppError::Java(message) => state.serialize_field("message", message)?,
+        }
+        state.end()
+    }
+}
+
+// Helper to convert any result to Result<T, AppError> using anyhow as bridge
+pub trait ToAppResult<T> {
+    fn to_app_result(self) -> Result<T, AppError>;
+}
+
+impl<T, E> ToAppResult<T> for Result<T, E>
+where
+    E: Into<AppError>,
+{
+    fn to_app_result(self) -> Result<T, AppError> {
+        self.map_err(Into::into)
+    }
+}
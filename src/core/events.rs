@@ -0,0 +1,109 @@
+//! Recent frontend events, kept per instance and channel so a reloaded webview can rebuild
+//! the startup phases and task progress it missed while it was gone. Console output is
+//! replayed from each server's own console buffer instead.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventChannel {
+    /// Startup phases reported while the server boots.
+    Startup,
+    /// Changes to long-running tasks.
+    Task,
+}
+
+impl EventChannel {
+    /// Events kept per instance before the oldest are dropped.
+    fn capacity(self) -> usize {
+        match self {
+            EventChannel::Startup => 50,
+            EventChannel::Task => 200,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedEvent {
+    /// Increases by one for every event published, across all instances and channels.
+    pub seq: u64,
+    pub instance_id: Option<Uuid>,
+    pub channel: EventChannel,
+    pub at: DateTime<Utc>,
+    pub payload: Value,
+}
+
+#[derive(Default)]
+struct Buffers {
+    last_seq: u64,
+    events: HashMap<(Option<Uuid>, EventChannel), VecDeque<RecordedEvent>>,
+}
+
+/// Ring buffers of the latest events. Events that aren't about one instance, such as
+/// app-wide downloads, are kept under `None`.
+#[derive(Default)]
+pub struct EventBus {
+    buffers: Mutex<Buffers>,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn buffers(&self) -> MutexGuard<'_, Buffers> {
+        self.buffers.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Records `payload` and returns its sequence number, which live listeners receive along
+    /// with it so they can skip what a replay already delivered.
+    pub fn publish<T: Serialize>(&self, instance_id: Option<Uuid>, channel: EventChannel, payload: &T) -> u64 {
+        let payload = serde_json::to_value(payload).unwrap_or(Value::Null);
+        let mut buffers = self.buffers();
+        buffers.last_seq += 1;
+        let seq = buffers.last_seq;
+        let buffer = buffers.events.entry((instance_id, channel)).or_default();
+        if buffer.len() >= channel.capacity() {
+            buffer.pop_front();
+        }
+        buffer.push_back(RecordedEvent {
+            seq,
+            instance_id,
+            channel,
+            at: Utc::now(),
+            payload,
+        });
+        seq
+    }
+
+    /// Events for `instance_id` on every channel with a sequence number above `since_seq`,
+    /// oldest first. Pass 0 to get everything still buffered.
+    pub fn recent(&self, instance_id: Option<Uuid>, since_seq: u64) -> Vec<RecordedEvent> {
+        let buffers = self.buffers();
+        let mut events: Vec<RecordedEvent> = buffers
+            .events
+            .iter()
+            .filter(|((id, _), _)| *id == instance_id)
+            .flat_map(|(_, buffer)| buffer.iter().filter(|e| e.seq > since_seq).cloned())
+            .collect();
+        events.sort_by_key(|e| e.seq);
+        events
+    }
+
+    /// Sequence number of the newest event, 0 before the first one.
+    pub fn last_seq(&self) -> u64 {
+        self.buffers().last_seq
+    }
+
+    /// Drops everything buffered for a deleted instance.
+    pub fn forget(&self, instance_id: Uuid) {
+        self.buffers()
+            .events
+            .retain(|(id, _), _| *id != Some(instance_id));
+    }
+}
@@ -0,0 +1,141 @@
+use super::{FileChunk, MAX_CHUNK_SIZE, MAX_INLINE_DOWNLOAD, TextChunk, resolve_for_write};
+use crate::utils::safe_join;
+use anyhow::{Context, Result, anyhow};
+use base64::Engine;
+use std::io::SeekFrom;
+use std::path::{Path, PathBuf};
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+fn resolve_file(root: &Path, rel: &str) -> Result<PathBuf> {
+    let path = safe_join(root, rel)?;
+    if !path.is_file() {
+        return Err(anyhow!("Not a file: {}", rel));
+    }
+    Ok(path)
+}
+
+/// Reads a whole text file, or an empty string when it doesn't exist. Invalid UTF-8 is
+/// replaced rather than failing; files over [`MAX_INLINE_DOWNLOAD`] must be read in chunks.
+pub async fn read_text(root: &Path, rel: &str) -> Result<String> {
+    let path = safe_join(root, rel)?;
+    let metadata = match tokio::fs::metadata(&path).await {
+        Ok(metadata) => metadata,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(String::new()),
+        Err(e) => return Err(e).with_context(|| format!("Could not read {}", rel)),
+    };
+    if !metadata.is_file() {
+        return Err(anyhow!("Not a file: {}", rel));
+    }
+    if metadata.len() > MAX_INLINE_DOWNLOAD {
+        return Err(anyhow!("{} is too large to open in one piece", rel));
+    }
+    let bytes = tokio::fs::read(&path)
+        .await
+        .with_context(|| format!("Could not read {}", rel))?;
+    Ok(match String::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(e) => String::from_utf8_lossy(e.as_bytes()).into_owned(),
+    })
+}
+
+/// Reads a file as base64 for downloading through the UI. Limited to
+/// [`MAX_INLINE_DOWNLOAD`] bytes.
+pub async fn read_base64(root: &Path, rel: &str) -> Result<String> {
+    let path = resolve_file(root, rel)?;
+    let size = tokio::fs::metadata(&path).await?.len();
+    if size > MAX_INLINE_DOWNLOAD {
+        return Err(anyhow!(
+            "{} is too large to download directly; save it to a folder instead",
+            rel
+        ));
+    }
+    let bytes = tokio::fs::read(&path)
+        .await
+        .with_context(|| format!("Could not read {}", rel))?;
+    Ok(base64::engine::general_purpose::STANDARD.encode(bytes))
+}
+
+/// Streams a file to `dest` outside the instance, e.g. a path picked in a save dialog.
+/// Returns the number of bytes written.
+pub async fn export_file(root: &Path, rel: &str, dest: &Path) -> Result<u64> {
+    let path = resolve_file(root, rel)?;
+    let mut source = tokio::fs::File::open(&path)
+        .await
+        .with_context(|| format!("Could not open {}", rel))?;
+    let mut target = tokio::fs::File::create(dest)
+        .await
+        .with_context(|| format!("Could not create {}", dest.display()))?;
+    let written = tokio::io::copy(&mut source, &mut target).await?;
+    tokio::io::AsyncWriteExt::flush(&mut target).await?;
+    Ok(written)
+}
+
+/// Reads `len` bytes (at most [`MAX_CHUNK_SIZE`]) from `offset`, plus up to `extra` more
+/// so callers can finish a partial character.
+async fn read_range(path: &Path, rel: &str, offset: u64, len: u64, extra: u64) -> Result<(Vec<u8>, u64)> {
+    let mut file = tokio::fs::File::open(path)
+        .await
+        .with_context(|| format!("Could not open {}", rel))?;
+    let total_size = file.metadata().await?.len();
+    file.seek(SeekFrom::Start(offset.min(total_size))).await?;
+    let mut buffer = Vec::new();
+    file.take(len + extra).read_to_end(&mut buffer).await?;
+    Ok((buffer, total_size))
+}
+
+/// Reads part of any file as base64, so binary files can be fetched piece by piece.
+pub async fn read_chunk(root: &Path, rel: &str, offset: u64, len: u64) -> Result<FileChunk> {
+    let path = resolve_file(root, rel)?;
+    let len = len.clamp(1, MAX_CHUNK_SIZE);
+    let (buffer, total_size) = read_range(&path, rel, offset, len, 0).await?;
+    let next_offset = offset.min(total_size) + buffer.len() as u64;
+    Ok(FileChunk {
+        data: base64::engine::general_purpose::STANDARD.encode(&buffer),
+        offset,
+        next_offset,
+        total_size,
+        eof: next_offset >= total_size,
+    })
+}
+
+/// Reads part of a text file. A character cut off at the end of the range is included
+/// whole; bytes that aren't valid UTF-8 are replaced.
+pub async fn read_text_chunk(root: &Path, rel: &str, offset: u64, len: u64) -> Result<TextChunk> {
+    let path = resolve_file(root, rel)?;
+    let len = len.clamp(1, MAX_CHUNK_SIZE);
+    // A UTF-8 character is at most 4 bytes, so 3 more always complete it
+    let (mut buffer, total_size) = read_range(&path, rel, offset, len, 3).await?;
+    let end = (len as usize).min(buffer.len());
+    let cut = (end..buffer.len())
+        .find(|i| buffer[*i] & 0xC0 != 0x80)
+        .unwrap_or(buffer.len());
+    buffer.truncate(cut);
+    let next_offset = offset.min(total_size) + buffer.len() as u64;
+    Ok(TextChunk {
+        binary: buffer.contains(&0),
+        content: String::from_utf8_lossy(&buffer).into_owned(),
+        offset,
+        next_offset,
+        total_size,
+        eof: next_offset >= total_size,
+    })
+}
+
+/// Replaces a file with base64-encoded `data`, creating missing folders. Limited to
+/// [`MAX_INLINE_DOWNLOAD`] bytes.
+pub async fn write_base64(root: &Path, rel: &str, data: &str, backup: bool) -> Result<()> {
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(data.trim())
+        .context("The file data isn't valid base64")?;
+    if bytes.len() as u64 > MAX_INLINE_DOWNLOAD {
+        return Err(anyhow!("{} is too large to upload in one piece", rel));
+    }
+    let path = resolve_for_write(root, rel)?;
+    if path.is_dir() {
+        return Err(anyhow!("{} is a folder", rel));
+    }
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    crate::utils::write_atomic(&path, bytes, backup).await
+}
@@ -0,0 +1,149 @@
+//! Browsing and editing the files of an instance. Every path is relative to the instance
+//! folder and resolved with [`safe_join`], so nothing outside it can be reached, including
+//! through symlinks.
+
+mod content;
+mod ops;
+
+pub use content::*;
+pub use ops::*;
+
+use crate::utils::{safe_join, validate_rel_path};
+use anyhow::{Context, Result, anyhow};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::path::{Component, Path, PathBuf};
+
+/// Largest file returned inline by [`read_base64`] or written by [`write_base64`]; bigger
+/// files go through [`export_file`] or chunked reads.
+pub const MAX_INLINE_DOWNLOAD: u64 = 16 * 1024 * 1024;
+/// Largest chunk returned by a single read, to keep IPC payloads reasonable.
+pub const MAX_CHUNK_SIZE: u64 = 1024 * 1024;
+
+/// Raw bytes of a file, base64-encoded.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct FileChunk {
+    pub data: String,
+    pub offset: u64,
+    /// Offset to pass for the following chunk.
+    pub next_offset: u64,
+    pub total_size: u64,
+    pub eof: bool,
+}
+
+/// A slice of a file decoded as UTF-8. Chunks never split a character.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct TextChunk {
+    pub content: String,
+    pub offset: u64,
+    pub next_offset: u64,
+    pub total_size: u64,
+    pub eof: bool,
+    /// The chunk contains NUL bytes, so the file is most likely not text.
+    pub binary: bool,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct FileEntry {
+    pub name: String,
+    /// Relative to the instance folder, with `/` separators.
+    pub path: String,
+    pub is_dir: bool,
+    /// Symlinks are listed but never followed.
+    pub is_symlink: bool,
+    pub size: u64,
+    pub modified: Option<DateTime<Utc>>,
+}
+
+/// `rel` with `.` components dropped and `..` applied, using `/` separators.
+fn normalize_rel(rel: &str) -> Result<String> {
+    validate_rel_path(rel)?;
+    let rel = rel.replace('\\', "/");
+    let mut parts: Vec<&str> = Vec::new();
+    for component in Path::new(&rel).components() {
+        match component {
+            Component::Normal(part) => parts.push(part.to_str().unwrap_or_default()),
+            Component::ParentDir => {
+                parts.pop();
+            }
+            _ => {}
+        }
+    }
+    Ok(parts.join("/"))
+}
+
+/// Resolves a path that may not exist yet, e.g. a file about to be written with its
+/// missing folders. [`safe_join`] can only check existing paths through symlinks, so the
+/// deepest existing ancestor is checked and the rest appended.
+pub fn resolve_for_write(root: &Path, rel: &str) -> Result<PathBuf> {
+    let rel = normalize_rel(rel)?;
+    let parts: Vec<&str> = rel.split('/').filter(|p| !p.is_empty()).collect();
+    let existing = (0..=parts.len())
+        .rev()
+        .find(|n| std::fs::symlink_metadata(root.join(parts[..*n].join("/"))).is_ok())
+        .unwrap_or(0);
+    let mut path = safe_join(root, &parts[..existing].join("/"))?;
+    path.extend(&parts[existing..]);
+    Ok(path)
+}
+
+/// Resolves the entry at `rel` itself rather than what it points to: the parent folder is
+/// checked with [`safe_join`] and the final name appended, so a symlink is renamed or
+/// deleted instead of its target. The instance folder itself is rejected.
+fn resolve_entry(root: &Path, rel: &str) -> Result<(String, PathBuf)> {
+    let rel = normalize_rel(rel)?;
+    let (parent, name) = match rel.rsplit_once('/') {
+        Some((parent, name)) => (parent, name),
+        None if rel.is_empty() => return Err(anyhow!("The instance folder itself can't be changed")),
+        None => ("", rel.as_str()),
+    };
+    let path = resolve_for_write(root, parent)?.join(name);
+    Ok((rel.clone(), path))
+}
+
+fn entry(path: &Path, rel: String, metadata: &std::fs::Metadata) -> FileEntry {
+    FileEntry {
+        name: path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        path: rel,
+        is_dir: metadata.is_dir(),
+        is_symlink: metadata.file_type().is_symlink(),
+        size: if metadata.is_file() { metadata.len() } else { 0 },
+        modified: metadata.modified().ok().map(DateTime::<Utc>::from),
+    }
+}
+
+/// Lists a folder of the instance, folders first and then by name. An empty `rel` lists
+/// the instance folder.
+pub async fn list_dir(root: &Path, rel: &str) -> Result<Vec<FileEntry>> {
+    let rel = normalize_rel(rel)?;
+    let dir = safe_join(root, &rel)?;
+    if !dir.is_dir() {
+        return Err(anyhow!("Not a folder: {}", rel));
+    }
+
+    let mut entries = Vec::new();
+    let mut read_dir = tokio::fs::read_dir(&dir)
+        .await
+        .with_context(|| format!("Could not read {}", rel))?;
+    while let Some(item) = read_dir.next_entry().await? {
+        let Ok(metadata) = tokio::fs::symlink_metadata(item.path()).await else {
+            continue;
+        };
+        let name = item.file_name().to_string_lossy().into_owned();
+        let child = if rel.is_empty() {
+            name
+        } else {
+            format!("{}/{}", rel, name)
+        };
+        entries.push(entry(&item.path(), child, &metadata));
+    }
+    entries.sort_by(|a, b| {
+        b.is_dir
+            .cmp(&a.is_dir)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+    Ok(entries)
+}
@@ -0,0 +1,95 @@
+use super::{FileEntry, entry, normalize_rel, resolve_entry};
+use crate::utils::safe_join;
+use anyhow::{Context, Result, anyhow};
+use std::path::Path;
+
+async fn ensure_free(path: &Path, rel: &str) -> Result<()> {
+    if tokio::fs::symlink_metadata(path).await.is_ok() {
+        return Err(anyhow!("{} already exists", rel));
+    }
+    match path.parent() {
+        Some(parent) if parent.is_dir() => Ok(()),
+        _ => Err(anyhow!("The folder for {} doesn't exist", rel)),
+    }
+}
+
+/// Creates a folder inside an existing one.
+pub async fn create_dir(root: &Path, rel: &str) -> Result<FileEntry> {
+    let (rel, path) = resolve_entry(root, rel)?;
+    ensure_free(&path, &rel).await?;
+    tokio::fs::create_dir(&path)
+        .await
+        .with_context(|| format!("Could not create {}", rel))?;
+    let metadata = tokio::fs::symlink_metadata(&path).await?;
+    Ok(entry(&path, rel, &metadata))
+}
+
+/// Renames or moves an entry within the instance. The destination must not exist.
+pub async fn rename(root: &Path, from: &str, to: &str) -> Result<FileEntry> {
+    let (from_rel, from_path) = resolve_entry(root, from)?;
+    let (to_rel, to_path) = resolve_entry(root, to)?;
+    if tokio::fs::symlink_metadata(&from_path).await.is_err() {
+        return Err(anyhow!("{} doesn't exist", from_rel));
+    }
+    if to_rel.starts_with(&format!("{}/", from_rel)) {
+        return Err(anyhow!("A folder can't be moved into itself"));
+    }
+    ensure_free(&to_path, &to_rel).await?;
+    tokio::fs::rename(&from_path, &to_path)
+        .await
+        .with_context(|| format!("Could not move {} to {}", from_rel, to_rel))?;
+    let metadata = tokio::fs::symlink_metadata(&to_path).await?;
+    Ok(entry(&to_path, to_rel, &metadata))
+}
+
+/// Deletes a file, symlink or folder with everything in it.
+pub async fn delete(root: &Path, rel: &str) -> Result<()> {
+    let (rel, path) = resolve_entry(root, rel)?;
+    let metadata = tokio::fs::symlink_metadata(&path)
+        .await
+        .with_context(|| format!("{} doesn't exist", rel))?;
+    let result = if metadata.is_dir() {
+        tokio::fs::remove_dir_all(&path).await
+    } else {
+        tokio::fs::remove_file(&path).await
+    };
+    result.with_context(|| format!("Could not delete {}", rel))
+}
+
+/// Copies a file or folder within the instance. Symlinks inside a copied folder are
+/// skipped so the copy can't pull in files from elsewhere.
+pub async fn copy(root: &Path, from: &str, to: &str) -> Result<FileEntry> {
+    let from_rel = normalize_rel(from)?;
+    let source = safe_join(root, &from_rel)?;
+    let (to_rel, dest) = resolve_entry(root, to)?;
+    if !source.exists() {
+        return Err(anyhow!("{} doesn't exist", from_rel));
+    }
+    if to_rel.starts_with(&format!("{}/", from_rel)) {
+        return Err(anyhow!("A folder can't be copied into itself"));
+    }
+    ensure_free(&dest, &to_rel).await?;
+
+    let (src, dst) = (source.clone(), dest.clone());
+    tokio::task::spawn_blocking(move || -> Result<()> {
+        if src.is_file() {
+            std::fs::copy(&src, &dst)?;
+            return Ok(());
+        }
+        for item in walkdir::WalkDir::new(&src).follow_links(false) {
+            let item = item?;
+            let target = dst.join(item.path().strip_prefix(&src)?);
+            if item.file_type().is_dir() {
+                std::fs::create_dir_all(&target)?;
+            } else if item.file_type().is_file() {
+                std::fs::copy(item.path(), &target)?;
+            }
+        }
+        Ok(())
+    })
+    .await?
+    .with_context(|| format!("Could not copy {} to {}", from_rel, to_rel))?;
+
+    let metadata = tokio::fs::symlink_metadata(&dest).await?;
+    Ok(entry(&dest, to_rel, &metadata))
+}
@@ -3,6 +3,7 @@ use tokio::fs;
 use anyhow::{Result, Context};
 use tracing::info;
 use std::collections::HashSet;
+use crate::storage_mode::{APP_DATA_ITEMS, MIGRATION_FILE};
 
 /// Application directory structure
 #[derive(Debug, Clone)]
@@ -22,9 +23,7 @@ pub async fn has_folder_clutter(exe_dir: &Path) -> Result<bool> {
     let mut entries = fs::read_dir(exe_dir).await?;
     
     // Items created by the app
-    let app_items: HashSet<&str> = [
-        "backups", "resources", "server", "java", "logs", "app_settings.json", "cache"
-    ].into_iter().collect();
+    let app_items: HashSet<&str> = APP_DATA_ITEMS.into_iter().chain([MIGRATION_FILE]).collect();
 
     // Development environment items
     let dev_folders: HashSet<&str> = [
@@ -92,10 +91,11 @@ pub async fn has_folder_clutter(exe_dir: &Path) -> Result<bool> {
     Ok(clutter_count > 0)
 }
 
-/// Initializes the application directory structure next to the executable.
+/// Initializes the application directory structure in the data folder.
 /// 
 /// # Arguments
-/// * `base_path` - The base path where the folders should be created (usually the exe directory).
+/// * `base_path` - The base path where the folders should be created: the exe directory in
+///   portable mode, the user's app data folder otherwise.
 pub async fn init_directories(base_path: &Path) -> Result<AppDirs> {
     let backups = base_path.join("backups");
     let resources = base_path.join("resources");
@@ -0,0 +1,114 @@
+use super::{normalize_root, relative_entry_name};
+use anyhow::{Result, anyhow};
+use std::path::Path;
+
+/// What an archive would extract to, read from its index without decompressing anything.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArchiveSummary {
+    /// Uncompressed size of the files below the root.
+    pub extracted_size: u64,
+    pub file_count: u64,
+    /// The folder every entry sits in, e.g. "backup/server/" for an archive whose only
+    /// top-level folder is "backup" holding only "server". None when files sit at the top.
+    pub single_root: Option<String>,
+}
+
+/// One archive entry: its name with `/` separators, whether it's a folder, and its size.
+type IndexEntry = (String, bool, u64);
+
+/// Reads the index of a .zip or .7z archive. `root_within_zip`, when given, limits the
+/// size to the entries below it; otherwise it is the detected single root, if any.
+pub fn inspect_archive(path: &Path, root_within_zip: Option<String>) -> Result<ArchiveSummary> {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+    let entries = match extension.as_str() {
+        "zip" => zip_index(path)?,
+        "7z" => sevenz_index(path)?,
+        _ => return Err(anyhow!("Unsupported archive format: .{}", extension)),
+    };
+
+    let single_root = detect_single_root(entries.iter().map(|(name, is_dir, _)| (name.as_str(), *is_dir)));
+    let root = normalize_root(root_within_zip).or_else(|| single_root.clone());
+    let (extracted_size, file_count) = entries
+        .iter()
+        .filter(|(name, is_dir, _)| !is_dir && relative_entry_name(name, root.as_deref()).is_some())
+        .fold((0u64, 0u64), |(size, count), (_, _, len)| {
+            (size.saturating_add(*len), count + 1)
+        });
+
+    Ok(ArchiveSummary {
+        extracted_size,
+        file_count,
+        single_root,
+    })
+}
+
+fn zip_index(path: &Path) -> Result<Vec<IndexEntry>> {
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path)?)?;
+    (0..archive.len())
+        .map(|i| {
+            let file = archive.by_index_raw(i)?;
+            Ok((file.name().replace('\\', "/"), file.is_dir(), file.size()))
+        })
+        .collect()
+}
+
+fn sevenz_index(path: &Path) -> Result<Vec<IndexEntry>> {
+    let mut file = std::fs::File::open(path)?;
+    let len = file.metadata()?.len();
+    let archive = sevenz_rust::Archive::read(&mut file, len, &[]).map_err(|e| anyhow!("7z read error: {}", e))?;
+    Ok(archive
+        .files
+        .iter()
+        .map(|entry| {
+            let name = entry.name().replace('\\', "/");
+            (name, entry.is_directory(), entry.size())
+        })
+        .collect())
+}
+
+/// Archive tools add these next to the real content; they don't count as a second root.
+fn is_archive_clutter(first: &str) -> bool {
+    first == "__MACOSX" || first == ".DS_Store" || first == "Thumbs.db"
+}
+
+/// Follows folders down for as long as everything sits in exactly one folder, so a
+/// server zipped as "backup/server/..." extracts from "backup/server/".
+pub fn detect_single_root<'a>(entries: impl IntoIterator<Item = (&'a str, bool)>) -> Option<String> {
+    let entries: Vec<(&str, bool)> = entries
+        .into_iter()
+        .filter(|(name, _)| !name.split('/').next().is_some_and(is_archive_clutter))
+        .collect();
+    let mut root = String::new();
+    loop {
+        let mut child: Option<&str> = None;
+        let mut diverges = false;
+        for (name, is_dir) in &entries {
+            let Some(rest) = name.strip_prefix(root.as_str()) else {
+                continue;
+            };
+            let rest = rest.trim_start_matches('/');
+            if rest.is_empty() {
+                continue;
+            }
+            // A file here, or a second folder, ends the descent
+            let (first, in_folder) = match rest.split_once('/') {
+                Some((first, inner)) => (first, !inner.is_empty() || *is_dir),
+                None => (rest, *is_dir),
+            };
+            if !in_folder || child.is_some_and(|c| c != first) {
+                diverges = true;
+                break;
+            }
+            child = Some(first);
+        }
+        match child {
+            Some(child) if !diverges => root = format!("{}{}/", root, child),
+            _ => break,
+        }
+    }
+    (!root.is_empty()).then_some(root)
+}
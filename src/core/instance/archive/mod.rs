@@ -0,0 +1,101 @@
+use anyhow::Result;
+use std::io::{Read, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use tokio::fs;
+
+mod inspect;
+mod seven_zip;
+mod zip_archive;
+
+pub use inspect::{ArchiveSummary, detect_single_root, inspect_archive};
+pub use seven_zip::extract_7z;
+pub use zip_archive::extract_zip;
+pub use crate::tasks::CancelFlag;
+
+/// Extraction streams through a buffer this size, so memory use doesn't grow with entry size.
+const COPY_BUFFER_SIZE: usize = 64 * 1024;
+/// At most one progress event per this interval; large archives would otherwise flood the UI.
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Forwards progress at most every [`PROGRESS_INTERVAL`], always passing the first and last update.
+struct ThrottledProgress<F> {
+    on_progress: F,
+    last: Option<Instant>,
+}
+
+impl<F: Fn(u64, u64, String)> ThrottledProgress<F> {
+    fn new(on_progress: F) -> Self {
+        Self { on_progress, last: None }
+    }
+
+    fn report(&mut self, current: u64, total: u64, message: impl FnOnce() -> String) {
+        let due = self.last.is_none_or(|last| last.elapsed() >= PROGRESS_INTERVAL);
+        if due || current >= total {
+            (self.on_progress)(current, total, message());
+            self.last = Some(Instant::now());
+        }
+    }
+}
+
+/// Copies `reader` into a new file at `outpath`, checking `cancel` between chunks.
+fn copy_entry(reader: &mut dyn Read, outpath: &Path, cancel: &CancelFlag) -> Result<()> {
+    if let Some(p) = outpath.parent() {
+        std::fs::create_dir_all(p)?;
+    }
+    let mut outfile = std::fs::File::create(outpath)?;
+    let mut buf = vec![0u8; COPY_BUFFER_SIZE];
+    loop {
+        cancel.check()?;
+        let n = reader.read(&mut buf)?;
+        if n == 0 {
+            return Ok(());
+        }
+        outfile.write_all(&buf[..n])?;
+    }
+}
+
+/// Maps an archive entry name onto its path below `root`, or `None` when it is outside
+/// `root` or would escape the destination (e.g. "../").
+fn relative_entry_name<'a>(name: &'a str, root: Option<&str>) -> Option<&'a str> {
+    let relative = match root {
+        Some(root) => name.strip_prefix(root)?,
+        None => name,
+    };
+    let escapes = Path::new(relative)
+        .components()
+        .any(|c| !matches!(c, std::path::Component::Normal(_) | std::path::Component::CurDir));
+    (!relative.is_empty() && !escapes).then_some(relative)
+}
+
+fn normalize_root(root_within_zip: Option<String>) -> Option<String> {
+    root_within_zip.map(|r| if r.ends_with('/') { r } else { format!("{}/", r) })
+}
+
+pub async fn copy_dir_all<F>(src: impl AsRef<Path>, dst: impl AsRef<Path>, on_progress: F) -> Result<()>
+where F: Fn(u64, u64, String) + Send + Sync + 'static
+{
+    let src = src.as_ref().to_path_buf();
+    let dst = dst.as_ref().to_path_buf();
+
+    if !dst.exists() {
+        fs::create_dir_all(&dst).await?;
+    }
+
+    let entries: Vec<_> = walkdir::WalkDir::new(&src).into_iter().filter_map(|e| e.ok()).collect();
+    let total = entries.len() as u64;
+
+    for (i, entry) in entries.into_iter().enumerate() {
+        let relative_path = entry.path().strip_prefix(&src)?;
+        let target_path = dst.join(relative_path);
+
+        on_progress(i as u64, total, format!("Copying {}...", relative_path.display()));
+
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&target_path).await?;
+        } else {
+            fs::copy(entry.path(), &target_path).await?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use super::{CancelFlag, ThrottledProgress, copy_entry, normalize_root, relative_entry_name};
+use anyhow::{Result, anyhow};
+use std::path::Path;
+
+/// Extracts `sz_path` into `dst` on the blocking pool, streaming each entry to disk.
+pub async fn extract_7z<F>(
+    sz_path: &Path,
+    dst: &Path,
+    root_within_zip: Option<String>,
+    cancel: CancelFlag,
+    on_progress: F,
+) -> Result<()>
+where
+    F: Fn(u64, u64, String) + Send + Sync + 'static,
+{
+    let sz_path = sz_path.to_path_buf();
+    let dst = dst.to_path_buf();
+
+    tokio::task::spawn_blocking(move || {
+        let root = normalize_root(root_within_zip);
+
+        // For 7z we need to count entries first to have a total
+        let total = {
+            let mut file = std::fs::File::open(&sz_path)?;
+            let len = file.metadata()?.len();
+            let archive = sevenz_rust::Archive::read(&mut file, len, &[])
+                .map_err(|e| anyhow!("7z read error: {}", e))?;
+            archive.files.len() as u64
+        };
+
+        let mut progress = ThrottledProgress::new(on_progress);
+        let mut current = 0;
+        // Errors from inside the callback are kept here; sevenz_rust would only return its own type
+        let mut failure = None;
+        sevenz_rust::decompress_file_with_extract_fn(&sz_path, &dst, |entry, reader, _| {
+            let name = entry.name().to_string();
+            current += 1;
+            progress.report(current, total, || format!("Extracting {}...", name));
+
+            let Some(relative_name) = relative_entry_name(&name, root.as_deref()) else {
+                return Ok(true);
+            };
+
+            // The extract fn's path already includes the entry name, so join onto dst
+            let outpath = dst.join(relative_name);
+            let result = if entry.is_directory() {
+                std::fs::create_dir_all(&outpath).map_err(anyhow::Error::from)
+            } else {
+                copy_entry(reader, &outpath, &cancel)
+            };
+            match result {
+                Ok(()) => Ok(true),
+                Err(e) => {
+                    failure = Some(e);
+                    Ok(false)
+                }
+            }
+        })
+        .map_err(|e| anyhow!("7z decompression error: {}", e))?;
+
+        if let Some(e) = failure {
+            return Err(e);
+        }
+        cancel.check()?;
+        progress.report(total, total, || "Extraction complete".to_string());
+        Ok::<(), anyhow::Error>(())
+    })
+    .await?
+}
@@ -0,0 +1,50 @@
+use super::{CancelFlag, ThrottledProgress, copy_entry, normalize_root, relative_entry_name};
+use anyhow::Result;
+use std::path::Path;
+
+/// Extracts `zip_path` into `dst` on the blocking pool, one entry at a time.
+pub async fn extract_zip<F>(
+    zip_path: &Path,
+    dst: &Path,
+    root_within_zip: Option<String>,
+    cancel: CancelFlag,
+    on_progress: F,
+) -> Result<()>
+where
+    F: Fn(u64, u64, String) + Send + Sync + 'static,
+{
+    let zip_path = zip_path.to_path_buf();
+    let dst = dst.to_path_buf();
+
+    tokio::task::spawn_blocking(move || {
+        let file = std::fs::File::open(&zip_path)?;
+        let mut archive = zip::ZipArchive::new(file)?;
+        let total = archive.len() as u64;
+        let root = normalize_root(root_within_zip);
+        let mut progress = ThrottledProgress::new(on_progress);
+
+        for i in 0..archive.len() {
+            cancel.check()?;
+            let mut file = archive.by_index(i)?;
+            let name = file.name().to_string();
+            progress.report(i as u64, total, || format!("Extracting {}...", name));
+
+            if file.enclosed_name().is_none() {
+                continue;
+            }
+            let Some(relative_name) = relative_entry_name(&name, root.as_deref()) else {
+                continue;
+            };
+
+            let outpath = dst.join(relative_name);
+            if name.ends_with('/') {
+                std::fs::create_dir_all(&outpath)?;
+            } else {
+                copy_entry(&mut file, &outpath, &cancel)?;
+            }
+        }
+        progress.report(total, total, || "Extraction complete".to_string());
+        Ok::<(), anyhow::Error>(())
+    })
+    .await?
+}
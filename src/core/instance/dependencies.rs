@@ -0,0 +1,117 @@
+//! Startup ordering between instances: an instance can declare other instances that
+//! must be running before it starts (e.g. backend servers before the proxy).
+
+use super::InstanceMetadata;
+use anyhow::{Result, anyhow};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+fn dependency_map(instances: &[InstanceMetadata]) -> HashMap<Uuid, Vec<Uuid>> {
+    let known: HashSet<Uuid> = instances.iter().map(|i| i.id).collect();
+    instances
+        .iter()
+        .map(|i| {
+            // Dependencies on deleted instances are ignored rather than blocking the start
+            let deps = i
+                .settings
+                .depends_on
+                .iter()
+                .copied()
+                .filter(|d| known.contains(d))
+                .collect();
+            (i.id, deps)
+        })
+        .collect()
+}
+
+fn names(instances: &[InstanceMetadata], ids: &[Uuid]) -> String {
+    ids.iter()
+        .map(|id| {
+            instances
+                .iter()
+                .find(|i| i.id == *id)
+                .map(|i| i.name.clone())
+                .unwrap_or_else(|| id.to_string())
+        })
+        .collect::<Vec<_>>()
+        .join(" -> ")
+}
+
+/// Returns a dependency cycle reachable from `start` as a path that ends where it began.
+fn find_cycle(graph: &HashMap<Uuid, Vec<Uuid>>, start: Uuid) -> Option<Vec<Uuid>> {
+    fn visit(
+        graph: &HashMap<Uuid, Vec<Uuid>>,
+        id: Uuid,
+        path: &mut Vec<Uuid>,
+        done: &mut HashSet<Uuid>,
+    ) -> Option<Vec<Uuid>> {
+        if let Some(pos) = path.iter().position(|p| *p == id) {
+            let mut cycle = path[pos..].to_vec();
+            cycle.push(id);
+            return Some(cycle);
+        }
+        if done.contains(&id) {
+            return None;
+        }
+        path.push(id);
+        for dep in graph.get(&id).into_iter().flatten() {
+            if let Some(cycle) = visit(graph, *dep, path, done) {
+                return Some(cycle);
+            }
+        }
+        path.pop();
+        done.insert(id);
+        None
+    }
+    visit(graph, start, &mut Vec::new(), &mut HashSet::new())
+}
+
+/// Checks that `depends_on` only names other existing instances and doesn't create a cycle.
+pub fn validate_dependencies(instances: &[InstanceMetadata], id: Uuid, depends_on: &[Uuid]) -> Result<()> {
+    if depends_on.contains(&id) {
+        return Err(anyhow!("An instance cannot depend on itself"));
+    }
+    if let Some(missing) = depends_on.iter().find(|d| !instances.iter().any(|i| i.id == **d)) {
+        return Err(anyhow!("Dependency {} does not exist", missing));
+    }
+    let mut graph = dependency_map(instances);
+    graph.insert(id, depends_on.to_vec());
+    match find_cycle(&graph, id) {
+        Some(cycle) => Err(anyhow!("Dependency cycle: {}", names(instances, &cycle))),
+        None => Ok(()),
+    }
+}
+
+/// Groups `ids` into waves that can be started together: every instance comes after
+/// all of its dependencies. Dependencies that weren't requested are pulled in so they
+/// start first. Fails with the offending path if the dependencies form a cycle.
+pub fn startup_waves(instances: &[InstanceMetadata], ids: &[Uuid]) -> Result<Vec<Vec<Uuid>>> {
+    let graph = dependency_map(instances);
+
+    let mut pending: Vec<Uuid> = Vec::new();
+    let mut stack: Vec<Uuid> = ids.iter().copied().filter(|id| graph.contains_key(id)).collect();
+    while let Some(id) = stack.pop() {
+        if pending.contains(&id) {
+            continue;
+        }
+        if let Some(cycle) = find_cycle(&graph, id) {
+            return Err(anyhow!("Dependency cycle: {}", names(instances, &cycle)));
+        }
+        pending.push(id);
+        stack.extend(graph[&id].iter().copied());
+    }
+    // Keep the requested order within a wave
+    pending.sort_by_key(|id| ids.iter().position(|i| i == id).unwrap_or(usize::MAX));
+
+    let mut started: HashSet<Uuid> = HashSet::new();
+    let mut waves = Vec::new();
+    while !pending.is_empty() {
+        let (ready, rest): (Vec<Uuid>, Vec<Uuid>) = pending
+            .into_iter()
+            .partition(|id| graph[id].iter().all(|d| started.contains(d)));
+        started.extend(ready.iter().copied());
+        waves.push(ready);
+        pending = rest;
+    }
+    Ok(waves)
+}
@@ -0,0 +1,94 @@
+use crate::shell::validate_command;
+use crate::utils::{safe_join, validate_rel_path};
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[serde(default)]
+pub struct EnvVar {
+    pub key: String,
+    pub value: String,
+}
+
+/// Environment, working folder and hook scripts for launching an instance.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[serde(default)]
+pub struct LaunchEnvironment {
+    /// Added to the server's environment, replacing inherited variables of the same name.
+    pub variables: Vec<EnvVar>,
+    /// Folder the server runs in, relative to the instance folder. Empty for the
+    /// instance folder itself.
+    pub working_dir: String,
+    /// Shell command run in the working folder before every launch. If it fails, the
+    /// server isn't started.
+    pub pre_start_hook: String,
+    /// Shell command run in the working folder once the server has stopped. Automatic
+    /// crash restarts don't run it.
+    pub post_stop_hook: String,
+}
+
+impl LaunchEnvironment {
+    pub fn validate(&self) -> Result<()> {
+        let mut seen = HashSet::new();
+        for var in &self.variables {
+            let key = var.key.trim();
+            if key.is_empty() {
+                return Err(anyhow!("Environment variables need a name"));
+            }
+            if key.contains(['=', '\0']) || key.contains(char::is_whitespace) {
+                return Err(anyhow!("Invalid environment variable name: {}", key));
+            }
+            if var.value.contains('\0') {
+                return Err(anyhow!("The value of {} contains a NUL character", key));
+            }
+            if !seen.insert(key) {
+                return Err(anyhow!("Environment variable {} is set twice", key));
+            }
+        }
+        if !self.working_dir.trim().is_empty() {
+            validate_rel_path(self.working_dir.trim())
+                .map_err(|_| anyhow!("The working folder must be inside the instance folder"))?;
+        }
+        for hook in [&self.pre_start_hook, &self.post_stop_hook] {
+            if !hook.trim().is_empty() {
+                validate_command(hook)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn variables(&self) -> Vec<(String, String)> {
+        self.variables
+            .iter()
+            .map(|v| (v.key.trim().to_string(), v.value.clone()))
+            .filter(|(key, _)| !key.is_empty())
+            .collect()
+    }
+
+    pub fn overrides_working_dir(&self) -> bool {
+        !self.working_dir.trim().is_empty()
+    }
+
+    /// The folder the server runs in.
+    pub fn resolve_working_dir(&self, instance_path: &Path) -> Result<PathBuf> {
+        match self.working_dir.trim() {
+            "" => Ok(instance_path.to_path_buf()),
+            dir => safe_join(instance_path, dir),
+        }
+    }
+
+    pub fn pre_start_hook(&self) -> Option<String> {
+        non_empty(&self.pre_start_hook)
+    }
+
+    pub fn post_stop_hook(&self) -> Option<String> {
+        non_empty(&self.post_stop_hook)
+    }
+}
+
+fn non_empty(command: &str) -> Option<String> {
+    let command = command.trim();
+    (!command.is_empty()).then(|| command.to_string())
+}
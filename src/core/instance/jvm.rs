@@ -0,0 +1,153 @@
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+use std::path::Path;
+
+/// Flags the wrapper sets itself, which would conflict with the structured settings.
+const MANAGED_FLAGS: [&str; 4] = ["-Xmx", "-Xms", "-jar", "-agentlib:jdwp"];
+
+/// A `-javaagent`, e.g. spark's standalone agent or the Prometheus JMX exporter.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct JavaAgent {
+    pub enabled: bool,
+    /// Agent jar, absolute or relative to the instance folder.
+    pub path: String,
+    /// Passed after `=`, e.g. `9404:jmx.yaml` for the JMX exporter.
+    pub options: String,
+}
+
+impl Default for JavaAgent {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            path: String::new(),
+            options: String::new(),
+        }
+    }
+}
+
+/// JDWP remote debugging, for attaching an IDE debugger to the server.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct DebugSettings {
+    pub enabled: bool,
+    pub port: u16,
+    pub bind_address: String,
+    /// Hold startup until a debugger attaches.
+    pub suspend: bool,
+}
+
+impl Default for DebugSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: 5005,
+            bind_address: "127.0.0.1".to_string(),
+            suspend: false,
+        }
+    }
+}
+
+/// Structured JVM flags merged into the launch command. Each flag is passed as its
+/// own argument, so nothing here is interpreted by a shell.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[serde(default)]
+pub struct JvmOptions {
+    pub agents: Vec<JavaAgent>,
+    pub debug: DebugSettings,
+    /// Additional flags such as `-XX:+UseG1GC`, one per entry.
+    pub extra_flags: Vec<String>,
+}
+
+fn is_loopback(address: &str) -> bool {
+    address.eq_ignore_ascii_case("localhost") || address.parse::<IpAddr>().is_ok_and(|ip| ip.is_loopback())
+}
+
+impl JvmOptions {
+    pub fn validate(&self) -> Result<()> {
+        for agent in &self.agents {
+            if agent.path.trim().is_empty() {
+                return Err(anyhow!("Java agents need a jar path"));
+            }
+        }
+        if self.debug.enabled {
+            if self.debug.port == 0 {
+                return Err(anyhow!("Debug port must be between 1 and 65535"));
+            }
+            let address = self.debug.bind_address.trim();
+            if address != "*" && !is_loopback(address) && address.parse::<IpAddr>().is_err() {
+                return Err(anyhow!("Invalid debug bind address: {}", address));
+            }
+        }
+        for flag in self.extra_flags.iter().map(|f| f.trim()).filter(|f| !f.is_empty()) {
+            if !flag.starts_with('-') || flag.contains(char::is_whitespace) {
+                return Err(anyhow!(
+                    "JVM flags must start with '-' and hold a single flag: {}",
+                    flag
+                ));
+            }
+            if let Some(managed) = MANAGED_FLAGS.iter().find(|m| flag.starts_with(*m)) {
+                return Err(anyhow!(
+                    "{} is managed by the instance settings and can't be added as a flag",
+                    managed
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Arguments inserted before `-jar`. Relative agent paths resolve against `instance_dir`.
+    pub fn jvm_args(&self, instance_dir: &Path) -> Vec<String> {
+        let mut args: Vec<String> = self
+            .agents
+            .iter()
+            .filter(|a| a.enabled && !a.path.trim().is_empty())
+            .map(|a| {
+                let path = instance_dir.join(a.path.trim());
+                match a.options.trim() {
+                    "" => format!("-javaagent:{}", path.display()),
+                    options => format!("-javaagent:{}={}", path.display(), options),
+                }
+            })
+            .collect();
+        if self.debug.enabled {
+            args.push(format!(
+                "-agentlib:jdwp=transport=dt_socket,server=y,suspend={},address={}:{}",
+                if self.debug.suspend { "y" } else { "n" },
+                self.debug.bind_address.trim(),
+                self.debug.port
+            ));
+        }
+        args.extend(
+            self.extra_flags
+                .iter()
+                .map(|f| f.trim().to_string())
+                .filter(|f| !f.is_empty()),
+        );
+        args
+    }
+
+    /// Problems worth showing before the server starts: missing agent jars and debug
+    /// ports reachable from other machines.
+    pub fn warnings(&self, instance_dir: &Path) -> Vec<String> {
+        let mut warnings = Vec::new();
+        for agent in self.agents.iter().filter(|a| a.enabled && !a.path.trim().is_empty()) {
+            if !instance_dir.join(agent.path.trim()).is_file() {
+                warnings.push(format!("Java agent {} was not found", agent.path.trim()));
+            }
+        }
+        if self.debug.enabled && !is_loopback(self.debug.bind_address.trim()) {
+            warnings.push(format!(
+                "The debug port {} is bound to {}, so anyone who can reach it can run code on this machine. \
+                 Bind it to 127.0.0.1 and use an SSH tunnel instead.",
+                self.debug.port,
+                self.debug.bind_address.trim()
+            ));
+        }
+        if self.debug.enabled && self.debug.suspend {
+            warnings.push("The server will wait for a debugger to attach before it starts".to_string());
+        }
+        warnings
+    }
+}
@@ -0,0 +1,72 @@
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+
+/// Scheduling priority of the server process relative to everything else on the machine.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessPriority {
+    Low,
+    BelowNormal,
+    #[default]
+    Normal,
+    AboveNormal,
+    High,
+}
+
+impl ProcessPriority {
+    /// Unix nice value. Going below 0 needs root or `CAP_SYS_NICE`.
+    pub fn niceness(self) -> i32 {
+        match self {
+            ProcessPriority::Low => 19,
+            ProcessPriority::BelowNormal => 10,
+            ProcessPriority::Normal => 0,
+            ProcessPriority::AboveNormal => -5,
+            ProcessPriority::High => -10,
+        }
+    }
+
+    /// Windows priority class, passed as a process creation flag.
+    pub fn windows_priority_class(self) -> u32 {
+        match self {
+            ProcessPriority::Low => 0x0000_0040,
+            ProcessPriority::BelowNormal => 0x0000_4000,
+            ProcessPriority::Normal => 0x0000_0020,
+            ProcessPriority::AboveNormal => 0x0000_8000,
+            ProcessPriority::High => 0x0000_0080,
+        }
+    }
+}
+
+/// Limits that keep one server from starving the others on the same machine.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[serde(default)]
+pub struct ResourceLimits {
+    pub priority: ProcessPriority,
+    /// Logical CPUs the server may run on, counting from 0. Empty for all of them.
+    pub cpu_affinity: Vec<usize>,
+    /// Memory the server's processes may commit in total, in megabytes. 0 for no cap.
+    /// Only enforced on Windows, through a Job Object.
+    pub memory_limit_mb: u64,
+}
+
+impl ResourceLimits {
+    /// `max_heap_mb` is the instance's `-Xmx`, which the cap has to leave room above.
+    pub fn validate(&self, max_heap_mb: u64) -> Result<()> {
+        let cpus = std::thread::available_parallelism().map_or(1, |n| n.get());
+        if let Some(cpu) = self.cpu_affinity.iter().find(|&&cpu| cpu >= cpus) {
+            return Err(anyhow!("CPU {} doesn't exist on this machine, which has {} CPUs", cpu, cpus));
+        }
+        if self.memory_limit_mb > 0 && self.memory_limit_mb <= max_heap_mb {
+            return Err(anyhow!(
+                "The memory cap of {} MB has to be above the maximum heap of {} MB",
+                self.memory_limit_mb,
+                max_heap_mb
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
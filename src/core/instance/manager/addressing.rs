@@ -0,0 +1,81 @@
+//! Lets callers refer to an instance by its UUID, its exact name or its slug
+//! ("My Server!" -> "my-server"), so scripts don't have to look up UUIDs first.
+
+use super::InstanceManager;
+use anyhow::{Result, anyhow};
+use crate::errors::AppError;
+use sqlx::Row;
+use uuid::Uuid;
+
+/// Lowercases `name` and joins its runs of letters and digits with single dashes.
+pub fn slugify(name: &str) -> String {
+    let mut slug = String::new();
+    for c in name.chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+impl InstanceManager {
+    async fn instance_names(&self) -> Result<Vec<(Uuid, String)>> {
+        let rows = sqlx::query("SELECT id, name FROM instances")
+            .fetch_all(self.db.pool())
+            .await?;
+        rows.iter()
+            .map(|row| Ok((Uuid::parse_str(&row.try_get::<String, _>("id")?)?, row.try_get("name")?)))
+            .collect()
+    }
+
+    /// Resolves a UUID, an exact instance name or a slug to the instance's id.
+    pub async fn resolve_instance_id(&self, reference: &str) -> Result<Uuid> {
+        let reference = reference.trim();
+        if let Ok(id) = Uuid::parse_str(reference) {
+            return Ok(id);
+        }
+
+        let names = self.instance_names().await?;
+        if let Some((id, _)) = names.iter().find(|(_, name)| name == reference) {
+            return Ok(*id);
+        }
+        let slug = slugify(reference);
+        let matches: Vec<&(Uuid, String)> = names.iter().filter(|(_, name)| slugify(name) == slug).collect();
+        match matches.as_slice() {
+            [(id, _)] => Ok(*id),
+            [] => Err(AppError::NotFound(format!("Instance not found: {}", reference)).into()),
+            _ => Err(anyhow!("\"{}\" matches more than one instance; use its UUID", reference)),
+        }
+    }
+
+    /// Checks that `name` can be used for a new or renamed instance: it must not look like
+    /// a UUID and its slug must not clash with any other instance (`exclude` is skipped).
+    pub async fn ensure_name_available(&self, name: &str, exclude: Option<Uuid>) -> Result<()> {
+        if Uuid::parse_str(name.trim()).is_ok() {
+            return Err(anyhow!("Instance names cannot be a UUID"));
+        }
+        let slug = slugify(name);
+        if slug.is_empty() {
+            return Err(anyhow!("Instance names need at least one letter or digit"));
+        }
+        let names = self.instance_names().await?;
+        match names.iter().find(|(id, other)| Some(*id) != exclude && slugify(other) == slug) {
+            Some((_, other)) => Err(anyhow!("The name \"{}\" is too close to the existing instance \"{}\"", name, other)),
+            None => Ok(()),
+        }
+    }
+
+    /// `base`, or `base (2)`, `base (3)`... whichever is first free.
+    pub async fn unique_instance_name(&self, base: &str) -> Result<String> {
+        let taken: Vec<String> = self.instance_names().await?.iter().map(|(_, name)| slugify(name)).collect();
+        let mut name = base.to_string();
+        let mut n = 2;
+        while taken.contains(&slugify(&name)) {
+            name = format!("{} ({})", base, n);
+            n += 1;
+        }
+        Ok(name)
+    }
+}
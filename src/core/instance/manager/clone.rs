@@ -1,4 +1,5 @@
 use super::InstanceManager;
+use crate::mod_loaders::requires_folia_plugins;
 use crate::instance::archive::copy_dir_all;
 use crate::instance::types::InstanceMetadata;
 use anyhow::{Context, Result};
@@ -9,6 +10,7 @@ use uuid::Uuid;
 impl InstanceManager {
     pub async fn clone_instance(&self, id: Uuid, new_name: &str) -> Result<InstanceMetadata> {
         let instance = self.get_instance(id).await?.context("Instance not found")?;
+        self.ensure_name_available(new_name, None).await?;
 
         let new_id = Uuid::new_v4();
         let new_path = self.base_dir.join(new_id.to_string());
@@ -16,17 +18,22 @@ impl InstanceManager {
         // Copy directory recursively
         copy_dir_all(&instance.path, &new_path, |_, _, _| {}).await?;
 
+        let mut settings = instance.settings.clone();
+        settings.lineage_id = Some(instance.settings.lineage_id.unwrap_or(instance.id));
+
         let new_metadata = InstanceMetadata {
             id: new_id,
             name: new_name.to_string(),
             version: instance.version.clone(),
+            folia_plugins_only: requires_folia_plugins(instance.mod_loader.as_deref()),
+            linked: false,
             mod_loader: instance.mod_loader.clone(),
             loader_version: instance.loader_version.clone(),
             created_at: Utc::now(),
             last_run: None,
             path: new_path,
             schedules: instance.schedules.clone(),
-            settings: instance.settings.clone(),
+            settings,
             status: crate::server::types::ServerStatus::Stopped,
             ip: None,
             port: None,
@@ -36,6 +43,13 @@ impl InstanceManager {
 
         self.save_instance_to_db(&new_metadata).await?;
 
+        // The source joins the lineage too, so it can be compared with its clones
+        if instance.settings.lineage_id.is_none() {
+            let mut source = instance.clone();
+            source.settings.lineage_id = Some(instance.id);
+            self.save_instance_to_db(&source).await?;
+        }
+
         info!(
             "Cloned instance: {} to {} (New ID: {})",
             instance.name, new_name, new_id
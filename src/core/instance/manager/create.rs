@@ -1,4 +1,5 @@
 use super::InstanceManager;
+use crate::mod_loaders::requires_folia_plugins;
 use crate::instance::types::{InstanceMetadata, InstanceSettings};
 use crate::mods::ModrinthClient;
 use crate::mods::types::ProjectVersion;
@@ -23,6 +24,7 @@ impl InstanceManager {
     where
         F: Fn(crate::mods::modrinth::modpack::ModpackProgress) + Send + 'static,
     {
+        self.ensure_name_available(name, None).await?;
         let client = ModrinthClient::new(cache);
 
         let id = Uuid::new_v4();
@@ -57,6 +59,8 @@ impl InstanceManager {
             id,
             name: name.to_string(),
             version: game_version.clone(),
+            folia_plugins_only: requires_folia_plugins(mod_loader.as_deref()),
+            linked: false,
             mod_loader,
             loader_version,
             created_at: Utc::now(),
@@ -84,6 +88,7 @@ impl InstanceManager {
         mod_loader: Option<String>,
         loader_version: Option<String>,
     ) -> Result<InstanceMetadata> {
+        self.ensure_name_available(name, None).await?;
         let id = Uuid::new_v4();
         let instance_path = self.base_dir.join(id.to_string());
         fs::create_dir_all(&instance_path).await?;
@@ -92,6 +97,8 @@ impl InstanceManager {
             id,
             name: name.to_string(),
             version: version.to_string(),
+            folia_plugins_only: requires_folia_plugins(mod_loader.as_deref()),
+            linked: false,
             mod_loader,
             loader_version,
             created_at: Utc::now(),
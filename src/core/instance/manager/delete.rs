@@ -1,33 +1,41 @@
 use super::InstanceManager;
+use crate::instance::types::InstanceMetadata;
+use crate::utils::fs::dir_size;
+use anyhow::{Context, Result};
 use tokio::fs;
-use tracing::info;
+use tracing::{info, warn};
 use uuid::Uuid;
-use anyhow::Result;
+
+/// Tables holding per-instance rows, cleared together with the instance itself.
+const INSTANCE_TABLES: [&str; 7] = [
+    "command_history",
+    "crash_reports",
+    "metrics_samples",
+    "notification_rules",
+    "profiler_sessions",
+    "query_history",
+    "timeline_events",
+];
+
+/// What [`InstanceManager::remove_instance`] deleted.
+pub struct RemovedInstance {
+    pub instance: InstanceMetadata,
+    /// Size of the instance folder before it was removed.
+    pub bytes: u64,
+    pub db_rows: u64,
+}
 
 impl InstanceManager {
     pub async fn delete_instance(&self, id: Uuid) -> Result<()> {
-        if let Some(instance) = self.get_instance(id).await? {
-            if instance.path.exists() {
-                fs::remove_dir_all(&instance.path).await?;
-            }
-            sqlx::query("DELETE FROM instances WHERE id = ?")
-                .bind(id.to_string())
-                .execute(self.db.pool())
-                .await?;
-            info!("Deleted instance: {} (ID: {})", instance.name, id);
+        if let Some(removed) = self.remove_instance(id).await? {
+            info!("Deleted instance: {} (ID: {})", removed.instance.name, id);
         }
         Ok(())
     }
 
     pub async fn delete_instance_by_name(&self, name: &str) -> Result<()> {
         if let Some(instance) = self.get_instance_by_name(name).await? {
-            if instance.path.exists() {
-                fs::remove_dir_all(&instance.path).await?;
-            }
-            sqlx::query("DELETE FROM instances WHERE name = ?")
-                .bind(name)
-                .execute(self.db.pool())
-                .await?;
+            self.remove_instance(instance.id).await?;
             info!(
                 "Deleted instance by name: {} (ID: {})",
                 instance.name, instance.id
@@ -35,4 +43,59 @@ impl InstanceManager {
         }
         Ok(())
     }
+
+    /// Deletes an instance's folder and all of its database rows. The folder is moved aside
+    /// first and put back if the rows can't be deleted, so a failure leaves the instance intact.
+    /// A linked instance's folder is the user's and is kept.
+    pub async fn remove_instance(&self, id: Uuid) -> Result<Option<RemovedInstance>> {
+        let Some(instance) = self.get_instance(id).await? else {
+            return Ok(None);
+        };
+
+        let bytes = if instance.linked { 0 } else { dir_size(&instance.path) };
+        // Next to the folder, so the move stays on its disk
+        let trash = instance.path.with_file_name(format!(".deleting-{}", id));
+        let moved = !instance.linked && instance.path.exists();
+        if moved {
+            fs::rename(&instance.path, &trash)
+                .await
+                .with_context(|| format!("Failed to move {:?} aside; is a file in it still open?", instance.path))?;
+        }
+
+        let db_rows = match self.delete_instance_rows(id).await {
+            Ok(rows) => rows,
+            Err(e) => {
+                if moved {
+                    let _ = fs::rename(&trash, &instance.path).await;
+                }
+                return Err(e);
+            }
+        };
+
+        if moved && let Err(e) = fs::remove_dir_all(&trash).await {
+            warn!("Failed to remove files of deleted instance {}: {}", instance.name, e);
+        }
+        Ok(Some(RemovedInstance { instance, bytes, db_rows }))
+    }
+
+    /// Removes the instance row and every row that references it in one transaction.
+    async fn delete_instance_rows(&self, id: Uuid) -> Result<u64> {
+        let id = id.to_string();
+        let mut tx = self.db.pool().begin().await?;
+        let mut rows = 0;
+        for table in INSTANCE_TABLES {
+            rows += sqlx::query(&format!("DELETE FROM {} WHERE instance_id = ?", table))
+                .bind(&id)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected();
+        }
+        rows += sqlx::query("DELETE FROM instances WHERE id = ?")
+            .bind(&id)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+        tx.commit().await?;
+        Ok(rows)
+    }
 }
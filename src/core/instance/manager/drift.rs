@@ -0,0 +1,117 @@
+use super::InstanceManager;
+use crate::config_files::{
+    ConfigDrift, ConfigFormat, diff_configs, list_available_configs, read_config_value,
+    save_config_value, set_value_at, value_at,
+};
+use crate::errors::AppError;
+use crate::instance::types::InstanceMetadata;
+use anyhow::{Context, Result, anyhow};
+use tracing::info;
+use uuid::Uuid;
+
+impl InstanceManager {
+    /// Lists the other instances sharing a lineage (clones of the same source) with `id`.
+    pub async fn get_related_instances(&self, id: Uuid) -> Result<Vec<InstanceMetadata>> {
+        let instance = self.get_instance(id).await?.context("Instance not found")?;
+        let Some(lineage) = instance.settings.lineage_id else {
+            return Ok(Vec::new());
+        };
+
+        Ok(self
+            .list_instances()
+            .await?
+            .into_iter()
+            .filter(|i| i.id != id && (i.settings.lineage_id == Some(lineage) || i.id == lineage))
+            .collect())
+    }
+
+    /// Diffs server.properties and the other known config files between instances.
+    ///
+    /// Files are taken from the first instance; only files and keys that differ are returned.
+    pub async fn compare_instance_configs(&self, ids: &[Uuid]) -> Result<Vec<ConfigDrift>> {
+        if ids.len() < 2 {
+            return Err(anyhow!("At least two instances are needed for a comparison"));
+        }
+
+        let mut instances = Vec::with_capacity(ids.len());
+        for id in ids {
+            instances.push(
+                self.get_instance(*id)
+                    .await?
+                    .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", id)))?,
+            );
+        }
+
+        let base = &instances[0];
+        let files = list_available_configs(&base.path, base.mod_loader.as_deref()).await;
+
+        let mut drift = Vec::new();
+        for file in files {
+            let mut configs = Vec::with_capacity(instances.len());
+            for instance in &instances {
+                // A file that fails to parse is treated as empty rather than aborting the comparison
+                let config = read_config_value(&instance.path, &file.path, file.format.clone())
+                    .await
+                    .unwrap_or_default();
+                configs.push((instance.id, config));
+            }
+
+            let entries = diff_configs(&configs);
+            if !entries.is_empty() {
+                drift.push(ConfigDrift {
+                    path: file.path,
+                    format: file.format,
+                    entries,
+                });
+            }
+        }
+        Ok(drift)
+    }
+
+    /// Copies the values at the chosen paths (see [`DriftEntry::path`]) of one config file
+    /// from `source_id` to each target instance, leaving the rest of their files alone.
+    ///
+    /// Values missing from the source are removed from the targets.
+    ///
+    /// [`DriftEntry::path`]: crate::config_files::DriftEntry::path
+    pub async fn sync_config_keys(
+        &self,
+        source_id: Uuid,
+        target_ids: &[Uuid],
+        rel_path: &str,
+        format: ConfigFormat,
+        paths: &[Vec<String>],
+    ) -> Result<()> {
+        if rel_path.contains("..") {
+            return Err(anyhow!("Invalid config path: {}", rel_path));
+        }
+        let source = self
+            .get_instance(source_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", source_id)))?;
+        let source_config = read_config_value(&source.path, rel_path, format.clone()).await?;
+
+        for target_id in target_ids.iter().filter(|id| **id != source_id) {
+            let target = self
+                .get_instance(*target_id)
+                .await?
+                .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", target_id)))?;
+            let mut config = read_config_value(&target.path, rel_path, format.clone()).await?;
+
+            for path in paths {
+                set_value_at(&mut config, path, value_at(&source_config, path).cloned());
+            }
+
+            // Keep the target's previous file since this overwrites another instance's config
+            save_config_value(&target.path, rel_path, format.clone(), config, true).await?;
+            info!(
+                "Synced {} key(s) of {} from {} to {}",
+                paths.len(),
+                rel_path,
+                source.name,
+                target.name
+            );
+        }
+        Ok(())
+    }
+}
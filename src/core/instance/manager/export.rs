@@ -0,0 +1,81 @@
+use super::InstanceManager;
+use crate::instance::portable::{ExportFormat, ExportManifest, MANIFEST_VERSION, write_instance_archive};
+use crate::mods::types::ModCache;
+use crate::plugins::metadata::PluginCache;
+use anyhow::{Context, Result, anyhow};
+use chrono::Utc;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tracing::info;
+use uuid::Uuid;
+
+pub(super) const MOD_CACHE: &str = "mods/.mod_metadata_cache.json";
+pub(super) const PLUGIN_CACHE: &str = "plugins/.plugin_metadata_cache.json";
+
+impl InstanceManager {
+    /// Packs an instance into a portable archive at `dest`, with a manifest of its
+    /// metadata, settings and mod/plugin sources. Returns the archive path.
+    pub async fn export_instance<F>(
+        &self,
+        id: Uuid,
+        dest: PathBuf,
+        format: ExportFormat,
+        on_progress: F,
+    ) -> Result<PathBuf>
+    where
+        F: Fn(u64, u64, String) + Send + Sync + 'static,
+    {
+        let instance = self.get_instance(id).await?.context("Instance not found")?;
+        let dest = if dest.extension().is_none() {
+            dest.with_extension(format.extension())
+        } else {
+            dest
+        };
+        // Compared resolved, so `..` or a symlinked folder can't sneak the archive inside
+        let dest = resolve_dest(&dest).await?;
+        let instance_path = fs::canonicalize(&instance.path)
+            .await
+            .with_context(|| format!("Instance folder {:?} is missing", instance.path))?;
+        if dest.starts_with(&instance_path) {
+            return Err(anyhow!("Cannot export an instance into its own folder"));
+        }
+
+        let mod_cache: ModCache = read_json(&instance.path.join(MOD_CACHE)).await;
+        let plugin_cache: PluginCache = read_json(&instance.path.join(PLUGIN_CACHE)).await;
+        let manifest = ExportManifest {
+            format_version: MANIFEST_VERSION,
+            exported_at: Utc::now(),
+            wrapper_version: env!("CARGO_PKG_VERSION").to_string(),
+            name: instance.name.clone(),
+            version: instance.version.clone(),
+            mod_loader: instance.mod_loader.clone(),
+            loader_version: instance.loader_version.clone(),
+            settings: instance.settings.clone(),
+            schedules: instance.schedules.clone(),
+            mod_sources: mod_cache.sources,
+            plugin_sources: plugin_cache.sources,
+        };
+
+        write_instance_archive(&instance.path, &dest, format, &manifest, on_progress).await?;
+        info!("Exported instance {} to {:?}", instance.name, dest);
+        Ok(dest)
+    }
+}
+
+/// Resolves `..`, symlinks and relative parts of an archive path that doesn't exist yet
+/// through its folder, which must.
+async fn resolve_dest(dest: &Path) -> Result<PathBuf> {
+    let file_name = dest.file_name().context("Export path has no file name")?;
+    let parent = dest.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    let parent = fs::canonicalize(parent)
+        .await
+        .with_context(|| format!("Export folder {:?} doesn't exist", parent))?;
+    Ok(parent.join(file_name))
+}
+
+pub(super) async fn read_json<T: serde::de::DeserializeOwned + Default>(path: &Path) -> T {
+    match fs::read_to_string(path).await {
+        Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+        Err(_) => T::default(),
+    }
+}
@@ -0,0 +1,180 @@
+use super::InstanceManager;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Row;
+use uuid::Uuid;
+
+/// Maximum number of console commands kept per instance.
+const MAX_HISTORY_PER_INSTANCE: i64 = 500;
+/// Maximum number of explorer queries kept per database.
+const MAX_QUERIES_PER_DATABASE: i64 = 100;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CommandHistoryEntry {
+    pub command: String,
+    pub sent_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct QueryHistoryEntry {
+    pub query: String,
+    pub ran_at: DateTime<Utc>,
+}
+
+impl InstanceManager {
+    /// Stores a console command sent to an instance, trimming the oldest entries
+    /// once the per-instance limit is reached.
+    pub async fn record_command(&self, instance_id: Uuid, command: &str) -> Result<()> {
+        let command = command.trim();
+        if command.is_empty() {
+            return Ok(());
+        }
+
+        sqlx::query("INSERT INTO command_history (instance_id, command, sent_at) VALUES (?, ?, ?)")
+            .bind(instance_id.to_string())
+            .bind(command)
+            .bind(Utc::now().to_rfc3339())
+            .execute(self.db.pool())
+            .await?;
+
+        sqlx::query(
+            "DELETE FROM command_history WHERE instance_id = ? AND id NOT IN (
+                SELECT id FROM command_history WHERE instance_id = ? ORDER BY id DESC LIMIT ?
+            )",
+        )
+        .bind(instance_id.to_string())
+        .bind(instance_id.to_string())
+        .bind(MAX_HISTORY_PER_INSTANCE)
+        .execute(self.db.pool())
+        .await?;
+
+        Ok(())
+    }
+
+    /// Returns the most recent commands sent to an instance, newest first.
+    pub async fn get_command_history(
+        &self,
+        instance_id: Uuid,
+        limit: u32,
+    ) -> Result<Vec<CommandHistoryEntry>> {
+        let rows = sqlx::query(
+            "SELECT command, sent_at FROM command_history WHERE instance_id = ? ORDER BY id DESC LIMIT ?",
+        )
+        .bind(instance_id.to_string())
+        .bind(limit as i64)
+        .fetch_all(self.db.pool())
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let sent_at: String = row.get("sent_at");
+                CommandHistoryEntry {
+                    command: row.get("command"),
+                    sent_at: DateTime::parse_from_rfc3339(&sent_at)
+                        .map(|d| d.with_timezone(&Utc))
+                        .unwrap_or_else(|_| Utc::now()),
+                }
+            })
+            .collect())
+    }
+
+    pub async fn clear_command_history(&self, instance_id: Uuid) -> Result<()> {
+        sqlx::query("DELETE FROM command_history WHERE instance_id = ?")
+            .bind(instance_id.to_string())
+            .execute(self.db.pool())
+            .await?;
+        Ok(())
+    }
+
+    /// Stores a query run in the database explorer. `db_path` is relative to the instance,
+    /// so the history follows the instance if it moves. Running the most recent query again
+    /// only refreshes its time.
+    pub async fn record_query(&self, instance_id: Uuid, db_path: &str, query: &str) -> Result<()> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Ok(());
+        }
+
+        let latest: Option<(i64, String)> = sqlx::query_as(
+            "SELECT id, query FROM query_history WHERE instance_id = ? AND db_path = ? ORDER BY id DESC LIMIT 1",
+        )
+        .bind(instance_id.to_string())
+        .bind(db_path)
+        .fetch_optional(self.db.pool())
+        .await?;
+        if let Some((id, latest)) = latest
+            && latest == query
+        {
+            sqlx::query("UPDATE query_history SET ran_at = ? WHERE id = ?")
+                .bind(Utc::now().to_rfc3339())
+                .bind(id)
+                .execute(self.db.pool())
+                .await?;
+            return Ok(());
+        }
+
+        sqlx::query("INSERT INTO query_history (instance_id, db_path, query, ran_at) VALUES (?, ?, ?, ?)")
+            .bind(instance_id.to_string())
+            .bind(db_path)
+            .bind(query)
+            .bind(Utc::now().to_rfc3339())
+            .execute(self.db.pool())
+            .await?;
+
+        sqlx::query(
+            "DELETE FROM query_history WHERE instance_id = ? AND db_path = ? AND id NOT IN (
+                SELECT id FROM query_history WHERE instance_id = ? AND db_path = ? ORDER BY id DESC LIMIT ?
+            )",
+        )
+        .bind(instance_id.to_string())
+        .bind(db_path)
+        .bind(instance_id.to_string())
+        .bind(db_path)
+        .bind(MAX_QUERIES_PER_DATABASE)
+        .execute(self.db.pool())
+        .await?;
+
+        Ok(())
+    }
+
+    /// Returns the most recent queries run against a database, newest first.
+    pub async fn get_query_history(
+        &self,
+        instance_id: Uuid,
+        db_path: &str,
+        limit: u32,
+    ) -> Result<Vec<QueryHistoryEntry>> {
+        let rows = sqlx::query(
+            "SELECT query, ran_at FROM query_history WHERE instance_id = ? AND db_path = ? ORDER BY id DESC LIMIT ?",
+        )
+        .bind(instance_id.to_string())
+        .bind(db_path)
+        .bind(limit as i64)
+        .fetch_all(self.db.pool())
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let ran_at: String = row.get("ran_at");
+                QueryHistoryEntry {
+                    query: row.get("query"),
+                    ran_at: DateTime::parse_from_rfc3339(&ran_at)
+                        .map(|d| d.with_timezone(&Utc))
+                        .unwrap_or_else(|_| Utc::now()),
+                }
+            })
+            .collect())
+    }
+
+    pub async fn clear_query_history(&self, instance_id: Uuid, db_path: &str) -> Result<()> {
+        sqlx::query("DELETE FROM query_history WHERE instance_id = ? AND db_path = ?")
+            .bind(instance_id.to_string())
+            .bind(db_path)
+            .execute(self.db.pool())
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use super::ImportPreflight;
+use super::super::InstanceManager;
+use crate::instance::types::InstanceMetadata;
+use anyhow::{Result, anyhow};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use uuid::Uuid;
+
+impl InstanceManager {
+    /// Checks a folder for importing in place: that the name is free and that the
+    /// folder can be run from where it is. Nothing is copied, so size isn't checked.
+    pub async fn preflight_link(&self, name: &str, source_path: &Path) -> Result<ImportPreflight> {
+        let name_error = self.ensure_name_available(name, None).await.err().map(|e| e.to_string());
+        let source_error = self.check_link_source(source_path).await.err().map(|e| e.to_string());
+        Ok(ImportPreflight {
+            name_error,
+            source_error,
+            estimated_size: 0,
+            file_count: 0,
+            available_space: None,
+            insufficient_space: None,
+            root_within_zip: None,
+            root_detected: false,
+        })
+    }
+
+    /// Registers a server folder as an instance without copying it, for servers too big
+    /// to duplicate. The instance runs from that folder, and deleting the instance
+    /// leaves the folder in place.
+    pub async fn link_instance(
+        &self,
+        name: &str,
+        source_path: PathBuf,
+        jar_name: String,
+        mod_loader: Option<String>,
+        script_path: Option<String>,
+    ) -> Result<InstanceMetadata> {
+        self.preflight_link(name, &source_path).await?.check()?;
+        let instance_path = std::path::absolute(&source_path)?;
+        let has_script = script_path.as_ref().is_some_and(|s| instance_path.join(s).is_file());
+        if !has_script && !instance_path.join(&jar_name).is_file() {
+            return Err(anyhow!("{} was not found in {:?}", jar_name, instance_path));
+        }
+        self.finish_import(Uuid::new_v4(), instance_path, name, jar_name, mod_loader, script_path).await
+    }
+
+    /// Why a folder can't be imported in place: it isn't a writable folder, or it
+    /// overlaps the instances directory, a storage root or another instance.
+    async fn check_link_source(&self, source_path: &Path) -> Result<()> {
+        if !source_path.is_dir() {
+            return Err(anyhow!("Only folders can be imported in place"));
+        }
+        let source = fs::canonicalize(source_path).await?;
+        let mut roots = vec![self.base_dir.clone()];
+        roots.extend(self.storage_roots());
+        for root in roots {
+            let Ok(root) = fs::canonicalize(&root).await else { continue };
+            if source.starts_with(&root) || root.starts_with(&source) {
+                return Err(anyhow!("The folder overlaps where instances are stored; import it as a copy instead"));
+            }
+        }
+        for instance in self.list_instances().await? {
+            let Ok(other) = fs::canonicalize(&instance.path).await else { continue };
+            if source.starts_with(&other) || other.starts_with(&source) {
+                return Err(anyhow!("The folder overlaps the files of instance \"{}\"", instance.name));
+            }
+        }
+
+        let probe = source.join(format!(".write-test-{}", Uuid::new_v4()));
+        fs::write(&probe, b"")
+            .await
+            .map_err(|e| anyhow!("The server can't write to this folder: {}", e))?;
+        let _ = fs::remove_file(&probe).await;
+        Ok(())
+    }
+}
@@ -1,54 +1,62 @@
+mod link;
+mod preflight;
+
+pub use preflight::ImportPreflight;
+
 use super::InstanceManager;
-use crate::instance::archive::{copy_dir_all, extract_7z, extract_zip};
+use crate::mod_loaders::requires_folia_plugins;
+use crate::instance::archive::{CancelFlag, copy_dir_all, extract_7z, extract_zip};
 use crate::instance::types::{CrashHandlingMode, InstanceMetadata, InstanceSettings};
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use chrono::Utc;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tokio::fs;
 use tracing::{error, info};
 use uuid::Uuid;
 
 impl InstanceManager {
+    /// Imports a server from a folder or a .zip/.7z archive, after [`Self::preflight_import`]
+    /// passes. Without `root_within_zip`, a nested archive extracts from its single root
+    /// folder. Setting `cancel` stops the extraction; partial files are removed.
     pub async fn import_instance<F>(
         &self,
         name: &str,
         source_path: PathBuf,
-        mut jar_name: String,
+        jar_name: String,
         mod_loader: Option<String>,
         root_within_zip: Option<String>,
         script_path: Option<String>,
+        cancel: CancelFlag,
         on_progress: F,
     ) -> Result<InstanceMetadata>
     where
         F: Fn(u64, u64, String) + Send + Sync + 'static,
     {
+        let preflight = self.preflight_import(name, &source_path, root_within_zip).await?;
+        preflight.check()?;
+        let root_within_zip = preflight.root_within_zip;
         let id = Uuid::new_v4();
         let instance_path = self.base_dir.join(id.to_string());
         fs::create_dir_all(&instance_path).await?;
 
-        if source_path.is_dir() {
-            copy_dir_all(&source_path, &instance_path, on_progress).await?;
-        } else if source_path.is_file() {
-            let extension = source_path
-                .extension()
-                .map_or("", |ext| ext.to_str().unwrap_or(""))
-                .to_lowercase();
-            if extension == "zip" {
-                extract_zip(&source_path, &instance_path, root_within_zip, on_progress).await?;
-            } else if extension == "7z" {
-                extract_7z(&source_path, &instance_path, root_within_zip, on_progress).await?;
-            } else {
-                return Err(anyhow::anyhow!(
-                    "Unsupported archive format: .{}",
-                    extension
-                ));
-            }
-        } else {
-            return Err(anyhow::anyhow!(
-                "Source path must be a directory or a supported archive file (.zip, .7z)"
-            ));
+        if let Err(e) = populate_from_source(&source_path, &instance_path, root_within_zip, cancel, on_progress).await {
+            let _ = fs::remove_dir_all(&instance_path).await;
+            return Err(e);
         }
+        self.finish_import(id, instance_path, name, jar_name, mod_loader, script_path).await
+    }
 
+    /// Registers an instance whose files are already in `instance_path`, picking up
+    /// memory, arguments and the jar from the start script when one is given.
+    pub(crate) async fn finish_import(
+        &self,
+        id: Uuid,
+        instance_path: PathBuf,
+        name: &str,
+        mut jar_name: String,
+        mod_loader: Option<String>,
+        script_path: Option<String>,
+    ) -> Result<InstanceMetadata> {
         let mut settings = InstanceSettings::default();
         let mut jvm_args = Vec::new();
         let mut server_args = vec!["nogui".to_string()];
@@ -129,6 +137,8 @@ impl InstanceManager {
             id,
             name: name.to_string(),
             version,
+            folia_plugins_only: requires_folia_plugins(mod_loader.as_deref()),
+            linked: self.is_linked_path(&instance_path),
             mod_loader,
             loader_version: None,
             created_at: Utc::now(),
@@ -160,3 +170,30 @@ impl InstanceManager {
         Ok(metadata)
     }
 }
+
+async fn populate_from_source<F>(
+    source_path: &Path,
+    instance_path: &Path,
+    root_within_zip: Option<String>,
+    cancel: CancelFlag,
+    on_progress: F,
+) -> Result<()>
+where
+    F: Fn(u64, u64, String) + Send + Sync + 'static,
+{
+    if source_path.is_dir() {
+        return copy_dir_all(source_path, instance_path, on_progress).await;
+    }
+    if !source_path.is_file() {
+        return Err(anyhow!("Source path must be a directory or a supported archive file (.zip, .7z)"));
+    }
+    let extension = source_path
+        .extension()
+        .map_or("", |ext| ext.to_str().unwrap_or(""))
+        .to_lowercase();
+    match extension.as_str() {
+        "zip" => extract_zip(source_path, instance_path, root_within_zip, cancel, on_progress).await,
+        "7z" => extract_7z(source_path, instance_path, root_within_zip, cancel, on_progress).await,
+        _ => Err(anyhow!("Unsupported archive format: .{}", extension)),
+    }
+}
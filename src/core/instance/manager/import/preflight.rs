@@ -0,0 +1,105 @@
+use super::super::InstanceManager;
+use crate::instance::archive::inspect_archive;
+use crate::utils::fs::space::available_space;
+use crate::utils::fs::{InsufficientSpace, check_free_space, dir_size};
+use anyhow::{Result, anyhow};
+use serde::Serialize;
+use std::path::Path;
+
+/// What importing a source would do, checked before anything is copied so the wizard can
+/// show problems up front.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ImportPreflight {
+    /// Why the name can't be used, e.g. it is too close to an existing instance.
+    pub name_error: Option<String>,
+    /// Why the source can't be read, e.g. it is missing or not a supported archive.
+    pub source_error: Option<String>,
+    /// Bytes the instance will take once copied or extracted.
+    pub estimated_size: u64,
+    pub file_count: u64,
+    /// Free bytes where instances are stored, None when unknown.
+    pub available_space: Option<u64>,
+    pub insufficient_space: Option<InsufficientSpace>,
+    /// The folder inside the archive the import extracts from: the one given, or the
+    /// single folder everything is nested in.
+    pub root_within_zip: Option<String>,
+    /// Whether `root_within_zip` was detected rather than given.
+    pub root_detected: bool,
+}
+
+impl ImportPreflight {
+    pub fn is_ok(&self) -> bool {
+        self.name_error.is_none() && self.source_error.is_none() && self.insufficient_space.is_none()
+    }
+
+    /// The first problem found, as an error.
+    pub fn check(&self) -> Result<()> {
+        if let Some(e) = self.name_error.as_ref().or(self.source_error.as_ref()) {
+            return Err(anyhow!("{}", e));
+        }
+        if let Some(e) = &self.insufficient_space {
+            return Err(e.clone().into());
+        }
+        Ok(())
+    }
+}
+
+/// Size, file count and archive root of an import source, on the blocking pool.
+async fn inspect_source(source_path: &Path, root_within_zip: Option<String>) -> Result<(u64, u64, Option<String>)> {
+    let source_path = source_path.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        if source_path.is_dir() {
+            let file_count = walkdir::WalkDir::new(&source_path)
+                .into_iter()
+                .filter_map(|e| e.ok())
+                .filter(|e| e.file_type().is_file())
+                .count() as u64;
+            return Ok((dir_size(&source_path), file_count, None));
+        }
+        if !source_path.is_file() {
+            return Err(anyhow!("Source path must be a directory or a supported archive file (.zip, .7z)"));
+        }
+        let summary = inspect_archive(&source_path, root_within_zip)?;
+        Ok((summary.extracted_size, summary.file_count, summary.single_root))
+    })
+    .await?
+}
+
+impl InstanceManager {
+    /// Checks an import without copying anything: that the name is free, that the
+    /// extracted size fits on the disk, and which folder of a nested archive to extract.
+    pub async fn preflight_import(
+        &self,
+        name: &str,
+        source_path: &Path,
+        root_within_zip: Option<String>,
+    ) -> Result<ImportPreflight> {
+        let name_error = self.ensure_name_available(name, None).await.err().map(|e| e.to_string());
+        let root_given = root_within_zip.as_deref().is_some_and(|r| !r.trim_matches('/').is_empty());
+        let root_within_zip = root_within_zip.filter(|_| root_given);
+
+        let (estimated_size, file_count, detected_root, source_error) =
+            match inspect_source(source_path, root_within_zip.clone()).await {
+                Ok((size, count, detected)) => (size, count, detected, None),
+                Err(e) => (0, 0, None, Some(e.to_string())),
+            };
+
+        let base_dir = self.base_dir.clone();
+        let available = tokio::task::spawn_blocking(move || available_space(&base_dir)).await?;
+        let insufficient_space = check_free_space(&self.base_dir, estimated_size, available)
+            .err()
+            .and_then(|e| e.downcast::<InsufficientSpace>().ok());
+
+        let root_detected = !root_given && detected_root.is_some();
+        Ok(ImportPreflight {
+            name_error,
+            source_error,
+            estimated_size,
+            file_count,
+            available_space: available,
+            insufficient_space,
+            root_within_zip: if root_given { root_within_zip } else { detected_root },
+            root_detected,
+        })
+    }
+}
@@ -0,0 +1,133 @@
+use super::InstanceManager;
+use crate::server::ResourceUsage;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Row;
+use std::path::Path;
+use uuid::Uuid;
+
+/// How long resource samples are kept before being pruned.
+const METRICS_RETENTION_DAYS: i64 = 7;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MetricSample {
+    pub sampled_at: DateTime<Utc>,
+    pub cpu_usage: f32,
+    pub memory_usage: u64,
+    pub player_count: u32,
+    pub tps: Option<f32>,
+    pub mspt: Option<f32>,
+}
+
+impl InstanceManager {
+    /// Stores a snapshot of a running server's resource usage and drops samples
+    /// older than the retention period.
+    pub async fn record_metric_sample(&self, instance_id: Uuid, usage: &ResourceUsage) -> Result<()> {
+        let now = Utc::now();
+        sqlx::query(
+            "INSERT INTO metrics_samples (instance_id, sampled_at, cpu_usage, memory_usage, player_count, tps, mspt)
+                VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(instance_id.to_string())
+        .bind(now.to_rfc3339())
+        .bind(usage.cpu_usage)
+        .bind(usage.memory_usage as i64)
+        .bind(usage.player_count)
+        .bind(usage.tps)
+        .bind(usage.mspt)
+        .execute(self.db.pool())
+        .await?;
+
+        let cutoff = now - Duration::days(METRICS_RETENTION_DAYS);
+        sqlx::query("DELETE FROM metrics_samples WHERE instance_id = ? AND sampled_at < ?")
+            .bind(instance_id.to_string())
+            .bind(cutoff.to_rfc3339())
+            .execute(self.db.pool())
+            .await?;
+        Ok(())
+    }
+
+    /// Returns samples taken since `since`, oldest first.
+    pub async fn get_metric_samples(&self, instance_id: Uuid, since: DateTime<Utc>) -> Result<Vec<MetricSample>> {
+        self.get_metric_samples_between(instance_id, since, None).await
+    }
+
+    /// Returns samples taken between `from` and `until` (open-ended when `None`), oldest first.
+    pub async fn get_metric_samples_between(
+        &self,
+        instance_id: Uuid,
+        from: DateTime<Utc>,
+        until: Option<DateTime<Utc>>,
+    ) -> Result<Vec<MetricSample>> {
+        let rows = sqlx::query(
+            "SELECT sampled_at, cpu_usage, memory_usage, player_count, tps, mspt FROM metrics_samples
+                WHERE instance_id = ? AND sampled_at >= ? AND (? IS NULL OR sampled_at <= ?) ORDER BY sampled_at",
+        )
+        .bind(instance_id.to_string())
+        .bind(from.to_rfc3339())
+        .bind(until.map(|u| u.to_rfc3339()))
+        .bind(until.map(|u| u.to_rfc3339()))
+        .fetch_all(self.db.pool())
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let sampled_at: String = row.get("sampled_at");
+                let memory_usage: i64 = row.get("memory_usage");
+                MetricSample {
+                    sampled_at: DateTime::parse_from_rfc3339(&sampled_at)
+                        .map(|d| d.with_timezone(&Utc))
+                        .unwrap_or_else(|_| Utc::now()),
+                    cpu_usage: row.get("cpu_usage"),
+                    memory_usage: memory_usage as u64,
+                    player_count: row.get("player_count"),
+                    tps: row.get("tps"),
+                    mspt: row.get("mspt"),
+                }
+            })
+            .collect())
+    }
+
+    pub async fn clear_metric_samples(&self, instance_id: Uuid) -> Result<()> {
+        sqlx::query("DELETE FROM metrics_samples WHERE instance_id = ?")
+            .bind(instance_id.to_string())
+            .execute(self.db.pool())
+            .await?;
+        Ok(())
+    }
+
+    /// Writes the samples between `from` and `until` to `dest` as CSV and returns how
+    /// many rows were written.
+    pub async fn export_metrics_csv(
+        &self,
+        instance_id: Uuid,
+        from: DateTime<Utc>,
+        until: Option<DateTime<Utc>>,
+        dest: &Path,
+    ) -> Result<usize> {
+        let samples = self.get_metric_samples_between(instance_id, from, until).await?;
+        crate::utils::write_atomic(dest, metrics_to_csv(&samples), false)
+            .await
+            .context("Could not write the metrics export")?;
+        Ok(samples.len())
+    }
+}
+
+/// Formats samples as CSV with a header row. Missing TPS/MSPT readings are left empty.
+pub fn metrics_to_csv(samples: &[MetricSample]) -> String {
+    let mut csv = String::from("sampled_at,cpu_percent,memory_mb,player_count,tps,mspt\n");
+    let optional = |v: Option<f32>| v.map(|v| format!("{:.2}", v)).unwrap_or_default();
+    for sample in samples {
+        csv.push_str(&format!(
+            "{},{:.2},{:.1},{},{},{}\n",
+            sample.sampled_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+            sample.cpu_usage,
+            sample.memory_usage as f64 / (1024.0 * 1024.0),
+            sample.player_count,
+            optional(sample.tps),
+            optional(sample.mspt),
+        ));
+    }
+    csv
+}
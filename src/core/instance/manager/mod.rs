@@ -1,21 +1,39 @@
 use crate::database::Database;
+use crate::players::PlayerListLocks;
 use anyhow::Result;
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 use tokio::fs;
 use tracing::warn;
 
+pub mod addressing;
 pub mod clone;
 pub mod create;
 pub mod delete;
 pub mod detection;
+pub mod drift;
+pub mod export;
+pub mod history;
 pub mod import;
+pub mod metrics;
 pub mod persistence;
 pub mod query;
+pub mod relocate;
+pub mod restore;
+pub mod worlds;
+
+pub use addressing::slugify;
+pub use history::{CommandHistoryEntry, QueryHistoryEntry};
+pub use metrics::{MetricSample, metrics_to_csv};
+pub use relocate::StorageRoot;
+pub use worlds::WorldInfo;
 
 pub struct InstanceManager {
     pub(crate) base_dir: PathBuf,
     pub(crate) db: Arc<Database>,
+    /// Extra folders, e.g. on a second disk, that instances may be moved to.
+    pub(crate) storage_roots: RwLock<Vec<PathBuf>>,
+    player_list_locks: PlayerListLocks,
 }
 
 impl InstanceManager {
@@ -24,7 +42,12 @@ impl InstanceManager {
         if !base_dir.exists() {
             fs::create_dir_all(&base_dir).await?;
         }
-        let manager = Self { base_dir, db };
+        let manager = Self {
+            base_dir,
+            db,
+            storage_roots: RwLock::new(Vec::new()),
+            player_list_locks: PlayerListLocks::default(),
+        };
         if let Err(e) = manager.migrate_from_json().await {
             warn!("Failed to migrate instances from JSON: {}", e);
         }
@@ -34,4 +57,15 @@ impl InstanceManager {
     pub fn get_base_dir(&self) -> PathBuf {
         self.base_dir.clone()
     }
+
+    /// Locks for editing the whitelist, ops and ban lists of any instance.
+    pub fn player_list_locks(&self) -> &PlayerListLocks {
+        &self.player_list_locks
+    }
+
+    /// Whether an instance folder lies outside the instances directory and every storage
+    /// root, so it belongs to the user rather than to the wrapper.
+    pub fn is_linked_path(&self, path: &Path) -> bool {
+        !path.starts_with(&self.base_dir) && !self.storage_roots().iter().any(|root| path.starts_with(root))
+    }
 }
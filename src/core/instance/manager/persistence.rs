@@ -1,4 +1,5 @@
 use super::InstanceManager;
+use crate::mod_loaders::requires_folia_plugins;
 use crate::instance::types::InstanceMetadata;
 use anyhow::{Context, Result};
 use chrono::Utc;
@@ -121,15 +122,18 @@ impl InstanceManager {
             name, schedules_json
         ))?;
 
+        let path = PathBuf::from(path);
         Ok(InstanceMetadata {
             id,
             name,
             version,
+            folia_plugins_only: requires_folia_plugins(mod_loader.as_deref()),
+            linked: self.is_linked_path(&path),
             mod_loader,
             loader_version,
             created_at,
             last_run,
-            path: PathBuf::from(path),
+            path,
             settings,
             schedules,
             status: crate::server::types::ServerStatus::Stopped,
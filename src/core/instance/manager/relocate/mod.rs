@@ -0,0 +1,154 @@
+mod transfer;
+
+use super::InstanceManager;
+use crate::errors::AppError;
+use crate::storage_mode::rebase_path;
+use crate::utils::fs::space::available_space;
+use anyhow::Result;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+/// A folder instances are stored in: the default instances directory or one of the
+/// configured storage roots.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct StorageRoot {
+    pub path: PathBuf,
+    /// Whether this is the instances directory next to the executable.
+    pub is_default: bool,
+    pub instance_count: usize,
+    /// Free bytes on its disk, None when unknown.
+    pub available_space: Option<u64>,
+}
+
+impl InstanceManager {
+    /// The extra storage roots instances may be moved to.
+    pub fn storage_roots(&self) -> Vec<PathBuf> {
+        self.storage_roots.read().map(|roots| roots.clone()).unwrap_or_default()
+    }
+
+    /// Absolute, deduplicated roots, refusing any that overlap the instances directory
+    /// or each other.
+    fn normalize_storage_roots(&self, roots: &[PathBuf]) -> Result<Vec<PathBuf>> {
+        let mut normalized: Vec<PathBuf> = Vec::new();
+        for root in roots.iter().filter(|r| !r.as_os_str().is_empty()) {
+            let root = std::path::absolute(root)?;
+            if root == self.base_dir || normalized.contains(&root) {
+                continue;
+            }
+            if root.starts_with(&self.base_dir) || self.base_dir.starts_with(&root) {
+                return Err(AppError::Validation(format!(
+                    "{} overlaps the default instances directory",
+                    root.display()
+                ))
+                .into());
+            }
+            if let Some(other) = normalized.iter().find(|o| root.starts_with(o) || o.starts_with(&root)) {
+                return Err(AppError::Validation(format!(
+                    "{} overlaps the storage root {}",
+                    root.display(),
+                    other.display()
+                ))
+                .into());
+            }
+            normalized.push(root);
+        }
+        Ok(normalized)
+    }
+
+    /// Restores the storage roots saved in the settings at startup, before any instance
+    /// is read.
+    pub fn load_storage_roots(&self, roots: &[PathBuf]) -> Result<()> {
+        let normalized = self.normalize_storage_roots(roots)?;
+        if let Ok(mut current) = self.storage_roots.write() {
+            *current = normalized;
+        }
+        Ok(())
+    }
+
+    /// Replaces the extra storage roots. A root may not overlap the instances directory,
+    /// another root or a linked instance, and can only be dropped once no instance lives
+    /// in it, since those would otherwise be treated as linked folders.
+    pub async fn set_storage_roots(&self, roots: &[PathBuf]) -> Result<()> {
+        let normalized = self.normalize_storage_roots(roots)?;
+        for instance in self.list_instances().await? {
+            let in_new_root = normalized.iter().any(|root| instance.path.starts_with(root));
+            if instance.linked && in_new_root {
+                // Its folder would start counting as the wrapper's and be deleted with it
+                return Err(AppError::Validation(format!(
+                    "\"{}\" was imported in place from inside that folder; pick a different storage root",
+                    instance.name
+                ))
+                .into());
+            }
+            if !instance.linked && !in_new_root && !instance.path.starts_with(&self.base_dir) {
+                return Err(AppError::Conflict(format!(
+                    "Move \"{}\" out of {} before removing that storage root",
+                    instance.name,
+                    instance.path.parent().unwrap_or(&instance.path).display()
+                ))
+                .into());
+            }
+        }
+
+        if let Ok(mut current) = self.storage_roots.write() {
+            *current = normalized;
+        }
+        Ok(())
+    }
+
+    /// The default instances directory followed by every storage root, with how many
+    /// instances each holds and how much space is left on it.
+    pub async fn list_storage_roots(&self) -> Result<Vec<StorageRoot>> {
+        let instances = self.list_instances().await?;
+        let mut paths = vec![self.base_dir.clone()];
+        paths.extend(self.storage_roots());
+
+        let mut roots = Vec::with_capacity(paths.len());
+        for path in paths {
+            let instance_count = instances
+                .iter()
+                .filter(|i| !i.linked && i.path.parent() == Some(path.as_path()))
+                .count();
+            let probe = path.clone();
+            let available_space = tokio::task::spawn_blocking(move || available_space(&probe)).await?;
+            roots.push(StorageRoot {
+                is_default: path == self.base_dir,
+                path,
+                instance_count,
+                available_space,
+            });
+        }
+        Ok(roots)
+    }
+
+    /// Points instance folders and Java overrides that were under `old_root` at the same
+    /// place under `new_root`, after the app data folder moved. Returns how many instances changed.
+    pub async fn rebase_paths(&self, old_root: &Path, new_root: &Path) -> Result<usize> {
+        let mut changed = 0;
+        for mut instance in self.list_instances().await? {
+            let path = rebase_path(&instance.path, old_root, new_root);
+            let java = instance
+                .settings
+                .java_path_override
+                .as_deref()
+                .and_then(|java| rebase_path(Path::new(java), old_root, new_root));
+            if path.is_none() && java.is_none() {
+                continue;
+            }
+            if let Some(java) = java {
+                instance.settings.java_path_override = Some(java.to_string_lossy().to_string());
+            }
+            let path = path.unwrap_or(instance.path);
+            sqlx::query("UPDATE instances SET path = ?, settings = ? WHERE id = ?")
+                .bind(path.to_string_lossy().to_string())
+                .bind(serde_json::to_string(&instance.settings)?)
+                .bind(instance.id.to_string())
+                .execute(self.db.pool())
+                .await?;
+            changed += 1;
+        }
+        info!("Updated the paths of {} instances for {:?}", changed, new_root);
+        Ok(changed)
+    }
+}
@@ -0,0 +1,107 @@
+use super::super::InstanceManager;
+use crate::errors::AppError;
+use crate::instance::archive::copy_dir_all;
+use crate::instance::types::InstanceMetadata;
+use crate::utils::fs::space::available_space;
+use crate::utils::fs::{check_free_space, dir_size};
+use anyhow::{Context, Result};
+use std::path::Path;
+use tokio::fs;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+impl InstanceManager {
+    /// Moves an instance's folder into `target_root`, which must be the instances directory
+    /// or a storage root. On the same disk the folder is renamed; otherwise it is copied
+    /// with progress and the original removed once the new path is saved. Any failure
+    /// before that leaves the instance where it was.
+    pub async fn relocate_instance<F>(&self, id: Uuid, target_root: &Path, on_progress: F) -> Result<InstanceMetadata>
+    where
+        F: Fn(u64, u64, String) + Send + Sync + 'static,
+    {
+        let mut instance = self
+            .get_instance(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", id)))?;
+        if instance.linked {
+            return Err(AppError::Validation(
+                "Instances imported in place stay in their own folder and can't be moved".to_string(),
+            )
+            .into());
+        }
+
+        let target_root = std::path::absolute(target_root)?;
+        if target_root != self.base_dir && !self.storage_roots().contains(&target_root) {
+            return Err(AppError::Validation(format!(
+                "{} is not a configured storage root",
+                target_root.display()
+            ))
+            .into());
+        }
+        let new_path = target_root.join(id.to_string());
+        if new_path == instance.path {
+            return Err(AppError::Validation(format!("{} is already stored there", instance.name)).into());
+        }
+        if new_path.exists() {
+            return Err(AppError::Conflict(format!("{} already exists", new_path.display())).into());
+        }
+        fs::create_dir_all(&target_root).await?;
+
+        let old_path = instance.path.clone();
+        let renamed = transfer_folder(&old_path, &new_path, on_progress)
+            .await
+            .with_context(|| format!("Failed to copy {} to {}", instance.name, target_root.display()))?;
+
+        let saved = sqlx::query("UPDATE instances SET path = ? WHERE id = ?")
+            .bind(new_path.to_string_lossy().to_string())
+            .bind(id.to_string())
+            .execute(self.db.pool())
+            .await;
+        if let Err(e) = saved {
+            undo_transfer(&old_path, &new_path, renamed).await;
+            return Err(anyhow::Error::from(e).context("Failed to save the new instance path"));
+        }
+
+        if !renamed && let Err(e) = fs::remove_dir_all(&old_path).await {
+            warn!("Moved {} but failed to remove the old folder {:?}: {}", instance.name, old_path, e);
+        }
+
+        info!("Moved instance {} from {:?} to {:?}", instance.name, old_path, new_path);
+        instance.path = new_path;
+        Ok(instance)
+    }
+}
+
+/// Moves `old_path` to `new_path`: a rename on the same disk, otherwise a copy after
+/// checking there is room. Returns whether it was renamed. A failed copy is cleaned up.
+async fn transfer_folder<F>(old_path: &Path, new_path: &Path, on_progress: F) -> Result<bool>
+where
+    F: Fn(u64, u64, String) + Send + Sync + 'static,
+{
+    if fs::rename(old_path, new_path).await.is_ok() {
+        return Ok(true);
+    }
+
+    // Most likely a different disk, where only a copy works
+    let source = old_path.to_path_buf();
+    let size = tokio::task::spawn_blocking(move || dir_size(&source)).await?;
+    let target_root = new_path.parent().unwrap_or(new_path).to_path_buf();
+    let probe = target_root.clone();
+    let available = tokio::task::spawn_blocking(move || available_space(&probe)).await?;
+    check_free_space(&target_root, size, available)?;
+
+    if let Err(e) = copy_dir_all(old_path, new_path, on_progress).await {
+        let _ = fs::remove_dir_all(new_path).await;
+        return Err(e);
+    }
+    Ok(false)
+}
+
+/// Puts the folder back after [`transfer_folder`] when the move can't be completed.
+async fn undo_transfer(old_path: &Path, new_path: &Path, renamed: bool) {
+    if renamed {
+        let _ = fs::rename(new_path, old_path).await;
+    } else {
+        let _ = fs::remove_dir_all(new_path).await;
+    }
+}
@@ -0,0 +1,167 @@
+use super::InstanceManager;
+use super::export::{MOD_CACHE, PLUGIN_CACHE, read_json};
+use crate::instance::archive::{CancelFlag, extract_7z, extract_zip};
+use crate::instance::portable::{ExportManifest, MANIFEST_FILE, MANIFEST_VERSION};
+use crate::instance::types::InstanceMetadata;
+use crate::mod_loaders::requires_folia_plugins;
+use crate::mods::types::ModCache;
+use crate::plugins::metadata::PluginCache;
+use anyhow::{Context, Result, anyhow};
+use chrono::Utc;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+impl InstanceManager {
+    /// Restores an archive made by [`Self::export_instance`] as a new instance.
+    pub async fn import_exported_instance<F>(
+        &self,
+        archive: PathBuf,
+        name: Option<String>,
+        on_progress: F,
+    ) -> Result<InstanceMetadata>
+    where
+        F: Fn(u64, u64, String) + Send + Sync + 'static,
+    {
+        let id = Uuid::new_v4();
+        let instance_path = self.base_dir.join(id.to_string());
+        fs::create_dir_all(&instance_path).await?;
+
+        let result = self
+            .restore_exported(&archive, id, &instance_path, name, on_progress)
+            .await;
+        if result.is_err() {
+            let _ = fs::remove_dir_all(&instance_path).await;
+        }
+        result
+    }
+
+    async fn restore_exported<F>(
+        &self,
+        archive: &Path,
+        id: Uuid,
+        instance_path: &Path,
+        name: Option<String>,
+        on_progress: F,
+    ) -> Result<InstanceMetadata>
+    where
+        F: Fn(u64, u64, String) + Send + Sync + 'static,
+    {
+        let extension = archive
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default()
+            .to_lowercase();
+        match extension.as_str() {
+            "zip" => extract_zip(archive, instance_path, None, CancelFlag::default(), on_progress).await?,
+            "7z" => extract_7z(archive, instance_path, None, CancelFlag::default(), on_progress).await?,
+            _ => return Err(anyhow!("Unsupported archive format: .{}", extension)),
+        }
+
+        let manifest_path = instance_path.join(MANIFEST_FILE);
+        let content = fs::read_to_string(&manifest_path)
+            .await
+            .context("Archive is not an exported instance (no manifest found)")?;
+        let manifest: ExportManifest =
+            serde_json::from_str(&content).context("Failed to parse instance manifest")?;
+        if manifest.format_version > MANIFEST_VERSION {
+            return Err(anyhow!(
+                "Archive was exported by a newer version ({}); update to import it",
+                manifest.wrapper_version
+            ));
+        }
+        fs::remove_file(&manifest_path).await?;
+
+        let mut settings = manifest.settings;
+        // Paths from the exporting machine are rebased or dropped
+        settings.icon_path = settings
+            .icon_path
+            .as_deref()
+            .and_then(|p| Path::new(p).file_name())
+            .map(|f| instance_path.join(f))
+            .filter(|p| p.exists())
+            .map(|p| p.to_string_lossy().to_string());
+        if let Some(java) = settings.java_path_override.as_deref().filter(|p| !Path::new(p).exists()) {
+            warn!("Java override {} does not exist on this machine; using the default", java);
+            settings.java_path_override = None;
+        }
+        settings.lineage_id = None;
+        // Hooks run shell commands on every start, so an archive doesn't get to bring its own
+        let environment = &mut settings.environment;
+        if environment.pre_start_hook().is_some() || environment.post_stop_hook().is_some() {
+            warn!("Dropped the start/stop hooks of imported instance {}", manifest.name);
+            environment.pre_start_hook.clear();
+            environment.post_stop_hook.clear();
+        }
+
+        let schedules = manifest
+            .schedules
+            .into_iter()
+            .map(|mut task| {
+                task.id = Uuid::new_v4();
+                task.instance_id = id;
+                task.last_run = None;
+                task.next_run = None;
+                task
+            })
+            .collect();
+
+        restore_sources::<ModCache, _>(&instance_path.join(MOD_CACHE), manifest.mod_sources, |c| &mut c.sources).await?;
+        restore_sources::<PluginCache, _>(&instance_path.join(PLUGIN_CACHE), manifest.plugin_sources, |c| &mut c.sources).await?;
+
+        // An archive restored next to its original gets a numbered name instead of failing
+        let name = match name {
+            Some(name) => name,
+            None => self.unique_instance_name(&manifest.name).await?,
+        };
+        self.ensure_name_available(&name, None).await?;
+
+        let metadata = InstanceMetadata {
+            id,
+            name,
+            version: manifest.version,
+            folia_plugins_only: requires_folia_plugins(manifest.mod_loader.as_deref()),
+            linked: false,
+            mod_loader: manifest.mod_loader,
+            loader_version: manifest.loader_version,
+            created_at: Utc::now(),
+            last_run: None,
+            path: instance_path.to_path_buf(),
+            schedules,
+            settings,
+            status: crate::server::types::ServerStatus::Stopped,
+            ip: None,
+            port: None,
+            max_players: None,
+            description: None,
+        };
+
+        self.save_instance_to_db(&metadata).await?;
+        info!("Imported exported instance: {} (ID: {})", metadata.name, id);
+        Ok(metadata)
+    }
+}
+}
+
+/// Adds manifest sources missing from an extracted metadata cache, e.g. when the
+/// cache file was deleted before export.
+async fn restore_sources<C, S>(
+    cache_path: &Path,
+    sources: std::collections::HashMap<String, S>,
+    field: impl Fn(&mut C) -> &mut std::collections::HashMap<String, S>,
+) -> Result<()>
+where
+    C: serde::de::DeserializeOwned + serde::Serialize + Default,
+{
+    if sources.is_empty() || !cache_path.parent().is_some_and(Path::exists) {
+        return Ok(());
+    }
+    let mut cache: C = read_json(cache_path).await;
+    let existing = field(&mut cache);
+    for (filename, source) in sources {
+        existing.entry(filename).or_insert(source);
+    }
+    fs::write(cache_path, serde_json::to_string(&cache)?).await?;
+    Ok(())
+}
@@ -0,0 +1,192 @@
+use super::InstanceManager;
+use crate::instance::archive::copy_dir_all;
+use crate::server_properties::{read_server_properties, write_server_properties};
+use crate::world_info::{LevelSummary, read_level_summary};
+use crate::world_upgrade::WorldUpgradeStatus;
+use anyhow::{Context, Result, anyhow};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::path::Path;
+use tokio::fs;
+use tracing::info;
+use uuid::Uuid;
+use walkdir::WalkDir;
+
+/// Suffixes Bukkit-based servers use for a world's extra dimension folders.
+const DIMENSION_SUFFIXES: [&str; 2] = ["_nether", "_the_end"];
+const DEFAULT_LEVEL_NAME: &str = "world";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WorldInfo {
+    pub name: String,
+    /// Whether `level-name` currently points at this world.
+    pub active: bool,
+    /// Combined size of the world and its dimension folders.
+    pub size: u64,
+    pub last_modified: Option<DateTime<Utc>>,
+    /// Separate dimension folders found next to the world, e.g. `world_nether`.
+    pub dimensions: Vec<String>,
+}
+
+fn validate_world_name(name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && name.len() <= 64
+        && !name.starts_with('.')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | ' ' | '.'));
+    if !valid {
+        return Err(anyhow!("Invalid world name: {}", name));
+    }
+    Ok(())
+}
+
+fn dir_stats(path: &Path) -> (u64, Option<DateTime<Utc>>) {
+    let mut size = 0;
+    let mut modified = None;
+    for entry in WalkDir::new(path).into_iter().filter_map(|e| e.ok()) {
+        let Ok(metadata) = entry.metadata() else { continue };
+        if metadata.is_file() {
+            size += metadata.len();
+        }
+        let time = metadata.modified().ok().map(DateTime::<Utc>::from);
+        modified = modified.max(time);
+    }
+    (size, modified)
+}
+
+/// Dimension folders that exist for `world`.
+fn dimension_dirs(instance_path: &Path, world: &str) -> Vec<String> {
+    DIMENSION_SUFFIXES
+        .iter()
+        .map(|suffix| format!("{}{}", world, suffix))
+        .filter(|dir| instance_path.join(dir).is_dir())
+        .collect()
+}
+
+impl InstanceManager {
+    async fn active_level_name(&self, instance_path: &Path) -> Result<String> {
+        let props = read_server_properties(instance_path).await?;
+        Ok(props
+            .get("level-name")
+            .filter(|name| !name.is_empty())
+            .cloned()
+            .unwrap_or_else(|| DEFAULT_LEVEL_NAME.to_string()))
+    }
+
+    /// Lists world folders (any top-level folder with a `level.dat`) in an instance.
+    pub async fn list_worlds(&self, id: Uuid) -> Result<Vec<WorldInfo>> {
+        let instance = self.get_instance(id).await?.context("Instance not found")?;
+        let active = self.active_level_name(&instance.path).await?;
+        let instance_path = instance.path.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let mut names = Vec::new();
+            for entry in std::fs::read_dir(&instance_path)?.filter_map(|e| e.ok()) {
+                if entry.path().join("level.dat").is_file() {
+                    names.push(entry.file_name().to_string_lossy().to_string());
+                }
+            }
+
+            let mut worlds = Vec::new();
+            for name in &names {
+                // `world_nether` is part of `world`, not a world of its own
+                let is_dimension = DIMENSION_SUFFIXES.iter().any(|suffix| {
+                    name.strip_suffix(suffix).is_some_and(|base| names.iter().any(|n| n == base))
+                });
+                if is_dimension {
+                    continue;
+                }
+                let dimensions = dimension_dirs(&instance_path, name);
+                let (mut size, mut last_modified) = dir_stats(&instance_path.join(name));
+                for dim in &dimensions {
+                    let (dim_size, dim_modified) = dir_stats(&instance_path.join(dim));
+                    size += dim_size;
+                    last_modified = last_modified.max(dim_modified);
+                }
+                worlds.push(WorldInfo {
+                    active: *name == active,
+                    name: name.clone(),
+                    size,
+                    last_modified,
+                    dimensions,
+                });
+            }
+            worlds.sort_by(|a, b| b.active.cmp(&a.active).then_with(|| a.name.cmp(&b.name)));
+            Ok(worlds)
+        })
+        .await?
+    }
+
+    /// Seed, spawn and version of a world, the active one when `world` is `None`.
+    pub async fn world_summary(&self, id: Uuid, world: Option<&str>) -> Result<LevelSummary> {
+        let instance = self.get_instance(id).await?.context("Instance not found")?;
+        if instance.mod_loader.as_deref().is_some_and(|l| l.eq_ignore_ascii_case("bedrock")) {
+            return Err(anyhow!("Bedrock worlds aren't supported"));
+        }
+        let name = match world {
+            Some(name) => {
+                validate_world_name(name)?;
+                name.to_string()
+            }
+            None => self.active_level_name(&instance.path).await?,
+        };
+        let dir = instance.path.join(&name);
+        if !dir.join("level.dat").is_file() {
+            return Err(anyhow!("World {} hasn't been generated yet", name));
+        }
+        read_level_summary(&dir).await
+    }
+
+    /// Whether the active world was last saved by an older version than the instance runs,
+    /// so its chunks would be converted as players load them.
+    pub async fn world_upgrade_status(&self, id: Uuid) -> Result<WorldUpgradeStatus> {
+        let instance = self.get_instance(id).await?.context("Instance not found")?;
+        let world = self.active_level_name(&instance.path).await?;
+        let summary = self.world_summary(id, Some(&world)).await?;
+        Ok(WorldUpgradeStatus::new(world, &summary, &instance.version))
+    }
+
+    /// Points `level-name` at another world folder. A name with no folder yet makes
+    /// the server generate a fresh world on its next start.
+    pub async fn switch_world(&self, id: Uuid, name: &str) -> Result<()> {
+        validate_world_name(name)?;
+        let instance = self.get_instance(id).await?.context("Instance not found")?;
+        let mut props = read_server_properties(&instance.path).await?;
+        props.insert("level-name".to_string(), name.to_string());
+        write_server_properties(&instance.path, &props).await?;
+        info!("Switched instance {} to world {}", instance.name, name);
+        Ok(())
+    }
+
+    /// Copies a world, including its dimension folders, under a new name.
+    pub async fn duplicate_world(&self, id: Uuid, source: &str, new_name: &str) -> Result<WorldInfo> {
+        validate_world_name(source)?;
+        validate_world_name(new_name)?;
+        let instance = self.get_instance(id).await?.context("Instance not found")?;
+        let src = instance.path.join(source);
+        if !src.join("level.dat").is_file() {
+            return Err(anyhow!("World not found: {}", source));
+        }
+        if instance.path.join(new_name).exists() {
+            return Err(anyhow!("A folder named {} already exists", new_name));
+        }
+
+        let mut folders = vec![(source.to_string(), new_name.to_string())];
+        for dim in dimension_dirs(&instance.path, source) {
+            let suffix = &dim[source.len()..];
+            folders.push((dim.clone(), format!("{}{}", new_name, suffix)));
+        }
+        for (from, to) in &folders {
+            let dst = instance.path.join(to);
+            copy_dir_all(instance.path.join(from), &dst, |_, _, _| {}).await?;
+            // The lock belongs to the running server of the original world
+            let _ = fs::remove_file(dst.join("session.lock")).await;
+        }
+
+        info!("Duplicated world {} as {} in instance {}", source, new_name, instance.name);
+        self.list_worlds(id)
+            .await?
+            .into_iter()
+            .find(|w| w.name == new_name)
+            .context("Duplicated world is missing")
+    }
+}
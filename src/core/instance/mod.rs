@@ -2,7 +2,16 @@ pub mod types;
 pub mod archive;
 pub mod manager;
 pub mod settings;
+pub mod portable;
+pub mod dependencies;
+pub mod jvm;
+pub mod environment;
+pub mod limits;
 
 pub use types::*;
 pub use archive::*;
 pub use manager::*;
+pub use jvm::JvmOptions;
+pub use environment::{EnvVar, LaunchEnvironment};
+pub use limits::{ProcessPriority, ResourceLimits};
+pub use portable::{ExportFormat, ExportManifest};
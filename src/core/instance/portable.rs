@@ -0,0 +1,138 @@
+use super::types::InstanceSettings;
+use crate::mods::types::ModSource;
+use crate::plugins::types::PluginSource;
+use crate::scheduler::ScheduledTask;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::Path;
+use walkdir::WalkDir;
+use zip::write::SimpleFileOptions;
+
+/// Name of the manifest stored at the root of an exported instance archive.
+pub const MANIFEST_FILE: &str = "instance-manifest.json";
+pub const MANIFEST_VERSION: u32 = 1;
+
+/// Top-level folders left out of exports; they only matter on the machine that made them.
+const EXCLUDED_DIRS: &[&str] = &["logs"];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ExportFormat {
+    Zip,
+    SevenZ,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Zip => "zip",
+            Self::SevenZ => "7z",
+        }
+    }
+}
+
+/// Everything needed to recreate an instance from its files on another machine.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportManifest {
+    pub format_version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub wrapper_version: String,
+    pub name: String,
+    pub version: String,
+    pub mod_loader: Option<String>,
+    pub loader_version: Option<String>,
+    pub settings: InstanceSettings,
+    #[serde(default)]
+    pub schedules: Vec<ScheduledTask>,
+    /// Where each mod jar came from, keyed by filename, so updates keep working.
+    #[serde(default)]
+    pub mod_sources: HashMap<String, ModSource>,
+    #[serde(default)]
+    pub plugin_sources: HashMap<String, PluginSource>,
+}
+
+/// Packs an instance directory and its manifest into a zip or 7z archive.
+pub async fn write_instance_archive<F>(
+    src: &Path,
+    dest: &Path,
+    format: ExportFormat,
+    manifest: &ExportManifest,
+    on_progress: F,
+) -> Result<()>
+where
+    F: Fn(u64, u64, String) + Send + Sync + 'static,
+{
+    let src = src.to_path_buf();
+    let dest = dest.to_path_buf();
+    let manifest_json = serde_json::to_vec_pretty(manifest)?;
+
+    tokio::task::spawn_blocking(move || {
+        let entries: Vec<_> = WalkDir::new(&src)
+            .min_depth(1)
+            .into_iter()
+            .filter_entry(|e| {
+                e.depth() != 1 || !EXCLUDED_DIRS.iter().any(|d| e.file_name() == *d)
+            })
+            .filter_map(|e| e.ok())
+            .filter(|e| e.depth() != 1 || e.file_name() != MANIFEST_FILE)
+            .collect();
+        let total = entries.len() as u64;
+
+        let files = entries.iter().enumerate().map(|(i, entry)| {
+            let name = entry
+                .path()
+                .strip_prefix(&src)
+                .unwrap_or(entry.path())
+                .to_string_lossy()
+                .replace('\\', "/");
+            on_progress(i as u64, total, format!("Packing {}...", name));
+            (entry.path(), name)
+        });
+
+        match format {
+            ExportFormat::Zip => {
+                let file = File::create(&dest).context("Failed to create export archive")?;
+                let mut zip = zip::ZipWriter::new(file);
+                let options = SimpleFileOptions::default()
+                    .compression_method(zip::CompressionMethod::Deflated)
+                    .large_file(true);
+
+                zip.start_file(MANIFEST_FILE, options)?;
+                std::io::Write::write_all(&mut zip, &manifest_json)?;
+                for (path, name) in files {
+                    if path.is_dir() {
+                        zip.add_directory(format!("{}/", name), options)?;
+                    } else {
+                        zip.start_file(name, options)?;
+                        let mut f = File::open(path).context("Failed to open file for export")?;
+                        std::io::copy(&mut f, &mut zip)?;
+                    }
+                }
+                zip.finish().context("Failed to finish export archive")?;
+            }
+            ExportFormat::SevenZ => {
+                let mut sz = sevenz_rust::SevenZWriter::create(&dest)
+                    .map_err(|e| anyhow::anyhow!("Failed to create export archive: {}", e))?;
+
+                let mut manifest_entry = sevenz_rust::SevenZArchiveEntry::new();
+                manifest_entry.name = MANIFEST_FILE.to_string();
+                manifest_entry.has_stream = true;
+                sz.push_archive_entry(manifest_entry, Some(manifest_json.as_slice()))
+                    .map_err(|e| anyhow::anyhow!("7z write error: {}", e))?;
+                for (path, name) in files {
+                    let entry = sevenz_rust::SevenZArchiveEntry::from_path(path, name);
+                    let reader = if path.is_file() { Some(File::open(path)?) } else { None };
+                    sz.push_archive_entry(entry, reader)
+                        .map_err(|e| anyhow::anyhow!("7z write error: {}", e))?;
+                }
+                sz.finish().context("Failed to finish export archive")?;
+            }
+        }
+
+        on_progress(total, total, "Export complete".to_string());
+        Ok::<(), anyhow::Error>(())
+    })
+    .await?
+}
@@ -4,9 +4,24 @@ use tracing::info;
 use chrono::Utc;
 use super::manager::InstanceManager;
 use super::types::InstanceSettings;
+use super::dependencies::validate_dependencies;
+use super::super::timeline::TimelineKind;
 use super::super::scheduler::ScheduledTask;
 use super::super::server_properties::{read_server_properties, write_server_properties};
 
+/// Names of the settings that differ between `old` and `new`.
+fn changed_settings(old: &InstanceSettings, new: &InstanceSettings) -> Vec<String> {
+    let (Ok(serde_json::Value::Object(old)), Ok(serde_json::Value::Object(new))) =
+        (serde_json::to_value(old), serde_json::to_value(new))
+    else {
+        return Vec::new();
+    };
+    new.iter()
+        .filter(|(key, value)| old.get(*key) != Some(value))
+        .map(|(key, _)| key.clone())
+        .collect()
+}
+
 impl InstanceManager {
     pub async fn update_last_run(&self, id: Uuid) -> Result<()> {
         let last_run = Utc::now().to_rfc3339();
@@ -20,7 +35,19 @@ impl InstanceManager {
         Ok(())
     }
 
+    /// Records the loader/build version currently installed for an instance.
+    pub async fn update_loader_version(&self, id: Uuid, loader_version: Option<String>) -> Result<()> {
+        sqlx::query("UPDATE instances SET loader_version = ? WHERE id = ?")
+            .bind(loader_version)
+            .bind(id.to_string())
+            .execute(self.db.pool())
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn add_schedule(&self, instance_id: Uuid, task: ScheduledTask) -> Result<()> {
+        task.validate()?;
         let mut metadata = self.get_instance(instance_id).await?
             .context("Instance not found")?;
         
@@ -55,6 +82,16 @@ impl InstanceManager {
     pub async fn update_settings(&self, id: Uuid, name: Option<String>, settings: InstanceSettings) -> Result<()> {
         let instance = self.get_instance(id).await?
             .context("Instance not found")?;
+        if let Some(new_name) = &name {
+            self.ensure_name_available(new_name, Some(id)).await?;
+        }
+        if !settings.depends_on.is_empty() {
+            let instances = self.list_instances().await?;
+            validate_dependencies(&instances, id, &settings.depends_on)?;
+        }
+        settings.jvm.validate()?;
+        settings.environment.validate()?;
+        settings.resource_limits.validate(settings.max_memory_mb())?;
 
         let settings_json = serde_json::to_string(&settings)?;
         
@@ -88,6 +125,12 @@ impl InstanceManager {
                 .await?;
             info!("Updated settings for instance (ID: {})", id);
         }
+
+        let changed = changed_settings(&instance.settings, &settings);
+        if !changed.is_empty() {
+            self.note_timeline_event(id, TimelineKind::ConfigChange, "Instance settings changed", Some(changed.join(", ")))
+                .await;
+        }
         
         Ok(())
     }
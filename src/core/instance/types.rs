@@ -4,11 +4,17 @@ use std::path::PathBuf;
 use chrono::{DateTime, Utc};
 use super::super::scheduler::ScheduledTask;
 use super::super::server::types::ServerStatus;
+use super::super::notifications::{HeartbeatSettings, WebhookSettings};
+use super::jvm::JvmOptions;
+use super::environment::LaunchEnvironment;
+use super::limits::ResourceLimits;
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub enum LaunchMethod {
     StartupLine,
     BatFile,
+    /// Runs the startup line as a complete command instead of reading the jar from it.
+    CustomCommand,
 }
 
 impl Default for LaunchMethod {
@@ -60,12 +66,57 @@ pub struct InstanceSettings {
     pub crash_handling: CrashHandlingMode,
     #[serde(default)]
     pub icon_path: Option<String>,
+    /// Seconds to wait for the server to finish starting before killing it (0 disables).
+    #[serde(default = "default_startup_timeout")]
+    pub startup_timeout: u64,
+    /// Leave the server running when the app closes and reattach on the next launch.
+    #[serde(default)]
+    pub keep_running_on_close: bool,
+    /// Shared by an instance and every clone made from it, so drifted configs can be compared.
+    #[serde(default)]
+    pub lineage_id: Option<Uuid>,
+    /// Marks a live server; experimental builds need explicit confirmation before install.
+    #[serde(default)]
+    pub production: bool,
+    /// Seconds between TPS/MSPT queries on Paper-family servers (0 disables). Off by
+    /// default since the queries and their replies show up in the console.
+    #[serde(default)]
+    pub tick_sample_interval: u64,
+    #[serde(default)]
+    pub webhooks: WebhookSettings,
+    /// Minutes without players before the server is stopped automatically (0 disables).
+    #[serde(default)]
+    pub idle_stop_minutes: u64,
+    /// Players that don't count as activity for the idle stop.
+    #[serde(default)]
+    pub idle_exempt_players: Vec<String>,
+    /// Hold the port while stopped and start the server when a player tries to join.
+    #[serde(default)]
+    pub wake_on_connect: bool,
+    /// Instances that must be running before this one is started by a bulk start or autostart.
+    #[serde(default)]
+    pub depends_on: Vec<Uuid>,
+    #[serde(default)]
+    pub heartbeat: HeartbeatSettings,
+    /// Java agents, remote debugging and extra flags added to the launch command.
+    #[serde(default)]
+    pub jvm: JvmOptions,
+    /// Address from an external tunnel (playit.gg, ngrok, ...) handed out to players.
+    #[serde(default)]
+    pub tunnel_address: String,
+    /// Environment variables, working folder and start/stop hooks.
+    #[serde(default)]
+    pub environment: LaunchEnvironment,
+    /// Priority, CPU affinity and memory cap applied to the server process.
+    #[serde(default)]
+    pub resource_limits: ResourceLimits,
 }
 
 fn default_min_ram() -> u32 { 1 }
 fn default_max_ram() -> u32 { 2 }
 fn default_ram_unit() -> String { "G".to_string() }
 fn default_port() -> u16 { 25565 }
+fn default_startup_timeout() -> u64 { 600 }
 fn default_startup_line() -> String { "java -Xms{min_ram}{min_unit} -Xmx{max_ram}{max_unit} -jar server.jar nogui".to_string() }
 
 impl Default for InstanceSettings {
@@ -85,8 +136,53 @@ impl Default for InstanceSettings {
             bat_file: None,
             crash_handling: CrashHandlingMode::Nothing,
             icon_path: None,
+            startup_timeout: default_startup_timeout(),
+            keep_running_on_close: false,
+            lineage_id: None,
+            production: false,
+            tick_sample_interval: 0,
+            webhooks: WebhookSettings::default(),
+            idle_stop_minutes: 0,
+            idle_exempt_players: Vec::new(),
+            wake_on_connect: false,
+            depends_on: Vec::new(),
+            heartbeat: HeartbeatSettings::default(),
+            jvm: JvmOptions::default(),
+            tunnel_address: String::new(),
+            environment: LaunchEnvironment::default(),
+            resource_limits: ResourceLimits::default(),
+        }
+    }
+}
+
+/// Converts a RAM unit as stored in the settings ("GB", "MB") to the JVM's suffix.
+pub fn jvm_memory_unit(unit: &str) -> &str {
+    match unit {
+        "GB" => "G",
+        "MB" => "M",
+        u => u,
+    }
+}
+
+impl InstanceSettings {
+    /// The max heap (-Xmx) in MB.
+    pub fn max_memory_mb(&self) -> u64 {
+        match jvm_memory_unit(&self.max_ram_unit).to_ascii_uppercase().as_str() {
+            "G" => self.max_ram as u64 * 1024,
+            "K" => self.max_ram as u64 / 1024,
+            _ => self.max_ram as u64,
         }
     }
+
+    /// The startup line with its `{min_ram}`, `{min_unit}`, `{max_ram}` and `{max_unit}`
+    /// placeholders filled in.
+    pub fn render_startup_line(&self) -> String {
+        self.startup_line
+            .replace("{min_ram}", &self.min_ram.to_string())
+            .replace("{min_unit}", jvm_memory_unit(&self.min_ram_unit))
+            .replace("{max_ram}", &self.max_ram.to_string())
+            .replace("{max_unit}", jvm_memory_unit(&self.max_ram_unit))
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -114,4 +210,11 @@ pub struct InstanceMetadata {
     pub max_players: Option<u32>,
     #[serde(default)]
     pub description: Option<String>,
+    /// Set for Folia, which only loads plugins built for its regionized threading.
+    #[serde(default)]
+    pub folia_plugins_only: bool,
+    /// Set when the instance runs from a folder outside the instances directory, imported
+    /// in place. Deleting it leaves that folder alone.
+    #[serde(default)]
+    pub linked: bool,
 }
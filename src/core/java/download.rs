@@ -71,6 +71,7 @@ impl JavaManager {
 
         download_with_resumption(
             &self.client,
+            &self.download_limits,
             DownloadOptions {
                 url,
                 target_path: &temp_file_path,
@@ -1,6 +1,7 @@
 use std::path::{Path, PathBuf};
 use anyhow::{Result, Context};
 use tokio::fs;
+use crate::utils::DownloadLimits;
 
 pub mod types;
 pub mod detection;
@@ -11,6 +12,7 @@ pub use types::*;
 pub struct JavaManager {
     pub(crate) base_dir: PathBuf,
     pub(crate) client: reqwest::Client,
+    pub(crate) download_limits: DownloadLimits,
 }
 
 impl JavaManager {
@@ -19,12 +21,22 @@ impl JavaManager {
         let base_dir = exe_path.parent()
             .context("Failed to get executable directory")?
             .join("java");
-        
+        Self::with_base_dir(base_dir)
+    }
+
+    /// A manager keeping its Java versions in `base_dir`, e.g. under the app data folder.
+    pub fn with_base_dir(base_dir: PathBuf) -> Result<Self> {
         let client = reqwest::Client::builder()
             .user_agent(concat!("mc-server-wrapper/", env!("CARGO_PKG_VERSION")))
             .build()?;
 
-        Ok(Self { base_dir, client })
+        Ok(Self { base_dir, client, download_limits: DownloadLimits::default() })
+    }
+
+    /// Counts Java downloads against the app's shared download limits.
+    pub fn with_download_limits(mut self, download_limits: DownloadLimits) -> Self {
+        self.download_limits = download_limits;
+        self
     }
 
     /// Returns the path where Java versions are stored
@@ -0,0 +1,123 @@
+use super::{Loadout, LoadoutEntry, base_filename, content_dir, load_loadouts, write_loadouts};
+use crate::content_report::ContentKind;
+use crate::{mods, plugins};
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::path::Path;
+use tokio::fs;
+use tracing::warn;
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LoadoutResult {
+    /// Files whose enabled state was flipped.
+    pub changed: Vec<String>,
+    /// Files named by the load-out that are no longer installed.
+    pub missing: Vec<String>,
+}
+
+/// Reads the enabled state of every jar in `mods/` and `plugins/`.
+async fn current_state(instance_path: &Path) -> Result<Vec<LoadoutEntry>> {
+    let mut entries = Vec::new();
+    for kind in [ContentKind::Mod, ContentKind::Plugin] {
+        let dir = instance_path.join(content_dir(kind));
+        if !dir.exists() {
+            continue;
+        }
+        let mut read_dir = fs::read_dir(&dir)
+            .await
+            .with_context(|| format!("Failed to read {}", dir.display()))?;
+        while let Some(entry) = read_dir.next_entry().await? {
+            let filename = entry.file_name().to_string_lossy().to_string();
+            let lower = filename.to_lowercase();
+            if !entry.path().is_file() || !(lower.ends_with(".jar") || lower.ends_with(".jar.disabled")) {
+                continue;
+            }
+            entries.push(LoadoutEntry {
+                kind,
+                file: base_filename(&filename).to_string(),
+                enabled: !lower.ends_with(".disabled"),
+            });
+        }
+    }
+    entries.sort_by(|a, b| a.file.cmp(&b.file));
+    Ok(entries)
+}
+
+async fn apply_entries(instance_path: &Path, entries: &[LoadoutEntry]) -> Result<LoadoutResult> {
+    let mut result = LoadoutResult::default();
+    for entry in entries {
+        let dir = instance_path.join(content_dir(entry.kind));
+        let disabled_name = format!("{}.disabled", entry.file);
+        let current = if dir.join(&entry.file).exists() {
+            (entry.file.clone(), true)
+        } else if dir.join(&disabled_name).exists() {
+            (disabled_name, false)
+        } else {
+            result.missing.push(entry.file.clone());
+            continue;
+        };
+        if current.1 == entry.enabled {
+            continue;
+        }
+        match entry.kind {
+            ContentKind::Mod => mods::toggle_mod(instance_path, current.0, entry.enabled).await?,
+            ContentKind::Plugin => plugins::toggle_plugin(instance_path, current.0, entry.enabled).await?,
+        }
+        result.changed.push(entry.file.clone());
+    }
+    Ok(result)
+}
+
+/// Builds a load-out from the instance's current enabled/disabled state.
+pub async fn capture_loadout(instance_path: impl AsRef<Path>, name: &str) -> Result<Loadout> {
+    Ok(Loadout {
+        name: name.trim().to_string(),
+        entries: current_state(instance_path.as_ref()).await?,
+    })
+}
+
+/// Applies a saved load-out. The state from before the first applied load-out is
+/// kept, so switching between load-outs and then reverting restores the original.
+/// If a file can't be toggled, the files already flipped are put back and the
+/// load-out isn't recorded as active.
+pub async fn apply_loadout(instance_path: impl AsRef<Path>, name: &str) -> Result<LoadoutResult> {
+    let instance_path = instance_path.as_ref();
+    let mut store = load_loadouts(instance_path).await;
+    let loadout = store
+        .loadouts
+        .iter()
+        .find(|l| l.name == name)
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("Load-out not found: {}", name))?;
+
+    let before = current_state(instance_path).await?;
+    let result = match apply_entries(instance_path, &loadout.entries).await {
+        Ok(result) => result,
+        Err(e) => {
+            if let Err(restore) = apply_entries(instance_path, &before).await {
+                warn!("Failed to restore mods after a failed load-out: {:#}", restore);
+            }
+            return Err(e.context(format!("Failed to apply load-out {}", name)));
+        }
+    };
+
+    store.previous.get_or_insert(before);
+    store.active = Some(loadout.name);
+    write_loadouts(instance_path, &store).await?;
+    Ok(result)
+}
+
+/// Restores the state recorded before the active load-out was applied.
+pub async fn revert_loadout(instance_path: impl AsRef<Path>) -> Result<LoadoutResult> {
+    let instance_path = instance_path.as_ref();
+    let mut store = load_loadouts(instance_path).await;
+    let previous = store
+        .previous
+        .take()
+        .ok_or_else(|| anyhow::anyhow!("No load-out is active"))?;
+
+    let result = apply_entries(instance_path, &previous).await?;
+    store.active = None;
+    write_loadouts(instance_path, &store).await?;
+    Ok(result)
+}
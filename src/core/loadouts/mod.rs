@@ -0,0 +1,96 @@
+//! Named enable/disable profiles ("load-outs") for an instance's mods and plugins.
+
+use crate::content_report::ContentKind;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tokio::fs;
+
+pub mod apply;
+
+pub use apply::{LoadoutResult, apply_loadout, capture_loadout, revert_loadout};
+
+/// Stored in the instance root so profiles travel with clones and exports.
+const LOADOUTS_FILE: &str = ".loadouts.json";
+
+/// Desired state of a single jar. `file` never carries the `.disabled` suffix.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LoadoutEntry {
+    pub kind: ContentKind,
+    pub file: String,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Loadout {
+    pub name: String,
+    #[serde(default)]
+    pub entries: Vec<LoadoutEntry>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LoadoutStore {
+    #[serde(default)]
+    pub loadouts: Vec<Loadout>,
+    /// Name of the last applied load-out, cleared on revert.
+    #[serde(default)]
+    pub active: Option<String>,
+    /// State of every jar right before `active` was applied.
+    #[serde(default)]
+    pub previous: Option<Vec<LoadoutEntry>>,
+}
+
+/// Strips the `.disabled` suffix used by the rename-based toggle.
+pub(crate) fn base_filename(filename: &str) -> &str {
+    filename.strip_suffix(".disabled").unwrap_or(filename)
+}
+
+pub(crate) fn content_dir(kind: ContentKind) -> &'static str {
+    match kind {
+        ContentKind::Mod => "mods",
+        ContentKind::Plugin => "plugins",
+    }
+}
+
+pub async fn load_loadouts(instance_path: impl AsRef<Path>) -> LoadoutStore {
+    match fs::read_to_string(instance_path.as_ref().join(LOADOUTS_FILE)).await {
+        Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+        Err(_) => LoadoutStore::default(),
+    }
+}
+
+pub(crate) async fn write_loadouts(instance_path: &Path, store: &LoadoutStore) -> Result<()> {
+    let content = serde_json::to_string_pretty(store)?;
+    fs::write(instance_path.join(LOADOUTS_FILE), content)
+        .await
+        .context("Failed to write load-outs")
+}
+
+/// Creates or replaces the load-out with the same name.
+pub async fn save_loadout(instance_path: impl AsRef<Path>, mut loadout: Loadout) -> Result<LoadoutStore> {
+    let name = loadout.name.trim().to_string();
+    if name.is_empty() {
+        return Err(anyhow::anyhow!("Load-out name cannot be empty"));
+    }
+    loadout.name = name;
+    for entry in &mut loadout.entries {
+        entry.file = base_filename(&entry.file).to_string();
+    }
+
+    let instance_path = instance_path.as_ref();
+    let mut store = load_loadouts(instance_path).await;
+    match store.loadouts.iter_mut().find(|l| l.name == loadout.name) {
+        Some(existing) => *existing = loadout,
+        None => store.loadouts.push(loadout),
+    }
+    write_loadouts(instance_path, &store).await?;
+    Ok(store)
+}
+
+pub async fn delete_loadout(instance_path: impl AsRef<Path>, name: &str) -> Result<LoadoutStore> {
+    let instance_path = instance_path.as_ref();
+    let mut store = load_loadouts(instance_path).await;
+    store.loadouts.retain(|l| l.name != name);
+    write_loadouts(instance_path, &store).await?;
+    Ok(store)
+}
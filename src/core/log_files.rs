@@ -0,0 +1,157 @@
+use anyhow::{Context, Result, anyhow};
+use chrono::{DateTime, Utc};
+use flate2::read::GzDecoder;
+use serde::Serialize;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+/// Largest page returned by a single read, to keep IPC payloads reasonable.
+pub const MAX_PAGE_SIZE: u64 = 1024 * 1024;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LogFileInfo {
+    pub name: String,
+    /// Size on disk (compressed size for `.gz` archives).
+    pub size: u64,
+    /// Decompressed size, read from the gzip trailer; equal to `size` for plain logs.
+    pub uncompressed_size: u64,
+    pub modified: Option<DateTime<Utc>>,
+    pub compressed: bool,
+}
+
+/// A slice of a log file. Pages end on a line break unless the file ends first.
+#[derive(Debug, Clone, Serialize)]
+pub struct LogPage {
+    pub content: String,
+    pub offset: u64,
+    /// Offset to pass for the following page.
+    pub next_offset: u64,
+    pub eof: bool,
+}
+
+fn is_log_name(name: &str) -> bool {
+    name.ends_with(".log") || name.ends_with(".log.gz")
+}
+
+/// Resolves a file name inside `logs/`, rejecting anything that is not a plain log name.
+fn resolve_log(instance_path: &Path, name: &str) -> Result<PathBuf> {
+    if !is_log_name(name) || name.contains(['/', '\\']) || name.starts_with('.') {
+        return Err(anyhow!("Invalid log file name: {}", name));
+    }
+    Ok(instance_path.join("logs").join(name))
+}
+
+/// Reads the uncompressed length stored in the last four bytes of a gzip file.
+/// The value wraps at 4 GiB, which is far beyond a rotated server log.
+fn gzip_uncompressed_size(path: &Path) -> Result<u64> {
+    let mut file = File::open(path)?;
+    file.seek(SeekFrom::End(-4))?;
+    let mut trailer = [0u8; 4];
+    file.read_exact(&mut trailer)?;
+    Ok(u32::from_le_bytes(trailer) as u64)
+}
+
+/// Lists `latest.log` and the rotated `*.log.gz` archives, newest first.
+pub async fn list_log_files(instance_path: &Path) -> Result<Vec<LogFileInfo>> {
+    let logs_dir = instance_path.join("logs");
+    if !logs_dir.exists() {
+        return Ok(vec![]);
+    }
+
+    tokio::task::spawn_blocking(move || {
+        let mut files = Vec::new();
+        for entry in std::fs::read_dir(&logs_dir)?.filter_map(|e| e.ok()) {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let metadata = entry.metadata()?;
+            if !metadata.is_file() || !is_log_name(&name) {
+                continue;
+            }
+            let compressed = name.ends_with(".gz");
+            let uncompressed_size = if compressed {
+                gzip_uncompressed_size(&entry.path()).unwrap_or(0)
+            } else {
+                metadata.len()
+            };
+            files.push(LogFileInfo {
+                name,
+                size: metadata.len(),
+                uncompressed_size,
+                modified: metadata.modified().ok().map(DateTime::<Utc>::from),
+                compressed,
+            });
+        }
+        files.sort_by(|a, b| b.modified.cmp(&a.modified).then_with(|| b.name.cmp(&a.name)));
+        Ok(files)
+    })
+    .await?
+}
+
+/// Reads up to `limit` bytes of a log starting at `offset` (in decompressed bytes).
+///
+/// Gzip archives are decompressed as a stream, so only the requested range is kept
+/// in memory.
+pub async fn read_log_page(instance_path: &Path, name: &str, offset: u64, limit: u64) -> Result<LogPage> {
+    let path = resolve_log(instance_path, name)?;
+    if !path.exists() {
+        return Err(anyhow!("Log file not found: {}", name));
+    }
+    let compressed = name.ends_with(".gz");
+    let limit = limit.clamp(1, MAX_PAGE_SIZE);
+
+    tokio::task::spawn_blocking(move || {
+        let mut file = File::open(&path).context("Failed to open log file")?;
+        let mut buffer = Vec::with_capacity(limit as usize);
+        if compressed {
+            let mut decoder = GzDecoder::new(file);
+            let skipped = std::io::copy(&mut (&mut decoder).take(offset), &mut std::io::sink())?;
+            if skipped < offset {
+                return Ok(page_from(buffer, offset, true));
+            }
+            decoder.take(limit + 1).read_to_end(&mut buffer)?;
+        } else {
+            file.seek(SeekFrom::Start(offset))?;
+            file.take(limit + 1).read_to_end(&mut buffer)?;
+        }
+        // One extra byte was requested to tell whether more follows
+        let eof = buffer.len() as u64 <= limit;
+        buffer.truncate(limit as usize);
+        Ok(page_from(buffer, offset, eof))
+    })
+    .await?
+}
+
+/// Reads the last `limit` bytes of a plain log, starting after a line break so the first
+/// line isn't cut. Archives are read from the start, since their end can't be found cheaply.
+pub async fn read_log_tail(instance_path: &Path, name: &str, limit: u64) -> Result<LogPage> {
+    let path = resolve_log(instance_path, name)?;
+    let limit = limit.clamp(1, MAX_PAGE_SIZE);
+    let size = match tokio::fs::metadata(&path).await {
+        Ok(metadata) if !name.ends_with(".gz") => metadata.len(),
+        _ => return read_log_page(instance_path, name, 0, limit).await,
+    };
+    let offset = size.saturating_sub(limit);
+    let mut page = read_log_page(instance_path, name, offset, limit).await?;
+    if offset > 0
+        && let Some(pos) = page.content.find('\n')
+    {
+        page.content.drain(..=pos);
+        page.offset += pos as u64 + 1;
+    }
+    Ok(page)
+}
+
+fn page_from(mut buffer: Vec<u8>, offset: u64, eof: bool) -> LogPage {
+    if !eof {
+        // Keep whole lines; a line longer than the page is returned split
+        if let Some(pos) = buffer.iter().rposition(|b| *b == b'\n') {
+            buffer.truncate(pos + 1);
+        }
+    }
+    LogPage {
+        content: String::from_utf8_lossy(&buffer).into_owned(),
+        offset,
+        next_offset: offset + buffer.len() as u64,
+        eof,
+    }
+}
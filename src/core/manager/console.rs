@@ -0,0 +1,162 @@
+use super::ServerManager;
+use crate::console::{
+    DiscoveredCommand, load_command_cache, parse_commands_report, parse_help_line,
+    parse_help_page_count, save_command_cache,
+};
+use crate::errors::AppError;
+use crate::server::ServerStatus;
+use crate::server::launch::java_command;
+use anyhow::{Context, Result, anyhow};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// How long the console must stay quiet before a `/help` page is considered complete.
+const HELP_QUIET_PERIOD: Duration = Duration::from_millis(750);
+const HELP_MAX_WAIT: Duration = Duration::from_secs(5);
+/// Upper bound on Bukkit help pages requested, in case the header is misread.
+const MAX_HELP_PAGES: u32 = 30;
+/// How long the data generator may run before it is killed.
+const DATA_GENERATOR_TIMEOUT: Duration = Duration::from_secs(300);
+
+impl ServerManager {
+    /// Returns the commands an instance's server actually provides, including plugin
+    /// and mod commands.
+    ///
+    /// A running server is asked via `/help`; otherwise the last discovered list is used,
+    /// falling back to a `--reports` command tree. With `refresh`, the list is rebuilt:
+    /// from `/help` if the server is running, or by running the vanilla data generator.
+    pub async fn get_server_commands(
+        &self,
+        instance_id: Uuid,
+        refresh: bool,
+    ) -> Result<Vec<DiscoveredCommand>> {
+        let instance = self
+            .instance_manager
+            .get_instance(instance_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
+
+        let running = self.get_server_status(instance_id).await == ServerStatus::Running;
+        let cached = load_command_cache(&instance.path).await;
+
+        if running && (refresh || cached.is_none()) {
+            let commands = self.discover_commands_from_help(instance_id).await?;
+            if !commands.is_empty() {
+                save_command_cache(&instance.path, &commands).await?;
+                return Ok(commands);
+            }
+        }
+
+        if refresh && !running {
+            let commands = self.generate_command_report(instance_id).await?;
+            save_command_cache(&instance.path, &commands).await?;
+            return Ok(commands);
+        }
+
+        if let Some(commands) = cached {
+            return Ok(commands);
+        }
+        read_existing_report(&instance.path).await
+    }
+
+    async fn discover_commands_from_help(&self, instance_id: Uuid) -> Result<Vec<DiscoveredCommand>> {
+        let server = self
+            .get_server(instance_id)
+            .await
+            .ok_or_else(|| anyhow!("Server not running"))?;
+
+        let mut lines = server
+            .capture_command_output("help", HELP_QUIET_PERIOD, HELP_MAX_WAIT)
+            .await?;
+
+        // Bukkit paginates its help index; fetch the remaining pages
+        let pages = lines.iter().find_map(|l| parse_help_page_count(l)).unwrap_or(1);
+        for page in 2..=pages.min(MAX_HELP_PAGES) {
+            lines.extend(
+                server
+                    .capture_command_output(&format!("help {}", page), HELP_QUIET_PERIOD, HELP_MAX_WAIT)
+                    .await?,
+            );
+        }
+
+        let mut commands: BTreeMap<String, DiscoveredCommand> = BTreeMap::new();
+        for command in lines.iter().filter_map(|l| parse_help_line(l)) {
+            commands.entry(command.name.clone()).or_insert(command);
+        }
+        Ok(commands.into_values().collect())
+    }
+
+    /// Runs the vanilla data generator against the instance's server jar and parses
+    /// the resulting Brigadier command tree.
+    async fn generate_command_report(&self, instance_id: Uuid) -> Result<Vec<DiscoveredCommand>> {
+        let instance = self
+            .instance_manager
+            .get_instance(instance_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
+        if instance.mod_loader.is_some() {
+            return Err(anyhow!(
+                "Start the server to discover its commands; reports can only be generated for vanilla servers"
+            ));
+        }
+
+        let config = self.build_server_config(&instance).await;
+        let jar = config
+            .jar_path
+            .clone()
+            .filter(|p| p.exists())
+            .ok_or_else(|| anyhow!("Server jar not found; install the server first"))?;
+        let output_dir = self
+            .instance_manager
+            .get_base_dir()
+            .join("cache")
+            .join("temp")
+            .join(format!("reports-{}", instance_id));
+
+        // Same Java, memory and JVM flags as the server itself
+        let mut command = java_command(&config, &["-DbundlerMainClass=net.minecraft.data.Main"]);
+        command
+            .current_dir(&instance.path)
+            .envs(config.env.iter().map(|(k, v)| (k, v)))
+            .arg("-jar")
+            .arg(&jar)
+            .arg("--reports")
+            .arg("--output")
+            .arg(&output_dir)
+            .kill_on_drop(true);
+        let output = match tokio::time::timeout(DATA_GENERATOR_TIMEOUT, command.output()).await {
+            Ok(output) => output.context("Failed to run the data generator")?,
+            Err(_) => {
+                let _ = tokio::fs::remove_dir_all(&output_dir).await;
+                return Err(anyhow!(
+                    "The data generator did not finish within {} seconds",
+                    DATA_GENERATOR_TIMEOUT.as_secs()
+                ));
+            }
+        };
+
+        let report_path = output_dir.join("reports").join("commands.json");
+        let result = match tokio::fs::read_to_string(&report_path).await {
+            Ok(json) => parse_commands_report(&json),
+            Err(_) => Err(anyhow!(
+                "Data generator did not produce a command report: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )),
+        };
+        let _ = tokio::fs::remove_dir_all(&output_dir).await;
+        result
+    }
+}
+
+async fn read_existing_report(instance_path: &Path) -> Result<Vec<DiscoveredCommand>> {
+    let report = instance_path
+        .join("generated")
+        .join("reports")
+        .join("commands.json");
+    match tokio::fs::read_to_string(&report).await {
+        Ok(json) => parse_commands_report(&json),
+        Err(_) => Ok(Vec::new()),
+    }
+}
@@ -0,0 +1,132 @@
+use super::ServerManager;
+use super::install::installer_state::{InstallerState, verify_installer_output};
+use super::install::pipeline::ProvisionState;
+use crate::doctor::{DiagnosisReport, Finding, Severity, SuggestedFix, checks, content, java_major_version};
+use crate::errors::AppError;
+use crate::protocol::required_java;
+use crate::server::ServerStatus;
+use anyhow::Result;
+use chrono::Utc;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+const CHECKS: &[&str] = &[
+    "launch_target",
+    "installation",
+    "eula",
+    "port",
+    "java",
+    "mod_jars",
+    "mod_loader",
+    "loader_version",
+    "leftover_files",
+];
+
+impl ServerManager {
+    /// Runs every health check against an instance and returns what was found, most severe first.
+    pub async fn diagnose_instance(&self, instance_id: Uuid) -> Result<DiagnosisReport> {
+        let instance = self
+            .instance_manager
+            .get_instance(instance_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
+        let loader = instance.mod_loader.as_deref().map(|l| l.to_lowercase());
+        let config = self.build_server_config(&instance).await;
+        let running = !matches!(
+            self.get_server_status(instance_id).await,
+            ServerStatus::Stopped | ServerStatus::Crashed
+        );
+
+        let mut findings = Vec::new();
+        if let (Some(loader @ ("forge" | "neoforge")), Some(loader_version)) =
+            (loader.as_deref(), &instance.loader_version)
+        {
+            let failed = InstallerState::load(&instance.path)
+                .await?
+                .and_then(|s| s.failed.map(|phase| (phase, s.error)));
+            if let Some((phase, error)) = failed {
+                findings.push(
+                    Finding::new(
+                        "installation",
+                        Severity::Error,
+                        format!(
+                            "The {} install failed while {}: {}",
+                            loader,
+                            phase.label().to_lowercase(),
+                            error.unwrap_or_default()
+                        ),
+                        "Repair the installation to re-run the failed step",
+                    )
+                    .with_fix(SuggestedFix::RepairInstallation),
+                );
+            } else if config.run_script.is_some() || config.jar_path.as_ref().is_some_and(|j| j.exists()) {
+                let expected = self.expected_installer_artifacts(&instance, loader, loader_version);
+                for problem in verify_installer_output(&instance.path, &expected) {
+                    findings.push(
+                        Finding::new(
+                            "installation",
+                            Severity::Error,
+                            problem,
+                            "Repair the installation to restore it",
+                        )
+                        .with_fix(SuggestedFix::RepairInstallation),
+                    );
+                }
+            }
+        }
+
+        let installed = ProvisionState::load(&instance.path).await?.filter(|s| s.is_complete());
+        findings.extend(content::check_loader_version(
+            instance.loader_version.as_deref(),
+            installed.as_ref().and_then(|s| s.loader_version.as_deref()),
+        ));
+
+        if loader.as_deref() != Some("bedrock") {
+            let required = match loader.as_deref() {
+                Some("velocity") | Some("bungeecord") => 17,
+                // Without a known version only check that Java runs at all
+                _ if instance.version == "Imported" => 8,
+                _ => required_java(&instance.version),
+            };
+            let java = config.java_path.clone().unwrap_or_else(|| PathBuf::from("java"));
+            let found = java_major_version(&java).await;
+            findings.extend(checks::check_java(required, found, &java, loader.as_deref()));
+        }
+
+        let others = self.instance_manager.list_instances().await?;
+        let port = instance.settings.port;
+        let sharing: Vec<String> = others
+            .iter()
+            .filter(|o| o.id != instance_id && o.settings.port == port)
+            .map(|o| o.name.clone())
+            .collect();
+        let taken: Vec<u16> = others.iter().map(|o| o.settings.port).collect();
+        let listening: Vec<Uuid> = self.wake_listeners.lock().await.keys().copied().collect();
+        let held_by_wrapper =
+            running || others.iter().any(|o| o.settings.port == port && listening.contains(&o.id));
+
+        let path = instance.path.clone();
+        let file_findings = tokio::task::spawn_blocking(move || {
+            let loader = loader.as_deref();
+            let mut findings = checks::check_launch_target(&path, &config, loader);
+            findings.extend(checks::check_eula(&path, loader));
+            // Bedrock listens on UDP, which the bind test doesn't cover
+            if loader != Some("bedrock") {
+                findings.extend(checks::check_port(port, held_by_wrapper, &sharing, &taken));
+            }
+            findings.extend(content::check_mod_jars(&path, loader));
+            findings.extend(content::check_leftover_files(&path));
+            findings
+        })
+        .await?;
+        findings.extend(file_findings);
+        findings.sort_by_key(|f| std::cmp::Reverse(f.severity));
+
+        Ok(DiagnosisReport {
+            instance_id,
+            checked_at: Utc::now(),
+            checks: CHECKS.iter().map(|c| c.to_string()).collect(),
+            findings,
+        })
+    }
+}
@@ -0,0 +1,74 @@
+use super::CrossplayChange;
+use crate::instance::InstanceMetadata;
+use crate::manager::{ServerManager, ViaAction};
+use crate::mods::{self, ModProvider};
+use crate::plugins::{self, CrossplayComponent, CrossplayTarget, ModrinthClient};
+use anyhow::{Context, Result, anyhow};
+use std::sync::Arc;
+
+fn is_component(name: &str, component: CrossplayComponent) -> bool {
+    // Jars are named per platform, e.g. Geyser-Spigot or Geyser-Fabric
+    name.to_lowercase().starts_with(component.modrinth_slug())
+}
+
+impl ServerManager {
+    pub(super) async fn install_crossplay_on(
+        &self,
+        instance: &InstanceMetadata,
+        target: &CrossplayTarget,
+    ) -> Result<Vec<CrossplayChange>> {
+        let loader = target.platform.modrinth_loader();
+        let mut changes = Vec::new();
+        for &component in &target.components {
+            let (version, action) = if target.platform.is_mod() {
+                self.ensure_modrinth_mod(instance, component, loader).await?
+            } else {
+                let installed = plugins::list_installed_plugins(&instance.path).await?;
+                let existing = installed.iter().find(|p| is_component(&p.name, component));
+                self.ensure_modrinth_plugin(instance, existing, component.modrinth_slug(), component.name(), loader)
+                    .await?
+            };
+            changes.push(CrossplayChange {
+                instance_name: instance.name.clone(),
+                component,
+                version,
+                action,
+            });
+        }
+        Ok(changes)
+    }
+
+    /// Mods are matched to the server's game version, so an installed one is left as is.
+    async fn ensure_modrinth_mod(
+        &self,
+        instance: &InstanceMetadata,
+        component: CrossplayComponent,
+        loader: &str,
+    ) -> Result<(String, ViaAction)> {
+        let installed = mods::list_installed_mods(&instance.path).await?;
+        if let Some(existing) = installed.iter().find(|m| is_component(&m.name, component)) {
+            return Ok((existing.version.clone().unwrap_or_default(), ViaAction::UpToDate));
+        }
+
+        let client = ModrinthClient::new(Arc::clone(&self.cache));
+        let latest = client
+            .get_versions(component.modrinth_slug(), Some(&instance.version), Some(loader))
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow!("No {} release for {} {}", component.name(), loader, instance.version))?;
+        mods::install_mod(
+            &instance.path,
+            component.modrinth_slug(),
+            ModProvider::Modrinth,
+            Some(&latest.id),
+            Some(&instance.version),
+            Some(loader),
+            None,
+            Arc::clone(&self.cache),
+        )
+        .await
+        .with_context(|| format!("Failed to install {}", component.name()))?;
+        Ok((latest.version_number, ViaAction::Installed))
+    }
+}
@@ -0,0 +1,139 @@
+mod install;
+
+use super::{ServerManager, ViaAction};
+use crate::instance::InstanceMetadata;
+use crate::plugins::geyser::{
+    bedrock_port_users, configure_yaml, floodgate_proxy_settings, geyser_settings, sync_floodgate_key, udp_port_free,
+};
+use crate::plugins::{CrossplayComponent, CrossplayPlan, DEFAULT_BEDROCK_PORT, plan_crossplay};
+use crate::server::ServerStatus;
+use anyhow::{Context, Result, anyhow};
+use serde::Serialize;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CrossplayChange {
+    pub instance_name: String,
+    pub component: CrossplayComponent,
+    pub version: String,
+    pub action: ViaAction,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CrossplaySetupReport {
+    pub plan: CrossplayPlan,
+    pub changes: Vec<CrossplayChange>,
+    /// Config and key files the wizard wrote, as `<instance>: <path>`.
+    pub configured: Vec<String>,
+}
+
+impl ServerManager {
+    /// Works out where Geyser and Floodgate belong for this instance's network. Problems
+    /// with the Bedrock port are listed in the notes.
+    pub async fn plan_crossplay(&self, instance_id: Uuid, bedrock_port: Option<u16>) -> Result<CrossplayPlan> {
+        let instances = self.instance_manager.list_instances().await?;
+        let mut plan = self.crossplay_plan(instance_id, bedrock_port, &instances).await?;
+        if let Some(problem) = self.bedrock_port_problem(&plan, &instances).await {
+            plan.notes.push(problem);
+        }
+        Ok(plan)
+    }
+
+    async fn crossplay_plan(
+        &self,
+        instance_id: Uuid,
+        bedrock_port: Option<u16>,
+        instances: &[InstanceMetadata],
+    ) -> Result<CrossplayPlan> {
+        let instance = instances
+            .iter()
+            .find(|i| i.id == instance_id)
+            .context("Instance not found")?;
+        let network = self.proxy_network(instance, instances).await;
+        plan_crossplay(
+            instance,
+            network.as_ref().map(|(p, b)| (p, b.as_slice())),
+            bedrock_port.unwrap_or(DEFAULT_BEDROCK_PORT),
+        )
+    }
+
+    async fn bedrock_port_problem(&self, plan: &CrossplayPlan, instances: &[InstanceMetadata]) -> Option<String> {
+        let port = plan.bedrock_port;
+        let users = bedrock_port_users(port, plan.host.instance_id, instances);
+        if !users.is_empty() {
+            return Some(format!("Bedrock port {} is already used by {}", port, users.join(", ")));
+        }
+        // A running host may be holding the port itself
+        let status = self.get_server_status(plan.host.instance_id).await;
+        if matches!(status, ServerStatus::Stopped | ServerStatus::Crashed) && !udp_port_free(port) {
+            return Some(format!("UDP port {} is already in use by another program", port));
+        }
+        None
+    }
+
+    /// Installs or updates Geyser and Floodgate where the plan puts them, points Geyser
+    /// at `bedrock_port` and shares the proxy's Floodgate key with its backends.
+    ///
+    /// Geyser and Floodgate write their config and key on first start, so on a fresh
+    /// install the notes ask for a start and a second run to finish the setup.
+    pub async fn setup_crossplay(&self, instance_id: Uuid, bedrock_port: Option<u16>) -> Result<CrossplaySetupReport> {
+        let instances = self.instance_manager.list_instances().await?;
+        let mut plan = self.crossplay_plan(instance_id, bedrock_port, &instances).await?;
+        if let Some(problem) = self.bedrock_port_problem(&plan, &instances).await {
+            return Err(anyhow!(problem));
+        }
+        let find = |id: Uuid| instances.iter().find(|i| i.id == id).context("Instance not found");
+
+        let mut changes = Vec::new();
+        let mut configured = Vec::new();
+        let host = find(plan.host.instance_id)?;
+        changes.extend(self.install_crossplay_on(host, &plan.host).await?);
+
+        let geyser_config = plan.host.platform.geyser_config();
+        if configure_yaml(&host.path, &geyser_config, &geyser_settings(plan.bedrock_port)).await? {
+            configured.push(format!("{}: {}", host.name, geyser_config));
+        } else {
+            plan.notes.push(format!(
+                "Start {} once so Geyser creates its config, then run the setup again to set the Bedrock port",
+                host.name
+            ));
+        }
+
+        if plan.host.platform.is_proxy() {
+            let floodgate_config = plan.host.platform.floodgate_config();
+            if configure_yaml(&host.path, &floodgate_config, &floodgate_proxy_settings()).await? {
+                configured.push(format!("{}: {}", host.name, floodgate_config));
+            }
+        }
+
+        let mut key_missing = false;
+        for target in &plan.backends {
+            let backend = find(target.instance_id)?;
+            changes.extend(self.install_crossplay_on(backend, target).await?);
+            if sync_floodgate_key(&host.path, plan.host.platform, &backend.path, target.platform).await? {
+                configured.push(format!("{}: {}", backend.name, target.platform.floodgate_key()));
+            } else {
+                key_missing = true;
+            }
+        }
+        if key_missing {
+            plan.notes.push(format!(
+                "Start {} once so Floodgate creates its key, then run the setup again to copy it to the backends",
+                host.name
+            ));
+        }
+
+        for target in std::iter::once(&plan.host).chain(&plan.backends) {
+            let status = self.get_server_status(target.instance_id).await;
+            if matches!(status, ServerStatus::Running | ServerStatus::Starting) {
+                plan.notes
+                    .push(format!("Restart {} to load the changes", target.instance_name));
+            }
+        }
+        Ok(CrossplaySetupReport {
+            plan,
+            changes,
+            configured,
+        })
+    }
+}
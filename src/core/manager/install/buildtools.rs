@@ -0,0 +1,117 @@
+use super::ServerManager;
+use crate::artifacts::HashAlgorithm;
+use crate::instance::InstanceMetadata;
+use crate::mod_loaders::BuildToolsTarget;
+use crate::server::ServerHandle;
+use crate::utils::fs::is_jar_valid;
+use anyhow::{Context, Result, anyhow};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicU32;
+use std::sync::{Arc, LazyLock};
+use tokio::sync::Mutex;
+
+/// Maps `<target>-<mc version>` to the SHA-1 of the jar cached in the artifact store.
+const BUILDS_INDEX: &str = "builds.json";
+
+/// BuildTools keeps its work trees in a shared directory, so only one build runs at a time.
+static BUILD_LOCK: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+
+async fn load_index(dir: &Path) -> HashMap<String, String> {
+    match tokio::fs::read_to_string(dir.join(BUILDS_INDEX)).await {
+        Ok(text) => serde_json::from_str(&text).unwrap_or_default(),
+        Err(_) => HashMap::new(),
+    }
+}
+
+fn cache_key(target: BuildToolsTarget, mc_version: &str) -> String {
+    format!("{}-{}", target.name().to_lowercase(), mc_version)
+}
+
+impl ServerManager {
+    fn buildtools_dir(&self) -> PathBuf {
+        self.instance_manager.get_base_dir().join("cache").join("buildtools")
+    }
+
+    /// Hashes of compiled jars kept for reuse, so maintenance doesn't prune them.
+    pub(crate) async fn cached_buildtools_hashes(&self) -> Vec<String> {
+        load_index(&self.buildtools_dir()).await.into_values().collect()
+    }
+
+    /// Builds Spigot or CraftBukkit with BuildTools, streaming its output to the console.
+    /// Compiled jars are cached in the artifact store, so later instances of the same
+    /// version skip the (slow) build.
+    pub(crate) async fn install_buildtools(
+        &self,
+        server: Arc<ServerHandle>,
+        instance: &InstanceMetadata,
+    ) -> Result<()> {
+        let loader = instance.mod_loader.as_deref().unwrap_or_default();
+        let target =
+            BuildToolsTarget::from_loader(loader).ok_or_else(|| anyhow!("{} is not built with BuildTools", loader))?;
+        let jar_path = instance.path.join("server.jar");
+        let key = cache_key(target, &instance.version);
+        let dir = self.buildtools_dir();
+        tokio::fs::create_dir_all(&dir).await?;
+
+        if self.buildtools_is_busy() {
+            server.emit_log("Waiting for another BuildTools build to finish...".to_string());
+        }
+        let _guard = BUILD_LOCK.lock().await;
+        let mut index = load_index(&dir).await;
+        if let Some(hash) = index.get(&key)
+            && self.artifact_store.exists(hash, HashAlgorithm::Sha1).await
+        {
+            server.emit_log(format!("Using cached {} {} build", target.name(), instance.version));
+            return self
+                .artifact_store
+                .provision(hash, HashAlgorithm::Sha1, &jar_path)
+                .await;
+        }
+
+        let buildtools = dir.join("BuildTools.jar");
+        server.emit_log("Downloading BuildTools...".to_string());
+        let server_clone = Arc::clone(&server);
+        let last_percent = Arc::new(AtomicU32::new(0));
+        self.mod_loader_client
+            .download_buildtools(&buildtools, move |current, total| {
+                server_clone.handle_download_progress(current, total, "Downloading BuildTools...", &last_percent);
+            })
+            .await?;
+
+        server.emit_log(format!(
+            "Building {} {}. This compiles the server from source and can take several minutes.",
+            target.name(),
+            instance.version
+        ));
+        let mut cmd = tokio::process::Command::new("java");
+        cmd.current_dir(&dir)
+            .arg("-jar")
+            .arg(&buildtools)
+            .args(target.args(&instance.version));
+        self.run_installer_command(cmd, Arc::clone(&server), target.name(), &instance.path)
+            .await?;
+
+        let output = dir.join(target.output_jar(&instance.version));
+        if !is_jar_valid(&output) {
+            return Err(anyhow!("BuildTools finished but {} was not created", output.display()));
+        }
+        let hash = self.artifact_store.calculate_hash(&output, HashAlgorithm::Sha1).await?;
+        self.artifact_store
+            .add_artifact(&output, &hash, HashAlgorithm::Sha1)
+            .await?;
+        let _ = tokio::fs::remove_file(&output).await;
+        index.insert(key, hash.clone());
+        tokio::fs::write(dir.join(BUILDS_INDEX), serde_json::to_string_pretty(&index)?)
+            .await
+            .context("Failed to save the BuildTools build index")?;
+
+        self.artifact_store
+            .provision(&hash, HashAlgorithm::Sha1, &jar_path)
+            .await
+    }
+
+    fn buildtools_is_busy(&self) -> bool {
+        BUILD_LOCK.try_lock().is_err()
+    }
+}
@@ -56,7 +56,8 @@ impl ServerManager {
             cmd.arg("-loader").arg(loader_ver);
         }
 
-        self.run_installer_command(cmd, server, "Fabric").await?;
+        self.run_installer_command(cmd, server, "Fabric", &instance.path)
+            .await?;
         let _ = tokio::fs::remove_file(installer_path).await;
 
         // Find and rename loader jar
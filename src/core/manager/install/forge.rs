@@ -1,23 +1,33 @@
 use super::ServerManager;
 use crate::instance::InstanceMetadata;
+use crate::mod_loaders::forge::FORGE_MAVEN;
 use crate::server::ServerHandle;
+use crate::utils::fs::is_jar_valid;
+use crate::utils::mirror_candidates;
 use anyhow::{Result, anyhow};
+use std::path::Path;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::time::Duration;
+use tracing::warn;
 
 impl ServerManager {
-    pub(crate) async fn install_forge(
+    pub(crate) async fn download_forge_installer(
         &self,
         server: Arc<ServerHandle>,
         instance: &InstanceMetadata,
+        installer_path: &Path,
     ) -> Result<()> {
         let loader_version = instance
             .loader_version
             .as_deref()
             .ok_or_else(|| anyhow!("Forge requires a loader version"))?;
 
-        let installer_name = "forge-installer.jar";
-        let installer_path = instance.path.join(installer_name);
+        // Reuse an installer left behind by a previous failed attempt instead of re-downloading it
+        if is_jar_valid(installer_path) {
+            server.emit_log("Reusing previously downloaded Forge installer".to_string());
+            return Ok(());
+        }
 
         let server_clone = Arc::clone(&server);
         let last_percent = Arc::new(AtomicU32::new(0));
@@ -28,7 +38,7 @@ impl ServerManager {
             .download_forge(
                 &instance.version,
                 loader_version,
-                &installer_path,
+                installer_path,
                 move |current, total| {
                     final_size_clone.store(current, Ordering::Relaxed);
                     server_clone.handle_download_progress(
@@ -43,15 +53,60 @@ impl ServerManager {
         let size_mb = final_size.load(Ordering::Relaxed) / (1024 * 1024);
         server.emit_log(format!("Final size: {} MB", size_mb));
         server.emit_log("Forge installer download complete!".to_string());
+        Ok(())
+    }
 
-        let mut cmd = tokio::process::Command::new("java");
-        cmd.current_dir(&instance.path)
-            .arg("-jar")
-            .arg(&installer_path)
-            .arg("--installServer");
+    pub(crate) async fn run_forge_installer(
+        &self,
+        server: Arc<ServerHandle>,
+        instance: &InstanceMetadata,
+        installer_path: &Path,
+    ) -> Result<()> {
+        // The first attempt uses the installer's default repository; each retry after a
+        // download failure switches to the next configured mirror of the Forge Maven.
+        // The installer checks every library against the hashes in its install profile,
+        // so a mirror cannot swap them, and libraries already fetched are kept.
+        let mirrors: Vec<Option<String>> = std::iter::once(None)
+            .chain(
+                mirror_candidates(self.cache.download_limits(), FORGE_MAVEN)
+                    .into_iter()
+                    .filter(|m| m != FORGE_MAVEN)
+                    .map(Some),
+            )
+            .collect();
+        let attempts = mirrors.len();
 
-        self.run_installer_command(cmd, server, "Forge").await?;
-        let _ = tokio::fs::remove_file(installer_path).await;
+        for (attempt, mirror) in mirrors.iter().enumerate() {
+            let mut cmd = tokio::process::Command::new("java");
+            cmd.current_dir(&instance.path)
+                .arg("-jar")
+                .arg(installer_path)
+                .arg("--installServer");
+            if let Some(mirror) = mirror {
+                cmd.arg("--mirror").arg(mirror);
+            }
+
+            let run = self
+                .execute_installer(cmd, Arc::clone(&server), "Forge", &instance.path)
+                .await?;
+            if run.status.success() {
+                break;
+            }
+
+            if !run.transcript.download_failure || attempt + 1 == attempts {
+                return Err(run.into_error("Forge"));
+            }
+
+            let msg = format!(
+                "Forge installer could not download its libraries. Retrying with mirror {} (attempt {}/{})...",
+                mirrors[attempt + 1].as_deref().unwrap_or_default(),
+                attempt + 2,
+                attempts
+            );
+            warn!("{}", msg);
+            server.emit_log(msg);
+            tokio::time::sleep(Duration::from_secs(2)).await;
+        }
 
         // For modern Forge, we don't rename anything. The run script will be used.
         // For older Forge, we might need to find the server jar.
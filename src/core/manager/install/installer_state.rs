@@ -0,0 +1,184 @@
+use crate::utils::fs::is_jar_valid;
+use crate::mod_loaders::argfile::server_argfile_name;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Marker file (relative to the instance root) tracking the phases of a Forge/NeoForge install.
+pub const INSTALLER_STATE_FILE: &str = ".installer-state.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InstallerPhase {
+    DownloadInstaller,
+    RunInstaller,
+    VerifyOutput,
+}
+
+impl InstallerPhase {
+    pub const ALL: [InstallerPhase; 3] = [
+        InstallerPhase::DownloadInstaller,
+        InstallerPhase::RunInstaller,
+        InstallerPhase::VerifyOutput,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            InstallerPhase::DownloadInstaller => "Downloading installer",
+            InstallerPhase::RunInstaller => "Running installer",
+            InstallerPhase::VerifyOutput => "Verifying installed libraries",
+        }
+    }
+}
+
+/// Which installer phases finished, and which one failed, for one loader version.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstallerState {
+    pub loader: String,
+    pub version: String,
+    pub loader_version: String,
+    pub completed: Vec<InstallerPhase>,
+    pub failed: Option<InstallerPhase>,
+    pub error: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl InstallerState {
+    pub fn new(loader: &str, version: &str, loader_version: &str) -> Self {
+        Self {
+            loader: loader.to_lowercase(),
+            version: version.to_string(),
+            loader_version: loader_version.to_string(),
+            completed: Vec::new(),
+            failed: None,
+            error: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn path(instance_path: &Path) -> PathBuf {
+        instance_path.join(INSTALLER_STATE_FILE)
+    }
+
+    pub async fn load(instance_path: &Path) -> Result<Option<Self>> {
+        let path = Self::path(instance_path);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = tokio::fs::read_to_string(&path)
+            .await
+            .context("Failed to read installer state")?;
+        Ok(serde_json::from_str(&content).ok())
+    }
+
+    /// Resumes the saved state when it belongs to the same loader version, otherwise starts over.
+    /// A failed verification is resumed from the installer run, since only that can fix it.
+    pub async fn load_or_new(instance_path: &Path, loader: &str, version: &str, loader_version: &str) -> Result<Self> {
+        let fresh = Self::new(loader, version, loader_version);
+        let saved = Self::load(instance_path).await?.filter(|saved| {
+            saved.loader == fresh.loader && saved.version == fresh.version && saved.loader_version == fresh.loader_version
+        });
+        let mut state = saved.unwrap_or(fresh);
+        if state.failed == Some(InstallerPhase::VerifyOutput) {
+            state.reset_from(InstallerPhase::RunInstaller);
+        }
+        Ok(state)
+    }
+
+    pub async fn save(&self, instance_path: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        tokio::fs::write(Self::path(instance_path), content)
+            .await
+            .context("Failed to save installer state")
+    }
+
+    /// First phase that hasn't completed yet.
+    pub fn next_phase(&self) -> Option<InstallerPhase> {
+        InstallerPhase::ALL.into_iter().find(|p| !self.completed.contains(p))
+    }
+
+    pub fn mark_completed(&mut self, phase: InstallerPhase) {
+        if !self.completed.contains(&phase) {
+            self.completed.push(phase);
+        }
+        self.failed = None;
+        self.error = None;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn mark_failed(&mut self, phase: InstallerPhase, error: String) {
+        self.failed = Some(phase);
+        self.error = Some(error);
+        self.updated_at = Utc::now();
+    }
+
+    /// Forgets `phase` and every phase after it so they run again.
+    pub fn reset_from(&mut self, phase: InstallerPhase) {
+        let start = InstallerPhase::ALL.iter().position(|p| *p == phase).unwrap_or(0);
+        self.completed.retain(|p| !InstallerPhase::ALL[start..].contains(p));
+        self.failed = None;
+        self.error = None;
+    }
+}
+
+/// Files a successful install must have produced, relative to the instance root.
+pub fn expected_artifacts(loader: &str, mc_version: &str, loader_version: &str, modern: bool) -> Vec<PathBuf> {
+    let run_script = if cfg!(windows) { "run.bat" } else { "run.sh" };
+    let args_file = server_argfile_name();
+    match loader.to_lowercase().as_str() {
+        "neoforge" => vec![
+            PathBuf::from(run_script),
+            Path::new("libraries/net/neoforged/neoforge").join(loader_version).join(args_file),
+        ],
+        "forge" if modern => vec![
+            PathBuf::from(run_script),
+            Path::new("libraries/net/minecraftforge/forge")
+                .join(format!("{}-{}", mc_version, loader_version))
+                .join(args_file),
+        ],
+        _ => vec![PathBuf::from("server.jar")],
+    }
+}
+
+fn broken_libraries(instance_path: &Path) -> Vec<PathBuf> {
+    WalkDir::new(instance_path.join("libraries"))
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "jar"))
+        .filter(|e| !is_jar_valid(e.path()))
+        .map(|e| e.into_path())
+        .collect()
+}
+
+/// Lists what is missing or corrupt after an install: expected files that don't exist
+/// and library jars that can't be opened. Empty means the install looks complete.
+pub fn verify_installer_output(instance_path: &Path, expected: &[PathBuf]) -> Vec<String> {
+    let mut problems: Vec<String> = expected
+        .iter()
+        .filter(|rel| {
+            let path = instance_path.join(rel);
+            match path.extension() {
+                Some(ext) if ext == "jar" => !is_jar_valid(&path),
+                _ => !path.exists(),
+            }
+        })
+        .map(|rel| format!("Missing {}", rel.display()))
+        .collect();
+    for path in broken_libraries(instance_path) {
+        let rel = path.strip_prefix(instance_path).unwrap_or(&path);
+        problems.push(format!("Corrupt library {}", rel.display()));
+    }
+    problems
+}
+
+/// Deletes library jars an interrupted installer left half-written, so the next run
+/// downloads them again instead of failing on them. Returns how many were removed.
+pub fn cleanup_partial_output(instance_path: &Path) -> usize {
+    broken_libraries(instance_path)
+        .into_iter()
+        .filter(|path| std::fs::remove_file(path).is_ok())
+        .count()
+}
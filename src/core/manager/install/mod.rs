@@ -1,57 +1,149 @@
 use super::ServerManager;
 use crate::server::ServerHandle;
-use anyhow::{Result, anyhow};
+use anyhow::{Context, Result, anyhow};
+use std::path::{Path, PathBuf};
 use std::process::Stdio;
 use std::sync::Arc;
-use tokio::io::AsyncBufReadExt;
+use tokio::process::Child;
+use tokio::sync::mpsc;
 use tracing::info;
 
+pub mod buildtools;
 pub mod fabric;
 pub mod forge;
+pub mod installer_state;
 pub mod neoforge;
+pub mod output;
+pub mod phases;
+pub mod pipeline;
+pub mod provision;
 pub mod quilt;
+pub mod steps;
+
+use output::{
+    InstallerStream, InstallerTranscript, collect_installer_output, describe_exit,
+    spawn_line_reader,
+};
+
+/// Outcome of a single installer invocation.
+pub(crate) struct InstallerRun {
+    pub status: std::process::ExitStatus,
+    pub transcript: InstallerTranscript,
+    pub log_path: PathBuf,
+}
+
+impl InstallerRun {
+    pub fn into_error(self, loader_name: &str) -> anyhow::Error {
+        anyhow!(
+            "{} installer failed with {}. Last output:\n{}\nFull installer log: {}",
+            loader_name,
+            describe_exit(&self.status),
+            self.transcript.tail.join("\n"),
+            self.log_path.display()
+        )
+    }
+}
 
 impl ServerManager {
+    /// Runs a loader installer, streaming its combined output to the console and
+    /// to a timestamped transcript stored in the instance's installer log directory.
     pub(crate) async fn run_installer_command(
         &self,
-        mut cmd: tokio::process::Command,
+        cmd: tokio::process::Command,
         server: Arc<ServerHandle>,
         loader_name: &str,
+        instance_path: &Path,
     ) -> Result<()> {
+        let run = self
+            .execute_installer(cmd, server, loader_name, instance_path)
+            .await?;
+        if !run.status.success() {
+            return Err(run.into_error(loader_name));
+        }
+        Ok(())
+    }
+
+    /// Runs an installer to completion and reports how it exited without treating
+    /// a non-zero exit as an error, so callers can decide whether to retry.
+    pub(crate) async fn execute_installer(
+        &self,
+        mut cmd: tokio::process::Command,
+        server: Arc<ServerHandle>,
+        loader_name: &str,
+        instance_path: &Path,
+    ) -> Result<InstallerRun> {
         let msg = format!("Running {} installer...", loader_name);
         info!("{}", msg);
         server.emit_log(msg);
 
-        let mut child = cmd.stdout(Stdio::piped()).stderr(Stdio::piped()).spawn()?;
+        let log_path = output::installer_log_path(instance_path, loader_name);
 
-        let stdout = child.stdout.take().unwrap();
-        let stderr = child.stderr.take().unwrap();
+        let mut child = cmd
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            // Covers this future being dropped mid-install
+            .kill_on_drop(true)
+            .spawn()
+            .context(format!("Failed to spawn {} installer", loader_name))?;
 
-        let server_clone = Arc::clone(&server);
-        tokio::spawn(async move {
-            let mut reader = tokio::io::BufReader::new(stdout).lines();
-            while let Ok(Some(line)) = reader.next_line().await {
-                server_clone.emit_log(line);
+        let run = match capture_output(&mut child, &server, &log_path).await {
+            Ok(transcript) => child
+                .wait()
+                .await
+                .map(|status| (status, transcript))
+                .context("Failed to wait for the installer"),
+            Err(e) => Err(e),
+        };
+        let (status, transcript) = match run {
+            Ok(run) => run,
+            Err(e) => {
+                // Don't leave the installer running once its output can't be recorded
+                let _ = child.kill().await;
+                return Err(e);
             }
-        });
-
-        let server_clone_err = Arc::clone(&server);
-        tokio::spawn(async move {
-            let mut reader = tokio::io::BufReader::new(stderr).lines();
-            while let Ok(Some(line)) = reader.next_line().await {
-                server_clone_err.emit_log(format!("ERROR: {}", line));
-            }
-        });
-
-        let status = child.wait().await?;
-        if !status.success() {
-            return Err(anyhow!(
-                "{} installer failed with status: {}",
+        };
+        if status.success() {
+            info!("{} installer log saved to {:?}", loader_name, log_path);
+        } else {
+            server.emit_log(format!(
+                "{} installer failed ({}). Full output saved to {}",
                 loader_name,
-                status
+                describe_exit(&status),
+                log_path.display()
             ));
         }
 
-        Ok(())
+        Ok(InstallerRun {
+            status,
+            transcript,
+            log_path,
+        })
     }
 }
+
+/// Records the installer's output to `log_path` and the server console until it closes.
+async fn capture_output(
+    child: &mut Child,
+    server: &Arc<ServerHandle>,
+    log_path: &Path,
+) -> Result<InstallerTranscript> {
+    let stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| anyhow!("Failed to capture installer stdout"))?;
+    let stderr = child
+        .stderr
+        .take()
+        .ok_or_else(|| anyhow!("Failed to capture installer stderr"))?;
+
+    let (tx, rx) = mpsc::unbounded_channel();
+    spawn_line_reader(stdout, InstallerStream::Stdout, tx.clone());
+    spawn_line_reader(stderr, InstallerStream::Stderr, tx);
+
+    let server = Arc::clone(server);
+    collect_installer_output(rx, log_path, move |entry| match entry.stream {
+        InstallerStream::Stdout => server.emit_log(entry.line.clone()),
+        InstallerStream::Stderr => server.emit_log(format!("ERROR: {}", entry.line)),
+    })
+    .await
+}
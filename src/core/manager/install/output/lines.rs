@@ -0,0 +1,64 @@
+use chrono::Utc;
+
+/// Output fragments (lowercased) that indicate an installer failed to fetch a file
+/// rather than failing for a reason a retry cannot fix.
+const DOWNLOAD_FAILURE_PATTERNS: [&str; 9] = [
+    "failed to download",
+    "these libraries failed to download",
+    "unable to download",
+    "download failed",
+    "sockettimeoutexception",
+    "connectexception",
+    "unknownhostexception",
+    "connection reset",
+    "failed to validate checksum",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InstallerStream {
+    Stdout,
+    Stderr,
+}
+
+#[derive(Debug, Clone)]
+pub struct InstallerLine {
+    pub stream: InstallerStream,
+    pub timestamp: chrono::DateTime<Utc>,
+    pub line: String,
+}
+
+impl InstallerLine {
+    pub(super) fn format_for_log(&self) -> String {
+        let tag = match self.stream {
+            InstallerStream::Stdout => "OUT",
+            InstallerStream::Stderr => "ERR",
+        };
+        format!(
+            "[{}] [{}] {}",
+            self.timestamp.format("%H:%M:%S%.3f"),
+            tag,
+            self.line
+        )
+    }
+}
+
+/// Returns true if an installer output line reports a failed download.
+pub fn is_download_failure(line: &str) -> bool {
+    let lower = line.to_lowercase();
+    DOWNLOAD_FAILURE_PATTERNS.iter().any(|p| lower.contains(p))
+}
+
+/// Describes why an installer process exited, including the signal on Unix.
+pub fn describe_exit(status: &std::process::ExitStatus) -> String {
+    if let Some(code) = status.code() {
+        return format!("exit code {}", code);
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        if let Some(signal) = status.signal() {
+            return format!("terminated by signal {}", signal);
+        }
+    }
+    status.to_string()
+}
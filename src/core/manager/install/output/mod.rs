@@ -0,0 +1,158 @@
+use anyhow::{Context, Result};
+use chrono::Utc;
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWriteExt, BufReader};
+use tokio::sync::mpsc;
+
+mod lines;
+
+pub use lines::{InstallerLine, InstallerStream, describe_exit, is_download_failure};
+
+/// Directory (relative to the instance root) where installer transcripts are kept.
+pub const INSTALLER_LOG_DIR: &str = "installer_logs";
+
+/// Number of trailing lines included in the error message when an installer fails.
+const FAILURE_TAIL_LINES: usize = 15;
+
+/// Summary of an installer's output once both streams have closed.
+#[derive(Debug, Clone, Default)]
+pub struct InstallerTranscript {
+    pub tail: Vec<String>,
+    pub download_failure: bool,
+}
+
+/// Builds the path of a fresh installer transcript for the given loader.
+pub fn installer_log_path(instance_path: &Path, loader_name: &str) -> PathBuf {
+    instance_path.join(INSTALLER_LOG_DIR).join(format!(
+        "{}_{}.log",
+        loader_name.to_lowercase(),
+        Utc::now().format("%Y-%m-%d_%H-%M-%S")
+    ))
+}
+
+/// Forwards every line of `reader` into `tx`, tagged with its stream and arrival time.
+pub(crate) fn spawn_line_reader<R>(
+    reader: R,
+    stream: InstallerStream,
+    tx: mpsc::UnboundedSender<InstallerLine>,
+) -> tokio::task::JoinHandle<()>
+where
+    R: AsyncRead + Unpin + Send + 'static,
+{
+    tokio::spawn(async move {
+        let mut lines = BufReader::new(reader).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            let entry = InstallerLine {
+                stream,
+                timestamp: Utc::now(),
+                line,
+            };
+            if tx.send(entry).is_err() {
+                break;
+            }
+        }
+    })
+}
+
+/// Drains installer output in arrival order until both streams reach end-of-stream.
+///
+/// Every line is written to the transcript at `log_path` and passed to `on_line`.
+/// The returned transcript keeps the last few lines so they can be surfaced on failure.
+pub(crate) async fn collect_installer_output<F>(
+    mut rx: mpsc::UnboundedReceiver<InstallerLine>,
+    log_path: &Path,
+    on_line: F,
+) -> Result<InstallerTranscript>
+where
+    F: Fn(&InstallerLine),
+{
+    if let Some(parent) = log_path.parent() {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .context("Failed to create installer log directory")?;
+    }
+    let mut file = tokio::fs::File::create(log_path)
+        .await
+        .context(format!("Failed to create installer log: {:?}", log_path))?;
+
+    let mut tail = VecDeque::with_capacity(FAILURE_TAIL_LINES);
+    let mut download_failure = false;
+    // The channel closes once every reader has hit EOF and dropped its sender
+    while let Some(entry) = rx.recv().await {
+        let formatted = entry.format_for_log();
+        file.write_all(formatted.as_bytes()).await?;
+        file.write_all(b"\n").await?;
+        on_line(&entry);
+        download_failure |= is_download_failure(&entry.line);
+
+        if tail.len() == FAILURE_TAIL_LINES {
+            tail.pop_front();
+        }
+        tail.push_back(formatted);
+    }
+    file.flush().await?;
+
+    Ok(InstallerTranscript {
+        tail: tail.into_iter().collect(),
+        download_failure,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[tokio::test]
+    async fn test_collect_installer_output_preserves_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let log_path = installer_log_path(dir.path(), "Forge");
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        for (i, stream) in [
+            InstallerStream::Stdout,
+            InstallerStream::Stderr,
+            InstallerStream::Stdout,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            tx.send(InstallerLine {
+                stream,
+                timestamp: Utc::now(),
+                line: format!("line {}", i),
+            })
+            .unwrap();
+        }
+        drop(tx);
+
+        let seen = Mutex::new(Vec::new());
+        let transcript = collect_installer_output(rx, &log_path, |entry| {
+            seen.lock().unwrap().push(entry.line.clone());
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(*seen.lock().unwrap(), vec!["line 0", "line 1", "line 2"]);
+        assert_eq!(transcript.tail.len(), 3);
+        assert!(transcript.tail[1].contains("[ERR] line 1"));
+        assert!(!transcript.download_failure);
+
+        let content = std::fs::read_to_string(&log_path).unwrap();
+        assert_eq!(content.lines().count(), 3);
+        assert!(log_path.starts_with(dir.path().join(INSTALLER_LOG_DIR)));
+    }
+
+    #[test]
+    fn test_is_download_failure() {
+        assert!(is_download_failure(
+            "These libraries failed to download. Try again."
+        ));
+        assert!(is_download_failure(
+            "java.net.SocketTimeoutException: Read timed out"
+        ));
+        assert!(!is_download_failure("Extracting json"));
+        assert!(!is_download_failure("The server installed successfully"));
+    }
+}
@@ -0,0 +1,184 @@
+use super::ServerManager;
+use super::installer_state::{
+    InstallerPhase, InstallerState, cleanup_partial_output, expected_artifacts, verify_installer_output,
+};
+use super::pipeline::{ProvisionState, ProvisionStep, StepStatus};
+use crate::errors::AppError;
+use crate::instance::InstanceMetadata;
+use crate::server::{ServerHandle, ServerStatus};
+use crate::tasks::TaskKind;
+use crate::utils::fs::is_jar_valid;
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// What [`ServerManager::repair_installation`] found and did.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstallerRepair {
+    pub loader: String,
+    /// Missing or corrupt files found before repairing.
+    pub problems: Vec<String>,
+    /// Phase the install was resumed from; `None` when nothing needed repairing.
+    pub rerun_from: Option<InstallerPhase>,
+    /// Broken library jars deleted before re-running the installer.
+    pub removed_files: usize,
+}
+
+fn installer_loader(instance: &InstanceMetadata) -> Result<(String, String)> {
+    let loader = instance.mod_loader.as_deref().unwrap_or_default().to_lowercase();
+    if loader != "forge" && loader != "neoforge" {
+        return Err(anyhow!("Only Forge and NeoForge installations can be repaired"));
+    }
+    let loader_version = instance
+        .loader_version
+        .clone()
+        .ok_or_else(|| anyhow!("{} requires a loader version", loader))?;
+    Ok((loader, loader_version))
+}
+
+fn installer_jar(instance: &InstanceMetadata, loader: &str) -> PathBuf {
+    instance.path.join(format!("{}-installer.jar", loader))
+}
+
+impl ServerManager {
+    pub(crate) fn expected_installer_artifacts(&self, instance: &InstanceMetadata, loader: &str, loader_version: &str) -> Vec<PathBuf> {
+        let modern = self.mod_loader_client.is_modern_forge(&instance.version);
+        expected_artifacts(loader, &instance.version, loader_version, modern)
+    }
+
+    /// Installs Forge or NeoForge one phase at a time, recording each phase in the
+    /// installer marker so a failed install resumes at the phase that failed.
+    pub(crate) async fn run_installer_phases(
+        &self,
+        server: Arc<ServerHandle>,
+        instance: &InstanceMetadata,
+    ) -> Result<()> {
+        let (loader, loader_version) = installer_loader(instance)?;
+        let installer_path = installer_jar(instance, &loader);
+        let mut state = InstallerState::load_or_new(&instance.path, &loader, &instance.version, &loader_version).await?;
+        if state.next_phase() == Some(InstallerPhase::RunInstaller) && !is_jar_valid(&installer_path) {
+            state.reset_from(InstallerPhase::DownloadInstaller);
+        }
+
+        while let Some(phase) = state.next_phase() {
+            let result = match phase {
+                InstallerPhase::DownloadInstaller if loader == "neoforge" => {
+                    self.download_neoforge_installer(Arc::clone(&server), instance, &installer_path).await
+                }
+                InstallerPhase::DownloadInstaller => {
+                    self.download_forge_installer(Arc::clone(&server), instance, &installer_path).await
+                }
+                InstallerPhase::RunInstaller if loader == "neoforge" => {
+                    self.run_neoforge_installer(Arc::clone(&server), instance, &installer_path).await
+                }
+                InstallerPhase::RunInstaller => {
+                    self.run_forge_installer(Arc::clone(&server), instance, &installer_path).await
+                }
+                InstallerPhase::VerifyOutput => {
+                    let expected = self.expected_installer_artifacts(instance, &loader, &loader_version);
+                    let problems = verify_installer_output(&instance.path, &expected);
+                    if problems.is_empty() {
+                        Ok(())
+                    } else {
+                        Err(anyhow!("Installation is incomplete:\n{}", problems.join("\n")))
+                    }
+                }
+            };
+
+            if let Err(e) = result {
+                if phase != InstallerPhase::DownloadInstaller {
+                    let removed = cleanup_partial_output(&instance.path);
+                    if removed > 0 {
+                        server.emit_log(format!("Removed {} partially downloaded libraries", removed));
+                    }
+                }
+                state.mark_failed(phase, e.to_string());
+                if let Err(save_err) = state.save(&instance.path).await {
+                    warn!("Failed to persist installer state: {}", save_err);
+                }
+                return Err(e);
+            }
+            state.mark_completed(phase);
+            state.save(&instance.path).await?;
+        }
+
+        let _ = tokio::fs::remove_file(&installer_path).await;
+        self.share_installed_libraries(instance.id, &instance.path).await;
+        Ok(())
+    }
+
+    /// Checks a Forge/NeoForge install for missing or corrupt files and re-runs the
+    /// installer from the phase that failed, cleaning up broken libraries first.
+    pub async fn repair_installation(&self, instance_id: Uuid) -> Result<InstallerRepair> {
+        let instance = self
+            .instance_manager
+            .get_instance(instance_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
+        let (loader, loader_version) = installer_loader(&instance)?;
+        if !matches!(self.get_server_status(instance_id).await, ServerStatus::Stopped | ServerStatus::Crashed) {
+            return Err(AppError::Conflict("Stop the server before repairing it".to_string()).into());
+        }
+
+        let expected = self.expected_installer_artifacts(&instance, &loader, &loader_version);
+        let problems = verify_installer_output(&instance.path, &expected);
+        let saved = InstallerState::load(&instance.path).await?;
+        let rerun_from = match saved.as_ref().and_then(|s| s.failed) {
+            Some(InstallerPhase::VerifyOutput) => Some(InstallerPhase::RunInstaller),
+            Some(phase) => Some(phase),
+            None if !problems.is_empty() => Some(InstallerPhase::DownloadInstaller),
+            None => None,
+        };
+        let Some(rerun_from) = rerun_from else {
+            return Ok(InstallerRepair { loader, problems, rerun_from: None, removed_files: 0 });
+        };
+
+        let removed_files = cleanup_partial_output(&instance.path);
+        let mut state = saved.unwrap_or_else(|| InstallerState::new(&loader, &instance.version, &loader_version));
+        state.reset_from(rerun_from);
+        state.save(&instance.path).await?;
+        info!("Repairing {} install of {} from phase {:?}", loader, instance.name, rerun_from);
+
+        let server = self.get_or_create_server(instance_id).await?;
+        server.set_status(ServerStatus::Installing).await;
+        server.emit_log(format!("Repairing installation: {}...", rerun_from.label()));
+        let result = self
+            .run_tracked(
+                &server,
+                TaskKind::Install,
+                format!("Repairing {}", instance.name),
+                instance_id,
+                self.finish_repair(Arc::clone(&server), &instance),
+            )
+            .await;
+        server.set_status(ServerStatus::Stopped).await;
+        result?;
+
+        Ok(InstallerRepair { loader, problems, rerun_from: Some(rerun_from), removed_files })
+    }
+
+    /// Re-runs the installer phases, then lets an interrupted provisioning finish its
+    /// remaining steps (EULA, configs) so the server is launchable afterwards.
+    async fn finish_repair(&self, server: Arc<ServerHandle>, instance: &InstanceMetadata) -> Result<()> {
+        self.run_installer_phases(Arc::clone(&server), instance).await?;
+
+        if let Some(mut provision) = ProvisionState::load(&instance.path).await?.filter(|p| !p.is_complete()) {
+            if let Some(index) = provision.steps.iter().position(|s| s.step == ProvisionStep::RunInstaller) {
+                provision.set_status(index, StepStatus::Completed, None);
+                provision.save(&instance.path).await?;
+            }
+            self.provision_instance(Arc::clone(&server), instance).await?;
+        }
+
+        let instance = self
+            .instance_manager
+            .get_instance(instance.id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance.id)))?;
+        server.update_config(self.build_server_config(&instance).await).await;
+        Ok(())
+    }
+}
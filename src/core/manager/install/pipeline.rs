@@ -0,0 +1,177 @@
+use crate::instance::InstanceMetadata;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// File (relative to the instance root) holding the persisted provisioning state.
+pub const PROVISION_STATE_FILE: &str = ".provisioning.json";
+
+/// Loaders that are provisioned by running their own installer instead of
+/// downloading a ready-made server jar.
+const INSTALLER_LOADERS: [&str; 6] = ["fabric", "quilt", "forge", "neoforge", "spigot", "craftbukkit"];
+
+/// Loaders that are proxies and therefore have no `server.properties`.
+const PROXY_LOADERS: [&str; 2] = ["velocity", "bungeecord"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProvisionStep {
+    DownloadJar,
+    RunInstaller,
+    WriteEula,
+    WriteConfigs,
+    Finalize,
+}
+
+impl ProvisionStep {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ProvisionStep::DownloadJar => "Downloading server",
+            ProvisionStep::RunInstaller => "Running loader installer",
+            ProvisionStep::WriteEula => "Accepting EULA",
+            ProvisionStep::WriteConfigs => "Writing server configuration",
+            ProvisionStep::Finalize => "Finalizing installation",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StepRecord {
+    pub step: ProvisionStep,
+    pub status: StepStatus,
+    pub error: Option<String>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Progress of provisioning an instance, persisted so an interrupted or failed
+/// install resumes at the first unfinished step.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProvisionState {
+    pub server_type: Option<String>,
+    pub version: String,
+    pub loader_version: Option<String>,
+    pub steps: Vec<StepRecord>,
+}
+
+/// Returns the ordered steps needed to provision a server of the given type.
+///
+/// New server types only need an entry here and, if they use an installer,
+/// a branch in `ServerManager::run_installer_step`.
+pub fn plan_steps(mod_loader: Option<&str>) -> Vec<ProvisionStep> {
+    let loader = mod_loader.map(|l| l.to_lowercase()).unwrap_or_default();
+    let mut steps = Vec::new();
+
+    if INSTALLER_LOADERS.contains(&loader.as_str()) {
+        steps.push(ProvisionStep::RunInstaller);
+    } else {
+        steps.push(ProvisionStep::DownloadJar);
+    }
+    // Bedrock ships its own server.properties and has no EULA file
+    if loader != "bedrock" {
+        steps.push(ProvisionStep::WriteEula);
+        if !PROXY_LOADERS.contains(&loader.as_str()) {
+            steps.push(ProvisionStep::WriteConfigs);
+        }
+    }
+    steps.push(ProvisionStep::Finalize);
+    steps
+}
+
+/// Whether a server type installs from one downloaded file that the artifact store can
+/// hold, so it can be installed without a network. Installers fetch their own libraries
+/// and Bedrock is an extracted archive.
+pub fn installs_offline(mod_loader: Option<&str>) -> bool {
+    let loader = mod_loader.map(|l| l.to_lowercase()).unwrap_or_default();
+    !INSTALLER_LOADERS.contains(&loader.as_str()) && loader != "bedrock"
+}
+
+impl ProvisionState {
+    pub fn plan(instance: &InstanceMetadata) -> Self {
+        Self {
+            server_type: instance.mod_loader.as_ref().map(|l| l.to_lowercase()),
+            version: instance.version.clone(),
+            loader_version: instance.loader_version.clone(),
+            steps: plan_steps(instance.mod_loader.as_deref())
+                .into_iter()
+                .map(|step| StepRecord {
+                    step,
+                    status: StepStatus::Pending,
+                    error: None,
+                    updated_at: None,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn path(instance_path: &Path) -> PathBuf {
+        instance_path.join(PROVISION_STATE_FILE)
+    }
+
+    pub async fn load(instance_path: &Path) -> Result<Option<Self>> {
+        let path = Self::path(instance_path);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = tokio::fs::read_to_string(&path)
+            .await
+            .context("Failed to read provisioning state")?;
+        Ok(serde_json::from_str(&content).ok())
+    }
+
+    /// Loads the saved state if it belongs to an unfinished install of the same
+    /// target, otherwise starts a fresh plan.
+    pub async fn load_or_plan(instance: &InstanceMetadata) -> Result<Self> {
+        let fresh = Self::plan(instance);
+        let resumable = Self::load(&instance.path).await?.filter(|saved| {
+            !saved.is_complete()
+                && saved.server_type == fresh.server_type
+                && saved.version == fresh.version
+                && saved.loader_version == fresh.loader_version
+        });
+        Ok(resumable.unwrap_or(fresh))
+    }
+
+    pub async fn save(&self, instance_path: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        tokio::fs::write(Self::path(instance_path), content)
+            .await
+            .context("Failed to save provisioning state")
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.steps.iter().all(|s| s.status == StepStatus::Completed)
+    }
+
+    /// Index of the first step that still has to run.
+    pub fn next_pending(&self) -> Option<usize> {
+        self.steps
+            .iter()
+            .position(|s| s.status != StepStatus::Completed)
+    }
+
+    /// Marks every step before `index` as pending again.
+    pub fn reset_before(&mut self, index: usize) {
+        for record in self.steps.iter_mut().take(index) {
+            record.status = StepStatus::Pending;
+            record.error = None;
+        }
+    }
+
+    pub fn set_status(&mut self, index: usize, status: StepStatus, error: Option<String>) {
+        if let Some(record) = self.steps.get_mut(index) {
+            record.status = status;
+            record.error = error;
+            record.updated_at = Some(Utc::now());
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use super::ServerManager;
+use super::pipeline::{ProvisionState, ProvisionStep, StepStatus};
+use crate::errors::AppError;
+use crate::instance::InstanceMetadata;
+use crate::server::ServerHandle;
+use anyhow::Result;
+use std::sync::Arc;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+impl ServerManager {
+    /// Returns the persisted provisioning progress of an instance, if it was ever provisioned.
+    pub async fn get_provisioning_state(&self, instance_id: Uuid) -> Result<Option<ProvisionState>> {
+        let instance = self
+            .instance_manager
+            .get_instance(instance_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
+        ProvisionState::load(&instance.path).await
+    }
+
+    /// Runs every unfinished provisioning step for an instance in order, persisting
+    /// the status of each step so a failed install resumes where it stopped.
+    pub(crate) async fn provision_instance(
+        &self,
+        server: Arc<ServerHandle>,
+        instance: &InstanceMetadata,
+    ) -> Result<()> {
+        let mut state = ProvisionState::load_or_plan(instance).await?;
+        let total = state.steps.len();
+
+        if let Some(index) = state.next_pending().filter(|i| *i > 0) {
+            let msg = format!(
+                "Resuming installation at step {}/{}: {}",
+                index + 1,
+                total,
+                state.steps[index].step.label()
+            );
+            info!("{}", msg);
+            server.emit_log(msg);
+        }
+
+        while let Some(index) = state.next_pending() {
+            let step = state.steps[index].step;
+            server.emit_log(format!("[{}/{}] {}...", index + 1, total, step.label()));
+            server.emit_progress(index as u64, total as u64, step.label().to_string());
+
+            state.set_status(index, StepStatus::Running, None);
+            state.save(&instance.path).await?;
+
+            if let Err(e) = self.run_provision_step(step, &server, instance).await {
+                if step == ProvisionStep::Finalize {
+                    // The earlier steps did not produce a launchable server, so redo them next time
+                    state.reset_before(index);
+                }
+                state.set_status(index, StepStatus::Failed, Some(e.to_string()));
+                if let Err(save_err) = state.save(&instance.path).await {
+                    warn!("Failed to persist provisioning state: {}", save_err);
+                }
+                return Err(e);
+            }
+
+            state.set_status(index, StepStatus::Completed, None);
+            state.save(&instance.path).await?;
+        }
+
+        server.emit_progress(total as u64, total as u64, "Installation complete".to_string());
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 use super::ServerManager;
 use crate::instance::InstanceMetadata;
+use crate::mod_loaders::quilt::{QUILT_SERVER_JAR, find_quilt_launch_jar, quilt_installer_args};
 use crate::server::ServerHandle;
 use anyhow::{Result, anyhow};
 use std::sync::Arc;
@@ -47,56 +48,22 @@ impl ServerManager {
         cmd.current_dir(&instance.path)
             .arg("-jar")
             .arg(&installer_path)
-            .arg("install")
-            .arg("server")
-            .arg(&instance.version);
+            .args(quilt_installer_args(&instance.version, instance.loader_version.as_deref()));
 
-        if let Some(loader_ver) = &instance.loader_version {
-            cmd.arg(loader_ver);
-        }
-
-        cmd.arg("--download-server");
-        cmd.arg("--install-dir=.");
-
-        self.run_installer_command(cmd, Arc::clone(&server), "Quilt")
+        self.run_installer_command(cmd, Arc::clone(&server), "Quilt", &instance.path)
             .await?;
         let _ = tokio::fs::remove_file(installer_path).await;
 
-        // Quilt installer creates a 'quilt-server-launch.jar' which requires 'server.jar' and 'libraries' folder
-        // We should rename 'quilt-server-launch.jar' to 'quilt-server.jar' to match our config detection
-        let mut loader_jar = None;
-        if let Ok(mut entries) = tokio::fs::read_dir(&instance.path).await {
-            while let Ok(Some(entry)) = entries.next_entry().await {
-                let name = entry.file_name().to_string_lossy().to_lowercase();
-                if name.contains("quilt-server-launch") && name.ends_with(".jar") {
-                    loader_jar = Some(entry.path());
-                    break;
-                }
-            }
-        }
-
-        if let Some(jar_path) = loader_jar {
-            let target_path = instance.path.join("quilt-server.jar");
-            server.emit_log(format!(
-                "Renaming {} to quilt-server.jar",
-                jar_path.file_name().unwrap().to_string_lossy()
-            ));
-            tokio::fs::rename(jar_path, target_path).await?;
-        } else {
-            server.emit_log("Warning: Could not find quilt-server-launch.jar. Checking for other loader jars...".to_string());
-            // Fallback: check for any quilt-loader jar
-            if let Ok(mut entries) = tokio::fs::read_dir(&instance.path).await {
-                while let Ok(Some(entry)) = entries.next_entry().await {
-                    let name = entry.file_name().to_string_lossy().to_lowercase();
-                    if name.contains("quilt-loader") && name.ends_with(".jar") {
-                        let target_path = instance.path.join("quilt-server.jar");
-                        server.emit_log(format!("Found {}, renaming to quilt-server.jar", name));
-                        tokio::fs::rename(entry.path(), target_path).await?;
-                        break;
-                    }
-                }
-            }
-        }
+        // The launch jar needs the vanilla server.jar and libraries next to it; it is
+        // renamed to quilt-server.jar to match config detection
+        let launch_jar = find_quilt_launch_jar(&instance.path)
+            .ok_or_else(|| anyhow!("Quilt installer did not create a server launch jar"))?;
+        server.emit_log(format!(
+            "Renaming {} to {}",
+            launch_jar.file_name().unwrap_or_default().to_string_lossy(),
+            QUILT_SERVER_JAR
+        ));
+        tokio::fs::rename(launch_jar, instance.path.join(QUILT_SERVER_JAR)).await?;
 
         Ok(())
     }
@@ -0,0 +1,157 @@
+use super::ServerManager;
+use super::pipeline::ProvisionStep;
+use crate::errors::AppError;
+use crate::instance::InstanceMetadata;
+use crate::server::ServerHandle;
+use crate::server_properties::{read_server_properties, write_server_properties};
+use anyhow::{Result, anyhow};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use tracing::info;
+
+impl ServerManager {
+    pub(super) async fn run_provision_step(
+        &self,
+        step: ProvisionStep,
+        server: &Arc<ServerHandle>,
+        instance: &InstanceMetadata,
+    ) -> Result<()> {
+        match step {
+            ProvisionStep::DownloadJar => self.download_server_jar(Arc::clone(server), instance).await,
+            ProvisionStep::RunInstaller => self.run_installer_step(Arc::clone(server), instance).await,
+            ProvisionStep::WriteEula => {
+                let eula_path = instance.path.join("eula.txt");
+                if !eula_path.exists() {
+                    tokio::fs::write(eula_path, "eula=true").await?;
+                }
+                Ok(())
+            }
+            ProvisionStep::WriteConfigs => {
+                let mut props = read_server_properties(&instance.path).await?;
+                let port = instance.settings.port.to_string();
+                if props.get("server-port") != Some(&port) {
+                    props.insert("server-port".to_string(), port);
+                    write_server_properties(&instance.path, &props).await?;
+                }
+                Ok(())
+            }
+            ProvisionStep::Finalize => {
+                // Installers may have renamed or created the launch target, so re-read it
+                let instance = self
+                    .instance_manager
+                    .get_instance(instance.id)
+                    .await?
+                    .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance.id)))?;
+                let config = self.build_server_config(&instance).await;
+                let launchable = match (&config.jar_path, &config.run_script) {
+                    (Some(jar), _) => jar.exists(),
+                    (None, Some(script)) => instance.path.join(script).exists(),
+                    (None, None) => false,
+                };
+                if !launchable {
+                    return Err(anyhow!(
+                        "Installation finished but no server jar or run script was found"
+                    ));
+                }
+                server.update_config(config).await;
+                Ok(())
+            }
+        }
+    }
+
+    async fn run_installer_step(
+        &self,
+        server: Arc<ServerHandle>,
+        instance: &InstanceMetadata,
+    ) -> Result<()> {
+        let loader = instance.mod_loader.as_deref().unwrap_or_default().to_lowercase();
+        match loader.as_str() {
+            "fabric" => self.install_fabric(server, instance).await,
+            "quilt" => self.install_quilt(server, instance).await,
+            "forge" | "neoforge" => self.run_installer_phases(server, instance).await,
+            "spigot" | "craftbukkit" => self.install_buildtools(server, instance).await,
+            other => Err(anyhow!("No installer is available for {}", other)),
+        }
+    }
+
+    async fn download_server_jar(
+        &self,
+        server: Arc<ServerHandle>,
+        instance: &InstanceMetadata,
+    ) -> Result<()> {
+        let jar_path = self
+            .build_server_config(instance)
+            .await
+            .jar_path
+            .unwrap_or_else(|| instance.path.join("server.jar"));
+
+        let display_name = match instance.mod_loader.as_deref().map(|l| l.to_lowercase()) {
+            None => "vanilla server".to_string(),
+            Some(l) if l == "paper" => "Paper".to_string(),
+            Some(l) if l == "purpur" => "Purpur".to_string(),
+            Some(l) if l == "folia" => "Folia".to_string(),
+            Some(l) if l == "pufferfish" => "Pufferfish".to_string(),
+            Some(l) if l == "velocity" => "Velocity".to_string(),
+            Some(l) if l == "bungeecord" => "BungeeCord".to_string(),
+            Some(l) if l == "bedrock" => "Bedrock".to_string(),
+            Some(_) => instance.mod_loader.clone().unwrap_or_default(),
+        };
+
+        let msg = format!(
+            "Starting download of {} for version {}",
+            display_name, instance.version
+        );
+        info!("{}", msg);
+        server.emit_log(msg);
+
+        if self.provision_stored_jar(instance, &jar_path).await? {
+            server.emit_log("Installed from the local artifact store".to_string());
+            return Ok(());
+        }
+
+        let server_clone = Arc::clone(&server);
+        let last_percent = Arc::new(AtomicU32::new(0));
+        let final_size = Arc::new(AtomicU64::new(0));
+        let final_size_clone = Arc::clone(&final_size);
+        let message = format!("Downloading {}...", display_name);
+        let on_progress = move |current, total| {
+            final_size_clone.store(current, Ordering::Relaxed);
+            server_clone.handle_download_progress(current, total, &message, &last_percent);
+        };
+
+        match &instance.mod_loader {
+            Some(loader) => {
+                self.mod_loader_client
+                    .download_loader(
+                        loader,
+                        &instance.version,
+                        instance.loader_version.as_deref(),
+                        &jar_path,
+                        on_progress,
+                    )
+                    .await?
+            }
+            None => {
+                self.downloader
+                    .download_server(&instance.version, &jar_path, on_progress)
+                    .await?
+            }
+        }
+
+        self.remember_server_jar(instance, &jar_path).await;
+
+        let size_mb = final_size.load(Ordering::Relaxed) / (1024 * 1024);
+        server.emit_log(format!("Final size: {} MB", size_mb));
+
+        let loader = instance.mod_loader.as_deref().map(|l| l.to_lowercase());
+        match loader.as_deref() {
+            Some("bedrock") => server.emit_log("Extracting Bedrock server...".to_string()),
+            Some("paper") | Some("folia") | Some("velocity") => {
+                server.emit_log("Verifying checksum...".to_string())
+            }
+            _ => {}
+        }
+        server.emit_log("Download complete!".to_string());
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use super::super::ServerManager;
+use crate::server::PidFile;
+use anyhow::Result;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+impl ServerManager {
+    /// Reattaches to server processes left running by a previous session, using the
+    /// PID file each server writes into its instance directory. Stale PID files are removed.
+    pub async fn adopt_running_servers(&self) -> Result<Vec<Uuid>> {
+        let mut adopted = Vec::new();
+        for instance in self.instance_manager.list_instances().await? {
+            let Some(pid_file) = PidFile::read(&instance.path).await else {
+                continue;
+            };
+
+            if !pid_file.is_alive() {
+                PidFile::remove(&instance.path).await;
+                continue;
+            }
+
+            match self.get_or_create_server(instance.id).await {
+                Ok(server) => {
+                    server.attach(pid_file.pid).await;
+                    self.spawn_run_watchers(instance.id, server).await;
+                    info!(
+                        "Adopted running server {} (PID {})",
+                        instance.name, pid_file.pid
+                    );
+                    adopted.push(instance.id);
+                }
+                Err(e) => warn!("Failed to adopt server {}: {}", instance.name, e),
+            }
+        }
+        Ok(adopted)
+    }
+
+    /// Running servers that should be stopped when the app exits, i.e. all running
+    /// servers except those configured to keep running after the app closes.
+    pub async fn servers_to_stop_on_exit(&self) -> Result<Vec<Uuid>> {
+        let mut to_stop = Vec::new();
+        for id in self.running_server_ids().await {
+            let keep_running = self
+                .instance_manager
+                .get_instance(id)
+                .await?
+                .map(|i| i.settings.keep_running_on_close)
+                .unwrap_or(false);
+            if !keep_running {
+                to_stop.push(id);
+            }
+        }
+        Ok(to_stop)
+    }
+}
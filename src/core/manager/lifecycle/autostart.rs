@@ -0,0 +1,32 @@
+use super::super::ServerManager;
+use anyhow::Result;
+use std::time::Duration;
+use tracing::info;
+use uuid::Uuid;
+
+/// Delay between autostarted servers that don't depend on each other.
+const AUTOSTART_STAGGER: Duration = Duration::from_secs(5);
+
+impl ServerManager {
+    /// IDs of the instances flagged to start when the app launches.
+    pub async fn autostart_instance_ids(&self) -> Result<Vec<Uuid>> {
+        let instances = self.instance_manager.list_instances().await?;
+        Ok(instances
+            .into_iter()
+            .filter(|i| i.settings.autostart)
+            .map(|i| i.id)
+            .collect())
+    }
+
+    /// Starts every autostart instance (and whatever they depend on) in dependency
+    /// order, staggered by [`AUTOSTART_STAGGER`]. Servers that are already running,
+    /// e.g. ones adopted from a previous session, are left alone.
+    pub async fn autostart_servers(&self) -> Result<Vec<Uuid>> {
+        let ids = self.autostart_instance_ids().await?;
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        info!("Autostarting {} server(s)", ids.len());
+        self.start_servers_staggered(&ids, AUTOSTART_STAGGER).await
+    }
+}
@@ -1,6 +1,8 @@
 use super::super::ServerManager;
 use crate::config::ServerConfig;
-use crate::instance::{InstanceMetadata, LaunchMethod};
+use crate::instance::{InstanceMetadata, LaunchMethod, jvm_memory_unit};
+use crate::mod_loaders::find_server_argfile;
+use tracing::warn;
 
 impl ServerManager {
     pub(crate) async fn build_server_config(&self, instance: &InstanceMetadata) -> ServerConfig {
@@ -23,6 +25,7 @@ impl ServerManager {
             Some(jar_path)
         };
         let mut final_run_script = None;
+        let mut custom_command = None;
         let mut args = vec!["nogui".to_string()];
 
         let loader_lower = instance.mod_loader.as_deref().map(|l| l.to_lowercase());
@@ -42,9 +45,24 @@ impl ServerManager {
             final_jar_path = Some(instance.path.join("quilt-server.jar"));
         }
 
-        // Check for run scripts (modern Forge/NeoForge)
+        // Modern Forge/NeoForge: launch with the installer's argfile, falling back to
+        // its run script
+        let mut argfile = None;
+        if matches!(
+            loader_lower.as_deref(),
+            None | Some("forge") | Some("neoforge")
+        ) {
+            argfile = find_server_argfile(
+                &instance.path,
+                loader_lower.as_deref(),
+                &instance.version,
+                instance.loader_version.as_deref(),
+            );
+        }
         let run_script_name = if cfg!(windows) { "run.bat" } else { "run.sh" };
-        if instance.path.join(run_script_name).exists() {
+        if argfile.is_some() {
+            final_jar_path = None;
+        } else if instance.path.join(run_script_name).exists() {
             final_run_script = Some(run_script_name.to_string());
             final_jar_path = None;
         }
@@ -55,12 +73,21 @@ impl ServerManager {
                 if let Some(bat) = &instance.settings.bat_file {
                     final_run_script = Some(bat.clone());
                     final_jar_path = None;
+                    argfile = None;
                     args.clear();
                 }
             }
+            LaunchMethod::CustomCommand => {
+                custom_command = Some(instance.settings.render_startup_line());
+                argfile = None;
+                final_run_script = None;
+                final_jar_path = None;
+                args.clear();
+            }
             LaunchMethod::StartupLine => {
                 let is_imported = instance.version == "Imported";
                 let has_specialized = final_run_script.is_some()
+                    || argfile.is_some()
                     || (loader_lower.as_deref() == Some("fabric")
                         && instance.path.join("fabric-server.jar").exists())
                     || (loader_lower.as_deref() == Some("quilt")
@@ -114,16 +141,30 @@ impl ServerManager {
             }
         }
 
-        let min_ram_unit = match instance.settings.min_ram_unit.as_str() {
-            "GB" => "G",
-            "MB" => "M",
-            u => u,
-        };
-        let max_ram_unit = match instance.settings.max_ram_unit.as_str() {
-            "GB" => "G",
-            "MB" => "M",
-            u => u,
-        };
+        let hooks_enabled = self
+            .config_manager
+            .load()
+            .await
+            .map(|settings| settings.shell.enabled)
+            .unwrap_or(false);
+
+        let environment = &instance.settings.environment;
+        let working_dir = environment
+            .resolve_working_dir(&instance.path)
+            .unwrap_or_else(|e| {
+                warn!("Ignoring the working folder of {}: {}", instance.name, e);
+                instance.path.clone()
+            });
+        // Files the wrapper found in the instance folder still have to be found from
+        // the overridden working folder
+        if environment.overrides_working_dir() {
+            argfile = argfile.map(|a| instance.path.join(a));
+            final_run_script =
+                final_run_script.map(|s| instance.path.join(s).to_string_lossy().to_string());
+        }
+
+        let min_ram_unit = jvm_memory_unit(&instance.settings.min_ram_unit);
+        let max_ram_unit = jvm_memory_unit(&instance.settings.max_ram_unit);
 
         ServerConfig {
             name: instance.name.clone(),
@@ -132,11 +173,24 @@ impl ServerManager {
             jar_path: final_jar_path,
             run_script: final_run_script,
             args,
-            working_dir: instance.path.clone(),
+            working_dir,
             java_path,
             crash_handling: instance.settings.crash_handling.clone(),
             stop_timeout: 30,
+            startup_timeout: instance.settings.startup_timeout,
             server_type,
+            detached: instance.settings.keep_running_on_close,
+            tick_sample_interval: instance.settings.tick_sample_interval,
+            idle_timeout: instance.settings.idle_stop_minutes * 60,
+            idle_exempt_players: instance.settings.idle_exempt_players.clone(),
+            jvm_args: instance.settings.jvm.jvm_args(&instance.path),
+            custom_command,
+            argfile,
+            env: environment.variables(),
+            pre_start_hook: environment.pre_start_hook(),
+            post_stop_hook: environment.post_stop_hook(),
+            hooks_enabled,
+            resource_limits: instance.settings.resource_limits.clone(),
         }
     }
 }
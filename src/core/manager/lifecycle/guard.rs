@@ -0,0 +1,13 @@
+use super::super::ServerManager;
+use std::sync::Arc;
+use tokio::sync::{Mutex, OwnedMutexGuard};
+use uuid::Uuid;
+
+impl ServerManager {
+    /// Serializes starting, preparing and moving one instance, so none of them runs
+    /// against files another is halfway through changing.
+    pub(crate) async fn lifecycle_guard(&self, instance_id: Uuid) -> OwnedMutexGuard<()> {
+        let lock = Arc::clone(self.lifecycle_locks.lock().await.entry(instance_id).or_default());
+        lock.lock_owned().await
+    }
+}
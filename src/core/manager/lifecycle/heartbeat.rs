@@ -0,0 +1,62 @@
+use super::super::ServerManager;
+use crate::notifications::send_heartbeat;
+use crate::server::{ServerHandle, ServerStatus};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+use tracing::warn;
+use uuid::Uuid;
+
+/// How often the server status is checked; pings themselves follow the configured interval.
+const HEARTBEAT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+/// How long a crashed server may wait for an auto-restart before pinging stops for good.
+const CRASH_GRACE: Duration = Duration::from_secs(30);
+
+impl ServerManager {
+    /// Pings the instance's heartbeat URL while the server is running, and the failure
+    /// URL once if it crashes. Stops once the server is stopped, or stays crashed past
+    /// [`CRASH_GRACE`]. Settings are re-read every poll so the heartbeat can be
+    /// enabled or changed without restarting the server.
+    pub(crate) fn spawn_heartbeat(&self, instance_id: Uuid, server: Arc<ServerHandle>) -> JoinHandle<()> {
+        let instance_manager = Arc::clone(&self.instance_manager);
+        tokio::spawn(async move {
+            let mut last_ping: Option<Instant> = None;
+            let mut crashed_since: Option<Instant> = None;
+            loop {
+                let Ok(Some(instance)) = instance_manager.get_instance(instance_id).await else {
+                    break;
+                };
+                let settings = &instance.settings.heartbeat;
+                let status = server.get_status().await;
+
+                let url = match status {
+                    ServerStatus::Running => {
+                        crashed_since = None;
+                        let due = last_ping.is_none_or(|t| t.elapsed() >= settings.interval());
+                        (settings.is_active() && due).then(|| settings.ping_url(true))
+                    }
+                    ServerStatus::Crashed => match crashed_since {
+                        Some(since) if since.elapsed() >= CRASH_GRACE => break,
+                        Some(_) => None,
+                        None => {
+                            crashed_since = Some(Instant::now());
+                            last_ping = None;
+                            (settings.is_active() && settings.report_failures).then(|| settings.ping_url(false))
+                        }
+                    },
+                    ServerStatus::Stopped => break,
+                    _ => None,
+                };
+
+                if let Some(url) = url {
+                    last_ping = Some(Instant::now());
+                    if let Err(e) = send_heartbeat(&url).await {
+                        warn!("Heartbeat for {} failed: {}", instance.name, e);
+                    }
+                }
+                tokio::time::sleep(HEARTBEAT_POLL_INTERVAL).await;
+            }
+        })
+    }
+}
@@ -1,14 +1,21 @@
 use super::super::ServerManager;
+use crate::errors::AppError;
 use crate::server::{ServerHandle, ServerStatus};
+use crate::tasks::TaskKind;
 use crate::utils::fs::is_jar_valid;
 use anyhow::{Result, anyhow};
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 use tracing::info;
 use uuid::Uuid;
 
 impl ServerManager {
     pub async fn prepare_server(&self, instance_id: Uuid) -> Result<Arc<ServerHandle>> {
+        let _guard = self.lifecycle_guard(instance_id).await;
+        self.prepare_server_locked(instance_id).await
+    }
+
+    /// [`Self::prepare_server`] for callers already holding the instance's lifecycle guard.
+    pub(crate) async fn prepare_server_locked(&self, instance_id: Uuid) -> Result<Arc<ServerHandle>> {
         let server = self.get_or_create_server(instance_id).await?;
 
         // Check if already installing and wait if so
@@ -27,7 +34,7 @@ impl ServerManager {
             .instance_manager
             .get_instance(instance_id)
             .await?
-            .ok_or_else(|| anyhow!("Instance not found: {}", instance_id))?;
+            .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
 
         let is_bedrock = instance
             .mod_loader
@@ -78,24 +85,6 @@ impl ServerManager {
             }
         }
 
-        // Determine the target JAR path for download if missing
-        let mut download_jar_path = config
-            .jar_path
-            .clone()
-            .unwrap_or_else(|| instance.path.join("server.jar"));
-
-        // For Fabric/Quilt, if we are not installed, the download_jar_path MUST be server.jar
-        // because build_server_config might have pointed it to fabric-server.jar which we just invalidated
-        if !is_installed {
-            if let Some(loader) = &instance.mod_loader {
-                let loader_lower = loader.to_lowercase();
-                if loader_lower == "fabric" || loader_lower == "quilt" {
-                    download_jar_path = instance.path.join("server.jar");
-                }
-            }
-        }
-
-        // Download jar/binary if missing or corrupt
         if !is_installed {
             // Set status to Installing
             {
@@ -127,141 +116,40 @@ impl ServerManager {
             }
 
             if instance.version == "Imported" {
+                server.set_status(ServerStatus::Stopped).await;
                 return Err(anyhow!(
                     "Imported instance is missing its executable (jar or bat file). Please check the instance settings."
                 ));
             }
-            if let Some(loader) = &instance.mod_loader {
-                let loader_lower = loader.to_lowercase();
-                let is_fabric = loader_lower == "fabric";
-                let is_quilt = loader_lower == "quilt";
-                let is_forge = loader_lower == "forge";
-                let is_neoforge = loader_lower == "neoforge";
 
-                if is_fabric {
-                    self.install_fabric(Arc::clone(&server), &instance).await?;
-                    // Force update instance metadata after installation to ensure
-                    // build_server_config sees the new fabric-server.jar
-                    instance = self
-                        .instance_manager
-                        .get_instance(instance_id)
-                        .await?
-                        .unwrap();
-                } else if is_quilt {
-                    self.install_quilt(Arc::clone(&server), &instance).await?;
-                    // Force update instance metadata after installation
-                    instance = self
-                        .instance_manager
-                        .get_instance(instance_id)
-                        .await?
-                        .unwrap();
-                } else if is_forge {
-                    self.install_forge(Arc::clone(&server), &instance).await?;
-                } else if is_neoforge {
-                    self.install_neoforge(Arc::clone(&server), &instance)
-                        .await?;
-                } else {
-                    let server_clone = Arc::clone(&server);
-                    let display_name = match loader_lower.as_str() {
-                        "paper" => "Paper".to_string(),
-                        "purpur" => "Purpur".to_string(),
-                        "velocity" => "Velocity".to_string(),
-                        "bungeecord" => "BungeeCord".to_string(),
-                        "bedrock" => "Bedrock".to_string(),
-                        _ => loader.clone(),
-                    };
-
-                    let msg = format!(
-                        "Starting download of {} for version {}",
-                        display_name, instance.version
-                    );
-                    info!("{}", msg);
-                    server.emit_log(msg);
-
-                    let last_percent = Arc::new(AtomicU32::new(0));
-                    let final_size = Arc::new(AtomicU64::new(0));
-                    let final_size_clone = Arc::clone(&final_size);
-                    let display_name_clone = display_name.clone();
-                    self.mod_loader_client
-                        .download_loader(
-                            loader,
-                            &instance.version,
-                            instance.loader_version.as_deref(),
-                            &download_jar_path,
-                            move |current, total| {
-                                final_size_clone.store(current, Ordering::Relaxed);
-                                server_clone.handle_download_progress(
-                                    current,
-                                    total,
-                                    &format!("Downloading {}...", display_name_clone),
-                                    &last_percent,
-                                );
-                            },
-                        )
-                        .await?;
-
-                    let size_mb = final_size.load(Ordering::Relaxed) / (1024 * 1024);
-                    server.emit_log(format!("Final size: {} MB", size_mb));
-
-                    if loader.to_lowercase() == "bedrock" {
-                        server.emit_log("Extracting Bedrock server...".to_string());
-                    } else if loader.to_lowercase() == "paper"
-                        || loader.to_lowercase() == "velocity"
-                    {
-                        server.emit_log("Verifying checksum...".to_string());
-                    }
-
-                    server.emit_log("Download complete!".to_string());
-                }
-            } else {
-                let msg = format!(
-                    "Starting download of vanilla server for version {}",
-                    instance.version
-                );
-                info!("{}", msg);
-                server.emit_log(msg);
-                let server_clone = Arc::clone(&server);
-                let last_percent = Arc::new(AtomicU32::new(0));
-                let final_size = Arc::new(AtomicU64::new(0));
-                let final_size_clone = Arc::clone(&final_size);
-                self.downloader
-                    .download_server(
-                        &instance.version,
-                        &download_jar_path,
-                        move |current, total| {
-                            final_size_clone.store(current, Ordering::Relaxed);
-                            server_clone.handle_download_progress(
-                                current,
-                                total,
-                                "Downloading vanilla server...",
-                                &last_percent,
-                            );
-                        },
-                    )
-                    .await?;
-                let size_mb = final_size.load(Ordering::Relaxed) / (1024 * 1024);
-                server.emit_log(format!("Final size: {} MB", size_mb));
-                server.emit_log("Download complete!".to_string());
-            }
-
-            // Also create eula.txt if it doesn't exist (Java only)
-            if !is_bedrock {
-                let eula_path = instance.path.join("eula.txt");
-                if !eula_path.exists() {
-                    tokio::fs::write(eula_path, "eula=true").await?;
-                }
-            }
-
-            // Reset status back to Stopped after installation
-            {
-                let mut status = server.status.lock().await;
-                *status = ServerStatus::Stopped;
-            }
+            let result = self
+                .run_tracked(
+                    &server,
+                    TaskKind::Install,
+                    format!("Installing {}", instance.name),
+                    instance_id,
+                    self.provision_instance(Arc::clone(&server), &instance),
+                )
+                .await;
+
+            // Reset status back to Stopped after installation, whether it succeeded or not
+            server.set_status(ServerStatus::Stopped).await;
+            result?;
+
+            // Installers may have changed the instance metadata
+            instance = self
+                .instance_manager
+                .get_instance(instance_id)
+                .await?
+                .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
         }
 
         // Update server config after potential installation (in case jar path changed or was created)
         let new_config = self.build_server_config(&instance).await;
         server.update_config(new_config).await;
+        for warning in instance.settings.jvm.warnings(&instance.path) {
+            server.emit_log(format!("WARNING: {}", warning));
+        }
         Ok(server)
     }
 }
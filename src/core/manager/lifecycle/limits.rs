@@ -0,0 +1,74 @@
+use super::super::ServerManager;
+use crate::app_config::StartLimits;
+use crate::errors::AppError;
+use crate::instance::InstanceMetadata;
+use anyhow::{Result, anyhow};
+use uuid::Uuid;
+
+/// Heap the instance reserves when running. Bedrock isn't a JVM, so it has no -Xmx to count.
+fn reserved_memory_mb(instance: &InstanceMetadata) -> u64 {
+    let is_bedrock = instance
+        .mod_loader
+        .as_deref()
+        .is_some_and(|l| l.eq_ignore_ascii_case("bedrock"));
+    if is_bedrock {
+        0
+    } else {
+        instance.settings.max_memory_mb()
+    }
+}
+
+/// Checks whether one more server reserving `requested_mb` fits next to the running
+/// servers, whose reserved heaps are given in `running_mb`.
+pub fn check_start_limits(limits: &StartLimits, running_mb: &[u64], requested_mb: u64) -> Result<()> {
+    if limits.max_running_instances > 0 && running_mb.len() as u32 >= limits.max_running_instances {
+        return Err(anyhow!(
+            "{} servers are already running, which is the limit set in the app settings. Stop one first.",
+            running_mb.len()
+        ));
+    }
+    if limits.ram_budget_mb > 0 {
+        let in_use: u64 = running_mb.iter().sum();
+        if in_use + requested_mb > limits.ram_budget_mb {
+            return Err(anyhow!(
+                "Starting this server would use {} MB of the {} MB RAM budget ({} MB already in use by running servers). \
+                 Stop a server or lower its max memory first.",
+                in_use + requested_mb,
+                limits.ram_budget_mb,
+                in_use
+            ));
+        }
+    }
+    Ok(())
+}
+
+impl ServerManager {
+    /// Refuses to start `instance_id` when that would exceed the global instance limit
+    /// or RAM budget. Callers hold `start_gate` so concurrent starts can't both pass.
+    pub(crate) async fn enforce_start_limits(&self, instance_id: Uuid) -> Result<()> {
+        let limits = self.config_manager.load().await?.start_limits;
+        if limits == StartLimits::default() {
+            return Ok(());
+        }
+
+        let instance = self
+            .instance_manager
+            .get_instance(instance_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+
+        let mut running_mb = Vec::new();
+        for id in self.running_server_ids().await {
+            if id == instance_id {
+                continue;
+            }
+            let reserved = match self.instance_manager.get_instance(id).await? {
+                Some(other) => reserved_memory_mb(&other),
+                None => 0,
+            };
+            running_mb.push(reserved);
+        }
+
+        check_start_limits(&limits, &running_mb, reserved_memory_mb(&instance))
+    }
+}
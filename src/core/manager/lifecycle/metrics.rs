@@ -0,0 +1,37 @@
+use super::super::ServerManager;
+use crate::server::{ServerHandle, ServerStatus};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::warn;
+use uuid::Uuid;
+
+/// Time between resource samples written to the metrics store.
+const METRICS_SAMPLE_INTERVAL: Duration = Duration::from_secs(60);
+
+impl ServerManager {
+    /// Records a resource sample every minute while the server runs. Stops once the
+    /// server is stopped, or still crashed one interval later (i.e. not auto-restarted).
+    pub(crate) fn spawn_metrics_recorder(&self, instance_id: Uuid, server: Arc<ServerHandle>) -> JoinHandle<()> {
+        let instance_manager = Arc::clone(&self.instance_manager);
+        tokio::spawn(async move {
+            let mut was_crashed = false;
+            loop {
+                tokio::time::sleep(METRICS_SAMPLE_INTERVAL).await;
+                match server.get_status().await {
+                    ServerStatus::Running => {
+                        was_crashed = false;
+                        let usage = server.get_usage().await;
+                        if let Err(e) = instance_manager.record_metric_sample(instance_id, &usage).await {
+                            warn!("Failed to record metrics for {}: {}", instance_id, e);
+                        }
+                    }
+                    ServerStatus::Stopped => break,
+                    ServerStatus::Crashed if was_crashed => break,
+                    ServerStatus::Crashed => was_crashed = true,
+                    _ => was_crashed = false,
+                }
+            }
+        })
+    }
+}
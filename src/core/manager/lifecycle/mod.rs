@@ -1,11 +1,24 @@
 use super::ServerManager;
+use crate::errors::AppError;
 use crate::server::{ResourceUsage, ServerHandle, ServerStatus};
 use anyhow::{Result, anyhow};
 use std::sync::Arc;
+use tracing::warn;
 use uuid::Uuid;
 
+pub mod adopt;
+pub mod autostart;
 pub mod config;
+pub mod guard;
+pub mod heartbeat;
 pub mod installer;
+pub mod limits;
+pub mod metrics;
+pub mod notifier;
+pub mod ordering;
+pub mod shutdown;
+pub mod wake;
+pub mod watchers;
 
 impl ServerManager {
     pub async fn get_or_create_server(&self, instance_id: Uuid) -> Result<Arc<ServerHandle>> {
@@ -19,7 +32,7 @@ impl ServerManager {
             .instance_manager
             .get_instance(instance_id)
             .await?
-            .ok_or_else(|| anyhow!("Instance not found"))?;
+            .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
 
         let config = self.build_server_config(&instance).await;
         let server = Arc::new(ServerHandle::new(config));
@@ -28,14 +41,37 @@ impl ServerManager {
     }
 
     pub async fn start_server(&self, instance_id: Uuid) -> Result<()> {
-        let server = self.prepare_server(instance_id).await?;
+        self.start_server_with_args(instance_id, &[]).await
+    }
+
+    /// Starts the server with `extra_args` after its usual ones, for this run only; the
+    /// next start rebuilds its launch config from the instance settings.
+    pub(crate) async fn start_server_with_args(&self, instance_id: Uuid, extra_args: &[String]) -> Result<()> {
+        let _guard = self.lifecycle_guard(instance_id).await;
+        let server = self.prepare_server_locked(instance_id).await?;
         let status = server.get_status().await;
 
         if status != ServerStatus::Stopped && status != ServerStatus::Crashed {
             return Ok(());
         }
+        if !extra_args.is_empty() {
+            let mut config = server.get_config().await;
+            if config.custom_command.is_some() {
+                return Err(anyhow!(
+                    "The instance starts with a custom command, which can't take extra arguments"
+                ));
+            }
+            config.args.extend(extra_args.iter().cloned());
+            server.update_config(config).await;
+        }
 
+        let gate = self.start_gate.lock().await;
+        self.enforce_start_limits(instance_id).await?;
+        let listeners = self.release_wake_listener(instance_id).await;
         server.start().await?;
+        drop(listeners);
+        drop(gate);
+        self.spawn_run_watchers(instance_id, server).await;
 
         self.instance_manager.update_last_run(instance_id).await?;
 
@@ -58,6 +94,60 @@ impl ServerManager {
         Ok(())
     }
 
+    /// Returns the IDs of servers that have a live process (starting, running or stopping).
+    pub async fn running_server_ids(&self) -> Vec<Uuid> {
+        let servers = self.servers.lock().await;
+        let mut running = Vec::new();
+        for (id, server) in servers.iter() {
+            if matches!(
+                server.get_status().await,
+                ServerStatus::Starting | ServerStatus::Running | ServerStatus::Stopping
+            ) {
+                running.push(*id);
+            }
+        }
+        running
+    }
+
+    /// Stops every running server concurrently and returns the IDs that were stopped.
+    ///
+    /// Servers are asked to stop gracefully (and killed once their stop timeout
+    /// expires) unless `force` is set, in which case they are killed immediately.
+    pub async fn stop_all_servers(&self, force: bool) -> Result<Vec<Uuid>> {
+        let running = self.running_server_ids().await;
+        self.stop_servers(&running, force).await
+    }
+
+    /// Stops the given servers concurrently; see [`Self::stop_all_servers`].
+    pub async fn stop_servers(&self, ids: &[Uuid], force: bool) -> Result<Vec<Uuid>> {
+        let targets: Vec<(Uuid, Arc<ServerHandle>)> = {
+            let servers = self.servers.lock().await;
+            ids.iter()
+                .filter_map(|id| servers.get(id).map(|s| (*id, Arc::clone(s))))
+                .collect()
+        };
+
+        let results = futures_util::future::join_all(targets.iter().map(|(id, server)| async move {
+            let result = if force { server.kill().await } else { server.stop().await };
+            (*id, result)
+        }))
+        .await;
+
+        let mut stopped = Vec::new();
+        let mut failures = Vec::new();
+        for (id, result) in results {
+            match result {
+                Ok(()) => stopped.push(id),
+                Err(e) => failures.push(format!("{}: {}", id, e)),
+            }
+        }
+
+        if !failures.is_empty() {
+            return Err(anyhow!("Failed to stop some servers: {}", failures.join("; ")));
+        }
+        Ok(stopped)
+    }
+
     pub async fn send_command(&self, instance_id: Uuid, command: &str) -> Result<()> {
         let servers = self.servers.lock().await;
         if let Some(server) = servers.get(&instance_id) {
@@ -65,6 +155,31 @@ impl ServerManager {
         } else {
             return Err(anyhow!("Server not running"));
         }
+        drop(servers);
+
+        if let Err(e) = self.instance_manager.record_command(instance_id, command).await {
+            warn!("Failed to record command history for {}: {}", instance_id, e);
+        }
+        Ok(())
+    }
+
+    /// Sends `message` to the players of a running server as chat from the server. See
+    /// [`ServerHandle::send_chat`].
+    pub async fn send_chat(&self, instance_id: Uuid, message: &str) -> Result<()> {
+        let server = self
+            .get_server(instance_id)
+            .await
+            .ok_or_else(|| AppError::Conflict("Start the server before sending chat".to_string()))?;
+        server.send_chat(message).await
+    }
+
+    /// Fails with a conflict unless the server is stopped; `action` completes "Stop the
+    /// server before ...".
+    pub(crate) async fn ensure_stopped(&self, instance_id: Uuid, action: &str) -> Result<()> {
+        let status = self.get_server_status(instance_id).await;
+        if !matches!(status, ServerStatus::Stopped | ServerStatus::Crashed) {
+            return Err(AppError::Conflict(format!("Stop the server before {}", action)).into());
+        }
         Ok(())
     }
 
@@ -0,0 +1,110 @@
+use super::super::ServerManager;
+use crate::instance::InstanceManager;
+use crate::notifications::{self, EventTracker, NotificationEvent, RuleSet};
+use crate::server::{ServerHandle, ServerStatus};
+use crate::timeline::TimelineKind;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::warn;
+use uuid::Uuid;
+
+/// Time between status/player/TPS snapshots compared for webhook events.
+const NOTIFY_POLL_INTERVAL: Duration = Duration::from_secs(5);
+/// How long a crashed server may wait for an auto-restart before the watcher gives up.
+const CRASH_GRACE: Duration = Duration::from_secs(30);
+
+impl ServerManager {
+    /// Watches a started server, posting webhook events and firing desktop notification
+    /// rules until it stops, or stays crashed past [`CRASH_GRACE`]. Settings and rules
+    /// are re-read every poll so edits apply live.
+    pub(crate) fn spawn_event_notifier(&self, instance_id: Uuid, server: Arc<ServerHandle>) -> JoinHandle<()> {
+        let instance_manager = Arc::clone(&self.instance_manager);
+        let desktop = self.notification_sender.clone();
+        let cache = Arc::clone(&self.cache);
+        tokio::spawn(async move {
+            let mut logs = server.subscribe_logs();
+            let mut tracker = EventTracker::new(server.get_status().await);
+            let mut rules = RuleSet::default();
+            let mut name = String::new();
+            let mut ticker = tokio::time::interval(NOTIFY_POLL_INTERVAL);
+            let mut crashed_for = Duration::ZERO;
+            let mut restarting = false;
+
+            loop {
+                tokio::select! {
+                    line = logs.recv() => match line {
+                        Ok(line) => {
+                            tracker.observe_log(&line);
+                            for fired in rules.match_log(instance_id, &name, &line) {
+                                let _ = desktop.send(fired);
+                            }
+                        }
+                        Err(RecvError::Lagged(_)) => {}
+                        Err(RecvError::Closed) => break,
+                    },
+                    _ = ticker.tick() => {
+                        let Ok(Some(instance)) = instance_manager.get_instance(instance_id).await else {
+                            break;
+                        };
+                        name = instance.name.clone();
+                        match instance_manager.list_notification_rules(Some(instance_id)).await {
+                            Ok(list) => rules.replace(list),
+                            Err(e) => warn!("Failed to load notification rules for {}: {}", name, e),
+                        }
+
+                        let status = server.get_status().await;
+                        let players = server.get_online_players().await;
+                        let tps = server.get_usage().await.tps;
+                        let threshold = instance.settings.webhooks.low_tps_threshold;
+                        for event in tracker.observe(status, &players, tps, threshold) {
+                            match &event {
+                                NotificationEvent::ServerCrashed { reason } => {
+                                    restarting = true;
+                                    scan_crash_reports(&instance_manager, instance_id).await;
+                                    instance_manager
+                                        .note_timeline_event(instance_id, TimelineKind::Crash, "Server crashed", Some(reason.clone()))
+                                        .await;
+                                }
+                                NotificationEvent::ServerStarted if restarting => {
+                                    restarting = false;
+                                    instance_manager
+                                        .note_timeline_event(instance_id, TimelineKind::Restart, "Restarted after a crash", None)
+                                        .await;
+                                }
+                                _ => {}
+                            }
+                            for fired in rules.match_event(instance_id, &name, &event) {
+                                let _ = desktop.send(fired);
+                            }
+                            notifications::notify(cache.get_client(), &instance, event).await;
+                        }
+
+                        match status {
+                            ServerStatus::Stopped => {
+                                scan_crash_reports(&instance_manager, instance_id).await;
+                                break;
+                            }
+                            ServerStatus::Crashed => {
+                                crashed_for += NOTIFY_POLL_INTERVAL;
+                                if crashed_for >= CRASH_GRACE {
+                                    break;
+                                }
+                            }
+                            _ => crashed_for = Duration::ZERO,
+                        }
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// Stores the crash reports written as the server went down, so they are in the crash
+/// list and the timeline without waiting for someone to open them.
+async fn scan_crash_reports(instance_manager: &InstanceManager, instance_id: Uuid) {
+    if let Err(e) = instance_manager.scan_crash_reports(instance_id).await {
+        warn!("Failed to scan crash reports for {}: {}", instance_id, e);
+    }
+}
@@ -0,0 +1,100 @@
+use super::super::ServerManager;
+use crate::instance::dependencies::startup_waves;
+use crate::server::ServerStatus;
+use anyhow::{Result, anyhow};
+use std::collections::HashSet;
+use std::time::Duration;
+use tokio::time::Instant;
+use tracing::info;
+use uuid::Uuid;
+
+const READY_POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// Upper bound on waiting for a dependency when its own startup timeout is disabled.
+const MAX_READY_WAIT: Duration = Duration::from_secs(1800);
+
+impl ServerManager {
+    /// Starts the given servers in dependency order: each wave waits until every
+    /// server in the previous waves is Running. Dependencies that weren't requested
+    /// are started too. A server whose dependency failed to come up is skipped.
+    ///
+    /// Returns the IDs that were started (or already running).
+    pub async fn start_servers(&self, ids: &[Uuid]) -> Result<Vec<Uuid>> {
+        self.start_servers_staggered(ids, Duration::ZERO).await
+    }
+
+    /// Like [`Self::start_servers`], but servers in the same wave start `stagger`
+    /// apart so they don't all load their worlds at once.
+    pub async fn start_servers_staggered(&self, ids: &[Uuid], stagger: Duration) -> Result<Vec<Uuid>> {
+        let instances = self.instance_manager.list_instances().await?;
+        let waves = startup_waves(&instances, ids)?;
+
+        let mut ready = Vec::new();
+        let mut failed: HashSet<Uuid> = HashSet::new();
+        let mut failures = Vec::new();
+        for wave in waves {
+            let mut to_start = Vec::new();
+            for id in wave {
+                let instance = instances
+                    .iter()
+                    .find(|i| i.id == id)
+                    .expect("waves only hold known instances");
+                match instance.settings.depends_on.iter().find(|d| failed.contains(d)) {
+                    Some(dep) => {
+                        let dep_name = instances.iter().find(|i| i.id == *dep).map_or("?", |i| i.name.as_str());
+                        failures.push(format!("{}: dependency {} did not start", instance.name, dep_name));
+                        failed.insert(id);
+                    }
+                    None => to_start.push((id, instance)),
+                }
+            }
+
+            let results =
+                futures_util::future::join_all(to_start.iter().enumerate().map(|(n, (id, instance))| async move {
+                    tokio::time::sleep(stagger * n as u32).await;
+                    info!("Starting {} as part of a bulk start", instance.name);
+                    let result = match self.start_server(*id).await {
+                        Ok(()) => self.wait_until_running(*id, instance.settings.startup_timeout).await,
+                        Err(e) => Err(e),
+                    };
+                    (*id, instance.name.as_str(), result)
+                }))
+                .await;
+
+            for (id, name, result) in results {
+                match result {
+                    Ok(()) => ready.push(id),
+                    Err(e) => {
+                        failures.push(format!("{}: {}", name, e));
+                        failed.insert(id);
+                    }
+                }
+            }
+        }
+
+        if !failures.is_empty() {
+            return Err(anyhow!("Failed to start some servers: {}", failures.join("; ")));
+        }
+        Ok(ready)
+    }
+
+    /// Waits for a server to reach Running, failing if it stops or crashes first.
+    pub(crate) async fn wait_until_running(&self, instance_id: Uuid, startup_timeout: u64) -> Result<()> {
+        let limit = match startup_timeout {
+            0 => MAX_READY_WAIT,
+            secs => Duration::from_secs(secs),
+        };
+        let deadline = Instant::now() + limit;
+        loop {
+            match self.get_server_status(instance_id).await {
+                ServerStatus::Running => return Ok(()),
+                ServerStatus::Stopped | ServerStatus::Crashed => {
+                    return Err(anyhow!("server stopped before it was ready"));
+                }
+                _ if Instant::now() >= deadline => {
+                    return Err(anyhow!("server was not ready after {}s", limit.as_secs()));
+                }
+                _ => tokio::time::sleep(READY_POLL_INTERVAL).await,
+            }
+        }
+    }
+}
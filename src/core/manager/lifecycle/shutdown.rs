@@ -0,0 +1,159 @@
+use super::super::ServerManager;
+use crate::errors::AppError;
+use crate::server::{ServerHandle, ServerStatus};
+use anyhow::Result;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::warn;
+use uuid::Uuid;
+
+/// Broadcast during a countdown when no message is given; `{time}` is the time left.
+pub const DEFAULT_COUNTDOWN_MESSAGE: &str = "Server stopping in {time}";
+/// [`DEFAULT_COUNTDOWN_MESSAGE`] for restarts.
+pub const DEFAULT_RESTART_MESSAGE: &str = "Server restarting in {time}";
+
+/// Points (seconds left) at which a warning is repeated during a countdown.
+const WARNING_MARKS: [u64; 11] = [600, 300, 120, 60, 30, 10, 5, 4, 3, 2, 1];
+
+/// Seconds left at each warning of a `delay_secs` countdown, starting with the full delay.
+pub fn countdown_marks(delay_secs: u64) -> Vec<u64> {
+    if delay_secs == 0 {
+        return Vec::new();
+    }
+    let mut marks = vec![delay_secs];
+    marks.extend(WARNING_MARKS.iter().copied().filter(|m| *m < delay_secs));
+    marks
+}
+
+/// "5 minutes", "1m 30s" or "10 seconds".
+pub fn format_time_left(secs: u64) -> String {
+    let plural = |n: u64, unit: &str| format!("{} {}{}", n, unit, if n == 1 { "" } else { "s" });
+    match (secs / 60, secs % 60) {
+        (0, s) => plural(s, "second"),
+        (m, 0) => plural(m, "minute"),
+        (m, s) => format!("{}m {}s", m, s),
+    }
+}
+
+pub fn render_countdown_message(template: &str, secs_left: u64) -> String {
+    template.replace("{time}", &format_time_left(secs_left))
+}
+
+/// Countdown messages are broadcast as console commands, so a line break would let the
+/// rest of the message run as a command of its own.
+pub fn validate_countdown_message(template: &str) -> Result<()> {
+    if template.contains(['\n', '\r']) {
+        return Err(AppError::Validation("Countdown messages must be a single line of text".to_string()).into());
+    }
+    Ok(())
+}
+
+impl ServerManager {
+    /// Stops a server after warning its players: the message is broadcast with `say`
+    /// at intervals over `delay`, players are kicked with a short reason, the world is
+    /// saved and the server is stopped. With nobody online the countdown is skipped.
+    /// Proxies have no `say` or `save-all`, so they are stopped right away.
+    pub async fn stop_server_graceful(
+        &self,
+        instance_id: Uuid,
+        delay: Duration,
+        message: Option<&str>,
+    ) -> Result<()> {
+        let message = message.unwrap_or(DEFAULT_COUNTDOWN_MESSAGE);
+        self.graceful_stop(instance_id, delay, message, "Server is shutting down")
+            .await
+    }
+
+    /// [`Self::stop_server_graceful`] followed by a start, for scheduled restarts.
+    pub async fn restart_server_graceful(
+        &self,
+        instance_id: Uuid,
+        delay: Duration,
+        message: Option<&str>,
+    ) -> Result<()> {
+        let message = message.unwrap_or(DEFAULT_RESTART_MESSAGE);
+        self.graceful_stop(
+            instance_id,
+            delay,
+            message,
+            "Server is restarting, reconnect in a minute",
+        )
+        .await?;
+        self.start_server(instance_id).await
+    }
+
+    async fn graceful_stop(
+        &self,
+        instance_id: Uuid,
+        delay: Duration,
+        message: &str,
+        kick_reason: &str,
+    ) -> Result<()> {
+        validate_countdown_message(message)?;
+        let server = {
+            let servers = self.servers.lock().await;
+            servers.get(&instance_id).cloned()
+        };
+        let Some(server) = server else {
+            return Ok(());
+        };
+        if server.get_status().await != ServerStatus::Running {
+            return server.stop().await;
+        }
+
+        let server_type = server.get_config().await.server_type;
+        let is_proxy = matches!(
+            server_type.as_deref(),
+            Some("velocity") | Some("bungeecord")
+        );
+        if is_proxy {
+            return server.stop().await;
+        }
+
+        if !server.get_online_players().await.is_empty() {
+            let template = if message.trim().is_empty() {
+                DEFAULT_COUNTDOWN_MESSAGE
+            } else {
+                message
+            };
+            if !run_countdown(&server, delay.as_secs(), template).await {
+                // Stopped by someone else during the countdown
+                return Ok(());
+            }
+            for player in server.get_online_players().await {
+                send_or_warn(&server, &format!("kick {} {}", player, kick_reason)).await;
+            }
+        }
+
+        if server_type.as_deref() != Some("bedrock") {
+            send_or_warn(&server, "save-all").await;
+        }
+        server.stop().await
+    }
+}
+
+/// Broadcasts the countdown; false if the server stopped running before it finished.
+async fn run_countdown(server: &Arc<ServerHandle>, delay_secs: u64, template: &str) -> bool {
+    let marks = countdown_marks(delay_secs);
+    for (i, left) in marks.iter().enumerate() {
+        if server.get_status().await != ServerStatus::Running {
+            return false;
+        }
+        let message = render_countdown_message(template, *left);
+        if let Err(e) = server.send_chat(&message).await {
+            warn!("Failed to broadcast the shutdown countdown: {}", e);
+        }
+        let next = marks.get(i + 1).copied().unwrap_or(0);
+        tokio::time::sleep(Duration::from_secs(left - next)).await;
+    }
+    server.get_status().await == ServerStatus::Running
+}
+
+async fn send_or_warn(server: &ServerHandle, command: &str) {
+    if let Err(e) = server.send_command(command).await {
+        warn!(
+            "Failed to send '{}' during graceful shutdown: {}",
+            command, e
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use super::super::ServerManager;
+use crate::instance::InstanceMetadata;
+use crate::protocol::{
+    self,
+    packets::{self, NextState},
+};
+use crate::server::ServerStatus;
+use anyhow::Result;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{MutexGuard, mpsc};
+use tokio::task::{JoinHandle, JoinSet};
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+/// How often listeners are reconciled with instance settings and server status.
+const WAKE_POLL_INTERVAL: Duration = Duration::from_secs(5);
+/// A single ping or login attempt must finish within this time.
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(5);
+const SLEEPING_MOTD: &str = "Server is sleeping. Join to wake it up!";
+const WAKING_MESSAGE: &str = "Server is starting… please reconnect in a moment.";
+
+fn supports_wake(instance: &InstanceMetadata) -> bool {
+    // Bedrock clients connect over UDP
+    instance.settings.wake_on_connect
+        && !instance
+            .mod_loader
+            .as_deref()
+            .is_some_and(|l| l.eq_ignore_ascii_case("bedrock"))
+}
+
+impl ServerManager {
+    /// Holds the port of every stopped instance with `wake_on_connect` enabled, answering
+    /// status pings and starting the server when a player tries to log in.
+    pub fn spawn_wake_supervisor(self: &Arc<Self>) {
+        let manager = Arc::clone(self);
+        let (wake_tx, mut wake_rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(WAKE_POLL_INTERVAL);
+            loop {
+                tokio::select! {
+                    Some(instance_id) = wake_rx.recv() => {
+                        let manager = Arc::clone(&manager);
+                        tokio::spawn(async move {
+                            if manager.get_or_create_server(instance_id).await.is_ok() {
+                                let _ = manager.wake_sender.send(instance_id);
+                            }
+                            if let Err(e) = manager.start_server(instance_id).await {
+                                warn!("Failed to wake server {}: {}", instance_id, e);
+                            }
+                        });
+                    }
+                    _ = ticker.tick() => manager.refresh_wake_listeners(&wake_tx).await,
+                }
+            }
+        });
+    }
+
+    async fn refresh_wake_listeners(&self, wake_tx: &mpsc::UnboundedSender<Uuid>) {
+        let instances = match self.instance_manager.list_instances().await {
+            Ok(list) => list,
+            Err(e) => {
+                warn!("Failed to list instances for wake listeners: {}", e);
+                return;
+            }
+        };
+        // Held while checking statuses and binding, so a server being started in between
+        // can't have its port taken back
+        let mut listeners = self.wake_listeners.lock().await;
+        let mut wanted = Vec::new();
+        for instance in instances {
+            // Crashed servers may be about to auto-restart and need the port back
+            if supports_wake(&instance) && self.get_server_status(instance.id).await == ServerStatus::Stopped {
+                wanted.push(instance);
+            }
+        }
+
+        listeners.retain(|id, handle| {
+            let keep = !handle.is_finished() && wanted.iter().any(|i| i.id == *id);
+            if !keep {
+                handle.abort();
+            }
+            keep
+        });
+        for instance in wanted {
+            if listeners.contains_key(&instance.id) {
+                continue;
+            }
+            // Same address the server itself binds, so other interfaces stay untouched
+            let host = instance.ip.as_deref().map(str::trim).filter(|ip| !ip.is_empty()).unwrap_or("0.0.0.0");
+            match TcpListener::bind((host, instance.settings.port)).await {
+                Ok(listener) => {
+                    debug!("Wake listener for {} bound to port {}", instance.name, instance.settings.port);
+                    let handle = tokio::spawn(run_wake_listener(listener, instance.clone(), wake_tx.clone()));
+                    listeners.insert(instance.id, handle);
+                }
+                Err(e) => debug!("Wake listener for {} could not bind port {}: {}", instance.name, instance.settings.port, e),
+            }
+        }
+    }
+
+    /// Stops an instance's wake listener and waits until its port is free again. No
+    /// listener is bound while the returned guard is held, so keep it until the server
+    /// has started or the instance is gone.
+    pub(crate) async fn release_wake_listener(&self, instance_id: Uuid) -> MutexGuard<'_, HashMap<Uuid, JoinHandle<()>>> {
+        let mut listeners = self.wake_listeners.lock().await;
+        if let Some(handle) = listeners.remove(&instance_id) {
+            handle.abort();
+            let _ = handle.await;
+        }
+        listeners
+    }
+}
+
+async fn run_wake_listener(listener: TcpListener, instance: InstanceMetadata, wake_tx: mpsc::UnboundedSender<Uuid>) {
+    let protocol = protocol::protocol_version(&instance.version).map(|p| p as i32);
+    // Dropped with the listener, which aborts connections still being answered
+    let mut connections = JoinSet::new();
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok((stream, addr)) => {
+                    let version = instance.version.clone();
+                    connections.spawn(async move {
+                        let result = tokio::time::timeout(CONNECTION_TIMEOUT, handle_connection(stream, &version, protocol)).await;
+                        (addr, result)
+                    });
+                }
+                Err(e) => {
+                    debug!("Wake listener for {} failed to accept: {}", instance.name, e);
+                    tokio::time::sleep(Duration::from_millis(200)).await;
+                }
+            },
+            Some(Ok((addr, result))) = connections.join_next() => match result {
+                Ok(Ok(true)) => {
+                    info!("Login attempt from {} woke {}", addr, instance.name);
+                    // Returning drops the listener so the server can bind the port
+                    let _ = wake_tx.send(instance.id);
+                    return;
+                }
+                Ok(Ok(false)) => {}
+                Ok(Err(e)) => debug!("Wake listener for {} dropped {}: {}", instance.name, addr, e),
+                Err(_) => debug!("Wake listener for {} timed out on {}", instance.name, addr),
+            },
+        }
+    }
+}
+
+/// Answers one connection; returns `true` when the client tried to log in.
+async fn handle_connection(mut stream: TcpStream, version: &str, protocol: Option<i32>) -> Result<bool> {
+    let (id, body) = packets::read_packet(&mut stream).await?;
+    if id != 0x00 {
+        return Ok(false);
+    }
+    let handshake = packets::parse_handshake(&body)?;
+    match handshake.next_state {
+        NextState::Status => {
+            let (id, _) = packets::read_packet(&mut stream).await?;
+            if id != 0x00 {
+                return Ok(false);
+            }
+            let protocol = protocol.unwrap_or(handshake.protocol);
+            stream.write_all(&packets::status_response(version, protocol, SLEEPING_MOTD)).await?;
+            if let Ok((0x01, payload)) = packets::read_packet(&mut stream).await {
+                stream.write_all(&packets::pong(&payload)).await?;
+            }
+            Ok(false)
+        }
+        NextState::Login => {
+            stream.write_all(&packets::login_disconnect(WAKING_MESSAGE)).await?;
+            stream.flush().await?;
+            Ok(true)
+        }
+    }
+}
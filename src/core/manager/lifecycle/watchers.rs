@@ -0,0 +1,27 @@
+use super::super::ServerManager;
+use crate::server::ServerHandle;
+use std::sync::Arc;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+impl ServerManager {
+    /// Starts the metrics recorder, event notifier and heartbeat for a run of the server.
+    /// Those of the previous run are aborted first: on a restart the server is running
+    /// again before they get to see it stop, so they would otherwise never exit.
+    pub(crate) async fn spawn_run_watchers(&self, instance_id: Uuid, server: Arc<ServerHandle>) {
+        let handles = vec![
+            self.spawn_metrics_recorder(instance_id, Arc::clone(&server)),
+            self.spawn_event_notifier(instance_id, Arc::clone(&server)),
+            self.spawn_heartbeat(instance_id, server),
+        ];
+        let previous = self.run_watchers.lock().await.insert(instance_id, handles);
+        previous.iter().flatten().for_each(JoinHandle::abort);
+    }
+
+    /// Aborts the watchers of a server that is going away, e.g. when its instance is deleted.
+    pub(crate) async fn abort_run_watchers(&self, instance_id: Uuid) {
+        if let Some(handles) = self.run_watchers.lock().await.remove(&instance_id) {
+            handles.iter().for_each(JoinHandle::abort);
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use super::ServerManager;
+use crate::errors::AppError;
+use crate::loadouts::{self, LoadoutResult};
+use anyhow::Result;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+impl ServerManager {
+    /// Applies a saved load-out to a stopped instance; a running server would keep the
+    /// jars it already loaded.
+    pub async fn apply_loadout(&self, instance_id: Uuid, name: &str) -> Result<LoadoutResult> {
+        self.ensure_stopped(instance_id, "applying a load-out").await?;
+        loadouts::apply_loadout(self.instance_path(instance_id).await?, name).await
+    }
+
+    /// Restores the state from before the active load-out of a stopped instance.
+    pub async fn revert_loadout(&self, instance_id: Uuid) -> Result<LoadoutResult> {
+        self.ensure_stopped(instance_id, "reverting a load-out").await?;
+        loadouts::revert_loadout(self.instance_path(instance_id).await?).await
+    }
+
+    async fn instance_path(&self, instance_id: Uuid) -> Result<PathBuf> {
+        let instance = self
+            .instance_manager
+            .get_instance(instance_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
+        Ok(instance.path)
+    }
+}
@@ -2,19 +2,63 @@ use super::app_config::GlobalConfigManager;
 use super::artifacts::{ArtifactStore, HashAlgorithm};
 use super::cache::CacheManager;
 use super::downloader::VersionDownloader;
+use super::events::EventBus;
 use super::instance::{InstanceManager, InstanceMetadata};
 use super::mod_loaders::ModLoaderClient;
+use super::notifications::DesktopNotification;
 use super::server::ServerHandle;
+use super::server_properties::write_default_server_properties;
+use super::tasks::TaskManager;
+use super::version_history;
 use anyhow::Result;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tokio::fs;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, broadcast};
+use tokio::task::JoinHandle;
 use tracing::{debug, info, warn};
 use uuid::Uuid;
 
+mod console;
+mod doctor;
+mod geyser;
 mod install;
 mod lifecycle;
+mod loadouts;
+mod offline;
+mod relocate;
+mod removal;
+mod shell;
+mod storage;
+mod summary;
+mod templates;
+mod tracked;
+mod updates;
+mod pregen;
+mod profiler;
+mod via;
+mod world_upgrade;
+mod worlds;
+
+pub use install::installer_state::{
+    INSTALLER_STATE_FILE, InstallerPhase, InstallerState, cleanup_partial_output, expected_artifacts,
+    verify_installer_output,
+};
+pub use install::phases::InstallerRepair;
+pub use lifecycle::limits::check_start_limits;
+pub use lifecycle::shutdown::{
+    DEFAULT_COUNTDOWN_MESSAGE, DEFAULT_RESTART_MESSAGE, countdown_marks, format_time_left, render_countdown_message,
+    validate_countdown_message,
+};
+pub use install::pipeline::{
+    ProvisionState, ProvisionStep, StepRecord, StepStatus, installs_offline, plan_steps,
+};
+pub use storage::{DeduplicationReport, LibrarySharing, SHARED_LIBRARIES_FILE, shared_library_hashes};
+pub use removal::{DeletionFailure, DeletionSummary, InstanceCleanup};
+pub use summary::InstanceSummary;
+pub use updates::ServerBuildUpdate;
+pub use via::{ViaAction, ViaChange, ViaSetupReport};
+pub use geyser::{CrossplayChange, CrossplaySetupReport};
 
 pub struct ServerManager {
     pub(crate) instance_manager: Arc<InstanceManager>,
@@ -24,6 +68,18 @@ pub struct ServerManager {
     pub(crate) cache: Arc<CacheManager>,
     pub(crate) artifact_store: Arc<ArtifactStore>,
     pub(crate) servers: Arc<Mutex<HashMap<Uuid, Arc<ServerHandle>>>>,
+    pub(crate) notification_sender: broadcast::Sender<DesktopNotification>,
+    pub(crate) wake_listeners: Arc<Mutex<HashMap<Uuid, JoinHandle<()>>>>,
+    /// Metrics, notification and heartbeat tasks following the current run of each server.
+    pub(crate) run_watchers: Arc<Mutex<HashMap<Uuid, Vec<JoinHandle<()>>>>>,
+    pub(crate) wake_sender: broadcast::Sender<Uuid>,
+    pub(crate) tasks: Arc<TaskManager>,
+    /// Startup phases and task changes kept for the UI to replay after a reload.
+    pub(crate) events: Arc<EventBus>,
+    /// Serializes the start-limit check with the start itself.
+    pub(crate) start_gate: Arc<Mutex<()>>,
+    /// Per-instance locks behind [`ServerManager::lifecycle_guard`].
+    pub(crate) lifecycle_locks: Arc<Mutex<HashMap<Uuid, Arc<Mutex<()>>>>>,
 }
 
 impl ServerManager {
@@ -39,7 +95,9 @@ impl ServerManager {
             1000,
             std::time::Duration::from_secs(3600),
             Some(cache_dir.clone()),
-        ));
+        )
+        .with_credentials(config_manager.credentials())
+        .with_download_limits(config_manager.download_limits()));
         let artifact_store = Arc::new(ArtifactStore::new(artifacts_dir));
 
         Self {
@@ -54,9 +112,37 @@ impl ServerManager {
             cache,
             artifact_store,
             servers: Arc::new(Mutex::new(HashMap::new())),
+            notification_sender: broadcast::channel(32).0,
+            wake_listeners: Arc::new(Mutex::new(HashMap::new())),
+            run_watchers: Arc::new(Mutex::new(HashMap::new())),
+            wake_sender: broadcast::channel(16).0,
+            tasks: Arc::new(TaskManager::new()),
+            events: Arc::new(EventBus::new()),
+            start_gate: Arc::new(Mutex::new(())),
+            lifecycle_locks: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
+    /// Desktop notifications fired by user-defined rules on any server.
+    pub fn subscribe_notifications(&self) -> broadcast::Receiver<DesktopNotification> {
+        self.notification_sender.subscribe()
+    }
+
+    /// IDs of servers being started because a player tried to join while they were stopped.
+    pub fn subscribe_wakes(&self) -> broadcast::Receiver<Uuid> {
+        self.wake_sender.subscribe()
+    }
+
+    /// Long-running operations (installs, imports, backups...) shared with the app.
+    pub fn get_task_manager(&self) -> Arc<TaskManager> {
+        Arc::clone(&self.tasks)
+    }
+
+    /// Recent startup phases and task changes, replayed to the UI after it reloads.
+    pub fn get_event_bus(&self) -> Arc<EventBus> {
+        Arc::clone(&self.events)
+    }
+
     pub fn get_downloader(&self) -> &VersionDownloader {
         &self.downloader
     }
@@ -65,6 +151,12 @@ impl ServerManager {
         &self.mod_loader_client
     }
 
+    /// Points PaperMC and Purpur build lookups at other hosts, e.g. a local mock.
+    pub fn with_loader_api_urls(mut self, papermc_api: String, purpur_api: String) -> Self {
+        self.mod_loader_client = self.mod_loader_client.with_api_urls(papermc_api, purpur_api);
+        self
+    }
+
     pub fn get_instance_manager(&self) -> Arc<InstanceManager> {
         Arc::clone(&self.instance_manager)
     }
@@ -73,6 +165,10 @@ impl ServerManager {
         Arc::clone(&self.cache)
     }
 
+    pub fn get_artifact_store(&self) -> Arc<ArtifactStore> {
+        Arc::clone(&self.artifact_store)
+    }
+
     pub async fn get_server(&self, instance_id: Uuid) -> Option<Arc<ServerHandle>> {
         let servers = self.servers.lock().await;
         servers.get(&instance_id).cloned()
@@ -85,10 +181,34 @@ impl ServerManager {
         mod_loader: Option<String>,
         loader_version: Option<String>,
     ) -> Result<InstanceMetadata> {
+        self.ensure_installable_offline(mod_loader.as_deref(), version, loader_version.as_deref())
+            .await?;
         let instance = self
             .instance_manager
             .create_instance_full(name, version, mod_loader, loader_version)
             .await?;
+
+        // Write server.properties up front so it can be edited before the first start
+        let writes_configs = install::pipeline::plan_steps(instance.mod_loader.as_deref())
+            .contains(&install::pipeline::ProvisionStep::WriteConfigs);
+        if writes_configs {
+            let defaults = self
+                .config_manager
+                .load()
+                .await
+                .map(|s| s.server_properties_defaults)
+                .unwrap_or_default();
+            if let Err(e) = write_default_server_properties(
+                &instance.path,
+                &instance.name,
+                instance.settings.port,
+                &defaults,
+            )
+            .await
+            {
+                warn!("Failed to write default server.properties for {}: {}", instance.name, e);
+            }
+        }
         Ok(instance)
     }
 
@@ -122,10 +242,19 @@ impl ServerManager {
 
         let instances = self.instance_manager.list_instances().await?;
         let mut active_hashes = HashSet::new();
+        // Server jars in the catalog are what offline installs are made from
+        let cataloged: HashSet<String> =
+            self.artifact_store.cached_servers().await.into_iter().map(|s| s.sha1).collect();
+        active_hashes.extend(cataloged.iter().cloned());
 
         for instance in instances {
             let instance_path = instance.path.clone();
 
+            // Jars kept for rolling back updates are only referenced by the history
+            active_hashes.extend(version_history::referenced_hashes(&instance_path).await);
+            // Linked libraries break or lose their sharing if their artifact goes
+            active_hashes.extend(shared_library_hashes(&instance_path).await);
+
             // 1. Handle server.jar
             let server_jar = instance_path.join("server.jar");
             if server_jar.exists() {
@@ -143,6 +272,9 @@ impl ServerManager {
                         {
                             warn!("Failed to add existing server.jar to store: {}", e);
                         } else {
+                            if !cataloged.contains(&hash) {
+                                self.remember_server_jar(&instance, &server_jar).await;
+                            }
                             active_hashes.insert(hash);
                         }
                     }
@@ -183,6 +315,9 @@ impl ServerManager {
             }
         }
 
+        // Spigot/CraftBukkit builds are slow to redo, so keep them even when unused
+        active_hashes.extend(self.cached_buildtools_hashes().await);
+
         // 3. Prune unlinked artifacts
         let pruned = self
             .artifact_store
@@ -0,0 +1,112 @@
+use super::ServerManager;
+use super::install::pipeline::installs_offline;
+use crate::artifacts::{CachedServer, HashAlgorithm};
+use crate::errors::AppError;
+use crate::instance::InstanceMetadata;
+use anyhow::Result;
+use std::path::Path;
+use tracing::{info, warn};
+
+fn unavailable_offline(mod_loader: Option<&str>, version: &str) -> anyhow::Error {
+    let server = match mod_loader {
+        Some(loader) => format!("{} {}", loader, version),
+        None => format!("Minecraft {}", version),
+    };
+    let reason = if installs_offline(mod_loader) {
+        "isn't in the local artifact store"
+    } else {
+        "needs its installer to download libraries"
+    };
+    AppError::Validation(format!("{} {}, so it can't be installed in offline mode", server, reason)).into()
+}
+
+impl ServerManager {
+    async fn offline_mode(&self) -> bool {
+        self.config_manager.load().await.map(|s| s.offline_mode).unwrap_or(false)
+    }
+
+    /// Servers that can be installed from the artifact store alone, newest first.
+    pub async fn get_offline_servers(&self) -> Vec<CachedServer> {
+        let mut servers: Vec<_> = self
+            .artifact_store
+            .cached_servers()
+            .await
+            .into_iter()
+            .filter(|s| installs_offline(s.server_type.as_deref()))
+            .collect();
+        servers.sort_by(|a, b| b.added_at.cmp(&a.added_at));
+        servers
+    }
+
+    /// The stored jar for a server. A pinned version must match exactly; an unpinned
+    /// loader only falls back to the newest stored build in offline mode.
+    async fn stored_server_jar(
+        &self,
+        mod_loader: Option<&str>,
+        version: &str,
+        loader_version: Option<&str>,
+        offline: bool,
+    ) -> Option<CachedServer> {
+        if !installs_offline(mod_loader) {
+            return None;
+        }
+        self.artifact_store
+            .find_server_jar(mod_loader, version, loader_version, offline)
+            .await
+    }
+
+    /// Refuses, in offline mode, a server that couldn't be installed.
+    pub(crate) async fn ensure_installable_offline(
+        &self,
+        mod_loader: Option<&str>,
+        version: &str,
+        loader_version: Option<&str>,
+    ) -> Result<()> {
+        if !self.offline_mode().await
+            || self.stored_server_jar(mod_loader, version, loader_version, true).await.is_some()
+        {
+            return Ok(());
+        }
+        Err(unavailable_offline(mod_loader, version))
+    }
+
+    /// Copies the server jar from the artifact store when it's there. Returns whether
+    /// it was, and fails in offline mode when it isn't.
+    pub(crate) async fn provision_stored_jar(&self, instance: &InstanceMetadata, jar_path: &Path) -> Result<bool> {
+        let offline = self.offline_mode().await;
+        let mod_loader = instance.mod_loader.as_deref();
+        let stored = self
+            .stored_server_jar(mod_loader, &instance.version, instance.loader_version.as_deref(), offline)
+            .await;
+        match stored {
+            Some(stored) => {
+                self.artifact_store
+                    .provision(&stored.sha1, HashAlgorithm::Sha1, jar_path)
+                    .await?;
+                info!("Installed {} {} from the artifact store", instance.name, instance.version);
+                Ok(true)
+            }
+            None if offline => Err(unavailable_offline(mod_loader, &instance.version)),
+            None => Ok(false),
+        }
+    }
+
+    /// Records a freshly downloaded server jar so it can be installed again offline.
+    pub(crate) async fn remember_server_jar(&self, instance: &InstanceMetadata, jar_path: &Path) {
+        if !installs_offline(instance.mod_loader.as_deref()) {
+            return;
+        }
+        if let Err(e) = self
+            .artifact_store
+            .add_server_jar(
+                jar_path,
+                instance.mod_loader.as_deref(),
+                &instance.version,
+                instance.loader_version.as_deref(),
+            )
+            .await
+        {
+            warn!("Failed to keep the server jar of {} for offline installs: {}", instance.name, e);
+        }
+    }
+}
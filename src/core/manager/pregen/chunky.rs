@@ -0,0 +1,71 @@
+use super::CONTROL_POLL;
+use crate::pregen::{PregenRequest, chunky_start_commands, parse_chunky_progress};
+use crate::server::{ServerHandle, ServerStatus};
+use crate::tasks::{CancelFlag, PauseFlag, TaskHandle};
+use anyhow::{Result, anyhow};
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast::error::RecvError;
+
+/// How long Chunky gets to report its first progress line before the run is given up,
+/// e.g. because the plugin didn't load.
+const CHUNKY_START_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Drives a Chunky run from the console, following its progress lines until it reports
+/// the task finished.
+pub(super) async fn run_chunky(
+    server: &ServerHandle,
+    request: &PregenRequest,
+    task: &TaskHandle,
+    cancel: &CancelFlag,
+    pause: &PauseFlag,
+) -> Result<()> {
+    let mut logs = server.subscribe_logs();
+    for command in chunky_start_commands(request) {
+        server.send_command(&command).await?;
+    }
+    task.progress(0, 10_000, "Waiting for Chunky");
+
+    let started = Instant::now();
+    let mut reported = false;
+    let mut paused = false;
+    let mut control = tokio::time::interval(CONTROL_POLL);
+    loop {
+        tokio::select! {
+            line = logs.recv() => match line {
+                Ok(line) => {
+                    let Some(progress) = parse_chunky_progress(&line) else {
+                        continue;
+                    };
+                    let message = match &progress.eta {
+                        Some(eta) => format!("{} chunks, ETA {}", progress.chunks, eta),
+                        None => format!("{} chunks", progress.chunks),
+                    };
+                    reported = true;
+                    task.progress((progress.percent * 100.0) as u64, 10_000, message);
+                    if progress.finished {
+                        return Ok(());
+                    }
+                }
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => return Err(anyhow!("Server stopped during pregeneration")),
+            },
+            _ = control.tick() => {
+                if cancel.is_cancelled() {
+                    server.send_command("chunky cancel").await?;
+                    server.send_command("chunky confirm").await?;
+                    return Err(anyhow!("Pregeneration cancelled"));
+                }
+                if pause.is_paused() != paused {
+                    paused = pause.is_paused();
+                    server.send_command(if paused { "chunky pause" } else { "chunky continue" }).await?;
+                }
+                if server.get_status().await != ServerStatus::Running {
+                    return Err(anyhow!("Server stopped during pregeneration"));
+                }
+                if !reported && started.elapsed() > CHUNKY_START_TIMEOUT {
+                    return Err(anyhow!("Chunky didn't report any progress. Check that it loaded on the server."));
+                }
+            }
+        }
+    }
+}
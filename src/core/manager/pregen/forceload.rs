@@ -0,0 +1,67 @@
+use super::CONTROL_POLL;
+use crate::pregen::{
+    PregenRequest, forceload_batches, forceload_chunk_command, forceload_command, forceload_query_command,
+    parse_forceloaded_chunks,
+};
+use crate::server::{ServerHandle, ServerStatus};
+use crate::tasks::{CancelFlag, PauseFlag, TaskHandle};
+use anyhow::{Result, anyhow};
+use std::time::Duration;
+
+/// How long the server gets to generate a force-loaded batch before it is released.
+const FORCELOAD_SETTLE: Duration = Duration::from_secs(10);
+const QUERY_QUIET: Duration = Duration::from_millis(500);
+const QUERY_MAX_WAIT: Duration = Duration::from_secs(3);
+
+/// Force-loads the area one batch at a time, giving the server time to generate each
+/// batch before releasing it again. Chunks that were force-loaded before the run are
+/// loaded again after their batch is released.
+pub(super) async fn run_forceload(
+    server: &ServerHandle,
+    request: &PregenRequest,
+    task: &TaskHandle,
+    cancel: &CancelFlag,
+    pause: &PauseFlag,
+) -> Result<()> {
+    let reply = server
+        .capture_command_output(&forceload_query_command(&request.world), QUERY_QUIET, QUERY_MAX_WAIT)
+        .await?;
+    let preloaded = parse_forceloaded_chunks(&reply);
+    let batches = forceload_batches(request);
+    let total: usize = batches.iter().map(|b| b.chunk_count()).sum();
+    let mut done = 0;
+
+    for (i, batch) in batches.iter().enumerate() {
+        while pause.is_paused() && !cancel.is_cancelled() {
+            tokio::time::sleep(CONTROL_POLL).await;
+        }
+        if cancel.is_cancelled() {
+            return Err(anyhow!("Pregeneration cancelled"));
+        }
+        if server.get_status().await != ServerStatus::Running {
+            return Err(anyhow!("Server stopped during pregeneration"));
+        }
+
+        server
+            .send_command(&forceload_command(&request.world, batch, true))
+            .await?;
+        tokio::time::sleep(FORCELOAD_SETTLE).await;
+        server
+            .send_command(&forceload_command(&request.world, batch, false))
+            .await?;
+        for (x, z) in preloaded.iter().filter(|(x, z)| batch.contains(*x, *z)) {
+            server
+                .send_command(&forceload_chunk_command(&request.world, *x, *z))
+                .await?;
+        }
+
+        done += batch.chunk_count();
+        task.progress(
+            done as u64,
+            total as u64,
+            format!("Batch {} of {}", i + 1, batches.len()),
+        );
+    }
+
+    server.send_command("save-all").await
+}
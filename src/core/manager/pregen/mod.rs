@@ -0,0 +1,132 @@
+use super::ServerManager;
+use crate::errors::AppError;
+use crate::instance::InstanceMetadata;
+use crate::mods::{self, ModProvider};
+use crate::plugins::{self, PluginProvider};
+use crate::pregen::{
+    CHUNKY_SLUG, ChunkyTarget, PregenMethod, PregenRequest, chunky_target, find_chunky_jar, resolve_method,
+};
+use crate::server::ServerStatus;
+use crate::tasks::{TaskHandle, TaskKind};
+use anyhow::{Context, Result, anyhow};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::warn;
+use uuid::Uuid;
+
+mod chunky;
+mod forceload;
+
+use chunky::run_chunky;
+use forceload::run_forceload;
+
+const CONTROL_POLL: Duration = Duration::from_millis(500);
+
+impl ServerManager {
+    /// Pregenerates the area around a point as a pausable, cancellable task and returns
+    /// the task id. Chunky is installed when it's needed and missing; a server that is
+    /// already running has to be restarted to load it. A stopped server is started.
+    pub async fn start_pregeneration(self: &Arc<Self>, instance_id: Uuid, request: PregenRequest) -> Result<Uuid> {
+        let instance = self
+            .instance_manager
+            .get_instance(instance_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+        let method = resolve_method(&request, instance.mod_loader.as_deref())?;
+
+        if method == PregenMethod::Chunky {
+            let target = chunky_target(instance.mod_loader.as_deref()).context("Chunky isn't available")?;
+            if find_chunky_jar(&instance.path, target).is_none() {
+                self.install_chunky(&instance, target).await?;
+                let status = self.get_server_status(instance_id).await;
+                if !matches!(status, ServerStatus::Stopped | ServerStatus::Crashed) {
+                    return Err(anyhow!(
+                        "Chunky was installed. Restart the server to load it, then start the pregeneration again."
+                    ));
+                }
+            }
+        }
+
+        let title = format!("Pregenerating {} ({} block radius)", instance.name, request.radius);
+        let task = self.tasks.start(TaskKind::Pregen, title, Some(instance_id));
+        let task_id = task.id();
+        let manager = Arc::clone(self);
+        tokio::spawn(async move {
+            let result = manager.run_pregeneration(&instance, &request, method, &task).await;
+            if let Err(e) = task.finish(result) {
+                warn!("Pregeneration of {} stopped: {}", instance.name, e);
+            }
+        });
+        Ok(task_id)
+    }
+
+    async fn install_chunky(&self, instance: &InstanceMetadata, target: ChunkyTarget) -> Result<()> {
+        self.install_modrinth_project(instance, CHUNKY_SLUG, target == ChunkyTarget::Plugin)
+            .await
+            .context("Failed to install Chunky")
+    }
+
+    /// Installs the latest build of a Modrinth project that fits the instance, into its
+    /// plugins folder or, for modded servers, its mods folder.
+    pub(super) async fn install_modrinth_project(
+        &self,
+        instance: &InstanceMetadata,
+        slug: &str,
+        as_plugin: bool,
+    ) -> Result<()> {
+        let loader = instance.mod_loader.as_deref().map(str::to_lowercase);
+        let result = if as_plugin {
+            plugins::install_plugin(
+                &instance.path,
+                slug,
+                PluginProvider::Modrinth,
+                None,
+                Some(&instance.version),
+                loader.as_deref(),
+                Arc::clone(&self.cache),
+            )
+            .await
+        } else {
+            mods::install_mod(
+                &instance.path,
+                slug,
+                ModProvider::Modrinth,
+                None,
+                Some(&instance.version),
+                loader.as_deref(),
+                None,
+                Arc::clone(&self.cache),
+            )
+            .await
+        };
+        result.map(|_| ())
+    }
+
+    async fn run_pregeneration(
+        &self,
+        instance: &InstanceMetadata,
+        request: &PregenRequest,
+        method: PregenMethod,
+        task: &TaskHandle,
+    ) -> Result<()> {
+        let cancel = task.cancel_flag();
+        let pause = task.pause_flag();
+
+        if self.get_server_status(instance.id).await != ServerStatus::Running {
+            task.progress(0, 0, "Starting server");
+            self.start_server(instance.id).await?;
+            self.wait_until_running(instance.id, instance.settings.startup_timeout)
+                .await?;
+        }
+        let server = {
+            let servers = self.servers.lock().await;
+            servers.get(&instance.id).cloned()
+        }
+        .context("Server not running")?;
+
+        match method {
+            PregenMethod::Chunky => run_chunky(&server, request, task, &cancel, &pause).await,
+            _ => run_forceload(&server, request, task, &cancel, &pause).await,
+        }
+    }
+}
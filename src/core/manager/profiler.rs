@@ -0,0 +1,175 @@
+use super::ServerManager;
+use crate::errors::AppError;
+use crate::instance::InstanceMetadata;
+use crate::profiler::{
+    ProfilerRequest, ProfilerSessionStatus, SPARK_SLUG, SparkTarget, find_spark_jar, parse_spark_failure,
+    parse_spark_url, profiler_cancel_command, profiler_start_command, profiler_stop_command, spark_command,
+    spark_target,
+};
+use crate::server::{ServerHandle, ServerStatus};
+use crate::tasks::{CancelFlag, TaskHandle, TaskKind, TaskState};
+use anyhow::{Context, Result, anyhow};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+const CONTROL_POLL: Duration = Duration::from_millis(500);
+/// Time spark gets to upload a profile once it has stopped.
+const UPLOAD_TIMEOUT: Duration = Duration::from_secs(60);
+
+impl ServerManager {
+    /// Starts a spark profiler run on a running server as a cancellable task and returns
+    /// the task id. spark is installed when it's missing; the server then has to be
+    /// restarted to load it. The run ends when spark reports the uploaded result, either
+    /// after the requested duration or after [`ServerManager::stop_profiling`].
+    pub async fn start_profiling(self: &Arc<Self>, instance_id: Uuid, request: ProfilerRequest) -> Result<Uuid> {
+        let instance = self
+            .instance_manager
+            .get_instance(instance_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+        let target = spark_target(instance.mod_loader.as_deref()).ok_or_else(|| {
+            AppError::Validation(format!(
+                "spark isn't available for {} servers",
+                instance.mod_loader.as_deref().unwrap_or("vanilla")
+            ))
+        })?;
+        if self.active_profiling_task(instance_id).is_some() {
+            return Err(AppError::Conflict(format!("{} is already being profiled", instance.name)).into());
+        }
+
+        let installed = find_spark_jar(&instance.path, target).is_none();
+        if installed {
+            self.install_modrinth_project(&instance, SPARK_SLUG, target == SparkTarget::Plugin)
+                .await
+                .context("Failed to install spark")?;
+        }
+        let running = self.get_server_status(instance_id).await == ServerStatus::Running;
+        match (installed, running) {
+            (true, true) => return Err(anyhow!("spark was installed. Restart the server to load it, then start profiling again.")),
+            (true, false) => return Err(anyhow!("spark was installed. Start the server, then start profiling.")),
+            (false, false) => return Err(anyhow!("Start the server before profiling it")),
+            (false, true) => {}
+        }
+        let server = {
+            let servers = self.servers.lock().await;
+            servers.get(&instance_id).cloned()
+        }
+        .context("Server not running")?;
+
+        let session_id = self
+            .instance_manager
+            .start_profiler_session(instance_id, request.duration_secs)
+            .await?;
+        let task = self.tasks.start(TaskKind::Profile, format!("Profiling {}", instance.name), Some(instance_id));
+        let task_id = task.id();
+        let cancel = task.cancel_flag();
+        let manager = Arc::clone(self);
+        tokio::spawn(async move {
+            let result = run_profiler(&server, &instance, &request, &task, &cancel).await;
+            let (status, url, error) = match &result {
+                Ok(url) => (ProfilerSessionStatus::Completed, Some(url.clone()), None),
+                Err(_) if cancel.is_cancelled() => (ProfilerSessionStatus::Cancelled, None, None),
+                Err(e) => (ProfilerSessionStatus::Failed, None, Some(e.to_string())),
+            };
+            if let Err(e) = manager
+                .instance_manager
+                .finish_profiler_session(session_id, status, url, error)
+                .await
+            {
+                warn!("Failed to save the profiler session of {}: {}", instance.name, e);
+            }
+            match task.finish(result) {
+                Ok(url) => info!("Profiled {}: {}", instance.name, url),
+                Err(e) => warn!("Profiling {} stopped: {}", instance.name, e),
+            }
+        });
+        Ok(task_id)
+    }
+
+    /// Stops the profiler so spark uploads what it has sampled; the running task picks
+    /// up the result.
+    pub async fn stop_profiling(&self, instance_id: Uuid) -> Result<()> {
+        if self.active_profiling_task(instance_id).is_none() {
+            return Err(AppError::Validation("No profiler is running for this instance".to_string()).into());
+        }
+        let instance = self
+            .instance_manager
+            .get_instance(instance_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+        let server = {
+            let servers = self.servers.lock().await;
+            servers.get(&instance_id).cloned()
+        }
+        .context("Server not running")?;
+        server
+            .send_command(&profiler_stop_command(spark_command(instance.mod_loader.as_deref())))
+            .await
+    }
+
+    fn active_profiling_task(&self, instance_id: Uuid) -> Option<Uuid> {
+        self.tasks
+            .list()
+            .into_iter()
+            .find(|t| t.kind == TaskKind::Profile && t.instance_id == Some(instance_id) && t.state == TaskState::Running)
+            .map(|t| t.id)
+    }
+}
+
+/// Starts spark and follows the console until it prints the link of the uploaded
+/// profile, which is returned.
+async fn run_profiler(
+    server: &ServerHandle,
+    instance: &InstanceMetadata,
+    request: &ProfilerRequest,
+    task: &TaskHandle,
+    cancel: &CancelFlag,
+) -> Result<String> {
+    let root = spark_command(instance.mod_loader.as_deref());
+    let mut logs = server.subscribe_logs();
+    server.send_command(&profiler_start_command(root, request)).await?;
+
+    let started = Instant::now();
+    let duration = request.duration_secs.filter(|s| *s > 0).map(|s| Duration::from_secs(s as u64));
+    let mut control = tokio::time::interval(CONTROL_POLL);
+    loop {
+        tokio::select! {
+            line = logs.recv() => match line {
+                Ok(line) => {
+                    if let Some(url) = parse_spark_url(&line) {
+                        return Ok(url);
+                    }
+                    if let Some(failure) = parse_spark_failure(&line) {
+                        return Err(anyhow!(failure));
+                    }
+                }
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => return Err(anyhow!("Server stopped while profiling")),
+            },
+            _ = control.tick() => {
+                if cancel.is_cancelled() {
+                    server.send_command(&profiler_cancel_command(root)).await?;
+                    return Err(anyhow!("Profiling cancelled"));
+                }
+                if server.get_status().await != ServerStatus::Running {
+                    return Err(anyhow!("Server stopped while profiling"));
+                }
+                let elapsed = started.elapsed();
+                match duration {
+                    Some(duration) if elapsed > duration + UPLOAD_TIMEOUT => {
+                        return Err(anyhow!("spark didn't report a result"));
+                    }
+                    Some(duration) => task.progress(
+                        elapsed.as_secs().min(duration.as_secs()),
+                        duration.as_secs(),
+                        if elapsed < duration { "Sampling" } else { "Uploading" },
+                    ),
+                    None => task.progress(0, 0, format!("Sampling for {}s", elapsed.as_secs())),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use super::ServerManager;
+use crate::errors::AppError;
+use crate::instance::InstanceMetadata;
+use crate::tasks::TaskKind;
+use anyhow::Result;
+use std::path::Path;
+use uuid::Uuid;
+
+impl ServerManager {
+    /// Moves a stopped instance to another storage root as a task, e.g. onto a second
+    /// disk. Starts wait until the move is done, and its server handle is dropped so the
+    /// next start uses the new folder.
+    pub async fn move_instance(&self, instance_id: Uuid, target_root: &Path) -> Result<InstanceMetadata> {
+        let _guard = self.lifecycle_guard(instance_id).await;
+        let instance = self
+            .instance_manager
+            .get_instance(instance_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+        self.ensure_stopped(instance_id, "moving it").await?;
+
+        let title = format!("Moving {} to {}", instance.name, target_root.display());
+        let task = self.tasks.start(TaskKind::Move, title, Some(instance_id));
+        let result = self
+            .instance_manager
+            .relocate_instance(instance_id, target_root, task.reporter())
+            .await;
+        let moved = task.finish(result)?;
+        self.servers.lock().await.remove(&instance_id);
+        Ok(moved)
+    }
+}
@@ -0,0 +1,122 @@
+use super::ServerManager;
+use crate::backup::BackupManager;
+use crate::errors::AppError;
+use crate::scheduler::SchedulerManager;
+use crate::server::ServerStatus;
+use crate::utils::fs::dir_size;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// What was removed along with one instance.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstanceCleanup {
+    pub id: Uuid,
+    pub name: String,
+    pub instance_bytes: u64,
+    pub backups_removed: usize,
+    pub backup_bytes: u64,
+    pub schedules_removed: usize,
+    pub db_rows_removed: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeletionFailure {
+    pub id: Uuid,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeletionSummary {
+    pub deleted: Vec<InstanceCleanup>,
+    pub failed: Vec<DeletionFailure>,
+    /// Cached jars no remaining instance uses anymore.
+    pub artifact_bytes: u64,
+    /// Everything freed on disk: instance folders, backups and unused artifacts.
+    pub reclaimed_bytes: u64,
+}
+
+impl ServerManager {
+    /// Deletes instances together with their backups, schedules, database rows and any cached
+    /// artifacts only they used. Running servers are skipped, and one failure doesn't stop the rest.
+    pub async fn delete_instances(
+        &self,
+        ids: &[Uuid],
+        backup_manager: &BackupManager,
+        scheduler: Option<&SchedulerManager>,
+    ) -> DeletionSummary {
+        let mut summary = DeletionSummary::default();
+        for &id in ids {
+            match self.delete_with_data(id, backup_manager, scheduler).await {
+                Ok(cleanup) => summary.deleted.push(cleanup),
+                Err(e) => summary.failed.push(DeletionFailure { id, error: format!("{:#}", e) }),
+            }
+        }
+
+        if !summary.deleted.is_empty() {
+            let store_dir = self.instance_manager.get_base_dir().join("resources").join("artifacts");
+            let before = dir_size(&store_dir);
+            if let Err(e) = self.perform_maintenance().await {
+                warn!("Failed to prune artifacts after deleting instances: {}", e);
+            }
+            summary.artifact_bytes = before.saturating_sub(dir_size(&store_dir));
+        }
+
+        summary.reclaimed_bytes = summary.artifact_bytes
+            + summary.deleted.iter().map(|d| d.instance_bytes + d.backup_bytes).sum::<u64>();
+        info!(
+            "Deleted {} instances ({} failed), reclaimed {} bytes",
+            summary.deleted.len(),
+            summary.failed.len(),
+            summary.reclaimed_bytes
+        );
+        summary
+    }
+
+    async fn delete_with_data(
+        &self,
+        id: Uuid,
+        backup_manager: &BackupManager,
+        scheduler: Option<&SchedulerManager>,
+    ) -> Result<InstanceCleanup> {
+        if !matches!(self.get_server_status(id).await, ServerStatus::Stopped | ServerStatus::Crashed) {
+            return Err(AppError::Conflict("Stop the server before deleting it".to_string()).into());
+        }
+        let listeners = self.release_wake_listener(id).await;
+
+        // The instance itself goes first; if that fails its backups and schedules stay usable
+        let removed = self
+            .instance_manager
+            .remove_instance(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", id)))?;
+        drop(listeners);
+        self.servers.lock().await.remove(&id);
+        self.events.forget(id);
+        self.abort_run_watchers(id).await;
+
+        // The instance is gone at this point, so leftovers are logged rather than reported as a failure
+        let schedules_removed = match scheduler {
+            Some(scheduler) => scheduler.remove_instance_tasks(id).await.unwrap_or_else(|e| {
+                warn!("Failed to unschedule tasks of deleted instance {}: {}", id, e);
+                0
+            }),
+            None => 0,
+        };
+        let (backups_removed, backup_bytes) = backup_manager.delete_all_backups(id).await.unwrap_or_else(|e| {
+            warn!("Failed to delete backups of deleted instance {}: {}", id, e);
+            (0, 0)
+        });
+
+        Ok(InstanceCleanup {
+            id,
+            name: removed.instance.name,
+            instance_bytes: removed.bytes,
+            backups_removed,
+            backup_bytes,
+            schedules_removed,
+            db_rows_removed: removed.db_rows,
+        })
+    }
+}
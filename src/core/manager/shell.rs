@@ -0,0 +1,74 @@
+use super::ServerManager;
+use crate::errors::AppError;
+use crate::server::ServerHandle;
+use crate::shell::{SHELL_LOG_PREFIX, run_streamed, shell_command, validate_command};
+use crate::tasks::{TaskHandle, TaskKind};
+use crate::timeline::TimelineKind;
+use anyhow::{Result, anyhow};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+impl ServerManager {
+    /// Runs `command` in the instance folder as a cancellable task and returns the task
+    /// id. Output goes to the instance console; the command is recorded in the timeline.
+    pub async fn run_shell_command(self: &Arc<Self>, instance_id: Uuid, command: &str) -> Result<Uuid> {
+        let settings = self.config_manager.load().await?.shell;
+        if !settings.enabled {
+            return Err(anyhow!(
+                "The instance terminal is turned off; enable it in Settings first"
+            ));
+        }
+        let command = validate_command(command)?.to_string();
+        let instance = self
+            .instance_manager
+            .get_instance(instance_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+        let server = self.get_or_create_server(instance_id).await?;
+
+        self.instance_manager
+            .note_timeline_event(
+                instance_id,
+                TimelineKind::ShellCommand,
+                format!("Ran `{}`", command),
+                None,
+            )
+            .await;
+
+        let title = format!("{}: {}", instance.name, command.lines().next().unwrap_or_default());
+        let task = self.tasks.start(TaskKind::Shell, title, Some(instance_id));
+        let task_id = task.id();
+        let timeout = Duration::from_secs(settings.timeout_secs.max(1));
+        tokio::spawn(async move {
+            let result = run_command(&command, &instance.path, &server, timeout, &task).await;
+            if let Err(e) = &result {
+                server.emit_log(format!("{} {}", SHELL_LOG_PREFIX, e));
+            }
+            let _ = task.finish(result);
+        });
+        Ok(task_id)
+    }
+}
+
+async fn run_command(
+    command: &str,
+    instance_dir: &Path,
+    server: &ServerHandle,
+    timeout: Duration,
+    task: &TaskHandle,
+) -> Result<()> {
+    let cancel = task.cancel_flag();
+    server.emit_log(format!("{} $ {}", SHELL_LOG_PREFIX, command));
+    task.progress(0, 0, "Running");
+    run_streamed(
+        shell_command(command, instance_dir),
+        timeout,
+        || cancel.is_cancelled(),
+        |line| server.emit_log(format!("{} {}", SHELL_LOG_PREFIX, line)),
+    )
+    .await?;
+    server.emit_log(format!("{} Exited with code 0", SHELL_LOG_PREFIX));
+    Ok(())
+}
@@ -0,0 +1,186 @@
+use super::ServerManager;
+use crate::artifacts::{HashAlgorithm, ProvisionMode};
+use crate::server::ServerStatus;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+use tracing::{info, warn};
+use uuid::Uuid;
+use walkdir::WalkDir;
+
+/// Records which library files of an instance are links into the artifact store, so
+/// maintenance keeps those artifacts. Paths are relative to the instance folder.
+pub const SHARED_LIBRARIES_FILE: &str = ".shared-libraries.json";
+
+const LIBRARY_DIRS: [&str; 2] = ["libraries", ".fabric"];
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct SharedLibraries {
+    files: BTreeMap<String, String>,
+}
+
+impl SharedLibraries {
+    async fn load(instance_path: &Path) -> Self {
+        match tokio::fs::read_to_string(instance_path.join(SHARED_LIBRARIES_FILE)).await {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+            Err(_) => Self::default(),
+        }
+    }
+
+    async fn save(&self, instance_path: &Path) -> Result<()> {
+        let path = instance_path.join(SHARED_LIBRARIES_FILE);
+        if self.files.is_empty() {
+            let _ = tokio::fs::remove_file(path).await;
+            return Ok(());
+        }
+        tokio::fs::write(path, serde_json::to_string_pretty(self)?).await?;
+        Ok(())
+    }
+}
+
+/// Store hashes of the shared libraries that are still in place in an instance.
+pub async fn shared_library_hashes(instance_path: impl AsRef<Path>) -> HashSet<String> {
+    let instance_path = instance_path.as_ref();
+    SharedLibraries::load(instance_path)
+        .await
+        .files
+        .into_iter()
+        .filter(|(relative, _)| instance_path.join(relative).exists())
+        .map(|(_, hash)| hash)
+        .collect()
+}
+
+/// What sharing one instance's libraries did.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LibrarySharing {
+    pub files_linked: usize,
+    /// Files left as copies because linking wasn't possible on this volume.
+    pub files_copied: usize,
+    pub bytes_saved: u64,
+    pub errors: Vec<String>,
+}
+
+/// Result of [`ServerManager::deduplicate_libraries`].
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DeduplicationReport {
+    pub mode: ProvisionMode,
+    pub instances: usize,
+    pub files_linked: usize,
+    pub files_copied: usize,
+    pub bytes_saved: u64,
+    /// Names of instances left alone because they were running.
+    pub skipped: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+impl ServerManager {
+    /// Replaces the library jars of one instance with links into the artifact store.
+    /// Instances share a single copy of each jar; a jar is only copied when no link can
+    /// be made to it. The server must not be running, since Windows keeps its jars locked.
+    pub async fn share_instance_libraries(&self, instance_path: &Path, mode: ProvisionMode) -> Result<LibrarySharing> {
+        let mut result = LibrarySharing::default();
+        let mut manifest = SharedLibraries::load(instance_path).await;
+        manifest.files.retain(|relative, _| instance_path.join(relative).exists());
+
+        let root = instance_path.to_path_buf();
+        let jars = tokio::task::spawn_blocking(move || {
+            LIBRARY_DIRS
+                .iter()
+                .flat_map(|dir| WalkDir::new(root.join(dir)).into_iter().filter_map(|e| e.ok()))
+                .filter(|e| !e.file_type().is_dir())
+                .map(|e| e.into_path())
+                .filter(|p| p.extension().is_some_and(|ext| ext == "jar"))
+                .collect::<Vec<_>>()
+        })
+        .await?;
+
+        for jar in jars {
+            let Ok(relative) = jar.strip_prefix(instance_path) else { continue };
+            let relative = relative.to_string_lossy().replace('\\', "/");
+            // Reflinked files look like copies on disk, so the manifest tells whether a
+            // jar was shared already
+            if let Some(hash) = manifest.files.get(&relative) {
+                let unchanged = self.artifact_store.calculate_hash(&jar, HashAlgorithm::Sha1).await.ok().as_ref() == Some(hash);
+                if unchanged && self.artifact_store.exists(hash, HashAlgorithm::Sha1).await {
+                    result.files_linked += 1;
+                    continue;
+                }
+            }
+            match self.artifact_store.share_file(&jar, mode).await {
+                Ok(shared) if shared.mode == ProvisionMode::Copy => {
+                    result.files_copied += 1;
+                    manifest.files.remove(&relative);
+                }
+                Ok(shared) => {
+                    result.files_linked += 1;
+                    result.bytes_saved += shared.bytes_saved;
+                    manifest.files.insert(relative, shared.hash);
+                }
+                Err(e) => result.errors.push(format!("{}: {}", relative, e)),
+            }
+        }
+
+        manifest.save(instance_path).await?;
+        Ok(result)
+    }
+
+    /// Links the library jars of every stopped instance into the artifact store with the
+    /// configured sharing mode, for instances installed before sharing was turned on.
+    /// Hard links are used when sharing is set to `Copy`.
+    pub async fn deduplicate_libraries(&self) -> Result<DeduplicationReport> {
+        let mode = match self.config_manager.load().await?.library_sharing {
+            ProvisionMode::Copy => ProvisionMode::Hardlink,
+            mode => mode,
+        };
+        let mut report = DeduplicationReport { mode, ..Default::default() };
+
+        for instance in self.instance_manager.list_instances().await? {
+            if !matches!(self.get_server_status(instance.id).await, ServerStatus::Stopped | ServerStatus::Crashed) {
+                report.skipped.push(instance.name);
+                continue;
+            }
+            match self.share_instance_libraries(&instance.path, mode).await {
+                Ok(result) => {
+                    report.instances += 1;
+                    report.files_linked += result.files_linked;
+                    report.files_copied += result.files_copied;
+                    report.bytes_saved += result.bytes_saved;
+                    report
+                        .errors
+                        .extend(result.errors.into_iter().map(|e| format!("{}: {}", instance.name, e)));
+                }
+                Err(e) => report.errors.push(format!("{}: {}", instance.name, e)),
+            }
+        }
+
+        info!(
+            "Deduplicated libraries of {} instances: {} files linked, {} bytes saved",
+            report.instances, report.files_linked, report.bytes_saved
+        );
+        Ok(report)
+    }
+
+    /// Shares a fresh install's libraries when sharing is turned on. Failures only cost
+    /// disk space, so they are logged rather than failing the install.
+    pub(crate) async fn share_installed_libraries(&self, instance_id: Uuid, instance_path: &Path) {
+        let mode = match self.config_manager.load().await {
+            Ok(settings) => settings.library_sharing,
+            Err(e) => {
+                warn!("Failed to load settings for library sharing: {}", e);
+                return;
+            }
+        };
+        if mode == ProvisionMode::Copy {
+            return;
+        }
+        match self.share_instance_libraries(instance_path, mode).await {
+            Ok(result) => {
+                for error in result.errors {
+                    warn!("Failed to share a library of {}: {}", instance_id, error);
+                }
+            }
+            Err(e) => warn!("Failed to share the libraries of {}: {}", instance_id, e),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use super::ServerManager;
+use crate::server::ServerStatus;
+use anyhow::Result;
+use serde::Serialize;
+use uuid::Uuid;
+
+/// A lightweight view of an instance for places that only show its state,
+/// such as the system tray.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct InstanceSummary {
+    pub id: Uuid,
+    pub name: String,
+    pub status: ServerStatus,
+    pub online_players: usize,
+    pub max_players: Option<u32>,
+}
+
+impl ServerManager {
+    /// Summarizes every instance with its live status and player count, sorted by name.
+    pub async fn get_instance_summaries(&self) -> Result<Vec<InstanceSummary>> {
+        let instances = self.instance_manager.list_instances().await?;
+        let servers = self.servers.lock().await;
+
+        let mut summaries = Vec::with_capacity(instances.len());
+        for instance in instances {
+            let (status, online_players) = match servers.get(&instance.id) {
+                Some(server) => (
+                    server.get_status().await,
+                    server.online_players.lock().await.len(),
+                ),
+                None => (ServerStatus::Stopped, 0),
+            };
+            summaries.push(InstanceSummary {
+                id: instance.id,
+                name: instance.name,
+                status,
+                online_players,
+                max_players: instance.max_players,
+            });
+        }
+
+        summaries.sort_by_key(|s| s.name.to_lowercase());
+        Ok(summaries)
+    }
+}
@@ -0,0 +1,134 @@
+use super::ServerManager;
+use crate::mods;
+use crate::plugins;
+use crate::instance::InstanceMetadata;
+use crate::templates::{InstanceTemplate, TemplateContent, TemplateReport};
+use crate::utils::{safe_join, write_atomic};
+use anyhow::{Context, Result};
+use tokio::fs;
+use tracing::{info, warn};
+
+impl ServerManager {
+    /// Creates a fresh instance from a stored template. Mods and plugins are downloaded
+    /// again from their providers; the server jar is fetched on first start as usual.
+    pub async fn create_instance_from_template(&self, template_id: i64, name: &str) -> Result<TemplateReport> {
+        let template = self
+            .instance_manager
+            .get_template(template_id)
+            .await?
+            .context("Template not found")?;
+
+        let instance = self
+            .create_instance_full(name, &template.version, template.mod_loader.clone(), template.loader_version.clone())
+            .await?;
+
+        // Don't leave a half-provisioned instance behind
+        let id = instance.id;
+        match self.apply_template(&template, instance).await {
+            Ok(report) => Ok(report),
+            Err(e) => {
+                if let Err(cleanup) = self.instance_manager.delete_instance(id).await {
+                    warn!("Failed to remove instance {} after its template failed: {:#}", id, cleanup);
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Installs the template's content, config files and settings into `instance`.
+    async fn apply_template(&self, template: &InstanceTemplate, instance: InstanceMetadata) -> Result<TemplateReport> {
+        let mut installed = Vec::new();
+        let mut failed = Vec::new();
+        let game_version = Some(instance.version.as_str());
+        let loader = instance.mod_loader.as_deref();
+
+        for content in &template.content {
+            let result = match content {
+                TemplateContent::Mod { project_id, provider, version_id, enabled } => {
+                    mods::install_mod(
+                        &instance.path,
+                        project_id,
+                        *provider,
+                        version_id.as_deref(),
+                        game_version,
+                        loader,
+                        self.config_manager.credentials().curseforge_api_key(),
+                        self.get_cache(),
+                    )
+                    .await
+                    .map(|file| (file, *enabled))
+                }
+                TemplateContent::Plugin { project_id, provider, version_id, enabled } => {
+                    plugins::install_plugin(
+                        &instance.path,
+                        project_id,
+                        *provider,
+                        version_id.as_deref(),
+                        game_version,
+                        loader,
+                        self.get_cache(),
+                    )
+                    .await
+                    .map(|file| (file, *enabled))
+                }
+            };
+            match result {
+                Ok((file, enabled)) => {
+                    if !enabled {
+                        let toggled = match content {
+                            TemplateContent::Mod { .. } => mods::toggle_mod(&instance.path, file.clone(), false).await,
+                            TemplateContent::Plugin { .. } => plugins::toggle_plugin(&instance.path, file.clone(), false).await,
+                        };
+                        if let Err(e) = toggled {
+                            warn!("Failed to disable {} for {}: {}", file, instance.name, e);
+                        }
+                    }
+                    installed.push(file);
+                }
+                Err(e) => {
+                    let project_id = match content {
+                        TemplateContent::Mod { project_id, .. } | TemplateContent::Plugin { project_id, .. } => project_id,
+                    };
+                    warn!("Failed to install {} from template {}: {}", project_id, template.name, e);
+                    failed.push(format!("{}: {}", project_id, e));
+                }
+            }
+        }
+
+        // Written after content so template configs win over freshly generated defaults
+        for file in &template.config_files {
+            let written = async {
+                let path = safe_join(&instance.path, &file.rel_path)?;
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent).await?;
+                }
+                write_atomic(&path, file.content.as_bytes(), false).await
+            }
+            .await;
+            if let Err(e) = written {
+                failed.push(format!("{}: {}", file.rel_path, e));
+            }
+        }
+
+        // Keep the new instance's port so it does not clash with the template's source
+        let mut settings = template.settings.clone();
+        settings.port = instance.settings.port;
+        self.instance_manager
+            .update_settings(instance.id, None, settings)
+            .await?;
+        let instance = self
+            .instance_manager
+            .get_instance(instance.id)
+            .await?
+            .context("Instance vanished while applying template")?;
+
+        info!(
+            "Created {} from template {}: {} installed, {} failed",
+            instance.name,
+            template.name,
+            installed.len(),
+            failed.len()
+        );
+        Ok(TemplateReport { instance, installed, failed })
+    }
+}
@@ -0,0 +1,36 @@
+use super::ServerManager;
+use crate::server::ServerHandle;
+use crate::tasks::TaskKind;
+use anyhow::Result;
+use std::future::Future;
+use tokio::sync::broadcast::error::RecvError;
+use uuid::Uuid;
+
+impl ServerManager {
+    /// Runs `work` as a task, reporting the download/install progress the server handle
+    /// emits while it runs.
+    pub(crate) async fn run_tracked<T>(
+        &self,
+        server: &ServerHandle,
+        kind: TaskKind,
+        title: String,
+        instance_id: Uuid,
+        work: impl Future<Output = Result<T>>,
+    ) -> Result<T> {
+        let task = self.tasks.start(kind, title, Some(instance_id));
+        let mut progress = server.subscribe_progress();
+        let report = task.reporter();
+        let forward = tokio::spawn(async move {
+            loop {
+                match progress.recv().await {
+                    Ok(p) => report(p.current, p.total, p.message),
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+        let result = work.await;
+        forward.abort();
+        task.finish(result)
+    }
+}
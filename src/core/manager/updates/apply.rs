@@ -0,0 +1,150 @@
+use super::ServerBuildUpdate;
+use crate::artifacts::HashAlgorithm;
+use crate::errors::AppError;
+use crate::manager::ServerManager;
+use crate::server::ServerStatus;
+use crate::tasks::TaskKind;
+use crate::timeline::TimelineKind;
+use anyhow::{Context, Result, anyhow};
+use std::sync::Arc;
+use std::sync::atomic::AtomicU32;
+use tracing::info;
+use uuid::Uuid;
+
+impl ServerManager {
+    /// Replaces the server jar of a stopped instance with the latest upstream build.
+    ///
+    /// The new jar is routed through the artifact store; configs, worlds and plugins
+    /// are left untouched. Returns the update that was applied, including its changelog.
+    ///
+    /// Experimental builds are refused on production instances unless `allow_experimental` is set.
+    pub async fn apply_server_update(
+        &self,
+        instance_id: Uuid,
+        allow_experimental: bool,
+    ) -> Result<ServerBuildUpdate> {
+        let status = self.get_server_status(instance_id).await;
+        if !matches!(status, ServerStatus::Stopped | ServerStatus::Crashed) {
+            return Err(AppError::Conflict("Stop the server before applying an update".to_string()).into());
+        }
+
+        let update = self.check_server_update(instance_id).await?;
+        if !update.update_available {
+            return Ok(update);
+        }
+        if update.production && update.latest_experimental && !allow_experimental {
+            return Err(anyhow!(
+                "Build {} is experimental; confirm to install it on a production instance",
+                update.latest_build
+            ));
+        }
+
+        let instance = self
+            .instance_manager
+            .get_instance(instance_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
+        let server = self.get_or_create_server(instance_id).await?;
+        let jar_path = self
+            .build_server_config(&instance)
+            .await
+            .jar_path
+            .unwrap_or_else(|| instance.path.join("server.jar"));
+
+        let msg = format!(
+            "Updating {} {} from build {} to {}",
+            update.server_type,
+            update.version,
+            update.installed_build.as_deref().unwrap_or("unknown"),
+            update.latest_build
+        );
+        info!("{}", msg);
+        server.emit_log(msg);
+
+        let temp_dir = self.instance_manager.get_base_dir().join("cache").join("temp");
+        tokio::fs::create_dir_all(&temp_dir).await?;
+        let temp_jar = temp_dir.join(format!(
+            "{}-{}-{}.jar",
+            update.server_type, update.version, update.latest_build
+        ));
+
+        let server_clone = Arc::clone(&server);
+        let last_percent = Arc::new(AtomicU32::new(0));
+        let download = self.mod_loader_client.download_loader(
+            &update.server_type,
+            &update.version,
+            Some(&update.latest_build),
+            &temp_jar,
+            move |current, total| {
+                server_clone.handle_download_progress(
+                    current,
+                    total,
+                    "Downloading server update...",
+                    &last_percent,
+                );
+            },
+        );
+        let title = format!("Updating {} to build {}", instance.name, update.latest_build);
+        let result = self
+            .run_tracked(&server, TaskKind::Download, title, instance_id, download)
+            .await;
+        if let Err(e) = result {
+            let _ = tokio::fs::remove_file(&temp_jar).await;
+            return Err(e);
+        }
+
+        let hash = self
+            .artifact_store
+            .calculate_hash(&temp_jar, HashAlgorithm::Sha1)
+            .await?;
+        self.artifact_store
+            .add_artifact(&temp_jar, &hash, HashAlgorithm::Sha1)
+            .await?;
+        let _ = tokio::fs::remove_file(&temp_jar).await;
+
+        // Keep the old jar around until the new one is in place so a failed swap can be undone
+        let previous_jar = jar_path.with_extension("jar.previous");
+        if jar_path.exists() {
+            tokio::fs::rename(&jar_path, &previous_jar)
+                .await
+                .context("Failed to move the current server jar aside")?;
+        }
+        if let Err(e) = self
+            .artifact_store
+            .provision(&hash, HashAlgorithm::Sha1, &jar_path)
+            .await
+        {
+            if previous_jar.exists() {
+                let _ = tokio::fs::rename(&previous_jar, &jar_path).await;
+            }
+            return Err(e);
+        }
+        let _ = tokio::fs::remove_file(&previous_jar).await;
+
+        self.instance_manager
+            .update_loader_version(instance_id, Some(update.latest_build.clone()))
+            .await?;
+
+        if let Some(instance) = self.instance_manager.get_instance(instance_id).await? {
+            let config = self.build_server_config(&instance).await;
+            server.update_config(config).await;
+        }
+
+        self.instance_manager
+            .note_timeline_event(
+                instance_id,
+                TimelineKind::UpdateApplied,
+                format!(
+                    "{} {} updated from build {} to {}",
+                    update.server_type,
+                    update.version,
+                    update.installed_build.as_deref().unwrap_or("unknown"),
+                    update.latest_build
+                ),
+                None,
+            )
+            .await;
+        server.emit_log(format!("Server updated to build {}", update.latest_build));
+        Ok(update)
+    }
+}
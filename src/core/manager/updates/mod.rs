@@ -0,0 +1,72 @@
+mod apply;
+
+use super::ServerManager;
+use crate::errors::AppError;
+use crate::mod_loaders::{BuildChange, is_newer_build};
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Server types whose builds can be tracked against upstream.
+const UPDATABLE_SERVER_TYPES: [&str; 4] = ["paper", "folia", "purpur", "velocity"];
+
+/// Result of comparing an instance's installed build against upstream.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ServerBuildUpdate {
+    pub instance_id: Uuid,
+    pub server_type: String,
+    pub version: String,
+    pub installed_build: Option<String>,
+    pub latest_build: String,
+    /// The latest build is on PaperMC's experimental channel.
+    pub latest_experimental: bool,
+    /// The instance is tagged as production, so installing an experimental build
+    /// must be confirmed.
+    pub production: bool,
+    pub update_available: bool,
+    pub changelog: Vec<BuildChange>,
+}
+
+impl ServerManager {
+    /// Checks whether a newer Paper/Purpur/Velocity build is available for an instance.
+    pub async fn check_server_update(&self, instance_id: Uuid) -> Result<ServerBuildUpdate> {
+        let instance = self
+            .instance_manager
+            .get_instance(instance_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Instance not found: {}", instance_id)))?;
+
+        let server_type = instance
+            .mod_loader
+            .as_deref()
+            .map(|l| l.to_lowercase())
+            .filter(|l| UPDATABLE_SERVER_TYPES.contains(&l.as_str()))
+            .ok_or_else(|| {
+                anyhow!("Build updates are only available for Paper, Folia, Purpur and Velocity instances")
+            })?;
+
+        let upstream = self
+            .mod_loader_client
+            .get_upstream_builds(
+                &server_type,
+                &instance.version,
+                instance.loader_version.as_deref(),
+            )
+            .await?;
+
+        let update_available =
+            is_newer_build(&upstream.latest_build, instance.loader_version.as_deref());
+
+        Ok(ServerBuildUpdate {
+            instance_id,
+            server_type,
+            version: instance.version,
+            installed_build: instance.loader_version,
+            latest_build: upstream.latest_build,
+            latest_experimental: upstream.latest_experimental,
+            production: instance.settings.production,
+            update_available,
+            changelog: upstream.changelog,
+        })
+    }
+}
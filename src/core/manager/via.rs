@@ -0,0 +1,165 @@
+use super::ServerManager;
+use crate::instance::InstanceMetadata;
+use crate::plugins::{
+    self, InstalledPlugin, ModrinthClient, PluginProvider, ViaPlan, ViaPlugin, ViaTarget, plan_via_setup,
+};
+use crate::server::ServerStatus;
+use anyhow::{Context, Result, anyhow};
+use serde::Serialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ViaAction {
+    Installed,
+    Updated,
+    UpToDate,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ViaChange {
+    pub instance_name: String,
+    pub plugin: ViaPlugin,
+    pub version: String,
+    pub action: ViaAction,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ViaSetupReport {
+    pub plan: ViaPlan,
+    pub changes: Vec<ViaChange>,
+}
+
+const PROXY_LOADERS: [&str; 2] = ["velocity", "bungeecord"];
+
+fn is_proxy(instance: &InstanceMetadata) -> bool {
+    instance
+        .mod_loader
+        .as_deref()
+        .is_some_and(|l| PROXY_LOADERS.contains(&l.to_lowercase().as_str()))
+}
+
+impl ServerManager {
+    /// The proxy an instance belongs to (itself, if it is one) and that proxy's local backends.
+    pub(super) async fn proxy_network(
+        &self,
+        instance: &InstanceMetadata,
+        instances: &[InstanceMetadata],
+    ) -> Option<(InstanceMetadata, Vec<InstanceMetadata>)> {
+        let backends_of = |ids: Vec<Uuid>| -> Vec<InstanceMetadata> {
+            instances.iter().filter(|i| ids.contains(&i.id)).cloned().collect()
+        };
+        for proxy in instances.iter().filter(|i| is_proxy(i)) {
+            let ids: Vec<Uuid> = match self.instance_manager.proxy_compatibility(proxy.id).await {
+                Ok(report) => report.backends.iter().filter_map(|b| b.instance_id).collect(),
+                Err(_) => vec![],
+            };
+            if proxy.id == instance.id || ids.contains(&instance.id) {
+                return Some((proxy.clone(), backends_of(ids)));
+            }
+        }
+        None
+    }
+
+    /// Works out where ViaVersion/ViaBackwards belong for this instance's network.
+    pub async fn plan_via(&self, instance_id: Uuid) -> Result<ViaPlan> {
+        let instances = self.instance_manager.list_instances().await?;
+        let instance = instances
+            .iter()
+            .find(|i| i.id == instance_id)
+            .context("Instance not found")?;
+        let network = self.proxy_network(instance, &instances).await;
+        plan_via_setup(instance, network.as_ref().map(|(p, b)| (p, b.as_slice())))
+    }
+
+    /// Installs ViaVersion and ViaBackwards where the plan puts them, or updates them to
+    /// the latest release when they are already there. Running targets need a restart.
+    pub async fn setup_via(&self, instance_id: Uuid) -> Result<ViaSetupReport> {
+        let mut plan = self.plan_via(instance_id).await?;
+        let mut changes = Vec::new();
+        for target in &plan.targets {
+            changes.extend(self.install_via_on(target).await?);
+            let status = self.get_server_status(target.instance_id).await;
+            if matches!(status, ServerStatus::Running | ServerStatus::Starting) {
+                plan.notes.push(format!("Restart {} to load the Via plugins", target.instance_name));
+            }
+        }
+        Ok(ViaSetupReport { plan, changes })
+    }
+
+    async fn install_via_on(&self, target: &ViaTarget) -> Result<Vec<ViaChange>> {
+        let instance = self
+            .instance_manager
+            .get_instance(target.instance_id)
+            .await?
+            .context("Instance not found")?;
+        let installed = plugins::list_installed_plugins(&instance.path).await?;
+
+        let mut changes = Vec::new();
+        for plugin in ViaPlugin::ALL {
+            let existing = installed.iter().find(|p| p.name.eq_ignore_ascii_case(plugin.name()));
+            let (version, action) = self
+                .ensure_modrinth_plugin(&instance, existing, plugin.modrinth_slug(), plugin.name(), &target.loader)
+                .await?;
+            changes.push(ViaChange {
+                instance_name: instance.name.clone(),
+                plugin,
+                version,
+                action,
+            });
+        }
+        Ok(changes)
+    }
+
+    /// Installs the latest Modrinth release of a plugin, or updates `existing` when it is
+    /// older. Returns the version it ended up on.
+    pub(super) async fn ensure_modrinth_plugin(
+        &self,
+        instance: &InstanceMetadata,
+        existing: Option<&InstalledPlugin>,
+        slug: &str,
+        name: &str,
+        loader: &str,
+    ) -> Result<(String, ViaAction)> {
+        let client = ModrinthClient::new(Arc::clone(&self.cache));
+        let latest = client
+            .get_versions(slug, None, Some(loader))
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow!("No {} release for {}", name, loader))?;
+        let action = match existing {
+            Some(p) if p.source.as_ref().and_then(|s| s.current_version_id.as_ref()) == Some(&latest.id) => {
+                ViaAction::UpToDate
+            }
+            Some(p) => {
+                plugins::update_plugin(
+                    &instance.path,
+                    p.filename.clone(),
+                    slug.to_string(),
+                    PluginProvider::Modrinth,
+                    latest.id.clone(),
+                    Arc::clone(&self.cache),
+                    &self.artifact_store,
+                )
+                .await?;
+                ViaAction::Updated
+            }
+            None => {
+                plugins::install_plugin(
+                    &instance.path,
+                    slug,
+                    PluginProvider::Modrinth,
+                    Some(&latest.id),
+                    None,
+                    Some(loader),
+                    Arc::clone(&self.cache),
+                )
+                .await?;
+                ViaAction::Installed
+            }
+        };
+        Ok((latest.version_number, action))
+    }
+}
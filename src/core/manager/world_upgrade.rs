@@ -0,0 +1,121 @@
+use super::ServerManager;
+use crate::errors::AppError;
+use crate::instance::InstanceMetadata;
+use crate::server::{ServerHandle, ServerStatus};
+use crate::tasks::{TaskHandle, TaskKind};
+use crate::timeline::TimelineKind;
+use crate::world_upgrade::{UpgradeLine, WorldUpgradeRequest, check_supported, parse_upgrade_line};
+use anyhow::{Result, anyhow};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::warn;
+use uuid::Uuid;
+
+const STATUS_POLL: Duration = Duration::from_secs(1);
+
+impl ServerManager {
+    /// Starts a stopped server once with `--forceUpgrade`, so every chunk is converted to
+    /// the server's version up front instead of lagging the first sessions, and stops it
+    /// again when the conversion is done. Returns the id of the task tracking it.
+    ///
+    /// Not cancellable: killing the server mid-conversion could leave chunks half-written.
+    pub async fn start_world_upgrade(
+        self: &Arc<Self>,
+        instance_id: Uuid,
+        request: WorldUpgradeRequest,
+    ) -> Result<Uuid> {
+        let instance = self
+            .instance_manager
+            .get_instance(instance_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Instance not found".to_string()))?;
+        check_supported(instance.mod_loader.as_deref())?;
+        let status = self.get_server_status(instance_id).await;
+        if !matches!(status, ServerStatus::Stopped | ServerStatus::Crashed) {
+            return Err(AppError::Conflict("Stop the server before upgrading its world".to_string()).into());
+        }
+
+        let title = format!("Upgrading the world of {} to {}", instance.name, instance.version);
+        let task = self.tasks.start(TaskKind::WorldUpgrade, title, Some(instance_id));
+        let task_id = task.id();
+        let manager = Arc::clone(self);
+        tokio::spawn(async move {
+            let result = manager.run_world_upgrade(&instance, &request, &task).await;
+            if let Err(e) = task.finish(result) {
+                warn!("World upgrade of {} stopped: {}", instance.name, e);
+            }
+        });
+        Ok(task_id)
+    }
+
+    async fn run_world_upgrade(
+        &self,
+        instance: &InstanceMetadata,
+        request: &WorldUpgradeRequest,
+        task: &TaskHandle,
+    ) -> Result<()> {
+        let server = self.get_or_create_server(instance.id).await?;
+        let mut logs = server.subscribe_logs();
+        task.progress(0, 0, "Starting server");
+        self.start_server_with_args(instance.id, &request.args()).await?;
+
+        let result = follow_upgrade(&server, &mut logs, task).await;
+
+        // The server finishes starting once the world is converted; stop it so the next
+        // start is a normal one
+        if server.get_status().await != ServerStatus::Stopped
+            && let Err(e) = server.stop().await
+        {
+            warn!("Failed to stop {} after its world upgrade: {}", instance.name, e);
+        }
+        if let Some(instance) = self.instance_manager.get_instance(instance.id).await? {
+            let config = self.build_server_config(&instance).await;
+            server.update_config(config).await;
+        }
+        result?;
+
+        self.instance_manager
+            .note_timeline_event(
+                instance.id,
+                TimelineKind::WorldUpgrade,
+                format!("World upgraded to {}", instance.version),
+                request.erase_cache.then(|| "Cached data was erased".to_string()),
+            )
+            .await;
+        server.emit_log(format!("World upgraded to {}", instance.version));
+        Ok(())
+    }
+}
+
+/// Follows the console until the server reports it is running, which it only does once
+/// every chunk has been converted.
+async fn follow_upgrade(
+    server: &ServerHandle,
+    logs: &mut tokio::sync::broadcast::Receiver<String>,
+    task: &TaskHandle,
+) -> Result<()> {
+    let mut poll = tokio::time::interval(STATUS_POLL);
+    loop {
+        tokio::select! {
+            line = logs.recv() => match line {
+                Ok(line) => match parse_upgrade_line(&line) {
+                    Some(UpgradeLine::Stage(stage)) => task.progress(0, 0, stage),
+                    Some(UpgradeLine::Progress { percent, done, total }) => {
+                        task.progress((percent * 100.0) as u64, 10_000, format!("{} of {} converted", done, total));
+                    }
+                    None => {}
+                },
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => return Err(anyhow!("Server stopped during the world upgrade")),
+            },
+            _ = poll.tick() => match server.get_status().await {
+                ServerStatus::Running => return Ok(()),
+                ServerStatus::Stopped | ServerStatus::Crashed => {
+                    return Err(anyhow!("Server stopped during the world upgrade; check its console for errors"));
+                }
+                _ => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use super::ServerManager;
+use crate::instance::{ExportFormat, WorldInfo};
+use anyhow::Result;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+impl ServerManager {
+    /// Switches the active world of a stopped instance.
+    pub async fn switch_world(&self, instance_id: Uuid, name: &str) -> Result<()> {
+        self.ensure_stopped(instance_id, "switching worlds").await?;
+        self.instance_manager.switch_world(instance_id, name).await
+    }
+
+    /// Duplicates a world of a stopped instance, so the copy is consistent on disk.
+    pub async fn duplicate_world(&self, instance_id: Uuid, source: &str, new_name: &str) -> Result<WorldInfo> {
+        self.ensure_stopped(instance_id, "duplicating a world").await?;
+        self.instance_manager.duplicate_world(instance_id, source, new_name).await
+    }
+
+    /// Exports a stopped instance, so the archive holds its worlds as they are on disk
+    /// rather than half written by the running server.
+    pub async fn export_instance<F>(&self, instance_id: Uuid, dest: PathBuf, format: ExportFormat, on_progress: F) -> Result<PathBuf>
+    where
+        F: Fn(u64, u64, String) + Send + Sync + 'static,
+    {
+        self.ensure_stopped(instance_id, "exporting it").await?;
+        self.instance_manager.export_instance(instance_id, dest, format, on_progress).await
+    }
+}
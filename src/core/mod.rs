@@ -1,23 +1,52 @@
 pub mod app_config;
 pub mod artifacts;
 pub mod assets;
+pub mod automation;
 pub mod backup;
 pub mod cache;
 pub mod config;
 pub mod config_files;
+pub mod conflicts;
+pub mod connection;
+pub mod console;
+pub mod content_report;
+pub mod crash;
+pub mod credentials;
 pub mod database;
+pub mod diagnostics;
+pub mod doctor;
 pub mod downloader;
 pub mod errors;
+pub mod events;
+pub mod file_manager;
 pub mod init;
 pub mod instance;
 pub mod java;
+pub mod loadouts;
+pub mod log_files;
 pub mod manager;
 pub mod mod_loaders;
 pub mod modrinth;
 pub mod mods;
+pub mod nbt;
+pub mod notifications;
 pub mod players;
 pub mod plugins;
+pub mod pregen;
+pub mod profiler;
+pub mod protocol;
+pub mod remote;
 pub mod scheduler;
 pub mod server;
+pub mod server_list;
 pub mod server_properties;
+pub mod shell;
+pub mod storage_mode;
+pub mod tasks;
+pub mod templates;
+pub mod timeline;
 pub mod utils;
+pub mod version_history;
+pub mod watcher;
+pub mod world_info;
+pub mod world_upgrade;
@@ -0,0 +1,60 @@
+use std::path::{Path, PathBuf};
+
+/// Argument file the Forge/NeoForge installer writes for the current platform.
+pub fn server_argfile_name() -> &'static str {
+    if cfg!(windows) { "win_args.txt" } else { "unix_args.txt" }
+}
+
+fn library_roots(loader: Option<&str>) -> &'static [&'static str] {
+    const FORGE: &str = "libraries/net/minecraftforge/forge";
+    const NEOFORGE: &str = "libraries/net/neoforged/neoforge";
+    match loader.map(|l| l.to_lowercase()).as_deref() {
+        Some("forge") => &[FORGE],
+        Some("neoforge") => &[NEOFORGE],
+        None => &[FORGE, NEOFORGE],
+        Some(_) => &[],
+    }
+}
+
+/// Finds the launch argfile of a modern (1.17+) Forge or NeoForge server, relative to
+/// `instance_dir`. These installs have no runnable server.jar; instead the JVM is started
+/// with `@libraries/.../unix_args.txt`. The folder for `loader_version` is preferred, and
+/// otherwise the most recently written argfile wins, so a loader updated outside the
+/// wrapper is still picked up. Imported instances with no known loader search both.
+pub fn find_server_argfile(
+    instance_dir: &Path,
+    loader: Option<&str>,
+    mc_version: &str,
+    loader_version: Option<&str>,
+) -> Option<PathBuf> {
+    let file_name = server_argfile_name();
+    let mut newest: Option<(std::time::SystemTime, PathBuf)> = None;
+
+    for root in library_roots(loader) {
+        if let Some(version) = loader_version {
+            let folder = if root.ends_with("/forge") {
+                format!("{}-{}", mc_version, version)
+            } else {
+                version.to_string()
+            };
+            let preferred = Path::new(root).join(folder).join(file_name);
+            if instance_dir.join(&preferred).is_file() {
+                return Some(preferred);
+            }
+        }
+
+        let Ok(entries) = std::fs::read_dir(instance_dir.join(root)) else {
+            continue;
+        };
+        for entry in entries.filter_map(|e| e.ok()) {
+            let candidate = entry.path().join(file_name);
+            let Ok(modified) = candidate.metadata().and_then(|m| m.modified()) else {
+                continue;
+            };
+            if newest.as_ref().is_none_or(|(t, _)| modified > *t) {
+                newest = Some((modified, Path::new(root).join(entry.file_name()).join(file_name)));
+            }
+        }
+    }
+    newest.map(|(_, path)| path)
+}
@@ -0,0 +1,90 @@
+use super::ModLoaderClient;
+use crate::utils::get_with_failover;
+use anyhow::{Result, anyhow};
+use regex::Regex;
+use std::sync::LazyLock;
+
+const BUILDTOOLS_URL: &str =
+    "https://hub.spigotmc.org/jenkins/job/BuildTools/lastSuccessfulBuild/artifact/target/BuildTools.jar";
+const SPIGOT_VERSIONS_URL: &str = "https://hub.spigotmc.org/versions/";
+
+static VERSION_LINK_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"href="(1\.\d+(?:\.\d+)?)\.json""#).unwrap());
+
+/// Server software compiled locally with SpigotMC's BuildTools, since neither may be
+/// redistributed as a ready-made jar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildToolsTarget {
+    Spigot,
+    CraftBukkit,
+}
+
+impl BuildToolsTarget {
+    pub fn from_loader(loader: &str) -> Option<Self> {
+        match loader.to_lowercase().as_str() {
+            "spigot" => Some(Self::Spigot),
+            "craftbukkit" => Some(Self::CraftBukkit),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Spigot => "Spigot",
+            Self::CraftBukkit => "CraftBukkit",
+        }
+    }
+
+    /// File BuildTools writes to its output directory.
+    pub fn output_jar(&self, mc_version: &str) -> String {
+        format!("{}-{}.jar", self.name().to_lowercase(), mc_version)
+    }
+
+    /// Arguments for `java -jar BuildTools.jar`, writing the jar to the working directory.
+    pub fn args(&self, mc_version: &str) -> Vec<String> {
+        vec![
+            "--rev".to_string(),
+            mc_version.to_string(),
+            "--compile".to_string(),
+            self.name().to_lowercase(),
+            "--output-dir".to_string(),
+            ".".to_string(),
+            "--nogui".to_string(),
+        ]
+    }
+}
+
+/// Minecraft versions listed in SpigotMC's version index page, newest first.
+pub fn parse_spigot_versions(html: &str) -> Vec<String> {
+    let mut versions: Vec<String> = VERSION_LINK_RE.captures_iter(html).map(|c| c[1].to_string()).collect();
+    let key = |v: &String| -> Vec<u32> { v.split('.').filter_map(|p| p.parse().ok()).collect() };
+    versions.sort_by_key(|v| std::cmp::Reverse(key(v)));
+    versions.dedup();
+    versions
+}
+
+impl ModLoaderClient {
+    /// Minecraft versions BuildTools can build, newest first.
+    pub async fn get_buildtools_versions(&self) -> Result<Vec<String>> {
+        let cache_key = "buildtools_versions".to_string();
+        if let Ok(Some(cached)) = self.cache.get::<Vec<String>>(&cache_key).await {
+            return Ok(cached);
+        }
+
+        let response = get_with_failover(&self.client, self.cache.download_limits(), SPIGOT_VERSIONS_URL).await?;
+        if !response.status().is_success() {
+            return Err(anyhow!("SpigotMC version index returned error: {}", response.status()));
+        }
+        let versions = parse_spigot_versions(&response.text().await?);
+
+        let _ = self.cache.set(cache_key, versions.clone()).await;
+        Ok(versions)
+    }
+
+    pub async fn download_buildtools<F>(&self, target_path: impl AsRef<std::path::Path>, on_progress: F) -> Result<()>
+    where
+        F: Fn(u64, u64) + Send + Sync + 'static,
+    {
+        self.download_with_progress(BUILDTOOLS_URL, target_path, on_progress)
+            .await
+    }
+}
@@ -7,6 +7,8 @@ pub struct ModLoaderClient {
     pub(crate) client: reqwest::Client,
     pub(crate) cache_dir: Option<PathBuf>,
     pub(crate) cache: Arc<CacheManager>,
+    pub(crate) papermc_api: String,
+    pub(crate) purpur_api: String,
 }
 
 impl ModLoaderClient {
@@ -20,6 +22,15 @@ impl ModLoaderClient {
                 .unwrap_or_else(|_| reqwest::Client::new()),
             cache_dir,
             cache,
+            papermc_api: "https://api.papermc.io/v2".to_string(),
+            purpur_api: "https://api.purpurmc.org/v2".to_string(),
         }
     }
+
+    /// Points the PaperMC and Purpur lookups at other hosts, e.g. a local mock.
+    pub fn with_api_urls(mut self, papermc_api: String, purpur_api: String) -> Self {
+        self.papermc_api = papermc_api.trim_end_matches('/').to_string();
+        self.purpur_api = purpur_api.trim_end_matches('/').to_string();
+        self
+    }
 }
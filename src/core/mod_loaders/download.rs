@@ -1,10 +1,8 @@
 use super::client::ModLoaderClient;
 use crate::utils::fs::is_jar_valid;
-use crate::utils::retry_async;
+use crate::utils::{download_to_file, retry_async, with_failover};
 use anyhow::{Result, anyhow};
-use futures_util::StreamExt;
 use std::time::Duration;
-use tokio::io::AsyncWriteExt;
 
 impl ModLoaderClient {
     pub(crate) async fn download_with_progress<F>(
@@ -18,28 +16,12 @@ impl ModLoaderClient {
     {
         let target_path_ref = target_path.as_ref();
         retry_async(
-            || async {
-                let response = self.client.get(url).send().await?;
-                if !response.status().is_success() {
-                    return Err(anyhow!("Failed to download: {}", response.status()));
-                }
-
-                let total_size = response.content_length().unwrap_or(0);
-                on_progress(0, total_size);
-
-                let mut file = tokio::fs::File::create(target_path_ref).await?;
-                let mut downloaded: u64 = 0;
-                let mut stream = response.bytes_stream();
-
-                while let Some(chunk_result) = stream.next().await {
-                    let chunk = chunk_result?;
-                    file.write_all(&chunk).await?;
-                    downloaded += chunk.len() as u64;
-                    on_progress(downloaded, total_size);
-                }
-
-                file.flush().await?;
-                Ok(())
+            || {
+                let limits = self.cache.download_limits();
+                with_failover(limits, url, |candidate| {
+                    let on_progress = &on_progress;
+                    async move { download_to_file(&self.client, limits, &candidate, target_path_ref, on_progress).await }
+                })
             },
             3,
             Duration::from_secs(2),
@@ -64,24 +46,54 @@ impl ModLoaderClient {
                 let build = match loader_version {
                     Some(v) => v.to_string(),
                     None => {
-                        let builds = self.get_paper_versions(mc_version).await?;
+                        // Prefer the newest stable build; versions may only have experimental ones
+                        let builds = self.get_paper_builds(mc_version).await?;
                         builds
-                            .first()
+                            .iter()
+                            .find(|b| b.channel == super::BuildChannel::Default)
+                            .or_else(|| builds.first())
                             .ok_or_else(|| {
                                 anyhow::anyhow!("No builds found for Paper version {}", mc_version)
                             })?
+                            .build
                             .clone()
                     }
                 };
                 self.download_paper(mc_version, &build, target_path.as_ref(), on_progress)
                     .await
             }
+            "folia" => {
+                let build = match loader_version {
+                    Some(v) => v.to_string(),
+                    None => self
+                        .get_folia_versions(mc_version)
+                        .await?
+                        .into_iter()
+                        .next()
+                        .ok_or_else(|| anyhow!("No builds found for Folia version {}", mc_version))?,
+                };
+                self.download_folia(mc_version, &build, target_path.as_ref(), on_progress)
+                    .await
+            }
+            "pufferfish" => {
+                self.download_pufferfish(mc_version, loader_version, target_path.as_ref(), on_progress)
+                    .await
+            }
             "fabric" => {
                 let version = loader_version
                     .ok_or_else(|| anyhow::anyhow!("Fabric requires a loader version"))?;
                 self.download_fabric(mc_version, version, target_path.as_ref(), on_progress)
                     .await
             }
+            "spigot" | "craftbukkit" => Err(anyhow!(
+                "{} has no downloadable jar; it is built with BuildTools during installation",
+                loader_name
+            )),
+            "quilt" => {
+                // Quilt has no prebuilt server jar, so its installer runs next to the target
+                self.install_quilt_server(mc_version, loader_version, target_path.as_ref(), on_progress)
+                    .await
+            }
             "forge" => {
                 let version =
                     loader_version.ok_or_else(|| anyhow::anyhow!("Forge requires a version"))?;
@@ -2,6 +2,7 @@ use serde::Deserialize;
 use anyhow::Result;
 use tracing::info;
 use super::ModLoaderClient;
+use crate::utils::get_with_failover;
 
 #[derive(Debug, Deserialize)]
 pub struct FabricLoaderVersion {
@@ -26,7 +27,7 @@ impl ModLoaderClient {
         }
 
         let url = format!("https://meta.fabricmc.net/v2/versions/loader/{}", mc_version);
-        let response = self.client.get(&url).send().await?;
+        let response = get_with_failover(&self.client, self.cache.download_limits(), &url).await?;
         
         if !response.status().is_success() {
             if response.status() == reqwest::StatusCode::NOT_FOUND {
@@ -52,7 +53,7 @@ impl ModLoaderClient {
         }
 
         let url = "https://meta.fabricmc.net/v2/versions/installer";
-        let response = self.client.get(url).send().await?;
+        let response = get_with_failover(&self.client, self.cache.download_limits(), url).await?;
         let installers: Vec<FabricInstallerVersion> = response.json().await?;
         let versions: Vec<String> = installers.into_iter().map(|i| i.version).collect();
         
@@ -0,0 +1,35 @@
+use super::{ModLoaderClient, PaperBuild};
+use anyhow::Result;
+
+/// Folia runs regions of the world on separate threads, so only plugins marked
+/// `folia-supported` in their plugin.yml load on it.
+pub fn requires_folia_plugins(loader: Option<&str>) -> bool {
+    loader.is_some_and(|l| l.eq_ignore_ascii_case("folia"))
+}
+
+impl ModLoaderClient {
+    /// Lists Folia builds for a version, newest first. Folia is published through the
+    /// same PaperMC API as Paper.
+    pub async fn get_folia_builds(&self, mc_version: &str) -> Result<Vec<PaperBuild>> {
+        self.list_papermc_builds("folia", mc_version).await
+    }
+
+    pub async fn get_folia_versions(&self, mc_version: &str) -> Result<Vec<String>> {
+        let builds = self.get_folia_builds(mc_version).await?;
+        Ok(builds.into_iter().map(|b| b.build).collect())
+    }
+
+    pub async fn download_folia<F>(
+        &self,
+        mc_version: &str,
+        build: &str,
+        target_path: impl AsRef<std::path::Path>,
+        on_progress: F,
+    ) -> Result<()>
+    where
+        F: Fn(u64, u64) + Send + Sync + 'static,
+    {
+        self.download_papermc("folia", mc_version, build, target_path, on_progress)
+            .await
+    }
+}
@@ -1,6 +1,12 @@
 use serde::Deserialize;
 use anyhow::Result;
 use super::ModLoaderClient;
+use crate::utils::get_with_failover;
+
+/// The official Forge Maven. Mirrors of it come from the configured API mirrors (see
+/// [`crate::utils::bmclapi_mirrors`]) rather than a built-in list, so nothing is fetched
+/// from a third party unless the user opted in.
+pub const FORGE_MAVEN: &str = "https://maven.minecraftforge.net";
 
 #[derive(Debug, Deserialize)]
 pub struct ForgePromotions {
@@ -15,7 +21,7 @@ impl ModLoaderClient {
         }
 
         let url = "https://files.minecraftforge.net/net/minecraftforge/forge/promotions_slim.json";
-        let response = self.client.get(url).send().await?;
+        let response = get_with_failover(&self.client, self.cache.download_limits(), url).await?;
 
         if !response.status().is_success() {
             return Ok(vec![]);
@@ -43,9 +49,9 @@ impl ModLoaderClient {
 
     pub async fn download_forge<F>(&self, mc_version: &str, forge_version: &str, target_path: impl AsRef<std::path::Path>, on_progress: F) -> Result<()> 
     where F: Fn(u64, u64) + Send + Sync + 'static {
-        // Forge download URL pattern: https://maven.minecraftforge.net/net/minecraftforge/forge/{mc_version}-{forge_version}/forge-{mc_version}-{forge_version}-installer.jar
+        // Forge download URL pattern: {maven}/net/minecraftforge/forge/{mc_version}-{forge_version}/forge-{mc_version}-{forge_version}-installer.jar
         let version_str = format!("{}-{}", mc_version, forge_version);
-        let url = format!("https://maven.minecraftforge.net/net/minecraftforge/forge/{}/forge-{}-installer.jar", version_str, version_str);
+        let url = format!("{}/net/minecraftforge/forge/{}/forge-{}-installer.jar", FORGE_MAVEN, version_str, version_str);
         self.download_with_progress(&url, target_path, on_progress).await
     }
 
@@ -1,15 +1,25 @@
+pub mod argfile;
 pub mod bedrock;
+pub mod buildtools;
 pub mod client;
 pub mod download;
 pub mod fabric;
+pub mod folia;
 pub mod forge;
 pub mod neoforge;
 pub mod paper;
 pub mod proxy;
+pub mod pufferfish;
 pub mod purpur;
 pub mod quilt;
 pub mod query;
 pub mod types;
+pub mod updates;
 
+pub use argfile::find_server_argfile;
+pub use buildtools::BuildToolsTarget;
 pub use client::*;
+pub use folia::requires_folia_plugins;
+pub use paper::{BuildChannel, PaperBuild};
 pub use types::*;
+pub use updates::*;
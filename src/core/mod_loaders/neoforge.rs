@@ -1,11 +1,12 @@
 use anyhow::Result;
 use super::ModLoaderClient;
+use crate::utils::get_with_failover;
 
 impl ModLoaderClient {
     pub async fn get_neoforge_versions(&self, mc_version: &str) -> Result<Vec<String>> {
         // NeoForge uses Maven metadata.
         let url = "https://maven.neoforged.net/releases/net/neoforged/neoforge/maven-metadata.xml";
-        let response = self.client.get(url).send().await?;
+        let response = get_with_failover(&self.client, self.cache.download_limits(), url).await?;
 
         if !response.status().is_success() {
             return Ok(vec![]);
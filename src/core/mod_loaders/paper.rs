@@ -1,6 +1,23 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use anyhow::Result;
 use super::ModLoaderClient;
+use crate::utils::get_with_failover;
+
+/// Release channel PaperMC assigns to each build.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BuildChannel {
+    #[default]
+    Default,
+    Experimental,
+}
+
+/// A Paper build number together with its release channel.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaperBuild {
+    pub build: String,
+    pub channel: BuildChannel,
+}
 
 #[derive(Debug, Deserialize)]
 pub struct PaperBuilds {
@@ -10,6 +27,8 @@ pub struct PaperBuilds {
 #[derive(Debug, Deserialize)]
 pub struct PaperBuildSummary {
     pub build: u32,
+    #[serde(default)]
+    pub channel: BuildChannel,
 }
 
 #[derive(Debug, Deserialize)]
@@ -30,36 +49,52 @@ pub struct PaperDownload {
 
 impl ModLoaderClient {
     pub async fn get_paper_versions(&self, mc_version: &str) -> Result<Vec<String>> {
-        let cache_key = format!("paper_versions_{}", mc_version);
-        if let Ok(Some(cached)) = self.cache.get::<Vec<String>>(&cache_key).await {
+        let builds = self.get_paper_builds(mc_version).await?;
+        Ok(builds.into_iter().map(|b| b.build).collect())
+    }
+
+    /// Lists Paper builds for a version, newest first, with their release channel.
+    pub async fn get_paper_builds(&self, mc_version: &str) -> Result<Vec<PaperBuild>> {
+        self.list_papermc_builds("paper", mc_version).await
+    }
+
+    pub async fn download_paper<F>(&self, mc_version: &str, build: &str, target_path: impl AsRef<std::path::Path>, on_progress: F) -> Result<()> 
+    where F: Fn(u64, u64) + Send + Sync + 'static {
+        self.download_papermc("paper", mc_version, build, target_path, on_progress).await
+    }
+
+    /// Lists builds of a PaperMC project (Paper, Folia) for a version, newest first.
+    pub(crate) async fn list_papermc_builds(&self, project: &str, mc_version: &str) -> Result<Vec<PaperBuild>> {
+        let cache_key = format!("{}_builds_{}", project, mc_version);
+        if let Ok(Some(cached)) = self.cache.get::<Vec<PaperBuild>>(&cache_key).await {
             return Ok(cached);
         }
 
-        let url = format!("https://api.papermc.io/v2/projects/paper/versions/{}/builds", mc_version);
-        let response = self.client.get(&url).send().await?;
+        let url = format!("{}/projects/{}/versions/{}/builds", self.papermc_api, project, mc_version);
+        let response = get_with_failover(&self.client, self.cache.download_limits(), &url).await?;
 
         if !response.status().is_success() {
             return Ok(vec![]);
         }
 
         let paper_builds: PaperBuilds = response.json().await?;
-        let mut versions: Vec<String> = paper_builds.builds.into_iter()
-            .map(|b| b.build.to_string())
+        let mut builds: Vec<PaperBuild> = paper_builds.builds.into_iter()
+            .map(|b| PaperBuild { build: b.build.to_string(), channel: b.channel })
             .collect();
-        
-        versions.reverse(); // Newest builds first
-        let _ = self.cache.set(cache_key, versions.clone()).await;
-        Ok(versions)
+
+        builds.reverse(); // Newest builds first
+        let _ = self.cache.set(cache_key, builds.clone()).await;
+        Ok(builds)
     }
 
-    pub async fn download_paper<F>(&self, mc_version: &str, build: &str, target_path: impl AsRef<std::path::Path>, on_progress: F) -> Result<()> 
+    pub(crate) async fn download_papermc<F>(&self, project: &str, mc_version: &str, build: &str, target_path: impl AsRef<std::path::Path>, on_progress: F) -> Result<()> 
     where F: Fn(u64, u64) + Send + Sync + 'static {
-        let url = format!("https://api.papermc.io/v2/projects/paper/versions/{}/builds/{}", mc_version, build);
-        let response = self.client.get(&url).send().await?;
+        let url = format!("{}/projects/{}/versions/{}/builds/{}", self.papermc_api, project, mc_version, build);
+        let response = get_with_failover(&self.client, self.cache.download_limits(), &url).await?;
         let build_info: PaperBuildDetails = response.json().await?;
         
         let download_name = build_info.downloads.application.name;
-        let download_url = format!("https://api.papermc.io/v2/projects/paper/versions/{}/builds/{}/downloads/{}", mc_version, build, download_name);
+        let download_url = format!("{}/projects/{}/versions/{}/builds/{}/downloads/{}", self.papermc_api, project, mc_version, build, download_name);
         
         self.download_with_progress(&download_url, &target_path, on_progress).await?;
 
@@ -72,7 +107,7 @@ impl ModLoaderClient {
         
         if actual_sha256 != build_info.downloads.application.sha256 {
             tokio::fs::remove_file(&target_path).await?;
-            return Err(anyhow::anyhow!("SHA256 mismatch for Paper download! Expected: {}, Got: {}", build_info.downloads.application.sha256, actual_sha256));
+            return Err(anyhow::anyhow!("SHA256 mismatch for {} download! Expected: {}, Got: {}", project, build_info.downloads.application.sha256, actual_sha256));
         }
 
         Ok(())
@@ -1,6 +1,7 @@
 use serde::Deserialize;
 use anyhow::Result;
 use super::ModLoaderClient;
+use crate::utils::get_with_failover;
 
 #[derive(Debug, Deserialize)]
 pub struct PaperBuilds {
@@ -31,8 +32,8 @@ pub struct PaperDownload {
 impl ModLoaderClient {
     pub async fn get_velocity_versions(&self) -> Result<Vec<String>> {
         // First get available versions for velocity
-        let url = "https://api.papermc.io/v2/projects/velocity";
-        let response = self.client.get(url).send().await?;
+        let url = format!("{}/projects/velocity", self.papermc_api);
+        let response = get_with_failover(&self.client, self.cache.download_limits(), &url).await?;
         
         #[derive(Debug, Deserialize)]
         struct VelocityProject {
@@ -46,8 +47,8 @@ impl ModLoaderClient {
     }
 
     pub async fn get_velocity_builds(&self, version: &str) -> Result<Vec<String>> {
-        let url = format!("https://api.papermc.io/v2/projects/velocity/versions/{}/builds", version);
-        let response = self.client.get(&url).send().await?;
+        let url = format!("{}/projects/velocity/versions/{}/builds", self.papermc_api, version);
+        let response = get_with_failover(&self.client, self.cache.download_limits(), &url).await?;
 
         if !response.status().is_success() {
             return Ok(vec![]);
@@ -64,12 +65,12 @@ impl ModLoaderClient {
 
     pub async fn download_velocity<F>(&self, version: &str, build: &str, target_path: impl AsRef<std::path::Path>, on_progress: F) -> Result<()> 
     where F: Fn(u64, u64) + Send + Sync + 'static {
-        let url = format!("https://api.papermc.io/v2/projects/velocity/versions/{}/builds/{}", version, build);
-        let response = self.client.get(&url).send().await?;
+        let url = format!("{}/projects/velocity/versions/{}/builds/{}", self.papermc_api, version, build);
+        let response = get_with_failover(&self.client, self.cache.download_limits(), &url).await?;
         let build_info: PaperBuildDetails = response.json().await?;
         
         let download_name = build_info.downloads.application.name;
-        let download_url = format!("https://api.papermc.io/v2/projects/velocity/versions/{}/builds/{}/downloads/{}", version, build, download_name);
+        let download_url = format!("{}/projects/velocity/versions/{}/builds/{}/downloads/{}", self.papermc_api, version, build, download_name);
         
         self.download_with_progress(&download_url, &target_path, on_progress).await?;
 
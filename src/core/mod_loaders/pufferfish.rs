@@ -0,0 +1,121 @@
+use super::ModLoaderClient;
+use crate::utils::get_with_failover;
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+
+const PUFFERFISH_JENKINS: &str = "https://ci.pufferfish.host/job";
+
+#[derive(Debug, Deserialize)]
+pub struct JenkinsJob {
+    #[serde(default)]
+    pub builds: Vec<JenkinsBuild>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct JenkinsBuild {
+    pub number: u32,
+    pub result: Option<String>,
+    #[serde(default)]
+    pub artifacts: Vec<JenkinsArtifact>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JenkinsArtifact {
+    pub file_name: String,
+    pub relative_path: String,
+}
+
+/// A successful Pufferfish Jenkins build and the server jar it produced.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PufferfishBuild {
+    pub build: String,
+    pub artifact_path: String,
+}
+
+/// Pufferfish has one Jenkins job per minor version line, e.g. `Pufferfish-1.20` for 1.20.4.
+pub fn pufferfish_job(mc_version: &str) -> String {
+    let line: Vec<&str> = mc_version.split('.').take(2).collect();
+    format!("Pufferfish-{}", line.join("."))
+}
+
+/// Successful builds of `mc_version`, newest first. A job builds several patch
+/// versions over time, so the jar name decides which version a build is for.
+pub fn parse_pufferfish_builds(job: JenkinsJob, mc_version: &str) -> Vec<PufferfishBuild> {
+    let marker = format!("-{}-", mc_version);
+    let mut builds: Vec<(u32, PufferfishBuild)> = job
+        .builds
+        .into_iter()
+        .filter(|b| b.result.as_deref() == Some("SUCCESS"))
+        .filter_map(|b| {
+            let artifact = b
+                .artifacts
+                .into_iter()
+                .find(|a| a.file_name.ends_with(".jar") && a.file_name.contains(&marker))?;
+            Some((
+                b.number,
+                PufferfishBuild {
+                    build: b.number.to_string(),
+                    artifact_path: artifact.relative_path,
+                },
+            ))
+        })
+        .collect();
+    builds.sort_by_key(|(number, _)| std::cmp::Reverse(*number));
+    builds.into_iter().map(|(_, b)| b).collect()
+}
+
+impl ModLoaderClient {
+    pub async fn get_pufferfish_builds(&self, mc_version: &str) -> Result<Vec<PufferfishBuild>> {
+        let cache_key = format!("pufferfish_builds_{}", mc_version);
+        if let Ok(Some(cached)) = self.cache.get::<Vec<PufferfishBuild>>(&cache_key).await {
+            return Ok(cached);
+        }
+
+        let url = format!(
+            "{}/{}/api/json?tree=builds[number,result,artifacts[fileName,relativePath]]",
+            PUFFERFISH_JENKINS,
+            pufferfish_job(mc_version)
+        );
+        let response = get_with_failover(&self.client, self.cache.download_limits(), &url).await?;
+        if !response.status().is_success() {
+            return Ok(vec![]);
+        }
+
+        let builds = parse_pufferfish_builds(response.json().await?, mc_version);
+        let _ = self.cache.set(cache_key, builds.clone()).await;
+        Ok(builds)
+    }
+
+    pub async fn get_pufferfish_versions(&self, mc_version: &str) -> Result<Vec<String>> {
+        let builds = self.get_pufferfish_builds(mc_version).await?;
+        Ok(builds.into_iter().map(|b| b.build).collect())
+    }
+
+    pub async fn download_pufferfish<F>(
+        &self,
+        mc_version: &str,
+        build: Option<&str>,
+        target_path: impl AsRef<std::path::Path>,
+        on_progress: F,
+    ) -> Result<()>
+    where
+        F: Fn(u64, u64) + Send + Sync + 'static,
+    {
+        let builds = self.get_pufferfish_builds(mc_version).await?;
+        let selected = match build {
+            Some(build) => builds.iter().find(|b| b.build == build),
+            None => builds.first(),
+        }
+        .ok_or_else(|| anyhow!("No Pufferfish build found for version {}", mc_version))?;
+
+        let url = format!(
+            "{}/{}/{}/artifact/{}",
+            PUFFERFISH_JENKINS,
+            pufferfish_job(mc_version),
+            selected.build,
+            selected.artifact_path
+        );
+        self.download_with_progress(&url, target_path, on_progress).await
+    }
+}
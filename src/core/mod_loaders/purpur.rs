@@ -1,6 +1,7 @@
 use serde::Deserialize;
 use anyhow::Result;
 use super::ModLoaderClient;
+use crate::utils::get_with_failover;
 
 #[derive(Debug, Deserialize)]
 pub struct PurpurVersions {
@@ -19,8 +20,8 @@ impl ModLoaderClient {
             return Ok(cached);
         }
 
-        let url = format!("https://api.purpurmc.org/v2/purpur/{}", mc_version);
-        let response = self.client.get(&url).send().await?;
+        let url = format!("{}/purpur/{}", self.purpur_api, mc_version);
+        let response = get_with_failover(&self.client, self.cache.download_limits(), &url).await?;
 
         if !response.status().is_success() {
             return Ok(vec![]);
@@ -36,7 +37,7 @@ impl ModLoaderClient {
 
     pub async fn download_purpur<F>(&self, mc_version: &str, build: &str, target_path: impl AsRef<std::path::Path>, on_progress: F) -> Result<()> 
     where F: Fn(u64, u64) + Send + Sync + 'static {
-        let url = format!("https://api.purpurmc.org/v2/purpur/{}/{}/download", mc_version, build);
+        let url = format!("{}/purpur/{}/{}/download", self.purpur_api, mc_version, build);
         self.download_with_progress(&url, target_path, on_progress).await
     }
 }
@@ -1,4 +1,5 @@
 use super::client::ModLoaderClient;
+use super::buildtools::BuildToolsTarget;
 use super::types::ModLoader;
 use anyhow::Result;
 
@@ -105,6 +106,43 @@ impl ModLoaderClient {
             }
         }
 
+        // Folia
+        if (st.is_none() || st.as_deref() == Some("folia"))
+            && let Ok(versions) = self.get_folia_versions(mc_version).await
+            && !versions.is_empty()
+        {
+            loaders.push(ModLoader {
+                name: "Folia".to_string(),
+                versions,
+            });
+        }
+
+        // Pufferfish
+        if (st.is_none() || st.as_deref() == Some("pufferfish"))
+            && let Ok(versions) = self.get_pufferfish_versions(mc_version).await
+            && !versions.is_empty()
+        {
+            loaders.push(ModLoader {
+                name: "Pufferfish".to_string(),
+                versions,
+            });
+        }
+
+        // Spigot/CraftBukkit are built locally for exactly the selected version
+        if (st.is_none() || matches!(st.as_deref(), Some("spigot") | Some("craftbukkit")))
+            && let Ok(versions) = self.get_buildtools_versions().await
+            && versions.iter().any(|v| v == mc_version)
+        {
+            for target in [BuildToolsTarget::Spigot, BuildToolsTarget::CraftBukkit] {
+                if st.is_none() || st.as_deref() == Some(target.name().to_lowercase().as_str()) {
+                    loaders.push(ModLoader {
+                        name: target.name().to_string(),
+                        versions: vec![mc_version.to_string()],
+                    });
+                }
+            }
+        }
+
         // Proxies (Velocity/BungeeCord)
         if st.is_none() || st.as_deref() == Some("velocity") {
             if let Ok(versions) = self.get_velocity_versions().await {
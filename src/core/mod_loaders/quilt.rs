@@ -1,7 +1,12 @@
 use serde::Deserialize;
-use anyhow::{Result, anyhow};
+use anyhow::{Result, anyhow, Context};
+use std::path::{Path, PathBuf};
 use tracing::info;
 use super::ModLoaderClient;
+use crate::utils::get_with_failover;
+
+/// Name the Quilt server launch jar is stored under, matching config detection.
+pub const QUILT_SERVER_JAR: &str = "quilt-server.jar";
 
 #[derive(Debug, Deserialize)]
 pub struct QuiltLoaderVersion {
@@ -26,7 +31,7 @@ impl ModLoaderClient {
         }
 
         let url = format!("https://meta.quiltmc.org/v3/versions/loader/{}", mc_version);
-        let response = self.client.get(&url).send().await?;
+        let response = get_with_failover(&self.client, self.cache.download_limits(), &url).await?;
         
         if !response.status().is_success() {
             if response.status() == reqwest::StatusCode::NOT_FOUND {
@@ -52,7 +57,7 @@ impl ModLoaderClient {
         }
 
         let url = "https://meta.quiltmc.org/v3/versions/installer";
-        let response = self.client.get(url).send().await?;
+        let response = get_with_failover(&self.client, self.cache.download_limits(), url).await?;
         let installers: Vec<QuiltInstallerVersion> = response.json().await?;
         let versions: Vec<String> = installers.into_iter().map(|i| i.version).collect();
         
@@ -65,4 +70,64 @@ impl ModLoaderClient {
         let url = format!("https://maven.quiltmc.org/repository/release/org/quiltmc/quilt-installer/{}/quilt-installer-{}.jar", installer_version, installer_version);
         self.download_with_progress(&url, target_path, on_progress).await
     }
+
+    /// Downloads the latest Quilt installer, runs it headless into the folder of
+    /// `target_path` (fetching the vanilla server next to it) and moves the launch
+    /// jar it creates to `target_path`.
+    pub async fn install_quilt_server<F>(&self, mc_version: &str, loader_version: Option<&str>, target_path: impl AsRef<Path>, on_progress: F) -> Result<()>
+    where F: Fn(u64, u64) + Send + Sync + 'static {
+        let target_path = target_path.as_ref();
+        let install_dir = target_path.parent()
+            .ok_or_else(|| anyhow!("Invalid target path for Quilt"))?;
+        let installer_versions = self.get_quilt_installer_versions().await?;
+        let latest_installer = installer_versions.first()
+            .ok_or_else(|| anyhow!("No Quilt installer versions found"))?;
+        let installer_path = install_dir.join("quilt-installer.jar");
+        self.download_quilt_installer(latest_installer, &installer_path, on_progress).await?;
+
+        let output = tokio::process::Command::new("java")
+            .current_dir(install_dir)
+            .arg("-jar")
+            .arg(&installer_path)
+            .args(quilt_installer_args(mc_version, loader_version))
+            .output()
+            .await
+            .context("Failed to run the Quilt installer")?;
+        let _ = tokio::fs::remove_file(&installer_path).await;
+        if !output.status.success() {
+            return Err(anyhow!("Quilt installer failed: {}", String::from_utf8_lossy(&output.stderr).trim()));
+        }
+
+        let launch_jar = find_quilt_launch_jar(install_dir)
+            .ok_or_else(|| anyhow!("Quilt installer did not create a server launch jar"))?;
+        if launch_jar != target_path {
+            tokio::fs::rename(&launch_jar, target_path).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Arguments for `java -jar quilt-installer.jar` that install a server into the working
+/// directory. Without a loader version the installer picks the latest one.
+pub fn quilt_installer_args(mc_version: &str, loader_version: Option<&str>) -> Vec<String> {
+    let mut args = vec!["install".to_string(), "server".to_string(), mc_version.to_string()];
+    if let Some(loader_version) = loader_version {
+        args.push(loader_version.to_string());
+    }
+    args.push("--download-server".to_string());
+    args.push("--install-dir=.".to_string());
+    args
+}
+
+/// Finds the launch jar the Quilt installer left in `dir`, falling back to a bare
+/// `quilt-loader` jar from older installers.
+pub fn find_quilt_launch_jar(dir: &Path) -> Option<PathBuf> {
+    let jars: Vec<(String, PathBuf)> = std::fs::read_dir(dir).ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| (entry.file_name().to_string_lossy().to_lowercase(), entry.path()))
+        .filter(|(name, _)| name.ends_with(".jar"))
+        .collect();
+    ["quilt-server-launch", "quilt-loader"].iter().find_map(|pattern| {
+        jars.iter().find(|(name, _)| name.contains(pattern)).map(|(_, path)| path.clone())
+    })
 }
@@ -0,0 +1,196 @@
+use super::ModLoaderClient;
+use crate::utils::get_with_failover;
+use super::paper::BuildChannel;
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+
+/// Maximum number of newer builds included in a changelog.
+const MAX_CHANGELOG_BUILDS: usize = 20;
+
+/// A single upstream change shipped in a server build.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BuildChange {
+    pub build: String,
+    pub commit: Option<String>,
+    pub summary: String,
+}
+
+/// Latest upstream build for a server project and the changes since the installed build.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UpstreamBuilds {
+    pub latest_build: String,
+    /// Whether the latest build is on PaperMC's experimental channel.
+    #[serde(default)]
+    pub latest_experimental: bool,
+    pub changelog: Vec<BuildChange>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PaperBuildList {
+    builds: Vec<PaperBuildEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PaperBuildEntry {
+    build: u32,
+    #[serde(default)]
+    channel: BuildChannel,
+    #[serde(default)]
+    changes: Vec<PaperChange>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PaperChange {
+    commit: String,
+    summary: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct PurpurVersionInfo {
+    builds: PurpurBuildIndex,
+}
+
+#[derive(Debug, Deserialize)]
+struct PurpurBuildIndex {
+    latest: String,
+    all: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PurpurBuildInfo {
+    #[serde(default)]
+    commits: Vec<PurpurCommit>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PurpurCommit {
+    hash: String,
+    description: String,
+}
+
+/// Returns true if `build` is newer than `installed`, comparing numerically when possible.
+pub fn is_newer_build(build: &str, installed: Option<&str>) -> bool {
+    match installed {
+        None => true,
+        Some(installed) => match (build.parse::<u64>(), installed.parse::<u64>()) {
+            (Ok(b), Ok(i)) => b > i,
+            _ => build != installed,
+        },
+    }
+}
+
+impl ModLoaderClient {
+    /// Fetches the latest build of a Paper, Folia, Purpur or Velocity version along with
+    /// the changelog of every build newer than `installed_build` (newest first).
+    pub async fn get_upstream_builds(
+        &self,
+        loader: &str,
+        version: &str,
+        installed_build: Option<&str>,
+    ) -> Result<UpstreamBuilds> {
+        match loader.to_lowercase().as_str() {
+            "paper" => self.get_papermc_builds("paper", version, installed_build).await,
+            "folia" => self.get_papermc_builds("folia", version, installed_build).await,
+            "velocity" => self.get_papermc_builds("velocity", version, installed_build).await,
+            "purpur" => self.get_purpur_builds(version, installed_build).await,
+            other => Err(anyhow!("Build updates are not supported for {}", other)),
+        }
+    }
+
+    async fn get_papermc_builds(
+        &self,
+        project: &str,
+        version: &str,
+        installed_build: Option<&str>,
+    ) -> Result<UpstreamBuilds> {
+        let url = format!(
+            "{}/projects/{}/versions/{}/builds",
+            self.papermc_api, project, version
+        );
+        let response = get_with_failover(&self.client, self.cache.download_limits(), &url).await?;
+        if !response.status().is_success() {
+            return Err(anyhow!(
+                "PaperMC API returned {} for {} {}",
+                response.status(),
+                project,
+                version
+            ));
+        }
+
+        let list: PaperBuildList = response.json().await?;
+        let latest_entry = list
+            .builds
+            .last()
+            .ok_or_else(|| anyhow!("No builds found for {} {}", project, version))?;
+        let latest = latest_entry.build.to_string();
+        let latest_experimental = latest_entry.channel == BuildChannel::Experimental;
+
+        let changelog = list
+            .builds
+            .iter()
+            .rev()
+            .filter(|b| is_newer_build(&b.build.to_string(), installed_build))
+            .take(MAX_CHANGELOG_BUILDS)
+            .flat_map(|b| {
+                b.changes.iter().map(move |c| BuildChange {
+                    build: b.build.to_string(),
+                    commit: Some(c.commit.clone()),
+                    summary: c.summary.clone(),
+                })
+            })
+            .collect();
+
+        Ok(UpstreamBuilds {
+            latest_build: latest,
+            latest_experimental,
+            changelog,
+        })
+    }
+
+    async fn get_purpur_builds(
+        &self,
+        version: &str,
+        installed_build: Option<&str>,
+    ) -> Result<UpstreamBuilds> {
+        let url = format!("{}/purpur/{}", self.purpur_api, version);
+        let response = get_with_failover(&self.client, self.cache.download_limits(), &url).await?;
+        if !response.status().is_success() {
+            return Err(anyhow!(
+                "Purpur API returned {} for version {}",
+                response.status(),
+                version
+            ));
+        }
+        let info: PurpurVersionInfo = response.json().await?;
+
+        let newer: Vec<&String> = info
+            .builds
+            .all
+            .iter()
+            .rev()
+            .filter(|b| is_newer_build(b, installed_build))
+            .take(MAX_CHANGELOG_BUILDS)
+            .collect();
+
+        let mut changelog = Vec::new();
+        for build in newer {
+            let url = format!("{}/purpur/{}/{}", self.purpur_api, version, build);
+            let Ok(response) = get_with_failover(&self.client, self.cache.download_limits(), &url).await else {
+                continue;
+            };
+            if let Ok(details) = response.json::<PurpurBuildInfo>().await {
+                changelog.extend(details.commits.into_iter().map(|c| BuildChange {
+                    build: build.clone(),
+                    commit: Some(c.hash),
+                    summary: c.description.lines().next().unwrap_or_default().to_string(),
+                }));
+            }
+        }
+
+        Ok(UpstreamBuilds {
+            latest_build: info.builds.latest,
+            latest_experimental: false,
+            changelog,
+        })
+    }
+}
@@ -20,6 +20,7 @@ impl From<Project> for ModrinthProject {
             categories: Some(p.categories),
             client_side: format!("{:?}", p.client_side).to_lowercase(),
             server_side: format!("{:?}", p.server_side).to_lowercase(),
+            license: Some(p.license.id).filter(|id| !id.is_empty()),
         }
     }
 }
@@ -39,6 +40,7 @@ impl From<SearchHit> for ModrinthProject {
             categories: Some(p.categories),
             client_side: format!("{:?}", p.client_side).to_lowercase(),
             server_side: format!("{:?}", p.server_side).to_lowercase(),
+            license: Some(p.license).filter(|id| !id.is_empty()),
         }
     }
 }
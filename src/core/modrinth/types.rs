@@ -72,6 +72,9 @@ pub struct ModrinthProject {
     pub categories: Option<Vec<String>>,
     pub client_side: String,
     pub server_side: String,
+    /// SPDX identifier, e.g. `MIT` or `LicenseRef-All-Rights-Reserved`.
+    #[serde(default)]
+    pub license: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
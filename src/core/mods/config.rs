@@ -1,6 +1,8 @@
+use std::collections::HashMap;
 use std::path::Path;
 use tokio::fs;
-use anyhow::Result;
+use anyhow::{Result, Context, anyhow};
+use crate::mods::metadata::parsers::ids::read_mod_ids_sync;
 use crate::mods::types::ModConfig;
 
 /// Lists all files in a mod's config directory/file.
@@ -38,35 +40,79 @@ pub async fn list_mod_config_files(instance_path: impl AsRef<Path>, rel_path: &s
     Ok(files)
 }
 
-pub async fn get_mod_configs(instance_path: impl AsRef<Path>, mod_name: &str) -> Result<Vec<ModConfig>> {
+/// Returns true if a top-level `config/` entry belongs to the mod with `mod_id`.
+///
+/// Mods name their configs after their ID: `config/<modid>/`, `<modid>.json`,
+/// `<modid>-common.toml`, `<modid>_client.properties` and so on.
+fn config_matches_id(entry_name: &str, mod_id: &str) -> bool {
+    let name = entry_name.to_lowercase();
+    match name.strip_prefix(mod_id) {
+        Some(rest) => rest.is_empty() || rest.starts_with(['.', '-', '_']),
+        None => false,
+    }
+}
+
+/// Maps every installed mod jar (keyed by filename) to the config entries it owns.
+///
+/// Each config entry is assigned to the mod with the longest matching ID, so a mod
+/// with the ID `create` does not claim the configs of `create_enchantment`.
+pub async fn map_mod_configs(instance_path: impl AsRef<Path>) -> Result<HashMap<String, Vec<ModConfig>>> {
+    let mods_dir = instance_path.as_ref().join("mods");
     let config_dir = instance_path.as_ref().join("config");
-    if !config_dir.exists() {
-        return Ok(vec![]);
+    let mut map: HashMap<String, Vec<ModConfig>> = HashMap::new();
+    if !mods_dir.exists() || !config_dir.exists() {
+        return Ok(map);
     }
 
-    let mut configs = Vec::new();
-    let mut entries = fs::read_dir(&config_dir).await?;
+    let mut jars = Vec::new();
+    let mut entries = fs::read_dir(&mods_dir).await.context("Failed to read mods directory")?;
+    while let Some(entry) = entries.next_entry().await? {
+        let filename = entry.file_name().to_string_lossy().to_string();
+        let lower = filename.to_lowercase();
+        if entry.path().is_file() && (lower.ends_with(".jar") || lower.ends_with(".jar.disabled")) {
+            jars.push((filename, entry.path()));
+        }
+    }
 
-    // Try to find files/dirs that match the mod name or a common ID pattern
-    // e.g., for "Fabric API", look for "fabric-api.json" or "fabric"
-    let mod_id = mod_name.to_lowercase().replace(' ', "-");
-    let mod_id_short = mod_id.split('-').next().unwrap_or(&mod_id);
+    let mod_ids: Vec<(String, String)> = tokio::task::spawn_blocking(move || {
+        jars.into_iter()
+            .flat_map(|(filename, path)| {
+                let ids = read_mod_ids_sync(&path).unwrap_or_default();
+                ids.into_iter().map(move |id| (filename.clone(), id))
+            })
+            .collect()
+    })
+    .await?;
 
+    let mut entries = fs::read_dir(&config_dir).await.context("Failed to read config directory")?;
     while let Some(entry) = entries.next_entry().await? {
-        let file_name = entry.file_name().to_string_lossy().to_lowercase();
-        let is_match = file_name.contains(&mod_id) || 
-                      file_name.contains(mod_id_short) ||
-                      mod_id.contains(&file_name);
-
-        if is_match {
-            let file_type = entry.file_type().await?;
-            configs.push(ModConfig {
-                name: entry.file_name().to_string_lossy().to_string(),
-                path: format!("config/{}", entry.file_name().to_string_lossy()),
-                is_dir: file_type.is_dir(),
+        let name = entry.file_name().to_string_lossy().to_string();
+        let owner = mod_ids
+            .iter()
+            .filter(|(_, id)| config_matches_id(&name, id))
+            .max_by_key(|(_, id)| id.len());
+        if let Some((filename, _)) = owner {
+            map.entry(filename.clone()).or_default().push(ModConfig {
+                path: format!("config/{}", name),
+                name,
+                is_dir: entry.file_type().await?.is_dir(),
             });
         }
     }
 
-    Ok(configs)
+    for configs in map.values_mut() {
+        configs.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+    Ok(map)
+}
+
+/// Lists the config files and folders owned by an installed mod jar, matched by the
+/// mod IDs declared in its metadata.
+pub async fn get_mod_configs(instance_path: impl AsRef<Path>, filename: &str) -> Result<Vec<ModConfig>> {
+    if filename.contains("..") || filename.contains('/') || filename.contains('\\') {
+        return Err(anyhow!("Invalid filename: {}", filename));
+    }
+
+    let mut map = map_mod_configs(instance_path).await?;
+    Ok(map.remove(filename).unwrap_or_default())
 }
@@ -43,6 +43,7 @@ impl CurseForgeClient {
 
         download_with_resumption(
             &self.client,
+            self.cache.download_limits(),
             DownloadOptions {
                 url,
                 target_path: &target_path,
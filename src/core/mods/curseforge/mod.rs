@@ -12,10 +12,11 @@ pub struct CurseForgeClient {
 }
 
 impl CurseForgeClient {
+    /// Creates a client, using the stored CurseForge API key when `api_key` is `None`.
     pub fn new(api_key: Option<String>, cache: Arc<CacheManager>) -> Self {
         Self {
             client: cache.get_client().clone(),
-            api_key,
+            api_key: api_key.or_else(|| cache.credentials().curseforge_api_key()),
             cache,
         }
     }
@@ -3,51 +3,39 @@ use super::CurseForgeClient;
 use crate::mods::types::{ProjectVersion, ProjectFile, Dependency};
 
 impl CurseForgeClient {
-    pub async fn get_dependencies(&self, project_id: &str, _game_version: Option<&str>, _loader: Option<&str>) -> Result<Vec<crate::mods::types::ResolvedDependency>> {
-        let cache_key = format!("curseforge_dependencies_{}_v:{:?}_lo:{:?}", project_id, _game_version, _loader);
+    /// Resolves the dependencies declared by the newest file matching the game version
+    /// and loader. CurseForge records dependencies per file, not per project.
+    pub async fn get_dependencies(&self, project_id: &str, game_version: Option<&str>, loader: Option<&str>) -> Result<Vec<crate::mods::types::ResolvedDependency>> {
+        let cache_key = format!("curseforge_dependencies_{}_v:{:?}_lo:{:?}", project_id, game_version, loader);
         if let Ok(Some(cached)) = self.cache.get::<Vec<crate::mods::types::ResolvedDependency>>(&cache_key).await {
             return Ok(cached);
         }
 
-        let api_key = self.api_key.as_ref().ok_or_else(|| anyhow!("CurseForge API key not provided"))?;
-        let url = format!("https://api.curseforge.com/v1/mods/{}", project_id);
-        let response = self.client.get(&url)
-            .header("x-api-key", api_key)
-            .send()
-            .await?
-            .json::<serde_json::Value>()
-            .await?;
-        
-        let dependencies = response["data"]["dependencies"].as_array();
-        if let Some(deps) = dependencies {
-            let mut resolved_deps = Vec::new();
-            for dep in deps {
-                let relation_type = dep["relationType"].as_u64();
-                
-                // Mapping relation types:
-                // 3: Required
-                // 2: Optional
-                // Others: Incompatible, Embedded, etc.
-                let dep_type = match relation_type {
-                    Some(3) => "required",
-                    Some(2) => "optional",
-                    _ => continue, // Skip others for now
-                };
+        let mut versions = self.get_versions(project_id, game_version, loader).await?;
+        if versions.is_empty() && game_version.is_some() {
+            // Bukkit plugins are often only tagged with older game versions
+            versions = self.get_versions(project_id, None, loader).await?;
+        }
 
-                if let Some(mod_id) = dep["modId"].as_u64() {
-                    if let Ok(project) = self.get_project(&mod_id.to_string()).await {
-                        resolved_deps.push(crate::mods::types::ResolvedDependency {
-                            project,
-                            dependency_type: dep_type.to_string(),
-                        });
-                    }
-                }
+        let mut resolved_deps = Vec::new();
+        let deps = versions.first().map(|v| v.dependencies.as_slice()).unwrap_or_default();
+        for dep in deps {
+            let Some(mod_id) = dep.project_id.as_deref() else {
+                continue;
+            };
+            if resolved_deps.iter().any(|d: &crate::mods::types::ResolvedDependency| d.project.id == mod_id) {
+                continue;
+            }
+            if let Ok(project) = self.get_project(mod_id).await {
+                resolved_deps.push(crate::mods::types::ResolvedDependency {
+                    project,
+                    dependency_type: dep.dependency_type.clone(),
+                });
             }
-            let _ = self.cache.set(cache_key, resolved_deps.clone()).await;
-            Ok(resolved_deps)
-        } else {
-            Ok(vec![])
         }
+
+        let _ = self.cache.set(cache_key, resolved_deps.clone()).await;
+        Ok(resolved_deps)
     }
 
     pub async fn get_versions(
@@ -1,42 +1,71 @@
 use std::path::Path;
 use tokio::fs;
 use anyhow::{Result, Context, anyhow};
+use crate::mods::ModConfig;
+use crate::mods::config::{get_mod_configs, map_mod_configs};
+use crate::utils::{BulkProgress, BulkReport, run_bulk};
 
-/// Uninstalls a mod by removing its file and optionally its configuration folder.
+/// Uninstalls a mod by removing its file and optionally the config files owned by its mod ID.
 pub async fn uninstall_mod(instance_path: impl AsRef<Path>, filename: String, delete_config: bool) -> Result<()> {
+    // Resolve configs while the jar (and its mod ID) is still there
+    let configs = if delete_config {
+        get_mod_configs(&instance_path, &filename).await?
+    } else {
+        Vec::new()
+    };
+    remove_mod(instance_path.as_ref(), &filename, configs).await
+}
+
+/// Removes a mod file and the `configs` resolved for it beforehand.
+async fn remove_mod(instance_path: &Path, filename: &str, configs: Vec<ModConfig>) -> Result<()> {
     // Path traversal protection
     if filename.contains("..") || filename.contains('/') || filename.contains('\\') {
         return Err(anyhow!("Invalid filename: {}", filename));
     }
 
-    let mods_dir = instance_path.as_ref().join("mods");
-    let mod_file = mods_dir.join(&filename);
-
+    let mod_file = instance_path.join("mods").join(filename);
     if mod_file.exists() {
         fs::remove_file(mod_file).await.context("Failed to delete mod file")?;
     }
 
-    if delete_config {
-        // Try to find the config directory. Mods usually put configs in the instance's 'config' directory.
-        // We'll need a way to map mod IDs to config files in Phase 4.
-        // For now, we'll just implement the placeholder logic.
-        let instance_config_dir = instance_path.as_ref().join("config");
-        if instance_config_dir.exists() {
-            // TODO: In Phase 4, implement actual config discovery for mods.
+    for config in configs {
+        let path = instance_path.join(&config.path);
+        if config.is_dir {
+            fs::remove_dir_all(&path).await
+        } else {
+            fs::remove_file(&path).await
         }
+        .with_context(|| format!("Failed to delete mod config {}", config.path))?;
     }
 
     Ok(())
 }
 
-/// Uninstalls multiple mods at once.
+/// Uninstalls multiple mods concurrently, reporting the outcome of each file. Config
+/// ownership is worked out once up front, before any jar is removed, so every config is
+/// deleted by exactly one item.
 pub async fn bulk_uninstall_mods(
     instance_path: impl AsRef<Path>,
     filenames: Vec<String>,
     delete_config: bool,
-) -> Result<()> {
-    for filename in filenames {
-        uninstall_mod(&instance_path, filename, delete_config).await?;
-    }
-    Ok(())
+    on_progress: impl Fn(BulkProgress),
+) -> BulkReport {
+    let instance_path = instance_path.as_ref();
+    let config_map = if delete_config {
+        map_mod_configs(instance_path).await.map_err(|e| format!("{:#}", e))
+    } else {
+        Ok(Default::default())
+    };
+    run_bulk(
+        filenames,
+        |filename| {
+            let configs = config_map
+                .as_ref()
+                .map(|map| map.get(&filename).cloned().unwrap_or_default())
+                .map_err(|e| anyhow!("Failed to look up mod configs: {}", e));
+            async move { remove_mod(instance_path, &filename, configs?).await }
+        },
+        on_progress,
+    )
+    .await
 }
@@ -6,8 +6,13 @@ use crate::mods::types::{ModProvider, ModUpdate};
 use crate::mods::modrinth::ModrinthClient;
 use crate::mods::curseforge::CurseForgeClient;
 use crate::mods::metadata::list_installed_mods;
+use crate::artifacts::ArtifactStore;
 use crate::cache::CacheManager;
+use crate::content_report::ContentKind;
+use crate::version_history::archive_version;
+use crate::content_report::record_update_check;
 use super::install::install_mod;
+use tracing::warn;
 
 /// Checks for updates for all installed mods that have source information.
 pub async fn check_for_updates(
@@ -19,6 +24,7 @@ pub async fn check_for_updates(
 ) -> Result<Vec<ModUpdate>> {
     let installed = list_installed_mods(&instance_path).await?;
     let mut updates = Vec::new();
+    let mut checked = Vec::new();
 
     for mod_item in installed {
         if let Some(source) = mod_item.source {
@@ -26,6 +32,7 @@ pub async fn check_for_updates(
                 ModProvider::Modrinth => {
                     let client = ModrinthClient::new(Arc::clone(&cache));
                     if let Ok(versions) = client.get_versions(&source.project_id, game_version, loader).await {
+                        checked.push(mod_item.filename.clone());
                         if let Some(latest) = versions.first() {
                             if Some(latest.id.clone()) != source.current_version_id {
                                 updates.push(ModUpdate {
@@ -43,6 +50,7 @@ pub async fn check_for_updates(
                 ModProvider::CurseForge => {
                     let client = CurseForgeClient::new(curseforge_api_key.clone(), Arc::clone(&cache));
                     if let Ok(versions) = client.get_versions(&source.project_id, game_version, loader).await {
+                        checked.push(mod_item.filename.clone());
                         if let Some(latest) = versions.first() {
                             if Some(latest.id.clone()) != source.current_version_id {
                                 updates.push(ModUpdate {
@@ -61,10 +69,17 @@ pub async fn check_for_updates(
         }
     }
 
+    let found = updates.iter().map(|u| (u.filename.clone(), u.latest_version.clone()));
+    if let Err(e) = record_update_check(&instance_path.as_ref().join("mods"), checked, found).await {
+        warn!("Failed to record mod update check: {}", e);
+    }
+
     Ok(updates)
 }
 
-/// Updates a mod by downloading the new version and replacing the old one.
+/// Updates a mod by downloading the new version and replacing the old one. The
+/// replaced jar is kept in `history` so the update can be rolled back.
+#[allow(clippy::too_many_arguments)]
 pub async fn update_mod(
     instance_path: impl AsRef<Path>,
     filename: String,
@@ -75,9 +90,12 @@ pub async fn update_mod(
     loader: Option<&str>,
     curseforge_api_key: Option<String>,
     cache: Arc<CacheManager>,
+    history: &ArtifactStore,
 ) -> Result<()> {
     let mods_dir = instance_path.as_ref().join("mods");
     let old_path = mods_dir.join(&filename);
+    let previous = list_installed_mods(&instance_path).await.ok()
+        .and_then(|mods| mods.into_iter().find(|m| m.filename == filename));
 
     // 1. Create backup
     let backup_path = mods_dir.join(format!("{}.bak", filename));
@@ -115,7 +133,15 @@ pub async fn update_mod(
                 let _ = fs::remove_file(old_path).await;
             }
 
-            // 5. Delete backup on success
+            // 5. Keep the old jar for rollbacks, then delete the backup
+            if backup_path.exists() {
+                let (version, version_id) = previous
+                    .map(|m| (m.version, m.source.and_then(|s| s.current_version_id)))
+                    .unwrap_or_default();
+                if let Err(e) = archive_version(history, &instance_path, ContentKind::Mod, &project_id, &backup_path, &filename, version, version_id).await {
+                    warn!("Failed to keep previous version of {}: {}", filename, e);
+                }
+            }
             let _ = fs::remove_file(backup_path).await;
             Ok(())
         }
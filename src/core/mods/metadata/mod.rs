@@ -2,6 +2,7 @@ use std::path::Path;
 use tokio::fs;
 use anyhow::{Result, Context};
 use crate::mods::types::{InstalledMod, ModCache, ModCacheEntry};
+use crate::utils::{BulkProgress, BulkReport, run_bulk};
 
 pub mod parsers;
 
@@ -115,14 +116,12 @@ pub async fn toggle_mod(instance_path: impl AsRef<Path>, filename: String, enabl
     Ok(())
 }
 
-/// Toggles multiple mods at once.
+/// Toggles multiple mods concurrently, reporting the outcome of each file.
 pub async fn bulk_toggle_mods(
     instance_path: impl AsRef<Path>,
     filenames: Vec<String>,
     enable: bool,
-) -> Result<()> {
-    for filename in filenames {
-        toggle_mod(&instance_path, filename, enable).await?;
-    }
-    Ok(())
+    on_progress: impl Fn(BulkProgress),
+) -> BulkReport {
+    run_bulk(filenames, |filename| toggle_mod(&instance_path, filename, enable), on_progress).await
 }
@@ -0,0 +1,87 @@
+use serde::Deserialize;
+use zip::ZipArchive;
+use std::io::Read;
+use std::path::Path;
+use anyhow::Result;
+
+#[derive(Deserialize)]
+struct FabricIdJson {
+    id: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct QuiltIdJson {
+    quilt_loader: QuiltIdLoader,
+}
+
+#[derive(Deserialize)]
+struct QuiltIdLoader {
+    id: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ModsIdToml {
+    mods: Vec<ForgeIdEntry>,
+}
+
+#[derive(Deserialize)]
+struct ForgeIdEntry {
+    #[serde(rename = "modId")]
+    mod_id: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct LegacyIdEntry {
+    modid: Option<String>,
+}
+
+fn read_entry(archive: &mut ZipArchive<std::fs::File>, name: &str) -> Option<String> {
+    let mut file = archive.by_name(name).ok()?;
+    let mut content = String::new();
+    file.read_to_string(&mut content).ok()?;
+    Some(content)
+}
+
+/// Reads the mod IDs declared in a mod jar's loader metadata.
+///
+/// Forge and NeoForge jars may bundle several mods, so every declared ID is returned.
+/// Returns an empty list for jars without recognised metadata.
+pub fn read_mod_ids_sync(path: &Path) -> Result<Vec<String>> {
+    let file = std::fs::File::open(path)?;
+    let mut archive = ZipArchive::new(file)?;
+    let mut ids = Vec::new();
+
+    let fabric = read_entry(&mut archive, "fabric.mod.json")
+        .and_then(|c| serde_json::from_str::<FabricIdJson>(&c).ok());
+    ids.extend(fabric.and_then(|json| json.id));
+
+    let quilt = read_entry(&mut archive, "quilt.mod.json")
+        .and_then(|c| serde_json::from_str::<QuiltIdJson>(&c).ok());
+    ids.extend(quilt.and_then(|json| json.quilt_loader.id));
+
+    for toml_path in ["META-INF/neoforge.mods.toml", "META-INF/mods.toml"] {
+        let forge = read_entry(&mut archive, toml_path)
+            .and_then(|c| toml::from_str::<ModsIdToml>(&c).ok());
+        ids.extend(forge.into_iter().flat_map(|t| t.mods).filter_map(|m| m.mod_id));
+    }
+
+    if let Some(content) = read_entry(&mut archive, "mcmod.info") {
+        #[derive(Deserialize)]
+        struct Wrapped {
+            #[serde(rename = "modList")]
+            mod_list: Vec<LegacyIdEntry>,
+        }
+        let entries = serde_json::from_str::<Vec<LegacyIdEntry>>(&content)
+            .or_else(|_| serde_json::from_str::<Wrapped>(&content).map(|w| w.mod_list))
+            .unwrap_or_default();
+        ids.extend(entries.into_iter().filter_map(|m| m.modid));
+    }
+
+    let mut unique: Vec<String> = Vec::new();
+    for id in ids.into_iter().map(|id| id.trim().to_lowercase()) {
+        if !id.is_empty() && !unique.contains(&id) {
+            unique.push(id);
+        }
+    }
+    Ok(unique)
+}
@@ -0,0 +1,55 @@
+use zip::ZipArchive;
+use std::io::Read;
+use std::path::Path;
+use anyhow::Result;
+
+fn read_entry(archive: &mut ZipArchive<std::fs::File>, name: &str) -> Option<String> {
+    let mut file = archive.by_name(name).ok()?;
+    let mut content = String::new();
+    file.read_to_string(&mut content).ok()?;
+    Some(content)
+}
+
+/// Flattens the license forms used by loader metadata: a string, an object with an
+/// `id` (Quilt), or a list of either.
+fn license_from_json(value: &serde_json::Value) -> Option<String> {
+    let ids: Vec<String> = match value {
+        serde_json::Value::String(s) => vec![s.clone()],
+        serde_json::Value::Object(o) => o.get("id").and_then(|id| id.as_str()).map(str::to_string).into_iter().collect(),
+        serde_json::Value::Array(items) => items.iter().filter_map(license_from_json).collect(),
+        _ => vec![],
+    };
+    let ids: Vec<String> = ids.into_iter().map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect();
+    if ids.is_empty() { None } else { Some(ids.join(" AND ")) }
+}
+
+/// Reads the license declared in a mod jar's loader metadata, if any.
+pub fn read_license_sync(path: &Path) -> Result<Option<String>> {
+    let file = std::fs::File::open(path)?;
+    let mut archive = ZipArchive::new(file)?;
+
+    let fabric = read_entry(&mut archive, "fabric.mod.json")
+        .and_then(|c| serde_json::from_str::<serde_json::Value>(&c).ok())
+        .and_then(|json| license_from_json(&json["license"]));
+    if fabric.is_some() {
+        return Ok(fabric);
+    }
+
+    let quilt = read_entry(&mut archive, "quilt.mod.json")
+        .and_then(|c| serde_json::from_str::<serde_json::Value>(&c).ok())
+        .and_then(|json| license_from_json(&json["quilt_loader"]["metadata"]["license"]));
+    if quilt.is_some() {
+        return Ok(quilt);
+    }
+
+    for toml_path in ["META-INF/neoforge.mods.toml", "META-INF/mods.toml"] {
+        let license = read_entry(&mut archive, toml_path)
+            .and_then(|c| toml::from_str::<toml::Value>(&c).ok())
+            .and_then(|t| t.get("license").and_then(|l| l.as_str()).map(|l| l.trim().to_string()))
+            .filter(|l| !l.is_empty());
+        if license.is_some() {
+            return Ok(license);
+        }
+    }
+    Ok(None)
+}
@@ -1,6 +1,8 @@
 pub mod fabric;
 pub mod forge;
 pub mod quilt;
+pub mod ids;
+pub mod license;
 
 use zip::ZipArchive;
 use anyhow::Result;
@@ -32,6 +32,7 @@ impl ModrinthClient {
 
         download_with_resumption(
             self.inner.cache.get_client(),
+            self.inner.cache.download_limits(),
             DownloadOptions {
                 url: &file.url,
                 target_path: &target_path,
@@ -1,8 +1,8 @@
 use super::ModrinthClient;
 use crate::mods::types::ProjectVersion;
+use crate::utils::{download_to_file, ensure_free_space};
 use anyhow::{Context, Result, anyhow};
 use serde::{Deserialize, Serialize};
-use std::io::Cursor;
 use std::path::Path;
 use tokio::fs;
 use zip::ZipArchive;
@@ -86,14 +86,9 @@ impl ModrinthClient {
             .or_else(|| version.files.first())
             .ok_or_else(|| anyhow!("No files found in modpack version"))?;
 
-        let response = self
-            .inner
-            .cache
-            .get_client()
-            .get(&primary_file.url)
-            .send()
-            .await?;
-        let bytes = response.bytes().await?;
+        let archive_path = temp_dir.join("modpack.mrpack");
+        let cache = &self.inner.cache;
+        download_to_file(cache.get_client(), cache.download_limits(), &primary_file.url, &archive_path, |_, _| {}).await?;
 
         // 2. Extract the .mrpack file
         on_progress(ModpackProgress {
@@ -105,7 +100,7 @@ impl ModrinthClient {
         });
 
         let index = {
-            let mut archive = ZipArchive::new(Cursor::new(bytes))?;
+            let mut archive = ZipArchive::new(std::fs::File::open(&archive_path)?)?;
 
             // Read index first
             let index: ModrinthIndex = {
@@ -115,6 +110,22 @@ impl ModrinthClient {
                 serde_json::from_reader(&mut index_file)?
             };
 
+            // Everything the pack will write: overrides plus the server-side downloads
+            let mut overrides_size = 0u64;
+            for i in 0..archive.len() {
+                let file = archive.by_index(i)?;
+                if file.name().starts_with("overrides/") || file.name().starts_with("server-overrides/") {
+                    overrides_size += file.size();
+                }
+            }
+            let downloads_size: u64 = index
+                .files
+                .iter()
+                .filter(|f| !f.env.as_ref().is_some_and(|env| matches!(env.server, ModrinthEnvSupport::Unsupported)))
+                .map(|f| f.file_size)
+                .sum();
+            ensure_free_space(&instance_path, overrides_size + downloads_size)?;
+
             // 3. Extract overrides
             for i in 0..archive.len() {
                 let mut file = archive.by_index(i)?;
@@ -171,18 +182,15 @@ impl ModrinthClient {
                 fs::create_dir_all(parent).await?;
             }
 
-            // Try downloads in order
+            // Try downloads in order, preferred mirrors first
+            let mut urls = mod_file.downloads.clone();
+            cache.download_limits().sort_by_mirror_preference(&mut urls);
             let mut success = false;
-            for url in &mod_file.downloads {
-                match self.inner.cache.get_client().get(url).send().await {
-                    Ok(resp) => {
-                        if resp.status().is_success() {
-                            let mut out_file = fs::File::create(&dest_path).await?;
-                            let mut content = Cursor::new(resp.bytes().await?);
-                            tokio::io::copy(&mut content, &mut out_file).await?;
-                            success = true;
-                            break;
-                        }
+            for url in &urls {
+                match download_to_file(cache.get_client(), cache.download_limits(), url, &dest_path, |_, _| {}).await {
+                    Ok(()) => {
+                        success = true;
+                        break;
                     }
                     Err(e) => {
                         tracing::warn!("Failed to download modpack file from {}: {}", url, e);
@@ -0,0 +1,97 @@
+//! Reader and writer for Java Edition NBT, the binary format of `level.dat`, player
+//! data, structure files and some mods' configs.
+
+use serde::Serialize;
+
+mod reader;
+mod writer;
+
+pub use reader::read_nbt;
+pub use writer::write_nbt;
+
+/// Nesting deeper than this is treated as a corrupt file.
+const MAX_DEPTH: usize = 512;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NbtCompression {
+    None,
+    Gzip,
+    Zlib,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum NbtTag {
+    Byte(i8),
+    Short(i16),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    ByteArray(Vec<i8>),
+    String(String),
+    List(Vec<NbtTag>),
+    /// Entries in file order.
+    Compound(Vec<(String, NbtTag)>),
+    IntArray(Vec<i32>),
+    LongArray(Vec<i64>),
+}
+
+impl NbtTag {
+    pub fn get(&self, key: &str) -> Option<&NbtTag> {
+        match self {
+            Self::Compound(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// Follows a dotted path of compound keys, e.g. `Data.WorldGenSettings.seed`.
+    pub fn get_path(&self, path: &str) -> Option<&NbtTag> {
+        path.split('.').try_fold(self, |tag, key| tag.get(key))
+    }
+
+    /// Any integer tag widened to `i64`.
+    pub fn as_i64(&self) -> Option<i64> {
+        match *self {
+            Self::Byte(v) => Some(v.into()),
+            Self::Short(v) => Some(v.into()),
+            Self::Int(v) => Some(v.into()),
+            Self::Long(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// The type id written before the tag.
+    pub fn id(&self) -> u8 {
+        match self {
+            Self::Byte(_) => 1,
+            Self::Short(_) => 2,
+            Self::Int(_) => 3,
+            Self::Long(_) => 4,
+            Self::Float(_) => 5,
+            Self::Double(_) => 6,
+            Self::ByteArray(_) => 7,
+            Self::String(_) => 8,
+            Self::List(_) => 9,
+            Self::Compound(_) => 10,
+            Self::IntArray(_) => 11,
+            Self::LongArray(_) => 12,
+        }
+    }
+}
+
+/// A parsed NBT file: the root compound, its name and how the file was compressed.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NbtFile {
+    pub name: String,
+    pub root: NbtTag,
+    pub compression: NbtCompression,
+}
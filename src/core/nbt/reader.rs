@@ -0,0 +1,134 @@
+use super::{MAX_DEPTH, NbtCompression, NbtFile, NbtTag};
+use anyhow::{Result, anyhow};
+use flate2::read::{GzDecoder, ZlibDecoder};
+use std::io::Read;
+
+/// Parses NBT that may be gzip-compressed (`level.dat`), zlib-compressed or raw.
+pub fn read_nbt(bytes: &[u8]) -> Result<NbtFile> {
+    let (compression, data) = match bytes {
+        [0x1f, 0x8b, ..] => {
+            let mut out = Vec::new();
+            GzDecoder::new(bytes).read_to_end(&mut out)?;
+            (NbtCompression::Gzip, out)
+        }
+        [0x78, ..] => {
+            let mut out = Vec::new();
+            ZlibDecoder::new(bytes).read_to_end(&mut out)?;
+            (NbtCompression::Zlib, out)
+        }
+        _ => (NbtCompression::None, bytes.to_vec()),
+    };
+
+    let mut reader = Reader { data: &data, pos: 0 };
+    let tag_type = reader.u8()?;
+    if tag_type != 10 {
+        return Err(anyhow!("Not an NBT file: the root tag isn't a compound"));
+    }
+    let name = reader.string()?;
+    let root = reader.payload(tag_type, 0)?;
+    Ok(NbtFile {
+        name,
+        root,
+        compression,
+    })
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| anyhow!("NBT data ends unexpectedly"))?;
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        Ok(self.take(N)?.try_into().expect("take returns N bytes"))
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.array::<1>()?[0])
+    }
+
+    /// An array or list length, checked against what's left so a corrupt length can't
+    /// trigger a huge allocation.
+    fn len(&mut self, element_size: usize) -> Result<usize> {
+        let len = i32::from_be_bytes(self.array()?);
+        let len = usize::try_from(len).map_err(|_| anyhow!("Negative NBT length"))?;
+        if len.saturating_mul(element_size) > self.data.len() - self.pos {
+            return Err(anyhow!("NBT length {} runs past the end of the data", len));
+        }
+        Ok(len)
+    }
+
+    fn string(&mut self) -> Result<String> {
+        let len = u16::from_be_bytes(self.array()?) as usize;
+        // Java's modified UTF-8 only differs for NUL and supplementary characters
+        Ok(String::from_utf8_lossy(self.take(len)?).into_owned())
+    }
+
+    fn payload(&mut self, tag_type: u8, depth: usize) -> Result<NbtTag> {
+        if depth > MAX_DEPTH {
+            return Err(anyhow!("NBT is nested too deeply"));
+        }
+        Ok(match tag_type {
+            1 => NbtTag::Byte(i8::from_be_bytes(self.array()?)),
+            2 => NbtTag::Short(i16::from_be_bytes(self.array()?)),
+            3 => NbtTag::Int(i32::from_be_bytes(self.array()?)),
+            4 => NbtTag::Long(i64::from_be_bytes(self.array()?)),
+            5 => NbtTag::Float(f32::from_be_bytes(self.array()?)),
+            6 => NbtTag::Double(f64::from_be_bytes(self.array()?)),
+            7 => {
+                let len = self.len(1)?;
+                NbtTag::ByteArray(self.take(len)?.iter().map(|b| *b as i8).collect())
+            }
+            8 => NbtTag::String(self.string()?),
+            9 => {
+                let element_type = self.u8()?;
+                let len = self.len(1)?;
+                if element_type == 0 && len > 0 {
+                    return Err(anyhow!("NBT list of end tags"));
+                }
+                let items = (0..len)
+                    .map(|_| self.payload(element_type, depth + 1))
+                    .collect::<Result<_>>()?;
+                NbtTag::List(items)
+            }
+            10 => {
+                let mut entries = Vec::new();
+                loop {
+                    let child_type = self.u8()?;
+                    if child_type == 0 {
+                        break;
+                    }
+                    let name = self.string()?;
+                    entries.push((name, self.payload(child_type, depth + 1)?));
+                }
+                NbtTag::Compound(entries)
+            }
+            11 => {
+                let len = self.len(4)?;
+                let values = (0..len)
+                    .map(|_| self.array().map(i32::from_be_bytes))
+                    .collect::<Result<_>>()?;
+                NbtTag::IntArray(values)
+            }
+            12 => {
+                let len = self.len(8)?;
+                let values = (0..len)
+                    .map(|_| self.array().map(i64::from_be_bytes))
+                    .collect::<Result<_>>()?;
+                NbtTag::LongArray(values)
+            }
+            other => return Err(anyhow!("Unknown NBT tag type {}", other)),
+        })
+    }
+}
@@ -0,0 +1,90 @@
+use super::{MAX_DEPTH, NbtCompression, NbtFile, NbtTag};
+use anyhow::{Result, anyhow};
+use flate2::Compression;
+use flate2::write::{GzEncoder, ZlibEncoder};
+use std::io::Write;
+
+/// Serializes `file`, compressed the way [`NbtFile::compression`] says.
+pub fn write_nbt(file: &NbtFile) -> Result<Vec<u8>> {
+    if !matches!(file.root, NbtTag::Compound(_)) {
+        return Err(anyhow!("The root NBT tag must be a compound"));
+    }
+    let mut data = vec![file.root.id()];
+    write_string(&mut data, &file.name)?;
+    write_payload(&mut data, &file.root, 0)?;
+
+    Ok(match file.compression {
+        NbtCompression::None => data,
+        NbtCompression::Gzip => {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(&data)?;
+            encoder.finish()?
+        }
+        NbtCompression::Zlib => {
+            let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(&data)?;
+            encoder.finish()?
+        }
+    })
+}
+
+fn write_string(out: &mut Vec<u8>, s: &str) -> Result<()> {
+    let len = u16::try_from(s.len()).map_err(|_| anyhow!("NBT string is longer than 65535 bytes"))?;
+    out.extend_from_slice(&len.to_be_bytes());
+    out.extend_from_slice(s.as_bytes());
+    Ok(())
+}
+
+fn write_len(out: &mut Vec<u8>, len: usize) -> Result<()> {
+    let len = i32::try_from(len).map_err(|_| anyhow!("NBT array is too long"))?;
+    out.extend_from_slice(&len.to_be_bytes());
+    Ok(())
+}
+
+fn write_payload(out: &mut Vec<u8>, tag: &NbtTag, depth: usize) -> Result<()> {
+    if depth > MAX_DEPTH {
+        return Err(anyhow!("NBT is nested too deeply"));
+    }
+    match tag {
+        NbtTag::Byte(v) => out.extend_from_slice(&v.to_be_bytes()),
+        NbtTag::Short(v) => out.extend_from_slice(&v.to_be_bytes()),
+        NbtTag::Int(v) => out.extend_from_slice(&v.to_be_bytes()),
+        NbtTag::Long(v) => out.extend_from_slice(&v.to_be_bytes()),
+        NbtTag::Float(v) => out.extend_from_slice(&v.to_be_bytes()),
+        NbtTag::Double(v) => out.extend_from_slice(&v.to_be_bytes()),
+        NbtTag::ByteArray(values) => {
+            write_len(out, values.len())?;
+            out.extend(values.iter().map(|b| *b as u8));
+        }
+        NbtTag::String(s) => write_string(out, s)?,
+        NbtTag::List(items) => {
+            // An empty list is written with the end tag as its element type
+            let element_type = items.first().map_or(0, NbtTag::id);
+            if items.iter().any(|item| item.id() != element_type) {
+                return Err(anyhow!("NBT list items must all have the same type"));
+            }
+            out.push(element_type);
+            write_len(out, items.len())?;
+            for item in items {
+                write_payload(out, item, depth + 1)?;
+            }
+        }
+        NbtTag::Compound(entries) => {
+            for (name, value) in entries {
+                out.push(value.id());
+                write_string(out, name)?;
+                write_payload(out, value, depth + 1)?;
+            }
+            out.push(0);
+        }
+        NbtTag::IntArray(values) => {
+            write_len(out, values.len())?;
+            values.iter().for_each(|v| out.extend_from_slice(&v.to_be_bytes()));
+        }
+        NbtTag::LongArray(values) => {
+            write_len(out, values.len())?;
+            values.iter().for_each(|v| out.extend_from_slice(&v.to_be_bytes()));
+        }
+    }
+    Ok(())
+}
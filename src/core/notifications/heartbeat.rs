@@ -0,0 +1,74 @@
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+const HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(10);
+/// Shortest allowed gap between pings, so a typo can't hammer the monitoring service.
+pub const MIN_HEARTBEAT_INTERVAL: u64 = 10;
+
+/// Outbound "I'm alive" pings for dead-man's-switch services such as healthchecks.io.
+/// The URL is pinged while the server is running; when pings stop arriving (server
+/// down, wrapper closed, host offline) the service raises the alert.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct HeartbeatSettings {
+    pub enabled: bool,
+    pub url: String,
+    pub interval_secs: u64,
+    /// Ping `<url>/fail` when the server crashes, so the alert fires immediately
+    /// instead of after the service's grace period.
+    pub report_failures: bool,
+}
+
+impl Default for HeartbeatSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            url: String::new(),
+            interval_secs: 60,
+            report_failures: true,
+        }
+    }
+}
+
+impl HeartbeatSettings {
+    pub fn is_active(&self) -> bool {
+        self.enabled && !self.url.trim().is_empty()
+    }
+
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(self.interval_secs.max(MIN_HEARTBEAT_INTERVAL))
+    }
+
+    /// URL to ping for a healthy server, or the healthchecks.io-style failure URL.
+    pub fn ping_url(&self, healthy: bool) -> String {
+        let url = self.url.trim();
+        if healthy {
+            return url.to_string();
+        }
+        match url.split_once('?') {
+            Some((base, query)) => format!("{}/fail?{}", base.trim_end_matches('/'), query),
+            None => format!("{}/fail", url.trim_end_matches('/')),
+        }
+    }
+}
+
+pub async fn send_heartbeat(url: &str) -> Result<()> {
+    let response = reqwest::Client::new()
+        .get(url)
+        .timeout(HEARTBEAT_TIMEOUT)
+        .send()
+        .await
+        .context("Failed to reach heartbeat URL")?;
+    if !response.status().is_success() {
+        return Err(anyhow!("Heartbeat URL returned {}", response.status()));
+    }
+    Ok(())
+}
+
+pub async fn send_test_heartbeat(settings: &HeartbeatSettings) -> Result<()> {
+    if settings.url.trim().is_empty() {
+        return Err(anyhow!("No heartbeat URL configured"));
+    }
+    send_heartbeat(&settings.ping_url(true)).await
+}
@@ -0,0 +1,159 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub mod heartbeat;
+pub mod rules;
+pub mod store;
+pub mod watcher;
+pub mod webhook;
+
+pub use heartbeat::{HeartbeatSettings, send_heartbeat, send_test_heartbeat};
+pub use rules::{DesktopNotification, NotificationRule, NotificationTrigger, RuleSet};
+pub use watcher::EventTracker;
+pub use webhook::{build_payload, notify, send_test_webhook, send_webhook};
+
+/// Something worth telling the server owner about.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum NotificationEvent {
+    ServerStarted,
+    ServerStopped,
+    ServerCrashed { reason: String },
+    PlayerJoined { player: String },
+    PlayerLeft { player: String },
+    BackupCompleted { backup: String },
+    LowTps { tps: f32 },
+    Test,
+}
+
+impl NotificationEvent {
+    /// Key used for per-event enable flags and template overrides.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::ServerStarted => "server_started",
+            Self::ServerStopped => "server_stopped",
+            Self::ServerCrashed { .. } => "server_crashed",
+            Self::PlayerJoined { .. } => "player_joined",
+            Self::PlayerLeft { .. } => "player_left",
+            Self::BackupCompleted { .. } => "backup_completed",
+            Self::LowTps { .. } => "low_tps",
+            Self::Test => "test",
+        }
+    }
+
+    pub fn default_template(&self) -> &'static str {
+        match self {
+            Self::ServerStarted => ":green_circle: **{instance}** is online",
+            Self::ServerStopped => ":red_circle: **{instance}** stopped",
+            Self::ServerCrashed { .. } => ":boom: **{instance}** crashed: {reason}",
+            Self::PlayerJoined { .. } => "{player} joined **{instance}**",
+            Self::PlayerLeft { .. } => "{player} left **{instance}**",
+            Self::BackupCompleted { .. } => ":floppy_disk: Backup `{backup}` of **{instance}** completed",
+            Self::LowTps { .. } => ":warning: **{instance}** is running at {tps} TPS",
+            Self::Test => "Test notification from **{instance}**",
+        }
+    }
+
+    /// Renders `template`, substituting `{instance}` and the event's own placeholders.
+    pub fn render(&self, template: &str, instance: &str) -> String {
+        let message = template.replace("{instance}", instance);
+        match self {
+            Self::ServerCrashed { reason } => message.replace("{reason}", reason),
+            Self::PlayerJoined { player } | Self::PlayerLeft { player } => message.replace("{player}", player),
+            Self::BackupCompleted { backup } => message.replace("{backup}", backup),
+            Self::LowTps { tps } => message.replace("{tps}", &format!("{:.1}", tps)),
+            _ => message,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookFormat {
+    #[default]
+    Discord,
+    Slack,
+    /// Posts the event itself alongside the rendered message.
+    Generic,
+}
+
+/// Which events are posted; everything but player traffic is on by default.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct WebhookEvents {
+    pub server_started: bool,
+    pub server_stopped: bool,
+    pub server_crashed: bool,
+    pub player_joined: bool,
+    pub player_left: bool,
+    pub backup_completed: bool,
+    pub low_tps: bool,
+}
+
+impl Default for WebhookEvents {
+    fn default() -> Self {
+        Self {
+            server_started: true,
+            server_stopped: true,
+            server_crashed: true,
+            player_joined: false,
+            player_left: false,
+            backup_completed: true,
+            low_tps: true,
+        }
+    }
+}
+
+/// Per-instance webhook configuration, stored in `InstanceSettings`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct WebhookSettings {
+    pub enabled: bool,
+    pub url: String,
+    pub format: WebhookFormat,
+    pub events: WebhookEvents,
+    /// Message overrides keyed by event (e.g. `player_joined`).
+    pub templates: HashMap<String, String>,
+    pub low_tps_threshold: f32,
+}
+
+impl Default for WebhookSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            url: String::new(),
+            format: WebhookFormat::default(),
+            events: WebhookEvents::default(),
+            templates: HashMap::new(),
+            low_tps_threshold: 15.0,
+        }
+    }
+}
+
+impl WebhookSettings {
+    pub fn wants(&self, event: &NotificationEvent) -> bool {
+        if !self.enabled || self.url.trim().is_empty() {
+            return false;
+        }
+        match event {
+            NotificationEvent::ServerStarted => self.events.server_started,
+            NotificationEvent::ServerStopped => self.events.server_stopped,
+            NotificationEvent::ServerCrashed { .. } => self.events.server_crashed,
+            NotificationEvent::PlayerJoined { .. } => self.events.player_joined,
+            NotificationEvent::PlayerLeft { .. } => self.events.player_left,
+            NotificationEvent::BackupCompleted { .. } => self.events.backup_completed,
+            NotificationEvent::LowTps { .. } => self.events.low_tps,
+            NotificationEvent::Test => true,
+        }
+    }
+
+    pub fn message(&self, event: &NotificationEvent, instance: &str) -> String {
+        let template = self
+            .templates
+            .get(event.key())
+            .filter(|t| !t.trim().is_empty())
+            .map(String::as_str)
+            .unwrap_or_else(|| event.default_template());
+        event.render(template, instance)
+    }
+}
@@ -0,0 +1,154 @@
+use super::NotificationEvent;
+use crate::server::ServerStatus;
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tracing::warn;
+use uuid::Uuid;
+
+/// Minimum time between two notifications from the same rule, so a chatty log
+/// pattern can't flood the desktop.
+const RULE_COOLDOWN: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NotificationTrigger {
+    /// Regex tested against each console line.
+    LogMatch { pattern: String },
+    /// The server became `Running`, `Stopped` or `Crashed`.
+    StatusChange { status: ServerStatus },
+    /// A player joined; `None` matches anyone.
+    PlayerJoin { player: Option<String> },
+}
+
+/// A user-defined desktop notification. Rules without an instance apply to every server.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NotificationRule {
+    #[serde(default)]
+    pub id: i64,
+    pub instance_id: Option<Uuid>,
+    pub name: String,
+    pub trigger: NotificationTrigger,
+    /// `{instance}`, `{line}`, `{player}` and `{status}` are substituted.
+    pub title: String,
+    pub body: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    #[serde(default = "Utc::now")]
+    pub created_at: DateTime<Utc>,
+}
+
+fn default_enabled() -> bool { true }
+
+/// A fired rule, forwarded to the desktop by the app.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct DesktopNotification {
+    pub instance_id: Uuid,
+    pub rule_id: i64,
+    pub title: String,
+    pub body: String,
+}
+
+fn render(template: &str, instance: &str, values: &[(&str, &str)]) -> String {
+    values
+        .iter()
+        .fold(template.replace("{instance}", instance), |text, (key, value)| {
+            text.replace(&format!("{{{}}}", key), value)
+        })
+}
+
+/// Enabled rules for one server, with log patterns compiled once.
+#[derive(Default)]
+pub struct RuleSet {
+    rules: Vec<(NotificationRule, Option<Regex>)>,
+    last_fired: HashMap<i64, Instant>,
+}
+
+impl RuleSet {
+    pub fn new(rules: Vec<NotificationRule>) -> Self {
+        let mut set = Self::default();
+        set.replace(rules);
+        set
+    }
+
+    /// Swaps in a fresh copy of the rules while keeping cooldowns.
+    pub fn replace(&mut self, rules: Vec<NotificationRule>) {
+        self.rules = rules
+            .into_iter()
+            .filter(|rule| rule.enabled)
+            .filter_map(|rule| match &rule.trigger {
+                NotificationTrigger::LogMatch { pattern } => match Regex::new(pattern) {
+                    Ok(re) => Some((rule, Some(re))),
+                    Err(e) => {
+                        warn!("Skipping notification rule '{}': invalid pattern: {}", rule.name, e);
+                        None
+                    }
+                },
+                _ => Some((rule, None)),
+            })
+            .collect();
+    }
+
+    fn fire(&mut self, rule: &NotificationRule, instance_id: Uuid, instance: &str, values: &[(&str, &str)]) -> Option<DesktopNotification> {
+        let now = Instant::now();
+        if self.last_fired.get(&rule.id).is_some_and(|at| now.duration_since(*at) < RULE_COOLDOWN) {
+            return None;
+        }
+        self.last_fired.insert(rule.id, now);
+        Some(DesktopNotification {
+            instance_id,
+            rule_id: rule.id,
+            title: render(&rule.title, instance, values),
+            body: render(&rule.body, instance, values),
+        })
+    }
+
+    pub fn match_log(&mut self, instance_id: Uuid, instance: &str, line: &str) -> Vec<DesktopNotification> {
+        let matched: Vec<NotificationRule> = self
+            .rules
+            .iter()
+            .filter(|(_, re)| re.as_ref().is_some_and(|re| re.is_match(line)))
+            .map(|(rule, _)| rule.clone())
+            .collect();
+        matched
+            .iter()
+            .filter_map(|rule| self.fire(rule, instance_id, instance, &[("line", line)]))
+            .collect()
+    }
+
+    pub fn match_event(&mut self, instance_id: Uuid, instance: &str, event: &NotificationEvent) -> Vec<DesktopNotification> {
+        let status = match event {
+            NotificationEvent::ServerStarted => Some(ServerStatus::Running),
+            NotificationEvent::ServerStopped => Some(ServerStatus::Stopped),
+            NotificationEvent::ServerCrashed { .. } => Some(ServerStatus::Crashed),
+            _ => None,
+        };
+        let joined = match event {
+            NotificationEvent::PlayerJoined { player } => Some(player.as_str()),
+            _ => None,
+        };
+
+        let matched: Vec<NotificationRule> = self
+            .rules
+            .iter()
+            .map(|(rule, _)| rule)
+            .filter(|rule| match &rule.trigger {
+                NotificationTrigger::StatusChange { status: wanted } => status == Some(*wanted),
+                NotificationTrigger::PlayerJoin { player } => joined.is_some_and(|joined| {
+                    player.as_deref().is_none_or(|p| p.eq_ignore_ascii_case(joined))
+                }),
+                NotificationTrigger::LogMatch { .. } => false,
+            })
+            .cloned()
+            .collect();
+
+        let status_text = status.map(|s| s.to_string()).unwrap_or_default();
+        let values = [("player", joined.unwrap_or_default()), ("status", status_text.as_str())];
+        matched
+            .iter()
+            .filter_map(|rule| self.fire(rule, instance_id, instance, &values))
+            .collect()
+    }
+}
@@ -0,0 +1,101 @@
+use super::rules::{NotificationRule, NotificationTrigger};
+use crate::instance::InstanceManager;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use sqlx::Row;
+use sqlx::sqlite::SqliteRow;
+use uuid::Uuid;
+
+fn rule_from_row(row: &SqliteRow) -> Option<NotificationRule> {
+    let instance_id: Option<String> = row.get("instance_id");
+    let trigger: String = row.get("trigger");
+    let created_at: String = row.get("created_at");
+    Some(NotificationRule {
+        id: row.get("id"),
+        instance_id: instance_id.and_then(|id| Uuid::parse_str(&id).ok()),
+        name: row.get("name"),
+        trigger: serde_json::from_str(&trigger).ok()?,
+        title: row.get("title"),
+        body: row.get("body"),
+        enabled: row.get("enabled"),
+        created_at: DateTime::parse_from_rfc3339(&created_at)
+            .map(|d| d.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now()),
+    })
+}
+
+impl InstanceManager {
+    /// Global rules, plus the instance's own rules when `instance_id` is given.
+    pub async fn list_notification_rules(&self, instance_id: Option<Uuid>) -> Result<Vec<NotificationRule>> {
+        let rows = sqlx::query(
+            "SELECT id, instance_id, name, trigger, title, body, enabled, created_at FROM notification_rules
+                WHERE instance_id IS NULL OR instance_id = ? ORDER BY id",
+        )
+        .bind(instance_id.map(|id| id.to_string()))
+        .fetch_all(self.db.pool())
+        .await?;
+        Ok(rows.iter().filter_map(rule_from_row).collect())
+    }
+
+    /// Inserts a rule when its id is 0, otherwise updates it. Returns the rule id.
+    pub async fn save_notification_rule(&self, rule: &NotificationRule) -> Result<i64> {
+        if let NotificationTrigger::LogMatch { pattern } = &rule.trigger {
+            Regex::new(pattern).context("Invalid log pattern")?;
+        }
+        let trigger = serde_json::to_string(&rule.trigger)?;
+        let instance_id = rule.instance_id.map(|id| id.to_string());
+
+        if rule.id == 0 {
+            let result = sqlx::query(
+                "INSERT INTO notification_rules (instance_id, name, trigger, title, body, enabled, created_at)
+                    VALUES (?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(instance_id)
+            .bind(&rule.name)
+            .bind(trigger)
+            .bind(&rule.title)
+            .bind(&rule.body)
+            .bind(rule.enabled)
+            .bind(Utc::now().to_rfc3339())
+            .execute(self.db.pool())
+            .await?;
+            return Ok(result.last_insert_rowid());
+        }
+
+        let result = sqlx::query(
+            "UPDATE notification_rules SET instance_id = ?, name = ?, trigger = ?, title = ?, body = ?, enabled = ?
+                WHERE id = ?",
+        )
+        .bind(instance_id)
+        .bind(&rule.name)
+        .bind(trigger)
+        .bind(&rule.title)
+        .bind(&rule.body)
+        .bind(rule.enabled)
+        .bind(rule.id)
+        .execute(self.db.pool())
+        .await?;
+        if result.rows_affected() == 0 {
+            anyhow::bail!("Notification rule {} not found", rule.id);
+        }
+        Ok(rule.id)
+    }
+
+    pub async fn delete_notification_rule(&self, rule_id: i64) -> Result<()> {
+        sqlx::query("DELETE FROM notification_rules WHERE id = ?")
+            .bind(rule_id)
+            .execute(self.db.pool())
+            .await?;
+        Ok(())
+    }
+
+    /// Removes an instance's own rules; global rules are kept.
+    pub async fn clear_notification_rules(&self, instance_id: Uuid) -> Result<()> {
+        sqlx::query("DELETE FROM notification_rules WHERE instance_id = ?")
+            .bind(instance_id.to_string())
+            .execute(self.db.pool())
+            .await?;
+        Ok(())
+    }
+}
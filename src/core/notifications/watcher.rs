@@ -0,0 +1,80 @@
+use super::NotificationEvent;
+use crate::server::ServerStatus;
+use std::collections::HashSet;
+
+/// TPS must climb this far above the threshold before another low-TPS alert can fire.
+const LOW_TPS_RECOVERY: f32 = 2.0;
+
+/// Turns successive server snapshots into notification events.
+#[derive(Debug, Default)]
+pub struct EventTracker {
+    status: ServerStatus,
+    players: HashSet<String>,
+    crash_reason: Option<String>,
+    low_tps_alerted: bool,
+}
+
+impl EventTracker {
+    pub fn new(status: ServerStatus) -> Self {
+        Self { status, ..Default::default() }
+    }
+
+    /// Remembers the exit message the lifecycle loop logs before marking a crash.
+    pub fn observe_log(&mut self, line: &str) {
+        if let Some(reason) = line.strip_prefix("CRASH: ") {
+            self.crash_reason = Some(reason.to_string());
+        }
+    }
+
+    pub fn observe(
+        &mut self,
+        status: ServerStatus,
+        players: &[String],
+        tps: Option<f32>,
+        low_tps_threshold: f32,
+    ) -> Vec<NotificationEvent> {
+        let mut events = Vec::new();
+
+        if status != self.status {
+            match status {
+                ServerStatus::Running => events.push(NotificationEvent::ServerStarted),
+                ServerStatus::Stopped => events.push(NotificationEvent::ServerStopped),
+                ServerStatus::Crashed => events.push(NotificationEvent::ServerCrashed {
+                    reason: self
+                        .crash_reason
+                        .take()
+                        .unwrap_or_else(|| "process exited unexpectedly".to_string()),
+                }),
+                _ => {}
+            }
+            self.status = status;
+        }
+
+        if status != ServerStatus::Running {
+            // Players are dropped silently when the server goes down
+            self.players.clear();
+            self.low_tps_alerted = false;
+            return events;
+        }
+
+        let current: HashSet<String> = players.iter().cloned().collect();
+        let mut joined: Vec<&String> = current.difference(&self.players).collect();
+        let mut left: Vec<&String> = self.players.difference(&current).collect();
+        joined.sort();
+        left.sort();
+        events.extend(joined.into_iter().map(|p| NotificationEvent::PlayerJoined { player: p.clone() }));
+        events.extend(left.into_iter().map(|p| NotificationEvent::PlayerLeft { player: p.clone() }));
+        self.players = current;
+
+        if let Some(tps) = tps {
+            if tps < low_tps_threshold && !self.low_tps_alerted {
+                self.low_tps_alerted = true;
+                events.push(NotificationEvent::LowTps { tps });
+            } else if tps >= low_tps_threshold + LOW_TPS_RECOVERY {
+                self.low_tps_alerted = false;
+            }
+        }
+
+        events
+    }
+}
@@ -0,0 +1,62 @@
+use super::{NotificationEvent, WebhookFormat, WebhookSettings};
+use crate::instance::InstanceMetadata;
+use anyhow::{Context, Result, anyhow};
+use serde_json::{Value, json};
+use std::time::Duration;
+use tracing::warn;
+
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub fn build_payload(format: WebhookFormat, instance: &str, event: &NotificationEvent, message: &str) -> Value {
+    match format {
+        WebhookFormat::Discord => json!({ "content": message }),
+        WebhookFormat::Slack => json!({ "text": message }),
+        WebhookFormat::Generic => json!({
+            "instance": instance,
+            "message": message,
+            "details": event,
+        }),
+    }
+}
+
+/// Posts `event` to the configured webhook with the app's shared `client`, regardless of
+/// its enable flags.
+pub async fn send_webhook(
+    client: &reqwest::Client,
+    settings: &WebhookSettings,
+    instance: &str,
+    event: &NotificationEvent,
+) -> Result<()> {
+    let message = settings.message(event, instance);
+    let payload = build_payload(settings.format, instance, event, &message);
+    let response = client
+        .post(settings.url.trim())
+        .timeout(WEBHOOK_TIMEOUT)
+        .json(&payload)
+        .send()
+        .await
+        .context("Failed to reach webhook")?;
+    if !response.status().is_success() {
+        return Err(anyhow!("Webhook returned {}", response.status()));
+    }
+    Ok(())
+}
+
+pub async fn send_test_webhook(client: &reqwest::Client, settings: &WebhookSettings, instance: &str) -> Result<()> {
+    if settings.url.trim().is_empty() {
+        return Err(anyhow!("No webhook URL configured"));
+    }
+    send_webhook(client, settings, instance, &NotificationEvent::Test).await
+}
+
+/// Sends `event` if the instance has it enabled. Failures are logged, never returned,
+/// so a broken webhook can't interfere with the operation that triggered it.
+pub async fn notify(client: &reqwest::Client, instance: &InstanceMetadata, event: NotificationEvent) {
+    let settings = &instance.settings.webhooks;
+    if !settings.wants(&event) {
+        return;
+    }
+    if let Err(e) = send_webhook(client, settings, &instance.name, &event).await {
+        warn!("Failed to send {} webhook for {}: {}", event.key(), instance.name, e);
+    }
+}
@@ -0,0 +1,104 @@
+//! Spots player list setups that lock everyone out of a server, such as a whitelist
+//! that is switched on but empty.
+
+use super::io::{PlayerListLocks, read_ops, read_usercache, read_whitelist, update_whitelist};
+use super::types::PlayerEntry;
+use crate::server_properties::{read_server_properties, write_server_properties};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccessIssue {
+    /// `white-list=true` with nobody on the whitelist, so no one can join.
+    EmptyWhitelist,
+    /// Nobody has operator rights and nobody has joined yet.
+    NoOperators,
+}
+
+/// A one-click fix the UI can offer for an issue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum AccessFix {
+    DisableWhitelist,
+    /// Whitelist every player the server has seen, from `usercache.json`.
+    WhitelistKnownPlayers,
+    /// Needs a username.
+    AddToWhitelist,
+    /// Needs a username.
+    AddOperator,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccessWarning {
+    pub issue: AccessIssue,
+    pub message: String,
+    pub fixes: Vec<AccessFix>,
+}
+
+/// Looks for lock-out mistakes in a Java server's player lists. Bedrock and proxies
+/// manage access differently and are skipped.
+pub async fn check_player_access(instance_path: &Path, loader: Option<&str>) -> Result<Vec<AccessWarning>> {
+    if matches!(
+        loader.map(|l| l.to_lowercase()).as_deref(),
+        Some("bedrock" | "velocity" | "bungeecord")
+    ) {
+        return Ok(Vec::new());
+    }
+    let props = read_server_properties(instance_path).await?;
+    let whitelist = read_whitelist(instance_path).await?;
+    let ops = read_ops(instance_path).await?;
+    let known = read_usercache(instance_path).await?;
+
+    let mut warnings = Vec::new();
+    if props.get("white-list").is_some_and(|v| v.trim() == "true") && whitelist.is_empty() {
+        let mut fixes = vec![AccessFix::DisableWhitelist];
+        if !known.is_empty() {
+            fixes.push(AccessFix::WhitelistKnownPlayers);
+        }
+        fixes.push(AccessFix::AddToWhitelist);
+        warnings.push(AccessWarning {
+            issue: AccessIssue::EmptyWhitelist,
+            message: "The whitelist is on but empty, so nobody can join".to_string(),
+            fixes,
+        });
+    }
+    if ops.is_empty() && known.is_empty() {
+        warnings.push(AccessWarning {
+            issue: AccessIssue::NoOperators,
+            message: "Nobody is an operator yet, so in-game admin commands can only be run from the console"
+                .to_string(),
+            fixes: vec![AccessFix::AddOperator],
+        });
+    }
+    Ok(warnings)
+}
+
+/// Turns the whitelist off in `server.properties`. A running server keeps its current
+/// setting until it restarts; use `whitelist off` for it instead.
+pub async fn disable_whitelist(instance_path: &Path) -> Result<()> {
+    let mut props = read_server_properties(instance_path).await?;
+    props.insert("white-list".to_string(), "false".to_string());
+    write_server_properties(instance_path, &props).await
+}
+
+/// Adds everyone in `usercache.json` to the whitelist and returns how many were added.
+pub async fn whitelist_known_players(locks: &PlayerListLocks, instance_path: &Path) -> Result<usize> {
+    let known = read_usercache(instance_path).await?;
+    let mut added = 0;
+    update_whitelist(locks, instance_path, |list| {
+        for player in known {
+            if !list.iter().any(|p| p.uuid == player.uuid) {
+                list.push(PlayerEntry {
+                    uuid: player.uuid,
+                    name: player.name,
+                });
+                added += 1;
+            }
+        }
+        added > 0
+    })
+    .await?;
+    Ok(added)
+}
@@ -0,0 +1,75 @@
+use super::*;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::{Mutex, OwnedMutexGuard};
+
+/// One lock per instance directory, shared by all of its player list files. Held by the
+/// instance manager so every writer in the app goes through the same locks.
+#[derive(Default)]
+pub struct PlayerListLocks {
+    locks: Mutex<HashMap<PathBuf, Arc<Mutex<()>>>>,
+}
+
+impl PlayerListLocks {
+    /// Serializes read-modify-write cycles on an instance's whitelist, ops and ban lists.
+    pub async fn lock(&self, path: &Path) -> OwnedMutexGuard<()> {
+        let key = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        let lock = Arc::clone(self.locks.lock().await.entry(key).or_default());
+        lock.lock_owned().await
+    }
+}
+
+/// Reads the whitelist, applies `f` and writes it back if `f` returns `true`,
+/// all while holding the instance's player list lock.
+pub async fn update_whitelist(
+    locks: &PlayerListLocks,
+    path: &Path,
+    f: impl FnOnce(&mut Vec<PlayerEntry>) -> bool,
+) -> Result<()> {
+    let _guard = locks.lock(path).await;
+    let mut list = read_whitelist(path).await?;
+    if f(&mut list) {
+        write_whitelist(path, &list).await?;
+    }
+    Ok(())
+}
+
+pub async fn update_ops(
+    locks: &PlayerListLocks,
+    path: &Path,
+    f: impl FnOnce(&mut Vec<OpEntry>) -> bool,
+) -> Result<()> {
+    let _guard = locks.lock(path).await;
+    let mut list = read_ops(path).await?;
+    if f(&mut list) {
+        write_ops(path, &list).await?;
+    }
+    Ok(())
+}
+
+pub async fn update_banned_players(
+    locks: &PlayerListLocks,
+    path: &Path,
+    f: impl FnOnce(&mut Vec<BannedPlayerEntry>) -> bool,
+) -> Result<()> {
+    let _guard = locks.lock(path).await;
+    let mut list = read_banned_players(path).await?;
+    if f(&mut list) {
+        write_banned_players(path, &list).await?;
+    }
+    Ok(())
+}
+
+pub async fn update_banned_ips(
+    locks: &PlayerListLocks,
+    path: &Path,
+    f: impl FnOnce(&mut Vec<BannedIpEntry>) -> bool,
+) -> Result<()> {
+    let _guard = locks.lock(path).await;
+    let mut list = read_banned_ips(path).await?;
+    if f(&mut list) {
+        write_banned_ips(path, &list).await?;
+    }
+    Ok(())
+}
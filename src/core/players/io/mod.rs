@@ -3,6 +3,11 @@ use anyhow::{Context, Result};
 use std::path::Path;
 use tokio::fs;
 
+mod lock;
+
+pub use lock::{PlayerListLocks, update_banned_ips, update_banned_players, update_ops, update_whitelist};
+use crate::utils::write_atomic;
+
 pub async fn read_usercache(path: &Path) -> Result<Vec<UserCacheEntry>> {
     let file_path = path.join("usercache.json");
     if !file_path.exists() {
@@ -41,7 +46,7 @@ pub async fn read_whitelist(path: &Path) -> Result<Vec<PlayerEntry>> {
 pub async fn write_whitelist(path: &Path, players: &[PlayerEntry]) -> Result<()> {
     let file_path = path.join("whitelist.json");
     let content = serde_json::to_string_pretty(players)?;
-    fs::write(file_path, content)
+    write_atomic(&file_path, content, false)
         .await
         .context("Failed to write whitelist.json")
 }
@@ -77,7 +82,7 @@ pub async fn read_ops(path: &Path) -> Result<Vec<OpEntry>> {
 pub async fn write_ops(path: &Path, ops: &[OpEntry]) -> Result<()> {
     let file_path = path.join("ops.json");
     let content = serde_json::to_string_pretty(ops)?;
-    fs::write(file_path, content)
+    write_atomic(&file_path, content, false)
         .await
         .context("Failed to write ops.json")
 }
@@ -129,7 +134,7 @@ pub async fn read_banned_players(path: &Path) -> Result<Vec<BannedPlayerEntry>>
 pub async fn write_banned_players(path: &Path, banned: &[BannedPlayerEntry]) -> Result<()> {
     let file_path = path.join("banned-players.json");
     let content = serde_json::to_string_pretty(banned)?;
-    fs::write(file_path, content)
+    write_atomic(&file_path, content, false)
         .await
         .context("Failed to write banned-players.json")
 }
@@ -179,7 +184,7 @@ pub async fn read_banned_ips(path: &Path) -> Result<Vec<BannedIpEntry>> {
 pub async fn write_banned_ips(path: &Path, banned: &[BannedIpEntry]) -> Result<()> {
     let file_path = path.join("banned-ips.json");
     let content = serde_json::to_string_pretty(banned)?;
-    fs::write(file_path, content)
+    write_atomic(&file_path, content, false)
         .await
         .context("Failed to write banned-ips.json")
 }
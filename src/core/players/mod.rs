@@ -1,7 +1,9 @@
 pub mod types;
 pub mod io;
 pub mod mojang;
+pub mod access;
 
 pub use types::*;
 pub use io::*;
 pub use mojang::*;
+pub use access::{AccessFix, AccessIssue, AccessWarning, check_player_access, disable_whitelist, whitelist_known_players};
@@ -0,0 +1,134 @@
+use crate::cache::CacheManager;
+use crate::mods::curseforge::CurseForgeClient as CommonClient;
+use crate::mods::types as mod_types;
+use crate::plugins::types::{
+    PluginProvider, Project, ProjectFile, ProjectVersion, ResolvedDependency, SearchOptions,
+    SortOrder,
+};
+use anyhow::{Result, anyhow};
+use std::path::Path;
+use std::sync::Arc;
+
+/// Bukkit plugins hosted on CurseForge, served through the mods CurseForge client.
+pub struct CurseForgeClient {
+    pub(crate) inner: CommonClient,
+}
+
+impl CurseForgeClient {
+    /// Creates a client using the stored CurseForge API key.
+    pub fn new(cache: Arc<CacheManager>) -> Self {
+        Self {
+            inner: CommonClient::new(None, cache),
+        }
+    }
+
+    pub async fn search(&self, options: &SearchOptions) -> Result<Vec<Project>> {
+        let options = mod_types::SearchOptions {
+            query: options.query.clone(),
+            facets: options.facets.clone(),
+            sort: options.sort.map(|s| match s {
+                SortOrder::Relevance => mod_types::SortOrder::Relevance,
+                SortOrder::Downloads => mod_types::SortOrder::Downloads,
+                SortOrder::Follows => mod_types::SortOrder::Follows,
+                SortOrder::Newest => mod_types::SortOrder::Newest,
+                SortOrder::Updated => mod_types::SortOrder::Updated,
+            }),
+            offset: options.offset,
+            limit: options.limit,
+            game_version: options.game_version.clone(),
+            // Bukkit files carry no mod loader tag
+            loader: None,
+            project_type: Some("plugin".to_string()),
+        };
+        let projects = self.inner.search(&options).await?;
+        Ok(projects.into_iter().map(convert_project).collect())
+    }
+
+    pub async fn get_project(&self, id: &str) -> Result<Project> {
+        self.inner.get_project(id).await.map(convert_project)
+    }
+
+    pub async fn get_dependencies(
+        &self,
+        project_id: &str,
+        game_version: Option<&str>,
+    ) -> Result<Vec<ResolvedDependency>> {
+        let deps = self.inner.get_dependencies(project_id, game_version, None).await?;
+        Ok(deps
+            .into_iter()
+            .map(|d| ResolvedDependency {
+                project: convert_project(d.project),
+                dependency_type: d.dependency_type,
+            })
+            .collect())
+    }
+
+    /// Lists files for a project, newest first. Falls back to all files when none are
+    /// tagged with the requested game version.
+    pub async fn get_versions(
+        &self,
+        project_id: &str,
+        game_version: Option<&str>,
+    ) -> Result<Vec<ProjectVersion>> {
+        let mut versions = self.inner.get_versions(project_id, game_version, None).await?;
+        if versions.is_empty() && game_version.is_some() {
+            versions = self.inner.get_versions(project_id, None, None).await?;
+        }
+        Ok(versions.into_iter().map(convert_version).collect())
+    }
+
+    pub async fn download_version(
+        &self,
+        version: &ProjectVersion,
+        target_dir: impl AsRef<Path>,
+    ) -> Result<String> {
+        let file = version
+            .files
+            .first()
+            .ok_or_else(|| anyhow!("No files found for version"))?;
+        if file.url.is_empty() {
+            // Authors can opt out of third-party distribution
+            return Err(anyhow!(
+                "{} cannot be downloaded outside CurseForge; install it manually",
+                file.filename
+            ));
+        }
+        self.inner.download_file(&file.url, &file.filename, target_dir).await
+    }
+}
+
+fn convert_project(p: mod_types::Project) -> Project {
+    Project {
+        id: p.id,
+        slug: p.slug,
+        title: p.title,
+        description: p.description,
+        downloads: p.downloads,
+        icon_url: p.icon_url,
+        screenshot_urls: p.screenshot_urls,
+        author: p.author,
+        provider: PluginProvider::CurseForge,
+        categories: p.categories,
+    }
+}
+
+fn convert_version(v: mod_types::ProjectVersion) -> ProjectVersion {
+    ProjectVersion {
+        id: v.id,
+        project_id: v.project_id,
+        version_number: v.version_number,
+        files: v
+            .files
+            .into_iter()
+            .map(|f| ProjectFile {
+                url: f.url,
+                filename: f.filename,
+                primary: f.primary,
+                size: f.size,
+                sha1: f.sha1,
+            })
+            .collect(),
+        loaders: v.loaders,
+        game_versions: v.game_versions,
+    }
+}
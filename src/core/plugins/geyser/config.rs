@@ -0,0 +1,70 @@
+use super::GeyserPlatform;
+use crate::config_files::yaml::{get_yaml_path, set_yaml_path};
+use anyhow::{Context, Result};
+use serde_yaml::Value as YamlValue;
+use std::path::Path;
+
+/// The Bedrock port a Geyser config listens on, if Geyser is set up there.
+pub fn read_bedrock_port(instance_path: &Path, platform: GeyserPlatform) -> Option<u16> {
+    let content = std::fs::read_to_string(instance_path.join(platform.geyser_config())).ok()?;
+    let yaml: YamlValue = serde_yaml::from_str(&content).ok()?;
+    get_yaml_path(&yaml, "bedrock.port")?.as_u64()?.try_into().ok()
+}
+
+/// Settings the wizard writes into Geyser's own config.
+pub fn geyser_settings(bedrock_port: u16) -> Vec<(&'static str, YamlValue)> {
+    vec![
+        ("bedrock.address", YamlValue::from("0.0.0.0")),
+        ("bedrock.port", YamlValue::from(bedrock_port)),
+        ("bedrock.clone-remote-port", YamlValue::from(false)),
+        ("remote.auth-type", YamlValue::from("floodgate")),
+    ]
+}
+
+/// Applies `settings` to the YAML file at `rel_path`. Returns false without touching
+/// anything when the file doesn't exist yet: Geyser and Floodgate migrate their configs
+/// by `config-version`, so a hand-written partial file could be misread on first start.
+pub async fn configure_yaml(instance_path: &Path, rel_path: &str, settings: &[(&str, YamlValue)]) -> Result<bool> {
+    let path = instance_path.join(rel_path);
+    let content = match tokio::fs::read_to_string(&path).await {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+        Err(e) => return Err(e).with_context(|| format!("Could not read {}", rel_path)),
+    };
+    let mut yaml: YamlValue =
+        serde_yaml::from_str(&content).with_context(|| format!("Could not parse {}", rel_path))?;
+    for (key, value) in settings {
+        set_yaml_path(&mut yaml, key, value.clone())?;
+    }
+    crate::utils::write_atomic(&path, serde_yaml::to_string(&yaml)?, true).await?;
+    Ok(true)
+}
+
+/// Tells Floodgate on a proxy to forward Bedrock player data to the backends.
+pub fn floodgate_proxy_settings() -> Vec<(&'static str, YamlValue)> {
+    vec![("send-floodgate-data", YamlValue::from(true))]
+}
+
+/// Copies the proxy's Floodgate key to a backend. Returns false when the proxy hasn't
+/// generated a key yet; true when the backend's key was written or already matched.
+pub async fn sync_floodgate_key(
+    proxy_path: &Path,
+    proxy: GeyserPlatform,
+    backend_path: &Path,
+    backend: GeyserPlatform,
+) -> Result<bool> {
+    let key = match tokio::fs::read(proxy_path.join(proxy.floodgate_key())).await {
+        Ok(key) => key,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+        Err(e) => return Err(e).context("Could not read the proxy's Floodgate key"),
+    };
+    let dest = backend_path.join(backend.floodgate_key());
+    if tokio::fs::read(&dest).await.ok().as_deref() == Some(key.as_slice()) {
+        return Ok(true);
+    }
+    if let Some(parent) = dest.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    crate::utils::write_atomic(&dest, key, false).await?;
+    Ok(true)
+}
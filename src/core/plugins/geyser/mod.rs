@@ -0,0 +1,115 @@
+mod config;
+mod platform;
+
+pub use config::{
+    configure_yaml, floodgate_proxy_settings, geyser_settings, read_bedrock_port, sync_floodgate_key,
+};
+pub use platform::{CrossplayComponent, GeyserPlatform};
+
+use crate::instance::InstanceMetadata;
+use anyhow::{Result, anyhow};
+use serde::Serialize;
+use std::net::UdpSocket;
+use uuid::Uuid;
+
+/// Port Bedrock clients connect to unless told otherwise.
+pub const DEFAULT_BEDROCK_PORT: u16 = 19132;
+
+/// An instance that gets Geyser and/or Floodgate.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CrossplayTarget {
+    pub instance_id: Uuid,
+    pub instance_name: String,
+    pub platform: GeyserPlatform,
+    pub components: Vec<CrossplayComponent>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CrossplayPlan {
+    /// Runs Geyser and receives the Bedrock listener.
+    pub host: CrossplayTarget,
+    /// Backends that only need Floodgate and the proxy's key.
+    pub backends: Vec<CrossplayTarget>,
+    pub bedrock_port: u16,
+    pub notes: Vec<String>,
+}
+
+fn target(
+    instance: &InstanceMetadata,
+    platform: GeyserPlatform,
+    components: Vec<CrossplayComponent>,
+) -> CrossplayTarget {
+    CrossplayTarget {
+        instance_id: instance.id,
+        instance_name: instance.name.clone(),
+        platform,
+        components,
+    }
+}
+
+/// Decides where Geyser and Floodgate go. `proxy` is the proxy `instance` sits behind, or
+/// `instance` itself when it is a proxy, along with that proxy's local backends.
+///
+/// On a network Geyser runs once on the proxy; every backend runs Floodgate with the
+/// proxy's key so Bedrock players keep their identity when switching servers.
+pub fn plan_crossplay(
+    instance: &InstanceMetadata,
+    proxy: Option<(&InstanceMetadata, &[InstanceMetadata])>,
+    bedrock_port: u16,
+) -> Result<CrossplayPlan> {
+    let both = vec![CrossplayComponent::Geyser, CrossplayComponent::Floodgate];
+    let own = GeyserPlatform::for_loader(instance.mod_loader.as_deref()).ok_or_else(|| {
+        anyhow!("Geyser needs a Paper/Spigot, Fabric or NeoForge server, or a Velocity/BungeeCord proxy")
+    })?;
+    let mut notes = Vec::new();
+
+    let proxy_platform = proxy.and_then(|(p, _)| GeyserPlatform::for_loader(p.mod_loader.as_deref()));
+    let (host, backends) = match (proxy, proxy_platform) {
+        (Some((proxy, backends)), Some(platform)) if platform.is_proxy() => {
+            if proxy.id != instance.id {
+                notes.push(format!(
+                    "{} is behind the proxy {}; Geyser goes on the proxy so Bedrock players can reach every backend",
+                    instance.name, proxy.name
+                ));
+            }
+            let mut targets = Vec::new();
+            for backend in backends {
+                match GeyserPlatform::for_loader(backend.mod_loader.as_deref()) {
+                    Some(p) if !p.is_proxy() => targets.push(target(backend, p, vec![CrossplayComponent::Floodgate])),
+                    _ => notes.push(format!("{} can't run Floodgate and was skipped", backend.name)),
+                }
+            }
+            (target(proxy, platform, both), targets)
+        }
+        _ => (target(instance, own, both), vec![]),
+    };
+
+    Ok(CrossplayPlan {
+        host,
+        backends,
+        bedrock_port,
+        notes,
+    })
+}
+
+/// Whether nothing else holds `port` for UDP, which is what the Bedrock listener binds.
+pub fn udp_port_free(port: u16) -> bool {
+    UdpSocket::bind(("0.0.0.0", port)).is_ok()
+}
+
+/// Names of other instances listening for Bedrock on `port`: Bedrock servers on that
+/// port and instances whose Geyser config uses it.
+pub fn bedrock_port_users(port: u16, host_id: Uuid, instances: &[InstanceMetadata]) -> Vec<String> {
+    instances
+        .iter()
+        .filter(|i| i.id != host_id)
+        .filter(|i| {
+            let loader = i.mod_loader.as_deref().unwrap_or_default();
+            if loader.eq_ignore_ascii_case("bedrock") {
+                return i.settings.port == port;
+            }
+            GeyserPlatform::for_loader(Some(loader)).and_then(|p| read_bedrock_port(&i.path, p)) == Some(port)
+        })
+        .map(|i| i.name.clone())
+        .collect()
+}
@@ -0,0 +1,94 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum CrossplayComponent {
+    /// Translates Bedrock connections to Java.
+    Geyser,
+    /// Lets Bedrock players join without a Java account.
+    Floodgate,
+}
+
+impl CrossplayComponent {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Geyser => "Geyser",
+            Self::Floodgate => "Floodgate",
+        }
+    }
+
+    pub fn modrinth_slug(&self) -> &'static str {
+        match self {
+            Self::Geyser => "geyser",
+            Self::Floodgate => "floodgate",
+        }
+    }
+}
+
+/// The Geyser build a server needs, which also decides where its files live.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GeyserPlatform {
+    Spigot,
+    Velocity,
+    BungeeCord,
+    Fabric,
+    NeoForge,
+}
+
+impl GeyserPlatform {
+    pub fn for_loader(loader: Option<&str>) -> Option<Self> {
+        match loader.map(|l| l.to_lowercase()).as_deref() {
+            Some("paper" | "purpur" | "pufferfish" | "folia" | "spigot" | "bukkit") => Some(Self::Spigot),
+            Some("velocity") => Some(Self::Velocity),
+            Some("bungeecord") => Some(Self::BungeeCord),
+            Some("fabric") => Some(Self::Fabric),
+            Some("neoforge") => Some(Self::NeoForge),
+            _ => None,
+        }
+    }
+
+    /// Modrinth loader the jars are picked for.
+    pub fn modrinth_loader(&self) -> &'static str {
+        match self {
+            Self::Spigot => "paper",
+            Self::Velocity => "velocity",
+            Self::BungeeCord => "bungeecord",
+            Self::Fabric => "fabric",
+            Self::NeoForge => "neoforge",
+        }
+    }
+
+    /// Installed into `mods/` rather than `plugins/`.
+    pub fn is_mod(&self) -> bool {
+        matches!(self, Self::Fabric | Self::NeoForge)
+    }
+
+    pub fn is_proxy(&self) -> bool {
+        matches!(self, Self::Velocity | Self::BungeeCord)
+    }
+
+    fn data_root(&self) -> &'static str {
+        if self.is_mod() { "config" } else { "plugins" }
+    }
+
+    /// Relative to the instance root.
+    pub fn geyser_config(&self) -> String {
+        let folder = match self {
+            Self::Spigot => "Geyser-Spigot",
+            Self::Velocity => "Geyser-Velocity",
+            Self::BungeeCord => "Geyser-BungeeCord",
+            Self::Fabric => "Geyser-Fabric",
+            Self::NeoForge => "Geyser-NeoForge",
+        };
+        format!("{}/{}/config.yml", self.data_root(), folder)
+    }
+
+    pub fn floodgate_config(&self) -> String {
+        format!("{}/floodgate/config.yml", self.data_root())
+    }
+
+    /// The key Floodgate signs player data with. A proxy and its backends must share it.
+    pub fn floodgate_key(&self) -> String {
+        format!("{}/floodgate/key.pem", self.data_root())
+    }
+}
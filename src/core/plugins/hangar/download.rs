@@ -40,6 +40,7 @@ impl HangarClient {
 
         download_with_resumption(
             &self.client,
+            self.cache.download_limits(),
             DownloadOptions {
                 url: &download_url,
                 target_path: &target_path,
@@ -6,8 +6,14 @@ use super::types::*;
 use super::modrinth::ModrinthClient;
 use super::spiget::SpigetClient;
 use super::hangar::HangarClient;
-use super::metadata::PluginCache;
+use super::curseforge::CurseForgeClient;
+use super::metadata::{PluginCache, extract_metadata_sync};
+use crate::artifacts::ArtifactStore;
 use crate::cache::CacheManager;
+use crate::content_report::ContentKind;
+use crate::version_history::archive_version;
+use tracing::warn;
+use crate::utils::{BulkProgress, BulkReport, run_bulk};
 
 /// Installs a plugin from a provider.
 pub async fn install_plugin(
@@ -64,6 +70,18 @@ pub async fn install_plugin(
             let fname = client.download_version(version, &plugins_dir).await?;
             (fname, Some(version.id.clone()))
         }
+        PluginProvider::CurseForge => {
+            let client = CurseForgeClient::new(cache);
+            let versions = client.get_versions(project_id, game_version).await?;
+            let version = if let Some(vid) = version_id {
+                versions.iter().find(|v| v.id == vid)
+                    .ok_or_else(|| anyhow::anyhow!("Version not found: {}", vid))?
+            } else {
+                versions.first().ok_or_else(|| anyhow::anyhow!("No versions found for project"))?
+            };
+            let fname = client.download_version(version, &plugins_dir).await?;
+            (fname, Some(version.id.clone()))
+        }
     };
 
     // Update source cache
@@ -88,25 +106,41 @@ pub async fn install_plugin(
     Ok(filename)
 }
 
-/// Uninstalls a plugin by removing its file and optionally its configuration folder.
+/// Uninstalls a plugin by removing its file and optionally its data folder.
 pub async fn uninstall_plugin(instance_path: impl AsRef<Path>, filename: String, delete_config: bool) -> Result<()> {
+    // Path traversal protection
+    if filename.contains("..") || filename.contains('/') || filename.contains('\\') {
+        return Err(anyhow::anyhow!("Invalid filename: {}", filename));
+    }
+
     let plugins_dir = instance_path.as_ref().join("plugins");
     let plugin_file = plugins_dir.join(&filename);
 
+    // Plugins keep their data in plugins/<Name>/, where Name comes from plugin.yml;
+    // read it before the jar is gone, falling back to the jar's file name.
+    let mut folder_names = Vec::new();
+    if delete_config && plugin_file.exists() {
+        let path = plugin_file.clone();
+        match tokio::task::spawn_blocking(move || extract_metadata_sync(&path)).await {
+            Ok(Ok(plugin)) if !plugin.name.contains("..") && !plugin.name.contains(['/', '\\']) => {
+                folder_names.push(plugin.name);
+            }
+            _ => {}
+        }
+    }
+    let stem = filename
+        .strip_suffix(".jar.disabled")
+        .or_else(|| filename.strip_suffix(".jar"))
+        .unwrap_or(&filename);
+    folder_names.push(stem.to_string());
+
     if plugin_file.exists() {
         fs::remove_file(plugin_file).await.context("Failed to delete plugin file")?;
     }
 
     if delete_config {
-        // Try to find the config directory. Usually it matches the plugin name.
-        let plugin_name = if filename.ends_with(".jar.disabled") {
-            filename.strip_suffix(".jar.disabled").unwrap()
-        } else {
-            filename.strip_suffix(".jar").unwrap_or(&filename)
-        };
-
-        let config_dir = plugins_dir.join(plugin_name);
-        if config_dir.is_dir() {
+        // Only remove the first folder that exists so a fallback never touches another plugin's data
+        if let Some(config_dir) = folder_names.iter().map(|n| plugins_dir.join(n)).find(|p| p.is_dir()) {
             fs::remove_dir_all(config_dir).await.context("Failed to delete plugin config directory")?;
         }
     }
@@ -114,19 +148,18 @@ pub async fn uninstall_plugin(instance_path: impl AsRef<Path>, filename: String,
     Ok(())
 }
 
-/// Uninstalls multiple plugins at once.
+/// Uninstalls multiple plugins concurrently, reporting the outcome of each file.
 pub async fn bulk_uninstall_plugins(
     instance_path: impl AsRef<Path>,
     filenames: Vec<String>,
     delete_config: bool,
-) -> Result<()> {
-    for filename in filenames {
-        let _ = uninstall_plugin(&instance_path, filename, delete_config).await;
-    }
-    Ok(())
+    on_progress: impl Fn(BulkProgress),
+) -> BulkReport {
+    run_bulk(filenames, |filename| uninstall_plugin(&instance_path, filename, delete_config), on_progress).await
 }
 
-/// Updates a plugin by downloading the new version and replacing the old one.
+/// Updates a plugin by downloading the new version and replacing the old one. The
+/// replaced jar is kept in `history` so the update can be rolled back.
 pub async fn update_plugin(
     instance_path: impl AsRef<Path>,
     filename: String,
@@ -134,9 +167,12 @@ pub async fn update_plugin(
     provider: PluginProvider,
     latest_version_id: String,
     cache: Arc<CacheManager>,
+    history: &ArtifactStore,
 ) -> Result<()> {
     let plugins_dir = instance_path.as_ref().join("plugins");
     let old_path = plugins_dir.join(&filename);
+    let previous = super::list_installed_plugins(&instance_path).await.ok()
+        .and_then(|plugins| plugins.into_iter().find(|p| p.filename == filename));
 
     // 1. Create backup
     let backup_path = plugins_dir.join(format!("{}.bak", filename));
@@ -165,7 +201,15 @@ pub async fn update_plugin(
                 let _ = fs::remove_file(old_path).await;
             }
 
-            // 5. Delete backup on success
+            // 5. Keep the old jar for rollbacks, then delete the backup
+            if backup_path.exists() {
+                let (version, version_id) = previous
+                    .map(|p| (p.version, p.source.and_then(|s| s.current_version_id)))
+                    .unwrap_or_default();
+                if let Err(e) = archive_version(history, &instance_path, ContentKind::Plugin, &project_id, &backup_path, &filename, version, version_id).await {
+                    warn!("Failed to keep previous version of {}: {}", filename, e);
+                }
+            }
             let _ = fs::remove_file(backup_path).await;
             Ok(())
         }
@@ -1,10 +1,14 @@
 use crate::cache::CacheManager;
+use crate::plugins::curseforge::CurseForgeClient;
 use crate::plugins::hangar::HangarClient;
 use crate::plugins::modrinth::ModrinthClient;
 use crate::plugins::spiget::SpigetClient;
-use crate::plugins::types::{PluginDependencies, PluginProvider, Project, SearchOptions};
+use crate::plugins::types::{
+    PluginDependencies, PluginProvider, Project, ResolvedDependency, SearchOptions,
+};
 use anyhow::Result;
 use std::sync::Arc;
+use tracing::debug;
 
 /// Searches for plugins across multiple providers.
 pub async fn search_plugins(
@@ -27,6 +31,10 @@ pub async fn search_plugins(
             let client = HangarClient::new(cache);
             results.extend(client.search(options).await?);
         }
+        Some(PluginProvider::CurseForge) => {
+            let client = CurseForgeClient::new(cache);
+            results.extend(client.search(options).await?);
+        }
         None => {
             // Search all providers
             let modrinth = ModrinthClient::new(Arc::clone(&cache));
@@ -70,13 +78,26 @@ pub async fn get_plugin_dependencies(
                 .await
         }
         PluginProvider::Spiget => {
-            let client = SpigetClient::new(cache);
-            client.get_dependencies(project_id).await
+            let client = SpigetClient::new(Arc::clone(&cache));
+            let mut deps = client.get_dependencies(project_id).await?;
+            for dep in client.get_external_dependencies(project_id).await? {
+                if let Some(project) = find_plugin_by_name(&dep.name, game_version, loader, &cache).await {
+                    deps.push(ResolvedDependency {
+                        project,
+                        dependency_type: dep.dependency_type,
+                    });
+                }
+            }
+            Ok(deps)
         }
         PluginProvider::Hangar => {
             let client = HangarClient::new(cache);
             client.get_dependencies(project_id, loader).await
         }
+        PluginProvider::CurseForge => {
+            let client = CurseForgeClient::new(cache);
+            client.get_dependencies(project_id, game_version).await
+        }
     }?;
 
     let mut mandatory = Vec::new();
@@ -95,3 +116,37 @@ pub async fn get_plugin_dependencies(
         optional,
     })
 }
+
+/// Looks up a plugin by its exact name on Modrinth, then Hangar. A provider that can't be
+/// reached counts as not having it.
+///
+/// Used for Spigot dependencies hosted outside SpigotMC, which are only known by name.
+async fn find_plugin_by_name(
+    name: &str,
+    game_version: Option<&str>,
+    loader: Option<&str>,
+    cache: &Arc<CacheManager>,
+) -> Option<Project> {
+    let options = SearchOptions {
+        query: name.to_string(),
+        facets: None,
+        sort: None,
+        offset: None,
+        limit: Some(10),
+        game_version: game_version.map(str::to_string),
+        loader: loader.map(str::to_string),
+    };
+    let matches_name = |p: &Project| p.title.eq_ignore_ascii_case(name) || p.slug.eq_ignore_ascii_case(name);
+
+    let modrinth = ModrinthClient::new(Arc::clone(cache));
+    match modrinth.search(&options).await {
+        Ok(found) => {
+            if let Some(project) = found.into_iter().find(matches_name) {
+                return Some(project);
+            }
+        }
+        Err(e) => debug!("Modrinth lookup of {} failed, trying Hangar: {}", name, e),
+    }
+    let hangar = HangarClient::new(Arc::clone(cache));
+    hangar.search(&options).await.ok()?.into_iter().find(matches_name)
+}
@@ -1,6 +1,7 @@
 use std::path::Path;
 use tokio::fs;
 use anyhow::{Result, Context};
+use crate::utils::{BulkProgress, BulkReport, run_bulk};
 
 /// Toggles a plugin's enabled state by renaming the file.
 pub async fn toggle_plugin(instance_path: impl AsRef<Path>, filename: String, enable: bool) -> Result<()> {
@@ -29,14 +30,12 @@ pub async fn toggle_plugin(instance_path: impl AsRef<Path>, filename: String, en
     Ok(())
 }
 
-/// Toggles multiple plugins at once.
+/// Toggles multiple plugins concurrently, reporting the outcome of each file.
 pub async fn bulk_toggle_plugins(
     instance_path: impl AsRef<Path>,
     filenames: Vec<String>,
     enable: bool,
-) -> Result<()> {
-    for filename in filenames {
-        let _ = toggle_plugin(&instance_path, filename, enable).await;
-    }
-    Ok(())
+    on_progress: impl Fn(BulkProgress),
+) -> BulkReport {
+    run_bulk(filenames, |filename| toggle_plugin(&instance_path, filename, enable), on_progress).await
 }
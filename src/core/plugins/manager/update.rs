@@ -5,7 +5,10 @@ use crate::plugins::types::{PluginUpdate, PluginProvider};
 use crate::plugins::modrinth::ModrinthClient;
 use crate::plugins::spiget::SpigetClient;
 use crate::plugins::hangar::HangarClient;
+use crate::plugins::curseforge::CurseForgeClient;
 use crate::cache::CacheManager;
+use crate::content_report::record_update_check;
+use tracing::warn;
 use super::list::list_installed_plugins;
 
 /// Checks for updates for all installed plugins that have source information.
@@ -17,6 +20,7 @@ pub async fn check_for_updates(
 ) -> Result<Vec<PluginUpdate>> {
     let installed = list_installed_plugins(&instance_path).await?;
     let mut updates = Vec::new();
+    let mut checked = Vec::new();
 
     for plugin in installed {
         if let Some(source) = plugin.source {
@@ -24,6 +28,7 @@ pub async fn check_for_updates(
                 PluginProvider::Modrinth => {
                     let client = ModrinthClient::new(Arc::clone(&cache));
                     if let Ok(versions) = client.get_versions(&source.project_id, game_version, loader).await {
+                        checked.push(plugin.filename.clone());
                         if let Some(latest) = versions.first() {
                             if Some(latest.id.clone()) != source.current_version_id {
                                 updates.push(PluginUpdate {
@@ -41,6 +46,7 @@ pub async fn check_for_updates(
                 PluginProvider::Spiget => {
                     let client = SpigetClient::new(Arc::clone(&cache));
                     if let Ok((latest_id, latest_name)) = client.get_latest_version(&source.project_id).await {
+                        checked.push(plugin.filename.clone());
                         if Some(latest_id.clone()) != source.current_version_id {
                             updates.push(PluginUpdate {
                                 filename: plugin.filename.clone(),
@@ -53,9 +59,31 @@ pub async fn check_for_updates(
                         }
                     }
                 }
+                PluginProvider::CurseForge => {
+                    let client = CurseForgeClient::new(Arc::clone(&cache));
+                    let Ok(versions) = client.get_versions(&source.project_id, game_version).await else {
+                        continue;
+                    };
+                    checked.push(plugin.filename.clone());
+                    let latest = versions
+                        .into_iter()
+                        .next()
+                        .filter(|latest| Some(&latest.id) != source.current_version_id.as_ref());
+                    if let Some(latest) = latest {
+                        updates.push(PluginUpdate {
+                            filename: plugin.filename.clone(),
+                            current_version: plugin.version.clone(),
+                            latest_version: latest.version_number,
+                            latest_version_id: latest.id,
+                            project_id: source.project_id.clone(),
+                            provider: source.provider,
+                        });
+                    }
+                }
                 PluginProvider::Hangar => {
                     let client = HangarClient::new(Arc::clone(&cache));
                     if let Ok(versions) = client.get_versions(&source.project_id, game_version, loader).await {
+                        checked.push(plugin.filename.clone());
                         if let Some(latest) = versions.first() {
                             if Some(latest.id.clone()) != source.current_version_id {
                                 updates.push(PluginUpdate {
@@ -74,5 +102,10 @@ pub async fn check_for_updates(
         }
     }
 
+    let found = updates.iter().map(|u| (u.filename.clone(), u.latest_version.clone()));
+    if let Err(e) = record_update_check(&instance_path.as_ref().join("plugins"), checked, found).await {
+        warn!("Failed to record plugin update check: {}", e);
+    }
+
     Ok(updates)
 }
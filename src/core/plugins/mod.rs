@@ -2,14 +2,22 @@ pub mod types;
 pub mod modrinth;
 pub mod spiget;
 pub mod hangar;
+pub mod curseforge;
 pub mod metadata;
 pub mod manager;
 pub mod installer;
+pub mod via;
+pub mod geyser;
 
 pub use types::*;
 pub use modrinth::ModrinthClient;
 pub use spiget::SpigetClient;
 pub use hangar::HangarClient;
+pub use curseforge::CurseForgeClient;
 pub use metadata::extract_metadata_sync;
 pub use manager::*;
 pub use installer::*;
+pub use via::{ViaPlan, ViaPlugin, ViaTarget, plan_via_setup};
+pub use geyser::{
+    CrossplayComponent, CrossplayPlan, CrossplayTarget, DEFAULT_BEDROCK_PORT, GeyserPlatform, plan_crossplay,
+};
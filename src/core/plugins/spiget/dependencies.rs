@@ -0,0 +1,79 @@
+use super::SpigetClient;
+use crate::plugins::types::ResolvedDependency;
+use anyhow::Result;
+use serde_json::Value;
+
+/// A dependency hosted outside SpigotMC, known only by its name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExternalDependency {
+    pub name: String,
+    /// `"required"`, or `"optional"` for soft dependencies.
+    pub dependency_type: String,
+}
+
+/// Soft dependencies are flagged with `soft`, or a `type` of `soft`/`softdepend`.
+fn dependency_type(dep: &Value) -> String {
+    let soft = dep["soft"].as_bool().unwrap_or(false)
+        || dep["type"]
+            .as_str()
+            .is_some_and(|t| t.eq_ignore_ascii_case("soft") || t.eq_ignore_ascii_case("softdepend"));
+    if soft { "optional" } else { "required" }.to_string()
+}
+
+impl SpigetClient {
+    /// Resolves dependencies that are themselves Spigot resources.
+    ///
+    /// Dependencies hosted elsewhere only carry a name; see [`Self::get_external_dependencies`].
+    pub async fn get_dependencies(&self, resource_id: &str) -> Result<Vec<ResolvedDependency>> {
+        let mut resolved_deps = Vec::new();
+        for dep in self.get_dependency_entries(resource_id).await? {
+            let Some(id) = dep["id"].as_u64().filter(|id| *id > 0) else {
+                continue;
+            };
+            if let Ok(project) = self.get_project(&id.to_string()).await {
+                resolved_deps.push(ResolvedDependency {
+                    project,
+                    dependency_type: dependency_type(&dep),
+                });
+            }
+        }
+        Ok(resolved_deps)
+    }
+
+    /// Dependencies that are not Spigot resources (e.g. hosted on another site).
+    pub async fn get_external_dependencies(&self, resource_id: &str) -> Result<Vec<ExternalDependency>> {
+        let entries = self.get_dependency_entries(resource_id).await?;
+        Ok(entries
+            .iter()
+            .filter(|dep| dep["id"].as_u64().unwrap_or(0) == 0)
+            .filter_map(|dep| {
+                let name = dep["name"].as_str()?.trim();
+                (!name.is_empty()).then(|| ExternalDependency {
+                    name: name.to_string(),
+                    dependency_type: dependency_type(dep),
+                })
+            })
+            .collect())
+    }
+
+    async fn get_dependency_entries(&self, resource_id: &str) -> Result<Vec<Value>> {
+        let cache_key = format!("spiget_dependency_entries_{}", resource_id);
+        let client = self.client.clone();
+        let url = format!("{}/resources/{}/dependencies", self.base_url, resource_id);
+
+        self.cache
+            .fetch_with_cache(cache_key, std::time::Duration::from_secs(3600), move || {
+                let client = client.clone();
+                let url = url.clone();
+                async move {
+                    let response = client.get(&url).send().await?;
+                    if response.status() == reqwest::StatusCode::NOT_FOUND {
+                        return Ok(vec![]);
+                    }
+                    let response = response.error_for_status()?;
+                    Ok(response.json::<Vec<Value>>().await?)
+                }
+            })
+            .await
+    }
+}
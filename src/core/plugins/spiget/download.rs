@@ -4,6 +4,7 @@ use reqwest::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
 use std::path::Path;
 use tokio::fs;
 
+use crate::utils::content_policy::is_page_content_type;
 use crate::utils::{DownloadOptions, download_with_resumption};
 
 impl SpigetClient {
@@ -60,7 +61,7 @@ impl SpigetClient {
             .and_then(|v| v.to_str().ok())
             .unwrap_or("");
 
-        if content_type.contains("text/html") || content_type.contains("application/json") {
+        if is_page_content_type(content_type) {
             return Err(anyhow::anyhow!(
                 "Spiget returned an unexpected content type for '{}': {}. \
                 This usually means the resource is blocked by Cloudflare, requires a manual download, or redirected to an external site. \
@@ -104,6 +105,7 @@ impl SpigetClient {
 
         download_with_resumption(
             &self.client,
+            self.cache.download_limits(),
             DownloadOptions {
                 url: &download_url,
                 target_path: &target_path,
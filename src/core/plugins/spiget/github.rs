@@ -38,15 +38,15 @@ impl SpigetClient {
             ),
         };
 
-        let response = self
-            .client
-            .get(&api_url)
-            .header(
-                USER_AGENT,
-                concat!("mc-server-wrapper/", env!("CARGO_PKG_VERSION")),
-            )
-            .send()
-            .await?;
+        let mut request = self.client.get(&api_url).header(
+            USER_AGENT,
+            concat!("mc-server-wrapper/", env!("CARGO_PKG_VERSION")),
+        );
+        // Authenticated requests get a much higher GitHub rate limit
+        if let Some(token) = &self.github_token {
+            request = request.bearer_auth(token);
+        }
+        let response = request.send().await?;
 
         if !response.status().is_success() {
             return Err(anyhow::anyhow!(
@@ -148,6 +148,7 @@ impl SpigetClient {
 
         download_with_resumption(
             &self.client,
+            self.cache.download_limits(),
             DownloadOptions {
                 url: download_url,
                 target_path: &target_path,
@@ -3,12 +3,15 @@ use crate::cache::CacheManager;
 
 pub mod search;
 pub mod download;
+pub mod dependencies;
 pub mod github;
 
 pub struct SpigetClient {
     pub(crate) client: reqwest::Client,
     pub(crate) base_url: String,
     pub(crate) cache: Arc<CacheManager>,
+    /// Sent with GitHub API requests for resources hosted on GitHub.
+    pub(crate) github_token: Option<String>,
 }
 
 impl SpigetClient {
@@ -20,6 +23,7 @@ impl SpigetClient {
         Self {
             client: cache.get_client().clone(),
             base_url,
+            github_token: cache.credentials().github_token(),
             cache,
         }
     }
@@ -1,7 +1,6 @@
 use super::SpigetClient;
-use crate::plugins::types::{PluginProvider, Project, ResolvedDependency, SearchOptions};
+use crate::plugins::types::{PluginProvider, Project, SearchOptions};
 use anyhow::Result;
-use std::sync::Arc;
 
 impl SpigetClient {
     pub async fn search(&self, options: &SearchOptions) -> Result<Vec<Project>> {
@@ -118,54 +117,6 @@ impl SpigetClient {
             .await
     }
 
-    pub async fn get_dependencies(&self, resource_id: &str) -> Result<Vec<ResolvedDependency>> {
-        let cache_key = format!("spiget_dependencies_{}", resource_id);
-        let client = self.client.clone();
-        let base_url = self.base_url.clone();
-        let resource_id = resource_id.to_string();
-        let cache = Arc::clone(&self.cache);
-
-        self.cache
-            .fetch_with_cache(cache_key, std::time::Duration::from_secs(3600), move || {
-                let client = client.clone();
-                let base_url = base_url.clone();
-                let resource_id = resource_id.clone();
-                let cache = Arc::clone(&cache);
-                async move {
-                    let url = format!("{}/resources/{}/dependencies", base_url, resource_id);
-                    let response = client.get(&url).send().await?;
-
-                    if response.status() == reqwest::StatusCode::NOT_FOUND {
-                        return Ok(vec![]);
-                    }
-
-                    let response = response.error_for_status()?;
-                    let json = response.json::<Vec<serde_json::Value>>().await?;
-
-                    let mut resolved_deps = Vec::new();
-                    for dep in json {
-                        if let Some(id) = dep["id"].as_u64() {
-                            // We'll use a temporary client to avoid recursive fetch issues if any,
-                            // though fetch_with_cache handles concurrency.
-                            let temp_client = super::SpigetClient::with_base_url(
-                                base_url.clone(),
-                                Arc::clone(&cache),
-                            );
-                            if let Ok(project) = temp_client.get_project(&id.to_string()).await {
-                                resolved_deps.push(ResolvedDependency {
-                                    project,
-                                    dependency_type: "required".to_string(),
-                                });
-                            }
-                        }
-                    }
-
-                    Ok(resolved_deps)
-                }
-            })
-            .await
-    }
-
     pub async fn get_project(&self, id: &str) -> Result<Project> {
         let cache_key = format!("spiget_project_{}", id);
         let client = self.client.clone();
@@ -5,6 +5,7 @@ pub enum PluginProvider {
     Modrinth,
     Spiget,
     Hangar,
+    CurseForge,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
@@ -0,0 +1,105 @@
+use crate::instance::InstanceMetadata;
+use anyhow::{Result, anyhow};
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Server software ViaVersion/ViaBackwards run on as backends.
+const BACKEND_LOADERS: [&str; 6] = ["paper", "purpur", "pufferfish", "folia", "spigot", "bukkit"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ViaPlugin {
+    /// Lets newer clients join older servers.
+    ViaVersion,
+    /// Lets older clients join newer servers; requires ViaVersion.
+    ViaBackwards,
+}
+
+impl ViaPlugin {
+    pub const ALL: [ViaPlugin; 2] = [ViaPlugin::ViaVersion, ViaPlugin::ViaBackwards];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::ViaVersion => "ViaVersion",
+            Self::ViaBackwards => "ViaBackwards",
+        }
+    }
+
+    pub fn modrinth_slug(&self) -> &'static str {
+        match self {
+            Self::ViaVersion => "viaversion",
+            Self::ViaBackwards => "viabackwards",
+        }
+    }
+}
+
+/// An instance that should carry the Via plugins.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ViaTarget {
+    pub instance_id: Uuid,
+    pub instance_name: String,
+    /// Modrinth loader the plugin jars are picked for.
+    pub loader: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ViaPlan {
+    pub targets: Vec<ViaTarget>,
+    pub notes: Vec<String>,
+}
+
+fn loader(instance: &InstanceMetadata) -> String {
+    instance.mod_loader.as_deref().unwrap_or_default().to_lowercase()
+}
+
+fn target(instance: &InstanceMetadata, loader: &str) -> ViaTarget {
+    ViaTarget {
+        instance_id: instance.id,
+        instance_name: instance.name.clone(),
+        loader: loader.to_string(),
+    }
+}
+
+/// Decides where the Via plugins go. `proxy` is the proxy `instance` sits behind, or
+/// `instance` itself when it is a proxy, along with that proxy's local backends.
+///
+/// Velocity proxies translate for the whole network, so backends are left alone.
+/// ViaVersion 5 dropped BungeeCord, so Bungee networks get the plugins on each backend.
+pub fn plan_via_setup(
+    instance: &InstanceMetadata,
+    proxy: Option<(&InstanceMetadata, &[InstanceMetadata])>,
+) -> Result<ViaPlan> {
+    let mut notes = Vec::new();
+    let own_loader = loader(instance);
+    let is_backend = BACKEND_LOADERS.contains(&own_loader.as_str());
+    if !is_backend && own_loader != "velocity" && own_loader != "bungeecord" {
+        return Err(anyhow!("ViaVersion needs a Paper/Spigot server or a Velocity proxy"));
+    }
+
+    let targets = match proxy {
+        Some((proxy, _)) if loader(proxy) == "velocity" => {
+            if proxy.id != instance.id {
+                notes.push(format!(
+                    "{} is behind the Velocity proxy {}; installing there covers every backend",
+                    instance.name, proxy.name
+                ));
+            }
+            vec![target(proxy, "velocity")]
+        }
+        Some((proxy, backends)) if loader(proxy) == "bungeecord" => {
+            notes.push("ViaVersion no longer runs on BungeeCord; installing on the backends instead".to_string());
+            let targets: Vec<ViaTarget> = backends
+                .iter()
+                .filter(|b| BACKEND_LOADERS.contains(&loader(b).as_str()))
+                .map(|b| target(b, "paper"))
+                .collect();
+            if targets.is_empty() {
+                return Err(anyhow!("{} has no Paper/Spigot backends to install ViaVersion on", proxy.name));
+            }
+            targets
+        }
+        _ if is_backend => vec![target(instance, "paper")],
+        _ => return Err(anyhow!("Could not read the backends of {}", instance.name)),
+    };
+
+    Ok(ViaPlan { targets, notes })
+}
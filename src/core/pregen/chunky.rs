@@ -0,0 +1,96 @@
+//! Driving Chunky from the console: which build a loader needs, the commands that
+//! start a run and its progress output.
+
+use regex::Regex;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use super::PregenRequest;
+
+/// Modrinth slug of the Chunky plugin/mod.
+pub const CHUNKY_SLUG: &str = "chunky";
+
+/// Where Chunky lives for a loader: Bukkit-family servers load it as a plugin, modded
+/// servers as a mod.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChunkyTarget {
+    Plugin,
+    Mod,
+}
+
+impl ChunkyTarget {
+    pub fn folder(self) -> &'static str {
+        match self {
+            ChunkyTarget::Plugin => "plugins",
+            ChunkyTarget::Mod => "mods",
+        }
+    }
+}
+
+pub fn chunky_target(loader: Option<&str>) -> Option<ChunkyTarget> {
+    match loader.map(|l| l.to_lowercase()).as_deref() {
+        Some("paper" | "purpur" | "folia" | "spigot" | "bukkit") => Some(ChunkyTarget::Plugin),
+        Some("fabric" | "quilt" | "forge" | "neoforge") => Some(ChunkyTarget::Mod),
+        _ => None,
+    }
+}
+
+/// An installed Chunky jar in the instance's plugins or mods folder.
+pub fn find_chunky_jar(instance_dir: &Path, target: ChunkyTarget) -> Option<PathBuf> {
+    let entries = std::fs::read_dir(instance_dir.join(target.folder())).ok()?;
+    entries.filter_map(|e| e.ok()).map(|e| e.path()).find(|path| {
+        let name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default()
+            .to_lowercase();
+        name.ends_with(".jar") && (name.starts_with("chunky-") || name.starts_with("chunky."))
+    })
+}
+
+/// Console commands that configure and start a Chunky run.
+pub fn chunky_start_commands(request: &PregenRequest) -> Vec<String> {
+    let mut commands = Vec::new();
+    if !request.world.trim().is_empty() {
+        commands.push(format!("chunky world {}", request.world.trim()));
+    }
+    commands.push(format!("chunky center {} {}", request.center_x, request.center_z));
+    commands.push(format!("chunky radius {}", request.radius));
+    commands.push("chunky start".to_string());
+    commands
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ChunkyProgress {
+    pub world: String,
+    pub chunks: u64,
+    pub percent: f64,
+    /// As printed by Chunky, e.g. `0:05:12`.
+    pub eta: Option<String>,
+    pub finished: bool,
+}
+
+/// Parses Chunky's periodic progress lines, e.g.
+/// `[Chunky] Task running for world. Processed: 1234 chunks (12.34%), ETA: 0:05:12, ...`
+/// and the final `Task finished for world. Processed: ...` line.
+pub fn parse_chunky_progress(line: &str) -> Option<ChunkyProgress> {
+    static PROGRESS_REGEX: OnceLock<Regex> = OnceLock::new();
+    static ANSI_REGEX: OnceLock<Regex> = OnceLock::new();
+    let re = PROGRESS_REGEX.get_or_init(|| {
+        Regex::new(r"Task (running|finished) for (\S+?)\. Processed: (\d+) chunks \(([\d.]+)%\)(?:, ETA: ([\d:]+))?")
+            .unwrap()
+    });
+    let ansi_re = ANSI_REGEX.get_or_init(|| Regex::new(r"\x1B(?:[@-Z\\-_]|\[[0-?]*[ -/]*[@-~])").unwrap());
+
+    let line = ansi_re.replace_all(line, "");
+    let caps = re.captures(&line)?;
+    Some(ChunkyProgress {
+        world: caps[2].to_string(),
+        chunks: caps[3].parse().ok()?,
+        percent: caps[4].parse().ok()?,
+        eta: caps.get(5).map(|m| m.as_str().to_string()),
+        finished: &caps[1] == "finished",
+    })
+}
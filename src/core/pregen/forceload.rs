@@ -0,0 +1,116 @@
+//! Vanilla pregeneration: force-loading the area in batches of chunks.
+
+use regex::Regex;
+use std::sync::OnceLock;
+
+use super::PregenRequest;
+
+/// A rectangle of chunks, by chunk coordinates (inclusive).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkBatch {
+    pub min_x: i32,
+    pub min_z: i32,
+    pub max_x: i32,
+    pub max_z: i32,
+}
+
+impl ChunkBatch {
+    pub fn contains(&self, chunk_x: i32, chunk_z: i32) -> bool {
+        (self.min_x..=self.max_x).contains(&chunk_x) && (self.min_z..=self.max_z).contains(&chunk_z)
+    }
+
+    pub fn chunk_count(&self) -> usize {
+        ((self.max_x - self.min_x + 1) * (self.max_z - self.min_z + 1)) as usize
+    }
+
+    fn block_range(&self) -> String {
+        format!(
+            "{} {} {} {}",
+            self.min_x * 16,
+            self.min_z * 16,
+            self.max_x * 16 + 15,
+            self.max_z * 16 + 15
+        )
+    }
+}
+
+/// Splits the square covering the request into 16x16 chunk tiles; vanilla refuses to
+/// force-load more than 256 chunks in one command.
+pub fn forceload_batches(request: &PregenRequest) -> Vec<ChunkBatch> {
+    let radius = request.radius as i32;
+    let min_x = (request.center_x - radius).div_euclid(16);
+    let max_x = (request.center_x + radius).div_euclid(16);
+    let min_z = (request.center_z - radius).div_euclid(16);
+    let max_z = (request.center_z + radius).div_euclid(16);
+
+    let mut batches = Vec::new();
+    for tile_z in (min_z..=max_z).step_by(16) {
+        for tile_x in (min_x..=max_x).step_by(16) {
+            batches.push(ChunkBatch {
+                min_x: tile_x,
+                min_z: tile_z,
+                max_x: (tile_x + 15).min(max_x),
+                max_z: (tile_z + 15).min(max_z),
+            });
+        }
+    }
+    batches
+}
+
+/// Dimension id for `execute in`, from a world folder or dimension name.
+pub fn forceload_dimension(world: &str) -> String {
+    let world = world.trim();
+    if world.contains(':') {
+        world.to_string()
+    } else if world.ends_with("_nether") {
+        "minecraft:the_nether".to_string()
+    } else if world.ends_with("_the_end") {
+        "minecraft:the_end".to_string()
+    } else {
+        "minecraft:overworld".to_string()
+    }
+}
+
+/// `forceload add`/`remove` for one batch. Removing releases every chunk in the batch,
+/// including ones that were force-loaded before the run; see [`parse_forceloaded_chunks`].
+pub fn forceload_command(world: &str, batch: &ChunkBatch, add: bool) -> String {
+    format!(
+        "execute in {} run forceload {} {}",
+        forceload_dimension(world),
+        if add { "add" } else { "remove" },
+        batch.block_range()
+    )
+}
+
+/// `forceload add` for a single chunk, by chunk coordinates.
+pub fn forceload_chunk_command(world: &str, chunk_x: i32, chunk_z: i32) -> String {
+    format!(
+        "execute in {} run forceload add {} {}",
+        forceload_dimension(world),
+        chunk_x * 16,
+        chunk_z * 16
+    )
+}
+
+/// Lists the chunks that are force-loaded in the world.
+pub fn forceload_query_command(world: &str) -> String {
+    format!("execute in {} run forceload query", forceload_dimension(world))
+}
+
+/// Chunk coordinates from the reply to [`forceload_query_command`], e.g.
+/// `2 force loaded chunks were found in minecraft:overworld at: [0, 0], [1, 0]`. Chunks
+/// listed here were force-loaded by someone else and are loaded again once their
+/// batch has been released.
+pub fn parse_forceloaded_chunks(lines: &[String]) -> Vec<(i32, i32)> {
+    static CHUNK_REGEX: OnceLock<Regex> = OnceLock::new();
+    let re = CHUNK_REGEX.get_or_init(|| Regex::new(r"\[(-?\d+), (-?\d+)\]").unwrap());
+    lines
+        .iter()
+        .filter(|line| line.contains("force loaded chunk"))
+        .flat_map(|line| {
+            re.captures_iter(line)
+                .filter_map(|caps| Some((caps[1].parse().ok()?, caps[2].parse().ok()?)))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
@@ -0,0 +1,99 @@
+//! World pregeneration, with Chunky or vanilla `forceload`.
+
+use crate::errors::AppError;
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+
+mod chunky;
+mod forceload;
+
+pub use chunky::{
+    CHUNKY_SLUG, ChunkyProgress, ChunkyTarget, chunky_start_commands, chunky_target, find_chunky_jar,
+    parse_chunky_progress,
+};
+pub use forceload::{
+    ChunkBatch, forceload_batches, forceload_chunk_command, forceload_command, forceload_dimension,
+    forceload_query_command,
+    parse_forceloaded_chunks,
+};
+
+/// Largest radius (in blocks) generated with vanilla `forceload`. Beyond this the
+/// number of chunks held loaded at once gets heavy, so Chunky is used instead.
+pub const FORCELOAD_MAX_RADIUS: u32 = 256;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PregenMethod {
+    /// Forceload for small radii, Chunky otherwise.
+    #[default]
+    Auto,
+    Chunky,
+    Forceload,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PregenRequest {
+    /// World to generate, e.g. `world_nether` or `minecraft:the_nether`. Empty means
+    /// the main overworld.
+    #[serde(default)]
+    pub world: String,
+    #[serde(default)]
+    pub center_x: i32,
+    #[serde(default)]
+    pub center_z: i32,
+    /// Radius in blocks around the center.
+    pub radius: u32,
+    #[serde(default)]
+    pub method: PregenMethod,
+}
+
+/// World folder or dimension names end up in console commands, so only the characters
+/// they can actually contain are accepted.
+pub fn validate_world_name(world: &str) -> Result<()> {
+    let world = world.trim();
+    let valid = world.len() <= 64
+        && world
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | ':' | '/'));
+    if !valid {
+        return Err(AppError::Validation(format!("Invalid world name: {}", world)).into());
+    }
+    Ok(())
+}
+
+/// Picks the method actually used for a request on a server with `loader`.
+pub fn resolve_method(request: &PregenRequest, loader: Option<&str>) -> Result<PregenMethod> {
+    validate_world_name(&request.world)?;
+    let lower = loader.map(|l| l.to_lowercase());
+    if matches!(lower.as_deref(), Some("bedrock" | "velocity" | "bungeecord")) {
+        return Err(anyhow!(
+            "Pregeneration isn't supported on {} servers",
+            loader.unwrap_or_default()
+        ));
+    }
+    if request.radius == 0 {
+        return Err(anyhow!("Radius must be greater than 0"));
+    }
+
+    let chunky = chunky_target(loader).is_some();
+    match request.method {
+        PregenMethod::Forceload if request.radius > FORCELOAD_MAX_RADIUS => Err(anyhow!(
+            "Forceload only handles radii up to {} blocks; use Chunky for larger areas",
+            FORCELOAD_MAX_RADIUS
+        )),
+        PregenMethod::Chunky if !chunky => Err(anyhow!(
+            "Chunky isn't available for {} servers",
+            loader.unwrap_or("vanilla")
+        )),
+        PregenMethod::Auto if request.radius <= FORCELOAD_MAX_RADIUS => Ok(PregenMethod::Forceload),
+        PregenMethod::Auto if !chunky => Err(anyhow!(
+            "A {} block radius needs Chunky, which isn't available for {} servers. Use a radius of at most {}.",
+            request.radius,
+            loader.unwrap_or("vanilla"),
+            FORCELOAD_MAX_RADIUS
+        )),
+        PregenMethod::Auto => Ok(PregenMethod::Chunky),
+        method => Ok(method),
+    }
+}
+
@@ -0,0 +1,154 @@
+//! Profiling servers with spark: picking the right build for a loader, the console
+//! commands that drive it and reading the uploaded result from the log. Sessions are
+//! kept per instance so earlier results stay reachable.
+
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use uuid::Uuid;
+
+mod store;
+
+/// Modrinth slug of the spark plugin/mod.
+pub const SPARK_SLUG: &str = "spark";
+
+/// Where spark lives for a loader: Bukkit-family servers and proxies load it as a
+/// plugin, modded servers as a mod.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SparkTarget {
+    Plugin,
+    Mod,
+}
+
+impl SparkTarget {
+    pub fn folder(self) -> &'static str {
+        match self {
+            SparkTarget::Plugin => "plugins",
+            SparkTarget::Mod => "mods",
+        }
+    }
+}
+
+pub fn spark_target(loader: Option<&str>) -> Option<SparkTarget> {
+    match loader.map(|l| l.to_lowercase()).as_deref() {
+        Some("paper" | "purpur" | "folia" | "spigot" | "bukkit" | "velocity" | "bungeecord") => {
+            Some(SparkTarget::Plugin)
+        }
+        Some("fabric" | "quilt" | "forge" | "neoforge") => Some(SparkTarget::Mod),
+        _ => None,
+    }
+}
+
+/// The root command spark registers; proxies get their own so it doesn't clash with
+/// the backend servers' spark.
+pub fn spark_command(loader: Option<&str>) -> &'static str {
+    match loader.map(|l| l.to_lowercase()).as_deref() {
+        Some("velocity") => "sparkv",
+        Some("bungeecord") => "sparkb",
+        _ => "spark",
+    }
+}
+
+/// An installed spark jar in the instance's plugins or mods folder.
+pub fn find_spark_jar(instance_dir: &Path, target: SparkTarget) -> Option<PathBuf> {
+    let entries = std::fs::read_dir(instance_dir.join(target.folder())).ok()?;
+    entries.filter_map(|e| e.ok()).map(|e| e.path()).find(|path| {
+        let name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default()
+            .to_lowercase();
+        name.ends_with(".jar") && (name.starts_with("spark-") || name.starts_with("spark."))
+    })
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProfilerRequest {
+    /// Stop and upload automatically after this many seconds; otherwise the profiler
+    /// runs until it is stopped.
+    #[serde(default)]
+    pub duration_secs: Option<u32>,
+    /// Sample every thread instead of only the server thread.
+    #[serde(default)]
+    pub all_threads: bool,
+}
+
+/// Console command that starts the profiler.
+pub fn profiler_start_command(root: &str, request: &ProfilerRequest) -> String {
+    let mut command = format!("{} profiler start", root);
+    if let Some(secs) = request.duration_secs.filter(|s| *s > 0) {
+        command.push_str(&format!(" --timeout {}", secs));
+    }
+    if request.all_threads {
+        command.push_str(" --thread *");
+    }
+    command
+}
+
+/// Console command that stops the profiler and uploads what it sampled.
+pub fn profiler_stop_command(root: &str) -> String {
+    format!("{} profiler stop", root)
+}
+
+/// Console command that stops the profiler without uploading.
+pub fn profiler_cancel_command(root: &str) -> String {
+    format!("{} profiler cancel", root)
+}
+
+fn strip_ansi(line: &str) -> std::borrow::Cow<'_, str> {
+    static ANSI_REGEX: OnceLock<Regex> = OnceLock::new();
+    ANSI_REGEX
+        .get_or_init(|| Regex::new(r"\x1B(?:[@-Z\\-_]|\[[0-?]*[ -/]*[@-~])").unwrap())
+        .replace_all(line, "")
+}
+
+/// The viewer link spark prints once a profile is uploaded, e.g.
+/// `[⚡] https://spark.lucko.me/AbCdEf1234`.
+pub fn parse_spark_url(line: &str) -> Option<String> {
+    static URL_REGEX: OnceLock<Regex> = OnceLock::new();
+    let re = URL_REGEX.get_or_init(|| Regex::new(r"https://spark\.lucko\.me/[A-Za-z0-9]+").unwrap());
+    re.find(&strip_ansi(line)).map(|m| m.as_str().to_string())
+}
+
+/// spark's reply when the profiler can't do what was asked, e.g. one is already running.
+pub fn parse_spark_failure(line: &str) -> Option<String> {
+    const FAILURES: [&str; 4] = [
+        "profiler is already running",
+        "There isn't an active profiler running",
+        "An error occurred whilst uploading",
+        "Unable to upload",
+    ];
+    let line = strip_ansi(line);
+    FAILURES
+        .iter()
+        .find(|failure| line.contains(*failure))
+        .map(|_| line.split("]: ").last().unwrap_or(&line).trim().to_string())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display, strum::EnumString)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum ProfilerSessionStatus {
+    Running,
+    Completed,
+    Cancelled,
+    Failed,
+}
+
+/// One profiler run of an instance.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfilerSession {
+    pub id: i64,
+    pub instance_id: Uuid,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    /// The requested duration; None when it was stopped by hand.
+    pub duration_secs: Option<u32>,
+    pub status: ProfilerSessionStatus,
+    /// The spark viewer link of a completed run.
+    pub url: Option<String>,
+    pub error: Option<String>,
+}
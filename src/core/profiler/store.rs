@@ -0,0 +1,101 @@
+use super::{ProfilerSession, ProfilerSessionStatus};
+use crate::instance::InstanceManager;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use sqlx::sqlite::SqliteRow;
+use std::str::FromStr;
+use uuid::Uuid;
+
+fn parse_time(value: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|d| d.with_timezone(&Utc))
+        .unwrap_or_else(|_| Utc::now())
+}
+
+fn session_from_row(row: &SqliteRow) -> ProfilerSession {
+    let instance_id: String = row.get("instance_id");
+    let started_at: String = row.get("started_at");
+    let finished_at: Option<String> = row.get("finished_at");
+    let status: String = row.get("status");
+    let duration_secs: Option<i64> = row.get("duration_secs");
+    ProfilerSession {
+        id: row.get("id"),
+        instance_id: Uuid::parse_str(&instance_id).unwrap_or_default(),
+        started_at: parse_time(&started_at),
+        finished_at: finished_at.as_deref().map(parse_time),
+        duration_secs: duration_secs.map(|d| d as u32),
+        status: ProfilerSessionStatus::from_str(&status).unwrap_or(ProfilerSessionStatus::Failed),
+        url: row.get("url"),
+        error: row.get("error"),
+    }
+}
+
+impl InstanceManager {
+    /// Records a profiler run as started and returns its id. Sessions of the instance
+    /// still marked running were cut off, e.g. by the app closing, and are marked failed.
+    pub async fn start_profiler_session(&self, instance_id: Uuid, duration_secs: Option<u32>) -> Result<i64> {
+        sqlx::query(
+            "UPDATE profiler_sessions SET status = ?, finished_at = ?, error = ?
+                WHERE instance_id = ? AND status = ?",
+        )
+        .bind(ProfilerSessionStatus::Failed.to_string())
+        .bind(Utc::now().to_rfc3339())
+        .bind("Interrupted")
+        .bind(instance_id.to_string())
+        .bind(ProfilerSessionStatus::Running.to_string())
+        .execute(self.db.pool())
+        .await?;
+
+        let id = sqlx::query(
+            "INSERT INTO profiler_sessions (instance_id, started_at, duration_secs, status) VALUES (?, ?, ?, ?)",
+        )
+        .bind(instance_id.to_string())
+        .bind(Utc::now().to_rfc3339())
+        .bind(duration_secs.map(|d| d as i64))
+        .bind(ProfilerSessionStatus::Running.to_string())
+        .execute(self.db.pool())
+        .await?
+        .last_insert_rowid();
+        Ok(id)
+    }
+
+    pub async fn finish_profiler_session(
+        &self,
+        id: i64,
+        status: ProfilerSessionStatus,
+        url: Option<String>,
+        error: Option<String>,
+    ) -> Result<()> {
+        sqlx::query("UPDATE profiler_sessions SET status = ?, finished_at = ?, url = ?, error = ? WHERE id = ?")
+            .bind(status.to_string())
+            .bind(Utc::now().to_rfc3339())
+            .bind(url)
+            .bind(error)
+            .bind(id)
+            .execute(self.db.pool())
+            .await?;
+        Ok(())
+    }
+
+    /// An instance's profiler runs, newest first.
+    pub async fn list_profiler_sessions(&self, instance_id: Uuid) -> Result<Vec<ProfilerSession>> {
+        let rows = sqlx::query(
+            "SELECT id, instance_id, started_at, finished_at, duration_secs, status, url, error
+                FROM profiler_sessions WHERE instance_id = ? ORDER BY id DESC",
+        )
+        .bind(instance_id.to_string())
+        .fetch_all(self.db.pool())
+        .await?;
+        Ok(rows.iter().map(session_from_row).collect())
+    }
+
+    pub async fn delete_profiler_session(&self, instance_id: Uuid, id: i64) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM profiler_sessions WHERE id = ? AND instance_id = ?")
+            .bind(id)
+            .bind(instance_id.to_string())
+            .execute(self.db.pool())
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
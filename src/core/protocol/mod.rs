@@ -0,0 +1,85 @@
+use serde::Serialize;
+
+pub mod packets;
+pub mod probe;
+pub mod proxy;
+pub mod query;
+pub mod status;
+
+pub use probe::{ServerProbe, probe_address, probe_instance};
+pub use proxy::{ProxyBackend, ProxyCompatibilityReport};
+pub use query::{QueryResponse, query_server};
+pub use status::{ServerStatusReport, parse_server_address, ping_server};
+
+/// Release versions and the network protocol number their clients speak.
+/// Versions sharing a number are interchangeable for joining a server.
+const PROTOCOLS: &[(&str, u32)] = &[
+    ("1.21.8", 772), ("1.21.7", 772), ("1.21.6", 771), ("1.21.5", 770),
+    ("1.21.4", 769), ("1.21.3", 768), ("1.21.2", 768), ("1.21.1", 767), ("1.21", 767),
+    ("1.20.6", 766), ("1.20.5", 766), ("1.20.4", 765), ("1.20.3", 765), ("1.20.2", 764),
+    ("1.20.1", 763), ("1.20", 763), ("1.19.4", 762), ("1.19.3", 761), ("1.19.2", 760),
+    ("1.19.1", 760), ("1.19", 759), ("1.18.2", 758), ("1.18.1", 757), ("1.18", 757),
+    ("1.17.1", 756), ("1.17", 755), ("1.16.5", 754), ("1.16.4", 754), ("1.16.3", 753),
+    ("1.16.2", 751), ("1.16.1", 736), ("1.16", 735), ("1.15.2", 578), ("1.15.1", 575),
+    ("1.15", 573), ("1.14.4", 498), ("1.14.3", 490), ("1.14.2", 485), ("1.14.1", 480),
+    ("1.14", 477), ("1.13.2", 404), ("1.13.1", 401), ("1.13", 393), ("1.12.2", 340),
+    ("1.12.1", 338), ("1.12", 335), ("1.11.2", 316), ("1.11.1", 316), ("1.11", 315),
+    ("1.10.2", 210), ("1.10.1", 210), ("1.10", 210), ("1.9.4", 110), ("1.9.3", 110),
+    ("1.9.2", 109), ("1.9.1", 108), ("1.9", 107), ("1.8.9", 47), ("1.8.8", 47),
+    ("1.8.7", 47), ("1.8.6", 47), ("1.8.5", 47), ("1.8.4", 47), ("1.8.3", 47),
+    ("1.8.2", 47), ("1.8.1", 47), ("1.8", 47), ("1.7.10", 5), ("1.7.9", 5),
+    ("1.7.8", 5), ("1.7.7", 5), ("1.7.6", 5), ("1.7.5", 4), ("1.7.4", 4), ("1.7.2", 4),
+];
+
+/// What a Minecraft version needs from players and from the host.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct VersionCompatibility {
+    pub version: String,
+    /// `None` for snapshots and versions newer than the built-in table.
+    pub protocol: Option<u32>,
+    /// Client versions that can join, oldest first.
+    pub compatible_clients: Vec<String>,
+    pub min_java: u32,
+}
+
+/// Splits `1.20.4` into `(20, 4)`; returns `None` for snapshots and other formats.
+fn release_parts(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.strip_prefix("1.")?.split('.');
+    let minor = parts.next()?.parse().ok()?;
+    let patch = parts.next().map(|p| p.parse().ok()).unwrap_or(Some(0))?;
+    parts.next().is_none().then_some((minor, patch))
+}
+
+pub fn protocol_version(version: &str) -> Option<u32> {
+    PROTOCOLS.iter().find(|(v, _)| *v == version).map(|(_, p)| *p)
+}
+
+/// Minimum Java major version a server of this version runs on.
+pub fn required_java(version: &str) -> u32 {
+    match release_parts(version) {
+        Some((minor, _)) if minor <= 16 => 8,
+        Some((17, _)) => 16,
+        Some((minor, patch)) if minor < 20 || (minor == 20 && patch < 5) => 17,
+        // 1.20.5+, and snapshots/unknown versions, which track the latest requirement
+        _ => 21,
+    }
+}
+
+pub fn compatibility(version: &str) -> VersionCompatibility {
+    let protocol = protocol_version(version);
+    let compatible_clients = match protocol {
+        Some(protocol) => PROTOCOLS
+            .iter()
+            .rev()
+            .filter(|(_, p)| *p == protocol)
+            .map(|(v, _)| v.to_string())
+            .collect(),
+        None => vec![version.to_string()],
+    };
+    VersionCompatibility {
+        version: version.to_string(),
+        protocol,
+        compatible_clients,
+        min_java: required_java(version),
+    }
+}
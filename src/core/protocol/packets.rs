@@ -0,0 +1,158 @@
+//! Just enough of the Java Edition wire format to answer status pings and turn
+//! away logins (handshake parsing plus status, pong and disconnect packets), and to
+//! ping other servers (handshake, status request and ping packets).
+
+use anyhow::{Result, anyhow};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// Packets larger than this are not something a handshake or ping ever sends.
+const MAX_PACKET_LEN: usize = 4096;
+/// Status responses carry the base64 server icon, so they get a much larger limit.
+pub const MAX_STATUS_LEN: usize = 1024 * 1024;
+
+/// What the client wants to do after the handshake.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NextState {
+    Status,
+    Login,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Handshake {
+    pub protocol: i32,
+    pub address: String,
+    pub port: u16,
+    pub next_state: NextState,
+}
+
+pub fn write_varint(buf: &mut Vec<u8>, value: i32) {
+    let mut value = value as u32;
+    loop {
+        if value & !0x7F == 0 {
+            buf.push(value as u8);
+            return;
+        }
+        buf.push((value as u8 & 0x7F) | 0x80);
+        value >>= 7;
+    }
+}
+
+/// Reads a VarInt from the front of `data`, returning it and the bytes consumed.
+pub fn read_varint(data: &[u8]) -> Result<(i32, usize)> {
+    let mut value: u32 = 0;
+    for (i, byte) in data.iter().take(5).enumerate() {
+        value |= ((byte & 0x7F) as u32) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Ok((value as i32, i + 1));
+        }
+    }
+    Err(anyhow!("Invalid or truncated VarInt"))
+}
+
+fn write_string(buf: &mut Vec<u8>, value: &str) {
+    write_varint(buf, value.len() as i32);
+    buf.extend_from_slice(value.as_bytes());
+}
+
+/// Prefixes `id` and `body` with the packet length.
+fn frame(id: i32, body: &[u8]) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(body.len() + 1);
+    write_varint(&mut payload, id);
+    payload.extend_from_slice(body);
+    let mut packet = Vec::with_capacity(payload.len() + 3);
+    write_varint(&mut packet, payload.len() as i32);
+    packet.extend(payload);
+    packet
+}
+
+/// Reads one length-prefixed packet and returns its id and body.
+pub async fn read_packet<R: AsyncRead + Unpin>(reader: &mut R) -> Result<(i32, Vec<u8>)> {
+    read_packet_limited(reader, MAX_PACKET_LEN).await
+}
+
+/// [`read_packet`] for packets up to `max_len` bytes.
+pub async fn read_packet_limited<R: AsyncRead + Unpin>(reader: &mut R, max_len: usize) -> Result<(i32, Vec<u8>)> {
+    let mut len_bytes = Vec::with_capacity(5);
+    let len = loop {
+        len_bytes.push(reader.read_u8().await?);
+        if len_bytes.last().is_some_and(|b| b & 0x80 == 0) || len_bytes.len() == 5 {
+            break read_varint(&len_bytes)?.0;
+        }
+    };
+    let len = usize::try_from(len).map_err(|_| anyhow!("Negative packet length"))?;
+    if len == 0 || len > max_len {
+        return Err(anyhow!("Unexpected packet length {}", len));
+    }
+    let mut data = vec![0; len];
+    reader.read_exact(&mut data).await?;
+    let (id, used) = read_varint(&data)?;
+    Ok((id, data.split_off(used)))
+}
+
+/// Reads a length-prefixed string from the front of `data`, returning it and the bytes consumed.
+pub fn read_string(data: &[u8]) -> Result<(String, usize)> {
+    let (len, used) = read_varint(data)?;
+    let end = used + usize::try_from(len).map_err(|_| anyhow!("Invalid string length"))?;
+    let bytes = data.get(used..end).ok_or_else(|| anyhow!("Truncated string"))?;
+    Ok((String::from_utf8_lossy(bytes).to_string(), end))
+}
+
+pub fn parse_handshake(body: &[u8]) -> Result<Handshake> {
+    let (protocol, mut pos) = read_varint(body)?;
+    let (addr_len, used) = read_varint(&body[pos..])?;
+    pos += used;
+    let addr_end = pos + usize::try_from(addr_len).map_err(|_| anyhow!("Invalid address length"))?;
+    let address = String::from_utf8_lossy(body.get(pos..addr_end).ok_or_else(|| anyhow!("Truncated handshake"))?).to_string();
+    let port_bytes = body.get(addr_end..addr_end + 2).ok_or_else(|| anyhow!("Truncated handshake"))?;
+    let port = u16::from_be_bytes([port_bytes[0], port_bytes[1]]);
+    let next_state = match read_varint(&body[addr_end + 2..])?.0 {
+        1 => NextState::Status,
+        // 3 is a transfer from another server, which logs in the same way
+        2 | 3 => NextState::Login,
+        other => return Err(anyhow!("Unknown handshake state {}", other)),
+    };
+    Ok(Handshake { protocol, address, port, next_state })
+}
+
+/// Client handshake; `next_state` picks status (1) or login (2).
+pub fn handshake(protocol: i32, address: &str, port: u16, next_state: NextState) -> Vec<u8> {
+    let mut body = Vec::new();
+    write_varint(&mut body, protocol);
+    write_string(&mut body, address);
+    body.extend_from_slice(&port.to_be_bytes());
+    write_varint(&mut body, if next_state == NextState::Status { 1 } else { 2 });
+    frame(0x00, &body)
+}
+
+pub fn status_request() -> Vec<u8> {
+    frame(0x00, &[])
+}
+
+/// Status-state ping; the server echoes `payload` back in a pong.
+pub fn ping(payload: i64) -> Vec<u8> {
+    frame(0x01, &payload.to_be_bytes())
+}
+
+/// Status response listing no players and `motd` as the description.
+pub fn status_response(version_name: &str, protocol: i32, motd: &str) -> Vec<u8> {
+    let json = serde_json::json!({
+        "version": { "name": version_name, "protocol": protocol },
+        "players": { "max": 0, "online": 0 },
+        "description": { "text": motd },
+    });
+    let mut body = Vec::new();
+    write_string(&mut body, &json.to_string());
+    frame(0x00, &body)
+}
+
+/// Echoes a status ping's payload back to the client.
+pub fn pong(payload: &[u8]) -> Vec<u8> {
+    frame(0x01, payload)
+}
+
+/// Login-state disconnect with `reason` shown on the client's error screen.
+pub fn login_disconnect(reason: &str) -> Vec<u8> {
+    let mut body = Vec::new();
+    write_string(&mut body, &serde_json::json!({ "text": reason }).to_string());
+    frame(0x00, &body)
+}
@@ -0,0 +1,84 @@
+use super::query::{QueryResponse, query_server};
+use super::status::{DEFAULT_PING_TIMEOUT, ServerStatusReport, parse_server_address, ping_server};
+use crate::connection::DEFAULT_JAVA_PORT;
+use crate::instance::InstanceMetadata;
+use crate::server_properties::read_server_properties;
+use anyhow::{Result, anyhow};
+use serde::Serialize;
+use std::net::IpAddr;
+use std::time::Duration;
+use tracing::debug;
+
+/// Query runs after a successful ping, so a short timeout is enough.
+const QUERY_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// A server as seen from the network.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ServerProbe {
+    pub host: String,
+    pub port: u16,
+    pub status: ServerStatusReport,
+    /// Present when the server has Query enabled; lists every online player.
+    pub query: Option<QueryResponse>,
+}
+
+/// Pings any Java server by `host[:port]`, e.g. an external server the user added.
+pub async fn probe_address(address: &str) -> Result<ServerProbe> {
+    let (host, port) = parse_server_address(address, DEFAULT_JAVA_PORT)?;
+    let status = ping_server(&host, port, DEFAULT_PING_TIMEOUT).await?;
+    Ok(ServerProbe {
+        host,
+        port,
+        status,
+        query: None,
+    })
+}
+
+/// Pings an instance over the network, so it works even when the wrapper didn't start
+/// the process. Query is tried as well when `server.properties` enables it.
+pub async fn probe_instance(instance: &InstanceMetadata) -> Result<ServerProbe> {
+    if instance
+        .mod_loader
+        .as_deref()
+        .is_some_and(|l| l.eq_ignore_ascii_case("bedrock"))
+    {
+        return Err(anyhow!("Bedrock servers don't answer Java status pings"));
+    }
+    let host = instance
+        .ip
+        .as_deref()
+        .map(str::trim)
+        .filter(|ip| {
+            ip.parse::<IpAddr>()
+                .map(|ip| !ip.is_unspecified())
+                .unwrap_or(!ip.is_empty())
+        })
+        .unwrap_or("127.0.0.1")
+        .to_string();
+    let port = instance.port.unwrap_or(DEFAULT_JAVA_PORT);
+    let status = ping_server(&host, port, DEFAULT_PING_TIMEOUT).await?;
+
+    let properties = read_server_properties(&instance.path).await.unwrap_or_default();
+    let query = if properties.get("enable-query").is_some_and(|v| v.trim() == "true") {
+        let query_port = properties
+            .get("query.port")
+            .and_then(|p| p.trim().parse().ok())
+            .unwrap_or(port);
+        match query_server(&host, query_port, QUERY_TIMEOUT).await {
+            Ok(response) => Some(response),
+            Err(e) => {
+                debug!("Query to {} failed: {}", instance.name, e);
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    Ok(ServerProbe {
+        host,
+        port,
+        status,
+        query,
+    })
+}
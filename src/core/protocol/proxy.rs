@@ -0,0 +1,126 @@
+use super::{compatibility, protocol_version};
+use crate::instance::InstanceManager;
+use anyhow::{Context, Result, anyhow};
+use serde::Serialize;
+use std::path::Path;
+use tokio::fs;
+use uuid::Uuid;
+
+const LOCAL_HOSTS: [&str; 5] = ["localhost", "127.0.0.1", "0.0.0.0", "::1", ""];
+
+/// A server registered in a proxy's config, matched to a local instance by port.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProxyBackend {
+    pub name: String,
+    pub address: String,
+    pub instance_id: Option<Uuid>,
+    pub instance_name: Option<String>,
+    pub version: Option<String>,
+    pub protocol: Option<u32>,
+    /// Speaks a different protocol than the newest backend.
+    pub mismatch: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProxyCompatibilityReport {
+    pub proxy_id: Uuid,
+    pub backends: Vec<ProxyBackend>,
+    pub warnings: Vec<String>,
+}
+
+/// Reads `(name, address)` pairs from `velocity.toml` or BungeeCord's `config.yml`.
+async fn read_proxy_servers(proxy_path: &Path, loader: &str) -> Result<Vec<(String, String)>> {
+    if loader == "velocity" {
+        let content = fs::read_to_string(proxy_path.join("velocity.toml"))
+            .await
+            .context("Failed to read velocity.toml")?;
+        let config: toml::Value = toml::from_str(&content).context("Failed to parse velocity.toml")?;
+        let Some(servers) = config.get("servers").and_then(|s| s.as_table()) else {
+            return Ok(vec![]);
+        };
+        // `try` is the fallback order, not a server
+        Ok(servers
+            .iter()
+            .filter_map(|(name, addr)| Some((name.clone(), addr.as_str()?.to_string())))
+            .collect())
+    } else {
+        let content = fs::read_to_string(proxy_path.join("config.yml"))
+            .await
+            .context("Failed to read config.yml")?;
+        let config: serde_yaml::Value = serde_yaml::from_str(&content).context("Failed to parse config.yml")?;
+        let Some(servers) = config.get("servers").and_then(|s| s.as_mapping()) else {
+            return Ok(vec![]);
+        };
+        Ok(servers
+            .iter()
+            .filter_map(|(name, server)| {
+                Some((name.as_str()?.to_string(), server.get("address")?.as_str()?.to_string()))
+            })
+            .collect())
+    }
+}
+
+/// Local port of an address such as `127.0.0.1:25566`; `None` for remote hosts.
+fn local_port(address: &str) -> Option<u16> {
+    let (host, port) = address.rsplit_once(':')?;
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    LOCAL_HOSTS.contains(&host).then(|| port.parse().ok()).flatten()
+}
+
+impl InstanceManager {
+    /// Matches a Velocity/BungeeCord instance's configured servers to local instances
+    /// and flags backends whose protocol differs from the rest.
+    pub async fn proxy_compatibility(&self, proxy_id: Uuid) -> Result<ProxyCompatibilityReport> {
+        let proxy = self.get_instance(proxy_id).await?.context("Instance not found")?;
+        let loader = proxy.mod_loader.as_deref().unwrap_or_default().to_lowercase();
+        if loader != "velocity" && loader != "bungeecord" {
+            return Err(anyhow!("{} is not a proxy instance", proxy.name));
+        }
+
+        let instances = self.list_instances().await?;
+        let mut backends: Vec<ProxyBackend> = read_proxy_servers(&proxy.path, &loader)
+            .await?
+            .into_iter()
+            .map(|(name, address)| {
+                let instance = local_port(&address).and_then(|port| {
+                    instances.iter().find(|i| i.id != proxy_id && i.settings.port == port)
+                });
+                let version = instance.map(|i| i.version.clone());
+                ProxyBackend {
+                    protocol: version.as_deref().and_then(protocol_version),
+                    instance_id: instance.map(|i| i.id),
+                    instance_name: instance.map(|i| i.name.clone()),
+                    name,
+                    address,
+                    version,
+                    mismatch: false,
+                }
+            })
+            .collect();
+
+        let mut warnings = Vec::new();
+        let newest = backends.iter().filter_map(|b| b.protocol).max();
+        if let Some(newest) = newest {
+            let clients = backends
+                .iter()
+                .find(|b| b.protocol == Some(newest))
+                .and_then(|b| b.version.as_deref())
+                .map(|v| compatibility(v).compatible_clients.join("/"))
+                .unwrap_or_default();
+            for backend in backends.iter_mut().filter(|b| b.protocol.is_some_and(|p| p != newest)) {
+                backend.mismatch = true;
+                warnings.push(format!(
+                    "{} runs {}, but other backends expect {} clients; players cannot switch to it without ViaVersion",
+                    backend.name,
+                    backend.version.as_deref().unwrap_or("an unknown version"),
+                    clients
+                ));
+            }
+        }
+        for backend in backends.iter().filter(|b| b.instance_id.is_none()) {
+            warnings.push(format!("{} ({}) does not match a local instance", backend.name, backend.address));
+        }
+
+        Ok(ProxyCompatibilityReport { proxy_id, backends, warnings })
+    }
+}
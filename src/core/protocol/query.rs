@@ -0,0 +1,159 @@
+//! GameSpy4 UDP Query client. Needs `enable-query=true` in the target's
+//! `server.properties`, but unlike a status ping it returns every online player.
+
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::net::UdpSocket;
+
+const MAGIC: [u8; 2] = [0xFE, 0xFD];
+const TYPE_HANDSHAKE: u8 = 0x09;
+const TYPE_STAT: u8 = 0x00;
+const STAT_PADDING: &[u8] = b"splitnum\x00\x80\x00";
+const PLAYERS_PADDING: &[u8] = b"\x01player_\x00\x00";
+
+/// Full stat response of a Query request.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct QueryResponse {
+    pub motd: String,
+    pub game_type: String,
+    pub version: String,
+    /// Server software and plugins, e.g. `Paper on 1.21.1: LuckPerms 5.4; EssentialsX 2.20`.
+    pub plugins: String,
+    pub map: String,
+    pub players_online: u32,
+    pub players_max: u32,
+    pub host_port: Option<u16>,
+    pub players: Vec<String>,
+}
+
+/// Session ids only keep the low 4 bits of each byte.
+pub fn session_id(seed: u32) -> u32 {
+    seed & 0x0F0F_0F0F
+}
+
+pub fn handshake_request(session: u32) -> Vec<u8> {
+    let mut packet = MAGIC.to_vec();
+    packet.push(TYPE_HANDSHAKE);
+    packet.extend_from_slice(&session.to_be_bytes());
+    packet
+}
+
+/// The challenge token from a handshake response.
+pub fn parse_handshake_response(packet: &[u8], session: u32) -> Result<i32> {
+    check_header(packet, TYPE_HANDSHAKE, session)?;
+    let token = read_cstr(packet, 5)?.0;
+    token
+        .trim()
+        .parse()
+        .with_context(|| format!("Invalid Query challenge token '{}'", token))
+}
+
+pub fn full_stat_request(session: u32, token: i32) -> Vec<u8> {
+    let mut packet = MAGIC.to_vec();
+    packet.push(TYPE_STAT);
+    packet.extend_from_slice(&session.to_be_bytes());
+    packet.extend_from_slice(&token.to_be_bytes());
+    // Four extra bytes ask for the full stat instead of the basic one
+    packet.extend_from_slice(&[0; 4]);
+    packet
+}
+
+pub fn parse_full_stat(packet: &[u8], session: u32) -> Result<QueryResponse> {
+    check_header(packet, TYPE_STAT, session)?;
+    let mut pos = 5;
+    if packet.get(pos..pos + STAT_PADDING.len()) != Some(STAT_PADDING) {
+        return Err(anyhow!("Query response isn't a full stat"));
+    }
+    pos += STAT_PADDING.len();
+
+    let mut values = HashMap::new();
+    loop {
+        let (key, next) = read_cstr(packet, pos)?;
+        pos = next;
+        if key.is_empty() {
+            break;
+        }
+        let (value, next) = read_cstr(packet, pos)?;
+        pos = next;
+        values.insert(key, value);
+    }
+
+    let mut players = Vec::new();
+    if packet.get(pos..pos + PLAYERS_PADDING.len()) == Some(PLAYERS_PADDING) {
+        pos += PLAYERS_PADDING.len();
+        while let Ok((name, next)) = read_cstr(packet, pos) {
+            if name.is_empty() {
+                break;
+            }
+            players.push(name);
+            pos = next;
+        }
+    }
+
+    let text = |key: &str| values.get(key).cloned().unwrap_or_default();
+    let number = |key: &str| values.get(key).and_then(|v| v.parse().ok());
+    Ok(QueryResponse {
+        motd: text("hostname"),
+        game_type: text("gametype"),
+        version: text("version"),
+        plugins: text("plugins"),
+        map: text("map"),
+        players_online: number("numplayers").unwrap_or(players.len() as u32),
+        players_max: number("maxplayers").unwrap_or(0),
+        host_port: values.get("hostport").and_then(|v| v.parse().ok()),
+        players,
+    })
+}
+
+fn check_header(packet: &[u8], kind: u8, session: u32) -> Result<()> {
+    if packet.len() < 5 || packet[0] != kind || packet[1..5] != session.to_be_bytes() {
+        return Err(anyhow!("Unexpected Query response"));
+    }
+    Ok(())
+}
+
+/// Reads a NUL-terminated string at `pos`, returning it and the position after the NUL.
+fn read_cstr(packet: &[u8], pos: usize) -> Result<(String, usize)> {
+    let rest = packet
+        .get(pos..)
+        .ok_or_else(|| anyhow!("Query response is truncated"))?;
+    let len = rest
+        .iter()
+        .position(|b| *b == 0)
+        .ok_or_else(|| anyhow!("Query response is truncated"))?;
+    Ok((String::from_utf8_lossy(&rest[..len]).to_string(), pos + len + 1))
+}
+
+/// Runs a full stat Query against `host:port`.
+pub async fn query_server(host: &str, port: u16, timeout: Duration) -> Result<QueryResponse> {
+    tokio::time::timeout(timeout, query_inner(host, port))
+        .await
+        .map_err(|_| anyhow!("Query to {}:{} timed out; is enable-query on?", host, port))?
+}
+
+async fn query_inner(host: &str, port: u16) -> Result<QueryResponse> {
+    let bind = if host.contains(':') { "[::]:0" } else { "0.0.0.0:0" };
+    let socket = UdpSocket::bind(bind).await.context("Failed to open a UDP socket")?;
+    socket
+        .connect((host, port))
+        .await
+        .with_context(|| format!("Failed to resolve {}", host))?;
+
+    let session = session_id(rand_seed());
+    let mut buf = vec![0u8; 8192];
+
+    socket.send(&handshake_request(session)).await?;
+    let len = socket.recv(&mut buf).await?;
+    let token = parse_handshake_response(&buf[..len], session)?;
+
+    socket.send(&full_stat_request(session, token)).await?;
+    let len = socket.recv(&mut buf).await?;
+    parse_full_stat(&buf[..len], session)
+}
+
+fn rand_seed() -> u32 {
+    let bytes = uuid::Uuid::new_v4().into_bytes();
+    u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
@@ -0,0 +1,137 @@
+//! Server List Ping client: what the multiplayer screen shows for a server.
+
+use super::packets::{self, MAX_STATUS_LEN, NextState};
+use crate::server_list::{MotdFormat, convert_motd, strip_formatting};
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::time::{Duration, Instant};
+use tokio::io::AsyncWriteExt;
+use tokio::net::TcpStream;
+
+pub const DEFAULT_PING_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A server's answer to a status ping.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ServerStatusReport {
+    pub version_name: String,
+    pub protocol: i32,
+    pub players_online: u32,
+    pub players_max: u32,
+    /// The handful of names the server chooses to show on hover; not a full list.
+    pub player_sample: Vec<String>,
+    /// With `§` codes, as set in `server.properties`.
+    pub motd: String,
+    pub motd_plain: String,
+    /// `data:image/png;base64,...`
+    pub favicon: Option<String>,
+    pub latency_ms: Option<u64>,
+}
+
+/// Splits `host[:port]`, accepting bracketed IPv6 (`[::1]:25565`) and bare IPv6.
+pub fn parse_server_address(address: &str, default_port: u16) -> Result<(String, u16)> {
+    let address = address.trim();
+    if address.is_empty() {
+        return Err(anyhow!("Server address is empty"));
+    }
+    if let Some(rest) = address.strip_prefix('[') {
+        let (host, tail) = rest
+            .split_once(']')
+            .ok_or_else(|| anyhow!("Unclosed '[' in {}", address))?;
+        let port = match tail.strip_prefix(':') {
+            Some(port) => port.parse().with_context(|| format!("Invalid port in {}", address))?,
+            None if tail.is_empty() => default_port,
+            None => return Err(anyhow!("Unexpected text after ']' in {}", address)),
+        };
+        return Ok((host.to_string(), port));
+    }
+    match address.rsplit_once(':') {
+        // More than one colon is a bare IPv6 address
+        Some((host, _)) if host.contains(':') => Ok((address.to_string(), default_port)),
+        Some((host, port)) => {
+            let port = port.parse().with_context(|| format!("Invalid port in {}", address))?;
+            Ok((host.to_string(), port))
+        }
+        None => Ok((address.to_string(), default_port)),
+    }
+}
+
+/// Parses the JSON body of a status response. `latency_ms` is left empty.
+pub fn parse_status_json(json: &str) -> Result<ServerStatusReport> {
+    let value: JsonValue = serde_json::from_str(json).context("Invalid status response")?;
+    let version = value.get("version");
+    let players = value.get("players");
+
+    let description = match value.get("description") {
+        Some(JsonValue::String(text)) => text.clone(),
+        Some(component) => convert_motd(&component.to_string(), MotdFormat::Json, MotdFormat::Legacy)?,
+        None => String::new(),
+    };
+    let count = |key: &str| players.and_then(|p| p.get(key)).and_then(|v| v.as_u64()).unwrap_or(0) as u32;
+
+    Ok(ServerStatusReport {
+        version_name: version
+            .and_then(|v| v.get("name"))
+            .and_then(|n| n.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        protocol: version
+            .and_then(|v| v.get("protocol"))
+            .and_then(|p| p.as_i64())
+            .unwrap_or(-1) as i32,
+        players_online: count("online"),
+        players_max: count("max"),
+        player_sample: players
+            .and_then(|p| p.get("sample"))
+            .and_then(|s| s.as_array())
+            .map(|sample| {
+                sample
+                    .iter()
+                    .filter_map(|p| p.get("name").and_then(|n| n.as_str()))
+                    .map(|name| name.to_string())
+                    .collect()
+            })
+            .unwrap_or_default(),
+        motd_plain: strip_formatting(&description, MotdFormat::Legacy)?,
+        motd: description,
+        favicon: value.get("favicon").and_then(|f| f.as_str()).map(|f| f.to_string()),
+        latency_ms: None,
+    })
+}
+
+/// Pings a Java Edition server the way the multiplayer screen does. Works for any
+/// server that accepts connections, whether or not the wrapper started it.
+pub async fn ping_server(host: &str, port: u16, timeout: Duration) -> Result<ServerStatusReport> {
+    tokio::time::timeout(timeout, ping_inner(host, port))
+        .await
+        .map_err(|_| anyhow!("{}:{} didn't answer within {}s", host, port, timeout.as_secs()))?
+}
+
+async fn ping_inner(host: &str, port: u16) -> Result<ServerStatusReport> {
+    let mut stream = TcpStream::connect((host, port))
+        .await
+        .with_context(|| format!("Failed to connect to {}:{}", host, port))?;
+
+    // -1 asks for the status without claiming a client version
+    stream
+        .write_all(&packets::handshake(-1, host, port, NextState::Status))
+        .await?;
+    stream.write_all(&packets::status_request()).await?;
+    let (id, body) = packets::read_packet_limited(&mut stream, MAX_STATUS_LEN).await?;
+    if id != 0x00 {
+        return Err(anyhow!("Unexpected status packet {:#04x}", id));
+    }
+    let (json, _) = packets::read_string(&body)?;
+    let mut report = parse_status_json(&json)?;
+
+    let sent = Instant::now();
+    let payload = chrono::Utc::now().timestamp_millis();
+    stream.write_all(&packets::ping(payload)).await?;
+    // Some servers close the connection instead of answering the ping
+    if let Ok((0x01, pong)) = packets::read_packet(&mut stream).await
+        && pong == payload.to_be_bytes()
+    {
+        report.latency_ms = Some(sent.elapsed().as_millis() as u64);
+    }
+    Ok(report)
+}
@@ -0,0 +1,192 @@
+//! A minimal passive-mode FTP client: enough to browse a host and download files.
+
+use super::{PROGRESS_STEP, RemoteEntry, RemoteSource, check_walk_limits, join_remote};
+use anyhow::{Context, Result, anyhow};
+use std::path::Path;
+use tokio::fs::File;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+
+pub struct FtpClient {
+    control: BufReader<TcpStream>,
+    host: String,
+}
+
+impl FtpClient {
+    pub async fn connect(source: &RemoteSource) -> Result<Self> {
+        let stream = TcpStream::connect((source.host.as_str(), source.port()))
+            .await
+            .with_context(|| format!("Failed to connect to {}:{}", source.host, source.port()))?;
+        let mut client = Self { control: BufReader::new(stream), host: source.host.clone() };
+
+        client.expect(None, &[220]).await?;
+        let (code, _) = client.send(&format!("USER {}", source.username)).await?;
+        if code == 331 {
+            let password = source.password.as_deref().unwrap_or_default();
+            client.expect(Some(&format!("PASS {}", password)), &[230, 202]).await.context("FTP login failed")?;
+        } else if code != 230 {
+            return Err(anyhow!("FTP login failed ({})", code));
+        }
+        client.expect(Some("TYPE I"), &[200]).await?;
+        Ok(client)
+    }
+
+    async fn read_reply(&mut self) -> Result<(u16, String)> {
+        let mut text = String::new();
+        let mut line = String::new();
+        loop {
+            line.clear();
+            if self.control.read_line(&mut line).await? == 0 {
+                return Err(anyhow!("FTP server closed the connection"));
+            }
+            text.push_str(&line);
+            // Multi-line replies start with "123-" and end with "123 "
+            if line.len() >= 4 && line.as_bytes()[3] == b' ' && line[..3].chars().all(|c| c.is_ascii_digit()) {
+                let code = line[..3].parse()?;
+                return Ok((code, text.trim_end().to_string()));
+            }
+        }
+    }
+
+    async fn send(&mut self, command: &str) -> Result<(u16, String)> {
+        self.control.get_mut().write_all(format!("{}\r\n", command).as_bytes()).await?;
+        self.read_reply().await
+    }
+
+    async fn expect(&mut self, command: Option<&str>, codes: &[u16]) -> Result<String> {
+        let (code, text) = match command {
+            Some(command) => self.send(command).await?,
+            None => self.read_reply().await?,
+        };
+        if !codes.contains(&code) {
+            return Err(anyhow!("Unexpected FTP reply: {}", text));
+        }
+        Ok(text)
+    }
+
+    /// Opens a passive data connection. The address in a PASV reply is ignored in favour
+    /// of the control host, since hosts behind NAT routinely advertise a private one.
+    async fn data_connection(&mut self) -> Result<TcpStream> {
+        let (code, text) = self.send("EPSV").await?;
+        let port = if code == 229 {
+            text.rsplit("|||").next().and_then(|p| p.trim_end_matches(['|', ')']).parse().ok())
+        } else {
+            let text = self.expect(Some("PASV"), &[227]).await?;
+            parse_pasv_port(&text)
+        }
+        .ok_or_else(|| anyhow!("Could not parse passive mode reply"))?;
+        TcpStream::connect((self.host.as_str(), port)).await.context("Failed to open FTP data connection")
+    }
+
+    /// Lists `path`, preferring machine-readable MLSD and falling back to `LIST`.
+    pub async fn list(&mut self, path: &str) -> Result<Vec<RemoteEntry>> {
+        let (lines, mlsd) = match self.read_listing(&format!("MLSD {}", path)).await {
+            Ok(lines) => (lines, true),
+            Err(_) => (self.read_listing(&format!("LIST -a {}", path)).await?, false),
+        };
+        Ok(lines
+            .iter()
+            .filter_map(|line| if mlsd { parse_mlsd_line(line) } else { parse_list_line(line) })
+            .filter(|(name, _, _)| name != "." && name != "..")
+            .map(|(name, is_dir, size)| RemoteEntry { path: join_remote(path, &name), name, is_dir, size })
+            .collect())
+    }
+
+    async fn read_listing(&mut self, command: &str) -> Result<Vec<String>> {
+        let mut data = self.data_connection().await?;
+        self.expect(Some(command), &[125, 150]).await?;
+        let mut raw = Vec::new();
+        data.read_to_end(&mut raw).await?;
+        self.expect(None, &[226, 250]).await?;
+        Ok(String::from_utf8_lossy(&raw).lines().map(str::to_string).collect())
+    }
+
+    /// Every file below `root` as `(path relative to root, entry)`.
+    pub async fn walk(&mut self, root: &str) -> Result<Vec<(String, RemoteEntry)>> {
+        let mut files = Vec::new();
+        let mut pending = vec![(root.to_string(), String::new(), 0)];
+        while let Some((dir, rel_dir, depth)) = pending.pop() {
+            check_walk_limits(files.len(), depth)?;
+            for entry in self.list(&dir).await? {
+                let rel = if rel_dir.is_empty() { entry.name.clone() } else { format!("{}/{}", rel_dir, entry.name) };
+                if entry.is_dir {
+                    pending.push((entry.path.clone(), rel, depth + 1));
+                } else {
+                    files.push((rel, entry));
+                }
+            }
+        }
+        Ok(files)
+    }
+
+    /// Streams `path` into `dest`, calling `on_bytes` with the running total every
+    /// [`PROGRESS_STEP`] bytes; returns the size.
+    pub async fn retrieve(&mut self, path: &str, dest: &Path, on_bytes: impl Fn(u64)) -> Result<u64> {
+        let mut data = self.data_connection().await?;
+        self.expect(Some(&format!("RETR {}", path)), &[125, 150]).await?;
+        let mut file = File::create(dest).await.with_context(|| format!("Failed to create {}", dest.display()))?;
+        let mut buf = vec![0u8; 64 * 1024];
+        let (mut written, mut reported) = (0u64, 0u64);
+        loop {
+            let n = data.read(&mut buf).await?;
+            if n == 0 {
+                break;
+            }
+            file.write_all(&buf[..n]).await?;
+            written += n as u64;
+            if written - reported >= PROGRESS_STEP {
+                on_bytes(written);
+                reported = written;
+            }
+        }
+        file.flush().await?;
+        self.expect(None, &[226, 250]).await.with_context(|| format!("Download of {} did not complete", path))?;
+        Ok(written)
+    }
+}
+
+fn parse_pasv_port(text: &str) -> Option<u16> {
+    let inner = text.split_once('(')?.1.split_once(')')?.0;
+    let parts: Vec<u16> = inner.split(',').map(|p| p.trim().parse().ok()).collect::<Option<_>>()?;
+    (parts.len() == 6).then(|| parts[4] * 256 + parts[5])
+}
+
+/// Parses `type=dir;size=12; name` into `(name, is_dir, size)`.
+pub fn parse_mlsd_line(line: &str) -> Option<(String, bool, u64)> {
+    let (facts, name) = line.split_once(' ')?;
+    let mut is_dir = false;
+    let mut size = 0;
+    for fact in facts.split(';') {
+        match fact.split_once('=').map(|(k, v)| (k.to_lowercase(), v)) {
+            Some((key, value)) if key == "type" => {
+                // Skips `cdir`/`pdir` and symlinks (`OS.unix=slink:...`)
+                match value.to_lowercase().as_str() {
+                    "dir" => is_dir = true,
+                    "file" => is_dir = false,
+                    _ => return None,
+                }
+            }
+            Some((key, value)) if key == "size" => size = value.parse().unwrap_or(0),
+            _ => {}
+        }
+    }
+    Some((name.to_string(), is_dir, size))
+}
+
+/// Parses a Unix-style `LIST` line (`drwxr-xr-x 2 user group 4096 Jan 1 00:00 name`).
+pub fn parse_list_line(line: &str) -> Option<(String, bool, u64)> {
+    let mut fields = line.split_whitespace();
+    let mode = fields.next()?;
+    let kind = mode.chars().next()?;
+    // Symlinks are skipped, they may point outside the server folder
+    if !matches!(kind, 'd' | '-') || mode.len() < 10 {
+        return None;
+    }
+    let size = fields.nth(3)?.parse().ok()?;
+    // Skip month, day and time/year, then the rest of the line is the name
+    let mut rest = line;
+    for _ in 0..8 {
+        rest = rest.trim_start().split_once(char::is_whitespace)?.1;
+    }
+    Some((rest.trim_start().to_string(), kind == 'd', size))
+}
@@ -0,0 +1,40 @@
+use super::{RemoteSource, download_remote_tree};
+use crate::instance::InstanceManager;
+use crate::instance::types::InstanceMetadata;
+use anyhow::Result;
+use tokio::fs;
+use uuid::Uuid;
+
+impl InstanceManager {
+    /// Downloads `remote_root` from the remote host straight into a new instance folder
+    /// and imports it like a local directory. A failed download leaves nothing behind.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn import_remote_instance<F>(
+        &self,
+        name: &str,
+        source: &RemoteSource,
+        remote_root: &str,
+        jar_name: String,
+        mod_loader: Option<String>,
+        script_path: Option<String>,
+        on_progress: F,
+    ) -> Result<InstanceMetadata>
+    where
+        F: Fn(u64, u64, String) + Send + Sync + 'static,
+    {
+        self.ensure_name_available(name, None).await?;
+        let id = Uuid::new_v4();
+        let instance_path = self.base_dir.join(id.to_string());
+
+        if let Err(e) = download_remote_tree(source, remote_root, &instance_path, on_progress).await {
+            let _ = fs::remove_dir_all(&instance_path).await;
+            return Err(e.context(format!("Failed to download {} from {}", remote_root, source.host)));
+        }
+        if !instance_path.join(&jar_name).exists() {
+            let _ = fs::remove_dir_all(&instance_path).await;
+            return Err(anyhow::anyhow!("{} was not found in {}", jar_name, remote_root));
+        }
+
+        self.finish_import(id, instance_path, name, jar_name, mod_loader, script_path).await
+    }
+}
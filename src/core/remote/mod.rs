@@ -0,0 +1,138 @@
+//! Pulls a server directory off an SFTP or FTP host, so instances can be migrated from
+//! shared hosting panels without zipping them up by hand first.
+
+use crate::utils::safe_join;
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tokio::fs;
+
+pub mod ftp;
+pub mod import;
+pub mod sftp;
+
+/// Directory walks stop here; a server root with more files than this is not a server root.
+const MAX_REMOTE_FILES: usize = 50_000;
+const MAX_REMOTE_DEPTH: usize = 32;
+/// Download progress is reported about once per this many bytes.
+pub(crate) const PROGRESS_STEP: u64 = 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RemoteProtocol {
+    Sftp,
+    Ftp,
+}
+
+/// Connection details for a remote host. Nothing here is persisted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteSource {
+    pub protocol: RemoteProtocol,
+    pub host: String,
+    /// Defaults to 22 for SFTP and 21 for FTP.
+    pub port: Option<u16>,
+    pub username: String,
+    /// The account password, or the passphrase of `private_key_path`.
+    #[serde(default)]
+    pub password: Option<String>,
+    /// SFTP only: a private key file used instead of the password.
+    #[serde(default)]
+    pub private_key_path: Option<String>,
+    /// SFTP only: the SHA-256 host key fingerprint the user confirmed after
+    /// [`fetch_host_key`]. Credentials are only sent once this is set, and only to a host
+    /// presenting this key.
+    #[serde(default)]
+    pub host_key_fingerprint: Option<String>,
+}
+
+impl RemoteSource {
+    pub(crate) fn port(&self) -> u16 {
+        self.port.unwrap_or(match self.protocol {
+            RemoteProtocol::Sftp => 22,
+            RemoteProtocol::Ftp => 21,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RemoteEntry {
+    pub name: String,
+    /// Absolute path on the remote host.
+    pub path: String,
+    pub is_dir: bool,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteListing {
+    pub path: String,
+    pub entries: Vec<RemoteEntry>,
+}
+
+pub(crate) fn join_remote(dir: &str, name: &str) -> String {
+    format!("{}/{}", dir.trim_end_matches('/'), name)
+}
+
+/// The SHA-256 fingerprint an SFTP host presents, read without logging in. Show it to
+/// the user and pass it back in [`RemoteSource::host_key_fingerprint`] once confirmed.
+pub async fn fetch_host_key(source: &RemoteSource) -> Result<String> {
+    match source.protocol {
+        RemoteProtocol::Sftp => sftp::host_key(source.clone()).await,
+        RemoteProtocol::Ftp => Err(anyhow!("FTP hosts have no host key")),
+    }
+}
+
+/// Lists one directory of the remote host, directories first.
+pub async fn list_remote_dir(source: &RemoteSource, path: &str) -> Result<RemoteListing> {
+    let mut entries = match source.protocol {
+        RemoteProtocol::Sftp => sftp::list_dir(source.clone(), path.to_string()).await?,
+        RemoteProtocol::Ftp => ftp::FtpClient::connect(source).await?.list(path).await?,
+    };
+    entries.sort_by(|a, b| b.is_dir.cmp(&a.is_dir).then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase())));
+    Ok(RemoteListing { path: path.to_string(), entries })
+}
+
+/// Stops a directory walk that has grown past the limits.
+pub(crate) fn check_walk_limits(files: usize, depth: usize) -> Result<()> {
+    if files > MAX_REMOTE_FILES {
+        return Err(anyhow!("The remote directory has more than {} files; pick the server folder itself", MAX_REMOTE_FILES));
+    }
+    if depth > MAX_REMOTE_DEPTH {
+        return Err(anyhow!("The remote directory is nested deeper than {} levels", MAX_REMOTE_DEPTH));
+    }
+    Ok(())
+}
+
+/// Downloads everything below `remote_root` into `dest`, reporting progress in bytes.
+pub async fn download_remote_tree<F>(source: &RemoteSource, remote_root: &str, dest: &Path, on_progress: F) -> Result<()>
+where
+    F: Fn(u64, u64, String) + Send + Sync + 'static,
+{
+    fs::create_dir_all(dest).await?;
+    match source.protocol {
+        RemoteProtocol::Sftp => sftp::download_tree(source.clone(), remote_root.to_string(), dest.to_path_buf(), on_progress).await,
+        RemoteProtocol::Ftp => {
+            let mut client = ftp::FtpClient::connect(source).await?;
+            let files = client.walk(remote_root).await?;
+            let total: u64 = files.iter().map(|(_, e)| e.size).sum();
+            let mut done = 0;
+            for (rel, entry) in files {
+                let local = local_path(dest, &rel)?;
+                let start = done;
+                on_progress(start, total, format!("Downloading {}", rel));
+                done += client.retrieve(&entry.path, &local, |n| on_progress(start + n, total, format!("Downloading {}", rel))).await?;
+            }
+            on_progress(total, total, "Download complete".to_string());
+            Ok(())
+        }
+    }
+}
+
+/// Where a remote file lands locally; remote names are untrusted and must stay inside `dest`.
+pub(crate) fn local_path(dest: &Path, rel: &str) -> Result<std::path::PathBuf> {
+    let path = safe_join(dest, rel)?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    Ok(path)
+}
@@ -0,0 +1,138 @@
+//! SFTP access through libssh2. Its API is blocking, so every call runs on the blocking pool.
+
+use super::{PROGRESS_STEP, RemoteEntry, RemoteSource, check_walk_limits, join_remote, local_path};
+use anyhow::{Context, Result, anyhow};
+use ssh2::{HashType, Session, Sftp};
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+fn fingerprint(session: &Session) -> Option<String> {
+    use base64::Engine;
+    session
+        .host_key_hash(HashType::Sha256)
+        .map(|hash| format!("SHA256:{}", base64::engine::general_purpose::STANDARD_NO_PAD.encode(hash)))
+}
+
+/// Opens the connection and runs the key exchange, which authenticates nothing yet.
+fn handshake(source: &RemoteSource) -> Result<(Session, Option<String>)> {
+    let addr = (source.host.as_str(), source.port());
+    let tcp = TcpStream::connect(addr).with_context(|| format!("Failed to connect to {}:{}", source.host, source.port()))?;
+    tcp.set_read_timeout(Some(TIMEOUT))?;
+
+    let mut session = Session::new()?;
+    session.set_tcp_stream(tcp);
+    session.set_timeout(TIMEOUT.as_millis() as u32);
+    session.handshake().context("SSH handshake failed")?;
+    let fingerprint = fingerprint(&session);
+    Ok((session, fingerprint))
+}
+
+pub(crate) async fn host_key(source: RemoteSource) -> Result<String> {
+    tokio::task::spawn_blocking(move || {
+        let (_, fingerprint) = handshake(&source)?;
+        fingerprint.ok_or_else(|| anyhow!("{} did not present a host key", source.host))
+    })
+    .await?
+}
+
+/// Connects and authenticates, but only to a host presenting the fingerprint the user
+/// confirmed, so credentials never reach a host that wasn't checked.
+fn connect(source: &RemoteSource) -> Result<Sftp> {
+    let Some(expected) = &source.host_key_fingerprint else {
+        return Err(anyhow!("Confirm the host key of {} before connecting", source.host));
+    };
+    let (session, actual) = handshake(source)?;
+    if actual.as_ref() != Some(expected) {
+        return Err(anyhow!("The host key of {} changed (expected {}); refusing to connect", source.host, expected));
+    }
+
+    match (&source.private_key_path, &source.password) {
+        (Some(key), passphrase) => session.userauth_pubkey_file(&source.username, None, Path::new(key), passphrase.as_deref()),
+        (None, Some(password)) => session.userauth_password(&source.username, password),
+        (None, None) => return Err(anyhow!("A password or private key is required for SFTP")),
+    }
+    .context("SFTP authentication failed")?;
+
+    session.sftp().context("Failed to start the SFTP subsystem")
+}
+
+fn read_dir(sftp: &Sftp, path: &str) -> Result<Vec<RemoteEntry>> {
+    let entries = sftp.readdir(Path::new(path)).with_context(|| format!("Failed to list {}", path))?;
+    Ok(entries
+        .into_iter()
+        .filter_map(|(entry_path, stat)| {
+            let name = entry_path.file_name()?.to_string_lossy().to_string();
+            // Symlinks are skipped, they may point outside the server folder
+            (stat.is_dir() || stat.is_file()).then(|| RemoteEntry {
+                path: join_remote(path, &name),
+                name,
+                is_dir: stat.is_dir(),
+                size: stat.size.unwrap_or(0),
+            })
+        })
+        .collect())
+}
+
+pub(crate) async fn list_dir(source: RemoteSource, path: String) -> Result<Vec<RemoteEntry>> {
+    tokio::task::spawn_blocking(move || {
+        let sftp = connect(&source)?;
+        read_dir(&sftp, &path)
+    })
+    .await?
+}
+
+fn walk(sftp: &Sftp, root: &str) -> Result<Vec<(String, RemoteEntry)>> {
+    let mut files = Vec::new();
+    let mut pending = vec![(root.to_string(), String::new(), 0)];
+    while let Some((dir, rel_dir, depth)) = pending.pop() {
+        check_walk_limits(files.len(), depth)?;
+        for entry in read_dir(sftp, &dir)? {
+            let rel = if rel_dir.is_empty() { entry.name.clone() } else { format!("{}/{}", rel_dir, entry.name) };
+            if entry.is_dir {
+                pending.push((entry.path.clone(), rel, depth + 1));
+            } else {
+                files.push((rel, entry));
+            }
+        }
+    }
+    Ok(files)
+}
+
+pub(crate) async fn download_tree<F>(source: RemoteSource, remote_root: String, dest: PathBuf, on_progress: F) -> Result<()>
+where
+    F: Fn(u64, u64, String) + Send + Sync + 'static,
+{
+    tokio::task::spawn_blocking(move || {
+        let sftp = connect(&source)?;
+        let files = walk(&sftp, &remote_root)?;
+        let total: u64 = files.iter().map(|(_, e)| e.size).sum();
+        let (mut done, mut reported) = (0, 0);
+        let mut buf = vec![0u8; 64 * 1024];
+
+        for (rel, entry) in files {
+            let message = format!("Downloading {}", rel);
+            on_progress(done, total, message.clone());
+            let mut remote = sftp.open(Path::new(&entry.path)).with_context(|| format!("Failed to open {}", entry.path))?;
+            let mut local = std::fs::File::create(local_path(&dest, &rel)?)?;
+            loop {
+                let n = remote.read(&mut buf).with_context(|| format!("Failed to read {}", entry.path))?;
+                if n == 0 {
+                    break;
+                }
+                local.write_all(&buf[..n])?;
+                done += n as u64;
+                if done - reported >= PROGRESS_STEP {
+                    on_progress(done, total, message.clone());
+                    reported = done;
+                }
+            }
+        }
+        on_progress(total, total, "Download complete".to_string());
+        Ok(())
+    })
+    .await?
+}
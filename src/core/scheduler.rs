@@ -1,3 +1,4 @@
+use crate::errors::AppError;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
@@ -9,6 +10,9 @@ use tokio_cron_scheduler::{Job, JobScheduler};
 use tracing::{info, error};
 use super::manager::ServerManager;
 use super::backup::BackupManager;
+use super::notifications::{self, NotificationEvent};
+use super::tasks::TaskKind;
+use super::timeline::TimelineKind;
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub enum ScheduleType {
@@ -16,6 +20,21 @@ pub enum ScheduleType {
     Restart,
 }
 
+/// Countdown broadcast to players before a scheduled restart.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RestartWarning {
+    pub delay_secs: u64,
+    /// `{time}` is replaced with the time left; a default message is used when empty.
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+impl RestartWarning {
+    pub fn validate(&self) -> Result<()> {
+        self.message.as_deref().map_or(Ok(()), super::manager::validate_countdown_message)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ScheduledTask {
     pub id: Uuid,
@@ -25,6 +44,9 @@ pub struct ScheduledTask {
     pub enabled: bool,
     pub last_run: Option<DateTime<Utc>>,
     pub next_run: Option<DateTime<Utc>>,
+    /// Restart tasks only; without it the server restarts immediately.
+    #[serde(default)]
+    pub warning: Option<RestartWarning>,
 }
 
 impl ScheduledTask {
@@ -37,8 +59,13 @@ impl ScheduledTask {
             enabled: true,
             last_run: None,
             next_run: None,
+            warning: None,
         }
     }
+
+    pub fn validate(&self) -> Result<()> {
+        self.warning.as_ref().map_or(Ok(()), RestartWarning::validate)
+    }
 }
 
 pub struct SchedulerManager {
@@ -67,6 +94,7 @@ impl SchedulerManager {
         let task_id = task.id;
         let instance_id = task.instance_id;
         let task_type = task.task_type.clone();
+        let warning = task.warning.clone();
         
         let server_manager = Arc::clone(&server_manager_ptr(self));
         let backup_manager = Arc::clone(&backup_manager_ptr(self));
@@ -77,6 +105,7 @@ impl SchedulerManager {
             let backup_manager = Arc::clone(&backup_manager);
             let tasks = Arc::clone(&tasks);
             let task_type = task_type.clone();
+            let warning = warning.clone();
 
             Box::pin(async move {
                 info!("Executing scheduled task {:?} for instance {}", task_type, instance_id);
@@ -85,19 +114,35 @@ impl SchedulerManager {
                     ScheduleType::Backup => {
                         let instance_manager = &server_manager.instance_manager;
                         if let Some(instance) = instance_manager.get_instance(instance_id).await.unwrap_or(None) {
-                            backup_manager.create_backup(
+                            let task = server_manager.tasks.start(TaskKind::Backup, format!("Backing up {}", instance.name), Some(instance_id));
+                            let report = task.reporter();
+                            let result = task.finish(backup_manager.create_backup(
                                 instance_id, 
-                                instance.path, 
+                                &instance.path, 
                                 "scheduled_backup", 
-                                |_, _| {}
-                            ).await.map(|_| ())
+                                move |current, total| report(current, total, format!("Backing up files ({}/{})", current, total))
+                            ).await);
+                            if let Ok(info) = &result {
+                                instance_manager.note_timeline_event(instance_id, TimelineKind::Backup, format!("Scheduled backup {}", info.name), None).await;
+                                notifications::notify(server_manager.cache.get_client(), &instance, NotificationEvent::BackupCompleted { backup: info.name.clone() }).await;
+                            }
+                            result.map(|_| ())
                         } else {
-                            Err(anyhow::anyhow!("Instance not found"))
+                            Err(AppError::NotFound("Instance not found".to_string()).into())
                         }
                     }
-                    ScheduleType::Restart => {
-                        server_manager.restart_server(instance_id).await
-                    }
+                    ScheduleType::Restart => match &warning {
+                        Some(warning) if warning.delay_secs > 0 => {
+                            server_manager
+                                .restart_server_graceful(
+                                    instance_id,
+                                    std::time::Duration::from_secs(warning.delay_secs),
+                                    warning.message.as_deref(),
+                                )
+                                .await
+                        }
+                        _ => server_manager.restart_server(instance_id).await,
+                    },
                 };
 
                 if let Err(e) = result {
@@ -135,6 +180,15 @@ impl SchedulerManager {
         Ok(())
     }
 
+    /// Unschedules every task of an instance; returns how many were removed.
+    pub async fn remove_instance_tasks(&self, instance_id: Uuid) -> Result<usize> {
+        let task_ids: Vec<Uuid> = self.list_tasks(instance_id).await.iter().map(|t| t.id).collect();
+        for task_id in &task_ids {
+            self.remove_task(*task_id).await?;
+        }
+        Ok(task_ids.len())
+    }
+
     pub async fn list_tasks(&self, instance_id: Uuid) -> Vec<ScheduledTask> {
         let tasks_lock = self.tasks.lock().await;
         tasks_lock.values()
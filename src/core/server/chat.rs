@@ -0,0 +1,140 @@
+use super::console::{LogLevel, parse_log_line};
+use regex::Regex;
+use serde::Serialize;
+use std::sync::OnceLock;
+
+/// Something players did that shows up in the log, for chat-focused views.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChatEvent {
+    /// A chat line. Messages sent from the console through `say` have `Server` as the player.
+    ChatMessage {
+        player: String,
+        message: String,
+    },
+    Join {
+        player: String,
+    },
+    Leave {
+        player: String,
+    },
+    /// `message` is the whole death message, e.g. `Steve was slain by Zombie`.
+    Death {
+        player: String,
+        message: String,
+    },
+    Advancement {
+        player: String,
+        advancement: String,
+        kind: AdvancementKind,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdvancementKind {
+    Advancement,
+    Goal,
+    Challenge,
+}
+
+/// Starts of vanilla death messages after the player's name. Most begin with "was", as in
+/// "was slain by", "was shot by" or "was blown up by".
+const DEATH_PHRASES: [&str; 20] = [
+    "was ",
+    "drowned",
+    "died",
+    "fell ",
+    "hit the ground too hard",
+    "blew up",
+    "burned to death",
+    "went up in flames",
+    "went off with a bang",
+    "tried to swim in lava",
+    "starved to death",
+    "suffocated in a wall",
+    "froze to death",
+    "walked into",
+    "experienced kinetic energy",
+    "withered away",
+    "didn't want to live",
+    "discovered the floor was lava",
+    "left the confines of this world",
+    "got finished off by",
+];
+
+fn player_regex(pattern: &str) -> Regex {
+    Regex::new(&pattern.replace("{player}", r"([A-Za-z0-9_.]{1,17})")).unwrap()
+}
+
+/// Reads a chat event from a console line with its color codes already stripped. Only
+/// `INFO` lines are considered, so warnings that quote a player's name aren't taken for
+/// deaths.
+pub fn parse_chat_event(text: &str) -> Option<ChatEvent> {
+    static PREFIX: OnceLock<Regex> = OnceLock::new();
+    static CHAT: OnceLock<Regex> = OnceLock::new();
+    static SAY: OnceLock<Regex> = OnceLock::new();
+    static JOIN: OnceLock<Regex> = OnceLock::new();
+    static LEAVE: OnceLock<Regex> = OnceLock::new();
+    static ADVANCEMENT: OnceLock<Regex> = OnceLock::new();
+    static DEATH: OnceLock<Regex> = OnceLock::new();
+
+    // `[..] [Server thread/INFO]: `, `[.. INFO]: ` or Forge's `[..] [Server thread/INFO] [minecraft/..]: `
+    if parse_log_line(text).0 != Some(LogLevel::Info) {
+        return None;
+    }
+    let prefix = PREFIX.get_or_init(|| Regex::new(r"^\[[^\]]*\](?:\s*\[[^\]]*\])*?\s*:\s").unwrap());
+    let content = &text[prefix.find(text)?.end()..];
+
+    let chat = CHAT.get_or_init(|| player_regex(r"^(?:\[Not Secure\] )?<{player}> (.*)$"));
+    if let Some(caps) = chat.captures(content) {
+        return Some(ChatEvent::ChatMessage {
+            player: caps[1].to_string(),
+            message: caps[2].to_string(),
+        });
+    }
+    let say = SAY.get_or_init(|| Regex::new(r"^\[(Server|Rcon)\] (.*)$").unwrap());
+    if let Some(caps) = say.captures(content) {
+        return Some(ChatEvent::ChatMessage {
+            player: caps[1].to_string(),
+            message: caps[2].to_string(),
+        });
+    }
+    let join = JOIN.get_or_init(|| player_regex(r"^{player} joined the game$"));
+    if let Some(caps) = join.captures(content) {
+        return Some(ChatEvent::Join {
+            player: caps[1].to_string(),
+        });
+    }
+    let leave = LEAVE.get_or_init(|| player_regex(r"^{player} left the game$"));
+    if let Some(caps) = leave.captures(content) {
+        return Some(ChatEvent::Leave {
+            player: caps[1].to_string(),
+        });
+    }
+    let advancement = ADVANCEMENT.get_or_init(|| {
+        player_regex(r"^{player} has (made the advancement|reached the goal|completed the challenge) \[(.+)\]$")
+    });
+    if let Some(caps) = advancement.captures(content) {
+        let kind = match &caps[2] {
+            "reached the goal" => AdvancementKind::Goal,
+            "completed the challenge" => AdvancementKind::Challenge,
+            _ => AdvancementKind::Advancement,
+        };
+        return Some(ChatEvent::Advancement {
+            player: caps[1].to_string(),
+            advancement: caps[3].to_string(),
+            kind,
+        });
+    }
+    let death = DEATH.get_or_init(|| player_regex(r"^{player} (.+)$"));
+    if let Some(caps) = death.captures(content)
+        && DEATH_PHRASES.iter().any(|p| caps[2].starts_with(p))
+    {
+        return Some(ChatEvent::Death {
+            player: caps[1].to_string(),
+            message: content.to_string(),
+        });
+    }
+    None
+}
@@ -0,0 +1,156 @@
+use super::chat::{ChatEvent, parse_chat_event};
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, PoisonError};
+use tokio::sync::broadcast;
+
+mod prefix;
+
+pub use prefix::{LogLevel, parse_log_line, strip_color_codes};
+
+/// Console lines each server keeps in memory for views opened after the lines were printed.
+pub const CONSOLE_BUFFER_LINES: usize = 5000;
+
+/// One line of console output with what could be read from its prefix.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConsoleLine {
+    /// Counts every line since the handle was created, so it keeps increasing after old
+    /// lines fall out of the buffer.
+    pub number: u64,
+    /// As printed, with ANSI escapes and `§` codes left in for views that render colors.
+    pub raw: String,
+    /// Without any color codes, for searching and plain display.
+    pub text: String,
+    pub level: Option<LogLevel>,
+    /// Time of day from the line's prefix, e.g. `14:03:27`.
+    pub time: Option<String>,
+}
+
+impl ConsoleLine {
+    /// Reads the level, time and plain text of `raw`. The number is set once the line is
+    /// added to a [`ConsoleBuffer`].
+    pub fn parse(raw: String) -> Self {
+        let text = strip_color_codes(&raw);
+        let (level, time) = parse_log_line(&text);
+        Self {
+            number: 0,
+            raw,
+            text,
+            level,
+            time,
+        }
+    }
+}
+
+/// Buffered lines starting at a requested line number.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConsolePage {
+    pub lines: Vec<ConsoleLine>,
+    /// Number to ask for next time to get only newer lines.
+    pub next_line: u64,
+    /// Lines before the requested one that had already been dropped from the buffer.
+    pub dropped: u64,
+}
+
+/// Ring buffer of the latest console lines.
+#[derive(Debug, Default)]
+pub struct ConsoleBuffer {
+    lines: VecDeque<ConsoleLine>,
+    next_number: u64,
+}
+
+impl ConsoleBuffer {
+    pub fn push(&mut self, raw: String) -> ConsoleLine {
+        self.push_line(ConsoleLine::parse(raw))
+    }
+
+    /// Adds a line parsed with [`ConsoleLine::parse`], numbering it, and returns it.
+    pub fn push_line(&mut self, mut line: ConsoleLine) -> ConsoleLine {
+        if self.lines.len() >= CONSOLE_BUFFER_LINES {
+            self.lines.pop_front();
+        }
+        line.number = self.next_number;
+        self.next_number += 1;
+        self.lines.push_back(line.clone());
+        line
+    }
+
+    /// Lines numbered `from_line` and up. Pass 0 for everything still buffered.
+    pub fn since(&self, from_line: u64) -> ConsolePage {
+        let first = self.lines.front().map_or(self.next_number, |l| l.number);
+        let start = from_line.max(first);
+        let skip = (start - first) as usize;
+        ConsolePage {
+            lines: self.lines.iter().skip(skip).cloned().collect(),
+            next_line: self.next_number.max(from_line),
+            dropped: first.saturating_sub(from_line),
+        }
+    }
+}
+
+/// Broadcasts console lines to subscribers after recording them in the handle's
+/// [`ConsoleBuffer`], so a line is buffered even when nobody is listening. The buffer is
+/// the only copy of the console kept for replay. Lines also go out numbered, and chat,
+/// joins, deaths and advancements read from them on a channel of their own.
+#[derive(Debug, Clone)]
+pub struct LogSender {
+    sender: broadcast::Sender<String>,
+    lines: broadcast::Sender<ConsoleLine>,
+    buffer: Arc<Mutex<ConsoleBuffer>>,
+    chat: broadcast::Sender<ChatEvent>,
+}
+
+impl LogSender {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            sender: broadcast::channel(capacity).0,
+            lines: broadcast::channel(capacity).0,
+            buffer: Arc::new(Mutex::new(ConsoleBuffer::default())),
+            chat: broadcast::channel(capacity).0,
+        }
+    }
+
+    pub fn send(&self, line: String) -> Result<usize, broadcast::error::SendError<String>> {
+        // Parsed before taking the lock, which readers of the buffer wait on
+        let parsed = ConsoleLine::parse(line.clone());
+        let event = parse_chat_event(&parsed.text);
+        let numbered = self.buffer().push_line(parsed);
+        let _ = self.lines.send(numbered);
+        if let Some(event) = event {
+            let _ = self.chat.send(event);
+        }
+        self.sender.send(line)
+    }
+
+    fn buffer(&self) -> std::sync::MutexGuard<'_, ConsoleBuffer> {
+        self.buffer.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Sends a chat event that has no console line of its own, such as a `tellraw`.
+    pub fn send_chat(&self, event: ChatEvent) {
+        let _ = self.chat.send(event);
+    }
+
+    pub fn subscribe_chat(&self) -> broadcast::Receiver<ChatEvent> {
+        self.chat.subscribe()
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<String> {
+        self.sender.subscribe()
+    }
+
+    /// Lines as they are buffered, with their number and parsed prefix.
+    pub fn subscribe_lines(&self) -> broadcast::Receiver<ConsoleLine> {
+        self.lines.subscribe()
+    }
+
+    pub fn buffered_since(&self, from_line: u64) -> ConsolePage {
+        self.buffer().since(from_line)
+    }
+
+    /// Chat events in the buffered lines, oldest first.
+    pub fn buffered_chat(&self) -> Vec<ChatEvent> {
+        let texts: Vec<String> = self.buffer().lines.iter().map(|l| l.text.clone()).collect();
+        texts.iter().filter_map(|text| parse_chat_event(text)).collect()
+    }
+}
@@ -0,0 +1,61 @@
+//! What can be read from a console line's prefix: its level and time of day.
+
+use regex::Regex;
+use serde::Serialize;
+use std::sync::OnceLock;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+fn color_code_regex() -> &'static Regex {
+    static COLOR_CODES: OnceLock<Regex> = OnceLock::new();
+    COLOR_CODES.get_or_init(|| Regex::new(r"\x1B(?:[@-Z\\-_]|\[[0-?]*[ -/]*[@-~])|§[0-9a-fk-orx]").unwrap())
+}
+
+/// Removes ANSI escapes and Minecraft `§` formatting codes.
+pub fn strip_color_codes(line: &str) -> String {
+    color_code_regex().replace_all(line, "").into_owned()
+}
+
+/// Reads the level and time from the prefixes used by vanilla (`[14:03:27] [Server thread/INFO]:`),
+/// Bukkit (`[14:03:27 INFO]:`) and Forge (`[12Mar2024 14:03:27.123] [Server thread/INFO]`), and
+/// from the wrapper's own `ERROR:` and `CRASH:` lines.
+pub fn parse_log_line(text: &str) -> (Option<LogLevel>, Option<String>) {
+    static PREFIX: OnceLock<Regex> = OnceLock::new();
+    let prefix = PREFIX.get_or_init(|| {
+        Regex::new(
+            r"^\[(?:[^\]]*?\s)?(\d{2}:\d{2}:\d{2})(?:\.\d+)?(?:\s+(?P<inline>[A-Z]+))?\](?:\s*\[[^\]]*?/(?P<thread>[A-Z]+)\])?",
+        )
+        .unwrap()
+    });
+    if let Some(caps) = prefix.captures(text) {
+        let time = caps.get(1).map(|m| m.as_str().to_string());
+        let level = caps
+            .name("thread")
+            .or_else(|| caps.name("inline"))
+            .and_then(|m| level_from_name(m.as_str()));
+        return (level, time);
+    }
+    if text.starts_with("ERROR:") || text.starts_with("CRASH:") {
+        return (Some(LogLevel::Error), None);
+    }
+    (None, None)
+}
+
+fn level_from_name(name: &str) -> Option<LogLevel> {
+    match name {
+        "TRACE" => Some(LogLevel::Trace),
+        "DEBUG" => Some(LogLevel::Debug),
+        "INFO" => Some(LogLevel::Info),
+        "WARN" | "WARNING" => Some(LogLevel::Warn),
+        "ERROR" | "SEVERE" | "FATAL" => Some(LogLevel::Error),
+        _ => None,
+    }
+}
@@ -1,5 +1,8 @@
 use super::super::config::ServerConfig;
-use super::types::{ProgressPayload, ResourceUsage, ServerStatus};
+use super::chat::ChatEvent;
+use super::console::{ConsoleLine, ConsolePage, LogSender};
+use super::status::StatusCell;
+use super::types::{ProgressPayload, ResourceUsage, ServerEvent, ServerStatus, StartupProgress};
 use std::collections::HashSet;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU32, Ordering};
@@ -14,28 +17,38 @@ pub struct ServerHandle {
     pub(crate) config: Arc<Mutex<ServerConfig>>,
     pub(crate) child: Arc<Mutex<Option<Child>>>,
     pub(crate) stdin: Arc<Mutex<Option<ChildStdin>>>,
-    pub(crate) status: Arc<Mutex<ServerStatus>>,
+    pub(crate) status: Arc<StatusCell>,
     pub(crate) usage: Arc<Mutex<ResourceUsage>>,
     pub(crate) online_players: Arc<Mutex<HashSet<String>>>,
-    pub(crate) log_sender: broadcast::Sender<String>,
+    pub(crate) log_sender: LogSender,
     pub(crate) progress_sender: broadcast::Sender<ProgressPayload>,
+    pub(crate) startup_sender: broadcast::Sender<StartupProgress>,
+    pub(crate) event_sender: broadcast::Sender<ServerEvent>,
     pub(crate) start_time: Arc<Mutex<Option<Instant>>>,
+    /// PID of the running server process. The lifecycle loop owns the `Child` while
+    /// waiting on it, so forced kills go through the PID instead.
+    pub(crate) pid: Arc<Mutex<Option<u32>>>,
 }
 
 impl ServerHandle {
     pub fn new(config: ServerConfig) -> Self {
-        let (log_sender, _) = broadcast::channel(100);
+        let log_sender = LogSender::new(100);
         let (progress_sender, _) = broadcast::channel(10);
+        let (startup_sender, _) = broadcast::channel(32);
+        let (event_sender, _) = broadcast::channel(16);
         Self {
             config: Arc::new(Mutex::new(config)),
             child: Arc::new(Mutex::new(None)),
             stdin: Arc::new(Mutex::new(None)),
-            status: Arc::new(Mutex::new(ServerStatus::Stopped)),
+            status: Arc::new(StatusCell::new(ServerStatus::Stopped, event_sender.clone())),
             usage: Arc::new(Mutex::new(ResourceUsage::default())),
             online_players: Arc::new(Mutex::new(HashSet::new())),
             log_sender,
             progress_sender,
+            startup_sender,
+            event_sender,
             start_time: Arc::new(Mutex::new(None)),
+            pid: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -68,10 +81,41 @@ impl ServerHandle {
         self.log_sender.subscribe()
     }
 
+    /// Console lines numbered as in [`ServerHandle::get_console_buffer`], so a view that
+    /// replayed the buffer can tell which live lines it already has.
+    pub fn subscribe_console(&self) -> broadcast::Receiver<ConsoleLine> {
+        self.log_sender.subscribe_lines()
+    }
+
+    /// Chat, joins, leaves, deaths and advancements read from the console.
+    pub fn subscribe_chat(&self) -> broadcast::Receiver<ChatEvent> {
+        self.log_sender.subscribe_chat()
+    }
+
     pub fn subscribe_progress(&self) -> broadcast::Receiver<ProgressPayload> {
         self.progress_sender.subscribe()
     }
 
+    pub fn subscribe_startup(&self) -> broadcast::Receiver<StartupProgress> {
+        self.startup_sender.subscribe()
+    }
+
+    pub fn subscribe_events(&self) -> broadcast::Receiver<ServerEvent> {
+        self.event_sender.subscribe()
+    }
+
+    /// Buffered console lines numbered `from_line` and up, for views opened after they were
+    /// printed.
+    pub fn get_console_buffer(&self, from_line: u64) -> ConsolePage {
+        self.log_sender.buffered_since(from_line)
+    }
+
+    /// Chat events among the buffered console lines, for chat views opened after they happened.
+    /// Messages sent with `tellraw` aren't logged, so they only reach live subscribers.
+    pub fn get_recent_chat(&self) -> Vec<ChatEvent> {
+        self.log_sender.buffered_chat()
+    }
+
     pub fn emit_log(&self, line: String) {
         let _ = self.log_sender.send(line);
     }
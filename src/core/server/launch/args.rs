@@ -0,0 +1,63 @@
+use crate::config::ServerConfig;
+use anyhow::{Result, anyhow};
+use tokio::process::Command;
+
+/// JVM flags the Forge installer leaves for the user to edit, next to run.sh.
+pub(super) const USER_JVM_ARGS_FILE: &str = "user_jvm_args.txt";
+
+/// `java` with `leading` args, the wrapper's memory and console flags, and the
+/// instance's JVM options, ready for the main class or jar to be appended.
+pub(crate) fn java_command(config: &ServerConfig, leading: &[&str]) -> Command {
+    let java_cmd = config
+        .java_path
+        .as_ref()
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(|| "java".to_string());
+
+    let mut c = Command::new(java_cmd);
+    c.args(leading)
+        .arg(format!("-Xmx{}", config.max_memory))
+        .arg(format!("-Xms{}", config.min_memory))
+        .arg("-Dterminal.jline=false")
+        .arg("-Dterminal.ansi=true")
+        .arg("-Dlog4j.skipJansi=false")
+        .args(&config.jvm_args);
+    c
+}
+
+/// Splits a command line into arguments, honouring single and double quotes so paths
+/// with spaces survive. No shell is involved, so variables and pipes are not expanded.
+pub fn split_command_line(line: &str) -> Result<Vec<String>> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut in_arg = false;
+    let mut quote: Option<char> = None;
+
+    for ch in line.chars() {
+        match quote {
+            Some(q) if ch == q => quote = None,
+            Some(_) => current.push(ch),
+            None if ch == '"' || ch == '\'' => {
+                quote = Some(ch);
+                in_arg = true;
+            }
+            None if ch.is_whitespace() => {
+                if in_arg {
+                    args.push(std::mem::take(&mut current));
+                    in_arg = false;
+                }
+            }
+            None => {
+                current.push(ch);
+                in_arg = true;
+            }
+        }
+    }
+    if quote.is_some() {
+        return Err(anyhow!("Unterminated quote in command: {}", line));
+    }
+    if in_arg {
+        args.push(current);
+    }
+    Ok(args)
+}
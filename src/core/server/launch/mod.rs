@@ -0,0 +1,161 @@
+use crate::config::ServerConfig;
+use anyhow::{Result, anyhow};
+use tokio::process::Command;
+
+mod args;
+
+pub(crate) use args::java_command;
+pub use args::split_command_line;
+use args::USER_JVM_ARGS_FILE;
+
+/// How a server process is spawned. The lifecycle loop sets the working directory,
+/// stdio pipes and process group on the returned command, so every strategy gets the
+/// same console capture, crash handling and stop behaviour.
+pub trait LaunchStrategy: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// Builds the program and arguments for `config`.
+    fn command(&self, config: &ServerConfig) -> Result<Command>;
+}
+
+/// `java ... -jar <jar> <args>` with the wrapper's memory and console flags.
+pub struct JarLaunch;
+
+/// `java ... @libraries/.../unix_args.txt <args>` for modern Forge and NeoForge, the
+/// same command their run scripts build, but with the wrapper's memory settings.
+pub struct ArgfileLaunch;
+
+/// A run.bat/run.sh script, as shipped by imported servers and modern Forge.
+pub struct ScriptLaunch;
+
+/// A native server binary, i.e. Bedrock Dedicated Server.
+pub struct BedrockLaunch;
+
+/// A complete command line from the instance settings, run as-is.
+pub struct CustomCommandLaunch;
+
+impl LaunchStrategy for JarLaunch {
+    fn name(&self) -> &'static str {
+        "jar"
+    }
+
+    fn command(&self, config: &ServerConfig) -> Result<Command> {
+        let mut c = java_command(config, &[]);
+        if let Some(jar_path) = &config.jar_path {
+            c.arg("-jar").arg(jar_path);
+        }
+        c.args(&config.args);
+        Ok(c)
+    }
+}
+
+impl LaunchStrategy for ArgfileLaunch {
+    fn name(&self) -> &'static str {
+        "argfile"
+    }
+
+    fn command(&self, config: &ServerConfig) -> Result<Command> {
+        let argfile = config
+            .argfile
+            .as_ref()
+            .ok_or_else(|| anyhow!("No Forge argument file configured"))?;
+
+        // user_jvm_args.txt comes first so the instance's memory settings override it
+        let user_args = config.working_dir.join(USER_JVM_ARGS_FILE);
+        let leading: &[&str] = if user_args.is_file() {
+            &["@user_jvm_args.txt"]
+        } else {
+            &[]
+        };
+        let mut c = java_command(config, leading);
+        c.arg(format!("@{}", argfile.to_string_lossy()));
+        c.args(&config.args);
+        Ok(c)
+    }
+}
+
+impl LaunchStrategy for ScriptLaunch {
+    fn name(&self) -> &'static str {
+        "script"
+    }
+
+    fn command(&self, config: &ServerConfig) -> Result<Command> {
+        let script = config
+            .run_script
+            .as_ref()
+            .ok_or_else(|| anyhow!("No run script configured"))?;
+
+        #[cfg(target_os = "windows")]
+        let mut c = {
+            let mut c = Command::new("cmd");
+            c.arg("/c").arg(script);
+            c
+        };
+        #[cfg(not(target_os = "windows"))]
+        let mut c = {
+            let mut c = Command::new("sh");
+            c.arg(script);
+            c
+        };
+        c.args(&config.args);
+        Ok(c)
+    }
+}
+
+impl LaunchStrategy for BedrockLaunch {
+    fn name(&self) -> &'static str {
+        "bedrock"
+    }
+
+    fn command(&self, config: &ServerConfig) -> Result<Command> {
+        let binary = config
+            .jar_path
+            .as_ref()
+            .ok_or_else(|| anyhow!("No server binary configured"))?;
+        let mut c = Command::new(binary);
+        c.args(&config.args);
+        Ok(c)
+    }
+}
+
+impl LaunchStrategy for CustomCommandLaunch {
+    fn name(&self) -> &'static str {
+        "custom"
+    }
+
+    fn command(&self, config: &ServerConfig) -> Result<Command> {
+        let line = config
+            .custom_command
+            .as_deref()
+            .ok_or_else(|| anyhow!("No custom command configured"))?;
+        let mut parts = split_command_line(line)?.into_iter();
+        let program = parts.next().ok_or_else(|| anyhow!("The custom command is empty"))?;
+        let mut c = Command::new(program);
+        c.args(parts);
+        Ok(c)
+    }
+}
+
+/// Picks the strategy for a config: a custom command wins, then a Forge argfile, then a
+/// run script, then a native binary (anything that isn't a jar), and finally a plain jar.
+pub fn select_strategy(config: &ServerConfig) -> Box<dyn LaunchStrategy> {
+    if config.custom_command.is_some() {
+        return Box::new(CustomCommandLaunch);
+    }
+    if config.argfile.is_some() {
+        return Box::new(ArgfileLaunch);
+    }
+    if config.run_script.is_some() {
+        return Box::new(ScriptLaunch);
+    }
+    let is_jar = config
+        .jar_path
+        .as_ref()
+        .map(|p| p.to_string_lossy().to_lowercase().ends_with(".jar"))
+        .unwrap_or(true);
+    if is_jar {
+        Box::new(JarLaunch)
+    } else {
+        Box::new(BedrockLaunch)
+    }
+}
@@ -1,7 +1,14 @@
 pub mod types;
+pub mod chat;
+pub mod console;
 pub mod handle;
+pub mod launch;
 pub mod ops;
+pub mod status;
 
 pub use types::*;
+pub use chat::*;
+pub use console::*;
 pub use handle::*;
 pub use ops::*;
+pub use status::*;
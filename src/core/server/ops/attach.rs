@@ -0,0 +1,149 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use sysinfo::{Pid, ProcessesToUpdate, System};
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+use tracing::info;
+
+use super::super::console::LogSender;
+use super::super::handle::ServerHandle;
+use super::super::types::ServerStatus;
+use super::detached::console_log_path;
+
+/// File (relative to the server's working directory) recording the running process.
+pub const PID_FILE: &str = ".server.pid";
+
+/// Identifies a server process that may outlive the wrapper.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PidFile {
+    pub pid: u32,
+    /// Process start time (seconds since the epoch), used to detect PID reuse.
+    pub process_start: u64,
+}
+
+impl PidFile {
+    pub fn path(working_dir: &Path) -> PathBuf {
+        working_dir.join(PID_FILE)
+    }
+
+    /// Records `pid` as the server process running in `working_dir`.
+    pub async fn write(working_dir: &Path, pid: u32) -> Result<()> {
+        let process_start = process_start_time(pid).unwrap_or_default();
+        let content = serde_json::to_string(&PidFile { pid, process_start })?;
+        tokio::fs::write(Self::path(working_dir), content)
+            .await
+            .context("Failed to write server PID file")
+    }
+
+    pub async fn read(working_dir: &Path) -> Option<PidFile> {
+        let content = tokio::fs::read_to_string(Self::path(working_dir)).await.ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    pub async fn remove(working_dir: &Path) {
+        let _ = tokio::fs::remove_file(Self::path(working_dir)).await;
+    }
+
+    /// Returns true if the recorded process is still the one that was started.
+    pub fn is_alive(&self) -> bool {
+        match process_start_time(self.pid) {
+            Some(start) => self.process_start == 0 || start == self.process_start,
+            None => false,
+        }
+    }
+}
+
+fn process_start_time(pid: u32) -> Option<u64> {
+    let mut sys = System::new();
+    let pid = Pid::from(pid as usize);
+    sys.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+    sys.process(pid).map(|p| p.start_time())
+}
+
+impl ServerHandle {
+    /// Takes over a server process started by a previous wrapper session.
+    ///
+    /// The console is followed through the console log the detached server writes, or
+    /// `logs/latest.log` for servers started without one. The original stdin pipe is
+    /// gone, so commands cannot be sent and stopping falls back to terminating the process.
+    pub async fn attach(&self, pid: u32) {
+        let working_dir = self.config.lock().await.working_dir.clone();
+
+        let mut sys = System::new();
+        let sys_pid = Pid::from(pid as usize);
+        sys.refresh_processes(ProcessesToUpdate::Some(&[sys_pid]), true);
+        let run_time = sys.process(sys_pid).map(|p| p.run_time()).unwrap_or(0);
+
+        *self.status.lock().await = ServerStatus::Running;
+        *self.pid.lock().await = Some(pid);
+        *self.start_time.lock().await =
+            Instant::now().checked_sub(Duration::from_secs(run_time));
+
+        let msg = format!("Reattached to running server process (PID {})", pid);
+        info!("{}", msg);
+        self.emit_log(msg);
+
+        let monitor_handle = tokio::spawn(Self::monitor_resources(
+            pid,
+            Arc::clone(&self.usage),
+            Arc::clone(&self.start_time),
+            Arc::clone(&self.online_players),
+        ));
+        let console_log = console_log_path(&working_dir);
+        let log_path = if tokio::fs::try_exists(&console_log).await.unwrap_or(false) {
+            console_log
+        } else {
+            working_dir.join("logs").join("latest.log")
+        };
+        let tail_handle = tokio::spawn(Self::tail_log(log_path, self.log_sender.clone()));
+
+        let handle = self.clone();
+        tokio::spawn(async move {
+            while process_start_time(pid).is_some() {
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+            monitor_handle.abort();
+            tail_handle.abort();
+
+            *handle.status.lock().await = ServerStatus::Stopped;
+            *handle.pid.lock().await = None;
+            *handle.start_time.lock().await = None;
+            handle.online_players.lock().await.clear();
+            PidFile::remove(&working_dir).await;
+            handle.emit_log("Server process exited.".to_string());
+        });
+    }
+
+    /// Follows a log file from its current end, emitting every appended line.
+    async fn tail_log(path: PathBuf, log_sender: LogSender) {
+        let mut offset = tokio::fs::metadata(&path).await.map(|m| m.len()).unwrap_or(0);
+        let mut pending = String::new();
+        loop {
+            tokio::time::sleep(Duration::from_millis(500)).await;
+            let Ok(mut file) = tokio::fs::File::open(&path).await else {
+                continue;
+            };
+            let len = file.metadata().await.map(|m| m.len()).unwrap_or(0);
+            if len < offset {
+                // The log was rotated or truncated
+                offset = 0;
+            }
+            if len == offset || file.seek(std::io::SeekFrom::Start(offset)).await.is_err() {
+                continue;
+            }
+
+            let mut buf = Vec::new();
+            if file.read_to_end(&mut buf).await.is_err() {
+                continue;
+            }
+            offset += buf.len() as u64;
+            pending.push_str(&String::from_utf8_lossy(&buf));
+            while let Some(pos) = pending.find('\n') {
+                let line: String = pending.drain(..=pos).collect();
+                let _ = log_sender.send(line.trim_end().to_string());
+            }
+        }
+    }
+}
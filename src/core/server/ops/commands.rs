@@ -1,7 +1,21 @@
 use super::super::handle::ServerHandle;
+use crate::errors::AppError;
+use crate::server::chat::ChatEvent;
 use crate::server::types::ServerStatus;
+use crate::server_list::format::{MotdFormat, convert_motd, strip_formatting};
 use anyhow::{Result, anyhow};
+use serde_json::{Value, json};
+use std::time::{Duration, Instant};
 use tokio::io::AsyncWriteExt;
+use tokio::sync::broadcast;
+
+/// Whether `message` has `&` or `§` formatting codes that only `tellraw` would render.
+fn has_color_codes(message: &str) -> bool {
+    let chars: Vec<char> = message.chars().collect();
+    chars
+        .windows(2)
+        .any(|w| (w[0] == '&' || w[0] == '§') && "0123456789abcdefklmnor".contains(w[1]))
+}
 
 impl ServerHandle {
     pub async fn send_command(&self, command: &str) -> Result<()> {
@@ -31,4 +45,61 @@ impl ServerHandle {
             Err(anyhow!("Server is not running or stdin is unavailable"))
         }
     }
+
+    /// Broadcasts `message` to every player as the server. Plain text goes through `say`,
+    /// whose output the chat stream picks up from the log. Text with `&` or `§` color codes
+    /// is sent with `tellraw` so the colors show, and since `tellraw` isn't logged its chat
+    /// event is sent directly.
+    pub async fn send_chat(&self, message: &str) -> Result<()> {
+        let message = message.trim();
+        if message.is_empty() || message.contains(['\n', '\r']) {
+            return Err(AppError::Validation("Chat messages must be a single line of text".to_string()).into());
+        }
+        if self.get_status().await != ServerStatus::Running {
+            return Err(AppError::Conflict("Start the server before sending chat".to_string()).into());
+        }
+        let server_type = self.config.lock().await.server_type.clone();
+        if matches!(server_type.as_deref(), Some("bungeecord" | "velocity")) {
+            return Err(anyhow!("Proxies have no chat of their own; send the message from a backend server"));
+        }
+
+        if !has_color_codes(message) {
+            return self.send_command(&format!("say {}", message)).await;
+        }
+        let component: Value = serde_json::from_str(&convert_motd(message, MotdFormat::Legacy, MotdFormat::Json)?)?;
+        let tellraw = json!(["", { "text": "[Server] ", "color": "light_purple" }, component]);
+        self.send_command(&format!("tellraw @a {}", tellraw)).await?;
+        self.log_sender.send_chat(ChatEvent::ChatMessage {
+            player: "Server".to_string(),
+            message: strip_formatting(message, MotdFormat::Legacy)?,
+        });
+        Ok(())
+    }
+
+    /// Sends a command and collects the console lines that follow it until the
+    /// output has been quiet for `quiet` or `max_wait` has elapsed.
+    pub async fn capture_command_output(
+        &self,
+        command: &str,
+        quiet: Duration,
+        max_wait: Duration,
+    ) -> Result<Vec<String>> {
+        let mut rx = self.subscribe_logs();
+        self.send_command(command).await?;
+
+        let deadline = Instant::now() + max_wait;
+        let mut lines = Vec::new();
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                break;
+            }
+            match tokio::time::timeout(quiet.min(remaining), rx.recv()).await {
+                Ok(Ok(line)) => lines.push(line),
+                Ok(Err(broadcast::error::RecvError::Lagged(_))) => continue,
+                Ok(Err(broadcast::error::RecvError::Closed)) | Err(_) => break,
+            }
+        }
+        Ok(lines)
+    }
 }
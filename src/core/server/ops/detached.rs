@@ -0,0 +1,91 @@
+//! Servers kept running after the app closes write their console to a file: pipes would
+//! break once the app exits. The wrapper reads the console back from that file.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use std::pin::Pin;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeekExt, AsyncWriteExt, DuplexStream};
+use tokio::process::{Child, Command};
+
+use crate::config::ServerConfig;
+
+/// Console output of a detached server, relative to its working directory.
+pub const CONSOLE_LOG_FILE: &str = "logs/wrapper-console.log";
+const FOLLOW_INTERVAL: Duration = Duration::from_millis(200);
+
+pub fn console_log_path(working_dir: &Path) -> PathBuf {
+    working_dir.join(CONSOLE_LOG_FILE)
+}
+
+/// Sends the server's output to pipes, or for a detached server to a fresh console log.
+pub(crate) async fn configure_output(cmd: &mut Command, config: &ServerConfig) -> Result<()> {
+    cmd.stdin(Stdio::piped());
+    if !config.detached {
+        cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+        return Ok(());
+    }
+
+    let path = console_log_path(&config.working_dir);
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let file = tokio::fs::File::create(&path)
+        .await
+        .with_context(|| format!("Failed to create console log {:?}", path))?
+        .into_std()
+        .await;
+    cmd.stdout(file.try_clone()?).stderr(file);
+    Ok(())
+}
+
+/// Where the server's console is read from: its stdout pipe, or else the console log it
+/// writes. Setting the returned flag once the server has exited ends the log reader.
+pub(crate) fn console_reader(
+    child: &mut Child,
+    config: &ServerConfig,
+) -> (Pin<Box<dyn AsyncRead + Send>>, Arc<AtomicBool>) {
+    let exited = Arc::new(AtomicBool::new(false));
+    if let Some(stdout) = child.stdout.take() {
+        return (Box::pin(stdout), exited);
+    }
+    let (writer, reader) = tokio::io::duplex(64 * 1024);
+    tokio::spawn(follow_console_log(
+        console_log_path(&config.working_dir),
+        writer,
+        Arc::clone(&exited),
+    ));
+    (Box::pin(reader), exited)
+}
+
+/// Copies what the server appends to its console log into `out`, until `exited` is set
+/// and the rest of the file has been read. Dropping `out` then ends the reader.
+async fn follow_console_log(
+    path: PathBuf,
+    mut out: DuplexStream,
+    exited: Arc<AtomicBool>,
+) {
+    let mut offset = 0;
+    loop {
+        // Checked before reading, so output written just before the exit isn't lost
+        let done = exited.load(Ordering::Acquire);
+        if let Ok(mut file) = tokio::fs::File::open(&path).await
+            && file.seek(std::io::SeekFrom::Start(offset)).await.is_ok()
+        {
+            let mut buf = Vec::new();
+            if let Ok(read) = file.read_to_end(&mut buf).await {
+                offset += read as u64;
+                if out.write_all(&buf).await.is_err() {
+                    return;
+                }
+            }
+        }
+        if done {
+            return;
+        }
+        tokio::time::sleep(FOLLOW_INTERVAL).await;
+    }
+}
@@ -0,0 +1,64 @@
+//! Pre-start and post-stop hook scripts, with their output sent to the console.
+
+use std::time::Duration;
+
+use anyhow::Result;
+
+use crate::config::ServerConfig;
+use crate::server::console::LogSender;
+use crate::shell::{run_streamed, shell_command};
+
+/// How long a hook may run before it is killed.
+pub const HOOK_TIMEOUT: Duration = Duration::from_secs(300);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hook {
+    PreStart,
+    PostStop,
+}
+
+impl Hook {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::PreStart => "pre-start",
+            Self::PostStop => "post-stop",
+        }
+    }
+
+    fn command<'a>(&self, config: &'a ServerConfig) -> Option<&'a str> {
+        match self {
+            Self::PreStart => config.pre_start_hook.as_deref(),
+            Self::PostStop => config.post_stop_hook.as_deref(),
+        }
+    }
+}
+
+/// Runs `hook` in the server's working folder with its environment variables, sending
+/// each output line to `log_sender`. Does nothing when the hook isn't set, and skips it
+/// with a note in the console while the terminal is turned off.
+pub async fn run_hook(hook: Hook, config: &ServerConfig, log_sender: &LogSender) -> Result<()> {
+    let Some(command) = hook.command(config) else {
+        return Ok(());
+    };
+    let prefix = format!("[{}]", hook.label());
+    if !config.hooks_enabled {
+        let _ = log_sender.send(format!(
+            "{} Skipped: hooks only run while the terminal is enabled in the app settings",
+            prefix
+        ));
+        return Ok(());
+    }
+    let _ = log_sender.send(format!("{} $ {}", prefix, command));
+
+    let mut cmd = shell_command(command, &config.working_dir);
+    cmd.envs(config.env.iter().map(|(k, v)| (k, v)));
+    run_streamed(
+        cmd,
+        HOOK_TIMEOUT,
+        || false,
+        |line| {
+            let _ = log_sender.send(format!("{} {}", prefix, line));
+        },
+    )
+    .await
+}
@@ -0,0 +1,98 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::io::AsyncWriteExt;
+use tokio::process::ChildStdin;
+use tokio::sync::{Mutex, broadcast};
+use tracing::{info, warn};
+
+use super::super::console::LogSender;
+use super::super::handle::ServerHandle;
+use super::super::status::StatusCell;
+use super::super::types::{ServerEvent, ServerStatus};
+use super::lifecycle::stop::{force_kill, stop_command};
+use crate::config::ServerConfig;
+
+/// Tracks how long a running server has gone without (non-exempt) players.
+#[derive(Debug)]
+pub struct IdleTracker {
+    timeout: Duration,
+    idle_since: Option<Instant>,
+}
+
+impl IdleTracker {
+    pub fn new(timeout: Duration) -> Self {
+        Self { timeout, idle_since: None }
+    }
+
+    /// Returns `true` once the server has been running and empty for the full timeout.
+    /// Any period where it isn't running, or someone is online, restarts the clock.
+    pub fn observe(&mut self, running: bool, active_players: usize, now: Instant) -> bool {
+        if !running || active_players > 0 {
+            self.idle_since = None;
+            return false;
+        }
+        let since = *self.idle_since.get_or_insert(now);
+        now.duration_since(since) >= self.timeout
+    }
+}
+
+/// Online players that keep the server alive, ignoring the exempt names (case-insensitive).
+pub fn active_player_count(online: &HashSet<String>, exempt: &[String]) -> usize {
+    online
+        .iter()
+        .filter(|p| !exempt.iter().any(|e| e.eq_ignore_ascii_case(p)))
+        .count()
+}
+
+/// Checks often enough to stop close to the deadline, but no more than every 15 seconds.
+fn check_interval(timeout_secs: u64) -> Duration {
+    Duration::from_secs((timeout_secs / 4).clamp(1, 15))
+}
+
+impl ServerHandle {
+    /// Gracefully stops the server once nobody has been online for `config.idle_timeout`
+    /// seconds, then kills it if it hasn't exited within the stop timeout.
+    pub(crate) async fn idle_watchdog(
+        pid: u32,
+        config: ServerConfig,
+        stdin_arc: Arc<Mutex<Option<ChildStdin>>>,
+        status_arc: Arc<StatusCell>,
+        online_players_arc: Arc<Mutex<HashSet<String>>>,
+        log_sender: LogSender,
+        event_sender: broadcast::Sender<ServerEvent>,
+    ) {
+        let mut tracker = IdleTracker::new(Duration::from_secs(config.idle_timeout));
+        loop {
+            tokio::time::sleep(check_interval(config.idle_timeout)).await;
+            let running = *status_arc.lock().await == ServerStatus::Running;
+            let active = active_player_count(&*online_players_arc.lock().await, &config.idle_exempt_players);
+            if tracker.observe(running, active, Instant::now()) {
+                break;
+            }
+        }
+
+        {
+            let mut status = status_arc.lock().await;
+            if *status != ServerStatus::Running {
+                return;
+            }
+            *status = ServerStatus::Stopping;
+        }
+        let msg = format!("No players online for {} minutes. Stopping the server.", config.idle_timeout / 60);
+        info!("{}: {}", config.name, msg);
+        let _ = log_sender.send(msg);
+        let _ = event_sender.send(ServerEvent::AutoStopped { idle_secs: config.idle_timeout });
+
+        let command = format!("{}\n", stop_command(config.server_type.as_deref()));
+        if let Some(stdin) = stdin_arc.lock().await.as_mut() {
+            let _ = stdin.write_all(command.as_bytes()).await;
+            let _ = stdin.flush().await;
+        }
+
+        // The lifecycle loop aborts this task once the process exits
+        tokio::time::sleep(Duration::from_secs(config.stop_timeout)).await;
+        warn!("Server failed to exit after idle stop. Killing process.");
+        force_kill(None, Some(pid)).await;
+    }
+}
@@ -0,0 +1,35 @@
+use anyhow::{Context, Result};
+use tokio::process::Command;
+
+use crate::config::ServerConfig;
+use crate::server::launch::select_strategy;
+use crate::server::ops::detached::configure_output;
+use crate::server::ops::limits;
+
+/// Builds the server's launch command with its working directory, environment, output
+/// and process flags set.
+pub(crate) async fn launch_command(config: &ServerConfig) -> Result<Command> {
+    let strategy = select_strategy(config);
+    let mut cmd = strategy
+        .command(config)
+        .with_context(|| format!("Failed to build {} launch command", strategy.name()))?;
+    cmd.current_dir(&config.working_dir)
+        .envs(config.env.iter().map(|(k, v)| (k, v)));
+    configure_output(&mut cmd, config).await?;
+
+    #[cfg(target_os = "windows")]
+    {
+        // CREATE_NO_WINDOW, plus CREATE_NEW_PROCESS_GROUP when the server should outlive the app
+        let group = if config.detached { 0x00000200 } else { 0 };
+        let priority = limits::prepare_command(&mut cmd, &config.resource_limits);
+        cmd.creation_flags(0x08000000 | group | priority);
+    }
+    #[cfg(unix)]
+    {
+        if config.detached {
+            cmd.process_group(0);
+        }
+        limits::prepare_command(&mut cmd, &config.resource_limits);
+    }
+    Ok(cmd)
+}
@@ -0,0 +1,51 @@
+use std::collections::HashSet;
+use std::process::ExitStatus;
+use std::sync::Arc;
+use tokio::process::ChildStdin;
+use tokio::sync::Mutex;
+use tracing::{error, info};
+
+use crate::config::ServerConfig;
+use crate::instance::CrashHandlingMode;
+use crate::server::console::LogSender;
+use crate::server::handle::ServerHandle;
+use crate::server::status::StatusCell;
+use crate::server::types::ServerStatus;
+
+impl ServerHandle {
+    /// Records how the server process ended and returns whether crash handling should
+    /// restart it.
+    pub(crate) async fn settle_exit(
+        exit_status: &std::io::Result<ExitStatus>,
+        config_arc: &Mutex<ServerConfig>,
+        status_arc: &StatusCell,
+        stdin_arc: &Mutex<Option<ChildStdin>>,
+        online_players_arc: &Arc<Mutex<HashSet<String>>>,
+        log_sender: &LogSender,
+    ) -> bool {
+        let mut status = status_arc.lock().await;
+        let exited_cleanly = exit_status.as_ref().map(|s| s.success()).unwrap_or(false);
+        *stdin_arc.lock().await = None;
+        online_players_arc.lock().await.clear();
+
+        if *status == ServerStatus::Stopping || *status == ServerStatus::Stopped || exited_cleanly {
+            info!("Server stopped gracefully.");
+            *status = ServerStatus::Stopped;
+            return false;
+        }
+
+        let exit_msg = match exit_status {
+            Ok(s) => format!("Server process exited unexpectedly with status: {}", s),
+            Err(e) => format!("Error waiting for server process: {}", e),
+        };
+        error!("{}", exit_msg);
+        let _ = log_sender.send(format!("CRASH: {}", exit_msg));
+        *status = ServerStatus::Crashed;
+
+        match config_arc.lock().await.crash_handling {
+            CrashHandlingMode::Nothing => false,
+            CrashHandlingMode::Elevated => exit_status.as_ref().map(|s| !s.success()).unwrap_or(true),
+            CrashHandlingMode::Aggressive => true,
+        }
+    }
+}
@@ -1,44 +1,61 @@
 use std::collections::HashSet;
-use std::process::Stdio;
 use std::sync::Arc;
+use std::sync::atomic::Ordering;
 use std::time::Duration;
-use tokio::process::{Child, ChildStdin, Command};
+use tokio::process::{Child, ChildStdin};
 use tokio::sync::{Mutex, broadcast};
 use tracing::{error, info};
 
+use super::command::launch_command;
 use crate::config::ServerConfig;
-use crate::instance::CrashHandlingMode;
+use crate::server::console::LogSender;
 use crate::server::handle::ServerHandle;
-use crate::server::types::{ProgressPayload, ResourceUsage, ServerStatus};
+use crate::server::ops::attach::PidFile;
+use crate::server::ops::detached::console_reader;
+use crate::server::ops::hooks::{Hook, run_hook};
+use crate::server::ops::limits;
+use crate::server::ops::tick::supports_tick_commands;
+use crate::server::status::StatusCell;
+use crate::server::types::{
+    ProgressPayload, ResourceUsage, ServerEvent, ServerStatus, StartupPhase, StartupProgress,
+};
 
 impl ServerHandle {
     pub(crate) async fn lifecycle_loop(
         config_arc: Arc<Mutex<ServerConfig>>,
-        status_arc: Arc<Mutex<ServerStatus>>,
+        status_arc: Arc<StatusCell>,
         child_arc: Arc<Mutex<Option<Child>>>,
         stdin_arc: Arc<Mutex<Option<ChildStdin>>>,
         usage_arc: Arc<Mutex<ResourceUsage>>,
         online_players_arc: Arc<Mutex<HashSet<String>>>,
-        log_sender: broadcast::Sender<String>,
+        log_sender: LogSender,
         _progress_sender: broadcast::Sender<ProgressPayload>,
+        startup_sender: broadcast::Sender<StartupProgress>,
+        event_sender: broadcast::Sender<ServerEvent>,
         start_time_arc: Arc<Mutex<Option<std::time::Instant>>>,
+        pid_arc: Arc<Mutex<Option<u32>>>,
     ) {
+        let mut launched = false;
         loop {
             let config = config_arc.lock().await.clone();
             info!("Starting server: {}", config.name);
 
-            let mut cmd = Self::build_command(&config);
-            cmd.current_dir(&config.working_dir);
-            for arg in &config.args {
-                cmd.arg(arg);
+            if let Err(e) = run_hook(Hook::PreStart, &config, &log_sender).await {
+                error!("Pre-start hook of {} failed: {}", config.name, e);
+                let _ = log_sender.send(format!("ERROR: Pre-start hook failed, not starting: {}", e));
+                *status_arc.lock().await = ServerStatus::Crashed;
+                break;
             }
 
-            cmd.stdin(Stdio::piped())
-                .stdout(Stdio::piped())
-                .stderr(Stdio::piped());
-
-            #[cfg(target_os = "windows")]
-            cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
+            let mut cmd = match launch_command(&config).await {
+                Ok(cmd) => cmd,
+                Err(e) => {
+                    error!("Failed to prepare {} for launch: {:#}", config.name, e);
+                    let _ = log_sender.send(format!("ERROR: {:#}", e));
+                    *status_arc.lock().await = ServerStatus::Crashed;
+                    break;
+                }
+            };
 
             let mut child = match cmd.spawn() {
                 Ok(c) => c,
@@ -50,16 +67,42 @@ impl ServerHandle {
                 }
             };
 
+            launched = true;
             let pid = child.id().unwrap_or(0);
-            let stdout = child.stdout.take().expect("Failed to open stdout");
-            let stderr = child.stderr.take().expect("Failed to open stderr");
-            let stdin = child.stdin.take().expect("Failed to open stdin");
+            if !config.resource_limits.is_default() {
+                for warning in limits::apply_to_process(pid, &config.resource_limits) {
+                    let _ = log_sender.send(format!("WARNING: {}", warning));
+                }
+            }
+            let (stdout, exited) = console_reader(&mut child, &config);
+            let stderr = child.stderr.take();
 
             {
+                *stdin_arc.lock().await = child.stdin.take();
                 *child_arc.lock().await = Some(child);
-                *stdin_arc.lock().await = Some(stdin);
                 *start_time_arc.lock().await = Some(std::time::Instant::now());
+                *pid_arc.lock().await = Some(pid);
             }
+            // Only detached servers can outlive the app and need to be found again
+            if config.detached
+                && let Err(e) = PidFile::write(&config.working_dir, pid).await
+            {
+                error!("{}", e);
+            }
+
+            let _ = startup_sender.send(StartupProgress::new(
+                StartupPhase::Launching,
+                0,
+                "Launching server process",
+            ));
+            let watchdog_handle = (config.startup_timeout > 0).then(|| {
+                tokio::spawn(Self::startup_watchdog(
+                    pid,
+                    config.startup_timeout,
+                    Arc::clone(&status_arc),
+                    log_sender.clone(),
+                ))
+            });
 
             let monitor_handle = tokio::spawn(Self::monitor_resources(
                 pid,
@@ -72,112 +115,78 @@ impl ServerHandle {
                 log_sender.clone(),
                 Arc::clone(&status_arc),
                 Arc::clone(&online_players_arc),
+                startup_sender.clone(),
+                Arc::clone(&usage_arc),
             ));
-            let stderr_handle = tokio::spawn(Self::process_stderr(stderr, log_sender.clone()));
-
-            let mut child = child_arc.lock().await.take().expect("Child disappeared");
-            let exit_status = child.wait().await;
+            let tick_handle = (config.tick_sample_interval > 0
+                && supports_tick_commands(config.server_type.as_deref()))
+            .then(|| {
+                tokio::spawn(Self::tick_sampler(
+                    Arc::clone(&stdin_arc),
+                    Arc::clone(&status_arc),
+                    config.tick_sample_interval,
+                ))
+            });
+            let idle_handle = (config.idle_timeout > 0).then(|| {
+                tokio::spawn(Self::idle_watchdog(
+                    pid,
+                    config.clone(),
+                    Arc::clone(&stdin_arc),
+                    Arc::clone(&status_arc),
+                    Arc::clone(&online_players_arc),
+                    log_sender.clone(),
+                    event_sender.clone(),
+                ))
+            });
+            let stderr_handle = stderr
+                .map(|stderr| tokio::spawn(Self::process_stderr(stderr, log_sender.clone())));
+
+            let child = child_arc.lock().await.take();
+            let exit_status = match child {
+                Some(mut child) => child.wait().await,
+                None => Err(std::io::Error::other("Server process handle was lost")),
+            };
+            exited.store(true, Ordering::Release);
 
             let _ = tokio::time::timeout(Duration::from_millis(500), stdout_handle).await;
-            let _ = tokio::time::timeout(Duration::from_millis(500), stderr_handle).await;
+            if let Some(handle) = stderr_handle {
+                let _ = tokio::time::timeout(Duration::from_millis(500), handle).await;
+            }
             monitor_handle.abort();
+            for handle in [watchdog_handle, tick_handle, idle_handle].into_iter().flatten() {
+                handle.abort();
+            }
 
             {
                 *start_time_arc.lock().await = None;
-                let mut usage = usage_arc.lock().await;
-                usage.cpu_usage = 0.0;
-                usage.memory_usage = 0;
-                usage.uptime = 0;
-                usage.player_count = 0;
+                *pid_arc.lock().await = None;
+                PidFile::remove(&config.working_dir).await;
+                usage_arc.lock().await.reset();
             }
 
-            let mut status = status_arc.lock().await;
-            let exited_cleanly = exit_status.as_ref().map(|s| s.success()).unwrap_or(false);
-
-            if *status == ServerStatus::Stopping
-                || *status == ServerStatus::Stopped
-                || exited_cleanly
-            {
-                info!("Server stopped gracefully.");
-                *status = ServerStatus::Stopped;
-                *stdin_arc.lock().await = None;
-                online_players_arc.lock().await.clear();
+            let restart = Self::settle_exit(
+                &exit_status,
+                &config_arc,
+                &status_arc,
+                &stdin_arc,
+                &online_players_arc,
+                &log_sender,
+            )
+            .await;
+            if !restart {
                 break;
-            } else {
-                let exit_msg = match &exit_status {
-                    Ok(s) => format!("Server process exited unexpectedly with status: {}", s),
-                    Err(e) => format!("Error waiting for server process: {}", e),
-                };
-                error!("{}", exit_msg);
-                let _ = log_sender.send(format!("CRASH: {}", exit_msg));
-                *status = ServerStatus::Crashed;
-                *stdin_arc.lock().await = None;
-                online_players_arc.lock().await.clear();
-
-                let should_restart = match config_arc.lock().await.crash_handling {
-                    CrashHandlingMode::Nothing => false,
-                    CrashHandlingMode::Elevated => {
-                        exit_status.as_ref().map(|s| !s.success()).unwrap_or(true)
-                    }
-                    CrashHandlingMode::Aggressive => true,
-                };
-
-                if should_restart {
-                    info!("Crash handling mode active. Restarting in 5 seconds...");
-                    let _ = log_sender
-                        .send("Crash handling mode active. Restarting in 5 seconds...".to_string());
-                    drop(status);
-                    tokio::time::sleep(Duration::from_secs(5)).await;
-                    *status_arc.lock().await = ServerStatus::Starting;
-                    continue;
-                } else {
-                    break;
-                }
             }
+            info!("Crash handling mode active. Restarting in 5 seconds...");
+            let _ = log_sender.send("Crash handling mode active. Restarting in 5 seconds...".to_string());
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            *status_arc.lock().await = ServerStatus::Starting;
         }
-    }
 
-    pub(crate) fn build_command(config: &ServerConfig) -> Command {
-        if let Some(script) = &config.run_script {
-            #[cfg(target_os = "windows")]
-            {
-                let mut c = Command::new("cmd");
-                c.arg("/c").arg(script);
-                c
-            }
-            #[cfg(not(target_os = "windows"))]
-            {
-                let mut c = Command::new("sh");
-                c.arg(script);
-                c
-            }
-        } else {
-            let is_jar = config
-                .jar_path
-                .as_ref()
-                .map(|p| p.to_string_lossy().to_lowercase().ends_with(".jar"))
-                .unwrap_or(true);
-
-            if is_jar {
-                let java_cmd = config
-                    .java_path
-                    .as_ref()
-                    .map(|p| p.to_string_lossy().to_string())
-                    .unwrap_or_else(|| "java".to_string());
-
-                let mut c = Command::new(java_cmd);
-                c.arg(format!("-Xmx{}", config.max_memory))
-                    .arg(format!("-Xms{}", config.min_memory))
-                    .arg("-Dterminal.jline=false")
-                    .arg("-Dterminal.ansi=true")
-                    .arg("-Dlog4j.skipJansi=false");
-
-                if let Some(jar_path) = &config.jar_path {
-                    c.arg("-jar").arg(jar_path);
-                }
-                c
-            } else {
-                Command::new(config.jar_path.as_ref().unwrap())
+        if launched {
+            let config = config_arc.lock().await.clone();
+            if let Err(e) = run_hook(Hook::PostStop, &config, &log_sender).await {
+                error!("Post-stop hook of {} failed: {}", config.name, e);
+                let _ = log_sender.send(format!("ERROR: Post-stop hook failed: {}", e));
             }
         }
     }
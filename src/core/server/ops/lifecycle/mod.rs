@@ -1,3 +1,5 @@
+mod command;
+mod exit;
 pub mod start;
 pub mod stop;
 pub mod r#loop;
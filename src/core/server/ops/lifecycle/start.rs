@@ -20,11 +20,14 @@ impl ServerHandle {
         let online_players = Arc::clone(&self.online_players);
         let log_sender = self.log_sender.clone();
         let progress_sender = self.progress_sender.clone();
+        let startup_sender = self.startup_sender.clone();
+        let event_sender = self.event_sender.clone();
         let start_time = Arc::clone(&self.start_time);
+        let pid = Arc::clone(&self.pid);
 
         tokio::spawn(async move {
             Self::lifecycle_loop(
-                config, status, child, stdin, usage, online_players, log_sender, progress_sender, start_time
+                config, status, child, stdin, usage, online_players, log_sender, progress_sender, startup_sender, event_sender, start_time, pid
             ).await;
         });
 
@@ -2,9 +2,18 @@ use crate::server::handle::ServerHandle;
 use crate::server::types::ServerStatus;
 use anyhow::Result;
 use std::time::Duration;
-use tokio::process::Command;
+use sysinfo::{Pid, ProcessesToUpdate, Signal, System};
+use tokio::process::{Child, Command};
 use tracing::warn;
 
+/// Console command that shuts the server down gracefully.
+pub(crate) fn stop_command(server_type: Option<&str>) -> &'static str {
+    match server_type {
+        Some("bungeecord") => "end",
+        _ => "stop",
+    }
+}
+
 impl ServerHandle {
     pub async fn stop(&self) -> Result<()> {
         let mut status = self.status.lock().await;
@@ -15,15 +24,14 @@ impl ServerHandle {
         *status = ServerStatus::Stopping;
         let config = self.config.lock().await;
         let stop_timeout = config.stop_timeout;
-        let stop_command = match config.server_type.as_deref() {
-            Some("bungeecord") => "end",
-            _ => "stop",
-        };
+        let stop_command = stop_command(config.server_type.as_deref());
         drop(config);
         drop(status);
 
         if let Err(e) = self.send_command(stop_command).await {
-            warn!("Failed to send stop command: {}. Falling back to kill.", e);
+            // Reattached servers have no stdin; ask the process to shut down instead
+            warn!("Failed to send stop command: {}. Terminating the process.", e);
+            self.terminate_process().await;
         }
 
         let start_wait = std::time::Instant::now();
@@ -37,20 +45,7 @@ impl ServerHandle {
         }
 
         warn!("Server failed to exit gracefully. Killing process.");
-        let mut child_lock = self.child.lock().await;
-        if let Some(mut child) = child_lock.take() {
-            #[cfg(target_os = "windows")]
-            if let Some(pid) = child.id() {
-                let _ = Command::new("taskkill")
-                    .arg("/F")
-                    .arg("/T")
-                    .arg("/PID")
-                    .arg(pid.to_string())
-                    .output()
-                    .await;
-            }
-            let _ = child.kill().await;
-        }
+        self.kill_process().await;
 
         let mut status = self.status.lock().await;
         *status = ServerStatus::Stopped;
@@ -65,24 +60,66 @@ impl ServerHandle {
             return Ok(());
         }
 
-        let mut child_lock = self.child.lock().await;
-        if let Some(mut child) = child_lock.take() {
-            #[cfg(target_os = "windows")]
-            if let Some(pid) = child.id() {
-                let _ = Command::new("taskkill")
-                    .arg("/F")
-                    .arg("/T")
-                    .arg("/PID")
-                    .arg(pid.to_string())
-                    .output()
-                    .await;
-            }
-            let _ = child.kill().await;
-        }
+        // Mark as stopping first so the lifecycle loop treats the exit as intentional
+        *status = ServerStatus::Stopping;
+        drop(status);
+        self.kill_process().await;
 
+        let mut status = self.status.lock().await;
         *status = ServerStatus::Stopped;
         *self.stdin.lock().await = None;
         self.online_players.lock().await.clear();
         Ok(())
     }
+
+    /// Asks the server process to exit. On Unix this sends SIGTERM, which runs the
+    /// server's shutdown hooks so worlds are saved; elsewhere it kills the process.
+    async fn terminate_process(&self) {
+        let Some(pid) = *self.pid.lock().await else {
+            return;
+        };
+        let mut sys = System::new();
+        let pid = Pid::from(pid as usize);
+        sys.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+        // kill_with returns None where SIGTERM is not supported
+        if let Some(process) = sys.process(pid).filter(|p| p.kill_with(Signal::Term).is_none()) {
+            process.kill();
+        }
+    }
+
+    /// Forcefully terminates the server process.
+    async fn kill_process(&self) {
+        let child = self.child.lock().await.take();
+        let pid = match &child {
+            Some(child) => child.id(),
+            None => *self.pid.lock().await,
+        };
+        force_kill(child, pid).await;
+    }
+}
+
+/// Forcefully terminates a server process, through its handle when there is one. On
+/// Windows the whole process tree is killed.
+pub(crate) async fn force_kill(child: Option<Child>, pid: Option<u32>) {
+    #[cfg(target_os = "windows")]
+    if let Some(pid) = pid {
+        let _ = Command::new("taskkill")
+            .arg("/F")
+            .arg("/T")
+            .arg("/PID")
+            .arg(pid.to_string())
+            .output()
+            .await;
+    }
+
+    if let Some(mut child) = child {
+        let _ = child.kill().await;
+    } else if let Some(pid) = pid {
+        let mut sys = System::new();
+        let pid = Pid::from(pid as usize);
+        sys.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+        if let Some(process) = sys.process(pid) {
+            process.kill();
+        }
+    }
 }
@@ -0,0 +1,131 @@
+use tokio::process::Command;
+
+use crate::instance::ResourceLimits;
+
+/// Sets up `cmd` so the server starts with `limits` already in place. On unix the child
+/// sets its own niceness and affinity before exec, so every JVM thread inherits them. On
+/// Windows the priority class goes into the creation flags, returned for the caller to
+/// combine with its own.
+#[cfg(unix)]
+pub(crate) fn prepare_command(cmd: &mut Command, limits: &ResourceLimits) {
+    if limits.is_default() {
+        return;
+    }
+    let niceness = limits.priority.niceness();
+    #[cfg(target_os = "linux")]
+    let cpus = limits.cpu_affinity.clone();
+    // Only async-signal-safe calls are made between fork and exec. Failures are ignored
+    // here and reported by `apply_to_process` once the process is running.
+    unsafe {
+        cmd.pre_exec(move || {
+            if niceness != 0 {
+                libc::setpriority(libc::PRIO_PROCESS as _, 0, niceness);
+            }
+            #[cfg(target_os = "linux")]
+            if !cpus.is_empty() {
+                let mut set: libc::cpu_set_t = std::mem::zeroed();
+                for &cpu in &cpus {
+                    libc::CPU_SET(cpu, &mut set);
+                }
+                libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &set);
+            }
+            Ok(())
+        });
+    }
+}
+
+#[cfg(target_os = "windows")]
+pub(crate) fn prepare_command(_cmd: &mut Command, limits: &ResourceLimits) -> u32 {
+    limits.priority.windows_priority_class()
+}
+
+/// Applies what couldn't be set before launch and checks what was. Returns a message for
+/// each limit that isn't in effect; the server keeps running either way.
+#[cfg(unix)]
+pub(crate) fn apply_to_process(pid: u32, limits: &ResourceLimits) -> Vec<String> {
+    let mut warnings = Vec::new();
+    let niceness = limits.priority.niceness();
+    if niceness != 0 {
+        // getpriority can legitimately return -1, which is fine for a comparison
+        let actual = unsafe { libc::getpriority(libc::PRIO_PROCESS as _, pid as libc::id_t) };
+        if actual != niceness {
+            warnings.push(format!(
+                "Couldn't set the server's niceness to {} (it's {}); raising priority needs root or CAP_SYS_NICE",
+                niceness, actual
+            ));
+        }
+    }
+    #[cfg(not(target_os = "linux"))]
+    if !limits.cpu_affinity.is_empty() {
+        warnings.push("CPU affinity isn't supported on this platform and was ignored".to_string());
+    }
+    if limits.memory_limit_mb > 0 {
+        warnings.push("The memory cap is only enforced on Windows and was ignored".to_string());
+    }
+    warnings
+}
+
+/// Puts the process in a Job Object carrying the affinity and memory cap. Processes it
+/// starts afterwards, like the JVM behind a `.bat` script, join the job too.
+#[cfg(target_os = "windows")]
+pub(crate) fn apply_to_process(pid: u32, limits: &ResourceLimits) -> Vec<String> {
+    use windows_sys::Win32::Foundation::CloseHandle;
+    use windows_sys::Win32::System::JobObjects::{
+        AssignProcessToJobObject, CreateJobObjectW, JOB_OBJECT_LIMIT_AFFINITY, JOB_OBJECT_LIMIT_JOB_MEMORY,
+        JOBOBJECT_EXTENDED_LIMIT_INFORMATION, JobObjectExtendedLimitInformation, SetInformationJobObject,
+    };
+    use windows_sys::Win32::System::Threading::{OpenProcess, PROCESS_SET_QUOTA, PROCESS_TERMINATE};
+
+    let mut warnings = Vec::new();
+    let mask = limits
+        .cpu_affinity
+        .iter()
+        .filter(|&&cpu| cpu < usize::BITS as usize)
+        .fold(0usize, |mask, &cpu| mask | (1 << cpu));
+    if limits.cpu_affinity.iter().any(|&cpu| cpu >= usize::BITS as usize) {
+        warnings.push(format!("CPUs from {} up can't be used in an affinity mask and were ignored", usize::BITS));
+    }
+    if mask == 0 && limits.memory_limit_mb == 0 {
+        return warnings;
+    }
+
+    unsafe {
+        let mut info: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = std::mem::zeroed();
+        if mask != 0 {
+            info.BasicLimitInformation.LimitFlags |= JOB_OBJECT_LIMIT_AFFINITY;
+            info.BasicLimitInformation.Affinity = mask;
+        }
+        if limits.memory_limit_mb > 0 {
+            info.BasicLimitInformation.LimitFlags |= JOB_OBJECT_LIMIT_JOB_MEMORY;
+            info.JobMemoryLimit = (limits.memory_limit_mb as usize).saturating_mul(1024 * 1024);
+        }
+
+        let job = CreateJobObjectW(std::ptr::null(), std::ptr::null());
+        if job.is_null() {
+            warnings.push(format!("Couldn't create a Job Object: {}", std::io::Error::last_os_error()));
+            return warnings;
+        }
+        let process = OpenProcess(PROCESS_SET_QUOTA | PROCESS_TERMINATE, 0, pid);
+        if process.is_null() {
+            warnings.push(format!("Couldn't open the server process: {}", std::io::Error::last_os_error()));
+        } else {
+            let applied = SetInformationJobObject(
+                job,
+                JobObjectExtendedLimitInformation,
+                &info as *const _ as *const std::ffi::c_void,
+                std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+            ) != 0
+                && AssignProcessToJobObject(job, process) != 0;
+            if !applied {
+                warnings.push(format!(
+                    "Couldn't apply the CPU affinity and memory cap: {}",
+                    std::io::Error::last_os_error()
+                ));
+            }
+            CloseHandle(process);
+        }
+        // The job lives on while the server is in it, so the handle isn't needed anymore
+        CloseHandle(job);
+    }
+    warnings
+}
@@ -1,6 +1,19 @@
+pub mod attach;
 pub mod lifecycle;
 pub mod commands;
+pub mod detached;
+pub mod hooks;
+pub mod idle;
+pub mod limits;
 pub mod monitor;
+pub mod startup;
+pub mod tick;
+
+pub use attach::PidFile;
+pub use hooks::{Hook, run_hook};
+pub use idle::{IdleTracker, active_player_count};
+pub use startup::detect_startup_phase;
+pub use tick::{TickSample, TickTracker, supports_tick_commands};
 
 use super::handle::ServerHandle;
 use super::super::config::ServerConfig;
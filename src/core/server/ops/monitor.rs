@@ -4,11 +4,15 @@ use std::sync::Arc;
 use std::sync::OnceLock;
 use std::time::Duration;
 use sysinfo::{Pid, ProcessesToUpdate, System};
-use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
 use tokio::sync::{Mutex, broadcast};
 
+use super::super::console::LogSender;
 use super::super::handle::ServerHandle;
-use super::super::types::{ResourceUsage, ServerStatus};
+use super::super::status::StatusCell;
+use super::super::types::{ResourceUsage, ServerStatus, StartupProgress};
+use super::startup::detect_startup_phase;
+use super::tick::{ESTIMATE_TTL, TickSample, TickTracker};
 
 use std::time::Instant;
 
@@ -38,6 +42,14 @@ impl ServerHandle {
 
                 // Update player count
                 usage.player_count = players_arc.lock().await.len() as u32;
+
+                // A lag estimate expires once vanilla stops warning
+                let estimate_expired = usage.tick_sampled_at.is_some_and(|t| t.elapsed() > ESTIMATE_TTL);
+                if usage.tick_estimated && estimate_expired {
+                    usage.tps = Some(20.0);
+                    usage.mspt = None;
+                    usage.tick_sampled_at = None;
+                }
             } else {
                 break;
             }
@@ -46,20 +58,32 @@ impl ServerHandle {
     }
 
     pub(crate) async fn process_stdout(
-        stdout: tokio::process::ChildStdout,
-        log_sender: broadcast::Sender<String>,
-        status_arc: Arc<Mutex<ServerStatus>>,
+        stdout: impl AsyncRead + Unpin,
+        log_sender: LogSender,
+        status_arc: Arc<StatusCell>,
         players_arc: Arc<Mutex<HashSet<String>>>,
+        startup_sender: broadcast::Sender<StartupProgress>,
+        usage_arc: Arc<Mutex<ResourceUsage>>,
     ) {
         static ANSI_REGEX: OnceLock<Regex> = OnceLock::new();
         let ansi_re = ANSI_REGEX
             .get_or_init(|| Regex::new(r"\x1B(?:[@-Z\\-_]|\[[0-?]*[ -/]*[@-~])").unwrap());
         let mut reader = BufReader::new(stdout).lines();
+        let mut tick_tracker = TickTracker::default();
         while let Ok(Some(line)) = reader.next_line().await {
             let _ = log_sender.send(line.clone());
             let line_stripped = ansi_re.replace_all(&line, "");
             let line_lower = line_stripped.to_lowercase();
 
+            if let Some(sample) = tick_tracker.observe(&line_stripped, Instant::now()) {
+                Self::apply_tick_sample(&mut *usage_arc.lock().await, sample);
+            }
+
+            let is_starting = *status_arc.lock().await == ServerStatus::Starting;
+            if let Some(progress) = detect_startup_phase(&line_stripped).filter(|_| is_starting) {
+                let _ = startup_sender.send(progress);
+            }
+
             if Self::is_ready_line(&line_lower) {
                 let mut status = status_arc.lock().await;
                 if *status == ServerStatus::Starting {
@@ -87,9 +111,20 @@ impl ServerHandle {
         }
     }
 
+    fn apply_tick_sample(usage: &mut ResourceUsage, sample: TickSample) {
+        // A reported value is never overwritten by an estimate
+        if sample.estimated && usage.tps.is_some() && !usage.tick_estimated {
+            return;
+        }
+        usage.tps = sample.tps.or(usage.tps);
+        usage.mspt = sample.mspt.or(usage.mspt);
+        usage.tick_estimated = sample.estimated;
+        usage.tick_sampled_at = Some(Instant::now());
+    }
+
     pub(crate) async fn process_stderr(
-        stderr: tokio::process::ChildStderr,
-        log_sender: broadcast::Sender<String>,
+        stderr: impl AsyncRead + Unpin,
+        log_sender: LogSender,
     ) {
         let mut reader = BufReader::new(stderr).lines();
         while let Ok(Some(line)) = reader.next_line().await {
@@ -0,0 +1,110 @@
+use regex::Regex;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use tracing::warn;
+
+use super::super::console::LogSender;
+use super::super::handle::ServerHandle;
+use super::super::status::StatusCell;
+use super::super::types::{ServerStatus, StartupPhase, StartupProgress};
+use super::lifecycle::stop::force_kill;
+
+impl StartupProgress {
+    pub fn new(phase: StartupPhase, percent: u8, message: impl Into<String>) -> Self {
+        Self {
+            phase,
+            percent,
+            startup_time: None,
+            message: message.into(),
+        }
+    }
+}
+
+/// Detects which startup phase a (ANSI-stripped) console line belongs to.
+///
+/// Returns `None` for lines that carry no startup information.
+pub fn detect_startup_phase(line: &str) -> Option<StartupProgress> {
+    static SPAWN_REGEX: OnceLock<Regex> = OnceLock::new();
+    static DONE_REGEX: OnceLock<Regex> = OnceLock::new();
+    let spawn_re = SPAWN_REGEX
+        .get_or_init(|| Regex::new(r"(?i)preparing spawn area:\s*(\d{1,3})%").unwrap());
+    let done_re =
+        DONE_REGEX.get_or_init(|| Regex::new(r"(?i)done \((\d+(?:[.,]\d+)?)s\)!").unwrap());
+
+    if let Some(caps) = done_re.captures(line) {
+        let secs = caps[1].replace(',', ".").parse::<f64>().ok();
+        return Some(StartupProgress {
+            phase: StartupPhase::Done,
+            percent: 100,
+            startup_time: secs,
+            message: "Server is ready".to_string(),
+        });
+    }
+
+    if let Some(caps) = spawn_re.captures(line) {
+        let spawn_percent = caps[1].parse::<u32>().unwrap_or(0).min(100);
+        // Spawn preparation covers the 35-95% range of the overall startup
+        let percent = 35 + (spawn_percent * 60 / 100) as u8;
+        return Some(StartupProgress::new(
+            StartupPhase::PreparingSpawn,
+            percent,
+            format!("Preparing spawn area: {}%", spawn_percent),
+        ));
+    }
+
+    let lower = line.to_lowercase();
+    if lower.contains("preparing level") || lower.contains("preparing start region") {
+        Some(StartupProgress::new(
+            StartupPhase::PreparingLevel,
+            30,
+            "Preparing level",
+        ))
+    } else if lower.contains("starting minecraft server version")
+        || lower.contains("loading properties")
+    {
+        Some(StartupProgress::new(
+            StartupPhase::StartingServer,
+            20,
+            "Starting server",
+        ))
+    } else if lower.contains("loading libraries")
+        || lower.contains("downloading mojang")
+        || lower.contains("applying patches")
+    {
+        Some(StartupProgress::new(
+            StartupPhase::LoadingLibraries,
+            10,
+            "Loading libraries",
+        ))
+    } else {
+        None
+    }
+}
+
+impl ServerHandle {
+    /// Kills the server process if it is still starting once `timeout_secs` have elapsed.
+    pub(crate) async fn startup_watchdog(
+        pid: u32,
+        timeout_secs: u64,
+        status_arc: Arc<StatusCell>,
+        log_sender: LogSender,
+    ) {
+        tokio::time::sleep(Duration::from_secs(timeout_secs)).await;
+
+        let mut status = status_arc.lock().await;
+        if *status != ServerStatus::Starting {
+            return;
+        }
+
+        let msg = format!(
+            "Server did not finish starting within {} seconds. Killing the hung process.",
+            timeout_secs
+        );
+        warn!("{}", msg);
+        let _ = log_sender.send(format!("ERROR: {}", msg));
+        *status = ServerStatus::Crashed;
+        drop(status);
+
+        force_kill(None, Some(pid)).await;
+    }
+}
@@ -0,0 +1,108 @@
+use regex::Regex;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+use tokio::io::AsyncWriteExt;
+use tokio::process::ChildStdin;
+use tokio::sync::Mutex;
+
+use super::super::handle::ServerHandle;
+use super::super::status::StatusCell;
+use super::super::types::ServerStatus;
+
+/// Server types whose console understands Paper's `tps` and `mspt` commands.
+const TICK_COMMAND_SERVERS: &[&str] = &["paper", "purpur", "pufferfish", "folia"];
+
+/// Vanilla only warns when it falls behind, at most once every 15 seconds.
+const LAG_WARNING_WINDOW: Duration = Duration::from_secs(15);
+
+/// How long an estimate from a lag warning stays valid without another warning.
+pub const ESTIMATE_TTL: Duration = Duration::from_secs(60);
+
+pub fn supports_tick_commands(server_type: Option<&str>) -> bool {
+    server_type.is_some_and(|t| TICK_COMMAND_SERVERS.contains(&t.to_lowercase().as_str()))
+}
+
+/// A tick-rate reading taken from console output.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TickSample {
+    pub tps: Option<f32>,
+    pub mspt: Option<f32>,
+    /// Derived from vanilla "Can't keep up!" warnings rather than reported by the server.
+    pub estimated: bool,
+}
+
+/// Turns console lines into tick samples. Keeps the little state needed for
+/// multi-line `mspt` output and for spacing vanilla lag warnings.
+#[derive(Debug, Default)]
+pub struct TickTracker {
+    awaiting_mspt: bool,
+    last_lag_warning: Option<Instant>,
+}
+
+impl TickTracker {
+    /// Parses an ANSI-stripped console line.
+    pub fn observe(&mut self, line: &str, now: Instant) -> Option<TickSample> {
+        static TPS_REGEX: OnceLock<Regex> = OnceLock::new();
+        static MSPT_REGEX: OnceLock<Regex> = OnceLock::new();
+        static LAG_REGEX: OnceLock<Regex> = OnceLock::new();
+        let tps_re = TPS_REGEX
+            .get_or_init(|| Regex::new(r"TPS from last 1m, 5m, 15m: \*?(\d+(?:\.\d+)?)").unwrap());
+        let mspt_re = MSPT_REGEX.get_or_init(|| Regex::new(r"(\d+(?:\.\d+)?)/\d+(?:\.\d+)?/\d+(?:\.\d+)?").unwrap());
+        let lag_re = LAG_REGEX
+            .get_or_init(|| Regex::new(r"Can't keep up!.*Running (\d+)ms or \d+ ticks behind").unwrap());
+
+        if let Some(caps) = tps_re.captures(line) {
+            let tps = caps[1].parse::<f32>().ok()?;
+            return Some(TickSample { tps: Some(tps.min(20.0)), mspt: None, estimated: false });
+        }
+
+        if line.contains("Server tick times") {
+            // The values follow on the next line
+            self.awaiting_mspt = true;
+            return None;
+        }
+        if self.awaiting_mspt {
+            self.awaiting_mspt = false;
+            // First triple is avg/min/max over the last 5 seconds
+            let mspt = mspt_re.captures(line)?[1].parse::<f32>().ok()?;
+            return Some(TickSample { tps: None, mspt: Some(mspt), estimated: false });
+        }
+
+        let behind_ms = lag_re.captures(line)?[1].parse::<f32>().ok()?;
+        let window = self
+            .last_lag_warning
+            .map(|last| now.duration_since(last))
+            .filter(|elapsed| *elapsed < ESTIMATE_TTL)
+            .unwrap_or(LAG_WARNING_WINDOW)
+            .max(LAG_WARNING_WINDOW)
+            .as_millis() as f32;
+        self.last_lag_warning = Some(now);
+        // The server needed `window + behind` ms of work for `window` ms of ticks
+        let load = (window + behind_ms) / window;
+        Some(TickSample { tps: Some(20.0 / load), mspt: Some(50.0 * load), estimated: true })
+    }
+}
+
+impl ServerHandle {
+    /// Periodically asks Paper-family servers for `tps` and `mspt`; the replies
+    /// are picked up by the console reader.
+    pub(crate) async fn tick_sampler(
+        stdin_arc: Arc<Mutex<Option<ChildStdin>>>,
+        status_arc: Arc<StatusCell>,
+        interval: u64,
+    ) {
+        loop {
+            tokio::time::sleep(Duration::from_secs(interval)).await;
+            if *status_arc.lock().await != ServerStatus::Running {
+                continue;
+            }
+            let mut stdin = stdin_arc.lock().await;
+            let Some(stdin) = stdin.as_mut() else {
+                break;
+            };
+            if stdin.write_all(b"tps\nmspt\n").await.is_err() || stdin.flush().await.is_err() {
+                break;
+            }
+        }
+    }
+}
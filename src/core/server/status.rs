@@ -0,0 +1,57 @@
+use super::types::{ServerEvent, ServerStatus};
+use std::ops::{Deref, DerefMut};
+use tokio::sync::{Mutex, MutexGuard, broadcast};
+
+/// A server's status. Every change made through [`StatusCell::lock`] is announced as a
+/// [`ServerEvent::StatusChanged`] when the guard is dropped, so listeners don't have to poll.
+pub struct StatusCell {
+    status: Mutex<ServerStatus>,
+    events: broadcast::Sender<ServerEvent>,
+}
+
+impl StatusCell {
+    pub fn new(status: ServerStatus, events: broadcast::Sender<ServerEvent>) -> Self {
+        Self {
+            status: Mutex::new(status),
+            events,
+        }
+    }
+
+    pub async fn lock(&self) -> StatusGuard<'_> {
+        let guard = self.status.lock().await;
+        StatusGuard {
+            before: *guard,
+            guard,
+            events: &self.events,
+        }
+    }
+}
+
+pub struct StatusGuard<'a> {
+    guard: MutexGuard<'a, ServerStatus>,
+    before: ServerStatus,
+    events: &'a broadcast::Sender<ServerEvent>,
+}
+
+impl Deref for StatusGuard<'_> {
+    type Target = ServerStatus;
+
+    fn deref(&self) -> &ServerStatus {
+        &self.guard
+    }
+}
+
+impl DerefMut for StatusGuard<'_> {
+    fn deref_mut(&mut self) -> &mut ServerStatus {
+        &mut self.guard
+    }
+}
+
+impl Drop for StatusGuard<'_> {
+    fn drop(&mut self) {
+        let status = *self.guard;
+        if status != self.before {
+            let _ = self.events.send(ServerEvent::StatusChanged { status });
+        }
+    }
+}
@@ -21,6 +21,38 @@ pub struct ResourceUsage {
     pub disk_write: u64,
     pub uptime: u64,
     pub player_count: u32,
+    /// Ticks per second; `None` until the server reports it.
+    pub tps: Option<f32>,
+    /// Milliseconds per tick.
+    pub mspt: Option<f32>,
+    /// The tick values are estimated from vanilla lag warnings.
+    pub tick_estimated: bool,
+    #[serde(skip)]
+    pub(crate) tick_sampled_at: Option<std::time::Instant>,
+}
+
+impl ResourceUsage {
+    /// Clears the live readings once the server process has exited.
+    pub(crate) fn reset(&mut self) {
+        self.cpu_usage = 0.0;
+        self.memory_usage = 0;
+        self.uptime = 0;
+        self.player_count = 0;
+        self.tps = None;
+        self.mspt = None;
+        self.tick_estimated = false;
+        self.tick_sampled_at = None;
+    }
+}
+
+/// Actions a server handle took on its own, for the app to surface.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerEvent {
+    /// Gracefully stopped after no players were online for `idle_secs`.
+    AutoStopped { idle_secs: u64 },
+    /// The server moved to `status`.
+    StatusChanged { status: ServerStatus },
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -29,3 +61,23 @@ pub struct ProgressPayload {
     pub total: u64,
     pub message: String,
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum StartupPhase {
+    Launching,
+    LoadingLibraries,
+    StartingServer,
+    PreparingLevel,
+    PreparingSpawn,
+    Done,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StartupProgress {
+    pub phase: StartupPhase,
+    /// Estimated overall progress from 0 to 100.
+    pub percent: u8,
+    /// Startup duration reported by the server once it is done, in seconds.
+    pub startup_time: Option<f64>,
+    pub message: String,
+}
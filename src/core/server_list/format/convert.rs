@@ -0,0 +1,174 @@
+use super::{COLORS, DECORATIONS, Span, Style, push_span};
+use serde_json::{Map, Value as JsonValue, json};
+
+/// Color the client draws unformatted MOTD text in.
+const DEFAULT_COLOR: &str = "#808080";
+
+const PALETTE: [&str; 16] = [
+    "#000000", "#0000aa", "#00aa00", "#00aaaa", "#aa0000", "#aa00aa", "#ffaa00", "#aaaaaa", "#555555", "#5555ff",
+    "#55ff55", "#55ffff", "#ff5555", "#ff55ff", "#ffff55", "#ffffff",
+];
+
+pub(super) fn parse_legacy(input: &str) -> Vec<Span> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut spans = Vec::new();
+    let mut style = Style::default();
+    let mut text = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let code = chars.get(i + 1).map(|c| c.to_ascii_lowercase());
+        if let Some(code) = code.filter(|_| c == '§' || c == '&') {
+            // §x§r§r§g§g§b§b hex color
+            if code == 'x' && i + 13 < chars.len() && (0..6).all(|n| chars[i + 2 + n * 2] == c && chars[i + 3 + n * 2].is_ascii_hexdigit()) {
+                push_span(&mut spans, &std::mem::take(&mut text), &style);
+                let hex: String = (0..6).map(|n| chars[i + 3 + n * 2].to_ascii_lowercase()).collect();
+                style = Style { color: Some(format!("#{}", hex)), ..Style::default() };
+                i += 14;
+                continue;
+            }
+            if let Some((_, name)) = COLORS.iter().find(|(k, _)| *k == code) {
+                push_span(&mut spans, &std::mem::take(&mut text), &style);
+                style = Style { color: Some(name.to_string()), ..Style::default() };
+                i += 2;
+                continue;
+            }
+            if let Some(idx) = DECORATIONS.iter().position(|(k, _, _)| *k == code) {
+                push_span(&mut spans, &std::mem::take(&mut text), &style);
+                style.decorations[idx] = true;
+                i += 2;
+                continue;
+            }
+            if code == 'r' {
+                push_span(&mut spans, &std::mem::take(&mut text), &style);
+                style = Style::default();
+                i += 2;
+                continue;
+            }
+        }
+        text.push(c);
+        i += 1;
+    }
+    push_span(&mut spans, &text, &style);
+    spans
+}
+
+pub(super) fn write_legacy(spans: &[Span]) -> String {
+    let mut out = String::new();
+    let mut current = Style::default();
+    for span in spans {
+        if span.style != current {
+            // Legacy colors reset decorations, so restate the whole style
+            match &span.style.color {
+                Some(color) if color.starts_with('#') => {
+                    out.push_str("§x");
+                    color[1..].chars().for_each(|c| {
+                        out.push('§');
+                        out.push(c);
+                    });
+                }
+                Some(color) => {
+                    let code = COLORS.iter().find(|(_, n)| n == color).map(|(k, _)| *k).unwrap_or('f');
+                    out.push('§');
+                    out.push(code);
+                }
+                None => out.push_str("§r"),
+            }
+            for (idx, (code, _, _)) in DECORATIONS.iter().enumerate() {
+                if span.style.decorations[idx] {
+                    out.push('§');
+                    out.push(*code);
+                }
+            }
+            current = span.style.clone();
+        }
+        out.push_str(&span.text);
+    }
+    out
+}
+
+pub(super) fn parse_json_component(value: &JsonValue, parent: &Style, spans: &mut Vec<Span>) {
+    match value {
+        JsonValue::String(text) => {
+            // Plain strings may still carry legacy codes
+            for span in parse_legacy(text) {
+                let mut style = parent.clone();
+                if span.style.color.is_some() {
+                    style = span.style.clone();
+                } else {
+                    (0..5).for_each(|i| style.decorations[i] |= span.style.decorations[i]);
+                }
+                push_span(spans, &span.text, &style);
+            }
+        }
+        JsonValue::Array(items) => items.iter().for_each(|item| parse_json_component(item, parent, spans)),
+        JsonValue::Object(map) => {
+            let mut style = parent.clone();
+            if let Some(color) = map.get("color").and_then(|c| c.as_str()) {
+                style.color = Some(color.to_lowercase());
+            }
+            for (idx, (_, name, _)) in DECORATIONS.iter().enumerate() {
+                if let Some(flag) = map.get(*name).and_then(|v| v.as_bool()) {
+                    style.decorations[idx] = flag;
+                }
+            }
+            if let Some(text) = map.get("text") {
+                parse_json_component(text, &style, spans);
+            }
+            if let Some(extra) = map.get("extra") {
+                parse_json_component(extra, &style, spans);
+            }
+        }
+        _ => {}
+    }
+}
+
+pub(super) fn write_json(spans: &[Span]) -> JsonValue {
+    let extra: Vec<JsonValue> = spans
+        .iter()
+        .map(|span| {
+            let mut obj = Map::new();
+            obj.insert("text".into(), json!(span.text));
+            if let Some(color) = &span.style.color {
+                obj.insert("color".into(), json!(color));
+            }
+            for (idx, (_, name, _)) in DECORATIONS.iter().enumerate() {
+                if span.style.decorations[idx] {
+                    obj.insert((*name).into(), json!(true));
+                }
+            }
+            JsonValue::Object(obj)
+        })
+        .collect();
+    json!({ "text": "", "extra": extra })
+}
+
+pub(super) fn resolve_color(color: Option<&str>) -> String {
+    match color {
+        Some(hex) if hex.starts_with('#') => hex.to_lowercase(),
+        Some(name) => COLORS
+            .iter()
+            .position(|(_, n)| *n == name)
+            .map(|i| PALETTE[i].to_string())
+            .unwrap_or_else(|| DEFAULT_COLOR.to_string()),
+        None => DEFAULT_COLOR.to_string(),
+    }
+}
+
+/// `§` followed by something that isn't a formatting code. Only `§` is checked since a
+/// lone `&` is ordinary text.
+pub(super) fn unknown_codes(input: &str) -> Vec<String> {
+    let mut unknown = Vec::new();
+    let mut chars = input.chars();
+    while let Some(c) = chars.next() {
+        if c != '§' {
+            continue;
+        }
+        match chars.next().map(|c| c.to_ascii_lowercase()) {
+            Some(code) if code.is_ascii_hexdigit() || "klmnorx".contains(code) => {}
+            Some(code) => unknown.push(format!("§{}", code)),
+            None => unknown.push("§ at the end".to_string()),
+        }
+    }
+    unknown
+}
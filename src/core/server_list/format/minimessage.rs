@@ -0,0 +1,78 @@
+use super::{COLORS, DECORATIONS, Span, Style, push_span};
+
+fn apply_tag(stack: &mut Vec<(String, Style)>, tag: &str) -> bool {
+    let current = stack.last().map(|(_, s)| s.clone()).unwrap_or_default();
+    let name = tag.to_lowercase();
+    let mut style = current;
+    if let Some(closing) = name.strip_prefix('/') {
+        if let Some(pos) = stack.iter().rposition(|(open, _)| open == closing) {
+            stack.truncate(pos);
+        }
+        return true;
+    }
+    if name == "reset" {
+        stack.clear();
+        return true;
+    }
+    let color = name.strip_prefix("color:").or_else(|| name.strip_prefix("c:")).unwrap_or(&name);
+    let is_hex = color.len() == 7 && color.starts_with('#') && color[1..].chars().all(|c| c.is_ascii_hexdigit());
+    let key = if is_hex || COLORS.iter().any(|(_, n)| *n == color) {
+        style.color = Some(color.to_string());
+        name.split(':').next().unwrap_or(&name).to_string()
+    } else if let Some(idx) = DECORATIONS.iter().position(|(_, n, aliases)| *n == name || aliases.contains(&name.as_str())) {
+        style.decorations[idx] = true;
+        name.clone()
+    } else {
+        return false;
+    };
+    stack.push((key, style));
+    true
+}
+
+pub(super) fn parse_minimessage(input: &str) -> Vec<Span> {
+    let mut spans = Vec::new();
+    let mut stack: Vec<(String, Style)> = Vec::new();
+    let mut rest = input;
+    while let Some(start) = rest.find('<') {
+        let style = stack.last().map(|(_, s)| s.clone()).unwrap_or_default();
+        push_span(&mut spans, &rest[..start], &style);
+        let after = &rest[start + 1..];
+        match after.find('>') {
+            Some(end) if matches!(&after[..end], "newline" | "br") => {
+                push_span(&mut spans, "\n", &style);
+                rest = &after[end + 1..];
+            }
+            Some(end) if apply_tag(&mut stack, &after[..end]) => rest = &after[end + 1..],
+            // Unknown tags are kept as literal text
+            _ => {
+                push_span(&mut spans, "<", &style);
+                rest = after;
+            }
+        }
+    }
+    let style = stack.last().map(|(_, s)| s.clone()).unwrap_or_default();
+    push_span(&mut spans, rest, &style);
+    spans
+}
+
+pub(super) fn write_minimessage(spans: &[Span]) -> String {
+    let mut out = String::new();
+    for span in spans {
+        let mut close = Vec::new();
+        if let Some(color) = &span.style.color {
+            out.push_str(&format!("<{}>", color));
+            close.push(color.clone());
+        }
+        for (idx, (_, name, _)) in DECORATIONS.iter().enumerate() {
+            if span.style.decorations[idx] {
+                out.push_str(&format!("<{}>", name));
+                close.push(name.to_string());
+            }
+        }
+        out.push_str(&span.text.replace('<', "\\<").replace('\n', "<newline>"));
+        for tag in close.iter().rev() {
+            out.push_str(&format!("</{}>", tag));
+        }
+    }
+    out
+}
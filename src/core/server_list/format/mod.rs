@@ -0,0 +1,95 @@
+//! Conversions between the text formats used for MOTDs: legacy `§` codes,
+//! JSON text components and MiniMessage tags.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+mod convert;
+mod minimessage;
+mod preview;
+
+use convert::{parse_json_component, parse_legacy, write_json, write_legacy};
+use minimessage::{parse_minimessage, write_minimessage};
+pub use preview::{
+    IssueSeverity, MOTD_MAX_LENGTH, MOTD_MAX_LINES, MotdIssue, MotdPreview, PreviewSpan, SERVER_LIST_WIDTH, preview_motd,
+};
+
+pub(super) const COLORS: [(char, &str); 16] = [
+    ('0', "black"), ('1', "dark_blue"), ('2', "dark_green"), ('3', "dark_aqua"),
+    ('4', "dark_red"), ('5', "dark_purple"), ('6', "gold"), ('7', "gray"),
+    ('8', "dark_gray"), ('9', "blue"), ('a', "green"), ('b', "aqua"),
+    ('c', "red"), ('d', "light_purple"), ('e', "yellow"), ('f', "white"),
+];
+
+/// Decorations in legacy code order, with their JSON/MiniMessage name and MiniMessage aliases.
+pub(super) const DECORATIONS: [(char, &str, &[&str]); 5] = [
+    ('k', "obfuscated", &["obf"]),
+    ('l', "bold", &["b"]),
+    ('m', "strikethrough", &["st"]),
+    ('n', "underlined", &["u"]),
+    ('o', "italic", &["i", "em"]),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MotdFormat {
+    /// `§`-prefixed codes as stored in `server.properties`; `&` is accepted as an alias.
+    Legacy,
+    Json,
+    MiniMessage,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(super) struct Style {
+    /// A named color such as `gold`, or `#rrggbb`.
+    pub(super) color: Option<String>,
+    /// Enabled decorations, indexed like [`DECORATIONS`].
+    pub(super) decorations: [bool; 5],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Span {
+    pub(super) text: String,
+    pub(super) style: Style,
+}
+
+pub(super) fn push_span(spans: &mut Vec<Span>, text: &str, style: &Style) {
+    if text.is_empty() {
+        return;
+    }
+    match spans.last_mut() {
+        Some(last) if last.style == *style => last.text.push_str(text),
+        _ => spans.push(Span { text: text.to_string(), style: style.clone() }),
+    }
+}
+
+fn parse(input: &str, format: MotdFormat) -> anyhow::Result<Vec<Span>> {
+    Ok(match format {
+        MotdFormat::Legacy => parse_legacy(input),
+        MotdFormat::MiniMessage => parse_minimessage(&input.replace("\\<", "\u{0}")).into_iter()
+            .map(|s| Span { text: s.text.replace('\u{0}', "<"), style: s.style })
+            .collect(),
+        MotdFormat::Json => {
+            let value: JsonValue = serde_json::from_str(input)
+                .map_err(|e| anyhow::anyhow!("Invalid JSON text component: {}", e))?;
+            let mut spans = Vec::new();
+            parse_json_component(&value, &Style::default(), &mut spans);
+            spans
+        }
+    })
+}
+
+/// Converts `input` between MOTD formats. JSON output is a compact component string.
+pub fn convert_motd(input: &str, from: MotdFormat, to: MotdFormat) -> anyhow::Result<String> {
+    let spans = parse(input, from)?;
+    Ok(match to {
+        MotdFormat::Legacy => write_legacy(&spans),
+        MotdFormat::Json => write_json(&spans).to_string(),
+        MotdFormat::MiniMessage => write_minimessage(&spans),
+    })
+}
+
+/// The text with all formatting removed.
+pub fn strip_formatting(input: &str, format: MotdFormat) -> anyhow::Result<String> {
+    Ok(parse(input, format)?.into_iter().map(|s| s.text).collect())
+}
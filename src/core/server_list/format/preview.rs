@@ -0,0 +1,177 @@
+use super::convert::{resolve_color, unknown_codes, write_legacy};
+use super::{DECORATIONS, MotdFormat, Span, parse};
+use serde::Serialize;
+
+/// The server list shows two lines; anything after that is cut off by the client.
+pub const MOTD_MAX_LINES: usize = 2;
+pub const MOTD_MAX_LENGTH: usize = 1024;
+/// Room the multiplayer screen leaves for each MOTD line, in GUI pixels.
+pub const SERVER_LIST_WIDTH: u32 = 270;
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct PreviewSpan {
+    pub text: String,
+    /// Always `#rrggbb`, with named colors resolved to the client's palette.
+    pub color: String,
+    pub bold: bool,
+    pub italic: bool,
+    pub underlined: bool,
+    pub strikethrough: bool,
+    pub obfuscated: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum IssueSeverity {
+    /// The server would reject or mangle the MOTD; saving fails.
+    Error,
+    /// Saved as-is, but clients won't show it the way it was written.
+    Warning,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MotdIssue {
+    pub severity: IssueSeverity,
+    pub message: String,
+}
+
+/// What the multiplayer screen will show for a MOTD, line by line.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MotdPreview {
+    pub lines: Vec<Vec<PreviewSpan>>,
+    /// Approximate rendered width of each line in GUI pixels.
+    pub line_widths: Vec<u32>,
+    pub legacy: String,
+    pub plain: String,
+    pub issues: Vec<MotdIssue>,
+    /// False when any issue is an error.
+    pub valid: bool,
+}
+
+/// Advance of a character in the default Minecraft font, including the 1px gap.
+fn char_width(c: char) -> u32 {
+    match c {
+        '!' | ',' | '.' | ':' | ';' | 'i' | '|' | '\'' => 2,
+        'l' | '`' => 3,
+        'I' | 't' | '[' | ']' | '"' | ' ' => 4,
+        'f' | 'k' | '<' | '>' | '(' | ')' | '{' | '}' | '*' => 5,
+        '@' | '~' => 7,
+        _ => 6,
+    }
+}
+
+fn preview_span(span: &Span, text: &str) -> PreviewSpan {
+    let decoration = |name: &str| {
+        DECORATIONS
+            .iter()
+            .position(|(_, n, _)| *n == name)
+            .is_some_and(|i| span.style.decorations[i])
+    };
+    PreviewSpan {
+        text: text.to_string(),
+        color: resolve_color(span.style.color.as_deref()),
+        bold: decoration("bold"),
+        italic: decoration("italic"),
+        underlined: decoration("underlined"),
+        strikethrough: decoration("strikethrough"),
+        obfuscated: decoration("obfuscated"),
+    }
+}
+
+/// Renders `input` the way the server list will, and checks it against the limits
+/// `server.properties` and the client impose.
+pub fn preview_motd(input: &str, format: MotdFormat) -> MotdPreview {
+    let spans = match parse(input, format) {
+        Ok(spans) => spans,
+        Err(e) => {
+            return MotdPreview {
+                lines: vec![],
+                line_widths: vec![],
+                legacy: String::new(),
+                plain: String::new(),
+                issues: vec![MotdIssue {
+                    severity: IssueSeverity::Error,
+                    message: e.to_string(),
+                }],
+                valid: false,
+            };
+        }
+    };
+
+    let mut lines: Vec<Vec<PreviewSpan>> = vec![vec![]];
+    let mut line_widths = vec![0u32];
+    for span in &spans {
+        for (i, part) in span.text.split('\n').enumerate() {
+            if i > 0 {
+                lines.push(vec![]);
+                line_widths.push(0);
+            }
+            if part.is_empty() {
+                continue;
+            }
+            let preview = preview_span(span, part);
+            let bold = u32::from(preview.bold);
+            *line_widths.last_mut().unwrap() += part.chars().map(|c| char_width(c) + bold).sum::<u32>();
+            lines.last_mut().unwrap().push(preview);
+        }
+    }
+
+    let legacy = write_legacy(&spans);
+    let plain: String = spans.iter().map(|s| s.text.as_str()).collect();
+
+    let mut issues = Vec::new();
+    let mut issue = |severity, message: String| issues.push(MotdIssue { severity, message });
+
+    if lines.len() > MOTD_MAX_LINES {
+        issue(
+            IssueSeverity::Error,
+            format!(
+                "The MOTD has {} lines; the server list shows at most {}",
+                lines.len(),
+                MOTD_MAX_LINES
+            ),
+        );
+    }
+    if legacy.chars().count() > MOTD_MAX_LENGTH {
+        issue(
+            IssueSeverity::Error,
+            format!("The MOTD is longer than {} characters", MOTD_MAX_LENGTH),
+        );
+    }
+    for (i, width) in line_widths.iter().enumerate().take(MOTD_MAX_LINES) {
+        if *width > SERVER_LIST_WIDTH {
+            issue(
+                IssueSeverity::Warning,
+                format!(
+                    "Line {} is about {}px wide; the server list cuts it off after {}px",
+                    i + 1,
+                    width,
+                    SERVER_LIST_WIDTH
+                ),
+            );
+        }
+    }
+    if spans
+        .iter()
+        .any(|s| s.style.color.as_deref().is_some_and(|c| c.starts_with('#')))
+    {
+        issue(
+            IssueSeverity::Warning,
+            "Hex colors need 1.16+ clients; older clients show that text uncolored".to_string(),
+        );
+    }
+    if format == MotdFormat::Legacy {
+        for code in unknown_codes(input) {
+            issue(IssueSeverity::Warning, format!("Unknown formatting code {}", code));
+        }
+    }
+
+    let valid = !issues.iter().any(|i| i.severity == IssueSeverity::Error);
+    MotdPreview {
+        lines,
+        line_widths,
+        legacy,
+        plain,
+        issues,
+        valid,
+    }
+}
@@ -0,0 +1,68 @@
+use crate::utils::write_atomic;
+use anyhow::{Context, Result, anyhow};
+use image::imageops::FilterType;
+use image::{ImageFormat, ImageReader, Limits};
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+/// The server list only accepts 64x64 PNG icons.
+pub const ICON_SIZE: u32 = 64;
+const ICON_FILE: &str = "server-icon.png";
+const MAX_SOURCE_BYTES: u64 = 20 * 1024 * 1024;
+const MAX_SOURCE_DIMENSION: u32 = 8192;
+
+/// Decodes any supported image and center-crops/scales it to a 64x64 PNG.
+pub fn convert_icon(source: &Path) -> Result<Vec<u8>> {
+    let mut reader = ImageReader::open(source)
+        .with_context(|| format!("Failed to open {}", source.display()))?
+        .with_guessed_format()?;
+    if reader.format().is_none() {
+        return Err(anyhow!("{} is not a supported image", source.display()));
+    }
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_SOURCE_DIMENSION);
+    limits.max_image_height = Some(MAX_SOURCE_DIMENSION);
+    reader.limits(limits);
+
+    let image = reader.decode().context("Failed to decode image")?;
+    let image = if image.width() == ICON_SIZE && image.height() == ICON_SIZE {
+        image
+    } else {
+        image.resize_to_fill(ICON_SIZE, ICON_SIZE, FilterType::Lanczos3)
+    };
+
+    let mut png = Vec::new();
+    image
+        .to_rgba8()
+        .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+        .context("Failed to encode icon")?;
+    Ok(png)
+}
+
+/// Replaces the instance's `server-icon.png` with a converted copy of `source`.
+pub async fn set_server_icon(instance_path: &Path, source: &Path) -> Result<PathBuf> {
+    let metadata = fs::metadata(source)
+        .await
+        .with_context(|| format!("Image not found: {}", source.display()))?;
+    if !metadata.is_file() {
+        return Err(anyhow!("{} is not a file", source.display()));
+    }
+    if metadata.len() > MAX_SOURCE_BYTES {
+        return Err(anyhow!("Image is larger than {} MB", MAX_SOURCE_BYTES / 1024 / 1024));
+    }
+
+    let source = source.to_path_buf();
+    let png = tokio::task::spawn_blocking(move || convert_icon(&source)).await??;
+    let target = instance_path.join(ICON_FILE);
+    write_atomic(&target, png, false).await?;
+    Ok(target)
+}
+
+pub async fn remove_server_icon(instance_path: &Path) -> Result<()> {
+    let target = instance_path.join(ICON_FILE);
+    if target.exists() {
+        fs::remove_file(&target).await.context("Failed to remove server icon")?;
+    }
+    Ok(())
+}
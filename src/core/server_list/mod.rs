@@ -0,0 +1,12 @@
+//! What players see in the multiplayer server list: the MOTD and the server icon.
+
+pub mod format;
+pub mod icon;
+pub mod motd;
+
+pub use format::{
+    IssueSeverity, MotdFormat, MotdIssue, MotdPreview, PreviewSpan, SERVER_LIST_WIDTH, convert_motd, preview_motd,
+    strip_formatting,
+};
+pub use icon::{ICON_SIZE, convert_icon, remove_server_icon, set_server_icon};
+pub use motd::{Motd, read_motd, write_motd};
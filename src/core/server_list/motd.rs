@@ -0,0 +1,131 @@
+use super::format::{MOTD_MAX_LENGTH, MOTD_MAX_LINES, MotdFormat, convert_motd, strip_formatting};
+use crate::utils::write_atomic;
+use anyhow::{Context, Result, anyhow};
+use serde::Serialize;
+use std::path::Path;
+use tokio::fs;
+
+/// The current MOTD in every supported format, for editing and previews.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Motd {
+    pub legacy: String,
+    pub json: String,
+    pub mini_message: String,
+    pub plain: String,
+}
+
+impl Motd {
+    fn from_legacy(legacy: &str) -> Result<Self> {
+        Ok(Self {
+            legacy: legacy.to_string(),
+            json: convert_motd(legacy, MotdFormat::Legacy, MotdFormat::Json)?,
+            mini_message: convert_motd(legacy, MotdFormat::Legacy, MotdFormat::MiniMessage)?,
+            plain: strip_formatting(legacy, MotdFormat::Legacy)?,
+        })
+    }
+}
+
+/// Undoes `.properties` escaping (`§`, `\n`, `\:` ...), which is how `§` is stored.
+fn unescape_property(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('r') => out.push('\r'),
+            Some('u') => {
+                let hex: String = chars.by_ref().take(4).collect();
+                match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                    Some(decoded) => out.push(decoded),
+                    None => out.push_str(&format!("\\u{}", hex)),
+                }
+            }
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+    out
+}
+
+/// Escapes a value the way the server itself writes `server.properties`.
+fn escape_property(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '=' => out.push_str("\\="),
+            ':' => out.push_str("\\:"),
+            c if !c.is_ascii() || c.is_ascii_control() => {
+                let mut buf = [0u16; 2];
+                for unit in c.encode_utf16(&mut buf) {
+                    out.push_str(&format!("\\u{:04X}", unit));
+                }
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn is_motd_line(line: &str) -> bool {
+    line.trim_start()
+        .split(['=', ':'])
+        .next()
+        .is_some_and(|key| key.trim() == "motd")
+}
+
+pub async fn read_motd(instance_path: &Path) -> Result<Motd> {
+    let path = instance_path.join("server.properties");
+    let content = fs::read_to_string(&path).await.unwrap_or_default();
+    let raw = content
+        .lines()
+        .find(|line| is_motd_line(line))
+        .and_then(|line| line.split_once(['=', ':']))
+        .map(|(_, value)| unescape_property(value.trim_start()))
+        .unwrap_or_default();
+    Motd::from_legacy(&raw)
+}
+
+/// Converts `text` from `format` and stores it as the `motd` in `server.properties`,
+/// leaving every other line of the file untouched.
+pub async fn write_motd(instance_path: &Path, text: &str, format: MotdFormat) -> Result<Motd> {
+    let legacy = convert_motd(text, format, MotdFormat::Legacy)?;
+    let motd = Motd::from_legacy(&legacy)?;
+    if motd.plain.lines().count() > MOTD_MAX_LINES {
+        return Err(anyhow!("The MOTD can have at most {} lines", MOTD_MAX_LINES));
+    }
+    if legacy.chars().count() > MOTD_MAX_LENGTH {
+        return Err(anyhow!("The MOTD is longer than {} characters", MOTD_MAX_LENGTH));
+    }
+
+    let path = instance_path.join("server.properties");
+    let content = fs::read_to_string(&path).await.unwrap_or_default();
+    let new_line = format!("motd={}", escape_property(&legacy));
+    let mut replaced = false;
+    let mut lines: Vec<String> = content
+        .lines()
+        .map(|line| {
+            if !replaced && is_motd_line(line) {
+                replaced = true;
+                new_line.clone()
+            } else {
+                line.to_string()
+            }
+        })
+        .collect();
+    if !replaced {
+        lines.push(new_line);
+    }
+    let mut content = lines.join("\n");
+    content.push('\n');
+    write_atomic(&path, content, false)
+        .await
+        .context("Failed to write server.properties")?;
+    Ok(motd)
+}
@@ -1,7 +1,10 @@
 use std::collections::HashMap;
 use std::path::Path;
 use anyhow::{Result, Context};
+use serde::{Deserialize, Serialize};
 use tokio::fs;
+use tracing::warn;
+use crate::utils::write_atomic;
 
 pub async fn read_server_properties(instance_path: &Path) -> Result<HashMap<String, String>> {
     let props_path = instance_path.join("server.properties");
@@ -44,8 +47,78 @@ pub async fn write_server_properties(instance_path: &Path, properties: &HashMap<
         }
     }
 
-    fs::write(&props_path, content).await
+    write_atomic(&props_path, content, false).await
         .context("Failed to write server.properties")?;
     
     Ok(())
 }
+
+/// Values written to `server.properties` when an instance is created, before the
+/// server has had a chance to generate its own file.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ServerPropertiesDefaults {
+    /// MOTD template; `{name}` is replaced by the instance name.
+    pub motd: String,
+    pub view_distance: u32,
+    pub simulation_distance: u32,
+    pub max_players: u32,
+    pub difficulty: String,
+    pub gamemode: String,
+    pub online_mode: bool,
+    /// Log a warning when a new instance is created with `online-mode=false`.
+    pub warn_offline_mode: bool,
+}
+
+impl Default for ServerPropertiesDefaults {
+    fn default() -> Self {
+        Self {
+            motd: "{name}".to_string(),
+            view_distance: 10,
+            simulation_distance: 10,
+            max_players: 20,
+            difficulty: "easy".to_string(),
+            gamemode: "survival".to_string(),
+            online_mode: true,
+            warn_offline_mode: true,
+        }
+    }
+}
+
+impl ServerPropertiesDefaults {
+    pub fn to_properties(&self, instance_name: &str, port: u16) -> HashMap<String, String> {
+        // Line breaks would end the property early
+        let motd = self.motd.replace("{name}", instance_name).replace(['\r', '\n'], " ");
+        HashMap::from([
+            ("motd".to_string(), motd),
+            ("server-port".to_string(), port.to_string()),
+            ("view-distance".to_string(), self.view_distance.to_string()),
+            ("simulation-distance".to_string(), self.simulation_distance.to_string()),
+            ("max-players".to_string(), self.max_players.to_string()),
+            ("difficulty".to_string(), self.difficulty.clone()),
+            ("gamemode".to_string(), self.gamemode.clone()),
+            ("online-mode".to_string(), self.online_mode.to_string()),
+        ])
+    }
+}
+
+/// Writes `server.properties` from `defaults` unless the instance already has one.
+/// Returns whether a file was written.
+pub async fn write_default_server_properties(
+    instance_path: &Path,
+    instance_name: &str,
+    port: u16,
+    defaults: &ServerPropertiesDefaults,
+) -> Result<bool> {
+    if instance_path.join("server.properties").exists() {
+        return Ok(false);
+    }
+    if !defaults.online_mode && defaults.warn_offline_mode {
+        warn!(
+            "Instance {} is being created with online-mode=false; players will not be authenticated",
+            instance_name
+        );
+    }
+    write_server_properties(instance_path, &defaults.to_properties(instance_name, port)).await?;
+    Ok(true)
+}
@@ -0,0 +1,141 @@
+//! Shell commands run inside an instance folder, for tools like the MCA Selector CLI or
+//! the admin's own scripts.
+
+use anyhow::{Context, Result, anyhow};
+use std::path::Path;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::Command;
+
+/// How often a running command checks for cancellation.
+const CANCEL_POLL: Duration = Duration::from_millis(250);
+
+/// Marks command output in the console so it isn't mistaken for server output.
+pub const SHELL_LOG_PREFIX: &str = "[shell]";
+pub const MAX_COMMAND_LEN: usize = 4096;
+
+/// Environment variables passed through to commands. Everything else the wrapper was
+/// started with (API keys, tokens) is dropped.
+const PASSTHROUGH_ENV: [&str; 14] = [
+    "PATH",
+    "HOME",
+    "USER",
+    "LANG",
+    "LC_ALL",
+    "TERM",
+    "TMPDIR",
+    "JAVA_HOME",
+    "SystemRoot",
+    "COMSPEC",
+    "PATHEXT",
+    "TEMP",
+    "TMP",
+    "USERPROFILE",
+];
+
+/// Trims `command` and rejects empty or oversized ones.
+pub fn validate_command(command: &str) -> Result<&str> {
+    let command = command.trim();
+    if command.is_empty() {
+        return Err(anyhow!("Command is empty"));
+    }
+    if command.len() > MAX_COMMAND_LEN {
+        return Err(anyhow!("Commands are limited to {} characters", MAX_COMMAND_LEN));
+    }
+    if command.contains('\0') {
+        return Err(anyhow!("Command contains a NUL character"));
+    }
+    Ok(command)
+}
+
+/// The variables of `vars` a command is allowed to see.
+pub fn sandbox_env(vars: impl IntoIterator<Item = (String, String)>) -> Vec<(String, String)> {
+    vars.into_iter()
+        .filter(|(key, _)| PASSTHROUGH_ENV.iter().any(|allowed| allowed.eq_ignore_ascii_case(key)))
+        .collect()
+}
+
+/// `sh -c` (or `cmd /C` on Windows) for `command`, with `instance_dir` as the working
+/// directory and `INSTANCE_DIR` set to it. Output is piped and stdin closed, and the
+/// process is killed if the returned `Command`'s child is dropped.
+pub fn shell_command(command: &str, instance_dir: &Path) -> Command {
+    #[cfg(target_os = "windows")]
+    let mut cmd = {
+        let mut cmd = Command::new("cmd");
+        cmd.arg("/C").arg(command);
+        // CREATE_NO_WINDOW
+        cmd.creation_flags(0x08000000);
+        cmd
+    };
+    #[cfg(not(target_os = "windows"))]
+    let mut cmd = {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c").arg(command);
+        cmd
+    };
+
+    cmd.current_dir(instance_dir)
+        .env_clear()
+        .envs(sandbox_env(std::env::vars()))
+        .env("INSTANCE_DIR", instance_dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    cmd
+}
+
+/// Spawns `cmd` and passes each line of its stdout and stderr to `emit` until it exits.
+/// The process is killed once `timeout` passes or `is_cancelled` returns true, and a
+/// non-zero exit is an error.
+pub async fn run_streamed(
+    mut cmd: Command,
+    timeout: Duration,
+    is_cancelled: impl Fn() -> bool,
+    mut emit: impl FnMut(String),
+) -> Result<()> {
+    let mut child = cmd.spawn().context("Failed to start the shell")?;
+    let mut stdout = BufReader::new(child.stdout.take().context("No stdout")?).lines();
+    let mut stderr = BufReader::new(child.stderr.take().context("No stderr")?).lines();
+    let (mut stdout_open, mut stderr_open) = (true, true);
+    let deadline = tokio::time::Instant::now() + timeout;
+    let mut poll = tokio::time::interval(CANCEL_POLL);
+
+    while stdout_open || stderr_open {
+        tokio::select! {
+            line = stdout.next_line(), if stdout_open => match line? {
+                Some(line) => emit(line),
+                None => stdout_open = false,
+            },
+            line = stderr.next_line(), if stderr_open => match line? {
+                Some(line) => emit(line),
+                None => stderr_open = false,
+            },
+            _ = poll.tick() => {
+                if is_cancelled() {
+                    child.kill().await?;
+                    return Err(anyhow!("Cancelled"));
+                }
+                if tokio::time::Instant::now() >= deadline {
+                    child.kill().await?;
+                    return Err(anyhow!("Killed after running for {}s", timeout.as_secs()));
+                }
+            }
+        }
+    }
+
+    // A command can close its output and keep running
+    let status = match tokio::time::timeout_at(deadline, child.wait()).await {
+        Ok(status) => status?,
+        Err(_) => {
+            child.kill().await?;
+            return Err(anyhow!("Killed after running for {}s", timeout.as_secs()));
+        }
+    };
+    match status.code() {
+        Some(0) => Ok(()),
+        Some(code) => Err(anyhow!("Exited with code {}", code)),
+        None => Err(anyhow!("Terminated by a signal")),
+    }
+}
@@ -0,0 +1,188 @@
+use crate::credentials::{CREDENTIALS_FILE, LEGACY_KEY_FILE};
+use crate::instance::archive::copy_dir_all;
+use crate::server::PidFile;
+use crate::utils::write_atomic;
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// Settings file whose presence marks a folder as holding app data.
+pub const SETTINGS_FILE: &str = "app_settings.json";
+
+/// Records a storage mode switch to carry out on the next start, before anything is open.
+pub const MIGRATION_FILE: &str = "storage_migration.json";
+
+/// Everything the app keeps in its data folder, moved as a whole between modes.
+pub const APP_DATA_ITEMS: [&str; 9] = [
+    SETTINGS_FILE,
+    CREDENTIALS_FILE,
+    LEGACY_KEY_FILE,
+    "backups",
+    "resources",
+    "server",
+    "cache",
+    "java",
+    "logs",
+];
+
+/// Where the app keeps its settings, servers and caches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageMode {
+    /// Next to the executable, so the whole folder can be carried around.
+    Portable,
+    /// In the user's app data folder, for installs in read-only places like Program Files.
+    Installed,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PendingMigration {
+    target: StorageMode,
+}
+
+/// The data folder of each storage mode.
+#[derive(Debug, Clone)]
+pub struct DataLocations {
+    pub portable: PathBuf,
+    pub installed: PathBuf,
+}
+
+impl DataLocations {
+    pub fn new(exe_dir: impl Into<PathBuf>, app_data_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            portable: exe_dir.into(),
+            installed: app_data_dir.into(),
+        }
+    }
+
+    pub fn root(&self, mode: StorageMode) -> &Path {
+        match mode {
+            StorageMode::Portable => &self.portable,
+            StorageMode::Installed => &self.installed,
+        }
+    }
+
+    /// The mode whose folder already holds app data, portable first so existing
+    /// installs keep working. None on the first run.
+    pub fn detect(&self) -> Option<StorageMode> {
+        [StorageMode::Portable, StorageMode::Installed]
+            .into_iter()
+            .find(|mode| self.root(*mode).join(SETTINGS_FILE).exists())
+    }
+
+    /// Whether the executable's folder can be written to, which portable mode needs.
+    pub fn portable_available(&self) -> bool {
+        let probe = self.portable.join(format!(".write-test-{}", Uuid::new_v4()));
+        let writable = std::fs::write(&probe, b"").is_ok();
+        let _ = std::fs::remove_file(&probe);
+        writable
+    }
+
+    /// The mode a switch was requested to, if one is waiting for the next start.
+    pub async fn pending_migration(&self, current: StorageMode) -> Option<StorageMode> {
+        let content = fs::read_to_string(self.root(current).join(MIGRATION_FILE)).await.ok()?;
+        let pending: PendingMigration = serde_json::from_str(&content).ok()?;
+        Some(pending.target).filter(|target| *target != current)
+    }
+
+    /// Requests a switch to `target` on the next start, or withdraws a request when
+    /// `target` is the current mode.
+    pub async fn request_migration(&self, current: StorageMode, target: StorageMode) -> Result<()> {
+        let marker = self.root(current).join(MIGRATION_FILE);
+        if target == current {
+            let _ = fs::remove_file(marker).await;
+            return Ok(());
+        }
+        if target == StorageMode::Portable && !self.portable_available() {
+            return Err(anyhow!("The app's folder {} isn't writable, so portable mode isn't available", self.portable.display()));
+        }
+        write_atomic(&marker, serde_json::to_string_pretty(&PendingMigration { target })?, false).await
+    }
+
+    /// Carries out a requested switch: moves the app data to the other mode's folder and
+    /// returns the new mode. The request is dropped either way, so a failing move
+    /// doesn't block every start.
+    pub async fn run_pending_migration(&self, current: StorageMode) -> Result<Option<StorageMode>> {
+        let Some(target) = self.pending_migration(current).await else {
+            return Ok(None);
+        };
+        let _ = fs::remove_file(self.root(current).join(MIGRATION_FILE)).await;
+        migrate_app_data(self.root(current), self.root(target)).await?;
+        Ok(Some(target))
+    }
+}
+
+/// Moves every [`APP_DATA_ITEMS`] entry from one data folder to another. Refuses when
+/// the target already holds app data or a server is still running from `from`, and
+/// moves everything back if one item fails. Returns the items moved.
+pub async fn migrate_app_data(from: &Path, to: &Path) -> Result<Vec<String>> {
+    let items: Vec<&str> = APP_DATA_ITEMS.into_iter().filter(|item| from.join(item).exists()).collect();
+    if let Some(existing) = items.iter().find(|item| to.join(item).exists()) {
+        return Err(anyhow!("{} already exists in {}", existing, to.display()));
+    }
+    if let Some(running) = running_server(&from.join("server")).await {
+        return Err(anyhow!("The server in {} is still running; stop it first", running.display()));
+    }
+    fs::create_dir_all(to)
+        .await
+        .with_context(|| format!("Failed to create {}", to.display()))?;
+
+    let mut moved = Vec::new();
+    for item in &items {
+        if let Err(e) = move_item(&from.join(item), &to.join(item)).await {
+            for done in moved.iter().rev() {
+                if let Err(back) = move_item(&to.join(done), &from.join(done)).await {
+                    warn!("Failed to move {} back to {:?}: {}", done, from, back);
+                }
+            }
+            return Err(e.context(format!("Failed to move {} to {}", item, to.display())));
+        }
+        moved.push(item.to_string());
+    }
+    info!("Moved app data from {:?} to {:?}", from, to);
+    Ok(moved)
+}
+
+/// An instance folder under `server_dir` whose server process is still alive.
+async fn running_server(server_dir: &Path) -> Option<PathBuf> {
+    let mut entries = fs::read_dir(server_dir).await.ok()?;
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let path = entry.path();
+        if let Some(pid_file) = PidFile::read(&path).await
+            && pid_file.is_alive()
+        {
+            return Some(path);
+        }
+    }
+    None
+}
+
+/// Renames `from` to `to`, copying and then removing it when they are on different disks.
+async fn move_item(from: &Path, to: &Path) -> Result<()> {
+    if fs::rename(from, to).await.is_ok() {
+        return Ok(());
+    }
+    let copied = if from.is_dir() {
+        copy_dir_all(from, to, |_, _, _| {}).await
+    } else {
+        fs::copy(from, to).await.map(|_| ()).map_err(Into::into)
+    };
+    if let Err(e) = copied {
+        let _ = if to.is_dir() { fs::remove_dir_all(to).await } else { fs::remove_file(to).await };
+        return Err(e);
+    }
+    if from.is_dir() {
+        fs::remove_dir_all(from).await?;
+    } else {
+        fs::remove_file(from).await?;
+    }
+    Ok(())
+}
+
+/// `path` moved from under `old_root` to under `new_root`, or None when it isn't under `old_root`.
+pub fn rebase_path(path: &Path, old_root: &Path, new_root: &Path) -> Option<PathBuf> {
+    path.strip_prefix(old_root).ok().map(|relative| new_root.join(relative))
+}
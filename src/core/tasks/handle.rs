@@ -0,0 +1,133 @@
+use super::{CancelFlag, PauseFlag, TaskInfo, TaskState, Tasks, lock, prune_finished};
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+/// Progress is broadcast at most this often per task; the stored state is always current.
+const EMIT_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Owned by the code running a task. A handle dropped without [`TaskHandle::finish`]
+/// (e.g. an early return or panic) marks its task as failed.
+pub struct TaskHandle {
+    id: Uuid,
+    cancel: CancelFlag,
+    pause: PauseFlag,
+    tasks: Arc<Tasks>,
+    sender: broadcast::Sender<TaskInfo>,
+}
+
+impl TaskHandle {
+    pub(super) fn new(
+        id: Uuid,
+        cancel: CancelFlag,
+        pause: PauseFlag,
+        tasks: Arc<Tasks>,
+        sender: broadcast::Sender<TaskInfo>,
+    ) -> Self {
+        Self { id, cancel, pause, tasks, sender }
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    /// Marks the task as cancellable and returns the flag the operation should poll.
+    pub fn cancel_flag(&self) -> CancelFlag {
+        update(&self.tasks, &self.sender, self.id, true, |info| info.cancellable = true);
+        self.cancel.clone()
+    }
+
+    /// Marks the task as pausable and returns the flag the operation should poll.
+    pub fn pause_flag(&self) -> PauseFlag {
+        update(&self.tasks, &self.sender, self.id, true, |info| info.pausable = true);
+        self.pause.clone()
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.is_cancelled()
+    }
+
+    pub fn progress(&self, current: u64, total: u64, message: impl Into<String>) {
+        report(&self.tasks, &self.sender, self.id, current, total, message.into());
+    }
+
+    /// A progress callback in the `(current, total, message)` shape the core operations take.
+    pub fn reporter(&self) -> impl Fn(u64, u64, String) + Send + Sync + 'static {
+        let tasks = Arc::clone(&self.tasks);
+        let sender = self.sender.clone();
+        let id = self.id;
+        move |current, total, message| report(&tasks, &sender, id, current, total, message)
+    }
+
+    /// Records how the operation ended and passes its result through. Errors after a
+    /// cancellation request are reported as cancelled rather than failed.
+    pub fn finish<T>(self, result: anyhow::Result<T>) -> anyhow::Result<T> {
+        let (state, error) = match &result {
+            Ok(_) => (TaskState::Completed, None),
+            Err(_) if self.cancel.is_cancelled() => (TaskState::Cancelled, None),
+            Err(e) => (TaskState::Failed, Some(e.to_string())),
+        };
+        self.close(state, error);
+        result
+    }
+
+    fn close(&self, state: TaskState, error: Option<String>) {
+        update(&self.tasks, &self.sender, self.id, true, |info| {
+            if state == TaskState::Completed && info.total > 0 {
+                info.current = info.total;
+            }
+            info.state = state;
+            info.paused = false;
+            info.error = error;
+            info.finished_at = Some(Utc::now());
+        });
+        prune_finished(&mut lock(&self.tasks));
+    }
+}
+
+impl Drop for TaskHandle {
+    fn drop(&mut self) {
+        self.close(TaskState::Failed, Some("Task ended unexpectedly".to_string()));
+    }
+}
+
+fn report(
+    tasks: &Tasks,
+    sender: &broadcast::Sender<TaskInfo>,
+    id: Uuid,
+    current: u64,
+    total: u64,
+    message: String,
+) {
+    let force = total > 0 && current >= total;
+    update(tasks, sender, id, force, |info| {
+        info.current = current;
+        info.total = total;
+        info.message = message;
+    });
+}
+
+/// Applies `change` to a running task and broadcasts it unless the last broadcast was too recent.
+fn update(
+    tasks: &Tasks,
+    sender: &broadcast::Sender<TaskInfo>,
+    id: Uuid,
+    force: bool,
+    change: impl FnOnce(&mut TaskInfo),
+) {
+    let mut tasks = lock(tasks);
+    let Some(task) = tasks.get_mut(&id) else {
+        return;
+    };
+    if task.info.state != TaskState::Running {
+        return;
+    }
+    change(&mut task.info);
+    let due = task.last_emit.is_none_or(|last| last.elapsed() >= EMIT_INTERVAL);
+    if force || due || task.info.state != TaskState::Running {
+        task.last_emit = Some(Instant::now());
+        let _ = sender.send(task.info.clone());
+    }
+}
@@ -0,0 +1,153 @@
+//! Registry of long-running operations (imports, downloads, installs, backups...) so the UI
+//! can show them in one place and cancel the ones that support it.
+
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Instant;
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+mod handle;
+mod types;
+
+pub use handle::TaskHandle;
+pub use types::*;
+
+/// Finished tasks kept around so the UI can still show how they ended.
+const MAX_FINISHED_TASKS: usize = 50;
+
+struct TrackedTask {
+    info: TaskInfo,
+    cancel: CancelFlag,
+    pause: PauseFlag,
+    last_emit: Option<Instant>,
+}
+
+type Tasks = Mutex<HashMap<Uuid, TrackedTask>>;
+
+/// Progress is reported from plain callbacks, so this is a std lock; a reporter that
+/// panicked mid-update leaves at worst one stale task, which is better than losing them all.
+fn lock(tasks: &Tasks) -> MutexGuard<'_, HashMap<Uuid, TrackedTask>> {
+    tasks.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+pub struct TaskManager {
+    tasks: Arc<Tasks>,
+    sender: broadcast::Sender<TaskInfo>,
+}
+
+impl Default for TaskManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TaskManager {
+    pub fn new() -> Self {
+        Self {
+            tasks: Arc::new(Mutex::new(HashMap::new())),
+            sender: broadcast::channel(64).0,
+        }
+    }
+
+    /// Registers a new running task; progress and the outcome are reported through the handle.
+    pub fn start(&self, kind: TaskKind, title: impl Into<String>, instance_id: Option<Uuid>) -> TaskHandle {
+        self.start_with_id(Uuid::new_v4(), kind, title, instance_id)
+    }
+
+    /// Like [`TaskManager::start`], for callers that picked the id up front so they can
+    /// cancel the task before the command that runs it returns.
+    pub fn start_with_id(
+        &self,
+        id: Uuid,
+        kind: TaskKind,
+        title: impl Into<String>,
+        instance_id: Option<Uuid>,
+    ) -> TaskHandle {
+        let info = TaskInfo {
+            id,
+            kind,
+            title: title.into(),
+            instance_id,
+            current: 0,
+            total: 0,
+            message: String::new(),
+            state: TaskState::Running,
+            error: None,
+            cancellable: false,
+            pausable: false,
+            paused: false,
+            started_at: Utc::now(),
+            finished_at: None,
+        };
+        let cancel = CancelFlag::default();
+        let pause = PauseFlag::default();
+        let tracked = TrackedTask {
+            info: info.clone(),
+            cancel: cancel.clone(),
+            pause: pause.clone(),
+            last_emit: None,
+        };
+        lock(&self.tasks).insert(id, tracked);
+        let _ = self.sender.send(info);
+        TaskHandle::new(id, cancel, pause, Arc::clone(&self.tasks), self.sender.clone())
+    }
+
+    /// All known tasks, oldest first.
+    pub fn list(&self) -> Vec<TaskInfo> {
+        let mut tasks: Vec<TaskInfo> = lock(&self.tasks).values().map(|t| t.info.clone()).collect();
+        tasks.sort_by_key(|t| t.started_at);
+        tasks
+    }
+
+    pub fn get(&self, id: Uuid) -> Option<TaskInfo> {
+        lock(&self.tasks).get(&id).map(|t| t.info.clone())
+    }
+
+    /// Asks a running, cancellable task to stop. Returns false when there is nothing to cancel.
+    pub fn cancel(&self, id: Uuid) -> bool {
+        let tasks = lock(&self.tasks);
+        match tasks.get(&id) {
+            Some(task) if task.info.state == TaskState::Running && task.info.cancellable => {
+                task.cancel.cancel();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Pauses or resumes a running, pausable task. Returns false when there is nothing to change.
+    pub fn set_paused(&self, id: Uuid, paused: bool) -> bool {
+        let mut tasks = lock(&self.tasks);
+        match tasks.get_mut(&id) {
+            Some(task) if task.info.state == TaskState::Running && task.info.pausable => {
+                task.pause.set(paused);
+                task.info.paused = paused;
+                let _ = self.sender.send(task.info.clone());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Every change to any task: creation, throttled progress and the final state.
+    pub fn subscribe(&self) -> broadcast::Receiver<TaskInfo> {
+        self.sender.subscribe()
+    }
+}
+
+/// Drops the oldest finished tasks beyond [`MAX_FINISHED_TASKS`].
+fn prune_finished(tasks: &mut HashMap<Uuid, TrackedTask>) {
+    let mut finished: Vec<(DateTime<Utc>, Uuid)> = tasks
+        .values()
+        .filter_map(|t| t.info.finished_at.map(|at| (at, t.info.id)))
+        .collect();
+    if finished.len() <= MAX_FINISHED_TASKS {
+        return;
+    }
+    finished.sort();
+    for (_, id) in &finished[..finished.len() - MAX_FINISHED_TASKS] {
+        tasks.remove(id);
+    }
+}
@@ -0,0 +1,89 @@
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use uuid::Uuid;
+
+/// Shared flag that long operations poll to stop early.
+#[derive(Debug, Clone, Default)]
+pub struct CancelFlag(Arc<AtomicBool>);
+
+impl CancelFlag {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn check(&self) -> Result<()> {
+        if self.is_cancelled() {
+            return Err(anyhow!("Operation cancelled"));
+        }
+        Ok(())
+    }
+}
+
+/// Shared flag that pausable operations poll to hold their work.
+#[derive(Debug, Clone, Default)]
+pub struct PauseFlag(Arc<AtomicBool>);
+
+impl PauseFlag {
+    pub fn set(&self, paused: bool) {
+        self.0.store(paused, Ordering::Relaxed);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TaskKind {
+    Import,
+    Export,
+    Download,
+    Install,
+    Modpack,
+    Backup,
+    Java,
+    Pregen,
+    Shell,
+    WorldUpgrade,
+    Move,
+    Profile,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TaskState {
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskInfo {
+    pub id: Uuid,
+    pub kind: TaskKind,
+    pub title: String,
+    pub instance_id: Option<Uuid>,
+    pub current: u64,
+    pub total: u64,
+    pub message: String,
+    pub state: TaskState,
+    pub error: Option<String>,
+    /// Whether [`TaskManager::cancel`](super::TaskManager::cancel) has any effect on this task.
+    pub cancellable: bool,
+    /// Whether [`TaskManager::set_paused`](super::TaskManager::set_paused) has any effect on this task.
+    #[serde(default)]
+    pub pausable: bool,
+    #[serde(default)]
+    pub paused: bool,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
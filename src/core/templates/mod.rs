@@ -0,0 +1,139 @@
+//! Reusable instance templates: settings, loader, content sources and selected
+//! config files, provisioned into new instances without copying any jars or worlds.
+
+use crate::instance::{InstanceMetadata, InstanceSettings};
+use crate::mods::{self, ModProvider};
+use crate::plugins::{self, PluginProvider};
+use crate::utils::safe_join;
+use anyhow::{Context, Result, anyhow};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+
+pub mod store;
+
+/// Config files are stored inline in the database, so keep them small.
+const MAX_CONFIG_FILE_SIZE: u64 = 1024 * 1024;
+
+/// A mod or plugin that can be downloaded again from its provider.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TemplateContent {
+    Mod {
+        project_id: String,
+        provider: ModProvider,
+        version_id: Option<String>,
+        enabled: bool,
+    },
+    Plugin {
+        project_id: String,
+        provider: PluginProvider,
+        version_id: Option<String>,
+        enabled: bool,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TemplateConfigFile {
+    /// Path relative to the instance root, using `/` separators.
+    pub rel_path: String,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstanceTemplate {
+    pub id: i64,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub version: String,
+    pub mod_loader: Option<String>,
+    pub loader_version: Option<String>,
+    pub settings: InstanceSettings,
+    #[serde(default)]
+    pub content: Vec<TemplateContent>,
+    #[serde(default)]
+    pub config_files: Vec<TemplateConfigFile>,
+    /// Jars without a known source; these cannot be re-downloaded.
+    #[serde(default)]
+    pub skipped: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Result of provisioning an instance from a template.
+#[derive(Debug, Clone, Serialize)]
+pub struct TemplateReport {
+    pub instance: InstanceMetadata,
+    /// Filenames that were downloaded.
+    pub installed: Vec<String>,
+    /// Content or config files that could not be restored, with the reason.
+    pub failed: Vec<String>,
+}
+
+/// Builds a template from `instance`. The id is assigned when it is stored.
+pub async fn capture_template(
+    instance: &InstanceMetadata,
+    name: &str,
+    description: Option<String>,
+    config_files: &[String],
+) -> Result<InstanceTemplate> {
+    let mut content = Vec::new();
+    let mut skipped = Vec::new();
+
+    for installed in mods::list_installed_mods(&instance.path).await.context("Failed to list mods")? {
+        match installed.source {
+            Some(source) => content.push(TemplateContent::Mod {
+                project_id: source.project_id,
+                provider: source.provider,
+                version_id: source.current_version_id,
+                enabled: installed.enabled,
+            }),
+            None => skipped.push(installed.filename),
+        }
+    }
+    for installed in plugins::list_installed_plugins(&instance.path).await.context("Failed to list plugins")? {
+        match installed.source {
+            Some(source) => content.push(TemplateContent::Plugin {
+                project_id: source.project_id,
+                provider: source.provider,
+                version_id: source.current_version_id,
+                enabled: installed.enabled,
+            }),
+            None => skipped.push(installed.filename),
+        }
+    }
+
+    let mut files = Vec::new();
+    for rel_path in config_files {
+        let rel_path = rel_path.replace('\\', "/");
+        let path = safe_join(&instance.path, &rel_path)?;
+        let size = fs::metadata(&path).await
+            .with_context(|| format!("Config file not found: {}", rel_path))?
+            .len();
+        if size > MAX_CONFIG_FILE_SIZE {
+            return Err(anyhow!("{} is too large to store in a template", rel_path));
+        }
+        let content = fs::read_to_string(&path).await
+            .with_context(|| format!("{} is not a text file", rel_path))?;
+        files.push(TemplateConfigFile { rel_path, content });
+    }
+
+    // Lineage and icons belong to the source instance, not to what is built from it
+    let mut settings = instance.settings.clone();
+    settings.lineage_id = None;
+    settings.icon_path = None;
+
+    Ok(InstanceTemplate {
+        id: 0,
+        name: name.trim().to_string(),
+        description,
+        version: instance.version.clone(),
+        mod_loader: instance.mod_loader.clone(),
+        loader_version: instance.loader_version.clone(),
+        settings,
+        content,
+        config_files: files,
+        skipped,
+        created_at: Utc::now(),
+    })
+}
@@ -0,0 +1,75 @@
+use super::{InstanceTemplate, capture_template};
+use crate::instance::InstanceManager;
+use anyhow::{Context, Result, anyhow};
+use chrono::Utc;
+use sqlx::Row;
+use uuid::Uuid;
+
+impl InstanceManager {
+    /// Saves `instance_id` as a template named `name`, replacing any template with that name.
+    pub async fn save_instance_as_template(
+        &self,
+        instance_id: Uuid,
+        name: &str,
+        description: Option<String>,
+        config_files: &[String],
+    ) -> Result<InstanceTemplate> {
+        if name.trim().is_empty() {
+            return Err(anyhow!("Template name cannot be empty"));
+        }
+        let instance = self.get_instance(instance_id).await?.context("Instance not found")?;
+        let mut template = capture_template(&instance, name, description, config_files).await?;
+
+        let data = serde_json::to_string(&template)?;
+        let row = sqlx::query(
+            "INSERT INTO instance_templates (name, data, created_at) VALUES (?, ?, ?)
+                ON CONFLICT(name) DO UPDATE SET data = excluded.data, created_at = excluded.created_at
+                RETURNING id",
+        )
+        .bind(&template.name)
+        .bind(data)
+        .bind(Utc::now().to_rfc3339())
+        .fetch_one(self.db.pool())
+        .await
+        .context("Failed to save template")?;
+        template.id = row.get("id");
+        Ok(template)
+    }
+
+    pub async fn list_templates(&self) -> Result<Vec<InstanceTemplate>> {
+        let rows = sqlx::query("SELECT id, data FROM instance_templates ORDER BY name COLLATE NOCASE")
+            .fetch_all(self.db.pool())
+            .await?;
+        Ok(rows
+            .iter()
+            .filter_map(|row| {
+                let data: String = row.get("data");
+                let mut template: InstanceTemplate = serde_json::from_str(&data).ok()?;
+                template.id = row.get("id");
+                Some(template)
+            })
+            .collect())
+    }
+
+    pub async fn get_template(&self, id: i64) -> Result<Option<InstanceTemplate>> {
+        let row = sqlx::query("SELECT data FROM instance_templates WHERE id = ?")
+            .bind(id)
+            .fetch_optional(self.db.pool())
+            .await?;
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let data: String = row.get("data");
+        let mut template: InstanceTemplate = serde_json::from_str(&data).context("Corrupt template data")?;
+        template.id = id;
+        Ok(Some(template))
+    }
+
+    pub async fn delete_template(&self, id: i64) -> Result<()> {
+        sqlx::query("DELETE FROM instance_templates WHERE id = ?")
+            .bind(id)
+            .execute(self.db.pool())
+            .await?;
+        Ok(())
+    }
+}
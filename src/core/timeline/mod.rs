@@ -0,0 +1,47 @@
+//! Per-instance timeline of notable events (crashes, restarts, updates, backups,
+//! config changes, console and terminal commands, failed tasks and world upgrades), so
+//! "it broke after X" can be traced back to X.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+mod sources;
+mod store;
+
+/// Events returned by [`crate::instance::InstanceManager::get_instance_timeline`] when no limit is given.
+pub const DEFAULT_TIMELINE_LIMIT: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display, strum::EnumString)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum TimelineKind {
+    /// The server process exited unexpectedly.
+    Crash,
+    /// A crash report written by the server.
+    CrashReport,
+    /// Started again automatically after a crash.
+    Restart,
+    /// A new server build, or a mod/plugin version, was installed.
+    UpdateApplied,
+    Backup,
+    /// Instance settings or a config file were changed.
+    ConfigChange,
+    /// A command was sent to the server console.
+    ConsoleCommand,
+    /// A command was run from the instance terminal.
+    ShellCommand,
+    /// A long-running task, such as an install or an import, failed.
+    TaskFailed,
+    /// The world's chunks were converted to the server's version with `--forceUpgrade`.
+    WorldUpgrade,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineEvent {
+    pub instance_id: Uuid,
+    pub kind: TimelineKind,
+    pub occurred_at: DateTime<Utc>,
+    pub summary: String,
+    pub details: Option<String>,
+}
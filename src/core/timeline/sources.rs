@@ -0,0 +1,97 @@
+//! Timeline entries that aren't recorded as timeline events: crash reports, the console
+//! command log and failed tasks still held by the event bus.
+
+use super::{TimelineEvent, TimelineKind};
+use crate::crash::{CRASH_REPORTS_DIR, CrashReportSummary};
+use crate::events::{EventBus, EventChannel};
+use crate::instance::CommandHistoryEntry;
+use crate::tasks::{TaskInfo, TaskState};
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone, Utc};
+use std::collections::HashMap;
+use std::path::Path;
+use tokio::fs;
+use uuid::Uuid;
+
+/// `Time:` formats written by current and older servers, in the server's local time.
+const REPORT_TIME_FORMATS: &[&str] = &["%Y-%m-%d %H:%M:%S%.f", "%m/%d/%y %I:%M %p", "%d/%m/%y %H:%M"];
+
+fn parse_report_time(time: &str) -> Option<DateTime<Utc>> {
+    REPORT_TIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(time.trim(), format).ok())
+        .and_then(|naive| Local.from_local_datetime(&naive).earliest())
+        .map(|local| local.with_timezone(&Utc))
+}
+
+/// When the crash happened: the report's `Time:` header, else the file's modification
+/// time, else when the wrapper first saw the file.
+async fn report_time(report: &CrashReportSummary, instance_path: &Path) -> DateTime<Utc> {
+    if let Some(time) = report.occurred_at.as_deref().and_then(parse_report_time) {
+        return time;
+    }
+    let path = instance_path.join(CRASH_REPORTS_DIR).join(&report.filename);
+    match fs::metadata(&path).await.and_then(|m| m.modified()) {
+        Ok(modified) => modified.into(),
+        Err(_) => report.detected_at,
+    }
+}
+
+pub(super) async fn crash_report_events(
+    instance_id: Uuid,
+    instance_path: &Path,
+    reports: Vec<CrashReportSummary>,
+) -> Vec<TimelineEvent> {
+    let mut events = Vec::with_capacity(reports.len());
+    for report in reports {
+        let summary = match &report.likely_culprit {
+            Some(culprit) => format!("Crash report {} (likely caused by {})", report.filename, culprit),
+            None => format!("Crash report {}", report.filename),
+        };
+        events.push(TimelineEvent {
+            instance_id,
+            kind: TimelineKind::CrashReport,
+            occurred_at: report_time(&report, instance_path).await,
+            summary,
+            details: report.description.or(report.exception),
+        });
+    }
+    events
+}
+
+pub(super) fn command_events(instance_id: Uuid, commands: Vec<CommandHistoryEntry>) -> Vec<TimelineEvent> {
+    commands
+        .into_iter()
+        .map(|entry| TimelineEvent {
+            instance_id,
+            kind: TimelineKind::ConsoleCommand,
+            occurred_at: entry.sent_at,
+            summary: format!("Ran /{}", entry.command.trim_start_matches('/')),
+            details: None,
+        })
+        .collect()
+}
+
+/// Tasks of the instance that failed, from the updates the event bus still holds.
+/// Finished tasks that change the instance record their own entries.
+pub(super) fn failed_task_events(instance_id: Uuid, bus: &EventBus) -> Vec<TimelineEvent> {
+    let mut latest: HashMap<Uuid, TaskInfo> = HashMap::new();
+    for event in bus.recent(Some(instance_id), 0) {
+        if event.channel != EventChannel::Task {
+            continue;
+        }
+        if let Ok(task) = serde_json::from_value::<TaskInfo>(event.payload) {
+            latest.insert(task.id, task);
+        }
+    }
+    latest
+        .into_values()
+        .filter(|task| task.state == TaskState::Failed)
+        .map(|task| TimelineEvent {
+            instance_id,
+            kind: TimelineKind::TaskFailed,
+            occurred_at: task.finished_at.unwrap_or(task.started_at),
+            summary: format!("{} failed", task.title),
+            details: task.error,
+        })
+        .collect()
+}
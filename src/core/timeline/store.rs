@@ -0,0 +1,91 @@
+use super::sources::{command_events, crash_report_events, failed_task_events};
+use super::{TimelineEvent, TimelineKind};
+use crate::events::EventBus;
+use crate::instance::InstanceManager;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use std::str::FromStr;
+use tracing::warn;
+use uuid::Uuid;
+
+fn parse_time(value: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|d| d.with_timezone(&Utc))
+        .unwrap_or_else(|_| Utc::now())
+}
+
+impl InstanceManager {
+    pub async fn record_timeline_event(
+        &self,
+        instance_id: Uuid,
+        kind: TimelineKind,
+        summary: impl Into<String>,
+        details: Option<String>,
+    ) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO timeline_events (instance_id, kind, occurred_at, summary, details) VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(instance_id.to_string())
+        .bind(kind.to_string())
+        .bind(Utc::now().to_rfc3339())
+        .bind(summary.into())
+        .bind(details)
+        .execute(self.db.pool())
+        .await?;
+        Ok(())
+    }
+
+    /// Records an event, logging instead of failing: the timeline is a diagnostic aid
+    /// and must never make the action it describes fail.
+    pub async fn note_timeline_event(
+        &self,
+        instance_id: Uuid,
+        kind: TimelineKind,
+        summary: impl Into<String>,
+        details: Option<String>,
+    ) {
+        if let Err(e) = self.record_timeline_event(instance_id, kind, summary, details).await {
+            warn!("Failed to record {} timeline event for {}: {}", kind, instance_id, e);
+        }
+    }
+
+    /// Returns up to `limit` events, newest first, merging recorded events with the
+    /// instance's crash reports, console commands and the failed tasks `bus` still holds.
+    pub async fn get_instance_timeline(&self, instance_id: Uuid, limit: usize, bus: &EventBus) -> Result<Vec<TimelineEvent>> {
+        let instance = self.get_instance(instance_id).await?.context("Instance not found")?;
+        let rows = sqlx::query(
+            "SELECT kind, occurred_at, summary, details FROM timeline_events
+                WHERE instance_id = ? ORDER BY occurred_at DESC LIMIT ?",
+        )
+        .bind(instance_id.to_string())
+        .bind(limit as i64)
+        .fetch_all(self.db.pool())
+        .await?;
+
+        let mut events: Vec<TimelineEvent> = rows
+            .iter()
+            .filter_map(|row| {
+                let kind: String = row.get("kind");
+                let occurred_at: String = row.get("occurred_at");
+                Some(TimelineEvent {
+                    instance_id,
+                    kind: TimelineKind::from_str(&kind).ok()?,
+                    occurred_at: parse_time(&occurred_at),
+                    summary: row.get("summary"),
+                    details: row.get("details"),
+                })
+            })
+            .collect();
+
+        let reports = self.list_crash_reports(instance_id).await?;
+        events.extend(crash_report_events(instance_id, &instance.path, reports).await);
+        let commands = self.get_command_history(instance_id, limit as u32).await?;
+        events.extend(command_events(instance_id, commands));
+        events.extend(failed_task_events(instance_id, bus));
+
+        events.sort_by_key(|e| std::cmp::Reverse(e.occurred_at));
+        events.truncate(limit);
+        Ok(events)
+    }
+}
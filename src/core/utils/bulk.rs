@@ -0,0 +1,70 @@
+use futures_util::future::join_all;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio::sync::Semaphore;
+
+/// How many items of a bulk operation run at the same time.
+pub const BULK_CONCURRENCY: usize = 8;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkItemResult {
+    pub filename: String,
+    /// `None` when the item succeeded.
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BulkReport {
+    /// One result per requested item, in request order.
+    pub results: Vec<BulkItemResult>,
+}
+
+impl BulkReport {
+    pub fn succeeded(&self) -> usize {
+        self.results.iter().filter(|r| r.error.is_none()).count()
+    }
+
+    pub fn failed(&self) -> Vec<&BulkItemResult> {
+        self.results.iter().filter(|r| r.error.is_some()).collect()
+    }
+}
+
+/// Emitted after each item of a bulk operation finishes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkProgress {
+    pub completed: usize,
+    pub total: usize,
+    pub filename: String,
+    pub error: Option<String>,
+}
+
+/// Runs `op` for every filename, at most [`BULK_CONCURRENCY`] at a time. A failing item
+/// does not stop the others; its error is recorded in the report instead.
+pub async fn run_bulk<F, Fut, P>(filenames: Vec<String>, op: F, on_progress: P) -> BulkReport
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = anyhow::Result<()>>,
+    P: Fn(BulkProgress),
+{
+    let total = filenames.len();
+    let semaphore = Semaphore::new(BULK_CONCURRENCY);
+    let completed = AtomicUsize::new(0);
+
+    let tasks = filenames.into_iter().map(|filename| {
+        let (semaphore, completed, op, on_progress) = (&semaphore, &completed, &op, &on_progress);
+        async move {
+            let _permit = semaphore.acquire().await.expect("bulk semaphore is never closed");
+            let error = op(filename.clone()).await.err().map(|e| format!("{:#}", e));
+            on_progress(BulkProgress {
+                completed: completed.fetch_add(1, Ordering::SeqCst) + 1,
+                total,
+                filename: filename.clone(),
+                error: error.clone(),
+            });
+            BulkItemResult { filename, error }
+        }
+    });
+
+    BulkReport { results: join_all(tasks).await }
+}
@@ -0,0 +1,86 @@
+//! Checks which version and loader APIs, and which of their mirrors, answer from here.
+
+use super::DownloadLimits;
+use super::mirrors::is_blocked_status;
+use futures_util::future::join_all;
+use serde::Serialize;
+use std::time::{Duration, Instant};
+
+/// An official endpoint checked by [`check_upstreams`].
+const UPSTREAMS: [(&str, &str); 8] = [
+    ("Mojang versions", "https://launchermeta.mojang.com/mc/game/version_manifest.json"),
+    ("PaperMC API", "https://api.papermc.io/v2/projects"),
+    ("Purpur API", "https://api.purpurmc.org/v2/purpur"),
+    ("Fabric meta", "https://meta.fabricmc.net/v2/versions/installer"),
+    ("Quilt meta", "https://meta.quiltmc.org/v3/versions/installer"),
+    ("Forge versions", "https://files.minecraftforge.net/net/minecraftforge/forge/promotions_slim.json"),
+    ("Forge Maven", "https://maven.minecraftforge.net/net/minecraftforge/forge/maven-metadata.xml"),
+    ("NeoForge Maven", "https://maven.neoforged.net/releases/net/neoforged/neoforge/maven-metadata.xml"),
+];
+
+/// Whether one URL answered.
+#[derive(Debug, Clone, Serialize)]
+pub struct EndpointCheck {
+    pub url: String,
+    pub reachable: bool,
+    pub status: Option<u16>,
+    pub latency_ms: u64,
+    pub error: Option<String>,
+}
+
+/// An upstream and each of its configured mirrors.
+#[derive(Debug, Clone, Serialize)]
+pub struct UpstreamCheck {
+    pub name: String,
+    pub official: EndpointCheck,
+    pub mirrors: Vec<EndpointCheck>,
+}
+
+impl UpstreamCheck {
+    /// Whether installs that need this upstream can work, through a mirror if need be.
+    pub fn usable(&self) -> bool {
+        self.official.reachable || self.mirrors.iter().any(|m| m.reachable)
+    }
+}
+
+async fn check_endpoint(client: &reqwest::Client, url: String) -> EndpointCheck {
+    let started = Instant::now();
+    let result = client.get(&url).timeout(Duration::from_secs(10)).send().await;
+    let latency_ms = started.elapsed().as_millis() as u64;
+    match result {
+        Ok(response) => EndpointCheck {
+            reachable: !is_blocked_status(response.status()),
+            status: Some(response.status().as_u16()),
+            latency_ms,
+            error: None,
+            url,
+        },
+        Err(e) => EndpointCheck {
+            reachable: false,
+            status: None,
+            latency_ms,
+            error: Some(e.to_string()),
+            url,
+        },
+    }
+}
+
+/// Checks every official upstream and its mirrors at the same time.
+pub async fn check_upstreams(client: &reqwest::Client, limits: &DownloadLimits) -> Vec<UpstreamCheck> {
+    let mirrors = limits.settings().api_mirrors;
+    join_all(UPSTREAMS.iter().map(|(name, url)| {
+        let mirrored: Vec<String> = mirrors.iter().filter_map(|m| m.rewrite(url)).collect();
+        async move {
+            let (official, mirrors) = futures_util::join!(
+                check_endpoint(client, url.to_string()),
+                join_all(mirrored.into_iter().map(|m| check_endpoint(client, m)))
+            );
+            UpstreamCheck {
+                name: name.to_string(),
+                official,
+                mirrors,
+            }
+        }
+    }))
+    .await
+}
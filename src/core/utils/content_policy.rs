@@ -0,0 +1,145 @@
+//! Rejects downloads that aren't what they claim to be, such as a Cloudflare or error
+//! page saved as a .jar, before a server tries to load them.
+
+use chrono::Utc;
+use serde::Serialize;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+use tracing::warn;
+
+/// Rejected files are moved here, next to where they were downloaded. Loaders only pick
+/// up jars directly inside `mods` and `plugins`, so nothing in here gets loaded.
+pub const QUARANTINE_DIR: &str = ".quarantine";
+
+/// Raised when a download fails validation; retrying the same URL won't help.
+#[derive(Debug, Clone, Error, Serialize, PartialEq)]
+#[error("Rejected download {file_name}: {reason}{}", quarantined_to.as_ref().map(|p| format!(" (moved to {})", p.display())).unwrap_or_default())]
+pub struct RejectedDownload {
+    pub file_name: String,
+    pub reason: String,
+    /// Where the file was moved to, when it got as far as the disk.
+    pub quarantined_to: Option<PathBuf>,
+}
+
+/// Whether a file at `path` has to be a zip archive, judged by its extension.
+pub fn expects_archive(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| matches!(ext.to_lowercase().as_str(), "jar" | "zip" | "mrpack"))
+}
+
+/// Content types of pages and API responses, which some hosts send with a 200 status
+/// in place of the file.
+pub fn is_page_content_type(content_type: &str) -> bool {
+    let content_type = content_type.to_lowercase();
+    ["text/html", "application/xhtml+xml", "application/json"]
+        .iter()
+        .any(|page| content_type.contains(page))
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Fails before anything is written when a download meant for `path` is served as a page.
+pub fn check_content_type(path: &Path, content_type: Option<&str>) -> Result<(), RejectedDownload> {
+    match content_type {
+        Some(content_type) if expects_archive(path) && is_page_content_type(content_type) => Err(RejectedDownload {
+            file_name: file_name(path),
+            reason: format!("the server sent {} instead of an archive", content_type),
+            quarantined_to: None,
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Why `path` isn't a usable zip archive, or None when it is. Looks at the magic bytes
+/// first so an HTML or JSON body gets a clearer reason than a zip error.
+pub fn archive_problem(path: &Path) -> Option<String> {
+    let mut head = Vec::with_capacity(64);
+    match std::fs::File::open(path) {
+        Ok(file) => {
+            if let Err(e) = file.take(64).read_to_end(&mut head) {
+                return Some(format!("it could not be read: {}", e));
+            }
+        }
+        Err(e) => return Some(format!("it could not be read: {}", e)),
+    }
+    if head.is_empty() {
+        return Some("the file is empty".to_string());
+    }
+    if !head.starts_with(b"PK\x03\x04") {
+        let text = String::from_utf8_lossy(&head).trim_start().to_lowercase();
+        return Some(if text.starts_with('<') {
+            "it is an HTML page, not an archive".to_string()
+        } else if text.starts_with('{') || text.starts_with('[') {
+            "it is a JSON response, not an archive".to_string()
+        } else {
+            "it doesn't start with a zip header".to_string()
+        });
+    }
+    let archive = std::fs::File::open(path)
+        .map_err(|e| e.to_string())
+        .and_then(|file| zip::ZipArchive::new(file).map_err(|e| e.to_string()));
+    match archive {
+        Ok(archive) if archive.is_empty() => Some("the archive is empty".to_string()),
+        Ok(_) => None,
+        Err(e) => Some(format!("it is not a valid archive: {}", e)),
+    }
+}
+
+/// Moves `path` into [`QUARANTINE_DIR`] beside it, renamed so no loader mistakes it for a jar.
+pub fn quarantine(path: &Path) -> std::io::Result<PathBuf> {
+    let dir = path.parent().unwrap_or(Path::new(".")).join(QUARANTINE_DIR);
+    std::fs::create_dir_all(&dir)?;
+    let dest = dir.join(format!(
+        "{}.{}.quarantined",
+        file_name(path),
+        Utc::now().format("%Y%m%d%H%M%S")
+    ));
+    std::fs::rename(path, &dest)?;
+    Ok(dest)
+}
+
+/// Checks a finished download that has to be an archive, quarantining it when it isn't.
+/// Files of other types pass unchecked.
+pub async fn validate_download(path: &Path) -> Result<(), RejectedDownload> {
+    if !expects_archive(path) {
+        return Ok(());
+    }
+    let owned = path.to_path_buf();
+    let checked = tokio::task::spawn_blocking(move || {
+        let path = owned.as_path();
+        let Some(reason) = archive_problem(path) else {
+            return Ok(());
+        };
+        let quarantined_to = match quarantine(path) {
+            Ok(dest) => Some(dest),
+            Err(e) => {
+                warn!("Could not quarantine {}: {}; deleting it", path.display(), e);
+                let _ = std::fs::remove_file(path);
+                None
+            }
+        };
+        warn!("Rejected download {}: {}", path.display(), reason);
+        Err(RejectedDownload {
+            file_name: file_name(path),
+            reason,
+            quarantined_to,
+        })
+    })
+    .await;
+    checked.unwrap_or_else(|e| {
+        // A check that never finished proves nothing, so the file doesn't stay where a loader finds it
+        warn!("Could not check download {}: {}; deleting it", path.display(), e);
+        let _ = std::fs::remove_file(path);
+        Err(RejectedDownload {
+            file_name: file_name(path),
+            reason: format!("it could not be checked: {}", e),
+            quarantined_to: None,
+        })
+    })
+}
@@ -1,8 +1,12 @@
+use super::content_policy::{RejectedDownload, check_content_type, validate_download};
+use super::download_limits::DownloadLimits;
+use super::fs::ensure_free_space;
 use crate::artifacts::HashAlgorithm;
 use anyhow::{Context, Result, anyhow};
 use futures_util::StreamExt;
 use sha1::{Digest, Sha1};
 use sha2::Sha256;
+use reqwest::header::CONTENT_TYPE;
 use std::path::Path;
 use std::time::Duration;
 use tokio::fs;
@@ -19,6 +23,7 @@ pub struct DownloadOptions<'a> {
 
 pub async fn download_with_resumption<F>(
     client: &reqwest::Client,
+    limits: &DownloadLimits,
     options: DownloadOptions<'_>,
     on_progress: F,
 ) -> Result<()>
@@ -31,7 +36,7 @@ where
 
     loop {
         attempt += 1;
-        match perform_download(client, &options, &on_progress).await {
+        match perform_download(client, limits, &options, &on_progress).await {
             Ok(_) => {
                 // Verify hash if provided
                 if let Some((expected_hash, algo)) = options.expected_hash {
@@ -57,8 +62,11 @@ where
                         return Err(anyhow!("Hash mismatch after {} attempts", max_retries));
                     }
                 }
+                validate_download(options.target_path).await?;
                 return Ok(());
             }
+            // The host answered with something other than the file; asking again won't change that
+            Err(e) if e.is::<RejectedDownload>() => return Err(e),
             Err(e) if attempt < max_retries => {
                 warn!(
                     "Download failed (attempt {}/{}): {}. Retrying in {:?}...",
@@ -76,6 +84,7 @@ where
 
 async fn perform_download<F>(
     client: &reqwest::Client,
+    limits: &DownloadLimits,
     options: &DownloadOptions<'_>,
     on_progress: &F,
 ) -> Result<()>
@@ -114,6 +123,7 @@ where
         }
     }
 
+    let _slot = limits.acquire_slot().await?;
     let mut request = client.get(options.url);
     if downloaded > 0 {
         request = request.header("Range", format!("bytes={}-", downloaded));
@@ -129,6 +139,7 @@ where
     if !status.is_success() && status != reqwest::StatusCode::PARTIAL_CONTENT {
         return Err(anyhow!("Failed to start download: HTTP {}", status));
     }
+    check_content_type(target_path, content_type(&response))?;
 
     let (mut file, current_pos) = if status == reqwest::StatusCode::PARTIAL_CONTENT {
         let file = fs::OpenOptions::new()
@@ -153,6 +164,7 @@ where
         .total_size
         .or_else(|| response.content_length().map(|len| len + current_pos))
         .unwrap_or(0);
+    ensure_free_space(target_path, total_size.saturating_sub(current_pos))?;
 
     on_progress(current_pos, total_size);
 
@@ -162,6 +174,7 @@ where
 
     while let Some(chunk_result) = stream.next().await {
         let chunk = chunk_result?;
+        limits.throttle(chunk.len()).await;
         file.write_all(&chunk).await?;
         current_downloaded += chunk.len() as u64;
 
@@ -178,7 +191,54 @@ where
     Ok(())
 }
 
-async fn calculate_hash(path: &Path, algorithm: HashAlgorithm) -> Result<String> {
+/// Fetches `url` into `target_path` in one attempt, within the app's download limits.
+/// Callers that need retries wrap this; unlike [`download_with_resumption`] it never resumes.
+pub async fn download_to_file<F>(
+    client: &reqwest::Client,
+    limits: &DownloadLimits,
+    url: &str,
+    target_path: &Path,
+    on_progress: F,
+) -> Result<()>
+where
+    F: Fn(u64, u64),
+{
+    let _slot = limits.acquire_slot().await?;
+    let response = client.get(url).send().await?;
+    if !response.status().is_success() {
+        return Err(anyhow!("Failed to download: {}", response.status()));
+    }
+    check_content_type(target_path, content_type(&response))?;
+
+    let total_size = response.content_length().unwrap_or(0);
+    ensure_free_space(target_path, total_size)?;
+    on_progress(0, total_size);
+
+    if let Some(parent) = target_path.parent() {
+        fs::create_dir_all(parent).await?;
+    }
+    let mut file = fs::File::create(target_path).await?;
+    let mut downloaded: u64 = 0;
+    let mut stream = response.bytes_stream();
+    while let Some(chunk_result) = stream.next().await {
+        let chunk = chunk_result?;
+        limits.throttle(chunk.len()).await;
+        file.write_all(&chunk).await?;
+        downloaded += chunk.len() as u64;
+        on_progress(downloaded, total_size);
+    }
+
+    file.flush().await?;
+    drop(file);
+    validate_download(target_path).await?;
+    Ok(())
+}
+
+fn content_type(response: &reqwest::Response) -> Option<&str> {
+    response.headers().get(CONTENT_TYPE).and_then(|v| v.to_str().ok())
+}
+
+pub(crate) async fn calculate_hash(path: &Path, algorithm: HashAlgorithm) -> Result<String> {
     let mut file = fs::File::open(path).await?;
     let mut buffer = [0u8; 8192];
 
@@ -0,0 +1,129 @@
+//! App-wide limits shared by every download, so a large modpack install or a bulk mod
+//! update doesn't saturate the user's connection.
+
+use super::ApiMirror;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DownloadSettings {
+    /// Downloads allowed to run at the same time across the whole app.
+    pub max_concurrent_downloads: usize,
+    /// Combined speed cap for all downloads in KiB/s; 0 means unlimited.
+    pub bandwidth_limit_kib: u64,
+    /// Hosts tried first, in this order, when a file is offered by several mirrors.
+    pub preferred_mirrors: Vec<String>,
+    /// Mirrors of the version and loader APIs, tried when the official host fails.
+    pub api_mirrors: Vec<ApiMirror>,
+}
+
+impl Default for DownloadSettings {
+    fn default() -> Self {
+        Self {
+            max_concurrent_downloads: 4,
+            bandwidth_limit_kib: 0,
+            preferred_mirrors: Vec::new(),
+            api_mirrors: Vec::new(),
+        }
+    }
+}
+
+struct Limits {
+    settings: DownloadSettings,
+    slots: Arc<Semaphore>,
+    /// When the bytes already let through will have been "spent" at the capped speed.
+    paced_until: Instant,
+    /// Origins of API upstreams and mirrors that failed, and when.
+    failed_origins: HashMap<String, Instant>,
+}
+
+/// The download settings in effect, with the slots and pacing they impose. Clones share
+/// them, so every client handed one counts against the same limits.
+#[derive(Clone)]
+pub struct DownloadLimits {
+    state: Arc<Mutex<Limits>>,
+}
+
+impl Default for DownloadLimits {
+    fn default() -> Self {
+        let settings = DownloadSettings::default();
+        Self {
+            state: Arc::new(Mutex::new(Limits {
+                slots: Arc::new(Semaphore::new(settings.max_concurrent_downloads)),
+                settings,
+                paced_until: Instant::now(),
+                failed_origins: HashMap::new(),
+            })),
+        }
+    }
+}
+
+impl DownloadLimits {
+    fn state(&self) -> MutexGuard<'_, Limits> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Applies new download settings. Downloads already running keep their slot.
+    pub fn configure(&self, settings: &DownloadSettings) {
+        let mut limits = self.state();
+        if limits.settings.max_concurrent_downloads != settings.max_concurrent_downloads {
+            limits.slots = Arc::new(Semaphore::new(settings.max_concurrent_downloads.max(1)));
+        }
+        limits.settings = settings.clone();
+    }
+
+    pub fn settings(&self) -> DownloadSettings {
+        self.state().settings.clone()
+    }
+
+    /// Waits for one of the [`DownloadSettings::max_concurrent_downloads`] slots; hold the
+    /// permit for as long as the transfer runs.
+    pub async fn acquire_slot(&self) -> Result<OwnedSemaphorePermit> {
+        let slots = Arc::clone(&self.state().slots);
+        slots.acquire_owned().await.context("Download slots are closed")
+    }
+
+    /// Sleeps long enough that `bytes` fits under the bandwidth cap shared by all downloads.
+    pub async fn throttle(&self, bytes: usize) {
+        let delay = {
+            let mut limits = self.state();
+            let cap = limits.settings.bandwidth_limit_kib * 1024;
+            if cap == 0 {
+                return;
+            }
+            let now = Instant::now();
+            let start = limits.paced_until.max(now);
+            limits.paced_until = start + Duration::from_secs_f64(bytes as f64 / cap as f64);
+            limits.paced_until - now
+        };
+        tokio::time::sleep(delay).await;
+    }
+
+    pub(super) fn mark_failed(&self, origin: String) {
+        self.state().failed_origins.insert(origin, Instant::now());
+    }
+
+    /// When `origin` last failed, if it did.
+    pub(super) fn failed_at(&self, origin: &str) -> Option<Instant> {
+        self.state().failed_origins.get(origin).copied()
+    }
+
+    /// Moves URLs on a preferred mirror to the front, keeping the original order otherwise.
+    pub fn sort_by_mirror_preference<T: AsRef<str>>(&self, urls: &mut [T]) {
+        let preferred = self.settings().preferred_mirrors;
+        urls.sort_by_key(|url| {
+            let host = reqwest::Url::parse(url.as_ref())
+                .ok()
+                .and_then(|u| u.host_str().map(|h| h.to_lowercase()));
+            preferred
+                .iter()
+                .position(|p| host.as_deref().is_some_and(|h| h == p.to_lowercase() || h.ends_with(&format!(".{}", p.to_lowercase()))))
+                .unwrap_or(preferred.len())
+        });
+    }
+}
@@ -0,0 +1,65 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+use uuid::Uuid;
+
+/// Path of the copy kept by [`write_atomic`] when `backup` is set.
+pub fn backup_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".bak");
+    path.with_file_name(name)
+}
+
+/// Replaces `path` with `content` without ever leaving a truncated file behind.
+///
+/// The content is written and fsync'd to a temp file in the same directory, which
+/// inherits the target's permissions and is then renamed over it. With `backup`,
+/// the previous contents are kept next to the file as `<name>.bak`.
+pub async fn write_atomic(path: &Path, content: impl AsRef<[u8]>, backup: bool) -> Result<()> {
+    // Write through symlinks instead of replacing the link with a regular file
+    let resolved;
+    let path = match fs::symlink_metadata(path).await {
+        Ok(m) if m.file_type().is_symlink() => {
+            resolved = fs::canonicalize(path).await?;
+            resolved.as_path()
+        }
+        _ => path,
+    };
+    let file_name = path
+        .file_name()
+        .with_context(|| format!("Invalid file path: {}", path.display()))?
+        .to_string_lossy();
+    let tmp_path = path.with_file_name(format!(".{}.{}.tmp", file_name, Uuid::new_v4().simple()));
+    let existing = fs::metadata(path).await.ok().filter(|m| m.is_file());
+
+    let result = async {
+        let mut file = fs::File::create(&tmp_path).await?;
+        file.write_all(content.as_ref()).await?;
+        file.sync_all().await?;
+        drop(file);
+
+        if let Some(metadata) = &existing {
+            fs::set_permissions(&tmp_path, metadata.permissions()).await?;
+            if backup {
+                fs::copy(path, backup_path(path)).await?;
+            }
+        }
+        fs::rename(&tmp_path, path).await
+    }
+    .await;
+
+    if let Err(e) = result {
+        let _ = fs::remove_file(&tmp_path).await;
+        return Err(e).with_context(|| format!("Failed to write {}", path.display()));
+    }
+
+    // Persist the rename itself; directories can't be opened for syncing on Windows
+    #[cfg(unix)]
+    if let Some(parent) = path.parent()
+        && let Ok(dir) = fs::File::open(parent).await
+    {
+        let _ = dir.sync_all().await;
+    }
+    Ok(())
+}
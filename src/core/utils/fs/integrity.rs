@@ -18,3 +18,16 @@ pub fn is_jar_valid(path: &Path) -> bool {
         Err(_) => false,
     }
 }
+
+/// Reads every entry of a JAR so a truncated or bit-flipped file fails its CRC check;
+/// slower than [`is_jar_valid`], which only looks at the central directory.
+pub fn verify_jar_contents(path: &Path) -> anyhow::Result<()> {
+    let mut archive = ZipArchive::new(File::open(path)?)?;
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        let name = entry.name().to_string();
+        std::io::copy(&mut entry, &mut std::io::sink())
+            .map_err(|e| anyhow::anyhow!("{}: {}", name, e))?;
+    }
+    Ok(())
+}
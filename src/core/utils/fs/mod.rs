@@ -2,8 +2,14 @@ pub mod validation;
 pub mod normalization;
 pub mod joining;
 pub mod integrity;
+pub mod atomic;
+pub mod size;
+pub mod space;
 
 pub use validation::validate_rel_path;
 pub use normalization::normalize_path;
 pub use joining::safe_join;
-pub use integrity::is_jar_valid;
+pub use integrity::{is_jar_valid, verify_jar_contents};
+pub use atomic::{backup_path, write_atomic};
+pub use size::dir_size;
+pub use space::{InsufficientSpace, check_free_space, ensure_free_space, format_size};
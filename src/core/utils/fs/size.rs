@@ -0,0 +1,13 @@
+use std::path::Path;
+
+/// Total size in bytes of the files below `path` (or of `path` itself when it is a file).
+/// Entries that can't be read are skipped; a missing path is 0.
+pub fn dir_size(path: &Path) -> u64 {
+    walkdir::WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| e.metadata().ok())
+        .map(|m| m.len())
+        .sum()
+}
@@ -0,0 +1,65 @@
+use anyhow::Result;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use sysinfo::Disks;
+use thiserror::Error;
+
+/// Left free on top of an estimate, since estimates are rough and a full disk also
+/// breaks the server, the database and the logs.
+pub const FREE_SPACE_MARGIN: u64 = 256 * 1024 * 1024;
+
+/// Raised before an install, download or backup that would not fit on the disk.
+#[derive(Debug, Clone, Error, Serialize, PartialEq)]
+#[error("Not enough disk space on {}: need {} free, have {}", path.display(), format_size(*required), format_size(*available))]
+pub struct InsufficientSpace {
+    pub path: PathBuf,
+    pub required: u64,
+    pub available: u64,
+}
+
+/// Formats a byte count as GB, or MB below 1 GB.
+pub fn format_size(bytes: u64) -> String {
+    const MB: f64 = 1024.0 * 1024.0;
+    const GB: f64 = MB * 1024.0;
+    if bytes as f64 >= GB {
+        format!("{:.1} GB", bytes as f64 / GB)
+    } else {
+        format!("{:.0} MB", (bytes as f64 / MB).ceil())
+    }
+}
+
+/// Free bytes on the disk holding `path`, or None when it can't be determined.
+/// `path` doesn't need to exist yet; its nearest existing ancestor is used.
+pub fn available_space(path: &Path) -> Option<u64> {
+    let existing = path.ancestors().find(|p| p.exists())?;
+    let resolved = existing.canonicalize().ok()?;
+    let disks = Disks::new_with_refreshed_list();
+    disks
+        .list()
+        .iter()
+        .filter(|d| resolved.starts_with(d.mount_point()))
+        .max_by_key(|d| d.mount_point().as_os_str().len())
+        .map(|d| d.available_space())
+}
+
+/// Checks that `required` bytes (plus [`FREE_SPACE_MARGIN`]) fit on the disk holding
+/// `path`, failing with [`InsufficientSpace`]. Passes when free space is unknown.
+pub fn check_free_space(path: &Path, required: u64, available: Option<u64>) -> Result<()> {
+    let Some(available) = available else {
+        return Ok(());
+    };
+    if required.saturating_add(FREE_SPACE_MARGIN) > available {
+        return Err(InsufficientSpace {
+            path: path.to_path_buf(),
+            required: required.saturating_add(FREE_SPACE_MARGIN),
+            available,
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// [`check_free_space`] against the disk's current free space.
+pub fn ensure_free_space(path: &Path, required: u64) -> Result<()> {
+    check_free_space(path, required, available_space(path))
+}
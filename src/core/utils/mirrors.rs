@@ -0,0 +1,149 @@
+//! Mirrors for the version and loader APIs, which are blocked or unreachable in some
+//! regions. Requests go to the official URL first and fail over to the configured
+//! mirrors; an upstream that just failed is tried last for a while.
+
+use super::DownloadLimits;
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+use tracing::warn;
+
+/// How long an upstream that failed is tried after its mirrors.
+const FAILED_UPSTREAM_COOLDOWN: Duration = Duration::from_secs(300);
+
+/// Serves everything under `upstream` from `mirror` instead.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiMirror {
+    /// Start of the official URLs, e.g. `https://meta.fabricmc.net`.
+    pub upstream: String,
+    /// What `upstream` is replaced with.
+    pub mirror: String,
+}
+
+impl ApiMirror {
+    fn new(upstream: &str, mirror: &str) -> Self {
+        Self {
+            upstream: upstream.to_string(),
+            mirror: mirror.to_string(),
+        }
+    }
+
+    pub fn rewrite(&self, url: &str) -> Option<String> {
+        if self.upstream.is_empty() || self.mirror.is_empty() {
+            return None;
+        }
+        let rest = url.strip_prefix(self.upstream.trim_end_matches('/'))?;
+        (rest.is_empty() || rest.starts_with('/') || rest.starts_with('?'))
+            .then(|| format!("{}{}", self.mirror.trim_end_matches('/'), rest))
+    }
+}
+
+/// The BMCLAPI preset, which mirrors Mojang's metadata and jars, Fabric's meta and the
+/// Forge, NeoForge, Fabric and Quilt Mavens.
+pub fn bmclapi_mirrors() -> Vec<ApiMirror> {
+    const BMCLAPI: &str = "https://bmclapi2.bangbang93.com";
+    vec![
+        ApiMirror::new("https://launchermeta.mojang.com", BMCLAPI),
+        ApiMirror::new("https://piston-meta.mojang.com", BMCLAPI),
+        ApiMirror::new("https://piston-data.mojang.com", BMCLAPI),
+        ApiMirror::new("https://launcher.mojang.com", BMCLAPI),
+        ApiMirror::new("https://meta.fabricmc.net", &format!("{}/fabric-meta", BMCLAPI)),
+        ApiMirror::new("https://maven.fabricmc.net", &format!("{}/maven", BMCLAPI)),
+        ApiMirror::new("https://maven.minecraftforge.net", &format!("{}/maven", BMCLAPI)),
+        ApiMirror::new("https://files.minecraftforge.net/maven", &format!("{}/maven", BMCLAPI)),
+        ApiMirror::new("https://maven.neoforged.net/releases", &format!("{}/maven", BMCLAPI)),
+        ApiMirror::new("https://maven.quiltmc.org/repository/release", &format!("{}/maven", BMCLAPI)),
+    ]
+}
+
+fn origin_of(url: &str) -> Option<String> {
+    let origin = reqwest::Url::parse(url).ok()?.origin();
+    origin.is_tuple().then(|| origin.ascii_serialization())
+}
+
+fn mark_failed(limits: &DownloadLimits, url: &str) {
+    if let Some(origin) = origin_of(url) {
+        limits.mark_failed(origin);
+    }
+}
+
+fn recently_failed(limits: &DownloadLimits, url: &str) -> bool {
+    origin_of(url)
+        .and_then(|origin| limits.failed_at(&origin))
+        .is_some_and(|at| at.elapsed() < FAILED_UPSTREAM_COOLDOWN)
+}
+
+/// `url` followed by the same URL on each configured mirror. URLs on servers that failed
+/// in the last few minutes are moved to the end.
+pub fn mirror_candidates(limits: &DownloadLimits, url: &str) -> Vec<String> {
+    let mut candidates = vec![url.to_string()];
+    for mirror in limits.settings().api_mirrors {
+        if let Some(mirrored) = mirror.rewrite(url)
+            && !candidates.contains(&mirrored)
+        {
+            candidates.push(mirrored);
+        }
+    }
+    candidates.sort_by_key(|c| recently_failed(limits, c));
+    candidates
+}
+
+/// Runs `op` with `url`, then with each of its mirrors until one succeeds. Returns the
+/// last error when they all fail.
+pub async fn with_failover<T, F, Fut>(limits: &DownloadLimits, url: &str, op: F) -> Result<T>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut last_error = anyhow!("No URL to fetch");
+    for candidate in mirror_candidates(limits, url) {
+        match op(candidate.clone()).await {
+            Ok(value) => return Ok(value),
+            Err(e) => {
+                warn!("Request to {} failed, trying the next mirror: {}", candidate, e);
+                mark_failed(limits, &candidate);
+                last_error = e;
+            }
+        }
+    }
+    Err(last_error)
+}
+
+/// Statuses that say more about the route to a host than about the resource.
+pub(super) fn is_blocked_status(status: reqwest::StatusCode) -> bool {
+    status.is_server_error()
+        || status == reqwest::StatusCode::FORBIDDEN
+        || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+        || status == reqwest::StatusCode::UNAVAILABLE_FOR_LEGAL_REASONS
+}
+
+/// GETs `url`, failing over to its mirrors on connection errors and on statuses that
+/// suggest the upstream is blocked or down. A 404 and other client errors are returned
+/// as they are, so callers keep telling "doesn't exist" apart from "unreachable".
+pub async fn get_with_failover(
+    client: &reqwest::Client,
+    limits: &DownloadLimits,
+    url: &str,
+) -> Result<reqwest::Response> {
+    let last_response = Mutex::new(None);
+    let result = with_failover(limits, url, |candidate| {
+        let last_response = &last_response;
+        async move {
+            let response = client.get(&candidate).send().await?;
+            let status = response.status();
+            if is_blocked_status(status) {
+                *last_response.lock().unwrap_or_else(PoisonError::into_inner) = Some(response);
+                return Err(anyhow!("{} returned {}", candidate, status));
+            }
+            Ok(response)
+        }
+    })
+    .await;
+    match (result, last_response.into_inner().unwrap_or_else(PoisonError::into_inner)) {
+        (Ok(response), _) => Ok(response),
+        (Err(_), Some(response)) => Ok(response),
+        (Err(e), None) => Err(e),
+    }
+}
@@ -1,9 +1,19 @@
 pub mod fs;
 pub mod retry;
 pub mod download;
+pub mod content_policy;
+pub mod download_limits;
 pub mod singleflight;
+pub mod bulk;
+pub mod mirrors;
+pub mod connectivity;
 
 pub use fs::*;
 pub use retry::*;
 pub use download::*;
+pub use content_policy::{QUARANTINE_DIR, RejectedDownload, validate_download};
+pub use download_limits::*;
 pub use singleflight::*;
+pub use bulk::*;
+pub use mirrors::*;
+pub use connectivity::*;
@@ -0,0 +1,118 @@
+//! Keeps the jars replaced by mod/plugin updates in the artifact store so an update
+//! can be rolled back without downloading the old version again.
+
+use crate::artifacts::{ArtifactStore, HashAlgorithm};
+use crate::content_report::ContentKind;
+use crate::loadouts::base_filename;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::Path;
+use tokio::fs;
+
+pub mod rollback;
+
+pub use rollback::rollback_version;
+
+const HISTORY_FILE: &str = ".version_history.json";
+/// Older versions beyond this are dropped from the history by `push_entry`. Their jars
+/// stay in the artifact store until its next prune, which keeps only referenced hashes.
+pub const MAX_VERSIONS_PER_PROJECT: usize = 3;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct InstalledVersion {
+    pub kind: ContentKind,
+    pub project_id: String,
+    /// File name the jar had when it was replaced, without `.disabled`.
+    pub filename: String,
+    pub version: Option<String>,
+    pub version_id: Option<String>,
+    /// SHA-1 of the jar, which is its key in the artifact store.
+    pub sha1: String,
+    pub replaced_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VersionHistory {
+    /// Newest first.
+    #[serde(default)]
+    pub entries: Vec<InstalledVersion>,
+}
+
+pub async fn load_history(instance_path: impl AsRef<Path>) -> VersionHistory {
+    match fs::read_to_string(instance_path.as_ref().join(HISTORY_FILE)).await {
+        Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+        Err(_) => VersionHistory::default(),
+    }
+}
+
+pub(crate) async fn write_history(instance_path: impl AsRef<Path>, history: &VersionHistory) -> Result<()> {
+    let content = serde_json::to_string_pretty(history)?;
+    fs::write(instance_path.as_ref().join(HISTORY_FILE), content)
+        .await
+        .context("Failed to write version history")
+}
+
+/// Previous versions of one project, or of every project when `project_id` is `None`.
+pub async fn list_installed_versions(
+    instance_path: impl AsRef<Path>,
+    kind: ContentKind,
+    project_id: Option<&str>,
+) -> Vec<InstalledVersion> {
+    load_history(instance_path)
+        .await
+        .entries
+        .into_iter()
+        .filter(|e| e.kind == kind && project_id.is_none_or(|id| e.project_id == id))
+        .collect()
+}
+
+/// Adds `jar` to the artifact store and records it as a previous version of `project_id`.
+#[allow(clippy::too_many_arguments)]
+pub async fn archive_version(
+    store: &ArtifactStore,
+    instance_path: impl AsRef<Path>,
+    kind: ContentKind,
+    project_id: &str,
+    jar: &Path,
+    filename: &str,
+    version: Option<String>,
+    version_id: Option<String>,
+) -> Result<()> {
+    let sha1 = store.calculate_hash(jar, HashAlgorithm::Sha1).await?;
+    store.add_artifact(jar, &sha1, HashAlgorithm::Sha1).await?;
+
+    let mut history = load_history(&instance_path).await;
+    push_entry(&mut history, InstalledVersion {
+        kind,
+        project_id: project_id.to_string(),
+        filename: base_filename(filename).to_string(),
+        version,
+        version_id,
+        sha1,
+        replaced_at: Utc::now(),
+    });
+    write_history(&instance_path, &history).await
+}
+
+/// Adds `entry` as the newest version of its project and trims older ones.
+pub(crate) fn push_entry(history: &mut VersionHistory, entry: InstalledVersion) {
+    history.entries.retain(|e| !(e.kind == entry.kind && e.project_id == entry.project_id && e.sha1 == entry.sha1));
+    let (kind, project_id) = (entry.kind, entry.project_id.clone());
+    history.entries.insert(0, entry);
+
+    let mut kept = 0;
+    history.entries.retain(|e| {
+        if e.kind != kind || e.project_id != project_id {
+            return true;
+        }
+        kept += 1;
+        kept <= MAX_VERSIONS_PER_PROJECT
+    });
+}
+
+/// Hashes referenced by an instance's history, which artifact pruning must keep.
+pub async fn referenced_hashes(instance_path: impl AsRef<Path>) -> HashSet<String> {
+    load_history(instance_path).await.entries.into_iter().map(|e| e.sha1).collect()
+}
@@ -0,0 +1,128 @@
+use super::{InstalledVersion, load_history, push_entry, write_history};
+use crate::artifacts::{ArtifactStore, HashAlgorithm};
+use crate::content_report::ContentKind;
+use crate::loadouts::{base_filename, content_dir};
+use crate::{mods, plugins};
+use anyhow::{Context, Result, anyhow};
+use chrono::Utc;
+use serde_json::Value;
+use std::path::Path;
+use tokio::fs;
+use tracing::info;
+
+/// The jar currently installed for a project.
+struct CurrentJar {
+    filename: String,
+    enabled: bool,
+    version: Option<String>,
+    version_id: Option<String>,
+}
+
+async fn find_current(instance_path: &Path, kind: ContentKind, project_id: &str) -> Result<Option<CurrentJar>> {
+    Ok(match kind {
+        ContentKind::Mod => mods::list_installed_mods(instance_path)
+            .await?
+            .into_iter()
+            .find(|m| m.source.as_ref().is_some_and(|s| s.project_id == project_id))
+            .map(|m| CurrentJar {
+                version_id: m.source.and_then(|s| s.current_version_id),
+                filename: m.filename,
+                enabled: m.enabled,
+                version: m.version,
+            }),
+        ContentKind::Plugin => plugins::list_installed_plugins(instance_path)
+            .await?
+            .into_iter()
+            .find(|p| p.source.as_ref().is_some_and(|s| s.project_id == project_id))
+            .map(|p| CurrentJar {
+                version_id: p.source.and_then(|s| s.current_version_id),
+                filename: p.filename,
+                enabled: p.enabled,
+                version: p.version,
+            }),
+    })
+}
+
+/// Points the metadata cache's source entry at the restored jar and version.
+async fn move_source(dir: &Path, kind: ContentKind, from: &str, to: &str, version_id: Option<&str>) -> Result<()> {
+    let cache_path = match kind {
+        ContentKind::Mod => dir.join(".mod_metadata_cache.json"),
+        ContentKind::Plugin => dir.join(".plugin_metadata_cache.json"),
+    };
+    let Ok(content) = fs::read_to_string(&cache_path).await else {
+        return Ok(());
+    };
+    let mut cache: Value = serde_json::from_str(&content).context("Corrupt metadata cache")?;
+    let Some(sources) = cache.get_mut("sources").and_then(Value::as_object_mut) else {
+        return Ok(());
+    };
+    if let Some(mut source) = sources.remove(from) {
+        source["current_version_id"] = version_id.map(Value::from).unwrap_or(Value::Null);
+        sources.insert(to.to_string(), source);
+    }
+    fs::write(&cache_path, serde_json::to_string(&cache)?).await?;
+    Ok(())
+}
+
+/// Swaps the installed jar of `project_id` for the stored version `sha1`. The jar being
+/// replaced goes into the history, so a rollback can itself be undone. Returns the new filename.
+pub async fn rollback_version(
+    store: &ArtifactStore,
+    instance_path: impl AsRef<Path>,
+    kind: ContentKind,
+    project_id: &str,
+    sha1: &str,
+) -> Result<String> {
+    let instance_path = instance_path.as_ref();
+    let mut history = load_history(instance_path).await;
+    let target = history
+        .entries
+        .iter()
+        .find(|e| e.kind == kind && e.project_id == project_id && e.sha1 == sha1)
+        .cloned()
+        .ok_or_else(|| anyhow!("Version {} of {} is not in the history", sha1, project_id))?;
+    if !store.exists(sha1, HashAlgorithm::Sha1).await {
+        return Err(anyhow!("The stored jar for {} is missing; it may have been pruned", target.filename));
+    }
+
+    let dir = instance_path.join(content_dir(kind));
+    let current = find_current(instance_path, kind, project_id).await?;
+    let current_sha1 = match &current {
+        Some(jar) => {
+            let path = dir.join(&jar.filename);
+            let hash = store.calculate_hash(&path, HashAlgorithm::Sha1).await?;
+            store.add_artifact(&path, &hash, HashAlgorithm::Sha1).await?;
+            Some(hash)
+        }
+        None => None,
+    };
+
+    let filename = match &current {
+        Some(jar) if !jar.enabled => format!("{}.disabled", target.filename),
+        _ => target.filename.clone(),
+    };
+    store.provision(sha1, HashAlgorithm::Sha1, dir.join(&filename)).await?;
+
+    history.entries.retain(|e| *e != target);
+    if let (Some(jar), Some(hash)) = (current, current_sha1) {
+        if jar.filename != filename {
+            fs::remove_file(dir.join(&jar.filename))
+                .await
+                .with_context(|| format!("Failed to remove {}", jar.filename))?;
+        }
+        move_source(&dir, kind, &jar.filename, &filename, target.version_id.as_deref()).await?;
+        push_entry(&mut history, InstalledVersion {
+            kind,
+            project_id: project_id.to_string(),
+            filename: base_filename(&jar.filename).to_string(),
+            version: jar.version,
+            version_id: jar.version_id,
+            sha1: hash,
+            replaced_at: Utc::now(),
+        });
+    }
+    write_history(instance_path, &history).await?;
+
+    info!("Rolled {} back to {}", project_id, filename);
+    Ok(filename)
+}
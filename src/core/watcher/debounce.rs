@@ -0,0 +1,61 @@
+use super::{FileChange, FileChangeKind, FileWatcher};
+use std::collections::{BTreeSet, HashMap};
+use std::path::PathBuf;
+use std::sync::Weak;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+/// Changes are reported once the folder has been quiet this long, so a save that writes
+/// a temp file and renames it, or a batch of mod updates, is a single event.
+const DEBOUNCE: Duration = Duration::from_millis(400);
+
+/// Changes that keep coming, like a plugin rewriting its config every tick, are still
+/// reported at least this often.
+const MAX_DELAY: Duration = Duration::from_secs(2);
+
+/// What the watcher callbacks pass on, before debouncing.
+pub(super) enum RawEvent {
+    Change(Uuid, FileChangeKind, String),
+    /// One of the watched folders was created after the instance started being watched.
+    Folder(Uuid, PathBuf),
+}
+
+/// Collects raw changes until the folders are quiet for [`DEBOUNCE`], or for at most
+/// [`MAX_DELAY`], then sends one [`FileChange`] per instance and kind. Ends once the
+/// [`FileWatcher`] is dropped.
+pub(super) fn debounce(raw_rx: mpsc::Receiver<RawEvent>, tx: broadcast::Sender<FileChange>, watcher: Weak<FileWatcher>) {
+    let mut pending: HashMap<(Uuid, FileChangeKind), BTreeSet<String>> = HashMap::new();
+    let mut oldest: Option<Instant> = None;
+    loop {
+        let received = match oldest {
+            None => raw_rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+            Some(oldest) => raw_rx.recv_timeout(DEBOUNCE.min(MAX_DELAY.saturating_sub(oldest.elapsed()))),
+        };
+        let quiet = matches!(received, Err(RecvTimeoutError::Timeout));
+        match received {
+            Ok(RawEvent::Change(instance_id, kind, rel)) => {
+                pending.entry((instance_id, kind)).or_default().insert(rel);
+                oldest.get_or_insert_with(Instant::now);
+            }
+            // Watching from here, since notify can't add a watch from inside its own callback
+            Ok(RawEvent::Folder(instance_id, path)) => match watcher.upgrade() {
+                Some(watcher) => watcher.watch_folder(instance_id, &path),
+                None => break,
+            },
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+        if quiet || oldest.is_some_and(|oldest| oldest.elapsed() >= MAX_DELAY) {
+            for ((instance_id, kind), paths) in pending.drain() {
+                let _ = tx.send(FileChange {
+                    instance_id,
+                    kind,
+                    paths: paths.into_iter().collect(),
+                });
+            }
+            oldest = None;
+        }
+    }
+}
@@ -0,0 +1,180 @@
+//! Watches instance folders for files changed outside the app, so open views can reload
+//! configs and the mod and plugin lists.
+
+mod debounce;
+
+use crate::config_files::format_for_path;
+use anyhow::{Context, Result};
+use debounce::{RawEvent, debounce};
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex, PoisonError};
+use tokio::sync::broadcast;
+use tracing::warn;
+use uuid::Uuid;
+
+/// The folders watched in full. Only the top of the instance folder is watched besides
+/// them, so big worlds don't use up the system's watch limit.
+const WATCHED_DIRS: &[&str] = &["config", "defaultconfigs", "mods", "plugins"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileChangeKind {
+    Config,
+    Mods,
+    Plugins,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FileChange {
+    pub instance_id: Uuid,
+    pub kind: FileChangeKind,
+    /// Relative to the instance folder, with `/` separators.
+    pub paths: Vec<String>,
+}
+
+fn is_config_file(name: &str) -> bool {
+    format_for_path(name).is_some()
+        || name
+            .rsplit_once('.')
+            .is_some_and(|(_, ext)| matches!(ext.to_lowercase().as_str(), "conf" | "hocon" | "json5" | "cfg"))
+}
+
+fn is_jar(name: &str) -> bool {
+    let name = name.to_lowercase();
+    name.ends_with(".jar") || name.ends_with(".jar.disabled")
+}
+
+/// What a change to `rel` (relative to the instance folder) affects, if anything the UI
+/// shows. Worlds, logs and editor/temp files are ignored.
+pub fn classify_change(rel: &str) -> Option<FileChangeKind> {
+    let rel = rel.replace('\\', "/");
+    let parts: Vec<&str> = rel.split('/').filter(|p| !p.is_empty()).collect();
+    let name = *parts.last()?;
+    // Temp files from atomic saves (`.name.<id>.tmp`) and editors
+    if name.ends_with(".tmp") || name.ends_with('~') || name.ends_with(".swp") || name.ends_with(".part") {
+        return None;
+    }
+    match parts.as_slice() {
+        ["mods", _] if is_jar(name) => Some(FileChangeKind::Mods),
+        ["plugins", _] if is_jar(name) => Some(FileChangeKind::Plugins),
+        ["plugins", _, ..] | ["config" | "defaultconfigs", ..] if is_config_file(name) => Some(FileChangeKind::Config),
+        // Player lists and caches at the root are JSON and change constantly
+        [_] if is_config_file(name) && !name.ends_with(".json") => Some(FileChangeKind::Config),
+        _ => None,
+    }
+}
+
+pub struct FileWatcher {
+    watchers: Mutex<HashMap<Uuid, RecommendedWatcher>>,
+    raw_tx: mpsc::Sender<RawEvent>,
+    tx: broadcast::Sender<FileChange>,
+}
+
+impl FileWatcher {
+    pub fn new() -> Arc<Self> {
+        let (raw_tx, raw_rx) = mpsc::channel();
+        let (tx, _) = broadcast::channel(64);
+        let forward = tx.clone();
+        Arc::new_cyclic(|weak| {
+            let weak = weak.clone();
+            // A plain thread, since watcher callbacks don't run on the async runtime
+            std::thread::Builder::new()
+                .name("file-watcher".into())
+                .spawn(move || debounce(raw_rx, forward, weak))
+                .expect("failed to spawn the file watcher thread");
+            Self {
+                watchers: Mutex::new(HashMap::new()),
+                raw_tx,
+                tx,
+            }
+        })
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<FileChange> {
+        self.tx.subscribe()
+    }
+
+    fn watchers(&self) -> std::sync::MutexGuard<'_, HashMap<Uuid, RecommendedWatcher>> {
+        self.watchers.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub fn is_watching(&self, instance_id: Uuid) -> bool {
+        self.watchers().contains_key(&instance_id)
+    }
+
+    /// Starts watching an instance folder. Watching one that's already watched does nothing.
+    pub fn watch(&self, instance_id: Uuid, instance_path: &Path) -> Result<()> {
+        let mut watchers = self.watchers();
+        if watchers.contains_key(&instance_id) {
+            return Ok(());
+        }
+        // Events carry the resolved path on some platforms
+        let root = instance_path.canonicalize().context("Instance folder not found")?;
+        let raw_tx = self.raw_tx.clone();
+        let event_root = root.clone();
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+            let event = match event {
+                Ok(event) => event,
+                Err(e) => {
+                    warn!("File watcher error for {}: {}", instance_id, e);
+                    return;
+                }
+            };
+            if matches!(event.kind, EventKind::Access(_)) {
+                return;
+            }
+            for path in &event.paths {
+                if matches!(event.kind, EventKind::Create(_)) && is_watched_dir(&event_root, path) {
+                    let _ = raw_tx.send(RawEvent::Folder(instance_id, path.clone()));
+                } else if let Some((kind, rel)) =
+                    relative(&event_root, path).and_then(|rel| classify_change(&rel).map(|kind| (kind, rel)))
+                {
+                    let _ = raw_tx.send(RawEvent::Change(instance_id, kind, rel));
+                }
+            }
+        })
+        .context("Could not create a file watcher")?;
+        watcher
+            .watch(&root, RecursiveMode::NonRecursive)
+            .with_context(|| format!("Could not watch {}", root.display()))?;
+        for dir in WATCHED_DIRS.iter().map(|dir| root.join(dir)).filter(|dir| dir.is_dir()) {
+            watcher
+                .watch(&dir, RecursiveMode::Recursive)
+                .with_context(|| format!("Could not watch {}", dir.display()))?;
+        }
+        watchers.insert(instance_id, watcher);
+        Ok(())
+    }
+
+    /// Adds a folder created after [`FileWatcher::watch`] to an instance's watcher.
+    fn watch_folder(&self, instance_id: Uuid, path: &Path) {
+        if let Some(watcher) = self.watchers().get_mut(&instance_id)
+            && let Err(e) = watcher.watch(path, RecursiveMode::Recursive)
+        {
+            warn!("Could not watch {}: {}", path.display(), e);
+        }
+    }
+
+    pub fn unwatch(&self, instance_id: Uuid) {
+        self.watchers().remove(&instance_id);
+    }
+
+    /// Keeps only the watchers of `instance_ids`.
+    pub fn retain(&self, instance_ids: &[Uuid]) {
+        self.watchers().retain(|id, _| instance_ids.contains(id));
+    }
+}
+
+fn is_watched_dir(root: &Path, path: &Path) -> bool {
+    path.parent() == Some(root)
+        && path.file_name().and_then(|n| n.to_str()).is_some_and(|n| WATCHED_DIRS.contains(&n))
+        && path.is_dir()
+}
+
+fn relative(root: &Path, path: &Path) -> Option<String> {
+    Some(path.strip_prefix(root).ok()?.to_string_lossy().replace('\\', "/"))
+}
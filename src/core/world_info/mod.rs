@@ -0,0 +1,170 @@
+//! Quick lookups on a Java world: seed and spawn from `level.dat`, chunk and region
+//! coordinates, and slime chunks.
+
+use crate::nbt::{NbtTag, read_nbt};
+use anyhow::{Context, Result, anyhow};
+use serde::{Serialize, Serializer};
+use std::path::Path;
+
+mod slime;
+
+pub use slime::{MAX_SLIME_RADIUS, SlimeChunkCheck, check_slime_chunks, is_slime_chunk};
+
+/// Data version of 1.20.5, which added the `spawnChunkRadius` game rule (default 2).
+const SPAWN_CHUNK_RULE_DATA_VERSION: i64 = 3837;
+/// Data version of 1.21.9, which stopped keeping spawn chunks loaded.
+const NO_SPAWN_CHUNKS_DATA_VERSION: i64 = 4554;
+/// Chunks loaded around spawn before the game rule existed (a 23x23 area).
+const LEGACY_SPAWN_CHUNK_RADIUS: u32 = 11;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ChunkPos {
+    pub x: i32,
+    pub z: i32,
+}
+
+impl ChunkPos {
+    pub fn from_block(x: i32, z: i32) -> Self {
+        Self {
+            x: x.div_euclid(16),
+            z: z.div_euclid(16),
+        }
+    }
+
+    pub fn region(&self) -> (i32, i32) {
+        (self.x.div_euclid(32), self.z.div_euclid(32))
+    }
+
+    /// The `.mca` file in `region/` that stores this chunk.
+    pub fn region_file(&self) -> String {
+        let (x, z) = self.region();
+        format!("r.{}.{}.mca", x, z)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CoordinateInfo {
+    pub x: i32,
+    pub z: i32,
+    pub chunk: ChunkPos,
+    /// Block coordinates of the chunk's north-west corner.
+    pub chunk_origin: (i32, i32),
+    pub region_file: String,
+    /// The matching Nether position when `x`/`z` are Overworld coordinates.
+    pub nether: (i32, i32),
+    /// The matching Overworld position when `x`/`z` are Nether coordinates.
+    pub overworld: (i32, i32),
+}
+
+pub fn describe_coordinates(x: i32, z: i32) -> CoordinateInfo {
+    let chunk = ChunkPos::from_block(x, z);
+    CoordinateInfo {
+        x,
+        z,
+        chunk,
+        chunk_origin: (chunk.x * 16, chunk.z * 16),
+        region_file: chunk.region_file(),
+        nether: (x.div_euclid(8), z.div_euclid(8)),
+        overworld: (x.saturating_mul(8), z.saturating_mul(8)),
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct SpawnChunks {
+    pub center: ChunkPos,
+    /// Chunks kept loaded in each direction around `center`; 0 when the version has no
+    /// spawn chunks.
+    pub radius: u32,
+    pub min: ChunkPos,
+    pub max: ChunkPos,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct LevelSummary {
+    pub level_name: Option<String>,
+    pub version_name: Option<String>,
+    pub data_version: Option<i64>,
+    /// Sent as a string so JavaScript doesn't round it.
+    #[serde(serialize_with = "as_string")]
+    pub seed: i64,
+    pub spawn: (i32, i32, i32),
+    pub spawn_chunks: SpawnChunks,
+}
+
+fn as_string<S: Serializer>(value: &i64, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+fn int(root: &NbtTag, path: &str) -> Option<i64> {
+    root.get_path(path).and_then(NbtTag::as_i64)
+}
+
+/// Reads the seed, spawn and version of the world in `world_dir`.
+pub async fn read_level_summary(world_dir: &Path) -> Result<LevelSummary> {
+    let bytes = tokio::fs::read(world_dir.join("level.dat"))
+        .await
+        .context("Could not read level.dat")?;
+    let root = read_nbt(&bytes).context("Could not parse level.dat")?.root;
+    level_summary(&root)
+}
+
+/// Extracts the summary from a parsed `level.dat`.
+pub fn level_summary(root: &NbtTag) -> Result<LevelSummary> {
+    // 1.16 moved the seed into WorldGenSettings
+    let seed = int(root, "Data.WorldGenSettings.seed")
+        .or_else(|| int(root, "Data.RandomSeed"))
+        .ok_or_else(|| anyhow!("level.dat has no world seed"))?;
+
+    // 1.21.9 replaced SpawnX/Y/Z with a `spawn` compound
+    let spawn = match (
+        int(root, "Data.SpawnX"),
+        int(root, "Data.SpawnY"),
+        int(root, "Data.SpawnZ"),
+    ) {
+        (Some(x), Some(y), Some(z)) => (x as i32, y as i32, z as i32),
+        _ => match root.get_path("Data.spawn.pos") {
+            Some(NbtTag::IntArray(pos)) if pos.len() == 3 => (pos[0], pos[1], pos[2]),
+            _ => return Err(anyhow!("level.dat has no spawn position")),
+        },
+    };
+
+    let data_version = int(root, "Data.DataVersion");
+    let rule = root
+        .get_path("Data.GameRules.spawnChunkRadius")
+        .and_then(NbtTag::as_str)
+        .and_then(|v| v.parse::<u32>().ok());
+    let radius = match (data_version, rule) {
+        (Some(v), _) if v >= NO_SPAWN_CHUNKS_DATA_VERSION => 0,
+        (_, Some(radius)) => radius,
+        (Some(v), None) if v >= SPAWN_CHUNK_RULE_DATA_VERSION => 2,
+        _ => LEGACY_SPAWN_CHUNK_RADIUS,
+    };
+    let center = ChunkPos::from_block(spawn.0, spawn.2);
+    let r = radius as i32;
+
+    Ok(LevelSummary {
+        level_name: root
+            .get_path("Data.LevelName")
+            .and_then(NbtTag::as_str)
+            .map(String::from),
+        version_name: root
+            .get_path("Data.Version.Name")
+            .and_then(NbtTag::as_str)
+            .map(String::from),
+        data_version,
+        seed,
+        spawn,
+        spawn_chunks: SpawnChunks {
+            center,
+            radius,
+            min: ChunkPos {
+                x: center.x - r,
+                z: center.z - r,
+            },
+            max: ChunkPos {
+                x: center.x + r,
+                z: center.z + r,
+            },
+        },
+    })
+}
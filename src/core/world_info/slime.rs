@@ -0,0 +1,88 @@
+use super::ChunkPos;
+use anyhow::{Result, anyhow};
+use serde::Serialize;
+
+/// Slime chunks are listed this far around a position at most.
+pub const MAX_SLIME_RADIUS: u32 = 16;
+
+/// `java.util.Random`, which the slime chunk check is defined in terms of.
+struct JavaRandom(i64);
+
+impl JavaRandom {
+    const MULTIPLIER: i64 = 0x5DEECE66D;
+    const MASK: i64 = (1 << 48) - 1;
+
+    fn new(seed: i64) -> Self {
+        Self((seed ^ Self::MULTIPLIER) & Self::MASK)
+    }
+
+    fn next(&mut self, bits: u32) -> i32 {
+        self.0 = (self.0.wrapping_mul(Self::MULTIPLIER).wrapping_add(0xB)) & Self::MASK;
+        (self.0 >> (48 - bits)) as i32
+    }
+
+    fn next_int(&mut self, bound: i32) -> i32 {
+        let m = bound - 1;
+        let mut r = self.next(31);
+        if bound & m == 0 {
+            return ((bound as i64 * r as i64) >> 31) as i32;
+        }
+        let mut u = r;
+        loop {
+            r = u % bound;
+            if u.wrapping_sub(r).wrapping_add(m) >= 0 {
+                return r;
+            }
+            u = self.next(31);
+        }
+    }
+}
+
+/// Whether slimes spawn below y=40 in this chunk of a Java world with `seed`,
+/// regardless of biome.
+pub fn is_slime_chunk(seed: i64, chunk: ChunkPos) -> bool {
+    let (x, z) = (chunk.x, chunk.z);
+    // Mirrors the game's int/long arithmetic, overflow included
+    let mixed = seed
+        .wrapping_add(x.wrapping_mul(x).wrapping_mul(0x4c1906) as i64)
+        .wrapping_add(x.wrapping_mul(0x5ac0db) as i64)
+        .wrapping_add((z.wrapping_mul(z) as i64).wrapping_mul(0x4307a7))
+        .wrapping_add(z.wrapping_mul(0x5f24f) as i64)
+        ^ 0x3ad8025f;
+    JavaRandom::new(mixed).next_int(10) == 0
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct SlimeChunkCheck {
+    pub chunk: ChunkPos,
+    pub is_slime_chunk: bool,
+    /// Slime chunks within the requested radius, nearest first.
+    pub nearby: Vec<ChunkPos>,
+}
+
+/// Checks the chunk holding block `x`/`z` and lists slime chunks up to `radius` chunks
+/// around it.
+pub fn check_slime_chunks(seed: i64, x: i32, z: i32, radius: u32) -> Result<SlimeChunkCheck> {
+    if radius > MAX_SLIME_RADIUS {
+        return Err(anyhow!("The search radius is limited to {} chunks", MAX_SLIME_RADIUS));
+    }
+    let chunk = ChunkPos::from_block(x, z);
+    let r = radius as i32;
+    let mut nearby: Vec<ChunkPos> = (-r..=r)
+        .flat_map(|dx| (-r..=r).map(move |dz| (dx, dz)))
+        .map(|(dx, dz)| ChunkPos {
+            x: chunk.x + dx,
+            z: chunk.z + dz,
+        })
+        .filter(|c| is_slime_chunk(seed, *c))
+        .collect();
+    nearby.sort_by_key(|c| {
+        let (dx, dz) = ((c.x - chunk.x) as i64, (c.z - chunk.z) as i64);
+        dx * dx + dz * dz
+    });
+    Ok(SlimeChunkCheck {
+        chunk,
+        is_slime_chunk: is_slime_chunk(seed, chunk),
+        nearby,
+    })
+}
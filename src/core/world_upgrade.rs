@@ -0,0 +1,125 @@
+use crate::protocol::protocol_version;
+use crate::world_info::LevelSummary;
+use anyhow::{Result, anyhow};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+/// Server argument that converts every chunk to the current format before the server
+/// finishes starting, instead of converting them lazily as players load them.
+pub const FORCE_UPGRADE_ARG: &str = "--forceUpgrade";
+/// Also drops cached data (lighting, heightmaps) so it is recomputed by the new version.
+pub const ERASE_CACHE_ARG: &str = "--eraseCache";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorldUpgradeRequest {
+    #[serde(default)]
+    pub erase_cache: bool,
+}
+
+impl WorldUpgradeRequest {
+    pub fn args(&self) -> Vec<String> {
+        let mut args = vec![FORCE_UPGRADE_ARG.to_string()];
+        if self.erase_cache {
+            args.push(ERASE_CACHE_ARG.to_string());
+        }
+        args
+    }
+}
+
+/// Whether a server with `loader` can run the upgrade: every Java server built on the
+/// vanilla one understands `--forceUpgrade`, proxies and Bedrock don't.
+pub fn check_supported(loader: Option<&str>) -> Result<()> {
+    match loader.map(|l| l.to_lowercase()).as_deref() {
+        Some(loader @ ("bedrock" | "velocity" | "bungeecord")) => {
+            Err(anyhow!("World upgrades aren't supported on {} servers", loader))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Whether a world last saved by `world_version` would be converted by a server running
+/// `server_version`. None when either version is unknown.
+pub fn is_outdated(world_version: &str, server_version: &str) -> Option<bool> {
+    if world_version == server_version {
+        return Some(false);
+    }
+    Some(protocol_version(world_version)? < protocol_version(server_version)?)
+}
+
+/// How the active world compares to the version an instance runs.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WorldUpgradeStatus {
+    pub world: String,
+    /// The version that last saved the world, from its level.dat.
+    pub world_version: Option<String>,
+    pub server_version: String,
+    /// None when the versions can't be compared.
+    pub outdated: Option<bool>,
+}
+
+impl WorldUpgradeStatus {
+    pub fn new(world: String, summary: &LevelSummary, server_version: &str) -> Self {
+        let world_version = summary.version_name.clone();
+        let outdated = world_version.as_deref().and_then(|v| is_outdated(v, server_version));
+        Self {
+            world,
+            world_version,
+            server_version: server_version.to_string(),
+            outdated,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum UpgradeLine {
+    /// A stage message, e.g. `Counting chunks...`.
+    Stage(String),
+    Progress {
+        percent: f64,
+        done: u64,
+        total: u64,
+    },
+}
+
+/// Parses what the server logs while converting chunks. Vanilla (and Fabric and Forge,
+/// which reuse its upgrader) prints `Forcing world upgrade!`, its stages and
+/// `45% completed (1234 / 5000 chunks)...`; Paper converts region files in parallel and
+/// counts those instead, e.g. `[world] Processed 12 of 40 region files`.
+pub fn parse_upgrade_line(line: &str) -> Option<UpgradeLine> {
+    static VANILLA_REGEX: OnceLock<Regex> = OnceLock::new();
+    static PAPER_REGEX: OnceLock<Regex> = OnceLock::new();
+    static STAGE_REGEX: OnceLock<Regex> = OnceLock::new();
+    static ANSI_REGEX: OnceLock<Regex> = OnceLock::new();
+    let vanilla = VANILLA_REGEX.get_or_init(|| Regex::new(r"(\d+)% completed \((\d+) / (\d+) chunks\)").unwrap());
+    let paper = PAPER_REGEX.get_or_init(|| {
+        Regex::new(r"(?i)(?:processed|converted) (\d+) ?(?:/|of) ?(\d+) (?:region ?files|chunks)").unwrap()
+    });
+    let stage = STAGE_REGEX.get_or_init(|| {
+        Regex::new(r"(Forcing world upgrade!?|Counting chunks\.\.\.|Upgrading all chunks\.\.\.)$").unwrap()
+    });
+    let ansi_re = ANSI_REGEX.get_or_init(|| Regex::new(r"\x1B(?:[@-Z\\-_]|\[[0-?]*[ -/]*[@-~])").unwrap());
+
+    let line = ansi_re.replace_all(line, "");
+    let line = line.trim_end();
+    if let Some(caps) = vanilla.captures(line) {
+        return Some(UpgradeLine::Progress {
+            percent: caps[1].parse().ok()?,
+            done: caps[2].parse().ok()?,
+            total: caps[3].parse().ok()?,
+        });
+    }
+    if let Some(caps) = paper.captures(line) {
+        let done: u64 = caps[1].parse().ok()?;
+        let total: u64 = caps[2].parse().ok()?;
+        let percent = if total == 0 {
+            100.0
+        } else {
+            done as f64 * 100.0 / total as f64
+        };
+        return Some(UpgradeLine::Progress { percent, done, total });
+    }
+    stage
+        .captures(line)
+        .map(|caps| UpgradeLine::Stage(caps[1].trim_end_matches('!').to_string()))
+}
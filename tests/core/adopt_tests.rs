@@ -0,0 +1,55 @@
+use mc_server_wrapper_core::config::ServerConfig;
+use mc_server_wrapper_core::server::{PidFile, ServerHandle, ServerStatus};
+use std::time::Duration;
+use tempfile::tempdir;
+
+#[tokio::test]
+async fn test_pid_file_roundtrip_and_liveness() {
+    let dir = tempdir().unwrap();
+    assert!(PidFile::read(dir.path()).await.is_none());
+
+    PidFile::write(dir.path(), std::process::id()).await.unwrap();
+    let pid_file = PidFile::read(dir.path()).await.unwrap();
+    assert_eq!(pid_file.pid, std::process::id());
+    assert!(pid_file.is_alive());
+
+    // A recorded start time that does not match means the PID was reused
+    let reused = PidFile {
+        process_start: pid_file.process_start + 1,
+        ..pid_file
+    };
+    assert!(!reused.is_alive());
+
+    PidFile::remove(dir.path()).await;
+    assert!(PidFile::read(dir.path()).await.is_none());
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_attach_tracks_external_process() {
+    let dir = tempdir().unwrap();
+    let mut child = tokio::process::Command::new("sleep")
+        .arg("2")
+        .spawn()
+        .unwrap();
+    let pid = child.id().unwrap();
+    PidFile::write(dir.path(), pid).await.unwrap();
+    // Reap the process when it exits so it does not linger as a zombie
+    tokio::spawn(async move {
+        let _ = child.wait().await;
+    });
+
+    let handle = ServerHandle::new(ServerConfig {
+        working_dir: dir.path().to_path_buf(),
+        ..Default::default()
+    });
+    handle.attach(pid).await;
+    assert_eq!(handle.get_status().await, ServerStatus::Running);
+
+    let start = std::time::Instant::now();
+    while handle.get_status().await != ServerStatus::Stopped {
+        assert!(start.elapsed() < Duration::from_secs(10), "attached process never exited");
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert!(PidFile::read(dir.path()).await.is_none());
+}
@@ -0,0 +1,102 @@
+use anyhow::Result;
+use mc_server_wrapper_core::automation::{
+    AutomationConfig, AutomationFormat, PortableRule, PortableSchedule, export_automation, import_automation,
+};
+use mc_server_wrapper_core::database::Database;
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::notifications::{NotificationRule, NotificationTrigger};
+use mc_server_wrapper_core::scheduler::{ScheduleType, ScheduledTask};
+use mc_server_wrapper_core::server::ServerStatus;
+use std::sync::Arc;
+use tempfile::tempdir;
+
+async fn manager(dir: &std::path::Path) -> Result<InstanceManager> {
+    let db = Arc::new(Database::new(dir.join("test.db")).await?);
+    InstanceManager::new(dir.join("instances"), db).await
+}
+
+#[tokio::test]
+async fn test_automation_round_trips_between_installations() -> Result<()> {
+    let source_dir = tempdir()?;
+    let source = manager(source_dir.path()).await?;
+    let survival = source.create_instance_full("Survival", "1.20.1", None, None).await?;
+    source.add_schedule(survival.id, ScheduledTask::new(survival.id, ScheduleType::Backup, "0 0 4 * * *".to_string())).await?;
+    let mut settings = survival.settings.clone();
+    settings.webhooks.enabled = true;
+    settings.webhooks.url = "https://example.com/hook".to_string();
+    source.update_settings(survival.id, None, settings).await?;
+    for (instance_id, name) in [(None, "Crashes"), (Some(survival.id), "Lag")] {
+        source
+            .save_notification_rule(&NotificationRule {
+                id: 0,
+                instance_id,
+                name: name.to_string(),
+                trigger: NotificationTrigger::StatusChange { status: ServerStatus::Crashed },
+                title: "{instance}".to_string(),
+                body: "{status}".to_string(),
+                enabled: true,
+                created_at: chrono::Utc::now(),
+            })
+            .await?;
+    }
+
+    let exported = export_automation(&source, &[]).await?;
+    let text = exported.to_string(AutomationFormat::Toml)?;
+    let parsed = AutomationConfig::parse(&text, AutomationFormat::Toml)?;
+    assert_eq!(parsed.instances.len(), 1);
+    assert_eq!(parsed.global_rules.len(), 1);
+
+    // The target machine has the same instance under a different UUID
+    let target_dir = tempdir()?;
+    let target = manager(target_dir.path()).await?;
+    let copy = target.create_instance_full("Survival", "1.20.1", None, None).await?;
+    let summary = import_automation(&target, None, &parsed).await?;
+    assert_eq!(summary.schedules_added, 1);
+    assert_eq!(summary.webhooks_updated, 1);
+    assert_eq!(summary.rules_added, 2);
+
+    let copy = target.get_instance(copy.id).await?.unwrap();
+    assert_eq!(copy.schedules.len(), 1);
+    assert_eq!(copy.schedules[0].instance_id, copy.id);
+    assert_eq!(copy.settings.webhooks.url, "https://example.com/hook");
+    assert_eq!(target.list_notification_rules(Some(copy.id)).await?.len(), 2);
+
+    // Importing the same document again changes nothing
+    let json = exported.to_string(AutomationFormat::Json)?;
+    let again = import_automation(&target, None, &AutomationConfig::parse(&json, AutomationFormat::Json)?).await?;
+    assert_eq!(again.schedules_skipped, 1);
+    assert_eq!(again.schedules_added + again.rules_added + again.rules_updated + again.webhooks_updated, 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_invalid_automation_is_rejected_without_changes() -> Result<()> {
+    let dir = tempdir()?;
+    let instances = manager(dir.path()).await?;
+    let survival = instances.create_instance_full("Survival", "1.20.1", None, None).await?;
+
+    let mut config = export_automation(&instances, &[]).await?;
+    config.instances[0].schedules.push(PortableSchedule {
+        task_type: ScheduleType::Restart,
+        cron: "0 0 6 * * *".to_string(),
+        enabled: true,
+        warning: None,
+    });
+    config.instances[0].rules.push(PortableRule {
+        name: "Broken".to_string(),
+        trigger: NotificationTrigger::LogMatch { pattern: "(unclosed".to_string() },
+        title: String::new(),
+        body: String::new(),
+        enabled: true,
+    });
+    let mut missing = config.instances[0].clone();
+    missing.instance = "Creative".to_string();
+    config.instances.push(missing);
+
+    let err = import_automation(&instances, None, &config).await.unwrap_err().to_string();
+    assert!(err.contains("invalid pattern"));
+    assert!(err.contains("Creative"));
+    assert!(instances.get_instance(survival.id).await?.unwrap().schedules.is_empty());
+    assert!(instances.list_notification_rules(Some(survival.id)).await?.is_empty());
+    Ok(())
+}
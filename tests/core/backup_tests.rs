@@ -134,3 +134,42 @@ async fn test_disaster_recovery_workflow() {
     // Verify only the original file exists (the restore should have wiped the corrupted one)
     assert!(config_path.exists());
 }
+
+fn write_zip(path: &std::path::Path, files: &[&str]) {
+    let mut zip = zip::ZipWriter::new(File::create(path).unwrap());
+    for name in files {
+        zip.start_file(*name, zip::write::SimpleFileOptions::default()).unwrap();
+        zip.write_all(b"x").unwrap();
+    }
+    zip.finish().unwrap();
+}
+
+#[tokio::test]
+async fn test_adopt_imported_backups() {
+    let base_dir = tempdir().unwrap();
+    let instance_dir = tempdir().unwrap();
+    let backup_mgr = BackupManager::new(base_dir.path());
+    let instance_id = Uuid::new_v4();
+
+    let imported = instance_dir.path().join("backups");
+    std::fs::create_dir_all(&imported).unwrap();
+    write_zip(&imported.join("2023-05-01.ZIP"), &["server.properties", "world/level.dat"]);
+    write_zip(&imported.join("world-only.zip"), &["level.dat", "region/r.0.0.mca"]);
+    std::fs::write(imported.join("notes.txt"), "kept").unwrap();
+
+    let report = backup_mgr.adopt_backups(instance_id, instance_dir.path()).await.unwrap();
+    assert_eq!(report.adopted.len(), 1);
+    assert_eq!(report.adopted[0].name, "2023-05-01.zip");
+    assert_eq!(report.skipped, vec!["world-only.zip".to_string()]);
+
+    // The adopted archive moved out of the instance; everything else stayed
+    assert!(!imported.join("2023-05-01.ZIP").exists());
+    assert!(imported.join("world-only.zip").exists());
+    let backups = backup_mgr.list_backups(instance_id).await.unwrap();
+    assert_eq!(backups.len(), 1);
+    assert_eq!(backups[0].name, "2023-05-01.zip");
+
+    // Nothing left to adopt the second time
+    let again = backup_mgr.adopt_backups(instance_id, instance_dir.path()).await.unwrap();
+    assert!(again.adopted.is_empty());
+}
@@ -0,0 +1,30 @@
+use mc_server_wrapper_core::manager::{ProvisionStep, plan_steps};
+use mc_server_wrapper_core::mod_loaders::BuildToolsTarget;
+use mc_server_wrapper_core::mod_loaders::buildtools::parse_spigot_versions;
+
+#[test]
+fn test_parse_spigot_versions_newest_first() {
+    let html = r#"<a href="1.8.8.json">1.8.8.json</a>
+        <a href="1.20.1.json">1.20.1.json</a>
+        <a href="1.9.json">1.9.json</a>
+        <a href="1.20.1.json">1.20.1.json</a>
+        <a href="3000.json">3000.json</a>
+        <a href="latest.json">latest.json</a>"#;
+    assert_eq!(parse_spigot_versions(html), vec!["1.20.1", "1.9", "1.8.8"]);
+}
+
+#[test]
+fn test_buildtools_targets() {
+    assert_eq!(BuildToolsTarget::from_loader("Spigot"), Some(BuildToolsTarget::Spigot));
+    assert_eq!(BuildToolsTarget::from_loader("craftbukkit"), Some(BuildToolsTarget::CraftBukkit));
+    assert_eq!(BuildToolsTarget::from_loader("paper"), None);
+
+    let target = BuildToolsTarget::CraftBukkit;
+    assert_eq!(target.output_jar("1.20.1"), "craftbukkit-1.20.1.jar");
+    assert_eq!(
+        target.args("1.20.1"),
+        vec!["--rev", "1.20.1", "--compile", "craftbukkit", "--output-dir", ".", "--nogui"]
+    );
+    // Built by running BuildTools, not downloaded
+    assert_eq!(plan_steps(Some("spigot"))[0], ProvisionStep::RunInstaller);
+}
@@ -0,0 +1,49 @@
+use mc_server_wrapper_core::mods::bulk_toggle_mods;
+use mc_server_wrapper_core::plugins::bulk_uninstall_plugins;
+use std::sync::Mutex;
+use tempfile::tempdir;
+
+#[tokio::test]
+async fn test_bulk_toggle_reports_each_file() {
+    let dir = tempdir().unwrap();
+    let mods_dir = dir.path().join("mods");
+    std::fs::create_dir_all(&mods_dir).unwrap();
+    let mut filenames: Vec<String> = (0..20).map(|i| format!("mod-{}.jar", i)).collect();
+    for filename in &filenames {
+        std::fs::write(mods_dir.join(filename), b"jar").unwrap();
+    }
+    filenames.push("missing.jar".to_string());
+
+    let progress = Mutex::new(Vec::new());
+    let report = bulk_toggle_mods(dir.path(), filenames, false, |p| progress.lock().unwrap().push(p)).await;
+
+    assert_eq!(report.results.len(), 21);
+    assert_eq!(report.succeeded(), 20);
+    let failed = report.failed();
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].filename, "missing.jar");
+    assert_eq!(report.results[20].filename, "missing.jar");
+    assert!(mods_dir.join("mod-7.jar.disabled").exists());
+
+    let progress = progress.into_inner().unwrap();
+    assert_eq!(progress.len(), 21);
+    assert!(progress.iter().all(|p| p.total == 21));
+    assert_eq!(progress.iter().map(|p| p.completed).max(), Some(21));
+}
+
+#[tokio::test]
+async fn test_bulk_uninstall_continues_past_failures() {
+    let dir = tempdir().unwrap();
+    let plugins_dir = dir.path().join("plugins");
+    std::fs::create_dir_all(&plugins_dir).unwrap();
+    std::fs::write(plugins_dir.join("A.jar"), b"jar").unwrap();
+    std::fs::write(plugins_dir.join("B.jar"), b"jar").unwrap();
+
+    let filenames = vec!["../escape.jar".to_string(), "A.jar".to_string(), "B.jar".to_string()];
+    let report = bulk_uninstall_plugins(dir.path(), filenames, false, |_| {}).await;
+
+    assert_eq!(report.succeeded(), 2);
+    assert_eq!(report.failed()[0].filename, "../escape.jar");
+    assert!(!plugins_dir.join("A.jar").exists());
+    assert!(!plugins_dir.join("B.jar").exists());
+}
@@ -0,0 +1,105 @@
+use mc_server_wrapper_core::config::ServerConfig;
+use mc_server_wrapper_core::errors::AppError;
+use mc_server_wrapper_core::server::{AdvancementKind, ChatEvent, ServerHandle, parse_chat_event};
+
+#[test]
+fn test_parse_chat_events() {
+    assert_eq!(
+        parse_chat_event("[14:03:27] [Server thread/INFO]: <Steve> anyone got iron?"),
+        Some(ChatEvent::ChatMessage {
+            player: "Steve".to_string(),
+            message: "anyone got iron?".to_string()
+        })
+    );
+    assert_eq!(
+        parse_chat_event("[14:03:27 INFO]: [Not Secure] <Alex> hi: there"),
+        Some(ChatEvent::ChatMessage {
+            player: "Alex".to_string(),
+            message: "hi: there".to_string()
+        })
+    );
+    assert_eq!(
+        parse_chat_event("[14:03:27] [Server thread/INFO]: [Server] Restarting in 5 minutes"),
+        Some(ChatEvent::ChatMessage {
+            player: "Server".to_string(),
+            message: "Restarting in 5 minutes".to_string()
+        })
+    );
+    assert_eq!(
+        parse_chat_event(
+            "[12Mar2024 14:03:27.123] [Server thread/INFO] [minecraft/MinecraftServer]: Steve joined the game"
+        ),
+        Some(ChatEvent::Join {
+            player: "Steve".to_string()
+        })
+    );
+    assert_eq!(
+        parse_chat_event("[14:03:27 INFO]: Steve left the game"),
+        Some(ChatEvent::Leave {
+            player: "Steve".to_string()
+        })
+    );
+    assert_eq!(
+        parse_chat_event("[14:03:27] [Server thread/INFO]: Steve was slain by Zombie"),
+        Some(ChatEvent::Death {
+            player: "Steve".to_string(),
+            message: "Steve was slain by Zombie".to_string()
+        })
+    );
+    assert_eq!(
+        parse_chat_event("[14:03:27] [Server thread/INFO]: Alex has completed the challenge [Cover Me in Debris]"),
+        Some(ChatEvent::Advancement {
+            player: "Alex".to_string(),
+            advancement: "Cover Me in Debris".to_string(),
+            kind: AdvancementKind::Challenge,
+        })
+    );
+
+    // Not chat: server status, plugin output and warnings that mention a player
+    assert_eq!(
+        parse_chat_event("[14:03:27] [Server thread/INFO]: Done (3.2s)! For help, type \"help\""),
+        None
+    );
+    assert_eq!(
+        parse_chat_event("[14:03:27 INFO]: [Essentials] Steve was teleported"),
+        None
+    );
+    assert_eq!(
+        parse_chat_event("[14:03:27] [Server thread/WARN]: Steve moved too quickly!"),
+        None
+    );
+    assert_eq!(
+        parse_chat_event("[14:03:27] [Server thread/WARN]: Steve was kicked for floating"),
+        None
+    );
+    assert_eq!(parse_chat_event("<Steve> not from the server log"), None);
+}
+
+#[tokio::test]
+async fn test_chat_stream_follows_the_console() {
+    let handle = ServerHandle::new(ServerConfig::default());
+    let mut chat = handle.subscribe_chat();
+    let mut logs = handle.subscribe_logs();
+
+    handle.emit_log("[14:03:27] [Server thread/INFO]: Preparing spawn area: 84%".to_string());
+    handle.emit_log("\u{1b}[0m[14:03:28] [Server thread/INFO]: <Steve> §ahello\u{1b}[m".to_string());
+    handle.emit_log("[14:03:29] [Server thread/INFO]: Steve fell from a high place".to_string());
+
+    // Every line still reaches the console, chat or not
+    for _ in 0..3 {
+        logs.recv().await.unwrap();
+    }
+    assert_eq!(
+        chat.recv().await.unwrap(),
+        ChatEvent::ChatMessage {
+            player: "Steve".to_string(),
+            message: "hello".to_string()
+        }
+    );
+    assert!(matches!(chat.recv().await.unwrap(), ChatEvent::Death { player, .. } if player == "Steve"));
+    assert!(chat.try_recv().is_err());
+    assert_eq!(handle.get_recent_chat().len(), 2);
+
+    let err = handle.send_chat("hello").await.unwrap_err();
+    assert_eq!(AppError::from(err).code(), "conflict");
+}
@@ -128,3 +128,408 @@ fn test_unflatten_yaml() {
     assert_eq!(root["a"]["b"]["c"], YamlValue::String("val".to_string()));
     assert_eq!(root["a"]["d"], YamlValue::Bool(true));
 }
+
+#[tokio::test]
+async fn test_save_config_file_is_atomic_with_backup() -> anyhow::Result<()> {
+    use mc_server_wrapper_core::config_files::{ConfigFormat, read_config_file, save_config_file};
+
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("server.properties");
+    std::fs::write(&path, "motd=Old\n")?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o640))?;
+    }
+
+    let props = HashMap::from([("motd".to_string(), "New".to_string())]);
+    save_config_file(dir.path(), "server.properties", ConfigFormat::Properties, props.clone(), true).await?;
+
+    let saved = read_config_file(dir.path(), "server.properties", ConfigFormat::Properties).await?;
+    assert_eq!(saved.get("motd").map(String::as_str), Some("New"));
+    assert_eq!(std::fs::read_to_string(dir.path().join("server.properties.bak"))?, "motd=Old\n");
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        assert_eq!(std::fs::metadata(&path)?.permissions().mode() & 0o777, 0o640);
+    }
+    let leftovers: Vec<_> = std::fs::read_dir(dir.path())?
+        .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+        .filter(|name| name.ends_with(".tmp"))
+        .collect();
+    assert!(leftovers.is_empty());
+
+    // Without backup the .bak keeps the earlier contents
+    save_config_file(dir.path(), "server.properties", ConfigFormat::Properties, props, false).await?;
+    assert_eq!(std::fs::read_to_string(dir.path().join("server.properties.bak"))?, "motd=Old\n");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_search_and_replace_across_configs() {
+    use mc_server_wrapper_core::config_files::{ConfigSearch, SearchTarget, replace_in_configs, search_configs};
+
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    std::fs::write(root.join("server.properties"), "motd=hunter2 server\n").unwrap();
+    for plugin in ["LuckPerms", "CoreProtect"] {
+        std::fs::create_dir_all(root.join("plugins").join(plugin)).unwrap();
+        std::fs::write(
+            root.join("plugins").join(plugin).join("config.yml"),
+            "# keep this comment\ndatabase:\n  password: hunter2\n  user: mc\n",
+        ).unwrap();
+    }
+    std::fs::create_dir_all(root.join("config/nested")).unwrap();
+    std::fs::write(root.join("config/nested/db.json"), "{\n  \"password\": \"hunter2\"\n}\n").unwrap();
+
+    let search = ConfigSearch {
+        pattern: "hunter2".to_string(),
+        regex: false,
+        case_sensitive: true,
+        target: SearchTarget::Value,
+    };
+    let matches = search_configs(root, &search).await.unwrap();
+    assert_eq!(matches.len(), 4);
+    let plugin_match = matches.iter().find(|m| m.path == "plugins/LuckPerms/config.yml").unwrap();
+    assert_eq!(plugin_match.line, 3);
+    assert_eq!(plugin_match.key.as_deref(), Some("password"));
+
+    let files: Vec<String> = matches.iter().map(|m| m.path.clone()).filter(|p| p != "server.properties").collect();
+    // A stale preview count must not write anything
+    assert!(replace_in_configs(root, &search, "s3cret", &files, 5, false).await.is_err());
+    assert!(std::fs::read_to_string(root.join("config/nested/db.json")).unwrap().contains("hunter2"));
+
+    let report = replace_in_configs(root, &search, "s3cret", &files, 3, false).await.unwrap();
+    assert_eq!(report.replacements, 3);
+    assert_eq!(
+        std::fs::read_to_string(root.join("plugins/CoreProtect/config.yml")).unwrap(),
+        "# keep this comment\ndatabase:\n  password: s3cret\n  user: mc\n"
+    );
+    assert!(std::fs::read_to_string(root.join("config/nested/db.json")).unwrap().contains("\"s3cret\""));
+    assert_eq!(std::fs::read_to_string(root.join("server.properties")).unwrap(), "motd=hunter2 server\n");
+
+    // Edits that leave a file unparsable are refused
+    let key_search = ConfigSearch { pattern: "user".to_string(), regex: false, case_sensitive: true, target: SearchTarget::Key };
+    let yml = vec!["plugins/LuckPerms/config.yml".to_string()];
+    assert!(replace_in_configs(root, &key_search, "a: b: c", &yml, 1, false).await.is_err());
+}
+
+#[tokio::test]
+async fn test_detect_config_format() {
+    use mc_server_wrapper_core::config_files::{ConfigFormat, detect_config_format, save_config_value};
+
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    std::fs::write(root.join("server.properties"), "motd=A Server: hi\nserver-port=25565\n").unwrap();
+    std::fs::write(root.join("bukkit.yml"), "settings:\n  allow-end: true\n").unwrap();
+    std::fs::write(root.join("paper-global.yml"), "_version: 29\nproxies:\n  velocity:\n    enabled: false\n").unwrap();
+    std::fs::write(root.join("settings.txt"), "[general]\nname = \"x\"\n").unwrap();
+    // A YAML extension with JSON content, and a HOCON file under a YAML name
+    std::fs::write(root.join("mislabeled.yml"), "{\"a\": 1}").unwrap();
+    std::fs::write(root.join("sponge.yml"), "sponge {\n  enabled = true\n}\n").unwrap();
+
+    assert_eq!(detect_config_format(root, "server.properties", None).await.unwrap(), ConfigFormat::Properties);
+    assert_eq!(detect_config_format(root, "bukkit.yml", None).await.unwrap(), ConfigFormat::Yaml);
+    assert_eq!(detect_config_format(root, "paper-global.yml", None).await.unwrap(), ConfigFormat::Yaml);
+    assert_eq!(detect_config_format(root, "settings.txt", None).await.unwrap(), ConfigFormat::Toml);
+    assert_eq!(detect_config_format(root, "mislabeled.yml", None).await.unwrap(), ConfigFormat::Json);
+    assert_eq!(detect_config_format(root, "new.toml", None).await.unwrap(), ConfigFormat::Toml);
+    assert_eq!(detect_config_format(root, "sponge.yml", None).await.unwrap(), ConfigFormat::Hocon);
+    assert_eq!(detect_config_format(root, "global.conf", None).await.unwrap(), ConfigFormat::Hocon);
+    assert!(detect_config_format(root, "unknown", None).await.is_err());
+
+    // A misreported format is refused instead of rewriting the file in another syntax
+    assert!(detect_config_format(root, "bukkit.yml", Some(ConfigFormat::Properties)).await.is_err());
+    let err = save_config_value(root, "mislabeled.yml", ConfigFormat::Yaml, json!({"a": 2}), false).await;
+    assert!(err.is_err());
+    assert_eq!(std::fs::read_to_string(root.join("mislabeled.yml")).unwrap(), "{\"a\": 1}");
+}
+
+#[test]
+fn test_hocon_round_trip() {
+    use mc_server_wrapper_core::config_files::hocon::{parse_hocon, serialize_hocon};
+
+    let content = r#"
+# Sponge global config
+sponge {
+    world.gamerules = { doFireTick = false }
+    modules {
+        tracking: true // inline comment
+        "odd key" = "a \"quoted\" value"
+    }
+    motd = Hello world
+    ports = [25565, 25566]
+    ports += 25567
+    path = ${HOME}/server
+}
+sponge.modules.timings = false
+"#;
+    let value = parse_hocon(content).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "sponge": {
+                "world": {"gamerules": {"doFireTick": false}},
+                "modules": {"tracking": true, "odd key": "a \"quoted\" value", "timings": false},
+                "motd": "Hello world",
+                "ports": [25565, 25566, 25567],
+                "path": "${HOME}/server",
+            }
+        })
+    );
+    assert_eq!(parse_hocon(&serialize_hocon(&value)).unwrap(), value);
+    assert!(parse_hocon("a {\n  b = 1\n").is_err());
+    assert!(parse_hocon("include \"other.conf\"").is_err());
+}
+
+#[test]
+fn test_hocon_patch_keeps_comments() {
+    use mc_server_wrapper_core::config_files::hocon::{parse_hocon, patch_hocon};
+
+    let content = "# Sponge global config\nsponge {\n    modules {\n        tracking: true // inline comment\n        timings = true\n    }\n    world.gamerules = { doFireTick = false }\n}\n";
+    let mut value = parse_hocon(content).unwrap();
+    value["sponge"]["modules"]["tracking"] = json!(false);
+    value["sponge"]["modules"].as_object_mut().unwrap().remove("timings");
+    value["sponge"]["world"]["gamerules"]["keepInventory"] = json!(true);
+    value["sponge"]["motd"] = json!("Hi there");
+
+    let patched = patch_hocon(content, &value);
+    assert_eq!(
+        patched,
+        "# Sponge global config\nsponge {\n    modules {\n        tracking: false // inline comment\n    }\n    world.gamerules = { doFireTick = false, keepInventory = true }\n    motd = \"Hi there\"\n}\n"
+    );
+    assert_eq!(parse_hocon(&patched).unwrap(), value);
+
+    // Values built from several definitions can't be patched, so the file is written out in full
+    let appended = "ports = [1]\nports += 2\n";
+    let patched = patch_hocon(appended, &json!({"ports": [3]}));
+    assert_eq!(parse_hocon(&patched).unwrap(), json!({"ports": [3]}));
+}
+
+#[tokio::test]
+async fn test_hocon_and_nbt_configs_are_editable() {
+    use mc_server_wrapper_core::config_files::{
+        ConfigFormat, list_available_configs, list_searchable_configs, read_config_file, read_config_value,
+        save_config_file, save_config_value,
+    };
+    use mc_server_wrapper_core::nbt::{NbtCompression, NbtFile, NbtTag, read_nbt, write_nbt};
+
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    std::fs::create_dir_all(root.join("config/sponge")).unwrap();
+    std::fs::write(root.join("config/sponge/global.conf"), "sponge {\n  enabled = true\n  view-distance = 8 # chunks\n}\n").unwrap();
+    let original = NbtFile {
+        name: String::new(),
+        root: NbtTag::Compound(vec![
+            ("enabled".to_string(), NbtTag::Byte(1)),
+            ("radius".to_string(), NbtTag::Short(16)),
+            ("scale".to_string(), NbtTag::Float(0.5)),
+            ("names".to_string(), NbtTag::List(vec![NbtTag::String("a".to_string())])),
+        ]),
+        compression: NbtCompression::Gzip,
+    };
+    std::fs::write(root.join("config/claims.dat"), write_nbt(&original).unwrap()).unwrap();
+
+    let configs = list_available_configs(root, None).await;
+    let format_of = |path: &str| configs.iter().find(|c| c.path == path).map(|c| c.format.clone());
+    assert_eq!(format_of("config/sponge/global.conf"), Some(ConfigFormat::Hocon));
+    assert_eq!(format_of("config/claims.dat"), Some(ConfigFormat::Nbt));
+    // Binary files can't be searched or replaced in as text
+    let searchable = list_searchable_configs(root).await;
+    assert!(!searchable.iter().any(|(path, _)| path.ends_with(".dat")));
+
+    let mut props = read_config_file(root, "config/sponge/global.conf", ConfigFormat::Hocon).await.unwrap();
+    assert_eq!(props.get("sponge.view-distance").map(String::as_str), Some("8"));
+    props.insert("sponge.view-distance".to_string(), "10".to_string());
+    save_config_file(root, "config/sponge/global.conf", ConfigFormat::Hocon, props, false).await.unwrap();
+    let value = read_config_value(root, "config/sponge/global.conf", ConfigFormat::Hocon).await.unwrap();
+    assert_eq!(value, json!({"sponge": {"enabled": true, "view-distance": 10}}));
+    assert_eq!(
+        std::fs::read_to_string(root.join("config/sponge/global.conf")).unwrap(),
+        "sponge {\n  enabled = true\n  view-distance = 10 # chunks\n}\n"
+    );
+
+    let mut props = read_config_file(root, "config/claims.dat", ConfigFormat::Nbt).await.unwrap();
+    assert_eq!(props.get("scale").map(String::as_str), Some("0.5"));
+    props.insert("radius".to_string(), "32".to_string());
+    props.insert("names[0]".to_string(), "b".to_string());
+    save_config_file(root, "config/claims.dat", ConfigFormat::Nbt, props, false).await.unwrap();
+
+    // Edits keep each tag's type and the file's compression
+    let saved = read_nbt(&std::fs::read(root.join("config/claims.dat")).unwrap()).unwrap();
+    assert_eq!(saved.compression, NbtCompression::Gzip);
+    let NbtTag::Compound(entries) = &saved.root else { panic!("root is not a compound") };
+    let tag = |name: &str| entries.iter().find(|(k, _)| k == name).map(|(_, v)| v.clone());
+    assert_eq!(tag("enabled"), Some(NbtTag::Byte(1)));
+    assert_eq!(tag("radius"), Some(NbtTag::Short(32)));
+    assert_eq!(tag("names"), Some(NbtTag::List(vec![NbtTag::String("b".to_string())])));
+
+    // A value that doesn't fit the tag's type is refused, and the file is left alone
+    let err = save_config_value(root, "config/claims.dat", ConfigFormat::Nbt, json!({"radius": 100000}), false).await;
+    assert!(err.is_err());
+    assert_eq!(read_nbt(&std::fs::read(root.join("config/claims.dat")).unwrap()).unwrap().root, saved.root);
+}
+
+#[tokio::test]
+async fn test_config_listing_walks_subfolders() {
+    use mc_server_wrapper_core::config_files::{list_available_configs, list_config_tree};
+
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    std::fs::create_dir_all(root.join("config/create")).unwrap();
+    std::fs::create_dir_all(root.join("config/a/b/c/d")).unwrap();
+    std::fs::write(root.join("config/top.json"), "{}").unwrap();
+    std::fs::write(root.join("config/create/common.toml"), "a = 1").unwrap();
+    std::fs::write(root.join("config/create/notes.txt"), "not a config").unwrap();
+    std::fs::write(root.join("config/a/b/c/deep.yml"), "a: 1").unwrap();
+    std::fs::write(root.join("config/a/b/c/d/too-deep.yml"), "a: 1").unwrap();
+
+    let paths: Vec<String> = list_available_configs(root, None).await.into_iter().map(|c| c.path).collect();
+    assert!(paths.contains(&"config/create/common.toml".to_string()));
+    assert!(paths.contains(&"config/a/b/c/deep.yml".to_string()));
+    assert!(!paths.iter().any(|p| p.ends_with("notes.txt") || p.ends_with("too-deep.yml")));
+
+    let tree = list_config_tree(root, None).await;
+    let config = tree.iter().find(|n| n.name == "config").unwrap();
+    assert!(config.format.is_none());
+    // Folders come before files
+    let names: Vec<&str> = config.children.iter().map(|n| n.name.as_str()).collect();
+    assert_eq!(names, ["a", "create", "top.json"]);
+    let create = &config.children[1];
+    assert_eq!(create.path, "config/create");
+    assert_eq!(create.children[0].path, "config/create/common.toml");
+    assert!(tree.iter().any(|n| n.path == "server.properties"));
+}
+
+#[tokio::test]
+async fn test_config_groups_by_owner() {
+    use mc_server_wrapper_core::config_files::{
+        ConfigOwnerKind, config_owner, list_available_configs, list_config_groups, list_group_configs,
+    };
+
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    std::fs::write(root.join("server.properties"), "level-name=survival\n").unwrap();
+    std::fs::write(root.join("bukkit.yml"), "a: 1").unwrap();
+    std::fs::create_dir_all(root.join("config/create")).unwrap();
+    std::fs::create_dir_all(root.join("survival/serverconfig")).unwrap();
+    std::fs::create_dir_all(root.join("plugins/Essentials/userdata")).unwrap();
+    std::fs::write(root.join("config/create/common.toml"), "a = 1").unwrap();
+    std::fs::write(root.join("config/create-client.toml"), "a = 1").unwrap();
+    std::fs::write(root.join("config/jei.json"), "{}").unwrap();
+    std::fs::write(root.join("survival/serverconfig/create-server.toml"), "a = 1").unwrap();
+    std::fs::write(root.join("plugins/Essentials/config.yml"), "a: 1").unwrap();
+    std::fs::write(root.join("plugins/Essentials/userdata/steve.yml"), "money: 1").unwrap();
+    std::fs::write(root.join("plugins/Essentials/cache.dat"), [0u8, 1, 2]).unwrap();
+    std::fs::write(root.join("plugins/EssentialsX.jar"), "PK").unwrap();
+
+    assert_eq!(config_owner("create-common.toml"), "create");
+    assert_eq!(config_owner("jei_client.json"), "jei");
+    assert_eq!(config_owner("Sodium-Options.json"), "sodium");
+    assert_eq!(config_owner("client.toml"), "client");
+
+    let groups = list_config_groups(root).await;
+    let owners: Vec<(ConfigOwnerKind, &str)> = groups.iter().map(|g| (g.kind, g.owner.as_str())).collect();
+    assert_eq!(
+        owners,
+        vec![
+            (ConfigOwnerKind::Server, "server"),
+            (ConfigOwnerKind::Mod, "create"),
+            (ConfigOwnerKind::Mod, "jei"),
+            (ConfigOwnerKind::Plugin, "Essentials"),
+        ]
+    );
+    // Folders are listed as roots, not walked
+    assert_eq!(groups[1].roots, vec!["config/create", "config/create-client.toml", "survival/serverconfig/create-server.toml"]);
+    assert_eq!(groups[0].roots, vec!["server.properties", "bukkit.yml"]);
+
+    let create: Vec<String> = list_group_configs(root, ConfigOwnerKind::Mod, "create")
+        .await
+        .into_iter()
+        .map(|c| c.name)
+        .collect();
+    assert_eq!(create, vec!["create-client.toml", "create/common.toml", "serverconfig/create-server.toml"]);
+    let essentials: Vec<String> = list_group_configs(root, ConfigOwnerKind::Plugin, "Essentials")
+        .await
+        .into_iter()
+        .map(|c| c.path)
+        .collect();
+    assert_eq!(essentials, vec!["plugins/Essentials/config.yml", "plugins/Essentials/userdata/steve.yml"]);
+    assert!(list_group_configs(root, ConfigOwnerKind::Mod, "missing").await.is_empty());
+
+    let all: Vec<String> = list_available_configs(root, None).await.into_iter().map(|c| c.path).collect();
+    assert!(all.contains(&"plugins/Essentials/config.yml".to_string()));
+    assert!(all.contains(&"survival/serverconfig/create-server.toml".to_string()));
+    assert!(!all.iter().any(|p| p.ends_with(".dat") || p.ends_with(".jar")));
+}
+
+#[tokio::test]
+async fn test_config_preset_preview_and_apply() {
+    use mc_server_wrapper_core::config_files::{apply_preset, find_preset, presets_supported, preview_preset};
+
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    std::fs::write(root.join("server.properties"), "# comment\ndifficulty=easy\npvp=true\n").unwrap();
+
+    let hardcore = find_preset("hardcore").unwrap();
+    let preview = preview_preset(root, &hardcore).await.unwrap();
+    assert_eq!(preview.missing_files, vec!["config/paper-world-defaults.yml".to_string()]);
+    let difficulty = preview.diffs.iter().find(|d| d.key == "difficulty").unwrap();
+    assert_eq!((difficulty.current.as_deref(), difficulty.proposed.as_str(), difficulty.changed), (Some("easy"), "hard", true));
+    assert!(!preview.diffs.iter().find(|d| d.key == "pvp").unwrap().changed);
+
+    // Nothing is written while a file the preset needs is missing
+    assert!(apply_preset(root, &hardcore, false).await.is_err());
+    assert!(std::fs::read_to_string(root.join("server.properties")).unwrap().contains("difficulty=easy"));
+
+    std::fs::create_dir_all(root.join("config")).unwrap();
+    std::fs::write(
+        root.join("config/paper-world-defaults.yml"),
+        "# Paper world defaults\n_version: 30\nentities:\n  spawning:\n    per-player-mob-spawns: false # caps\n    monster-spawn-max-light-level: -1\n",
+    ).unwrap();
+    let changed = apply_preset(root, &hardcore, false).await.unwrap();
+    assert_eq!(changed.len(), 2);
+    let world_text = std::fs::read_to_string(root.join("config/paper-world-defaults.yml")).unwrap();
+    assert!(world_text.starts_with("# Paper world defaults\n"));
+    assert!(world_text.contains("    per-player-mob-spawns: true # caps\n"));
+    assert_eq!(
+        std::fs::read_to_string(root.join("server.properties")).unwrap(),
+        "# comment\ndifficulty=hard\npvp=true\nhardcore=true\nspawn-protection=0\n"
+    );
+    let world: YamlValue = serde_yaml::from_str(&std::fs::read_to_string(root.join("config/paper-world-defaults.yml")).unwrap()).unwrap();
+    assert_eq!(world["entities"]["spawning"]["per-player-mob-spawns"], YamlValue::Bool(true));
+    assert_eq!(world["entities"]["spawning"]["monster-spawn-max-light-level"], YamlValue::from(-1));
+    assert_eq!(world["_version"], YamlValue::from(30));
+
+    // A second run finds nothing to change
+    assert!(preview_preset(root, &hardcore).await.unwrap().diffs.iter().all(|d| !d.changed));
+    assert!(apply_preset(root, &hardcore, false).await.unwrap().is_empty());
+
+    // Missing sections are created
+    let anti_xray = find_preset("anti-xray").unwrap();
+    apply_preset(root, &anti_xray, false).await.unwrap();
+    let world: YamlValue = serde_yaml::from_str(&std::fs::read_to_string(root.join("config/paper-world-defaults.yml")).unwrap()).unwrap();
+    assert_eq!(world["anticheat"]["anti-xray"]["enabled"], YamlValue::Bool(true));
+
+    assert!(find_preset("nope").is_err());
+    assert!(presets_supported(Some("Purpur")));
+    assert!(!presets_supported(Some("fabric")));
+}
+
+#[test]
+fn test_patch_yaml_value_keeps_layout() {
+    use mc_server_wrapper_core::config_files::yaml_patch::patch_yaml_value;
+
+    let content = "# header\nchunks:\n  auto-save: 10 # ticks\n\n# misc section\nmisc:\n  redstone: VANILLA\n";
+    let patched = patch_yaml_value(content, "chunks.auto-save", &YamlValue::from(8)).unwrap();
+    assert_eq!(patched, content.replace("auto-save: 10", "auto-save: 8"));
+
+    // Missing keys go at the end of their section, before the next section's comment
+    let patched = patch_yaml_value(&patched, "chunks.max-per-tick", &YamlValue::from(4)).unwrap();
+    assert!(patched.contains("  auto-save: 8 # ticks\n  max-per-tick: 4\n\n# misc section\n"));
+    let patched = patch_yaml_value(&patched, "anticheat.anti-xray.enabled", &YamlValue::Bool(true)).unwrap();
+    assert!(patched.ends_with("anticheat:\n  anti-xray:\n    enabled: true\n"));
+
+    assert!(patch_yaml_value(&patched, "chunks", &YamlValue::Bool(true)).is_err());
+    assert!(patch_yaml_value(&patched, "misc.redstone.mode", &YamlValue::Bool(true)).is_err());
+}
@@ -0,0 +1,74 @@
+use mc_server_wrapper_core::conflicts::{ConflictKind, check_install_conflicts_sync};
+use mc_server_wrapper_core::content_report::ContentKind;
+use std::io::Write;
+use std::path::Path;
+use tempfile::tempdir;
+use zip::write::SimpleFileOptions;
+
+fn write_jar(path: &Path, entry: &str, content: &str) {
+    let mut zip = zip::ZipWriter::new(std::fs::File::create(path).unwrap());
+    zip.start_file(entry, SimpleFileOptions::default()).unwrap();
+    zip.write_all(content.as_bytes()).unwrap();
+    zip.finish().unwrap();
+}
+
+fn fabric_mod(path: &Path, id: &str) {
+    write_jar(path, "fabric.mod.json", &format!(r#"{{"schemaVersion": 1, "id": "{}", "version": "1.0"}}"#, id));
+}
+
+#[test]
+fn test_mod_conflicts() {
+    let dir = tempdir().unwrap();
+    let mods = dir.path().join("mods");
+    std::fs::create_dir_all(&mods).unwrap();
+    fabric_mod(&mods.join("lithium-0.11.jar"), "lithium");
+    fabric_mod(&mods.join("lithium-0.12.jar"), "lithium");
+    fabric_mod(&mods.join("canary-0.3.jar"), "canary");
+    fabric_mod(&mods.join("old-lithium.jar.disabled"), "lithium");
+    fabric_mod(&mods.join("fabric-api.jar"), "fabric-api");
+    std::fs::write(
+        mods.join(".mod_metadata_cache.json"),
+        r#"{"entries": {}, "sources": {
+            "lithium-0.11.jar": {"project_id": "gvQqBUqZ", "provider": "Modrinth", "current_version_id": "a"},
+            "lithium-0.12.jar": {"project_id": "gvQqBUqZ", "provider": "Modrinth", "current_version_id": "b"}
+        }}"#,
+    )
+    .unwrap();
+
+    let warnings = check_install_conflicts_sync(dir.path(), ContentKind::Mod, "lithium-0.12.jar");
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(warnings[0].kind, ConflictKind::Incompatible);
+    assert_eq!(warnings[0].other_file, "canary-0.3.jar");
+    assert_eq!(warnings[1].kind, ConflictKind::DuplicateProject);
+    assert_eq!(warnings[1].other_file, "lithium-0.11.jar");
+
+    // Without a recorded source the same mod ID still gives it away
+    fabric_mod(&mods.join("lithium-manual.jar"), "lithium");
+    let warnings = check_install_conflicts_sync(dir.path(), ContentKind::Mod, "lithium-manual.jar");
+    let duplicates: Vec<_> = warnings.iter().filter(|w| w.kind == ConflictKind::DuplicateId).collect();
+    assert_eq!(duplicates.len(), 2);
+    assert!(duplicates[0].message.contains("lithium"));
+
+    assert!(check_install_conflicts_sync(dir.path(), ContentKind::Mod, "fabric-api.jar").is_empty());
+    assert!(check_install_conflicts_sync(dir.path(), ContentKind::Mod, "missing.jar").is_empty());
+}
+
+#[test]
+fn test_plugin_conflicts() {
+    let dir = tempdir().unwrap();
+    let plugins = dir.path().join("plugins");
+    std::fs::create_dir_all(&plugins).unwrap();
+    write_jar(&plugins.join("EssentialsX-2.20.jar"), "plugin.yml", "name: Essentials\nversion: 2.20.1\n");
+    write_jar(&plugins.join("EssentialsX-2.21.jar"), "plugin.yml", "name: Essentials\nversion: 2.21.0\n");
+    write_jar(&plugins.join("CMI.jar"), "plugin.yml", "name: CMI\nversion: 9.7\n");
+    write_jar(&plugins.join("Vault.jar"), "plugin.yml", "name: Vault\nversion: 1.7\n");
+
+    let warnings = check_install_conflicts_sync(dir.path(), ContentKind::Plugin, "EssentialsX-2.21.jar");
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(warnings[0].kind, ConflictKind::Incompatible);
+    assert_eq!(warnings[0].other_file, "CMI.jar");
+    assert_eq!(warnings[1].kind, ConflictKind::DuplicateId);
+    assert_eq!(warnings[1].other_file, "EssentialsX-2.20.jar");
+
+    assert!(check_install_conflicts_sync(dir.path(), ContentKind::Plugin, "Vault.jar").is_empty());
+}
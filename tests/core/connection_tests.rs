@@ -0,0 +1,111 @@
+use mc_server_wrapper_core::connection::stun::{binding_request, parse_binding_response, stun_public_address};
+use mc_server_wrapper_core::connection::{AddressKind, build_connection_info, format_address};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use tokio::net::UdpSocket;
+
+const COOKIE: [u8; 4] = [0x21, 0x12, 0xA4, 0x42];
+
+/// A Binding success response carrying `mapped` as XOR-MAPPED-ADDRESS.
+fn xor_mapped_response(transaction_id: &[u8], mapped: SocketAddrV4Parts) -> Vec<u8> {
+    let mut packet = vec![0x01, 0x01, 0x00, 0x0C];
+    packet.extend_from_slice(&COOKIE);
+    packet.extend_from_slice(transaction_id);
+    packet.extend_from_slice(&[0x00, 0x20, 0x00, 0x08, 0x00, 0x01]);
+    packet.extend_from_slice(&(mapped.port ^ 0x2112).to_be_bytes());
+    for (octet, c) in mapped.ip.iter().zip(COOKIE) {
+        packet.push(octet ^ c);
+    }
+    packet
+}
+
+struct SocketAddrV4Parts {
+    ip: [u8; 4],
+    port: u16,
+}
+
+#[test]
+fn test_parse_xor_mapped_address() {
+    let txid = [7u8; 12];
+    let request = binding_request(txid);
+    assert_eq!(request.len(), 20);
+    assert_eq!(&request[4..8], &COOKIE);
+
+    let response = xor_mapped_response(
+        &txid,
+        SocketAddrV4Parts {
+            ip: [203, 0, 113, 7],
+            port: 54321,
+        },
+    );
+    let address = parse_binding_response(&response, txid).unwrap();
+    assert_eq!(
+        address,
+        SocketAddr::new(IpAddr::V4(Ipv4Addr::new(203, 0, 113, 7)), 54321)
+    );
+
+    // A response to someone else's request is rejected
+    assert!(parse_binding_response(&response, [8u8; 12]).is_err());
+}
+
+#[tokio::test]
+async fn test_stun_lookup_against_local_server() {
+    let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let server_addr = server.local_addr().unwrap();
+    tokio::spawn(async move {
+        let mut buf = [0u8; 64];
+        let (len, from) = server.recv_from(&mut buf).await.unwrap();
+        assert_eq!(len, 20);
+        let response = xor_mapped_response(
+            &buf[8..20],
+            SocketAddrV4Parts {
+                ip: [198, 51, 100, 2],
+                port: 40000,
+            },
+        );
+        server.send_to(&response, from).await.unwrap();
+    });
+
+    let address = stun_public_address(&server_addr.to_string()).await.unwrap();
+    assert_eq!(address.ip(), IpAddr::V4(Ipv4Addr::new(198, 51, 100, 2)));
+}
+
+#[test]
+fn test_format_address_omits_default_port() {
+    assert_eq!(format_address("192.168.1.20", 25565, false), "192.168.1.20");
+    assert_eq!(format_address("192.168.1.20", 25566, false), "192.168.1.20:25566");
+    assert_eq!(format_address("192.168.1.20", 19132, true), "192.168.1.20");
+    assert_eq!(format_address("2001:db8::1", 25566, false), "[2001:db8::1]:25566");
+}
+
+#[test]
+fn test_connection_info_orders_addresses_and_builds_invite() {
+    let lan: IpAddr = "192.168.1.20".parse().unwrap();
+    let public: IpAddr = "203.0.113.7".parse().unwrap();
+    let info = build_connection_info("Survival", "1.21.1", 25566, false, Some(lan), Some(public), Some("  "));
+
+    let kinds: Vec<AddressKind> = info.addresses.iter().map(|a| a.kind).collect();
+    assert_eq!(kinds, vec![AddressKind::Public, AddressKind::Lan]);
+    assert!(info.invite.contains("Address: 203.0.113.7:25566"));
+    assert!(info.invite.contains("On the same Wi-Fi: 192.168.1.20:25566"));
+    assert!(info.notes[0].contains("port 25566 (TCP) forwarded to 192.168.1.20"));
+
+    let tunneled = build_connection_info(
+        "Survival",
+        "1.21.1",
+        25565,
+        false,
+        Some(lan),
+        None,
+        Some("mc.example.gg"),
+    );
+    assert_eq!(tunneled.addresses[0].kind, AddressKind::Tunnel);
+    assert!(tunneled.invite.contains("Address: mc.example.gg"));
+}
+
+#[test]
+fn test_connection_info_flags_cgnat() {
+    let public: IpAddr = "100.72.1.9".parse().unwrap();
+    let info = build_connection_info("Bedrock", "1.21", 19132, true, None, Some(public), None);
+    assert!(info.notes[0].contains("CGNAT"));
+    assert!(info.invite.contains("Port: 19132"));
+}
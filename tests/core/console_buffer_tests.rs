@@ -0,0 +1,77 @@
+use mc_server_wrapper_core::server::{
+    CONSOLE_BUFFER_LINES, ConsoleBuffer, LogLevel, LogSender, parse_log_line, strip_color_codes,
+};
+
+#[test]
+fn test_parse_log_line_prefixes() {
+    assert_eq!(
+        parse_log_line("[14:03:27] [Server thread/INFO]: Done (3.2s)! For help, type \"help\""),
+        (Some(LogLevel::Info), Some("14:03:27".to_string()))
+    );
+    assert_eq!(
+        parse_log_line("[14:03:27 WARN]: Can't keep up! Is the server overloaded?"),
+        (Some(LogLevel::Warn), Some("14:03:27".to_string()))
+    );
+    assert_eq!(
+        parse_log_line("[12Mar2024 14:03:27.123] [Server thread/ERROR] [minecraft/DedicatedServer]: Oops"),
+        (Some(LogLevel::Error), Some("14:03:27".to_string()))
+    );
+    assert_eq!(
+        parse_log_line("ERROR: Exception in thread \"main\""),
+        (Some(LogLevel::Error), None)
+    );
+    assert_eq!(parse_log_line("Downloading server jar..."), (None, None));
+}
+
+#[test]
+fn test_color_codes_are_stripped_but_kept_raw() {
+    let raw = "\u{1b}[33;1m[14:03:27 INFO]: §aWelcome §lback\u{1b}[m";
+    assert_eq!(strip_color_codes(raw), "[14:03:27 INFO]: Welcome back");
+
+    let mut buffer = ConsoleBuffer::default();
+    buffer.push(raw.to_string());
+    let page = buffer.since(0);
+    assert_eq!(page.lines[0].raw, raw);
+    assert_eq!(page.lines[0].text, "[14:03:27 INFO]: Welcome back");
+    assert_eq!(page.lines[0].level, Some(LogLevel::Info));
+}
+
+#[test]
+fn test_console_buffer_keeps_the_newest_lines() {
+    let mut buffer = ConsoleBuffer::default();
+    let total = CONSOLE_BUFFER_LINES + 250;
+    for i in 0..total {
+        buffer.push(format!("line {}", i));
+    }
+
+    let page = buffer.since(0);
+    assert_eq!(page.lines.len(), CONSOLE_BUFFER_LINES);
+    assert_eq!(page.lines[0].text, "line 250");
+    assert_eq!(page.lines[0].number, 250);
+    assert_eq!(page.dropped, 250);
+    assert_eq!(page.next_line, total as u64);
+
+    // A view that already has everything up to `next_line` gets only what's new
+    buffer.push("newest".to_string());
+    let newer = buffer.since(page.next_line);
+    assert_eq!(newer.lines.len(), 1);
+    assert_eq!(newer.lines[0].text, "newest");
+    assert_eq!(newer.dropped, 0);
+    assert!(buffer.since(newer.next_line).lines.is_empty());
+}
+
+#[test]
+fn test_live_lines_carry_their_buffer_number() {
+    let sender = LogSender::new(16);
+    let _ = sender.send("before anyone listened".to_string());
+    let mut lines = sender.subscribe_lines();
+    let _ = sender.send("\u{1b}[31m[14:03:27 ERROR]: Boom".to_string());
+
+    let live = lines.try_recv().expect("line was broadcast");
+    assert_eq!(live.number, 1);
+    assert_eq!(live.level, Some(LogLevel::Error));
+    let page = sender.buffered_since(0);
+    assert_eq!(page.lines.len(), 2);
+    assert_eq!(page.lines[1].number, live.number);
+    assert_eq!(page.lines[1].raw, live.raw);
+}
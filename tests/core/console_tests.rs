@@ -0,0 +1,92 @@
+use anyhow::Result;
+use mc_server_wrapper_core::console::suggest_commands;
+use mc_server_wrapper_core::database::Database;
+use mc_server_wrapper_core::instance::InstanceManager;
+use std::sync::Arc;
+use tempfile::tempdir;
+
+#[tokio::test]
+async fn test_command_history_persistence() -> Result<()> {
+    let dir = tempdir()?;
+    let db = Arc::new(Database::new(dir.path().join("test.db")).await?);
+    let manager = InstanceManager::new(dir.path(), db).await?;
+    let instance = manager.create_instance("History", "1.20.1").await?;
+
+    manager.record_command(instance.id, "say hello").await?;
+    manager.record_command(instance.id, "   ").await?;
+    manager.record_command(instance.id, "time set day").await?;
+
+    let history = manager.get_command_history(instance.id, 10).await?;
+    let commands: Vec<&str> = history.iter().map(|e| e.command.as_str()).collect();
+    assert_eq!(commands, vec!["time set day", "say hello"]);
+
+    manager.delete_instance(instance.id).await?;
+    assert!(manager.get_command_history(instance.id, 10).await?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_suggest_commands() {
+    let names = |s: Vec<mc_server_wrapper_core::console::CommandSuggestion>| {
+        s.into_iter().map(|s| s.command).collect::<Vec<_>>()
+    };
+
+    let vanilla = names(suggest_commands("/wh", None, &[], &[]));
+    assert_eq!(vanilla, vec!["whitelist"]);
+
+    // Bukkit commands are only offered on Bukkit-based servers
+    assert!(suggest_commands("tp", None, &[], &[]).iter().all(|s| s.command != "tps"));
+    assert!(suggest_commands("tp", Some("paper"), &[], &[]).iter().any(|s| s.command == "tps"));
+
+    // History comes first, and a complete command name yields its usage hint
+    let history = vec!["gamemode creative Steve".to_string()];
+    let suggestions = suggest_commands("gamemode ", None, &history, &[]);
+    assert!(suggestions[0].from_history);
+    assert_eq!(suggestions[1].command, "gamemode");
+    assert!(suggestions[1].usage.as_deref().unwrap().contains("spectator"));
+}
+
+#[test]
+fn test_parse_help_output() {
+    use mc_server_wrapper_core::console::{parse_help_line, parse_help_page_count};
+
+    let vanilla = parse_help_line("[12:00:00] [Server thread/INFO]: /gamemode <gamemode> [<target>]").unwrap();
+    assert_eq!(vanilla.name, "gamemode");
+    assert_eq!(vanilla.usage.as_deref(), Some("gamemode <gamemode> [<target>]"));
+
+    let bukkit = parse_help_line("[12:00:00 INFO]: /essentials: Shows the Essentials version").unwrap();
+    assert_eq!(bukkit.name, "essentials");
+    assert_eq!(bukkit.description.as_deref(), Some("Shows the Essentials version"));
+
+    assert!(parse_help_line("[12:00:00] [Server thread/INFO]: <Steve> hello").is_none());
+    assert_eq!(parse_help_page_count("--------- Help: Index (1/4) ---------"), Some(4));
+}
+
+#[test]
+fn test_parse_commands_report_and_discovered_suggestions() {
+    use mc_server_wrapper_core::console::parse_commands_report;
+
+    let report = r#"{
+        "type": "root",
+        "children": {
+            "weather": {"type": "literal", "children": {
+                "clear": {"type": "literal", "executable": true},
+                "rain": {"type": "literal", "executable": true}
+            }},
+            "tp": {"type": "literal", "redirect": ["teleport"]},
+            "claim": {"type": "literal", "children": {
+                "radius": {"type": "argument", "parser": "brigadier:integer"}
+            }}
+        }
+    }"#;
+    let commands = parse_commands_report(report).unwrap();
+    let names: Vec<&str> = commands.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, vec!["claim", "tp", "weather"]);
+    assert_eq!(commands[0].usage.as_deref(), Some("claim <radius>"));
+    assert_eq!(commands[2].usage.as_deref(), Some("weather (clear|rain)"));
+
+    // Discovered commands replace the static catalog
+    let suggestions = suggest_commands("c", None, &[], &commands);
+    let names: Vec<&str> = suggestions.iter().map(|s| s.command.as_str()).collect();
+    assert_eq!(names, vec!["claim"]);
+}
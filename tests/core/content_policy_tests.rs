@@ -0,0 +1,93 @@
+use mc_server_wrapper_core::errors::AppError;
+use mc_server_wrapper_core::utils::content_policy::{archive_problem, check_content_type, expects_archive};
+use mc_server_wrapper_core::utils::{DownloadLimits, QUARANTINE_DIR, RejectedDownload, download_to_file, validate_download};
+use std::io::Write;
+use std::path::Path;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn write_jar(path: &Path) {
+    let mut zip = zip::ZipWriter::new(std::fs::File::create(path).unwrap());
+    zip.start_file("plugin.yml", zip::write::SimpleFileOptions::default()).unwrap();
+    zip.write_all(b"name: Test\n").unwrap();
+    zip.finish().unwrap();
+}
+
+#[test]
+fn test_archive_problem_sniffs_pages() {
+    let dir = tempfile::tempdir().unwrap();
+    let jar = dir.path().join("good.jar");
+    write_jar(&jar);
+    assert_eq!(archive_problem(&jar), None);
+
+    let page = dir.path().join("page.jar");
+    std::fs::write(&page, "  <!DOCTYPE html><html><title>Just a moment...</title></html>").unwrap();
+    assert!(archive_problem(&page).unwrap().contains("HTML page"));
+
+    let json = dir.path().join("error.jar");
+    std::fs::write(&json, r#"{"error":"not found"}"#).unwrap();
+    assert!(archive_problem(&json).unwrap().contains("JSON"));
+
+    let truncated = dir.path().join("truncated.jar");
+    std::fs::write(&truncated, &std::fs::read(&jar).unwrap()[..20]).unwrap();
+    assert!(archive_problem(&truncated).unwrap().contains("not a valid archive"));
+
+    assert!(expects_archive(Path::new("mods/Sodium.JAR")));
+    assert!(!expects_archive(Path::new("config/sodium.json")));
+    assert!(check_content_type(Path::new("a.jar"), Some("text/html; charset=utf-8")).is_err());
+    assert!(check_content_type(Path::new("a.json"), Some("application/json")).is_ok());
+    assert!(check_content_type(Path::new("a.jar"), Some("application/octet-stream")).is_ok());
+}
+
+#[tokio::test]
+async fn test_validate_download_quarantines_bad_jars() {
+    let dir = tempfile::tempdir().unwrap();
+    let page = dir.path().join("plugin.jar");
+    std::fs::write(&page, "<html>Access denied</html>").unwrap();
+
+    let rejected = validate_download(&page).await.unwrap_err();
+    assert_eq!(rejected.file_name, "plugin.jar");
+    let quarantined = rejected.quarantined_to.clone().unwrap();
+    assert!(quarantined.starts_with(dir.path().join(QUARANTINE_DIR)));
+    assert!(quarantined.to_string_lossy().ends_with(".quarantined"));
+    assert!(quarantined.exists() && !page.exists());
+
+    let err = anyhow::Error::from(rejected);
+    assert!(matches!(AppError::from(err), AppError::RejectedDownload(_)));
+
+    let notes = dir.path().join("notes.txt");
+    std::fs::write(&notes, "<html>").unwrap();
+    assert!(validate_download(&notes).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_download_rejects_error_pages() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/blocked.jar"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw("<html>Blocked</html>", "text/html"))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/mislabeled.jar"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw("<html>Blocked</html>", "application/octet-stream"))
+        .mount(&server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let client = reqwest::Client::new();
+    let limits = DownloadLimits::default();
+    let target = dir.path().join("blocked.jar");
+    let err = download_to_file(&client, &limits, &format!("{}/blocked.jar", server.uri()), &target, |_, _| {})
+        .await
+        .unwrap_err();
+    assert!(err.downcast_ref::<RejectedDownload>().unwrap().reason.contains("text/html"));
+    assert!(!target.exists());
+
+    let target = dir.path().join("mislabeled.jar");
+    let err = download_to_file(&client, &limits, &format!("{}/mislabeled.jar", server.uri()), &target, |_, _| {})
+        .await
+        .unwrap_err();
+    assert!(err.downcast_ref::<RejectedDownload>().unwrap().quarantined_to.is_some());
+    assert!(!target.exists());
+}
@@ -0,0 +1,69 @@
+use mc_server_wrapper_core::cache::CacheManager;
+use mc_server_wrapper_core::content_report::{
+    ContentKind, generate_content_report, record_update_check,
+};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+use tempfile::tempdir;
+use tokio::fs;
+
+fn create_jar(path: &Path, entries: &[(&str, &str)]) {
+    let file = std::fs::File::create(path).unwrap();
+    let mut zip = zip::ZipWriter::new(file);
+    for (name, content) in entries {
+        zip.start_file(*name, zip::write::SimpleFileOptions::default()).unwrap();
+        zip.write_all(content.as_bytes()).unwrap();
+    }
+    zip.finish().unwrap();
+}
+
+#[tokio::test]
+async fn test_content_report_licenses_and_update_status() {
+    let dir = tempdir().unwrap();
+    let mods_dir = dir.path().join("mods");
+    let plugins_dir = dir.path().join("plugins");
+    fs::create_dir_all(&mods_dir).await.unwrap();
+    fs::create_dir_all(&plugins_dir).await.unwrap();
+
+    create_jar(
+        &mods_dir.join("sodium.jar"),
+        &[("fabric.mod.json", r#"{"id": "sodium", "name": "Sodium", "version": "0.5.3", "license": ["LGPL-3.0-only"]}"#)],
+    );
+    create_jar(
+        &mods_dir.join("create.jar"),
+        &[("META-INF/mods.toml", "license = \"MIT\"\n[[mods]]\nmodId = \"create\"\ndisplayName = \"Create\"\n")],
+    );
+    create_jar(
+        &plugins_dir.join("Essentials.jar"),
+        &[("plugin.yml", "name: Essentials\nversion: 2.20.1\nmain: com.earth2me.essentials.Essentials\n")],
+    );
+
+    record_update_check(
+        &mods_dir,
+        vec!["sodium.jar".to_string(), "create.jar".to_string()],
+        vec![("create.jar".to_string(), "0.6.0".to_string())],
+    )
+    .await
+    .unwrap();
+
+    let report = generate_content_report(dir.path(), Arc::new(CacheManager::default()))
+        .await
+        .unwrap();
+    assert_eq!(report.entries.len(), 3);
+
+    let sodium = report.entries.iter().find(|e| e.filename == "sodium.jar").unwrap();
+    assert_eq!(sodium.kind, ContentKind::Mod);
+    assert_eq!(sodium.license.as_deref(), Some("LGPL-3.0-only"));
+    assert_eq!(sodium.update.update_available, Some(false));
+    assert!(sodium.update.checked_at.is_some());
+
+    let create = report.entries.iter().find(|e| e.filename == "create.jar").unwrap();
+    assert_eq!(create.license.as_deref(), Some("MIT"));
+    assert_eq!(create.update.latest_version.as_deref(), Some("0.6.0"));
+
+    let essentials = report.entries.iter().find(|e| e.kind == ContentKind::Plugin).unwrap();
+    assert_eq!(essentials.version.as_deref(), Some("2.20.1"));
+    assert!(essentials.license.is_none());
+    assert!(essentials.update.checked_at.is_none());
+}
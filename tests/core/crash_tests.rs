@@ -0,0 +1,97 @@
+use anyhow::Result;
+use mc_server_wrapper_core::crash::{likely_culprit, parse_crash_report};
+use mc_server_wrapper_core::database::Database;
+use mc_server_wrapper_core::instance::InstanceManager;
+use std::sync::Arc;
+use tempfile::tempdir;
+
+const FABRIC_REPORT: &str = "---- Minecraft Crash Report ----
+// Who set us up the TNT?
+
+Time: 2024-05-01 12:00:00
+Description: Exception in server tick loop
+
+java.lang.NullPointerException: Cannot invoke \"Object.hashCode()\" because \"key\" is null
+\tat java.base/java.util.HashMap.get(HashMap.java:556)
+\tat com.example.coolmod.world.Spawner.tick(Spawner.java:42)
+\tat net.minecraft.server.MinecraftServer.tick(MinecraftServer.java:100)
+
+A detailed walkthrough of the error, its code path and all known details is as follows:
+---------------------------------------------------------------------------------------
+
+-- System Details --
+Details:
+\tMinecraft Version: 1.20.1
+\tJava Version: 17.0.9, Eclipse Adoptium
+\tFabric Mods:
+\t\tcoolmod: Cool Mod 1.0.0
+";
+
+const FORGE_REPORT: &str = "---- Minecraft Crash Report ----
+Time: 2024-05-02 08:30:00
+Description: Ticking entity
+
+java.lang.IllegalStateException: boom
+\tat TRANSFORMER/brokenmod@2.1/org.broken.Entity.tick(Entity.java:10)
+
+-- Head --
+Thread: Server thread
+Suspected Mod: Broken Mod (brokenmod), Version: 2.1
+\tIssue tracker URL: https://example.com/issues
+\tat TRANSFORMER/brokenmod@2.1/org.broken.Entity.tick(Entity.java:10)
+";
+
+#[test]
+fn test_parse_crash_report() {
+    let report = parse_crash_report(FABRIC_REPORT);
+    assert_eq!(report.time.as_deref(), Some("2024-05-01 12:00:00"));
+    assert_eq!(report.description.as_deref(), Some("Exception in server tick loop"));
+    assert!(report.exception.as_deref().unwrap().starts_with("java.lang.NullPointerException"));
+    assert_eq!(report.stack_trace.len(), 4);
+    assert!(report.suspected_mods.is_empty());
+    assert_eq!(report.system_details.get("Minecraft Version").map(String::as_str), Some("1.20.1"));
+    assert!(!report.system_details.contains_key("coolmod"));
+}
+
+#[test]
+fn test_likely_culprit() {
+    let fabric = parse_crash_report(FABRIC_REPORT);
+    let installed = vec!["Fabric API".to_string(), "Cool Mod".to_string()];
+    assert_eq!(likely_culprit(&fabric, &installed).as_deref(), Some("Cool Mod"));
+    // Without a matching install the frame's package is the lead
+    assert_eq!(likely_culprit(&fabric, &[]).as_deref(), Some("com.example.coolmod.world.Spawner"));
+
+    let forge = parse_crash_report(FORGE_REPORT);
+    assert_eq!(forge.suspected_mods, vec!["Broken Mod (brokenmod), Version: 2.1".to_string()]);
+    assert_eq!(likely_culprit(&forge, &[]).as_deref(), Some("Broken Mod"));
+}
+
+#[tokio::test]
+async fn test_crash_reports_are_detected_and_stored() -> Result<()> {
+    let dir = tempdir()?;
+    let db = Arc::new(Database::new(dir.path().join("test.db")).await?);
+    let manager = InstanceManager::new(dir.path(), db).await?;
+    let instance = manager.create_instance("Crashy", "1.20.1").await?;
+    assert!(manager.list_crash_reports(instance.id).await?.is_empty());
+
+    let reports_dir = instance.path.join("crash-reports");
+    std::fs::create_dir_all(&reports_dir)?;
+    std::fs::write(reports_dir.join("crash-2024-05-01_12.00.00-server.txt"), FABRIC_REPORT)?;
+    std::fs::write(reports_dir.join("crash-2024-05-02_08.30.00-server.txt"), FORGE_REPORT)?;
+
+    let reports = manager.list_crash_reports(instance.id).await?;
+    assert_eq!(reports.len(), 2);
+    assert_eq!(reports[0].filename, "crash-2024-05-02_08.30.00-server.txt");
+    assert_eq!(reports[0].likely_culprit.as_deref(), Some("Broken Mod"));
+
+    // Already stored files are not parsed again
+    assert_eq!(manager.scan_crash_reports(instance.id).await?, 0);
+
+    let report = manager.get_crash_report(instance.id, reports[1].id).await?.unwrap();
+    assert_eq!(report.details.stack_trace.len(), 4);
+    assert!(manager.get_crash_report(instance.id, -1).await?.is_none());
+
+    manager.delete_instance(instance.id).await?;
+    assert!(manager.list_crash_reports(instance.id).await?.is_empty());
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use mc_server_wrapper_core::app_config::GlobalConfigManager;
+use mc_server_wrapper_core::credentials::{CredentialKind, Credentials, KeyStore};
+use tempfile::tempdir;
+
+#[tokio::test]
+async fn test_credentials_are_encrypted_at_rest() -> anyhow::Result<()> {
+    let dir = tempdir()?;
+    let key_store = KeyStore::in_memory();
+    let manager = GlobalConfigManager::new(dir.path().join("app_settings.json")).with_key_store(key_store.clone());
+
+    assert_eq!(manager.load_credentials().await?, Credentials::default());
+
+    manager
+        .set_credential(CredentialKind::CurseForge, Some("  cf-secret-key  ".to_string()))
+        .await?;
+    manager
+        .set_credential(CredentialKind::GitHub, Some("ghp_secret".to_string()))
+        .await?;
+
+    let raw = std::fs::read(dir.path().join("credentials.enc"))?;
+    assert!(!String::from_utf8_lossy(&raw).contains("cf-secret-key"));
+    assert!(!dir.path().join("credentials.key").exists());
+    assert_eq!(manager.credentials().curseforge_api_key().as_deref(), Some("cf-secret-key"));
+
+    // A fresh manager reads the same credentials back
+    let reloaded = GlobalConfigManager::new(dir.path().join("app_settings.json")).with_key_store(key_store);
+    let credentials = reloaded.load_credentials().await?;
+    assert_eq!(credentials.curseforge_api_key.as_deref(), Some("cf-secret-key"));
+    assert_eq!(credentials.github_token.as_deref(), Some("ghp_secret"));
+    assert!(credentials.status().github_token);
+
+    reloaded.set_credential(CredentialKind::GitHub, None).await?;
+    assert!(!reloaded.load_credentials().await?.status().github_token);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_legacy_key_file_moves_into_key_store() -> anyhow::Result<()> {
+    let dir = tempdir()?;
+    std::fs::write(dir.path().join("credentials.key"), [7u8; 32])?;
+    let key_store = KeyStore::in_memory();
+    let manager = GlobalConfigManager::new(dir.path().join("app_settings.json")).with_key_store(key_store.clone());
+
+    manager
+        .set_credential(CredentialKind::GitHub, Some("ghp_secret".to_string()))
+        .await?;
+    assert!(!dir.path().join("credentials.key").exists());
+
+    let reloaded = GlobalConfigManager::new(dir.path().join("app_settings.json")).with_key_store(key_store);
+    assert_eq!(reloaded.load_credentials().await?.github_token.as_deref(), Some("ghp_secret"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_undecryptable_credentials_are_set_aside() -> anyhow::Result<()> {
+    let dir = tempdir()?;
+    std::fs::write(dir.path().join("credentials.enc"), b"written with a key that is gone")?;
+    let manager = GlobalConfigManager::new(dir.path().join("app_settings.json")).with_key_store(KeyStore::in_memory());
+
+    assert_eq!(manager.load_credentials().await?, Credentials::default());
+    let backups: Vec<_> = std::fs::read_dir(dir.path())?
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_name().to_string_lossy().ends_with(".bak"))
+        .collect();
+    assert_eq!(backups.len(), 1);
+    assert_eq!(std::fs::read(backups[0].path())?, b"written with a key that is gone");
+
+    manager
+        .set_credential(CredentialKind::GitHub, Some("ghp_new".to_string()))
+        .await?;
+    assert_eq!(manager.load_credentials().await?.github_token.as_deref(), Some("ghp_new"));
+    Ok(())
+}
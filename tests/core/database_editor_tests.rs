@@ -0,0 +1,139 @@
+use anyhow::Result;
+use mc_server_wrapper_core::database::editor::{DatabaseEditor, RowUpdate, backup_path};
+use mc_server_wrapper_core::database::explorer;
+use mc_server_wrapper_core::server::ServerStatus;
+use serde_json::json;
+use sqlx::SqlitePool;
+use sqlx::sqlite::SqliteConnectOptions;
+use std::path::Path;
+use tempfile::tempdir;
+
+async fn create_db(path: &Path) -> Result<()> {
+    let options = SqliteConnectOptions::new().filename(path).create_if_missing(true);
+    let pool = SqlitePool::connect_with(options).await?;
+    sqlx::query("CREATE TABLE co_user (id INTEGER PRIMARY KEY, user TEXT, time INTEGER)")
+        .execute(&pool)
+        .await?;
+    sqlx::query("INSERT INTO co_user (user, time) VALUES ('Notch', 1), ('jeb_', 2), ('Dinnerbone', 3)")
+        .execute(&pool)
+        .await?;
+    pool.close().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_database_writes_need_a_stopped_server_inside_the_instance() -> Result<()> {
+    let dir = tempdir()?;
+    let instance = dir.path().join("instance");
+    let db = instance.join("plugins").join("CoreProtect").join("database.db");
+    std::fs::create_dir_all(db.parent().unwrap())?;
+    create_db(&db).await?;
+    let outside = dir.path().join("other.db");
+    create_db(&outside).await?;
+    std::fs::write(instance.join("script.sql"), "SELECT 1;")?;
+
+    let editor = DatabaseEditor::new();
+    assert!(
+        editor
+            .open_for_write(&instance, ServerStatus::Running, &db)
+            .await
+            .is_err()
+    );
+    assert!(
+        editor
+            .open_for_write(&instance, ServerStatus::Starting, &db)
+            .await
+            .is_err()
+    );
+    assert!(
+        editor
+            .open_for_write(&instance, ServerStatus::Stopped, &outside)
+            .await
+            .is_err()
+    );
+    assert!(
+        editor
+            .open_for_write(&instance, ServerStatus::Stopped, &instance.join("script.sql"))
+            .await
+            .is_err()
+    );
+    // Nothing was written, so nothing was backed up
+    assert!(!backup_path(&db).exists());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_database_edits_back_up_once_per_session() -> Result<()> {
+    let dir = tempdir()?;
+    let db = dir.path().join("database.db");
+    create_db(&db).await?;
+
+    let editor = DatabaseEditor::new();
+    let writable = editor.open_for_write(dir.path(), ServerStatus::Stopped, &db).await?;
+    assert_eq!(
+        writable.backup.as_deref(),
+        Some(backup_path(&db).canonicalize()?.as_path())
+    );
+    assert_eq!(backup_path(&db), dir.path().join("database.db.bak"));
+
+    let data = explorer::get_table_data(&db, "co_user", 50, 0).await?;
+    assert_eq!(data.columns, vec!["id", "user", "time"]);
+    assert_eq!(data.row_ids, vec![1, 2, 3]);
+
+    let update = RowUpdate {
+        table: "co_user".to_string(),
+        rowid: data.row_ids[0],
+        changes: json!({ "user": "Notch_", "time": null }).as_object().unwrap().clone(),
+    };
+    assert_eq!(writable.update_row(&update).await?, 1);
+    assert_eq!(writable.delete_row("co_user", data.row_ids[1]).await?, 1);
+    assert_eq!(
+        writable
+            .execute_statement(
+                "UPDATE co_user SET time = ? WHERE user = ?",
+                &[json!(42), json!("Dinnerbone")]
+            )
+            .await?,
+        1
+    );
+    assert!(
+        writable
+            .execute_statement("DELETE FROM co_user; DROP TABLE co_user", &[])
+            .await
+            .is_err()
+    );
+    let bad_column = RowUpdate {
+        table: "co_user".to_string(),
+        rowid: 1,
+        changes: json!({ "user\" = 'x' --": "y" }).as_object().unwrap().clone(),
+    };
+    assert!(writable.update_row(&bad_column).await.is_err());
+
+    let data = explorer::get_table_data(&db, "co_user", 50, 0).await?;
+    assert_eq!(
+        data.rows,
+        vec![
+            vec![json!(1), json!("Notch_"), json!(null)],
+            vec![json!(3), json!("Dinnerbone"), json!(42)],
+        ]
+    );
+
+    // Later writes in the same session keep the first backup, which still has every row
+    let again = editor.open_for_write(dir.path(), ServerStatus::Crashed, &db).await?;
+    assert!(again.backup.is_none());
+    assert_eq!(
+        explorer::get_table_data(&backup_db(&db)?, "co_user", 50, 0)
+            .await?
+            .rows
+            .len(),
+        3
+    );
+    Ok(())
+}
+
+/// The explorer reads by extension, so look at the backup under a `.db` name.
+fn backup_db(db: &Path) -> Result<std::path::PathBuf> {
+    let copy = db.with_file_name("backup-copy.db");
+    std::fs::copy(backup_path(db), &copy)?;
+    Ok(copy)
+}
@@ -0,0 +1,153 @@
+use anyhow::Result;
+use mc_server_wrapper_core::database::Database;
+use mc_server_wrapper_core::database::explorer::{
+    check_read_only_query, execute_query, explain_query, history_key, stream_query,
+};
+use mc_server_wrapper_core::instance::InstanceManager;
+use serde_json::json;
+use sqlx::SqlitePool;
+use sqlx::sqlite::SqliteConnectOptions;
+use std::path::Path;
+use std::sync::Arc;
+use tempfile::tempdir;
+
+async fn create_db(path: &Path, rows: i64) -> Result<()> {
+    let options = SqliteConnectOptions::new().filename(path).create_if_missing(true);
+    let pool = SqlitePool::connect_with(options).await?;
+    sqlx::query("CREATE TABLE co_block (id INTEGER PRIMARY KEY, user TEXT, action INTEGER)")
+        .execute(&pool)
+        .await?;
+    sqlx::query(
+        "WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < ?)
+         INSERT INTO co_block (user, action) SELECT 'player' || (i % 3), i % 2 FROM n",
+    )
+    .bind(rows)
+    .execute(&pool)
+    .await?;
+    pool.close().await;
+    Ok(())
+}
+
+#[test]
+fn test_query_console_only_reads() {
+    assert_eq!(check_read_only_query("  SELECT 1;  ").unwrap(), "SELECT 1");
+    assert!(check_read_only_query("with x as (select 1) select * from x").is_ok());
+    assert!(check_read_only_query("EXPLAIN QUERY PLAN SELECT 1").is_ok());
+    assert!(check_read_only_query("DELETE FROM co_block").is_err());
+    assert!(check_read_only_query("SELECT 1; DROP TABLE co_block").is_err());
+    assert!(check_read_only_query("SELECT * FROM co_user WHERE user = 'a;b'").is_ok());
+    assert!(check_read_only_query("SELECT 'it''s;' AS \"x;y\" -- done; really\n").is_ok());
+    assert!(check_read_only_query("SELECT ';'; DELETE FROM co_block").is_err());
+    assert!(check_read_only_query("   ").is_err());
+}
+
+#[tokio::test]
+async fn test_execute_query_pages_results() -> Result<()> {
+    let dir = tempdir()?;
+    let db = dir.path().join("database.db");
+    create_db(&db, 25).await?;
+
+    let sql = "SELECT user, COUNT(*) AS blocks FROM co_block GROUP BY user ORDER BY user";
+    let result = execute_query(&db, sql, 2, 0).await?;
+    assert_eq!(result.columns, vec!["user", "blocks"]);
+    assert_eq!(
+        result.rows,
+        vec![vec![json!("player0"), json!(8)], vec![json!("player1"), json!(9)]]
+    );
+    assert!(result.has_more);
+    let last = execute_query(&db, sql, 2, 2).await?;
+    assert_eq!(last.rows, vec![vec![json!("player2"), json!(8)]]);
+    assert!(!last.has_more);
+
+    // Columns are known even when nothing matches
+    let empty = execute_query(&db, "SELECT id, user FROM co_block WHERE id < 0", 10, 0).await?;
+    assert_eq!(empty.columns, vec!["id", "user"]);
+    assert!(empty.rows.is_empty());
+
+    // The connection is read-only, so a CTE can't be used to sneak in a write
+    assert!(
+        execute_query(&db, "WITH x AS (SELECT 1) DELETE FROM co_block", 10, 0)
+            .await
+            .is_err()
+    );
+    assert_eq!(
+        execute_query(&db, "SELECT COUNT(*) FROM co_block", 1, 0).await?.rows,
+        vec![vec![json!(25)]]
+    );
+
+    let plan = explain_query(&db, "SELECT * FROM co_block WHERE id = 3").await?;
+    assert!(plan.columns.contains(&"detail".to_string()));
+    assert!(!plan.rows.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stream_query_delivers_batches() -> Result<()> {
+    let dir = tempdir()?;
+    let db = dir.path().join("database.db");
+    create_db(&db, 1200).await?;
+
+    let mut batches = Vec::new();
+    let delivered = stream_query(&db, "SELECT id FROM co_block", 500, |columns, rows| {
+        assert_eq!(columns, ["id".to_string()]);
+        batches.push(rows.len());
+        true
+    })
+    .await?;
+    assert_eq!(delivered, 1200);
+    assert_eq!(batches, vec![500, 500, 200]);
+
+    let mut seen = 0;
+    let delivered = stream_query(&db, "SELECT id FROM co_block", 100, |_, rows| {
+        seen += rows.len();
+        seen < 300
+    })
+    .await?;
+    assert_eq!(delivered, 300);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_history_per_database() -> Result<()> {
+    let dir = tempdir()?;
+    let db = Arc::new(Database::new(dir.path().join("test.db")).await?);
+    let manager = InstanceManager::new(dir.path(), db).await?;
+    let instance = manager.create_instance("Queries", "1.20.1").await?;
+
+    let key = history_key(
+        &instance.path,
+        &instance.path.join("plugins").join("CoreProtect").join("database.db"),
+    );
+    assert_eq!(key, "plugins/CoreProtect/database.db");
+
+    manager.record_query(instance.id, &key, "SELECT 1").await?;
+    manager.record_query(instance.id, &key, "SELECT 2").await?;
+    // Running the latest query again doesn't add an entry
+    manager.record_query(instance.id, &key, " SELECT 2 ").await?;
+    manager
+        .record_query(instance.id, "plugins/LuckPerms/luckperms-sqlite.db", "SELECT 3")
+        .await?;
+
+    let history = manager.get_query_history(instance.id, &key, 10).await?;
+    let queries: Vec<&str> = history.iter().map(|e| e.query.as_str()).collect();
+    assert_eq!(queries, vec!["SELECT 2", "SELECT 1"]);
+
+    manager.clear_query_history(instance.id, &key).await?;
+    assert!(manager.get_query_history(instance.id, &key, 10).await?.is_empty());
+    assert_eq!(
+        manager
+            .get_query_history(instance.id, "plugins/LuckPerms/luckperms-sqlite.db", 10)
+            .await?
+            .len(),
+        1
+    );
+
+    manager.delete_instance(instance.id).await?;
+    assert!(
+        manager
+            .get_query_history(instance.id, "plugins/LuckPerms/luckperms-sqlite.db", 10)
+            .await?
+            .is_empty()
+    );
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anyhow::Result;
+use mc_server_wrapper_core::app_config::GlobalConfigManager;
+use mc_server_wrapper_core::backup::BackupManager;
+use mc_server_wrapper_core::database::Database;
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::manager::ServerManager;
+use mc_server_wrapper_core::scheduler::{ScheduleType, ScheduledTask, SchedulerManager};
+use std::sync::Arc;
+use tempfile::tempdir;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_delete_instances_removes_associated_data() -> Result<()> {
+    let dir = tempdir()?;
+    let db = Arc::new(Database::new(dir.path().join("test.db")).await?);
+    let instance_manager = Arc::new(InstanceManager::new(dir.path().join("instances"), db).await?);
+    let config_manager = Arc::new(GlobalConfigManager::new(dir.path().join("config.json")));
+    let server_manager = Arc::new(ServerManager::new(Arc::clone(&instance_manager), config_manager));
+    let backup_manager = Arc::new(BackupManager::new(dir.path().join("backups")));
+    let scheduler = SchedulerManager::new(Arc::clone(&server_manager), Arc::clone(&backup_manager)).await?;
+
+    let doomed = instance_manager.create_instance_full("Doomed", "1.20.1", None, None).await?;
+    let kept = instance_manager.create_instance_full("Kept", "1.20.1", None, None).await?;
+    std::fs::write(doomed.path.join("world.dat"), vec![0u8; 2048])?;
+    instance_manager.record_command(doomed.id, "say hi").await?;
+    backup_manager.create_backup(doomed.id, &doomed.path, "before", |_, _| {}).await?;
+    scheduler.add_task(ScheduledTask::new(doomed.id, ScheduleType::Backup, "0 0 * * * *".to_string())).await?;
+    scheduler.add_task(ScheduledTask::new(kept.id, ScheduleType::Backup, "0 0 * * * *".to_string())).await?;
+
+    let missing = Uuid::new_v4();
+    let summary = server_manager.delete_instances(&[doomed.id, missing], &backup_manager, Some(&scheduler)).await;
+
+    assert_eq!(summary.deleted.len(), 1);
+    let cleanup = &summary.deleted[0];
+    assert_eq!(cleanup.name, "Doomed");
+    assert!(cleanup.instance_bytes >= 2048);
+    assert_eq!(cleanup.backups_removed, 1);
+    assert_eq!(cleanup.schedules_removed, 1);
+    assert_eq!(cleanup.db_rows_removed, 2);
+    assert!(summary.reclaimed_bytes >= cleanup.instance_bytes + cleanup.backup_bytes);
+    assert_eq!(summary.failed.len(), 1);
+    assert_eq!(summary.failed[0].id, missing);
+
+    assert!(!doomed.path.exists());
+    assert!(instance_manager.get_instance(doomed.id).await?.is_none());
+    assert!(instance_manager.get_command_history(doomed.id, 10).await?.is_empty());
+    assert!(backup_manager.list_backups(doomed.id).await?.is_empty());
+    assert!(scheduler.list_tasks(doomed.id).await.is_empty());
+    assert_eq!(scheduler.list_tasks(kept.id).await.len(), 1);
+    assert!(instance_manager.get_instance(kept.id).await?.is_some());
+    Ok(())
+}
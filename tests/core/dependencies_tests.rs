@@ -0,0 +1,82 @@
+use anyhow::Result;
+use mc_server_wrapper_core::app_config::GlobalConfigManager;
+use mc_server_wrapper_core::database::Database;
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::instance::dependencies::startup_waves;
+use mc_server_wrapper_core::manager::ServerManager;
+use std::sync::Arc;
+use tempfile::tempdir;
+use uuid::Uuid;
+
+async fn manager(dir: &std::path::Path) -> Result<InstanceManager> {
+    let db = Arc::new(Database::new(dir.join("test.db")).await?);
+    InstanceManager::new(dir.join("instances"), db).await
+}
+
+async fn depend(manager: &InstanceManager, id: Uuid, depends_on: Vec<Uuid>) -> Result<()> {
+    let mut settings = manager.get_instance(id).await?.unwrap().settings;
+    settings.depends_on = depends_on;
+    manager.update_settings(id, None, settings).await
+}
+
+#[tokio::test]
+async fn test_startup_waves_start_dependencies_first() -> Result<()> {
+    let dir = tempdir()?;
+    let manager = manager(dir.path()).await?;
+    let lobby = manager.create_instance_full("Lobby", "1.20.1", None, None).await?;
+    let survival = manager.create_instance_full("Survival", "1.20.1", None, None).await?;
+    let proxy = manager.create_instance_full("Proxy", "1.20.1", None, None).await?;
+    let unrelated = manager.create_instance_full("Creative", "1.20.1", None, None).await?;
+    depend(&manager, proxy.id, vec![lobby.id, survival.id]).await?;
+    depend(&manager, survival.id, vec![lobby.id]).await?;
+
+    let instances = manager.list_instances().await?;
+    let waves = startup_waves(&instances, &[proxy.id, unrelated.id])?;
+    assert_eq!(
+        waves,
+        vec![vec![unrelated.id, lobby.id], vec![survival.id], vec![proxy.id]]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dependency_cycles_are_rejected() -> Result<()> {
+    let dir = tempdir()?;
+    let manager = manager(dir.path()).await?;
+    let a = manager.create_instance_full("A", "1.20.1", None, None).await?;
+    let b = manager.create_instance_full("B", "1.20.1", None, None).await?;
+    let c = manager.create_instance_full("C", "1.20.1", None, None).await?;
+    depend(&manager, b.id, vec![a.id]).await?;
+    depend(&manager, c.id, vec![b.id]).await?;
+
+    let err = depend(&manager, a.id, vec![c.id]).await.unwrap_err();
+    assert!(err.to_string().contains("A -> C -> B -> A"), "{}", err);
+    assert!(depend(&manager, a.id, vec![a.id]).await.is_err());
+    assert!(depend(&manager, a.id, vec![Uuid::new_v4()]).await.is_err());
+    assert!(
+        manager
+            .get_instance(a.id)
+            .await?
+            .unwrap()
+            .settings
+            .depends_on
+            .is_empty()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_autostart_only_picks_flagged_instances() -> Result<()> {
+    let dir = tempdir()?;
+    let instance_manager = Arc::new(manager(dir.path()).await?);
+    let flagged = instance_manager.create_instance_full("Flagged", "1.20.1", None, None).await?;
+    instance_manager.create_instance_full("Manual", "1.20.1", None, None).await?;
+    let mut settings = flagged.settings.clone();
+    settings.autostart = true;
+    instance_manager.update_settings(flagged.id, None, settings).await?;
+
+    let config_manager = Arc::new(GlobalConfigManager::new(dir.path().join("config.json")));
+    let server_manager = ServerManager::new(Arc::clone(&instance_manager), config_manager);
+    assert_eq!(server_manager.autostart_instance_ids().await?, vec![flagged.id]);
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use mc_server_wrapper_core::database::Database;
+use mc_server_wrapper_core::diagnostics::{DiagnosticsOptions, DiagnosticsSources, export_diagnostics, redact};
+use mc_server_wrapper_core::instance::InstanceManager;
+use std::io::Read;
+use std::sync::Arc;
+use tempfile::tempdir;
+use anyhow::Result;
+
+fn read_entry(archive: &mut zip::ZipArchive<std::fs::File>, name: &str) -> Result<String> {
+    let mut content = String::new();
+    archive.by_name(name)?.read_to_string(&mut content)?;
+    Ok(content)
+}
+
+#[test]
+fn test_redact_keeps_loopback_and_versions() {
+    let text = "steve[/203.0.113.7:51234] logged in, bound to 127.0.0.1:25565 on 1.20.1 at 12:34:56 via 2001:db8::1";
+    assert_eq!(
+        redact(text),
+        "<player>[/<ip>:51234] logged in, bound to 127.0.0.1:25565 on 1.20.1 at 12:34:56 via <ip>"
+    );
+}
+
+#[test]
+fn test_redact_only_touches_names_in_player_lines() {
+    let text = "Server joined the game\nServer thread started\n<Server> meet at spawn\n";
+    assert_eq!(redact(text), "<player> joined the game\nServer thread started\n<<player>> meet at spawn\n");
+    assert_eq!(
+        redact("java -Ddb.password=hunter2 -XX:+UseG1GC -javaagent:agent.jar=token=abc -jar server.jar"),
+        "java -Ddb.password=<redacted> -XX:+UseG1GC -javaagent:agent.jar=<redacted> -jar server.jar"
+    );
+}
+
+#[tokio::test]
+async fn test_export_diagnostics_redacts_and_sanitizes() -> Result<()> {
+    let dir = tempdir()?;
+    let db = Arc::new(Database::new(dir.path().join("test.db")).await?);
+    let manager = InstanceManager::new(dir.path().join("server"), db).await?;
+    let instance = manager.create_instance("Survival", "1.20.1").await?;
+    let mut settings = instance.settings.clone();
+    settings.webhooks.url = "https://discord.com/api/webhooks/secret".to_string();
+    settings.environment.pre_start_hook = "./notify.sh --token hook-secret".to_string();
+    settings.jvm.extra_flags = vec!["-Dapi.key=flag-secret".to_string(), "-XX:+UseG1GC".to_string()];
+    manager.update_settings(instance.id, None, settings).await?;
+
+    tokio::fs::create_dir_all(instance.path.join("logs")).await?;
+    tokio::fs::write(
+        instance.path.join("logs/latest.log"),
+        "[Server thread/INFO]: Alex[/198.51.100.4:50000] logged in\n[Server thread/INFO]: Alex joined the game\n",
+    )
+    .await?;
+    tokio::fs::write(
+        instance.path.join("usercache.json"),
+        r#"[{"uuid":"069a79f4-44e9-4726-a5be-fca90e38aaf5","name":"Notch","expiresOn":"2030-01-01 00:00:00 +0000"}]"#,
+    )
+    .await?;
+    tokio::fs::create_dir_all(instance.path.join("crash-reports")).await?;
+    tokio::fs::write(
+        instance.path.join("crash-reports/crash-2024-01-01_00.00.00-server.txt"),
+        "Description: Ticking player\nPlayer name: Notch\n",
+    )
+    .await?;
+
+    let app_log_root = dir.path().join("resources");
+    tokio::fs::create_dir_all(app_log_root.join("logs")).await?;
+    tokio::fs::write(app_log_root.join("logs/app.log"), "Pinging 192.0.2.10 for Notch\n").await?;
+
+    let dest = dir.path().join("diagnostics.zip");
+    let sources = DiagnosticsSources {
+        app_log_root,
+        instances: manager.list_instances().await?,
+        java_versions: vec![],
+    };
+    let bundle = export_diagnostics(sources, &dest, DiagnosticsOptions { redact: true }).await?;
+    assert!(bundle.redacted);
+    assert!(bundle.size > 0);
+
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(&dest)?)?;
+    let prefix = format!("instances/{}", instance.id);
+    let latest = read_entry(&mut archive, &format!("{}/latest.log", prefix))?;
+    assert!(latest.contains("<player>[/<ip>:50000] logged in"));
+    assert!(!latest.contains("Alex"));
+
+    let crash = read_entry(&mut archive, &format!("{}/crash-reports/crash-2024-01-01_00.00.00-server.txt", prefix))?;
+    assert!(crash.contains("Player name: <player>"));
+
+    let metadata = read_entry(&mut archive, &format!("{}/instance.json", prefix))?;
+    assert!(!metadata.contains("secret"));
+    assert!(metadata.contains("-Dapi.key=<redacted>"));
+    assert!(metadata.contains("-XX:+UseG1GC"));
+    assert!(metadata.contains("Survival"));
+
+    let app_log = read_entry(&mut archive, "wrapper-logs/app.log")?;
+    assert_eq!(app_log, "Pinging <ip> for Notch\n");
+
+    assert!(read_entry(&mut archive, "summary.json")?.contains("\"redacted\": true"));
+    assert!(archive.by_name("java.json").is_ok());
+    Ok(())
+}
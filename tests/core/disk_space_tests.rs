@@ -0,0 +1,35 @@
+use mc_server_wrapper_core::errors::AppError;
+use mc_server_wrapper_core::utils::{InsufficientSpace, check_free_space, ensure_free_space, format_size};
+use std::path::Path;
+
+const GB: u64 = 1024 * 1024 * 1024;
+
+#[test]
+fn test_check_free_space_reports_need_and_have() {
+    let err = check_free_space(Path::new("/srv/mc"), 5 * GB, Some(2 * GB)).unwrap_err();
+    let space = err.downcast_ref::<InsufficientSpace>().expect("structured error");
+    assert_eq!(space.available, 2 * GB);
+    assert!(space.required > 5 * GB);
+    assert!(err.to_string().contains("free, have 2.0 GB"), "{}", err);
+}
+
+#[test]
+fn test_check_free_space_passes_when_it_fits_or_is_unknown() {
+    assert!(check_free_space(Path::new("/srv/mc"), GB, Some(10 * GB)).is_ok());
+    assert!(check_free_space(Path::new("/srv/mc"), 100 * GB, None).is_ok());
+}
+
+#[test]
+fn test_ensure_free_space_on_missing_subdirectory() {
+    let dir = tempfile::tempdir().unwrap();
+    let target = dir.path().join("not/created/yet.jar");
+    assert!(ensure_free_space(&target, 1024).is_ok());
+    assert!(ensure_free_space(&target, u64::MAX / 2).is_err());
+}
+
+#[test]
+fn test_insufficient_space_maps_to_app_error() {
+    let err = check_free_space(Path::new("/srv/mc"), 5 * GB, Some(GB)).unwrap_err();
+    assert!(matches!(AppError::from(err), AppError::InsufficientSpace(_)));
+    assert_eq!(format_size(512 * 1024 * 1024), "512 MB");
+}
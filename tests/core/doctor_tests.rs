@@ -0,0 +1,102 @@
+use anyhow::Result;
+use mc_server_wrapper_core::app_config::GlobalConfigManager;
+use mc_server_wrapper_core::database::Database;
+use mc_server_wrapper_core::doctor::{Severity, SuggestedFix, checks, content};
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::manager::ServerManager;
+use std::io::Write;
+use std::net::TcpListener;
+use std::path::Path;
+use std::sync::Arc;
+use tempfile::tempdir;
+use zip::write::SimpleFileOptions;
+
+fn write_mod(path: &Path, entries: &[(&str, &str)]) {
+    let mut zip = zip::ZipWriter::new(std::fs::File::create(path).unwrap());
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+    for (name, content) in entries {
+        zip.start_file(*name, options).unwrap();
+        zip.write_all(content.as_bytes()).unwrap();
+    }
+    zip.finish().unwrap();
+}
+
+#[test]
+fn test_mod_jar_checks() {
+    let dir = tempdir().unwrap();
+    let mods = dir.path().join("mods");
+    std::fs::create_dir_all(&mods).unwrap();
+    let fabric_json = r#"{"schemaVersion": 1, "id": "sodium", "version": "0.5.0", "name": "Sodium"}"#;
+    write_mod(&mods.join("sodium.jar"), &[("fabric.mod.json", fabric_json)]);
+    write_mod(&mods.join("broken.jar"), &[("data.txt", "the quick brown fox")]);
+
+    // Flip a byte of the stored entry so only the CRC check can notice
+    let mut bytes = std::fs::read(mods.join("broken.jar")).unwrap();
+    let at = bytes.windows(5).position(|w| w == b"quick").unwrap();
+    bytes[at] = b'Q';
+    std::fs::write(mods.join("broken.jar"), bytes).unwrap();
+
+    let findings = content::check_mod_jars(dir.path(), Some("forge"));
+    assert_eq!(findings.len(), 2);
+    let corrupt = findings.iter().find(|f| f.check == "mod_jars").unwrap();
+    assert_eq!(corrupt.fix, Some(SuggestedFix::DeleteFile { path: "mods/broken.jar".to_string() }));
+    let wrong_loader = findings.iter().find(|f| f.check == "mod_loader").unwrap();
+    assert_eq!(wrong_loader.fix, Some(SuggestedFix::DisableMod { filename: "sodium.jar".to_string() }));
+
+    // Quilt loads Fabric mods
+    assert_eq!(content::check_mod_jars(dir.path(), Some("quilt")).len(), 1);
+}
+
+#[test]
+fn test_eula_leftovers_and_java_checks() {
+    let dir = tempdir().unwrap();
+    assert_eq!(checks::check_eula(dir.path(), None)[0].fix, Some(SuggestedFix::AcceptEula));
+    assert!(checks::check_eula(dir.path(), Some("velocity")).is_empty());
+    std::fs::write(dir.path().join("eula.txt"), "#comment\neula=true\n").unwrap();
+    assert!(checks::check_eula(dir.path(), None).is_empty());
+
+    std::fs::create_dir_all(dir.path().join("config")).unwrap();
+    std::fs::write(dir.path().join("config/sodium.json.bak"), "{}").unwrap();
+    std::fs::write(dir.path().join(".server.properties.1234.tmp"), "").unwrap();
+    let leftovers = content::check_leftover_files(dir.path());
+    assert_eq!(leftovers.len(), 2);
+    assert!(leftovers.iter().all(|f| f.severity == Severity::Info));
+
+    let java = Path::new("java");
+    assert_eq!(checks::check_java(17, Some(8), java, None)[0].fix, Some(SuggestedFix::InstallJava { major_version: 17 }));
+    assert!(checks::check_java(17, Some(21), java, None).is_empty());
+    assert_eq!(checks::check_java(8, Some(17), java, Some("forge"))[0].severity, Severity::Warning);
+    assert_eq!(checks::check_java(8, None, java, None)[0].severity, Severity::Error);
+}
+
+#[test]
+fn test_port_check_reports_conflicts() {
+    let listener = TcpListener::bind(("0.0.0.0", 0)).unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    let findings = checks::check_port(port, false, &["Creative".to_string()], &[port]);
+    assert_eq!(findings.len(), 2);
+    assert!(findings.iter().any(|f| f.severity == Severity::Error));
+    assert!(matches!(findings[0].fix, Some(SuggestedFix::ChangePort { port: p }) if p != port));
+
+    // A running server or a wake listener holds the port on the wrapper's behalf
+    assert!(checks::check_port(port, true, &[], &[port]).is_empty());
+}
+
+#[tokio::test]
+async fn test_diagnose_instance_reports_missing_jar() -> Result<()> {
+    let dir = tempdir()?;
+    let db = Arc::new(Database::new(dir.path().join("test.db")).await?);
+    let instance_manager = Arc::new(InstanceManager::new(dir.path().join("instances"), db).await?);
+    let config_manager = Arc::new(GlobalConfigManager::new(dir.path().join("config.json")));
+    let server_manager = ServerManager::new(Arc::clone(&instance_manager), config_manager);
+    let instance = instance_manager.create_instance_full("Broken", "1.20.1", None, None).await?;
+
+    let report = server_manager.diagnose_instance(instance.id).await?;
+    assert!(!report.is_healthy());
+    let missing = report.findings.iter().find(|f| f.check == "launch_target").unwrap();
+    assert_eq!(missing.severity, Severity::Error);
+    assert_eq!(missing.fix, Some(SuggestedFix::Reinstall));
+    assert!(report.findings.windows(2).all(|w| w[0].severity >= w[1].severity));
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use mc_server_wrapper_core::app_config::{AppSettings, GlobalConfigManager};
+use mc_server_wrapper_core::utils::{DownloadLimits, DownloadSettings};
+use tempfile::tempdir;
+
+#[test]
+fn test_mirror_preference_and_settings_defaults() {
+    // Settings files written before download limits existed still load
+    let mut json = serde_json::to_value(AppSettings::default()).unwrap();
+    json.as_object_mut().unwrap().remove("downloads");
+    let settings: AppSettings = serde_json::from_value(json).unwrap();
+    assert_eq!(settings.downloads, DownloadSettings::default());
+
+    let limits = DownloadLimits::default();
+    limits.configure(&DownloadSettings {
+        preferred_mirrors: vec!["mirror.example".to_string(), "cdn.modrinth.com".to_string()],
+        ..DownloadSettings::default()
+    });
+    let mut urls = vec![
+        "https://github.com/a.jar",
+        "https://cdn.modrinth.com/b.jar",
+        "https://eu.mirror.example/c.jar",
+        "not a url",
+    ];
+    limits.sort_by_mirror_preference(&mut urls);
+    assert_eq!(urls, ["https://eu.mirror.example/c.jar", "https://cdn.modrinth.com/b.jar", "https://github.com/a.jar", "not a url"]);
+}
+
+#[tokio::test]
+async fn test_saved_settings_reach_the_shared_limits() {
+    let dir = tempdir().unwrap();
+    let config_manager = GlobalConfigManager::new(dir.path().join("settings.json"));
+    let limits = config_manager.download_limits();
+
+    let mut settings = config_manager.load().await.unwrap();
+    settings.downloads.max_concurrent_downloads = 1;
+    config_manager.save(&settings).await.unwrap();
+    assert_eq!(limits.settings().max_concurrent_downloads, 1);
+
+    let slot = limits.acquire_slot().await.unwrap();
+    let waiting = tokio::time::timeout(std::time::Duration::from_millis(100), limits.acquire_slot()).await;
+    assert!(waiting.is_err(), "a second download should wait for the only slot");
+    drop(slot);
+    let _slot = limits.acquire_slot().await.unwrap();
+}
@@ -0,0 +1,147 @@
+use mc_server_wrapper_core::instance::{EnvVar, LaunchEnvironment};
+
+fn var(key: &str, value: &str) -> EnvVar {
+    EnvVar {
+        key: key.to_string(),
+        value: value.to_string(),
+    }
+}
+
+#[test]
+fn test_launch_environment_validation() {
+    let env = LaunchEnvironment {
+        variables: vec![var("MALLOC_ARENA_MAX", "2"), var(" ", "")],
+        working_dir: "server".to_string(),
+        pre_start_hook: "./sync.sh".to_string(),
+        ..Default::default()
+    };
+    assert!(env.validate().is_err(), "blank names are rejected");
+
+    let env = LaunchEnvironment {
+        variables: vec![var("MALLOC_ARENA_MAX", "2")],
+        ..env
+    };
+    env.validate().unwrap();
+    assert_eq!(env.variables(), vec![("MALLOC_ARENA_MAX".to_string(), "2".to_string())]);
+    assert_eq!(env.pre_start_hook().as_deref(), Some("./sync.sh"));
+    assert_eq!(env.post_stop_hook(), None);
+
+    for bad in [
+        LaunchEnvironment {
+            variables: vec![var("A=B", "1")],
+            ..Default::default()
+        },
+        LaunchEnvironment {
+            variables: vec![var("A", "1"), var("A", "2")],
+            ..Default::default()
+        },
+        LaunchEnvironment {
+            working_dir: "../other".to_string(),
+            ..Default::default()
+        },
+        LaunchEnvironment {
+            working_dir: "/srv/mc".to_string(),
+            ..Default::default()
+        },
+    ] {
+        assert!(bad.validate().is_err(), "{:?} should be rejected", bad);
+    }
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_hooks_and_env_reach_the_server() {
+    use mc_server_wrapper_core::config::ServerConfig;
+    use mc_server_wrapper_core::server::{ServerHandle, ServerStatus};
+    use std::time::Duration;
+
+    let dir = tempfile::tempdir().unwrap();
+    let script = dir.path().join("run.sh");
+    std::fs::write(&script, "#!/bin/sh\necho \"greeting=$GREETING\"\n").unwrap();
+
+    let handle = ServerHandle::new(ServerConfig {
+        run_script: Some(script.to_string_lossy().to_string()),
+        working_dir: dir.path().to_path_buf(),
+        env: vec![("GREETING".to_string(), "hello".to_string())],
+        pre_start_hook: Some("echo \"pre $GREETING\"".to_string()),
+        post_stop_hook: Some("echo done > stopped.txt".to_string()),
+        hooks_enabled: true,
+        ..Default::default()
+    });
+    let mut logs = handle.subscribe_logs();
+    handle.start().await.unwrap();
+
+    let mut lines = Vec::new();
+    let stopped = dir.path().join("stopped.txt");
+    for _ in 0..100 {
+        while let Ok(line) = logs.try_recv() {
+            lines.push(line);
+        }
+        if stopped.exists() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    assert!(lines.iter().any(|l| l == "[pre-start] pre hello"), "{:?}", lines);
+    assert!(lines.iter().any(|l| l.contains("greeting=hello")), "{:?}", lines);
+    assert!(stopped.exists(), "the post-stop hook runs after the server exits");
+    assert_eq!(handle.get_status().await, ServerStatus::Stopped);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_failing_pre_start_hook_blocks_launch() {
+    use mc_server_wrapper_core::config::ServerConfig;
+    use mc_server_wrapper_core::server::{ServerHandle, ServerStatus};
+    use std::time::Duration;
+
+    let dir = tempfile::tempdir().unwrap();
+    let script = dir.path().join("run.sh");
+    std::fs::write(&script, "#!/bin/sh\ntouch launched.txt\n").unwrap();
+
+    let handle = ServerHandle::new(ServerConfig {
+        run_script: Some(script.to_string_lossy().to_string()),
+        working_dir: dir.path().to_path_buf(),
+        pre_start_hook: Some("exit 3".to_string()),
+        hooks_enabled: true,
+        ..Default::default()
+    });
+    handle.start().await.unwrap();
+    for _ in 0..100 {
+        if handle.get_status().await == ServerStatus::Crashed {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    assert_eq!(handle.get_status().await, ServerStatus::Crashed);
+    assert!(!dir.path().join("launched.txt").exists());
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_hooks_skipped_while_terminal_disabled() {
+    use mc_server_wrapper_core::config::ServerConfig;
+    use mc_server_wrapper_core::server::{ServerHandle, ServerStatus};
+    use std::time::Duration;
+
+    let dir = tempfile::tempdir().unwrap();
+    let script = dir.path().join("run.sh");
+    std::fs::write(&script, "#!/bin/sh\ntouch launched.txt\n").unwrap();
+
+    let handle = ServerHandle::new(ServerConfig {
+        run_script: Some(script.to_string_lossy().to_string()),
+        working_dir: dir.path().to_path_buf(),
+        pre_start_hook: Some("touch hooked.txt".to_string()),
+        ..Default::default()
+    });
+    handle.start().await.unwrap();
+    for _ in 0..100 {
+        if dir.path().join("launched.txt").exists() && handle.get_status().await == ServerStatus::Stopped {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    assert!(dir.path().join("launched.txt").exists());
+    assert!(!dir.path().join("hooked.txt").exists());
+}
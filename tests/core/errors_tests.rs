@@ -0,0 +1,49 @@
+use anyhow::{Context, Result};
+use mc_server_wrapper_core::database::Database;
+use mc_server_wrapper_core::errors::AppError;
+use mc_server_wrapper_core::instance::InstanceManager;
+use serde_json::json;
+use std::sync::Arc;
+use tempfile::tempdir;
+
+#[test]
+fn test_app_error_serializes_with_code() {
+    let err = AppError::Conflict("Stop the server before deleting it".to_string());
+    assert_eq!(
+        serde_json::to_value(&err).unwrap(),
+        json!({ "type": "Conflict", "code": "conflict", "message": "Stop the server before deleting it" })
+    );
+    assert_eq!(
+        serde_json::to_value(AppError::NotFound("world".to_string())).unwrap()["code"],
+        "not_found"
+    );
+}
+
+#[test]
+fn test_app_error_kind_survives_anyhow() {
+    // Raised in core, wrapped in context on the way up, still a conflict at the command boundary
+    let err = Err::<(), _>(AppError::Conflict("Stop the server before repairing it".to_string()))
+        .context("Failed to repair the server")
+        .unwrap_err();
+    assert!(matches!(AppError::from(err), AppError::Conflict(m) if m == "Stop the server before repairing it"));
+
+    let missing = std::fs::read("/definitely/not/here")
+        .context("Failed to read the file")
+        .unwrap_err();
+    // The cause is kept after the context
+    assert!(matches!(AppError::from(missing), AppError::NotFound(m) if m.starts_with("Failed to read the file: ")));
+
+    let other = anyhow::anyhow!("something broke");
+    assert_eq!(AppError::from(other).code(), "internal");
+}
+
+#[tokio::test]
+async fn test_unknown_instance_is_not_found() -> Result<()> {
+    let dir = tempdir()?;
+    let db = Arc::new(Database::new(dir.path().join("test.db")).await?);
+    let manager = InstanceManager::new(dir.path(), db).await?;
+
+    let err = manager.resolve_instance_id("nope").await.unwrap_err();
+    assert_eq!(AppError::from(err).code(), "not_found");
+    Ok(())
+}
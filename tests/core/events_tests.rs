@@ -0,0 +1,57 @@
+use mc_server_wrapper_core::events::{EventBus, EventChannel};
+use serde_json::json;
+use uuid::Uuid;
+
+#[test]
+fn test_recent_events_replay_after_seq() {
+    let bus = EventBus::new();
+    let server = Uuid::new_v4();
+    let other = Uuid::new_v4();
+
+    let first = bus.publish(Some(server), EventChannel::Startup, &json!({ "phase": "Preparing" }));
+    bus.publish(Some(other), EventChannel::Startup, &json!({ "phase": "Preparing" }));
+    bus.publish(None, EventChannel::Task, &json!({ "kind": "java" }));
+    let done = bus.publish(Some(server), EventChannel::Startup, &json!({ "phase": "Done" }));
+    let task = bus.publish(
+        Some(server),
+        EventChannel::Task,
+        &json!({ "kind": "install", "current": 5 }),
+    );
+    assert_eq!(bus.last_seq(), task);
+
+    // Every channel of the instance, in the order it was published
+    let events = bus.recent(Some(server), 0);
+    let seqs: Vec<u64> = events.iter().map(|e| e.seq).collect();
+    assert_eq!(seqs, vec![first, done, task]);
+    assert_eq!(events[1].payload, json!({ "phase": "Done" }));
+    assert_eq!(events[2].channel, EventChannel::Task);
+
+    // A client that already saw `done` only gets what came after
+    let newer = bus.recent(Some(server), done);
+    assert_eq!(newer.len(), 1);
+    assert_eq!(newer[0].payload["current"], 5);
+
+    assert_eq!(bus.recent(None, 0).len(), 1);
+
+    bus.forget(server);
+    assert!(bus.recent(Some(server), 0).is_empty());
+    assert_eq!(bus.recent(Some(other), 0).len(), 1);
+}
+
+#[test]
+fn test_task_buffer_keeps_the_newest_events() {
+    let bus = EventBus::new();
+    let server = Uuid::new_v4();
+    bus.publish(Some(server), EventChannel::Startup, &json!({ "phase": "Done" }));
+    for i in 0..300 {
+        bus.publish(Some(server), EventChannel::Task, &json!({ "current": i }));
+    }
+
+    let events = bus.recent(Some(server), 0);
+    let tasks: Vec<_> = events.iter().filter(|e| e.channel == EventChannel::Task).collect();
+    assert_eq!(tasks.len(), 200);
+    assert_eq!(tasks[0].payload["current"], 100);
+    assert_eq!(tasks[199].payload["current"], 299);
+    // A full task buffer doesn't push out other channels
+    assert_eq!(events[0].channel, EventChannel::Startup);
+}
@@ -0,0 +1,121 @@
+use base64::Engine;
+use mc_server_wrapper_core::file_manager::{
+    copy, create_dir, delete, list_dir, read_base64, read_chunk, read_text, read_text_chunk, rename, resolve_for_write,
+    write_base64,
+};
+use tempfile::tempdir;
+
+#[tokio::test]
+async fn test_file_manager_operations() {
+    let dir = tempdir().unwrap();
+    let root = dir.path();
+    std::fs::create_dir_all(root.join("config/sub")).unwrap();
+    std::fs::write(root.join("config/a.yml"), "a: 1").unwrap();
+    std::fs::write(root.join("config/sub/b.yml"), "b: 2").unwrap();
+    std::fs::write(root.join("server.properties"), "motd=hi").unwrap();
+
+    let entries = list_dir(root, "").await.unwrap();
+    let names: Vec<_> = entries.iter().map(|e| e.name.as_str()).collect();
+    assert_eq!(names, vec!["config", "server.properties"]);
+    let nested = list_dir(root, "config").await.unwrap();
+    assert_eq!(nested[0].path, "config/sub");
+    assert_eq!(nested[1].size, 4);
+
+    let made = create_dir(root, "config/new").await.unwrap();
+    assert!(made.is_dir);
+    assert!(create_dir(root, "config/new").await.is_err());
+    assert!(create_dir(root, "missing/deeper").await.is_err());
+
+    let copied = copy(root, "config", "config-copy").await.unwrap();
+    assert!(copied.is_dir);
+    assert_eq!(
+        std::fs::read_to_string(root.join("config-copy/sub/b.yml")).unwrap(),
+        "b: 2"
+    );
+    assert!(copy(root, "config", "config/sub/inner").await.is_err());
+
+    let moved = rename(root, "config-copy/a.yml", "moved.yml").await.unwrap();
+    assert_eq!(moved.path, "moved.yml");
+    assert!(rename(root, "moved.yml", "server.properties").await.is_err());
+    assert!(rename(root, "config", "config/sub/config").await.is_err());
+
+    assert_eq!(read_base64(root, "moved.yml").await.unwrap(), "YTogMQ==");
+    assert!(read_base64(root, "config").await.is_err());
+
+    delete(root, "config-copy").await.unwrap();
+    assert!(!root.join("config-copy").exists());
+    assert!(delete(root, "").await.is_err());
+}
+
+#[tokio::test]
+async fn test_file_manager_stays_inside_instance() {
+    let outside = tempdir().unwrap();
+    std::fs::write(outside.path().join("secret.txt"), "secret").unwrap();
+    let dir = tempdir().unwrap();
+    let root = dir.path();
+
+    assert!(list_dir(root, "..").await.is_err());
+    assert!(read_base64(root, "../secret.txt").await.is_err());
+    assert!(create_dir(root, "/tmp/escape").await.is_err());
+    assert!(rename(root, "a", "../a").await.is_err());
+
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink(outside.path(), root.join("link")).unwrap();
+        assert!(list_dir(root, "link").await.is_err());
+        assert!(read_base64(root, "link/secret.txt").await.is_err());
+        assert!(create_dir(root, "link/new").await.is_err());
+        assert!(resolve_for_write(root, "link/missing/file.txt").is_err());
+        assert!(
+            resolve_for_write(root, "missing/file.txt")
+                .unwrap()
+                .ends_with("missing/file.txt")
+        );
+
+        // Deleting the link removes the link, not the folder it points to
+        delete(root, "link").await.unwrap();
+        assert!(outside.path().join("secret.txt").exists());
+    }
+}
+
+#[tokio::test]
+async fn test_file_manager_chunked_io() {
+    let dir = tempdir().unwrap();
+    let root = dir.path();
+
+    // "é" is two bytes, so a 3-byte chunk would split the second one
+    std::fs::write(root.join("motd.txt"), "aéé").unwrap();
+    let first = read_text_chunk(root, "motd.txt", 0, 4).await.unwrap();
+    assert_eq!(first.content, "aéé");
+    let first = read_text_chunk(root, "motd.txt", 0, 2).await.unwrap();
+    assert_eq!(first.content, "aé");
+    assert!(!first.eof && !first.binary);
+    let second = read_text_chunk(root, "motd.txt", first.next_offset, 2).await.unwrap();
+    assert_eq!(second.content, "é");
+    assert!(second.eof);
+
+    let icon = vec![0x89, b'P', b'N', b'G', 0, 0xff, 0xfe];
+    let encoded = base64::engine::general_purpose::STANDARD.encode(&icon);
+    write_base64(root, "assets/icon.png", &encoded, false).await.unwrap();
+    assert_eq!(std::fs::read(root.join("assets/icon.png")).unwrap(), icon);
+    assert!(write_base64(root, "assets", &encoded, false).await.is_err());
+    assert!(write_base64(root, "bad.bin", "not base64!", false).await.is_err());
+
+    let mut rebuilt = Vec::new();
+    let mut offset = 0;
+    loop {
+        let chunk = read_chunk(root, "assets/icon.png", offset, 3).await.unwrap();
+        rebuilt.extend(base64::engine::general_purpose::STANDARD.decode(&chunk.data).unwrap());
+        assert_eq!(chunk.total_size, icon.len() as u64);
+        offset = chunk.next_offset;
+        if chunk.eof {
+            break;
+        }
+    }
+    assert_eq!(rebuilt, icon);
+    assert!(read_text_chunk(root, "assets/icon.png", 0, 100).await.unwrap().binary);
+
+    // Whole-file reads no longer fail on binary content
+    assert!(read_text(root, "assets/icon.png").await.unwrap().contains('\u{fffd}'));
+    assert_eq!(read_text(root, "missing.txt").await.unwrap(), "");
+}
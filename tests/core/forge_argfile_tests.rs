@@ -0,0 +1,77 @@
+use mc_server_wrapper_core::config::ServerConfig;
+use mc_server_wrapper_core::mod_loaders::argfile::server_argfile_name;
+use mc_server_wrapper_core::mod_loaders::find_server_argfile;
+use mc_server_wrapper_core::server::launch::select_strategy;
+use std::path::{Path, PathBuf};
+use tempfile::tempdir;
+
+fn write_argfile(instance: &Path, folder: &str) -> PathBuf {
+    let rel = Path::new(folder).join(server_argfile_name());
+    std::fs::create_dir_all(instance.join(folder)).unwrap();
+    std::fs::write(
+        instance.join(&rel),
+        "-p libraries/foo.jar\nnet.minecraftforge.bootstrap.ForgeBootstrap\n",
+    )
+    .unwrap();
+    rel
+}
+
+#[test]
+fn test_find_argfile_prefers_loader_version() {
+    let dir = tempdir().unwrap();
+    let current = write_argfile(dir.path(), "libraries/net/minecraftforge/forge/1.20.1-47.2.0");
+    write_argfile(dir.path(), "libraries/net/minecraftforge/forge/1.20.1-47.3.0");
+
+    let found = find_server_argfile(dir.path(), Some("Forge"), "1.20.1", Some("47.2.0"));
+    assert_eq!(found, Some(current));
+}
+
+#[test]
+fn test_find_argfile_falls_back_to_any_install() {
+    let dir = tempdir().unwrap();
+    let neoforge = write_argfile(dir.path(), "libraries/net/neoforged/neoforge/21.1.77");
+
+    assert_eq!(
+        find_server_argfile(dir.path(), Some("neoforge"), "1.21.1", Some("21.1.50")),
+        Some(neoforge.clone())
+    );
+    // Imported instances don't know their loader
+    assert_eq!(find_server_argfile(dir.path(), None, "Imported", None), Some(neoforge));
+    assert_eq!(find_server_argfile(dir.path(), Some("fabric"), "1.21.1", None), None);
+    assert_eq!(find_server_argfile(dir.path(), Some("forge"), "1.21.1", None), None);
+}
+
+#[test]
+fn test_argfile_launch_command() {
+    let dir = tempdir().unwrap();
+    std::fs::write(dir.path().join("user_jvm_args.txt"), "# -Xmx4G\n").unwrap();
+    let config = ServerConfig {
+        jar_path: None,
+        argfile: Some(PathBuf::from("libraries/net/neoforged/neoforge/21.1.77/unix_args.txt")),
+        args: vec!["nogui".to_string()],
+        jvm_args: vec!["-XX:+UseG1GC".to_string()],
+        max_memory: "4G".to_string(),
+        working_dir: dir.path().to_path_buf(),
+        ..Default::default()
+    };
+
+    let strategy = select_strategy(&config);
+    assert_eq!(strategy.name(), "argfile");
+    let command = strategy.command(&config).unwrap();
+    let args: Vec<String> = command
+        .as_std()
+        .get_args()
+        .map(|a| a.to_string_lossy().to_string())
+        .collect();
+
+    assert_eq!(args.first().map(String::as_str), Some("@user_jvm_args.txt"));
+    assert!(args.contains(&"-Xmx4G".to_string()));
+    assert!(!args.contains(&"-jar".to_string()));
+    let g1 = args.iter().position(|a| a == "-XX:+UseG1GC").unwrap();
+    let argfile = args
+        .iter()
+        .position(|a| a == "@libraries/net/neoforged/neoforge/21.1.77/unix_args.txt")
+        .unwrap();
+    assert!(g1 < argfile);
+    assert_eq!(args.last().map(String::as_str), Some("nogui"));
+}
@@ -0,0 +1,87 @@
+use anyhow::Result;
+use mc_server_wrapper_core::database::Database;
+use mc_server_wrapper_core::instance::{InstanceManager, InstanceMetadata};
+use mc_server_wrapper_core::plugins::geyser::{
+    bedrock_port_users, configure_yaml, geyser_settings, read_bedrock_port, sync_floodgate_key,
+};
+use mc_server_wrapper_core::plugins::{CrossplayComponent, DEFAULT_BEDROCK_PORT, GeyserPlatform, plan_crossplay};
+use std::sync::Arc;
+use tempfile::{TempDir, tempdir};
+
+async fn instances(specs: &[(&str, &str)]) -> Result<(TempDir, Vec<InstanceMetadata>)> {
+    let dir = tempdir()?;
+    let db = Arc::new(Database::new(dir.path().join("test.db")).await?);
+    let manager = InstanceManager::new(dir.path(), db).await?;
+    let mut created = Vec::new();
+    for (name, loader) in specs {
+        created.push(manager.create_instance_full(name, "1.21.1", Some(loader.to_string()), None).await?);
+    }
+    Ok((dir, created))
+}
+
+#[tokio::test]
+async fn test_standalone_server_hosts_geyser() -> Result<()> {
+    let (_dir, list) = instances(&[("Survival", "paper"), ("Modded", "fabric")]).await?;
+    let plan = plan_crossplay(&list[0], None, DEFAULT_BEDROCK_PORT)?;
+    assert_eq!(plan.host.instance_id, list[0].id);
+    assert_eq!(plan.host.platform, GeyserPlatform::Spigot);
+    assert_eq!(plan.host.components, vec![CrossplayComponent::Geyser, CrossplayComponent::Floodgate]);
+    assert!(plan.backends.is_empty());
+
+    let modded = plan_crossplay(&list[1], None, 19133)?;
+    assert!(modded.host.platform.is_mod());
+    assert_eq!(modded.host.platform.geyser_config(), "config/Geyser-Fabric/config.yml");
+    assert_eq!(modded.bedrock_port, 19133);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_proxy_network_puts_floodgate_on_backends() -> Result<()> {
+    let (_dir, list) = instances(&[("Proxy", "velocity"), ("Lobby", "paper"), ("Bedrock", "bedrock")]).await?;
+    let (proxy, backends) = (&list[0], &list[1..]);
+
+    let plan = plan_crossplay(&list[1], Some((proxy, backends)), DEFAULT_BEDROCK_PORT)?;
+    assert_eq!(plan.host.instance_id, proxy.id);
+    assert_eq!(plan.host.platform, GeyserPlatform::Velocity);
+    assert_eq!(plan.backends.len(), 1);
+    assert_eq!(plan.backends[0].instance_id, list[1].id);
+    assert_eq!(plan.backends[0].components, vec![CrossplayComponent::Floodgate]);
+    // One note for planning from a backend, one for the skipped Bedrock server
+    assert_eq!(plan.notes.len(), 2);
+
+    assert!(plan_crossplay(&list[2], None, DEFAULT_BEDROCK_PORT).is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_geyser_config_and_floodgate_key() -> Result<()> {
+    let (_dir, list) = instances(&[("Proxy", "velocity"), ("Lobby", "paper"), ("Other", "paper")]).await?;
+    let (proxy, lobby) = (&list[0], &list[1]);
+    let config = GeyserPlatform::Velocity.geyser_config();
+
+    // Configs that Geyser hasn't generated yet are left alone
+    assert!(!configure_yaml(&proxy.path, &config, &geyser_settings(19140)).await?);
+    assert!(!proxy.path.join(&config).exists());
+
+    std::fs::create_dir_all(proxy.path.join("plugins/Geyser-Velocity"))?;
+    std::fs::write(
+        proxy.path.join(&config),
+        "bedrock:\n  address: 0.0.0.0\n  port: 19132\nremote:\n  auth-type: online\nconfig-version: 4\n",
+    )?;
+    assert!(configure_yaml(&proxy.path, &config, &geyser_settings(19140)).await?);
+    assert_eq!(read_bedrock_port(&proxy.path, GeyserPlatform::Velocity), Some(19140));
+    let written = std::fs::read_to_string(proxy.path.join(&config))?;
+    assert!(written.contains("auth-type: floodgate"));
+    assert!(written.contains("config-version: 4"));
+
+    assert_eq!(bedrock_port_users(19140, list[2].id, &list), vec!["Proxy".to_string()]);
+    assert!(bedrock_port_users(19140, proxy.id, &list).is_empty());
+
+    let (velocity, spigot) = (GeyserPlatform::Velocity, GeyserPlatform::Spigot);
+    assert!(!sync_floodgate_key(&proxy.path, velocity, &lobby.path, spigot).await?);
+    std::fs::create_dir_all(proxy.path.join("plugins/floodgate"))?;
+    std::fs::write(proxy.path.join("plugins/floodgate/key.pem"), [7u8; 16])?;
+    assert!(sync_floodgate_key(&proxy.path, velocity, &lobby.path, spigot).await?);
+    assert_eq!(std::fs::read(lobby.path.join("plugins/floodgate/key.pem"))?, vec![7u8; 16]);
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use mc_server_wrapper_core::manager::{
+    DEFAULT_COUNTDOWN_MESSAGE, countdown_marks, format_time_left, render_countdown_message,
+    validate_countdown_message,
+};
+use mc_server_wrapper_core::scheduler::{RestartWarning, ScheduleType, ScheduledTask};
+use uuid::Uuid;
+
+#[test]
+fn test_countdown_marks() {
+    assert!(countdown_marks(0).is_empty());
+    assert_eq!(countdown_marks(60), vec![60, 30, 10, 5, 4, 3, 2, 1]);
+    assert_eq!(countdown_marks(90), vec![90, 60, 30, 10, 5, 4, 3, 2, 1]);
+    assert_eq!(countdown_marks(3), vec![3, 2, 1]);
+}
+
+#[test]
+fn test_countdown_message() {
+    assert_eq!(format_time_left(1), "1 second");
+    assert_eq!(format_time_left(45), "45 seconds");
+    assert_eq!(format_time_left(300), "5 minutes");
+    assert_eq!(format_time_left(90), "1m 30s");
+    assert_eq!(
+        render_countdown_message(DEFAULT_COUNTDOWN_MESSAGE, 60),
+        "Server stopping in 1 minute"
+    );
+}
+
+#[test]
+fn test_restart_warning_is_optional_in_saved_tasks() {
+    let task = ScheduledTask::new(Uuid::new_v4(), ScheduleType::Restart, "0 0 4 * * *".to_string());
+    let mut json = serde_json::to_value(&task).unwrap();
+    json.as_object_mut().unwrap().remove("warning");
+    let loaded: ScheduledTask = serde_json::from_value(json).unwrap();
+    assert!(loaded.warning.is_none());
+
+    let mut task = task;
+    task.warning = Some(RestartWarning {
+        delay_secs: 300,
+        message: None,
+    });
+    let round_trip: ScheduledTask = serde_json::from_str(&serde_json::to_string(&task).unwrap()).unwrap();
+    assert_eq!(round_trip.warning, task.warning);
+}
+
+#[test]
+fn test_multi_line_countdown_messages_are_rejected() {
+    let mut task = ScheduledTask::new(Uuid::new_v4(), ScheduleType::Restart, "0 0 4 * * *".to_string());
+    task.warning = Some(RestartWarning {
+        delay_secs: 60,
+        message: Some("Restarting in {time}".to_string()),
+    });
+    task.validate().unwrap();
+
+    task.warning = Some(RestartWarning {
+        delay_secs: 60,
+        message: Some("Restarting in {time}\nop griefer".to_string()),
+    });
+    assert!(task.validate().is_err());
+    assert!(validate_countdown_message("bye\r").is_err());
+}
@@ -0,0 +1,114 @@
+use anyhow::Result;
+use mc_server_wrapper_core::database::explorer;
+use mc_server_wrapper_core::database::h2::{
+    H2Exporter, export_dir, find_bundled_h2_jar, h2_database_name, h2_jar_version, required_h2_major, store_format,
+};
+use mc_server_wrapper_core::java::JavaManager;
+use std::path::Path;
+use tempfile::tempdir;
+
+#[test]
+fn test_h2_database_name() {
+    assert_eq!(
+        h2_database_name(Path::new("plugins/LuckPerms/luckperms-h2-v2.mv.db")).as_deref(),
+        Some("luckperms-h2-v2")
+    );
+    assert_eq!(h2_database_name(Path::new("old.H2.DB")).as_deref(), Some("old"));
+    assert_eq!(h2_database_name(Path::new("database.db")), None);
+}
+
+#[test]
+fn test_h2_store_format_picks_major() {
+    let v1 = b"H:2,block:2,blockSize:1000,chunk:5,created:18a,format:1,version:5,fletcher:1a2b3c4d\n";
+    let v2 = b"H:2,blockSize:1000,chunk:3,created:18b,format:3,version:3,fletcher:5e6f7a8b\n";
+    assert_eq!(store_format(v1), Some(1));
+    assert_eq!(store_format(v2), Some(3));
+    assert_eq!(store_format(b"\0\0\0garbage"), None);
+
+    assert_eq!(required_h2_major(Path::new("a.mv.db"), v1), 1);
+    assert_eq!(required_h2_major(Path::new("a.mv.db"), v2), 2);
+    // Page stores predate MVStore and only 1.x reads them
+    assert_eq!(required_h2_major(Path::new("a.h2.db"), v2), 1);
+    assert_eq!(required_h2_major(Path::new("a.mv.db"), b""), 2);
+}
+
+#[test]
+fn test_find_bundled_h2_jar() -> Result<()> {
+    let dir = tempdir()?;
+    let plugin = dir.path().join("plugins").join("LuckPerms");
+    std::fs::create_dir_all(plugin.join("libs"))?;
+    std::fs::create_dir_all(dir.path().join("plugins").join("libs"))?;
+    for jar in [
+        "h2-driver-2.1.214.jar",
+        "h2-driver-2.0.206.jar",
+        "h2-1.4.199.jar",
+        "caffeine-3.1.8.jar",
+    ] {
+        std::fs::write(plugin.join("libs").join(jar), b"")?;
+    }
+    // Outside the plugin's folder, never picked
+    std::fs::write(dir.path().join("plugins").join("libs").join("h2-2.3.232.jar"), b"")?;
+
+    assert_eq!(h2_jar_version("h2-driver-2.1.214.jar"), Some((2, 1, 214)));
+    assert_eq!(h2_jar_version("h2-1.4.199.jar"), Some((1, 4, 199)));
+    assert_eq!(h2_jar_version("h2database.jar"), None);
+
+    let db = plugin.join("luckperms-h2-v2.mv.db");
+    assert_eq!(
+        find_bundled_h2_jar(&db, 2),
+        Some(plugin.join("libs").join("h2-driver-2.1.214.jar"))
+    );
+    assert_eq!(
+        find_bundled_h2_jar(&db, 1),
+        Some(plugin.join("libs").join("h2-1.4.199.jar"))
+    );
+    assert_eq!(
+        find_bundled_h2_jar(&dir.path().join("plugins").join("Other").join("x.mv.db"), 2),
+        None
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_h2_export_is_reused_until_the_database_changes() -> Result<()> {
+    let dir = tempdir()?;
+    let cache = dir.path().join("cache");
+    let db = dir
+        .path()
+        .join("plugins")
+        .join("LuckPerms")
+        .join("luckperms-h2-v2.mv.db");
+    std::fs::create_dir_all(db.parent().unwrap())?;
+    std::fs::write(&db, b"H:2,format:3\n")?;
+    assert_ne!(
+        export_dir(&cache, &db),
+        export_dir(&cache, &dir.path().join("luckperms-h2-v2.mv.db"))
+    );
+
+    // An export written after the database was last modified is read without running Java
+    let script = export_dir(&cache, &db).join("luckperms-h2-v2.h2.sql");
+    std::fs::create_dir_all(script.parent().unwrap())?;
+    std::fs::write(
+        &script,
+        "CREATE CACHED TABLE \"PUBLIC\".\"LUCKPERMS_PLAYERS\"(\n    \"UUID\" VARCHAR(36) NOT NULL,\n    \"USERNAME\" VARCHAR(16) NOT NULL\n);\n\
+         INSERT INTO \"PUBLIC\".\"LUCKPERMS_PLAYERS\" VALUES('069a79f4-44e9-4726-a5be-fca90e38aaf5', 'Notch');\n",
+    )?;
+
+    let java_manager = JavaManager::new()?;
+    let exporter = H2Exporter::with_dirs(&java_manager, dir.path().join("tools"), cache.clone());
+    let exported = exporter.export(&db).await?;
+    assert_eq!(exported, script);
+
+    assert_eq!(
+        explorer::list_tables(&exported).await?,
+        vec!["LUCKPERMS_PLAYERS".to_string()]
+    );
+    let data = explorer::get_table_data(&exported, "LUCKPERMS_PLAYERS", 50, 0).await?;
+    assert_eq!(data.columns, vec!["UUID", "USERNAME"]);
+    assert_eq!(data.rows.len(), 1);
+
+    // Everything but H2 is read in place
+    let sqlite = dir.path().join("data.db");
+    assert_eq!(explorer::readable_path(&sqlite, &java_manager).await?, sqlite);
+    Ok(())
+}
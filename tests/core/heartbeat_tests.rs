@@ -0,0 +1,51 @@
+use anyhow::Result;
+use mc_server_wrapper_core::notifications::{HeartbeatSettings, send_heartbeat, send_test_heartbeat};
+use std::time::Duration;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[test]
+fn test_heartbeat_urls_and_interval() {
+    let mut settings = HeartbeatSettings::default();
+    assert!(!settings.is_active());
+
+    settings.enabled = true;
+    settings.url = " https://hc-ping.com/abc/ ".to_string();
+    assert!(settings.is_active());
+    assert_eq!(settings.ping_url(true), "https://hc-ping.com/abc/");
+    assert_eq!(settings.ping_url(false), "https://hc-ping.com/abc/fail");
+
+    settings.url = "https://example.com/ping/abc?create=1".to_string();
+    assert_eq!(settings.ping_url(false), "https://example.com/ping/abc/fail?create=1");
+
+    settings.interval_secs = 1;
+    assert_eq!(settings.interval(), Duration::from_secs(10));
+}
+
+#[tokio::test]
+async fn test_send_heartbeat_pings_url() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/abc"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/abc/fail"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let settings = HeartbeatSettings {
+        enabled: true,
+        url: format!("{}/abc", server.uri()),
+        ..Default::default()
+    };
+    send_test_heartbeat(&settings).await?;
+    send_heartbeat(&settings.ping_url(false)).await?;
+    assert!(send_heartbeat(&format!("{}/missing", server.uri())).await.is_err());
+    assert!(send_test_heartbeat(&HeartbeatSettings::default()).await.is_err());
+    Ok(())
+}
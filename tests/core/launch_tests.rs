@@ -0,0 +1,104 @@
+use mc_server_wrapper_core::config::ServerConfig;
+use mc_server_wrapper_core::instance::InstanceSettings;
+use mc_server_wrapper_core::server::launch::{select_strategy, split_command_line};
+use mc_server_wrapper_core::server::{ServerHandle, ServerStatus};
+use std::path::PathBuf;
+use tempfile::tempdir;
+use tokio::time::{Duration, sleep, timeout};
+
+#[test]
+fn test_strategy_selection() {
+    let strategy = |config: ServerConfig| select_strategy(&config).name();
+    assert_eq!(strategy(ServerConfig::default()), "jar");
+    assert_eq!(
+        strategy(ServerConfig {
+            run_script: Some("run.sh".to_string()),
+            ..Default::default()
+        }),
+        "script"
+    );
+    assert_eq!(
+        strategy(ServerConfig {
+            jar_path: Some(PathBuf::from("bedrock_server")),
+            ..Default::default()
+        }),
+        "bedrock"
+    );
+    assert_eq!(
+        strategy(ServerConfig {
+            run_script: Some("run.sh".to_string()),
+            custom_command: Some("./start".to_string()),
+            ..Default::default()
+        }),
+        "custom"
+    );
+}
+
+#[test]
+fn test_split_command_line_honours_quotes() {
+    assert_eq!(
+        split_command_line(r#"java -Xmx2G -jar "my server.jar" 'no gui' """#).unwrap(),
+        vec!["java", "-Xmx2G", "-jar", "my server.jar", "no gui", ""]
+    );
+    assert!(split_command_line("java -jar \"server.jar").is_err());
+}
+
+#[test]
+fn test_render_startup_line_fills_placeholders() {
+    let settings = InstanceSettings {
+        startup_line: "java -Xms{min_ram}{min_unit} -Xmx{max_ram}{max_unit} -jar server.jar".to_string(),
+        min_ram: 1,
+        min_ram_unit: "GB".to_string(),
+        max_ram: 4096,
+        max_ram_unit: "M".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(settings.render_startup_line(), "java -Xms1G -Xmx4096M -jar server.jar");
+}
+
+#[cfg(not(target_os = "windows"))]
+#[tokio::test]
+async fn test_custom_command_runs_through_lifecycle() {
+    let dir = tempdir().unwrap();
+    let script = r#"echo "Done (0.5s)! For help, type help"; while read cmd; do [ "$cmd" = stop ] && exit 0; echo "Got $cmd"; done"#;
+    let handle = ServerHandle::new(ServerConfig {
+        custom_command: Some(format!("sh -c '{}'", script)),
+        working_dir: dir.path().to_path_buf(),
+        args: Vec::new(),
+        ..Default::default()
+    });
+    let mut logs = handle.subscribe_logs();
+    handle.start().await.unwrap();
+
+    let mut running = false;
+    for _ in 0..50 {
+        if handle.get_status().await == ServerStatus::Running {
+            running = true;
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+    assert!(running, "custom command never reported Done");
+
+    handle.send_command("ping").await.unwrap();
+    let mut echoed = false;
+    let start = std::time::Instant::now();
+    while start.elapsed() < Duration::from_secs(5) {
+        if let Ok(Ok(line)) = timeout(Duration::from_millis(100), logs.recv()).await
+            && line.contains("Got ping")
+        {
+            echoed = true;
+            break;
+        }
+    }
+    assert!(echoed, "stdin was not forwarded to the custom command");
+
+    handle.stop().await.unwrap();
+    for _ in 0..50 {
+        if handle.get_status().await == ServerStatus::Stopped {
+            return;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+    panic!("custom command did not stop");
+}
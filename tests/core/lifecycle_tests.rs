@@ -3,6 +3,8 @@ use mc_server_wrapper_core::app_config::GlobalConfigManager;
 use mc_server_wrapper_core::database::Database;
 use mc_server_wrapper_core::instance::InstanceManager;
 use mc_server_wrapper_core::manager::ServerManager;
+use mc_server_wrapper_core::server::ServerStatus;
+use mc_server_wrapper_core::server_properties::read_server_properties;
 use std::sync::Arc;
 use tempfile::tempdir;
 use uuid::Uuid;
@@ -217,3 +219,87 @@ async fn test_prepare_server_invalid_instance() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_stop_all_servers_without_running_servers() -> Result<()> {
+    let dir = tempdir()?;
+    let instances_dir = dir.path().join("instances");
+    let config_dir = dir.path().join("config");
+    std::fs::create_dir_all(&instances_dir)?;
+
+    let instance_manager = setup_instance_manager(&instances_dir).await?;
+    let config_manager = GlobalConfigManager::new(config_dir.join("config.json"));
+    let manager = ServerManager::new(Arc::new(instance_manager), Arc::new(config_manager));
+
+    let instance = manager
+        .get_instance_manager()
+        .create_instance("Idle Server", "1.20.1")
+        .await?;
+    manager.get_or_create_server(instance.id).await?;
+
+    assert!(manager.running_server_ids().await.is_empty());
+    assert!(manager.stop_all_servers(false).await?.is_empty());
+    assert!(manager.stop_all_servers(true).await?.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_instance_summaries() -> Result<()> {
+    let dir = tempdir()?;
+    let instances_dir = dir.path().join("instances");
+    let config_dir = dir.path().join("config");
+    std::fs::create_dir_all(&instances_dir)?;
+
+    let instance_manager = setup_instance_manager(&instances_dir).await?;
+    let config_manager = GlobalConfigManager::new(config_dir.join("config.json"));
+    let manager = ServerManager::new(Arc::new(instance_manager), Arc::new(config_manager));
+
+    let survival = manager
+        .get_instance_manager()
+        .create_instance("survival", "1.20.1")
+        .await?;
+    manager
+        .get_instance_manager()
+        .create_instance("Creative", "1.20.1")
+        .await?;
+    std::fs::write(survival.path.join("server.properties"), "max-players=12\n")?;
+    manager.get_or_create_server(survival.id).await?;
+
+    let summaries = manager.get_instance_summaries().await?;
+    let names: Vec<&str> = summaries.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, vec!["Creative", "survival"]);
+    assert!(summaries.iter().all(|s| s.status == ServerStatus::Stopped && s.online_players == 0));
+    assert_eq!(summaries[1].max_players, Some(12));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_instance_writes_default_server_properties() -> Result<()> {
+    let dir = tempdir()?;
+    let instances_dir = dir.path().join("instances");
+    std::fs::create_dir_all(&instances_dir)?;
+
+    let config_manager = GlobalConfigManager::new(dir.path().join("config").join("config.json"));
+    let mut settings = config_manager.load().await?;
+    settings.server_properties_defaults.view_distance = 6;
+    settings.server_properties_defaults.motd = "Welcome to {name}".to_string();
+    config_manager.save(&settings).await?;
+
+    let instance_manager = setup_instance_manager(&instances_dir).await?;
+    let manager = ServerManager::new(Arc::new(instance_manager), Arc::new(config_manager));
+
+    let instance = manager.create_instance_full("Survival", "1.20.1", None, None).await?;
+    let props = read_server_properties(&instance.path).await?;
+    assert_eq!(props.get("motd").map(String::as_str), Some("Welcome to Survival"));
+    assert_eq!(props.get("view-distance").map(String::as_str), Some("6"));
+    assert_eq!(props.get("online-mode").map(String::as_str), Some("true"));
+    assert_eq!(props.get("server-port"), Some(&instance.settings.port.to_string()));
+
+    // Proxies have no server.properties
+    let proxy = manager
+        .create_instance_full("Proxy", "3.3.0", Some("velocity".to_string()), None)
+        .await?;
+    assert!(!proxy.path.join("server.properties").exists());
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use mc_server_wrapper_core::instance::{ProcessPriority, ResourceLimits};
+
+#[test]
+fn test_resource_limits_validation() {
+    assert!(ResourceLimits::default().is_default());
+    ResourceLimits::default().validate(4096).unwrap();
+
+    let limits = ResourceLimits {
+        priority: ProcessPriority::BelowNormal,
+        cpu_affinity: vec![0],
+        memory_limit_mb: 6144,
+    };
+    assert!(!limits.is_default());
+    limits.validate(4096).unwrap();
+
+    let capped_below_heap = ResourceLimits {
+        memory_limit_mb: 4096,
+        ..Default::default()
+    };
+    assert!(capped_below_heap.validate(4096).is_err(), "the cap has to leave room above -Xmx");
+
+    let missing_cpu = ResourceLimits {
+        cpu_affinity: vec![usize::MAX],
+        ..Default::default()
+    };
+    assert!(missing_cpu.validate(1024).is_err());
+}
+
+#[test]
+fn test_priority_serializes_snake_case() {
+    let limits: ResourceLimits = serde_json::from_str(r#"{"priority":"below_normal"}"#).unwrap();
+    assert_eq!(limits.priority, ProcessPriority::BelowNormal);
+    assert!(limits.cpu_affinity.is_empty());
+    assert_eq!(limits.memory_limit_mb, 0);
+    assert_eq!(ProcessPriority::Low.niceness(), 19);
+    assert_eq!(ProcessPriority::Normal.niceness(), 0);
+}
+
+#[cfg(target_os = "linux")]
+#[tokio::test]
+async fn test_lowered_priority_reaches_the_server() {
+    use mc_server_wrapper_core::config::ServerConfig;
+    use mc_server_wrapper_core::server::{ServerHandle, ServerStatus};
+    use std::time::Duration;
+
+    let dir = tempfile::tempdir().unwrap();
+    let script = dir.path().join("run.sh");
+    std::fs::write(&script, "#!/bin/sh\necho \"nice=$(nice)\"\n").unwrap();
+
+    let handle = ServerHandle::new(ServerConfig {
+        run_script: Some(script.to_string_lossy().to_string()),
+        working_dir: dir.path().to_path_buf(),
+        resource_limits: ResourceLimits {
+            priority: ProcessPriority::Low,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let mut logs = handle.subscribe_logs();
+    handle.start().await.unwrap();
+
+    let mut lines = Vec::new();
+    for _ in 0..100 {
+        while let Ok(line) = logs.try_recv() {
+            lines.push(line);
+        }
+        if lines.iter().any(|l| l.contains("nice=")) && handle.get_status().await == ServerStatus::Stopped {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    assert!(lines.iter().any(|l| l.contains("nice=19")), "{:?}", lines);
+}
@@ -0,0 +1,80 @@
+use anyhow::Result;
+use mc_server_wrapper_core::content_report::ContentKind;
+use mc_server_wrapper_core::loadouts::{
+    Loadout, LoadoutEntry, apply_loadout, capture_loadout, load_loadouts, revert_loadout, save_loadout,
+};
+use std::path::Path;
+use tempfile::tempdir;
+
+fn touch(path: &Path) {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, b"jar").unwrap();
+}
+
+fn entry(kind: ContentKind, file: &str, enabled: bool) -> LoadoutEntry {
+    LoadoutEntry { kind, file: file.to_string(), enabled }
+}
+
+#[tokio::test]
+async fn test_apply_and_revert_loadout() -> Result<()> {
+    let dir = tempdir()?;
+    let root = dir.path();
+    touch(&root.join("plugins/EssentialsX.jar"));
+    touch(&root.join("plugins/EventTools.jar.disabled"));
+    touch(&root.join("mods/lithium.jar"));
+
+    save_loadout(root, Loadout {
+        name: "events".to_string(),
+        entries: vec![
+            entry(ContentKind::Plugin, "EssentialsX.jar", false),
+            entry(ContentKind::Plugin, "EventTools.jar.disabled", true),
+            entry(ContentKind::Plugin, "Removed.jar", true),
+            entry(ContentKind::Mod, "lithium.jar", true),
+        ],
+    }).await?;
+
+    let result = apply_loadout(root, "events").await?;
+    assert_eq!(result.changed, vec!["EssentialsX.jar", "EventTools.jar"]);
+    assert_eq!(result.missing, vec!["Removed.jar"]);
+    assert!(root.join("plugins/EssentialsX.jar.disabled").exists());
+    assert!(root.join("plugins/EventTools.jar").exists());
+    assert_eq!(load_loadouts(root).await.active.as_deref(), Some("events"));
+
+    let result = revert_loadout(root).await?;
+    assert_eq!(result.changed.len(), 2);
+    assert!(root.join("plugins/EssentialsX.jar").exists());
+    assert!(root.join("plugins/EventTools.jar.disabled").exists());
+    let store = load_loadouts(root).await;
+    assert!(store.active.is_none() && store.previous.is_none());
+    assert!(revert_loadout(root).await.is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_switching_loadouts_keeps_original_state() -> Result<()> {
+    let dir = tempdir()?;
+    let root = dir.path();
+    touch(&root.join("mods/a.jar"));
+    touch(&root.join("mods/b.jar"));
+
+    let original = capture_loadout(root, " baseline ").await?;
+    assert_eq!(original.name, "baseline");
+    assert_eq!(original.entries, vec![
+        entry(ContentKind::Mod, "a.jar", true),
+        entry(ContentKind::Mod, "b.jar", true),
+    ]);
+
+    save_loadout(root, Loadout { name: "no-a".to_string(), entries: vec![entry(ContentKind::Mod, "a.jar", false)] }).await?;
+    save_loadout(root, Loadout { name: "no-b".to_string(), entries: vec![entry(ContentKind::Mod, "b.jar", false)] }).await?;
+
+    apply_loadout(root, "no-a").await?;
+    apply_loadout(root, "no-b").await?;
+    assert!(root.join("mods/a.jar.disabled").exists());
+    assert!(root.join("mods/b.jar.disabled").exists());
+
+    revert_loadout(root).await?;
+    assert!(root.join("mods/a.jar").exists());
+    assert!(root.join("mods/b.jar").exists());
+    assert!(apply_loadout(root, "unknown").await.is_err());
+    Ok(())
+}
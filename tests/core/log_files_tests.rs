@@ -0,0 +1,64 @@
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use mc_server_wrapper_core::log_files::{list_log_files, read_log_page, read_log_tail};
+use std::io::Write;
+use tempfile::tempdir;
+
+#[tokio::test]
+async fn test_list_and_page_archived_logs() {
+    let dir = tempdir().unwrap();
+    let logs_dir = dir.path().join("logs");
+    std::fs::create_dir_all(&logs_dir).unwrap();
+
+    let content: String = (0..200).map(|i| format!("[12:00:00] [Server thread/INFO]: line {}\n", i)).collect();
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(content.as_bytes()).unwrap();
+    std::fs::write(logs_dir.join("2024-01-01-1.log.gz"), encoder.finish().unwrap()).unwrap();
+    std::fs::write(logs_dir.join("latest.log"), "[12:00:00] started\n").unwrap();
+    std::fs::write(logs_dir.join("notes.txt"), "ignored").unwrap();
+
+    let files = list_log_files(dir.path()).await.unwrap();
+    assert_eq!(files.len(), 2);
+    let archive = files.iter().find(|f| f.compressed).unwrap();
+    assert_eq!(archive.uncompressed_size, content.len() as u64);
+
+    // Page through the archive and check pages split on line boundaries
+    let mut offset = 0;
+    let mut rebuilt = String::new();
+    loop {
+        let page = read_log_page(dir.path(), "2024-01-01-1.log.gz", offset, 1000).await.unwrap();
+        assert!(page.eof || page.content.ends_with('\n'));
+        rebuilt.push_str(&page.content);
+        offset = page.next_offset;
+        if page.eof {
+            break;
+        }
+    }
+    assert_eq!(rebuilt, content);
+
+    let latest = read_log_page(dir.path(), "latest.log", 0, 4096).await.unwrap();
+    assert_eq!(latest.content, "[12:00:00] started\n");
+    assert!(latest.eof);
+
+    assert!(read_log_page(dir.path(), "../instance.json", 0, 10).await.is_err());
+    assert!(read_log_page(dir.path(), "notes.txt", 0, 10).await.is_err());
+}
+
+#[tokio::test]
+async fn test_read_log_tail_starts_on_a_line() {
+    let dir = tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("logs")).unwrap();
+    let content: String = (0..100).map(|i| format!("line {}\n", i)).collect();
+    std::fs::write(dir.path().join("logs/app.log"), &content).unwrap();
+
+    let tail = read_log_tail(dir.path(), "app.log", 30).await.unwrap();
+    assert!(tail.eof);
+    assert!(tail.content.starts_with("line "));
+    assert!(tail.content.ends_with("line 99\n"));
+    assert!(content.ends_with(&tail.content));
+    assert_eq!(tail.offset as usize, content.len() - tail.content.len());
+
+    let whole = read_log_tail(dir.path(), "app.log", 1024 * 1024).await.unwrap();
+    assert_eq!(whole.content, content);
+    assert!(read_log_tail(dir.path(), "../app_settings.json", 10).await.is_err());
+}
@@ -0,0 +1,89 @@
+use mc_server_wrapper_core::utils::{
+    ApiMirror, DownloadLimits, DownloadSettings, bmclapi_mirrors, get_with_failover, mirror_candidates,
+};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[test]
+fn test_bmclapi_rewrites() {
+    let preset = bmclapi_mirrors();
+    let rewrite = |url: &str| preset.iter().find_map(|m| m.rewrite(url));
+
+    assert_eq!(
+        rewrite("https://launchermeta.mojang.com/mc/game/version_manifest.json").as_deref(),
+        Some("https://bmclapi2.bangbang93.com/mc/game/version_manifest.json")
+    );
+    assert_eq!(
+        rewrite("https://meta.fabricmc.net/v2/versions/loader/1.21.1").as_deref(),
+        Some("https://bmclapi2.bangbang93.com/fabric-meta/v2/versions/loader/1.21.1")
+    );
+    assert_eq!(
+        rewrite("https://maven.neoforged.net/releases/net/neoforged/neoforge/maven-metadata.xml").as_deref(),
+        Some("https://bmclapi2.bangbang93.com/maven/net/neoforged/neoforge/maven-metadata.xml")
+    );
+    // Only whole path segments match
+    assert_eq!(rewrite("https://meta.fabricmc.network/v2"), None);
+    assert_eq!(rewrite("https://api.papermc.io/v2/projects"), None);
+
+    let settings: DownloadSettings = serde_json::from_str(r#"{"max_concurrent_downloads": 2}"#).unwrap();
+    assert!(settings.api_mirrors.is_empty());
+}
+
+#[tokio::test]
+async fn test_get_with_failover() {
+    let official = MockServer::start().await;
+    let mirror = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/manifest.json"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&official)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/missing.json"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&official)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/meta/v2/manifest.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("from mirror"))
+        .mount(&mirror)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/meta/v2/missing.json"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mirror)
+        .await;
+
+    let api_mirror = ApiMirror {
+        upstream: official.uri(),
+        mirror: format!("{}/meta", mirror.uri()),
+    };
+    let limits = DownloadLimits::default();
+    limits.configure(&DownloadSettings {
+        api_mirrors: vec![api_mirror],
+        ..DownloadSettings::default()
+    });
+    let client = reqwest::Client::new();
+
+    // A missing file is not a reason to ask the mirror
+    let response = get_with_failover(&client, &limits, &format!("{}/v2/missing.json", official.uri()))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 404);
+
+    let manifest_url = format!("{}/v2/manifest.json", official.uri());
+    let response = get_with_failover(&client, &limits, &manifest_url).await.unwrap();
+    assert_eq!(response.text().await.unwrap(), "from mirror");
+
+    // The official server just failed, so its mirror is tried first for a while
+    assert_eq!(
+        mirror_candidates(&limits, &manifest_url),
+        [format!("{}/meta/v2/manifest.json", mirror.uri()), manifest_url.clone()]
+    );
+
+    limits.configure(&DownloadSettings::default());
+    // Without mirrors the last response is returned as it is
+    let response = get_with_failover(&client, &limits, &manifest_url).await.unwrap();
+    assert_eq!(response.status(), 503);
+}
@@ -18,3 +18,65 @@ mod retry_tests;
 mod cache_tests;
 mod asset_tests;
 mod monitor_tests;
+mod server_update_tests;
+mod provisioning_tests;
+mod console_tests;
+mod adopt_tests;
+mod mod_config_tests;
+mod crash_tests;
+mod credentials_tests;
+mod content_report_tests;
+mod log_files_tests;
+mod protocol_tests;
+mod notifications_tests;
+mod notification_rules_tests;
+mod via_tests;
+mod loadout_tests;
+mod wake_tests;
+mod server_list_tests;
+mod template_tests;
+mod version_history_tests;
+mod bulk_tests;
+mod remote_tests;
+mod task_tests;
+mod deletion_tests;
+mod download_limits_tests;
+mod automation_tests;
+mod doctor_tests;
+mod dependencies_tests;
+mod quilt_tests;
+mod timeline_tests;
+mod buildtools_tests;
+mod heartbeat_tests;
+mod pufferfish_tests;
+mod jvm_tests;
+mod launch_tests;
+mod disk_space_tests;
+mod forge_argfile_tests;
+mod start_limits_tests;
+mod graceful_shutdown_tests;
+mod connection_tests;
+mod pregen_tests;
+mod server_ping_tests;
+mod geyser_tests;
+mod shell_tests;
+mod environment_tests;
+mod file_manager_tests;
+mod watcher_tests;
+mod world_info_tests;
+mod content_policy_tests;
+mod world_upgrade_tests;
+mod h2_tests;
+mod database_editor_tests;
+mod database_query_tests;
+mod errors_tests;
+mod events_tests;
+mod console_buffer_tests;
+mod chat_tests;
+mod limits_tests;
+mod shared_storage_tests;
+mod mirrors_tests;
+mod storage_mode_tests;
+mod diagnostics_tests;
+mod profiler_tests;
+mod conflicts_tests;
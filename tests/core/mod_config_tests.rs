@@ -0,0 +1,95 @@
+use mc_server_wrapper_core::mods;
+use mc_server_wrapper_core::plugins;
+use std::io::Write;
+use std::path::Path;
+use tempfile::tempdir;
+use tokio::fs;
+
+fn create_jar(path: &Path, entries: &[(&str, &str)]) {
+    let file = std::fs::File::create(path).unwrap();
+    let mut zip = zip::ZipWriter::new(file);
+    for (name, content) in entries {
+        zip.start_file(*name, zip::write::SimpleFileOptions::default()).unwrap();
+        zip.write_all(content.as_bytes()).unwrap();
+    }
+    zip.finish().unwrap();
+}
+
+#[tokio::test]
+async fn test_mod_configs_are_mapped_by_mod_id() {
+    let dir = tempdir().unwrap();
+    let mods_dir = dir.path().join("mods");
+    let config_dir = dir.path().join("config");
+    fs::create_dir_all(&mods_dir).await.unwrap();
+    fs::create_dir_all(config_dir.join("create")).await.unwrap();
+
+    create_jar(
+        &mods_dir.join("create-1.20.1-0.5.1.jar"),
+        &[("META-INF/mods.toml", "[[mods]]\nmodId = \"create\"\ndisplayName = \"Create\"\n")],
+    );
+    create_jar(
+        &mods_dir.join("createench.jar.disabled"),
+        &[("fabric.mod.json", r#"{"id": "create_enchantment", "name": "Create Enchantment"}"#)],
+    );
+
+    for file in ["create-common.toml", "create_enchantment.json", "sodium-options.json", "creative.toml"] {
+        fs::write(config_dir.join(file), "").await.unwrap();
+    }
+
+    let configs = mods::get_mod_configs(dir.path(), "create-1.20.1-0.5.1.jar").await.unwrap();
+    let paths: Vec<&str> = configs.iter().map(|c| c.path.as_str()).collect();
+    assert_eq!(paths, vec!["config/create", "config/create-common.toml"]);
+    assert!(configs[0].is_dir);
+
+    // The longer ID wins, even for a disabled mod
+    let configs = mods::get_mod_configs(dir.path(), "createench.jar.disabled").await.unwrap();
+    assert_eq!(configs.len(), 1);
+    assert_eq!(configs[0].name, "create_enchantment.json");
+
+    assert!(mods::get_mod_configs(dir.path(), "../create.jar").await.is_err());
+
+    mods::uninstall_mod(dir.path(), "create-1.20.1-0.5.1.jar".to_string(), true).await.unwrap();
+    assert!(!config_dir.join("create").exists());
+    assert!(!config_dir.join("create-common.toml").exists());
+    assert!(config_dir.join("create_enchantment.json").exists());
+    assert!(config_dir.join("sodium-options.json").exists());
+    assert!(config_dir.join("creative.toml").exists());
+}
+
+#[tokio::test]
+async fn test_bulk_uninstall_deletes_each_config_once() {
+    let dir = tempdir().unwrap();
+    let mods_dir = dir.path().join("mods");
+    let config_dir = dir.path().join("config");
+    fs::create_dir_all(&mods_dir).await.unwrap();
+    fs::create_dir_all(&config_dir).await.unwrap();
+    create_jar(&mods_dir.join("create.jar"), &[("META-INF/mods.toml", "[[mods]]\nmodId = \"create\"\n")]);
+    create_jar(&mods_dir.join("createench.jar"), &[("fabric.mod.json", r#"{"id": "create_enchantment"}"#)]);
+    for file in ["create-common.toml", "create_enchantment.json"] {
+        fs::write(config_dir.join(file), "").await.unwrap();
+    }
+
+    let names = vec!["create.jar".to_string(), "createench.jar".to_string()];
+    let report = mods::bulk_uninstall_mods(dir.path(), names, true, |_| {}).await;
+    assert_eq!(report.succeeded(), 2, "{:?}", report.failed());
+    assert!(!config_dir.join("create-common.toml").exists());
+    assert!(!config_dir.join("create_enchantment.json").exists());
+    assert!(!mods_dir.join("create.jar").exists());
+}
+
+#[tokio::test]
+async fn test_uninstall_plugin_removes_named_data_folder() {
+    let dir = tempdir().unwrap();
+    let plugins_dir = dir.path().join("plugins");
+    fs::create_dir_all(plugins_dir.join("EssentialsX")).await.unwrap();
+    fs::create_dir_all(plugins_dir.join("WorldEdit")).await.unwrap();
+    create_jar(
+        &plugins_dir.join("EssentialsX-2.20.1.jar"),
+        &[("plugin.yml", "name: EssentialsX\nversion: 2.20.1\n")],
+    );
+
+    plugins::uninstall_plugin(dir.path(), "EssentialsX-2.20.1.jar".to_string(), true).await.unwrap();
+    assert!(!plugins_dir.join("EssentialsX-2.20.1.jar").exists());
+    assert!(!plugins_dir.join("EssentialsX").exists());
+    assert!(plugins_dir.join("WorldEdit").exists());
+}
@@ -1,4 +1,5 @@
-use mc_server_wrapper_core::server::ServerHandle;
+use mc_server_wrapper_core::server::{ServerHandle, TickTracker, supports_tick_commands};
+use std::time::{Duration, Instant};
 
 #[test]
 fn test_is_ready_line() {
@@ -10,3 +11,58 @@ fn test_is_ready_line() {
     assert!(!ServerHandle::is_ready_line(&"Loading libraries, please wait...".to_lowercase()));
     assert!(!ServerHandle::is_ready_line(&"Checking for updates...".to_lowercase()));
 }
+
+#[test]
+fn test_detect_startup_phase() {
+    use mc_server_wrapper_core::server::{StartupPhase, detect_startup_phase};
+
+    let libs = detect_startup_phase("Loading libraries, please wait...").unwrap();
+    assert_eq!(libs.phase, StartupPhase::LoadingLibraries);
+
+    let spawn = detect_startup_phase("[12:00:01] [Worker-Main-3/INFO]: Preparing spawn area: 50%").unwrap();
+    assert_eq!(spawn.phase, StartupPhase::PreparingSpawn);
+    assert_eq!(spawn.percent, 65);
+
+    let done = detect_startup_phase("[12:00:05] [Server thread/INFO]: Done (4.213s)! For help, type \"help\"").unwrap();
+    assert_eq!(done.phase, StartupPhase::Done);
+    assert_eq!(done.percent, 100);
+    assert_eq!(done.startup_time, Some(4.213));
+
+    assert!(detect_startup_phase("[12:00:06] [Server thread/INFO]: Steve joined the game").is_none());
+}
+
+#[test]
+fn test_tick_tracker_paper_output() {
+    let mut tracker = TickTracker::default();
+    let now = Instant::now();
+
+    let tps = tracker.observe("[12:00:00 INFO]: TPS from last 1m, 5m, 15m: *20.0, 19.87, 19.5", now).unwrap();
+    assert_eq!(tps.tps, Some(20.0));
+    assert!(!tps.estimated);
+
+    assert!(tracker.observe("[12:00:00 INFO]: Server tick times (avg/min/max) from last 5s, 10s, 1m:", now).is_none());
+    let mspt = tracker.observe("[12:00:00 INFO]: ◴ 12.5/3.1/40.2, 11.0/2.9/41.0, 10.2/2.0/55.3", now).unwrap();
+    assert_eq!(mspt.mspt, Some(12.5));
+
+    assert!(tracker.observe("[12:00:01 INFO]: Done (3.2s)!", now).is_none());
+    assert!(supports_tick_commands(Some("Paper")));
+    assert!(!supports_tick_commands(Some("fabric")));
+    assert!(!supports_tick_commands(None));
+}
+
+#[test]
+fn test_tick_tracker_vanilla_estimate() {
+    let mut tracker = TickTracker::default();
+    let now = Instant::now();
+    let line = "[12:00:00] [Server thread/WARN]: Can't keep up! Is the server overloaded? Running 15000ms or 300 ticks behind";
+
+    // 15s behind over a 15s window means half speed
+    let sample = tracker.observe(line, now).unwrap();
+    assert!(sample.estimated);
+    assert_eq!(sample.tps, Some(10.0));
+    assert_eq!(sample.mspt, Some(100.0));
+
+    // A second warning 30s later spreads the lag over the longer window
+    let sample = tracker.observe(line, now + Duration::from_secs(30)).unwrap();
+    assert!((sample.tps.unwrap() - 40.0 / 3.0).abs() < 0.01);
+}
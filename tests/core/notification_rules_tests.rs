@@ -0,0 +1,96 @@
+use anyhow::Result;
+use mc_server_wrapper_core::database::Database;
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::notifications::{NotificationEvent, NotificationRule, NotificationTrigger, RuleSet};
+use mc_server_wrapper_core::server::ServerStatus;
+use std::sync::Arc;
+use tempfile::tempdir;
+use uuid::Uuid;
+
+fn rule(id: i64, instance_id: Option<Uuid>, trigger: NotificationTrigger, body: &str) -> NotificationRule {
+    NotificationRule {
+        id,
+        instance_id,
+        name: format!("rule {}", id),
+        trigger,
+        title: "{instance}".to_string(),
+        body: body.to_string(),
+        enabled: true,
+        created_at: chrono::Utc::now(),
+    }
+}
+
+#[test]
+fn test_rule_set_matches_logs_with_cooldown() {
+    let id = Uuid::new_v4();
+    let mut rules = RuleSet::new(vec![
+        rule(1, None, NotificationTrigger::LogMatch { pattern: r"Can't keep up!".to_string() }, "Lag: {line}"),
+        rule(2, None, NotificationTrigger::LogMatch { pattern: "(unclosed".to_string() }, "never"),
+    ]);
+
+    let fired = rules.match_log(id, "Survival", "[WARN]: Can't keep up! Is the server overloaded?");
+    assert_eq!(fired.len(), 1);
+    assert_eq!(fired[0].title, "Survival");
+    assert_eq!(fired[0].body, "Lag: [WARN]: Can't keep up! Is the server overloaded?");
+
+    // Same rule again within the cooldown is suppressed
+    assert!(rules.match_log(id, "Survival", "[WARN]: Can't keep up!").is_empty());
+    assert!(rules.match_log(id, "Survival", "Done (3.2s)!").is_empty());
+}
+
+#[test]
+fn test_rule_set_matches_status_and_player_events() {
+    let id = Uuid::new_v4();
+    let mut disabled = rule(3, None, NotificationTrigger::StatusChange { status: ServerStatus::Running }, "x");
+    disabled.enabled = false;
+    let mut rules = RuleSet::new(vec![
+        rule(1, None, NotificationTrigger::StatusChange { status: ServerStatus::Crashed }, "{instance} is {status}"),
+        rule(2, None, NotificationTrigger::PlayerJoin { player: Some("notch".to_string()) }, "{player} is here"),
+        disabled,
+    ]);
+
+    assert!(rules.match_event(id, "Lobby", &NotificationEvent::ServerStarted).is_empty());
+    let crashed = rules.match_event(id, "Lobby", &NotificationEvent::ServerCrashed { reason: "oom".to_string() });
+    assert_eq!(crashed[0].body, "Lobby is Crashed");
+
+    assert!(rules.match_event(id, "Lobby", &NotificationEvent::PlayerJoined { player: "Steve".to_string() }).is_empty());
+    let joined = rules.match_event(id, "Lobby", &NotificationEvent::PlayerJoined { player: "Notch".to_string() });
+    assert_eq!(joined[0].body, "Notch is here");
+}
+
+#[tokio::test]
+async fn test_rule_storage_scopes() -> Result<()> {
+    let dir = tempdir()?;
+    let db = Arc::new(Database::new(dir.path().join("test.db")).await?);
+    let manager = InstanceManager::new(dir.path(), db).await?;
+    let first = manager.create_instance("First", "1.20.1").await?;
+    let second = manager.create_instance("Second", "1.20.1").await?;
+
+    let status = NotificationTrigger::StatusChange { status: ServerStatus::Stopped };
+    let global_id = manager.save_notification_rule(&rule(0, None, status.clone(), "global")).await?;
+    let own_id = manager.save_notification_rule(&rule(0, Some(first.id), status.clone(), "own")).await?;
+
+    let bad = rule(0, None, NotificationTrigger::LogMatch { pattern: "[".to_string() }, "bad");
+    assert!(manager.save_notification_rule(&bad).await.is_err());
+
+    assert_eq!(manager.list_notification_rules(Some(first.id)).await?.len(), 2);
+    assert_eq!(manager.list_notification_rules(Some(second.id)).await?.len(), 1);
+    assert_eq!(manager.list_notification_rules(None).await?.len(), 1);
+
+    let mut updated = rule(own_id, Some(first.id), status, "renamed");
+    updated.enabled = false;
+    manager.save_notification_rule(&updated).await?;
+    let stored = manager.list_notification_rules(Some(first.id)).await?;
+    let stored = stored.iter().find(|r| r.id == own_id).unwrap();
+    assert_eq!(stored.body, "renamed");
+    assert!(!stored.enabled);
+
+    manager.delete_instance(first.id).await?;
+    let remaining = manager.list_notification_rules(Some(first.id)).await?;
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].id, global_id);
+
+    manager.delete_notification_rule(global_id).await?;
+    assert!(manager.list_notification_rules(None).await?.is_empty());
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use anyhow::Result;
+use mc_server_wrapper_core::notifications::{
+    EventTracker, NotificationEvent, WebhookFormat, WebhookSettings, build_payload, send_test_webhook, send_webhook,
+};
+use mc_server_wrapper_core::server::ServerStatus;
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn players(names: &[&str]) -> Vec<String> {
+    names.iter().map(|n| n.to_string()).collect()
+}
+
+#[test]
+fn test_templates_and_overrides() {
+    let mut settings = WebhookSettings::default();
+    let event = NotificationEvent::PlayerJoined { player: "Steve".to_string() };
+    assert_eq!(settings.message(&event, "Survival"), "Steve joined **Survival**");
+
+    settings.templates.insert("player_joined".to_string(), "{player} hopped on {instance}".to_string());
+    assert_eq!(settings.message(&event, "Survival"), "Steve hopped on Survival");
+
+    let low = NotificationEvent::LowTps { tps: 12.345 };
+    assert!(settings.message(&low, "Survival").contains("12.3 TPS"));
+}
+
+#[test]
+fn test_event_flags_require_enabled_url() {
+    let mut settings = WebhookSettings::default();
+    assert!(!settings.wants(&NotificationEvent::ServerStarted));
+
+    settings.enabled = true;
+    settings.url = "https://example.com/hook".to_string();
+    assert!(settings.wants(&NotificationEvent::ServerStarted));
+    assert!(!settings.wants(&NotificationEvent::PlayerJoined { player: "Alex".to_string() }));
+}
+
+#[test]
+fn test_payload_formats() {
+    let event = NotificationEvent::ServerStopped;
+    assert_eq!(build_payload(WebhookFormat::Discord, "A", &event, "msg"), json!({ "content": "msg" }));
+    assert_eq!(build_payload(WebhookFormat::Slack, "A", &event, "msg"), json!({ "text": "msg" }));
+    assert_eq!(
+        build_payload(WebhookFormat::Generic, "A", &event, "msg"),
+        json!({ "instance": "A", "message": "msg", "details": { "event": "server_stopped" } })
+    );
+}
+
+#[test]
+fn test_tracker_emits_lifecycle_and_player_events() {
+    let mut tracker = EventTracker::new(ServerStatus::Starting);
+    assert!(tracker.observe(ServerStatus::Starting, &[], None, 15.0).is_empty());
+    assert_eq!(tracker.observe(ServerStatus::Running, &[], None, 15.0), vec![NotificationEvent::ServerStarted]);
+
+    let events = tracker.observe(ServerStatus::Running, &players(&["Alex", "Steve"]), None, 15.0);
+    assert_eq!(events.len(), 2);
+    assert_eq!(
+        tracker.observe(ServerStatus::Running, &players(&["Steve"]), None, 15.0),
+        vec![NotificationEvent::PlayerLeft { player: "Alex".to_string() }]
+    );
+
+    tracker.observe_log("CRASH: Server process exited unexpectedly with status: 1");
+    assert_eq!(
+        tracker.observe(ServerStatus::Crashed, &[], None, 15.0),
+        vec![NotificationEvent::ServerCrashed { reason: "Server process exited unexpectedly with status: 1".to_string() }]
+    );
+}
+
+#[test]
+fn test_tracker_low_tps_fires_once_until_recovered() {
+    let mut tracker = EventTracker::new(ServerStatus::Running);
+    assert_eq!(tracker.observe(ServerStatus::Running, &[], Some(12.0), 15.0), vec![NotificationEvent::LowTps { tps: 12.0 }]);
+    assert!(tracker.observe(ServerStatus::Running, &[], Some(10.0), 15.0).is_empty());
+    // Hovering just above the threshold does not re-arm the alert
+    assert!(tracker.observe(ServerStatus::Running, &[], Some(15.5), 15.0).is_empty());
+    assert!(tracker.observe(ServerStatus::Running, &[], Some(14.0), 15.0).is_empty());
+    assert!(tracker.observe(ServerStatus::Running, &[], Some(20.0), 15.0).is_empty());
+    assert_eq!(tracker.observe(ServerStatus::Running, &[], Some(13.0), 15.0).len(), 1);
+}
+
+#[tokio::test]
+async fn test_send_webhook_posts_payload() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/hook"))
+        .and(body_json(json!({ "text": "**Lobby** stopped" })))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut settings = WebhookSettings {
+        enabled: true,
+        url: format!("{}/hook", server.uri()),
+        format: WebhookFormat::Slack,
+        ..Default::default()
+    };
+    settings.templates.insert("server_stopped".to_string(), "**{instance}** stopped".to_string());
+    let client = reqwest::Client::new();
+    send_webhook(&client, &settings, "Lobby", &NotificationEvent::ServerStopped).await?;
+
+    settings.url = format!("{}/missing", server.uri());
+    assert!(send_test_webhook(&client, &settings, "Lobby").await.is_err());
+    Ok(())
+}
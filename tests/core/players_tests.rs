@@ -1,6 +1,7 @@
 use mc_server_wrapper_core::players::{self, PlayerEntry, OpEntry, BannedPlayerEntry, BannedIpEntry, UserCacheEntry};
 use tempfile::tempdir;
 use anyhow::Result;
+use std::sync::Arc;
 
 #[tokio::test]
 async fn test_whitelist_roundtrip() -> Result<()> {
@@ -195,3 +196,75 @@ async fn test_legacy_banned_ips() -> Result<()> {
     
     Ok(())
 }
+
+#[tokio::test]
+async fn test_concurrent_whitelist_updates_are_not_lost() -> Result<()> {
+    let dir = tempdir()?;
+    let path = dir.path().to_path_buf();
+    let locks = Arc::new(players::PlayerListLocks::default());
+
+    let tasks: Vec<_> = (0..20)
+        .map(|i| {
+            let (path, locks) = (path.clone(), Arc::clone(&locks));
+            tokio::spawn(async move {
+                players::update_whitelist(&locks, &path, |list| {
+                    list.push(PlayerEntry { uuid: format!("uuid-{}", i), name: format!("Player{}", i) });
+                    true
+                })
+                .await
+            })
+        })
+        .collect();
+    for task in tasks {
+        task.await??;
+    }
+
+    let read = players::read_whitelist(&path).await?;
+    assert_eq!(read.len(), 20);
+    assert!(std::fs::read_dir(&path)?.all(|e| !e.unwrap().file_name().to_string_lossy().ends_with(".tmp")));
+
+    players::update_whitelist(&locks, &path, |list| {
+        list.retain(|p| p.name != "Player3");
+        true
+    })
+    .await?;
+    assert_eq!(players::read_whitelist(&path).await?.len(), 19);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_player_access_warnings() -> Result<()> {
+    use players::{AccessFix, AccessIssue};
+
+    let dir = tempdir()?;
+    let path = dir.path();
+    tokio::fs::write(path.join("server.properties"), "white-list=true\n").await?;
+
+    let warnings = players::check_player_access(path, Some("paper")).await?;
+    let issues: Vec<AccessIssue> = warnings.iter().map(|w| w.issue).collect();
+    assert_eq!(issues, vec![AccessIssue::EmptyWhitelist, AccessIssue::NoOperators]);
+    assert_eq!(warnings[0].fixes, vec![AccessFix::DisableWhitelist, AccessFix::AddToWhitelist]);
+    assert!(players::check_player_access(path, Some("velocity")).await?.is_empty());
+
+    // Once someone has joined, their name can be whitelisted in one go
+    let cache = vec![UserCacheEntry {
+        uuid: "uuid1".to_string(),
+        name: "Player1".to_string(),
+        expires_on: "2023-12-31".to_string(),
+    }];
+    tokio::fs::write(path.join("usercache.json"), serde_json::to_string(&cache)?).await?;
+    let warnings = players::check_player_access(path, None).await?;
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].fixes.contains(&AccessFix::WhitelistKnownPlayers));
+
+    let locks = players::PlayerListLocks::default();
+    assert_eq!(players::whitelist_known_players(&locks, path).await?, 1);
+    assert_eq!(players::whitelist_known_players(&locks, path).await?, 0);
+    assert!(players::check_player_access(path, None).await?.is_empty());
+
+    players::write_whitelist(path, &[]).await?;
+    players::disable_whitelist(path).await?;
+    assert!(players::check_player_access(path, None).await?.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use mc_server_wrapper_core::pregen::{
+    ChunkyTarget, FORCELOAD_MAX_RADIUS, PregenMethod, PregenRequest, chunky_start_commands, chunky_target,
+    find_chunky_jar, forceload_batches, forceload_chunk_command, forceload_command, parse_chunky_progress,
+    parse_forceloaded_chunks, resolve_method, validate_world_name,
+};
+use tempfile::tempdir;
+
+fn request(radius: u32, method: PregenMethod) -> PregenRequest {
+    PregenRequest {
+        world: String::new(),
+        center_x: 0,
+        center_z: 0,
+        radius,
+        method,
+    }
+}
+
+#[test]
+fn test_resolve_method() {
+    let small = request(FORCELOAD_MAX_RADIUS, PregenMethod::Auto);
+    let large = request(5000, PregenMethod::Auto);
+
+    assert_eq!(resolve_method(&small, Some("paper")).unwrap(), PregenMethod::Forceload);
+    assert_eq!(resolve_method(&large, Some("paper")).unwrap(), PregenMethod::Chunky);
+    assert_eq!(resolve_method(&large, Some("Fabric")).unwrap(), PregenMethod::Chunky);
+    assert!(resolve_method(&large, None).is_err(), "vanilla has no Chunky");
+    assert_eq!(resolve_method(&small, None).unwrap(), PregenMethod::Forceload);
+
+    assert!(resolve_method(&request(5000, PregenMethod::Forceload), Some("paper")).is_err());
+    assert!(resolve_method(&request(100, PregenMethod::Chunky), None).is_err());
+    assert!(resolve_method(&small, Some("velocity")).is_err());
+    assert!(resolve_method(&request(0, PregenMethod::Auto), Some("paper")).is_err());
+
+    assert_eq!(chunky_target(Some("purpur")), Some(ChunkyTarget::Plugin));
+    assert_eq!(chunky_target(Some("neoforge")), Some(ChunkyTarget::Mod));
+}
+
+#[test]
+fn test_parse_chunky_progress() {
+    let line = "[12:00:01 INFO]: [Chunky] Task running for world. Processed: 1234 chunks (12.34%), ETA: 0:05:12, Rate: 95.2 cps, Current: 12, -4";
+    let progress = parse_chunky_progress(line).unwrap();
+    assert_eq!(progress.world, "world");
+    assert_eq!(progress.chunks, 1234);
+    assert_eq!(progress.percent, 12.34);
+    assert_eq!(progress.eta.as_deref(), Some("0:05:12"));
+    assert!(!progress.finished);
+
+    let done = parse_chunky_progress(
+        "\x1b[0;32m[Chunky] Task finished for minecraft:overworld. Processed: 15876 chunks (100.00%), Total time: 0:02:47\x1b[m",
+    )
+    .unwrap();
+    assert_eq!(done.world, "minecraft:overworld");
+    assert!(done.finished);
+    assert_eq!(done.eta, None);
+
+    assert!(parse_chunky_progress("[Server thread/INFO]: Done (3.2s)!").is_none());
+}
+
+#[test]
+fn test_chunky_commands() {
+    let mut req = request(2000, PregenMethod::Chunky);
+    assert_eq!(
+        chunky_start_commands(&req),
+        vec!["chunky center 0 0", "chunky radius 2000", "chunky start"]
+    );
+
+    req.world = "world_nether".to_string();
+    req.center_x = -100;
+    assert_eq!(
+        chunky_start_commands(&req)[..2],
+        ["chunky world world_nether", "chunky center -100 0"]
+    );
+}
+
+#[test]
+fn test_forceload_batches_cover_area() {
+    let req = PregenRequest {
+        world: "world_nether".to_string(),
+        center_x: 8,
+        center_z: -8,
+        radius: 256,
+        method: PregenMethod::Forceload,
+    };
+    let batches = forceload_batches(&req);
+
+    // -248..=264 blocks is chunks -16..=16, so 33x33 chunks in 3x3 tiles
+    assert_eq!(batches.len(), 9);
+    assert!(batches.iter().all(|b| b.chunk_count() <= 256));
+    assert_eq!(batches.iter().map(|b| b.chunk_count()).sum::<usize>(), 33 * 33);
+
+    assert_eq!(
+        forceload_command(&req.world, &batches[0], true),
+        "execute in minecraft:the_nether run forceload add -256 -272 -1 -17"
+    );
+    assert!(
+        forceload_command("", &batches[0], false).starts_with("execute in minecraft:overworld run forceload remove")
+    );
+}
+
+#[test]
+fn test_find_chunky_jar() {
+    let dir = tempdir().unwrap();
+    assert!(find_chunky_jar(dir.path(), ChunkyTarget::Plugin).is_none());
+
+    std::fs::create_dir_all(dir.path().join("plugins")).unwrap();
+    std::fs::write(dir.path().join("plugins/ChunkyBorder-1.2.jar"), b"").unwrap();
+    assert!(find_chunky_jar(dir.path(), ChunkyTarget::Plugin).is_none());
+
+    std::fs::write(dir.path().join("plugins/Chunky-Bukkit-1.4.28.jar"), b"").unwrap();
+    assert!(find_chunky_jar(dir.path(), ChunkyTarget::Plugin).is_some());
+    assert!(find_chunky_jar(dir.path(), ChunkyTarget::Mod).is_none());
+}
+
+#[test]
+fn test_world_names_are_validated() {
+    let mut req = request(100, PregenMethod::Auto);
+    for world in ["", "world_nether", "minecraft:the_end", "DIM-1"] {
+        req.world = world.to_string();
+        assert!(validate_world_name(world).is_ok(), "{}", world);
+        assert!(resolve_method(&req, Some("paper")).is_ok());
+    }
+    for world in ["world\nop griefer", "world; stop", "my world"] {
+        req.world = world.to_string();
+        assert!(resolve_method(&req, Some("paper")).is_err(), "{:?}", world);
+    }
+}
+
+#[test]
+fn test_parse_forceloaded_chunks() {
+    let reply = vec![
+        "[12:00:00] [Server thread/INFO]: 2 force loaded chunks were found in minecraft:overworld at: [0, 0], [-3, 12]"
+            .to_string(),
+        "[12:00:01] [Server thread/INFO]: Player joined [1, 2]".to_string(),
+    ];
+    assert_eq!(parse_forceloaded_chunks(&reply), vec![(0, 0), (-3, 12)]);
+    assert!(parse_forceloaded_chunks(&["No force loaded chunks were found in minecraft:overworld".to_string()]).is_empty());
+
+    let batch = forceload_batches(&request(256, PregenMethod::Forceload))[0];
+    assert!(batch.contains(batch.min_x, batch.max_z));
+    assert!(!batch.contains(batch.max_x + 1, batch.min_z));
+    assert_eq!(forceload_chunk_command("", -3, 12), "execute in minecraft:overworld run forceload add -48 192");
+}
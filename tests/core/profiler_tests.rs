@@ -0,0 +1,84 @@
+use mc_server_wrapper_core::database::Database;
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::profiler::{
+    ProfilerRequest, ProfilerSessionStatus, SparkTarget, find_spark_jar, parse_spark_failure, parse_spark_url,
+    profiler_start_command, spark_command, spark_target,
+};
+use std::sync::Arc;
+use tempfile::tempdir;
+use anyhow::Result;
+
+#[test]
+fn test_spark_target_and_command() {
+    assert_eq!(spark_target(Some("Paper")), Some(SparkTarget::Plugin));
+    assert_eq!(spark_target(Some("velocity")), Some(SparkTarget::Plugin));
+    assert_eq!(spark_target(Some("neoforge")), Some(SparkTarget::Mod));
+    assert_eq!(spark_target(None), None);
+    assert_eq!(spark_target(Some("bedrock")), None);
+
+    assert_eq!(spark_command(Some("paper")), "spark");
+    assert_eq!(spark_command(Some("Velocity")), "sparkv");
+    assert_eq!(spark_command(Some("bungeecord")), "sparkb");
+}
+
+#[test]
+fn test_profiler_start_command() {
+    assert_eq!(profiler_start_command("spark", &ProfilerRequest::default()), "spark profiler start");
+    let request = ProfilerRequest { duration_secs: Some(120), all_threads: true };
+    assert_eq!(profiler_start_command("sparkv", &request), "sparkv profiler start --timeout 120 --thread *");
+}
+
+#[test]
+fn test_parse_spark_output() {
+    let line = "[12:00:00] [Server thread/INFO]: \x1b[38;5;214m[⚡] \x1b[0mhttps://spark.lucko.me/AbCdEf1234";
+    assert_eq!(parse_spark_url(line).as_deref(), Some("https://spark.lucko.me/AbCdEf1234"));
+    assert_eq!(parse_spark_url("[⚡] Profiler is now running!"), None);
+
+    let failure = "[12:00:00] [Server thread/INFO]: [⚡] There isn't an active profiler running.";
+    assert_eq!(parse_spark_failure(failure).as_deref(), Some("[⚡] There isn't an active profiler running."));
+    assert_eq!(parse_spark_failure("[⚡] Profiler stopped & upload complete!"), None);
+}
+
+#[test]
+fn test_find_spark_jar() -> Result<()> {
+    let dir = tempdir()?;
+    assert!(find_spark_jar(dir.path(), SparkTarget::Plugin).is_none());
+    std::fs::create_dir_all(dir.path().join("plugins"))?;
+    std::fs::write(dir.path().join("plugins/sparkle-1.0.jar"), b"")?;
+    assert!(find_spark_jar(dir.path(), SparkTarget::Plugin).is_none());
+    std::fs::write(dir.path().join("plugins/spark-1.10.73-bukkit.jar"), b"")?;
+    assert!(find_spark_jar(dir.path(), SparkTarget::Plugin).is_some());
+    assert!(find_spark_jar(dir.path(), SparkTarget::Mod).is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_profiler_session_history() -> Result<()> {
+    let dir = tempdir()?;
+    let db = Arc::new(Database::new(dir.path().join("test.db")).await?);
+    let manager = InstanceManager::new(dir.path(), db).await?;
+    let instance = manager.create_instance("Survival", "1.21.1").await?;
+
+    let first = manager.start_profiler_session(instance.id, None).await?;
+    // A new run marks one left running by an earlier session as interrupted
+    let second = manager.start_profiler_session(instance.id, Some(60)).await?;
+    manager
+        .finish_profiler_session(second, ProfilerSessionStatus::Completed, Some("https://spark.lucko.me/abc".into()), None)
+        .await?;
+
+    let sessions = manager.list_profiler_sessions(instance.id).await?;
+    assert_eq!(sessions.len(), 2);
+    assert_eq!(sessions[0].id, second);
+    assert_eq!(sessions[0].status, ProfilerSessionStatus::Completed);
+    assert_eq!(sessions[0].duration_secs, Some(60));
+    assert_eq!(sessions[0].url.as_deref(), Some("https://spark.lucko.me/abc"));
+    assert_eq!(sessions[1].id, first);
+    assert_eq!(sessions[1].status, ProfilerSessionStatus::Failed);
+    assert!(sessions[1].finished_at.is_some());
+
+    assert!(manager.delete_profiler_session(instance.id, first).await?);
+    assert!(!manager.delete_profiler_session(instance.id, first).await?);
+    manager.delete_instance(instance.id).await?;
+    assert!(manager.list_profiler_sessions(instance.id).await?.is_empty());
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anyhow::Result;
+use mc_server_wrapper_core::database::Database;
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::protocol::{compatibility, protocol_version, required_java};
+use std::sync::Arc;
+use tempfile::tempdir;
+
+#[test]
+fn test_protocol_version_lookup() {
+    assert_eq!(protocol_version("1.20.1"), Some(763));
+    assert_eq!(protocol_version("1.8.9"), Some(47));
+    assert_eq!(protocol_version("24w14a"), None);
+}
+
+#[test]
+fn test_required_java() {
+    assert_eq!(required_java("1.12.2"), 8);
+    assert_eq!(required_java("1.16.5"), 8);
+    assert_eq!(required_java("1.17.1"), 16);
+    assert_eq!(required_java("1.20.4"), 17);
+    assert_eq!(required_java("1.20.5"), 21);
+    assert_eq!(required_java("1.21"), 21);
+}
+
+#[test]
+fn test_compatibility_lists_shared_protocol_clients() {
+    let compat = compatibility("1.20.4");
+    assert_eq!(compat.protocol, Some(765));
+    assert_eq!(compat.compatible_clients, vec!["1.20.3", "1.20.4"]);
+    assert_eq!(compat.min_java, 17);
+
+    let snapshot = compatibility("24w14a");
+    assert_eq!(snapshot.protocol, None);
+    assert_eq!(snapshot.compatible_clients, vec!["24w14a"]);
+}
+
+#[tokio::test]
+async fn test_proxy_compatibility_flags_mismatched_backends() -> Result<()> {
+    let dir = tempdir()?;
+    let db = Arc::new(Database::new(dir.path().join("test.db")).await?);
+    let manager = InstanceManager::new(dir.path(), db).await?;
+
+    let proxy = manager.create_instance_full("Proxy", "3.3.0", Some("velocity".to_string()), None).await?;
+    tokio::fs::write(
+        proxy.path.join("velocity.toml"),
+        "[servers]\nlobby = \"127.0.0.1:25566\"\nsurvival = \"localhost:25567\"\nremote = \"mc.example.com:25565\"\ntry = [\"lobby\"]\n",
+    ).await?;
+
+    for (name, version, port) in [("Lobby", "1.21.1", 25566), ("Survival", "1.20.4", 25567)] {
+        let instance = manager.create_instance(name, version).await?;
+        let mut settings = instance.settings.clone();
+        settings.port = port;
+        manager.update_settings(instance.id, None, settings).await?;
+    }
+
+    let report = manager.proxy_compatibility(proxy.id).await?;
+    assert_eq!(report.backends.len(), 3);
+
+    let lobby = report.backends.iter().find(|b| b.name == "lobby").unwrap();
+    assert_eq!(lobby.instance_name.as_deref(), Some("Lobby"));
+    assert_eq!(lobby.protocol, Some(767));
+    assert!(!lobby.mismatch);
+
+    let survival = report.backends.iter().find(|b| b.name == "survival").unwrap();
+    assert!(survival.mismatch);
+
+    let remote = report.backends.iter().find(|b| b.name == "remote").unwrap();
+    assert!(remote.instance_id.is_none());
+    assert_eq!(report.warnings.len(), 2);
+
+    let backend = manager.create_instance("Plain", "1.21.1").await?;
+    assert!(manager.proxy_compatibility(backend.id).await.is_err());
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use anyhow::Result;
+use mc_server_wrapper_core::database::Database;
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::app_config::GlobalConfigManager;
+use mc_server_wrapper_core::manager::{
+    InstallerPhase, InstallerState, ProvisionState, ProvisionStep, ServerManager, StepStatus,
+    cleanup_partial_output, expected_artifacts, plan_steps, verify_installer_output,
+};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+use tempfile::tempdir;
+
+fn write_jar(path: &Path) {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    let mut zip = zip::ZipWriter::new(std::fs::File::create(path).unwrap());
+    zip.start_file("META-INF/MANIFEST.MF", zip::write::SimpleFileOptions::default()).unwrap();
+    zip.write_all(b"Manifest-Version: 1.0").unwrap();
+    zip.finish().unwrap();
+}
+
+#[test]
+fn test_plan_steps_per_server_type() {
+    assert_eq!(
+        plan_steps(None),
+        vec![
+            ProvisionStep::DownloadJar,
+            ProvisionStep::WriteEula,
+            ProvisionStep::WriteConfigs,
+            ProvisionStep::Finalize,
+        ]
+    );
+    assert_eq!(plan_steps(Some("Forge"))[0], ProvisionStep::RunInstaller);
+    assert!(!plan_steps(Some("velocity")).contains(&ProvisionStep::WriteConfigs));
+    assert_eq!(
+        plan_steps(Some("bedrock")),
+        vec![ProvisionStep::DownloadJar, ProvisionStep::Finalize]
+    );
+}
+
+#[tokio::test]
+async fn test_provision_state_resumes_unfinished_install() -> Result<()> {
+    let dir = tempdir()?;
+    let db = Arc::new(Database::new(dir.path().join("test.db")).await?);
+    let instance_manager = InstanceManager::new(dir.path(), db).await?;
+    let instance = instance_manager
+        .create_instance_full("Resume", "1.20.1", Some("paper".to_string()), None)
+        .await?;
+
+    let mut state = ProvisionState::plan(&instance);
+    state.set_status(0, StepStatus::Completed, None);
+    state.set_status(1, StepStatus::Failed, Some("disk full".to_string()));
+    state.save(&instance.path).await?;
+
+    let resumed = ProvisionState::load_or_plan(&instance).await?;
+    assert_eq!(resumed.next_pending(), Some(1));
+    assert_eq!(resumed.steps[1].error.as_deref(), Some("disk full"));
+
+    // A finished install is never resumed, so a broken jar triggers a full reinstall
+    for i in 0..state.steps.len() {
+        state.set_status(i, StepStatus::Completed, None);
+    }
+    state.save(&instance.path).await?;
+    let fresh = ProvisionState::load_or_plan(&instance).await?;
+    assert_eq!(fresh.next_pending(), Some(0));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_installer_state_resumes_failed_phase() -> Result<()> {
+    let dir = tempdir()?;
+    let mut state = InstallerState::new("Forge", "1.20.1", "47.2.0");
+    state.mark_completed(InstallerPhase::DownloadInstaller);
+    state.mark_failed(InstallerPhase::RunInstaller, "connection reset".to_string());
+    state.save(dir.path()).await?;
+
+    let resumed = InstallerState::load_or_new(dir.path(), "forge", "1.20.1", "47.2.0").await?;
+    assert_eq!(resumed.next_phase(), Some(InstallerPhase::RunInstaller));
+    assert_eq!(resumed.error.as_deref(), Some("connection reset"));
+
+    // A failed verification can only be fixed by running the installer again
+    state.mark_completed(InstallerPhase::RunInstaller);
+    state.mark_failed(InstallerPhase::VerifyOutput, "missing args".to_string());
+    state.save(dir.path()).await?;
+    let resumed = InstallerState::load_or_new(dir.path(), "forge", "1.20.1", "47.2.0").await?;
+    assert_eq!(resumed.next_phase(), Some(InstallerPhase::RunInstaller));
+
+    let other = InstallerState::load_or_new(dir.path(), "forge", "1.20.1", "47.3.0").await?;
+    assert_eq!(other.next_phase(), Some(InstallerPhase::DownloadInstaller));
+    Ok(())
+}
+
+#[test]
+fn test_verify_and_clean_installer_output() {
+    let dir = tempdir().unwrap();
+    let expected = expected_artifacts("neoforge", "1.21.1", "21.1.77", true);
+    for rel in &expected {
+        std::fs::create_dir_all(dir.path().join(rel).parent().unwrap()).unwrap();
+        std::fs::write(dir.path().join(rel), "").unwrap();
+    }
+    write_jar(&dir.path().join("libraries/com/example/good/1.0/good-1.0.jar"));
+    let broken = dir.path().join("libraries/com/example/bad/1.0/bad-1.0.jar");
+    std::fs::create_dir_all(broken.parent().unwrap()).unwrap();
+    std::fs::write(&broken, b"PK\x03\x04trunc").unwrap();
+
+    let problems = verify_installer_output(dir.path(), &expected);
+    assert_eq!(problems.len(), 1);
+    assert!(problems[0].contains("bad-1.0.jar"));
+
+    assert_eq!(cleanup_partial_output(dir.path()), 1);
+    assert!(!broken.exists());
+    assert!(verify_installer_output(dir.path(), &expected).is_empty());
+
+    std::fs::remove_file(dir.path().join(&expected[1])).unwrap();
+    assert!(verify_installer_output(dir.path(), &expected)[0].starts_with("Missing"));
+}
+
+#[tokio::test]
+async fn test_repair_installation_leaves_healthy_install_alone() -> Result<()> {
+    let dir = tempdir()?;
+    let db = Arc::new(Database::new(dir.path().join("test.db")).await?);
+    let instance_manager = Arc::new(InstanceManager::new(dir.path().join("instances"), db).await?);
+    let config_manager = Arc::new(GlobalConfigManager::new(dir.path().join("config.json")));
+    let server_manager = ServerManager::new(Arc::clone(&instance_manager), config_manager);
+
+    let paper = instance_manager
+        .create_instance_full("Paper", "1.20.1", Some("paper".to_string()), None)
+        .await?;
+    assert!(server_manager.repair_installation(paper.id).await.is_err());
+
+    let forge = instance_manager
+        .create_instance_full("Forge", "1.12.2", Some("forge".to_string()), Some("14.23.5.2860".to_string()))
+        .await?;
+    write_jar(&forge.path.join("server.jar"));
+    let report = server_manager.repair_installation(forge.id).await?;
+    assert!(report.problems.is_empty());
+    assert_eq!(report.rerun_from, None);
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anyhow::Result;
+use mc_server_wrapper_core::database::Database;
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::mod_loaders::pufferfish::{parse_pufferfish_builds, pufferfish_job};
+use serde_json::json;
+use std::sync::Arc;
+use tempfile::tempdir;
+
+#[test]
+fn test_pufferfish_job_uses_minor_version_line() {
+    assert_eq!(pufferfish_job("1.20.4"), "Pufferfish-1.20");
+    assert_eq!(pufferfish_job("1.21"), "Pufferfish-1.21");
+}
+
+#[test]
+fn test_parse_pufferfish_builds_filters_by_version() -> Result<()> {
+    let jar = |v: &str| format!("pufferfish-paperclip-{}-R0.1-SNAPSHOT-reobf.jar", v);
+    let job = serde_json::from_value(json!({
+        "builds": [
+            { "number": 40, "result": "SUCCESS", "artifacts": [
+                { "fileName": jar("1.20.2"), "relativePath": format!("build/libs/{}", jar("1.20.2")) }
+            ]},
+            { "number": 52, "result": "FAILURE", "artifacts": [] },
+            { "number": 55, "result": "SUCCESS", "artifacts": [
+                { "fileName": jar("1.20.4"), "relativePath": format!("build/libs/{}", jar("1.20.4")) }
+            ]},
+            { "number": 51, "result": "SUCCESS", "artifacts": [
+                { "fileName": jar("1.20.4"), "relativePath": format!("build/libs/{}", jar("1.20.4")) }
+            ]}
+        ]
+    }))?;
+
+    let builds = parse_pufferfish_builds(job, "1.20.4");
+    let numbers: Vec<&str> = builds.iter().map(|b| b.build.as_str()).collect();
+    assert_eq!(numbers, vec!["55", "51"]);
+    assert_eq!(builds[0].artifact_path, format!("build/libs/{}", jar("1.20.4")));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_folia_instances_are_flagged() -> Result<()> {
+    let dir = tempdir()?;
+    let db = Arc::new(Database::new(dir.path().join("test.db")).await?);
+    let manager = InstanceManager::new(dir.path().join("instances"), db).await?;
+    let folia = manager
+        .create_instance_full("Regions", "1.20.4", Some("folia".to_string()), Some("10".to_string()))
+        .await?;
+    let paper = manager
+        .create_instance_full("Lobby", "1.20.4", Some("paper".to_string()), None)
+        .await?;
+
+    assert!(folia.folia_plugins_only);
+    assert!(manager.get_instance(folia.id).await?.unwrap().folia_plugins_only);
+    assert!(!manager.get_instance(paper.id).await?.unwrap().folia_plugins_only);
+    Ok(())
+}
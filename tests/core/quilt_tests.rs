@@ -0,0 +1,34 @@
+use mc_server_wrapper_core::mod_loaders::quilt::{find_quilt_launch_jar, quilt_installer_args};
+use tempfile::tempdir;
+
+#[test]
+fn test_quilt_installer_args() {
+    assert_eq!(
+        quilt_installer_args("1.20.1", Some("0.26.0")),
+        vec!["install", "server", "1.20.1", "0.26.0", "--download-server", "--install-dir=."]
+    );
+    assert_eq!(
+        quilt_installer_args("1.20.1", None),
+        vec!["install", "server", "1.20.1", "--download-server", "--install-dir=."]
+    );
+}
+
+#[test]
+fn test_find_quilt_launch_jar_prefers_launch_jar() -> anyhow::Result<()> {
+    let dir = tempdir()?;
+    assert!(find_quilt_launch_jar(dir.path()).is_none());
+
+    std::fs::write(dir.path().join("server.jar"), b"vanilla")?;
+    std::fs::write(dir.path().join("quilt-loader-0.26.0.jar"), b"loader")?;
+    assert_eq!(
+        find_quilt_launch_jar(dir.path()),
+        Some(dir.path().join("quilt-loader-0.26.0.jar"))
+    );
+
+    std::fs::write(dir.path().join("quilt-server-launch.jar"), b"launch")?;
+    assert_eq!(
+        find_quilt_launch_jar(dir.path()),
+        Some(dir.path().join("quilt-server-launch.jar"))
+    );
+    Ok(())
+}
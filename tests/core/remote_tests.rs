@@ -0,0 +1,161 @@
+use anyhow::Result;
+use mc_server_wrapper_core::database::Database;
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::remote::ftp::{parse_list_line, parse_mlsd_line};
+use mc_server_wrapper_core::remote::{RemoteProtocol, RemoteSource, fetch_host_key, list_remote_dir};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tempfile::tempdir;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+
+/// Serves `files` (path -> content) over just enough FTP for the client.
+async fn spawn_ftp_server(files: BTreeMap<&'static str, &'static str>) -> u16 {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let (read, mut write) = stream.into_split();
+        let mut lines = BufReader::new(read).lines();
+        write.write_all(b"220 ready\r\n").await.unwrap();
+        let mut data: Option<TcpListener> = None;
+
+        while let Ok(Some(line)) = lines.next_line().await {
+            let (cmd, arg) = line.split_once(' ').unwrap_or((&line, ""));
+            let reply = match cmd {
+                "USER" => "331 password please".to_string(),
+                "PASS" if arg == "secret" => "230 logged in".to_string(),
+                "PASS" => "530 denied".to_string(),
+                "TYPE" => "200 ok".to_string(),
+                "EPSV" => {
+                    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+                    let port = listener.local_addr().unwrap().port();
+                    data = Some(listener);
+                    format!("229 Entering Extended Passive Mode (|||{}|)", port)
+                }
+                "MLSD" | "RETR" => {
+                    let (mut conn, _) = data.take().unwrap().accept().await.unwrap();
+                    write.write_all(b"150 opening\r\n").await.unwrap();
+                    let body = if cmd == "RETR" {
+                        files[arg].to_string()
+                    } else {
+                        let prefix = format!("{}/", arg.trim_end_matches('/'));
+                        let mut entries = BTreeMap::new();
+                        for (path, content) in &files {
+                            if let Some(rest) = path.strip_prefix(&prefix) {
+                                match rest.split_once('/') {
+                                    Some((dir, _)) => entries.insert(dir.to_string(), "type=dir;".to_string()),
+                                    None => entries.insert(rest.to_string(), format!("type=file;size={};", content.len())),
+                                };
+                            }
+                        }
+                        entries.iter().map(|(name, facts)| format!("{} {}\r\n", facts, name)).collect()
+                    };
+                    conn.write_all(body.as_bytes()).await.unwrap();
+                    drop(conn);
+                    "226 done".to_string()
+                }
+                _ => "502 not implemented".to_string(),
+            };
+            write.write_all(format!("{}\r\n", reply).as_bytes()).await.unwrap();
+        }
+    });
+    port
+}
+
+fn ftp_source(port: u16) -> RemoteSource {
+    RemoteSource {
+        protocol: RemoteProtocol::Ftp,
+        host: "127.0.0.1".to_string(),
+        port: Some(port),
+        username: "panel".to_string(),
+        password: Some("secret".to_string()),
+        private_key_path: None,
+        host_key_fingerprint: None,
+    }
+}
+
+#[test]
+fn test_parse_ftp_listings() {
+    assert_eq!(parse_mlsd_line("type=file;size=42;modify=20240101; server.jar"), Some(("server.jar".to_string(), false, 42)));
+    assert_eq!(parse_mlsd_line("type=dir;modify=20240101; world"), Some(("world".to_string(), true, 0)));
+    assert_eq!(parse_mlsd_line("type=cdir; ."), None);
+    assert_eq!(parse_mlsd_line("type=OS.unix=slink:/etc; link"), None);
+
+    assert_eq!(
+        parse_list_line("-rw-r--r--   1 mc mc  1048576 Jan 01 12:00 paper 1.20.jar"),
+        Some(("paper 1.20.jar".to_string(), false, 1048576))
+    );
+    assert_eq!(parse_list_line("drwxr-xr-x 2 mc mc 4096 Mar 3 2023 plugins"), Some(("plugins".to_string(), true, 4096)));
+    assert_eq!(parse_list_line("lrwxrwxrwx 1 mc mc 4 Jan 01 12:00 etc -> /etc"), None);
+    assert_eq!(parse_list_line("total 12"), None);
+}
+
+#[tokio::test]
+async fn test_list_remote_dir_over_ftp() {
+    let port = spawn_ftp_server(BTreeMap::from([("/srv/server.jar", "jar"), ("/srv/world/level.dat", "x")])).await;
+    let listing = list_remote_dir(&ftp_source(port), "/srv").await.unwrap();
+    let names: Vec<(&str, bool)> = listing.entries.iter().map(|e| (e.name.as_str(), e.is_dir)).collect();
+    assert_eq!(names, [("world", true), ("server.jar", false)]);
+    assert_eq!(listing.entries[1].path, "/srv/server.jar");
+}
+
+#[tokio::test]
+async fn test_sftp_needs_a_confirmed_host_key_before_connecting() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let source = RemoteSource {
+        protocol: RemoteProtocol::Sftp,
+        port: Some(listener.local_addr().unwrap().port()),
+        ..ftp_source(0)
+    };
+    let err = list_remote_dir(&source, "/").await.unwrap_err();
+    assert!(err.to_string().contains("Confirm the host key"));
+    // Nothing, credentials included, went out to the host
+    assert!(tokio::time::timeout(std::time::Duration::from_millis(50), listener.accept()).await.is_err());
+    assert!(fetch_host_key(&ftp_source(0)).await.is_err());
+}
+
+#[tokio::test]
+async fn test_import_remote_instance_over_ftp() -> Result<()> {
+    let files = BTreeMap::from([
+        ("/home/mc/server.jar", "jar"),
+        ("/home/mc/server.properties", "server-port=25570\n"),
+        ("/home/mc/plugins/Essentials/config.yml", "a: 1\n"),
+    ]);
+    let port = spawn_ftp_server(files).await;
+
+    let dir = tempdir()?;
+    let db = Arc::new(Database::new(dir.path().join("test.db")).await?);
+    let manager = InstanceManager::new(dir.path().join("instances"), db).await?;
+    let progress = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let seen = progress.clone();
+
+    let instance = manager
+        .import_remote_instance("Migrated", &ftp_source(port), "/home/mc", "server.jar".to_string(), None, None, move |current, total, _| {
+            seen.lock().unwrap().push((current, total));
+        })
+        .await?;
+
+    assert_eq!(std::fs::read_to_string(instance.path.join("plugins/Essentials/config.yml"))?, "a: 1\n");
+    assert!(instance.path.join("server.jar").exists());
+    assert!(instance.settings.startup_line.contains("server.jar"));
+    assert_eq!(progress.lock().unwrap().last(), Some(&(26, 26)));
+    assert!(manager.get_instance(instance.id).await?.is_some());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_failed_remote_import_leaves_nothing_behind() -> Result<()> {
+    let port = spawn_ftp_server(BTreeMap::from([("/srv/readme.txt", "no jar here")])).await;
+    let dir = tempdir()?;
+    let db = Arc::new(Database::new(dir.path().join("test.db")).await?);
+    let manager = InstanceManager::new(dir.path().join("instances"), db).await?;
+
+    let result = manager
+        .import_remote_instance("Broken", &ftp_source(port), "/srv", "server.jar".to_string(), None, None, |_, _, _| {})
+        .await;
+    assert!(result.is_err());
+    assert!(manager.list_instances().await?.is_empty());
+    assert_eq!(std::fs::read_dir(dir.path().join("instances"))?.count(), 0);
+    Ok(())
+}
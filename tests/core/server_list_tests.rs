@@ -0,0 +1,112 @@
+use anyhow::Result;
+use mc_server_wrapper_core::server_list::{
+    ICON_SIZE, IssueSeverity, MotdFormat, SERVER_LIST_WIDTH, convert_motd, preview_motd, read_motd, set_server_icon,
+    strip_formatting, write_motd,
+};
+use tempfile::tempdir;
+
+#[test]
+fn test_motd_format_conversions() -> Result<()> {
+    let legacy = "§6§lGold Bold§r plain";
+    let mini = convert_motd(legacy, MotdFormat::Legacy, MotdFormat::MiniMessage)?;
+    assert_eq!(mini, "<gold><bold>Gold Bold</bold></gold> plain");
+    assert_eq!(convert_motd(&mini, MotdFormat::MiniMessage, MotdFormat::Legacy)?, legacy);
+
+    let json: serde_json::Value = serde_json::from_str(&convert_motd(legacy, MotdFormat::Legacy, MotdFormat::Json)?)?;
+    assert_eq!(json["extra"][0]["color"], "gold");
+    assert_eq!(json["extra"][0]["bold"], true);
+    assert_eq!(convert_motd(&json.to_string(), MotdFormat::Json, MotdFormat::Legacy)?, legacy);
+
+    // Hex colors, `&` aliases and literal ampersands
+    let hex = convert_motd("§x§f§f§0§0§0§0Red", MotdFormat::Legacy, MotdFormat::Json)?;
+    assert!(hex.contains("\"color\":\"#ff0000\""));
+    assert_eq!(convert_motd("&aGreen & co", MotdFormat::Legacy, MotdFormat::Legacy)?, "§aGreen & co");
+    assert_eq!(convert_motd("<#00ff00>Hi <unknown>", MotdFormat::MiniMessage, MotdFormat::Legacy)?, "§x§0§0§f§f§0§0Hi <unknown>");
+    assert_eq!(strip_formatting("<red>A</red><newline>B", MotdFormat::MiniMessage)?, "A\nB");
+    assert!(convert_motd("{not json", MotdFormat::Json, MotdFormat::Legacy).is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_write_motd_preserves_other_properties() -> Result<()> {
+    let dir = tempdir()?;
+    let path = dir.path().join("server.properties");
+    std::fs::write(&path, "#Minecraft server properties\nmax-players=20\nmotd=A Minecraft Server\nserver-port=25565\n")?;
+
+    let motd = write_motd(dir.path(), "<red>Hi</red><newline>Line 2", MotdFormat::MiniMessage).await?;
+    assert_eq!(motd.legacy, "§cHi§r\nLine 2");
+    let content = std::fs::read_to_string(&path)?;
+    assert_eq!(content, "#Minecraft server properties\nmax-players=20\nmotd=\\u00A7cHi\\u00A7r\\nLine 2\nserver-port=25565\n");
+
+    let read = read_motd(dir.path()).await?;
+    assert_eq!(read, motd);
+    assert_eq!(read.plain, "Hi\nLine 2");
+
+    assert!(write_motd(dir.path(), "one\ntwo\nthree", MotdFormat::Legacy).await.is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_server_icon_converts_to_64px_png() -> Result<()> {
+    let dir = tempdir()?;
+    let source = dir.path().join("banner.jpg");
+    image::RgbImage::from_pixel(200, 100, image::Rgb([200, 30, 30])).save(&source)?;
+
+    let icon = set_server_icon(dir.path(), &source).await?;
+    assert_eq!(icon, dir.path().join("server-icon.png"));
+    let saved = image::open(&icon)?;
+    assert_eq!((saved.width(), saved.height()), (ICON_SIZE, ICON_SIZE));
+    assert_eq!(image::ImageFormat::from_path(&icon)?, image::ImageFormat::Png);
+
+    let not_an_image = dir.path().join("notes.txt");
+    std::fs::write(&not_an_image, "hello")?;
+    assert!(set_server_icon(dir.path(), &not_an_image).await.is_err());
+    assert!(set_server_icon(dir.path(), &dir.path().join("missing.png")).await.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_motd_preview_resolves_styles_per_line() {
+    let preview = preview_motd("§6§lGold§r plain\n§x§f§f§0§0§0§0Red", MotdFormat::Legacy);
+    assert!(preview.valid);
+    assert_eq!(preview.lines.len(), 2);
+
+    let first = &preview.lines[0];
+    assert_eq!(first[0].text, "Gold");
+    assert_eq!(first[0].color, "#ffaa00");
+    assert!(first[0].bold);
+    assert_eq!(first[1].text, " plain");
+    assert_eq!(first[1].color, "#808080", "unformatted text uses the server list's gray");
+    assert!(!first[1].bold);
+
+    assert_eq!(preview.lines[1][0].color, "#ff0000");
+    // Bold adds a pixel per char: "Gold" is 7+7+4+7, " plain" is 4+6+3+6+2+6
+    assert_eq!(preview.line_widths, vec![25 + 27, 18]);
+    assert_eq!(preview.plain, "Gold plain\nRed");
+    assert!(
+        preview.issues.iter().any(|i| i.severity == IssueSeverity::Warning && i.message.contains("Hex colors")),
+        "hex colors are flagged for old clients"
+    );
+}
+
+#[test]
+fn test_motd_preview_validation() {
+    let three_lines = preview_motd("one\ntwo\nthree", MotdFormat::Legacy);
+    assert!(!three_lines.valid);
+
+    let wide = preview_motd(&"W".repeat(50), MotdFormat::Legacy);
+    assert!(wide.valid);
+    assert!(wide.line_widths[0] > SERVER_LIST_WIDTH);
+    assert!(wide.issues.iter().any(|i| i.message.contains("cuts it off")));
+
+    let unknown = preview_motd("§zHello", MotdFormat::Legacy);
+    assert!(unknown.issues.iter().any(|i| i.message.contains("§z")));
+
+    let bad_json = preview_motd("{not json", MotdFormat::Json);
+    assert!(!bad_json.valid);
+    assert!(bad_json.lines.is_empty());
+
+    let mini = preview_motd("<aqua>Hi</aqua>", MotdFormat::MiniMessage);
+    assert!(mini.valid && mini.issues.is_empty());
+    assert_eq!(mini.lines[0][0].color, "#55ffff");
+}
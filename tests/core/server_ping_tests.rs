@@ -0,0 +1,157 @@
+use mc_server_wrapper_core::protocol::packets::{self, NextState};
+use mc_server_wrapper_core::protocol::query::{
+    full_stat_request, handshake_request, parse_full_stat, parse_handshake_response, session_id,
+};
+use mc_server_wrapper_core::protocol::status::parse_status_json;
+use mc_server_wrapper_core::protocol::{parse_server_address, ping_server, query_server};
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::net::{TcpListener, UdpSocket};
+
+#[test]
+fn test_parse_server_address() {
+    assert_eq!(
+        parse_server_address("play.example.com", 25565).unwrap(),
+        ("play.example.com".to_string(), 25565)
+    );
+    assert_eq!(
+        parse_server_address(" 10.0.0.2:25570 ", 25565).unwrap(),
+        ("10.0.0.2".to_string(), 25570)
+    );
+    assert_eq!(
+        parse_server_address("[::1]:25566", 25565).unwrap(),
+        ("::1".to_string(), 25566)
+    );
+    assert_eq!(
+        parse_server_address("[::1]", 25565).unwrap(),
+        ("::1".to_string(), 25565)
+    );
+    assert_eq!(
+        parse_server_address("2001:db8::1", 25565).unwrap(),
+        ("2001:db8::1".to_string(), 25565)
+    );
+    assert!(parse_server_address("host:notaport", 25565).is_err());
+    assert!(parse_server_address("", 25565).is_err());
+}
+
+#[test]
+fn test_parse_status_json() {
+    let json = r#"{
+        "version": {"name": "Paper 1.21.1", "protocol": 767},
+        "players": {"max": 20, "online": 2, "sample": [{"name": "Alex", "id": "x"}, {"name": "Steve", "id": "y"}]},
+        "description": {"text": "", "extra": [{"text": "Hello ", "color": "gold"}, {"text": "world", "bold": true}]},
+        "favicon": "data:image/png;base64,AAAA"
+    }"#;
+    let report = parse_status_json(json).unwrap();
+    assert_eq!(report.version_name, "Paper 1.21.1");
+    assert_eq!(report.protocol, 767);
+    assert_eq!((report.players_online, report.players_max), (2, 20));
+    assert_eq!(report.player_sample, vec!["Alex", "Steve"]);
+    assert_eq!(report.motd_plain, "Hello world");
+    assert!(report.motd.starts_with("§6Hello "));
+    assert_eq!(report.favicon.as_deref(), Some("data:image/png;base64,AAAA"));
+
+    let legacy =
+        parse_status_json(r#"{"version": {"name": "1.8.9", "protocol": 47}, "description": "§aA Minecraft Server"}"#)
+            .unwrap();
+    assert_eq!(legacy.motd_plain, "A Minecraft Server");
+    assert!(legacy.player_sample.is_empty());
+}
+
+#[tokio::test]
+async fn test_ping_server_round_trip() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let (_, body) = packets::read_packet(&mut stream).await.unwrap();
+        assert_eq!(packets::parse_handshake(&body).unwrap().next_state, NextState::Status);
+        let (id, _) = packets::read_packet(&mut stream).await.unwrap();
+        assert_eq!(id, 0x00);
+        stream
+            .write_all(&packets::status_response("1.21.1", 767, "Test MOTD"))
+            .await
+            .unwrap();
+        let (id, payload) = packets::read_packet(&mut stream).await.unwrap();
+        assert_eq!(id, 0x01);
+        stream.write_all(&packets::pong(&payload)).await.unwrap();
+    });
+
+    let report = ping_server("127.0.0.1", port, Duration::from_secs(5)).await.unwrap();
+    assert_eq!(report.version_name, "1.21.1");
+    assert_eq!(report.motd_plain, "Test MOTD");
+    assert!(report.latency_ms.is_some());
+}
+
+fn full_stat_packet(session: u32) -> Vec<u8> {
+    let mut packet = vec![0x00];
+    packet.extend_from_slice(&session.to_be_bytes());
+    packet.extend_from_slice(b"splitnum\x00\x80\x00");
+    for (key, value) in [
+        ("hostname", "A Minecraft Server"),
+        ("gametype", "SMP"),
+        ("version", "1.21.1"),
+        ("plugins", "Paper on 1.21.1: LuckPerms 5.4"),
+        ("map", "world"),
+        ("numplayers", "2"),
+        ("maxplayers", "20"),
+        ("hostport", "25565"),
+    ] {
+        packet.extend_from_slice(key.as_bytes());
+        packet.push(0);
+        packet.extend_from_slice(value.as_bytes());
+        packet.push(0);
+    }
+    packet.push(0);
+    packet.extend_from_slice(b"\x01player_\x00\x00");
+    packet.extend_from_slice(b"Alex\x00Steve\x00\x00");
+    packet
+}
+
+#[test]
+fn test_query_packets() {
+    let session = session_id(0xFFFF_FFFF);
+    assert_eq!(session, 0x0F0F_0F0F);
+    assert_eq!(
+        handshake_request(session),
+        vec![0xFE, 0xFD, 0x09, 0x0F, 0x0F, 0x0F, 0x0F]
+    );
+
+    let mut response = vec![0x09];
+    response.extend_from_slice(&session.to_be_bytes());
+    response.extend_from_slice(b"-9513307\x00");
+    assert_eq!(parse_handshake_response(&response, session).unwrap(), -9513307);
+    assert!(parse_handshake_response(&response, 1).is_err(), "session must match");
+
+    assert_eq!(full_stat_request(session, 1).len(), 15);
+
+    let stat = parse_full_stat(&full_stat_packet(session), session).unwrap();
+    assert_eq!(stat.motd, "A Minecraft Server");
+    assert_eq!(stat.plugins, "Paper on 1.21.1: LuckPerms 5.4");
+    assert_eq!((stat.players_online, stat.players_max), (2, 20));
+    assert_eq!(stat.host_port, Some(25565));
+    assert_eq!(stat.players, vec!["Alex", "Steve"]);
+}
+
+#[tokio::test]
+async fn test_query_server_round_trip() {
+    let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let port = socket.local_addr().unwrap().port();
+    tokio::spawn(async move {
+        let mut buf = [0u8; 64];
+        let (_, peer) = socket.recv_from(&mut buf).await.unwrap();
+        let session = u32::from_be_bytes([buf[3], buf[4], buf[5], buf[6]]);
+        let mut response = vec![0x09];
+        response.extend_from_slice(&session.to_be_bytes());
+        response.extend_from_slice(b"12345\x00");
+        socket.send_to(&response, peer).await.unwrap();
+
+        let (len, peer) = socket.recv_from(&mut buf).await.unwrap();
+        assert_eq!(len, 15);
+        assert_eq!(i32::from_be_bytes([buf[7], buf[8], buf[9], buf[10]]), 12345);
+        socket.send_to(&full_stat_packet(session), peer).await.unwrap();
+    });
+
+    let stat = query_server("127.0.0.1", port, Duration::from_secs(5)).await.unwrap();
+    assert_eq!(stat.players, vec!["Alex", "Steve"]);
+}
@@ -1,4 +1,4 @@
-use mc_server_wrapper_core::server::{ServerHandle, ServerStatus};
+use mc_server_wrapper_core::server::{IdleTracker, ServerEvent, ServerHandle, ServerStatus, active_player_count};
 use mc_server_wrapper_core::config::ServerConfig;
 use tempfile::tempdir;
 use tokio::time::{sleep, Duration, timeout};
@@ -313,3 +313,95 @@ done
     
     handle.stop().await.expect("Failed to stop server");
 }
+
+#[test]
+fn test_idle_tracker_resets_on_activity() {
+    let start = std::time::Instant::now();
+    let mut tracker = IdleTracker::new(Duration::from_secs(60));
+    assert!(!tracker.observe(true, 0, start));
+    assert!(!tracker.observe(true, 0, start + Duration::from_secs(30)));
+    // A player joining restarts the clock
+    assert!(!tracker.observe(true, 1, start + Duration::from_secs(40)));
+    assert!(!tracker.observe(true, 0, start + Duration::from_secs(50)));
+    assert!(!tracker.observe(true, 0, start + Duration::from_secs(100)));
+    assert!(tracker.observe(true, 0, start + Duration::from_secs(110)));
+    // Not running never counts as idle
+    assert!(!tracker.observe(false, 0, start + Duration::from_secs(500)));
+
+    let online = ["Steve".to_string(), "AFKBot".to_string()].into_iter().collect();
+    assert_eq!(active_player_count(&online, &["afkbot".to_string()]), 1);
+    assert_eq!(active_player_count(&online, &[]), 2);
+}
+
+#[tokio::test]
+async fn test_idle_server_is_auto_stopped() {
+    let dir = tempdir().unwrap();
+    let working_dir = dir.path().to_path_buf();
+
+    #[cfg(target_os = "windows")]
+    let (script_name, script_content) = ("mock_server.bat", r#"@echo off
+echo [Server thread/INFO]: Done (1.23s)! For help, type "help"
+:loop
+set /p cmd=
+if "%cmd%"=="stop" exit /b 0
+goto loop
+"#);
+
+    #[cfg(not(target_os = "windows"))]
+    let (script_name, script_content) = ("mock_server.sh", r#"#!/bin/sh
+echo '[Server thread/INFO]: Done (1.23s)! For help, type "help"'
+while read cmd; do
+    if [ "$cmd" = "stop" ]; then
+        exit 0
+    fi
+done
+"#);
+
+    let script_path = working_dir.join(script_name);
+    fs::write(&script_path, script_content).unwrap();
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = fs::metadata(&script_path).unwrap().permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&script_path, perms).unwrap();
+    }
+
+    let config = ServerConfig {
+        name: "Test Server Idle".to_string(),
+        run_script: Some(script_path.to_string_lossy().to_string()),
+        working_dir: working_dir.clone(),
+        idle_timeout: 1,
+        ..Default::default()
+    };
+
+    let handle = ServerHandle::new(config);
+    let mut events = handle.subscribe_events();
+    handle.start().await.expect("Failed to start server");
+
+    let mut statuses = Vec::new();
+    let idle_secs = timeout(Duration::from_secs(10), async {
+        loop {
+            match events.recv().await.unwrap() {
+                ServerEvent::AutoStopped { idle_secs } => break idle_secs,
+                ServerEvent::StatusChanged { status } => statuses.push(status),
+            }
+        }
+    })
+    .await
+    .expect("Server was not auto-stopped");
+    assert_eq!(idle_secs, 1);
+    // Status changes go out on the same stream, without polling
+    assert_eq!(statuses, [ServerStatus::Starting, ServerStatus::Running, ServerStatus::Stopping]);
+
+    let mut stopped = false;
+    for _ in 0..50 {
+        if handle.get_status().await == ServerStatus::Stopped {
+            stopped = true;
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+    assert!(stopped, "Server should exit gracefully after an idle stop");
+}
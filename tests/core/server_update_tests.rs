@@ -0,0 +1,72 @@
+use anyhow::Result;
+use mc_server_wrapper_core::app_config::GlobalConfigManager;
+use mc_server_wrapper_core::database::Database;
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::manager::ServerManager;
+use mc_server_wrapper_core::mod_loaders::is_newer_build;
+use mc_server_wrapper_core::mod_loaders::paper::PaperBuilds;
+use mc_server_wrapper_core::mod_loaders::BuildChannel;
+use serde_json::json;
+use std::sync::Arc;
+use tempfile::tempdir;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[test]
+fn test_is_newer_build() {
+    assert!(is_newer_build("120", Some("119")));
+    assert!(!is_newer_build("119", Some("119")));
+    assert!(!is_newer_build("99", Some("119")));
+    assert!(is_newer_build("1", None));
+
+    // Non-numeric builds fall back to inequality
+    assert!(is_newer_build("b2", Some("b1")));
+    assert!(!is_newer_build("b1", Some("b1")));
+}
+
+#[test]
+fn test_paper_build_channels() {
+    let json = r#"{"builds": [
+        {"build": 100, "channel": "default"},
+        {"build": 101, "channel": "experimental"},
+        {"build": 102}
+    ]}"#;
+    let builds: PaperBuilds = serde_json::from_str(json).unwrap();
+    let channels: Vec<BuildChannel> = builds.builds.iter().map(|b| b.channel).collect();
+    assert_eq!(
+        channels,
+        vec![BuildChannel::Default, BuildChannel::Experimental, BuildChannel::Default]
+    );
+}
+
+#[tokio::test]
+async fn test_check_server_update_compares_builds() -> Result<()> {
+    let api = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/projects/paper/versions/1.21.1/builds"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"builds": [
+            {"build": 119, "channel": "default", "changes": []},
+            {"build": 120, "channel": "default", "changes": [{"commit": "abc", "summary": "Fix things"}]}
+        ]})))
+        .mount(&api)
+        .await;
+
+    let dir = tempdir()?;
+    let db = Arc::new(Database::new(dir.path().join("test.db")).await?);
+    let instance_manager = Arc::new(InstanceManager::new(dir.path(), db).await?);
+    let config_manager = Arc::new(GlobalConfigManager::new(dir.path().join("app_settings.json")));
+    let manager = ServerManager::new(Arc::clone(&instance_manager), config_manager)
+        .with_loader_api_urls(format!("{}/v2", api.uri()), format!("{}/v2", api.uri()));
+
+    // Remote build is newer, the same, and older than the installed one
+    for (name, installed, expected) in [("Behind", "119", true), ("Current", "120", false), ("Ahead", "121", false)] {
+        let instance = instance_manager
+            .create_instance_full(name, "1.21.1", Some("paper".to_string()), Some(installed.to_string()))
+            .await?;
+        let update = manager.check_server_update(instance.id).await?;
+        assert_eq!(update.latest_build, "120");
+        assert_eq!(update.update_available, expected, "installed build {}", installed);
+        assert_eq!(update.changelog.len(), usize::from(expected));
+    }
+    Ok(())
+}
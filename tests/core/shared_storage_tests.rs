@@ -0,0 +1,107 @@
+use anyhow::Result;
+use mc_server_wrapper_core::app_config::GlobalConfigManager;
+use mc_server_wrapper_core::artifacts::{ArtifactStore, HashAlgorithm, ProvisionMode};
+use mc_server_wrapper_core::database::Database;
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::manager::{SHARED_LIBRARIES_FILE, ServerManager, shared_library_hashes};
+use std::sync::Arc;
+use tempfile::tempdir;
+
+#[tokio::test]
+async fn test_share_file_links_duplicates_to_one_copy() -> Result<()> {
+    let dir = tempdir()?;
+    let store = ArtifactStore::new(dir.path().join("store"));
+    let first = dir.path().join("a").join("lib.jar");
+    let second = dir.path().join("b").join("lib.jar");
+    for path in [&first, &second] {
+        std::fs::create_dir_all(path.parent().unwrap())?;
+        std::fs::write(path, vec![7u8; 4096])?;
+    }
+
+    // Filesystems with reflinks clone the file instead of hard linking it
+    let shared = store.share_file(&first, ProvisionMode::Hardlink).await?;
+    assert!(matches!(shared.mode, ProvisionMode::Hardlink | ProvisionMode::Reflink));
+    assert_eq!(shared.bytes_saved, 0, "the first copy moves into the store");
+    assert!(store.exists(&shared.hash, HashAlgorithm::Sha1).await);
+
+    let shared = store.share_file(&second, ProvisionMode::Hardlink).await?;
+    assert_eq!(shared.bytes_saved, 4096);
+    assert_eq!(std::fs::read(&second)?, vec![7u8; 4096]);
+
+    if shared.mode == ProvisionMode::Hardlink {
+        // Hard links can only be replaced, so writing through one instance can't change the others
+        assert!(std::fs::metadata(&second)?.permissions().readonly());
+        let again = store.share_file(&second, ProvisionMode::Hardlink).await?;
+        assert_eq!(again.bytes_saved, 0, "already linked files aren't counted twice");
+    }
+
+    // A copy of a shared artifact belongs to its instance alone
+    let copy = dir.path().join("d").join("lib.jar");
+    store.provision_as(&shared.hash, HashAlgorithm::Sha1, &copy, ProvisionMode::Copy).await?;
+    assert!(!std::fs::metadata(&copy)?.permissions().readonly());
+
+    let untouched = dir.path().join("c.jar");
+    std::fs::write(&untouched, b"other")?;
+    let copied = store.share_file(&untouched, ProvisionMode::Copy).await?;
+    assert_eq!(copied.mode, ProvisionMode::Copy);
+    assert!(!store.exists(&copied.hash, HashAlgorithm::Sha1).await);
+    Ok(())
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_provision_as_symlink_points_at_the_store() -> Result<()> {
+    let dir = tempdir()?;
+    let store = ArtifactStore::new(dir.path().join("store"));
+    let source = dir.path().join("source.jar");
+    std::fs::write(&source, b"hello world")?;
+    let hash = "2aae6c35c94fcfb415dbe95f408b9ce91ee846ed";
+    let artifact = store.add_artifact(&source, hash, HashAlgorithm::Sha1).await?;
+
+    let target = dir.path().join("instance").join("server.jar");
+    let used = store.provision_as(hash, HashAlgorithm::Sha1, &target, ProvisionMode::Symlink).await?;
+    assert_eq!(used, ProvisionMode::Symlink);
+    assert_eq!(std::fs::read_link(&target)?, artifact);
+    assert_eq!(std::fs::read(&target)?, b"hello world");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deduplicate_libraries_across_instances() -> Result<()> {
+    let dir = tempdir()?;
+    let db = Arc::new(Database::new(dir.path().join("test.db")).await?);
+    let instance_manager = Arc::new(InstanceManager::new(dir.path().join("instances"), db).await?);
+    let config_manager = Arc::new(GlobalConfigManager::new(dir.path().join("config.json")));
+    let server_manager = ServerManager::new(Arc::clone(&instance_manager), config_manager);
+
+    let mut paths = Vec::new();
+    for name in ["Forge A", "Forge B"] {
+        let instance = instance_manager.create_instance_full(name, "1.20.1", None, None).await?;
+        let lib = instance.path.join("libraries/com/example/lib/1.0/lib-1.0.jar");
+        std::fs::create_dir_all(lib.parent().unwrap())?;
+        std::fs::write(&lib, vec![1u8; 10_000])?;
+        std::fs::write(lib.with_extension("txt"), b"not a jar")?;
+        paths.push(instance.path);
+    }
+
+    let report = server_manager.deduplicate_libraries().await?;
+    assert_eq!(report.mode, ProvisionMode::Hardlink);
+    assert_eq!(report.instances, 2);
+    assert_eq!(report.files_linked, 2);
+    assert_eq!(report.bytes_saved, 10_000);
+    assert!(report.errors.is_empty(), "{:?}", report.errors);
+
+    for path in &paths {
+        assert!(path.join(SHARED_LIBRARIES_FILE).exists());
+        assert_eq!(shared_library_hashes(path).await.len(), 1);
+    }
+
+    // Maintenance keeps the shared artifact even though no server.jar or mod refers to it
+    server_manager.perform_maintenance().await?;
+    let hash = shared_library_hashes(&paths[0]).await.into_iter().next().unwrap();
+    assert!(server_manager.get_artifact_store().exists(&hash, HashAlgorithm::Sha1).await);
+
+    let report = server_manager.deduplicate_libraries().await?;
+    assert_eq!(report.bytes_saved, 0);
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anyhow::Result;
+use mc_server_wrapper_core::app_config::GlobalConfigManager;
+use mc_server_wrapper_core::database::Database;
+use mc_server_wrapper_core::events::EventBus;
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::manager::ServerManager;
+use mc_server_wrapper_core::shell::{MAX_COMMAND_LEN, SHELL_LOG_PREFIX, sandbox_env, validate_command};
+use mc_server_wrapper_core::tasks::TaskState;
+use mc_server_wrapper_core::timeline::TimelineKind;
+use std::sync::Arc;
+use std::time::Duration;
+use tempfile::tempdir;
+
+#[test]
+fn test_validate_command() {
+    assert_eq!(validate_command("  ls -la \n").unwrap(), "ls -la");
+    assert!(validate_command("   ").is_err());
+    assert!(validate_command(&"x".repeat(MAX_COMMAND_LEN + 1)).is_err());
+    assert!(validate_command("echo \0").is_err());
+}
+
+#[test]
+fn test_sandbox_env_drops_secrets() {
+    let vars = vec![
+        ("PATH".to_string(), "/usr/bin".to_string()),
+        ("CURSEFORGE_API_KEY".to_string(), "secret".to_string()),
+        ("Path".to_string(), "C:\\Windows".to_string()),
+    ];
+    let kept: Vec<String> = sandbox_env(vars).into_iter().map(|(k, _)| k).collect();
+    assert_eq!(kept, vec!["PATH".to_string(), "Path".to_string()]);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_shell_command_runs_in_instance_dir() -> Result<()> {
+    let dir = tempdir()?;
+    let db = Arc::new(Database::new(dir.path().join("test.db")).await?);
+    let instance_manager = Arc::new(InstanceManager::new(dir.path(), db).await?);
+    let config_manager = Arc::new(GlobalConfigManager::new(dir.path().join("app_settings.json")));
+    let manager = Arc::new(ServerManager::new(Arc::clone(&instance_manager), Arc::clone(&config_manager)));
+    let instance = instance_manager.create_instance_full("Shell", "1.21.1", Some("vanilla".to_string()), None).await?;
+
+    // Off until enabled in the settings
+    assert!(manager.run_shell_command(instance.id, "true").await.is_err());
+    let mut settings = config_manager.load().await?;
+    settings.shell.enabled = true;
+    config_manager.save(&settings).await?;
+
+    std::fs::write(instance.path.join("marker.txt"), "here\n")?;
+    let mut logs = manager.get_or_create_server(instance.id).await?.subscribe_logs();
+    let task_id = manager.run_shell_command(instance.id, "cat marker.txt; echo \"$INSTANCE_DIR\"").await?;
+
+    let tasks = manager.get_task_manager();
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while tasks.get(task_id).map(|t| t.state) == Some(TaskState::Running) {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+    })
+    .await?;
+    assert_eq!(tasks.get(task_id).unwrap().state, TaskState::Completed);
+
+    let mut lines = Vec::new();
+    while let Ok(line) = logs.try_recv() {
+        lines.push(line);
+    }
+    assert!(lines.iter().all(|l| l.starts_with(SHELL_LOG_PREFIX)));
+    assert!(lines.contains(&format!("{} here", SHELL_LOG_PREFIX)));
+    assert!(lines.contains(&format!("{} {}", SHELL_LOG_PREFIX, instance.path.display())));
+
+    let timeline = instance_manager.get_instance_timeline(instance.id, 10, &EventBus::new()).await?;
+    assert!(timeline.iter().any(|e| e.kind == TimelineKind::ShellCommand && e.summary.contains("cat marker.txt")));
+
+    // Failures are reported through the task
+    let failing = manager.run_shell_command(instance.id, "exit 3").await?;
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while tasks.get(failing).map(|t| t.state) == Some(TaskState::Running) {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+    })
+    .await?;
+    let info = tasks.get(failing).unwrap();
+    assert_eq!(info.state, TaskState::Failed);
+    assert!(info.error.unwrap_or_default().contains("code 3"));
+    Ok(())
+}
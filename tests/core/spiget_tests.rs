@@ -1,11 +1,20 @@
 use wiremock::{MockServer, Mock, ResponseTemplate};
 use wiremock::matchers::{method, path};
 use mc_server_wrapper_core::plugins::spiget::SpigetClient;
+use mc_server_wrapper_core::plugins::spiget::dependencies::ExternalDependency;
 use mc_server_wrapper_core::plugins::types::{SearchOptions, PluginProvider};
 use mc_server_wrapper_core::cache::CacheManager;
 use std::sync::Arc;
 use serde_json::json;
 
+fn plugin_jar() -> Vec<u8> {
+    use std::io::Write;
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    zip.start_file("plugin.yml", zip::write::SimpleFileOptions::default()).unwrap();
+    zip.write_all(b"name: MyPlugin\n").unwrap();
+    zip.finish().unwrap().into_inner()
+}
+
 #[tokio::test]
 async fn test_spiget_search_parsing() {
     let mock_server = MockServer::start().await;
@@ -120,10 +129,11 @@ async fn test_spiget_download_resource() {
         .mount(&mock_server)
         .await;
 
+    let jar = plugin_jar();
     Mock::given(method("GET"))
         .and(path("/resources/12345/download"))
         .respond_with(ResponseTemplate::new(200)
-            .set_body_raw(vec![0u8; 100], "application/java-archive")
+            .set_body_raw(jar.clone(), "application/java-archive")
             .insert_header("Content-Disposition", "attachment; filename=\"my-actual-plugin.jar\""))
         .mount(&mock_server)
         .await;
@@ -132,7 +142,7 @@ async fn test_spiget_download_resource() {
         .and(path("/resources/12345/download"))
         .respond_with(ResponseTemplate::new(200)
             .insert_header("Content-Type", "application/java-archive")
-            .insert_header("Content-Length", "100")
+            .insert_header("Content-Length", jar.len().to_string().as_str())
             .insert_header("Content-Disposition", "attachment; filename=\"my-actual-plugin.jar\""))
         .mount(&mock_server)
         .await;
@@ -186,3 +196,47 @@ async fn test_spiget_network_failure() {
     let result = client.get_project("12345").await;
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn test_spiget_external_dependencies() {
+    let mock_server = MockServer::start().await;
+    let cache = Arc::new(CacheManager::default());
+    let client = SpigetClient::with_base_url(mock_server.uri(), cache);
+
+    Mock::given(method("GET"))
+        .and(path("/resources/12345/dependencies"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "id": 34315, "name": "Vault" },
+            { "name": "LuckPerms", "external": true },
+            { "name": "PlaceholderAPI", "external": true, "soft": true }
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/resources/34315"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 34315,
+            "name": "Vault",
+            "tag": "Vault is a Permissions, Chat, & Economy API",
+            "downloads": 1000,
+            "author": { "id": 1 }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let deps = client.get_dependencies("12345").await.unwrap();
+    assert_eq!(deps.len(), 1);
+    assert_eq!(deps[0].project.title, "Vault");
+
+    assert_eq!(deps[0].dependency_type, "required");
+
+    let external = client.get_external_dependencies("12345").await.unwrap();
+    assert_eq!(
+        external,
+        vec![
+            ExternalDependency { name: "LuckPerms".to_string(), dependency_type: "required".to_string() },
+            ExternalDependency { name: "PlaceholderAPI".to_string(), dependency_type: "optional".to_string() },
+        ]
+    );
+}
@@ -0,0 +1,44 @@
+use mc_server_wrapper_core::app_config::StartLimits;
+use mc_server_wrapper_core::instance::InstanceSettings;
+use mc_server_wrapper_core::manager::check_start_limits;
+
+#[test]
+fn test_start_limits_disabled_by_default() {
+    let limits = StartLimits::default();
+    assert!(check_start_limits(&limits, &[8192, 8192, 8192], 16384).is_ok());
+}
+
+#[test]
+fn test_max_running_instances() {
+    let limits = StartLimits {
+        max_running_instances: 2,
+        ..Default::default()
+    };
+    assert!(check_start_limits(&limits, &[2048], 2048).is_ok());
+    let err = check_start_limits(&limits, &[2048, 2048], 2048).unwrap_err();
+    assert!(err.to_string().contains("2 servers are already running"));
+}
+
+#[test]
+fn test_ram_budget() {
+    let limits = StartLimits {
+        ram_budget_mb: 8192,
+        ..Default::default()
+    };
+    assert!(check_start_limits(&limits, &[4096], 4096).is_ok());
+    let err = check_start_limits(&limits, &[4096, 2048], 4096).unwrap_err();
+    assert!(err.to_string().contains("10240 MB of the 8192 MB"), "{}", err);
+}
+
+#[test]
+fn test_max_memory_mb_units() {
+    let settings = |max_ram, unit: &str| InstanceSettings {
+        max_ram,
+        max_ram_unit: unit.to_string(),
+        ..Default::default()
+    };
+    assert_eq!(settings(4, "GB").max_memory_mb(), 4096);
+    assert_eq!(settings(4, "G").max_memory_mb(), 4096);
+    assert_eq!(settings(3072, "MB").max_memory_mb(), 3072);
+    assert_eq!(settings(3072, "M").max_memory_mb(), 3072);
+}
@@ -0,0 +1,69 @@
+use anyhow::Result;
+use mc_server_wrapper_core::storage_mode::{
+    DataLocations, MIGRATION_FILE, SETTINGS_FILE, StorageMode, migrate_app_data, rebase_path,
+};
+use std::path::Path;
+use tempfile::tempdir;
+
+#[tokio::test]
+async fn test_detect_and_switch_storage_mode() -> Result<()> {
+    let exe_dir = tempdir()?;
+    let app_data = tempdir()?;
+    let installed = app_data.path().join("mc-server-wrapper");
+    let locations = DataLocations::new(exe_dir.path(), &installed);
+    assert_eq!(locations.detect(), None);
+    assert!(locations.portable_available());
+
+    std::fs::write(exe_dir.path().join(SETTINGS_FILE), "{}")?;
+    std::fs::create_dir_all(exe_dir.path().join("server/abc"))?;
+    std::fs::write(exe_dir.path().join("server/abc/server.jar"), "jar")?;
+    assert_eq!(locations.detect(), Some(StorageMode::Portable));
+
+    // Choosing the current mode withdraws a request
+    locations.request_migration(StorageMode::Portable, StorageMode::Installed).await?;
+    assert_eq!(locations.pending_migration(StorageMode::Portable).await, Some(StorageMode::Installed));
+    locations.request_migration(StorageMode::Portable, StorageMode::Portable).await?;
+    assert_eq!(locations.pending_migration(StorageMode::Portable).await, None);
+
+    locations.request_migration(StorageMode::Portable, StorageMode::Installed).await?;
+    let switched = locations.run_pending_migration(StorageMode::Portable).await?;
+    assert_eq!(switched, Some(StorageMode::Installed));
+    assert!(installed.join("server/abc/server.jar").exists());
+    assert!(!exe_dir.path().join("server").exists());
+    assert!(!exe_dir.path().join(MIGRATION_FILE).exists());
+    assert_eq!(locations.detect(), Some(StorageMode::Installed));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_migrate_app_data_refuses_existing_target() -> Result<()> {
+    let from = tempdir()?;
+    let to = tempdir()?;
+    std::fs::write(from.path().join(SETTINGS_FILE), "{}")?;
+    std::fs::create_dir_all(from.path().join("backups"))?;
+    std::fs::create_dir_all(to.path().join("backups"))?;
+    std::fs::write(from.path().join("notes.txt"), "not ours")?;
+
+    assert!(migrate_app_data(from.path(), to.path()).await.is_err());
+    assert!(from.path().join(SETTINGS_FILE).exists());
+    assert!(!to.path().join(SETTINGS_FILE).exists());
+
+    std::fs::remove_dir(to.path().join("backups"))?;
+    let moved = migrate_app_data(from.path(), to.path()).await?;
+    assert_eq!(moved, vec![SETTINGS_FILE.to_string(), "backups".to_string()]);
+    // Files the app didn't create stay where they are
+    assert!(from.path().join("notes.txt").exists());
+    Ok(())
+}
+
+#[test]
+fn test_rebase_path() {
+    let old = Path::new("/opt/wrapper");
+    let new = Path::new("/home/me/.local/share/wrapper");
+    assert_eq!(
+        rebase_path(Path::new("/opt/wrapper/server/abc"), old, new),
+        Some(new.join("server/abc"))
+    );
+    assert_eq!(rebase_path(Path::new("/mnt/disk/abc"), old, new), None);
+    assert_eq!(rebase_path(Path::new("/opt/wrapper2/abc"), old, new), None);
+}
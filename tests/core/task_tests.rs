@@ -0,0 +1,80 @@
+use anyhow::anyhow;
+use mc_server_wrapper_core::tasks::{TaskKind, TaskManager, TaskState};
+use uuid::Uuid;
+
+#[test]
+fn test_task_lifecycle_and_events() {
+    let manager = TaskManager::new();
+    let mut events = manager.subscribe();
+    let instance_id = Uuid::new_v4();
+
+    let task = manager.start(TaskKind::Backup, "Backing up", Some(instance_id));
+    let id = task.id();
+    task.progress(5, 10, "Halfway");
+    assert_eq!(manager.get(id).unwrap().current, 5);
+    assert!(!manager.cancel(id), "tasks are not cancellable unless they ask for a flag");
+
+    let result = task.finish(Ok::<_, anyhow::Error>(42));
+    assert_eq!(result.unwrap(), 42);
+
+    let info = manager.get(id).unwrap();
+    assert_eq!(info.state, TaskState::Completed);
+    assert_eq!((info.current, info.total), (10, 10));
+    assert!(info.finished_at.is_some());
+
+    let mut states = Vec::new();
+    while let Ok(event) = events.try_recv() {
+        assert_eq!(event.instance_id, Some(instance_id));
+        states.push(event.state);
+    }
+    assert_eq!(states.first(), Some(&TaskState::Running));
+    assert_eq!(states.last(), Some(&TaskState::Completed));
+}
+
+#[test]
+fn test_cancel_and_failure_states() {
+    let manager = TaskManager::new();
+
+    let task = manager.start(TaskKind::Import, "Importing", None);
+    let flag = task.cancel_flag();
+    assert!(manager.cancel(task.id()));
+    assert!(flag.is_cancelled());
+    let id = task.id();
+    let _ = task.finish::<()>(Err(anyhow!("Operation cancelled")));
+    assert_eq!(manager.get(id).unwrap().state, TaskState::Cancelled);
+    assert!(!manager.cancel(id));
+
+    let failed = manager.start(TaskKind::Download, "Downloading", None);
+    let failed_id = failed.id();
+    let _ = failed.finish::<()>(Err(anyhow!("connection reset")));
+    let info = manager.get(failed_id).unwrap();
+    assert_eq!(info.state, TaskState::Failed);
+    assert_eq!(info.error.as_deref(), Some("connection reset"));
+
+    let dropped_id = manager.start(TaskKind::Install, "Installing", None).id();
+    assert_eq!(manager.get(dropped_id).unwrap().state, TaskState::Failed);
+    assert_eq!(manager.list().len(), 3);
+}
+
+#[test]
+fn test_pause_and_resume() {
+    let manager = TaskManager::new();
+
+    let task = manager.start(TaskKind::Pregen, "Pregenerating", None);
+    assert!(!manager.set_paused(task.id(), true), "tasks are not pausable unless they ask for a flag");
+
+    let flag = task.pause_flag();
+    assert!(manager.set_paused(task.id(), true));
+    assert!(flag.is_paused());
+    assert!(manager.get(task.id()).unwrap().paused);
+
+    assert!(manager.set_paused(task.id(), false));
+    assert!(!flag.is_paused());
+
+    let id = task.id();
+    manager.set_paused(id, true);
+    task.finish(Ok::<_, anyhow::Error>(())).unwrap();
+    let info = manager.get(id).unwrap();
+    assert!(!info.paused, "finished tasks are never reported as paused");
+    assert!(!manager.set_paused(id, false));
+}
@@ -0,0 +1,95 @@
+use anyhow::Result;
+use mc_server_wrapper_core::app_config::GlobalConfigManager;
+use mc_server_wrapper_core::database::Database;
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::manager::ServerManager;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+use tempfile::tempdir;
+
+fn create_plugin_jar(path: &Path) {
+    let file = std::fs::File::create(path).unwrap();
+    let mut zip = zip::ZipWriter::new(file);
+    zip.start_file("plugin.yml", zip::write::SimpleFileOptions::default()).unwrap();
+    zip.write_all(b"name: Manual\nversion: 1.0\nmain: dev.example.Manual\n").unwrap();
+    zip.finish().unwrap();
+}
+
+#[tokio::test]
+async fn test_save_list_and_delete_template() -> Result<()> {
+    let dir = tempdir()?;
+    let db = Arc::new(Database::new(dir.path().join("test.db")).await?);
+    let manager = InstanceManager::new(dir.path(), db).await?;
+
+    let mut instance = manager.create_instance_full("Source", "1.20.1", Some("paper".to_string()), None).await?;
+    instance.settings.max_ram = 6;
+    instance.settings.port = 25570;
+    instance.settings.lineage_id = Some(instance.id);
+    manager.update_settings(instance.id, None, instance.settings.clone()).await?;
+    std::fs::create_dir_all(instance.path.join("plugins"))?;
+    create_plugin_jar(&instance.path.join("plugins/Manual.jar"));
+    std::fs::write(instance.path.join("bukkit.yml"), "settings:\n  allow-end: false\n")?;
+
+    let saved = manager
+        .save_instance_as_template(instance.id, " Survival ", None, &["bukkit.yml".to_string()])
+        .await?;
+    assert_eq!(saved.name, "Survival");
+    assert_eq!(saved.mod_loader.as_deref(), Some("paper"));
+    assert_eq!(saved.settings.max_ram, 6);
+    assert!(saved.settings.lineage_id.is_none());
+    assert_eq!(saved.skipped, vec!["Manual.jar"]);
+    assert_eq!(saved.config_files[0].rel_path, "bukkit.yml");
+
+    // Saving under the same name replaces the template instead of adding another
+    let resaved = manager.save_instance_as_template(instance.id, "Survival", None, &[]).await?;
+    assert_eq!(resaved.id, saved.id);
+    let templates = manager.list_templates().await?;
+    assert_eq!(templates.len(), 1);
+    assert!(templates[0].config_files.is_empty());
+
+    assert!(manager.save_instance_as_template(instance.id, "Bad", None, &["../escape.yml".to_string()]).await.is_err());
+
+    manager.delete_template(saved.id).await?;
+    assert!(manager.get_template(saved.id).await?.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_instance_from_template() -> Result<()> {
+    let dir = tempdir()?;
+    let instances_dir = dir.path().join("instances");
+    std::fs::create_dir_all(&instances_dir)?;
+    let db = Arc::new(Database::new(instances_dir.join("test.db")).await?);
+    let instance_manager = Arc::new(InstanceManager::new(&instances_dir, db).await?);
+    let config_manager = GlobalConfigManager::new(dir.path().join("config.json"));
+    let manager = ServerManager::new(Arc::clone(&instance_manager), Arc::new(config_manager));
+
+    let mut source = manager.create_instance_full("Source", "1.20.1", Some("paper".to_string()), None).await?;
+    source.settings.max_ram = 8;
+    source.settings.port = 25570;
+    instance_manager.update_settings(source.id, None, source.settings.clone()).await?;
+    std::fs::create_dir_all(source.path.join("config"))?;
+    std::fs::write(source.path.join("config/paper-global.yml"), "chunk-loading: {}\n")?;
+    std::fs::create_dir_all(source.path.join("world"))?;
+    std::fs::write(source.path.join("world/level.dat"), b"data")?;
+
+    let template = instance_manager
+        .save_instance_as_template(source.id, "Paper base", None, &["config/paper-global.yml".to_string()])
+        .await?;
+    let report = manager.create_instance_from_template(template.id, "Fresh").await?;
+
+    let created = report.instance;
+    assert_ne!(created.id, source.id);
+    assert_eq!(created.name, "Fresh");
+    assert_eq!(created.mod_loader.as_deref(), Some("paper"));
+    assert_eq!(created.settings.max_ram, 8);
+    assert_ne!(created.settings.port, 25570);
+    assert!(report.failed.is_empty());
+    assert_eq!(
+        std::fs::read_to_string(created.path.join("config/paper-global.yml"))?,
+        "chunk-loading: {}\n"
+    );
+    assert!(!created.path.join("world").exists());
+    Ok(())
+}
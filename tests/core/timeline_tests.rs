@@ -0,0 +1,97 @@
+use anyhow::Result;
+use chrono::Utc;
+use mc_server_wrapper_core::database::Database;
+use mc_server_wrapper_core::events::{EventBus, EventChannel};
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::tasks::{TaskInfo, TaskKind, TaskState};
+use mc_server_wrapper_core::timeline::TimelineKind;
+use uuid::Uuid;
+use std::sync::Arc;
+use tempfile::tempdir;
+
+async fn manager(dir: &std::path::Path) -> Result<InstanceManager> {
+    let db = Arc::new(Database::new(dir.join("test.db")).await?);
+    InstanceManager::new(dir.join("instances"), db).await
+}
+
+#[tokio::test]
+async fn test_timeline_merges_events_and_crash_reports_newest_first() -> Result<()> {
+    let dir = tempdir()?;
+    let manager = manager(dir.path()).await?;
+    let instance = manager.create_instance_full("Survival", "1.20.1", None, None).await?;
+
+    manager
+        .record_timeline_event(instance.id, TimelineKind::Backup, "Backup nightly", None)
+        .await?;
+    let mut settings = instance.settings.clone();
+    settings.max_ram = 8;
+    settings.port = 25570;
+    manager.update_settings(instance.id, None, settings.clone()).await?;
+    // Saving unchanged settings isn't a change
+    manager.update_settings(instance.id, None, settings).await?;
+
+    let reports = instance.path.join("crash-reports");
+    std::fs::create_dir_all(&reports)?;
+    std::fs::write(
+        reports.join("crash-2024-01-01_00.00.00-server.txt"),
+        "---- Minecraft Crash Report ----\nDescription: Exception in server tick loop\n",
+    )?;
+    // Dated by its header rather than by when it was found
+    std::fs::write(
+        reports.join("crash-2020-05-01_12.00.00-server.txt"),
+        "---- Minecraft Crash Report ----\nTime: 2020-05-01 12:00:00\nDescription: Ticking entity\n",
+    )?;
+
+    let bus = EventBus::new();
+    let timeline = manager.get_instance_timeline(instance.id, 10, &bus).await?;
+    let kinds: Vec<TimelineKind> = timeline.iter().map(|e| e.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![TimelineKind::CrashReport, TimelineKind::ConfigChange, TimelineKind::Backup, TimelineKind::CrashReport]
+    );
+    assert_eq!(timeline[3].details.as_deref(), Some("Ticking entity"));
+    let details = timeline[1].details.as_deref().unwrap_or_default();
+    assert!(details.contains("max_ram") && details.contains("port"), "{}", details);
+
+    assert_eq!(manager.get_instance_timeline(instance.id, 1, &bus).await?.len(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_timeline_includes_console_commands_and_failed_tasks() -> Result<()> {
+    let dir = tempdir()?;
+    let manager = manager(dir.path()).await?;
+    let instance = manager.create_instance_full("Survival", "1.20.1", None, None).await?;
+    manager.record_command(instance.id, "gamerule keepInventory true").await?;
+
+    let bus = EventBus::new();
+    let mut task = TaskInfo {
+        id: Uuid::new_v4(),
+        kind: TaskKind::Install,
+        title: "Installing Forge".to_string(),
+        instance_id: Some(instance.id),
+        current: 0,
+        total: 0,
+        message: String::new(),
+        state: TaskState::Running,
+        error: None,
+        cancellable: false,
+        pausable: false,
+        paused: false,
+        started_at: Utc::now(),
+        finished_at: None,
+    };
+    bus.publish(Some(instance.id), EventChannel::Task, &task);
+    task.state = TaskState::Failed;
+    task.error = Some("Installer exited with code 1".to_string());
+    task.finished_at = Some(Utc::now());
+    bus.publish(Some(instance.id), EventChannel::Task, &task);
+
+    let timeline = manager.get_instance_timeline(instance.id, 10, &bus).await?;
+    assert_eq!(timeline.len(), 2);
+    assert_eq!(timeline[0].kind, TimelineKind::TaskFailed);
+    assert_eq!(timeline[0].details.as_deref(), Some("Installer exited with code 1"));
+    assert_eq!(timeline[1].kind, TimelineKind::ConsoleCommand);
+    assert_eq!(timeline[1].summary, "Ran /gamerule keepInventory true");
+    Ok(())
+}
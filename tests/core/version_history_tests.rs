@@ -0,0 +1,104 @@
+use anyhow::Result;
+use mc_server_wrapper_core::artifacts::ArtifactStore;
+use mc_server_wrapper_core::content_report::ContentKind;
+use mc_server_wrapper_core::plugins::list_installed_plugins;
+use mc_server_wrapper_core::version_history::{
+    MAX_VERSIONS_PER_PROJECT, archive_version, list_installed_versions, referenced_hashes, rollback_version,
+};
+use std::io::Write;
+use std::path::Path;
+use tempfile::tempdir;
+
+fn create_plugin_jar(path: &Path, version: &str) {
+    let file = std::fs::File::create(path).unwrap();
+    let mut zip = zip::ZipWriter::new(file);
+    zip.start_file("plugin.yml", zip::write::SimpleFileOptions::default()).unwrap();
+    write!(zip, "name: Essentials\nversion: {}\nmain: dev.example.Essentials\n", version).unwrap();
+    zip.finish().unwrap();
+}
+
+fn write_source(plugins_dir: &Path, filename: &str, version_id: &str) {
+    let cache = serde_json::json!({
+        "entries": {},
+        "sources": { filename: { "project_id": "essentials", "provider": "Modrinth", "current_version_id": version_id } }
+    });
+    std::fs::write(plugins_dir.join(".plugin_metadata_cache.json"), cache.to_string()).unwrap();
+}
+
+#[tokio::test]
+async fn test_archive_keeps_limited_history() -> Result<()> {
+    let dir = tempdir()?;
+    let store = ArtifactStore::new(dir.path().join("artifacts"));
+    let instance = dir.path().join("instance");
+    std::fs::create_dir_all(&instance)?;
+
+    for i in 0..MAX_VERSIONS_PER_PROJECT + 2 {
+        let jar = dir.path().join(format!("Essentials-{}.jar", i));
+        create_plugin_jar(&jar, &i.to_string());
+        archive_version(&store, &instance, ContentKind::Plugin, "essentials", &jar, "Essentials.jar.disabled", Some(i.to_string()), None).await?;
+    }
+
+    let versions = list_installed_versions(&instance, ContentKind::Plugin, Some("essentials")).await;
+    assert_eq!(versions.len(), MAX_VERSIONS_PER_PROJECT);
+    assert_eq!(versions[0].version.as_deref(), Some("4"));
+    assert_eq!(versions[0].filename, "Essentials.jar");
+    assert!(list_installed_versions(&instance, ContentKind::Mod, None).await.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_history_limit_is_per_project() -> Result<()> {
+    let dir = tempdir()?;
+    let store = ArtifactStore::new(dir.path().join("artifacts"));
+    let instance = dir.path().join("instance");
+    std::fs::create_dir_all(&instance)?;
+
+    let other = dir.path().join("LuckPerms.jar");
+    create_plugin_jar(&other, "5.4");
+    archive_version(&store, &instance, ContentKind::Plugin, "luckperms", &other, "LuckPerms.jar", None, None).await?;
+    for i in 0..MAX_VERSIONS_PER_PROJECT + 1 {
+        let jar = dir.path().join(format!("Essentials-{}.jar", i));
+        create_plugin_jar(&jar, &i.to_string());
+        archive_version(&store, &instance, ContentKind::Plugin, "essentials", &jar, "Essentials.jar", None, None).await?;
+    }
+
+    assert_eq!(list_installed_versions(&instance, ContentKind::Plugin, Some("essentials")).await.len(), MAX_VERSIONS_PER_PROJECT);
+    assert_eq!(list_installed_versions(&instance, ContentKind::Plugin, Some("luckperms")).await.len(), 1);
+    // The dropped version is no longer kept by artifact pruning
+    assert_eq!(referenced_hashes(&instance).await.len(), MAX_VERSIONS_PER_PROJECT + 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rollback_swaps_installed_jar() -> Result<()> {
+    let dir = tempdir()?;
+    let store = ArtifactStore::new(dir.path().join("artifacts"));
+    let instance = dir.path().join("instance");
+    let plugins_dir = instance.join("plugins");
+    std::fs::create_dir_all(&plugins_dir)?;
+
+    // An update replaced Essentials-1.jar with Essentials-2.jar
+    let old_jar = dir.path().join("backup.jar");
+    create_plugin_jar(&old_jar, "1.0");
+    archive_version(&store, &instance, ContentKind::Plugin, "essentials", &old_jar, "Essentials-1.jar", Some("1.0".to_string()), Some("v1".to_string())).await?;
+    create_plugin_jar(&plugins_dir.join("Essentials-2.jar"), "2.0");
+    write_source(&plugins_dir, "Essentials-2.jar", "v2");
+
+    let sha1 = list_installed_versions(&instance, ContentKind::Plugin, None).await[0].sha1.clone();
+    let restored = rollback_version(&store, &instance, ContentKind::Plugin, "essentials", &sha1).await?;
+    assert_eq!(restored, "Essentials-1.jar");
+    assert!(!plugins_dir.join("Essentials-2.jar").exists());
+
+    let installed = list_installed_plugins(&instance).await?;
+    assert_eq!(installed.len(), 1);
+    assert_eq!(installed[0].version.as_deref(), Some("1.0"));
+    assert_eq!(installed[0].source.as_ref().and_then(|s| s.current_version_id.as_deref()), Some("v1"));
+
+    // The replaced version is now in the history, so the rollback can be undone
+    let versions = list_installed_versions(&instance, ContentKind::Plugin, Some("essentials")).await;
+    assert_eq!(versions.len(), 1);
+    assert_eq!(versions[0].filename, "Essentials-2.jar");
+    assert_eq!(versions[0].version_id.as_deref(), Some("v2"));
+    assert!(rollback_version(&store, &instance, ContentKind::Plugin, "essentials", &sha1).await.is_err());
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anyhow::Result;
+use mc_server_wrapper_core::database::Database;
+use mc_server_wrapper_core::instance::{InstanceManager, InstanceMetadata};
+use mc_server_wrapper_core::plugins::plan_via_setup;
+use std::sync::Arc;
+use tempfile::{TempDir, tempdir};
+
+async fn instances(specs: &[(&str, &str)]) -> Result<(TempDir, Vec<InstanceMetadata>)> {
+    let dir = tempdir()?;
+    let db = Arc::new(Database::new(dir.path().join("test.db")).await?);
+    let manager = InstanceManager::new(dir.path(), db).await?;
+    let mut created = Vec::new();
+    for (name, loader) in specs {
+        created.push(manager.create_instance_full(name, "1.21.1", Some(loader.to_string()), None).await?);
+    }
+    Ok((dir, created))
+}
+
+#[tokio::test]
+async fn test_standalone_paper_gets_plugins_itself() -> Result<()> {
+    let (_dir, list) = instances(&[("Survival", "paper")]).await?;
+    let plan = plan_via_setup(&list[0], None)?;
+    assert_eq!(plan.targets.len(), 1);
+    assert_eq!(plan.targets[0].instance_id, list[0].id);
+    assert_eq!(plan.targets[0].loader, "paper");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_velocity_network_installs_on_proxy() -> Result<()> {
+    let (_dir, list) = instances(&[("Proxy", "velocity"), ("Lobby", "paper")]).await?;
+    let (proxy, backends) = (&list[0], &list[1..]);
+
+    let from_backend = plan_via_setup(&list[1], Some((proxy, backends)))?;
+    assert_eq!(from_backend.targets.len(), 1);
+    assert_eq!(from_backend.targets[0].instance_id, proxy.id);
+    assert_eq!(from_backend.targets[0].loader, "velocity");
+    assert_eq!(from_backend.notes.len(), 1);
+
+    let from_proxy = plan_via_setup(proxy, Some((proxy, backends)))?;
+    assert_eq!(from_proxy.targets, from_backend.targets);
+    assert!(from_proxy.notes.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bungeecord_network_installs_on_backends() -> Result<()> {
+    let (_dir, list) = instances(&[("Bungee", "bungeecord"), ("Lobby", "paper"), ("Modded", "fabric")]).await?;
+    let plan = plan_via_setup(&list[0], Some((&list[0], &list[1..])))?;
+    assert_eq!(plan.targets.len(), 1);
+    assert_eq!(plan.targets[0].instance_id, list[1].id);
+
+    assert!(plan_via_setup(&list[0], Some((&list[0], &list[2..]))).is_err());
+    assert!(plan_via_setup(&list[0], None).is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unsupported_loader_is_rejected() -> Result<()> {
+    let (_dir, list) = instances(&[("Modded", "fabric")]).await?;
+    assert!(plan_via_setup(&list[0], None).is_err());
+    Ok(())
+}
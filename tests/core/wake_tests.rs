@@ -0,0 +1,102 @@
+use anyhow::Result;
+use mc_server_wrapper_core::app_config::GlobalConfigManager;
+use mc_server_wrapper_core::database::Database;
+use mc_server_wrapper_core::instance::InstanceManager;
+use mc_server_wrapper_core::manager::ServerManager;
+use mc_server_wrapper_core::protocol::packets::{self, NextState};
+use std::sync::Arc;
+use std::time::Duration;
+use tempfile::tempdir;
+use tokio::io::AsyncWriteExt;
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+
+fn handshake(next_state: i32, port: u16) -> Vec<u8> {
+    let mut body = vec![0x00];
+    packets::write_varint(&mut body, 767);
+    packets::write_varint(&mut body, 9);
+    body.extend_from_slice(b"localhost");
+    body.extend_from_slice(&port.to_be_bytes());
+    packets::write_varint(&mut body, next_state);
+    let mut packet = Vec::new();
+    packets::write_varint(&mut packet, body.len() as i32);
+    packet.extend(body);
+    packet
+}
+
+#[test]
+fn test_varint_roundtrip() -> Result<()> {
+    for value in [0, 1, 127, 128, 255, 25565, 2097151, i32::MAX, -1] {
+        let mut buf = Vec::new();
+        packets::write_varint(&mut buf, value);
+        assert_eq!(packets::read_varint(&buf)?, (value, buf.len()));
+    }
+    assert!(packets::read_varint(&[0x80, 0x80]).is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_parse_handshake() -> Result<()> {
+    let packet = handshake(2, 25565);
+    let (id, body) = packets::read_packet(&mut packet.as_slice()).await?;
+    assert_eq!(id, 0x00);
+    let parsed = packets::parse_handshake(&body)?;
+    assert_eq!(parsed.protocol, 767);
+    assert_eq!(parsed.address, "localhost");
+    assert_eq!(parsed.port, 25565);
+    assert_eq!(parsed.next_state, NextState::Login);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_wake_listener_answers_pings_and_wakes_on_login() -> Result<()> {
+    let dir = tempdir()?;
+    let db = Arc::new(Database::new(dir.path().join("test.db")).await?);
+    let instance_manager = Arc::new(InstanceManager::new(dir.path(), db).await?);
+    let config_manager = Arc::new(GlobalConfigManager::new(dir.path().join("app_settings.json")));
+    let manager = Arc::new(ServerManager::new(Arc::clone(&instance_manager), config_manager));
+
+    let port = std::net::TcpListener::bind("127.0.0.1:0")?.local_addr()?.port();
+    let instance = instance_manager.create_instance_full("Sleepy", "1.21.1", Some("vanilla".to_string()), None).await?;
+    let mut settings = instance.settings.clone();
+    settings.port = port;
+    settings.wake_on_connect = true;
+    instance_manager.update_settings(instance.id, None, settings).await?;
+
+    let mut wakes = manager.subscribe_wakes();
+    manager.spawn_wake_supervisor();
+
+    let mut stream = None;
+    for _ in 0..50 {
+        if let Ok(s) = TcpStream::connect(("127.0.0.1", port)).await {
+            stream = Some(s);
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    let mut stream = stream.expect("wake listener never bound the port");
+
+    // Status ping: handshake, status request, then ping
+    stream.write_all(&handshake(1, port)).await?;
+    stream.write_all(&[0x01, 0x00]).await?;
+    let (id, body) = packets::read_packet(&mut stream).await?;
+    assert_eq!(id, 0x00);
+    let (len, used) = packets::read_varint(&body)?;
+    let json: serde_json::Value = serde_json::from_slice(&body[used..used + len as usize])?;
+    assert_eq!(json["version"]["protocol"], 767);
+    assert!(json["description"]["text"].as_str().unwrap().contains("sleeping"));
+    stream.write_all(&[0x09, 0x01, 0, 0, 0, 0, 0, 0, 0, 42]).await?;
+    let (id, payload) = packets::read_packet(&mut stream).await?;
+    assert_eq!((id, payload.last().copied()), (0x01, Some(42)));
+
+    // Login attempt gets a disconnect and wakes the server
+    let mut stream = TcpStream::connect(("127.0.0.1", port)).await?;
+    stream.write_all(&handshake(2, port)).await?;
+    let (id, body) = packets::read_packet(&mut stream).await?;
+    assert_eq!(id, 0x00);
+    assert!(String::from_utf8_lossy(&body).contains("starting"));
+
+    let woken = timeout(Duration::from_secs(5), wakes.recv()).await??;
+    assert_eq!(woken, instance.id);
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use mc_server_wrapper_core::watcher::{FileChangeKind, FileWatcher, classify_change};
+use std::time::Duration;
+use tempfile::tempdir;
+use uuid::Uuid;
+
+#[test]
+fn test_classify_change() {
+    assert_eq!(classify_change("mods/sodium.jar"), Some(FileChangeKind::Mods));
+    assert_eq!(classify_change("mods/sodium.jar.disabled"), Some(FileChangeKind::Mods));
+    assert_eq!(
+        classify_change("plugins/EssentialsX.jar"),
+        Some(FileChangeKind::Plugins)
+    );
+    assert_eq!(
+        classify_change("plugins/Essentials/config.yml"),
+        Some(FileChangeKind::Config)
+    );
+    assert_eq!(
+        classify_change("config/sodium-options.json"),
+        Some(FileChangeKind::Config)
+    );
+    assert_eq!(classify_change("server.properties"), Some(FileChangeKind::Config));
+    assert_eq!(
+        classify_change("config\\fabric\\indigo.properties"),
+        Some(FileChangeKind::Config)
+    );
+
+    assert_eq!(classify_change("usercache.json"), None);
+    assert_eq!(classify_change("logs/latest.log"), None);
+    assert_eq!(classify_change("world/level.dat"), None);
+    assert_eq!(classify_change(".server.properties.0f3a.tmp"), None);
+    assert_eq!(classify_change("mods/readme.txt"), None);
+}
+
+#[tokio::test]
+async fn test_watcher_reports_debounced_changes() {
+    let dir = tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("mods")).unwrap();
+    let watcher = FileWatcher::new();
+    let mut rx = watcher.subscribe();
+    let id = Uuid::new_v4();
+    watcher.watch(id, dir.path()).unwrap();
+    assert!(watcher.is_watching(id));
+
+    std::fs::write(dir.path().join("mods/a.jar"), "a").unwrap();
+    std::fs::write(dir.path().join("mods/b.jar"), "b").unwrap();
+    std::fs::write(dir.path().join("logs.txt"), "ignored").unwrap();
+
+    let change = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(change.instance_id, id);
+    assert_eq!(change.kind, FileChangeKind::Mods);
+    assert_eq!(change.paths, vec!["mods/a.jar".to_string(), "mods/b.jar".to_string()]);
+
+    // Folders created later are picked up once they appear
+    std::fs::create_dir_all(dir.path().join("plugins")).unwrap();
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    std::fs::write(dir.path().join("plugins/c.jar"), "c").unwrap();
+    let change = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(change.kind, FileChangeKind::Plugins);
+    assert_eq!(change.paths, vec!["plugins/c.jar".to_string()]);
+
+    watcher.unwatch(id);
+    assert!(!watcher.is_watching(id));
+    std::fs::write(dir.path().join("server.properties"), "motd=x").unwrap();
+    assert!(tokio::time::timeout(Duration::from_secs(1), rx.recv()).await.is_err());
+}
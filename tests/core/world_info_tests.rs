@@ -0,0 +1,137 @@
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use mc_server_wrapper_core::nbt::{NbtCompression, NbtTag, read_nbt};
+use mc_server_wrapper_core::world_info::{
+    ChunkPos, check_slime_chunks, describe_coordinates, is_slime_chunk, level_summary, read_level_summary,
+};
+use std::io::Write;
+
+fn named(tag_type: u8, name: &str, payload: &[u8]) -> Vec<u8> {
+    let mut out = vec![tag_type];
+    out.extend((name.len() as u16).to_be_bytes());
+    out.extend(name.as_bytes());
+    out.extend(payload);
+    out
+}
+
+fn compound(children: &[Vec<u8>]) -> Vec<u8> {
+    let mut out: Vec<u8> = children.concat();
+    out.push(0);
+    out
+}
+
+fn string(value: &str) -> Vec<u8> {
+    let mut out = (value.len() as u16).to_be_bytes().to_vec();
+    out.extend(value.as_bytes());
+    out
+}
+
+/// A 1.20.1-style level.dat with the seed in WorldGenSettings.
+fn level_dat() -> Vec<u8> {
+    let data = compound(&[
+        named(8, "LevelName", &string("Survival")),
+        named(3, "DataVersion", &3465i32.to_be_bytes()),
+        named(3, "SpawnX", &(-120i32).to_be_bytes()),
+        named(3, "SpawnY", &64i32.to_be_bytes()),
+        named(3, "SpawnZ", &40i32.to_be_bytes()),
+        named(10, "Version", &compound(&[named(8, "Name", &string("1.20.1"))])),
+        named(
+            10,
+            "WorldGenSettings",
+            &compound(&[named(4, "seed", &(-4172144997902289642i64).to_be_bytes())]),
+        ),
+        named(9, "ServerBrands", &[8, 0, 0, 0, 1, 0, 5, b'p', b'a', b'p', b'e', b'r']),
+    ]);
+    let raw = named(10, "", &compound(&[named(10, "Data", &data)]));
+    let mut gz = GzEncoder::new(Vec::new(), Compression::default());
+    gz.write_all(&raw).unwrap();
+    gz.finish().unwrap()
+}
+
+#[tokio::test]
+async fn test_read_level_summary() {
+    let file = read_nbt(&level_dat()).unwrap();
+    assert_eq!(file.compression, NbtCompression::Gzip);
+    assert_eq!(
+        file.root.get_path("Data.ServerBrands"),
+        Some(&NbtTag::List(vec![NbtTag::String("paper".to_string())]))
+    );
+
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("level.dat"), level_dat()).unwrap();
+    let summary = read_level_summary(dir.path()).await.unwrap();
+    assert_eq!(summary.level_name.as_deref(), Some("Survival"));
+    assert_eq!(summary.version_name.as_deref(), Some("1.20.1"));
+    assert_eq!(summary.seed, -4172144997902289642);
+    assert_eq!(summary.spawn, (-120, 64, 40));
+    assert_eq!(summary.spawn_chunks.center, ChunkPos { x: -8, z: 2 });
+    assert_eq!(summary.spawn_chunks.radius, 11);
+    assert_eq!(summary.spawn_chunks.min, ChunkPos { x: -19, z: -9 });
+
+    let json = serde_json::to_value(&summary).unwrap();
+    assert_eq!(json["seed"], "-4172144997902289642");
+}
+
+#[test]
+fn test_level_summary_of_newer_versions() {
+    let root = NbtTag::Compound(vec![(
+        "Data".to_string(),
+        NbtTag::Compound(vec![
+            ("DataVersion".to_string(), NbtTag::Int(4556)),
+            ("RandomSeed".to_string(), NbtTag::Long(12345)),
+            (
+                "spawn".to_string(),
+                NbtTag::Compound(vec![("pos".to_string(), NbtTag::IntArray(vec![5, 70, -17]))]),
+            ),
+        ]),
+    )]);
+    let summary = level_summary(&root).unwrap();
+    assert_eq!(summary.seed, 12345);
+    assert_eq!(summary.spawn, (5, 70, -17));
+    assert_eq!(summary.spawn_chunks.radius, 0, "1.21.9 has no spawn chunks");
+
+    assert!(
+        read_nbt(&[10, 0, 0, 9, 0, 1, b'x', 0, 0, 0, 0, 5]).is_err(),
+        "a list of end tags is corrupt"
+    );
+    assert!(
+        read_nbt(&[10, 0, 0, 7, 0, 1, b'x', 0x7f, 0, 0, 0]).is_err(),
+        "lengths past the end are rejected"
+    );
+}
+
+#[test]
+fn test_slime_chunks_match_the_game() {
+    // Reference values from java.util.Random with the game's formula
+    let chunks = |seed| {
+        let mut found = Vec::new();
+        for x in -3..=3 {
+            for z in -3..=3 {
+                if is_slime_chunk(seed, ChunkPos { x, z }) {
+                    found.push((x, z));
+                }
+            }
+        }
+        found
+    };
+    assert_eq!(chunks(12345), vec![(-2, 1), (-1, 2), (0, -2), (3, 0)]);
+    assert_eq!(chunks(-4172144997902289642), vec![(-2, 3), (1, 0), (1, 1), (3, 0)]);
+    assert!(is_slime_chunk(12345, ChunkPos { x: 100000, z: -70000 }));
+    assert!(!is_slime_chunk(12345, ChunkPos { x: -46341, z: 46341 }));
+
+    let check = check_slime_chunks(12345, 3, -30, 3).unwrap();
+    assert_eq!(check.chunk, ChunkPos { x: 0, z: -2 });
+    assert!(check.is_slime_chunk);
+    assert_eq!(check.nearby.first(), Some(&ChunkPos { x: 0, z: -2 }));
+    assert!(check_slime_chunks(12345, 0, 0, 100).is_err());
+}
+
+#[test]
+fn test_describe_coordinates() {
+    let info = describe_coordinates(-1, 513);
+    assert_eq!(info.chunk, ChunkPos { x: -1, z: 32 });
+    assert_eq!(info.chunk_origin, (-16, 512));
+    assert_eq!(info.region_file, "r.-1.1.mca");
+    assert_eq!(info.nether, (-1, 64));
+    assert_eq!(info.overworld, (-8, 4104));
+}
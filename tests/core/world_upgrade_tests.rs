@@ -0,0 +1,47 @@
+use mc_server_wrapper_core::world_upgrade::{
+    ERASE_CACHE_ARG, FORCE_UPGRADE_ARG, UpgradeLine, WorldUpgradeRequest, check_supported, is_outdated,
+    parse_upgrade_line,
+};
+
+#[test]
+fn test_world_upgrade_args_and_support() {
+    assert_eq!(WorldUpgradeRequest::default().args(), vec![FORCE_UPGRADE_ARG.to_string()]);
+    let erase = WorldUpgradeRequest { erase_cache: true };
+    assert_eq!(erase.args(), vec![FORCE_UPGRADE_ARG.to_string(), ERASE_CACHE_ARG.to_string()]);
+
+    assert!(check_supported(None).is_ok());
+    assert!(check_supported(Some("paper")).is_ok());
+    assert!(check_supported(Some("NeoForge")).is_ok());
+    assert!(check_supported(Some("Velocity")).is_err());
+    assert!(check_supported(Some("bedrock")).is_err());
+}
+
+#[test]
+fn test_world_is_outdated() {
+    assert_eq!(is_outdated("1.20.4", "1.21.4"), Some(true));
+    assert_eq!(is_outdated("1.21.4", "1.21.4"), Some(false));
+    // A world opened by a newer version can't be downgraded, so there is nothing to upgrade
+    assert_eq!(is_outdated("1.21.4", "1.20.4"), Some(false));
+    assert_eq!(is_outdated("24w14a", "1.21.4"), None);
+}
+
+#[test]
+fn test_parse_upgrade_line() {
+    assert_eq!(
+        parse_upgrade_line("[12:00:01] [Server thread/INFO]: Forcing world upgrade!"),
+        Some(UpgradeLine::Stage("Forcing world upgrade".to_string()))
+    );
+    assert_eq!(
+        parse_upgrade_line("[12:00:02] [Server thread/INFO]: Upgrading all chunks..."),
+        Some(UpgradeLine::Stage("Upgrading all chunks...".to_string()))
+    );
+    assert_eq!(
+        parse_upgrade_line("[12:00:03] [Server thread/INFO]: 45% completed (2250 / 5000 chunks)..."),
+        Some(UpgradeLine::Progress { percent: 45.0, done: 2250, total: 5000 })
+    );
+    assert_eq!(
+        parse_upgrade_line("\x1b[32m[12:00:03 INFO]: [world] Processed 10 of 40 region files\x1b[0m"),
+        Some(UpgradeLine::Progress { percent: 25.0, done: 10, total: 40 })
+    );
+    assert_eq!(parse_upgrade_line("[12:00:04 INFO]: Preparing spawn area: 45%"), None);
+}
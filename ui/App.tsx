@@ -1,4 +1,6 @@
 import { useState, useEffect } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { listen } from '@tauri-apps/api/event'
 import { AnimatePresence, motion } from 'framer-motion'
 import { CreateInstanceModal } from './CreateInstanceModal'
 import { DownloadProgressModal } from './DownloadProgressModal'
@@ -6,6 +8,7 @@ import { AppSettingsModal } from './components/AppSettingsModal'
 import { useServer } from './hooks/useServer'
 import { useAppSettings } from './hooks/useAppSettings'
 import { useConsoleScroll } from './hooks/useConsoleScroll'
+import { useToast } from './hooks/useToast'
 import { Sidebar } from './components/Sidebar'
 import { Header } from './components/Header'
 import { TitleBar } from './components/TitleBar'
@@ -31,12 +34,14 @@ function App() {
     addInstance,
     startServer,
     stopServer,
+    killServer,
     restartServer,
     sendCommand,
     loading: serverLoading
   } = useServer()
 
   const { settings, updateSettings, isLoading: settingsLoading } = useAppSettings()
+  const { showToast } = useToast()
 
   const [activeTab, setActiveTab] = useState<TabId>('dashboard')
   const [showCreateModal, setShowCreateModal] = useState(false)
@@ -74,6 +79,33 @@ function App() {
     }
   }, [currentInstance, activeTab, tabs]);
 
+  // Let the user know when an idle server was shut down or woken up for them
+  useEffect(() => {
+    if (!(window as any).__TAURI_INTERNALS__) return;
+    const unlisten = listen<{ instance_id: string, idle_secs: number }>('auto-stopped', (event) => {
+      const name = instances.find(i => i.id === event.payload.instance_id)?.name || 'A server';
+      showToast(`${name} was stopped after ${Math.round(event.payload.idle_secs / 60)} minutes without players`, 'info');
+    });
+    const unlistenWake = listen<string>('server-woken', (event) => {
+      const name = instances.find(i => i.id === event.payload)?.name || 'A server';
+      showToast(`${name} is starting because a player tried to join`, 'info');
+    });
+    return () => {
+      unlisten.then(f => f());
+      unlistenWake.then(f => f());
+    };
+  }, [instances]);
+
+  // Watch the open instance so its tabs reload files changed outside the app
+  useEffect(() => {
+    if (!selectedInstanceId || !(window as any).__TAURI_INTERNALS__) return;
+    invoke('watch_instance_files', { instanceId: selectedInstanceId })
+      .catch(err => console.error('Failed to watch instance files:', err));
+    return () => {
+      invoke('unwatch_instance_files', { instanceId: selectedInstanceId }).catch(() => {});
+    };
+  }, [selectedInstanceId]);
+
   // Disable browser context menu
   useEffect(() => {
     const handleContextMenu = (e: MouseEvent) => {
@@ -135,6 +167,7 @@ function App() {
                 tabs={tabs}
                 onStartServer={() => startServer()}
                 onStopServer={() => stopServer()}
+                onKillServer={() => killServer()}
                 onRestartServer={() => restartServer()}
                 onSetActiveTab={setActiveTab}
                 onInstancesUpdated={loadInstances}
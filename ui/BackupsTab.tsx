@@ -14,7 +14,7 @@ import {
   Loader2
 } from 'lucide-react'
 import { motion, AnimatePresence } from 'framer-motion'
-import { BackupInfo } from './types'
+import { BackupInfo, TaskInfo } from './types'
 import { useToast } from './hooks/useToast'
 import { ConfirmDropdown } from './components/ConfirmDropdown'
 import { formatSize } from './utils'
@@ -23,13 +23,6 @@ interface BackupsTabProps {
   instanceId: string;
 }
 
-interface BackupProgressPayload {
-  instance_id: string;
-  current: number;
-  total: number;
-  message: string;
-}
-
 export function BackupsTab({ instanceId }: BackupsTabProps) {
   const [backups, setBackups] = useState<BackupInfo[]>([])
   const [loading, setLoading] = useState(true)
@@ -42,9 +35,10 @@ export function BackupsTab({ instanceId }: BackupsTabProps) {
   }, [instanceId])
 
   useEffect(() => {
-    const unlisten = listen<BackupProgressPayload>('backup-progress', (event) => {
-      if (event.payload.instance_id === instanceId) {
-        const progress = Math.round((event.payload.current / event.payload.total) * 100);
+    const unlisten = listen<TaskInfo>('task-progress', (event) => {
+      const task = event.payload;
+      if (task.kind === 'backup' && task.instance_id === instanceId && task.total > 0) {
+        const progress = Math.round((task.current / task.total) * 100);
         setBackups(prev => prev.map(b =>
           b.status === 'creating' ? { ...b, progress } : b
         ));
@@ -0,0 +1,172 @@
+import { useState, useEffect, useRef } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { listen } from '@tauri-apps/api/event'
+import { MessageSquare, Send, LogIn, LogOut, Skull, Award } from 'lucide-react'
+import { ChatEvent } from './types'
+import { cn, formatError } from './utils'
+import { useToast } from './hooks/useToast'
+
+interface ChatTabProps {
+  instanceId: string;
+}
+
+interface ChatPayload {
+  instance_id: string;
+  event: ChatEvent;
+}
+
+type ChatFilter = 'join' | 'death' | 'advancement';
+
+// Older events are dropped so a busy server doesn't grow the list forever
+const MAX_EVENTS = 500
+
+const FILTERS: { id: ChatFilter; label: string; icon: any }[] = [
+  { id: 'join', label: 'Joins', icon: LogIn },
+  { id: 'death', label: 'Deaths', icon: Skull },
+  { id: 'advancement', label: 'Advancements', icon: Award },
+]
+
+const isShown = (event: ChatEvent, hidden: ChatFilter[]) => {
+  if (event.type === 'join' || event.type === 'leave') return !hidden.includes('join')
+  if (event.type === 'death') return !hidden.includes('death')
+  if (event.type === 'advancement') return !hidden.includes('advancement')
+  return true
+}
+
+function ChatEventRow({ event }: { event: ChatEvent }) {
+  switch (event.type) {
+    case 'chat_message':
+      return (
+        <div className="text-sm">
+          <span className={cn('font-bold mr-2', event.player === 'Server' || event.player === 'Rcon' ? 'text-accent-rose' : 'text-primary')}>
+            {event.player}
+          </span>
+          <span className="text-gray-300 break-words">{event.message}</span>
+        </div>
+      )
+    case 'join':
+    case 'leave':
+      return (
+        <div className="flex items-center gap-2 text-xs text-gray-500 italic">
+          {event.type === 'join' ? <LogIn size={12} className="text-emerald-400" /> : <LogOut size={12} className="text-gray-500" />}
+          {event.player} {event.type === 'join' ? 'joined' : 'left'} the game
+        </div>
+      )
+    case 'death':
+      return (
+        <div className="flex items-center gap-2 text-xs text-accent-rose/80">
+          <Skull size={12} />
+          {event.message}
+        </div>
+      )
+    case 'advancement':
+      return (
+        <div className="flex items-center gap-2 text-xs text-accent-amber">
+          <Award size={12} />
+          {event.player} {event.kind === 'goal' ? 'reached the goal' : event.kind === 'challenge' ? 'completed the challenge' : 'made the advancement'}
+          <span className="font-bold">[{event.advancement}]</span>
+        </div>
+      )
+  }
+}
+
+export function ChatTab({ instanceId }: ChatTabProps) {
+  const [events, setEvents] = useState<ChatEvent[]>([])
+  const [hidden, setHidden] = useState<ChatFilter[]>([])
+  const [message, setMessage] = useState('')
+  const [sending, setSending] = useState(false)
+  const endRef = useRef<HTMLDivElement>(null)
+  const { showToast } = useToast()
+
+  useEffect(() => {
+    setEvents([])
+    invoke<ChatEvent[]>('get_recent_chat', { instanceId })
+      .then(recent => setEvents(prev => [...recent, ...prev].slice(-MAX_EVENTS)))
+      .catch(err => console.error('Failed to load recent chat:', err))
+
+    const unlisten = listen<ChatPayload>('server-chat', (e) => {
+      if (e.payload.instance_id !== instanceId) return
+      setEvents(prev => [...prev, e.payload.event].slice(-MAX_EVENTS))
+    })
+    return () => {
+      unlisten.then(f => f())
+    }
+  }, [instanceId])
+
+  useEffect(() => {
+    endRef.current?.scrollIntoView({ block: 'end' })
+  }, [events, hidden])
+
+  const send = async (e: React.FormEvent) => {
+    e.preventDefault()
+    if (!message.trim()) return
+    setSending(true)
+    try {
+      await invoke('send_chat', { instanceId, message })
+      setMessage('')
+    } catch (err) {
+      showToast(`Failed to send message: ${formatError(err)}`, 'error')
+    } finally {
+      setSending(false)
+    }
+  }
+
+  const toggle = (filter: ChatFilter) =>
+    setHidden(prev => prev.includes(filter) ? prev.filter(f => f !== filter) : [...prev, filter])
+
+  const visible = events.filter(event => isShown(event, hidden))
+
+  return (
+    <div className="h-full flex flex-col gap-4">
+      <div className="flex items-center justify-between">
+        <div>
+          <h2 className="text-2xl font-bold flex items-center gap-2">
+            <MessageSquare className="w-6 h-6 text-primary" />
+            Chat
+          </h2>
+          <p className="text-gray-400 mt-1">Player chat and activity, without the rest of the console.</p>
+        </div>
+        <div className="flex items-center gap-2">
+          {FILTERS.map(({ id, label, icon: Icon }) => (
+            <button
+              key={id}
+              onClick={() => toggle(id)}
+              className={cn(
+                'flex items-center gap-2 px-3 py-2 rounded-xl text-xs font-bold transition-all border',
+                hidden.includes(id)
+                  ? 'bg-transparent border-white/5 text-gray-600 line-through'
+                  : 'bg-white/5 border-white/10 text-gray-300 hover:bg-white/10'
+              )}
+            >
+              <Icon size={14} />
+              {label}
+            </button>
+          ))}
+        </div>
+      </div>
+
+      <div className="flex-1 overflow-y-auto custom-scrollbar bg-surface/50 rounded-2xl border border-black/5 dark:border-white/5 p-4 space-y-2">
+        {visible.length === 0 ? (
+          <p className="text-sm text-gray-500 italic text-center py-12">Chat from the running server shows up here</p>
+        ) : visible.map((event, idx) => <ChatEventRow key={idx} event={event} />)}
+        <div ref={endRef} />
+      </div>
+
+      <form onSubmit={send} className="flex items-center gap-2">
+        <input
+          value={message}
+          onChange={(e) => setMessage(e.target.value)}
+          placeholder="Say something to everyone online (&a color codes work too)"
+          className="flex-1 px-4 py-3 bg-black/5 dark:bg-white/5 border border-black/5 dark:border-white/5 rounded-xl text-sm focus:outline-none focus:border-primary/50"
+        />
+        <button
+          type="submit"
+          disabled={sending || !message.trim()}
+          className="flex items-center gap-2 px-4 py-3 bg-primary hover:bg-primary/90 disabled:opacity-30 text-white rounded-xl transition-all shadow-lg shadow-primary/20"
+        >
+          <Send className="w-4 h-4" />
+        </button>
+      </form>
+    </div>
+  )
+}
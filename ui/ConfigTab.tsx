@@ -1,13 +1,16 @@
 import { useState, useEffect } from 'react'
 import { invoke } from '@tauri-apps/api/core'
-import { RefreshCw } from 'lucide-react'
+import { RefreshCw, Search, Sparkles } from 'lucide-react'
 import { motion, AnimatePresence } from 'framer-motion'
 import { TextEditor } from './components/TextEditor'
-import { ConfigFile } from './config/types'
+import { ConfigFile, ConfigGroup, groupKey } from './config/types'
 import { ConfigSidebar } from './config/ConfigSidebar'
 import { ConfigControls } from './config/ConfigControls'
 import { PropertyGrid } from './config/PropertyGrid'
+import { FindReplacePanel } from './config/FindReplacePanel'
+import { PresetsPanel } from './config/PresetsPanel'
 import { useToast } from './hooks/useToast'
+import { useFileChanges } from './hooks/useFileChanges'
 
 interface ConfigTabProps {
   instanceId: string
@@ -18,19 +21,39 @@ export function ConfigTab({ instanceId }: ConfigTabProps) {
   const [loading, setLoading] = useState(true)
   const [saving, setSaving] = useState(false)
   const [searchTerm, setSearchTerm] = useState('')
-  const [availableConfigs, setAvailableConfigs] = useState<ConfigFile[]>([])
+  const [groups, setGroups] = useState<ConfigGroup[]>([])
+  const [groupFiles, setGroupFiles] = useState<Record<string, ConfigFile[]>>({})
   const [selectedConfig, setSelectedConfig] = useState<ConfigFile | null>(null)
   const [isRawEditing, setIsRawEditing] = useState(false)
   const [rawContent, setRawContent] = useState('')
   const [nestedConfig, setNestedConfig] = useState<any>(null)
+  const [showFindReplace, setShowFindReplace] = useState(false)
+  const [showPresets, setShowPresets] = useState(false)
   const { showToast } = useToast()
 
+  const loadGroup = async (group: ConfigGroup) => {
+    try {
+      const files = await invoke<ConfigFile[]>('get_group_configs', { instanceId, kind: group.kind, owner: group.owner })
+      setGroupFiles(prev => ({ ...prev, [groupKey(group)]: files }))
+      return files
+    } catch (err) {
+      console.error(`Failed to list the configs of ${group.owner}:`, err)
+      return []
+    }
+  }
+
+  // Only the server's files are listed up front; mod and plugin groups load when opened
   const fetchAvailableConfigs = async () => {
     try {
-      const configs = await invoke<ConfigFile[]>('get_available_configs', { instanceId })
-      setAvailableConfigs(configs)
-      if (configs.length > 0 && !selectedConfig) {
-        setSelectedConfig(configs[0])
+      const groups = await invoke<ConfigGroup[]>('get_config_groups', { instanceId })
+      setGroups(groups)
+      const opened = new Set(Object.keys(groupFiles))
+      for (const group of groups) {
+        if (group.kind !== 'server' && !opened.has(groupKey(group))) continue
+        const files = await loadGroup(group)
+        if (group.kind === 'server' && files.length > 0 && !selectedConfig) {
+          setSelectedConfig(files[0])
+        }
       }
     } catch (err) {
       console.error('Failed to fetch available configs:', err)
@@ -117,6 +140,14 @@ export function ConfigTab({ instanceId }: ConfigTabProps) {
     }
   }, [selectedConfig, instanceId])
 
+  // Pick up edits made outside the app; an open raw editor keeps its unsaved text
+  useFileChanges(instanceId, 'config', (paths) => {
+    fetchAvailableConfigs()
+    if (selectedConfig && !isRawEditing && paths.includes(selectedConfig.path)) {
+      fetchProperties()
+    }
+  })
+
   const handleSave = async () => {
     if (!selectedConfig) return
     setSaving(true)
@@ -169,12 +200,44 @@ export function ConfigTab({ instanceId }: ConfigTabProps) {
   return (
     <div className="flex gap-8 h-full min-h-[600px]">
       <ConfigSidebar
-        availableConfigs={availableConfigs}
+        groups={groups}
+        groupFiles={groupFiles}
+        onOpenGroup={loadGroup}
         selectedConfig={selectedConfig}
         setSelectedConfig={setSelectedConfig}
       />
 
       <div className="flex-1 space-y-8 pb-8">
+        {showFindReplace ? (
+          <FindReplacePanel
+            instanceId={instanceId}
+            onClose={() => setShowFindReplace(false)}
+            onReplaced={fetchProperties}
+          />
+        ) : (
+          <button
+            onClick={() => setShowFindReplace(true)}
+            className="flex items-center gap-2 text-xs font-black uppercase tracking-widest text-gray-400 dark:text-white/30 hover:text-primary transition-colors"
+          >
+            <Search size={14} /> Find &amp; replace across all configs
+          </button>
+        )}
+
+        {showPresets ? (
+          <PresetsPanel
+            instanceId={instanceId}
+            onClose={() => setShowPresets(false)}
+            onApplied={fetchProperties}
+          />
+        ) : (
+          <button
+            onClick={() => setShowPresets(true)}
+            className="flex items-center gap-2 text-xs font-black uppercase tracking-widest text-gray-400 dark:text-white/30 hover:text-primary transition-colors"
+          >
+            <Sparkles size={14} /> Apply a preset (anti-xray, performance, hardcore)
+          </button>
+        )}
+
         <ConfigControls
           selectedConfig={selectedConfig}
           searchTerm={searchTerm}
@@ -5,6 +5,8 @@ import { SoftwareSelection } from './create-instance/SoftwareSelection'
 import { VersionSelection } from './create-instance/VersionSelection'
 import { ImportSource } from './create-instance/ImportSource'
 import { ModrinthSource } from './create-instance/ModrinthSource'
+import { TemplateSource } from './create-instance/TemplateSource'
+import { RemoteSource } from './create-instance/RemoteSource'
 import { Footer } from './create-instance/Footer'
 import { useCreateInstance } from './create-instance/useCreateInstance'
 import { CreateInstanceModalProps } from './create-instance/types'
@@ -26,6 +28,7 @@ export function CreateInstanceModal({ isOpen, onClose, onCreated }: CreateInstan
     selectedVersion,
     setSelectedVersion,
     modLoaders,
+    experimentalBuilds,
     selectedLoaderVersion,
     setSelectedLoaderVersion,
     creating,
@@ -47,7 +50,12 @@ export function CreateInstanceModal({ isOpen, onClose, onCreated }: CreateInstan
     setBypassServerPropertiesCheck,
     rootWithinZip,
     setRootWithinZip,
+    importPreflight,
+    linkInPlace,
+    setLinkInPlace,
     importProgress,
+    importTaskId,
+    cancelImport,
     startAfterCreation,
     setStartAfterCreation,
     nameExists,
@@ -63,7 +71,11 @@ export function CreateInstanceModal({ isOpen, onClose, onCreated }: CreateInstan
     selectedModpackVersion,
     setSelectedModpackVersion,
     loadingModpackVersions,
-    modpackProgress
+    modpackProgress,
+    selectedTemplate,
+    setSelectedTemplate,
+    remoteImport,
+    setRemoteImport
   } = useCreateInstance(isOpen, onCreated, onClose);
 
   if (!isOpen) return null;
@@ -90,7 +102,7 @@ export function CreateInstanceModal({ isOpen, onClose, onCreated }: CreateInstan
         >
           {/* Progress Overlay */}
           <AnimatePresence>
-            {creating && activeTab === 'import' && (
+            {creating && (activeTab === 'import' || activeTab === 'remote') && (
               <motion.div
                 initial={{ opacity: 0 }}
                 animate={{ opacity: 1 }}
@@ -141,7 +153,7 @@ export function CreateInstanceModal({ isOpen, onClose, onCreated }: CreateInstan
                         <div className="flex items-center gap-2 text-[10px] font-black uppercase tracking-widest text-gray-400 dark:text-white/20">
                           <span className="text-primary/40">{importProgress?.current || 0}</span>
                           <span className="opacity-30">/</span>
-                          <span>{importProgress?.total || 0} items</span>
+                          <span>{importProgress?.total || 0} {activeTab === 'remote' ? 'bytes' : 'items'}</span>
                         </div>
                         <div className="text-[10px] font-black uppercase tracking-widest text-gray-400 dark:text-white/20">
                           {percentage < 100 ? 'In Progress' : 'Finishing up...'}
@@ -149,6 +161,17 @@ export function CreateInstanceModal({ isOpen, onClose, onCreated }: CreateInstan
                       </div>
                     </div>
                   </div>
+
+                  {importTaskId && (
+                    <div className="flex justify-center">
+                      <button
+                        onClick={cancelImport}
+                        className="px-6 py-2.5 rounded-xl text-xs font-black uppercase tracking-widest text-accent-rose bg-accent-rose/10 hover:bg-accent-rose/20 ring-1 ring-accent-rose/30 transition-all"
+                      >
+                        Cancel Import
+                      </button>
+                    </div>
+                  )}
                 </div>
               </motion.div>
             )}
@@ -318,6 +341,7 @@ export function CreateInstanceModal({ isOpen, onClose, onCreated }: CreateInstan
                         selectedLoaderVersion={selectedLoaderVersion}
                         setSelectedLoaderVersion={setSelectedLoaderVersion}
                         modLoaders={modLoaders}
+                        experimentalBuilds={experimentalBuilds}
                         loadingModLoaders={loadingModLoaders}
                       />
                     )}
@@ -345,12 +369,38 @@ export function CreateInstanceModal({ isOpen, onClose, onCreated }: CreateInstan
                       setBypassServerPropertiesCheck={setBypassServerPropertiesCheck}
                       rootWithinZip={rootWithinZip}
                       setRootWithinZip={setRootWithinZip}
+                      preflight={importPreflight}
+                      linkInPlace={linkInPlace}
+                      setLinkInPlace={setLinkInPlace}
                       availableScripts={availableScripts}
                       setAvailableScripts={setAvailableScripts}
                       selectedScript={selectedScript}
                       setSelectedScript={setSelectedScript}
                     />
                   </motion.div>
+                ) : activeTab === 'remote' ? (
+                  <motion.div
+                    key="remote"
+                    initial={{ opacity: 0, x: 20 }}
+                    animate={{ opacity: 1, x: 0 }}
+                    exit={{ opacity: 0, x: -20 }}
+                    className="flex-1 flex flex-col overflow-hidden"
+                  >
+                    <RemoteSource selection={remoteImport} setSelection={setRemoteImport} />
+                  </motion.div>
+                ) : activeTab === 'template' ? (
+                  <motion.div
+                    key="template"
+                    initial={{ opacity: 0, x: 20 }}
+                    animate={{ opacity: 1, x: 0 }}
+                    exit={{ opacity: 0, x: -20 }}
+                    className="flex-1 flex flex-col overflow-hidden"
+                  >
+                    <TemplateSource
+                      selectedTemplate={selectedTemplate}
+                      setSelectedTemplate={setSelectedTemplate}
+                    />
+                  </motion.div>
                 ) : activeTab === 'modrinth' ? (
                   <motion.div
                     key="modrinth"
@@ -408,8 +458,11 @@ export function CreateInstanceModal({ isOpen, onClose, onCreated }: CreateInstan
             startAfterCreation={startAfterCreation}
             setStartAfterCreation={setStartAfterCreation}
             nameExists={nameExists}
+            importBlocked={!!importPreflight?.source_error || !!importPreflight?.insufficient_space}
             selectedModpack={selectedModpack?.id}
             selectedModpackVersion={selectedModpackVersion}
+            selectedTemplate={selectedTemplate}
+            remoteJar={remoteImport?.jar}
           />
         </motion.div>
       </div>
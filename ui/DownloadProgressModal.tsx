@@ -1,15 +1,10 @@
 import { useState, useEffect } from 'react'
+import { invoke } from '@tauri-apps/api/core'
 import { listen } from '@tauri-apps/api/event'
 import { Download, Loader2, CheckCircle2, Info, ArrowRight } from 'lucide-react'
 import { motion, AnimatePresence } from 'framer-motion'
 import { cn } from './utils'
-
-interface ProgressPayload {
-  instance_id: string;
-  current: number;
-  total: number;
-  message: string;
-}
+import { RecordedEvent, TaskInfo } from './types'
 
 interface DownloadProgressModalProps {
   isOpen: boolean;
@@ -19,7 +14,7 @@ interface DownloadProgressModalProps {
 }
 
 export function DownloadProgressModal({ isOpen, onClose, instanceId, instanceName }: DownloadProgressModalProps) {
-  const [progress, setProgress] = useState<ProgressPayload | null>(null);
+  const [progress, setProgress] = useState<TaskInfo | null>(null);
   const [isFinished, setIsFinished] = useState(false);
 
   useEffect(() => {
@@ -29,15 +24,33 @@ export function DownloadProgressModal({ isOpen, onClose, instanceId, instanceNam
       return;
     }
 
-    const unlisten = listen<ProgressPayload>('download-progress', (event) => {
-      if (event.payload.instance_id === instanceId) {
-        setProgress(event.payload);
-        if (event.payload.current >= event.payload.total && event.payload.total > 0) {
-          setTimeout(() => setIsFinished(true), 800);
-        }
+    const isSetupTask = (task: TaskInfo) =>
+      task.instance_id === instanceId && (task.kind === 'install' || task.kind === 'download');
+    const show = (task: TaskInfo) => {
+      setProgress(task);
+      if (task.state === 'completed') {
+        setTimeout(() => setIsFinished(true), 800);
+      }
+    };
+
+    let live = false;
+    const unlisten = listen<TaskInfo>('task-progress', (event) => {
+      if (isSetupTask(event.payload)) {
+        live = true;
+        show(event.payload);
       }
     });
 
+    // Pick up a download that was already underway when the window reloaded
+    invoke<RecordedEvent[]>('fetch_recent_events', { instanceId, sinceSeq: 0 })
+      .then(events => {
+        const latest = events
+          .filter(e => e.channel === 'task' && isSetupTask(e.payload))
+          .pop();
+        if (latest && !live) show(latest.payload);
+      })
+      .catch(err => console.error('Failed to replay task progress:', err));
+
     return () => {
       unlisten.then(u => u());
     };
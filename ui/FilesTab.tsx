@@ -0,0 +1,224 @@
+import { useState, useEffect } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { save } from '@tauri-apps/plugin-dialog'
+import {
+  Folder,
+  File,
+  FolderPlus,
+  ChevronRight,
+  RefreshCw,
+  Pencil,
+  Copy,
+  Download,
+  Trash2,
+  Link
+} from 'lucide-react'
+import { FileEntry } from './types'
+import { useToast } from './hooks/useToast'
+import { ConfirmDropdown } from './components/ConfirmDropdown'
+import { FileViewer } from './components/files/FileViewer'
+import { formatSize } from './utils'
+
+interface FilesTabProps {
+  instanceId: string;
+}
+
+type PendingAction = { kind: 'mkdir' } | { kind: 'rename' | 'copy'; entry: FileEntry };
+
+const joinPath = (dir: string, name: string) => (dir ? `${dir}/${name}` : name);
+
+export function FilesTab({ instanceId }: FilesTabProps) {
+  const [currentDir, setCurrentDir] = useState('')
+  const [entries, setEntries] = useState<FileEntry[]>([])
+  const [loading, setLoading] = useState(true)
+  const [pending, setPending] = useState<PendingAction | null>(null)
+  const [name, setName] = useState('')
+  const [viewing, setViewing] = useState<FileEntry | null>(null)
+  const { showToast } = useToast()
+
+  useEffect(() => {
+    setCurrentDir('')
+    setViewing(null)
+  }, [instanceId])
+
+  useEffect(() => {
+    loadEntries()
+  }, [instanceId, currentDir])
+
+  const loadEntries = async () => {
+    setLoading(true)
+    try {
+      setEntries(await invoke<FileEntry[]>('list_instance_dir', { instanceId, relPath: currentDir }))
+    } catch (err) {
+      showToast(`Failed to list files: ${err}`, 'error')
+      setEntries([])
+    } finally {
+      setLoading(false)
+    }
+  }
+
+  const startAction = (action: PendingAction) => {
+    setPending(action)
+    setName(action.kind === 'mkdir' ? '' : action.kind === 'copy' ? `${action.entry.name} copy` : action.entry.name)
+  }
+
+  const submitAction = async (e: React.FormEvent) => {
+    e.preventDefault()
+    if (!pending || !name.trim()) return
+    const target = joinPath(currentDir, name.trim())
+    try {
+      if (pending.kind === 'mkdir') {
+        await invoke('create_instance_dir', { instanceId, relPath: target })
+      } else if (pending.kind === 'rename') {
+        await invoke('rename_instance_file', { instanceId, from: pending.entry.path, to: target })
+      } else {
+        await invoke('copy_instance_file', { instanceId, from: pending.entry.path, to: target })
+      }
+      setPending(null)
+      loadEntries()
+    } catch (err) {
+      showToast(`Error: ${err}`, 'error')
+    }
+  }
+
+  const handleDelete = async (entry: FileEntry) => {
+    try {
+      await invoke('delete_instance_file', { instanceId, relPath: entry.path })
+      showToast(`Deleted ${entry.name}`)
+      loadEntries()
+    } catch (err) {
+      showToast(`Failed to delete ${entry.name}: ${err}`, 'error')
+    }
+  }
+
+  const handleDownload = async (entry: FileEntry) => {
+    const destination = await save({ defaultPath: entry.name })
+    if (!destination) return
+    try {
+      await invoke('export_instance_file', { instanceId, relPath: entry.path, destination })
+      showToast(`Saved ${entry.name}`)
+    } catch (err) {
+      showToast(`Failed to save ${entry.name}: ${err}`, 'error')
+    }
+  }
+
+  const crumbs = currentDir ? currentDir.split('/') : []
+
+  return (
+    <div className="flex flex-col h-full gap-4">
+      <div className="flex items-center gap-2 bg-surface/50 border border-black/5 dark:border-white/5 rounded-2xl px-4 py-3">
+        <button onClick={() => setCurrentDir('')} className="text-sm font-bold hover:text-primary transition-colors">
+          Instance
+        </button>
+        {crumbs.map((crumb, i) => (
+          <span key={i} className="flex items-center gap-2">
+            <ChevronRight size={14} className="text-gray-400" />
+            <button
+              onClick={() => setCurrentDir(crumbs.slice(0, i + 1).join('/'))}
+              className="text-sm font-bold hover:text-primary transition-colors"
+            >
+              {crumb}
+            </button>
+          </span>
+        ))}
+        <div className="flex-1" />
+        <button
+          onClick={() => startAction({ kind: 'mkdir' })}
+          className="p-2 hover:bg-primary/10 text-primary rounded-lg transition-all"
+          title="New folder"
+        >
+          <FolderPlus size={18} />
+        </button>
+        <button
+          onClick={loadEntries}
+          className="p-2 hover:bg-black/5 dark:hover:bg-white/5 rounded-lg transition-all"
+          title="Refresh"
+        >
+          <RefreshCw size={18} className={loading ? 'animate-spin' : ''} />
+        </button>
+      </div>
+
+      {pending && (
+        <form onSubmit={submitAction} className="flex items-center gap-2">
+          <input
+            autoFocus
+            value={name}
+            onChange={e => setName(e.target.value)}
+            placeholder={pending.kind === 'mkdir' ? 'Folder name' : 'New name'}
+            className="flex-1 bg-black/5 dark:bg-white/5 border border-black/10 dark:border-white/10 rounded-lg px-3 py-2 text-sm focus:outline-none focus:border-primary/50"
+          />
+          <button type="submit" className="px-4 py-2 bg-primary text-white rounded-lg text-sm font-bold">
+            {pending.kind === 'mkdir' ? 'Create' : pending.kind === 'rename' ? 'Rename' : 'Copy'}
+          </button>
+          <button type="button" onClick={() => setPending(null)} className="px-4 py-2 text-sm font-bold text-gray-500">
+            Cancel
+          </button>
+        </form>
+      )}
+
+      <div className="flex-1 overflow-y-auto min-h-0 custom-scrollbar bg-surface/50 border border-black/5 dark:border-white/5 rounded-2xl">
+        {!loading && entries.length === 0 && (
+          <p className="text-sm text-gray-500 dark:text-white/40 text-center py-12">This folder is empty.</p>
+        )}
+        {entries.map(entry => (
+          <div
+            key={entry.path}
+            className="group flex items-center gap-3 px-4 py-2.5 border-b border-black/5 dark:border-white/5 last:border-0 hover:bg-black/[0.02] dark:hover:bg-white/[0.02]"
+          >
+            {entry.is_symlink ? (
+              <Link size={16} className="text-gray-400 shrink-0" />
+            ) : entry.is_dir ? (
+              <Folder size={16} className="text-primary shrink-0" />
+            ) : (
+              <File size={16} className="text-gray-400 shrink-0" />
+            )}
+            <button
+              onClick={() => {
+                if (entry.is_symlink) return
+                if (entry.is_dir) setCurrentDir(entry.path)
+                else setViewing(entry)
+              }}
+              className="flex-1 text-left text-sm font-medium truncate"
+            >
+              {entry.name}
+            </button>
+            <span className="text-xs text-gray-500 dark:text-white/40 w-20 text-right">
+              {entry.is_dir ? '' : formatSize(entry.size)}
+            </span>
+            <span className="text-xs text-gray-500 dark:text-white/40 w-36 text-right">
+              {entry.modified ? new Date(entry.modified).toLocaleString() : ''}
+            </span>
+            <div className="flex items-center gap-1 opacity-0 group-hover:opacity-100 transition-opacity">
+              <button onClick={() => startAction({ kind: 'rename', entry })} className="p-1.5 hover:bg-black/5 dark:hover:bg-white/5 rounded-lg" title="Rename">
+                <Pencil size={14} />
+              </button>
+              {!entry.is_symlink && (
+                <button onClick={() => startAction({ kind: 'copy', entry })} className="p-1.5 hover:bg-black/5 dark:hover:bg-white/5 rounded-lg" title="Copy">
+                  <Copy size={14} />
+                </button>
+              )}
+              {!entry.is_dir && !entry.is_symlink && (
+                <button onClick={() => handleDownload(entry)} className="p-1.5 hover:bg-black/5 dark:hover:bg-white/5 rounded-lg" title="Download">
+                  <Download size={14} />
+                </button>
+              )}
+              <ConfirmDropdown
+                title={entry.is_dir ? 'Delete Folder' : 'Delete File'}
+                message={`Are you sure you want to delete "${entry.name}"${entry.is_dir ? ' and everything in it' : ''}? This action cannot be undone.`}
+                onConfirm={() => handleDelete(entry)}
+                confirmText="Delete"
+                variant="danger"
+              >
+                <button className="p-1.5 hover:bg-red-500/20 text-red-500 rounded-lg" title="Delete">
+                  <Trash2 size={14} />
+                </button>
+              </ConfirmDropdown>
+            </div>
+          </div>
+        ))}
+      </div>
+
+      {viewing && <FileViewer key={viewing.path} instanceId={instanceId} entry={viewing} onClose={() => setViewing(null)} />}
+    </div>
+  );
+}
@@ -1,11 +1,15 @@
 import { useState, useRef, useEffect } from 'react'
 import { createPortal } from 'react-dom'
 import { invoke } from '@tauri-apps/api/core'
+import { save } from '@tauri-apps/plugin-dialog'
 import { Settings } from 'lucide-react'
-import { cn } from './utils'
+import { cn, formatSize } from './utils'
+import { DeletionSummary, InstallerRepair } from './types'
 import { motion, AnimatePresence } from 'framer-motion'
 import { MainActions } from './instance-settings/MainActions'
 import { CloneForm } from './instance-settings/CloneForm'
+import { TemplateForm } from './instance-settings/TemplateForm'
+import { MoveForm } from './instance-settings/MoveForm'
 import { DeleteConfirm } from './instance-settings/DeleteConfirm'
 import { useToast } from './hooks/useToast'
 import { useAppSettings } from './hooks/useAppSettings'
@@ -14,6 +18,9 @@ interface InstanceSettingsDropdownProps {
   instance: {
     id: string;
     name: string;
+    mod_loader?: string | null;
+    path?: string;
+    linked?: boolean;
   };
   onUpdated: (id?: string) => void;
   size?: number;
@@ -34,6 +41,12 @@ export function InstanceSettingsDropdown({
   const [cloneName, setCloneName] = useState(`${instance.name} (Copy)`);
   const [isDeleting, setIsDeleting] = useState(false);
   const [isCloning, setIsCloning] = useState(false);
+  const [showTemplateForm, setShowTemplateForm] = useState(false);
+  const [templateName, setTemplateName] = useState(instance.name);
+  const [templateConfigFiles, setTemplateConfigFiles] = useState('server.properties');
+  const [isSavingTemplate, setIsSavingTemplate] = useState(false);
+  const [showMoveForm, setShowMoveForm] = useState(false);
+  const [isMoving, setIsMoving] = useState(false);
   const [coords, setCoords] = useState({ top: 0, left: 0 });
   const { showToast } = useToast();
   const { settings } = useAppSettings();
@@ -62,6 +75,8 @@ export function InstanceSettingsDropdown({
         setTimeout(() => {
           setShowDeleteConfirm(false);
           setShowCloneForm(false);
+          setShowTemplateForm(false);
+          setShowMoveForm(false);
         }, 200);
       }
     }
@@ -83,10 +98,10 @@ export function InstanceSettingsDropdown({
   async function handleDelete() {
     try {
       setIsDeleting(true);
-      await invoke('delete_instance', { instanceId: instance.id });
+      const summary = await invoke<DeletionSummary>('delete_instance', { instanceId: instance.id });
       onUpdated();
       setIsOpen(false);
-      showToast('Instance deleted successfully');
+      showToast(`Instance deleted, ${formatSize(summary.reclaimed_bytes)} reclaimed`);
     } catch (e) {
       console.error('Failed to delete instance', e);
       showToast('Failed to delete instance: ' + e, 'error');
@@ -111,6 +126,85 @@ export function InstanceSettingsDropdown({
     }
   }
 
+  async function handleSaveTemplate() {
+    if (!templateName.trim()) return;
+    try {
+      setIsSavingTemplate(true);
+      const configFiles = templateConfigFiles.split('\n').map(f => f.trim()).filter(Boolean);
+      await invoke('save_instance_as_template', {
+        instanceId: instance.id,
+        name: templateName,
+        description: null,
+        configFiles,
+      });
+      setIsOpen(false);
+      setShowTemplateForm(false);
+      showToast(`Saved template "${templateName.trim()}"`);
+    } catch (e) {
+      console.error('Failed to save template', e);
+      showToast('Failed to save template: ' + e, 'error');
+    } finally {
+      setIsSavingTemplate(false);
+    }
+  }
+
+  async function handleMove(targetRoot: string) {
+    try {
+      setIsMoving(true);
+      await invoke('move_instance', { instanceId: instance.id, targetRoot });
+      onUpdated(instance.id);
+      setIsOpen(false);
+      setShowMoveForm(false);
+      showToast('Instance moved');
+    } catch (e) {
+      console.error('Failed to move instance', e);
+      showToast('Failed to move instance: ' + e, 'error');
+    } finally {
+      setIsMoving(false);
+    }
+  }
+
+  async function handleExport() {
+    setIsOpen(false);
+    const destPath = await save({
+      defaultPath: `${instance.name}.zip`,
+      filters: [
+        { name: 'Zip Archive', extensions: ['zip'] },
+        { name: '7-Zip Archive', extensions: ['7z'] },
+      ],
+    });
+    if (!destPath) return;
+
+    try {
+      showToast('Exporting instance...');
+      const format = destPath.toLowerCase().endsWith('.7z') ? 'SevenZ' : 'Zip';
+      await invoke<string>('export_instance', { instanceId: instance.id, destPath, format });
+      showToast('Instance exported successfully');
+    } catch (e) {
+      console.error('Failed to export instance', e);
+      showToast('Failed to export instance: ' + e, 'error');
+    }
+  }
+
+  async function handleRepair() {
+    setIsOpen(false);
+    try {
+      showToast('Checking installation...');
+      const report = await invoke<InstallerRepair>('repair_installation', { instanceId: instance.id });
+      if (!report.rerun_from) {
+        showToast('Installation is intact, nothing to repair');
+      } else {
+        showToast(`Installation repaired (${report.problems.length} problems, ${report.removed_files} broken files removed)`);
+      }
+      onUpdated(instance.id);
+    } catch (e) {
+      console.error('Failed to repair installation', e);
+      showToast('Failed to repair installation: ' + e, 'error');
+    }
+  }
+
+  const canRepair = ['forge', 'neoforge'].includes(instance.mod_loader?.toLowerCase() ?? '');
+
   const dropdownContent = (
     <AnimatePresence mode="wait">
       {isOpen && (
@@ -132,9 +226,13 @@ export function InstanceSettingsDropdown({
             className="w-72 bg-white dark:bg-gray-900 border border-black/10 dark:border-white/10 rounded-2xl shadow-2xl overflow-hidden ring-1 ring-black/5 dark:ring-white/10"
           >
             <AnimatePresence mode="wait">
-              {!showDeleteConfirm && !showCloneForm && (
+              {!showDeleteConfirm && !showCloneForm && !showTemplateForm && !showMoveForm && (
                 <MainActions
                   onShowClone={() => setShowCloneForm(true)}
+                  onShowTemplate={() => setShowTemplateForm(true)}
+                  onExport={handleExport}
+                  onShowMove={instance.linked ? undefined : () => setShowMoveForm(true)}
+                  onRepair={canRepair ? handleRepair : undefined}
                   onShowDelete={() => setShowDeleteConfirm(true)}
                 />
               )}
@@ -149,6 +247,27 @@ export function InstanceSettingsDropdown({
                 />
               )}
 
+              {showTemplateForm && (
+                <TemplateForm
+                  templateName={templateName}
+                  setTemplateName={setTemplateName}
+                  configFiles={templateConfigFiles}
+                  setConfigFiles={setTemplateConfigFiles}
+                  onSave={handleSaveTemplate}
+                  onBack={() => setShowTemplateForm(false)}
+                  isSaving={isSavingTemplate}
+                />
+              )}
+
+              {showMoveForm && (
+                <MoveForm
+                  currentPath={instance.path}
+                  onMove={handleMove}
+                  onBack={() => setShowMoveForm(false)}
+                  isMoving={isMoving}
+                />
+              )}
+
               {showDeleteConfirm && (
                 <DeleteConfirm
                   instanceName={instance.name}
@@ -8,7 +8,10 @@ import {
   RefreshCw,
   Save,
   Upload,
-  AlertCircle
+  AlertCircle,
+  Bell,
+  Stethoscope,
+  History
 } from 'lucide-react'
 import { motion, AnimatePresence } from 'framer-motion'
 import { cn } from './utils'
@@ -17,14 +20,24 @@ import { useAppSettings } from './hooks/useAppSettings'
 import { Instance, InstanceSettings, CrashHandlingMode } from './types'
 import { GeneralSettings } from './settings/GeneralSettings'
 import { JVMOptions } from './settings/JVMOptions'
+import { JavaAgents } from './settings/JavaAgents'
+import { LaunchEnvironment } from './settings/LaunchEnvironment'
+import { ResourceLimits } from './settings/ResourceLimits'
 import { IconSettings } from './settings/IconSettings'
+import { WebhookSettings } from './settings/WebhookSettings'
+import { HeartbeatSettings } from './settings/HeartbeatSettings'
+import { NotificationRules } from './settings/NotificationRules'
+import { HealthCheck } from './settings/HealthCheck'
+import { Timeline } from './settings/Timeline'
+import { StartupDependencies } from './settings/StartupDependencies'
+import { MotdEditor } from './settings/MotdEditor'
 
 interface InstanceSettingsTabProps {
   instance: Instance;
   onUpdate?: () => void;
 }
 
-type SettingsSubTab = 'general' | 'advanced' | 'crash' | 'update';
+type SettingsSubTab = 'general' | 'advanced' | 'crash' | 'notifications' | 'health' | 'update';
 
 export function InstanceSettingsTab({ instance, onUpdate }: InstanceSettingsTabProps) {
   const [activeSubTab, setActiveSubTab] = useState<SettingsSubTab>('general')
@@ -157,6 +170,9 @@ export function InstanceSettingsTab({ instance, onUpdate }: InstanceSettingsTabP
     { id: 'general', label: 'General', icon: Settings },
     { id: 'advanced', label: 'Advanced', icon: Terminal },
     { id: 'crash', label: 'Crash Handling', icon: Shield },
+    { id: 'notifications', label: 'Notifications', icon: Bell },
+    { id: 'health', label: 'Health Check', icon: Stethoscope },
+    { id: 'timeline', label: 'Timeline', icon: History },
     { id: 'update', label: 'Update Server', icon: RefreshCw },
   ]
 
@@ -218,6 +234,12 @@ export function InstanceSettingsTab({ instance, onUpdate }: InstanceSettingsTabP
                   updateSetting={updateSetting}
                 />
               </div>
+              <MotdEditor instanceId={instance.id} />
+              <StartupDependencies
+                instanceId={instance.id}
+                settings={settings}
+                updateSetting={updateSetting}
+              />
             </motion.div>
           )}
 
@@ -242,6 +264,41 @@ export function InstanceSettingsTab({ instance, onUpdate }: InstanceSettingsTabP
                 loadBatFiles={loadBatFiles}
                 showToast={showToast}
               />
+              <JavaAgents
+                instanceId={instance.id}
+                settings={settings}
+                updateSetting={updateSetting}
+              />
+              <LaunchEnvironment
+                settings={settings}
+                updateSetting={updateSetting}
+              />
+              <ResourceLimits
+                settings={settings}
+                updateSetting={updateSetting}
+              />
+            </motion.div>
+          )}
+
+          {activeSubTab === 'notifications' && (
+            <motion.div
+              key="notifications"
+              initial={{ opacity: 0, y: 10 }}
+              animate={{ opacity: 1, y: 0 }}
+              exit={{ opacity: 0, y: -10 }}
+              className="space-y-10"
+            >
+              <WebhookSettings
+                instanceId={instance.id}
+                settings={settings}
+                updateSetting={updateSetting}
+              />
+              <HeartbeatSettings
+                instanceId={instance.id}
+                settings={settings}
+                updateSetting={updateSetting}
+              />
+              <NotificationRules instanceId={instance.id} />
             </motion.div>
           )}
 
@@ -311,6 +368,30 @@ export function InstanceSettingsTab({ instance, onUpdate }: InstanceSettingsTabP
             </motion.div>
           )}
 
+          {activeSubTab === 'health' && (
+            <motion.div
+              key="health"
+              initial={{ opacity: 0, y: 10 }}
+              animate={{ opacity: 1, y: 0 }}
+              exit={{ opacity: 0, y: -10 }}
+              className="space-y-6"
+            >
+              <HealthCheck instanceId={instance.id} />
+            </motion.div>
+          )}
+
+          {activeSubTab === 'timeline' && (
+            <motion.div
+              key="timeline"
+              initial={{ opacity: 0, y: 10 }}
+              animate={{ opacity: 1, y: 0 }}
+              exit={{ opacity: 0, y: -10 }}
+              className="space-y-6"
+            >
+              <Timeline instanceId={instance.id} />
+            </motion.div>
+          )}
+
           {activeSubTab === 'update' && (
             <motion.div
               key="update"
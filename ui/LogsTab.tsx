@@ -9,11 +9,33 @@ interface LogsTabProps {
   instanceId: string;
 }
 
+interface LogFileInfo {
+  name: string;
+  size: number;
+  uncompressed_size: number;
+  modified?: string;
+  compressed: boolean;
+}
+
+interface LogPage {
+  content: string;
+  offset: number;
+  next_offset: number;
+  eof: boolean;
+}
+
+const LATEST_LOG = 'latest.log'
+const PAGE_SIZE = 256 * 1024
+
 export function LogsTab({ instanceId }: LogsTabProps) {
   const [logs, setLogs] = useState<string[]>([])
   const [searchQuery, setSearchQuery] = useState('')
   const [loading, setLoading] = useState(true)
   const [error, setError] = useState<string | null>(null)
+  const [logFiles, setLogFiles] = useState<LogFileInfo[]>([])
+  const [selectedFile, setSelectedFile] = useState(LATEST_LOG)
+  const [nextOffset, setNextOffset] = useState<number | null>(null)
+  const [loadingMore, setLoadingMore] = useState(false)
   const logsEndRef = useRef<HTMLDivElement>(null)
   const { showToast } = useToast()
 
@@ -21,19 +43,44 @@ export function LogsTab({ instanceId }: LogsTabProps) {
     setLoading(true)
     setError(null)
     try {
-      const content = await invoke<string>('read_latest_log', { instanceId })
-      setLogs(content.split('\n').filter(line => line.trim() !== ''))
+      invoke<LogFileInfo[]>('list_log_files', { instanceId }).then(setLogFiles).catch(() => setLogFiles([]))
+      if (selectedFile === LATEST_LOG) {
+        const content = await invoke<string>('read_latest_log', { instanceId })
+        setLogs(content.split('\n').filter(line => line.trim() !== ''))
+        setNextOffset(null)
+      } else {
+        const page = await invoke<LogPage>('read_log_file', { instanceId, name: selectedFile, offset: 0, limit: PAGE_SIZE })
+        setLogs(page.content.split('\n').filter(line => line.trim() !== ''))
+        setNextOffset(page.eof ? null : page.next_offset)
+      }
     } catch (err) {
       console.error('Failed to read logs:', err)
-      setError('Failed to load latest.log. Make sure the server has been started at least once.')
+      setError(selectedFile === LATEST_LOG
+        ? 'Failed to load latest.log. Make sure the server has been started at least once.'
+        : `Failed to load ${selectedFile}.`)
     } finally {
       setLoading(false)
     }
   }
 
+  const loadMore = async () => {
+    if (nextOffset === null) return
+    setLoadingMore(true)
+    try {
+      const page = await invoke<LogPage>('read_log_file', { instanceId, name: selectedFile, offset: nextOffset, limit: PAGE_SIZE })
+      setLogs(prev => [...prev, ...page.content.split('\n').filter(line => line.trim() !== '')])
+      setNextOffset(page.eof ? null : page.next_offset)
+    } catch (err) {
+      console.error('Failed to read logs:', err)
+      showToast(`Error: ${err}`, 'error')
+    } finally {
+      setLoadingMore(false)
+    }
+  }
+
   useEffect(() => {
     fetchLogs()
-  }, [instanceId])
+  }, [instanceId, selectedFile])
 
   const filteredLogs = logs.filter(line =>
     line.toLowerCase().includes(searchQuery.toLowerCase())
@@ -43,7 +90,7 @@ export function LogsTab({ instanceId }: LogsTabProps) {
     const element = document.createElement("a");
     const file = new Blob([logs.join('\n')], { type: 'text/plain' });
     element.href = URL.createObjectURL(file);
-    element.download = "latest.log";
+    element.download = selectedFile.replace(/\.gz$/, '');
     document.body.appendChild(element);
     element.click();
     document.body.removeChild(element);
@@ -53,7 +100,7 @@ export function LogsTab({ instanceId }: LogsTabProps) {
     try {
       await invoke('open_file_in_editor', {
         instanceId,
-        relPath: 'logs/latest.log'
+        relPath: `logs/${selectedFile}`
       })
     } catch (err) {
       console.error('Failed to open external editor:', err)
@@ -93,6 +140,17 @@ export function LogsTab({ instanceId }: LogsTabProps) {
           />
         </div>
         <div className="flex items-center gap-3">
+          <select
+            value={selectedFile}
+            onChange={(e) => setSelectedFile(e.target.value)}
+            className="bg-black/5 dark:bg-white/[0.03] border border-black/10 dark:border-white/10 rounded-xl py-3 px-4 text-sm text-gray-900 dark:text-white focus:outline-none focus:ring-2 focus:ring-primary/50 transition-all font-medium max-w-56"
+            title="Log file"
+          >
+            <option value={LATEST_LOG}>{LATEST_LOG}</option>
+            {logFiles.filter(f => f.name !== LATEST_LOG).map(f => (
+              <option key={f.name} value={f.name}>{f.name}</option>
+            ))}
+          </select>
           <motion.button
             whileHover={{ scale: 1.02, translateY: -2 }}
             whileTap={{ scale: 0.98 }}
@@ -107,7 +165,8 @@ export function LogsTab({ instanceId }: LogsTabProps) {
             whileHover={{ scale: 1.02, translateY: -2 }}
             whileTap={{ scale: 0.98 }}
             onClick={openExternal}
-            className="p-3 bg-black/5 dark:bg-white/[0.03] hover:bg-black/10 dark:hover:bg-white/[0.08] rounded-xl border border-black/10 dark:border-white/5 text-gray-400 dark:text-white/40 hover:text-gray-900 dark:hover:text-white transition-all shadow-lg"
+            disabled={selectedFile.endsWith('.gz')}
+            className="p-3 bg-black/5 dark:bg-white/[0.03] hover:bg-black/10 dark:hover:bg-white/[0.08] rounded-xl border border-black/10 dark:border-white/5 text-gray-400 dark:text-white/40 hover:text-gray-900 dark:hover:text-white transition-all disabled:opacity-50 shadow-lg"
             title="Open in external editor"
           >
             <Share size={20} />
@@ -137,7 +196,7 @@ export function LogsTab({ instanceId }: LogsTabProps) {
             <div className="w-3 h-3 rounded-full bg-accent-emerald/50" />
           </div>
           <FileText size={16} className="text-primary" />
-          <span className="text-gray-900 dark:text-white">latest.log</span>
+          <span className="text-gray-900 dark:text-white">{selectedFile}</span>
           {searchQuery && (
             <motion.span
               initial={{ opacity: 0, x: -10 }}
@@ -205,6 +264,15 @@ export function LogsTab({ instanceId }: LogsTabProps) {
                     {formatLogLine(line)}
                   </div>
                 ))}
+                {nextOffset !== null && (
+                  <button
+                    onClick={loadMore}
+                    disabled={loadingMore}
+                    className="mt-4 w-full py-2 bg-primary/10 hover:bg-primary/20 rounded-lg text-xs font-black uppercase tracking-widest text-primary transition-all disabled:opacity-50"
+                  >
+                    {loadingMore ? 'Loading...' : 'Load more'}
+                  </button>
+                )}
               </motion.div>
             )}
           </AnimatePresence>
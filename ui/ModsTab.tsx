@@ -6,6 +6,7 @@ import {
 import { InstalledMods } from './mods/InstalledMods'
 import { AnimatePresence } from 'framer-motion'
 import { ModMarketplaceModal } from './mods/ModMarketplaceModal'
+import { useFileChanges } from './hooks/useFileChanges'
 
 interface ModsTabProps {
   instanceId: string;
@@ -15,6 +16,8 @@ export function ModsTab({ instanceId }: ModsTabProps) {
   const [isMarketplaceOpen, setIsMarketplaceOpen] = useState(false)
   const [refreshTrigger, setRefreshTrigger] = useState(0)
 
+  useFileChanges(instanceId, 'mods', () => setRefreshTrigger(prev => prev + 1))
+
   const handleInstallSuccess = () => {
     setRefreshTrigger(prev => prev + 1)
     setIsMarketplaceOpen(false)
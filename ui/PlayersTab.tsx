@@ -8,6 +8,7 @@ import { TextEditor } from './components/TextEditor'
 import { PlayerCard } from './components/players/PlayerCard'
 import { PlayerListTable } from './components/players/PlayerListTable'
 import { PlayerHeader } from './components/players/PlayerHeader'
+import { AccessWarnings } from './components/players/AccessWarnings'
 import { useToast } from './hooks/useToast'
 import { AppSettings } from './hooks/useAppSettings'
 
@@ -306,6 +307,8 @@ export function PlayersTab({ instanceId, settings }: PlayersTabProps) {
         )}
       </AnimatePresence>
 
+      <AccessWarnings instanceId={instanceId} lists={lists} onFixed={fetchLists} />
+
       <div className="flex-1 overflow-y-auto min-h-0 pr-2 custom-scrollbar">
         <AnimatePresence mode="wait">
           <motion.div
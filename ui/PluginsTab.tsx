@@ -2,21 +2,26 @@ import { useState } from 'react'
 import {
   Puzzle,
   Plus,
+  AlertTriangle,
 } from 'lucide-react'
 import { InstalledPlugins } from './plugins/InstalledPlugins'
 import { MarketplaceModal } from './plugins/MarketplaceModal'
 import { DatabaseExplorerModal } from './database/DatabaseExplorerModal'
 import { AnimatePresence } from 'framer-motion'
+import { useFileChanges } from './hooks/useFileChanges'
 
 interface PluginsTabProps {
   instanceId: string;
+  foliaPluginsOnly?: boolean;
 }
 
-export function PluginsTab({ instanceId }: PluginsTabProps) {
+export function PluginsTab({ instanceId, foliaPluginsOnly }: PluginsTabProps) {
   const [isMarketplaceOpen, setIsMarketplaceOpen] = useState(false)
   const [isDbExplorerOpen, setIsDbExplorerOpen] = useState(false)
   const [refreshTrigger, setRefreshTrigger] = useState(0)
 
+  useFileChanges(instanceId, 'plugins', () => setRefreshTrigger(prev => prev + 1))
+
   const handleInstallSuccess = () => {
     setRefreshTrigger(prev => prev + 1)
     setIsMarketplaceOpen(false)
@@ -44,6 +49,16 @@ export function PluginsTab({ instanceId }: PluginsTabProps) {
         </button>
       </div>
 
+      {foliaPluginsOnly && (
+        <div className="flex items-start gap-3 p-4 rounded-2xl bg-amber-500/10 border border-amber-500/20 text-amber-600 dark:text-amber-400 text-sm">
+          <AlertTriangle size={18} className="shrink-0 mt-0.5" />
+          <p>
+            This server runs Folia. Only plugins built for Folia's regionized threading (marked
+            <code className="mx-1 font-mono">folia-supported</code>in plugin.yml) will load; most Paper plugins won't.
+          </p>
+        </div>
+      )}
+
       <div className="min-h-[500px]">
         <InstalledPlugins 
           instanceId={instanceId} 
@@ -1,5 +1,6 @@
 import React, { useState, useEffect } from 'react'
 import { invoke } from '@tauri-apps/api/core'
+import { open, save } from '@tauri-apps/plugin-dialog'
 import {
     Calendar,
     Plus,
@@ -9,10 +10,13 @@ import {
     Save,
     CheckCircle2,
     AlertCircle,
-    AlertTriangle
+    AlertTriangle,
+    Download,
+    Upload,
+    Megaphone
 } from 'lucide-react'
 import { motion, AnimatePresence } from 'framer-motion'
-import { ScheduledTask, ScheduleType } from './types'
+import { AutomationImportSummary, ScheduledTask, ScheduleType } from './types'
 import { useToast } from './hooks/useToast'
 import { ConfirmDropdown } from './components/ConfirmDropdown'
 import { Select } from './components/Select'
@@ -29,9 +33,13 @@ export function SchedulesTab({ instanceId }: SchedulesTabProps) {
     const [newTask, setNewTask] = useState<{
         task_type: ScheduleType;
         cron: string;
+        warning_secs: number;
+        warning_message: string;
     }>({
         task_type: 'Backup',
-        cron: '0 0 * * *' // Default daily at midnight
+        cron: '0 0 * * *', // Default daily at midnight
+        warning_secs: 60,
+        warning_message: ''
     });
 
     const fetchTasks = async () => {
@@ -54,7 +62,10 @@ export function SchedulesTab({ instanceId }: SchedulesTabProps) {
             await invoke('add_scheduled_task', {
                 instanceId,
                 taskType: newTask.task_type,
-                cron: newTask.cron
+                cron: newTask.cron,
+                warning: newTask.task_type === 'Restart' && newTask.warning_secs > 0
+                    ? { delay_secs: newTask.warning_secs, message: newTask.warning_message.trim() || null }
+                    : null
             });
             setIsAdding(false);
             fetchTasks();
@@ -75,6 +86,39 @@ export function SchedulesTab({ instanceId }: SchedulesTabProps) {
         }
     };
 
+    const automationFilters = [
+        { name: 'TOML', extensions: ['toml'] },
+        { name: 'JSON', extensions: ['json'] },
+    ];
+
+    const handleExportAutomation = async () => {
+        const destPath = await save({ defaultPath: 'automation.toml', filters: automationFilters });
+        if (!destPath) return;
+        try {
+            await invoke<string>('export_automation_config', { instanceIds: [instanceId], destPath });
+            showToast('Schedules and notifications exported');
+        } catch (error) {
+            console.error('Failed to export automation config:', error);
+            showToast(`Failed to export: ${error}`, 'error');
+        }
+    };
+
+    const handleImportAutomation = async () => {
+        const selected = await open({ multiple: false, filters: automationFilters });
+        if (!selected || Array.isArray(selected)) return;
+        try {
+            const summary = await invoke<AutomationImportSummary>('import_automation_config', { sourcePath: selected });
+            showToast(
+                `Imported ${summary.schedules_added} schedules and ${summary.rules_added + summary.rules_updated} notification rules` +
+                (summary.schedules_skipped > 0 ? ` (${summary.schedules_skipped} duplicates skipped)` : '')
+            );
+            fetchTasks();
+        } catch (error) {
+            console.error('Failed to import automation config:', error);
+            showToast(`Failed to import: ${error}`, 'error');
+        }
+    };
+
     if (loading) {
         return (
             <div className="flex items-center justify-center h-full">
@@ -93,13 +137,29 @@ export function SchedulesTab({ instanceId }: SchedulesTabProps) {
                     </h2>
                     <p className="text-gray-400 mt-1">Manage automated backups and server restarts.</p>
                 </div>
-                <button
-                    onClick={() => setIsAdding(true)}
-                    className="flex items-center gap-2 px-4 py-2 bg-primary hover:bg-primary/90 text-white rounded-xl transition-all shadow-lg shadow-primary/20"
-                >
-                    <Plus className="w-4 h-4" />
-                    Add Schedule
-                </button>
+                <div className="flex items-center gap-2">
+                    <button
+                        onClick={handleImportAutomation}
+                        title="Import schedules, webhooks and notification rules"
+                        className="flex items-center gap-2 px-3 py-2 bg-white/5 hover:bg-white/10 rounded-xl transition-all"
+                    >
+                        <Upload className="w-4 h-4" />
+                    </button>
+                    <button
+                        onClick={handleExportAutomation}
+                        title="Export schedules, webhooks and notification rules"
+                        className="flex items-center gap-2 px-3 py-2 bg-white/5 hover:bg-white/10 rounded-xl transition-all"
+                    >
+                        <Download className="w-4 h-4" />
+                    </button>
+                    <button
+                        onClick={() => setIsAdding(true)}
+                        className="flex items-center gap-2 px-4 py-2 bg-primary hover:bg-primary/90 text-white rounded-xl transition-all shadow-lg shadow-primary/20"
+                    >
+                        <Plus className="w-4 h-4" />
+                        Add Schedule
+                    </button>
+                </div>
             </div>
 
             <AnimatePresence>
@@ -136,6 +196,38 @@ export function SchedulesTab({ instanceId }: SchedulesTabProps) {
                                 </p>
                             </div>
                         </div>
+                        {newTask.task_type === 'Restart' && (
+                            <div className="grid grid-cols-1 md:grid-cols-2 gap-4">
+                                <div className="space-y-2">
+                                    <label className="text-sm font-medium text-gray-400">Warn Players</label>
+                                    <Select
+                                        value={String(newTask.warning_secs)}
+                                        onChange={(value) => setNewTask({ ...newTask, warning_secs: parseInt(value, 10) })}
+                                        options={[
+                                            { value: '0', label: 'No warning' },
+                                            { value: '30', label: '30 seconds before' },
+                                            { value: '60', label: '1 minute before' },
+                                            { value: '300', label: '5 minutes before' },
+                                            { value: '600', label: '10 minutes before' }
+                                        ]}
+                                    />
+                                </div>
+                                <div className="space-y-2">
+                                    <label className="text-sm font-medium text-gray-400">Warning Message</label>
+                                    <input
+                                        type="text"
+                                        value={newTask.warning_message}
+                                        disabled={newTask.warning_secs === 0}
+                                        onChange={(e) => setNewTask({ ...newTask, warning_message: e.target.value })}
+                                        placeholder="Server restarting in {time}"
+                                        className="w-full bg-black/20 border border-white/10 rounded-xl px-4 py-2 focus:outline-none focus:border-primary transition-colors disabled:opacity-50"
+                                    />
+                                    <p className="text-xs text-gray-500">
+                                        Broadcast with <code>say</code>; <code>{'{time}'}</code> becomes the time left. Players are kicked and the world saved before the restart.
+                                    </p>
+                                </div>
+                            </div>
+                        )}
                         <div className="flex justify-end gap-3">
                             <button
                                 onClick={() => setIsAdding(false)}
@@ -180,6 +272,12 @@ export function SchedulesTab({ instanceId }: SchedulesTabProps) {
                                             <Clock className="w-3.5 h-3.5" />
                                             {task.cron}
                                         </span>
+                                        {task.warning && task.warning.delay_secs > 0 && (
+                                            <span className="flex items-center gap-1 text-gray-400 shrink-0">
+                                                <Megaphone className="w-3.5 h-3.5" />
+                                                Warns {task.warning.delay_secs >= 60 ? `${Math.round(task.warning.delay_secs / 60)}m` : `${task.warning.delay_secs}s`} ahead
+                                            </span>
+                                        )}
                                         {task.last_run && (
                                             <span className="flex items-center gap-1 text-gray-500 truncate">
                                                 <CheckCircle2 className="w-3.5 h-3.5 text-green-500/70 shrink-0" />
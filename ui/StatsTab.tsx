@@ -10,9 +10,10 @@ import {
   LineChart,
   Line
 } from 'recharts'
-import { BarChart3, Cpu, HardDrive, MemoryStick } from 'lucide-react'
+import { BarChart3, Cpu, Gauge, HardDrive, MemoryStick } from 'lucide-react'
 import { ResourceUsage, Instance } from './types'
 import { AppSettings } from './hooks/useAppSettings'
+import { MetricsExportButton } from './components/MetricsExportButton'
 
 interface StatsTabProps {
   history: ResourceUsage[];
@@ -36,7 +37,8 @@ export function StatsTab({ history, settings, currentInstance }: StatsTabProps)
     }))
   }, [history])
 
-  const latestUsage = history[history.length - 1] || { cpu_usage: 0, memory_usage: 0, disk_read: 0, disk_write: 0 }
+  const latestUsage = history[history.length - 1] || { cpu_usage: 0, memory_usage: 0, disk_read: 0, disk_write: 0, tps: null, mspt: null, tick_estimated: false }
+  const tpsColor = latestUsage.tps == null ? 'text-gray-400' : latestUsage.tps >= 18 ? 'text-emerald-500' : latestUsage.tps >= 15 ? 'text-amber-500' : 'text-red-500'
 
   const formatBytes = (bytes: number) => {
     if (bytes === 0) return '0 B'
@@ -58,9 +60,10 @@ export function StatsTab({ history, settings, currentInstance }: StatsTabProps)
             Real-time resource usage monitoring and historical data.
           </p>
         </div>
+        <MetricsExportButton instanceId={currentInstance.id} instanceName={currentInstance.name} />
       </div>
 
-      <div className="grid grid-cols-1 md:grid-cols-3 gap-6">
+      <div className="grid grid-cols-1 md:grid-cols-2 xl:grid-cols-4 gap-6">
         {/* CPU Card */}
         <div className="bg-surface/50 border border-black/5 dark:border-white/5 rounded-2xl p-6">
           <div className="flex items-center gap-3 mb-4">
@@ -87,6 +90,23 @@ export function StatsTab({ history, settings, currentInstance }: StatsTabProps)
           </div>
         </div>
 
+        {/* Tick Card */}
+        <div className="bg-surface/50 border border-black/5 dark:border-white/5 rounded-2xl p-6">
+          <div className="flex items-center gap-3 mb-4">
+            <div className="p-2 bg-amber-500/10 text-amber-500 rounded-lg">
+              <Gauge size={20} />
+            </div>
+            <h3 className="font-bold text-lg">TPS</h3>
+          </div>
+          <div className={`text-3xl font-black ${tpsColor}`}>
+            {latestUsage.tps == null ? 'N/A' : `${latestUsage.tick_estimated ? '~' : ''}${latestUsage.tps.toFixed(1)}`}
+          </div>
+          <div className="text-xs text-gray-500 mt-1">
+            {latestUsage.mspt == null ? 'MSPT unavailable' : `${latestUsage.mspt.toFixed(1)} ms/tick`}
+            {latestUsage.tick_estimated && ' · estimated from lag warnings'}
+          </div>
+        </div>
+
         {/* Disk Card */}
         <div className="bg-surface/50 border border-black/5 dark:border-white/5 rounded-2xl p-6">
           <div className="flex items-center gap-3 mb-4">
@@ -1,4 +1,4 @@
-import { X, Settings, Palette, Layout, Users, ChevronRight, Database } from 'lucide-react'
+import { X, Settings, Palette, Layout, Users, ChevronRight, Database, KeyRound, Download, Gauge, SquareTerminal, HardDrive, ScrollText } from 'lucide-react'
 import { motion, AnimatePresence } from 'framer-motion'
 import { useState } from 'react'
 import { cn } from '../utils'
@@ -7,6 +7,12 @@ import { AppearanceSettings } from './settings/AppearanceSettings'
 import { BehaviorSettings } from './settings/BehaviorSettings'
 import { SystemSettings } from './settings/SystemSettings'
 import { CacheSettings } from './settings/CacheSettings'
+import { DownloadSettingsPanel } from './settings/DownloadSettings'
+import { CredentialsSettings } from './settings/CredentialsSettings'
+import { ResourceLimitsSettings } from './settings/ResourceLimitsSettings'
+import { TerminalSettings } from './settings/TerminalSettings'
+import { StorageSettings } from './settings/StorageSettings'
+import { LogSettingsPanel } from './settings/LogSettings'
 
 interface AppSettingsModalProps {
   isOpen: boolean;
@@ -15,7 +21,7 @@ interface AppSettingsModalProps {
   updateSettings: (newSettings: Partial<AppSettings>) => void;
 }
 
-type SettingsTab = 'general' | 'appearance' | 'interface' | 'players' | 'cache' | 'java';
+type SettingsTab = 'general' | 'appearance' | 'interface' | 'players' | 'cache' | 'storage' | 'downloads' | 'limits' | 'terminal' | 'logs' | 'java' | 'accounts';
 
 interface TabItem {
   id: SettingsTab;
@@ -30,7 +36,13 @@ const TABS: TabItem[] = [
   { id: 'interface', label: 'Interface', icon: Layout, description: 'UI elements and layout' },
   { id: 'players', label: 'Players', icon: Users, description: 'Player skin and data settings' },
   { id: 'cache', label: 'Cache', icon: Database, description: 'Manage cached images and data' },
+  { id: 'storage', label: 'Storage', icon: HardDrive, description: 'Libraries shared between instances' },
+  { id: 'downloads', label: 'Downloads', icon: Download, description: 'Speed limits and mirrors' },
+  { id: 'limits', label: 'Limits', icon: Gauge, description: 'Running servers and RAM budget' },
+  { id: 'terminal', label: 'Terminal', icon: SquareTerminal, description: 'Shell commands in instance folders' },
+  { id: 'logs', label: 'Logs', icon: ScrollText, description: 'Wrapper log files and verbosity' },
   { id: 'java', label: 'Java', icon: Settings, description: 'Manage Java versions' },
+  { id: 'accounts', label: 'Accounts', icon: KeyRound, description: 'API keys and provider tokens' },
 ];
 
 function SidebarItem({
@@ -181,6 +193,41 @@ export function AppSettingsModal({
                         />
                       )}
 
+                      {activeTab === 'downloads' && (
+                        <DownloadSettingsPanel
+                          settings={settings}
+                          updateSettings={updateSettings}
+                        />
+                      )}
+
+                      {activeTab === 'storage' && (
+                        <StorageSettings
+                          settings={settings}
+                          updateSettings={updateSettings}
+                        />
+                      )}
+
+                      {activeTab === 'limits' && (
+                        <ResourceLimitsSettings
+                          settings={settings}
+                          updateSettings={updateSettings}
+                        />
+                      )}
+
+                      {activeTab === 'terminal' && (
+                        <TerminalSettings
+                          settings={settings}
+                          updateSettings={updateSettings}
+                        />
+                      )}
+
+                      {activeTab === 'logs' && (
+                        <LogSettingsPanel
+                          settings={settings}
+                          updateSettings={updateSettings}
+                        />
+                      )}
+
                       {activeTab === 'java' && (
                         <SystemSettings
                           settings={settings}
@@ -189,7 +236,14 @@ export function AppSettingsModal({
                       )}
 
                       {activeTab === 'cache' && (
-                        <CacheSettings />
+                        <CacheSettings
+                          settings={settings}
+                          updateSettings={updateSettings}
+                        />
+                      )}
+
+                      {activeTab === 'accounts' && (
+                        <CredentialsSettings />
                       )}
                     </motion.div>
                   </AnimatePresence>
@@ -0,0 +1,42 @@
+import { useEffect, useState } from 'react'
+import { listen } from '@tauri-apps/api/event'
+import { BulkProgress, BulkReport } from '../types'
+
+/** Shows the progress of a running bulk toggle/uninstall for this instance. */
+export function BulkProgressBar({ instanceId }: { instanceId: string }) {
+  const [progress, setProgress] = useState<BulkProgress | null>(null)
+
+  useEffect(() => {
+    const unlisten = listen<BulkProgress>('bulk-operation-progress', (event) => {
+      if (event.payload.instance_id !== instanceId) return
+      setProgress(event.payload.completed < event.payload.total ? event.payload : null)
+    })
+
+    return () => {
+      unlisten.then(u => u())
+    }
+  }, [instanceId])
+
+  if (!progress) return null
+
+  return (
+    <div className="p-3 bg-white/[0.02] border border-white/5 rounded-2xl space-y-2">
+      <div className="flex justify-between text-xs text-gray-400">
+        <span className="truncate">{progress.filename}</span>
+        <span>{progress.completed} / {progress.total}</span>
+      </div>
+      <div className="h-1.5 bg-white/5 rounded-full overflow-hidden">
+        <div className="h-full bg-primary transition-all" style={{ width: `${(progress.completed / progress.total) * 100}%` }} />
+      </div>
+    </div>
+  )
+}
+
+/** Summarizes a bulk report for a toast, naming the first few files that failed. */
+export function describeBulkReport(report: BulkReport, verb: string): { message: string; type: 'success' | 'error' } {
+  const failed = report.results.filter(r => r.error)
+  const succeeded = report.results.length - failed.length
+  if (failed.length === 0) return { message: `${verb} ${succeeded} file(s)`, type: 'success' }
+  const names = failed.slice(0, 3).map(r => `${r.filename}: ${r.error}`).join('; ')
+  return { message: `${verb} ${succeeded}, ${failed.length} failed (${names}${failed.length > 3 ? '; ...' : ''})`, type: 'error' }
+}
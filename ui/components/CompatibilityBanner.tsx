@@ -0,0 +1,61 @@
+import { useEffect, useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { AlertTriangle, Coffee, Gamepad2 } from 'lucide-react'
+import { Instance, ProxyCompatibilityReport, VersionCompatibility } from '../types'
+
+const PROXY_LOADERS = ['velocity', 'bungeecord'];
+
+function clientRange(clients: string[]) {
+  if (clients.length === 0) return 'unknown';
+  if (clients.length === 1) return clients[0];
+  return `${clients[0]} – ${clients[clients.length - 1]}`;
+}
+
+export function CompatibilityBanner({ instance }: { instance: Instance }) {
+  const [compat, setCompat] = useState<VersionCompatibility | null>(null);
+  const [proxy, setProxy] = useState<ProxyCompatibilityReport | null>(null);
+  const isProxy = PROXY_LOADERS.includes(instance.mod_loader?.toLowerCase() || '');
+
+  useEffect(() => {
+    setCompat(null);
+    setProxy(null);
+    if (isProxy) {
+      invoke<ProxyCompatibilityReport>('get_proxy_compatibility', { instanceId: instance.id })
+        .then(setProxy)
+        .catch(err => console.error('Failed to check proxy compatibility:', err));
+    } else {
+      invoke<VersionCompatibility>('get_version_compatibility', { instanceId: instance.id })
+        .then(setCompat)
+        .catch(err => console.error('Failed to load version compatibility:', err));
+    }
+  }, [instance.id, instance.version, isProxy]);
+
+  if (proxy) {
+    if (proxy.warnings.length === 0) return null;
+    return (
+      <div className="card border border-amber-500/30 bg-amber-500/5 space-y-1">
+        {proxy.warnings.map(warning => (
+          <p key={warning} className="flex items-center gap-2 text-sm text-amber-500 font-medium">
+            <AlertTriangle size={16} /> {warning}
+          </p>
+        ))}
+      </div>
+    );
+  }
+
+  if (!compat) return null;
+
+  return (
+    <div className="card flex flex-wrap items-center gap-6 text-sm font-medium text-gray-500">
+      <span className="flex items-center gap-2">
+        <Gamepad2 size={16} className="text-primary" />
+        Players need {clientRange(compat.compatible_clients)} clients
+        {compat.protocol != null && <span className="font-mono text-xs opacity-70">(protocol {compat.protocol})</span>}
+      </span>
+      <span className="flex items-center gap-2">
+        <Coffee size={16} className="text-accent-amber" />
+        Requires Java {compat.min_java}+
+      </span>
+    </div>
+  );
+}
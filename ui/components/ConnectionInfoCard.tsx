@@ -0,0 +1,88 @@
+import { useEffect, useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { Check, Copy, Globe, Home, Info, Share2, Waypoints } from 'lucide-react'
+import { AddressKind, ConnectionInfo, Instance } from '../types'
+import { useToast } from '../hooks/useToast'
+
+const KIND_LABELS: Record<AddressKind, { label: string; icon: any }> = {
+  tunnel: { label: 'Tunnel', icon: Waypoints },
+  public: { label: 'Internet', icon: Globe },
+  lan: { label: 'Same network', icon: Home },
+};
+
+export function ConnectionInfoCard({ instance, hideAddresses }: { instance: Instance; hideAddresses: boolean }) {
+  const [info, setInfo] = useState<ConnectionInfo | null>(null);
+  const [copied, setCopied] = useState<string | null>(null);
+  const { showToast } = useToast();
+
+  useEffect(() => {
+    setInfo(null);
+    invoke<ConnectionInfo>('get_connection_info', { instanceId: instance.id })
+      .then(setInfo)
+      .catch(err => console.error('Failed to load connection info:', err));
+  }, [instance.id, instance.port, instance.settings.tunnel_address]);
+
+  const copy = async (key: string, text: string) => {
+    try {
+      await navigator.clipboard.writeText(text);
+      setCopied(key);
+      setTimeout(() => setCopied(current => (current === key ? null : current)), 1500);
+    } catch (err) {
+      showToast(`Failed to copy: ${err}`, 'error');
+    }
+  };
+
+  if (!info) return null;
+
+  return (
+    <div className="card space-y-4">
+      <div className="flex items-center justify-between gap-4">
+        <h3 className="text-lg font-bold flex items-center gap-2">
+          <Share2 size={18} className="text-primary" />
+          How players connect
+        </h3>
+        <button
+          onClick={() => copy('invite', info.invite)}
+          disabled={info.addresses.length === 0}
+          className="flex items-center gap-2 px-4 py-2 rounded-xl bg-primary/10 text-primary text-sm font-bold hover:bg-primary hover:text-white transition-all disabled:opacity-50"
+        >
+          {copied === 'invite' ? <Check size={16} /> : <Copy size={16} />}
+          Copy invite
+        </button>
+      </div>
+
+      <div className="grid grid-cols-1 md:grid-cols-3 gap-3">
+        {info.addresses.map(({ kind, address }) => {
+          const { label, icon: Icon } = KIND_LABELS[kind];
+          return (
+            <button
+              key={kind}
+              onClick={() => copy(kind, address)}
+              className="flex items-center justify-between gap-3 p-3 rounded-xl bg-black/5 dark:bg-white/5 border border-black/5 dark:border-white/5 hover:border-primary/30 transition-all text-left"
+              title="Copy address"
+            >
+              <div className="min-w-0">
+                <div className="flex items-center gap-1.5 text-[10px] uppercase font-bold tracking-widest text-gray-500">
+                  <Icon size={12} /> {label}
+                </div>
+                <div className="font-mono text-sm truncate mt-1">
+                  {hideAddresses ? '•••••••••' : address}
+                </div>
+              </div>
+              {copied === kind ? <Check size={16} className="text-accent-emerald shrink-0" /> : <Copy size={14} className="text-gray-500 shrink-0" />}
+            </button>
+          );
+        })}
+      </div>
+
+      {info.bedrock && (
+        <p className="text-xs text-gray-500">Bedrock players enter the address and port {info.port} separately.</p>
+      )}
+      {info.notes.map(note => (
+        <p key={note} className="flex items-start gap-2 text-xs text-gray-500">
+          <Info size={14} className="shrink-0 mt-0.5" /> {note}
+        </p>
+      ))}
+    </div>
+  );
+}
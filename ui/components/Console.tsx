@@ -1,10 +1,12 @@
 import { useState, useRef, useEffect, useLayoutEffect } from 'react'
-import { Terminal, Maximize2, Send, ChevronRight, Activity, Check } from 'lucide-react'
+import { Terminal, Maximize2, Send, ChevronRight, Activity, Check, SquareTerminal } from 'lucide-react'
 import { motion, AnimatePresence } from 'framer-motion'
 import Ansi from 'ansi-to-react'
 import { openUrl } from '@tauri-apps/plugin-opener'
+import { invoke } from '@tauri-apps/api/core'
 import { cn } from '../utils'
 import { useAppSettings, AppSettings } from '../hooks/useAppSettings'
+import { useToast } from '../hooks/useToast'
 
 interface ConsoleProps {
   logs: string[];
@@ -16,6 +18,8 @@ interface ConsoleProps {
   isFull?: boolean;
   onViewFull?: () => void;
   settings?: AppSettings;
+  /** Enables the shell mode when the instance terminal is turned on in settings. */
+  instanceId?: string;
 }
 
 export function Console({
@@ -27,10 +31,15 @@ export function Console({
   onSendCommand,
   isFull = false,
   onViewFull,
-  settings: propSettings
+  settings: propSettings,
+  instanceId
 }: ConsoleProps) {
   const { settings: hookSettings } = useAppSettings();
   const settings = propSettings || hookSettings;
+  const { showToast } = useToast();
+  const [shellMode, setShellMode] = useState(false);
+  const shellAvailable = !!instanceId && !!settings.shell?.enabled;
+  const inShell = shellAvailable && shellMode;
   const [isAtBottom, setIsAtBottom] = useState(true);
   const [isWrapped, setIsWrapped] = useState(true);
   const [scrollTop, setScrollTop] = useState(0);
@@ -131,6 +140,21 @@ export function Console({
     }
   };
 
+  const handleSubmit = async (e: React.FormEvent) => {
+    if (!inShell) {
+      onSendCommand(e);
+      return;
+    }
+    e.preventDefault();
+    if (!command.trim()) return;
+    try {
+      await invoke('run_shell_command', { instanceId, command });
+      onCommandChange('');
+    } catch (err) {
+      showToast(`Failed to run shell command: ${err}`, 'error');
+    }
+  };
+
   // Auto-scroll when logs change
   useLayoutEffect(() => {
     if (isAtBottom) {
@@ -294,8 +318,24 @@ export function Console({
         <div ref={consoleEndRef} />
       </div>
 
-      <form onSubmit={onSendCommand} className="px-4 py-3 bg-black/[0.02] dark:bg-white/[0.02] border-t border-black/10 dark:border-white/5 flex items-center gap-3">
-        <div className="text-primary">
+      <form onSubmit={handleSubmit} className="px-4 py-3 bg-black/[0.02] dark:bg-white/[0.02] border-t border-black/10 dark:border-white/5 flex items-center gap-3">
+        {shellAvailable && (
+          <button
+            type="button"
+            onClick={() => setShellMode(!shellMode)}
+            className={cn(
+              "flex items-center gap-1.5 px-2 py-1 rounded-lg text-[10px] font-black uppercase tracking-widest transition-all",
+              inShell
+                ? "bg-accent-amber/15 text-accent-amber"
+                : "bg-black/5 dark:bg-white/5 text-gray-500 hover:text-gray-900 dark:hover:text-white"
+            )}
+            title={inShell ? "Switch to server commands" : "Switch to shell commands"}
+          >
+            <SquareTerminal size={12} />
+            {inShell ? 'Shell' : 'Server'}
+          </button>
+        )}
+        <div className={inShell ? "text-accent-amber" : "text-primary"}>
           <ChevronRight size={18} />
         </div>
         <div className="flex-1 relative flex items-center">
@@ -304,7 +344,7 @@ export function Console({
             value={command}
             onChange={(e) => onCommandChange(e.target.value)}
             onKeyDown={handleKeyDown}
-            placeholder="Enter server command..."
+            placeholder={inShell ? "Enter shell command (runs in the instance folder)..." : "Enter server command..."}
             className={cn(
               "w-full bg-transparent border-none focus:ring-0 focus:outline-none font-mono text-gray-900 dark:text-white placeholder:text-gray-400 dark:placeholder:text-white/20 transition-all",
               isFull ? "text-sm" : "text-[13px]",
@@ -3,6 +3,13 @@ import { motion } from 'framer-motion'
 import { AreaChart, Area, XAxis, YAxis, CartesianGrid, Tooltip, ResponsiveContainer } from 'recharts'
 import { Instance, ResourceUsage } from '../types'
 import { AppSettings } from '../hooks/useAppSettings'
+import { CompatibilityBanner } from './CompatibilityBanner'
+import { ConnectionInfoCard } from './ConnectionInfoCard'
+import { InstancePingCard } from './InstancePingCard'
+import { PregenerationCard } from './PregenerationCard'
+import { ProfilerCard } from './ProfilerCard'
+import { WorldUpgradeCard } from './WorldUpgradeCard'
+import { WorldToolsCard } from './WorldToolsCard'
 
 interface DashboardProps {
   currentInstance: Instance;
@@ -68,6 +75,10 @@ export function Dashboard({
 
   return (
     <div className="space-y-8 animate-fade-in">
+      <CompatibilityBanner instance={currentInstance} />
+      <ConnectionInfoCard instance={currentInstance} hideAddresses={settings.hide_ip_address} />
+      <InstancePingCard instance={currentInstance} />
+
       {/* Stats Grid */}
       <div className="grid grid-cols-1 md:grid-cols-2 lg:grid-cols-4 gap-6">
         {stats.map((stat, i) => (
@@ -266,6 +277,11 @@ export function Dashboard({
           </div>
         </motion.div>
       </div>
+
+      <WorldUpgradeCard instance={currentInstance} />
+      <PregenerationCard instance={currentInstance} />
+      <ProfilerCard instance={currentInstance} />
+      <WorldToolsCard instance={currentInstance} />
     </div>
   );
 }
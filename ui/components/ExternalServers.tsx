@@ -0,0 +1,111 @@
+import { useEffect, useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { Globe, Plus, RefreshCw, Trash2 } from 'lucide-react'
+import { ServerProbe } from '../types'
+import { useAppSettings } from '../hooks/useAppSettings'
+import { ServerListEntry } from './ServerListEntry'
+
+type PingResult = { probe: ServerProbe | null; error: string | null; loading: boolean };
+
+/** Servers the wrapper doesn't manage, pinged like the multiplayer screen does. */
+export function ExternalServers() {
+  const { settings, updateSettings } = useAppSettings();
+  const [results, setResults] = useState<Record<string, PingResult>>({});
+  const [name, setName] = useState('');
+  const [address, setAddress] = useState('');
+
+  const ping = (id: string, serverAddress: string) => {
+    setResults(prev => ({ ...prev, [id]: { probe: prev[id]?.probe ?? null, error: null, loading: true } }));
+    invoke<ServerProbe>('ping_address', { address: serverAddress })
+      .then(probe => setResults(prev => ({ ...prev, [id]: { probe, error: null, loading: false } })))
+      .catch(err => setResults(prev => ({ ...prev, [id]: { probe: null, error: String(err), loading: false } })));
+  };
+
+  const refreshAll = () => settings.external_servers.forEach(s => ping(s.id, s.address));
+
+  useEffect(() => {
+    refreshAll();
+  }, [settings.external_servers.length]);
+
+  const add = async () => {
+    if (!address.trim()) return;
+    const server = { id: crypto.randomUUID(), name: name.trim() || address.trim(), address: address.trim() };
+    await updateSettings({ external_servers: [...settings.external_servers, server] });
+    setName('');
+    setAddress('');
+  };
+
+  const remove = (id: string) => {
+    updateSettings({ external_servers: settings.external_servers.filter(s => s.id !== id) });
+  };
+
+  const inputClass = 'bg-black/5 dark:bg-white/[0.05] border border-black/10 dark:border-white/10 rounded-xl py-2 px-4 text-sm focus:outline-none focus:ring-2 focus:ring-primary/50 transition-all';
+
+  return (
+    <div className="card space-y-4">
+      <div className="flex items-center justify-between gap-4">
+        <h3 className="text-lg font-bold flex items-center gap-2">
+          <Globe size={18} className="text-primary" />
+          External servers
+        </h3>
+        {settings.external_servers.length > 0 && (
+          <button
+            onClick={refreshAll}
+            className="p-2 rounded-lg text-gray-500 hover:text-primary hover:bg-primary/10 transition-colors"
+            title="Ping again"
+          >
+            <RefreshCw size={16} />
+          </button>
+        )}
+      </div>
+
+      <div className="space-y-2">
+        {settings.external_servers.map(server => (
+          <div key={server.id} className="flex items-center gap-2">
+            <div className="flex-1 min-w-0">
+              <ServerListEntry
+                name={server.name}
+                probe={results[server.id]?.probe ?? null}
+                error={results[server.id]?.error ?? null}
+                loading={results[server.id]?.loading}
+              />
+            </div>
+            <button
+              onClick={() => remove(server.id)}
+              className="p-2 rounded-lg text-gray-400 dark:text-white/30 hover:text-accent-rose hover:bg-accent-rose/10 transition-colors"
+              title="Remove"
+            >
+              <Trash2 size={16} />
+            </button>
+          </div>
+        ))}
+      </div>
+
+      <div className="flex flex-col md:flex-row gap-2">
+        <input
+          type="text"
+          value={name}
+          placeholder="Name"
+          onChange={e => setName(e.target.value)}
+          className={`${inputClass} md:w-48`}
+        />
+        <input
+          type="text"
+          value={address}
+          placeholder="play.example.com:25565"
+          onChange={e => setAddress(e.target.value)}
+          onKeyDown={e => e.key === 'Enter' && add()}
+          className={`${inputClass} flex-1 font-mono`}
+        />
+        <button
+          onClick={add}
+          disabled={!address.trim()}
+          className="flex items-center justify-center gap-2 px-4 py-2 rounded-xl bg-primary/10 text-primary text-sm font-bold hover:bg-primary hover:text-white transition-all disabled:opacity-50"
+        >
+          <Plus size={16} />
+          Add
+        </button>
+      </div>
+    </div>
+  );
+}
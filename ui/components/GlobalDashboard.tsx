@@ -4,6 +4,7 @@ import { convertFileSrc } from '@tauri-apps/api/core'
 import { Instance, TransitionType } from '../types'
 import { cn } from '../utils'
 import { AppSettings } from '../hooks/useAppSettings'
+import { ExternalServers } from './ExternalServers'
 
 interface GlobalDashboardProps {
   instances: Instance[];
@@ -190,6 +191,8 @@ export function GlobalDashboard({
           </motion.div>
         ))}
       </div>
+
+      <ExternalServers />
     </div>
   )
 }
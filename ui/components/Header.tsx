@@ -1,4 +1,4 @@
-import { Database, Network, Beaker, Users, Tag, Play, Square, Settings2, FolderOpen, Loader2, RotateCcw, Clock } from 'lucide-react'
+import { Database, Network, Beaker, Users, Tag, Play, Square, Settings2, FolderOpen, Loader2, RotateCcw, Clock, Skull } from 'lucide-react'
 import { motion } from 'framer-motion'
 import { convertFileSrc } from '@tauri-apps/api/core'
 import { Instance, TabId, TransitionType, ResourceUsage } from '../types'
@@ -15,6 +15,7 @@ interface HeaderProps {
   tabs: { id: TabId; label: string; icon: any }[];
   onStartServer: () => void;
   onStopServer: () => void;
+  onKillServer: () => void;
   onRestartServer: () => void;
   onSetActiveTab: (tab: TabId) => void;
   onInstancesUpdated: () => void;
@@ -29,6 +30,7 @@ export function Header({
   tabs,
   onStartServer,
   onStopServer,
+  onKillServer,
   onRestartServer,
   onSetActiveTab,
   onInstancesUpdated
@@ -191,6 +193,18 @@ export function Header({
               )}
             </motion.button>
           )}
+
+          {(status === 'Stopping' || isTransitioning === 'stopping' || isTransitioning === 'restarting') && (
+            <motion.button
+              whileHover={{ scale: 1.05 }}
+              whileTap={{ scale: 0.95 }}
+              onClick={onKillServer}
+              className="flex items-center justify-center w-12 h-12 bg-accent-rose/10 hover:bg-accent-rose/20 text-accent-rose rounded-xl font-bold transition-all ring-1 ring-accent-rose/30"
+              title="Force Kill Server"
+            >
+              <Skull size={20} />
+            </motion.button>
+          )}
         </div>
       </div>
 
@@ -0,0 +1,49 @@
+import { useEffect, useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { Radar } from 'lucide-react'
+import { Instance, ServerProbe } from '../types'
+import { ServerListEntry } from './ServerListEntry'
+
+const REFRESH_MS = 30_000;
+
+/** What clients see when they ping this instance, refreshed while it runs. */
+export function InstancePingCard({ instance }: { instance: Instance }) {
+  const [probe, setProbe] = useState<ServerProbe | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const running = instance.status === 'Running';
+  const bedrock = instance.mod_loader?.toLowerCase() === 'bedrock';
+
+  useEffect(() => {
+    setProbe(null);
+    setError(null);
+    if (!running || bedrock) return;
+
+    const ping = () => invoke<ServerProbe>('ping_instance', { instanceId: instance.id })
+      .then(result => {
+        setProbe(result);
+        setError(null);
+      })
+      .catch(err => setError(String(err)));
+    ping();
+    const interval = setInterval(ping, REFRESH_MS);
+    return () => clearInterval(interval);
+  }, [instance.id, running, bedrock]);
+
+  if (!running || bedrock) return null;
+
+  return (
+    <div className="card space-y-3">
+      <h3 className="text-lg font-bold flex items-center gap-2">
+        <Radar size={18} className="text-primary" />
+        Server list preview
+      </h3>
+      <ServerListEntry name={instance.name} probe={probe} error={error} loading={!probe && !error} />
+      {probe?.query && (
+        <p className="text-xs text-gray-500">
+          Query: {probe.query.players.length ? probe.query.players.join(', ') : 'no players online'}
+          {probe.query.plugins && ` · ${probe.query.plugins}`}
+        </p>
+      )}
+    </div>
+  );
+}
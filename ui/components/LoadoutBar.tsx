@@ -0,0 +1,114 @@
+import { useEffect, useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { Layers, Play, Save, Trash2, Undo2 } from 'lucide-react'
+import { LoadoutResult, LoadoutStore } from '../types'
+import { useToast } from '../hooks/useToast'
+import { Select } from './Select'
+
+interface LoadoutBarProps {
+  instanceId: string;
+  refreshTrigger?: number;
+  onApplied: () => void;
+}
+
+export function LoadoutBar({ instanceId, refreshTrigger, onApplied }: LoadoutBarProps) {
+  const [store, setStore] = useState<LoadoutStore | null>(null)
+  const [selected, setSelected] = useState('')
+  const [newName, setNewName] = useState('')
+  const [busy, setBusy] = useState(false)
+  const { showToast } = useToast()
+
+  useEffect(() => {
+    invoke<LoadoutStore>('list_loadouts', { instanceId })
+      .then(setStore)
+      .catch(err => console.error('Failed to load load-outs:', err))
+  }, [instanceId, refreshTrigger])
+
+  const run = async (action: () => Promise<void>) => {
+    setBusy(true)
+    try {
+      await action()
+    } catch (err) {
+      showToast(`${err}`, 'error')
+    } finally {
+      setBusy(false)
+    }
+  }
+
+  const report = (result: LoadoutResult, label: string) => {
+    showToast(`${label}: ${result.changed.length} file(s) toggled`, 'success')
+    if (result.missing.length > 0) {
+      showToast(`Not installed: ${result.missing.join(', ')}`, 'info')
+    }
+    onApplied()
+  }
+
+  const saveCurrent = () => run(async () => {
+    const name = newName.trim()
+    if (!name) return
+    setStore(await invoke<LoadoutStore>('save_loadout', { instanceId, loadout: { name, entries: [] } }))
+    setSelected(name)
+    setNewName('')
+    showToast(`Saved current state as "${name}"`, 'success')
+  })
+
+  const apply = () => run(async () => {
+    const result = await invoke<LoadoutResult>('apply_loadout', { instanceId, name: selected })
+    setStore(await invoke<LoadoutStore>('list_loadouts', { instanceId }))
+    report(result, `Applied "${selected}"`)
+  })
+
+  const revert = () => run(async () => {
+    const result = await invoke<LoadoutResult>('revert_loadout', { instanceId })
+    setStore(await invoke<LoadoutStore>('list_loadouts', { instanceId }))
+    report(result, 'Restored previous state')
+  })
+
+  const remove = () => run(async () => {
+    setStore(await invoke<LoadoutStore>('delete_loadout', { instanceId, name: selected }))
+    setSelected('')
+  })
+
+  const buttonClass = 'flex items-center gap-2 px-3 py-2 bg-white/5 hover:bg-white/10 text-gray-300 rounded-xl transition-all border border-white/5 text-sm font-medium disabled:opacity-50'
+
+  return (
+    <div className="flex flex-wrap items-center gap-2 p-3 bg-white/[0.02] border border-white/5 rounded-2xl">
+      <span className="flex items-center gap-2 text-sm font-bold text-gray-400 mr-1">
+        <Layers size={16} />
+        Load-outs
+        {store?.active && <span className="px-2 py-0.5 rounded-lg bg-primary/10 text-primary text-xs">{store.active}</span>}
+      </span>
+      <Select
+        value={selected}
+        onChange={setSelected}
+        options={(store?.loadouts ?? []).map(l => ({ value: l.name, label: `${l.name} (${l.entries.length})` }))}
+        placeholder="Choose a load-out"
+        size="sm"
+        className="w-56"
+      />
+      <button onClick={apply} disabled={busy || !selected} className={buttonClass} title="Enable and disable files to match this load-out">
+        <Play size={14} /> Apply
+      </button>
+      <button onClick={remove} disabled={busy || !selected} className={buttonClass} title="Delete this load-out">
+        <Trash2 size={14} />
+      </button>
+      {store?.previous && (
+        <button onClick={revert} disabled={busy} className={buttonClass} title="Restore the state from before the load-out was applied">
+          <Undo2 size={14} /> Revert
+        </button>
+      )}
+      <div className="flex items-center gap-2 ml-auto">
+        <input
+          value={newName}
+          onChange={e => setNewName(e.target.value)}
+          onKeyDown={e => e.key === 'Enter' && saveCurrent()}
+          placeholder="New load-out name"
+          className="px-3 py-2 bg-black/20 border border-white/10 rounded-xl text-sm focus:outline-none focus:border-primary/50"
+        />
+        <button onClick={saveCurrent} disabled={busy || !newName.trim()} className={buttonClass} title="Save which mods and plugins are currently enabled">
+          <Save size={14} /> Save current
+        </button>
+      </div>
+    </div>
+  )
+}
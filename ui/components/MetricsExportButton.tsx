@@ -0,0 +1,56 @@
+import { useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { save } from '@tauri-apps/plugin-dialog'
+import { Download } from 'lucide-react'
+import { Select } from './Select'
+import { useToast } from '../hooks/useToast'
+
+const RANGES = [
+  { value: '1', label: 'Last hour' },
+  { value: '24', label: 'Last 24 hours' },
+  { value: '168', label: 'Last 7 days' },
+];
+
+interface MetricsExportButtonProps {
+  instanceId: string;
+  instanceName: string;
+}
+
+/** Saves the stored usage, TPS and player history of an instance as CSV. */
+export function MetricsExportButton({ instanceId, instanceName }: MetricsExportButtonProps) {
+  const [hours, setHours] = useState('24')
+  const [exporting, setExporting] = useState(false)
+  const { showToast } = useToast()
+
+  const handleExport = async () => {
+    const destPath = await save({
+      defaultPath: `${instanceName}-metrics.csv`,
+      filters: [{ name: 'CSV', extensions: ['csv'] }],
+    })
+    if (!destPath) return
+    setExporting(true)
+    try {
+      const from = new Date(Date.now() - Number(hours) * 60 * 60 * 1000).toISOString()
+      const rows = await invoke<number>('export_metrics_csv', { instanceId, destPath, from, until: null })
+      showToast(`Exported ${rows} samples`)
+    } catch (err) {
+      showToast(`Failed to export metrics: ${err}`, 'error')
+    } finally {
+      setExporting(false)
+    }
+  }
+
+  return (
+    <div className="flex items-center gap-2">
+      <Select value={hours} onChange={setHours} options={RANGES} size="sm" className="w-40" />
+      <button
+        onClick={handleExport}
+        disabled={exporting}
+        className="flex items-center gap-2 px-4 py-2 bg-primary/10 text-primary rounded-xl text-sm font-bold hover:bg-primary/20 transition-all disabled:opacity-50"
+      >
+        <Download size={16} />
+        Export CSV
+      </button>
+    </div>
+  );
+}
@@ -0,0 +1,173 @@
+import { useEffect, useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { listen } from '@tauri-apps/api/event'
+import { Map as MapIcon, Pause, Play, X } from 'lucide-react'
+import { Instance, PregenMethod, PregenRequest, TaskInfo } from '../types'
+import { useToast } from '../hooks/useToast'
+import { Select } from './Select'
+
+const FORCELOAD_MAX_RADIUS = 256;
+const UNSUPPORTED_LOADERS = ['bedrock', 'velocity', 'bungeecord'];
+const INPUT_CLASS = 'w-full bg-black/5 dark:bg-white/[0.05] border border-black/10 dark:border-white/10 rounded-xl py-2 px-4 font-mono focus:outline-none focus:ring-2 focus:ring-primary/50 transition-all disabled:opacity-50';
+
+export function PregenerationCard({ instance }: { instance: Instance }) {
+  const [request, setRequest] = useState<PregenRequest>({
+    world: '',
+    center_x: 0,
+    center_z: 0,
+    radius: 1000,
+    method: 'auto',
+  });
+  const [task, setTask] = useState<TaskInfo | null>(null);
+  const [starting, setStarting] = useState(false);
+  const { showToast } = useToast();
+
+  useEffect(() => {
+    setTask(null);
+    invoke<TaskInfo[]>('list_tasks')
+      .then(tasks => {
+        const running = tasks.filter(t => t.kind === 'pregen' && t.instance_id === instance.id && t.state === 'running');
+        setTask(running[running.length - 1] ?? null);
+      })
+      .catch(err => console.error('Failed to load tasks:', err));
+
+    const unlisten = listen<TaskInfo>('task-progress', (event) => {
+      const info = event.payload;
+      if (info.kind !== 'pregen' || info.instance_id !== instance.id) return;
+      setTask(info);
+      if (info.state === 'completed') showToast('World pregeneration finished', 'success');
+      if (info.state === 'failed') showToast(`Pregeneration failed: ${info.error}`, 'error');
+    });
+    return () => {
+      unlisten.then(f => f());
+    };
+  }, [instance.id]);
+
+  if (UNSUPPORTED_LOADERS.includes(instance.mod_loader?.toLowerCase() ?? '')) return null;
+
+  const running = task?.state === 'running';
+
+  const start = async () => {
+    setStarting(true);
+    try {
+      await invoke<string>('start_pregeneration', { instanceId: instance.id, request });
+    } catch (err) {
+      showToast(`Failed to start pregeneration: ${err}`, 'error');
+    } finally {
+      setStarting(false);
+    }
+  };
+
+  const control = async (command: 'pause_task' | 'resume_task' | 'cancel_task') => {
+    if (!task) return;
+    try {
+      await invoke<boolean>(command, { taskId: task.id });
+    } catch (err) {
+      showToast(`Failed to update pregeneration: ${err}`, 'error');
+    }
+  };
+
+  const numberField = (key: 'center_x' | 'center_z' | 'radius', label: string) => (
+    <label className="space-y-1">
+      <span className="text-[10px] uppercase font-bold tracking-widest text-gray-500">{label}</span>
+      <input
+        type="number"
+        value={request[key]}
+        min={key === 'radius' ? 1 : undefined}
+        disabled={running}
+        onChange={e => setRequest({ ...request, [key]: parseInt(e.target.value) || 0 })}
+        className={INPUT_CLASS}
+      />
+    </label>
+  );
+
+  const percent = task && task.total > 0 ? Math.min(100, (task.current / task.total) * 100) : 0;
+
+  return (
+    <div className="card space-y-4">
+      <div>
+        <h3 className="text-lg font-bold flex items-center gap-2">
+          <MapIcon size={18} className="text-primary" />
+          World pregeneration
+        </h3>
+        <p className="text-xs text-gray-500 mt-1">
+          Generates terrain ahead of time so players don't cause lag exploring a new map.
+          Radii up to {FORCELOAD_MAX_RADIUS} blocks use vanilla forceload; larger ones install Chunky.
+        </p>
+      </div>
+
+      <div className="grid grid-cols-2 md:grid-cols-5 gap-3">
+        <label className="space-y-1">
+          <span className="text-[10px] uppercase font-bold tracking-widest text-gray-500">World</span>
+          <input
+            type="text"
+            value={request.world}
+            placeholder="world"
+            disabled={running}
+            onChange={e => setRequest({ ...request, world: e.target.value })}
+            className={INPUT_CLASS}
+          />
+        </label>
+        {numberField('center_x', 'Center X')}
+        {numberField('center_z', 'Center Z')}
+        {numberField('radius', 'Radius (blocks)')}
+        <label className="space-y-1">
+          <span className="text-[10px] uppercase font-bold tracking-widest text-gray-500">Method</span>
+          <Select
+            value={request.method}
+            disabled={running}
+            onChange={value => setRequest({ ...request, method: value as PregenMethod })}
+            options={[
+              { value: 'auto', label: 'Automatic' },
+              { value: 'chunky', label: 'Chunky' },
+              { value: 'forceload', label: 'Forceload' },
+            ]}
+          />
+        </label>
+      </div>
+
+      {running && task ? (
+        <div className="space-y-2">
+          <div className="flex items-center justify-between text-xs text-gray-500">
+            <span>{task.paused ? 'Paused' : task.message || 'Working...'}</span>
+            <span className="font-mono">{percent.toFixed(1)}%</span>
+          </div>
+          <div className="h-2 rounded-full bg-black/5 dark:bg-white/5 overflow-hidden">
+            <div className="h-full bg-primary transition-all" style={{ width: `${percent}%` }} />
+          </div>
+          <div className="flex gap-2 justify-end">
+            {task.pausable && (
+              <button
+                onClick={() => control(task.paused ? 'resume_task' : 'pause_task')}
+                className="flex items-center gap-2 px-4 py-2 rounded-xl bg-black/5 dark:bg-white/5 text-sm font-bold hover:bg-black/10 dark:hover:bg-white/10 transition-all"
+              >
+                {task.paused ? <Play size={16} /> : <Pause size={16} />}
+                {task.paused ? 'Resume' : 'Pause'}
+              </button>
+            )}
+            {task.cancellable && (
+              <button
+                onClick={() => control('cancel_task')}
+                className="flex items-center gap-2 px-4 py-2 rounded-xl bg-accent-rose/10 text-accent-rose text-sm font-bold hover:bg-accent-rose hover:text-white transition-all"
+              >
+                <X size={16} />
+                Cancel
+              </button>
+            )}
+          </div>
+        </div>
+      ) : (
+        <div className="flex justify-end">
+          <button
+            onClick={start}
+            disabled={starting || request.radius <= 0}
+            className="flex items-center gap-2 px-4 py-2 rounded-xl bg-primary/10 text-primary text-sm font-bold hover:bg-primary hover:text-white transition-all disabled:opacity-50"
+          >
+            <Play size={16} />
+            Start pregeneration
+          </button>
+        </div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,213 @@
+import { useEffect, useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { listen } from '@tauri-apps/api/event'
+import { openUrl } from '@tauri-apps/plugin-opener'
+import { Activity, ExternalLink, Play, Square, Trash2, X } from 'lucide-react'
+import { Instance, ProfilerRequest, ProfilerSession, TaskInfo } from '../types'
+import { useToast } from '../hooks/useToast'
+import { Select } from './Select'
+
+const SPARK_LOADERS = ['paper', 'purpur', 'folia', 'spigot', 'bukkit', 'velocity', 'bungeecord', 'fabric', 'quilt', 'forge', 'neoforge'];
+
+const DURATIONS = [
+  { value: '30', label: '30 seconds' },
+  { value: '60', label: '1 minute' },
+  { value: '300', label: '5 minutes' },
+  { value: '0', label: 'Until stopped' },
+];
+
+const STATUS_CLASS: Record<ProfilerSession['status'], string> = {
+  running: 'text-primary',
+  completed: 'text-accent-emerald',
+  cancelled: 'text-gray-500',
+  failed: 'text-accent-rose',
+};
+
+export function ProfilerCard({ instance }: { instance: Instance }) {
+  const [request, setRequest] = useState<ProfilerRequest>({ duration_secs: 60, all_threads: false });
+  const [task, setTask] = useState<TaskInfo | null>(null);
+  const [sessions, setSessions] = useState<ProfilerSession[]>([]);
+  const [starting, setStarting] = useState(false);
+  const { showToast } = useToast();
+
+  const loadSessions = () => {
+    invoke<ProfilerSession[]>('list_profiler_sessions', { instanceId: instance.id })
+      .then(setSessions)
+      .catch(err => console.error('Failed to load profiler sessions:', err));
+  };
+
+  useEffect(() => {
+    setTask(null);
+    loadSessions();
+    invoke<TaskInfo[]>('list_tasks')
+      .then(tasks => {
+        const running = tasks.filter(t => t.kind === 'profile' && t.instance_id === instance.id && t.state === 'running');
+        setTask(running[running.length - 1] ?? null);
+      })
+      .catch(err => console.error('Failed to load tasks:', err));
+
+    const unlisten = listen<TaskInfo>('task-progress', (event) => {
+      const info = event.payload;
+      if (info.kind !== 'profile' || info.instance_id !== instance.id) return;
+      setTask(info);
+      if (info.state === 'completed') showToast('Profile uploaded', 'success');
+      if (info.state === 'failed') showToast(`Profiling failed: ${info.error}`, 'error');
+      if (info.state !== 'running') loadSessions();
+    });
+    return () => {
+      unlisten.then(f => f());
+    };
+  }, [instance.id]);
+
+  if (!SPARK_LOADERS.includes(instance.mod_loader?.toLowerCase() ?? '')) return null;
+
+  const running = task?.state === 'running';
+
+  const start = async () => {
+    setStarting(true);
+    try {
+      await invoke<string>('start_profiling', { instanceId: instance.id, request });
+      loadSessions();
+    } catch (err) {
+      showToast(`${err}`, 'error');
+    } finally {
+      setStarting(false);
+    }
+  };
+
+  const stop = async () => {
+    try {
+      await invoke('stop_profiling', { instanceId: instance.id });
+    } catch (err) {
+      showToast(`Failed to stop the profiler: ${err}`, 'error');
+    }
+  };
+
+  const cancel = async () => {
+    if (!task) return;
+    try {
+      await invoke<boolean>('cancel_task', { taskId: task.id });
+    } catch (err) {
+      showToast(`Failed to cancel profiling: ${err}`, 'error');
+    }
+  };
+
+  const deleteSession = async (sessionId: number) => {
+    try {
+      await invoke<boolean>('delete_profiler_session', { instanceId: instance.id, sessionId });
+      setSessions(sessions.filter(s => s.id !== sessionId));
+    } catch (err) {
+      showToast(`Failed to delete the session: ${err}`, 'error');
+    }
+  };
+
+  const percent = task && task.total > 0 ? Math.min(100, (task.current / task.total) * 100) : 0;
+
+  return (
+    <div className="card space-y-4">
+      <div>
+        <h3 className="text-lg font-bold flex items-center gap-2">
+          <Activity size={18} className="text-primary" />
+          Profiler
+        </h3>
+        <p className="text-xs text-gray-500 mt-1">
+          Samples what the server spends its ticks on with spark, which is installed if it's missing.
+          Results are uploaded to spark's viewer.
+        </p>
+      </div>
+
+      <div className="grid grid-cols-2 gap-3">
+        <label className="space-y-1">
+          <span className="text-[10px] uppercase font-bold tracking-widest text-gray-500">Duration</span>
+          <Select
+            value={String(request.duration_secs ?? 0)}
+            disabled={running}
+            onChange={value => setRequest({ ...request, duration_secs: Number(value) || null })}
+            options={DURATIONS}
+          />
+        </label>
+        <label className="flex items-center gap-2 pt-5 text-sm">
+          <input
+            type="checkbox"
+            checked={request.all_threads}
+            disabled={running}
+            onChange={e => setRequest({ ...request, all_threads: e.target.checked })}
+          />
+          Sample all threads
+        </label>
+      </div>
+
+      {running && task ? (
+        <div className="space-y-2">
+          <div className="flex items-center justify-between text-xs text-gray-500">
+            <span>{task.message || 'Starting...'}</span>
+            {task.total > 0 && <span className="font-mono">{percent.toFixed(0)}%</span>}
+          </div>
+          {task.total > 0 && (
+            <div className="h-2 rounded-full bg-black/5 dark:bg-white/5 overflow-hidden">
+              <div className="h-full bg-primary transition-all" style={{ width: `${percent}%` }} />
+            </div>
+          )}
+          <div className="flex gap-2 justify-end">
+            <button
+              onClick={stop}
+              className="flex items-center gap-2 px-4 py-2 rounded-xl bg-primary/10 text-primary text-sm font-bold hover:bg-primary hover:text-white transition-all"
+            >
+              <Square size={16} />
+              Stop & upload
+            </button>
+            {task.cancellable && (
+              <button
+                onClick={cancel}
+                className="flex items-center gap-2 px-4 py-2 rounded-xl bg-accent-rose/10 text-accent-rose text-sm font-bold hover:bg-accent-rose hover:text-white transition-all"
+              >
+                <X size={16} />
+                Discard
+              </button>
+            )}
+          </div>
+        </div>
+      ) : (
+        <div className="flex justify-end">
+          <button
+            onClick={start}
+            disabled={starting}
+            className="flex items-center gap-2 px-4 py-2 rounded-xl bg-primary/10 text-primary text-sm font-bold hover:bg-primary hover:text-white transition-all disabled:opacity-50"
+          >
+            <Play size={16} />
+            Start profiling
+          </button>
+        </div>
+      )}
+
+      {sessions.length > 0 && (
+        <div className="space-y-1">
+          <span className="text-[10px] uppercase font-bold tracking-widest text-gray-500">History</span>
+          {sessions.map(session => (
+            <div key={session.id} className="flex items-center gap-3 text-xs py-1.5 border-b border-black/5 dark:border-white/5 last:border-0">
+              <span className="text-gray-500 w-40 shrink-0">{new Date(session.started_at).toLocaleString()}</span>
+              <span className={`font-bold capitalize w-20 shrink-0 ${STATUS_CLASS[session.status]}`}>{session.status}</span>
+              <span className="flex-1 truncate text-gray-500" title={session.error ?? undefined}>
+                {session.url ? (
+                  <button onClick={() => openUrl(session.url!)} className="inline-flex items-center gap-1 text-primary hover:underline">
+                    {session.url.replace('https://', '')}
+                    <ExternalLink size={12} />
+                  </button>
+                ) : session.error}
+              </span>
+              {session.status !== 'running' && (
+                <button
+                  onClick={() => deleteSession(session.id)}
+                  className="p-1 text-gray-400 hover:text-accent-rose transition-colors"
+                  title="Remove from history"
+                >
+                  <Trash2 size={14} />
+                </button>
+              )}
+            </div>
+          ))}
+        </div>
+      )}
+    </div>
+  );
+}
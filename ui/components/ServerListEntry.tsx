@@ -0,0 +1,55 @@
+import { Database, Signal, Users } from 'lucide-react'
+import { ServerProbe } from '../types'
+
+interface ServerListEntryProps {
+  name: string;
+  probe: ServerProbe | null;
+  error: string | null;
+  loading?: boolean;
+}
+
+/** One server as the multiplayer screen would list it: icon, MOTD, version, players and latency. */
+export function ServerListEntry({ name, probe, error, loading }: ServerListEntryProps) {
+  const status = probe?.status;
+  // Query lists every player, the status ping only a sample
+  const players = probe?.query?.players.length ? probe.query.players : status?.player_sample ?? [];
+
+  return (
+    <div className="flex items-start gap-4 p-3 rounded-xl bg-black/5 dark:bg-white/5 border border-black/5 dark:border-white/5">
+      <div className="w-12 h-12 rounded-lg bg-black/10 dark:bg-white/10 flex items-center justify-center overflow-hidden shrink-0">
+        {status?.favicon ? (
+          <img src={status.favicon} alt={name} className="w-full h-full object-cover [image-rendering:pixelated]" />
+        ) : (
+          <Database size={20} className="text-gray-500" />
+        )}
+      </div>
+      <div className="min-w-0 flex-1">
+        <div className="flex items-center justify-between gap-3">
+          <span className="font-bold truncate">{name}</span>
+          {status && (
+            <span className="flex items-center gap-3 text-xs text-gray-500 font-mono shrink-0">
+              <span className="flex items-center gap-1" title={players.join(', ') || undefined}>
+                <Users size={12} /> {status.players_online}/{status.players_max}
+              </span>
+              {status.latency_ms !== null && (
+                <span className="flex items-center gap-1">
+                  <Signal size={12} /> {status.latency_ms} ms
+                </span>
+              )}
+            </span>
+          )}
+        </div>
+        {status ? (
+          <>
+            <p className="text-sm text-gray-600 dark:text-gray-300 whitespace-pre-line line-clamp-2 mt-0.5">{status.motd_plain}</p>
+            <p className="text-[10px] uppercase font-bold tracking-widest text-gray-500 mt-1">{status.version_name}</p>
+          </>
+        ) : (
+          <p className="text-sm text-gray-500 mt-0.5">
+            {loading ? 'Pinging...' : error ? `Can't reach server: ${error}` : 'Not pinged yet'}
+          </p>
+        )}
+      </div>
+    </div>
+  );
+}
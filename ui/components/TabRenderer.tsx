@@ -9,9 +9,11 @@ import {
 import { Dashboard } from './Dashboard'
 import { Console } from './Console'
 import { LogsTab } from '../LogsTab'
+import { ChatTab } from '../ChatTab'
 import { PlayersTab } from '../PlayersTab'
 import { ConfigTab } from '../ConfigTab'
 import { BackupsTab } from '../BackupsTab'
+import { FilesTab } from '../FilesTab'
 import { SchedulesTab } from '../SchedulesTab'
 import { PluginsTab } from '../PluginsTab'
 import { ModsTab } from '../ModsTab'
@@ -93,10 +95,15 @@ export function TabRenderer({
           onCommandChange={onCommandChange}
           onSendCommand={onSendCommand}
           settings={settings}
+          instanceId={selectedInstanceId}
         />
       );
     }
 
+    if (activeTab === 'chat') {
+      return <ChatTab instanceId={selectedInstanceId} />;
+    }
+
     if (activeTab === 'logs') {
       return <LogsTab instanceId={selectedInstanceId} />;
     }
@@ -113,6 +120,10 @@ export function TabRenderer({
       return <ConfigTab instanceId={selectedInstanceId} />;
     }
 
+    if (activeTab === 'files') {
+      return <FilesTab instanceId={selectedInstanceId} />;
+    }
+
     if (activeTab === 'backups') {
       return <BackupsTab instanceId={selectedInstanceId} />;
     }
@@ -126,7 +137,7 @@ export function TabRenderer({
     }
 
     if (activeTab === 'plugins') {
-      return <PluginsTab instanceId={selectedInstanceId} />;
+      return <PluginsTab instanceId={selectedInstanceId} foliaPluginsOnly={currentInstance?.folia_plugins_only} />;
     }
 
     if (activeTab === 'mods') {
@@ -0,0 +1,73 @@
+import { useEffect, useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { History, Undo2 } from 'lucide-react'
+import { InstalledVersion } from '../types'
+import { useToast } from '../hooks/useToast'
+
+interface VersionHistoryBarProps {
+  instanceId: string;
+  kind: 'mod' | 'plugin';
+  refreshTrigger?: number;
+  /** Reloads the history whenever this list changes, i.e. after an update was applied. */
+  pendingUpdates?: unknown[];
+  onRolledBack: () => void;
+}
+
+export function VersionHistoryBar({ instanceId, kind, refreshTrigger, pendingUpdates, onRolledBack }: VersionHistoryBarProps) {
+  const [versions, setVersions] = useState<InstalledVersion[]>([])
+  const [expanded, setExpanded] = useState(false)
+  const [busy, setBusy] = useState(false)
+  const { showToast } = useToast()
+
+  const load = () => {
+    invoke<InstalledVersion[]>(`list_${kind}_versions_installed`, { instanceId, projectId: null })
+      .then(setVersions)
+      .catch(err => console.error('Failed to load version history:', err))
+  }
+
+  useEffect(load, [instanceId, kind, refreshTrigger, pendingUpdates])
+
+  if (versions.length === 0) return null
+
+  const rollback = async (version: InstalledVersion) => {
+    setBusy(true)
+    try {
+      const filename = await invoke<string>(`rollback_${kind}`, { instanceId, projectId: version.project_id, sha1: version.sha1 })
+      showToast(`Restored ${filename}`, 'success')
+      load()
+      onRolledBack()
+    } catch (err) {
+      showToast(`Rollback failed: ${err}`, 'error')
+    } finally {
+      setBusy(false)
+    }
+  }
+
+  return (
+    <div className="p-3 bg-white/[0.02] border border-white/5 rounded-2xl">
+      <button onClick={() => setExpanded(!expanded)} className="flex items-center gap-2 text-sm font-bold text-gray-400">
+        <History size={16} />
+        Previous versions ({versions.length})
+      </button>
+      {expanded && (
+        <div className="mt-3 space-y-1">
+          {versions.map(v => (
+            <div key={`${v.project_id}-${v.sha1}`} className="flex items-center gap-3 px-3 py-2 rounded-xl bg-black/10 text-sm">
+              <span className="flex-1 truncate text-gray-300">{v.filename}</span>
+              <span className="text-xs text-gray-500">{v.version ?? v.sha1.slice(0, 8)}</span>
+              <span className="text-xs text-gray-600">{new Date(v.replaced_at).toLocaleString()}</span>
+              <button
+                onClick={() => rollback(v)}
+                disabled={busy}
+                className="flex items-center gap-1 px-2 py-1 bg-white/5 hover:bg-white/10 text-gray-300 rounded-lg border border-white/5 text-xs disabled:opacity-50"
+                title="Replace the installed version with this one"
+              >
+                <Undo2 size={12} /> Roll back
+              </button>
+            </div>
+          ))}
+        </div>
+      )}
+    </div>
+  )
+}
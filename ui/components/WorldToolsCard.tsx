@@ -0,0 +1,141 @@
+import { useEffect, useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { Compass, Copy, Search } from 'lucide-react'
+import { CoordinateInfo, Instance, LevelSummary, SlimeChunkCheck } from '../types'
+import { useToast } from '../hooks/useToast'
+
+const UNSUPPORTED_LOADERS = ['bedrock', 'velocity', 'bungeecord'];
+const SLIME_RADIUS = 4;
+const INPUT_CLASS = 'w-full bg-black/5 dark:bg-white/[0.05] border border-black/10 dark:border-white/10 rounded-xl py-2 px-4 font-mono focus:outline-none focus:ring-2 focus:ring-primary/50 transition-all';
+
+function Stat({ label, value }: { label: string; value: string }) {
+  return (
+    <div className="space-y-1">
+      <div className="text-[10px] uppercase font-bold tracking-widest text-gray-500">{label}</div>
+      <div className="font-mono text-sm break-all">{value}</div>
+    </div>
+  );
+}
+
+export function WorldToolsCard({ instance }: { instance: Instance }) {
+  const [summary, setSummary] = useState<LevelSummary | null>(null);
+  const [coords, setCoords] = useState({ x: 0, z: 0 });
+  const [info, setInfo] = useState<CoordinateInfo | null>(null);
+  const [slime, setSlime] = useState<SlimeChunkCheck | null>(null);
+  const { showToast } = useToast();
+
+  useEffect(() => {
+    setSummary(null);
+    setInfo(null);
+    setSlime(null);
+    invoke<LevelSummary>('get_world_summary', { instanceId: instance.id })
+      .then(setSummary)
+      // The world doesn't exist until the first start
+      .catch(() => setSummary(null));
+  }, [instance.id]);
+
+  if (UNSUPPORTED_LOADERS.includes(instance.mod_loader?.toLowerCase() ?? '')) return null;
+
+  const lookup = async () => {
+    try {
+      setInfo(await invoke<CoordinateInfo>('convert_coordinates', coords));
+      if (summary) {
+        setSlime(await invoke<SlimeChunkCheck>('find_slime_chunks', {
+          instanceId: instance.id,
+          ...coords,
+          radius: SLIME_RADIUS,
+        }));
+      }
+    } catch (err) {
+      showToast(`Failed to look up coordinates: ${err}`, 'error');
+    }
+  };
+
+  const copySeed = async () => {
+    if (!summary) return;
+    await navigator.clipboard.writeText(summary.seed);
+    showToast('Seed copied', 'success');
+  };
+
+  const spawnChunks = summary?.spawn_chunks;
+
+  return (
+    <div className="card space-y-4">
+      <div>
+        <h3 className="text-lg font-bold flex items-center gap-2">
+          <Compass size={18} className="text-primary" />
+          World tools
+        </h3>
+        <p className="text-xs text-gray-500 mt-1">
+          Seed and spawn of the active world, chunk and region lookups, and slime chunks.
+        </p>
+      </div>
+
+      {summary ? (
+        <div className="grid grid-cols-2 md:grid-cols-4 gap-4">
+          <div className="space-y-1">
+            <div className="text-[10px] uppercase font-bold tracking-widest text-gray-500">Seed</div>
+            <button onClick={copySeed} className="flex items-center gap-2 font-mono text-sm hover:text-primary transition-colors" title="Copy seed">
+              {summary.seed}
+              <Copy size={12} />
+            </button>
+          </div>
+          <Stat label="Spawn" value={summary.spawn.join(' ')} />
+          <Stat
+            label="Spawn chunks"
+            value={spawnChunks && spawnChunks.radius > 0
+              ? `${spawnChunks.min.x}, ${spawnChunks.min.z} to ${spawnChunks.max.x}, ${spawnChunks.max.z}`
+              : 'None'}
+          />
+          <Stat label="Version" value={summary.version_name ?? 'Unknown'} />
+        </div>
+      ) : (
+        <p className="text-sm text-gray-500">Start the server once to generate the world.</p>
+      )}
+
+      <div className="grid grid-cols-2 md:grid-cols-[1fr_1fr_auto] gap-3 items-end">
+        {(['x', 'z'] as const).map(axis => (
+          <label key={axis} className="space-y-1">
+            <span className="text-[10px] uppercase font-bold tracking-widest text-gray-500">Block {axis.toUpperCase()}</span>
+            <input
+              type="number"
+              value={coords[axis]}
+              onChange={e => setCoords({ ...coords, [axis]: parseInt(e.target.value) || 0 })}
+              className={INPUT_CLASS}
+            />
+          </label>
+        ))}
+        <button
+          onClick={lookup}
+          className="flex items-center justify-center gap-2 px-4 py-2 rounded-xl bg-primary/10 text-primary text-sm font-bold hover:bg-primary hover:text-white transition-all"
+        >
+          <Search size={16} />
+          Look up
+        </button>
+      </div>
+
+      {info && (
+        <div className="grid grid-cols-2 md:grid-cols-4 gap-4">
+          <Stat label="Chunk" value={`${info.chunk.x}, ${info.chunk.z}`} />
+          <Stat label="Region file" value={info.region_file} />
+          <Stat label="In the Nether" value={info.nether.join(', ')} />
+          <Stat label="In the Overworld" value={info.overworld.join(', ')} />
+        </div>
+      )}
+
+      {slime && (
+        <div className="text-sm space-y-1">
+          <div className={slime.is_slime_chunk ? 'text-accent-emerald font-bold' : 'text-gray-500'}>
+            {slime.is_slime_chunk ? 'This is a slime chunk' : 'Not a slime chunk'}
+          </div>
+          {slime.nearby.length > 0 && (
+            <div className="text-xs text-gray-500">
+              Slime chunks within {SLIME_RADIUS} chunks:{' '}
+              <span className="font-mono">{slime.nearby.map(c => `${c.x},${c.z}`).join('  ')}</span>
+            </div>
+          )}
+        </div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,117 @@
+import { useEffect, useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { listen } from '@tauri-apps/api/event'
+import { ArrowUpCircle } from 'lucide-react'
+import { Instance, TaskInfo, WorldUpgradeStatus } from '../types'
+import { useToast } from '../hooks/useToast'
+
+const UNSUPPORTED_LOADERS = ['bedrock', 'velocity', 'bungeecord'];
+
+export function WorldUpgradeCard({ instance }: { instance: Instance }) {
+  const [status, setStatus] = useState<WorldUpgradeStatus | null>(null);
+  const [eraseCache, setEraseCache] = useState(false);
+  const [task, setTask] = useState<TaskInfo | null>(null);
+  const [starting, setStarting] = useState(false);
+  const { showToast } = useToast();
+
+  const loadStatus = () => {
+    invoke<WorldUpgradeStatus>('get_world_upgrade_status', { instanceId: instance.id })
+      .then(setStatus)
+      // No world generated yet: nothing to upgrade
+      .catch(() => setStatus(null));
+  };
+
+  useEffect(() => {
+    setTask(null);
+    setStatus(null);
+    if (UNSUPPORTED_LOADERS.includes(instance.mod_loader?.toLowerCase() ?? '')) return;
+    loadStatus();
+    invoke<TaskInfo[]>('list_tasks')
+      .then(tasks => {
+        const running = tasks.filter(t => t.kind === 'worldupgrade' && t.instance_id === instance.id && t.state === 'running');
+        setTask(running[running.length - 1] ?? null);
+      })
+      .catch(err => console.error('Failed to load tasks:', err));
+
+    const unlisten = listen<TaskInfo>('task-progress', (event) => {
+      const info = event.payload;
+      if (info.kind !== 'worldupgrade' || info.instance_id !== instance.id) return;
+      setTask(info);
+      if (info.state === 'completed') {
+        showToast('World upgrade finished', 'success');
+        loadStatus();
+      }
+      if (info.state === 'failed') showToast(`World upgrade failed: ${info.error}`, 'error');
+    });
+    return () => {
+      unlisten.then(f => f());
+    };
+  }, [instance.id]);
+
+  if (!status || UNSUPPORTED_LOADERS.includes(instance.mod_loader?.toLowerCase() ?? '')) return null;
+
+  const running = task?.state === 'running';
+  const stopped = instance.status === 'Stopped' || instance.status === 'Crashed';
+  const percent = task && task.total > 0 ? Math.min(100, (task.current / task.total) * 100) : 0;
+
+  const start = async () => {
+    setStarting(true);
+    try {
+      await invoke<string>('start_world_upgrade', { instanceId: instance.id, request: { erase_cache: eraseCache } });
+    } catch (err) {
+      showToast(`Failed to start the world upgrade: ${err}`, 'error');
+    } finally {
+      setStarting(false);
+    }
+  };
+
+  return (
+    <div className="card space-y-4">
+      <div>
+        <h3 className="text-lg font-bold flex items-center gap-2">
+          <ArrowUpCircle size={18} className="text-primary" />
+          World upgrade
+        </h3>
+        <p className="text-xs text-gray-500 mt-1">
+          Converts every chunk of <span className="font-mono">{status.world}</span> to {status.server_version} in one
+          run, so it doesn't happen chunk by chunk while players explore. The server starts with --forceUpgrade and
+          stops again when it's done.
+        </p>
+        {status.world_version && (
+          <p className={`text-xs mt-2 ${status.outdated ? 'text-amber-500 font-bold' : 'text-gray-500'}`}>
+            Last saved by {status.world_version}
+            {status.outdated ? ` — older than the server's ${status.server_version}` : ''}
+          </p>
+        )}
+      </div>
+
+      {running && task ? (
+        <div className="space-y-2">
+          <div className="flex items-center justify-between text-xs text-gray-500">
+            <span>{task.message || 'Working...'}</span>
+            {task.total > 0 && <span className="font-mono">{percent.toFixed(0)}%</span>}
+          </div>
+          <div className="h-2 rounded-full bg-black/5 dark:bg-white/5 overflow-hidden">
+            <div className="h-full bg-primary transition-all" style={{ width: `${percent}%` }} />
+          </div>
+        </div>
+      ) : (
+        <div className="flex items-center justify-between gap-4">
+          <label className="flex items-center gap-2 text-sm text-gray-500">
+            <input type="checkbox" checked={eraseCache} onChange={e => setEraseCache(e.target.checked)} />
+            Also erase cached lighting and heightmaps
+          </label>
+          <button
+            onClick={start}
+            disabled={starting || !stopped}
+            title={stopped ? undefined : 'Stop the server first'}
+            className="flex items-center gap-2 px-4 py-2 rounded-xl bg-primary/10 text-primary text-sm font-bold hover:bg-primary hover:text-white transition-all disabled:opacity-50"
+          >
+            <ArrowUpCircle size={16} />
+            Upgrade world
+          </button>
+        </div>
+      )}
+    </div>
+  );
+}
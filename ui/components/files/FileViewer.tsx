@@ -0,0 +1,125 @@
+import { useState, useEffect } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { X, Loader2 } from 'lucide-react'
+import { FileChunk, FileEntry, TextChunk } from '../../types'
+import { useToast } from '../../hooks/useToast'
+import { formatSize } from '../../utils'
+
+const CHUNK_SIZE = 256 * 1024;
+/** Images above this size aren't previewed. */
+const MAX_IMAGE_PREVIEW = 8 * 1024 * 1024;
+
+const IMAGE_TYPES: Record<string, string> = {
+  png: 'image/png',
+  jpg: 'image/jpeg',
+  jpeg: 'image/jpeg',
+  gif: 'image/gif',
+  webp: 'image/webp',
+};
+
+interface FileViewerProps {
+  instanceId: string;
+  entry: FileEntry;
+  onClose: () => void;
+}
+
+async function readImage(instanceId: string, relPath: string): Promise<Uint8Array> {
+  const parts: Uint8Array[] = [];
+  let offset = 0;
+  for (;;) {
+    const chunk = await invoke<FileChunk>('read_file_chunk', { instanceId, relPath, offset, length: CHUNK_SIZE });
+    parts.push(Uint8Array.from(atob(chunk.data), c => c.charCodeAt(0)));
+    offset = chunk.next_offset;
+    if (chunk.eof) break;
+  }
+  const bytes = new Uint8Array(parts.reduce((n, p) => n + p.length, 0));
+  let pos = 0;
+  for (const part of parts) {
+    bytes.set(part, pos);
+    pos += part.length;
+  }
+  return bytes;
+}
+
+export function FileViewer({ instanceId, entry, onClose }: FileViewerProps) {
+  const [text, setText] = useState('')
+  const [next, setNext] = useState<TextChunk | null>(null)
+  const [imageUrl, setImageUrl] = useState<string | null>(null)
+  const [loading, setLoading] = useState(false)
+  const { showToast } = useToast()
+
+  const mime = IMAGE_TYPES[entry.name.split('.').pop()?.toLowerCase() ?? ''];
+
+  const loadText = async (offset: number) => {
+    setLoading(true)
+    try {
+      const chunk = await invoke<TextChunk>('read_text_chunk', { instanceId, relPath: entry.path, offset, length: CHUNK_SIZE })
+      setText(prev => (offset === 0 ? chunk.content : prev + chunk.content))
+      setNext(chunk)
+    } catch (err) {
+      showToast(`Failed to read ${entry.name}: ${err}`, 'error')
+    } finally {
+      setLoading(false)
+    }
+  }
+
+  useEffect(() => {
+    setText('')
+    setNext(null)
+    setImageUrl(null)
+    if (!mime) {
+      loadText(0)
+      return
+    }
+    if (entry.size > MAX_IMAGE_PREVIEW) return
+    let url: string | null = null
+    setLoading(true)
+    readImage(instanceId, entry.path)
+      .then(bytes => {
+        url = URL.createObjectURL(new Blob([bytes], { type: mime }))
+        setImageUrl(url)
+      })
+      .catch(err => showToast(`Failed to read ${entry.name}: ${err}`, 'error'))
+      .finally(() => setLoading(false))
+    return () => {
+      if (url) URL.revokeObjectURL(url)
+    }
+  }, [instanceId, entry.path])
+
+  return (
+    <div className="flex flex-col min-h-0 max-h-[50%] bg-surface/50 border border-black/5 dark:border-white/5 rounded-2xl overflow-hidden">
+      <div className="flex items-center gap-3 px-4 py-2.5 border-b border-black/5 dark:border-white/5">
+        <span className="text-sm font-bold truncate flex-1">{entry.path}</span>
+        <span className="text-xs text-gray-500 dark:text-white/40">{formatSize(entry.size)}</span>
+        <button onClick={onClose} className="p-1.5 hover:bg-black/5 dark:hover:bg-white/5 rounded-lg" title="Close">
+          <X size={14} />
+        </button>
+      </div>
+      <div className="flex-1 overflow-auto custom-scrollbar p-4">
+        {mime ? (
+          entry.size > MAX_IMAGE_PREVIEW ? (
+            <p className="text-sm text-gray-500 dark:text-white/40">This image is too large to preview.</p>
+          ) : imageUrl ? (
+            <img src={imageUrl} alt={entry.name} className="max-w-full [image-rendering:pixelated]" />
+          ) : null
+        ) : next?.binary ? (
+          <p className="text-sm text-gray-500 dark:text-white/40">This looks like a binary file; download it to open it elsewhere.</p>
+        ) : (
+          <>
+            <pre className="text-xs font-mono whitespace-pre-wrap break-all">{text}</pre>
+            {next && !next.eof && (
+              <button
+                onClick={() => loadText(next.next_offset)}
+                disabled={loading}
+                className="mt-3 px-3 py-1.5 text-xs font-bold bg-primary/10 text-primary rounded-lg hover:bg-primary/20 disabled:opacity-50"
+              >
+                Load more ({formatSize(next.total_size - next.next_offset)} left)
+              </button>
+            )}
+          </>
+        )}
+        {loading && <Loader2 size={16} className="animate-spin text-gray-400 mt-2" />}
+      </div>
+    </div>
+  );
+}
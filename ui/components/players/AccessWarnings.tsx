@@ -0,0 +1,87 @@
+import { useEffect, useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { AlertTriangle, Check } from 'lucide-react'
+import { AccessFix, AccessWarning, AllPlayerLists } from '../../types'
+import { useToast } from '../../hooks/useToast'
+
+const FIX_LABELS: Record<AccessFix['action'], string> = {
+  disable_whitelist: 'Turn whitelist off',
+  whitelist_known_players: 'Whitelist known players',
+  add_to_whitelist: 'Whitelist',
+  add_operator: 'Make operator',
+};
+
+const NEEDS_USERNAME: AccessFix['action'][] = ['add_to_whitelist', 'add_operator'];
+
+interface AccessWarningsProps {
+  instanceId: string;
+  /** Re-checked whenever the lists change. */
+  lists: AllPlayerLists | null;
+  onFixed: () => void;
+}
+
+export function AccessWarnings({ instanceId, lists, onFixed }: AccessWarningsProps) {
+  const [warnings, setWarnings] = useState<AccessWarning[]>([]);
+  const [usernames, setUsernames] = useState<Record<string, string>>({});
+  const [fixing, setFixing] = useState(false);
+  const { showToast } = useToast();
+
+  useEffect(() => {
+    invoke<AccessWarning[]>('get_player_access_warnings', { instanceId })
+      .then(setWarnings)
+      .catch(err => console.error('Failed to check player access:', err));
+  }, [instanceId, JSON.stringify(lists)]);
+
+  const applyFix = async (warning: AccessWarning, fix: AccessFix) => {
+    const username = usernames[warning.issue]?.trim();
+    if (NEEDS_USERNAME.includes(fix.action) && !username) {
+      showToast('Enter a username first', 'error');
+      return;
+    }
+    setFixing(true);
+    try {
+      await invoke('fix_player_access', { instanceId, fix, username });
+      showToast(`${FIX_LABELS[fix.action]}: done`);
+      onFixed();
+    } catch (err) {
+      showToast(`Failed to apply fix: ${err}`, 'error');
+    } finally {
+      setFixing(false);
+    }
+  };
+
+  if (warnings.length === 0) return null;
+
+  return (
+    <div className="space-y-3">
+      {warnings.map(warning => {
+        const needsName = warning.fixes.some(f => NEEDS_USERNAME.includes(f.action));
+        return (
+          <div key={warning.issue} className="bg-accent-amber/10 border border-accent-amber/20 p-4 rounded-2xl flex flex-wrap items-center gap-3">
+            <AlertTriangle size={18} className="text-accent-amber shrink-0" />
+            <p className="text-sm font-medium flex-1 min-w-[16rem]">{warning.message}</p>
+            {needsName && (
+              <input
+                type="text"
+                value={usernames[warning.issue] ?? ''}
+                onChange={e => setUsernames({ ...usernames, [warning.issue]: e.target.value })}
+                placeholder="Username"
+                className="w-40 bg-black/5 dark:bg-white/5 border border-black/10 dark:border-white/10 rounded-lg px-3 py-1.5 text-sm focus:outline-none focus:border-primary/50"
+              />
+            )}
+            {warning.fixes.map(fix => (
+              <button
+                key={fix.action}
+                onClick={() => applyFix(warning, fix)}
+                disabled={fixing}
+                className="flex items-center gap-1.5 px-3 py-1.5 text-sm bg-accent-amber/15 text-accent-amber rounded-lg font-bold hover:bg-accent-amber hover:text-white transition-all disabled:opacity-50"
+              >
+                <Check size={14} /> {FIX_LABELS[fix.action]}
+              </button>
+            ))}
+          </div>
+        );
+      })}
+    </div>
+  );
+}
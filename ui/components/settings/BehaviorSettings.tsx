@@ -1,8 +1,9 @@
-import { Layout, Power, Globe, Monitor, Users } from 'lucide-react'
+import { Layout, Power, Globe, Monitor, Users, Save } from 'lucide-react'
 import { cn } from '../../utils'
-import { AppSettings } from '../../hooks/useAppSettings'
+import { AppSettings, ExitServerPolicy } from '../../hooks/useAppSettings'
 import { Select } from '../Select'
 import { Section, Checkbox } from './SettingsShared'
+import { InstanceDefaultsSettings } from './InstanceDefaultsSettings'
 
 interface BehaviorSettingsProps {
   settings: AppSettings;
@@ -80,7 +81,38 @@ export function BehaviorSettings({ settings, updateSettings, activeTab }: Behavi
               />
             </div>
           )}
+
+          <div className="mt-4 pt-4 border-t border-black/5 dark:border-white/5">
+            <div className="flex items-center justify-between gap-4">
+              <div>
+                <div className="text-sm font-semibold text-gray-700 dark:text-gray-200">Running servers on quit</div>
+                <div className="text-xs text-gray-500 mt-1">What happens to running servers when the application exits</div>
+              </div>
+              <div className="w-48">
+                <Select
+                  value={settings.exit_server_policy}
+                  onChange={(val) => updateSettings({ exit_server_policy: val as ExitServerPolicy })}
+                  options={[
+                    { label: 'Stop all servers', value: 'StopAll' },
+                    { label: 'Keep running', value: 'KeepRunning' },
+                    { label: 'Ask every time', value: 'Ask' },
+                  ]}
+                />
+              </div>
+            </div>
+          </div>
+        </Section>
+
+        <Section title="Config Files" icon={Save}>
+          <Checkbox
+            label="Keep a backup when saving"
+            description="Save the previous version of an edited config file as <name>.bak next to it"
+            checked={settings.backup_configs_on_save}
+            onChange={(val) => updateSettings({ backup_configs_on_save: val })}
+          />
         </Section>
+
+        <InstanceDefaultsSettings settings={settings} updateSettings={updateSettings} />
       </div>
     );
   }
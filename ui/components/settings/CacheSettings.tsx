@@ -1,26 +1,55 @@
-import { Database, Trash2, RefreshCw, HardDrive, FileText, Clock } from 'lucide-react'
+import { Database, Trash2, RefreshCw, HardDrive, FileText, Clock, Server } from 'lucide-react'
 import { useState, useEffect } from 'react'
 import { invoke } from '@tauri-apps/api/core'
 import { Section } from './SettingsShared'
 import { formatSize } from '../../utils'
-import { motion, AnimatePresence } from 'framer-motion'
 import { useToast } from '../../hooks/useToast'
+import { AppSettings } from '../../hooks/useAppSettings'
 
 interface AssetCacheStats {
   count: number;
   total_size: number;
 }
 
-export function CacheSettings() {
+interface ApiCacheStats {
+  hits: number;
+  stale_hits: number;
+  misses: number;
+  memory_entries: number;
+  disk_entries: number;
+  disk_bytes: number;
+  max_disk_bytes: number;
+}
+
+type CacheNamespace = 'manifests' | 'search' | 'projects' | 'avatars';
+
+const NAMESPACES: { id: CacheNamespace; label: string; description: string }[] = [
+  { id: 'manifests', label: 'Manifests', description: 'Minecraft versions and loader builds' },
+  { id: 'search', label: 'Search Results', description: 'Mod and plugin searches' },
+  { id: 'projects', label: 'Project Details', description: 'Mod and plugin pages, versions and dependencies' },
+  { id: 'avatars', label: 'Images', description: 'Player heads, icons and screenshots' },
+]
+
+interface CacheSettingsProps {
+  settings: AppSettings;
+  updateSettings: (newSettings: Partial<AppSettings>) => void;
+}
+
+export function CacheSettings({ settings, updateSettings }: CacheSettingsProps) {
   const [stats, setStats] = useState<AssetCacheStats | null>(null)
+  const [apiStats, setApiStats] = useState<ApiCacheStats | null>(null)
   const [isLoading, setIsLoading] = useState(true)
   const [isCleaning, setIsCleaning] = useState(false)
   const { showToast } = useToast()
 
   const fetchStats = async () => {
     try {
-      const result = await invoke<AssetCacheStats>('get_asset_cache_stats')
-      setStats(result)
+      const [assets, api] = await Promise.all([
+        invoke<AssetCacheStats>('get_asset_cache_stats'),
+        invoke<ApiCacheStats>('get_cache_stats'),
+      ])
+      setStats(assets)
+      setApiStats(api)
     } catch (err) {
       console.error('Failed to fetch cache stats:', err)
       showToast('Failed to fetch cache statistics', 'error')
@@ -47,8 +76,88 @@ export function CacheSettings() {
     }
   }
 
+  const handleClearNamespace = async (namespace: CacheNamespace | null) => {
+    setIsCleaning(true)
+    try {
+      const count = await invoke<number>('clear_cache', { namespace })
+      showToast(`Removed ${count} cached entries`, 'success')
+      await fetchStats()
+    } catch (err) {
+      console.error('Failed to clear cache:', err)
+      showToast('Failed to clear cache', 'error')
+    } finally {
+      setIsCleaning(false)
+    }
+  }
+
+  const lookups = apiStats ? apiStats.hits + apiStats.stale_hits + apiStats.misses : 0
+  const hitRate = apiStats && lookups > 0 ? Math.round(((apiStats.hits + apiStats.stale_hits) / lookups) * 100) : null
+
   return (
     <div className="space-y-8">
+      <Section title="API Cache" icon={Server}>
+        <div className="p-4 bg-black/5 dark:bg-white/5 rounded-2xl border border-black/5 dark:border-white/5 space-y-4">
+          <div className="grid grid-cols-3 gap-4 text-center">
+            <div>
+              <div className="text-xs text-gray-500">On disk</div>
+              <div className="text-lg font-bold text-gray-900 dark:text-white">
+                {apiStats ? formatSize(apiStats.disk_bytes) : '-'}
+              </div>
+              <div className="text-[10px] text-gray-500">{apiStats?.disk_entries ?? 0} entries</div>
+            </div>
+            <div>
+              <div className="text-xs text-gray-500">In memory</div>
+              <div className="text-lg font-bold text-gray-900 dark:text-white">{apiStats?.memory_entries ?? '-'}</div>
+              <div className="text-[10px] text-gray-500">entries</div>
+            </div>
+            <div>
+              <div className="text-xs text-gray-500">Hit rate</div>
+              <div className="text-lg font-bold text-gray-900 dark:text-white">{hitRate === null ? '-' : `${hitRate}%`}</div>
+              <div className="text-[10px] text-gray-500">{lookups} lookups since launch</div>
+            </div>
+          </div>
+          <div className="flex items-center justify-between gap-4">
+            <div>
+              <div className="text-sm font-semibold text-gray-700 dark:text-gray-200">Disk limit</div>
+              <div className="text-xs text-gray-500 mt-1">MB kept on disk before the least recently used entries are removed, 0 for no limit</div>
+            </div>
+            <input
+              type="number"
+              min={0}
+              value={settings.cache_limits.max_disk_mb}
+              onChange={(e) => {
+                const parsed = parseInt(e.target.value, 10);
+                if (!Number.isNaN(parsed) && parsed >= 0) updateSettings({ cache_limits: { max_disk_mb: parsed } });
+              }}
+              className="w-48 px-3 py-2 bg-black/5 dark:bg-white/5 border border-black/10 dark:border-white/10 rounded-xl text-sm focus:outline-none focus:border-primary"
+            />
+          </div>
+          <div className="grid grid-cols-1 sm:grid-cols-2 gap-3">
+            {NAMESPACES.map((namespace) => (
+              <button
+                key={namespace.id}
+                onClick={() => handleClearNamespace(namespace.id)}
+                disabled={isCleaning}
+                className="flex items-center gap-3 p-3 rounded-xl border border-black/5 dark:border-white/5 hover:border-primary/30 bg-black/5 dark:bg-white/5 transition-all group text-left"
+              >
+                <Trash2 size={16} className="text-gray-500 group-hover:text-primary shrink-0" />
+                <div>
+                  <div className="text-sm font-bold text-gray-700 dark:text-gray-200">Clear {namespace.label}</div>
+                  <div className="text-[10px] text-gray-500 mt-0.5">{namespace.description}</div>
+                </div>
+              </button>
+            ))}
+          </div>
+          <button
+            onClick={() => handleClearNamespace(null)}
+            disabled={isCleaning}
+            className="text-xs text-red-500 hover:underline"
+          >
+            Clear everything
+          </button>
+        </div>
+      </Section>
+
       <Section title="Asset Cache" icon={Database}>
         <div className="grid grid-cols-1 md:grid-cols-2 gap-4">
           <div className="p-6 bg-black/5 dark:bg-white/5 rounded-2xl border border-black/5 dark:border-white/5 flex items-center gap-4">
@@ -0,0 +1,104 @@
+import { KeyRound, Check, Loader2, Trash2 } from 'lucide-react'
+import { useState, useEffect } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { Section } from './SettingsShared'
+import { useToast } from '../../hooks/useToast'
+
+type CredentialKind = 'CurseForge' | 'GitHub'
+
+interface CredentialStatus {
+  curseforge_api_key: boolean;
+  github_token: boolean;
+}
+
+const CREDENTIALS: { kind: CredentialKind; label: string; desc: string; field: keyof CredentialStatus }[] = [
+  { kind: 'CurseForge', label: 'CurseForge API key', desc: 'Required to search and install CurseForge mods', field: 'curseforge_api_key' },
+  { kind: 'GitHub', label: 'GitHub token', desc: 'Raises rate limits for plugins downloaded from GitHub releases', field: 'github_token' },
+]
+
+export function CredentialsSettings() {
+  const [status, setStatus] = useState<CredentialStatus | null>(null)
+  const [values, setValues] = useState<Record<string, string>>({})
+  const [busy, setBusy] = useState<CredentialKind | null>(null)
+  const { showToast } = useToast()
+
+  const fetchStatus = async () => {
+    try {
+      setStatus(await invoke<CredentialStatus>('get_credential_status'))
+    } catch (err) {
+      console.error('Failed to load credentials:', err)
+      showToast('Failed to load credentials', 'error')
+    }
+  }
+
+  useEffect(() => {
+    fetchStatus()
+  }, [])
+
+  const handleSave = async (kind: CredentialKind, value: string | null) => {
+    setBusy(kind)
+    try {
+      if (value) {
+        await invoke('test_credential', { kind, value })
+      }
+      await invoke('set_credential', { kind, value })
+      setValues(v => ({ ...v, [kind]: '' }))
+      showToast(value ? 'Credential verified and saved' : 'Credential removed', 'success')
+      await fetchStatus()
+    } catch (err) {
+      console.error('Failed to save credential:', err)
+      showToast(`Failed to save credential: ${err}`, 'error')
+    } finally {
+      setBusy(null)
+    }
+  }
+
+  return (
+    <div className="space-y-8">
+      <Section title="Provider Credentials" icon={KeyRound}>
+        <div className="space-y-3">
+          {CREDENTIALS.map(({ kind, label, desc, field }) => (
+            <div key={kind} className="p-4 bg-black/5 dark:bg-white/5 rounded-2xl border border-black/5 dark:border-white/5 space-y-3">
+              <div className="flex items-center justify-between gap-4">
+                <div>
+                  <div className="text-sm font-semibold text-gray-700 dark:text-gray-200">{label}</div>
+                  <div className="text-xs text-gray-500 mt-1">{desc}</div>
+                </div>
+                {status?.[field] && (
+                  <div className="flex items-center gap-2">
+                    <span className="flex items-center gap-1 text-xs text-green-500"><Check size={14} /> Configured</span>
+                    <button
+                      onClick={() => handleSave(kind, null)}
+                      disabled={busy !== null}
+                      className="p-2 rounded-lg text-gray-400 hover:text-red-500 hover:bg-red-500/10 transition-colors"
+                    >
+                      <Trash2 size={16} />
+                    </button>
+                  </div>
+                )}
+              </div>
+              <div className="flex gap-2">
+                <input
+                  type="password"
+                  value={values[kind] ?? ''}
+                  onChange={(e) => setValues(v => ({ ...v, [kind]: e.target.value }))}
+                  placeholder={status?.[field] ? 'Enter a new value to replace' : 'Paste value'}
+                  className="flex-1 px-3 py-2 bg-black/5 dark:bg-white/5 border border-black/10 dark:border-white/10 rounded-xl text-sm focus:outline-none focus:border-primary"
+                />
+                <button
+                  onClick={() => handleSave(kind, values[kind]?.trim() || null)}
+                  disabled={busy !== null || !values[kind]?.trim()}
+                  className="px-4 py-2 bg-primary text-white rounded-xl text-sm font-semibold disabled:opacity-50 flex items-center gap-2"
+                >
+                  {busy === kind && <Loader2 size={14} className="animate-spin" />}
+                  Test & Save
+                </button>
+              </div>
+            </div>
+          ))}
+        </div>
+        <p className="text-xs text-gray-500 mt-3">Credentials are stored encrypted next to the app settings, with the key kept in the system keychain.</p>
+      </Section>
+    </div>
+  )
+}
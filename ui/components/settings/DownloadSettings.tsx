@@ -0,0 +1,205 @@
+import { Download, Globe, Plus, Trash2, Loader2, CheckCircle2, AlertCircle } from 'lucide-react'
+import { useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { AppSettings, ApiMirror, DownloadSettings } from '../../hooks/useAppSettings'
+import { useToast } from '../../hooks/useToast'
+import { Section } from './SettingsShared'
+
+interface EndpointCheck {
+  url: string;
+  reachable: boolean;
+  status: number | null;
+  latency_ms: number;
+  error: string | null;
+}
+
+interface UpstreamCheck {
+  name: string;
+  official: EndpointCheck;
+  mirrors: EndpointCheck[];
+}
+
+interface DownloadSettingsProps {
+  settings: AppSettings;
+  updateSettings: (newSettings: Partial<AppSettings>) => void;
+}
+
+const inputClass = "w-48 px-3 py-2 bg-black/5 dark:bg-white/5 border border-black/10 dark:border-white/10 rounded-xl text-sm focus:outline-none focus:border-primary";
+
+function EndpointStatus({ check }: { check: EndpointCheck }) {
+  return (
+    <span
+      title={check.error ?? check.url}
+      className={`flex items-center gap-1 ${check.reachable ? 'text-green-500' : 'text-red-500'}`}
+    >
+      {check.reachable ? <CheckCircle2 size={12} /> : <AlertCircle size={12} />}
+      {check.reachable ? `${check.latency_ms} ms` : check.status ?? 'unreachable'}
+    </span>
+  );
+}
+
+function Row({ label, description, children }: { label: string; description: string; children: React.ReactNode }) {
+  return (
+    <div className="flex items-center justify-between gap-4">
+      <div>
+        <div className="text-sm font-semibold text-gray-700 dark:text-gray-200">{label}</div>
+        <div className="text-xs text-gray-500 mt-1">{description}</div>
+      </div>
+      {children}
+    </div>
+  );
+}
+
+export function DownloadSettingsPanel({ settings, updateSettings }: DownloadSettingsProps) {
+  const downloads = settings.downloads;
+  const [mirrors, setMirrors] = useState(downloads.preferred_mirrors.join(', '));
+  const [checks, setChecks] = useState<UpstreamCheck[] | null>(null);
+  const [isChecking, setIsChecking] = useState(false);
+  const { showToast } = useToast();
+  const update = (patch: Partial<DownloadSettings>) =>
+    updateSettings({ downloads: { ...downloads, ...patch } });
+
+  const updateApiMirror = (index: number, patch: Partial<ApiMirror>) =>
+    update({ api_mirrors: downloads.api_mirrors.map((m, i) => (i === index ? { ...m, ...patch } : m)) });
+
+  const addBmclapi = async () => {
+    try {
+      const preset = await invoke<ApiMirror[]>('get_bmclapi_mirrors');
+      const existing = new Set(downloads.api_mirrors.map((m) => `${m.upstream} ${m.mirror}`));
+      update({ api_mirrors: [...downloads.api_mirrors, ...preset.filter((m) => !existing.has(`${m.upstream} ${m.mirror}`))] });
+    } catch (err) {
+      console.error('Failed to load mirror preset:', err);
+    }
+  };
+
+  const testConnectivity = async () => {
+    setIsChecking(true);
+    try {
+      setChecks(await invoke<UpstreamCheck[]>('test_connectivity'));
+    } catch (err) {
+      console.error('Failed to test connectivity:', err);
+      showToast('Failed to test connectivity', 'error');
+    } finally {
+      setIsChecking(false);
+    }
+  };
+
+  const saveMirrors = () => {
+    const hosts = mirrors.split(/[\s,]+/).map((h) => h.trim().toLowerCase()).filter(Boolean);
+    update({ preferred_mirrors: hosts });
+  };
+
+  return (
+    <div className="space-y-8">
+      <Section title="Downloads" icon={Download}>
+        <div className="p-4 bg-black/5 dark:bg-white/5 rounded-2xl border border-black/5 dark:border-white/5 space-y-4">
+          <div className="text-xs text-gray-500">
+            Applies to every download: server jars, mod loaders, mods and modpacks.
+          </div>
+          <Row label="Parallel downloads" description="Files downloaded at the same time">
+            <input
+              type="number"
+              min={1}
+              max={32}
+              value={downloads.max_concurrent_downloads}
+              onChange={(e) => {
+                const parsed = parseInt(e.target.value, 10);
+                if (!Number.isNaN(parsed) && parsed >= 1) update({ max_concurrent_downloads: parsed });
+              }}
+              className={inputClass}
+            />
+          </Row>
+          <Row label="Bandwidth limit" description="Combined speed in KiB/s, 0 for unlimited">
+            <input
+              type="number"
+              min={0}
+              value={downloads.bandwidth_limit_kib}
+              onChange={(e) => {
+                const parsed = parseInt(e.target.value, 10);
+                if (!Number.isNaN(parsed) && parsed >= 0) update({ bandwidth_limit_kib: parsed });
+              }}
+              className={inputClass}
+            />
+          </Row>
+          <Row label="Preferred mirrors" description="Hosts tried first, comma separated">
+            <input
+              value={mirrors}
+              placeholder="cdn.modrinth.com"
+              onChange={(e) => setMirrors(e.target.value)}
+              onBlur={saveMirrors}
+              className={inputClass}
+            />
+          </Row>
+        </div>
+      </Section>
+
+      <Section title="API Mirrors" icon={Globe}>
+        <div className="p-4 bg-black/5 dark:bg-white/5 rounded-2xl border border-black/5 dark:border-white/5 space-y-4">
+          <div className="text-xs text-gray-500">
+            Used when Mojang, Fabric, Forge or other loader servers can't be reached. URLs starting with the
+            upstream are fetched from the mirror instead.
+          </div>
+          {downloads.api_mirrors.map((mirror, index) => (
+            <div key={index} className="flex items-center gap-2">
+              <input
+                value={mirror.upstream}
+                placeholder="https://meta.fabricmc.net"
+                onChange={(e) => updateApiMirror(index, { upstream: e.target.value.trim() })}
+                className={`${inputClass} flex-1`}
+              />
+              <input
+                value={mirror.mirror}
+                placeholder="https://bmclapi2.bangbang93.com/fabric-meta"
+                onChange={(e) => updateApiMirror(index, { mirror: e.target.value.trim() })}
+                className={`${inputClass} flex-1`}
+              />
+              <button
+                onClick={() => update({ api_mirrors: downloads.api_mirrors.filter((_, i) => i !== index) })}
+                className="p-2 text-gray-500 hover:text-red-500"
+              >
+                <Trash2 size={14} />
+              </button>
+            </div>
+          ))}
+          <div className="flex items-center gap-4">
+            <button
+              onClick={() => update({ api_mirrors: [...downloads.api_mirrors, { upstream: '', mirror: '' }] })}
+              className="flex items-center gap-1 text-xs text-primary hover:underline"
+            >
+              <Plus size={12} /> Add mirror
+            </button>
+            <button onClick={addBmclapi} className="text-xs text-primary hover:underline">
+              Add BMCLAPI
+            </button>
+          </div>
+        </div>
+
+        <div className="p-4 bg-black/5 dark:bg-white/5 rounded-2xl border border-black/5 dark:border-white/5 space-y-3">
+          <Row label="Connectivity" description="Checks which upstreams and mirrors can be reached">
+            <button
+              onClick={testConnectivity}
+              disabled={isChecking}
+              className="flex items-center gap-2 px-4 py-2 bg-primary/10 hover:bg-primary/20 text-primary rounded-xl text-sm font-bold disabled:opacity-50"
+            >
+              {isChecking && <Loader2 size={14} className="animate-spin" />}
+              Test connectivity
+            </button>
+          </Row>
+          {checks?.map((check) => (
+            <div key={check.name} className="flex items-center justify-between gap-4 text-xs">
+              <span className="font-semibold text-gray-700 dark:text-gray-200">{check.name}</span>
+              <div className="flex items-center gap-3">
+                <EndpointStatus check={check.official} />
+                {check.mirrors.map((mirror) => (
+                  <span key={mirror.url} className="flex items-center gap-1 text-gray-500">
+                    mirror <EndpointStatus check={mirror} />
+                  </span>
+                ))}
+              </div>
+            </div>
+          ))}
+        </div>
+      </Section>
+    </div>
+  );
+}
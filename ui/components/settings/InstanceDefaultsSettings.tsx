@@ -0,0 +1,111 @@
+import { FileCog } from 'lucide-react'
+import { AppSettings, ServerPropertiesDefaults } from '../../hooks/useAppSettings'
+import { Select } from '../Select'
+import { Section, Checkbox } from './SettingsShared'
+
+interface InstanceDefaultsSettingsProps {
+  settings: AppSettings;
+  updateSettings: (newSettings: Partial<AppSettings>) => void;
+}
+
+const inputClass = "w-48 px-3 py-2 bg-black/5 dark:bg-white/5 border border-black/10 dark:border-white/10 rounded-xl text-sm focus:outline-none focus:border-primary";
+
+function Row({ label, description, children }: { label: string; description: string; children: React.ReactNode }) {
+  return (
+    <div className="flex items-center justify-between gap-4">
+      <div>
+        <div className="text-sm font-semibold text-gray-700 dark:text-gray-200">{label}</div>
+        <div className="text-xs text-gray-500 mt-1">{description}</div>
+      </div>
+      {children}
+    </div>
+  );
+}
+
+export function InstanceDefaultsSettings({ settings, updateSettings }: InstanceDefaultsSettingsProps) {
+  const defaults = settings.server_properties_defaults;
+  const update = (patch: Partial<ServerPropertiesDefaults>) =>
+    updateSettings({ server_properties_defaults: { ...defaults, ...patch } });
+  const numberValue = (value: string, fallback: number) => {
+    const parsed = parseInt(value, 10);
+    return Number.isNaN(parsed) || parsed < 1 ? fallback : parsed;
+  };
+
+  return (
+    <Section title="New Instance Defaults" icon={FileCog}>
+      <div className="p-4 bg-black/5 dark:bg-white/5 rounded-2xl border border-black/5 dark:border-white/5 space-y-4">
+        <div className="text-xs text-gray-500">
+          Written to server.properties when an instance is created, so settings can be changed before the first start.
+        </div>
+        <Row label="MOTD" description="{name} is replaced by the instance name">
+          <input
+            value={defaults.motd}
+            onChange={(e) => update({ motd: e.target.value })}
+            className={inputClass}
+          />
+        </Row>
+        <Row label="View distance" description="Chunks sent to each player">
+          <input
+            type="number"
+            min={2}
+            max={32}
+            value={defaults.view_distance}
+            onChange={(e) => update({ view_distance: numberValue(e.target.value, defaults.view_distance) })}
+            className={inputClass}
+          />
+        </Row>
+        <Row label="Simulation distance" description="Chunks around players that keep ticking">
+          <input
+            type="number"
+            min={2}
+            max={32}
+            value={defaults.simulation_distance}
+            onChange={(e) => update({ simulation_distance: numberValue(e.target.value, defaults.simulation_distance) })}
+            className={inputClass}
+          />
+        </Row>
+        <Row label="Max players" description="Player slots shown in the server list">
+          <input
+            type="number"
+            min={1}
+            value={defaults.max_players}
+            onChange={(e) => update({ max_players: numberValue(e.target.value, defaults.max_players) })}
+            className={inputClass}
+          />
+        </Row>
+        <Row label="Difficulty" description="Initial world difficulty">
+          <div className="w-48">
+            <Select
+              value={defaults.difficulty}
+              onChange={(val) => update({ difficulty: val })}
+              options={['peaceful', 'easy', 'normal', 'hard'].map(d => ({ label: d[0].toUpperCase() + d.slice(1), value: d }))}
+            />
+          </div>
+        </Row>
+        <Row label="Game mode" description="Game mode for new players">
+          <div className="w-48">
+            <Select
+              value={defaults.gamemode}
+              onChange={(val) => update({ gamemode: val })}
+              options={['survival', 'creative', 'adventure', 'spectator'].map(g => ({ label: g[0].toUpperCase() + g.slice(1), value: g }))}
+            />
+          </div>
+        </Row>
+        <div className="pt-2 space-y-2">
+          <Checkbox
+            label="Online mode"
+            description="Authenticate players with Mojang; disable only behind a proxy"
+            checked={defaults.online_mode}
+            onChange={(val) => update({ online_mode: val })}
+          />
+          <Checkbox
+            label="Warn about offline mode"
+            description="Log a warning when an instance is created with online mode off"
+            checked={defaults.warn_offline_mode}
+            onChange={(val) => update({ warn_offline_mode: val })}
+          />
+        </div>
+      </div>
+    </Section>
+  );
+}
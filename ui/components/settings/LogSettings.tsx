@@ -0,0 +1,214 @@
+import { ScrollText, Copy, RefreshCw, LifeBuoy } from 'lucide-react'
+import { useState, useEffect } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { save } from '@tauri-apps/plugin-dialog'
+import { AppSettings, LogLevel, LogSettings } from '../../hooks/useAppSettings'
+import { Section, Checkbox } from './SettingsShared'
+import { formatSize } from '../../utils'
+import { useToast } from '../../hooks/useToast'
+
+interface LogFileInfo {
+  name: string;
+  size: number;
+  uncompressed_size: number;
+  modified: string | null;
+  compressed: boolean;
+}
+
+interface LogPage {
+  content: string;
+  offset: number;
+  next_offset: number;
+  eof: boolean;
+}
+
+interface DiagnosticsBundle {
+  path: string;
+  files: string[];
+  size: number;
+  redacted: boolean;
+}
+
+const LEVELS: { id: LogLevel; label: string }[] = [
+  { id: 'error', label: 'Errors only' },
+  { id: 'warn', label: 'Warnings' },
+  { id: 'info', label: 'Info' },
+  { id: 'debug', label: 'Debug' },
+  { id: 'trace', label: 'Trace' },
+]
+
+// Enough of the end of a log for a bug report
+const TAIL_SIZE = 256 * 1024;
+
+interface LogSettingsProps {
+  settings: AppSettings;
+  updateSettings: (newSettings: Partial<AppSettings>) => void;
+}
+
+export function LogSettingsPanel({ settings, updateSettings }: LogSettingsProps) {
+  const logging = settings.logging;
+  const update = (patch: Partial<LogSettings>) =>
+    updateSettings({ logging: { ...logging, ...patch } });
+
+  const [files, setFiles] = useState<LogFileInfo[]>([])
+  const [selected, setSelected] = useState('app.log')
+  const [content, setContent] = useState('')
+  const [isLoading, setIsLoading] = useState(false)
+  const [redact, setRedact] = useState(true)
+  const [isExporting, setIsExporting] = useState(false)
+  const { showToast } = useToast()
+
+  const loadLogs = async (name: string) => {
+    setIsLoading(true)
+    try {
+      const [list, page] = await Promise.all([
+        invoke<LogFileInfo[]>('list_app_logs'),
+        invoke<LogPage>('get_app_logs', { name, limit: TAIL_SIZE }),
+      ])
+      setFiles(list)
+      setContent(page.content)
+    } catch (err) {
+      console.error('Failed to read app logs:', err)
+      setContent('')
+    } finally {
+      setIsLoading(false)
+    }
+  }
+
+  useEffect(() => {
+    loadLogs(selected)
+  }, [selected])
+
+  const copyLogs = async () => {
+    try {
+      await navigator.clipboard.writeText(content)
+      showToast('Log copied to clipboard', 'success')
+    } catch (err) {
+      showToast(`Failed to copy the log: ${err}`, 'error')
+    }
+  }
+
+  const exportDiagnostics = async () => {
+    const destPath = await save({
+      defaultPath: `diagnostics-${new Date().toISOString().slice(0, 10)}.zip`,
+      filters: [{ name: 'Zip', extensions: ['zip'] }],
+    })
+    if (!destPath) return
+    setIsExporting(true)
+    try {
+      const bundle = await invoke<DiagnosticsBundle>('export_diagnostics', { destPath, redact })
+      showToast(`Saved ${bundle.files.length} files (${formatSize(bundle.size)})`, 'success')
+    } catch (err) {
+      showToast(`Failed to export diagnostics: ${err}`, 'error')
+    } finally {
+      setIsExporting(false)
+    }
+  }
+
+  const numberInput = (value: number, min: number, onChange: (value: number) => void) => (
+    <input
+      type="number"
+      min={min}
+      value={value}
+      onChange={(e) => {
+        const parsed = parseInt(e.target.value, 10);
+        if (!Number.isNaN(parsed) && parsed >= min) onChange(parsed);
+      }}
+      className="w-48 px-3 py-2 bg-black/5 dark:bg-white/5 border border-black/10 dark:border-white/10 rounded-xl text-sm focus:outline-none focus:border-primary"
+    />
+  )
+
+  return (
+    <div className="space-y-8">
+      <Section title="Wrapper Logs" icon={ScrollText}>
+        <div className="p-4 bg-black/5 dark:bg-white/5 rounded-2xl border border-black/5 dark:border-white/5 space-y-4">
+          <div className="flex items-center justify-between gap-4">
+            <div>
+              <div className="text-sm font-semibold text-gray-700 dark:text-gray-200">Verbosity</div>
+              <div className="text-xs text-gray-500 mt-1">Applies right away; use Debug or Trace while reproducing a problem</div>
+            </div>
+            <select
+              value={logging.level}
+              onChange={(e) => update({ level: e.target.value as LogLevel })}
+              className="w-48 px-3 py-2 bg-black/5 dark:bg-white/5 border border-black/10 dark:border-white/10 rounded-xl text-sm focus:outline-none focus:border-primary"
+            >
+              {LEVELS.map((level) => (
+                <option key={level.id} value={level.id}>{level.label}</option>
+              ))}
+            </select>
+          </div>
+          <div className="flex items-center justify-between gap-4">
+            <div>
+              <div className="text-sm font-semibold text-gray-700 dark:text-gray-200">File size</div>
+              <div className="text-xs text-gray-500 mt-1">MB written to a log file before a new one is started, after a restart</div>
+            </div>
+            {numberInput(logging.max_file_size_mb, 1, (max_file_size_mb) => update({ max_file_size_mb }))}
+          </div>
+          <div className="flex items-center justify-between gap-4">
+            <div>
+              <div className="text-sm font-semibold text-gray-700 dark:text-gray-200">Files kept</div>
+              <div className="text-xs text-gray-500 mt-1">Older log files are deleted, after a restart</div>
+            </div>
+            {numberInput(logging.max_files, 1, (max_files) => update({ max_files }))}
+          </div>
+        </div>
+
+        <div className="p-4 bg-black/5 dark:bg-white/5 rounded-2xl border border-black/5 dark:border-white/5 space-y-3">
+          <div className="flex items-center justify-between gap-3">
+            <select
+              value={selected}
+              onChange={(e) => setSelected(e.target.value)}
+              className="flex-1 px-3 py-2 bg-black/5 dark:bg-white/5 border border-black/10 dark:border-white/10 rounded-xl text-sm focus:outline-none focus:border-primary"
+            >
+              {files.length === 0 && <option value="app.log">app.log</option>}
+              {files.map((file) => (
+                <option key={file.name} value={file.name}>{file.name} ({formatSize(file.size)})</option>
+              ))}
+            </select>
+            <button
+              onClick={() => loadLogs(selected)}
+              disabled={isLoading}
+              className="p-2 hover:bg-black/5 dark:hover:bg-white/5 rounded-lg transition-colors text-gray-500 hover:text-primary"
+              title="Reload"
+            >
+              <RefreshCw size={18} className={isLoading ? 'animate-spin' : ''} />
+            </button>
+            <button
+              onClick={copyLogs}
+              disabled={!content}
+              className="p-2 hover:bg-black/5 dark:hover:bg-white/5 rounded-lg transition-colors text-gray-500 hover:text-primary"
+              title="Copy for a bug report"
+            >
+              <Copy size={18} />
+            </button>
+          </div>
+          <pre className="h-64 overflow-auto p-3 bg-black/10 dark:bg-black/40 rounded-xl text-[11px] font-mono text-gray-700 dark:text-gray-300 whitespace-pre-wrap custom-scrollbar">
+            {content || 'No log entries yet'}
+          </pre>
+        </div>
+      </Section>
+
+      <Section title="Diagnostics Bundle" icon={LifeBuoy}>
+        <div className="p-4 bg-black/5 dark:bg-white/5 rounded-2xl border border-black/5 dark:border-white/5 space-y-4">
+          <div className="text-xs text-gray-500">
+            One zip with the wrapper logs, each instance's settings, latest.log and newest crash reports, the Java runtimes found and system details.
+            Webhook URLs and environment variable values are always left out.
+          </div>
+          <Checkbox
+            label="Hide IP addresses and player names"
+            description="Replaces them with placeholders, for bundles shared in public issues"
+            checked={redact}
+            onChange={setRedact}
+          />
+          <button
+            onClick={exportDiagnostics}
+            disabled={isExporting}
+            className="px-4 py-2 bg-primary text-white rounded-xl text-sm font-bold disabled:opacity-50"
+          >
+            {isExporting ? 'Exporting...' : 'Export Diagnostics'}
+          </button>
+        </div>
+      </Section>
+    </div>
+  );
+}
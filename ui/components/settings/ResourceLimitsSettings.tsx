@@ -0,0 +1,65 @@
+import { Gauge } from 'lucide-react'
+import { AppSettings, StartLimits } from '../../hooks/useAppSettings'
+import { Section } from './SettingsShared'
+
+interface ResourceLimitsSettingsProps {
+  settings: AppSettings;
+  updateSettings: (newSettings: Partial<AppSettings>) => void;
+}
+
+const inputClass = "w-48 px-3 py-2 bg-black/5 dark:bg-white/5 border border-black/10 dark:border-white/10 rounded-xl text-sm focus:outline-none focus:border-primary";
+
+function Row({ label, description, children }: { label: string; description: string; children: React.ReactNode }) {
+  return (
+    <div className="flex items-center justify-between gap-4">
+      <div>
+        <div className="text-sm font-semibold text-gray-700 dark:text-gray-200">{label}</div>
+        <div className="text-xs text-gray-500 mt-1">{description}</div>
+      </div>
+      {children}
+    </div>
+  );
+}
+
+export function ResourceLimitsSettings({ settings, updateSettings }: ResourceLimitsSettingsProps) {
+  const limits = settings.start_limits;
+  const update = (patch: Partial<StartLimits>) =>
+    updateSettings({ start_limits: { ...limits, ...patch } });
+
+  return (
+    <div className="space-y-8">
+      <Section title="Resource Limits" icon={Gauge}>
+        <div className="p-4 bg-black/5 dark:bg-white/5 rounded-2xl border border-black/5 dark:border-white/5 space-y-4">
+          <div className="text-xs text-gray-500">
+            Starting a server that would go over these limits is refused, so the host doesn't run out of memory.
+          </div>
+          <Row label="Max running servers" description="Servers running at the same time, 0 for unlimited">
+            <input
+              type="number"
+              min={0}
+              value={limits.max_running_instances}
+              onChange={(e) => {
+                const parsed = parseInt(e.target.value, 10);
+                if (!Number.isNaN(parsed) && parsed >= 0) update({ max_running_instances: parsed });
+              }}
+              className={inputClass}
+            />
+          </Row>
+          <Row label="RAM budget" description="Combined max memory of running servers in MB, 0 for unlimited">
+            <input
+              type="number"
+              min={0}
+              step={512}
+              value={limits.ram_budget_mb}
+              onChange={(e) => {
+                const parsed = parseInt(e.target.value, 10);
+                if (!Number.isNaN(parsed) && parsed >= 0) update({ ram_budget_mb: parsed });
+              }}
+              className={inputClass}
+            />
+          </Row>
+        </div>
+      </Section>
+    </div>
+  );
+}
@@ -0,0 +1,274 @@
+import { FolderOpen, FolderPlus, HardDrive, Link2, Trash2, WifiOff } from 'lucide-react'
+import { useState, useEffect } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { open } from '@tauri-apps/plugin-dialog'
+import { AppSettings, ProvisionMode } from '../../hooks/useAppSettings'
+import { CachedServer } from '../../create-instance/types'
+import { StorageMode, StorageModeInfo, StorageRoot } from '../../types'
+import { Section, Checkbox } from './SettingsShared'
+import { formatSize } from '../../utils'
+import { useToast } from '../../hooks/useToast'
+
+interface StorageSettingsProps {
+  settings: AppSettings;
+  updateSettings: (newSettings: Partial<AppSettings>) => void;
+}
+
+interface DeduplicationReport {
+  mode: ProvisionMode;
+  instances: number;
+  files_linked: number;
+  files_copied: number;
+  bytes_saved: number;
+  skipped: string[];
+  errors: string[];
+}
+
+const STORAGE_MODES: { id: StorageMode; label: string; description: string }[] = [
+  { id: 'portable', label: 'Portable', description: 'Next to the app, so the whole folder can be carried around' },
+  { id: 'installed', label: 'User folder', description: "In your account's app data folder, for installs in Program Files and similar" },
+]
+
+const MODES: { id: ProvisionMode; label: string; description: string }[] = [
+  { id: 'copy', label: 'Copy', description: 'Every instance keeps its own libraries' },
+  { id: 'hardlink', label: 'Hard links', description: 'One read-only file on disk, shared by every instance on the same drive. Cloned instead where the drive supports it' },
+  { id: 'symlink', label: 'Symbolic links', description: 'Instances point at the shared file. Needs developer mode on Windows' },
+]
+
+export function StorageSettings({ settings, updateSettings }: StorageSettingsProps) {
+  const [isDeduplicating, setIsDeduplicating] = useState(false)
+  const [report, setReport] = useState<DeduplicationReport | null>(null)
+  const [offlineServers, setOfflineServers] = useState<CachedServer[]>([])
+  const [storageRoots, setStorageRoots] = useState<StorageRoot[]>([])
+  const [storageMode, setStorageMode] = useState<StorageModeInfo | null>(null)
+  const { showToast } = useToast()
+
+  useEffect(() => {
+    invoke<CachedServer[]>('get_offline_servers')
+      .then(setOfflineServers)
+      .catch((err) => console.error('Failed to list stored servers:', err))
+    invoke<StorageRoot[]>('get_storage_roots')
+      .then(setStorageRoots)
+      .catch((err) => console.error('Failed to list storage roots:', err))
+    invoke<StorageModeInfo>('get_storage_mode')
+      .then(setStorageMode)
+      .catch((err) => console.error('Failed to get storage mode:', err))
+  }, [])
+
+  const chooseStorageMode = async (mode: StorageMode) => {
+    try {
+      const info = await invoke<StorageModeInfo>('set_storage_mode', { mode })
+      setStorageMode(info)
+      if (info.pending) {
+        showToast('The data folder will be moved the next time the app starts', 'success')
+      }
+    } catch (err) {
+      console.error('Failed to change storage mode:', err)
+      showToast(`${err}`, 'error')
+    }
+  }
+
+  const saveStorageRoots = async (roots: string[]) => {
+    try {
+      // Applied first, since a root that still holds servers can't be removed
+      setStorageRoots(await invoke<StorageRoot[]>('set_storage_roots', { roots }))
+      await updateSettings({ storage_roots: roots })
+    } catch (err) {
+      console.error('Failed to update storage locations:', err)
+      showToast(`${err}`, 'error')
+    }
+  }
+
+  const addStorageRoot = async () => {
+    const selected = await open({ directory: true, multiple: false })
+    if (selected && typeof selected === 'string') {
+      await saveStorageRoots([...settings.storage_roots, selected])
+    }
+  }
+
+  const deduplicate = async () => {
+    setIsDeduplicating(true)
+    try {
+      const result = await invoke<DeduplicationReport>('deduplicate_libraries')
+      setReport(result)
+      showToast(`Saved ${formatSize(result.bytes_saved)} across ${result.instances} instances`, 'success')
+    } catch (err) {
+      console.error('Failed to deduplicate libraries:', err)
+      showToast('Failed to deduplicate libraries', 'error')
+    } finally {
+      setIsDeduplicating(false)
+    }
+  }
+
+  return (
+    <div className="space-y-8">
+      <Section title="Shared Libraries" icon={Link2}>
+        <div className="p-4 bg-black/5 dark:bg-white/5 rounded-2xl border border-black/5 dark:border-white/5 space-y-4">
+          <div className="text-xs text-gray-500">
+            Forge, NeoForge and Fabric download the same libraries into every instance. Sharing keeps one copy in the
+            artifact store. Files that can't be linked, such as on another drive, stay as copies.
+          </div>
+          <div className="grid grid-cols-1 sm:grid-cols-3 gap-3">
+            {MODES.map((mode) => (
+              <button
+                key={mode.id}
+                onClick={() => updateSettings({ library_sharing: mode.id })}
+                className={`p-4 rounded-xl border text-left transition-all ${
+                  settings.library_sharing === mode.id
+                    ? 'border-primary/50 bg-primary/10'
+                    : 'border-black/5 dark:border-white/5 bg-black/5 dark:bg-white/5 hover:border-primary/30'
+                }`}
+              >
+                <div className="text-sm font-bold text-gray-700 dark:text-gray-200">{mode.label}</div>
+                <div className="text-[10px] text-gray-500 mt-0.5">{mode.description}</div>
+              </button>
+            ))}
+          </div>
+        </div>
+      </Section>
+
+      {storageMode && (
+        <Section title="Data Folder" icon={FolderOpen}>
+          <div className="p-4 bg-black/5 dark:bg-white/5 rounded-2xl border border-black/5 dark:border-white/5 space-y-4">
+            <div className="text-xs text-gray-500">
+              Settings, servers, backups and caches are kept in <span className="font-mono">{storageMode.data_dir}</span>.
+              Switching moves everything the next time the app starts, once all servers are stopped.
+            </div>
+            <div className="grid grid-cols-1 sm:grid-cols-2 gap-3">
+              {STORAGE_MODES.map((mode) => {
+                const target = storageMode.pending ?? storageMode.mode
+                const disabled = mode.id === 'portable' && !storageMode.portable_available
+                return (
+                  <button
+                    key={mode.id}
+                    onClick={() => chooseStorageMode(mode.id)}
+                    disabled={disabled}
+                    className={`p-4 rounded-xl border text-left transition-all disabled:opacity-50 ${
+                      target === mode.id
+                        ? 'border-primary/50 bg-primary/10'
+                        : 'border-black/5 dark:border-white/5 bg-black/5 dark:bg-white/5 hover:border-primary/30'
+                    }`}
+                  >
+                    <div className="text-sm font-bold text-gray-700 dark:text-gray-200">{mode.label}</div>
+                    <div className="text-[10px] text-gray-500 mt-0.5">
+                      {disabled ? "The app's folder isn't writable" : mode.description}
+                    </div>
+                    <div className="text-[10px] text-gray-400 mt-1 font-mono truncate">
+                      {mode.id === 'portable' ? storageMode.portable_dir : storageMode.installed_dir}
+                    </div>
+                  </button>
+                )
+              })}
+            </div>
+            {storageMode.pending && (
+              <div className="text-xs text-amber-500">Restart the app to move the data folder.</div>
+            )}
+          </div>
+        </Section>
+      )}
+
+      <Section title="Storage Locations" icon={HardDrive}>
+        <div className="p-4 bg-black/5 dark:bg-white/5 rounded-2xl border border-black/5 dark:border-white/5 space-y-4">
+          <div className="flex items-center justify-between gap-4">
+            <div className="text-xs text-gray-500">
+              Folders, e.g. on a second disk, that servers can be moved to from their settings menu. A location can
+              only be removed once no server is stored there.
+            </div>
+            <button
+              onClick={addStorageRoot}
+              className="px-4 py-2 bg-primary text-white rounded-xl text-sm font-bold flex items-center gap-2 shrink-0"
+            >
+              <FolderPlus size={16} />
+              Add
+            </button>
+          </div>
+          <div className="space-y-2">
+            {storageRoots.map((root) => (
+              <div key={root.path} className="flex items-center justify-between gap-4 text-xs">
+                <div className="min-w-0">
+                  <div className="font-semibold text-gray-700 dark:text-gray-200 truncate" title={root.path}>
+                    {root.is_default ? `${root.path} (default)` : root.path}
+                  </div>
+                  <div className="text-gray-500">
+                    {root.instance_count} servers
+                    {root.available_space !== null && `, ${formatSize(root.available_space)} free`}
+                  </div>
+                </div>
+                {!root.is_default && (
+                  <button
+                    onClick={() => saveStorageRoots(settings.storage_roots.filter((r) => r !== root.path))}
+                    disabled={root.instance_count > 0}
+                    className="p-2 text-gray-400 hover:text-red-500 disabled:opacity-30 disabled:hover:text-gray-400 rounded-lg"
+                    title={root.instance_count > 0 ? 'Move its servers elsewhere first' : 'Remove'}
+                  >
+                    <Trash2 size={14} />
+                  </button>
+                )}
+              </div>
+            ))}
+          </div>
+        </div>
+      </Section>
+
+      <Section title="Existing Instances" icon={HardDrive}>
+        <div className="p-4 bg-black/5 dark:bg-white/5 rounded-2xl border border-black/5 dark:border-white/5 space-y-4">
+          <div className="flex items-center justify-between gap-4">
+            <div>
+              <div className="text-sm font-semibold text-gray-700 dark:text-gray-200">Deduplicate libraries</div>
+              <div className="text-xs text-gray-500 mt-1">
+                Links the libraries of stopped instances into the store. Uses hard links when sharing is off.
+              </div>
+            </div>
+            <button
+              onClick={deduplicate}
+              disabled={isDeduplicating}
+              className="px-4 py-2 bg-primary text-white rounded-xl text-sm font-bold disabled:opacity-50"
+            >
+              {isDeduplicating ? 'Deduplicating...' : 'Deduplicate'}
+            </button>
+          </div>
+          {report && (
+            <div className="text-xs text-gray-500 space-y-1">
+              <div>
+                {report.files_linked} files linked in {report.instances} instances, {formatSize(report.bytes_saved)} saved.
+                {report.files_copied > 0 && ` ${report.files_copied} files couldn't be linked.`}
+              </div>
+              {report.skipped.length > 0 && <div>Skipped while running: {report.skipped.join(', ')}</div>}
+              {report.errors.map((error) => (
+                <div key={error} className="text-red-500">{error}</div>
+              ))}
+            </div>
+          )}
+        </div>
+      </Section>
+
+      <Section title="Offline Mode" icon={WifiOff}>
+        <div className="p-4 bg-black/5 dark:bg-white/5 rounded-2xl border border-black/5 dark:border-white/5 space-y-4">
+          <Checkbox
+            label="Install servers from local files only"
+            description="New instances use server jars already in the artifact store and never ask Mojang or loader APIs. Forge, NeoForge, Fabric, Quilt and Spigot need their installers online."
+            checked={settings.offline_mode}
+            onChange={(checked) => updateSettings({ offline_mode: checked })}
+          />
+          <div className="space-y-1">
+            <div className="text-xs font-semibold text-gray-700 dark:text-gray-200">
+              Installable offline ({offlineServers.length})
+            </div>
+            {offlineServers.length === 0 && (
+              <div className="text-xs text-gray-500">Server jars are kept here once they have been downloaded.</div>
+            )}
+            {offlineServers.map((server) => (
+              <div key={`${server.server_type}-${server.mc_version}-${server.loader_version}`} className="flex items-center justify-between text-xs text-gray-500">
+                <span>
+                  {server.server_type ?? 'vanilla'} {server.mc_version}
+                  {server.loader_version && ` (build ${server.loader_version})`}
+                </span>
+                <span>{formatSize(server.size)}</span>
+              </div>
+            ))}
+          </div>
+        </div>
+      </Section>
+    </div>
+  );
+}
@@ -7,6 +7,7 @@ import { Section } from './SettingsShared'
 import { invoke } from '@tauri-apps/api/core'
 import { listen } from '@tauri-apps/api/event'
 import { useToast } from '../../hooks/useToast'
+import { TaskInfo } from '../../types'
 
 interface SystemSettingsProps {
   settings: AppSettings;
@@ -22,10 +23,11 @@ export function SystemSettings({ settings, updateSettings }: SystemSettingsProps
     let unlisten: (() => void) | undefined;
 
     const setupListener = async () => {
-      unlisten = await listen('java_download_progress', (event: any) => {
+      unlisten = await listen<TaskInfo>('task-progress', (event) => {
+        if (event.payload.kind !== 'java') return;
         setDownloadProgress({
-          downloaded: event.payload.downloaded as number,
-          total: event.payload.total as number
+          downloaded: event.payload.current,
+          total: event.payload.total
         });
       });
     };
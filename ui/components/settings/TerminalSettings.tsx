@@ -0,0 +1,45 @@
+import { SquareTerminal } from 'lucide-react'
+import { AppSettings, ShellSettings } from '../../hooks/useAppSettings'
+import { Section, Checkbox } from './SettingsShared'
+
+interface TerminalSettingsProps {
+  settings: AppSettings;
+  updateSettings: (newSettings: Partial<AppSettings>) => void;
+}
+
+export function TerminalSettings({ settings, updateSettings }: TerminalSettingsProps) {
+  const shell = settings.shell;
+  const update = (patch: Partial<ShellSettings>) =>
+    updateSettings({ shell: { ...shell, ...patch } });
+
+  return (
+    <div className="space-y-8">
+      <Section title="Instance Terminal" icon={SquareTerminal}>
+        <div className="p-4 bg-black/5 dark:bg-white/5 rounded-2xl border border-black/5 dark:border-white/5 space-y-4">
+          <Checkbox
+            label="Allow shell commands"
+            description="Adds a Shell mode to the console that runs commands in the instance folder with this app's permissions. Every command is recorded in the instance timeline."
+            checked={shell.enabled}
+            onChange={(enabled) => update({ enabled })}
+          />
+          <div className="flex items-center justify-between gap-4">
+            <div>
+              <div className="text-sm font-semibold text-gray-700 dark:text-gray-200">Timeout</div>
+              <div className="text-xs text-gray-500 mt-1">Commands still running after this many seconds are killed</div>
+            </div>
+            <input
+              type="number"
+              min={1}
+              value={shell.timeout_secs}
+              onChange={(e) => {
+                const parsed = parseInt(e.target.value, 10);
+                if (!Number.isNaN(parsed) && parsed >= 1) update({ timeout_secs: parsed });
+              }}
+              className="w-48 px-3 py-2 bg-black/5 dark:bg-white/5 border border-black/10 dark:border-white/10 rounded-xl text-sm focus:outline-none focus:border-primary"
+            />
+          </div>
+        </div>
+      </Section>
+    </div>
+  );
+}
@@ -63,7 +63,10 @@ export function ConfigControls({
           }}
           whileTap={{ scale: 0.98 }}
           onClick={onRawEdit}
-          className="flex-1 md:flex-none flex items-center justify-center gap-2 px-6 py-3.5 bg-black/5 dark:bg-white/[0.03] hover:bg-black/10 dark:hover:bg-white/[0.08] border border-black/10 dark:border-white/10 rounded-2xl transition-all duration-200 text-sm font-bold uppercase tracking-widest text-gray-500 dark:text-white/60 hover:text-gray-900 dark:hover:text-white"
+          // NBT files are binary, so they can only be edited through the form
+          disabled={selectedConfig?.format === 'Nbt'}
+          title={selectedConfig?.format === 'Nbt' ? 'NBT files are binary and have no raw text' : undefined}
+          className="disabled:opacity-40 disabled:cursor-not-allowed flex-1 md:flex-none flex items-center justify-center gap-2 px-6 py-3.5 bg-black/5 dark:bg-white/[0.03] hover:bg-black/10 dark:hover:bg-white/[0.08] border border-black/10 dark:border-white/10 rounded-2xl transition-all duration-200 text-sm font-bold uppercase tracking-widest text-gray-500 dark:text-white/60 hover:text-gray-900 dark:hover:text-white"
         >
           <Edit3 size={18} />
           Edit Raw
@@ -1,15 +1,114 @@
+import { useEffect, useState } from 'react'
 import { motion } from 'framer-motion'
-import { FileText } from 'lucide-react'
+import { ChevronRight, FileText, Folder, Loader2, Package, Puzzle, Server } from 'lucide-react'
 import { cn } from '../utils'
-import { ConfigFile } from './types'
+import { ConfigFile, ConfigGroup, ConfigTreeNode, buildConfigTree, groupKey } from './types'
 
 interface ConfigSidebarProps {
-  availableConfigs: ConfigFile[]
+  groups: ConfigGroup[]
+  /** Files of the groups opened so far, by group key */
+  groupFiles: Record<string, ConfigFile[]>
+  onOpenGroup: (group: ConfigGroup) => void
   selectedConfig: ConfigFile | null
   setSelectedConfig: (config: ConfigFile) => void
 }
 
-export function ConfigSidebar({ availableConfigs, selectedConfig, setSelectedConfig }: ConfigSidebarProps) {
+const GROUP_ICONS = { server: Server, mod: Package, plugin: Puzzle }
+
+interface ConfigTreeItemProps {
+  node: ConfigTreeNode
+  depth: number
+  selectedConfig: ConfigFile | null
+  setSelectedConfig: (config: ConfigFile) => void
+}
+
+function ConfigTreeItem({ node, depth, selectedConfig, setSelectedConfig }: ConfigTreeItemProps) {
+  const [open, setOpen] = useState(depth === 0 || !!selectedConfig?.path.startsWith(`${node.path}/`))
+  const indent = { paddingLeft: `${1 + depth * 0.75}rem` }
+
+  if (!node.format) {
+    return (
+      <div>
+        <button
+          onClick={() => setOpen(!open)}
+          style={indent}
+          className="w-full text-left pr-4 py-2 rounded-xl text-sm font-medium flex items-center gap-2 text-gray-500 dark:text-white/40 hover:bg-black/5 dark:hover:bg-white/5"
+        >
+          <ChevronRight size={14} className={cn('transition-transform', open && 'rotate-90')} />
+          <Folder size={14} />
+          <span className="truncate">{node.name}</span>
+        </button>
+        {open && node.children?.map(child => (
+          <ConfigTreeItem key={child.path} node={child} depth={depth + 1} selectedConfig={selectedConfig} setSelectedConfig={setSelectedConfig} />
+        ))}
+      </div>
+    )
+  }
+
+  const selected = selectedConfig?.path === node.path
+  return (
+    <button
+      onClick={() => setSelectedConfig({ name: node.name, path: node.path, format: node.format! })}
+      style={indent}
+      className={cn(
+        "w-full text-left pr-4 py-3 rounded-xl text-sm font-medium transition-all duration-200 flex items-center justify-between group",
+        selected
+          ? "bg-primary text-white shadow-glow-primary"
+          : "hover:bg-black/5 dark:hover:bg-white/5 text-gray-500 dark:text-white/40 hover:text-gray-900 dark:hover:text-white"
+      )}
+    >
+      <span className="truncate">{node.name}</span>
+      {selected && (
+        <motion.div layoutId="active-indicator" className="w-1.5 h-1.5 rounded-full bg-white" />
+      )}
+    </button>
+  )
+}
+
+interface ConfigGroupItemProps {
+  group: ConfigGroup
+  files: ConfigFile[] | undefined
+  onOpen: () => void
+  selectedConfig: ConfigFile | null
+  setSelectedConfig: (config: ConfigFile) => void
+}
+
+function ConfigGroupItem({ group, files, onOpen, selectedConfig, setSelectedConfig }: ConfigGroupItemProps) {
+  const owns = (path?: string) => !!path && group.roots.some(root => path === root || path.startsWith(`${root}/`))
+  const [open, setOpen] = useState(group.kind === 'server' || owns(selectedConfig?.path))
+  const Icon = GROUP_ICONS[group.kind]
+
+  useEffect(() => {
+    if (open && !files) onOpen()
+  }, [open, files])
+
+  return (
+    <div>
+      <button
+        onClick={() => setOpen(!open)}
+        className="w-full text-left px-4 py-2 rounded-xl text-sm font-bold flex items-center gap-2 text-gray-600 dark:text-white/60 hover:bg-black/5 dark:hover:bg-white/5"
+      >
+        <ChevronRight size={14} className={cn('transition-transform', open && 'rotate-90')} />
+        <Icon size={14} className="text-primary" />
+        <span className="truncate flex-1">{group.kind === 'server' ? 'Server' : group.owner}</span>
+        {group.kind !== 'server' && (
+          <span className="text-[10px] uppercase tracking-widest text-gray-400 dark:text-white/20">{group.kind}</span>
+        )}
+      </button>
+      {open && (files ? (
+        buildConfigTree(files).map(node => (
+          <ConfigTreeItem key={node.path} node={node} depth={1} selectedConfig={selectedConfig} setSelectedConfig={setSelectedConfig} />
+        ))
+      ) : (
+        <div className="flex items-center gap-2 pl-8 py-2 text-xs text-gray-400 dark:text-white/30">
+          <Loader2 size={12} className="animate-spin" /> Loading...
+        </div>
+      ))}
+    </div>
+  )
+}
+
+export function ConfigSidebar({ groups, groupFiles, onOpenGroup, selectedConfig, setSelectedConfig }: ConfigSidebarProps) {
   return (
     <div className="w-64 shrink-0 flex flex-col gap-4">
       <div className="flex items-center gap-2 px-2">
@@ -18,23 +117,16 @@ export function ConfigSidebar({ availableConfigs, selectedConfig, setSelectedCon
           Config Files
         </h3>
       </div>
-      <div className="space-y-1">
-        {availableConfigs.map((config) => (
-          <button
-            key={config.path}
-            onClick={() => setSelectedConfig(config)}
-            className={cn(
-              "w-full text-left px-4 py-3 rounded-xl text-sm font-medium transition-all duration-200 flex items-center justify-between group",
-              selectedConfig?.path === config.path
-                ? "bg-primary text-white shadow-glow-primary"
-                : "hover:bg-black/5 dark:hover:bg-white/5 text-gray-500 dark:text-white/40 hover:text-gray-900 dark:hover:text-white"
-            )}
-          >
-            <span className="truncate">{config.name}</span>
-            {selectedConfig?.path === config.path && (
-              <motion.div layoutId="active-indicator" className="w-1.5 h-1.5 rounded-full bg-white" />
-            )}
-          </button>
+      <div className="space-y-1 overflow-y-auto max-h-[70vh]">
+        {groups.map(group => (
+          <ConfigGroupItem
+            key={groupKey(group)}
+            group={group}
+            files={groupFiles[groupKey(group)]}
+            onOpen={() => onOpenGroup(group)}
+            selectedConfig={selectedConfig}
+            setSelectedConfig={setSelectedConfig}
+          />
         ))}
       </div>
     </div>
@@ -0,0 +1,131 @@
+import { useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { Search, Replace, X } from 'lucide-react'
+import { useToast } from '../hooks/useToast'
+import { Select } from '../components/Select'
+import { cn } from '../utils'
+
+type SearchTarget = 'key' | 'value' | 'any'
+
+interface ConfigMatch {
+  path: string
+  line: number
+  key: string | null
+  text: string
+}
+
+interface FindReplacePanelProps {
+  instanceId: string
+  onClose: () => void
+  onReplaced: () => void
+}
+
+export function FindReplacePanel({ instanceId, onClose, onReplaced }: FindReplacePanelProps) {
+  const [pattern, setPattern] = useState('')
+  const [replacement, setReplacement] = useState('')
+  const [target, setTarget] = useState<SearchTarget>('any')
+  const [regex, setRegex] = useState(false)
+  const [caseSensitive, setCaseSensitive] = useState(false)
+  const [matches, setMatches] = useState<ConfigMatch[] | null>(null)
+  const [excluded, setExcluded] = useState<Set<string>>(new Set())
+  const [busy, setBusy] = useState(false)
+  const { showToast } = useToast()
+
+  const search = { pattern, regex, case_sensitive: caseSensitive, target }
+  const files = [...new Set((matches ?? []).map(m => m.path))]
+  const selectedFiles = files.filter(f => !excluded.has(f))
+  const selectedCount = (matches ?? []).filter(m => !excluded.has(m.path)).length
+
+  const runSearch = async () => {
+    if (!pattern) return
+    setBusy(true)
+    try {
+      setMatches(await invoke<ConfigMatch[]>('search_instance_configs', { instanceId, search }))
+      setExcluded(new Set())
+    } catch (err) {
+      showToast(`Search failed: ${err}`, 'error')
+    } finally {
+      setBusy(false)
+    }
+  }
+
+  const runReplace = async () => {
+    setBusy(true)
+    try {
+      const report = await invoke<{ files_changed: string[]; replacements: number }>('replace_in_instance_configs', {
+        instanceId,
+        search,
+        replacement,
+        files: selectedFiles,
+        expectedMatches: selectedCount,
+      })
+      showToast(`Replaced ${report.replacements} line(s) in ${report.files_changed.length} file(s)`, 'success')
+      setMatches(null)
+      onReplaced()
+    } catch (err) {
+      showToast(`${err}`, 'error')
+    } finally {
+      setBusy(false)
+    }
+  }
+
+  const toggleFile = (file: string) => {
+    const next = new Set(excluded)
+    if (next.has(file)) next.delete(file); else next.add(file)
+    setExcluded(next)
+  }
+
+  const inputClass = 'flex-1 px-3 py-2 bg-black/20 border border-white/10 rounded-xl text-sm font-mono focus:outline-none focus:border-primary/50'
+  const buttonClass = 'flex items-center gap-2 px-3 py-2 bg-white/5 hover:bg-white/10 text-gray-300 rounded-xl transition-all border border-white/5 text-sm font-medium disabled:opacity-50'
+
+  return (
+    <div className="p-4 bg-white/[0.02] border border-white/5 rounded-2xl space-y-3">
+      <div className="flex items-center justify-between">
+        <span className="text-xs font-black uppercase tracking-widest text-gray-400 dark:text-white/30">Find &amp; Replace in all configs</span>
+        <button onClick={onClose} className="p-1 text-gray-400 hover:text-white"><X size={16} /></button>
+      </div>
+      <div className="flex flex-wrap items-center gap-2">
+        <input value={pattern} onChange={e => setPattern(e.target.value)} onKeyDown={e => e.key === 'Enter' && runSearch()} placeholder="Find" className={inputClass} />
+        <Select
+          value={target}
+          onChange={v => setTarget(v as SearchTarget)}
+          options={[{ value: 'any', label: 'Anywhere' }, { value: 'key', label: 'Keys' }, { value: 'value', label: 'Values' }]}
+          size="sm"
+          className="w-32"
+        />
+        <label className="flex items-center gap-1 text-xs text-gray-400"><input type="checkbox" checked={regex} onChange={e => setRegex(e.target.checked)} /> Regex</label>
+        <label className="flex items-center gap-1 text-xs text-gray-400"><input type="checkbox" checked={caseSensitive} onChange={e => setCaseSensitive(e.target.checked)} /> Match case</label>
+        <button onClick={runSearch} disabled={busy || !pattern} className={buttonClass}><Search size={14} /> Search</button>
+      </div>
+
+      {matches && (
+        <>
+          <div className="max-h-72 overflow-y-auto space-y-2">
+            {matches.length === 0 && <p className="text-sm text-gray-500">No matches.</p>}
+            {files.map(file => (
+              <div key={file} className={cn('rounded-xl border border-white/5 p-2', excluded.has(file) && 'opacity-40')}>
+                <label className="flex items-center gap-2 text-sm font-bold text-gray-300">
+                  <input type="checkbox" checked={!excluded.has(file)} onChange={() => toggleFile(file)} />
+                  {file}
+                </label>
+                {matches.filter(m => m.path === file).map(m => (
+                  <div key={m.line} className="pl-6 text-xs font-mono text-gray-500 truncate">
+                    <span className="text-gray-600 mr-2">{m.line}</span>{m.text}
+                  </div>
+                ))}
+              </div>
+            ))}
+          </div>
+          {matches.length > 0 && (
+            <div className="flex items-center gap-2">
+              <input value={replacement} onChange={e => setReplacement(e.target.value)} placeholder="Replace with" className={inputClass} />
+              <button onClick={runReplace} disabled={busy || selectedCount === 0} className={buttonClass}>
+                <Replace size={14} /> Replace {selectedCount} in {selectedFiles.length} file(s)
+              </button>
+            </div>
+          )}
+        </>
+      )}
+    </div>
+  )
+}
@@ -0,0 +1,135 @@
+import { useState, useEffect } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { Sparkles, X, AlertTriangle } from 'lucide-react'
+import { useToast } from '../hooks/useToast'
+import { cn } from '../utils'
+
+interface ConfigPreset {
+  id: string
+  name: string
+  description: string
+}
+
+interface PresetDiff {
+  file: string
+  key: string
+  current: string | null
+  proposed: string
+  changed: boolean
+}
+
+interface PresetPreview {
+  preset_id: string
+  diffs: PresetDiff[]
+  missing_files: string[]
+}
+
+interface PresetsPanelProps {
+  instanceId: string
+  onClose: () => void
+  onApplied: () => void
+}
+
+export function PresetsPanel({ instanceId, onClose, onApplied }: PresetsPanelProps) {
+  const [presets, setPresets] = useState<ConfigPreset[]>([])
+  const [preview, setPreview] = useState<PresetPreview | null>(null)
+  const [busy, setBusy] = useState(false)
+  const { showToast } = useToast()
+
+  useEffect(() => {
+    invoke<ConfigPreset[]>('list_config_presets').then(setPresets).catch(() => setPresets([]))
+  }, [])
+
+  const loadPreview = async (presetId: string) => {
+    setBusy(true)
+    try {
+      setPreview(await invoke<PresetPreview>('preview_config_preset', { instanceId, presetId }))
+    } catch (err) {
+      showToast(`Could not preview preset: ${err}`, 'error')
+    } finally {
+      setBusy(false)
+    }
+  }
+
+  const apply = async () => {
+    if (!preview) return
+    setBusy(true)
+    try {
+      const files = await invoke<string[]>('apply_config_preset', { instanceId, presetId: preview.preset_id })
+      showToast(files.length > 0 ? `Updated ${files.length} file(s); restart the server to apply` : 'Already up to date', 'success')
+      setPreview(null)
+      onApplied()
+    } catch (err) {
+      showToast(`${err}`, 'error')
+    } finally {
+      setBusy(false)
+    }
+  }
+
+  const changes = (preview?.diffs ?? []).filter(d => d.changed)
+  const buttonClass = 'flex items-center gap-2 px-3 py-2 bg-white/5 hover:bg-white/10 text-gray-300 rounded-xl transition-all border border-white/5 text-sm font-medium disabled:opacity-50'
+
+  return (
+    <div className="p-4 bg-white/[0.02] border border-white/5 rounded-2xl space-y-3">
+      <div className="flex items-center justify-between">
+        <span className="text-xs font-black uppercase tracking-widest text-gray-400 dark:text-white/30">Config presets</span>
+        <button onClick={onClose} className="p-1 text-gray-400 hover:text-white"><X size={16} /></button>
+      </div>
+      <div className="grid grid-cols-1 md:grid-cols-3 gap-2">
+        {presets.map(p => (
+          <button
+            key={p.id}
+            onClick={() => loadPreview(p.id)}
+            disabled={busy}
+            className={cn(
+              'text-left p-3 rounded-xl border transition-all disabled:opacity-50',
+              preview?.preset_id === p.id ? 'border-primary/50 bg-primary/10' : 'border-white/5 hover:bg-white/5'
+            )}
+          >
+            <div className="text-sm font-bold text-gray-200">{p.name}</div>
+            <div className="text-xs text-gray-500">{p.description}</div>
+          </button>
+        ))}
+      </div>
+
+      {preview && (
+        <>
+          {preview.missing_files.length > 0 && (
+            <div className="flex items-start gap-2 text-xs text-amber-400">
+              <AlertTriangle size={14} className="mt-0.5 shrink-0" />
+              <span>Start the server once to generate {preview.missing_files.join(', ')} before applying this preset.</span>
+            </div>
+          )}
+          <div className="max-h-72 overflow-y-auto">
+            <table className="w-full text-xs font-mono">
+              <thead>
+                <tr className="text-left text-gray-500">
+                  <th className="py-1 pr-2">File</th>
+                  <th className="py-1 pr-2">Key</th>
+                  <th className="py-1 pr-2">Current</th>
+                  <th className="py-1">New</th>
+                </tr>
+              </thead>
+              <tbody>
+                {preview.diffs.map(d => (
+                  <tr key={`${d.file}:${d.key}`} className={cn('border-t border-white/5', !d.changed && 'opacity-40')}>
+                    <td className="py-1 pr-2 text-gray-500">{d.file}</td>
+                    <td className="py-1 pr-2 text-gray-300">{d.key}</td>
+                    <td className="py-1 pr-2 text-red-400/80">{d.current ?? '(unset)'}</td>
+                    <td className="py-1 text-green-400/80">{d.proposed}</td>
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+          </div>
+          <div className="flex items-center gap-2">
+            <button onClick={apply} disabled={busy || changes.length === 0 || preview.missing_files.length > 0} className={buttonClass}>
+              <Sparkles size={14} /> Apply {changes.length} change(s)
+            </button>
+            <span className="text-xs text-gray-500">YAML comments in edited files are not kept.</span>
+          </div>
+        </>
+      )}
+    </div>
+  )
+}
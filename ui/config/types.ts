@@ -1,5 +1,52 @@
 export interface ConfigFile {
   name: string
   path: string
-  format: 'Properties' | 'Yaml' | 'Toml' | 'Json'
+  format: 'Properties' | 'Yaml' | 'Toml' | 'Json' | 'Hocon' | 'Nbt'
+}
+
+export interface ConfigTreeNode {
+  name: string
+  path: string
+  /** null for folders */
+  format: ConfigFile['format'] | null
+  children?: ConfigTreeNode[]
+}
+
+export type ConfigOwnerKind = 'server' | 'mod' | 'plugin'
+
+export interface ConfigGroup {
+  kind: ConfigOwnerKind
+  owner: string
+  /** Files and folders holding the group's configs; folders are walked when the group is opened */
+  roots: string[]
+}
+
+export function groupKey(group: Pick<ConfigGroup, 'kind' | 'owner'>): string {
+  return `${group.kind}:${group.owner}`
+}
+
+/** Nests a group's files by the subfolders in their names, folders first */
+export function buildConfigTree(files: ConfigFile[]): ConfigTreeNode[] {
+  const root: ConfigTreeNode[] = []
+  for (const file of files) {
+    const segments = file.name.split('/')
+    let base = file.path.slice(0, file.path.length - file.name.length).replace(/\/$/, '')
+    let nodes = root
+    for (const dir of segments.slice(0, -1)) {
+      base = base ? `${base}/${dir}` : dir
+      let folder = nodes.find(n => n.format === null && n.name === dir)
+      if (!folder) {
+        folder = { name: dir, path: base, format: null, children: [] }
+        nodes.push(folder)
+      }
+      nodes = folder.children!
+    }
+    nodes.push({ name: segments[segments.length - 1], path: file.path, format: file.format })
+  }
+  const sort = (nodes: ConfigTreeNode[]) => {
+    nodes.sort((a, b) => Number(a.format !== null) - Number(b.format !== null) || a.name.localeCompare(b.name))
+    nodes.forEach(n => n.children && sort(n.children))
+  }
+  sort(root)
+  return root
 }
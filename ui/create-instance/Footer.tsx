@@ -19,8 +19,11 @@ interface FooterProps {
   startAfterCreation: boolean;
   setStartAfterCreation: (val: boolean) => void;
   nameExists?: boolean;
+  importBlocked?: boolean;
   selectedModpack?: string | null;
   selectedModpackVersion?: string | null;
+  selectedTemplate?: number | null;
+  remoteJar?: string | null;
 }
 
 export function Footer({
@@ -38,17 +41,26 @@ export function Footer({
   startAfterCreation,
   setStartAfterCreation,
   nameExists = false,
+  importBlocked = false,
   selectedModpack,
-  selectedModpackVersion
+  selectedModpackVersion,
+  selectedTemplate,
+  remoteJar
 }: FooterProps) {
   const isImport = activeTab === 'import';
   const isModrinth = activeTab === 'modrinth';
+  const isTemplate = activeTab === 'template';
+  const isRemote = activeTab === 'remote';
 
   const isDisabled = isImport
-    ? !name || !importSourcePath || !selectedJar || creating || nameExists
+    ? !name || !importSourcePath || !selectedJar || creating || nameExists || importBlocked
     : isModrinth
       ? !name || !selectedModpack || !selectedModpackVersion || creating || nameExists
-      : !name || !selectedVersion || creating || loadingModLoaders || nameExists;
+      : isTemplate
+        ? !name || selectedTemplate == null || creating || nameExists
+        : isRemote
+          ? !name || !remoteJar || creating || nameExists
+          : !name || !selectedVersion || creating || loadingModLoaders || nameExists;
 
   const showWarning = isImport && !serverPropertiesExists && !isDisabled && !bypassServerPropertiesCheck;
 
@@ -61,6 +73,14 @@ export function Footer({
       if (name && selectedModpack && selectedModpackVersion) return `Ready to create ${name} from modpack`;
       return 'Select a modpack and version to continue';
     }
+    if (isRemote) {
+      if (name && remoteJar) return `Ready to download and import ${name}`;
+      return 'Connect, pick the server folder and JAR to continue';
+    }
+    if (isTemplate) {
+      if (name && selectedTemplate != null) return `Ready to create ${name} from template`;
+      return 'Select a template to continue';
+    }
     return selectedVersion ? `Ready to install Minecraft ${selectedVersion}` : 'Select a software and version to continue';
   };
 
@@ -92,7 +112,7 @@ export function Footer({
         <span>{getReadyMessage()}</span>
       </div>
       <div className="flex items-center gap-5">
-        {!isImport && (
+        {!isImport && !isTemplate && (
           <button
             onClick={() => setStartAfterCreation(!startAfterCreation)}
             className={cn(
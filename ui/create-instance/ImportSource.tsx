@@ -1,8 +1,9 @@
 import { motion, AnimatePresence } from 'framer-motion'
-import { HardDrive, FileWarning, Folder, FileArchive, ChevronDown, Cpu, Zap, FileCode, Terminal } from 'lucide-react'
+import { HardDrive, FileWarning, Folder, FileArchive, ChevronDown, Cpu, Zap, FileCode, Terminal, FolderTree, Link2 } from 'lucide-react'
 import { open } from '@tauri-apps/plugin-dialog'
 import { invoke } from '@tauri-apps/api/core'
-import { cn } from '../utils'
+import { cn, formatSize } from '../utils'
+import { ImportPreflight } from '../types'
 import { useState } from 'react'
 import { Select } from '../components/Select'
 import { ArchiveFileTree } from './ArchiveFileTree'
@@ -39,6 +40,9 @@ interface ImportSourceProps {
   setBypassServerPropertiesCheck: (bypass: boolean) => void;
   rootWithinZip: string | null;
   setRootWithinZip: (path: string | null) => void;
+  preflight: ImportPreflight | null;
+  linkInPlace: boolean;
+  setLinkInPlace: (link: boolean) => void;
 }
 
 export function ImportSource({
@@ -59,7 +63,10 @@ export function ImportSource({
   bypassServerPropertiesCheck,
   setBypassServerPropertiesCheck,
   rootWithinZip,
-  setRootWithinZip
+  setRootWithinZip,
+  preflight,
+  linkInPlace,
+  setLinkInPlace
 }: ImportSourceProps) {
   const [loading, setLoading] = useState(false);
   const [scriptInfo, setScriptInfo] = useState<ParsedScriptInfo | null>(null);
@@ -159,6 +166,7 @@ export function ImportSource({
     });
     if (selected && typeof selected === 'string') {
       setRootWithinZip(null);
+      setLinkInPlace(false);
       setImportSourcePath(selected);
     }
   };
@@ -217,6 +225,55 @@ export function ImportSource({
                 </div>
               </div>
 
+              {!(importSourcePath.endsWith('.zip') || importSourcePath.endsWith('.7z')) && (
+                <label className="flex items-start gap-3 cursor-pointer">
+                  <input
+                    type="checkbox"
+                    checked={linkInPlace}
+                    onChange={(e) => setLinkInPlace(e.target.checked)}
+                    className="mt-0.5 accent-primary"
+                  />
+                  <div>
+                    <div className="flex items-center gap-2 text-xs font-bold">
+                      <Link2 size={14} /> Use this folder in place
+                    </div>
+                    <div className="text-[11px] text-gray-500 dark:text-white/50">
+                      Nothing is copied. The server runs from this folder, and deleting the instance keeps it.
+                    </div>
+                  </div>
+                </label>
+              )}
+
+              {preflight && (
+                <div className="space-y-2">
+                  {!preflight.source_error && !linkInPlace && (
+                    <div className="flex items-center gap-3 text-[11px] text-gray-500 dark:text-white/50">
+                      <HardDrive size={14} className="shrink-0" />
+                      <span>
+                        {preflight.file_count} files, {formatSize(preflight.estimated_size)}
+                        {preflight.available_space != null && ` of ${formatSize(preflight.available_space)} free`}
+                      </span>
+                    </div>
+                  )}
+                  {preflight.root_detected && preflight.root_within_zip && (
+                    <div className="flex items-center gap-3 text-[11px] text-primary">
+                      <FolderTree size={14} className="shrink-0" />
+                      <span>Importing from the nested folder <span className="font-mono">{preflight.root_within_zip}</span></span>
+                    </div>
+                  )}
+                  {[
+                    preflight.source_error,
+                    preflight.insufficient_space &&
+                      `Not enough disk space: need ${formatSize(preflight.insufficient_space.required)} free, have ${formatSize(preflight.insufficient_space.available)}`
+                  ].filter(Boolean).map(problem => (
+                    <div key={problem as string} className="flex items-start gap-3 p-3 rounded-lg bg-accent-rose/10 border border-accent-rose/20 text-accent-rose">
+                      <FileWarning size={16} className="mt-0.5 shrink-0" />
+                      <div className="text-[11px] font-medium leading-relaxed">{problem}</div>
+                    </div>
+                  ))}
+                </div>
+              )}
+
               {!serverPropertiesExists && (
                 <div className="flex flex-col gap-3">
                   {!bypassServerPropertiesCheck && (
@@ -0,0 +1,171 @@
+import { useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { ArrowUp, FileArchive, Folder, Plug, ShieldCheck } from 'lucide-react'
+import { Select } from '../components/Select'
+import { cn } from '../utils'
+import { RemoteConnection, RemoteEntry, RemoteImportSelection } from './types'
+
+interface RemoteListing {
+  path: string;
+  entries: RemoteEntry[];
+}
+
+interface RemoteSourceProps {
+  selection: RemoteImportSelection | null;
+  setSelection: (selection: RemoteImportSelection | null) => void;
+}
+
+const emptyConnection: RemoteConnection = {
+  protocol: 'sftp',
+  host: '',
+  port: null,
+  username: '',
+  password: null,
+  private_key_path: null,
+  host_key_fingerprint: null,
+}
+
+const parentOf = (path: string) => path.replace(/\/[^/]+\/?$/, '') || '/'
+
+export function RemoteSource({ selection, setSelection }: RemoteSourceProps) {
+  const [connection, setConnection] = useState<RemoteConnection>(selection?.source ?? emptyConnection)
+  const [listing, setListing] = useState<RemoteListing | null>(null)
+  const [loading, setLoading] = useState(false)
+  const [error, setError] = useState<string | null>(null)
+  // Host key shown to the user before anything, credentials included, is sent
+  const [pendingKey, setPendingKey] = useState<string | null>(null)
+
+  const browse = async (path: string, source = connection) => {
+    setLoading(true)
+    setError(null)
+    try {
+      if (source.protocol === 'sftp' && !source.host_key_fingerprint) {
+        setPendingKey(await invoke<string>('fetch_remote_host_key', { source }))
+        return
+      }
+      const result = await invoke<RemoteListing>('list_remote_directory', { source, path })
+      setListing(result)
+      if (selection && selection.root !== result.path) setSelection(null)
+    } catch (e) {
+      setError(String(e))
+    } finally {
+      setLoading(false)
+    }
+  }
+
+  const trustHostKey = () => {
+    if (!pendingKey) return
+    const pinned = { ...connection, host_key_fingerprint: pendingKey }
+    setConnection(pinned)
+    setPendingKey(null)
+    browse('/', pinned)
+  }
+
+  const update = (patch: Partial<RemoteConnection>) => {
+    setConnection({ ...connection, ...patch, host_key_fingerprint: null })
+    setPendingKey(null)
+    setListing(null)
+    setSelection(null)
+  }
+
+  const jars = listing?.entries.filter(e => !e.is_dir && e.name.toLowerCase().endsWith('.jar')) ?? []
+  const useFolder = () => {
+    if (!listing) return
+    setSelection({ source: connection, root: listing.path, jar: jars.length === 1 ? jars[0].name : null, serverType: 'vanilla' })
+  }
+
+  const inputClass = 'w-full px-3 py-2 bg-black/5 dark:bg-white/[0.02] border border-black/5 dark:border-white/5 rounded-xl text-sm focus:outline-none focus:border-primary/50'
+
+  return (
+    <div className="flex-1 overflow-y-auto p-8 space-y-6">
+      <h2 className="text-sm font-black text-gray-500 dark:text-white/40 uppercase tracking-[0.2em]">Remote Server</h2>
+      <div className="grid grid-cols-6 gap-3">
+        <Select
+          value={connection.protocol}
+          onChange={v => update({ protocol: v as RemoteConnection['protocol'] })}
+          options={[{ value: 'sftp', label: 'SFTP' }, { value: 'ftp', label: 'FTP' }]}
+          className="col-span-1"
+        />
+        <input className={cn(inputClass, 'col-span-4')} placeholder="Host" value={connection.host} onChange={e => update({ host: e.target.value })} />
+        <input className={inputClass} placeholder={connection.protocol === 'sftp' ? '22' : '21'} value={connection.port ?? ''} onChange={e => update({ port: e.target.value ? Number(e.target.value) : null })} />
+        <input className={cn(inputClass, 'col-span-2')} placeholder="Username" value={connection.username} onChange={e => update({ username: e.target.value })} />
+        <input className={cn(inputClass, 'col-span-2')} type="password" placeholder={connection.private_key_path ? 'Key passphrase' : 'Password'} value={connection.password ?? ''} onChange={e => update({ password: e.target.value || null })} />
+        {connection.protocol === 'sftp' && (
+          <input className={cn(inputClass, 'col-span-2')} placeholder="Private key path (optional)" value={connection.private_key_path ?? ''} onChange={e => update({ private_key_path: e.target.value || null })} />
+        )}
+      </div>
+      <button
+        onClick={() => browse(listing?.path ?? '/')}
+        disabled={loading || !connection.host || !connection.username}
+        className="flex items-center gap-2 px-4 py-2 bg-primary text-white rounded-xl text-sm font-bold disabled:opacity-50"
+      >
+        <Plug size={14} /> {loading ? 'Connecting...' : 'Connect'}
+      </button>
+      {error && <p className="text-sm text-red-500">{error}</p>}
+
+      {pendingKey && (
+        <div className="space-y-3 p-4 rounded-2xl bg-amber-500/5 border border-amber-500/20 text-sm">
+          <p>Check that this host key matches the one your hosting provider shows before logging in:</p>
+          <div className="flex items-center gap-2 font-mono text-xs break-all"><ShieldCheck size={14} /> {pendingKey}</div>
+          <div className="flex gap-2">
+            <button onClick={trustHostKey} className="px-3 py-2 rounded-xl bg-primary text-white text-sm font-bold">Trust and connect</button>
+            <button onClick={() => setPendingKey(null)} className="px-3 py-2 rounded-xl bg-black/5 dark:bg-white/5 text-sm font-bold">Cancel</button>
+          </div>
+        </div>
+      )}
+
+      {listing && (
+        <div className="space-y-3">
+          {connection.host_key_fingerprint && (
+            <div className="flex items-center gap-2 text-[10px] font-mono text-gray-500 dark:text-white/30">
+              <ShieldCheck size={12} /> {connection.host_key_fingerprint}
+            </div>
+          )}
+          <div className="flex items-center gap-2">
+            <button onClick={() => browse(parentOf(listing.path))} disabled={listing.path === '/'} className="p-2 rounded-lg bg-black/5 dark:bg-white/5 disabled:opacity-30"><ArrowUp size={14} /></button>
+            <span className="flex-1 font-mono text-sm truncate">{listing.path}</span>
+            <button onClick={useFolder} className="px-3 py-2 rounded-xl bg-black/5 dark:bg-white/5 text-sm font-bold hover:text-primary">Use this folder</button>
+          </div>
+          <div className="max-h-64 overflow-y-auto rounded-2xl border border-black/5 dark:border-white/5 divide-y divide-black/5 dark:divide-white/5">
+            {listing.entries.map(entry => (
+              <button
+                key={entry.path}
+                onClick={() => entry.is_dir && browse(entry.path)}
+                className={cn('w-full flex items-center gap-3 px-4 py-2 text-sm text-left', entry.is_dir ? 'hover:bg-black/5 dark:hover:bg-white/5' : 'cursor-default opacity-60')}
+              >
+                {entry.is_dir ? <Folder size={14} className="text-primary" /> : <FileArchive size={14} />}
+                <span className="truncate">{entry.name}</span>
+              </button>
+            ))}
+          </div>
+        </div>
+      )}
+
+      {selection && (
+        <div className="grid grid-cols-2 gap-4 p-4 rounded-2xl bg-primary/5 border border-primary/20">
+          <div className="col-span-2 text-sm">Server root: <span className="font-mono">{selection.root}</span></div>
+          <Select
+            value={selection.jar ?? ''}
+            onChange={jar => setSelection({ ...selection, jar })}
+            options={jars.map(j => ({ value: j.name, label: j.name }))}
+            placeholder="Select server JAR..."
+          />
+          <Select
+            value={selection.serverType}
+            onChange={serverType => setSelection({ ...selection, serverType })}
+            options={[
+              { value: 'vanilla', label: 'Vanilla' },
+              { value: 'paper', label: 'Paper/Spigot/Bukkit' },
+              { value: 'forge', label: 'Forge' },
+              { value: 'fabric', label: 'Fabric' },
+              { value: 'quilt', label: 'Quilt' },
+              { value: 'velocity', label: 'Velocity (Proxy)' },
+              { value: 'bungeecord', label: 'BungeeCord (Proxy)' },
+              { value: 'custom', label: 'Custom/Other' },
+            ]}
+          />
+        </div>
+      )}
+    </div>
+  )
+}
@@ -1,4 +1,4 @@
-import { Globe, HardDrive, Package } from 'lucide-react'
+import { Globe, HardDrive, LayoutTemplate, Package, Server } from 'lucide-react'
 import { SidebarItem } from './SidebarItem'
 import { Tab } from './types'
 
@@ -23,6 +23,18 @@ export function Sidebar({ activeTab, setActiveTab }: SidebarProps) {
         active={activeTab === 'import'}
         onClick={() => setActiveTab('import')}
       />
+      <SidebarItem
+        icon={<Server size={18} />}
+        label="Import from SFTP/FTP"
+        active={activeTab === 'remote'}
+        onClick={() => setActiveTab('remote')}
+      />
+      <SidebarItem
+        icon={<LayoutTemplate size={18} />}
+        label="From Template"
+        active={activeTab === 'template'}
+        onClick={() => setActiveTab('template')}
+      />
       <div className="my-3 border-t border-black/5 dark:border-white/5" />
       <div className="px-3 py-1.5 text-[9px] font-black uppercase tracking-[0.2em] text-gray-500 dark:text-white/30">Modpacks</div>
       <SidebarItem
@@ -0,0 +1,85 @@
+import { useEffect, useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { LayoutTemplate, Trash2 } from 'lucide-react'
+import { InstanceTemplate } from '../types'
+import { cn } from '../utils'
+import { useToast } from '../hooks/useToast'
+
+interface TemplateSourceProps {
+  selectedTemplate: number | null;
+  setSelectedTemplate: (id: number | null) => void;
+}
+
+export function TemplateSource({ selectedTemplate, setSelectedTemplate }: TemplateSourceProps) {
+  const [templates, setTemplates] = useState<InstanceTemplate[]>([])
+  const [loading, setLoading] = useState(true)
+  const { showToast } = useToast()
+
+  useEffect(() => {
+    invoke<InstanceTemplate[]>('list_templates')
+      .then(setTemplates)
+      .catch(err => console.error('Failed to load templates:', err))
+      .finally(() => setLoading(false))
+  }, [])
+
+  const remove = async (template: InstanceTemplate) => {
+    try {
+      await invoke('delete_template', { templateId: template.id })
+      setTemplates(templates.filter(t => t.id !== template.id))
+      if (selectedTemplate === template.id) setSelectedTemplate(null)
+    } catch (err) {
+      showToast(`Failed to delete template: ${err}`, 'error')
+    }
+  }
+
+  if (!loading && templates.length === 0) {
+    return (
+      <div className="flex-1 flex flex-col items-center justify-center gap-4 p-12 text-center text-gray-400 dark:text-white/30">
+        <LayoutTemplate size={48} strokeWidth={1} />
+        <p className="max-w-xs text-sm font-medium leading-relaxed">
+          No templates yet. Use "Save as Template" in an instance's settings menu to create one.
+        </p>
+      </div>
+    )
+  }
+
+  return (
+    <div className="flex-1 overflow-y-auto p-6 space-y-2">
+      {templates.map(template => (
+        <div
+          key={template.id}
+          onClick={() => setSelectedTemplate(template.id)}
+          className={cn(
+            "flex items-center gap-4 p-4 rounded-2xl border cursor-pointer transition-all",
+            selectedTemplate === template.id
+              ? "bg-primary/10 border-primary/30"
+              : "bg-black/5 dark:bg-white/[0.02] border-black/5 dark:border-white/5 hover:border-primary/20"
+          )}
+        >
+          <LayoutTemplate size={20} className="text-primary shrink-0" />
+          <div className="flex-1 min-w-0">
+            <div className="font-bold text-gray-900 dark:text-white truncate">{template.name}</div>
+            <div className="text-xs text-gray-500 dark:text-white/40 truncate">
+              {template.mod_loader ?? 'vanilla'} {template.version}
+              {' · '}{template.content.length} mod(s)/plugin(s)
+              {' · '}{template.config_files.length} config file(s)
+              {template.description ? ` · ${template.description}` : ''}
+            </div>
+            {template.skipped.length > 0 && (
+              <div className="text-[10px] text-accent-amber mt-1 truncate" title={template.skipped.join(', ')}>
+                Not included (no known source): {template.skipped.length} file(s)
+              </div>
+            )}
+          </div>
+          <button
+            onClick={e => { e.stopPropagation(); remove(template) }}
+            className="p-2 rounded-lg text-gray-400 dark:text-white/30 hover:text-accent-rose hover:bg-accent-rose/10 transition-colors"
+            title="Delete template"
+          >
+            <Trash2 size={16} />
+          </button>
+        </div>
+      ))}
+    </div>
+  )
+}
@@ -19,6 +19,7 @@ interface VersionSelectionProps {
   selectedLoaderVersion: string | null;
   setSelectedLoaderVersion: (v: string) => void;
   modLoaders: ModLoader[];
+  experimentalBuilds?: string[];
   loadingModLoaders: boolean;
 }
 
@@ -36,6 +37,7 @@ export function VersionSelection({
   selectedLoaderVersion,
   setSelectedLoaderVersion,
   modLoaders,
+  experimentalBuilds = [],
   loadingModLoaders
 }: VersionSelectionProps) {
   const selectedServer = SERVER_TYPES.find(t => t.id === selectedServerType);
@@ -163,7 +165,7 @@ export function VersionSelection({
       </div>
 
       {/* Mod Loader Selection */}
-      {['forge', 'fabric', 'quilt', 'neoforge', 'paper', 'purpur'].includes(selectedServerType || '') && (
+      {['forge', 'fabric', 'quilt', 'neoforge', 'paper', 'purpur', 'pufferfish', 'folia'].includes(selectedServerType || '') && (
         <motion.div
           initial={{ y: 20, opacity: 0 }}
           animate={{ y: 0, opacity: 1 }}
@@ -178,6 +180,11 @@ export function VersionSelection({
               <div className="text-[13px] font-bold text-gray-600 dark:text-white/70">
                 Select {selectedServer?.name} version
               </div>
+              {selectedLoaderVersion && experimentalBuilds.includes(selectedLoaderVersion) && (
+                <div className="text-[11px] font-semibold text-amber-500 mt-0.5">
+                  Experimental build; it may be unstable and is not recommended for production.
+                </div>
+              )}
             </div>
           </div>
           <div className="flex items-center gap-3 min-w-[200px]">
@@ -187,7 +194,7 @@ export function VersionSelection({
               options={
                 modLoaders.find(l => l.name.toLowerCase() === (selectedServerType?.toLowerCase()))?.versions.map(v => ({
                   value: v,
-                  label: v
+                  label: experimentalBuilds.includes(v) ? `${v} (experimental)` : v
                 })) || []
               }
               placeholder="Select version"
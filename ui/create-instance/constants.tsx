@@ -38,6 +38,34 @@ export const SERVER_TYPES: ServerType[] = [
     icon: <Flame className="text-emerald-400" size={24} />,
     imageUrl: purpurIcon,
   },
+  {
+    id: 'pufferfish',
+    name: 'Pufferfish',
+    description: 'A Paper fork tuned for large servers, with further performance optimizations.',
+    category: 'Plugins',
+    icon: <Zap className="text-emerald-400" size={24} />,
+  },
+  {
+    id: 'folia',
+    name: 'Folia',
+    description: 'Paper with regionized multithreading for very large player counts. Needs Folia-compatible plugins.',
+    category: 'Plugins',
+    icon: <Share2 className="text-emerald-400" size={24} />,
+  },
+  {
+    id: 'spigot',
+    name: 'Spigot',
+    description: 'The original high-performance Bukkit fork. Built locally with BuildTools on first install.',
+    category: 'Plugins',
+    icon: <Settings className="text-emerald-400" size={24} />,
+  },
+  {
+    id: 'craftbukkit',
+    name: 'CraftBukkit',
+    description: 'The reference Bukkit plugin server. Built locally with BuildTools on first install.',
+    category: 'Plugins',
+    icon: <Database className="text-emerald-400" size={24} />,
+  },
   {
     id: 'forge',
     name: 'Forge',
@@ -31,6 +31,16 @@ export interface ModLoader {
   versions: string[];
 }
 
+/** A server jar held by the artifact store, installable without a network. */
+export interface CachedServer {
+  server_type: string | null;
+  mc_version: string;
+  loader_version: string | null;
+  sha1: string;
+  size: number;
+  added_at: string;
+}
+
 export interface ZipEntry {
   name: string;
   path: string;
@@ -43,4 +53,28 @@ export interface CreateInstanceModalProps {
   onCreated: (instance: Instance) => void;
 }
 
-export type Tab = 'custom' | 'import' | 'modrinth' | 'curseforge';
+export type Tab = 'custom' | 'import' | 'remote' | 'template' | 'modrinth' | 'curseforge';
+
+export interface RemoteConnection {
+  protocol: 'sftp' | 'ftp';
+  host: string;
+  port: number | null;
+  username: string;
+  password: string | null;
+  private_key_path: string | null;
+  host_key_fingerprint: string | null;
+}
+
+export interface RemoteEntry {
+  name: string;
+  path: string;
+  is_dir: boolean;
+  size: number;
+}
+
+export interface RemoteImportSelection {
+  source: RemoteConnection;
+  root: string;
+  jar: string | null;
+  serverType: string;
+}
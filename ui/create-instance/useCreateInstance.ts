@@ -1,8 +1,9 @@
 import { useState, useEffect, useMemo } from 'react'
 import { invoke } from '@tauri-apps/api/core'
 import { listen } from '@tauri-apps/api/event'
-import { Instance, Project, ProjectVersion, ModpackProgress } from '../types'
-import { VersionManifest, ModLoader, Tab } from './types'
+import { Instance, Project, ProjectVersion, ModpackProgress, PaperBuild, TemplateReport, TaskInfo, ImportPreflight } from '../types'
+import { VersionManifest, ModLoader, Tab, RemoteImportSelection, CachedServer } from './types'
+import { AppSettings } from '../hooks/useAppSettings'
 import { useToast } from '../hooks/useToast'
 import { useDebounce } from '../hooks/useDebounce'
 
@@ -20,10 +21,13 @@ export function useCreateInstance(isOpen: boolean, onCreated: (instance: Instanc
   const [modLoaders, setModLoaders] = useState<ModLoader[]>([]);
   const [selectedLoader, setSelectedLoader] = useState<string>('none');
   const [selectedLoaderVersion, setSelectedLoaderVersion] = useState<string | null>(null);
+  const [experimentalBuilds, setExperimentalBuilds] = useState<string[]>([]);
   const [creating, setCreating] = useState(false);
   const [error, setError] = useState<string | null>(null);
   const [nameExists, setNameExists] = useState(false);
   const [startAfterCreation, setStartAfterCreation] = useState(false);
+  // Set while versions come from the artifact store instead of the remote APIs
+  const [offlineServers, setOfflineServers] = useState<CachedServer[] | null>(null);
 
   const [importSourcePath, setImportSourcePath] = useState<string | null>(null);
   const [importServerType, setImportServerType] = useState<string>('vanilla');
@@ -35,6 +39,9 @@ export function useCreateInstance(isOpen: boolean, onCreated: (instance: Instanc
   const [bypassServerPropertiesCheck, setBypassServerPropertiesCheck] = useState<boolean>(false);
   const [rootWithinZip, setRootWithinZip] = useState<string | null>(null);
   const [importProgress, setImportProgress] = useState<{ current: number, total: number, message: string } | null>(null);
+  const [importPreflight, setImportPreflight] = useState<ImportPreflight | null>(null);
+  // Register the folder where it is instead of copying it
+  const [linkInPlace, setLinkInPlace] = useState(false);
 
   const [modpackResults, setModpackResults] = useState<Project[]>([]);
   const [searchingModpacks, setSearchingModpacks] = useState(false);
@@ -43,6 +50,9 @@ export function useCreateInstance(isOpen: boolean, onCreated: (instance: Instanc
   const [selectedModpackVersion, setSelectedModpackVersion] = useState<string | null>(null);
   const [loadingModpackVersions, setLoadingModpackVersions] = useState(false);
   const [modpackProgress, setModpackProgress] = useState<ModpackProgress | null>(null);
+  const [selectedTemplate, setSelectedTemplate] = useState<number | null>(null);
+  const [remoteImport, setRemoteImport] = useState<RemoteImportSelection | null>(null);
+  const [importTaskId, setImportTaskId] = useState<string | null>(null);
 
   const resetForm = () => {
     setActiveTab('custom');
@@ -64,33 +74,40 @@ export function useCreateInstance(isOpen: boolean, onCreated: (instance: Instanc
     setBypassServerPropertiesCheck(false);
     setRootWithinZip(null);
     setImportProgress(null);
+    setImportPreflight(null);
+    setLinkInPlace(false);
     setModpackProgress(null);
     setError(null);
     setModpackResults([]);
     setSelectedModpack(null);
     setModpackVersions([]);
     setSelectedModpackVersion(null);
+    setSelectedTemplate(null);
+    setRemoteImport(null);
   };
 
   useEffect(() => {
-    let unlistenImport: any;
-    let unlistenModpack: any;
+    let unlistenTasks: any;
     
     const setupListeners = async () => {
-      unlistenImport = await listen<{ current: number, total: number, message: string }>('import-progress', (event) => {
-        setImportProgress(event.payload);
-      });
-
-      unlistenModpack = await listen<ModpackProgress>('modpack-installation-progress', (event) => {
-        setModpackProgress(event.payload);
+      unlistenTasks = await listen<TaskInfo>('task-progress', (event) => {
+        const task = event.payload;
+        if (task.state !== 'running') return;
+        if (task.kind === 'import') {
+          setImportProgress({ current: task.current, total: task.total, message: task.message });
+        } else if (task.kind === 'modpack') {
+          setModpackProgress({
+            currentStep: task.message,
+            progress: task.total > 0 ? task.current / task.total : 0,
+          });
+        }
       });
     };
 
     setupListeners();
 
     return () => {
-      if (unlistenImport) unlistenImport();
-      if (unlistenModpack) unlistenModpack();
+      if (unlistenTasks) unlistenTasks();
     };
   }, []);
 
@@ -144,6 +161,34 @@ export function useCreateInstance(isOpen: boolean, onCreated: (instance: Instanc
     return () => clearTimeout(timer);
   }, [name]);
 
+  useEffect(() => {
+    if (!importSourcePath) {
+      setImportPreflight(null);
+      return;
+    }
+
+    const timer = setTimeout(async () => {
+      try {
+        const preflight = await invoke<ImportPreflight>('preflight_import', {
+          name,
+          sourcePath: importSourcePath,
+          rootWithinZip,
+          link: linkInPlace,
+        });
+        setImportPreflight(preflight);
+        // A server zipped inside a folder extracts from that folder unless another is picked
+        if (preflight.root_detected && !rootWithinZip) {
+          setRootWithinZip(preflight.root_within_zip);
+        }
+      } catch (e) {
+        console.error('Failed to check import', e);
+        setImportPreflight(null);
+      }
+    }, 300);
+
+    return () => clearTimeout(timer);
+  }, [name, importSourcePath, rootWithinZip, linkInPlace]);
+
   async function loadVersions() {
     if (!selectedServerType) {
       setLoading(false);
@@ -153,6 +198,12 @@ export function useCreateInstance(isOpen: boolean, onCreated: (instance: Instanc
     try {
       setLoading(true);
       setError(null);
+      setOfflineServers(null);
+      const settings = await invoke<AppSettings>('get_app_settings');
+      if (settings.offline_mode) {
+        await loadOfflineVersions();
+        return;
+      }
       if (selectedServerType === 'bedrock') {
         const manifest = await invoke<VersionManifest>('get_bedrock_versions');
         setManifest(manifest);
@@ -186,19 +237,48 @@ export function useCreateInstance(isOpen: boolean, onCreated: (instance: Instanc
       }
     } catch (e) {
       console.error('Failed to load versions', e);
-      setError('Failed to load versions. Please check your internet connection.');
+      // Without a connection, offer what can be installed from local files
+      if (!(await loadOfflineVersions().catch(() => false))) {
+        setError('Failed to load versions. Please check your internet connection.');
+      }
     } finally {
       setLoading(false);
     }
   }
 
+  /** Lists the versions of the selected server type held by the artifact store. */
+  async function loadOfflineVersions(): Promise<boolean> {
+    const serverType = selectedServerType === 'vanilla' ? null : selectedServerType;
+    const servers = (await invoke<CachedServer[]>('get_offline_servers')).filter(s => s.server_type === serverType);
+    setOfflineServers(servers);
+    const ids = [...new Set(servers.map(s => s.mc_version))];
+    setManifest({
+      latest: { release: ids[0] ?? '', snapshot: ids[0] ?? '' },
+      versions: ids.map(id => ({ id, type: 'release', url: '', releaseTime: servers.find(s => s.mc_version === id)!.added_at })),
+    });
+    if (ids.length === 0) {
+      setError('No versions of this server type are stored locally, so it can\'t be installed offline.');
+    }
+    return ids.length > 0;
+  }
+
   async function loadModLoaders(version: string) {
-    const isModded = ['forge', 'fabric', 'quilt', 'neoforge', 'paper', 'purpur', 'velocity', 'bungeecord'].includes(selectedServerType || '');
+    const isModded = ['forge', 'fabric', 'quilt', 'neoforge', 'paper', 'purpur', 'pufferfish', 'folia', 'spigot', 'craftbukkit', 'velocity', 'bungeecord'].includes(selectedServerType || '');
     if (!isModded) {
       setModLoaders([]);
       return;
     }
 
+    if (offlineServers) {
+      const builds = offlineServers
+        .filter(s => s.mc_version === version && s.loader_version)
+        .map(s => s.loader_version!);
+      setModLoaders([{ name: selectedServerType || '', versions: builds }]);
+      setSelectedLoaderVersion(builds[0] ?? null);
+      setExperimentalBuilds([]);
+      return;
+    }
+
     try {
       setLoadingModLoaders(true);
       const loaders = await invoke<ModLoader[]>('get_mod_loaders', { 
@@ -210,6 +290,16 @@ export function useCreateInstance(isOpen: boolean, onCreated: (instance: Instanc
       if (currentLoader && currentLoader.versions.length > 0) {
         setSelectedLoaderVersion(currentLoader.versions[0]);
       }
+
+      if (selectedServerType === 'paper') {
+        const builds = await invoke<PaperBuild[]>('get_paper_builds', { mcVersion: version });
+        setExperimentalBuilds(builds.filter(b => b.channel === 'experimental').map(b => b.build));
+        // Default to the newest stable build when one exists
+        const stable = builds.find(b => b.channel === 'default');
+        if (stable) setSelectedLoaderVersion(stable.build);
+      } else {
+        setExperimentalBuilds([]);
+      }
     } catch (e) {
       console.error('Failed to load mod loaders', e);
     } finally {
@@ -298,6 +388,14 @@ export function useCreateInstance(isOpen: boolean, onCreated: (instance: Instanc
       return handleImport();
     }
 
+    if (activeTab === 'template') {
+      return handleCreateFromTemplate();
+    }
+
+    if (activeTab === 'remote') {
+      return handleRemoteImport();
+    }
+
     if (activeTab === 'modrinth') {
       if (!name || !selectedModpack || !selectedModpackVersion || nameExists) return;
       
@@ -370,6 +468,31 @@ export function useCreateInstance(isOpen: boolean, onCreated: (instance: Instanc
     }
   }
 
+  async function handleCreateFromTemplate() {
+    if (!name || selectedTemplate === null || nameExists) return;
+
+    try {
+      setCreating(true);
+      setError(null);
+      const report = await invoke<TemplateReport>('create_instance_from_template', {
+        templateId: selectedTemplate,
+        name,
+      });
+      showToast(`Created "${name}" with ${report.installed.length} mod(s)/plugin(s)`, 'success');
+      if (report.failed.length > 0) {
+        showToast(`Could not restore: ${report.failed.join(', ')}`, 'error');
+      }
+      onCreated(report.instance);
+      resetForm();
+      onClose();
+    } catch (e) {
+      console.error('Failed to create instance from template', e);
+      setError(e instanceof Error ? e.message : String(e));
+    } finally {
+      setCreating(false);
+    }
+  }
+
   async function handleImport() {
     console.log('handleImport called', { name, importSourcePath, selectedJar });
     if (!name || !importSourcePath || !selectedJar || nameExists) {
@@ -377,9 +500,11 @@ export function useCreateInstance(isOpen: boolean, onCreated: (instance: Instanc
       return;
     }
 
+    const taskId = crypto.randomUUID();
     try {
       setCreating(true);
       setError(null);
+      setImportTaskId(taskId);
 
       const instance = await invoke<Instance>('import_instance', {
         name,
@@ -388,6 +513,8 @@ export function useCreateInstance(isOpen: boolean, onCreated: (instance: Instanc
         serverType: importServerType,
         rootWithinZip,
         scriptPath: selectedScript,
+        taskId,
+        link: linkInPlace,
       });
       showToast(`Successfully imported instance "${name}"`, 'success');
       onCreated(instance);
@@ -396,6 +523,44 @@ export function useCreateInstance(isOpen: boolean, onCreated: (instance: Instanc
     } catch (e) {
       console.error('Failed to import instance', e);
       setError(e instanceof Error ? e.message : String(e));
+    } finally {
+      setCreating(false);
+      setImportTaskId(null);
+      setImportProgress(null);
+    }
+  }
+
+  async function cancelImport() {
+    if (!importTaskId) return;
+    try {
+      await invoke('cancel_task', { taskId: importTaskId });
+    } catch (e) {
+      console.error('Failed to cancel import', e);
+    }
+  }
+
+  async function handleRemoteImport() {
+    if (!name || !remoteImport?.jar || nameExists) return;
+
+    try {
+      setCreating(true);
+      setError(null);
+      setImportProgress(null);
+      const instance = await invoke<Instance>('import_remote_instance', {
+        name,
+        source: remoteImport.source,
+        remoteRoot: remoteImport.root,
+        jarName: remoteImport.jar,
+        serverType: remoteImport.serverType,
+        scriptPath: null,
+      });
+      showToast(`Successfully imported instance "${name}"`, 'success');
+      onCreated(instance);
+      resetForm();
+      onClose();
+    } catch (e) {
+      console.error('Failed to import remote instance', e);
+      setError(e instanceof Error ? e.message : String(e));
     } finally {
       setCreating(false);
     }
@@ -417,6 +582,7 @@ export function useCreateInstance(isOpen: boolean, onCreated: (instance: Instanc
     selectedVersion,
     setSelectedVersion,
     modLoaders,
+    experimentalBuilds,
     selectedLoaderVersion,
     setSelectedLoaderVersion,
     startAfterCreation,
@@ -445,7 +611,12 @@ export function useCreateInstance(isOpen: boolean, onCreated: (instance: Instanc
     setBypassServerPropertiesCheck,
     rootWithinZip,
     setRootWithinZip,
+    importPreflight,
+    linkInPlace,
+    setLinkInPlace,
     importProgress,
+    importTaskId,
+    cancelImport,
     nameExists,
     modpackResults,
     searchingModpacks,
@@ -455,6 +626,11 @@ export function useCreateInstance(isOpen: boolean, onCreated: (instance: Instanc
     selectedModpackVersion,
     setSelectedModpackVersion,
     loadingModpackVersions,
-    modpackProgress
+    modpackProgress,
+    selectedTemplate,
+    setSelectedTemplate,
+    remoteImport,
+    setRemoteImport,
+    offlineServers
   };
 }
@@ -8,12 +8,19 @@ import {
   ChevronRight,
   ChevronDown,
   X,
-  Download
+  Download,
+  Trash2,
+  Terminal,
+  Lock,
+  Code
 } from 'lucide-react'
 import { motion, AnimatePresence } from 'framer-motion'
 import { createPortal } from 'react-dom'
 import { useAppSettings } from '../hooks/useAppSettings'
 import { useToast } from '../hooks/useToast'
+import { ConfirmDropdown } from '../components/ConfirmDropdown'
+import { QueryConsole } from './QueryConsole'
+import { errorCode, formatError } from '../utils'
 
 interface DatabaseExplorerModalProps {
   instanceId: string;
@@ -23,6 +30,7 @@ interface DatabaseExplorerModalProps {
 interface TableData {
   columns: string[];
   rows: any[][];
+  row_ids?: number[];
 }
 
 interface DBFile {
@@ -51,12 +59,98 @@ export function DatabaseExplorerModal({ instanceId, onClose }: DatabaseExplorerM
   const [searchQuery, setSearchQuery] = useState('')
   const [sqlContent, setSqlContent] = useState<string | null>(null)
   const [page, setPage] = useState(0)
+  const [serverStatus, setServerStatus] = useState<string | null>(null)
+  const [editing, setEditing] = useState<{ row: number; col: number; value: string } | null>(null)
+  const [showStatement, setShowStatement] = useState(false)
+  const [statementSql, setStatementSql] = useState('')
+  const [statementParams, setStatementParams] = useState('[]')
+  const [queryDb, setQueryDb] = useState<string | null>(null)
   const pageSize = 50
 
   useEffect(() => {
     loadDatabases()
+    invoke<string>('get_server_status', { instanceId })
+      .then(setServerStatus)
+      .catch(err => console.error('Failed to load server status:', err))
   }, [])
 
+  const selectedDbType = groups.flatMap(g => g.files).find(f => f.path === selectedDb)?.dbType
+  const serverStopped = serverStatus === 'Stopped' || serverStatus === 'Crashed'
+  // Rows are addressed by rowid, which only SQLite tables have
+  const editable = selectedDbType === 'SQLite' && serverStopped && (tableData?.row_ids?.length ?? 0) > 0
+
+  const reloadPage = async () => {
+    if (!selectedDb || !selectedTable) return
+    const data = await invoke<TableData>('explore_get_data', {
+      path: selectedDb,
+      table: selectedTable,
+      limit: pageSize,
+      offset: page * pageSize
+    })
+    setTableData(data)
+  }
+
+  const handleWriteError = (action: string, err: unknown) => {
+    if (errorCode(err) === 'conflict') {
+      // The server was started since the explorer opened; drop back to read-only
+      invoke<string>('get_server_status', { instanceId }).then(setServerStatus).catch(() => {})
+    }
+    showToast(`${action}: ${formatError(err)}`, 'error')
+  }
+
+  const saveCell = async (rowIndex: number, colIndex: number, input: string) => {
+    setEditing(null)
+    if (!tableData?.row_ids || !selectedDb || !selectedTable) return
+    const original = tableData.rows[rowIndex][colIndex]
+    if (input === (original === null ? '' : original.toString())) return
+    // Keep numbers numeric so SQLite stores them with the column's affinity
+    const value = typeof original === 'number' && input.trim() !== '' && !isNaN(Number(input)) ? Number(input) : input
+    try {
+      await invoke<number>('explore_update_row', {
+        instanceId,
+        path: selectedDb,
+        update: { table: selectedTable, rowid: tableData.row_ids[rowIndex], changes: { [tableData.columns[colIndex]]: value } }
+      })
+      await reloadPage()
+    } catch (err) {
+      handleWriteError('Failed to update row', err)
+    }
+  }
+
+  const deleteRow = async (rowIndex: number) => {
+    if (!tableData?.row_ids || !selectedDb || !selectedTable) return
+    try {
+      await invoke<number>('explore_delete_row', {
+        instanceId,
+        path: selectedDb,
+        table: selectedTable,
+        rowid: tableData.row_ids[rowIndex]
+      })
+      await reloadPage()
+    } catch (err) {
+      handleWriteError('Failed to delete row', err)
+    }
+  }
+
+  const runStatement = async () => {
+    if (!selectedDb || !statementSql.trim()) return
+    let params: unknown[]
+    try {
+      params = JSON.parse(statementParams || '[]')
+      if (!Array.isArray(params)) throw new Error()
+    } catch {
+      showToast('Parameters must be a JSON array, e.g. ["Notch", 42]', 'error')
+      return
+    }
+    try {
+      const changed = await invoke<number>('explore_execute', { instanceId, path: selectedDb, sql: statementSql, params })
+      showToast(`Statement ran, ${changed} row${changed === 1 ? '' : 's'} changed`, 'success')
+      await reloadPage()
+    } catch (err) {
+      handleWriteError('Statement failed', err)
+    }
+  }
+
   const loadDatabases = async () => {
     setLoading(true)
     setSqlContent(null)
@@ -93,12 +187,11 @@ export function DatabaseExplorerModal({ instanceId, onClose }: DatabaseExplorerM
     const group = groups[groupIndex]
     const db = group.files[fileIndex]
 
-    if (db.dbType === 'H2') {
-      showToast('H2 databases (.mv.db) are currently only supported for identification. Inspection requires a Java driver.', 'info')
-      return
-    }
-
     if (!db.isOpen && db.tables.length === 0) {
+      if (db.dbType === 'H2') {
+        // The first read exports the database with Java, which can take a moment
+        showToast('Exporting H2 database...', 'info')
+      }
       // Don't clear sqlContent here yet, we might want to keep it if it's already selected
       try {
         const tables = await invoke<string[]>('explore_list_tables', { path: db.path })
@@ -117,7 +210,16 @@ export function DatabaseExplorerModal({ instanceId, onClose }: DatabaseExplorerM
     }
   }
 
+  const openQueryConsole = (dbPath: string) => {
+    setSelectedDb(dbPath)
+    setSelectedTable(null)
+    setTableData(null)
+    setSqlContent(null)
+    setQueryDb(dbPath)
+  }
+
   const handleSourceView = async (dbPath: string) => {
+    setQueryDb(null)
     setSelectedDb(dbPath)
     setSelectedTable(null)
     setTableData(null)
@@ -133,6 +235,7 @@ export function DatabaseExplorerModal({ instanceId, onClose }: DatabaseExplorerM
   }
 
   const loadTableData = async (dbPath: string, tableName: string) => {
+    setQueryDb(null)
     setSelectedDb(dbPath)
     setSelectedTable(tableName)
     setSqlContent(null)
@@ -286,6 +389,18 @@ export function DatabaseExplorerModal({ instanceId, onClose }: DatabaseExplorerM
                               </motion.div>
                               <span className="text-sm font-bold truncate flex-1">{db.name}</span>
                               <div className="flex items-center gap-2">
+                                {db.dbType === 'SQLite' && (
+                                  <button
+                                    onClick={(e) => {
+                                      e.stopPropagation();
+                                      openQueryConsole(db.path);
+                                    }}
+                                    className={`p-1 hover:bg-white/10 rounded transition-all ${queryDb === db.path ? 'text-primary' : 'text-gray-500 hover:text-gray-300'}`}
+                                    title="Query console"
+                                  >
+                                    <Code size={12} />
+                                  </button>
+                                )}
                                 {db.dbType === 'SQL' && (
                                   <button
                                     onClick={(e) => {
@@ -342,7 +457,9 @@ export function DatabaseExplorerModal({ instanceId, onClose }: DatabaseExplorerM
 
           {/* Main Area */}
           <div className="flex-1 flex flex-col overflow-hidden bg-[#16161a]">
-            {sqlContent ? (
+            {queryDb ? (
+              <QueryConsole instanceId={instanceId} path={queryDb} />
+            ) : sqlContent ? (
               <div className="h-full flex flex-col">
                 <div className="p-4 border-b border-white/5 flex items-center justify-between bg-[#1a1a1f]">
                   <div className="flex items-center gap-2 text-sm font-medium">
@@ -373,6 +490,21 @@ export function DatabaseExplorerModal({ instanceId, onClose }: DatabaseExplorerM
                     </div>
                   </div>
                   <div className="flex items-center gap-2">
+                    {selectedDbType === 'SQLite' && !serverStopped && (
+                      <span className="flex items-center gap-1.5 text-[11px] text-gray-500 font-bold" title="Stop the server to edit its databases">
+                        <Lock size={14} />
+                        Read-only while the server runs
+                      </span>
+                    )}
+                    {selectedDbType === 'SQLite' && serverStopped && (
+                      <button
+                        onClick={() => setShowStatement(!showStatement)}
+                        className={`p-2 rounded-xl transition-colors ${showStatement ? 'bg-primary/10 text-primary' : 'hover:bg-white/5 text-gray-400'}`}
+                        title="Run a statement"
+                      >
+                        <Terminal size={18} />
+                      </button>
+                    )}
                     <button
                       onClick={() => loadTableData(selectedDb!, selectedTable!)}
                       className="p-2 hover:bg-white/5 rounded-xl text-gray-400 transition-colors"
@@ -387,6 +519,35 @@ export function DatabaseExplorerModal({ instanceId, onClose }: DatabaseExplorerM
                   </div>
                 </div>
 
+                {showStatement && selectedDbType === 'SQLite' && serverStopped && (
+                  <div className="p-4 border-b border-white/5 bg-[#1a1a1f] space-y-2">
+                    <textarea
+                      value={statementSql}
+                      onChange={(e) => setStatementSql(e.target.value)}
+                      placeholder="UPDATE co_user SET user = ? WHERE id = ?"
+                      rows={2}
+                      className="w-full px-3 py-2 bg-[#0e0e11] border border-white/5 rounded-xl text-xs font-mono focus:outline-none focus:border-primary/50"
+                    />
+                    <div className="flex items-center gap-2">
+                      <input
+                        value={statementParams}
+                        onChange={(e) => setStatementParams(e.target.value)}
+                        placeholder='["Notch", 1]'
+                        title="Values for the ? placeholders, as a JSON array"
+                        className="flex-1 px-3 py-2 bg-[#0e0e11] border border-white/5 rounded-xl text-xs font-mono focus:outline-none focus:border-primary/50"
+                      />
+                      <button
+                        onClick={runStatement}
+                        disabled={!statementSql.trim()}
+                        className="px-4 py-2 bg-primary/10 hover:bg-primary text-primary hover:text-white disabled:opacity-30 rounded-xl text-xs font-black uppercase tracking-wider transition-all"
+                      >
+                        Run
+                      </button>
+                    </div>
+                    <p className="text-[10px] text-gray-600">The database is backed up to a .bak file before its first change this session.</p>
+                  </div>
+                )}
+
                 {/* Data Grid */}
                 <div className="flex-1 overflow-auto custom-scrollbar bg-[#16161a]">
                   {dataLoading && !tableData ? (
@@ -402,20 +563,38 @@ export function DatabaseExplorerModal({ instanceId, onClose }: DatabaseExplorerM
                               {col}
                             </th>
                           ))}
+                          {editable && <th className="bg-white/5" />}
                         </tr>
                       </thead>
                       <tbody className="divide-y divide-white/5">
                         {tableData.rows
-                          .filter(row =>
+                          .map((row, ridx) => ({ row, ridx }))
+                          .filter(({ row }) =>
                             row.some(val =>
                               val?.toString().toLowerCase().includes(searchQuery.toLowerCase())
                             )
                           )
-                          .map((row, ridx) => (
+                          .map(({ row, ridx }) => (
                             <tr key={ridx} className="hover:bg-white/[0.02] transition-colors group">
                               {row.map((val, cidx) => (
-                                <td key={cidx} className="px-4 py-3 text-xs text-gray-400 font-mono whitespace-nowrap overflow-hidden text-ellipsis max-w-[300px] group-hover:text-gray-200">
-                                  {val === null ? (
+                                <td
+                                  key={cidx}
+                                  onDoubleClick={() => editable && setEditing({ row: ridx, col: cidx, value: val === null ? '' : typeof val === 'object' ? JSON.stringify(val) : val.toString() })}
+                                  className="px-4 py-3 text-xs text-gray-400 font-mono whitespace-nowrap overflow-hidden text-ellipsis max-w-[300px] group-hover:text-gray-200"
+                                >
+                                  {editing?.row === ridx && editing.col === cidx ? (
+                                    <input
+                                      autoFocus
+                                      value={editing.value}
+                                      onChange={(e) => setEditing({ ...editing, value: e.target.value })}
+                                      onBlur={() => saveCell(ridx, cidx, editing.value)}
+                                      onKeyDown={(e) => {
+                                        if (e.key === 'Enter') saveCell(ridx, cidx, editing.value)
+                                        if (e.key === 'Escape') setEditing(null)
+                                      }}
+                                      className="w-full px-2 py-1 bg-[#0e0e11] border border-primary/50 rounded text-xs font-mono text-white focus:outline-none"
+                                    />
+                                  ) : val === null ? (
                                     <span className="text-gray-600 italic text-[10px]">NULL</span>
                                   ) : typeof val === 'object' ? (
                                     JSON.stringify(val)
@@ -424,11 +603,29 @@ export function DatabaseExplorerModal({ instanceId, onClose }: DatabaseExplorerM
                                   )}
                                 </td>
                               ))}
+                              {editable && (
+                                <td className="px-2 py-3 text-right">
+                                  <ConfirmDropdown
+                                    title="Delete Row"
+                                    message="This row will be removed from the database."
+                                    onConfirm={() => deleteRow(ridx)}
+                                    confirmText="Delete"
+                                    variant="danger"
+                                  >
+                                    <button
+                                      className="p-1 opacity-0 group-hover:opacity-100 hover:bg-red-500/20 text-red-400 rounded transition-all"
+                                      title="Delete row"
+                                    >
+                                      <Trash2 size={12} />
+                                    </button>
+                                  </ConfirmDropdown>
+                                </td>
+                              )}
                             </tr>
                           ))}
                         {tableData.rows.length === 0 && (
                           <tr>
-                            <td colSpan={tableData.columns.length} className="px-4 py-12 text-center text-gray-500 font-medium italic">
+                            <td colSpan={tableData.columns.length + (editable ? 1 : 0)} className="px-4 py-12 text-center text-gray-500 font-medium italic">
                               Table is empty
                             </td>
                           </tr>
@@ -0,0 +1,281 @@
+import { useEffect, useRef, useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { listen } from '@tauri-apps/api/event'
+import { Play, ListTree, History, Trash2, RefreshCw, Rows3 } from 'lucide-react'
+import { useToast } from '../hooks/useToast'
+
+interface QueryConsoleProps {
+  instanceId: string;
+  path: string;
+}
+
+interface QueryResult {
+  columns: string[];
+  rows: any[][];
+  has_more: boolean;
+  elapsed_ms: number;
+}
+
+interface QueryHistoryEntry {
+  query: string;
+  ran_at: string;
+}
+
+interface QueryRowsPayload {
+  query_id: string;
+  columns: string[];
+  rows: any[][];
+}
+
+const PAGE_SIZE = 100
+// Streaming stops here so a huge table doesn't exhaust the UI's memory
+const STREAM_LIMIT = 10000
+
+const errorMessage = (err: any) => typeof err === 'string' ? err : err.message || 'Unknown error'
+
+export function QueryConsole({ instanceId, path }: QueryConsoleProps) {
+  const { showToast } = useToast()
+  const [sql, setSql] = useState('')
+  const [ranSql, setRanSql] = useState('')
+  const [result, setResult] = useState<QueryResult | null>(null)
+  const [page, setPage] = useState(0)
+  const [running, setRunning] = useState(false)
+  const [streaming, setStreaming] = useState(false)
+  const [history, setHistory] = useState<QueryHistoryEntry[]>([])
+  const streamId = useRef<string | null>(null)
+
+  const loadHistory = () => {
+    invoke<QueryHistoryEntry[]>('explore_get_query_history', { instanceId, path, limit: 50 })
+      .then(setHistory)
+      .catch(err => console.error('Failed to load query history:', err))
+  }
+
+  useEffect(() => {
+    setResult(null)
+    setPage(0)
+    loadHistory()
+
+    const unlisten = listen<QueryRowsPayload>('query-rows', (event) => {
+      const { query_id, columns, rows } = event.payload
+      if (query_id !== streamId.current) return
+      setResult(prev => ({
+        columns,
+        rows: [...(prev?.rows ?? []), ...rows],
+        has_more: false,
+        elapsed_ms: prev?.elapsed_ms ?? 0
+      }))
+    })
+    return () => {
+      streamId.current = null
+      unlisten.then(f => f())
+    }
+  }, [path])
+
+  const run = async (query: string, nextPage: number) => {
+    if (!query.trim()) return
+    streamId.current = null
+    setRunning(true)
+    try {
+      const data = await invoke<QueryResult>('explore_execute_query', {
+        instanceId,
+        path,
+        sql: query,
+        limit: PAGE_SIZE,
+        offset: nextPage * PAGE_SIZE
+      })
+      setResult(data)
+      setRanSql(query)
+      setPage(nextPage)
+      if (nextPage === 0) loadHistory()
+    } catch (err: any) {
+      showToast(`Query failed: ${errorMessage(err)}`, 'error')
+    } finally {
+      setRunning(false)
+    }
+  }
+
+  const explain = async () => {
+    if (!sql.trim()) return
+    streamId.current = null
+    setRunning(true)
+    try {
+      setResult(await invoke<QueryResult>('explore_explain_query', { path, sql }))
+      setRanSql('')
+      setPage(0)
+    } catch (err: any) {
+      showToast(`Explain failed: ${errorMessage(err)}`, 'error')
+    } finally {
+      setRunning(false)
+    }
+  }
+
+  const streamAll = async () => {
+    if (!sql.trim()) return
+    const queryId = crypto.randomUUID()
+    streamId.current = queryId
+    setResult(null)
+    setRanSql('')
+    setPage(0)
+    setStreaming(true)
+    const started = performance.now()
+    try {
+      const sent = await invoke<number>('explore_stream_query', { path, sql, queryId, maxRows: STREAM_LIMIT })
+      setResult(prev => prev && { ...prev, elapsed_ms: Math.round(performance.now() - started) })
+      if (sent >= STREAM_LIMIT) showToast(`Showing the first ${STREAM_LIMIT} rows`, 'info')
+    } catch (err: any) {
+      showToast(`Query failed: ${errorMessage(err)}`, 'error')
+    } finally {
+      setStreaming(false)
+    }
+  }
+
+  const clearHistory = async () => {
+    try {
+      await invoke('explore_clear_query_history', { instanceId, path })
+      setHistory([])
+    } catch (err: any) {
+      showToast(`Failed to clear history: ${errorMessage(err)}`, 'error')
+    }
+  }
+
+  const busy = running || streaming
+
+  return (
+    <div className="h-full flex overflow-hidden">
+      <div className="flex-1 flex flex-col overflow-hidden">
+        <div className="p-4 border-b border-white/5 bg-[#1a1a1f] space-y-2">
+          <textarea
+            value={sql}
+            onChange={(e) => setSql(e.target.value)}
+            onKeyDown={(e) => {
+              if (e.key === 'Enter' && (e.ctrlKey || e.metaKey)) run(sql, 0)
+            }}
+            placeholder="SELECT user, COUNT(*) FROM co_block GROUP BY user"
+            rows={4}
+            className="w-full px-3 py-2 bg-[#0e0e11] border border-white/5 rounded-xl text-xs font-mono focus:outline-none focus:border-primary/50"
+          />
+          <div className="flex items-center gap-2">
+            <button
+              onClick={() => run(sql, 0)}
+              disabled={busy || !sql.trim()}
+              title="Run (Ctrl+Enter)"
+              className="flex items-center gap-2 px-4 py-2 bg-primary/10 hover:bg-primary text-primary hover:text-white disabled:opacity-30 rounded-xl text-xs font-black uppercase tracking-wider transition-all"
+            >
+              {running ? <RefreshCw size={14} className="animate-spin" /> : <Play size={14} />}
+              Run
+            </button>
+            <button
+              onClick={explain}
+              disabled={busy || !sql.trim()}
+              title="Show the query plan"
+              className="flex items-center gap-2 px-4 py-2 bg-white/5 hover:bg-white/10 disabled:opacity-30 text-gray-300 rounded-xl text-xs font-black uppercase tracking-wider border border-white/5 transition-all"
+            >
+              <ListTree size={14} />
+              Explain
+            </button>
+            <button
+              onClick={streamAll}
+              disabled={busy || !sql.trim()}
+              title={`Load every row, up to ${STREAM_LIMIT}`}
+              className="flex items-center gap-2 px-4 py-2 bg-white/5 hover:bg-white/10 disabled:opacity-30 text-gray-300 rounded-xl text-xs font-black uppercase tracking-wider border border-white/5 transition-all"
+            >
+              {streaming ? <RefreshCw size={14} className="animate-spin" /> : <Rows3 size={14} />}
+              Load all
+            </button>
+            {result && (
+              <span className="ml-auto text-[11px] text-gray-500 font-bold">
+                {result.rows.length} row{result.rows.length === 1 ? '' : 's'} · {result.elapsed_ms} ms
+              </span>
+            )}
+          </div>
+        </div>
+
+        <div className="flex-1 overflow-auto custom-scrollbar bg-[#16161a]">
+          {result && (
+            <table className="w-full text-left border-collapse min-w-full">
+              <thead className="sticky top-0 bg-[#1a1a1f] z-10">
+                <tr className="border-b border-white/5">
+                  {result.columns.map((col, idx) => (
+                    <th key={idx} className="px-4 py-3 text-[10px] font-black text-gray-500 uppercase tracking-widest bg-white/5 whitespace-nowrap">
+                      {col}
+                    </th>
+                  ))}
+                </tr>
+              </thead>
+              <tbody className="divide-y divide-white/5">
+                {result.rows.map((row, ridx) => (
+                  <tr key={ridx} className="hover:bg-white/[0.02] transition-colors group">
+                    {row.map((val, cidx) => (
+                      <td key={cidx} className="px-4 py-3 text-xs text-gray-400 font-mono whitespace-nowrap overflow-hidden text-ellipsis max-w-[300px] group-hover:text-gray-200">
+                        {val === null ? (
+                          <span className="text-gray-600 italic text-[10px]">NULL</span>
+                        ) : typeof val === 'object' ? JSON.stringify(val) : val.toString()}
+                      </td>
+                    ))}
+                  </tr>
+                ))}
+                {result.rows.length === 0 && (
+                  <tr>
+                    <td colSpan={Math.max(1, result.columns.length)} className="px-4 py-12 text-center text-gray-500 font-medium italic">
+                      No rows
+                    </td>
+                  </tr>
+                )}
+              </tbody>
+            </table>
+          )}
+        </div>
+
+        {ranSql && (
+          <div className="px-6 py-4 border-t border-white/5 flex items-center justify-between bg-[#1a1a1f]">
+            <span className="text-[11px] text-gray-500 font-bold">Page {page + 1}</span>
+            <div className="flex items-center gap-2">
+              <button
+                onClick={() => run(ranSql, page - 1)}
+                disabled={page === 0 || busy}
+                className="px-4 py-2 bg-white/5 hover:bg-white/10 disabled:opacity-30 text-gray-300 rounded-xl text-xs font-black uppercase tracking-wider border border-white/5 transition-all active:scale-95"
+              >
+                Previous
+              </button>
+              <button
+                onClick={() => run(ranSql, page + 1)}
+                disabled={!result?.has_more || busy}
+                className="px-4 py-2 bg-white/5 hover:bg-white/10 disabled:opacity-30 text-gray-300 rounded-xl text-xs font-black uppercase tracking-wider border border-white/5 transition-all active:scale-95"
+              >
+                Next
+              </button>
+            </div>
+          </div>
+        )}
+      </div>
+
+      <div className="w-64 border-l border-white/5 bg-[#111114] flex flex-col overflow-hidden">
+        <div className="p-4 flex items-center justify-between border-b border-white/5">
+          <span className="flex items-center gap-2 text-[11px] font-black uppercase tracking-wider text-gray-500">
+            <History size={14} />
+            History
+          </span>
+          {history.length > 0 && (
+            <button onClick={clearHistory} className="p-1 hover:bg-white/10 rounded text-gray-500 hover:text-red-400 transition-all" title="Clear history">
+              <Trash2 size={12} />
+            </button>
+          )}
+        </div>
+        <div className="flex-1 overflow-y-auto custom-scrollbar p-2 space-y-1">
+          {history.length === 0 ? (
+            <p className="text-[10px] text-gray-600 italic p-2">Queries you run show up here</p>
+          ) : history.map((entry, idx) => (
+            <button
+              key={idx}
+              onClick={() => setSql(entry.query)}
+              title={new Date(entry.ran_at).toLocaleString()}
+              className="w-full p-2 rounded-lg text-left hover:bg-white/5 text-[11px] font-mono text-gray-400 hover:text-gray-200 line-clamp-3 break-all transition-all"
+            >
+              {entry.query}
+            </button>
+          ))}
+        </div>
+      </div>
+    </div>
+  )
+}
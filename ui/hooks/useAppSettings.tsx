@@ -18,6 +18,7 @@ export const ACCENT_COLORS: AccentColor[] = [
 
 export type Theme = 'dark' | 'light';
 export type CloseBehavior = 'HideToSystemTray' | 'HideToTaskbar' | 'Exit';
+export type ExitServerPolicy = 'StopAll' | 'KeepRunning' | 'Ask';
 
 export interface ManagedJavaVersion {
   id: string;
@@ -27,6 +28,59 @@ export interface ManagedJavaVersion {
   major_version: number;
 }
 
+export interface ServerPropertiesDefaults {
+  motd: string;
+  view_distance: number;
+  simulation_distance: number;
+  max_players: number;
+  difficulty: string;
+  gamemode: string;
+  online_mode: boolean;
+  warn_offline_mode: boolean;
+}
+
+export interface DownloadSettings {
+  max_concurrent_downloads: number;
+  bandwidth_limit_kib: number;
+  preferred_mirrors: string[];
+  api_mirrors: ApiMirror[];
+}
+
+export interface ApiMirror {
+  upstream: string;
+  mirror: string;
+}
+
+export interface CacheLimits {
+  max_disk_mb: number;
+}
+
+export type ProvisionMode = 'copy' | 'hardlink' | 'reflink' | 'symlink';
+
+export interface ShellSettings {
+  enabled: boolean;
+  timeout_secs: number;
+}
+
+export type LogLevel = 'error' | 'warn' | 'info' | 'debug' | 'trace';
+
+export interface LogSettings {
+  level: LogLevel;
+  max_file_size_mb: number;
+  max_files: number;
+}
+
+export interface StartLimits {
+  max_running_instances: number;
+  ram_budget_mb: number;
+}
+
+export interface ExternalServer {
+  id: string;
+  name: string;
+  address: string;
+}
+
 export interface AppSettings {
   // Interface
   display_ipv6: boolean;
@@ -51,6 +105,7 @@ export interface AppSettings {
   // Close Preference
   close_behavior: CloseBehavior;
   show_tray_notification: boolean;
+  exit_server_policy: ExitServerPolicy;
 
   // Appearance (Existing)
   accent_color: string;
@@ -59,6 +114,39 @@ export interface AppSettings {
 
   // Java Management
   managed_java_versions: ManagedJavaVersion[];
+
+  // New Instances
+  server_properties_defaults: ServerPropertiesDefaults;
+
+  // Config Editing
+  backup_configs_on_save: boolean;
+
+  // Downloads
+  downloads: DownloadSettings;
+
+  // Resource Limits
+  start_limits: StartLimits;
+
+  // Servers not managed by the wrapper
+  external_servers: ExternalServer[];
+
+  // Instance Terminal
+  shell: ShellSettings;
+
+  // Cache
+  cache_limits: CacheLimits;
+
+  // Shared Storage
+  library_sharing: ProvisionMode;
+
+  // Offline Mode
+  offline_mode: boolean;
+
+  // Storage Locations
+  storage_roots: string[];
+
+  // Logging
+  logging: LogSettings;
 }
 
 const DEFAULT_SETTINGS: AppSettings = {
@@ -76,10 +164,48 @@ const DEFAULT_SETTINGS: AppSettings = {
   display_navigational_buttons: true,
   close_behavior: 'HideToSystemTray',
   show_tray_notification: true,
+  exit_server_policy: 'StopAll',
   accent_color: "Blue",
   theme: "dark",
   scaling: 1.0,
   managed_java_versions: [],
+  server_properties_defaults: {
+    motd: "{name}",
+    view_distance: 10,
+    simulation_distance: 10,
+    max_players: 20,
+    difficulty: "easy",
+    gamemode: "survival",
+    online_mode: true,
+    warn_offline_mode: true,
+  },
+  backup_configs_on_save: true,
+  downloads: {
+    max_concurrent_downloads: 4,
+    bandwidth_limit_kib: 0,
+    preferred_mirrors: [],
+    api_mirrors: [],
+  },
+  start_limits: {
+    max_running_instances: 0,
+    ram_budget_mb: 0,
+  },
+  external_servers: [],
+  shell: {
+    enabled: false,
+    timeout_secs: 600,
+  },
+  cache_limits: {
+    max_disk_mb: 256,
+  },
+  library_sharing: 'copy',
+  offline_mode: false,
+  storage_roots: [],
+  logging: {
+    level: 'info',
+    max_file_size_mb: 10,
+    max_files: 5,
+  },
 };
 
 interface AppSettingsContextType {
@@ -0,0 +1,27 @@
+import { useEffect, useRef } from 'react';
+import { listen } from '@tauri-apps/api/event';
+import { FileChange, FileChangeKind } from '../types';
+
+/**
+ * Calls `onChange` when files of the given kind change outside the app in the instance
+ * currently being watched.
+ * @param instanceId The instance to react to
+ * @param kind Which files to react to
+ * @param onChange Called with the changed paths
+ */
+export function useFileChanges(instanceId: string, kind: FileChangeKind, onChange: (paths: string[]) => void) {
+  const callback = useRef(onChange);
+  callback.current = onChange;
+
+  useEffect(() => {
+    if (!(window as any).__TAURI_INTERNALS__) return;
+    const unlisten = listen<FileChange>(`${kind}-changed`, (event) => {
+      if (event.payload.instance_id === instanceId) {
+        callback.current(event.payload.paths);
+      }
+    });
+    return () => {
+      unlisten.then(f => f());
+    };
+  }, [instanceId, kind]);
+}
@@ -1,7 +1,7 @@
 import { useState, useEffect, useRef } from 'react'
 import { invoke } from '@tauri-apps/api/core'
 import { listen } from '@tauri-apps/api/event'
-import { Instance, ResourceUsage, TransitionType } from '../types'
+import { ConsolePage, Instance, ResourceUsage, TransitionType } from '../types'
 
 export function useServer() {
   const [instances, setInstances] = useState<Instance[]>([])
@@ -13,6 +13,9 @@ export function useServer() {
   const [loading, setLoading] = useState(true)
   const [logs, setLogs] = useState<Record<string, string[]>>({})
   const historyRef = useRef<ResourceUsage[]>([])
+  // Number of the first live line per instance; replayed lines are only taken from before it
+  const firstLiveLineRef = useRef<Record<string, number>>({})
+  const replayedRef = useRef<Set<string>>(new Set())
 
   useEffect(() => {
     if (!(window as any).__TAURI_INTERNALS__) {
@@ -21,18 +24,59 @@ export function useServer() {
     }
     loadInstances()
 
-    const unlisten = listen<{ instance_id: string, line: string }>('server-log', (event) => {
+    const unlisten = listen<{ instance_id: string, line: string, number: number | null }>('server-log', (event) => {
+      const { instance_id, number } = event.payload
+      if (number !== null && !(instance_id in firstLiveLineRef.current)) {
+        firstLiveLineRef.current[instance_id] = number
+      }
       setLogs(prev => ({
         ...prev,
         [event.payload.instance_id]: [...(prev[event.payload.instance_id] || []), event.payload.line].slice(-500)
       }))
     })
 
+    // Pushed by start/stop/kill/restart so buttons settle without waiting for the next poll
+    const unlistenStatus = listen<{ instance_id: string, status: string }>('server-status', (event) => {
+      const { instance_id, status } = event.payload
+      setInstances(prev => prev.map(inst => inst.id === instance_id ? { ...inst, status } : inst))
+      if (status === 'Stopped' || status === 'Crashed' || status === 'Running') {
+        setIsTransitioning(prev => {
+          if (!prev[instance_id]) return prev;
+          const next = { ...prev };
+          delete next[instance_id];
+          return next;
+        })
+      }
+    })
+
     return () => {
       unlisten.then(f => f())
+      unlistenStatus.then(f => f())
     }
   }, [])
 
+  // Console lines sent before a reload are buffered by the backend; put them back in front
+  useEffect(() => {
+    if (!(window as any).__TAURI_INTERNALS__) return;
+    instances.forEach(inst => {
+      if (replayedRef.current.has(inst.id)) return
+      replayedRef.current.add(inst.id)
+      invoke<ConsolePage>('get_console_buffer', { instanceId: inst.id, fromLine: 0 })
+        .then(page => {
+          const firstLive = firstLiveLineRef.current[inst.id] ?? Infinity
+          return page.lines.filter(l => l.number < firstLive).map(l => l.raw)
+        })
+        .then(missed => {
+          if (missed.length === 0) return
+          setLogs(prev => ({
+            ...prev,
+            [inst.id]: [...missed, ...(prev[inst.id] || [])].slice(-500)
+          }))
+        })
+        .catch(e => console.error('Failed to replay console output:', e))
+    })
+  }, [instances])
+
   useEffect(() => {
     let interval: number;
     interval = window.setInterval(async () => {
@@ -176,12 +220,22 @@ export function useServer() {
     }
   }
 
+  async function killServer(instanceId?: string) {
+    const id = instanceId || selectedInstanceId;
+    if (!id || !(window as any).__TAURI_INTERNALS__) return;
+    try {
+      await invoke('kill_server', { instanceId: id })
+    } catch (e) {
+      console.error(e)
+    }
+  }
+
   async function restartServer(instanceId?: string) {
     const id = instanceId || selectedInstanceId;
     if (!id || !(window as any).__TAURI_INTERNALS__) return;
     setIsTransitioning(prev => ({ ...prev, [id]: 'restarting' }))
     try {
-      await invoke('send_command', { instanceId: id, command: 'restart' })
+      await invoke('restart_server', { instanceId: id })
     } catch (e) {
       console.error(e)
       setIsTransitioning(prev => {
@@ -234,6 +288,7 @@ export function useServer() {
     addInstance,
     startServer,
     stopServer,
+    killServer,
     restartServer,
     sendCommand
   }
@@ -32,7 +32,7 @@ export function DeleteConfirm({ instanceName, onDelete, onCancel, isDeleting }:
 
       <div className="space-y-2">
         <p className="text-xs text-gray-600 dark:text-white/60 leading-relaxed">
-          Are you sure you want to delete <strong className="text-gray-900 dark:text-white font-bold">{instanceName}</strong>? This action cannot be undone.
+          Are you sure you want to delete <strong className="text-gray-900 dark:text-white font-bold">{instanceName}</strong>? Its backups and scheduled tasks are deleted too. This action cannot be undone.
         </p>
       </div>
 
@@ -1,12 +1,16 @@
 import { motion } from 'framer-motion'
-import { Copy, Trash2 } from 'lucide-react'
+import { Copy, FolderInput, LayoutTemplate, PackageOpen, Trash2, Wrench } from 'lucide-react'
 
 interface MainActionsProps {
   onShowClone: () => void;
+  onShowTemplate: () => void;
+  onExport: () => void;
+  onShowMove?: () => void;
+  onRepair?: () => void;
   onShowDelete: () => void;
 }
 
-export function MainActions({ onShowClone, onShowDelete }: MainActionsProps) {
+export function MainActions({ onShowClone, onShowTemplate, onExport, onShowMove, onRepair, onShowDelete }: MainActionsProps) {
   return (
     <motion.div
       key="main"
@@ -30,6 +34,58 @@ export function MainActions({ onShowClone, onShowDelete }: MainActionsProps) {
           <span className="text-[10px] text-gray-400 dark:text-white/40 uppercase font-black tracking-widest">Duplicate this server</span>
         </div>
       </button>
+      <button
+        onClick={onShowTemplate}
+        className="w-full flex items-center gap-3 px-3 py-3 text-sm text-gray-700 dark:text-white/70 hover:bg-black/5 dark:hover:bg-white/[0.05] hover:text-gray-900 dark:hover:text-white rounded-xl transition-all group"
+      >
+        <div className="w-8 h-8 rounded-lg bg-black/[0.03] dark:bg-white/[0.03] flex items-center justify-center group-hover:bg-primary/20 group-hover:text-primary transition-all">
+          <LayoutTemplate size={16} />
+        </div>
+        <div className="flex flex-col items-start">
+          <span className="font-bold">Save as Template</span>
+          <span className="text-[10px] text-gray-400 dark:text-white/40 uppercase font-black tracking-widest">Reuse for new servers</span>
+        </div>
+      </button>
+      <button
+        onClick={onExport}
+        className="w-full flex items-center gap-3 px-3 py-3 text-sm text-gray-700 dark:text-white/70 hover:bg-black/5 dark:hover:bg-white/[0.05] hover:text-gray-900 dark:hover:text-white rounded-xl transition-all group"
+      >
+        <div className="w-8 h-8 rounded-lg bg-black/[0.03] dark:bg-white/[0.03] flex items-center justify-center group-hover:bg-primary/20 group-hover:text-primary transition-all">
+          <PackageOpen size={16} />
+        </div>
+        <div className="flex flex-col items-start">
+          <span className="font-bold">Export Instance</span>
+          <span className="text-[10px] text-gray-400 dark:text-white/40 uppercase font-black tracking-widest">Portable archive</span>
+        </div>
+      </button>
+      {onShowMove && (
+        <button
+          onClick={onShowMove}
+          className="w-full flex items-center gap-3 px-3 py-3 text-sm text-gray-700 dark:text-white/70 hover:bg-black/5 dark:hover:bg-white/[0.05] hover:text-gray-900 dark:hover:text-white rounded-xl transition-all group"
+        >
+          <div className="w-8 h-8 rounded-lg bg-black/[0.03] dark:bg-white/[0.03] flex items-center justify-center group-hover:bg-primary/20 group-hover:text-primary transition-all">
+            <FolderInput size={16} />
+          </div>
+          <div className="flex flex-col items-start">
+            <span className="font-bold">Move Instance</span>
+            <span className="text-[10px] text-gray-400 dark:text-white/40 uppercase font-black tracking-widest">Another storage location</span>
+          </div>
+        </button>
+      )}
+      {onRepair && (
+        <button
+          onClick={onRepair}
+          className="w-full flex items-center gap-3 px-3 py-3 text-sm text-gray-700 dark:text-white/70 hover:bg-black/5 dark:hover:bg-white/[0.05] hover:text-gray-900 dark:hover:text-white rounded-xl transition-all group"
+        >
+          <div className="w-8 h-8 rounded-lg bg-black/[0.03] dark:bg-white/[0.03] flex items-center justify-center group-hover:bg-primary/20 group-hover:text-primary transition-all">
+            <Wrench size={16} />
+          </div>
+          <div className="flex flex-col items-start">
+            <span className="font-bold">Repair Installation</span>
+            <span className="text-[10px] text-gray-400 dark:text-white/40 uppercase font-black tracking-widest">Re-run failed installer</span>
+          </div>
+        </button>
+      )}
       <button
         onClick={onShowDelete}
         className="w-full flex items-center gap-3 px-3 py-3 text-sm text-gray-700 dark:text-white/70 hover:bg-accent-rose/10 hover:text-accent-rose rounded-xl transition-all group"
@@ -0,0 +1,91 @@
+import { useEffect, useState } from 'react'
+import { motion } from 'framer-motion'
+import { invoke } from '@tauri-apps/api/core'
+import { ChevronLeft, RefreshCw, FolderInput, HardDrive } from 'lucide-react'
+import { StorageRoot } from '../types'
+import { cn, formatSize } from '../utils'
+
+interface MoveFormProps {
+  currentPath?: string;
+  onMove: (targetRoot: string) => void;
+  onBack: () => void;
+  isMoving: boolean;
+}
+
+export function MoveForm({ currentPath, onMove, onBack, isMoving }: MoveFormProps) {
+  const [roots, setRoots] = useState<StorageRoot[]>([]);
+  const [target, setTarget] = useState<string | null>(null);
+
+  useEffect(() => {
+    invoke<StorageRoot[]>('get_storage_roots')
+      .then(setRoots)
+      .catch((err) => console.error('Failed to list storage roots:', err));
+  }, []);
+
+  return (
+    <motion.div
+      key="move"
+      initial={{ opacity: 0, x: 20 }}
+      animate={{ opacity: 1, x: 0 }}
+      exit={{ opacity: 0, x: 20 }}
+      className="p-4 space-y-4"
+    >
+      <div className="flex items-center gap-3 mb-2">
+        <button
+          onClick={onBack}
+          className="p-1.5 hover:bg-black/5 dark:hover:bg-white/5 rounded-lg text-gray-400 dark:text-white/40 hover:text-gray-900 dark:hover:text-white transition-colors"
+        >
+          <ChevronLeft size={16} />
+        </button>
+        <span className="text-xs font-black uppercase tracking-widest text-gray-500 dark:text-white/60">Move Server</span>
+      </div>
+
+      {roots.length < 2 && (
+        <div className="text-xs text-gray-500 dark:text-white/40">
+          Add a storage location in Settings &gt; Storage to move servers onto another disk.
+        </div>
+      )}
+
+      <div className="space-y-2">
+        {roots.map((root) => (
+          <button
+            key={root.path}
+            onClick={() => setTarget(root.path)}
+            disabled={currentPath?.startsWith(root.path)}
+            className={cn(
+              "w-full flex items-center gap-3 p-3 rounded-xl border text-left transition-all disabled:opacity-50",
+              target === root.path
+                ? "border-primary/50 bg-primary/10"
+                : "border-black/5 dark:border-white/5 bg-black/5 dark:bg-white/[0.03] hover:border-primary/30"
+            )}
+          >
+            <HardDrive size={16} className="shrink-0 text-gray-400" />
+            <div className="min-w-0">
+              <div className="text-xs font-bold text-gray-700 dark:text-white/80 truncate" title={root.path}>
+                {root.is_default ? 'Default location' : root.path}
+              </div>
+              <div className="text-[10px] text-gray-400 dark:text-white/40">
+                {currentPath?.startsWith(root.path) ? 'Current location, ' : ''}
+                {root.instance_count} servers
+                {root.available_space !== null && `, ${formatSize(root.available_space)} free`}
+              </div>
+            </div>
+          </button>
+        ))}
+      </div>
+
+      <div className="flex gap-2 pt-2">
+        <motion.button
+          whileHover={{ scale: 1.02 }}
+          whileTap={{ scale: 0.98 }}
+          onClick={() => target && onMove(target)}
+          disabled={isMoving || !target}
+          className="flex-1 py-3 bg-primary hover:bg-primary-hover disabled:opacity-50 text-white rounded-xl text-xs font-black uppercase tracking-widest shadow-glow-primary transition-all flex items-center justify-center gap-2"
+        >
+          {isMoving ? <RefreshCw size={14} className="animate-spin" /> : <FolderInput size={14} />}
+          {isMoving ? 'Moving...' : 'Move'}
+        </motion.button>
+      </div>
+    </motion.div>
+  );
+}
@@ -0,0 +1,73 @@
+import { motion } from 'framer-motion'
+import { ChevronLeft, RefreshCw, LayoutTemplate } from 'lucide-react'
+
+interface TemplateFormProps {
+  templateName: string;
+  setTemplateName: (name: string) => void;
+  configFiles: string;
+  setConfigFiles: (files: string) => void;
+  onSave: () => void;
+  onBack: () => void;
+  isSaving: boolean;
+}
+
+export function TemplateForm({ templateName, setTemplateName, configFiles, setConfigFiles, onSave, onBack, isSaving }: TemplateFormProps) {
+  const inputClass = "w-full bg-black/5 dark:bg-white/[0.03] border border-black/10 dark:border-white/10 rounded-xl px-4 py-3 text-sm text-gray-900 dark:text-white focus:outline-none focus:ring-2 focus:ring-primary/50 focus:border-primary/50 transition-all"
+
+  return (
+    <motion.div
+      key="template"
+      initial={{ opacity: 0, x: 20 }}
+      animate={{ opacity: 1, x: 0 }}
+      exit={{ opacity: 0, x: 20 }}
+      className="p-4 space-y-4"
+    >
+      <div className="flex items-center gap-3 mb-2">
+        <button
+          onClick={onBack}
+          className="p-1.5 hover:bg-black/5 dark:hover:bg-white/5 rounded-lg text-gray-400 dark:text-white/40 hover:text-gray-900 dark:hover:text-white transition-colors"
+        >
+          <ChevronLeft size={16} />
+        </button>
+        <span className="text-xs font-black uppercase tracking-widest text-gray-500 dark:text-white/60">Save as Template</span>
+      </div>
+
+      <div className="space-y-2">
+        <label className="text-[10px] font-black uppercase tracking-widest text-gray-400 dark:text-white/20 ml-1">Template Name</label>
+        <input
+          type="text"
+          value={templateName}
+          onChange={e => setTemplateName(e.target.value)}
+          className={inputClass}
+          placeholder="Template name..."
+          autoFocus
+        />
+      </div>
+
+      <div className="space-y-2">
+        <label className="text-[10px] font-black uppercase tracking-widest text-gray-400 dark:text-white/20 ml-1">Config Files (one per line)</label>
+        <textarea
+          value={configFiles}
+          onChange={e => setConfigFiles(e.target.value)}
+          rows={4}
+          className={`${inputClass} font-mono text-xs resize-none`}
+          placeholder="server.properties"
+        />
+        <p className="text-[10px] text-gray-400 dark:text-white/30 ml-1">Mods and plugins are re-downloaded from their source. Worlds are not included.</p>
+      </div>
+
+      <div className="flex gap-2 pt-2">
+        <motion.button
+          whileHover={{ scale: 1.02 }}
+          whileTap={{ scale: 0.98 }}
+          onClick={onSave}
+          disabled={isSaving || !templateName.trim()}
+          className="flex-1 py-3 bg-primary hover:bg-primary-hover disabled:opacity-50 text-white rounded-xl text-xs font-black uppercase tracking-widest shadow-glow-primary transition-all flex items-center justify-center gap-2"
+        >
+          {isSaving ? <RefreshCw size={14} className="animate-spin" /> : <LayoutTemplate size={14} />}
+          {isSaving ? 'Saving...' : 'Save Template'}
+        </motion.button>
+      </div>
+    </motion.div>
+  );
+}
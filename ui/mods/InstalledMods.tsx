@@ -7,6 +7,10 @@ import { InstalledModsEmpty } from './InstalledModsEmpty'
 import { InstalledModGridView } from './InstalledModGridView'
 import { InstalledModTableView } from './InstalledModTableView'
 import { useInstalledMods } from './useInstalledMods'
+import { LoadoutBar } from '../components/LoadoutBar'
+import { VersionHistoryBar } from '../components/VersionHistoryBar'
+import { BulkProgressBar } from '../components/BulkProgressBar'
+import { CrossplaySetup } from '../plugins/CrossplaySetup'
 
 interface InstalledModsProps {
   instanceId: string;
@@ -68,6 +72,14 @@ export function InstalledMods({ instanceId, refreshTrigger }: InstalledModsProps
         </AnimatePresence>
       </div>
 
+      <LoadoutBar instanceId={instanceId} refreshTrigger={refreshTrigger} onApplied={loadMods} />
+
+      <VersionHistoryBar instanceId={instanceId} kind="mod" refreshTrigger={refreshTrigger} pendingUpdates={updates} onRolledBack={loadMods} />
+
+      <BulkProgressBar instanceId={instanceId} />
+
+      <CrossplaySetup instanceId={instanceId} onInstalled={loadMods} />
+
       {loading ? (
         <InstalledModsLoading />
       ) : filteredMods.length === 0 ? (
@@ -68,7 +68,7 @@ export function ModConfigModal({ mod, instanceId, onClose }: ModConfigModalProps
     try {
       const initialConfigs = await invoke<ModConfig[]>('get_mod_configs', {
         instanceId,
-        modName: mod.name
+        filename: mod.filename
       })
 
       const allFiles: string[] = []
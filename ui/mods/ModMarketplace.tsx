@@ -2,7 +2,7 @@ import { useState, useRef, useEffect } from 'react'
 import { invoke } from '@tauri-apps/api/core'
 import { Search } from 'lucide-react'
 import { AnimatePresence } from 'framer-motion'
-import { Project, ResolvedDependency } from '../types'
+import { InstallResult, Project, ResolvedDependency } from '../types'
 import { useToast } from '../hooks/useToast'
 import { ModDetailsModal } from './ModDetailsModal'
 import { ModReviewModal } from './ModReviewModal'
@@ -98,18 +98,20 @@ export function ModMarketplace({ instanceId, onInstallSuccess }: ModMarketplaceP
     setIsInstalling(true)
     setInstallProgress({ current: 0, total: mods.length, name: mods[0]?.title || '' })
     setShowReview(false)
+    const warnings: string[] = []
 
     try {
       for (let i = 0; i < mods.length; i++) {
         const mod = mods[i]
         setInstallProgress(prev => ({ ...prev, current: i, name: mod.title }))
 
-        await invoke('install_mod', {
+        const result = await invoke<InstallResult>('install_mod', {
           instanceId,
           projectId: mod.id,
           provider: mod.provider,
           versionId: null // Latest compatible
         })
+        warnings.push(...result.warnings.map(w => `${mod.title}: ${w.message}`))
       }
 
       setInstallProgress(prev => ({ ...prev, current: mods.length }))
@@ -117,6 +119,7 @@ export function ModMarketplace({ instanceId, onInstallSuccess }: ModMarketplaceP
       await new Promise(resolve => setTimeout(resolve, 1000))
 
       showToast(`Successfully installed ${mods.length} mods!`, 'success')
+      warnings.forEach(warning => showToast(warning, 'info'))
       setSelectedMods(new Map())
       onInstallSuccess?.()
     } catch (err) {
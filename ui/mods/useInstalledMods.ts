@@ -1,7 +1,8 @@
 import { useState, useEffect, useMemo } from 'react'
 import { invoke } from '@tauri-apps/api/core'
-import { InstalledMod, ModUpdate } from '../types'
+import { BulkReport, InstalledMod, ModUpdate } from '../types'
 import { useToast } from '../hooks/useToast'
+import { describeBulkReport } from '../components/BulkProgressBar'
 
 export function useInstalledMods(instanceId: string, refreshTrigger?: number) {
   const [mods, setMods] = useState<InstalledMod[]>([])
@@ -119,12 +120,13 @@ export function useInstalledMods(instanceId: string, refreshTrigger?: number) {
 
   const handleBulkToggle = async (enable: boolean) => {
     try {
-      await invoke('bulk_toggle_mods', {
+      const report = await invoke<BulkReport>('bulk_toggle_mods', {
         instanceId,
         filenames: Array.from(selectedFilenames),
         enable
       })
-      showToast(`Bulk ${enable ? 'enable' : 'disable'} successful`)
+      const { message, type } = describeBulkReport(report, enable ? 'Enabled' : 'Disabled')
+      showToast(message, type)
       await loadMods()
     } catch (err) {
       showToast(`Bulk toggle failed: ${err}`, 'error')
@@ -133,12 +135,13 @@ export function useInstalledMods(instanceId: string, refreshTrigger?: number) {
 
   const handleBulkDelete = async (deleteConfig: boolean) => {
     try {
-      await invoke('bulk_uninstall_mods', {
+      const report = await invoke<BulkReport>('bulk_uninstall_mods', {
         instanceId,
         filenames: Array.from(selectedFilenames),
         deleteConfig
       })
-      showToast(`Bulk uninstall successful`)
+      const { message, type } = describeBulkReport(report, 'Uninstalled')
+      showToast(message, type)
       await loadMods()
     } catch (err) {
       showToast(`Bulk uninstall failed: ${err}`, 'error')
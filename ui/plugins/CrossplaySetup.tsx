@@ -0,0 +1,103 @@
+import { useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { Gamepad2, RefreshCw, X } from 'lucide-react'
+import { CrossplayPlan, CrossplaySetupReport } from '../types'
+import { useToast } from '../hooks/useToast'
+
+interface CrossplaySetupProps {
+  instanceId: string;
+  onInstalled: () => void;
+}
+
+const ACTION_LABELS = { installed: 'installed', updated: 'updated', up_to_date: 'already up to date' }
+const DEFAULT_BEDROCK_PORT = 19132
+
+export function CrossplaySetup({ instanceId, onInstalled }: CrossplaySetupProps) {
+  const [plan, setPlan] = useState<CrossplayPlan | null>(null)
+  const [port, setPort] = useState(DEFAULT_BEDROCK_PORT)
+  const [busy, setBusy] = useState(false)
+  const { showToast } = useToast()
+
+  const loadPlan = async (bedrockPort: number) => {
+    setBusy(true)
+    try {
+      setPlan(await invoke<CrossplayPlan>('get_crossplay_plan', { instanceId, bedrockPort }))
+    } catch (err) {
+      showToast(`${err}`, 'error')
+    } finally {
+      setBusy(false)
+    }
+  }
+
+  const runSetup = async () => {
+    setBusy(true)
+    try {
+      const report = await invoke<CrossplaySetupReport>('setup_crossplay', { instanceId, bedrockPort: port })
+      const summary = report.changes
+        .map(c => `${c.component} ${c.version} ${ACTION_LABELS[c.action]} on ${c.instance_name}`)
+        .join('\n')
+      showToast(summary || 'Nothing to do', 'success')
+      report.plan.notes
+        .filter(n => n.startsWith('Restart') || n.startsWith('Start'))
+        .forEach(n => showToast(n, 'info'))
+      setPlan(null)
+      onInstalled()
+    } catch (err) {
+      showToast(`Crossplay setup failed: ${err}`, 'error')
+    } finally {
+      setBusy(false)
+    }
+  }
+
+  if (!plan) {
+    return (
+      <button
+        onClick={() => loadPlan(port)}
+        disabled={busy}
+        className="flex items-center gap-2 px-4 py-2 bg-white/5 hover:bg-white/10 text-gray-300 rounded-xl transition-all border border-white/5 font-medium disabled:opacity-50"
+        title="Let Bedrock players join with Geyser and Floodgate"
+      >
+        {busy ? <RefreshCw size={18} className="animate-spin" /> : <Gamepad2 size={18} />}
+        Bedrock Crossplay
+      </button>
+    )
+  }
+
+  return (
+    <div className="p-4 bg-primary/5 border border-primary/20 rounded-2xl space-y-3">
+      <div className="flex items-center justify-between">
+        <h4 className="font-bold flex items-center gap-2"><Gamepad2 size={18} className="text-primary" /> Geyser + Floodgate</h4>
+        <button onClick={() => setPlan(null)} className="p-1 text-gray-500 hover:text-white"><X size={16} /></button>
+      </div>
+      <p className="text-sm text-gray-400">
+        Geyser and Floodgate on: <span className="font-medium text-white">{plan.host.instance_name} ({plan.host.platform})</span>
+      </p>
+      {plan.backends.length > 0 && (
+        <p className="text-sm text-gray-400">
+          Floodgate and the proxy's key on: <span className="font-medium text-white">{plan.backends.map(t => `${t.instance_name} (${t.platform})`).join(', ')}</span>
+        </p>
+      )}
+      <label className="flex items-center gap-2 text-sm text-gray-400">
+        Bedrock port (UDP)
+        <input
+          type="number"
+          min={1}
+          max={65535}
+          value={port}
+          onChange={e => setPort(Number(e.target.value))}
+          onBlur={() => loadPlan(port)}
+          className="w-28 bg-black/5 dark:bg-white/[0.05] border border-black/10 dark:border-white/10 rounded-xl py-1 px-3 focus:outline-none focus:ring-2 focus:ring-primary/50 transition-all"
+        />
+      </label>
+      {plan.notes.map(note => <p key={note} className="text-xs text-gray-500">{note}</p>)}
+      <button
+        onClick={runSetup}
+        disabled={busy}
+        className="flex items-center gap-2 px-4 py-2 bg-primary text-white rounded-xl font-bold disabled:opacity-50"
+      >
+        {busy && <RefreshCw size={16} className="animate-spin" />}
+        Install / Configure
+      </button>
+    </div>
+  )
+}
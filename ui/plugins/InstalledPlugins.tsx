@@ -7,13 +7,18 @@ import {
   Square
 } from 'lucide-react'
 import { motion, AnimatePresence } from 'framer-motion'
-import { InstalledPlugin, PluginUpdate } from '../types'
+import { BulkReport, InstalledPlugin, PluginUpdate } from '../types'
 import { useToast } from '../hooks/useToast'
 import { PluginConfigModal } from './PluginConfigModal'
 import { PluginCard } from './PluginCard'
 import { PluginTableRow } from './PluginTableRow'
 import { PluginFilters } from './PluginFilters'
 import { BulkActions } from './BulkActions'
+import { ViaSetup } from './ViaSetup'
+import { CrossplaySetup } from './CrossplaySetup'
+import { LoadoutBar } from '../components/LoadoutBar'
+import { VersionHistoryBar } from '../components/VersionHistoryBar'
+import { BulkProgressBar, describeBulkReport } from '../components/BulkProgressBar'
 
 interface InstalledPluginsProps {
   instanceId: string;
@@ -145,12 +150,13 @@ export function InstalledPlugins({ instanceId, refreshTrigger, onOpenDatabaseExp
 
   const handleBulkToggle = async (enable: boolean) => {
     try {
-      await invoke('bulk_toggle_plugins', {
+      const report = await invoke<BulkReport>('bulk_toggle_plugins', {
         instanceId,
         filenames: Array.from(selectedFilenames),
         enable
       })
-      showToast(`Bulk ${enable ? 'enable' : 'disable'} successful`)
+      const { message, type } = describeBulkReport(report, enable ? 'Enabled' : 'Disabled')
+      showToast(message, type)
       await loadPlugins()
     } catch (err) {
       showToast(`Bulk toggle failed: ${err}`, 'error')
@@ -159,12 +165,13 @@ export function InstalledPlugins({ instanceId, refreshTrigger, onOpenDatabaseExp
 
   const handleBulkDelete = async (deleteConfig: boolean) => {
     try {
-      await invoke('bulk_uninstall_plugins', {
+      const report = await invoke<BulkReport>('bulk_uninstall_plugins', {
         instanceId,
         filenames: Array.from(selectedFilenames),
         deleteConfig
       })
-      showToast(`Bulk uninstall successful`)
+      const { message, type } = describeBulkReport(report, 'Uninstalled')
+      showToast(message, type)
       await loadPlugins()
     } catch (err) {
       showToast(`Bulk uninstall failed: ${err}`, 'error')
@@ -220,6 +227,16 @@ export function InstalledPlugins({ instanceId, refreshTrigger, onOpenDatabaseExp
         checkingUpdates={checkingUpdates}
       />
 
+      <LoadoutBar instanceId={instanceId} refreshTrigger={refreshTrigger} onApplied={loadPlugins} />
+
+      <VersionHistoryBar instanceId={instanceId} kind="plugin" refreshTrigger={refreshTrigger} pendingUpdates={updates} onRolledBack={loadPlugins} />
+
+      <BulkProgressBar instanceId={instanceId} />
+
+      <ViaSetup instanceId={instanceId} onInstalled={loadPlugins} />
+
+      <CrossplaySetup instanceId={instanceId} onInstalled={loadPlugins} />
+
       <AnimatePresence>
         {selectedFilenames.size > 0 && (
           <BulkActions
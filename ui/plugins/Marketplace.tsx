@@ -19,7 +19,7 @@ import {
   List
 } from 'lucide-react'
 import { motion, AnimatePresence } from 'framer-motion'
-import { Project, PluginProvider, SortOrder, SearchOptions, Instance, ResolvedDependency, PluginDependencies } from '../types'
+import { InstallResult, Project, PluginProvider, SortOrder, SearchOptions, Instance, ResolvedDependency, PluginDependencies } from '../types'
 import { useToast } from '../hooks/useToast'
 import { formatNumber } from '../utils'
 import { PluginDetailsModal } from './PluginDetailsModal'
@@ -229,18 +229,20 @@ export function Marketplace({ instanceId, onInstallSuccess }: MarketplaceProps)
     setIsInstalling(true)
     setInstallProgress({ current: 0, total: plugins.length, name: plugins[0]?.title || '' })
     setShowReview(false)
+    const warnings: string[] = []
 
     try {
       for (let i = 0; i < plugins.length; i++) {
         const plugin = plugins[i]
         setInstallProgress(prev => ({ ...prev, current: i, name: plugin.title }))
 
-        await invoke('install_plugin', {
+        const result = await invoke<InstallResult>('install_plugin', {
           instanceId,
           projectId: plugin.id,
           provider: plugin.provider,
           versionId: null // Latest
         })
+        warnings.push(...result.warnings.map(w => `${plugin.title}: ${w.message}`))
       }
 
       setInstallProgress(prev => ({ ...prev, current: plugins.length }))
@@ -248,6 +250,7 @@ export function Marketplace({ instanceId, onInstallSuccess }: MarketplaceProps)
       await new Promise(resolve => setTimeout(resolve, 1000))
 
       showToast(`Successfully installed ${plugins.length} plugins!`, 'success')
+      warnings.forEach(warning => showToast(warning, 'info'))
       setSelectedPlugins(new Map())
       onInstallSuccess?.()
     } catch (err) {
@@ -262,7 +265,9 @@ export function Marketplace({ instanceId, onInstallSuccess }: MarketplaceProps)
     ? MODRINTH_CATEGORIES
     : provider === 'Spiget'
       ? SPIGET_CATEGORIES
-      : HANGAR_CATEGORIES
+      : provider === 'Hangar'
+        ? HANGAR_CATEGORIES
+        : []
 
   return (
     <div className="flex flex-1 gap-8 overflow-hidden min-h-0">
@@ -274,7 +279,7 @@ export function Marketplace({ instanceId, onInstallSuccess }: MarketplaceProps)
             Providers
           </div>
           <div className="space-y-1">
-            {(['Modrinth', 'Spiget', 'Hangar'] as const).map((p) => {
+            {(['Modrinth', 'Spiget', 'Hangar', 'CurseForge'] as const).map((p) => {
               const isDisabled = isVelocity && p !== 'Modrinth';
               if (isVelocity && p !== 'Modrinth') return null; // Hide other providers for Velocity
 
@@ -296,7 +301,7 @@ export function Marketplace({ instanceId, onInstallSuccess }: MarketplaceProps)
                       : 'text-gray-500 hover:text-gray-300 hover:bg-white/5'
                     }`}
                 >
-                  <div className={`w-2 h-2 rounded-full ${p === 'Modrinth' ? 'bg-green-500' : p === 'Spiget' ? 'bg-orange-500' : 'bg-blue-500'}`} />
+                  <div className={`w-2 h-2 rounded-full ${p === 'Modrinth' ? 'bg-green-500' : p === 'Spiget' ? 'bg-orange-500' : p === 'CurseForge' ? 'bg-amber-600' : 'bg-blue-500'}`} />
                   {p}
                 </button>
               );
@@ -436,7 +441,7 @@ export function Marketplace({ instanceId, onInstallSuccess }: MarketplaceProps)
                             {project.title}
                           </h3>
                           <div className="flex items-center gap-1.5 px-2 py-0.5 bg-white/5 rounded-lg shrink-0">
-                            <div className={`w-1.5 h-1.5 rounded-full ${project.provider === 'Modrinth' ? 'bg-green-500' : project.provider === 'Spiget' ? 'bg-orange-500' : 'bg-blue-500'}`} />
+                            <div className={`w-1.5 h-1.5 rounded-full ${project.provider === 'Modrinth' ? 'bg-green-500' : project.provider === 'Spiget' ? 'bg-orange-500' : project.provider === 'CurseForge' ? 'bg-amber-600' : 'bg-blue-500'}`} />
                             <span className="text-[9px] font-black uppercase tracking-widest text-gray-500">{project.provider}</span>
                           </div>
                         </div>
@@ -508,7 +513,7 @@ export function Marketplace({ instanceId, onInstallSuccess }: MarketplaceProps)
                       <div className="flex-1 min-w-0">
                         <h3 className="font-bold text-white truncate text-base group-hover:text-primary transition-colors">{project.title}</h3>
                         <div className="flex items-center gap-2 mt-1">
-                          <div className={`w-2 h-2 rounded-full ${project.provider === 'Modrinth' ? 'bg-green-500' : project.provider === 'Spiget' ? 'bg-orange-500' : 'bg-blue-500'}`} />
+                          <div className={`w-2 h-2 rounded-full ${project.provider === 'Modrinth' ? 'bg-green-500' : project.provider === 'Spiget' ? 'bg-orange-500' : project.provider === 'CurseForge' ? 'bg-amber-600' : 'bg-blue-500'}`} />
                           <span className="text-[10px] font-black uppercase tracking-widest text-gray-500">{project.provider}</span>
                         </div>
                       </div>
@@ -143,15 +143,6 @@ export function PluginConfigModal({ plugin, instanceId, onClose }: PluginConfigM
     return root
   }, [configs])
 
-  const getConfigFormat = (filename: string): string | null => {
-    const ext = filename.split('.').pop()?.toLowerCase();
-    if (ext === 'yml' || ext === 'yaml') return 'Yaml';
-    if (ext === 'toml') return 'Toml';
-    if (ext === 'json') return 'Json';
-    if (ext === 'properties') return 'Properties';
-    return null;
-  };
-
   const loadFileContent = async (filename: string) => {
     if (!configDir) return
     setLoading(true)
@@ -159,16 +150,12 @@ export function PluginConfigModal({ plugin, instanceId, onClose }: PluginConfigM
       const relPath = `plugins/${configDir}/${filename}`
 
       if (isTreeMode) {
-        const format = getConfigFormat(filename);
-        if (format) {
-          const result = await invoke<any>('get_config_value', {
-            instanceId,
-            relPath,
-            format
-          });
+        // The backend detects the format from the file itself
+        try {
+          const result = await invoke<any>('get_config_value', { instanceId, relPath });
           setParsedContent(result);
-        } else {
-          showToast('Tree view not supported for this file type', 'info');
+        } catch (err) {
+          showToast(`Tree view not supported for this file: ${err}`, 'info');
           setIsTreeMode(false);
         }
       }
@@ -193,15 +180,11 @@ export function PluginConfigModal({ plugin, instanceId, onClose }: PluginConfigM
       const relPath = `plugins/${configDir}/${selectedConfig}`
 
       if (isTreeMode) {
-        const format = getConfigFormat(selectedConfig);
-        if (format) {
-          await invoke('save_config_value', {
-            instanceId,
-            relPath,
-            format,
-            value: parsedContent
-          });
-        }
+        await invoke('save_config_value', {
+          instanceId,
+          relPath,
+          value: parsedContent
+        });
       } else {
         const valueToSave = editorRef.current ? editorRef.current.getValue() : content;
         await invoke('save_text_file', {
@@ -107,7 +107,7 @@ export function PluginDetailsModal({
               <div className="min-w-0 flex-1">
                 <div className="flex items-center gap-3 mb-1">
                   <h2 className="text-2xl font-bold text-white truncate">{project.title}</h2>
-                  <span className={`text-xs px-2 py-0.5 rounded-full font-bold uppercase tracking-wider ${project.provider === 'Modrinth' ? 'bg-green-500/10 text-green-500' : project.provider === 'Spiget' ? 'bg-orange-500/10 text-orange-500' : 'bg-blue-500/10 text-blue-500'
+                  <span className={`text-xs px-2 py-0.5 rounded-full font-bold uppercase tracking-wider ${project.provider === 'Modrinth' ? 'bg-green-500/10 text-green-500' : project.provider === 'Spiget' ? 'bg-orange-500/10 text-orange-500' : project.provider === 'CurseForge' ? 'bg-amber-600/10 text-amber-600' : 'bg-blue-500/10 text-blue-500'
                     }`}>
                     {project.provider}
                   </span>
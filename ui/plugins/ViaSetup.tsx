@@ -0,0 +1,82 @@
+import { useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { Network, RefreshCw, X } from 'lucide-react'
+import { ViaPlan, ViaSetupReport } from '../types'
+import { useToast } from '../hooks/useToast'
+
+interface ViaSetupProps {
+  instanceId: string;
+  onInstalled: () => void;
+}
+
+const ACTION_LABELS = { installed: 'installed', updated: 'updated', up_to_date: 'already up to date' }
+
+export function ViaSetup({ instanceId, onInstalled }: ViaSetupProps) {
+  const [plan, setPlan] = useState<ViaPlan | null>(null)
+  const [busy, setBusy] = useState(false)
+  const { showToast } = useToast()
+
+  const loadPlan = async () => {
+    setBusy(true)
+    try {
+      setPlan(await invoke<ViaPlan>('get_via_plan', { instanceId }))
+    } catch (err) {
+      showToast(`${err}`, 'error')
+    } finally {
+      setBusy(false)
+    }
+  }
+
+  const runSetup = async () => {
+    setBusy(true)
+    try {
+      const report = await invoke<ViaSetupReport>('setup_via', { instanceId })
+      const summary = report.changes
+        .map(c => `${c.plugin} ${c.version} ${ACTION_LABELS[c.action]} on ${c.instance_name}`)
+        .join('\n')
+      showToast(summary || 'Nothing to do', 'success')
+      report.plan.notes.filter(n => n.startsWith('Restart')).forEach(n => showToast(n, 'info'))
+      setPlan(null)
+      onInstalled()
+    } catch (err) {
+      showToast(`ViaVersion setup failed: ${err}`, 'error')
+    } finally {
+      setBusy(false)
+    }
+  }
+
+  if (!plan) {
+    return (
+      <button
+        onClick={loadPlan}
+        disabled={busy}
+        className="flex items-center gap-2 px-4 py-2 bg-white/5 hover:bg-white/10 text-gray-300 rounded-xl transition-all border border-white/5 font-medium disabled:opacity-50"
+        title="Let older and newer clients join with ViaVersion/ViaBackwards"
+      >
+        {busy ? <RefreshCw size={18} className="animate-spin" /> : <Network size={18} />}
+        Client Compatibility
+      </button>
+    )
+  }
+
+  return (
+    <div className="p-4 bg-primary/5 border border-primary/20 rounded-2xl space-y-3">
+      <div className="flex items-center justify-between">
+        <h4 className="font-bold flex items-center gap-2"><Network size={18} className="text-primary" /> ViaVersion + ViaBackwards</h4>
+        <button onClick={() => setPlan(null)} className="p-1 text-gray-500 hover:text-white"><X size={16} /></button>
+      </div>
+      <p className="text-sm text-gray-400">
+        Will install or update on: <span className="font-medium text-white">{plan.targets.map(t => `${t.instance_name} (${t.loader})`).join(', ')}</span>
+      </p>
+      {plan.notes.map(note => <p key={note} className="text-xs text-gray-500">{note}</p>)}
+      <button
+        onClick={runSetup}
+        disabled={busy}
+        className="flex items-center gap-2 px-4 py-2 bg-primary text-white rounded-xl font-bold disabled:opacity-50"
+      >
+        {busy && <RefreshCw size={16} className="animate-spin" />}
+        Install / Update
+      </button>
+    </div>
+  )
+}
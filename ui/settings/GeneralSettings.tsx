@@ -1,4 +1,5 @@
-import { HardDrive, Cpu } from 'lucide-react'
+import { useState } from 'react'
+import { HardDrive, Cpu, Moon } from 'lucide-react'
 import { invoke } from '@tauri-apps/api/core'
 import { Instance, InstanceSettings } from '../types'
 import { Select } from '../components/Select'
@@ -12,6 +13,8 @@ interface GeneralSettingsProps {
 }
 
 export function GeneralSettings({ instance, name, setName, settings, updateSetting }: GeneralSettingsProps) {
+  const [exemptText, setExemptText] = useState((settings.idle_exempt_players ?? []).join(', '))
+
   return (
     <div className="space-y-8">
       <div className="grid grid-cols-1 md:grid-cols-2 gap-8">
@@ -109,10 +112,75 @@ export function GeneralSettings({ instance, name, setName, settings, updateSetti
                 className="w-full bg-black/5 dark:bg-white/[0.05] border border-black/10 dark:border-white/10 rounded-xl py-2 px-4 focus:outline-none focus:ring-2 focus:ring-primary/50 transition-all"
               />
             </div>
+            <div className="space-y-2">
+              <label className="text-sm font-medium text-gray-500 dark:text-white/40">Tunnel Address (optional)</label>
+              <input
+                type="text"
+                value={settings.tunnel_address ?? ''}
+                onChange={(e) => updateSetting('tunnel_address', e.target.value)}
+                className="w-full bg-black/5 dark:bg-white/[0.05] border border-black/10 dark:border-white/10 rounded-xl py-2 px-4 focus:outline-none focus:ring-2 focus:ring-primary/50 transition-all"
+                placeholder="example.joinmc.link"
+              />
+              <p className="text-xs text-gray-500">From playit.gg, ngrok or similar. Shown first in the connection info.</p>
+            </div>
           </div>
         </div>
       </div>
 
+      <div className="pt-4 border-t border-black/10 dark:border-white/10 space-y-4">
+        <h3 className="text-lg font-bold flex items-center gap-2">
+          <Moon size={20} className="text-primary" />
+          Idle Shutdown
+        </h3>
+        <div className="grid grid-cols-1 md:grid-cols-2 gap-4">
+          <div className="space-y-2">
+            <label className="text-sm font-medium text-gray-500 dark:text-white/40">Stop after minutes without players (0 = never)</label>
+            <input
+              type="number"
+              min={0}
+              value={settings.idle_stop_minutes ?? 0}
+              onChange={(e) => updateSetting('idle_stop_minutes', Math.max(0, parseInt(e.target.value) || 0))}
+              className="w-full bg-black/5 dark:bg-white/[0.05] border border-black/10 dark:border-white/10 rounded-xl py-2 px-4 focus:outline-none focus:ring-2 focus:ring-primary/50 transition-all"
+            />
+          </div>
+          <div className="space-y-2">
+            <label className="text-sm font-medium text-gray-500 dark:text-white/40">Players that don't count (comma separated)</label>
+            <input
+              type="text"
+              value={exemptText}
+              onChange={(e) => setExemptText(e.target.value)}
+              onBlur={() => updateSetting('idle_exempt_players', exemptText.split(',').map(p => p.trim()).filter(Boolean))}
+              className="w-full bg-black/5 dark:bg-white/[0.05] border border-black/10 dark:border-white/10 rounded-xl py-2 px-4 focus:outline-none focus:ring-2 focus:ring-primary/50 transition-all"
+              placeholder="AFKBot, CameraMan"
+            />
+          </div>
+        </div>
+        <label className="flex items-center gap-3 cursor-pointer">
+          <input
+            type="checkbox"
+            checked={settings.wake_on_connect ?? false}
+            onChange={(e) => updateSetting('wake_on_connect', e.target.checked)}
+            className="w-5 h-5 rounded-lg text-primary"
+          />
+          <span className="text-sm">
+            Wake on connect
+            <span className="block text-xs text-gray-500 dark:text-white/40">While stopped, answer pings on the server port and start the server when a player tries to join</span>
+          </span>
+        </label>
+      </div>
+
+      <div className="space-y-2 pt-4 border-t border-black/10 dark:border-white/10">
+        <label className="text-sm font-medium text-gray-500 dark:text-white/40">Query TPS/MSPT every N seconds (0 = off)</label>
+        <input
+          type="number"
+          min={0}
+          value={settings.tick_sample_interval ?? 0}
+          onChange={(e) => updateSetting('tick_sample_interval', Math.max(0, parseInt(e.target.value) || 0))}
+          className="w-full bg-black/5 dark:bg-white/[0.05] border border-black/10 dark:border-white/10 rounded-xl py-2 px-4 focus:outline-none focus:ring-2 focus:ring-primary/50 transition-all"
+        />
+        <p className="text-xs text-gray-500">Paper-family servers only. The tps and mspt commands and their replies show in the console.</p>
+      </div>
+
       <div className="space-y-2 pt-4 border-t border-black/10 dark:border-white/10">
         <label className="text-sm font-medium text-gray-500 dark:text-white/40">Instance Folder Path</label>
         <div className="flex gap-2">
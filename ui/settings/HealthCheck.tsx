@@ -0,0 +1,98 @@
+import { useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { Stethoscope, CheckCircle2, AlertTriangle, AlertCircle, Info, Loader2, Wrench } from 'lucide-react'
+import { cn } from '../utils'
+import { useToast } from '../hooks/useToast'
+import { DiagnosisReport, FindingSeverity, InstallerRepair } from '../types'
+
+const SEVERITY_STYLES: Record<FindingSeverity, { icon: typeof Info; className: string }> = {
+  error: { icon: AlertCircle, className: 'text-red-500 bg-red-500/10' },
+  warning: { icon: AlertTriangle, className: 'text-amber-500 bg-amber-500/10' },
+  info: { icon: Info, className: 'text-blue-500 bg-blue-500/10' },
+}
+
+export function HealthCheck({ instanceId }: { instanceId: string }) {
+  const { showToast } = useToast()
+  const [report, setReport] = useState<DiagnosisReport | null>(null)
+  const [running, setRunning] = useState(false)
+  const [repairing, setRepairing] = useState(false)
+
+  const runChecks = async () => {
+    setRunning(true)
+    try {
+      setReport(await invoke<DiagnosisReport>('diagnose_instance', { instanceId }))
+    } catch (err) {
+      showToast(`Health check failed: ${err}`, 'error')
+    } finally {
+      setRunning(false)
+    }
+  }
+
+  const repair = async () => {
+    setRepairing(true)
+    try {
+      const result = await invoke<InstallerRepair>('repair_installation', { instanceId })
+      showToast(result.rerun_from ? 'Installation repaired' : 'Installation is intact, nothing to repair')
+      await runChecks()
+    } catch (err) {
+      showToast(`Failed to repair installation: ${err}`, 'error')
+    } finally {
+      setRepairing(false)
+    }
+  }
+
+  const problems = report?.findings.filter(f => f.severity !== 'info').length ?? 0
+
+  return (
+    <div className="space-y-4">
+      <div className="flex items-center justify-between">
+        <div className="space-y-1">
+          <h3 className="text-lg font-bold flex items-center gap-2">
+            <Stethoscope size={20} className="text-primary" />
+            Health Check
+          </h3>
+          <p className="text-sm text-gray-500 dark:text-white/40">
+            Checks the server jar, Java, EULA, port, mod jars and leftover files for common problems.
+          </p>
+        </div>
+        <button
+          onClick={runChecks}
+          disabled={running}
+          className="flex items-center gap-2 px-4 py-2 bg-primary text-white rounded-xl font-bold disabled:opacity-50"
+        >
+          {running ? <Loader2 size={16} className="animate-spin" /> : <Stethoscope size={16} />} Run Checks
+        </button>
+      </div>
+
+      {report && problems === 0 && (
+        <div className="flex items-center gap-3 p-4 bg-accent-emerald/10 text-accent-emerald rounded-xl text-sm font-medium">
+          <CheckCircle2 size={18} /> All {report.checks.length} checks passed
+        </div>
+      )}
+
+      {report?.findings.map((finding, index) => {
+        const { icon: Icon, className } = SEVERITY_STYLES[finding.severity]
+        return (
+          <div key={index} className="flex items-start gap-3 p-4 bg-black/5 dark:bg-white/[0.03] rounded-xl">
+            <div className={cn('p-1.5 rounded-lg shrink-0', className)}>
+              <Icon size={16} />
+            </div>
+            <div className="flex-1 space-y-1">
+              <div className="text-sm font-semibold whitespace-pre-line">{finding.message}</div>
+              <div className="text-xs text-gray-500 dark:text-white/40">{finding.suggestion}</div>
+            </div>
+            {finding.fix?.action === 'repair_installation' && (
+              <button
+                onClick={repair}
+                disabled={repairing}
+                className="flex items-center gap-2 px-3 py-1.5 text-sm bg-primary/10 text-primary rounded-lg font-bold disabled:opacity-50"
+              >
+                <Wrench size={14} /> Repair
+              </button>
+            )}
+          </div>
+        )
+      })}
+    </div>
+  )
+}
@@ -0,0 +1,107 @@
+import { useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { HeartPulse, Send, RefreshCw } from 'lucide-react'
+import { useToast } from '../hooks/useToast'
+import { InstanceSettings, HeartbeatSettings as Heartbeat } from '../types'
+
+const DEFAULT_HEARTBEAT: Heartbeat = {
+  enabled: false,
+  url: '',
+  interval_secs: 60,
+  report_failures: true,
+}
+
+interface HeartbeatSettingsProps {
+  instanceId: string;
+  settings: InstanceSettings;
+  updateSetting: <K extends keyof InstanceSettings>(key: K, value: InstanceSettings[K]) => void;
+}
+
+export function HeartbeatSettings({ instanceId, settings, updateSetting }: HeartbeatSettingsProps) {
+  const { showToast } = useToast()
+  const [testing, setTesting] = useState(false)
+  const heartbeat = settings.heartbeat ?? DEFAULT_HEARTBEAT
+
+  const update = (patch: Partial<Heartbeat>) => updateSetting('heartbeat', { ...heartbeat, ...patch })
+
+  const handleTest = async () => {
+    setTesting(true)
+    try {
+      await invoke('test_heartbeat', { instanceId, heartbeat })
+      showToast('Heartbeat sent', 'success')
+    } catch (err) {
+      showToast(`Heartbeat failed: ${err}`, 'error')
+    } finally {
+      setTesting(false)
+    }
+  }
+
+  return (
+    <div className="space-y-6">
+      <div className="space-y-2">
+        <h3 className="text-lg font-bold flex items-center gap-2">
+          <HeartPulse size={20} className="text-primary" />
+          Heartbeat Monitoring
+        </h3>
+        <p className="text-sm text-gray-500 dark:text-white/40">
+          Ping a monitoring URL (e.g. healthchecks.io) while the server is running. If the pings stop because the
+          server, the app or the whole machine went down, the service alerts you.
+        </p>
+      </div>
+
+      <label className="flex items-center gap-3 p-3 bg-black/5 dark:bg-white/[0.03] rounded-xl cursor-pointer">
+        <input
+          type="checkbox"
+          checked={heartbeat.enabled}
+          onChange={(e) => update({ enabled: e.target.checked })}
+          className="w-5 h-5 rounded-lg border-black/10 dark:border-white/10 text-primary focus:ring-primary"
+        />
+        <p className="font-medium">Send heartbeats for this instance</p>
+      </label>
+
+      <div className="grid grid-cols-1 md:grid-cols-[1fr_auto_auto] gap-3 items-end">
+        <div className="space-y-2">
+          <label className="text-sm font-medium text-gray-500 dark:text-white/60">Ping URL</label>
+          <input
+            type="url"
+            value={heartbeat.url}
+            onChange={(e) => update({ url: e.target.value })}
+            placeholder="https://hc-ping.com/..."
+            className="w-full bg-black/5 dark:bg-white/5 border border-black/10 dark:border-white/10 rounded-xl px-4 py-2 focus:outline-none focus:border-primary/50"
+          />
+        </div>
+        <div className="space-y-2">
+          <label className="text-sm font-medium text-gray-500 dark:text-white/60">Every (seconds)</label>
+          <input
+            type="number"
+            min={10}
+            value={heartbeat.interval_secs}
+            onChange={(e) => update({ interval_secs: Number(e.target.value) })}
+            className="w-28 bg-black/5 dark:bg-white/5 border border-black/10 dark:border-white/10 rounded-xl px-4 py-2"
+          />
+        </div>
+        <button
+          onClick={handleTest}
+          disabled={testing || !heartbeat.url.trim()}
+          className="flex items-center gap-2 px-4 py-2 bg-primary text-white rounded-xl font-bold disabled:opacity-50"
+        >
+          {testing ? <RefreshCw size={16} className="animate-spin" /> : <Send size={16} />}
+          Test
+        </button>
+      </div>
+
+      <label className="flex items-center gap-3 p-3 bg-black/5 dark:bg-white/[0.03] rounded-xl cursor-pointer">
+        <input
+          type="checkbox"
+          checked={heartbeat.report_failures}
+          onChange={(e) => update({ report_failures: e.target.checked })}
+          className="w-5 h-5 rounded-lg border-black/10 dark:border-white/10 text-primary focus:ring-primary"
+        />
+        <div>
+          <p className="font-medium">Report crashes immediately</p>
+          <p className="text-sm text-gray-500 dark:text-white/40">Pings the URL with /fail appended when the server crashes.</p>
+        </div>
+      </label>
+    </div>
+  )
+}
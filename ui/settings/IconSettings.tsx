@@ -117,7 +117,31 @@ export function IconSettings({ tempIconPath, setTempIconPath, settings, updateSe
             />
             <div>
               <p className="font-medium">Autostart</p>
-              <p className="text-xs text-gray-500 dark:text-white/40">Automatically start this server when the application launches.</p>
+              <p className="text-xs text-gray-500 dark:text-white/40">Automatically start this server when the application launches, after any servers it depends on.</p>
+            </div>
+          </label>
+          <label className="flex items-center gap-3 p-3 bg-black/5 dark:bg-white/[0.03] rounded-xl cursor-pointer hover:bg-black/10 dark:hover:bg-white/5 transition-colors">
+            <input
+              type="checkbox"
+              checked={settings.keep_running_on_close ?? false}
+              onChange={(e) => updateSetting('keep_running_on_close', e.target.checked)}
+              className="w-5 h-5 rounded-lg border-black/10 dark:border-white/10 text-primary focus:ring-primary"
+            />
+            <div>
+              <p className="font-medium">Keep running when the app closes</p>
+              <p className="text-xs text-gray-500 dark:text-white/40">Leave this server running after quitting and reattach to it on the next launch. Console input is unavailable until it is restarted.</p>
+            </div>
+          </label>
+          <label className="flex items-center gap-3 p-3 bg-black/5 dark:bg-white/[0.03] rounded-xl cursor-pointer hover:bg-black/10 dark:hover:bg-white/5 transition-colors">
+            <input
+              type="checkbox"
+              checked={settings.production ?? false}
+              onChange={(e) => updateSetting('production', e.target.checked)}
+              className="w-5 h-5 rounded-lg border-black/10 dark:border-white/10 text-primary focus:ring-primary"
+            />
+            <div>
+              <p className="font-medium">Production server</p>
+              <p className="text-xs text-gray-500 dark:text-white/40">Require confirmation before installing experimental Paper builds on this server.</p>
             </div>
           </label>
         </div>
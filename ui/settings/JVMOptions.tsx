@@ -122,10 +122,21 @@ export function JVMOptions({
             >
               Batch File
             </button>
+            <button
+              onClick={() => updateSetting('launch_method', 'CustomCommand')}
+              className={cn(
+                "px-6 py-2 rounded-xl text-sm font-bold transition-all",
+                settings.launch_method === 'CustomCommand'
+                  ? "bg-white dark:bg-white/10 shadow-sm text-primary"
+                  : "text-gray-500 hover:text-gray-700 dark:hover:text-white/80"
+              )}
+            >
+              Custom Command
+            </button>
           </div>
 
           <AnimatePresence mode="wait">
-            {settings.launch_method === 'StartupLine' ? (
+            {settings.launch_method !== 'BatFile' ? (
               <motion.div
                 key="startup-line"
                 initial={{ opacity: 0, height: 0 }}
@@ -134,6 +145,11 @@ export function JVMOptions({
                 className="space-y-2 overflow-hidden"
               >
                 <label className="text-sm font-medium text-gray-500 dark:text-white/40">Startup Command</label>
+                {settings.launch_method === 'CustomCommand' && (
+                  <p className="text-xs text-gray-500 dark:text-white/40">
+                    The whole command is run as-is, without a shell. Use this for servers that don't start from a jar or script.
+                  </p>
+                )}
                 <textarea
                   value={settings.startup_line}
                   onChange={(e) => updateSetting('startup_line', e.target.value)}
@@ -0,0 +1,167 @@
+import { useEffect, useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { Bug, Plus, Trash2, AlertTriangle } from 'lucide-react'
+import { InstanceSettings, JavaAgent, JvmOptions } from '../types'
+
+const DEFAULT_JVM: JvmOptions = {
+  agents: [],
+  debug: { enabled: false, port: 5005, bind_address: '127.0.0.1', suspend: false },
+  extra_flags: [],
+}
+
+const inputClass = 'w-full bg-black/5 dark:bg-white/5 border border-black/10 dark:border-white/10 rounded-xl px-3 py-2 focus:outline-none focus:border-primary/50'
+const checkboxClass = 'w-5 h-5 rounded-lg border-black/10 dark:border-white/10 text-primary focus:ring-primary'
+
+interface JavaAgentsProps {
+  instanceId: string;
+  settings: InstanceSettings;
+  updateSetting: <K extends keyof InstanceSettings>(key: K, value: InstanceSettings[K]) => void;
+}
+
+export function JavaAgents({ instanceId, settings, updateSetting }: JavaAgentsProps) {
+  const jvm = settings.jvm ?? DEFAULT_JVM
+  const [warnings, setWarnings] = useState<string[]>([])
+  const [error, setError] = useState<string | null>(null)
+
+  const update = (patch: Partial<JvmOptions>) => updateSetting('jvm', { ...jvm, ...patch })
+  const updateAgent = (index: number, patch: Partial<JavaAgent>) =>
+    update({ agents: jvm.agents.map((a, i) => (i === index ? { ...a, ...patch } : a)) })
+
+  useEffect(() => {
+    const timer = setTimeout(() => {
+      invoke<string[]>('check_jvm_options', { instanceId, jvm })
+        .then((result) => {
+          setWarnings(result)
+          setError(null)
+        })
+        .catch((err) => {
+          setWarnings([])
+          setError(String(err))
+        })
+    }, 400)
+    return () => clearTimeout(timer)
+  }, [instanceId, JSON.stringify(jvm)])
+
+  return (
+    <div className="space-y-6">
+      <div className="space-y-2">
+        <h3 className="text-lg font-bold flex items-center gap-2">
+          <Bug size={20} className="text-primary" />
+          Agents &amp; Debugging
+        </h3>
+        <p className="text-sm text-gray-500 dark:text-white/40">
+          Attach Java agents such as spark or a JMX exporter, open a remote debug port, or add extra JVM flags.
+          These are placed before <code className="font-mono">-jar</code> when the server starts.
+        </p>
+      </div>
+
+      <div className="space-y-3">
+        {jvm.agents.map((agent, index) => (
+          <div key={index} className="grid grid-cols-[auto_1fr_1fr_auto] gap-3 items-center p-3 bg-black/5 dark:bg-white/[0.03] rounded-xl">
+            <input
+              type="checkbox"
+              checked={agent.enabled}
+              onChange={(e) => updateAgent(index, { enabled: e.target.checked })}
+              className={checkboxClass}
+            />
+            <input
+              type="text"
+              value={agent.path}
+              onChange={(e) => updateAgent(index, { path: e.target.value })}
+              placeholder="Agent jar, e.g. spark-agent.jar"
+              className={inputClass}
+            />
+            <input
+              type="text"
+              value={agent.options}
+              onChange={(e) => updateAgent(index, { options: e.target.value })}
+              placeholder="Options, e.g. 9404:jmx.yaml"
+              className={inputClass}
+            />
+            <button
+              onClick={() => update({ agents: jvm.agents.filter((_, i) => i !== index) })}
+              className="p-2 text-red-500 hover:bg-red-500/10 rounded-lg"
+            >
+              <Trash2 size={16} />
+            </button>
+          </div>
+        ))}
+        <button
+          onClick={() => update({ agents: [...jvm.agents, { enabled: true, path: '', options: '' }] })}
+          className="flex items-center gap-2 px-4 py-2 bg-black/5 dark:bg-white/5 rounded-xl font-medium hover:bg-black/10 dark:hover:bg-white/10"
+        >
+          <Plus size={16} />
+          Add agent
+        </button>
+      </div>
+
+      <div className="p-3 bg-black/5 dark:bg-white/[0.03] rounded-xl space-y-3">
+        <label className="flex items-center gap-3 cursor-pointer">
+          <input
+            type="checkbox"
+            checked={jvm.debug.enabled}
+            onChange={(e) => update({ debug: { ...jvm.debug, enabled: e.target.checked } })}
+            className={checkboxClass}
+          />
+          <span className="font-medium">Enable remote debugging (JDWP)</span>
+        </label>
+        {jvm.debug.enabled && (
+          <div className="grid grid-cols-1 md:grid-cols-[1fr_8rem_auto] gap-3 items-center">
+            <input
+              type="text"
+              value={jvm.debug.bind_address}
+              onChange={(e) => update({ debug: { ...jvm.debug, bind_address: e.target.value } })}
+              placeholder="127.0.0.1"
+              className={inputClass}
+            />
+            <input
+              type="number"
+              min={1}
+              max={65535}
+              value={jvm.debug.port}
+              onChange={(e) => update({ debug: { ...jvm.debug, port: Number(e.target.value) } })}
+              className={inputClass}
+            />
+            <label className="flex items-center gap-2 text-sm cursor-pointer">
+              <input
+                type="checkbox"
+                checked={jvm.debug.suspend}
+                onChange={(e) => update({ debug: { ...jvm.debug, suspend: e.target.checked } })}
+                className={checkboxClass}
+              />
+              Wait for debugger
+            </label>
+          </div>
+        )}
+      </div>
+
+      <div className="space-y-2">
+        <label className="text-sm font-medium text-gray-500 dark:text-white/60">Extra JVM flags (one per line)</label>
+        <textarea
+          value={jvm.extra_flags.join('\n')}
+          onChange={(e) => update({ extra_flags: e.target.value.split('\n') })}
+          rows={3}
+          placeholder="-XX:+UseG1GC"
+          className={`${inputClass} font-mono text-sm`}
+        />
+      </div>
+
+      {(error || warnings.length > 0) && (
+        <div className="space-y-2">
+          {error && (
+            <div className="flex items-start gap-2 p-3 rounded-xl bg-red-500/10 text-red-500 text-sm">
+              <AlertTriangle size={16} className="shrink-0 mt-0.5" />
+              {error}
+            </div>
+          )}
+          {warnings.map((warning) => (
+            <div key={warning} className="flex items-start gap-2 p-3 rounded-xl bg-amber-500/10 text-amber-600 dark:text-amber-400 text-sm">
+              <AlertTriangle size={16} className="shrink-0 mt-0.5" />
+              {warning}
+            </div>
+          ))}
+        </div>
+      )}
+    </div>
+  )
+}
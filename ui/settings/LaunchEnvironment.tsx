@@ -0,0 +1,114 @@
+import { FolderCog, Plus, Trash2 } from 'lucide-react'
+import { InstanceSettings, LaunchEnvironment as LaunchEnvironmentSettings, EnvVar } from '../types'
+
+const DEFAULT_ENVIRONMENT: LaunchEnvironmentSettings = {
+  variables: [],
+  working_dir: '',
+  pre_start_hook: '',
+  post_stop_hook: '',
+}
+
+const inputClass = 'w-full bg-black/5 dark:bg-white/5 border border-black/10 dark:border-white/10 rounded-xl px-3 py-2 focus:outline-none focus:border-primary/50'
+
+interface LaunchEnvironmentProps {
+  settings: InstanceSettings;
+  updateSetting: <K extends keyof InstanceSettings>(key: K, value: InstanceSettings[K]) => void;
+}
+
+export function LaunchEnvironment({ settings, updateSetting }: LaunchEnvironmentProps) {
+  const environment = settings.environment ?? DEFAULT_ENVIRONMENT
+
+  const update = (patch: Partial<LaunchEnvironmentSettings>) =>
+    updateSetting('environment', { ...environment, ...patch })
+  const updateVariable = (index: number, patch: Partial<EnvVar>) =>
+    update({ variables: environment.variables.map((v, i) => (i === index ? { ...v, ...patch } : v)) })
+
+  return (
+    <div className="space-y-6">
+      <div className="space-y-2">
+        <h3 className="text-lg font-bold flex items-center gap-2">
+          <FolderCog size={20} className="text-primary" />
+          Environment &amp; Hooks
+        </h3>
+        <p className="text-sm text-gray-500 dark:text-white/40">
+          Environment variables and the folder the server runs in, plus shell commands run before each start and after
+          the server stops. Hook output appears in the console.
+        </p>
+      </div>
+
+      <div className="space-y-3">
+        {environment.variables.map((variable, index) => (
+          <div key={index} className="grid grid-cols-[1fr_2fr_auto] gap-3 items-center">
+            <input
+              type="text"
+              value={variable.key}
+              onChange={(e) => updateVariable(index, { key: e.target.value })}
+              placeholder="NAME"
+              className={`${inputClass} font-mono text-sm`}
+            />
+            <input
+              type="text"
+              value={variable.value}
+              onChange={(e) => updateVariable(index, { value: e.target.value })}
+              placeholder="value"
+              className={`${inputClass} font-mono text-sm`}
+            />
+            <button
+              onClick={() => update({ variables: environment.variables.filter((_, i) => i !== index) })}
+              className="p-2 text-red-500 hover:bg-red-500/10 rounded-lg"
+            >
+              <Trash2 size={16} />
+            </button>
+          </div>
+        ))}
+        <button
+          onClick={() => update({ variables: [...environment.variables, { key: '', value: '' }] })}
+          className="flex items-center gap-2 px-4 py-2 bg-black/5 dark:bg-white/5 rounded-xl font-medium hover:bg-black/10 dark:hover:bg-white/10"
+        >
+          <Plus size={16} />
+          Add variable
+        </button>
+      </div>
+
+      <div className="space-y-2">
+        <label className="text-sm font-medium text-gray-500 dark:text-white/60">Working folder</label>
+        <input
+          type="text"
+          value={environment.working_dir}
+          onChange={(e) => update({ working_dir: e.target.value })}
+          placeholder="Instance folder"
+          className={`${inputClass} font-mono text-sm`}
+        />
+        <p className="text-xs text-gray-500 dark:text-white/40">Relative to the instance folder. Leave empty to run the server there.</p>
+      </div>
+
+      <p className="text-xs text-gray-500 dark:text-white/40">
+        Hooks only run while the terminal is enabled in the app settings. Hooks of imported instances are removed.
+      </p>
+      <div className="grid grid-cols-1 md:grid-cols-2 gap-4">
+        <div className="space-y-2">
+          <label className="text-sm font-medium text-gray-500 dark:text-white/60">Pre-start hook</label>
+          <textarea
+            value={environment.pre_start_hook}
+            onChange={(e) => update({ pre_start_hook: e.target.value })}
+            rows={3}
+            placeholder="./sync-world.sh"
+            className={`${inputClass} font-mono text-sm`}
+          />
+          <p className="text-xs text-gray-500 dark:text-white/40">The server isn't started if this fails.</p>
+        </div>
+        <div className="space-y-2">
+          <label className="text-sm font-medium text-gray-500 dark:text-white/60">Post-stop hook</label>
+          <textarea
+            value={environment.post_stop_hook}
+            onChange={(e) => update({ post_stop_hook: e.target.value })}
+            rows={3}
+            placeholder="./upload-logs.sh"
+            className={`${inputClass} font-mono text-sm`}
+          />
+          <p className="text-xs text-gray-500 dark:text-white/40">Not run between automatic crash restarts.</p>
+        </div>
+      </div>
+    </div>
+  )
+}
@@ -0,0 +1,134 @@
+import { useEffect, useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { AlertTriangle, MessageSquare, Save, XCircle } from 'lucide-react'
+import { Motd, MotdFormat, MotdPreview } from '../types'
+import { useToast } from '../hooks/useToast'
+import { Select } from '../components/Select'
+
+/** SERVER_LIST_WIDTH in the core; the preview box is about that wide at 2x GUI scale. */
+const SERVER_LIST_WIDTH = 270;
+const FORMAT_KEYS: Record<MotdFormat, keyof Motd> = {
+  legacy: 'legacy',
+  json: 'json',
+  mini_message: 'mini_message',
+};
+
+export function MotdEditor({ instanceId }: { instanceId: string }) {
+  const [format, setFormat] = useState<MotdFormat>('legacy');
+  const [text, setText] = useState('');
+  const [preview, setPreview] = useState<MotdPreview | null>(null);
+  const [saving, setSaving] = useState(false);
+  const { showToast } = useToast();
+
+  useEffect(() => {
+    invoke<Motd>('get_motd', { instanceId })
+      .then(motd => setText(motd.legacy))
+      .catch(err => console.error('Failed to load MOTD:', err));
+  }, [instanceId]);
+
+  useEffect(() => {
+    const timeout = setTimeout(() => {
+      invoke<MotdPreview>('preview_motd', { text, format })
+        .then(setPreview)
+        .catch(err => console.error('Failed to preview MOTD:', err));
+    }, 150);
+    return () => clearTimeout(timeout);
+  }, [text, format]);
+
+  const changeFormat = async (next: MotdFormat) => {
+    try {
+      setText(await invoke<string>('convert_motd', { text, from: format, to: next }));
+      setFormat(next);
+    } catch (err) {
+      showToast(`Can't convert the MOTD: ${err}`, 'error');
+    }
+  };
+
+  const save = async () => {
+    setSaving(true);
+    try {
+      const motd = await invoke<Motd>('set_motd', { instanceId, text, format });
+      setText(motd[FORMAT_KEYS[format]]);
+      showToast('MOTD saved', 'success');
+    } catch (err) {
+      showToast(`Failed to save MOTD: ${err}`, 'error');
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  return (
+    <div className="space-y-4">
+      <div className="flex items-center justify-between gap-4">
+        <h3 className="text-lg font-bold flex items-center gap-2">
+          <MessageSquare size={20} className="text-primary" />
+          MOTD
+        </h3>
+        <div className="w-44">
+          <Select
+            value={format}
+            onChange={value => changeFormat(value as MotdFormat)}
+            options={[
+              { value: 'legacy', label: '§ codes' },
+              { value: 'mini_message', label: 'MiniMessage' },
+              { value: 'json', label: 'JSON' },
+            ]}
+          />
+        </div>
+      </div>
+
+      <textarea
+        value={text}
+        onChange={e => setText(e.target.value)}
+        spellCheck={false}
+        className="w-full bg-black/5 dark:bg-white/[0.05] border border-black/10 dark:border-white/10 rounded-xl py-2 px-4 font-mono text-sm focus:outline-none focus:ring-2 focus:ring-primary/50 transition-all min-h-[80px] resize-y"
+        placeholder="§6A Minecraft Server"
+      />
+
+      <div
+        className="rounded-xl bg-[#1e1e1e] p-3 font-mono text-[15px] leading-[18px] overflow-hidden"
+        style={{ width: SERVER_LIST_WIDTH * 2 + 24 }}
+      >
+        {(preview?.lines.length ? preview.lines : [[]]).slice(0, 2).map((line, i) => (
+          <div key={i} className="whitespace-pre overflow-hidden h-[18px]">
+            {line.map((span, j) => (
+              <span
+                key={j}
+                style={{
+                  color: span.color,
+                  fontWeight: span.bold ? 'bold' : undefined,
+                  fontStyle: span.italic ? 'italic' : undefined,
+                  textDecoration: [span.underlined && 'underline', span.strikethrough && 'line-through'].filter(Boolean).join(' ') || undefined,
+                  filter: span.obfuscated ? 'blur(3px)' : undefined,
+                }}
+              >
+                {span.text}
+              </span>
+            ))}
+          </div>
+        ))}
+      </div>
+
+      {preview?.issues.map(issue => (
+        <p
+          key={issue.message}
+          className={`flex items-start gap-2 text-xs ${issue.severity === 'error' ? 'text-accent-rose' : 'text-accent-amber'}`}
+        >
+          {issue.severity === 'error' ? <XCircle size={14} className="shrink-0 mt-0.5" /> : <AlertTriangle size={14} className="shrink-0 mt-0.5" />}
+          {issue.message}
+        </p>
+      ))}
+
+      <div className="flex justify-end">
+        <button
+          onClick={save}
+          disabled={saving || !preview?.valid}
+          className="flex items-center gap-2 px-4 py-2 rounded-xl bg-primary/10 text-primary text-sm font-bold hover:bg-primary hover:text-white transition-all disabled:opacity-50"
+        >
+          <Save size={16} />
+          Save MOTD
+        </button>
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,153 @@
+import { useEffect, useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { BellRing, Plus, Trash2, Save } from 'lucide-react'
+import { useToast } from '../hooks/useToast'
+import { NotificationRule, NotificationTrigger } from '../types'
+
+const TRIGGER_LABELS: Record<NotificationTrigger['type'], string> = {
+  log_match: 'Log line matches',
+  status_change: 'Status changes to',
+  player_join: 'Player joins',
+}
+
+const inputClass = 'bg-black/5 dark:bg-white/5 border border-black/10 dark:border-white/10 rounded-xl px-3 py-1.5 text-sm focus:outline-none focus:border-primary/50'
+
+function defaultTrigger(type: NotificationTrigger['type']): NotificationTrigger {
+  if (type === 'log_match') return { type, pattern: '' }
+  if (type === 'status_change') return { type, status: 'Crashed' }
+  return { type, player: null }
+}
+
+export function NotificationRules({ instanceId }: { instanceId: string }) {
+  const { showToast } = useToast()
+  const [rules, setRules] = useState<NotificationRule[]>([])
+
+  const loadRules = async () => {
+    try {
+      setRules(await invoke<NotificationRule[]>('list_notification_rules', { instanceId }))
+    } catch (err) {
+      console.error('Failed to load notification rules:', err)
+    }
+  }
+
+  useEffect(() => { loadRules() }, [instanceId])
+
+  const updateRule = (index: number, patch: Partial<NotificationRule>) => {
+    setRules(prev => prev.map((rule, i) => (i === index ? { ...rule, ...patch } : rule)))
+  }
+
+  const addRule = () => {
+    setRules(prev => [...prev, {
+      id: 0,
+      instance_id: instanceId,
+      name: 'New rule',
+      trigger: defaultTrigger('status_change'),
+      title: '{instance}',
+      body: 'Server is now {status}',
+      enabled: true,
+    }])
+  }
+
+  const saveRule = async (rule: NotificationRule) => {
+    try {
+      await invoke('save_notification_rule', { rule })
+      showToast('Notification rule saved', 'success')
+      loadRules()
+    } catch (err) {
+      showToast(`Failed to save rule: ${err}`, 'error')
+    }
+  }
+
+  const deleteRule = async (rule: NotificationRule, index: number) => {
+    if (rule.id === 0) {
+      setRules(prev => prev.filter((_, i) => i !== index))
+      return
+    }
+    try {
+      await invoke('delete_notification_rule', { ruleId: rule.id })
+      loadRules()
+    } catch (err) {
+      showToast(`Failed to delete rule: ${err}`, 'error')
+    }
+  }
+
+  return (
+    <div className="space-y-4">
+      <div className="flex items-center justify-between">
+        <div className="space-y-1">
+          <h3 className="text-lg font-bold flex items-center gap-2">
+            <BellRing size={20} className="text-primary" />
+            Desktop Notification Rules
+          </h3>
+          <p className="text-sm text-gray-500 dark:text-white/40">
+            Show a desktop notification when a trigger fires. Global rules apply to every instance.
+          </p>
+        </div>
+        <button onClick={addRule} className="flex items-center gap-2 px-4 py-2 bg-primary text-white rounded-xl font-bold">
+          <Plus size={16} /> Add Rule
+        </button>
+      </div>
+
+      {rules.map((rule, index) => (
+        <div key={rule.id || `new-${index}`} className="p-4 bg-black/5 dark:bg-white/[0.03] rounded-xl space-y-3">
+          <div className="flex flex-wrap items-center gap-3">
+            <input type="checkbox" checked={rule.enabled} onChange={(e) => updateRule(index, { enabled: e.target.checked })} className="w-5 h-5 rounded-lg text-primary" />
+            <input value={rule.name} onChange={(e) => updateRule(index, { name: e.target.value })} className={`${inputClass} font-medium flex-1`} />
+            <select
+              value={rule.instance_id ? 'instance' : 'global'}
+              onChange={(e) => updateRule(index, { instance_id: e.target.value === 'global' ? null : instanceId })}
+              className={inputClass}
+            >
+              <option value="instance">This instance</option>
+              <option value="global">All instances</option>
+            </select>
+            <button onClick={() => saveRule(rule)} className="p-2 text-accent-emerald hover:bg-accent-emerald/10 rounded-lg"><Save size={16} /></button>
+            <button onClick={() => deleteRule(rule, index)} className="p-2 text-red-500 hover:bg-red-500/10 rounded-lg"><Trash2 size={16} /></button>
+          </div>
+
+          <div className="flex flex-wrap items-center gap-3">
+            <select
+              value={rule.trigger.type}
+              onChange={(e) => updateRule(index, { trigger: defaultTrigger(e.target.value as NotificationTrigger['type']) })}
+              className={inputClass}
+            >
+              {Object.entries(TRIGGER_LABELS).map(([type, label]) => <option key={type} value={type}>{label}</option>)}
+            </select>
+            {rule.trigger.type === 'log_match' && (
+              <input
+                value={rule.trigger.pattern}
+                onChange={(e) => updateRule(index, { trigger: { type: 'log_match', pattern: e.target.value } })}
+                placeholder="Regex, e.g. \[Server thread/WARN\]"
+                className={`${inputClass} font-mono flex-1`}
+              />
+            )}
+            {rule.trigger.type === 'status_change' && (
+              <select
+                value={rule.trigger.status}
+                onChange={(e) => updateRule(index, { trigger: { type: 'status_change', status: e.target.value as 'Running' | 'Stopped' | 'Crashed' } })}
+                className={inputClass}
+              >
+                <option value="Running">Running</option>
+                <option value="Stopped">Stopped</option>
+                <option value="Crashed">Crashed</option>
+              </select>
+            )}
+            {rule.trigger.type === 'player_join' && (
+              <input
+                value={rule.trigger.player ?? ''}
+                onChange={(e) => updateRule(index, { trigger: { type: 'player_join', player: e.target.value || null } })}
+                placeholder="Any player"
+                className={`${inputClass} flex-1`}
+              />
+            )}
+          </div>
+
+          <div className="grid grid-cols-1 md:grid-cols-2 gap-3">
+            <input value={rule.title} onChange={(e) => updateRule(index, { title: e.target.value })} placeholder="Title" className={inputClass} />
+            <input value={rule.body} onChange={(e) => updateRule(index, { body: e.target.value })} placeholder="Body ({instance}, {line}, {player}, {status})" className={inputClass} />
+          </div>
+        </div>
+      ))}
+    </div>
+  )
+}
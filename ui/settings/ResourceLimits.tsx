@@ -0,0 +1,113 @@
+import { Gauge } from 'lucide-react'
+import { InstanceSettings, ResourceLimits as ResourceLimitsSettings, ProcessPriority } from '../types'
+import { cn } from '../utils'
+
+const DEFAULT_LIMITS: ResourceLimitsSettings = {
+  priority: 'normal',
+  cpu_affinity: [],
+  memory_limit_mb: 0,
+}
+
+const PRIORITIES: { id: ProcessPriority; label: string }[] = [
+  { id: 'low', label: 'Low' },
+  { id: 'below_normal', label: 'Below normal' },
+  { id: 'normal', label: 'Normal' },
+  { id: 'above_normal', label: 'Above normal' },
+  { id: 'high', label: 'High' },
+]
+
+const inputClass = 'w-full bg-black/5 dark:bg-white/5 border border-black/10 dark:border-white/10 rounded-xl px-3 py-2 focus:outline-none focus:border-primary/50'
+
+interface ResourceLimitsProps {
+  settings: InstanceSettings;
+  updateSetting: <K extends keyof InstanceSettings>(key: K, value: InstanceSettings[K]) => void;
+}
+
+export function ResourceLimits({ settings, updateSetting }: ResourceLimitsProps) {
+  const limits = settings.resource_limits ?? DEFAULT_LIMITS
+  const cpuCount = navigator.hardwareConcurrency || 1
+
+  const update = (patch: Partial<ResourceLimitsSettings>) =>
+    updateSetting('resource_limits', { ...limits, ...patch })
+  const toggleCpu = (cpu: number) =>
+    update({
+      cpu_affinity: limits.cpu_affinity.includes(cpu)
+        ? limits.cpu_affinity.filter(c => c !== cpu)
+        : [...limits.cpu_affinity, cpu].sort((a, b) => a - b),
+    })
+
+  return (
+    <div className="space-y-6">
+      <div className="space-y-2">
+        <h3 className="text-lg font-bold flex items-center gap-2">
+          <Gauge size={20} className="text-primary" />
+          Resource Limits
+        </h3>
+        <p className="text-sm text-gray-500 dark:text-white/40">
+          Keep this server from starving others on the same machine. Applied the next time the server starts.
+        </p>
+      </div>
+
+      <div className="grid grid-cols-1 md:grid-cols-2 gap-4">
+        <div className="space-y-2">
+          <label className="text-sm font-medium text-gray-500 dark:text-white/60">Process priority</label>
+          <select
+            value={limits.priority}
+            onChange={(e) => update({ priority: e.target.value as ProcessPriority })}
+            className={inputClass}
+          >
+            {PRIORITIES.map(({ id, label }) => (
+              <option key={id} value={id}>{label}</option>
+            ))}
+          </select>
+          <p className="text-xs text-gray-500 dark:text-white/40">Above normal needs root or CAP_SYS_NICE on Linux.</p>
+        </div>
+        <div className="space-y-2">
+          <label className="text-sm font-medium text-gray-500 dark:text-white/60">Memory cap (MB)</label>
+          <input
+            type="number"
+            min={0}
+            value={limits.memory_limit_mb}
+            onChange={(e) => update({ memory_limit_mb: Math.max(0, parseInt(e.target.value) || 0) })}
+            className={inputClass}
+          />
+          <p className="text-xs text-gray-500 dark:text-white/40">
+            Windows only. Must be above the maximum RAM, leaving room for the JVM's own overhead. 0 for no cap.
+          </p>
+        </div>
+      </div>
+
+      <div className="space-y-2">
+        <div className="flex items-center justify-between">
+          <label className="text-sm font-medium text-gray-500 dark:text-white/60">CPU affinity</label>
+          {limits.cpu_affinity.length > 0 && (
+            <button onClick={() => update({ cpu_affinity: [] })} className="text-xs text-primary hover:underline">
+              Use all CPUs
+            </button>
+          )}
+        </div>
+        <div className="flex flex-wrap gap-2">
+          {Array.from({ length: cpuCount }, (_, cpu) => (
+            <button
+              key={cpu}
+              onClick={() => toggleCpu(cpu)}
+              className={cn(
+                'w-10 h-10 rounded-xl text-xs font-bold font-mono border transition-all',
+                limits.cpu_affinity.includes(cpu)
+                  ? 'bg-primary/20 border-primary/50 text-primary'
+                  : 'bg-black/5 dark:bg-white/5 border-black/10 dark:border-white/10 text-gray-500'
+              )}
+            >
+              {cpu}
+            </button>
+          ))}
+        </div>
+        <p className="text-xs text-gray-500 dark:text-white/40">
+          {limits.cpu_affinity.length === 0
+            ? 'Runs on all CPUs. Pick some to keep the server on those only.'
+            : `Runs on ${limits.cpu_affinity.length} of ${cpuCount} CPUs. Not supported on macOS.`}
+        </p>
+      </div>
+    </div>
+  )
+}
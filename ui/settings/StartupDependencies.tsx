@@ -0,0 +1,59 @@
+import { useEffect, useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { GitBranch } from 'lucide-react'
+import { Instance, InstanceSettings } from '../types'
+
+interface StartupDependenciesProps {
+  instanceId: string;
+  settings: InstanceSettings;
+  updateSetting: <K extends keyof InstanceSettings>(key: K, value: InstanceSettings[K]) => void;
+}
+
+export function StartupDependencies({ instanceId, settings, updateSetting }: StartupDependenciesProps) {
+  const [others, setOthers] = useState<Instance[]>([])
+  const dependsOn = settings.depends_on ?? []
+
+  useEffect(() => {
+    invoke<Instance[]>('list_instances')
+      .then(list => setOthers(list.filter(i => i.id !== instanceId)))
+      .catch(console.error)
+  }, [instanceId])
+
+  const toggle = (id: string, checked: boolean) => {
+    updateSetting('depends_on', checked ? [...dependsOn, id] : dependsOn.filter(d => d !== id))
+  }
+
+  return (
+    <div className="space-y-4 pt-8 border-t border-black/10 dark:border-white/10">
+      <div className="space-y-1">
+        <h3 className="text-lg font-bold flex items-center gap-2">
+          <GitBranch size={20} className="text-primary" />
+          Startup Dependencies
+        </h3>
+        <p className="text-xs text-gray-500 dark:text-white/40">
+          When several servers are started together or on launch, this one waits until the selected servers are running.
+        </p>
+      </div>
+      {others.length === 0 ? (
+        <p className="text-sm text-gray-500 dark:text-white/40">No other instances to depend on.</p>
+      ) : (
+        <div className="grid grid-cols-1 md:grid-cols-2 gap-2">
+          {others.map(other => (
+            <label
+              key={other.id}
+              className="flex items-center gap-3 p-3 bg-black/5 dark:bg-white/[0.03] rounded-xl cursor-pointer hover:bg-black/10 dark:hover:bg-white/5 transition-colors"
+            >
+              <input
+                type="checkbox"
+                checked={dependsOn.includes(other.id)}
+                onChange={(e) => toggle(other.id, e.target.checked)}
+                className="w-5 h-5 rounded-lg border-black/10 dark:border-white/10 text-primary focus:ring-primary"
+              />
+              <span className="font-medium truncate">{other.name}</span>
+            </label>
+          ))}
+        </div>
+      )}
+    </div>
+  )
+}
@@ -0,0 +1,89 @@
+import { useEffect, useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { History, Skull, FileWarning, RotateCcw, ArrowUpCircle, Archive, SlidersHorizontal, SquareTerminal, Terminal, AlertTriangle, Globe, Loader2, RefreshCw } from 'lucide-react'
+import { cn } from '../utils'
+import { useToast } from '../hooks/useToast'
+import { TimelineEvent, TimelineKind } from '../types'
+
+const KIND_STYLES: Record<TimelineKind, { icon: typeof History; label: string; className: string }> = {
+  crash: { icon: Skull, label: 'Crash', className: 'text-red-500 bg-red-500/10' },
+  crash_report: { icon: FileWarning, label: 'Crash report', className: 'text-red-500 bg-red-500/10' },
+  restart: { icon: RotateCcw, label: 'Restart', className: 'text-amber-500 bg-amber-500/10' },
+  update_applied: { icon: ArrowUpCircle, label: 'Update', className: 'text-blue-500 bg-blue-500/10' },
+  backup: { icon: Archive, label: 'Backup', className: 'text-accent-emerald bg-accent-emerald/10' },
+  config_change: { icon: SlidersHorizontal, label: 'Config change', className: 'text-purple-500 bg-purple-500/10' },
+  console_command: { icon: Terminal, label: 'Console command', className: 'text-gray-500 bg-gray-500/10' },
+  shell_command: { icon: SquareTerminal, label: 'Terminal command', className: 'text-gray-500 bg-gray-500/10' },
+  task_failed: { icon: AlertTriangle, label: 'Task failed', className: 'text-amber-500 bg-amber-500/10' },
+  world_upgrade: { icon: Globe, label: 'World upgrade', className: 'text-accent-emerald bg-accent-emerald/10' },
+}
+
+export function Timeline({ instanceId }: { instanceId: string }) {
+  const { showToast } = useToast()
+  const [events, setEvents] = useState<TimelineEvent[]>([])
+  const [loading, setLoading] = useState(false)
+
+  const load = async () => {
+    setLoading(true)
+    try {
+      setEvents(await invoke<TimelineEvent[]>('get_instance_timeline', { instanceId }))
+    } catch (err) {
+      showToast(`Failed to load timeline: ${err}`, 'error')
+    } finally {
+      setLoading(false)
+    }
+  }
+
+  useEffect(() => {
+    load()
+  }, [instanceId])
+
+  return (
+    <div className="space-y-4">
+      <div className="flex items-center justify-between">
+        <div className="space-y-1">
+          <h3 className="text-lg font-bold flex items-center gap-2">
+            <History size={20} className="text-primary" />
+            Timeline
+          </h3>
+          <p className="text-sm text-gray-500 dark:text-white/40">
+            Crashes, restarts, updates, backups and config changes, newest first.
+          </p>
+        </div>
+        <button
+          onClick={load}
+          disabled={loading}
+          className="flex items-center gap-2 px-4 py-2 bg-black/5 dark:bg-white/5 rounded-xl font-bold disabled:opacity-50"
+        >
+          {loading ? <Loader2 size={16} className="animate-spin" /> : <RefreshCw size={16} />} Refresh
+        </button>
+      </div>
+
+      {!loading && events.length === 0 && (
+        <p className="text-sm text-gray-500 dark:text-white/40">Nothing has happened yet.</p>
+      )}
+
+      {events.map((event, index) => {
+        const { icon: Icon, label, className } = KIND_STYLES[event.kind]
+        return (
+          <div key={index} className="flex items-start gap-3 p-4 bg-black/5 dark:bg-white/[0.03] rounded-xl">
+            <div className={cn('p-1.5 rounded-lg shrink-0', className)}>
+              <Icon size={16} />
+            </div>
+            <div className="flex-1 min-w-0 space-y-1">
+              <div className="flex items-center justify-between gap-4">
+                <span className="text-sm font-semibold truncate">{event.summary}</span>
+                <span className="text-xs text-gray-500 dark:text-white/40 shrink-0">
+                  {label} · {new Date(event.occurred_at).toLocaleString()}
+                </span>
+              </div>
+              {event.details && (
+                <div className="text-xs text-gray-500 dark:text-white/40 break-words">{event.details}</div>
+              )}
+            </div>
+          </div>
+        )
+      })}
+    </div>
+  )
+}
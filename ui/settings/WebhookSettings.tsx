@@ -0,0 +1,152 @@
+import { useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+import { Bell, Send, RefreshCw } from 'lucide-react'
+import { useToast } from '../hooks/useToast'
+import { InstanceSettings, WebhookEventKey, WebhookFormat, WebhookSettings as Webhooks } from '../types'
+
+const DEFAULT_WEBHOOKS: Webhooks = {
+  enabled: false,
+  url: '',
+  format: 'discord',
+  events: {
+    server_started: true,
+    server_stopped: true,
+    server_crashed: true,
+    player_joined: false,
+    player_left: false,
+    backup_completed: true,
+    low_tps: true,
+  },
+  templates: {},
+  low_tps_threshold: 15,
+}
+
+const EVENTS: { key: WebhookEventKey; label: string; placeholders: string }[] = [
+  { key: 'server_started', label: 'Server started', placeholders: '{instance}' },
+  { key: 'server_stopped', label: 'Server stopped', placeholders: '{instance}' },
+  { key: 'server_crashed', label: 'Server crashed', placeholders: '{instance}, {reason}' },
+  { key: 'player_joined', label: 'Player joined', placeholders: '{instance}, {player}' },
+  { key: 'player_left', label: 'Player left', placeholders: '{instance}, {player}' },
+  { key: 'backup_completed', label: 'Backup completed', placeholders: '{instance}, {backup}' },
+  { key: 'low_tps', label: 'Low TPS', placeholders: '{instance}, {tps}' },
+]
+
+interface WebhookSettingsProps {
+  instanceId: string;
+  settings: InstanceSettings;
+  updateSetting: <K extends keyof InstanceSettings>(key: K, value: InstanceSettings[K]) => void;
+}
+
+export function WebhookSettings({ instanceId, settings, updateSetting }: WebhookSettingsProps) {
+  const { showToast } = useToast()
+  const [testing, setTesting] = useState(false)
+  const webhooks = settings.webhooks ?? DEFAULT_WEBHOOKS
+
+  const update = (patch: Partial<Webhooks>) => updateSetting('webhooks', { ...webhooks, ...patch })
+
+  const handleTest = async () => {
+    setTesting(true)
+    try {
+      await invoke('test_webhook', { instanceId, webhooks })
+      showToast('Test notification sent', 'success')
+    } catch (err) {
+      showToast(`Webhook test failed: ${err}`, 'error')
+    } finally {
+      setTesting(false)
+    }
+  }
+
+  return (
+    <div className="space-y-6">
+      <div className="space-y-2">
+        <h3 className="text-lg font-bold flex items-center gap-2">
+          <Bell size={20} className="text-primary" />
+          Webhook Notifications
+        </h3>
+        <p className="text-sm text-gray-500 dark:text-white/40">
+          Post server events to a Discord or Slack channel, or any endpoint that accepts JSON.
+        </p>
+      </div>
+
+      <label className="flex items-center gap-3 p-3 bg-black/5 dark:bg-white/[0.03] rounded-xl cursor-pointer">
+        <input
+          type="checkbox"
+          checked={webhooks.enabled}
+          onChange={(e) => update({ enabled: e.target.checked })}
+          className="w-5 h-5 rounded-lg border-black/10 dark:border-white/10 text-primary focus:ring-primary"
+        />
+        <p className="font-medium">Enable webhooks for this instance</p>
+      </label>
+
+      <div className="grid grid-cols-1 md:grid-cols-[1fr_auto_auto] gap-3 items-end">
+        <div className="space-y-2">
+          <label className="text-sm font-medium text-gray-500 dark:text-white/60">Webhook URL</label>
+          <input
+            type="url"
+            value={webhooks.url}
+            onChange={(e) => update({ url: e.target.value })}
+            placeholder="https://discord.com/api/webhooks/..."
+            className="w-full bg-black/5 dark:bg-white/5 border border-black/10 dark:border-white/10 rounded-xl px-4 py-2 focus:outline-none focus:border-primary/50"
+          />
+        </div>
+        <select
+          value={webhooks.format}
+          onChange={(e) => update({ format: e.target.value as WebhookFormat })}
+          className="bg-black/5 dark:bg-white/5 border border-black/10 dark:border-white/10 rounded-xl px-4 py-2"
+        >
+          <option value="discord">Discord</option>
+          <option value="slack">Slack</option>
+          <option value="generic">Generic JSON</option>
+        </select>
+        <button
+          onClick={handleTest}
+          disabled={testing || !webhooks.url.trim()}
+          className="flex items-center gap-2 px-4 py-2 bg-primary text-white rounded-xl font-bold disabled:opacity-50"
+        >
+          {testing ? <RefreshCw size={16} className="animate-spin" /> : <Send size={16} />}
+          Test
+        </button>
+      </div>
+
+      <div className="space-y-3">
+        {EVENTS.map(({ key, label, placeholders }) => (
+          <div key={key} className="p-3 bg-black/5 dark:bg-white/[0.03] rounded-xl space-y-2">
+            <label className="flex items-center gap-3 cursor-pointer">
+              <input
+                type="checkbox"
+                checked={webhooks.events[key]}
+                onChange={(e) => update({ events: { ...webhooks.events, [key]: e.target.checked } })}
+                className="w-5 h-5 rounded-lg border-black/10 dark:border-white/10 text-primary focus:ring-primary"
+              />
+              <span className="font-medium">{label}</span>
+              {key === 'low_tps' && (
+                <span className="flex items-center gap-2 text-sm text-gray-500 dark:text-white/40">
+                  below
+                  <input
+                    type="number"
+                    min={1}
+                    max={20}
+                    step={0.5}
+                    value={webhooks.low_tps_threshold}
+                    onChange={(e) => update({ low_tps_threshold: Number(e.target.value) })}
+                    className="w-20 bg-black/5 dark:bg-white/5 border border-black/10 dark:border-white/10 rounded-lg px-2 py-1"
+                  />
+                  TPS
+                </span>
+              )}
+            </label>
+            {webhooks.events[key] && (
+              <input
+                type="text"
+                value={webhooks.templates[key] ?? ''}
+                onChange={(e) => update({ templates: { ...webhooks.templates, [key]: e.target.value } })}
+                placeholder={`Default message (placeholders: ${placeholders})`}
+                className="w-full bg-black/5 dark:bg-white/5 border border-black/10 dark:border-white/10 rounded-xl px-3 py-1.5 text-sm focus:outline-none focus:border-primary/50"
+              />
+            )}
+          </div>
+        ))}
+      </div>
+    </div>
+  )
+}
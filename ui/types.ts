@@ -14,6 +14,8 @@ export interface Instance {
   max_players?: number;
   status: string;
   settings: InstanceSettings;
+  folia_plugins_only?: boolean;
+  linked?: boolean;
 }
 
 export interface InstanceSettings {
@@ -31,9 +33,110 @@ export interface InstanceSettings {
   bat_file?: string;
   crash_handling: CrashHandlingMode;
   icon_path?: string;
+  keep_running_on_close?: boolean;
+  lineage_id?: string;
+  production?: boolean;
+  tick_sample_interval?: number;
+  webhooks?: WebhookSettings;
+  idle_stop_minutes?: number;
+  idle_exempt_players?: string[];
+  wake_on_connect?: boolean;
+  depends_on?: string[];
+  heartbeat?: HeartbeatSettings;
+  jvm?: JvmOptions;
+  tunnel_address?: string;
+  environment?: LaunchEnvironment;
+  resource_limits?: ResourceLimits;
 }
 
-export type LaunchMethod = 'StartupLine' | 'BatFile';
+export interface EnvVar {
+  key: string;
+  value: string;
+}
+
+export interface LaunchEnvironment {
+  variables: EnvVar[];
+  working_dir: string;
+  pre_start_hook: string;
+  post_stop_hook: string;
+}
+
+export type ProcessPriority = 'low' | 'below_normal' | 'normal' | 'above_normal' | 'high';
+
+export interface ResourceLimits {
+  priority: ProcessPriority;
+  cpu_affinity: number[];
+  memory_limit_mb: number;
+}
+
+export interface JavaAgent {
+  enabled: boolean;
+  path: string;
+  options: string;
+}
+
+export interface JvmOptions {
+  agents: JavaAgent[];
+  debug: {
+    enabled: boolean;
+    port: number;
+    bind_address: string;
+    suspend: boolean;
+  };
+  extra_flags: string[];
+}
+
+export interface HeartbeatSettings {
+  enabled: boolean;
+  url: string;
+  interval_secs: number;
+  report_failures: boolean;
+}
+
+export type WebhookFormat = 'discord' | 'slack' | 'generic';
+
+export type WebhookEventKey =
+  | 'server_started'
+  | 'server_stopped'
+  | 'server_crashed'
+  | 'player_joined'
+  | 'player_left'
+  | 'backup_completed'
+  | 'low_tps';
+
+export interface WebhookSettings {
+  enabled: boolean;
+  url: string;
+  format: WebhookFormat;
+  events: Record<WebhookEventKey, boolean>;
+  templates: Partial<Record<WebhookEventKey, string>>;
+  low_tps_threshold: number;
+}
+
+export type NotificationTrigger =
+  | { type: 'log_match'; pattern: string }
+  | { type: 'status_change'; status: 'Running' | 'Stopped' | 'Crashed' }
+  | { type: 'player_join'; player: string | null };
+
+export interface NotificationRule {
+  id: number;
+  instance_id: string | null;
+  name: string;
+  trigger: NotificationTrigger;
+  title: string;
+  body: string;
+  enabled: boolean;
+  created_at?: string;
+}
+
+export interface DesktopNotification {
+  instance_id: string;
+  rule_id: number;
+  title: string;
+  body: string;
+}
+
+export type LaunchMethod = 'StartupLine' | 'BatFile' | 'CustomCommand';
 
 export type CrashHandlingMode = 'Nothing' | 'Elevated' | 'Aggressive';
 
@@ -44,10 +147,22 @@ export interface ResourceUsage {
   disk_write: number;
   uptime: number;
   player_count: number;
+  tps: number | null;
+  mspt: number | null;
+  tick_estimated: boolean;
   timestamp?: number;
 }
 
-export type TabId = 'dashboard' | 'console' | 'logs' | 'stats' | 'plugins' | 'mods' | 'players' | 'config' | 'backups' | 'scheduler' | 'settings';
+export interface MetricSample {
+  sampled_at: string;
+  cpu_usage: number;
+  memory_usage: number;
+  player_count: number;
+  tps: number | null;
+  mspt: number | null;
+}
+
+export type TabId = 'dashboard' | 'console' | 'chat' | 'logs' | 'stats' | 'plugins' | 'mods' | 'players' | 'config' | 'files' | 'backups' | 'scheduler' | 'settings';
 
 export type TransitionType = 'starting' | 'stopping' | 'restarting';
 
@@ -86,6 +201,20 @@ export interface UserCacheEntry {
   expiresOn: string;
 }
 
+export type AccessIssue = 'empty_whitelist' | 'no_operators';
+
+export type AccessFix =
+  | { action: 'disable_whitelist' }
+  | { action: 'whitelist_known_players' }
+  | { action: 'add_to_whitelist' }
+  | { action: 'add_operator' };
+
+export interface AccessWarning {
+  issue: AccessIssue;
+  message: string;
+  fixes: AccessFix[];
+}
+
 export interface AllPlayerLists {
   whitelist: PlayerEntry[];
   ops: OpEntry[];
@@ -105,6 +234,11 @@ export interface BackupInfo {
 
 export type ScheduleType = 'Backup' | 'Restart';
 
+export interface RestartWarning {
+  delay_secs: number;
+  message?: string | null;
+}
+
 export interface ScheduledTask {
   id: string;
   instance_id: string;
@@ -113,6 +247,60 @@ export interface ScheduledTask {
   enabled: boolean;
   last_run?: string;
   next_run?: string;
+  warning?: RestartWarning | null;
+}
+
+export type FindingSeverity = 'info' | 'warning' | 'error';
+
+export type SuggestedFix =
+  | { action: 'reinstall' }
+  | { action: 'repair_installation' }
+  | { action: 'accept_eula' }
+  | { action: 'change_port'; port: number }
+  | { action: 'install_java'; major_version: number }
+  | { action: 'delete_file'; path: string }
+  | { action: 'disable_mod'; filename: string };
+
+export interface Finding {
+  check: string;
+  severity: FindingSeverity;
+  message: string;
+  suggestion: string;
+  fix: SuggestedFix | null;
+}
+
+export interface DiagnosisReport {
+  instance_id: string;
+  checked_at: string;
+  checks: string[];
+  findings: Finding[];
+}
+
+export type TimelineKind = 'crash' | 'crash_report' | 'restart' | 'update_applied' | 'backup' | 'config_change' | 'console_command' | 'shell_command' | 'task_failed' | 'world_upgrade';
+
+export interface TimelineEvent {
+  instance_id: string;
+  kind: TimelineKind;
+  occurred_at: string;
+  summary: string;
+  details?: string;
+}
+
+export type InstallerPhase = 'download_installer' | 'run_installer' | 'verify_output';
+
+export interface InstallerRepair {
+  loader: string;
+  problems: string[];
+  rerun_from: InstallerPhase | null;
+  removed_files: number;
+}
+
+export interface AutomationImportSummary {
+  schedules_added: number;
+  schedules_skipped: number;
+  webhooks_updated: number;
+  rules_added: number;
+  rules_updated: number;
 }
 
 export interface InstalledPlugin {
@@ -140,7 +328,7 @@ export interface PluginUpdate {
   provider: PluginProvider;
 }
 
-export type PluginProvider = 'Modrinth' | 'Spiget' | 'Hangar';
+export type PluginProvider = 'Modrinth' | 'Spiget' | 'Hangar' | 'CurseForge';
 
 export type ModProvider = 'Modrinth' | 'CurseForge';
 
@@ -213,6 +401,277 @@ export interface ModUpdate {
   provider: ModProvider;
 }
 
+export interface ContentReportEntry {
+  kind: 'Mod' | 'Plugin';
+  name: string;
+  filename: string;
+  enabled: boolean;
+  version?: string;
+  author?: string;
+  provider?: string;
+  project_id?: string;
+  license?: string;
+  update: {
+    checked_at?: string;
+    update_available?: boolean;
+    latest_version?: string;
+  };
+}
+
+export interface ContentReport {
+  generated_at: string;
+  entries: ContentReportEntry[];
+}
+
+export interface CrashReportSummary {
+  id: number;
+  instance_id: string;
+  filename: string;
+  detected_at: string;
+  occurred_at: string | null;
+  description: string | null;
+  exception: string | null;
+  likely_culprit: string | null;
+}
+
+export interface CrashReport extends CrashReportSummary {
+  details: {
+    time: string | null;
+    description: string | null;
+    exception: string | null;
+    stack_trace: string[];
+    suspected_mods: string[];
+    system_details: Record<string, string>;
+  };
+}
+
+export interface WorldInfo {
+  name: string;
+  active: boolean;
+  size: number;
+  last_modified: string | null;
+  dimensions: string[];
+}
+
+export interface VersionCompatibility {
+  version: string;
+  protocol: number | null;
+  compatible_clients: string[];
+  min_java: number;
+}
+
+export interface ProxyBackend {
+  name: string;
+  address: string;
+  instance_id: string | null;
+  instance_name: string | null;
+  version: string | null;
+  protocol: number | null;
+  mismatch: boolean;
+}
+
+export interface ProxyCompatibilityReport {
+  proxy_id: string;
+  backends: ProxyBackend[];
+  warnings: string[];
+}
+
+export type ViaPlugin = 'ViaVersion' | 'ViaBackwards';
+
+export interface ViaTarget {
+  instance_id: string;
+  instance_name: string;
+  loader: string;
+}
+
+export interface ViaPlan {
+  targets: ViaTarget[];
+  notes: string[];
+}
+
+export interface ViaSetupReport {
+  plan: ViaPlan;
+  changes: {
+    instance_name: string;
+    plugin: ViaPlugin;
+    version: string;
+    action: 'installed' | 'updated' | 'up_to_date';
+  }[];
+}
+
+export type CrossplayComponent = 'Geyser' | 'Floodgate';
+
+export type GeyserPlatform = 'spigot' | 'velocity' | 'bungeecord' | 'fabric' | 'neoforge';
+
+export interface CrossplayTarget {
+  instance_id: string;
+  instance_name: string;
+  platform: GeyserPlatform;
+  components: CrossplayComponent[];
+}
+
+export interface CrossplayPlan {
+  host: CrossplayTarget;
+  backends: CrossplayTarget[];
+  bedrock_port: number;
+  notes: string[];
+}
+
+export interface CrossplaySetupReport {
+  plan: CrossplayPlan;
+  changes: {
+    instance_name: string;
+    component: CrossplayComponent;
+    version: string;
+    action: 'installed' | 'updated' | 'up_to_date';
+  }[];
+  configured: string[];
+}
+
+export type MotdFormat = 'legacy' | 'json' | 'mini_message';
+
+export interface Motd {
+  legacy: string;
+  json: string;
+  mini_message: string;
+  plain: string;
+}
+
+export interface PreviewSpan {
+  text: string;
+  /** Always `#rrggbb`. */
+  color: string;
+  bold: boolean;
+  italic: boolean;
+  underlined: boolean;
+  strikethrough: boolean;
+  obfuscated: boolean;
+}
+
+export interface MotdIssue {
+  severity: 'error' | 'warning';
+  message: string;
+}
+
+/** Result of `preview_motd`: the MOTD as the server list renders it. */
+export interface MotdPreview {
+  lines: PreviewSpan[][];
+  line_widths: number[];
+  legacy: string;
+  plain: string;
+  issues: MotdIssue[];
+  valid: boolean;
+}
+
+export interface LoadoutEntry {
+  kind: 'Mod' | 'Plugin';
+  file: string;
+  enabled: boolean;
+}
+
+export interface Loadout {
+  name: string;
+  entries: LoadoutEntry[];
+}
+
+export interface LoadoutStore {
+  loadouts: Loadout[];
+  active: string | null;
+  previous: LoadoutEntry[] | null;
+}
+
+export interface LoadoutResult {
+  changed: string[];
+  missing: string[];
+}
+
+export interface InstalledVersion {
+  kind: 'Mod' | 'Plugin';
+  project_id: string;
+  filename: string;
+  version: string | null;
+  version_id: string | null;
+  sha1: string;
+  replaced_at: string;
+}
+
+export interface BulkItemResult {
+  filename: string;
+  error: string | null;
+}
+
+export interface BulkReport {
+  results: BulkItemResult[];
+}
+
+export interface BulkProgress {
+  instance_id: string;
+  completed: number;
+  total: number;
+  filename: string;
+  error: string | null;
+}
+
+export type TemplateContent =
+  | { kind: 'mod'; project_id: string; provider: string; version_id: string | null; enabled: boolean }
+  | { kind: 'plugin'; project_id: string; provider: string; version_id: string | null; enabled: boolean };
+
+export interface InstanceTemplate {
+  id: number;
+  name: string;
+  description: string | null;
+  version: string;
+  mod_loader: string | null;
+  loader_version: string | null;
+  settings: InstanceSettings;
+  content: TemplateContent[];
+  config_files: { rel_path: string; content: string }[];
+  skipped: string[];
+  created_at: string;
+}
+
+export interface TemplateReport {
+  instance: Instance;
+  installed: string[];
+  failed: string[];
+}
+
+/** Stable code of an error returned by a command, see AppError::code in the core. */
+export type AppErrorCode =
+  | 'io' | 'database' | 'network' | 'instance' | 'server' | 'config' | 'validation' | 'internal'
+  | 'not_found' | 'conflict' | 'plugin' | 'mod' | 'backup' | 'scheduler' | 'java'
+  | 'insufficient_space' | 'rejected_download';
+
+export interface AppError {
+  type: string;
+  code: AppErrorCode;
+  message: any;
+}
+
+export interface InsufficientSpace {
+  path: string;
+  required: number;
+  available: number;
+}
+
+export interface ImportPreflight {
+  name_error: string | null;
+  source_error: string | null;
+  estimated_size: number;
+  file_count: number;
+  available_space: number | null;
+  insufficient_space: InsufficientSpace | null;
+  root_within_zip: string | null;
+  root_detected: boolean;
+}
+
+export type BuildChannel = 'default' | 'experimental';
+
+export interface PaperBuild {
+  build: string;
+  channel: BuildChannel;
+}
+
 export interface ProjectVersion {
   id: string;
   project_id: string;
@@ -237,3 +696,267 @@ export interface ModpackProgress {
   filesCompleted?: number;
   totalFiles?: number;
 }
+
+export interface StorageRoot {
+  path: string;
+  is_default: boolean;
+  instance_count: number;
+  available_space: number | null;
+}
+
+export type StorageMode = 'portable' | 'installed';
+
+export interface StorageModeInfo {
+  mode: StorageMode;
+  data_dir: string;
+  portable_dir: string;
+  installed_dir: string;
+  portable_available: boolean;
+  pending: StorageMode | null;
+}
+
+export type TaskKind = 'import' | 'export' | 'download' | 'install' | 'modpack' | 'backup' | 'java' | 'pregen' | 'shell' | 'worldupgrade' | 'move' | 'profile';
+export type TaskState = 'running' | 'completed' | 'failed' | 'cancelled';
+
+/** Payload of the 'task-progress' event and entries of `list_tasks`. */
+export interface TaskInfo {
+  id: string;
+  kind: TaskKind;
+  title: string;
+  instance_id: string | null;
+  current: number;
+  total: number;
+  message: string;
+  state: TaskState;
+  error: string | null;
+  cancellable: boolean;
+  pausable: boolean;
+  paused: boolean;
+  started_at: string;
+  finished_at: string | null;
+}
+
+export type LogLevel = 'trace' | 'debug' | 'info' | 'warn' | 'error';
+
+export interface ConsoleLine {
+  number: number;
+  /** As printed, with ANSI and `§` color codes. */
+  raw: string;
+  /** With color codes stripped. */
+  text: string;
+  level: LogLevel | null;
+  time: string | null;
+}
+
+/** Result of `get_console_buffer`. */
+export interface ConsolePage {
+  lines: ConsoleLine[];
+  next_line: number;
+  dropped: number;
+}
+
+/** Payload of the 'server-chat' event and entries of `get_recent_chat`. */
+export type ChatEvent =
+  | { type: 'chat_message'; player: string; message: string }
+  | { type: 'join'; player: string }
+  | { type: 'leave'; player: string }
+  | { type: 'death'; player: string; message: string }
+  | { type: 'advancement'; player: string; advancement: string; kind: 'advancement' | 'goal' | 'challenge' };
+
+export type EventChannel = 'startup' | 'task';
+
+/** Entries of `fetch_recent_events`; the payload is a startup phase or a `TaskInfo`. */
+export interface RecordedEvent {
+  seq: number;
+  instance_id: string | null;
+  channel: EventChannel;
+  at: string;
+  payload: any;
+}
+
+export type PregenMethod = 'auto' | 'chunky' | 'forceload';
+
+export interface PregenRequest {
+  world: string;
+  center_x: number;
+  center_z: number;
+  radius: number;
+  method: PregenMethod;
+}
+
+export interface ProfilerRequest {
+  duration_secs: number | null;
+  all_threads: boolean;
+}
+
+export type ProfilerSessionStatus = 'running' | 'completed' | 'cancelled' | 'failed';
+
+export interface ProfilerSession {
+  id: number;
+  instance_id: string;
+  started_at: string;
+  finished_at: string | null;
+  duration_secs: number | null;
+  status: ProfilerSessionStatus;
+  url: string | null;
+  error: string | null;
+}
+
+export type ConflictKind = 'duplicate_project' | 'duplicate_id' | 'incompatible';
+
+export interface InstallWarning {
+  kind: ConflictKind;
+  message: string;
+  other_file: string;
+}
+
+export interface InstallResult {
+  filename: string;
+  warnings: InstallWarning[];
+}
+
+export interface WorldUpgradeRequest {
+  erase_cache: boolean;
+}
+
+export interface WorldUpgradeStatus {
+  world: string;
+  world_version?: string;
+  server_version: string;
+  outdated?: boolean;
+}
+
+export interface ChunkPos {
+  x: number;
+  z: number;
+}
+
+export interface LevelSummary {
+  level_name?: string;
+  version_name?: string;
+  data_version?: number;
+  /** Decimal string; a 64-bit seed doesn't fit in a JS number. */
+  seed: string;
+  spawn: [number, number, number];
+  spawn_chunks: {
+    center: ChunkPos;
+    radius: number;
+    min: ChunkPos;
+    max: ChunkPos;
+  };
+}
+
+export interface CoordinateInfo {
+  x: number;
+  z: number;
+  chunk: ChunkPos;
+  chunk_origin: [number, number];
+  region_file: string;
+  nether: [number, number];
+  overworld: [number, number];
+}
+
+export interface SlimeChunkCheck {
+  chunk: ChunkPos;
+  is_slime_chunk: boolean;
+  nearby: ChunkPos[];
+}
+
+export interface InstanceCleanup {
+  id: string;
+  name: string;
+  instance_bytes: number;
+  backups_removed: number;
+  backup_bytes: number;
+  schedules_removed: number;
+  db_rows_removed: number;
+}
+
+/** Returned by `delete_instance` and `delete_instances`. */
+export interface DeletionSummary {
+  deleted: InstanceCleanup[];
+  failed: { id: string; error: string }[];
+  artifact_bytes: number;
+  reclaimed_bytes: number;
+}
+
+export type AddressKind = 'lan' | 'public' | 'tunnel';
+
+export interface ConnectionAddress {
+  kind: AddressKind;
+  address: string;
+}
+
+export interface ConnectionInfo {
+  port: number;
+  bedrock: boolean;
+  addresses: ConnectionAddress[];
+  notes: string[];
+  invite: string;
+}
+
+/** Answer to a Server List Ping, as the multiplayer screen shows it. */
+export interface ServerStatusReport {
+  version_name: string;
+  protocol: number;
+  players_online: number;
+  players_max: number;
+  player_sample: string[];
+  motd: string;
+  motd_plain: string;
+  favicon: string | null;
+  latency_ms: number | null;
+}
+
+export interface QueryResponse {
+  motd: string;
+  game_type: string;
+  version: string;
+  plugins: string;
+  map: string;
+  players_online: number;
+  players_max: number;
+  host_port: number | null;
+  players: string[];
+}
+
+export interface ServerProbe {
+  host: string;
+  port: number;
+  status: ServerStatusReport;
+  query: QueryResponse | null;
+}
+
+export interface FileEntry {
+  name: string;
+  path: string;
+  is_dir: boolean;
+  is_symlink: boolean;
+  size: number;
+  modified: string | null;
+}
+
+export interface FileChunk {
+  data: string;
+  offset: number;
+  next_offset: number;
+  total_size: number;
+  eof: boolean;
+}
+
+export interface TextChunk {
+  content: string;
+  offset: number;
+  next_offset: number;
+  total_size: number;
+  eof: boolean;
+  binary: boolean;
+}
+
+export type FileChangeKind = 'config' | 'mods' | 'plugins';
+
+export interface FileChange {
+  instance_id: string;
+  kind: FileChangeKind;
+  paths: string[];
+}
@@ -1,5 +1,6 @@
 import { clsx, type ClassValue } from 'clsx'
 import { twMerge } from 'tailwind-merge'
+import type { AppError, AppErrorCode } from './types'
 
 export function cn(...inputs: ClassValue[]) {
   return twMerge(clsx(inputs))
@@ -33,8 +34,24 @@ export function getParentDir(path: string): string {
   return parts.join('/');
 }
 
+/** The code of an error thrown by a command, or null for errors that didn't come from one. */
+export function errorCode(err: unknown): AppErrorCode | null {
+  if (err && typeof err === 'object' && 'code' in err && typeof (err as AppError).code === 'string') {
+    return (err as AppError).code;
+  }
+  return null;
+}
+
 export function formatError(err: any): string {
   if (typeof err === 'string') return err;
+  if (err?.type === 'InsufficientSpace' && err.message) {
+    const { path, required, available } = err.message;
+    return `Not enough disk space on ${path}: need ${formatSize(required)} free, have ${formatSize(available)}`;
+  }
+  if (err?.type === 'RejectedDownload' && err.message) {
+    const { file_name, reason, quarantined_to } = err.message;
+    return `Rejected download ${file_name}: ${reason}${quarantined_to ? ` (moved to ${quarantined_to})` : ''}`;
+  }
   if (err && typeof err === 'object' && 'message' in err) {
     return err.message as string;
   }
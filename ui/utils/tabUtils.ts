@@ -9,17 +9,21 @@ import {
   Calendar,
   FileText,
   Sliders,
-  BarChart3
+  BarChart3,
+  FolderOpen,
+  MessageSquare
 } from 'lucide-react'
 import { TabId } from '../types'
 
 export const ALL_TABS: { id: TabId; label: string; icon: any }[] = [
   { id: 'dashboard', label: 'Dashboard', icon: LayoutDashboard },
   { id: 'console', label: 'Console', icon: Terminal },
+  { id: 'chat', label: 'Chat', icon: MessageSquare },
   { id: 'logs', label: 'Logs', icon: FileText },
   { id: 'stats', label: 'Statistics', icon: BarChart3 },
   { id: 'players', label: 'Players', icon: Users },
   { id: 'config', label: 'Config', icon: Sliders },
+  { id: 'files', label: 'Files', icon: FolderOpen },
   { id: 'plugins', label: 'Plugins', icon: Puzzle },
   { id: 'mods', label: 'Mods', icon: Layers },
   { id: 'backups', label: 'Backups', icon: History },
@@ -30,7 +34,7 @@ export const ALL_TABS: { id: TabId; label: string; icon: any }[] = [
 export const supportsPlugins = (loader?: string) => {
   if (!loader) return false;
   const l = loader.toLowerCase();
-  return ['paper', 'purpur', 'spigot', 'bukkit', 'velocity'].includes(l);
+  return ['paper', 'purpur', 'pufferfish', 'folia', 'spigot', 'bukkit', 'craftbukkit', 'velocity'].includes(l);
 };
 
 export const supportsMods = (loader?: string) => {